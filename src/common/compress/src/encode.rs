@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Result;
+
+use async_compression::futures::write::BrotliEncoder;
+use async_compression::futures::write::BzEncoder;
+use async_compression::futures::write::DeflateEncoder;
+use async_compression::futures::write::GzipEncoder;
+use async_compression::futures::write::LzmaEncoder;
+use async_compression::futures::write::XzEncoder;
+use async_compression::futures::write::ZlibEncoder;
+use async_compression::futures::write::ZstdEncoder;
+use futures::io::AsyncWriteExt;
+
+use crate::CompressAlgorithm;
+
+macro_rules! encode_all {
+    ($encoder:ident, $data:expr) => {{
+        let mut encoder = $encoder::new(Vec::with_capacity($data.len() / 2));
+        encoder.write_all($data).await?;
+        encoder.close().await?;
+        encoder.into_inner()
+    }};
+}
+
+/// Compress the whole data at once, e.g. the content of a file to write.
+pub async fn compress_all(algo: CompressAlgorithm, data: &[u8]) -> Result<Vec<u8>> {
+    let compressed = match algo {
+        CompressAlgorithm::Brotli => encode_all!(BrotliEncoder, data),
+        CompressAlgorithm::Bz2 => encode_all!(BzEncoder, data),
+        CompressAlgorithm::Deflate => encode_all!(DeflateEncoder, data),
+        CompressAlgorithm::Gzip => encode_all!(GzipEncoder, data),
+        CompressAlgorithm::Lzma => encode_all!(LzmaEncoder, data),
+        CompressAlgorithm::Xz => encode_all!(XzEncoder, data),
+        CompressAlgorithm::Zlib => encode_all!(ZlibEncoder, data),
+        CompressAlgorithm::Zstd => encode_all!(ZstdEncoder, data),
+    };
+    Ok(compressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::io::Cursor;
+    use futures::AsyncReadExt;
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::DecompressReader;
+
+    #[tokio::test]
+    async fn test_compress_all_round_trip() -> Result<()> {
+        let mut rng = ThreadRng::default();
+        let size = rng.gen_range(1..1024 * 1024);
+        let mut content = vec![0; size];
+        rng.fill_bytes(&mut content);
+
+        for algo in [
+            CompressAlgorithm::Gzip,
+            CompressAlgorithm::Zstd,
+            CompressAlgorithm::Bz2,
+            CompressAlgorithm::Xz,
+        ] {
+            let compressed = compress_all(algo, &content).await?;
+
+            let mut reader = DecompressReader::new(Cursor::new(compressed), algo);
+            let mut result = vec![];
+            reader.read_to_end(&mut result).await?;
+            assert_eq!(result, content, "{algo:?}");
+        }
+        Ok(())
+    }
+}
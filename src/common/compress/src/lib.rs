@@ -17,8 +17,11 @@
 //! This mod provides compress support for BytesWrite and decompress support for BytesRead.
 
 mod compress;
+mod encode;
+
 pub use compress::CompressAlgorithm;
 pub use compress::DecompressCodec;
 pub use compress::DecompressDecoder;
 pub use compress::DecompressReader;
 pub use compress::DecompressState;
+pub use encode::compress_all;
@@ -196,6 +196,10 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    UnknownPreparedCommit(2017),
+    PreparedCommitAlreadyExists(2018),
+    TransactionConflict(2019),
+    InvalidTransactionState(2020),
 
     // User api error codes.
     UnknownUser(2201),
@@ -256,6 +260,16 @@ build_exceptions! {
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
 
+    // Task error codes.
+    IllegalTaskFormat(2611),
+    UnknownTask(2612),
+    TaskAlreadyExists(2613),
+    InvalidTaskDependency(2614),
+
+    // Plan baseline error codes.
+    IllegalPlanBaselineFormat(2621),
+    UnknownPlanBaseline(2622),
+
     // Database error codes.
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
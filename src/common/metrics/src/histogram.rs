@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The time from the query being accepted to its result being finished, in seconds.
+pub const METRIC_QUERY_LATENCY_SECONDS: &str = "query_latency_seconds";
+/// The time from the query being accepted to its pipeline starting to execute, in seconds.
+pub const METRIC_QUERY_QUEUE_WAIT_SECONDS: &str = "query_queue_wait_seconds";
+/// The time of reading the columns of a block from the storage, in seconds.
+pub const METRIC_BLOCK_READ_LATENCY_SECONDS: &str = "fuse_block_read_latency_seconds";
+
+/// The histograms exported with [`LATENCY_BUCKETS_SECONDS`] instead of as summaries.
+pub const LATENCY_HISTOGRAMS: &[&str] = &[
+    METRIC_QUERY_LATENCY_SECONDS,
+    METRIC_QUERY_QUEUE_WAIT_SECONDS,
+    METRIC_BLOCK_READ_LATENCY_SECONDS,
+];
+
+/// The upper bounds of the buckets of the latency histograms, from 1ms to 10min.
+pub const LATENCY_BUCKETS_SECONDS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0, 600.0,
+];
@@ -16,6 +16,7 @@
 
 pub mod counter;
 mod dump;
+mod histogram;
 mod recorder;
 
 pub use dump::dump_metric_samples;
@@ -23,6 +24,11 @@ pub use dump::HistogramCount;
 pub use dump::MetricSample;
 pub use dump::MetricValue;
 pub use dump::SummaryCount;
+pub use histogram::LATENCY_BUCKETS_SECONDS;
+pub use histogram::LATENCY_HISTOGRAMS;
+pub use histogram::METRIC_BLOCK_READ_LATENCY_SECONDS;
+pub use histogram::METRIC_QUERY_LATENCY_SECONDS;
+pub use histogram::METRIC_QUERY_QUEUE_WAIT_SECONDS;
 pub use metrics::Counter;
 pub use metrics::Gauge;
 pub use metrics::Histogram;
@@ -20,12 +20,16 @@ use metrics::decrement_gauge;
 use metrics::gauge;
 use metrics::histogram;
 use metrics::increment_gauge;
+use metrics_exporter_prometheus::Matcher;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use metrics_exporter_prometheus::PrometheusHandle;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use tracing::warn;
 
+use crate::LATENCY_BUCKETS_SECONDS;
+use crate::LATENCY_HISTOGRAMS;
+
 static PROMETHEUS_HANDLE: Lazy<Arc<RwLock<Option<PrometheusHandle>>>> =
     Lazy::new(|| Arc::new(RwLock::new(None)));
 
@@ -107,7 +111,13 @@ pub fn init_default_metrics_recorder() {
 
 /// Init prometheus recorder.
 fn init_prometheus_recorder() {
-    let recorder = PrometheusBuilder::new().build_recorder();
+    let mut builder = PrometheusBuilder::new();
+    for name in LATENCY_HISTOGRAMS {
+        builder = builder
+            .set_buckets_for_metric(Matcher::Full(name.to_string()), LATENCY_BUCKETS_SECONDS)
+            .expect("the latency buckets are not empty");
+    }
+    let recorder = builder.build_recorder();
     let mut h = PROMETHEUS_HANDLE.as_ref().write();
     *h = Some(recorder.handle());
     unsafe {
@@ -26,7 +26,10 @@ async fn test_dump_metric_samples() -> common_exception::Result<()> {
     metrics::counter!("test.test2_count", 2);
 
     #[cfg(feature = "enable_histogram")]
-    metrics::histogram!("test.test_query_usedtime", 2.0);
+    {
+        metrics::histogram!("test.test_query_usedtime", 2.0);
+        metrics::histogram!(common_metrics::METRIC_QUERY_LATENCY_SECONDS, 0.2);
+    }
 
     let handle = crate::try_handle().unwrap();
     let samples = dump_metric_samples(handle)
@@ -47,6 +50,18 @@ async fn test_dump_metric_samples() -> common_exception::Result<()> {
             _ => return Err(ErrorCode::Internal("test failed")),
         };
         assert_eq!(7, summaries.len());
+
+        // the latency metrics are histograms with the latency buckets and `+Inf`
+        let buckets = match &samples.get("query_latency_seconds").unwrap().value {
+            MetricValue::Histogram(buckets) => buckets,
+            _ => return Err(ErrorCode::Internal("test failed")),
+        };
+        assert_eq!(
+            common_metrics::LATENCY_BUCKETS_SECONDS.len() + 1,
+            buckets.len()
+        );
+        assert_eq!(0.0, buckets[4].count);
+        assert_eq!(1.0, buckets[5].count);
     }
 
     Ok(())
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+
+thread_local! {
+    static CURRENT_COUNTERS: Cell<ProfCounters> = Cell::new(ProfCounters::default());
+}
+
+/// Counters recorded by the code running on the current thread.
+///
+/// A profiled processor swaps in empty counters before it runs and takes them back
+/// afterwards, so everything recorded in between is attributed to that processor.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ProfCounters {
+    pub output_rows: u64,
+    pub spill_bytes: u64,
+    /// The time waiting for the data from or to other nodes in nanoseconds
+    pub exchange_wait_time: u64,
+    /// The time waiting for table locks in nanoseconds
+    pub lock_wait_time: u64,
+}
+
+impl ProfCounters {
+    /// Replace the counters of the current thread and return the previous ones.
+    pub fn swap(counters: ProfCounters) -> ProfCounters {
+        CURRENT_COUNTERS.with(|c| c.replace(counters))
+    }
+
+    #[inline]
+    pub fn record_output_rows(rows: usize) {
+        CURRENT_COUNTERS.with(|c| {
+            let mut counters = c.get();
+            counters.output_rows += rows as u64;
+            c.set(counters);
+        })
+    }
+
+    #[inline]
+    pub fn record_spill_bytes(bytes: usize) {
+        CURRENT_COUNTERS.with(|c| {
+            let mut counters = c.get();
+            counters.spill_bytes += bytes as u64;
+            c.set(counters);
+        })
+    }
+
+    #[inline]
+    pub fn record_exchange_wait_time(nanos: u64) {
+        CURRENT_COUNTERS.with(|c| {
+            let mut counters = c.get();
+            counters.exchange_wait_time += nanos;
+            c.set(counters);
+        })
+    }
+
+    #[inline]
+    pub fn record_lock_wait_time(nanos: u64) {
+        CURRENT_COUNTERS.with(|c| {
+            let mut counters = c.get();
+            counters.lock_wait_time += nanos;
+            c.set(counters);
+        })
+    }
+}
+
+/// The CPU time consumed by the current thread in nanoseconds.
+pub fn thread_cpu_time() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid pointer to a timespec.
+    let ret = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+    if ret != 0 {
+        return 0;
+    }
+    ts.tv_sec as u64 * 1_000_000_000 + ts.tv_nsec as u64
+}
@@ -12,5 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod counters;
 mod span;
+
+pub use counters::*;
 pub use span::*;
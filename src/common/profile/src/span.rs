@@ -17,17 +17,46 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::ProfCounters;
+
 pub type ProfSpanSetRef<K = u32> = Arc<Mutex<ProfSpanSet<K>>>;
 
 #[derive(Default)]
 pub struct ProfSpan {
     /// The time spent to process in nanoseconds
     pub process_time: u64,
+    /// The CPU time spent to process in nanoseconds
+    pub cpu_time: u64,
+    /// The time from the first schedule to the finish in nanoseconds
+    pub wall_time: u64,
+    /// The number of rows produced
+    pub output_rows: u64,
+    /// The peak memory allocated while processing in bytes
+    pub memory_peak: u64,
+    /// The bytes written to spill files
+    pub spill_bytes: u64,
+    /// The time waiting for IO in nanoseconds, that is the time of the async processing
+    /// neither on CPU nor waiting for exchanges or locks
+    pub io_wait_time: u64,
+    /// The time waiting for the data from or to other nodes in nanoseconds
+    pub exchange_wait_time: u64,
+    /// The time waiting for table locks in nanoseconds
+    pub lock_wait_time: u64,
 }
 
 impl ProfSpan {
+    /// Merge the span of another processor of the same plan. The processors
+    /// run in parallel, so the wall time is the longest one.
     pub fn add(&mut self, other: &Self) {
         self.process_time += other.process_time;
+        self.cpu_time += other.cpu_time;
+        self.wall_time = self.wall_time.max(other.wall_time);
+        self.output_rows += other.output_rows;
+        self.memory_peak += other.memory_peak;
+        self.spill_bytes += other.spill_bytes;
+        self.io_wait_time += other.io_wait_time;
+        self.exchange_wait_time += other.exchange_wait_time;
+        self.lock_wait_time += other.lock_wait_time;
     }
 }
 
@@ -51,11 +80,28 @@ where K: std::hash::Hash + Eq + PartialEq + Clone + Debug
     pub fn get(&self, k: &K) -> Option<&ProfSpan> {
         self.spans.get(k)
     }
+
+    /// The spans of all the plans merged, which tells where the time of the whole query goes.
+    pub fn total(&self) -> ProfSpan {
+        let mut total = ProfSpan::default();
+        for span in self.spans.values() {
+            total.add(span);
+        }
+        total
+    }
 }
 
 #[derive(Clone, Default)]
 pub struct ProfSpanBuilder {
     process_time: u64,
+    cpu_time: u64,
+    wall_time: u64,
+    output_rows: u64,
+    memory_peak: u64,
+    spill_bytes: u64,
+    io_wait_time: u64,
+    exchange_wait_time: u64,
+    lock_wait_time: u64,
 }
 
 impl ProfSpanBuilder {
@@ -63,9 +109,40 @@ impl ProfSpanBuilder {
         self.process_time += nanos;
     }
 
+    pub fn accumulate_cpu_time(&mut self, nanos: u64) {
+        self.cpu_time += nanos;
+    }
+
+    pub fn accumulate_io_wait_time(&mut self, nanos: u64) {
+        self.io_wait_time += nanos;
+    }
+
+    pub fn accumulate_counters(&mut self, counters: &ProfCounters) {
+        self.output_rows += counters.output_rows;
+        self.spill_bytes += counters.spill_bytes;
+        self.exchange_wait_time += counters.exchange_wait_time;
+        self.lock_wait_time += counters.lock_wait_time;
+    }
+
+    pub fn set_wall_time(&mut self, nanos: u64) {
+        self.wall_time = nanos;
+    }
+
+    pub fn set_memory_peak(&mut self, bytes: u64) {
+        self.memory_peak = bytes;
+    }
+
     pub fn finish(self) -> ProfSpan {
         ProfSpan {
             process_time: self.process_time,
+            cpu_time: self.cpu_time,
+            wall_time: self.wall_time,
+            output_rows: self.output_rows,
+            memory_peak: self.memory_peak,
+            spill_bytes: self.spill_bytes,
+            io_wait_time: self.io_wait_time,
+            exchange_wait_time: self.exchange_wait_time,
+            lock_wait_time: self.lock_wait_time,
         }
     }
 }
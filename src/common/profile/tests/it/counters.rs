@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_profile::thread_cpu_time;
+use common_profile::ProfCounters;
+
+#[test]
+fn test_counters_accumulate_until_swapped() {
+    let previous = ProfCounters::swap(ProfCounters::default());
+
+    ProfCounters::record_output_rows(3);
+    ProfCounters::record_output_rows(4);
+    ProfCounters::record_spill_bytes(1024);
+    ProfCounters::record_exchange_wait_time(10);
+    ProfCounters::record_exchange_wait_time(5);
+    ProfCounters::record_lock_wait_time(7);
+
+    let counters = ProfCounters::swap(previous);
+    assert_eq!(counters.output_rows, 7);
+    assert_eq!(counters.spill_bytes, 1024);
+    assert_eq!(counters.exchange_wait_time, 15);
+    assert_eq!(counters.lock_wait_time, 7);
+}
+
+#[test]
+fn test_counters_are_thread_local() {
+    ProfCounters::swap(ProfCounters::default());
+    ProfCounters::record_output_rows(1);
+
+    let other = std::thread::spawn(|| {
+        ProfCounters::record_output_rows(100);
+        ProfCounters::swap(ProfCounters::default())
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(other.output_rows, 100);
+    assert_eq!(ProfCounters::swap(ProfCounters::default()).output_rows, 1);
+}
+
+#[test]
+fn test_thread_cpu_time() {
+    let start = thread_cpu_time();
+    let mut sum = 0u64;
+    for i in 0..10_000_000u64 {
+        sum = sum.wrapping_add(std::hint::black_box(i));
+    }
+    std::hint::black_box(sum);
+    let end = thread_cpu_time();
+
+    assert!(start > 0);
+    assert!(end > start);
+}
@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_profile::ProfCounters;
+use common_profile::ProfSpan;
+use common_profile::ProfSpanBuilder;
+use common_profile::ProfSpanSet;
+
+fn span(process_time: u64, wall_time: u64, output_rows: u64) -> ProfSpan {
+    ProfSpan {
+        process_time,
+        cpu_time: process_time / 2,
+        wall_time,
+        output_rows,
+        memory_peak: 64,
+        spill_bytes: 8,
+        io_wait_time: 3,
+        exchange_wait_time: 2,
+        lock_wait_time: 1,
+    }
+}
+
+#[test]
+fn test_span_add() {
+    let mut merged = span(100, 500, 10);
+    merged.add(&span(40, 300, 5));
+
+    assert_eq!(merged.process_time, 140);
+    assert_eq!(merged.cpu_time, 70);
+    // The processors run in parallel, the wall time is the longest one.
+    assert_eq!(merged.wall_time, 500);
+    assert_eq!(merged.output_rows, 15);
+    assert_eq!(merged.memory_peak, 128);
+    assert_eq!(merged.spill_bytes, 16);
+    assert_eq!(merged.io_wait_time, 6);
+    assert_eq!(merged.exchange_wait_time, 4);
+    assert_eq!(merged.lock_wait_time, 2);
+}
+
+#[test]
+fn test_span_set_update_and_total() {
+    let mut set = ProfSpanSet::<u32>::default();
+    set.update(1, span(100, 500, 10));
+    set.update(1, span(50, 800, 20));
+    set.update(2, span(10, 200, 1));
+
+    let plan_1 = set.get(&1).unwrap();
+    assert_eq!(plan_1.process_time, 150);
+    assert_eq!(plan_1.wall_time, 800);
+    assert_eq!(plan_1.output_rows, 30);
+    assert!(set.get(&3).is_none());
+    assert_eq!(set.iter().count(), 2);
+
+    let total = set.total();
+    assert_eq!(total.process_time, 160);
+    assert_eq!(total.cpu_time, 80);
+    assert_eq!(total.wall_time, 800);
+    assert_eq!(total.output_rows, 31);
+    assert_eq!(total.lock_wait_time, 3);
+}
+
+#[test]
+fn test_span_builder() {
+    let mut builder = ProfSpanBuilder::default();
+    builder.accumulate_process_time(100);
+    builder.accumulate_process_time(20);
+    builder.accumulate_cpu_time(90);
+    builder.accumulate_io_wait_time(30);
+    builder.accumulate_counters(&ProfCounters {
+        output_rows: 10,
+        spill_bytes: 256,
+        exchange_wait_time: 5,
+        lock_wait_time: 0,
+    });
+    builder.accumulate_counters(&ProfCounters {
+        output_rows: 2,
+        spill_bytes: 0,
+        exchange_wait_time: 0,
+        lock_wait_time: 4,
+    });
+    builder.set_wall_time(1000);
+    builder.set_memory_peak(4096);
+
+    let span = builder.finish();
+    assert_eq!(span.process_time, 120);
+    assert_eq!(span.cpu_time, 90);
+    assert_eq!(span.io_wait_time, 30);
+    assert_eq!(span.wall_time, 1000);
+    assert_eq!(span.memory_peak, 4096);
+    assert_eq!(span.output_rows, 12);
+    assert_eq!(span.spill_bytes, 256);
+    assert_eq!(span.exchange_wait_time, 5);
+    assert_eq!(span.lock_wait_time, 4);
+}
@@ -0,0 +1,155 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use reqsign::AwsConfig;
+use reqsign::AwsCredential;
+use reqsign::AwsCredentialLoad;
+use reqsign::AwsLoader;
+use reqsign::AwsV4Signer;
+use reqwest::Client;
+
+const STS_API_VERSION: &str = "2011-06-15";
+const ROLE_SESSION_NAME: &str = "databend";
+const ROLE_SESSION_DURATION_SECONDS: u32 = 3600;
+
+/// AssumeRoleCredentialLoader loads temporary credentials by calling STS `AssumeRole`.
+///
+/// The source credential comes from the identity of the node itself (env, profile
+/// or instance metadata), the stage or connection only carries the role arn,
+/// external id and session tags. The returned credential carries its expiration,
+/// so reqsign will call this loader again before it expires.
+pub struct AssumeRoleCredentialLoader {
+    region: String,
+    role_arn: String,
+    external_id: String,
+    session_tags: BTreeMap<String, String>,
+}
+
+impl AssumeRoleCredentialLoader {
+    pub fn new(
+        region: &str,
+        role_arn: &str,
+        external_id: &str,
+        session_tags: &BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            region: region.to_string(),
+            role_arn: role_arn.to_string(),
+            external_id: external_id.to_string(),
+            session_tags: session_tags.clone(),
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        if self.region.is_empty() {
+            "https://sts.amazonaws.com/".to_string()
+        } else {
+            format!("https://sts.{}.amazonaws.com/", self.region)
+        }
+    }
+
+    fn params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("Action".to_string(), "AssumeRole".to_string()),
+            ("Version".to_string(), STS_API_VERSION.to_string()),
+            ("RoleArn".to_string(), self.role_arn.clone()),
+            ("RoleSessionName".to_string(), ROLE_SESSION_NAME.to_string()),
+            (
+                "DurationSeconds".to_string(),
+                ROLE_SESSION_DURATION_SECONDS.to_string(),
+            ),
+        ];
+        if !self.external_id.is_empty() {
+            params.push(("ExternalId".to_string(), self.external_id.clone()));
+        }
+        for (idx, (k, v)) in self.session_tags.iter().enumerate() {
+            params.push((format!("Tags.member.{}.Key", idx + 1), k.clone()));
+            params.push((format!("Tags.member.{}.Value", idx + 1), v.clone()));
+        }
+        params
+    }
+}
+
+#[async_trait]
+impl AwsCredentialLoad for AssumeRoleCredentialLoader {
+    async fn load_credential(&self, client: Client) -> anyhow::Result<Option<AwsCredential>> {
+        let source = AwsLoader::new(
+            client.clone(),
+            AwsConfig::default().from_profile().from_env(),
+        )
+        .load()
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "no source credential found to assume role {}",
+                self.role_arn
+            )
+        })?;
+
+        let region = if self.region.is_empty() {
+            "us-east-1"
+        } else {
+            self.region.as_str()
+        };
+        let mut req = client.get(self.endpoint()).query(&self.params()).build()?;
+        AwsV4Signer::new("sts", region).sign(&mut req, &source)?;
+
+        let resp = client.execute(req).await?;
+        let status = resp.status();
+        let content = resp.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!(
+                "assume role {} failed with {}: {}",
+                self.role_arn,
+                status,
+                content
+            ));
+        }
+
+        parse_assume_role_response(&content).map(Some)
+    }
+}
+
+/// Extract the credential from the xml response of STS `AssumeRole`.
+fn parse_assume_role_response(content: &str) -> anyhow::Result<AwsCredential> {
+    let get = |tag: &str| -> anyhow::Result<String> {
+        let (start, end) = (format!("<{tag}>"), format!("</{tag}>"));
+        let begin = content
+            .find(&start)
+            .ok_or_else(|| anyhow!("{tag} is missing in assume role response"))?
+            + start.len();
+        let len = content[begin..]
+            .find(&end)
+            .ok_or_else(|| anyhow!("{tag} is not closed in assume role response"))?;
+        Ok(content[begin..begin + len].trim().to_string())
+    };
+
+    let expiration = get("Expiration")?;
+    let expires_in = DateTime::parse_from_rfc3339(&expiration)
+        .map_err(|err| anyhow!("invalid expiration {expiration} in assume role response: {err}"))?
+        .with_timezone(&Utc);
+
+    Ok(AwsCredential {
+        access_key_id: get("AccessKeyId")?,
+        secret_access_key: get("SecretAccessKey")?,
+        session_token: Some(get("SessionToken")?),
+        expires_in: Some(expires_in),
+    })
+}
@@ -47,6 +47,46 @@ pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
     pub params: StorageParams,
+    pub retry: StorageRetryConfig,
+    pub range_read: StorageRangeReadConfig,
+}
+
+/// Config for retrying the failed storage operations.
+///
+/// Temporary errors like S3 503 are retried with exponential backoff: the n-th retry
+/// waits `min_delay_ms * factor^(n-1)` (with jitter), at most `max_delay_ms`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageRetryConfig {
+    pub max_times: usize,
+    pub min_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub factor: u32,
+    /// Sends a second read request if the first one has not responded in this time,
+    /// 0 to disable hedged reads.
+    pub hedged_read_delay_ms: u64,
+}
+
+impl Default for StorageRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_times: 3,
+            min_delay_ms: 1000,
+            max_delay_ms: 60_000,
+            factor: 2,
+            hedged_read_delay_ms: 0,
+        }
+    }
+}
+
+/// Config for the ranged reads of the block readers.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageRangeReadConfig {
+    /// Splits the ranges larger than this into parallel ranged reads, 0 to disable.
+    pub parallel_bytes: u64,
+    /// Starts a duplicate request if a ranged read has not responded in the p99 latency.
+    pub hedged: bool,
+    /// Lower bound of the hedging delay, avoids doubling the requests of a fast backend.
+    pub hedge_min_delay_ms: u64,
 }
 
 // TODO: This config should be moved out of common-storage crate.
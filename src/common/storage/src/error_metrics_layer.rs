@@ -0,0 +1,137 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use metrics::counter;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpAppend;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpAppend;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::ErrorKind;
+use opendal::Result;
+
+const STORAGE_OPERATION_ERRORS: &str = "storage_operation_errors";
+
+/// Counts the failed attempts of storage operations by error class.
+///
+/// It sits below the `RetryLayer`, so every attempt is counted, including the ones
+/// recovered by a retry later.
+#[derive(Clone, Debug, Default)]
+pub struct ErrorMetricsLayer;
+
+impl<A: Accessor> Layer<A> for ErrorMetricsLayer {
+    type LayeredAccessor = ErrorMetricsAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        ErrorMetricsAccessor { inner }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ErrorMetricsAccessor<A: Accessor> {
+    inner: A,
+}
+
+fn record_error<T>(operation: &'static str, result: Result<T>) -> Result<T> {
+    if let Err(err) = &result {
+        // Not found is an expected answer to stat and delete, not a failure of the storage.
+        if err.kind() != ErrorKind::NotFound {
+            let class = match err.is_temporary() {
+                true => "temporary",
+                false => "permanent",
+            };
+            counter!(STORAGE_OPERATION_ERRORS, 1,
+                "operation" => operation,
+                "kind" => err.kind().to_string(),
+                "class" => class);
+        }
+    }
+    result
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for ErrorMetricsAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+    type Appender = A::Appender;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        record_error("create_dir", self.inner.create_dir(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        record_error("read", self.inner.read(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        record_error("write", self.inner.write(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        record_error("stat", self.inner.stat(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        record_error("delete", self.inner.delete(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        record_error("list", self.inner.list(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        record_error("append", self.inner.append(path, args).await)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        record_error("blocking_read", self.inner.blocking_read(path, args))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        record_error("blocking_write", self.inner.blocking_write(path, args))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        record_error("blocking_list", self.inner.blocking_list(path, args))
+    }
+}
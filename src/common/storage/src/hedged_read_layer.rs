@@ -0,0 +1,123 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use common_base::base::tokio::time::timeout;
+use futures::future::select;
+use futures::future::Either;
+use metrics::counter;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpAppend;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpWrite;
+use opendal::raw::RpAppend;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpWrite;
+use opendal::Result;
+
+const STORAGE_HEDGED_READS: &str = "storage_hedged_reads";
+
+/// Sends a second read request if the first one has not responded in `delay`, and
+/// takes whichever responds first. It cuts the tail latency of slow object storage nodes.
+#[derive(Clone, Debug)]
+pub struct HedgedReadLayer {
+    delay: Duration,
+}
+
+impl HedgedReadLayer {
+    pub fn new(delay: Duration) -> Self {
+        HedgedReadLayer { delay }
+    }
+}
+
+impl<A: Accessor> Layer<A> for HedgedReadLayer {
+    type LayeredAccessor = HedgedReadAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        HedgedReadAccessor {
+            inner,
+            delay: self.delay,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HedgedReadAccessor<A: Accessor> {
+    inner: A,
+    delay: Duration,
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for HedgedReadAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+    type Appender = A::Appender;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut first = Box::pin(self.inner.read(path, args.clone()));
+        if let Ok(result) = timeout(self.delay, &mut first).await {
+            return result;
+        }
+
+        counter!(STORAGE_HEDGED_READS, 1);
+        let second = Box::pin(self.inner.read(path, args));
+        match select(first, second).await {
+            Either::Left((result, _)) => result,
+            Either::Right((result, _)) => result,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn append(&self, path: &str, args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        self.inner.append(path, args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
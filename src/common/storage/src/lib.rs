@@ -35,17 +35,27 @@
 mod config;
 pub use config::ShareTableConfig;
 pub use config::StorageConfig;
+pub use config::StorageRangeReadConfig;
+pub use config::StorageRetryConfig;
 
+mod assume_role;
 mod operator;
 pub use operator::init_operator;
 pub use operator::DataOperator;
+pub use operator::SpillOperator;
 
 mod metrics;
+pub use metrics::DataCacheMetrics;
 pub use metrics::StorageMetrics;
 pub use metrics::StorageMetricsLayer;
 
+mod error_metrics_layer;
+mod hedged_read_layer;
 mod runtime_layer;
 
+mod range_read;
+pub use range_read::RangeReadPolicy;
+
 mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
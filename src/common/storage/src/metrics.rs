@@ -136,6 +136,37 @@ impl StorageMetrics {
     }
 }
 
+/// DataCacheMetrics represents the hits and misses of the table data cache in a query.
+#[derive(Debug, Default)]
+pub struct DataCacheMetrics {
+    /// Number of columns served from the table data cache.
+    hits: AtomicU64,
+    /// Number of columns read from storage because they were not cached.
+    misses: AtomicU64,
+}
+
+impl DataCacheMetrics {
+    pub fn inc_hits(&self, v: u64) {
+        if v > 0 {
+            self.hits.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_misses(&self, v: u64) {
+        if v > 0 {
+            self.misses.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct StorageMetricsLayer {
     metrics: Arc<StorageMetrics>,
@@ -16,6 +16,7 @@ use std::env;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
+use std::sync::OnceLock;
 use std::time::Duration;
 
 use anyhow::anyhow;
@@ -50,8 +51,16 @@ use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
 
+use crate::assume_role::AssumeRoleCredentialLoader;
+use crate::error_metrics_layer::ErrorMetricsLayer;
+use crate::hedged_read_layer::HedgedReadLayer;
 use crate::runtime_layer::RuntimeLayer;
+use crate::RangeReadPolicy;
 use crate::StorageConfig;
+use crate::StorageRetryConfig;
+
+/// Retry policy of the operators, set once by `DataOperator::init` from the storage config.
+static RETRY_CONFIG: OnceLock<StorageRetryConfig> = OnceLock::new();
 
 /// init_operator will init an opendal operator based on storage config.
 pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
@@ -86,6 +95,7 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 }
 
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
+    let retry = RETRY_CONFIG.get().cloned().unwrap_or_default();
     let ob = Operator::new(builder)?;
 
     let op = ob
@@ -104,15 +114,32 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
                 // 1 KiB/s.
                 .with_speed(1024),
         )
+        // Count the failed attempts, including the ones recovered by retry
+        .layer(ErrorMetricsLayer)
         // Add retry
-        .layer(RetryLayer::new().with_jitter())
+        .layer(
+            RetryLayer::new()
+                .with_max_times(retry.max_times)
+                .with_min_delay(Duration::from_millis(retry.min_delay_ms))
+                .with_max_delay(Duration::from_millis(retry.max_delay_ms))
+                .with_factor(retry.factor.max(1) as f32)
+                .with_jitter(),
+        )
+        .finish();
+
+    // Add hedged read upon retry, so that each request is retried on its own.
+    let op = match retry.hedged_read_delay_ms {
+        0 => op,
+        delay => op.layer(HedgedReadLayer::new(Duration::from_millis(delay))),
+    };
+
+    let op = op
         // Add metrics
         .layer(MetricsLayer)
         // Add logging
         .layer(LoggingLayer::default())
         // Add tracing
-        .layer(TracingLayer)
-        .finish();
+        .layer(TracingLayer);
 
     Ok(op)
 }
@@ -238,8 +265,16 @@ fn init_s3_operator(cfg: &StorageS3Config) -> Result<impl Builder> {
     builder.access_key_id(&cfg.access_key_id);
     builder.secret_access_key(&cfg.secret_access_key);
     builder.security_token(&cfg.security_token);
-    builder.role_arn(&cfg.role_arn);
-    builder.external_id(&cfg.external_id);
+    if !cfg.role_arn.is_empty() {
+        // AssumeRole with the credential of current node, the temporary
+        // credential will be refreshed before expired.
+        builder.customed_credential_load(Box::new(AssumeRoleCredentialLoader::new(
+            &cfg.region,
+            &cfg.role_arn,
+            &cfg.external_id,
+            &cfg.session_tags,
+        )));
+    }
 
     // Bucket.
     builder.bucket(&cfg.bucket);
@@ -399,7 +434,14 @@ impl DataOperator {
 
     #[async_backtrace::framed]
     pub async fn init(conf: &StorageConfig) -> common_exception::Result<()> {
-        GlobalInstance::set(Self::try_create(&conf.params).await?);
+        // The retry policy applies to all the operators built after this point.
+        let _ = RETRY_CONFIG.set(conf.retry.clone());
+        let data_operator = Self::try_create(&conf.params).await?;
+        RangeReadPolicy::register(
+            data_operator.operator.info().scheme(),
+            conf.range_read.clone(),
+        );
+        GlobalInstance::set(data_operator);
 
         Ok(())
     }
@@ -435,3 +477,38 @@ impl DataOperator {
         GlobalInstance::get()
     }
 }
+
+/// SpillOperator is the operator to write and read the temporary spill files.
+///
+/// It's the operator of the data storage, unless a local spill directory is configured.
+#[derive(Clone, Debug)]
+pub struct SpillOperator {
+    operator: Operator,
+}
+
+impl SpillOperator {
+    pub fn operator(&self) -> Operator {
+        self.operator.clone()
+    }
+
+    /// Must be called after [`DataOperator::init`].
+    #[async_backtrace::framed]
+    pub async fn init(local_path: &str) -> common_exception::Result<()> {
+        let operator = match local_path.is_empty() {
+            true => DataOperator::instance().operator(),
+            false => {
+                let params = StorageParams::Fs(StorageFsConfig {
+                    root: local_path.to_string(),
+                });
+                DataOperator::try_create(&params).await?.operator()
+            }
+        };
+        GlobalInstance::set(SpillOperator { operator });
+
+        Ok(())
+    }
+
+    pub fn instance() -> SpillOperator {
+        GlobalInstance::get()
+    }
+}
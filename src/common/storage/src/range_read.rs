@@ -0,0 +1,205 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::timeout;
+use futures::future::select;
+use futures::future::try_join_all;
+use futures::future::Either;
+use metrics::counter;
+use opendal::Operator;
+use opendal::Result;
+use opendal::Scheme;
+
+use crate::StorageRangeReadConfig;
+
+const STORAGE_PARALLEL_RANGE_READS: &str = "storage_parallel_range_reads";
+const STORAGE_HEDGED_RANGE_READS: &str = "storage_hedged_range_reads";
+
+/// Latencies are bucketed by quarter powers of two of microseconds, which covers up to ~40min.
+const LATENCY_BUCKETS: usize = 128;
+/// The p99 is not meaningful until this many reads have been observed.
+const MIN_LATENCY_SAMPLES: u64 = 100;
+/// Counts are halved after this many samples, so that the p99 follows the recent latency.
+const MAX_LATENCY_SAMPLES: u64 = 10_000;
+
+static RANGE_READ_POLICIES: OnceLock<RwLock<HashMap<Scheme, Arc<RangeReadPolicy>>>> =
+    OnceLock::new();
+
+/// How the block readers issue the ranged reads against a storage backend.
+///
+/// Large ranges are split into parallel ranged reads of `parallel_bytes`, and a duplicate
+/// request is started for every ranged read that has not responded in the observed p99
+/// latency of the backend.
+pub struct RangeReadPolicy {
+    config: StorageRangeReadConfig,
+    latency: Mutex<LatencyHistogram>,
+}
+
+impl RangeReadPolicy {
+    /// Register the policy of the backend, replacing the previous one.
+    pub fn register(scheme: Scheme, config: StorageRangeReadConfig) {
+        let policy = Arc::new(RangeReadPolicy {
+            config,
+            latency: Mutex::new(LatencyHistogram::default()),
+        });
+
+        let policies = RANGE_READ_POLICIES.get_or_init(|| RwLock::new(HashMap::new()));
+        policies.write().unwrap().insert(scheme, policy);
+    }
+
+    /// Get the policy of the backend of the operator, `None` if no policy is registered.
+    pub fn get(op: &Operator) -> Option<Arc<RangeReadPolicy>> {
+        let policies = RANGE_READ_POLICIES.get()?;
+        let policies = policies.read().unwrap();
+        policies.get(&op.info().scheme()).cloned()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn range_read(
+        &self,
+        op: &Operator,
+        path: &str,
+        range: Range<u64>,
+    ) -> Result<Vec<u8>> {
+        let ranges = self.split(range);
+        if ranges.len() == 1 {
+            return self.hedged_range_read(op, path, ranges[0].clone()).await;
+        }
+
+        counter!(STORAGE_PARALLEL_RANGE_READS, 1);
+        let chunks = try_join_all(
+            ranges
+                .into_iter()
+                .map(|range| self.hedged_range_read(op, path, range)),
+        )
+        .await?;
+        Ok(chunks.concat())
+    }
+
+    fn split(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let parallel_bytes = self.config.parallel_bytes;
+        if parallel_bytes == 0 || range.end - range.start <= parallel_bytes {
+            return vec![range];
+        }
+
+        let mut ranges =
+            Vec::with_capacity(((range.end - range.start) / parallel_bytes + 1) as usize);
+        let mut start = range.start;
+        while start < range.end {
+            let end = std::cmp::min(start + parallel_bytes, range.end);
+            ranges.push(start..end);
+            start = end;
+        }
+        ranges
+    }
+
+    #[async_backtrace::framed]
+    async fn hedged_range_read(
+        &self,
+        op: &Operator,
+        path: &str,
+        range: Range<u64>,
+    ) -> Result<Vec<u8>> {
+        let start = Instant::now();
+        let mut first = Box::pin(op.range_read(path, range.clone()));
+
+        let result = match self.hedge_delay() {
+            None => first.await,
+            Some(delay) => match timeout(delay, &mut first).await {
+                Ok(result) => result,
+                Err(_) => {
+                    counter!(STORAGE_HEDGED_RANGE_READS, 1);
+                    let second = Box::pin(op.range_read(path, range));
+                    match select(first, second).await {
+                        Either::Left((result, _)) => result,
+                        Either::Right((result, _)) => result,
+                    }
+                }
+            },
+        };
+
+        if result.is_ok() {
+            self.latency.lock().unwrap().observe(start.elapsed());
+        }
+        result
+    }
+
+    fn hedge_delay(&self) -> Option<Duration> {
+        if !self.config.hedged {
+            return None;
+        }
+
+        let p99 = self.latency.lock().unwrap().p99()?;
+        let min_delay = Duration::from_millis(self.config.hedge_min_delay_ms);
+        Some(std::cmp::max(p99, min_delay))
+    }
+}
+
+struct LatencyHistogram {
+    buckets: [u64; LATENCY_BUCKETS],
+    samples: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        LatencyHistogram {
+            buckets: [0; LATENCY_BUCKETS],
+            samples: 0,
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, latency: Duration) {
+        let micros = std::cmp::max(latency.as_micros(), 1) as f64;
+        let bucket = ((micros.log2() * 4.0) as usize).min(LATENCY_BUCKETS - 1);
+        self.buckets[bucket] += 1;
+        self.samples += 1;
+
+        if self.samples >= MAX_LATENCY_SAMPLES {
+            self.samples = 0;
+            for count in self.buckets.iter_mut() {
+                *count /= 2;
+                self.samples += *count;
+            }
+        }
+    }
+
+    /// The upper bound of the bucket holding the 99th percentile.
+    fn p99(&self) -> Option<Duration> {
+        if self.samples < MIN_LATENCY_SAMPLES {
+            return None;
+        }
+
+        let target = self.samples - self.samples / 100;
+        let mut seen = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                let micros = 2f64.powf((bucket + 1) as f64 / 4.0);
+                return Some(Duration::from_micros(micros as u64));
+            }
+        }
+        None
+    }
+}
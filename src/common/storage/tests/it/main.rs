@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod column_node;
+mod range_read;
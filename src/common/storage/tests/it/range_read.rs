@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_storage::RangeReadPolicy;
+use common_storage::StorageRangeReadConfig;
+use opendal::services::Memory;
+use opendal::Operator;
+use opendal::Scheme;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_parallel_hedged_range_read() -> anyhow::Result<()> {
+    let op = Operator::new(Memory::default())?.finish();
+    let data = (0..=255u8).cycle().take(10_000).collect::<Vec<_>>();
+    op.write("block", data.clone()).await?;
+
+    assert!(RangeReadPolicy::get(&op).is_none());
+    RangeReadPolicy::register(Scheme::Memory, StorageRangeReadConfig {
+        parallel_bytes: 1000,
+        hedged: true,
+        hedge_min_delay_ms: 0,
+    });
+    let policy = RangeReadPolicy::get(&op).unwrap();
+
+    // Enough reads to get a p99, so that the later reads are hedged.
+    for i in 0..200u64 {
+        let range = i * 7..i * 7 + 3500;
+        let chunk = policy.range_read(&op, "block", range.clone()).await?;
+        assert_eq!(chunk, data[range.start as usize..range.end as usize]);
+    }
+
+    let chunk = policy.range_read(&op, "block", 0..1000).await?;
+    assert_eq!(chunk, data[0..1000]);
+
+    Ok(())
+}
@@ -72,6 +72,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -252,6 +253,7 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -2077,6 +2079,147 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            }
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        info!("--- update multi table meta, normal case");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let reqs = vec![
+                update_req(&tb1, tb1.ident.seq, 1),
+                update_req(&tb2, tb2.ident.seq, 2),
+            ];
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: reqs.clone(),
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta, reqs[0].new_table_meta);
+            assert_eq!(tb2.meta, reqs[1].new_table_meta);
+        }
+
+        info!("--- update multi table meta: version mismatch");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(&tb1, tb1.ident.seq, 10),
+                        update_req(&tb2, tb2.ident.seq + 1, 20),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            // none of the tables is updated
+            let got1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let got2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(got1.ident.seq, tb1.ident.seq);
+            assert_eq!(got1.meta, tb1.meta);
+            assert_eq!(got2.ident.seq, tb2.ident.seq);
+            assert_eq!(got2.meta, tb2.meta);
+        }
+
+        info!("--- update multi table meta: conflict with a concurrent update");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            // another writer commits to tb2 after its version is read
+            mt.update_table_meta(update_req(&tb2, tb2.ident.seq, 200))
+                .await?;
+            let committed_tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(&tb1, tb1.ident.seq, 100),
+                        update_req(&tb2, tb2.ident.seq, 300),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            // tb1 is not changed, and the concurrent update of tb2 is kept
+            let got1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let got2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(got1.ident.seq, tb1.ident.seq);
+            assert_eq!(got1.meta, tb1.meta);
+            assert_eq!(got2.ident.seq, committed_tb2.ident.seq);
+            assert_eq!(got2.meta.statistics.data_bytes, 200);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
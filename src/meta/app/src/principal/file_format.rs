@@ -20,6 +20,7 @@ use std::str::FromStr;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::constants::NULL_BYTES_ESCAPE;
 use common_io::escape_string;
 use serde::Deserialize;
 use serde::Serialize;
@@ -34,6 +35,25 @@ const OPT_NAN_DISPLAY: &str = "nan_display";
 const OPT_ESCAPE: &str = "escape";
 const OPT_QUOTE: &str = "quote";
 const OPT_ROW_TAG: &str = "row_tag";
+const OPT_NULL_DISPLAY: &str = "null_display";
+const OPT_OUTPUT_HEADER: &str = "output_header";
+const OPT_QUOTE_POLICY: &str = "quote_policy";
+const OPT_OUTPUT_BOM: &str = "output_bom";
+const OPT_NULL_IF: &str = "null_if";
+const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
+const OPT_TRIM_SPACE: &str = "trim_space";
+const OPT_BINARY_AS_HEX: &str = "binary_as_hex";
+const OPT_ROW_GROUP_SIZE: &str = "row_group_size";
+const OPT_DATA_PAGE_SIZE: &str = "data_page_size";
+const OPT_STATISTICS: &str = "statistics";
+const OPT_BLOOM_FILTER_COLUMNS: &str = "bloom_filter_columns";
+
+pub const QUOTE_POLICY_ALWAYS: &str = "always";
+pub const QUOTE_POLICY_MINIMAL: &str = "minimal";
+
+pub const EMPTY_FIELD_AS_NULL: &str = "null";
+pub const EMPTY_FIELD_AS_STRING: &str = "string";
+pub const EMPTY_FIELD_AS_FIELD_DEFAULT: &str = "field_default";
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FileFormatOptionsAst {
@@ -84,6 +104,31 @@ impl FileFormatOptionsAst {
             None => Ok(default),
         }
     }
+
+    /// The lists are kept as JSON arrays of strings in the options, see [`encode_string_list`].
+    fn take_string_list(&mut self, key: &str, default: Vec<String>) -> Result<Vec<String>> {
+        match self.options.remove(key) {
+            Some(v) => serde_json::from_str(&v).map_err(|_| {
+                ErrorCode::IllegalFileFormat(format!(
+                    "Invalid value for {key}, expect a list of strings, got {v}"
+                ))
+            }),
+            None => Ok(default),
+        }
+    }
+
+    fn take_bool(&mut self, key: &str, default: bool) -> Result<bool> {
+        match self.options.remove(key) {
+            Some(v) => match v.to_lowercase().as_str() {
+                "true" | "1" => Ok(true),
+                "false" | "0" => Ok(false),
+                _ => Err(ErrorCode::IllegalFileFormat(format!(
+                    "Invalid value for {key}, expect true or false, got {v}"
+                ))),
+            },
+            None => Ok(default),
+        }
+    }
 }
 
 /// File format parameters after checking and parsing.
@@ -96,6 +141,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -107,6 +154,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
         }
     }
 
@@ -124,6 +173,10 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -139,6 +192,10 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            // The blocks of an Avro file are compressed with the codec in its header.
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            // The streams of an ORC file are compressed with the codec in its postscript.
+            FileFormatParams::Orc(_) => StageFileCompression::None,
         }
     }
 
@@ -160,10 +217,43 @@ impl FileFormatParams {
                 FileFormatParams::Json(JsonFileFormatParams { compression })
             }
             StageFileFormatType::NdJson => {
+                let default = NdJsonFileFormatParams::default();
                 let compression = ast.take_compression()?;
-                FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
+                let record_delimiter =
+                    ast.take_string(OPT_RECORDE_DELIMITER, default.record_delimiter);
+                let output_bom = ast.take_bool(OPT_OUTPUT_BOM, default.output_bom)?;
+                FileFormatParams::NdJson(NdJsonFileFormatParams {
+                    compression,
+                    record_delimiter,
+                    output_bom,
+                })
+            }
+            StageFileFormatType::Parquet => {
+                let default = ParquetFileFormatParams::default();
+                let compression = match ast.options.remove("compression") {
+                    Some(c) => {
+                        StageFileCompression::from_str(&c).map_err(ErrorCode::IllegalFileFormat)?
+                    }
+                    None => default.compression,
+                };
+                let row_group_size = ast.take_u64(OPT_ROW_GROUP_SIZE, default.row_group_size)?;
+                let data_page_size = ast.take_u64(OPT_DATA_PAGE_SIZE, default.data_page_size)?;
+                let statistics = ast.take_bool(OPT_STATISTICS, default.statistics)?;
+                let bloom_filter_columns = ast
+                    .take_string_list(OPT_BLOOM_FILTER_COLUMNS, default.bloom_filter_columns)?
+                    .into_iter()
+                    .map(|c| c.to_lowercase())
+                    .collect();
+                FileFormatParams::Parquet(ParquetFileFormatParams {
+                    compression,
+                    row_group_size,
+                    data_page_size,
+                    statistics,
+                    bloom_filter_columns,
+                })
             }
-            StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => FileFormatParams::Avro(AvroFileFormatParams {}),
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -174,6 +264,18 @@ impl FileFormatParams {
                 let nan_display = ast.take_string(OPT_NAN_DISPLAY, default.nan_display);
                 let escape = ast.take_string(OPT_ESCAPE, default.escape);
                 let quote = ast.take_string(OPT_QUOTE, default.quote);
+                let null_display = ast.take_string(OPT_NULL_DISPLAY, default.null_display);
+                let output_header = ast.take_bool(OPT_OUTPUT_HEADER, default.output_header)?;
+                let quote_policy = ast
+                    .take_string(OPT_QUOTE_POLICY, default.quote_policy)
+                    .to_lowercase();
+                let output_bom = ast.take_bool(OPT_OUTPUT_BOM, default.output_bom)?;
+                let null_if = ast.take_string_list(OPT_NULL_IF, default.null_if)?;
+                let empty_field_as = ast
+                    .take_string(OPT_EMPTY_FIELD_AS, default.empty_field_as)
+                    .to_lowercase();
+                let trim_space = ast.take_bool(OPT_TRIM_SPACE, default.trim_space)?;
+                let binary_as_hex = ast.take_bool(OPT_BINARY_AS_HEX, default.binary_as_hex)?;
                 FileFormatParams::Csv(CsvFileFormatParams {
                     compression,
                     headers,
@@ -182,6 +284,14 @@ impl FileFormatParams {
                     nan_display,
                     escape,
                     quote,
+                    null_display,
+                    output_header,
+                    quote_policy,
+                    output_bom,
+                    null_if,
+                    empty_field_as,
+                    trim_space,
+                    binary_as_hex,
                 })
             }
             StageFileFormatType::Tsv => {
@@ -194,6 +304,12 @@ impl FileFormatParams {
                 let nan_display = ast.take_string(OPT_NAN_DISPLAY, default.nan_display);
                 let escape = ast.take_string(OPT_ESCAPE, default.escape);
                 let quote = ast.take_string(OPT_QUOTE, default.quote);
+                let null_if = ast.take_string_list(OPT_NULL_IF, default.null_if)?;
+                let empty_field_as = ast
+                    .take_string(OPT_EMPTY_FIELD_AS, default.empty_field_as)
+                    .to_lowercase();
+                let trim_space = ast.take_bool(OPT_TRIM_SPACE, default.trim_space)?;
+                let binary_as_hex = ast.take_bool(OPT_BINARY_AS_HEX, default.binary_as_hex)?;
                 FileFormatParams::Tsv(TsvFileFormatParams {
                     compression,
                     headers,
@@ -202,6 +318,10 @@ impl FileFormatParams {
                     nan_display,
                     quote,
                     escape,
+                    null_if,
+                    empty_field_as,
+                    trim_space,
+                    binary_as_hex,
                 })
             }
             _ => {
@@ -233,6 +353,7 @@ impl FileFormatParams {
                 check_str_len(&p.escape, 1, 1, "TSV", "escape")?;
                 check_nan_display(&p.nan_display)?;
                 check_record_delimiter(&p.record_delimiter)?;
+                check_empty_field_as(&p.empty_field_as)?;
             }
             FileFormatParams::Csv(p) => {
                 check_str_len(&p.field_delimiter, 1, 1, "CSV", "field_delimiter")?;
@@ -240,10 +361,34 @@ impl FileFormatParams {
                 check_str_len(&p.escape, 0, 1, "CSV", "escape")?;
                 check_nan_display(&p.nan_display)?;
                 check_record_delimiter(&p.record_delimiter)?;
+                check_quote_policy(&p.quote_policy)?;
+                check_empty_field_as(&p.empty_field_as)?;
+            }
+            FileFormatParams::NdJson(p) => {
+                if p.record_delimiter != "\n" && p.record_delimiter != "\r\n" {
+                    return Err(ErrorCode::InvalidArgument(
+                        "record_delimiter for NDJSON can only be '\\n' or '\\r\\n'",
+                    ));
+                }
             }
             FileFormatParams::Xml(p) => {
                 check_str_len(&p.row_tag, 1, 1014, "XML", "row_tag")?;
             }
+            FileFormatParams::Parquet(p) => {
+                if !matches!(
+                    p.compression,
+                    StageFileCompression::Auto
+                        | StageFileCompression::Zstd
+                        | StageFileCompression::Snappy
+                        | StageFileCompression::Gzip
+                        | StageFileCompression::None
+                ) {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "compression for PARQUET can only be one of auto, zstd, snappy, gzip or none, got {}",
+                        p.compression.to_string()
+                    )));
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -252,7 +397,7 @@ impl FileFormatParams {
 
 impl Default for FileFormatParams {
     fn default() -> Self {
-        FileFormatParams::Parquet(ParquetFileFormatParams {})
+        FileFormatParams::Parquet(ParquetFileFormatParams::default())
     }
 }
 
@@ -273,6 +418,24 @@ pub struct CsvFileFormatParams {
     pub nan_display: String,
     pub escape: String,
     pub quote: String,
+    /// The string that represents NULL.
+    pub null_display: String,
+    /// Write the column names as the first row when unloading.
+    pub output_header: bool,
+    /// `always` quotes every string value, `minimal` quotes a string value only
+    /// when it contains the field delimiter, the quote or a line break.
+    pub quote_policy: String,
+    /// Write the UTF-8 BOM at the beginning of each file when unloading.
+    pub output_bom: bool,
+    /// Field values which are loaded as NULL.
+    pub null_if: Vec<String>,
+    /// How an empty field is loaded: `null`, `string` (an empty string for string columns)
+    /// or `field_default` (the default value of the column type, NULL if nullable).
+    pub empty_field_as: String,
+    /// Trim the leading and trailing white spaces of the fields when loading.
+    pub trim_space: bool,
+    /// Decode the fields of string columns from hex when loading.
+    pub binary_as_hex: bool,
 }
 
 impl Default for CsvFileFormatParams {
@@ -285,6 +448,14 @@ impl Default for CsvFileFormatParams {
             nan_display: "NaN".to_string(),
             escape: "".to_string(),
             quote: "\"".to_string(),
+            null_display: NULL_BYTES_ESCAPE.to_string(),
+            output_header: false,
+            quote_policy: QUOTE_POLICY_ALWAYS.to_string(),
+            output_bom: false,
+            null_if: vec![],
+            empty_field_as: EMPTY_FIELD_AS_FIELD_DEFAULT.to_string(),
+            trim_space: false,
+            binary_as_hex: false,
         }
     }
 }
@@ -307,6 +478,15 @@ pub struct TsvFileFormatParams {
     pub nan_display: String,
     pub escape: String,
     pub quote: String,
+    /// Field values which are loaded as NULL.
+    pub null_if: Vec<String>,
+    /// How an empty field is loaded: `null`, `string` (an empty string for string columns)
+    /// or `field_default` (the default value of the column type, NULL if nullable).
+    pub empty_field_as: String,
+    /// Trim the leading and trailing white spaces of the fields when loading.
+    pub trim_space: bool,
+    /// Decode the fields of string columns from hex when loading.
+    pub binary_as_hex: bool,
 }
 
 impl Default for TsvFileFormatParams {
@@ -319,6 +499,10 @@ impl Default for TsvFileFormatParams {
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
+            null_if: vec![],
+            empty_field_as: EMPTY_FIELD_AS_FIELD_DEFAULT.to_string(),
+            trim_space: false,
+            binary_as_hex: false,
         }
     }
 }
@@ -381,12 +565,17 @@ impl Default for JsonFileFormatParams {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct NdJsonFileFormatParams {
     pub compression: StageFileCompression,
+    pub record_delimiter: String,
+    /// Write the UTF-8 BOM at the beginning of each file when unloading.
+    pub output_bom: bool,
 }
 
 impl Default for NdJsonFileFormatParams {
     fn default() -> Self {
         NdJsonFileFormatParams {
             compression: StageFileCompression::None,
+            record_delimiter: "\n".to_string(),
+            output_bom: false,
         }
     }
 }
@@ -400,8 +589,39 @@ impl NdJsonFileFormatParams {
     }
 }
 
+/// The options of Parquet only take effect on unloading, the codecs of a Parquet file to load
+/// are read from its metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParquetFileFormatParams {
+    /// The codec of the column chunks, `Auto` is LZ4.
+    pub compression: StageFileCompression,
+    /// Max rows of a row group, 0 means a row group per data block.
+    pub row_group_size: u64,
+    /// Max bytes of a data page, 0 means the default of the writer.
+    pub data_page_size: u64,
+    /// Whether to write the min/max statistics of the column chunks.
+    pub statistics: bool,
+    /// The columns to write the bloom filters of the column chunks for.
+    pub bloom_filter_columns: Vec<String>,
+}
+
+impl Default for ParquetFileFormatParams {
+    fn default() -> Self {
+        Self {
+            compression: StageFileCompression::Auto,
+            row_group_size: 0,
+            data_page_size: 0,
+            statistics: false,
+            bloom_filter_columns: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ParquetFileFormatParams {}
+pub struct OrcFileFormatParams {}
 
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -409,27 +629,39 @@ impl Display for FileFormatParams {
             FileFormatParams::Csv(params) => {
                 write!(
                     f,
-                    "TYPE = CSV COMPRESSION = {:?} HEADERS= {} FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' NAN_DISPLAY = '{}' ESCAPE = '{}' QUOTE = '{}'",
+                    "TYPE = CSV COMPRESSION = {:?} HEADERS= {} FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' NAN_DISPLAY = '{}' ESCAPE = '{}' QUOTE = '{}' NULL_DISPLAY = '{}' OUTPUT_HEADER = {} QUOTE_POLICY = '{}' OUTPUT_BOM = {} NULL_IF = ({}) EMPTY_FIELD_AS = '{}' TRIM_SPACE = {} BINARY_AS_HEX = {}",
                     params.compression,
                     params.headers,
                     escape_string(&params.field_delimiter),
                     escape_string(&params.record_delimiter),
                     escape_string(&params.nan_display),
                     escape_string(&params.escape),
-                    escape_string(&params.quote)
+                    escape_string(&params.quote),
+                    escape_string(&params.null_display),
+                    params.output_header,
+                    params.quote_policy,
+                    params.output_bom,
+                    display_string_list(&params.null_if),
+                    params.empty_field_as,
+                    params.trim_space,
+                    params.binary_as_hex
                 )
             }
             FileFormatParams::Tsv(params) => {
                 write!(
                     f,
-                    "TYPE = TSV COMPRESSION = {:?} HEADERS= {} FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' NAN_DISPLAY = '{}' ESCAPE = '{}' QUOTE = '{}'",
+                    "TYPE = TSV COMPRESSION = {:?} HEADERS= {} FIELD_DELIMITER = '{}' RECORD_DELIMITER = '{}' NAN_DISPLAY = '{}' ESCAPE = '{}' QUOTE = '{}' NULL_IF = ({}) EMPTY_FIELD_AS = '{}' TRIM_SPACE = {} BINARY_AS_HEX = {}",
                     params.compression,
                     params.headers,
                     escape_string(&params.field_delimiter),
                     escape_string(&params.record_delimiter),
                     escape_string(&params.nan_display),
                     escape_string(&params.escape),
-                    escape_string(&params.quote)
+                    escape_string(&params.quote),
+                    display_string_list(&params.null_if),
+                    params.empty_field_as,
+                    params.trim_space,
+                    params.binary_as_hex
                 )
             }
             FileFormatParams::Xml(params) => {
@@ -443,10 +675,30 @@ impl Display for FileFormatParams {
                 write!(f, "TYPE = JSON, COMPRESSION = {:?}", params.compression)
             }
             FileFormatParams::NdJson(params) => {
-                write!(f, "TYPE = NDJSON, COMPRESSION = {:?}", params.compression)
+                write!(
+                    f,
+                    "TYPE = NDJSON, COMPRESSION = {:?}, RECORD_DELIMITER = '{}', OUTPUT_BOM = {}",
+                    params.compression,
+                    escape_string(&params.record_delimiter),
+                    params.output_bom
+                )
+            }
+            FileFormatParams::Parquet(params) => {
+                write!(
+                    f,
+                    "TYPE = PARQUET, COMPRESSION = {:?}, ROW_GROUP_SIZE = {}, DATA_PAGE_SIZE = {}, STATISTICS = {}, BLOOM_FILTER_COLUMNS = ({})",
+                    params.compression,
+                    params.row_group_size,
+                    params.data_page_size,
+                    params.statistics,
+                    display_string_list(&params.bloom_filter_columns)
+                )
+            }
+            FileFormatParams::Avro(_) => {
+                write!(f, "TYPE = AVRO")
             }
-            FileFormatParams::Parquet(_) => {
-                write!(f, "TYPE = PARQUET")
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
             }
         }
     }
@@ -489,6 +741,37 @@ pub fn check_record_delimiter(option: &str) -> Result<()> {
     Ok(())
 }
 
+fn check_quote_policy(quote_policy: &str) -> Result<()> {
+    if quote_policy != QUOTE_POLICY_ALWAYS && quote_policy != QUOTE_POLICY_MINIMAL {
+        Err(ErrorCode::InvalidArgument(
+            "quote_policy must be `always` or `minimal` (case-insensitive)",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_empty_field_as(empty_field_as: &str) -> Result<()> {
+    match empty_field_as {
+        EMPTY_FIELD_AS_NULL | EMPTY_FIELD_AS_STRING | EMPTY_FIELD_AS_FIELD_DEFAULT => Ok(()),
+        _ => Err(ErrorCode::InvalidArgument(
+            "empty_field_as must be `null`, `string` or `field_default` (case-insensitive)",
+        )),
+    }
+}
+
+fn display_string_list(list: &[String]) -> String {
+    list.iter()
+        .map(|s| format!("'{}'", escape_string(s)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Encode a list option, like `NULL_IF = ('NULL', '')`, into the value of [`FileFormatOptionsAst`].
+pub fn encode_string_list(list: &[String]) -> String {
+    serde_json::to_string(list).unwrap()
+}
+
 fn check_nan_display(nan_display: &str) -> Result<()> {
     let lower = nan_display.to_lowercase();
     if lower != "nan" && lower != "null" {
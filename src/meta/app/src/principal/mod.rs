@@ -15,8 +15,10 @@
 //! Principal is a user or role that accesses an entity.
 
 mod file_format;
+mod plan_baseline;
 mod principal_identity;
 mod role_info;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -29,9 +31,13 @@ mod user_setting;
 mod user_stage;
 
 pub use file_format::*;
+pub use plan_baseline::JoinShape;
+pub use plan_baseline::PlanBaseline;
+pub use plan_baseline::PlanShape;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use task::Task;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// A plan baseline pins the plan shape of a query, so that the query keeps
+/// running with the same join order after the optimizer changes.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PlanBaseline {
+    /// See [`PlanBaseline::gen_id`].
+    pub id: String,
+    /// The database the query runs in.
+    pub database: String,
+    pub query_text: String,
+    pub shape: PlanShape,
+    pub created_on: DateTime<Utc>,
+}
+
+impl PlanBaseline {
+    /// Generates the id of the baseline for a query, which is the same for the
+    /// query text formatted from the same AST.
+    pub fn gen_id(database: &str, query_text: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(database.as_bytes());
+        hasher.update([0]);
+        hasher.update(query_text.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// The join tree of a plan. Tables are identified by their indexes in the
+/// metadata of the query, which are assigned in the order of binding.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PlanShape {
+    /// Ids of the tables read by the query, by table index. The shape is stale
+    /// once any of them has been replaced.
+    pub table_ids: Vec<u64>,
+    /// The joins of the plan in post-order, so the joins of the children come
+    /// before their parent.
+    pub joins: Vec<JoinShape>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct JoinShape {
+    /// Sorted indexes of the tables on the probe (left) side.
+    pub probe: Vec<usize>,
+    /// Sorted indexes of the tables on the build (right) side.
+    pub build: Vec<usize>,
+}
+
+impl TryFrom<Vec<u8>> for PlanBaseline {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(baseline) => Ok(baseline),
+            Err(serialize_error) => Err(ErrorCode::IllegalPlanBaselineFormat(format!(
+                "Cannot deserialize plan baseline from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
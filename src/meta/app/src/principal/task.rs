@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A task runs its SQL periodically by a cron schedule, or after all the tasks
+/// it depends on have succeeded.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct Task {
+    pub name: String,
+    /// Cron expression with five fields: minute, hour, day of month, month, day of week.
+    pub schedule: Option<String>,
+    /// Names of the predecessor tasks.
+    pub after: Vec<String>,
+    /// The database the query runs in.
+    pub database: String,
+    pub query_text: String,
+    pub suspended: bool,
+    pub owner: String,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl TryFrom<Vec<u8>> for Task {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(task) => Ok(task),
+            Err(serialize_error) => Err(ErrorCode::IllegalTaskFormat(format!(
+                "Cannot deserialize task from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Debug;
 use std::fmt::Display;
 
 use chrono::DateTime;
@@ -24,6 +25,7 @@ pub enum CatalogType {
     Default = 1,
     Hive = 2,
     Iceberg = 3,
+    Databend = 4,
 }
 
 impl Display for CatalogType {
@@ -32,6 +34,7 @@ impl Display for CatalogType {
             CatalogType::Default => write!(f, "DEFAULT"),
             CatalogType::Hive => write!(f, "HIVE"),
             CatalogType::Iceberg => write!(f, "ICEBERG"),
+            CatalogType::Databend => write!(f, "DATABEND"),
         }
     }
 }
@@ -45,6 +48,25 @@ pub struct IcebergCatalogOption {
     pub flatten: bool,
 }
 
+/// Option for creating a catalog of a remote databend deployment
+#[derive(Clone, PartialEq, Eq)]
+pub struct DatabendCatalogOption {
+    /// `<host>:<port>` of the Flight SQL handler of the remote deployment
+    pub address: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl Debug for DatabendCatalogOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabendCatalogOption")
+            .field("address", &self.address)
+            .field("username", &self.username)
+            .field("password", &"******")
+            .finish()
+    }
+}
+
 /// different options for creating catalogs
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CatalogOption {
@@ -52,6 +74,7 @@ pub enum CatalogOption {
     Hive(String),
     // Uri location for iceberg
     Iceberg(IcebergCatalogOption),
+    Databend(DatabendCatalogOption),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -25,6 +25,7 @@ pub use catalog::CatalogNameIdent;
 pub use catalog::CatalogOption;
 pub use catalog::CatalogType;
 pub use catalog::CreateCatalogReq;
+pub use catalog::DatabendCatalogOption;
 pub use catalog::DropCatalogReq;
 pub use catalog::IcebergCatalogOption;
 pub use database::CreateDatabaseReply;
@@ -84,6 +85,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
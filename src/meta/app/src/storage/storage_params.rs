@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -322,6 +323,9 @@ pub struct StorageS3Config {
     pub role_arn: String,
     /// The ExternalId that used for AssumeRole.
     pub external_id: String,
+    /// The session tags that passed to AssumeRole.
+    #[serde(default)]
+    pub session_tags: BTreeMap<String, String>,
     /// Allow anonymous access to S3 if credential not loaded.
     pub allow_anonymous: bool,
 }
@@ -341,6 +345,7 @@ impl Default for StorageS3Config {
             enable_virtual_host_style: false,
             role_arn: "".to_string(),
             external_id: "".to_string(),
+            session_tags: BTreeMap::new(),
             allow_anonymous: false,
         }
     }
@@ -357,6 +362,7 @@ impl Debug for StorageS3Config {
             .field("enable_virtual_host_style", &self.enable_virtual_host_style)
             .field("role_arn", &self.role_arn)
             .field("external_id", &self.external_id)
+            .field("session_tags", &self.session_tags)
             .field("access_key_id", &mask_string(&self.access_key_id, 3))
             .field(
                 "secret_access_key",
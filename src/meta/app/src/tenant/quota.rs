@@ -31,6 +31,15 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max bytes of the tables in a database, including the indexes.
+    pub max_storage_bytes_per_database: u64,
+
+    // The max bytes of a table, including the indexes.
+    pub max_storage_bytes_per_table: u64,
+
+    // The max bytes of the files in an internal stage.
+    pub max_storage_bytes_per_stage: u64,
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
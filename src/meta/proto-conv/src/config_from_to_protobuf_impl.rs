@@ -103,6 +103,7 @@ impl FromToProto for StorageS3Config {
             enable_virtual_host_style: p.enable_virtual_host_style,
             role_arn: p.role_arn,
             external_id: p.external_id,
+            session_tags: p.session_tags,
             allow_anonymous: p.allow_anonymous,
         })
     }
@@ -124,6 +125,7 @@ impl FromToProto for StorageS3Config {
             role_arn: self.role_arn.clone(),
             external_id: self.external_id.clone(),
             allow_anonymous: self.allow_anonymous,
+            session_tags: self.session_tags.clone(),
         })
     }
 }
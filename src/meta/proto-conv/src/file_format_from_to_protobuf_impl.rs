@@ -243,6 +243,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Orc(p)) => {
+                Ok(mt::principal::FileFormatParams::Orc(
+                    mt::principal::OrcFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -281,6 +291,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Orc(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Orc(
+                    mt::principal::OrcFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -294,13 +314,70 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     fn from_pb(p: pb::ParquetFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        Ok(mt::principal::ParquetFileFormatParams {})
+        let compression = mt::principal::StageFileCompression::from_pb(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+        Ok(mt::principal::ParquetFileFormatParams {
+            compression,
+            row_group_size: p.row_group_size,
+            data_page_size: p.data_page_size,
+            statistics: p.statistics,
+            bloom_filter_columns: p.bloom_filter_columns,
+        })
     }
 
     fn to_pb(&self) -> Result<pb::ParquetFileFormatParams, Incompatible> {
+        let compression = mt::principal::StageFileCompression::to_pb(&self.compression)? as i32;
         Ok(pb::ParquetFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
+            compression,
+            row_group_size: self.row_group_size,
+            data_page_size: self.data_page_size,
+            statistics: self.statistics,
+            bloom_filter_columns: self.bloom_filter_columns.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::AvroFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
+impl FromToProto for mt::principal::OrcFileFormatParams {
+    type PB = pb::OrcFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::OrcFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::OrcFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::OrcFileFormatParams, Incompatible> {
+        Ok(pb::OrcFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
         })
     }
 }
@@ -319,7 +396,12 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
                 reason: format!("invalid StageFileCompression: {}", p.compression),
             })?,
         )?;
-        Ok(mt::principal::NdJsonFileFormatParams { compression })
+        let default = mt::principal::NdJsonFileFormatParams::default();
+        Ok(mt::principal::NdJsonFileFormatParams {
+            compression,
+            record_delimiter: p.record_delimiter.unwrap_or(default.record_delimiter),
+            output_bom: p.output_bom,
+        })
     }
 
     fn to_pb(&self) -> Result<pb::NdJsonFileFormatParams, Incompatible> {
@@ -328,6 +410,8 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             compression,
+            record_delimiter: Some(self.record_delimiter.clone()),
+            output_bom: self.output_bom,
         })
     }
 }
@@ -404,6 +488,7 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
                 reason: format!("invalid StageFileCompression: {}", p.compression),
             })?,
         )?;
+        let default = Self::default();
         Ok(Self {
             compression,
             headers: p.headers,
@@ -412,6 +497,14 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             quote: p.quote,
             escape: p.escape,
             nan_display: p.nan_display,
+            null_display: p.null_display.unwrap_or(default.null_display),
+            output_header: p.output_header,
+            quote_policy: p.quote_policy.unwrap_or(default.quote_policy),
+            output_bom: p.output_bom,
+            null_if: p.null_if,
+            empty_field_as: p.empty_field_as.unwrap_or(default.empty_field_as),
+            trim_space: p.trim_space,
+            binary_as_hex: p.binary_as_hex,
         })
     }
 
@@ -427,6 +520,14 @@ impl FromToProto for mt::principal::CsvFileFormatParams {
             quote: self.quote.clone(),
             escape: self.escape.clone(),
             nan_display: self.nan_display.clone(),
+            null_display: Some(self.null_display.clone()),
+            output_header: self.output_header,
+            quote_policy: Some(self.quote_policy.clone()),
+            output_bom: self.output_bom,
+            null_if: self.null_if.clone(),
+            empty_field_as: Some(self.empty_field_as.clone()),
+            trim_space: self.trim_space,
+            binary_as_hex: self.binary_as_hex,
         })
     }
 }
@@ -445,6 +546,7 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
                 reason: format!("invalid StageFileCompression: {}", p.compression),
             })?,
         )?;
+        let default = Self::default();
         Ok(Self {
             compression,
             headers: p.headers,
@@ -453,6 +555,10 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
             escape: p.escape,
             nan_display: p.nan_display,
             quote: p.quote,
+            null_if: p.null_if,
+            empty_field_as: p.empty_field_as.unwrap_or(default.empty_field_as),
+            trim_space: p.trim_space,
+            binary_as_hex: p.binary_as_hex,
         })
     }
 
@@ -468,6 +574,10 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
             escape: self.escape.clone(),
             quote: self.quote.clone(),
             nan_display: self.nan_display.clone(),
+            null_if: self.null_if.clone(),
+            empty_field_as: Some(self.empty_field_as.clone()),
+            trim_space: self.trim_space,
+            binary_as_hex: self.binary_as_hex,
         })
     }
 }
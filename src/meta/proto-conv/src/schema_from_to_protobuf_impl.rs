@@ -265,6 +265,8 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::UuidT(_) => ex::TableDataType::Uuid,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                 };
                 Ok(x)
             }
@@ -325,6 +327,8 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Uuid => new_pb_dt24(Dt24::UuidT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
         };
         Ok(x)
     }
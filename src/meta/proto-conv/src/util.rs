@@ -74,6 +74,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (42, "2023-06-03: Add allow_anonymous in S3 Config", ),
     (43, "2023-06-05: Add fields `number_of_segments` and `number_of_blocks` to TableStatistics", ),
     (44, "2023-06-07: Add: metadata.proto/ComputedExpr", ),
+    (45, "2023-06-12: Add: datatype.proto/DataType Uuid type", ),
+    (46, "2023-06-14: Add: file_format.proto/CsvFileFormatParams and NdJsonFileFormatParams add unload options", ),
+    (47, "2023-06-16: Add: file_format.proto/CsvFileFormatParams and TsvFileFormatParams add load options", ),
+    (48, "2023-06-19: Add: file_format.proto/AvroFileFormatParams", ),
+    (49, "2023-06-20: Add: file_format.proto/OrcFileFormatParams", ),
+    (50, "2023-06-21: Add: file_format.proto/ParquetFileFormatParams add unload options", ),
+    (51, "2023-06-25: Add: file_format.proto/ParquetFileFormatParams add bloom_filter_columns", ),
+    (52, "2023-06-28: Add: config.proto/S3StorageConfig add session_tags", ),
+    (53, "2023-06-29: Add: datatype.proto/DataType Geometry type", ),
 
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
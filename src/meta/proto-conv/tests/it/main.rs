@@ -49,3 +49,12 @@ mod v041_virtual_column;
 mod v042_s3_stage_new_field;
 mod v043_table_statistics;
 mod v044_table_meta;
+mod v045_schema;
+mod v046_file_format_params;
+mod v047_file_format_params;
+mod v048_file_format_params;
+mod v049_file_format_params;
+mod v050_file_format_params;
+mod v051_file_format_params;
+mod v052_s3_session_tags;
+mod v053_schema;
@@ -47,6 +47,14 @@ fn test_decode_v32_csv_file_format_params() -> anyhow::Result<()> {
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
+            null_display: "\\N".to_string(),
+            output_header: false,
+            quote_policy: "always".to_string(),
+            output_bom: false,
+            null_if: vec![],
+            empty_field_as: "field_default".to_string(),
+            trim_space: false,
+            binary_as_hex: false,
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
@@ -70,6 +78,10 @@ fn test_decode_v32_tsv_file_format_params() -> anyhow::Result<()> {
             nan_display: "nan".to_string(),
             escape: "\\".to_string(),
             quote: "\'".to_string(),
+            null_if: vec![],
+            empty_field_as: "field_default".to_string(),
+            trim_space: false,
+            binary_as_hex: false,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -84,6 +96,8 @@ fn test_decode_v32_ndjson_file_format_params() -> anyhow::Result<()> {
     let want = || {
         mt::principal::FileFormatParams::NdJson(NdJsonFileFormatParams {
             compression: StageFileCompression::Gzip,
+            record_delimiter: "\n".to_string(),
+            output_bom: false,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -125,7 +139,7 @@ fn test_decode_v32_xml_file_format_params() -> anyhow::Result<()> {
 fn test_decode_v32_parquet_file_format_params() -> anyhow::Result<()> {
     let file_format_params_v32 = vec![10, 6, 160, 6, 32, 168, 6, 24];
 
-    let want = || mt::principal::FileFormatParams::Parquet(ParquetFileFormatParams {});
+    let want = || mt::principal::FileFormatParams::Parquet(ParquetFileFormatParams::default());
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
     common::test_pb_from_to(func_name!(), want())?;
     Ok(())
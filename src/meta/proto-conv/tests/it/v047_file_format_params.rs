@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app as mt;
+use common_meta_app::principal::CsvFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::TsvFileFormatParams;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v47_csv_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v47 = vec![
+        18, 66, 8, 1, 16, 1, 26, 2, 102, 100, 34, 2, 114, 100, 42, 3, 110, 97, 110, 50, 1, 92, 58,
+        1, 39, 66, 4, 78, 85, 76, 76, 72, 1, 82, 7, 109, 105, 110, 105, 109, 97, 108, 88, 1, 98, 4,
+        78, 85, 76, 76, 98, 0, 106, 4, 110, 117, 108, 108, 112, 1, 120, 1, 160, 6, 47, 168, 6, 24,
+    ];
+    let want = || {
+        mt::principal::FileFormatParams::Csv(CsvFileFormatParams {
+            compression: StageFileCompression::Gzip,
+            headers: 1,
+            field_delimiter: "fd".to_string(),
+            record_delimiter: "rd".to_string(),
+            nan_display: "nan".to_string(),
+            escape: "\\".to_string(),
+            quote: "\'".to_string(),
+            null_display: "NULL".to_string(),
+            output_header: true,
+            quote_policy: "minimal".to_string(),
+            output_bom: true,
+            null_if: vec!["NULL".to_string(), "".to_string()],
+            empty_field_as: "null".to_string(),
+            trim_space: true,
+            binary_as_hex: true,
+        })
+    };
+    common::test_load_old(func_name!(), file_format_params_v47.as_slice(), 0, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v47_tsv_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v47 = vec![
+        26, 49, 8, 1, 16, 1, 26, 2, 102, 100, 34, 2, 114, 100, 42, 3, 110, 97, 110, 50, 1, 92, 58,
+        1, 39, 66, 4, 78, 85, 76, 76, 66, 0, 74, 6, 115, 116, 114, 105, 110, 103, 80, 1, 88, 1,
+        160, 6, 47, 168, 6, 24,
+    ];
+    let want = || {
+        mt::principal::FileFormatParams::Tsv(TsvFileFormatParams {
+            compression: StageFileCompression::Gzip,
+            headers: 1,
+            field_delimiter: "fd".to_string(),
+            record_delimiter: "rd".to_string(),
+            nan_display: "nan".to_string(),
+            escape: "\\".to_string(),
+            quote: "\'".to_string(),
+            null_if: vec!["NULL".to_string(), "".to_string()],
+            empty_field_as: "string".to_string(),
+            trim_space: true,
+            binary_as_hex: true,
+        })
+    };
+    common::test_load_old(func_name!(), file_format_params_v47.as_slice(), 0, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
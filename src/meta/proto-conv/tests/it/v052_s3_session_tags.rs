@@ -0,0 +1,50 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use common_meta_app::storage::StorageS3Config;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v52_s3_session_tags() -> anyhow::Result<()> {
+    let s3_storage_config_v52 = vec![
+        10, 9, 117, 115, 45, 101, 97, 115, 116, 45, 50, 18, 24, 104, 116, 116, 112, 115, 58, 47,
+        47, 115, 51, 46, 97, 109, 97, 122, 111, 110, 97, 119, 115, 46, 99, 111, 109, 42, 4, 116,
+        101, 115, 116, 90, 39, 97, 114, 110, 58, 97, 119, 115, 58, 105, 97, 109, 58, 58, 49, 50,
+        51, 52, 53, 54, 55, 56, 57, 48, 49, 50, 58, 114, 111, 108, 101, 47, 100, 97, 116, 97, 98,
+        101, 110, 100, 98, 8, 100, 97, 116, 97, 98, 101, 110, 100, 114, 12, 10, 4, 116, 101, 97,
+        109, 18, 4, 100, 97, 116, 97, 160, 6, 52, 168, 6, 24,
+    ];
+    let want = || StorageS3Config {
+        region: "us-east-2".to_string(),
+        bucket: "test".to_string(),
+        role_arn: "arn:aws:iam::123456789012:role/databend".to_string(),
+        external_id: "databend".to_string(),
+        session_tags: BTreeMap::from([("team".to_string(), "data".to_string())]),
+        ..Default::default()
+    };
+    common::test_load_old(func_name!(), s3_storage_config_v52.as_slice(), 52, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
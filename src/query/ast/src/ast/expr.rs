@@ -180,6 +180,8 @@ pub enum Expr {
         args: Vec<Expr>,
         params: Vec<Literal>,
         window: Option<Window>,
+        /// The lambda passed as the last argument, like `x -> x + 1` in `array_transform(arr, x -> x + 1)`
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -235,6 +237,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// The `?` placeholder of a prepared statement, bound to a parameter at execute time
+    Placeholder { span: Span },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -306,6 +310,8 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Uuid,
+    Geometry,
     Nullable(Box<TypeName>),
 }
 
@@ -326,6 +332,13 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// A lambda expression like `x -> x + 1` or `(x, y) -> x + y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<Identifier>,
+    pub expr: Box<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -357,6 +370,7 @@ pub struct WindowFrame {
     pub units: WindowFrameUnits,
     pub start_bound: WindowFrameBound,
     pub end_bound: WindowFrameBound,
+    pub exclusion: WindowFrameExclusion,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EnumAsInner)]
@@ -376,6 +390,20 @@ pub enum WindowFrameBound {
     Following(Option<Box<Expr>>),
 }
 
+/// Rows excluded from [WindowFrame], specified by `EXCLUDE ...`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowFrameExclusion {
+    /// `NO OTHERS`
+    #[default]
+    NoOthers,
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `GROUP`, the current row and its peers
+    Group,
+    /// `TIES`, the peers of the current row but not the current row itself
+    Ties,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BinaryOperator {
     Plus,
@@ -402,6 +430,8 @@ pub enum BinaryOperator {
     Xor,
     Like,
     NotLike,
+    ILike,
+    NotILike,
     Regexp,
     RLike,
     NotRegexp,
@@ -502,7 +532,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 }
@@ -627,6 +658,12 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::ILike => {
+                write!(f, "ILIKE")
+            }
+            BinaryOperator::NotILike => {
+                write!(f, "NOT ILIKE")
+            }
             BinaryOperator::Regexp => {
                 write!(f, "REGEXP")
             }
@@ -749,6 +786,12 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Uuid => {
+                write!(f, "UUID")?;
+            }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -799,6 +842,19 @@ impl Display for Literal {
     }
 }
 
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
 impl Display for WindowDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "WINDOW {} {}", self.name, self.spec)
@@ -876,7 +932,13 @@ impl Display for WindowSpec {
                 " BETWEEN {} AND {}",
                 format_frame(&frame.start_bound),
                 format_frame(&frame.end_bound)
-            )?
+            )?;
+            match frame.exclusion {
+                WindowFrameExclusion::NoOthers => {}
+                WindowFrameExclusion::CurrentRow => write!(f, " EXCLUDE CURRENT ROW")?,
+                WindowFrameExclusion::Group => write!(f, " EXCLUDE GROUP")?,
+                WindowFrameExclusion::Ties => write!(f, " EXCLUDE TIES")?,
+            }
         }
         Ok(())
     }
@@ -1043,6 +1105,7 @@ impl Display for Expr {
                 args,
                 params,
                 window,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -1056,6 +1119,9 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if let Some(lambda) = lambda {
+                    write!(f, ", {lambda}")?;
+                }
                 write!(f, ")")?;
 
                 if let Some(window) = window {
@@ -1141,6 +1207,9 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
         }
 
         Ok(())
@@ -95,6 +95,78 @@ impl AstFormatVisitor {
     pub fn new() -> Self {
         Self { children: vec![] }
     }
+
+    fn format_group_by(&mut self, group_by: &GroupBy) -> FormatTreeNode<AstFormatContext> {
+        match group_by {
+            GroupBy::Normal(exprs) => {
+                let mut group_by_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    group_by_list_children.push(self.children.pop().unwrap());
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx = AstFormatContext::with_children(
+                    group_by_list_name,
+                    group_by_list_children.len(),
+                );
+                FormatTreeNode::with_children(group_by_list_format_ctx, group_by_list_children)
+            }
+            GroupBy::GroupingSets(sets) => {
+                let mut grouping_sets = Vec::with_capacity(sets.len());
+                for set in sets.iter() {
+                    let mut grouping_set = Vec::with_capacity(set.len());
+                    for expr in set.iter() {
+                        self.visit_expr(expr);
+                        grouping_set.push(self.children.pop().unwrap());
+                    }
+                    let name = "GroupingSet".to_string();
+                    let grouping_set_format_ctx =
+                        AstFormatContext::with_children(name, grouping_set.len());
+                    let grouping_set_node =
+                        FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
+                    grouping_sets.push(grouping_set_node);
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx =
+                    AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
+                FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets)
+            }
+            GroupBy::Rollup(exprs) => {
+                let mut rollup_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    rollup_list_children.push(self.children.pop().unwrap());
+                }
+                let rollup_list_name = "GroupByRollUpList".to_string();
+                let rollup_list_format_ctx =
+                    AstFormatContext::with_children(rollup_list_name, rollup_list_children.len());
+                FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children)
+            }
+            GroupBy::Cube(exprs) => {
+                let mut cube_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    cube_list_children.push(self.children.pop().unwrap());
+                }
+                let cube_list_name = "GroupByCubeList".to_string();
+                let cube_list_format_ctx =
+                    AstFormatContext::with_children(cube_list_name, cube_list_children.len());
+                FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children)
+            }
+            GroupBy::Combined(items) => {
+                let mut combined_list_children = Vec::with_capacity(items.len());
+                for item in items.iter() {
+                    combined_list_children.push(self.format_group_by(item));
+                }
+                let combined_list_name = "GroupByCombinedList".to_string();
+                let combined_list_format_ctx = AstFormatContext::with_children(
+                    combined_list_name,
+                    combined_list_children.len(),
+                );
+                FormatTreeNode::with_children(combined_list_format_ctx, combined_list_children)
+            }
+        }
+    }
 }
 
 impl<'ast> Visitor<'ast> for AstFormatVisitor {
@@ -431,12 +503,30 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         _over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let lambda_child = self.children.pop().unwrap();
+            let lambda_name = format!(
+                "Lambda {}",
+                lambda
+                    .params
+                    .iter()
+                    .map(|param| param.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let lambda_format_ctx = AstFormatContext::with_children(lambda_name, 1);
+            children.push(FormatTreeNode::with_children(lambda_format_ctx, vec![
+                lambda_child,
+            ]));
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -634,6 +724,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {
+        let format_ctx = AstFormatContext::new("Placeholder".to_string());
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_query(&mut self, query: &'ast Query) {
         let mut children = Vec::new();
         if let Some(with) = &query.with {
@@ -694,6 +790,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             ExplainKind::Memo(_) => "Memo",
             ExplainKind::JOIN => "JOIN",
             ExplainKind::AnalyzePlan => "Analyze",
+            ExplainKind::Verify => "Verify",
+            ExplainKind::Repro(_) => "Repro",
         });
         let format_ctx = AstFormatContext::with_children(name, 1);
         let node = FormatTreeNode::with_children(format_ctx, vec![child]);
@@ -879,6 +977,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_describe_function(&mut self, name: &'ast Identifier) {
+        let name_format_ctx = AstFormatContext::new(format!("FunctionName {}", name));
+        let child = FormatTreeNode::new(name_format_ctx);
+
+        let name = "DescribeFunction".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_limit(&mut self, limit: &'ast ShowLimit) {
         match limit {
             ShowLimit::Like { pattern } => {
@@ -898,6 +1006,48 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         }
     }
 
+    fn visit_prepare_commit(&mut self, id: &'ast str, stmt: &'ast Statement) {
+        self.visit_statement(stmt);
+        let child = self.children.pop().unwrap();
+
+        let name = format!("PrepareCommit {}", id);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_commit_prepared(&mut self, id: &'ast str) {
+        let name = format!("CommitPrepared {}", id);
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_rollback_prepared(&mut self, id: &'ast str) {
+        let name = format!("RollbackPrepared {}", id);
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_begin(&mut self) {
+        let format_ctx = AstFormatContext::new("Begin".to_string());
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit(&mut self) {
+        let format_ctx = AstFormatContext::new("Commit".to_string());
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort(&mut self) {
+        let format_ctx = AstFormatContext::new("Abort".to_string());
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_kill(&mut self, kill_target: &'ast KillTarget, object_id: &'ast str) {
         let name = format!("Kill {} {}", kill_target, object_id);
         let format_ctx = AstFormatContext::new(name);
@@ -1042,6 +1192,60 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_merge_into(&mut self, merge_into: &'ast MergeIntoStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&merge_into.catalog, &merge_into.database, &merge_into.table);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_reference(&merge_into.source);
+        children.push(self.children.pop().unwrap());
+        self.visit_expr(&merge_into.join_expr);
+        children.push(self.children.pop().unwrap());
+
+        for option in merge_into.merge_options.iter() {
+            let mut option_children = Vec::new();
+            let (selection, name) = match option {
+                MergeOption::Match(clause) => {
+                    if let MatchOperation::Update { update_list } = &clause.operation {
+                        for update_expr in update_list.iter() {
+                            self.visit_identifier(&update_expr.name);
+                            option_children.push(self.children.pop().unwrap());
+                            self.visit_expr(&update_expr.expr);
+                            option_children.push(self.children.pop().unwrap());
+                        }
+                    }
+                    let name = match clause.operation {
+                        MatchOperation::Update { .. } => "MatchedUpdate",
+                        MatchOperation::Delete => "MatchedDelete",
+                    };
+                    (&clause.selection, name)
+                }
+                MergeOption::Unmatch(clause) => {
+                    for column in clause.columns.iter() {
+                        self.visit_identifier(column);
+                        option_children.push(self.children.pop().unwrap());
+                    }
+                    for value in clause.values.iter() {
+                        self.visit_expr(value);
+                        option_children.push(self.children.pop().unwrap());
+                    }
+                    (&clause.selection, "UnmatchedInsert")
+                }
+            };
+            if let Some(selection) = selection {
+                self.visit_expr(selection);
+                option_children.push(self.children.pop().unwrap());
+            }
+            let format_ctx =
+                AstFormatContext::with_children(name.to_string(), option_children.len());
+            children.push(FormatTreeNode::with_children(format_ctx, option_children));
+        }
+
+        let name = "MergeInto".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_databases(&mut self, stmt: &'ast ShowDatabasesStmt) {
         let mut children = Vec::new();
         if let Some(limit) = &stmt.limit {
@@ -1388,6 +1592,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::Amend => {
+                let action_name = "Action Amend".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1467,6 +1676,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_declare_result(&mut self, stmt: &'ast DeclareResultStmt) {
+        self.visit_identifier(&stmt.name);
+        let name_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "DeclareResult".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![name_child, query_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_view(&mut self, stmt: &'ast CreateViewStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
         let view_child = self.children.pop().unwrap();
@@ -1501,6 +1722,127 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_task(&mut self, stmt: &'ast CreateTaskStmt) {
+        let mut children = Vec::new();
+        let task_name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        children.push(FormatTreeNode::new(task_name_format_ctx));
+        if let Some(schedule) = &stmt.schedule {
+            let schedule_format_ctx = AstFormatContext::new(format!("Schedule {}", schedule));
+            children.push(FormatTreeNode::new(schedule_format_ctx));
+        }
+        if !stmt.after.is_empty() {
+            let mut after_children = Vec::with_capacity(stmt.after.len());
+            for task in stmt.after.iter() {
+                self.visit_identifier(task);
+                after_children.push(self.children.pop().unwrap());
+            }
+            let after_name = "After".to_string();
+            let after_format_ctx =
+                AstFormatContext::with_children(after_name, after_children.len());
+            children.push(FormatTreeNode::with_children(
+                after_format_ctx,
+                after_children,
+            ));
+        }
+        self.visit_statement(&stmt.sql);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreateTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_alter_task(&mut self, stmt: &'ast AlterTaskStmt) {
+        let task_name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(task_name_format_ctx);
+
+        let name = match stmt.action {
+            AlterTaskAction::Suspend => "AlterTaskSuspend".to_string(),
+            AlterTaskAction::Resume => "AlterTaskResume".to_string(),
+        };
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_task(&mut self, stmt: &'ast DropTaskStmt) {
+        let task_name_format_ctx = AstFormatContext::new(format!("TaskIdentifier {}", stmt.name));
+        let child = FormatTreeNode::new(task_name_format_ctx);
+
+        let name = "DropTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_statement(&mut self, stmt: &'ast AlterStatementStmt) {
+        let query_format_ctx = AstFormatContext::new(format!("Query {}", stmt.query));
+        let child = FormatTreeNode::new(query_format_ctx);
+
+        let name = match stmt.action {
+            AlterStatementAction::PinPlan => "AlterStatementPinPlan".to_string(),
+            AlterStatementAction::UnpinPlan => "AlterStatementUnpinPlan".to_string(),
+        };
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1523,6 +1865,81 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_bloom_index(&mut self, stmt: &'ast CreateBloomIndexStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateBloomIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, columns_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_bloom_index(&mut self, stmt: &'ast DropBloomIndexStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "DropBloomIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, columns_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            index_child,
+            table_child,
+            columns_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_columns(&mut self, stmt: &'ast CreateVirtualColumnsStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2290,75 +2707,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(selection_node);
         }
         if let Some(group_by) = &stmt.group_by {
-            match group_by {
-                GroupBy::Normal(exprs) => {
-                    let mut group_by_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        group_by_list_children.push(self.children.pop().unwrap());
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx = AstFormatContext::with_children(
-                        group_by_list_name,
-                        group_by_list_children.len(),
-                    );
-                    let group_by_list_node = FormatTreeNode::with_children(
-                        group_by_list_format_ctx,
-                        group_by_list_children,
-                    );
-                    children.push(group_by_list_node);
-                }
-                GroupBy::GroupingSets(sets) => {
-                    let mut grouping_sets = Vec::with_capacity(sets.len());
-                    for set in sets.iter() {
-                        let mut grouping_set = Vec::with_capacity(set.len());
-                        for expr in set.iter() {
-                            self.visit_expr(expr);
-                            grouping_set.push(self.children.pop().unwrap());
-                        }
-                        let name = "GroupingSet".to_string();
-                        let grouping_set_format_ctx =
-                            AstFormatContext::with_children(name, grouping_set.len());
-                        let grouping_set_node =
-                            FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
-                        grouping_sets.push(grouping_set_node);
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx =
-                        AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
-                    let group_by_list_node =
-                        FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets);
-                    children.push(group_by_list_node);
-                }
-                GroupBy::Rollup(exprs) => {
-                    let mut rollup_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        rollup_list_children.push(self.children.pop().unwrap());
-                    }
-                    let rollup_list_name = "GroupByRollUpList".to_string();
-                    let rollup_list_format_ctx = AstFormatContext::with_children(
-                        rollup_list_name,
-                        rollup_list_children.len(),
-                    );
-                    let rollup_list_node =
-                        FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children);
-                    children.push(rollup_list_node);
-                }
-                GroupBy::Cube(exprs) => {
-                    let mut cube_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        cube_list_children.push(self.children.pop().unwrap());
-                    }
-                    let cube_list_name = "GroupByCubeList".to_string();
-                    let cube_list_format_ctx =
-                        AstFormatContext::with_children(cube_list_name, cube_list_children.len());
-                    let cube_list_node =
-                        FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
-                    children.push(cube_list_node);
-                }
-            }
+            let group_by_node = self.format_group_by(group_by);
+            children.push(group_by_node);
         }
 
         if let Some(having) = &stmt.having {
@@ -2477,12 +2827,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
             } => {
                 self.visit_query(subquery);
                 let child = self.children.pop().unwrap();
-                let name = "Subquery".to_string();
+                let name = if *lateral {
+                    "LateralSubquery".to_string()
+                } else {
+                    "Subquery".to_string()
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(name, 1, Some(format!("{}", alias)))
                 } else {
@@ -2493,6 +2848,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2512,7 +2868,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("LateralTableFunction {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
@@ -2581,6 +2941,25 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Stream {
+                catalog,
+                database,
+                name,
+            } => {
+                let mut stream_name = String::new();
+                if let Some(catalog) = catalog {
+                    stream_name.push_str(&catalog.to_string());
+                    stream_name.push('.');
+                }
+                if let Some(database) = database {
+                    stream_name.push_str(&database.to_string());
+                    stream_name.push('.');
+                }
+                stream_name.push_str(&name.to_string());
+                let format_ctx = AstFormatContext::new(format!("Stream {}", stream_name));
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
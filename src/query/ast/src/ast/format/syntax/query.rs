@@ -30,7 +30,6 @@ use crate::ast::SelectTarget;
 use crate::ast::SetExpr;
 use crate::ast::SetOperator;
 use crate::ast::TableReference;
-use crate::ast::TimeTravelPoint;
 use crate::ast::WindowDefinition;
 use crate::ast::With;
 use crate::ast::CTE;
@@ -252,6 +251,13 @@ fn pretty_group_by(group_by: Option<GroupBy>) -> RcDoc<'static> {
                 )
                 .append(RcDoc::line())
                 .append(RcDoc::text(")")),
+            GroupBy::Combined(items) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY").append(RcDoc::line().nest(NEST_FACTOR)))
+                .append(
+                    interweave_comma(items.into_iter().map(|item| RcDoc::text(item.to_string())))
+                        .nest(NEST_FACTOR)
+                        .group(),
+                ),
         }
     } else {
         RcDoc::nil()
@@ -322,10 +328,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
-        .append(if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-            RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
-        } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
-            RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        .append(if let Some(travel_point) = travel_point {
+            RcDoc::text(format!(" AT{travel_point}"))
         } else {
             RcDoc::nil()
         })
@@ -336,15 +340,23 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         }),
         TableReference::Subquery {
             span: _,
+            lateral,
             subquery,
             alias,
-        } => parenthesized(pretty_query(*subquery)).append(if let Some(alias) = alias {
+        } => if lateral {
+            RcDoc::text("LATERAL ")
+        } else {
+            RcDoc::nil()
+        }
+        .append(parenthesized(pretty_query(*subquery)))
+        .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -355,21 +367,26 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
-                .append(RcDoc::text("("))
-                .append(inline_comma(params.into_iter().map(pretty_expr)))
-                .append(separator)
-                .append(inline_comma(named_params.into_iter().map(|(k, v)| {
-                    RcDoc::text(k)
-                        .append(RcDoc::text("=>"))
-                        .append(pretty_expr(v))
-                })))
-                .append(RcDoc::text(")"))
-                .append(if let Some(alias) = alias {
-                    RcDoc::text(format!(" AS {alias}"))
-                } else {
-                    RcDoc::nil()
-                })
+            (if lateral {
+                RcDoc::text("LATERAL ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(name.to_string()))
+            .append(RcDoc::text("("))
+            .append(inline_comma(params.into_iter().map(pretty_expr)))
+            .append(separator)
+            .append(inline_comma(named_params.into_iter().map(|(k, v)| {
+                RcDoc::text(k)
+                    .append(RcDoc::text("=>"))
+                    .append(pretty_expr(v))
+            })))
+            .append(RcDoc::text(")"))
+            .append(if let Some(alias) = alias {
+                RcDoc::text(format!(" AS {alias}"))
+            } else {
+                RcDoc::nil()
+            })
         }
         TableReference::Join { span: _, join } => pretty_table(*join.left)
             .append(RcDoc::line())
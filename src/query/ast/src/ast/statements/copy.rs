@@ -23,8 +23,10 @@ use std::io::Result;
 use itertools::Itertools;
 use url::Url;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_separated_map;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -56,6 +58,14 @@ pub struct CopyStmt {
     pub force: bool,
     pub disable_variant_check: bool,
     pub on_error: String,
+    /// The Hive-style partitions of the unloaded files, only for `COPY INTO <location>`.
+    pub partition_by: Option<Vec<CopyPartitionKey>>,
+    pub file_name_prefix: Option<String>,
+    /// Fails the unload unless the masking policies of the unloaded columns are applied, only
+    /// for `COPY INTO <location>`.
+    pub apply_masking: bool,
+    /// The percent of the rows randomly sampled during unload, only for `COPY INTO <location>`.
+    pub sample_percent: Option<f64>,
 }
 
 impl CopyStmt {
@@ -74,6 +84,10 @@ impl CopyStmt {
             CopyOption::Force(v) => self.force = v,
             CopyOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyOption::OnError(v) => self.on_error = v,
+            CopyOption::PartitionBy(v) => self.partition_by = Some(v),
+            CopyOption::FileNamePrefix(v) => self.file_name_prefix = Some(v),
+            CopyOption::ApplyMasking => self.apply_masking = true,
+            CopyOption::Sample(v) => self.sample_percent = Some(v),
         }
     }
 }
@@ -131,6 +145,42 @@ impl Display for CopyStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = '{}'", self.on_error)?;
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, partition_by)?;
+            write!(f, ")")?;
+        }
+
+        if let Some(file_name_prefix) = &self.file_name_prefix {
+            write!(f, " FILE_NAME_PREFIX = '{}'", file_name_prefix)?;
+        }
+
+        if self.apply_masking {
+            write!(f, " APPLY MASKING")?;
+        }
+
+        if let Some(sample_percent) = &self.sample_percent {
+            write!(f, " SAMPLE {} PERCENT", sample_percent)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A key of `PARTITION BY`, the name of the partition directory is the alias, or the name
+/// of the column if the expression is a column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyPartitionKey {
+    pub expr: Expr,
+    pub alias: Option<Identifier>,
+}
+
+impl Display for CopyPartitionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {alias}")?;
+        }
         Ok(())
     }
 }
@@ -410,4 +460,8 @@ pub enum CopyOption {
     Force(bool),
     DisableVariantCheck(bool),
     OnError(String),
+    PartitionBy(Vec<CopyPartitionKey>),
+    FileNamePrefix(String),
+    ApplyMasking,
+    Sample(f64),
 }
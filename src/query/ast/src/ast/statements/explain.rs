@@ -27,6 +27,13 @@ pub enum ExplainKind {
 
     JOIN,
 
+    // Check the statement can be executed, without executing it
+    Verify,
+
     // Explain analyze plan
     AnalyzePlan,
+
+    // The bundle to reproduce the planning of the statement, with the SQL text
+    // of the statement.
+    Repro(String),
 }
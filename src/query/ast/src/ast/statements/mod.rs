@@ -18,18 +18,23 @@ mod columns;
 mod copy;
 mod data_mask;
 mod database;
+mod declare;
 mod explain;
 mod hint;
 mod index;
 mod insert;
 mod kill;
+mod merge_into;
+mod plan_baseline;
 mod presign;
 mod replace;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
+mod task;
 mod unset;
 mod update;
 mod user;
@@ -42,18 +47,23 @@ pub use columns::*;
 pub use copy::*;
 pub use data_mask::*;
 pub use database::*;
+pub use declare::*;
 pub use explain::*;
 pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use replace::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
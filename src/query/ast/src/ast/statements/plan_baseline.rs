@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_io::escape_string_with_quote;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlterStatementAction {
+    PinPlan,
+    UnpinPlan,
+}
+
+/// Pins or unpins the plan of a query, the query is kept as a string to be
+/// matched with the queries executed later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterStatementStmt {
+    pub query: String,
+    pub action: AlterStatementAction,
+}
+
+impl Display for AlterStatementStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ALTER STATEMENT '{}'",
+            escape_string_with_quote(&self.query, Some('\''))
+        )?;
+        match self.action {
+            AlterStatementAction::PinPlan => write!(f, " PIN PLAN"),
+            AlterStatementAction::UnpinPlan => write!(f, " UNPIN PLAN"),
+        }
+    }
+}
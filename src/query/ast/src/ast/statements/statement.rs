@@ -51,6 +51,9 @@ pub enum Statement {
     ShowFunctions {
         limit: Option<ShowLimit>,
     },
+    DescribeFunction {
+        name: Identifier,
+    },
     ShowTableFunctions {
         limit: Option<ShowLimit>,
     },
@@ -85,6 +88,25 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    MergeInto(MergeIntoStmt),
+
+    // Two-phase commit
+    PrepareCommit {
+        id: String,
+        statement: Box<Statement>,
+    },
+    CommitPrepared {
+        id: String,
+    },
+    RollbackPrepared {
+        id: String,
+    },
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -108,6 +130,7 @@ pub enum Statement {
     DescribeTable(DescribeTableStmt),
     ShowTablesStatus(ShowTablesStatusStmt),
     CreateTable(CreateTableStmt),
+    CreateExternalTable(CreateExternalTableStmt),
     DropTable(DropTableStmt),
     UndropTable(UndropTableStmt),
     AlterTable(AlterTableStmt),
@@ -120,14 +143,38 @@ pub enum Statement {
     // Columns
     ShowColumns(ShowColumnsStmt),
 
+    // Results
+    DeclareResult(DeclareResultStmt),
+
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
+
+    // Tasks
+    CreateTask(CreateTaskStmt),
+    AlterTask(AlterTaskStmt),
+    DropTask(DropTaskStmt),
+
+    // Plan baselines
+    AlterStatement(AlterStatementStmt),
+
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
+    CreateBloomIndex(CreateBloomIndexStmt),
+    DropBloomIndex(DropBloomIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumns(CreateVirtualColumnsStmt),
@@ -257,6 +304,13 @@ impl Statement {
                 }
                 format!("{}", Statement::CreateStage(stage_clone))
             }
+            Statement::CreateExternalTable(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                for v in stmt_clone.connection.values_mut() {
+                    *v = "********".to_string();
+                }
+                format!("{}", Statement::CreateExternalTable(stmt_clone))
+            }
             _ => format!("{}", self),
         }
     }
@@ -278,6 +332,8 @@ impl Display for Statement {
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::JOIN => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Verify => write!(f, " VERIFY")?,
+                    ExplainKind::Repro(_) => write!(f, "(REPRO)")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -301,6 +357,15 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
+            Statement::PrepareCommit { id, statement } => {
+                write!(f, "PREPARE COMMIT '{id}' AS {statement}")?;
+            }
+            Statement::CommitPrepared { id } => write!(f, "COMMIT PREPARED '{id}'")?,
+            Statement::RollbackPrepared { id } => write!(f, "ROLLBACK PREPARED '{id}'")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -318,6 +383,7 @@ impl Display for Statement {
                     write!(f, " {limit}")?;
                 }
             }
+            Statement::DescribeFunction { name } => write!(f, "DESCRIBE FUNCTION {name}")?,
             Statement::ShowTableFunctions { limit } => {
                 write!(f, "SHOW TABLE_FUNCTIONS")?;
                 if let Some(limit) = limit {
@@ -375,6 +441,7 @@ impl Display for Statement {
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateExternalTable(stmt) => write!(f, "{stmt}")?,
             Statement::DropTable(stmt) => write!(f, "{stmt}")?,
             Statement::UndropTable(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
@@ -384,11 +451,25 @@ impl Display for Statement {
             Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
+            Statement::DeclareResult(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterStatement(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateBloomIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropBloomIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumns(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -159,6 +159,57 @@ impl Display for CreateTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateExternalTableStmt {
+    pub if_not_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    /// The columns are inferred from the files if it's not specified.
+    pub source: Option<CreateTableSource>,
+    pub location: String,
+    pub connection: BTreeMap<String, String>,
+    pub pattern: Option<String>,
+    pub file_format: BTreeMap<String, String>,
+}
+
+impl Display for CreateExternalTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE EXTERNAL TABLE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(source) = &self.source {
+            write!(f, " {source}")?;
+        }
+        write!(f, " LOCATION = '{}'", self.location)?;
+        if !self.connection.is_empty() {
+            write!(f, " CONNECTION = ( ")?;
+            write_space_separated_map(f, &self.connection)?;
+            write!(f, " )")?;
+        }
+        if let Some(pattern) = &self.pattern {
+            write!(f, " PATTERN = '{pattern}'")?;
+        }
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            for (k, v) in self.file_format.iter() {
+                write!(f, " {k} = '{v}'")?;
+            }
+            write!(f, " )")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
@@ -303,6 +354,7 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    Amend,
 }
 
 impl Display for AlterTableAction {
@@ -344,6 +396,9 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::Amend => {
+                write!(f, "AMEND")
+            }
         }
     }
 }
@@ -506,6 +561,8 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    Delta,
+    External,
 }
 
 impl Display for Engine {
@@ -516,6 +573,8 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::Delta => write!(f, "DELTA"),
+            Engine::External => write!(f, "EXTERNAL"),
         }
     }
 }
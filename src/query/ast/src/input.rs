@@ -119,4 +119,40 @@ impl Dialect {
             Dialect::PostgreSQL => false,
         }
     }
+
+    /// `a || b` concatenates strings, otherwise it's `a OR b` as MySQL.
+    pub fn is_pipes_as_concat(&self) -> bool {
+        match self {
+            Dialect::MySQL => false,
+            Dialect::Hive => true,
+            Dialect::PostgreSQL => true,
+        }
+    }
+
+    /// `a / 0`, `a DIV 0` and `a % 0` are NULL instead of an error.
+    pub fn is_divide_by_zero_null(&self) -> bool {
+        match self {
+            Dialect::MySQL => true,
+            Dialect::Hive => true,
+            Dialect::PostgreSQL => false,
+        }
+    }
+
+    /// The builtin function to call for a function of the dialect named differently.
+    pub fn function_alias(&self, name: &str) -> Option<&'static str> {
+        match (self, name) {
+            (Dialect::MySQL, "curdate") => Some("today"),
+            (Dialect::MySQL, "sysdate") => Some("now"),
+            (Dialect::MySQL | Dialect::Hive, "dayofmonth") => Some("to_day_of_month"),
+            (Dialect::MySQL | Dialect::Hive, "from_unixtime") => Some("to_timestamp"),
+            (Dialect::MySQL | Dialect::Hive, "unix_timestamp") => Some("to_unix_timestamp"),
+            (Dialect::Hive, "nvl") => Some("ifnull"),
+            (Dialect::Hive, "size") => Some("length"),
+            (Dialect::Hive, "collect_list") => Some("array_agg"),
+            (Dialect::PostgreSQL, "strpos") => Some("instr"),
+            (Dialect::PostgreSQL, "random") => Some("rand"),
+            (Dialect::PostgreSQL, "to_char") => Some("date_format"),
+            _ => None,
+        }
+    }
 }
@@ -293,6 +293,7 @@ pub enum ExprElement {
         args: Vec<Expr>,
         window: Option<Window>,
         params: Vec<Literal>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -343,6 +344,8 @@ pub enum ExprElement {
         unit: IntervalKind,
         date: Expr,
     },
+    /// `?`
+    Placeholder,
 }
 
 struct ExprParser;
@@ -386,6 +389,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::Lte => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Like => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::ILike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NotILike => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Regexp => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotRegexp => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::RLike => Affix::Infix(Precedence(20), Associativity::Left),
@@ -491,6 +496,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
@@ -498,6 +504,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             },
             ExprElement::Case {
                 operand,
@@ -560,6 +567,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 unit,
                 date: Box::new(date),
             },
+            ExprElement::Placeholder => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -830,6 +840,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: None,
+            lambda: None,
+        },
+    );
+
+    let function_call_with_lambda = map(
+        rule! {
+            #lambda_function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
 
@@ -845,6 +874,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: Some(window.1),
+            lambda: None,
         },
     );
 
@@ -860,6 +890,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: params.map(|x| x.1).unwrap_or_default(),
             window: None,
+            lambda: None,
         },
     );
 
@@ -927,6 +958,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let placeholder = value(ExprElement::Placeholder, rule! { "?" });
+
     let map_expr = map(
         rule! { "{" ~ #comma_separated_list0(map_element) ~ "}" },
         |(_, kvs, _)| ExprElement::Map { kvs },
@@ -994,6 +1027,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let uuid_expr = map(
+        rule! {
+            UUID ~ #consumed(literal_string)
+        },
+        |(_, (span, uuid))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(uuid),
+            }),
+            target_type: TypeName::Uuid,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1017,6 +1063,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_trunc: "`DATE_TRUNC((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND), ...)`"
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
+            | #uuid_expr: "`UUID <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
@@ -1028,6 +1075,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all_with_window : "`COUNT(*) OVER ...`"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_window : "<function>"
             | #function_call_with_params : "<function>"
             | #function_call : "<function>"
@@ -1039,6 +1087,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #literal : "<literal>"
             | #array : "`[...]`"
             | #map_expr : "`{...}`"
+            | #placeholder : "`?`"
         ),
     )))(i)?;
 
@@ -1058,6 +1107,11 @@ pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
 }
 
 pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
+    let pipes = if i.1.is_pipes_as_concat() {
+        BinaryOperator::StringConcat
+    } else {
+        BinaryOperator::Or
+    };
     alt((
         alt((
             value(BinaryOperator::Plus, rule! { "+" }),
@@ -1067,7 +1121,7 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::IntDiv, rule! { "//" }),
             value(BinaryOperator::Div, rule! { DIV }),
             value(BinaryOperator::Modulo, rule! { "%" }),
-            value(BinaryOperator::StringConcat, rule! { "||" }),
+            value(pipes, rule! { "||" }),
             value(BinaryOperator::Gt, rule! { ">" }),
             value(BinaryOperator::Lt, rule! { "<" }),
             value(BinaryOperator::Gte, rule! { ">=" }),
@@ -1082,6 +1136,8 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::Xor, rule! { XOR }),
             value(BinaryOperator::Like, rule! { LIKE }),
             value(BinaryOperator::NotLike, rule! { NOT ~ LIKE }),
+            value(BinaryOperator::ILike, rule! { ILIKE }),
+            value(BinaryOperator::NotILike, rule! { NOT ~ ILIKE }),
             value(BinaryOperator::Regexp, rule! { REGEXP }),
             value(BinaryOperator::NotRegexp, rule! { NOT ~ REGEXP }),
             value(BinaryOperator::RLike, rule! { RLIKE }),
@@ -1316,6 +1372,8 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_uuid = value(TypeName::Uuid, rule! { UUID });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY | GEOGRAPHY });
     map(
         alt((
             rule! {
@@ -1343,6 +1401,8 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_datetime
             | #ty_string
             | #ty_variant
+            | #ty_uuid
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -1447,6 +1507,40 @@ pub fn map_access(i: Input) -> IResult<MapAccessor> {
     )(i)
 }
 
+/// The higher-order functions accepting a lambda after the array, only these names are
+/// tried as lambda calls to avoid parsing the first argument of every function twice.
+const LAMBDA_FUNCTIONS: [&str; 4] = [
+    "array_transform",
+    "array_filter",
+    "array_reduce",
+    "array_sort",
+];
+
+pub fn lambda_function_name(i: Input) -> IResult<Identifier> {
+    map_res(function_name, |name| {
+        if LAMBDA_FUNCTIONS.contains(&name.name.to_lowercase().as_str()) {
+            Ok(name)
+        } else {
+            Err(ErrorKind::ExpectText("lambda function"))
+        }
+    })(i)
+}
+
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(rule! { #ident }, |param| vec![param]);
+    let multi_params = map(
+        rule! {
+            "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, params, _)| params,
+    );
+
+    rule!(
+        #single_param
+        | #multi_params
+    )(i)
+}
+
 pub fn map_element(i: Input) -> IResult<(Expr, Expr)> {
     map(
         rule! {
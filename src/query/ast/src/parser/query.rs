@@ -403,9 +403,17 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_stream = map(
+        rule! { "(" ~ STREAM ~ "=>" ~ #period_separated_idents_1_to_3 ~ ")" },
+        |(_, _, _, (catalog, database, name), _)| TimeTravelPoint::Stream {
+            catalog,
+            database,
+            name,
+        },
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_stream
     )(i)
 }
 
@@ -501,14 +509,16 @@ pub enum TableReferenceElement {
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
     },
@@ -589,9 +599,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -599,9 +610,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let subquery = map(
         rule! {
-            "(" ~ #query ~ ")" ~ #table_alias?
+            LATERAL? ~ "(" ~ #query ~ ")" ~ #table_alias?
         },
-        |(_, subquery, _, alias)| TableReferenceElement::Subquery {
+        |(lateral, _, subquery, _, alias)| TableReferenceElement::Subquery {
+            lateral: lateral.is_some(),
             subquery: Box::new(subquery),
             alias,
         },
@@ -685,6 +697,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 unpivot,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -705,14 +718,20 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
                     alias,
                 }
             }
-            TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
+            TableReferenceElement::Subquery {
+                lateral,
+                subquery,
+                alias,
+            } => TableReference::Subquery {
                 span: transform_span(input.span.0),
+                lateral,
                 subquery,
                 alias,
             },
@@ -796,9 +815,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
 }
 
 pub fn group_by_items(i: Input) -> IResult<GroupBy> {
-    let normal = map(rule! { ^#comma_separated_list1(expr) }, |groups| {
-        GroupBy::Normal(groups)
-    });
+    let normal = map(rule! { #expr }, |expr| GroupBy::Normal(vec![expr]));
     let cube = map(
         rule! { CUBE ~ "(" ~ ^#comma_separated_list1(expr) ~ ")" },
         |(_, _, groups, _)| GroupBy::Cube(groups),
@@ -819,7 +836,31 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
         rule! { GROUPING ~ SETS ~ "(" ~ ^#comma_separated_list1(group_set) ~ ")"  },
         |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
     );
-    rule!(#group_sets | #cube | #rollup | #normal)(i)
+    let group_item = alt((group_sets, cube, rollup, normal));
+    map(
+        rule! { ^#comma_separated_list1(group_item) },
+        combine_group_by_items,
+    )(i)
+}
+
+/// Merge adjacent plain expressions into one `GroupBy::Normal`, and wrap
+/// the items into `GroupBy::Combined` if there is more than one kind of them.
+fn combine_group_by_items(items: Vec<GroupBy>) -> GroupBy {
+    let mut combined: Vec<GroupBy> = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            GroupBy::Normal(exprs) => match combined.last_mut() {
+                Some(GroupBy::Normal(last)) => last.extend(exprs),
+                _ => combined.push(GroupBy::Normal(exprs)),
+            },
+            item => combined.push(item),
+        }
+    }
+    if combined.len() == 1 {
+        combined.pop().unwrap()
+    } else {
+        GroupBy::Combined(combined)
+    }
 }
 
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
@@ -854,12 +895,27 @@ pub fn window_frame_between(i: Input) -> IResult<(WindowFrameBound, WindowFrameB
     ))(i)
 }
 
+pub fn window_frame_exclusion(i: Input) -> IResult<WindowFrameExclusion> {
+    alt((
+        value(
+            WindowFrameExclusion::CurrentRow,
+            rule! { EXCLUDE ~ CURRENT ~ ^ROW },
+        ),
+        value(WindowFrameExclusion::Group, rule! { EXCLUDE ~ GROUP }),
+        value(WindowFrameExclusion::Ties, rule! { EXCLUDE ~ TIES }),
+        value(
+            WindowFrameExclusion::NoOthers,
+            rule! { EXCLUDE ~ NO ~ ^OTHERS },
+        ),
+    ))(i)
+}
+
 pub fn window_spec(i: Input) -> IResult<WindowSpec> {
     map(
         rule! {
             (#ident )? ~ (PARTITION ~ ^BY ~ #comma_separated_list1(subexpr(0)))?
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
-            ~ ((ROWS | RANGE) ~ #window_frame_between)?
+            ~ ((ROWS | RANGE) ~ #window_frame_between ~ #window_frame_exclusion?)?
         },
         |(existing_window_name, opt_partition, opt_order, between)| WindowSpec {
             existing_window_name,
@@ -876,6 +932,7 @@ pub fn window_spec(i: Input) -> IResult<WindowSpec> {
                     units: unit,
                     start_bound: bw.0,
                     end_bound: bw.1,
+                    exclusion: x.2.unwrap_or_default(),
                 }
             }),
         },
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 
+use common_meta_app::principal::encode_string_list;
 use nom::branch::alt;
 use nom::combinator::map;
 
@@ -76,7 +77,7 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
 
     let option_compression = map(
         rule! {
-        (COMPRESSION ~ "=" ~ (AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ ) )
+        (COMPRESSION ~ "=" ~ (AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ | SNAPPY ) )
         },
         |(_, _, v)| ("COMPRESSION".to_string(), v.text().to_string()),
     );
@@ -90,6 +91,13 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
                 | FIELD_DELIMITER
                 | QUOTE
                 | NON_DISPLAY
+                | NULL_DISPLAY
+                | QUOTE_POLICY
+                | OUTPUT_HEADER
+                | OUTPUT_BOM
+                | EMPTY_FIELD_AS
+                | TRIM_SPACE
+                | BINARY_AS_HEX
                 | ESCAPE
                 | ROW_TAG) ~ "=" ~ #literal_string
         },
@@ -98,11 +106,32 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
 
     let int_options = map(
         rule! {
-            SKIP_HEADER ~ "=" ~ #literal_u64
+            (SKIP_HEADER | ROW_GROUP_SIZE | DATA_PAGE_SIZE) ~ "=" ~ #literal_u64
         },
         |(k, _, v)| (k.text().to_string(), v.to_string()),
     );
 
+    let bool_options = map(
+        rule! {
+            (OUTPUT_HEADER | OUTPUT_BOM | TRIM_SPACE | BINARY_AS_HEX | STATISTICS) ~ "=" ~ #literal_bool
+        },
+        |(k, _, v)| (k.text().to_string(), v.to_string()),
+    );
+
+    let empty_field_as = map(
+        rule! {
+            EMPTY_FIELD_AS ~ "=" ~ (NULL | STRING | FIELD_DEFAULT)
+        },
+        |(k, _, v)| (k.text().to_string(), v.text().to_string()),
+    );
+
+    let string_list_options = map(
+        rule! {
+            (NULL_IF | BLOOM_FILTER_COLUMNS) ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")"
+        },
+        |(k, _, _, v, _)| (k.text().to_string(), encode_string_list(&v)),
+    );
+
     let none_options = map(
         rule! {
             (RECORD_DELIMITER | FIELD_DELIMITER | QUOTE | SKIP_HEADER | NON_DISPLAY | ESCAPE ) ~ "=" ~ NONE
@@ -111,7 +140,7 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
     );
 
     map(
-        rule! { (#option_type | #option_compression | #string_options | #int_options | #none_options)* },
+        rule! { (#option_type | #option_compression | #string_options | #int_options | #bool_options | #empty_field_as | #string_list_options | #none_options)* },
         |opts| BTreeMap::from_iter(opts.iter().map(|(k, v)| (k.to_lowercase(), v.clone()))),
     )(i)
 }
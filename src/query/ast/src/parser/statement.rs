@@ -57,7 +57,7 @@ pub enum CreateDatabaseOption {
 pub fn statement(i: Input) -> IResult<StatementMsg> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | MEMO )? ~ #statement
+            EXPLAIN ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | MEMO | VERIFY )? ~ #statement
         },
         |(_, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -78,6 +78,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::VERIFY) => ExplainKind::Verify,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
@@ -85,6 +86,15 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let explain_repro = map(
+        rule! {
+            EXPLAIN ~ "(" ~ REPRO ~ ")" ~ #statement
+        },
+        |(_, _, _, _, statement)| Statement::Explain {
+            kind: ExplainKind::Repro(statement.stmt.to_string()),
+            query: Box::new(statement.stmt),
+        },
+    );
     let explain_analyze = map(
         rule! {
             EXPLAIN ~ ANALYZE ~ #statement
@@ -175,6 +185,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge = map(
+        rule! {
+            MERGE ~ #hint? ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ ^#merge_source ~ ON ~ ^#expr ~ ^#merge_option+
+        },
+        |(
+            _,
+            opt_hints,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -212,6 +252,33 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let prepare_commit = map(
+        rule! {
+            PREPARE ~ COMMIT ~ #literal_string ~ AS ~ #statement
+        },
+        |(_, _, id, _, statement)| Statement::PrepareCommit {
+            id,
+            statement: Box::new(statement.stmt),
+        },
+    );
+    let commit_prepared = map(
+        rule! {
+            COMMIT ~ PREPARED ~ #literal_string
+        },
+        |(_, _, id)| Statement::CommitPrepared { id },
+    );
+    let rollback_prepared = map(
+        rule! {
+            ROLLBACK ~ PREPARED ~ #literal_string
+        },
+        |(_, _, id)| Statement::RollbackPrepared { id },
+    );
+
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let start_transaction = value(Statement::Begin, rule! { START ~ TRANSACTION });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { ROLLBACK ~ WORK? });
+
     let set_variable = map(
         rule! {
             SET ~ (GLOBAL)? ~ #ident ~ "=" ~ #subexpr(0)
@@ -435,6 +502,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let describe_function = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ FUNCTION ~ #ident
+        },
+        |(_, _, name)| Statement::DescribeFunction { name },
+    );
     let describe_table = map(
         rule! {
             ( DESC | DESCRIBE ) ~ #period_separated_idents_1_to_3
@@ -514,6 +587,45 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_external_table = map(
+        rule! {
+            CREATE ~ EXTERNAL ~ TABLE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ #create_table_source?
+            ~ LOCATION ~ ^"=" ~ ^#literal_string
+            ~ ( CONNECTION ~ ^"=" ~ ^#connection_options )?
+            ~ ( PATTERN ~ ^"=" ~ ^#literal_string )?
+            ~ #file_format_clause?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, table),
+            source,
+            _,
+            _,
+            location,
+            opt_connection,
+            opt_pattern,
+            opt_file_format,
+        )| {
+            Statement::CreateExternalTable(CreateExternalTableStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                table,
+                source,
+                location,
+                connection: opt_connection
+                    .map(|(_, _, connection)| connection)
+                    .unwrap_or_default(),
+                pattern: opt_pattern.map(|(_, _, pattern)| pattern),
+                file_format: opt_file_format.unwrap_or_default(),
+            })
+        },
+    );
     let drop_table = map(
         rule! {
             DROP ~ TABLE ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3 ~ ( ALL )?
@@ -638,6 +750,17 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let declare_result = map(
+        rule! {
+            DECLARE ~ #ident ~ RESULT ~ AS ~ #query
+        },
+        |(_, name, _, _, query)| {
+            Statement::DeclareResult(DeclareResultStmt {
+                name,
+                query: Box::new(query),
+            })
+        },
+    );
     let create_view = map(
         rule! {
             CREATE ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
@@ -691,6 +814,125 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ( REFRESH ~ ^EVERY ~ ^#literal_u64 ~ ^#refresh_interval_unit )?
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), opt_refresh, _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                refresh_interval_secs: opt_refresh.map(|(_, _, n, unit_secs)| n * unit_secs),
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+        },
+        |(_, _, opt_if_not_exists, (catalog, database, stream), _, _, (table_database, table))| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
+
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( SCHEDULE ~ "=" ~ #literal_string )?
+            ~ ( AFTER ~ #comma_separated_list1(ident) )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ #statement
+        },
+        |(_, _, opt_if_not_exists, name, opt_schedule, opt_after, opt_comment, _, statement)| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                schedule: opt_schedule.map(|(_, _, schedule)| schedule),
+                after: opt_after.map(|(_, after)| after).unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                sql: Box::new(statement.stmt),
+            })
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ #ident ~ #alter_task_action
+        },
+        |(_, _, name, action)| Statement::AlterTask(AlterTaskStmt { name, action }),
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let alter_statement = map(
+        rule! {
+            ALTER ~ STATEMENT ~ #literal_string ~ #alter_statement_action
+        },
+        |(_, _, query, action)| Statement::AlterStatement(AlterStatementStmt { query, action }),
+    );
+
     let create_index = map(
         rule! {
             CREATE ~ AGGREGATING ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
@@ -719,6 +961,74 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_bloom_index = map(
+        rule! {
+            CREATE ~ BLOOM ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateBloomIndex(CreateBloomIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let drop_bloom_index = map(
+        rule! {
+            DROP ~ BLOOM ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_exists, _, (catalog, database, table), _, columns, _)| {
+            Statement::DropBloomIndex(DropBloomIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident
+            ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_columns = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -1042,6 +1352,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 force: Default::default(),
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
+                partition_by: Default::default(),
+                file_name_prefix: Default::default(),
+                apply_masking: Default::default(),
+                sample_percent: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -1269,6 +1583,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
+            | #explain_repro : "`EXPLAIN(REPRO) <statement>`"
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
@@ -1293,6 +1608,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge : "`MERGE INTO <table> USING <source> ON <expr> WHEN [NOT] MATCHED [AND <expr>] THEN ...`"
+            | #prepare_commit : "`PREPARE COMMIT '<id>' AS <statement>`"
+            | #commit_prepared : "`COMMIT PREPARED '<id>'`"
+            | #rollback_prepared : "`ROLLBACK PREPARED '<id>'`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #start_transaction : "`START TRANSACTION`"
+            | #commit : "`COMMIT [WORK]`"
+            | #abort : "`ROLLBACK [WORK]`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1302,10 +1625,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
             | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
+            | #describe_function : "`DESCRIBE FUNCTION <name>`"
             | #describe_table : "`DESCRIBE [<database>.]<table>`"
             | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
             | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
             | #create_table : "`CREATE TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
+            | #create_external_table : "`CREATE EXTERNAL TABLE [IF NOT EXISTS] [<database>.]<table> (<column>, ...) LOCATION = '@<stage>/<path>' [PATTERN = '<regex>'] [FILE_FORMAT = (<format_options>)]`"
             | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
@@ -1321,10 +1646,32 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #declare_result : "`DECLARE <name> RESULT AS SELECT ...`"
+        ),
+        rule!(
+            #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> [REFRESH EVERY <n> SECOND | MINUTE | HOUR | DAY] AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+        ),
+        rule!(
+            #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table>`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
+        ),
+        rule!(
+            #create_task : "`CREATE TASK [IF NOT EXISTS] <name> [SCHEDULE = '<cron>'] [AFTER <task>, ...] [COMMENT = '<comment>'] AS <statement>`"
+            | #alter_task : "`ALTER TASK <name> SUSPEND | RESUME`"
+            | #drop_task : "`DROP TASK [IF EXISTS] <name>`"
+        ),
+        rule!(
+            #alter_statement : "`ALTER STATEMENT '<query>' PIN | UNPIN PLAN`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
+            | #create_bloom_index: "`CREATE BLOOM INDEX [IF NOT EXISTS] ON [<database>.]<table> (<column>, ...)`"
+            | #drop_bloom_index: "`DROP BLOOM INDEX [IF EXISTS] ON [<database>.]<table> (<column>, ...)`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
@@ -1862,6 +2209,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let amend_table = value(AlterTableAction::Amend, rule! { AMEND });
+
     rule!(
         #rename_table
         | #add_column
@@ -1871,6 +2220,7 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #amend_table
     )(i)
 }
 
@@ -2002,9 +2352,31 @@ pub fn show_limit(i: Input) -> IResult<ShowLimit> {
 }
 
 pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
+    // Options of columns like `COMPRESSION = (c1 => 'zstd(9)', c2 => 'lz4')`,
+    // kept as `c1=zstd(9),c2=lz4`. Unquoted column names are lowercased.
+    let column_option = map(
+        rule! {
+            #ident ~ "=>" ~ #literal_string
+        },
+        |(column, _, value)| {
+            let name = if column.is_quoted() {
+                column.name
+            } else {
+                column.name.to_lowercase()
+            };
+            format!("{}={}", name, value)
+        },
+    );
+    let column_options = map(
+        rule! {
+            "(" ~ #comma_separated_list1(column_option) ~ ^")"
+        },
+        |(_, opts, _)| opts.join(","),
+    );
+
     map(
         rule! {
-           ( #ident ~ "=" ~ #parameter_to_string )*
+           ( #ident ~ "=" ~ ( #parameter_to_string | #column_options ) )*
         },
         |opts| {
             BTreeMap::from_iter(
@@ -2022,6 +2394,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -2077,6 +2450,7 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
         value(CatalogType::Default, rule! {DEFAULT}),
         value(CatalogType::Hive, rule! {HIVE}),
         value(CatalogType::Iceberg, rule! {ICEBERG}),
+        value(CatalogType::Databend, rule! {DATABEND}),
     ));
     map(rule! { ^#catalog_type }, |catalog_type| catalog_type)(i)
 }
@@ -2121,6 +2495,30 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
     ))(i)
 }
 
+/// The seconds of the interval unit in `REFRESH EVERY <n> <unit>`.
+pub fn refresh_interval_unit(i: Input) -> IResult<u64> {
+    alt((
+        value(1, rule! { SECOND }),
+        value(60, rule! { MINUTE }),
+        value(3600, rule! { HOUR }),
+        value(86400, rule! { DAY }),
+    ))(i)
+}
+
+pub fn alter_task_action(i: Input) -> IResult<AlterTaskAction> {
+    alt((
+        value(AlterTaskAction::Suspend, rule! { SUSPEND }),
+        value(AlterTaskAction::Resume, rule! { RESUME }),
+    ))(i)
+}
+
+pub fn alter_statement_action(i: Input) -> IResult<AlterStatementAction> {
+    alt((
+        value(AlterStatementAction::PinPlan, rule! { PIN ~ PLAN }),
+        value(AlterStatementAction::UnpinPlan, rule! { UNPIN ~ PLAN }),
+    ))(i)
+}
+
 pub fn copy_option(i: Input) -> IResult<CopyOption> {
     alt((
         map(
@@ -2170,9 +2568,37 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
             rule! {DISABLE_VARIANT_CHECK ~ "=" ~ #literal_bool},
             |(_, _, disable_variant_check)| CopyOption::DisableVariantCheck(disable_variant_check),
         ),
+        map(
+            rule! { PARTITION ~ BY ~ "(" ~ #comma_separated_list1(copy_partition_key) ~ ")" },
+            |(_, _, _, keys, _)| CopyOption::PartitionBy(keys),
+        ),
+        map(
+            rule! { FILE_NAME_PREFIX ~ "=" ~ #literal_string },
+            |(_, _, file_name_prefix)| CopyOption::FileNamePrefix(file_name_prefix),
+        ),
+        value(CopyOption::ApplyMasking, rule! { APPLY ~ MASKING }),
+        map_res(
+            rule! { SAMPLE ~ ( LiteralInteger | LiteralFloat ) ~ PERCENT },
+            |(_, percent, _)| {
+                let percent = percent
+                    .text()
+                    .parse::<f64>()
+                    .map_err(|_| ErrorKind::Other("invalid sample percent"))?;
+                Ok(CopyOption::Sample(percent))
+            },
+        ),
     ))(i)
 }
 
+pub fn copy_partition_key(i: Input) -> IResult<CopyPartitionKey> {
+    map(rule! { #expr ~ ( AS ~ #ident )? }, |(expr, alias)| {
+        CopyPartitionKey {
+            expr,
+            alias: alias.map(|(_, alias)| alias),
+        }
+    })(i)
+}
+
 pub fn presign_action(i: Input) -> IResult<PresignAction> {
     alt((
         value(PresignAction::Download, rule! { DOWNLOAD }),
@@ -2219,6 +2645,82 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
     )(i)
 }
 
+pub fn merge_source(i: Input) -> IResult<TableReference> {
+    let table = map(
+        consumed(rule! {
+            #period_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(span, ((catalog, database, table), alias))| TableReference::Table {
+            span: transform_span(span.0),
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        },
+    );
+    let subquery = map(
+        consumed(rule! {
+            "(" ~ #query ~ ")" ~ #table_alias?
+        }),
+        |(span, (_, subquery, _, alias))| TableReference::Subquery {
+            span: transform_span(span.0),
+            lateral: false,
+            subquery: Box::new(subquery),
+            alias,
+        },
+    );
+
+    rule!(
+        #table
+        | #subquery
+    )(i)
+}
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let matched_update = map(
+        rule! {
+            UPDATE ~ SET ~ ^#comma_separated_list1(update_expr)
+        },
+        |(_, _, update_list)| MatchOperation::Update { update_list },
+    );
+    let matched_delete = value(MatchOperation::Delete, rule! { DELETE });
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ ^( #matched_update | #matched_delete )
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ ^INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ ^VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
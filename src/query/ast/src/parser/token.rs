@@ -291,10 +291,14 @@ pub enum TokenKind {
     ALL,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("AFTER", ignore(ascii_case))]
+    AFTER,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
+    #[token("APPLY", ignore(ascii_case))]
+    APPLY,
     #[token("ARGS", ignore(ascii_case))]
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
@@ -303,6 +307,8 @@ pub enum TokenKind {
     SOME,
     #[token("ALTER", ignore(ascii_case))]
     ALTER,
+    #[token("AMEND", ignore(ascii_case))]
+    AMEND,
     #[token("ANALYZE", ignore(ascii_case))]
     ANALYZE,
     #[token("AND", ignore(ascii_case))]
@@ -321,14 +327,22 @@ pub enum TokenKind {
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
     #[token("BINARY", ignore(ascii_case))]
     BINARY,
+    #[token("BINARY_AS_HEX", ignore(ascii_case))]
+    BINARY_AS_HEX,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOOM", ignore(ascii_case))]
+    BLOOM,
+    #[token("BLOOM_FILTER_COLUMNS", ignore(ascii_case))]
+    BLOOM_FILTER_COLUMNS,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -359,6 +373,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -399,6 +415,10 @@ pub enum TokenKind {
     DATABASE,
     #[token("DATABASES", ignore(ascii_case))]
     DATABASES,
+    #[token("DATABEND", ignore(ascii_case))]
+    DATABEND,
+    #[token("DATA_PAGE_SIZE", ignore(ascii_case))]
+    DATA_PAGE_SIZE,
     #[token("DATE", ignore(ascii_case))]
     DATE,
     #[token("DATE_ADD", ignore(ascii_case))]
@@ -413,6 +433,8 @@ pub enum TokenKind {
     DAY,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
+    #[token("DECLARE", ignore(ascii_case))]
+    DECLARE,
     #[token("DECIMAL", ignore(ascii_case))]
     DECIMAL,
     #[token("DEFAULT", ignore(ascii_case))]
@@ -421,6 +443,8 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
@@ -451,6 +475,8 @@ pub enum TokenKind {
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
+    #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
+    EMPTY_FIELD_AS,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
     #[token("END", ignore(ascii_case))]
@@ -465,18 +491,24 @@ pub enum TokenKind {
     EPOCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("EVERY", ignore(ascii_case))]
+    EVERY,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
     #[token("EXPLAIN", ignore(ascii_case))]
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
     FALSE,
     #[token("FIELDS", ignore(ascii_case))]
     FIELDS,
+    #[token("FIELD_DEFAULT", ignore(ascii_case))]
+    FIELD_DEFAULT,
     #[token("FIELD_DELIMITER", ignore(ascii_case))]
     FIELD_DELIMITER,
     #[token("NAN_DISPLAY", ignore(ascii_case))]
@@ -487,6 +519,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILE_NAME_PREFIX", ignore(ascii_case))]
+    FILE_NAME_PREFIX,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -527,6 +561,10 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATE", ignore(ascii_case))]
     GENERATE,
+    #[token("GEOGRAPHY", ignore(ascii_case))]
+    GEOGRAPHY,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -553,6 +591,8 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("ILIKE", ignore(ascii_case))]
+    ILIKE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
@@ -577,6 +617,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -595,10 +637,14 @@ pub enum TokenKind {
     KEY,
     #[token("KILL", ignore(ascii_case))]
     KILL,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -619,10 +665,16 @@ pub enum TokenKind {
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -643,6 +695,8 @@ pub enum TokenKind {
     NATURAL,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO", ignore(ascii_case))]
+    NO,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -655,6 +709,10 @@ pub enum TokenKind {
     NULL,
     #[token("NULLABLE", ignore(ascii_case))]
     NULLABLE,
+    #[token("NULL_DISPLAY", ignore(ascii_case))]
+    NULL_DISPLAY,
+    #[token("NULL_IF", ignore(ascii_case))]
+    NULL_IF,
     #[token("OBJECT", ignore(ascii_case))]
     OBJECT,
     #[token("OF", ignore(ascii_case))]
@@ -669,8 +727,14 @@ pub enum TokenKind {
     OR,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHERS", ignore(ascii_case))]
+    OTHERS,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
+    #[token("OUTPUT_BOM", ignore(ascii_case))]
+    OUTPUT_BOM,
+    #[token("OUTPUT_HEADER", ignore(ascii_case))]
+    OUTPUT_HEADER,
     #[token("ON_ERROR", ignore(ascii_case))]
     ON_ERROR,
     #[token("OVER", ignore(ascii_case))]
@@ -683,8 +747,14 @@ pub enum TokenKind {
     PARQUET,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
+    #[token("PIN", ignore(ascii_case))]
+    PIN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POLICY", ignore(ascii_case))]
@@ -701,6 +771,8 @@ pub enum TokenKind {
     QUERY,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
+    #[token("QUOTE_POLICY", ignore(ascii_case))]
+    QUOTE_POLICY,
     #[token("RANGE", ignore(ascii_case))]
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
@@ -711,32 +783,48 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFRESH", ignore(ascii_case))]
+    REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("REPRO", ignore(ascii_case))]
+    REPRO,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
     ROWS,
+    #[token("ROW_GROUP_SIZE", ignore(ascii_case))]
+    ROW_GROUP_SIZE,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
     #[token("GRANT", ignore(ascii_case))]
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
     PRECISION,
+    #[token("PREPARE", ignore(ascii_case))]
+    PREPARE,
+    #[token("PREPARED", ignore(ascii_case))]
+    PREPARED,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESULT", ignore(ascii_case))]
+    RESULT,
+    #[token("RESUME", ignore(ascii_case))]
+    RESUME,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -755,6 +843,10 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
+    #[token("SCHEDULE", ignore(ascii_case))]
+    SCHEDULE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -777,8 +869,14 @@ pub enum TokenKind {
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
     STAGES,
+    #[token("START", ignore(ascii_case))]
+    START,
+    #[token("STATEMENT", ignore(ascii_case))]
+    STATEMENT,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("STATISTICS", ignore(ascii_case))]
+    STATISTICS,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
     SHA256_PASSWORD,
     #[token("SHOW", ignore(ascii_case))]
@@ -803,6 +901,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -817,6 +917,8 @@ pub enum TokenKind {
     SHARES,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
+    #[token("SUSPEND", ignore(ascii_case))]
+    SUSPEND,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
     #[token("STORED", ignore(ascii_case))]
@@ -835,6 +937,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TASK", ignore(ascii_case))]
+    TASK,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("TENANTSETTING", ignore(ascii_case))]
@@ -845,6 +949,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIES", ignore(ascii_case))]
+    TIES,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -861,10 +967,14 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
     TRIM,
+    #[token("TRIM_SPACE", ignore(ascii_case))]
+    TRIM_SPACE,
     #[token("TRUE", ignore(ascii_case))]
     TRUE,
     #[token("TRUNCATE", ignore(ascii_case))]
@@ -891,6 +1001,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNPIN", ignore(ascii_case))]
+    UNPIN,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -903,6 +1015,8 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("UUID", ignore(ascii_case))]
+    UUID,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
     #[token("VALUES", ignore(ascii_case))]
@@ -915,6 +1029,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VERIFY", ignore(ascii_case))]
+    VERIFY,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
@@ -929,6 +1045,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1082,7 +1200,7 @@ impl TokenKind {
             | TokenKind::INT
             | TokenKind::INTEGER
             | TokenKind::INTERVAL
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             // | TokenKind::LEAST
             // | TokenKind::LOCALTIME
@@ -1222,7 +1340,7 @@ impl TokenKind {
             | TokenKind::INNER
             | TokenKind::IS
             | TokenKind::JOIN
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
@@ -19,6 +19,7 @@ use common_meta_app::principal::UserIdentity;
 
 use super::walk::walk_cte;
 use super::walk::walk_expr;
+use super::walk::walk_group_by;
 use super::walk::walk_identifier;
 use super::walk::walk_join_condition;
 use super::walk::walk_query;
@@ -230,6 +231,7 @@ pub trait Visitor<'ast>: Sized {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
             walk_expr(self, arg);
@@ -238,6 +240,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(over) = over {
             self.visit_window(over);
         }
+
+        if let Some(lambda) = lambda {
+            walk_expr(self, &lambda.expr);
+        }
     }
 
     fn visit_window(&mut self, window: &'ast Window) {
@@ -351,6 +357,8 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -379,6 +387,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_functions(&mut self, _limit: &'ast Option<ShowLimit>) {}
 
+    fn visit_describe_function(&mut self, _name: &'ast Identifier) {}
+
     fn visit_show_table_functions(&mut self, _limit: &'ast Option<ShowLimit>) {}
 
     fn visit_show_limit(&mut self, _limit: &'ast ShowLimit) {}
@@ -387,6 +397,18 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_kill(&mut self, _kill_target: &'ast KillTarget, _object_id: &'ast str) {}
 
+    fn visit_prepare_commit(&mut self, _id: &'ast str, _stmt: &'ast Statement) {}
+
+    fn visit_commit_prepared(&mut self, _id: &'ast str) {}
+
+    fn visit_rollback_prepared(&mut self, _id: &'ast str) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -411,6 +433,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_update(&mut self, _update: &'ast UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &'ast ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &'ast ShowCreateCatalogStmt) {}
@@ -445,6 +469,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_create_table(&mut self, _stmt: &'ast CreateTableStmt) {}
 
+    fn visit_create_external_table(&mut self, _stmt: &'ast CreateExternalTableStmt) {}
+
     fn visit_create_table_source(&mut self, _source: &'ast CreateTableSource) {}
 
     fn visit_column_definition(&mut self, _column_definition: &'ast ColumnDefinition) {}
@@ -467,16 +493,44 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_exists_table(&mut self, _stmt: &'ast ExistsTableStmt) {}
 
+    fn visit_declare_result(&mut self, _stmt: &'ast DeclareResultStmt) {}
+
     fn visit_create_view(&mut self, _stmt: &'ast CreateViewStmt) {}
 
     fn visit_alter_view(&mut self, _stmt: &'ast AlterViewStmt) {}
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
+    fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
+
+    fn visit_alter_statement(&mut self, _stmt: &'ast AlterStatementStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
 
+    fn visit_create_bloom_index(&mut self, _stmt: &'ast CreateBloomIndexStmt) {}
+
+    fn visit_drop_bloom_index(&mut self, _stmt: &'ast DropBloomIndexStmt) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_columns(&mut self, _stmt: &'ast CreateVirtualColumnsStmt) {}
 
     fn visit_alter_virtual_columns(&mut self, _stmt: &'ast AlterVirtualColumnsStmt) {}
@@ -627,20 +681,8 @@ pub trait Visitor<'ast>: Sized {
             walk_expr(self, selection);
         }
 
-        match group_by {
-            Some(GroupBy::Normal(exprs)) => {
-                for expr in exprs {
-                    walk_expr(self, expr);
-                }
-            }
-            Some(GroupBy::GroupingSets(sets)) => {
-                for set in sets {
-                    for expr in set {
-                        walk_expr(self, expr);
-                    }
-                }
-            }
-            _ => {}
+        if let Some(group_by) = group_by {
+            walk_group_by(self, group_by);
         }
 
         if let Some(having) = having {
@@ -19,6 +19,7 @@ use common_meta_app::principal::UserIdentity;
 
 use super::walk_mut::walk_cte_mut;
 use super::walk_mut::walk_expr_mut;
+use super::walk_mut::walk_group_by_mut;
 use super::walk_mut::walk_identifier_mut;
 use super::walk_mut::walk_join_condition_mut;
 use super::walk_mut::walk_query_mut;
@@ -244,11 +245,16 @@ pub trait VisitorMut: Sized {
         args: &mut [Expr],
         _params: &mut [Literal],
         over: &mut Option<Window>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
 
+        if let Some(lambda) = lambda {
+            walk_expr_mut(self, &mut lambda.expr);
+        }
+
         if let Some(over) = over {
             match over {
                 Window::WindowReference(reference) => {
@@ -364,6 +370,8 @@ pub trait VisitorMut: Sized {
         walk_expr_mut(self, date);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {}
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -392,6 +400,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_functions(&mut self, _limit: &mut Option<ShowLimit>) {}
 
+    fn visit_describe_function(&mut self, _name: &mut Identifier) {}
+
     fn visit_show_indexes(&mut self) {}
 
     fn visit_show_table_functions(&mut self, _limit: &mut Option<ShowLimit>) {}
@@ -400,6 +410,20 @@ pub trait VisitorMut: Sized {
 
     fn visit_kill(&mut self, _kill_target: &mut KillTarget, _object_id: &mut String) {}
 
+    fn visit_prepare_commit(&mut self, _id: &mut String, stmt: &mut Statement) {
+        walk_statement_mut(self, stmt);
+    }
+
+    fn visit_commit_prepared(&mut self, _id: &mut String) {}
+
+    fn visit_rollback_prepared(&mut self, _id: &mut String) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -426,6 +450,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -460,6 +486,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_create_table(&mut self, _stmt: &mut CreateTableStmt) {}
 
+    fn visit_create_external_table(&mut self, _stmt: &mut CreateExternalTableStmt) {}
+
     fn visit_create_table_source(&mut self, _source: &mut CreateTableSource) {}
 
     fn visit_column_definition(&mut self, _column_definition: &mut ColumnDefinition) {}
@@ -482,16 +510,44 @@ pub trait VisitorMut: Sized {
 
     fn visit_exists_table(&mut self, _stmt: &mut ExistsTableStmt) {}
 
+    fn visit_declare_result(&mut self, _stmt: &mut DeclareResultStmt) {}
+
     fn visit_create_view(&mut self, _stmt: &mut CreateViewStmt) {}
 
     fn visit_alter_view(&mut self, _stmt: &mut AlterViewStmt) {}
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
+    fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
+
+    fn visit_alter_statement(&mut self, _stmt: &mut AlterStatementStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
 
+    fn visit_create_bloom_index(&mut self, _stmt: &mut CreateBloomIndexStmt) {}
+
+    fn visit_drop_bloom_index(&mut self, _stmt: &mut DropBloomIndexStmt) {}
+
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
     fn visit_create_virtual_columns(&mut self, _stmt: &mut CreateVirtualColumnsStmt) {}
 
     fn visit_alter_virtual_columns(&mut self, _stmt: &mut AlterVirtualColumnsStmt) {}
@@ -642,20 +698,8 @@ pub trait VisitorMut: Sized {
             walk_expr_mut(self, selection);
         }
 
-        match group_by {
-            Some(GroupBy::Normal(exprs)) => {
-                for expr in exprs {
-                    walk_expr_mut(self, expr);
-                }
-            }
-            Some(GroupBy::GroupingSets(sets)) => {
-                for set in sets {
-                    for expr in set {
-                        walk_expr_mut(self, expr);
-                    }
-                }
-            }
-            _ => {}
+        if let Some(group_by) = group_by {
+            walk_group_by_mut(self, group_by);
         }
 
         if let Some(having) = having {
@@ -82,6 +82,13 @@ fn test_statement() {
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
+        r#"explain verify select a from b;"#,
+        r#"commit prepared 'p1';"#,
+        r#"rollback prepared 'p1';"#,
+        r#"begin;"#,
+        r#"start transaction;"#,
+        r#"commit work;"#,
+        r#"rollback;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table a (c decimal(38, 0))"#,
@@ -96,12 +103,16 @@ fn test_statement() {
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
              location_prefix = 'db';"#,
+        r#"create table t engine = delta 's3://testbucket/admin/delta_tbl/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin');"#,
+        r#"create external table if not exists a.b (c int, d string) location = '@s1/data/' pattern = '.*[.]csv' file_format = (type = CSV skip_header = 1);"#,
+        r#"create external table t location = 's3://bucket/lake/' connection = (aws_key_id = 'minioadmin' aws_secret_key = 'minioadmin') file_format = (type = PARQUET);"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
         r#"use "a";"#,
         r#"create catalog ctl type=hive connection=(url='<hive-meta-store>' thrift_protocol='binary');"#,
+        r#"create catalog remote type=databend connection=(address='127.0.0.1:8900' username='root' password='');"#,
         r#"create database if not exists a;"#,
         r#"create database ctl.t engine = Default;"#,
         r#"create database t engine = Default;"#,
@@ -112,6 +123,18 @@ fn test_statement() {
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
+        r#"declare r result as select a from b;"#,
+        r#"create materialized view mv refresh every 1 hour as select a from b;"#,
+        r#"refresh materialized view db.mv;"#,
+        r#"drop materialized view if exists mv;"#,
+        r#"create stream if not exists s on table db.t;"#,
+        r#"drop stream s;"#,
+        r#"create task if not exists t1 schedule = '*/5 * * * *' comment = 'hourly' as truncate table a;"#,
+        r#"create task t2 after t1, t3 as truncate table b;"#,
+        r#"alter task t1 suspend;"#,
+        r#"drop task if exists t1;"#,
+        r#"alter statement 'select * from t1 join t2 on t1.a = t2.a' pin plan;"#,
+        r#"alter statement 'select ''a''' unpin plan;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
         r#"rename table d.t to e.s;"#,
@@ -187,6 +210,7 @@ fn test_statement() {
         r#"VACUUM TABLE t RETAIN 4 HOURS DRY RUN;"#,
         r#"VACUUM TABLE t RETAIN 40 HOURS;"#,
         r#"CREATE TABLE t (a INT COMMENT 'col comment') COMMENT='table comment';"#,
+        r#"CREATE TABLE t (a INT, b INT) COMPRESSION=(a => 'zstd(9)', b => 'lz4') ENCODING=(b => 'delta');"#,
         r#"GRANT CREATE, CREATE USER ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON * TO 'test-grant'@'localhost';"#,
         r#"GRANT SELECT, CREATE ON *.* TO 'test-grant'@'localhost';"#,
@@ -288,6 +312,26 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO @my_stage/orders
+                FROM mytable
+                FILE_FORMAT = (type = PARQUET)
+                PARTITION BY (to_date(created_at) AS dt, region)
+                MAX_FILE_SIZE = 64000000
+                FILE_NAME_PREFIX = 'orders_';"#,
+        r#"COPY INTO @my_stage/orders
+                FROM mytable
+                FILE_FORMAT = (
+                    type = PARQUET
+                    compression = SNAPPY
+                    row_group_size = 100000
+                    data_page_size = 1048576
+                    statistics = true
+                    bloom_filter_columns = ('id', 'name')
+                );"#,
+        r#"COPY INTO @my_stage/dev
+                FROM mytable
+                APPLY MASKING
+                SAMPLE 1 PERCENT;"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -401,6 +445,7 @@ fn test_statement() {
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (), (d, e))"#,
         r#"SELECT * FROM t GROUP BY CUBE (a, b, c)"#,
         r#"SELECT * FROM t GROUP BY ROLLUP (a, b, c)"#,
+        r#"SELECT * FROM t GROUP BY a, ROLLUP (b, c)"#,
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURN STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"CREATE VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
@@ -23,3 +23,4 @@ pub use parquet::ParquetTableInfo;
 pub use parquet_read_options::ParquetReadOptions;
 pub use result_scan::ResultScanTableInfo;
 pub use stage::StageTableInfo;
+pub use stage::UnloadPartitionKey;
@@ -17,6 +17,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_expression::RemoteExpr;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::principal::StageInfo;
@@ -31,6 +32,22 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Set when unloading the result of a query with ORDER BY, the rows must be
+    /// written in order across the output files.
+    pub is_ordered: bool,
+    /// Set when unloading with `PARTITION BY`, the files are written under the Hive-style
+    /// directories of the partitions, e.g. `dt=2023-06-01/`.
+    pub partition_by: Vec<UnloadPartitionKey>,
+    /// Replaces the `data_` prefix of the names of the unloaded files.
+    pub file_name_prefix: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UnloadPartitionKey {
+    pub name: String,
+    /// Evaluated on the unloaded blocks to a nullable string, the NULL values are written to
+    /// the default partition of Hive.
+    pub expr: RemoteExpr,
 }
 
 impl StageTableInfo {
@@ -43,6 +43,9 @@ pub struct DataSourcePlan {
 
     // data mask policy for `output_schema` columns
     pub data_mask_policy: Option<BTreeMap<FieldIndex, RemoteExpr>>,
+
+    /// The table index of the scan in the query, to receive the runtime filters of hash joins.
+    pub table_index: Option<usize>,
 }
 
 impl DataSourcePlan {
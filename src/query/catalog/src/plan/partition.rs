@@ -259,4 +259,27 @@ impl StealablePartitions {
         drop(partitions);
         self.ctx.get_partitions(max_size)
     }
+
+    /// Returns the next `max_size` partitions that `steal` would return for `idx`, without taking them.
+    ///
+    /// Only the local partitions are visible, the ones dispatched by the query context are not.
+    pub fn peek(&self, idx: usize, max_size: usize) -> Vec<PartInfoPtr> {
+        let partitions = self.partitions.read();
+        if partitions.is_empty() {
+            return vec![];
+        }
+
+        let idx = idx % partitions.len();
+        for step in 0..partitions.len() {
+            let index = (idx + step) % partitions.len();
+            if !partitions[index].is_empty() {
+                return partitions[index].iter().take(max_size).cloned().collect();
+            }
+
+            if self.disable_steal {
+                break;
+            }
+        }
+        vec![]
+    }
 }
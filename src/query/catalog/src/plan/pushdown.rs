@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
@@ -72,6 +73,69 @@ pub struct AggIndexInfo {
     // The index in aggregating index is the offset in the output list.
     pub selection: Vec<RemoteExpr>,
     pub filter: Option<RemoteExpr>,
+    /// Used to build the index data of the blocks which are not refreshed yet.
+    pub definition: AggIndexDefinition,
+}
+
+/// How to build the aggregating index data of a block from its raw data.
+///
+/// The expressions refer to the columns of the table by name.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct AggIndexDefinition {
+    /// Assumption: expression's data type must be `DataType::Boolean`.
+    pub filter: Option<RemoteExpr<String>>,
+    pub is_agg: bool,
+    pub group_items: Vec<RemoteExpr<String>>,
+    /// Each item is a column of the index data.
+    pub selection: Vec<AggIndexItem>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AggIndexItem {
+    Scalar(RemoteExpr<String>),
+    /// Stored as the serialized state of the aggregate function,
+    /// so that the states of different blocks can be merged.
+    Aggregate {
+        func_name: String,
+        params: Vec<Scalar>,
+        args: Vec<RemoteExpr<String>>,
+    },
+}
+
+impl AggIndexDefinition {
+    /// Names of the table columns used to build the index data.
+    pub fn column_names(&self) -> BTreeSet<String> {
+        let mut exprs = Vec::new();
+        exprs.extend(self.filter.iter());
+        exprs.extend(self.group_items.iter());
+        for item in self.selection.iter() {
+            match item {
+                AggIndexItem::Scalar(expr) => exprs.push(expr),
+                AggIndexItem::Aggregate { args, .. } => exprs.extend(args.iter()),
+            }
+        }
+
+        let mut names = BTreeSet::new();
+        for expr in exprs {
+            collect_column_names(expr, &mut names);
+        }
+        names
+    }
+}
+
+fn collect_column_names(expr: &RemoteExpr<String>, names: &mut BTreeSet<String>) {
+    match expr {
+        RemoteExpr::ColumnRef { id, .. } => {
+            names.insert(id.clone());
+        }
+        RemoteExpr::Cast { expr, .. } => collect_column_names(expr, names),
+        RemoteExpr::FunctionCall { args, .. } | RemoteExpr::LambdaFunctionCall { args, .. } => {
+            for arg in args {
+                collect_column_names(arg, names);
+            }
+        }
+        RemoteExpr::Constant { .. } => {}
+    }
 }
 
 /// Extras is a wrapper for push down items.
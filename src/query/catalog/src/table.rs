@@ -273,6 +273,43 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Resolve the point of the history of the table `table_id` referred to by this table,
+    /// which is read by `AT (STREAM => <this table>)`.
+    #[async_backtrace::framed]
+    async fn resolve_navigation_point(&self, table_id: u64) -> Result<NavigationPoint> {
+        let _ = table_id;
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {}, of engine type {}, can not be used as a time travel point",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    /// The distinct values of the low-cardinality string column `column_name` known by the
+    /// table, returns [None] if the table keeps no dictionary of the column.
+    #[async_backtrace::framed]
+    async fn column_dictionary(&self, column_name: &str) -> Result<Option<Vec<Vec<u8>>>> {
+        let _ = column_name;
+
+        Ok(None)
+    }
+
+    /// Pin the table to the latest snapshot committed no later than `time_point`, so that
+    /// the tables read by a query are consistent with each other.
+    ///
+    /// Returns the pinned table and the id of its snapshot, or `None` if the table is not
+    /// versioned by snapshots.
+    #[async_backtrace::framed]
+    async fn pin_snapshot(
+        &self,
+        time_point: DateTime<Utc>,
+    ) -> Result<Option<(Arc<dyn Table>, String)>> {
+        let _ = time_point;
+
+        Ok(None)
+    }
+
     #[async_backtrace::framed]
     async fn delete(
         &self,
@@ -317,6 +354,22 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Assembly the pipeline of applying MERGE INTO to the table in one commit.
+    ///
+    /// The last pipe of `pipeline` has two outputs: (0) the rows to be appended, in the
+    /// schema of the table, (1) the `_row_id` of the rows to be deleted, as a single
+    /// non-nullable UInt64 column.
+    #[async_backtrace::framed]
+    async fn merge_into(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        let (_, _) = (ctx, pipeline);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "table {}, engine type {}, does not support MERGE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -347,14 +400,16 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    // `limit` is the max number of blocks to rewrite.
     #[async_backtrace::framed]
     async fn recluster(
         &self,
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         push_downs: Option<PushDownInfo>,
+        limit: Option<usize>,
     ) -> Result<()> {
-        let (_, _, _) = (ctx, pipeline, push_downs);
+        let (_, _, _, _) = (ctx, pipeline, push_downs, limit);
 
         Err(ErrorCode::Unimplemented(format!(
             "table {},  of engine type {}, does not support recluster",
@@ -425,6 +480,15 @@ pub struct ColumnStatistics {
     pub max: Scalar,
     pub null_count: u64,
     pub number_of_distinct_values: u64,
+    /// Equi-height histogram collected by `ANALYZE TABLE`, empty if none is collected.
+    pub histogram: Vec<ColumnHistogramBucket>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnHistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
 
 pub enum CompactTarget {
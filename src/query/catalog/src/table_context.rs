@@ -19,10 +19,13 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -32,6 +35,7 @@ use common_meta_app::principal::UserInfo;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataCacheMetrics;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
@@ -43,6 +47,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -63,6 +68,23 @@ pub struct ProcessInfo {
     pub status_info: Option<String>,
 }
 
+/// A processor in the pipeline of a running query.
+#[derive(Debug, Clone)]
+pub struct PipelineTaskInfo {
+    pub query_id: String,
+    /// The id of the processor, unique in the pipeline.
+    pub processor_id: usize,
+    pub processor_name: String,
+    /// The scheduling state of the processor, e.g. waiting for the input data.
+    pub state: String,
+    pub input_rows: u64,
+    pub output_rows: u64,
+    /// The ids of the processors this processor pulls data from.
+    pub inputs: Vec<usize>,
+    /// The ids of the processors this processor pushes data to.
+    pub outputs: Vec<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -120,12 +142,26 @@ pub trait TableContext: Send + Sync {
     fn get_settings(&self) -> Arc<Settings>;
     fn get_shard_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
+    /// State of the warehouse auto-suspend, e.g. `running` or `suspended`.
+    fn get_warehouse_state(&self) -> String;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// Get the processors of the pipelines executed by the running queries.
+    fn get_pipeline_tasks_info(&self) -> Vec<PipelineTaskInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
+    /// Get the location of the result declared as `name` by `DECLARE <name> RESULT AS ...`.
+    fn get_declared_result(&self, name: &str) -> Option<String>;
+    fn set_declared_result(&self, name: String, location: String);
+    /// The transaction of the session, see `BEGIN`.
+    fn txn_mgr(&self) -> TxnManagerRef;
+    /// The logical time that the tables read by the query are pinned to.
+    fn get_snapshot_pin_time(&self) -> DateTime<Utc>;
+    /// The snapshot ids of the tables pinned by the query, keyed by `catalog.database.table`.
+    fn get_pinned_snapshots(&self) -> BTreeMap<String, String>;
+    fn add_pinned_snapshot(&self, table: String, snapshot_id: String);
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>>;
     fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>);
     fn get_on_error_mode(&self) -> Option<OnErrorMode>;
@@ -138,11 +174,18 @@ pub trait TableContext: Send + Sync {
     // Get the storage data accessor operator from the session manager.
     fn get_data_operator(&self) -> Result<DataOperator>;
 
+    // Get the hits and misses of the table data cache in this query.
+    fn get_data_cache_metrics(&self) -> Arc<DataCacheMetrics>;
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Add the filters on the columns of a table scan, built from the build side of a hash join.
+    fn add_runtime_filters(&self, table_index: usize, filters: Vec<Expr<String>>);
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Expr<String>>;
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
@@ -0,0 +1,164 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session-scoped transactions of `BEGIN`, `COMMIT` and `ROLLBACK`.
+//!
+//! Every statement commits its table mutations to the meta service by default. In a
+//! transaction, the new table metas of the mutations are buffered in the [`TxnManager`]
+//! of the session instead, and are committed to the meta service atomically at `COMMIT`.
+//!
+//! A statement writing multiple tables, e.g. an `INSERT` consuming a stream, buffers its
+//! mutations the same way in a statement transaction, which is committed atomically once
+//! the statement finishes.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement is committed on its own.
+    #[default]
+    AutoCommit,
+    /// The mutations are buffered until `COMMIT` or `ROLLBACK`.
+    Active,
+    /// The mutations of the running statement are buffered until it finishes.
+    Statement,
+}
+
+/// The buffered mutations of a table in a transaction.
+#[derive(Clone, Debug)]
+pub struct TxnTableMutation {
+    /// The table info with the latest buffered table meta. Its `ident.seq` is the version of
+    /// the table meta read by the first mutation, which must be unchanged at `COMMIT`.
+    pub table_info: TableInfo,
+    pub copied_files: Option<UpsertTableCopiedFileReq>,
+    pub deduplicated_label: Option<String>,
+    /// Files written by the mutations, which are not referenced once rolled back.
+    pub uncommitted_files: Vec<String>,
+}
+
+#[derive(Default)]
+pub struct TxnManager {
+    state: TxnState,
+    /// Keyed by table id.
+    mutations: BTreeMap<u64, TxnTableMutation>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    /// Returns whether the table mutations are buffered instead of being committed.
+    pub fn is_buffering(&self) -> bool {
+        self.state != TxnState::AutoCommit
+    }
+
+    pub fn begin(&mut self) -> Result<()> {
+        if self.is_active() {
+            return Err(ErrorCode::InvalidTransactionState(
+                "there is already a transaction in progress",
+            ));
+        }
+        self.state = TxnState::Active;
+        Ok(())
+    }
+
+    /// Ends the transaction, returns the buffered mutations to commit or roll back.
+    pub fn end(&mut self) -> Vec<TxnTableMutation> {
+        self.state = TxnState::AutoCommit;
+        std::mem::take(&mut self.mutations).into_values().collect()
+    }
+
+    /// Starts a statement transaction for a statement writing multiple tables.
+    ///
+    /// It is a no-op in a transaction, whose mutations are already buffered.
+    pub fn begin_statement(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Statement;
+        }
+    }
+
+    /// Ends the statement transaction, returns its buffered mutations to commit or roll back.
+    ///
+    /// Returns nothing if there is no statement transaction.
+    pub fn end_statement(&mut self) -> Vec<TxnTableMutation> {
+        if self.state != TxnState::Statement {
+            return vec![];
+        }
+        self.end()
+    }
+
+    /// Buffers the new table meta of a mutation of the table `table_info`.
+    ///
+    /// `uncommitted_file` is the file written by the mutation, e.g. its snapshot.
+    pub fn update_table_meta(
+        &mut self,
+        table_info: &TableInfo,
+        new_table_meta: TableMeta,
+        copied_files: &Option<UpsertTableCopiedFileReq>,
+        deduplicated_label: Option<String>,
+        uncommitted_file: Option<String>,
+    ) {
+        let mutation = self
+            .mutations
+            .entry(table_info.ident.table_id)
+            .or_insert_with(|| TxnTableMutation {
+                table_info: table_info.clone(),
+                copied_files: None,
+                deduplicated_label: None,
+                uncommitted_files: vec![],
+            });
+        mutation.table_info.meta = new_table_meta;
+        mutation.uncommitted_files.extend(uncommitted_file);
+        if deduplicated_label.is_some() {
+            mutation.deduplicated_label = deduplicated_label;
+        }
+        if let Some(req) = copied_files {
+            match &mut mutation.copied_files {
+                Some(merged) => {
+                    merged.file_info.extend(req.file_info.clone());
+                    merged.expire_at = merged.expire_at.max(req.expire_at);
+                    merged.fail_if_duplicated |= req.fail_if_duplicated;
+                }
+                None => mutation.copied_files = Some(req.clone()),
+            }
+        }
+    }
+
+    /// Returns the table info with the buffered table meta, if the table has been mutated
+    /// in the transaction, so the statements see the mutations made before them.
+    pub fn get_table_info(&self, table_id: u64) -> Option<TableInfo> {
+        self.mutations
+            .get(&table_id)
+            .map(|mutation| mutation.table_info.clone())
+    }
+}
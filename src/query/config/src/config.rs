@@ -41,6 +41,8 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_storage::StorageRangeReadConfig as InnerStorageRangeReadConfig;
+use common_storage::StorageRetryConfig as InnerStorageRetryConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
@@ -258,6 +260,14 @@ pub struct StorageConfig {
     // COS storage backend config
     #[clap(flatten)]
     pub cos: CosStorageConfig,
+
+    // Retry config of the storage operations
+    #[clap(flatten)]
+    pub retry: RetryStorageConfig,
+
+    // Ranged read config of the block readers
+    #[clap(flatten)]
+    pub range_read: RangeReadStorageConfig,
 }
 
 impl Default for StorageConfig {
@@ -285,6 +295,8 @@ impl From<InnerStorageConfig> for StorageConfig {
             obs: Default::default(),
             webhdfs: Default::default(),
             cos: Default::default(),
+            retry: inner.retry.into(),
+            range_read: inner.range_read.into(),
         };
 
         match inner.params {
@@ -358,6 +370,8 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
                     _ => return Err(ErrorCode::StorageOther("not supported storage type")),
                 }
             },
+            retry: self.retry.try_into()?,
+            range_read: self.range_read.try_into()?,
         })
     }
 }
@@ -499,6 +513,106 @@ impl TryInto<InnerStorageFsConfig> for FsStorageConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct RetryStorageConfig {
+    /// Max times to retry a failed storage operation
+    #[clap(long = "storage-retry-max-times", default_value = "3")]
+    pub max_times: usize,
+
+    /// Delay before the first retry in milliseconds
+    #[clap(long = "storage-retry-min-delay-ms", default_value = "1000")]
+    pub min_delay_ms: u64,
+
+    /// Max delay between retries in milliseconds
+    #[clap(long = "storage-retry-max-delay-ms", default_value = "60000")]
+    pub max_delay_ms: u64,
+
+    /// Backoff factor of the delay between retries
+    #[clap(long = "storage-retry-factor", default_value = "2")]
+    pub factor: u32,
+
+    /// Send a hedged read request if the first one has not responded in this time, 0 to disable
+    #[clap(long = "storage-retry-hedged-read-delay-ms", default_value = "0")]
+    pub hedged_read_delay_ms: u64,
+}
+
+impl Default for RetryStorageConfig {
+    fn default() -> Self {
+        InnerStorageRetryConfig::default().into()
+    }
+}
+
+impl From<InnerStorageRetryConfig> for RetryStorageConfig {
+    fn from(inner: InnerStorageRetryConfig) -> Self {
+        Self {
+            max_times: inner.max_times,
+            min_delay_ms: inner.min_delay_ms,
+            max_delay_ms: inner.max_delay_ms,
+            factor: inner.factor,
+            hedged_read_delay_ms: inner.hedged_read_delay_ms,
+        }
+    }
+}
+
+impl TryInto<InnerStorageRetryConfig> for RetryStorageConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageRetryConfig> {
+        Ok(InnerStorageRetryConfig {
+            max_times: self.max_times,
+            min_delay_ms: self.min_delay_ms,
+            max_delay_ms: self.max_delay_ms,
+            factor: self.factor,
+            hedged_read_delay_ms: self.hedged_read_delay_ms,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct RangeReadStorageConfig {
+    /// Split the block reads larger than this into parallel ranged reads, 0 to disable
+    #[clap(long = "storage-range-read-parallel-bytes", default_value_t)]
+    pub parallel_bytes: u64,
+
+    /// Start a duplicate ranged read if the first one has not responded in the p99 latency
+    #[clap(long = "storage-range-read-hedged")]
+    pub hedged: bool,
+
+    /// Lower bound of the hedging delay in milliseconds
+    #[clap(long = "storage-range-read-hedge-min-delay-ms", default_value_t)]
+    pub hedge_min_delay_ms: u64,
+}
+
+impl Default for RangeReadStorageConfig {
+    fn default() -> Self {
+        InnerStorageRangeReadConfig::default().into()
+    }
+}
+
+impl From<InnerStorageRangeReadConfig> for RangeReadStorageConfig {
+    fn from(inner: InnerStorageRangeReadConfig) -> Self {
+        Self {
+            parallel_bytes: inner.parallel_bytes,
+            hedged: inner.hedged,
+            hedge_min_delay_ms: inner.hedge_min_delay_ms,
+        }
+    }
+}
+
+impl TryInto<InnerStorageRangeReadConfig> for RangeReadStorageConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageRangeReadConfig> {
+        Ok(InnerStorageRangeReadConfig {
+            parallel_bytes: self.parallel_bytes,
+            hedged: self.hedged,
+            hedge_min_delay_ms: self.hedge_min_delay_ms,
+        })
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct GcsStorageConfig {
@@ -681,6 +795,7 @@ impl TryInto<InnerStorageS3Config> for S3StorageConfig {
             enable_virtual_host_style: self.enable_virtual_host_style,
             role_arn: self.s3_role_arn,
             external_id: self.s3_external_id,
+            session_tags: Default::default(),
             allow_anonymous: self.s3_allow_anonymous,
         })
     }
@@ -1252,6 +1367,12 @@ pub struct QueryConfig {
     #[clap(long, default_value = "8900")]
     pub flight_sql_handler_port: u16,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
@@ -1415,6 +1536,31 @@ pub struct QueryConfig {
     #[clap(long)]
     pub disable_system_table_load: bool,
 
+    /// Local directory for the spill files of aggregations, the data storage is used if empty.
+    /// Only takes effect in standalone mode.
+    #[clap(long, default_value = "")]
+    pub spill_local_path: String,
+
+    /// Suspend the warehouse after it has been idle for N seconds, 0 disables auto-suspend.
+    #[clap(long, default_value = "0")]
+    pub auto_suspend_secs: u64,
+
+    /// Webhook called with `{"action": "suspend" | "resume", ...}` to scale the warehouse.
+    #[clap(long, default_value = "")]
+    pub auto_suspend_webhook_url: String,
+
+    /// Check the materialized views due to refresh every N seconds, 0 disables scheduled refresh.
+    #[clap(long, default_value = "10")]
+    pub materialized_view_refresh_check_secs: u64,
+
+    /// Check the tasks due to run every N seconds, 0 disables running tasks.
+    #[clap(long, default_value = "10")]
+    pub task_schedule_check_secs: u64,
+
+    /// Check the fuse tables due to compact every N seconds, 0 disables background compaction.
+    #[clap(long, default_value = "60")]
+    pub auto_compaction_check_secs: u64,
+
     /// chat base url.
     #[clap(long, default_value = "https://api.openai.com/v1/")]
     pub openai_api_chat_base_url: String,
@@ -1468,6 +1614,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
@@ -1502,6 +1650,12 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
             disable_system_table_load: self.disable_system_table_load,
+            spill_local_path: self.spill_local_path,
+            auto_suspend_secs: self.auto_suspend_secs,
+            auto_suspend_webhook_url: self.auto_suspend_webhook_url,
+            materialized_view_refresh_check_secs: self.materialized_view_refresh_check_secs,
+            task_schedule_check_secs: self.task_schedule_check_secs,
+            auto_compaction_check_secs: self.auto_compaction_check_secs,
             openai_api_chat_base_url: self.openai_api_chat_base_url,
             openai_api_embedding_base_url: self.openai_api_embedding_base_url,
             openai_api_key: self.openai_api_key,
@@ -1539,6 +1693,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
@@ -1583,6 +1739,12 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_cache_bloom_index_filter_count: None,
             table_cache_bloom_index_data_bytes: None,
             disable_system_table_load: inner.disable_system_table_load,
+            spill_local_path: inner.spill_local_path,
+            auto_suspend_secs: inner.auto_suspend_secs,
+            auto_suspend_webhook_url: inner.auto_suspend_webhook_url,
+            materialized_view_refresh_check_secs: inner.materialized_view_refresh_check_secs,
+            task_schedule_check_secs: inner.task_schedule_check_secs,
+            auto_compaction_check_secs: inner.auto_compaction_check_secs,
             openai_api_chat_base_url: inner.openai_api_chat_base_url,
             openai_api_embedding_base_url: inner.openai_api_embedding_base_url,
             openai_api_key: inner.openai_api_key,
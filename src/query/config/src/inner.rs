@@ -139,6 +139,8 @@ pub struct QueryConfig {
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
@@ -179,6 +181,17 @@ pub struct QueryConfig {
     pub internal_merge_on_read_mutation: bool,
     /// Disable some system load(For example system.configs) for cloud security.
     pub disable_system_table_load: bool,
+    /// Local directory for the spill files of aggregations, empty to use the data storage.
+    pub spill_local_path: String,
+    /// Seconds of idleness before the warehouse is suspended, 0 means never.
+    pub auto_suspend_secs: u64,
+    pub auto_suspend_webhook_url: String,
+    /// Interval of checking the materialized views due to refresh, 0 means never.
+    pub materialized_view_refresh_check_secs: u64,
+    /// Interval of checking the tasks due to run, 0 means never.
+    pub task_schedule_check_secs: u64,
+    /// Interval of checking the fuse tables due to compact, 0 means never.
+    pub auto_compaction_check_secs: u64,
 
     /// (azure) openai
     pub openai_api_key: String,
@@ -209,6 +222,8 @@ impl Default for QueryConfig {
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -240,6 +255,12 @@ impl Default for QueryConfig {
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
             disable_system_table_load: false,
+            spill_local_path: "".to_string(),
+            auto_suspend_secs: 0,
+            auto_suspend_webhook_url: "".to_string(),
+            materialized_view_refresh_check_secs: 10,
+            task_schedule_check_secs: 10,
+            auto_compaction_check_secs: 60,
             flight_sql_tls_server_key: "".to_string(),
             openai_api_chat_base_url: "https://api.openai.com/v1/".to_string(),
             openai_api_embedding_base_url: "https://api.openai.com/v1/".to_string(),
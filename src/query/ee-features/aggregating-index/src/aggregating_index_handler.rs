@@ -16,11 +16,15 @@ use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
 use common_catalog::catalog::Catalog;
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
 
 #[async_trait::async_trait]
 pub trait AggregatingIndexHandler: Sync + Send {
@@ -35,6 +39,16 @@ pub trait AggregatingIndexHandler: Sync + Send {
         catalog: Arc<dyn Catalog>,
         req: DropIndexReq,
     ) -> Result<DropIndexReply>;
+
+    /// Build the index data of the blocks in the segments, all the segments if [None].
+    async fn do_refresh_index(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        definition: &AggIndexDefinition,
+        segment_locs: Option<Vec<Location>>,
+    ) -> Result<()>;
 }
 
 pub struct AggregatingIndexHandlerWrapper {
@@ -63,6 +77,20 @@ impl AggregatingIndexHandlerWrapper {
     ) -> Result<DropIndexReply> {
         self.handler.do_drop_index(catalog, req).await
     }
+
+    #[async_backtrace::framed]
+    pub async fn do_refresh_index(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        definition: &AggIndexDefinition,
+        segment_locs: Option<Vec<Location>>,
+    ) -> Result<()> {
+        self.handler
+            .do_refresh_index(fuse_table, ctx, index_id, definition, segment_locs)
+            .await
+    }
 }
 
 pub fn get_agg_index_handler() -> Arc<AggregatingIndexHandlerWrapper> {
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_base::base::GlobalInstance;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::TableInfo;
+use common_profile::ProfCounters;
 
 use crate::TableLockHeartbeat;
 
@@ -60,7 +62,10 @@ impl TableLockHandlerWrapper {
         ctx: Arc<dyn TableContext>,
         table_info: TableInfo,
     ) -> Result<TableLockHeartbeat> {
-        self.handler.try_lock(ctx, table_info).await
+        let instant = Instant::now();
+        let res = self.handler.try_lock(ctx, table_info).await;
+        ProfCounters::record_lock_wait_time(instant.elapsed().as_nanos() as u64);
+        res
     }
 
     pub fn instance(ctx: Arc<dyn TableContext>) -> Arc<TableLockHandlerWrapper> {
@@ -27,6 +27,7 @@ use common_meta_app::schema::UpdateVirtualColumnReply;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::VirtualColumnMeta;
 use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
 
 #[async_trait::async_trait]
 pub trait VirtualColumnsHandler: Sync + Send {
@@ -54,11 +55,13 @@ pub trait VirtualColumnsHandler: Sync + Send {
         req: ListVirtualColumnsReq,
     ) -> Result<Vec<VirtualColumnMeta>>;
 
+    /// Generate the virtual columns of the blocks in the segments, all the segments if [None].
     async fn do_generate_virtual_columns(
         &self,
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
+        segment_locs: Option<Vec<Location>>,
     ) -> Result<()>;
 }
 
@@ -113,9 +116,10 @@ impl VirtualColumnsHandlerWrapper {
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
+        segment_locs: Option<Vec<Location>>,
     ) -> Result<()> {
         self.handler
-            .do_generate_virtual_columns(fuse_table, ctx, virtual_columns)
+            .do_generate_virtual_columns(fuse_table, ctx, virtual_columns, segment_locs)
             .await
     }
 }
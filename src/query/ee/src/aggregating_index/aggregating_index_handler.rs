@@ -18,11 +18,17 @@ use aggregating_index::AggregatingIndexHandler;
 use aggregating_index::AggregatingIndexHandlerWrapper;
 use common_base::base::GlobalInstance;
 use common_catalog::catalog::Catalog;
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
+
+use crate::storages::fuse::do_refresh_agg_index;
 
 pub struct RealAggregatingIndexHandler {}
 
@@ -45,6 +51,18 @@ impl AggregatingIndexHandler for RealAggregatingIndexHandler {
     ) -> Result<DropIndexReply> {
         catalog.drop_index(req).await
     }
+
+    #[async_backtrace::framed]
+    async fn do_refresh_index(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        definition: &AggIndexDefinition,
+        segment_locs: Option<Vec<Location>>,
+    ) -> Result<()> {
+        do_refresh_agg_index(fuse_table, ctx, index_id, definition, segment_locs).await
+    }
 }
 
 impl RealAggregatingIndexHandler {
@@ -0,0 +1,105 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_fuse::io::write_data;
+use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::ReadSettings;
+use common_storages_fuse::io::TableMetaLocationGenerator;
+use common_storages_fuse::operations::build_agg_index_block;
+use common_storages_fuse::operations::serialize_agg_index_block;
+use common_storages_fuse::FuseTable;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::Location;
+
+/// Build and write the aggregating index data of the blocks in `segment_locs`,
+/// or of all the blocks of the current snapshot if `segment_locs` is [None].
+#[async_backtrace::framed]
+pub async fn do_refresh_agg_index(
+    fuse_table: &FuseTable,
+    ctx: Arc<dyn TableContext>,
+    index_id: u64,
+    definition: &AggIndexDefinition,
+    segment_locs: Option<Vec<Location>>,
+) -> Result<()> {
+    let segment_locs = match segment_locs {
+        Some(segment_locs) => segment_locs,
+        None => match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            // no snapshot
+            None => return Ok(()),
+        },
+    };
+    if segment_locs.is_empty() {
+        return Ok(());
+    }
+
+    let table_schema = &fuse_table.get_table_info().meta.schema;
+    let column_names = definition.column_names();
+    let field_indices = table_schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| column_names.contains(f.name()))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let projection = Projection::Columns(field_indices);
+    let block_reader = fuse_table.create_block_reader(projection, false, ctx.clone())?;
+    let source_schema = block_reader.schema();
+
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table_schema.clone());
+
+    let settings = ReadSettings::from_ctx(&ctx)?;
+    let write_settings = fuse_table.get_write_settings();
+    let storage_format = write_settings.storage_format;
+    let func_ctx = ctx.get_function_context()?;
+
+    let operator = fuse_table.get_operator_ref();
+
+    for (location, ver) in &segment_locs {
+        let segment_info = segment_reader
+            .read(&LoadParams {
+                location: location.to_string(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            })
+            .await?;
+
+        let block_metas = segment_info.block_metas()?;
+        for block_meta in block_metas {
+            let block = block_reader
+                .read_by_meta(&settings, &block_meta, &storage_format)
+                .await?;
+            let index_block = build_agg_index_block(&func_ctx, definition, &source_schema, block)?;
+
+            let index_loc = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                &block_meta.location.0,
+                index_id,
+            );
+            let data = serialize_agg_index_block(index_block, write_settings.table_compression)?;
+            write_data(data, operator, &index_loc).await?;
+        }
+    }
+
+    Ok(())
+}
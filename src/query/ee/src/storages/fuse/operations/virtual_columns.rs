@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -20,17 +22,18 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::DataType;
 use common_expression::types::VariantType;
 use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
-use common_expression::TableSchemaRefExt;
 use common_expression::Value;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_storages_fuse::io::serialize_block;
@@ -40,29 +43,57 @@ use common_storages_fuse::io::ReadSettings;
 use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::io::WriteSettings;
 use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_VIRTUAL_COLUMN_STATS_PREFIX;
+use jsonb::array_length;
+use jsonb::as_bool;
+use jsonb::as_f64;
+use jsonb::as_i64;
+use jsonb::as_str;
+use jsonb::get_by_index;
+use jsonb::get_by_name;
+use jsonb::is_array;
+use jsonb::is_object;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::jsonpath::Selector;
+use jsonb::object_keys;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::Location;
 
+// A path is extracted automatically if it is in at least half of the rows of a block.
+const AUTO_VIRTUAL_COLUMN_MIN_RATIO: f64 = 0.5;
+// The max depth of the automatically extracted paths.
+const AUTO_VIRTUAL_COLUMN_MAX_DEPTH: usize = 3;
+// The max number of the automatically extracted paths of a variant column in a block.
+const AUTO_VIRTUAL_COLUMN_MAX_NUM: usize = 32;
+// The strings longer than this are not kept in the statistics.
+const VIRTUAL_COLUMN_STATS_MAX_STRING_LEN: usize = 256;
+
+/// Generate the virtual columns of the blocks in `segment_locs`, or of all the blocks of
+/// the current snapshot if `segment_locs` is [None].
+///
+/// Besides the declared `virtual_columns`, the paths of the object keys found in most of
+/// the rows of a block are extracted from every variant column automatically.
 #[async_backtrace::framed]
 pub async fn do_generate_virtual_columns(
     fuse_table: &FuseTable,
     ctx: Arc<dyn TableContext>,
     virtual_columns: Vec<String>,
+    segment_locs: Option<Vec<Location>>,
 ) -> Result<()> {
-    if virtual_columns.is_empty() {
+    let segment_locs = match segment_locs {
+        Some(segment_locs) => segment_locs,
+        None => match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            // no snapshot
+            None => return Ok(()),
+        },
+    };
+    if segment_locs.is_empty() {
         return Ok(());
     }
 
-    let snapshot_opt = fuse_table.read_table_snapshot().await?;
-    let snapshot = if let Some(val) = snapshot_opt {
-        val
-    } else {
-        // no snapshot
-        return Ok(());
-    };
-
     let table_schema = &fuse_table.get_table_info().meta.schema;
 
     let mut field_indices = Vec::new();
@@ -71,19 +102,15 @@ pub async fn do_generate_virtual_columns(
         if f.data_type().remove_nullable() != TableDataType::Variant {
             continue;
         }
-        let mut is_src_column = false;
         for virtual_column in &virtual_columns {
             if virtual_column.starts_with(&f.name().clone()) {
-                is_src_column = true;
                 let name = virtual_column.clone();
                 let mut src_name = virtual_column.clone();
                 let path = src_name.split_off(f.name().len());
                 paths.push((name, src_name, path));
             }
         }
-        if is_src_column {
-            field_indices.push(i);
-        }
+        field_indices.push(i);
     }
 
     if field_indices.is_empty() {
@@ -104,7 +131,7 @@ pub async fn do_generate_virtual_columns(
 
     let operator = fuse_table.get_operator_ref();
 
-    for (location, ver) in &snapshot.segments {
+    for (location, ver) in &segment_locs {
         let segment_info = segment_reader
             .read(&LoadParams {
                 location: location.to_string(),
@@ -147,9 +174,28 @@ async fn materialize_virtual_columns(
     block: DataBlock,
 ) -> Result<()> {
     let len = block.num_rows();
-    let mut virtual_fields = Vec::with_capacity(paths.len());
-    let mut virtual_columns = Vec::with_capacity(paths.len());
-    for (virtual_name, src_name, path) in paths {
+
+    let mut all_paths = paths.clone();
+    for (index, field) in source_schema.fields().iter().enumerate() {
+        let block_entry = block.get_by_offset(index);
+        let column = block_entry
+            .value
+            .convert_to_full_column(&block_entry.data_type, len);
+        for path in frequent_paths(&column, len) {
+            let name = format!("{}{}", field.name(), path);
+            if all_paths.iter().all(|(n, _, _)| n != &name) {
+                all_paths.push((name, field.name().clone(), path));
+            }
+        }
+    }
+    if all_paths.is_empty() {
+        return Ok(());
+    }
+
+    let mut virtual_fields = Vec::with_capacity(all_paths.len());
+    let mut virtual_columns = Vec::with_capacity(all_paths.len());
+    let mut metadata = BTreeMap::new();
+    for (virtual_name, src_name, path) in &all_paths {
         let index = source_schema.index_of(src_name).unwrap();
         let virtual_field = TableField::new(
             virtual_name.as_str(),
@@ -167,6 +213,7 @@ async fn materialize_virtual_columns(
 
         let mut validity = MutableBitmap::with_capacity(len);
         let mut builder = StringColumnBuilder::with_capacity(len, len * 10);
+        let mut stats = VirtualColumnStatsBuilder::default();
         for row in 0..len {
             let val = unsafe { column.index_unchecked(row) };
             if let ScalarRef::Variant(v) = val {
@@ -174,14 +221,22 @@ async fn materialize_virtual_columns(
                 if !vals.is_empty() {
                     validity.push(true);
                     let val = vals.remove(0);
+                    stats.add(&val);
                     builder.put_slice(val.as_slice());
                     builder.commit_row();
                     continue;
                 }
             }
             validity.push(false);
+            stats.add_null();
             builder.commit_row();
         }
+        if let Some(stats) = stats.finish() {
+            metadata.insert(
+                format!("{FUSE_VIRTUAL_COLUMN_STATS_PREFIX}{virtual_name}"),
+                serde_json::to_string(&stats)?,
+            );
+        }
         let column = Column::Nullable(Box::new(
             NullableColumn::<VariantType> {
                 column: builder.build(),
@@ -195,7 +250,7 @@ async fn materialize_virtual_columns(
         );
         virtual_columns.push(virtual_column);
     }
-    let virtual_schema = TableSchemaRefExt::create(virtual_fields);
+    let virtual_schema = Arc::new(TableSchema::new_from(virtual_fields, metadata));
     let virtual_block = DataBlock::new(virtual_columns, len);
 
     let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
@@ -205,3 +260,152 @@ async fn materialize_virtual_columns(
 
     Ok(())
 }
+
+// Returns the paths of the object keys in at least `AUTO_VIRTUAL_COLUMN_MIN_RATIO` of the
+// rows, in the form of `:k1:k2`, the most frequent ones first.
+fn frequent_paths(column: &Column, len: usize) -> Vec<String> {
+    let mut counts = HashMap::new();
+    for row in 0..len {
+        if let ScalarRef::Variant(v) = unsafe { column.index_unchecked(row) } {
+            collect_object_paths(v, String::new(), 1, &mut counts);
+        }
+    }
+
+    let min_count = ((len as f64) * AUTO_VIRTUAL_COLUMN_MIN_RATIO)
+        .ceil()
+        .max(1.0) as usize;
+    let mut paths = counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_count)
+        .collect::<Vec<_>>();
+    paths.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    paths.truncate(AUTO_VIRTUAL_COLUMN_MAX_NUM);
+    paths.into_iter().map(|(path, _)| path).collect()
+}
+
+// Counts the paths of the scalar values under the object, the keys not being identifiers
+// are skipped since they can not be written in the paths.
+fn collect_object_paths(
+    value: &[u8],
+    prefix: String,
+    depth: usize,
+    counts: &mut HashMap<String, usize>,
+) {
+    let Some(keys) = object_keys(value) else {
+        return;
+    };
+    let len = array_length(&keys).unwrap_or(0);
+    for i in 0..len {
+        let key =
+            get_by_index(&keys, i as i32).and_then(|key| as_str(&key).map(|key| key.to_string()));
+        let Some(key) = key else {
+            continue;
+        };
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            continue;
+        }
+        let Some(child) = get_by_name(value, &key) else {
+            continue;
+        };
+        let path = format!("{prefix}:{key}");
+        if is_object(&child) {
+            if depth < AUTO_VIRTUAL_COLUMN_MAX_DEPTH {
+                collect_object_paths(&child, path, depth + 1, counts);
+            }
+        } else if !is_array(&child) {
+            *counts.entry(path).or_default() += 1;
+        }
+    }
+}
+
+// Builds the typed min/max statistics of the values of a virtual column, there are
+// statistics only if all the values have the same type.
+#[derive(Default)]
+struct VirtualColumnStatsBuilder {
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+    null_count: u64,
+    mixed: bool,
+}
+
+impl VirtualColumnStatsBuilder {
+    fn add_null(&mut self) {
+        self.null_count += 1;
+    }
+
+    fn add(&mut self, value: &[u8]) {
+        if self.mixed {
+            return;
+        }
+        let scalar = if let Some(v) = as_i64(value) {
+            Scalar::Number(NumberScalar::Int64(v))
+        } else if let Some(v) = as_f64(value) {
+            Scalar::Number(NumberScalar::Float64(v.into()))
+        } else if let Some(v) = as_bool(value) {
+            Scalar::Boolean(v)
+        } else if let Some(v) = as_str(value) {
+            if v.len() > VIRTUAL_COLUMN_STATS_MAX_STRING_LEN {
+                self.mixed = true;
+                return;
+            }
+            Scalar::String(v.as_bytes().to_vec())
+        } else if is_object(value) || is_array(value) {
+            self.mixed = true;
+            return;
+        } else {
+            // json null
+            self.null_count += 1;
+            return;
+        };
+
+        // the integers are kept as floats together with the floats
+        let (min, max) = match (self.min.take(), self.max.take()) {
+            (Some(min), Some(max)) => {
+                let (scalar, min, max) = match (&scalar, &min) {
+                    (
+                        Scalar::Number(NumberScalar::Float64(_)),
+                        Scalar::Number(NumberScalar::Int64(_)),
+                    ) => (scalar, to_float(min), to_float(max)),
+                    (
+                        Scalar::Number(NumberScalar::Int64(_)),
+                        Scalar::Number(NumberScalar::Float64(_)),
+                    ) => (to_float(scalar), min, max),
+                    _ => (scalar, min, max),
+                };
+                if scalar.as_ref().infer_data_type() != min.as_ref().infer_data_type() {
+                    self.mixed = true;
+                    return;
+                }
+                (
+                    if scalar < min { scalar.clone() } else { min },
+                    if scalar > max { scalar } else { max },
+                )
+            }
+            _ => (scalar.clone(), scalar),
+        };
+        self.min = Some(min);
+        self.max = Some(max);
+    }
+
+    fn finish(self) -> Option<ColumnStatistics> {
+        if self.mixed {
+            return None;
+        }
+        Some(ColumnStatistics {
+            min: self.min.unwrap_or(Scalar::Null),
+            max: self.max.unwrap_or(Scalar::Null),
+            null_count: self.null_count,
+            in_memory_size: 0,
+            distinct_of_values: None,
+        })
+    }
+}
+
+fn to_float(scalar: Scalar) -> Scalar {
+    match scalar {
+        Scalar::Number(NumberScalar::Int64(v)) => {
+            Scalar::Number(NumberScalar::Float64((v as f64).into()))
+        }
+        scalar => scalar,
+    }
+}
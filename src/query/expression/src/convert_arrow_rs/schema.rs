@@ -32,6 +32,8 @@ use crate::DataSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_UUID;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -93,6 +95,8 @@ impl From<&DataType> for ArrowDataType {
             DataType::EmptyArray => ArrowDataType::Null,
             DataType::EmptyMap => ArrowDataType::Null,
             DataType::Variant => ArrowDataType::LargeBinary,
+            DataType::Uuid => ArrowDataType::FixedSizeBinary(16),
+            DataType::Geometry => ArrowDataType::LargeBinary,
 
             _ => unreachable!(),
         }
@@ -144,6 +148,15 @@ impl From<&DataField> for ArrowField {
             DataType::Bitmap => {
                 metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
             }
+            DataType::Uuid => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_UUID.to_string());
+            }
+            DataType::Geometry => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                );
+            }
             _ => Default::default(),
         };
         match ty {
@@ -201,6 +214,8 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(DataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(DataType::Bitmap),
+            Some(ARROW_EXT_TYPE_UUID) => Some(DataType::Uuid),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(DataType::Geometry),
             _ => None,
         };
 
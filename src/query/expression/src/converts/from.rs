@@ -14,8 +14,11 @@
 
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 use crate::types::number::NumberScalar;
+use crate::types::uuid::string_to_uuid;
 use crate::types::*;
 use crate::ColumnBuilder;
 use crate::Scalar;
@@ -85,13 +88,13 @@ pub fn from_schema(schema: &common_datavalues::DataSchema) -> TableSchema {
     TableSchema::new_from(fields, schema.meta().clone())
 }
 
-pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
+pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Result<Scalar> {
     if datavalue.is_null() {
-        return Scalar::Null;
+        return Ok(Scalar::Null);
     }
 
     let datatype = datatype.remove_nullable();
-    match datatype {
+    let scalar = match datatype {
         DataType::Null => Scalar::Null,
         DataType::Boolean => Scalar::Boolean(*datavalue.as_boolean().unwrap()),
         DataType::Number(NumberDataType::Int8) => {
@@ -126,7 +129,22 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
         )),
         DataType::Timestamp => Scalar::Timestamp(*datavalue.as_int64().unwrap()),
         DataType::Date => Scalar::Date(*datavalue.as_int64().unwrap() as i32),
+        DataType::Uuid => {
+            let value = datavalue.as_string().ok_or_else(|| {
+                ErrorCode::BadDataValueType(format!(
+                    "expect a string value for UUID, but got {datavalue:?}"
+                ))
+            })?;
+            let uuid = string_to_uuid(value).ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "invalid UUID value '{}'",
+                    String::from_utf8_lossy(value)
+                ))
+            })?;
+            Scalar::Uuid(uuid)
+        }
         DataType::String => Scalar::String(datavalue.as_string().unwrap().to_vec()),
+        DataType::Geometry => Scalar::Geometry(datavalue.as_string().unwrap().to_vec()),
         DataType::Variant => match datavalue {
             DataValue::String(x) => Scalar::Variant(x.clone()),
             DataValue::Variant(x) => {
@@ -144,7 +162,7 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
                 .iter()
                 .zip(values.iter())
                 .map(|(ty, v)| from_scalar(v, ty))
-                .collect();
+                .collect::<Result<_>>()?;
 
             Scalar::Tuple(inners)
         }
@@ -157,12 +175,13 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
             let mut builder = ColumnBuilder::with_capacity(ty.as_ref(), values.len());
 
             for value in values.iter() {
-                let scalar = from_scalar(value, ty.as_ref());
+                let scalar = from_scalar(value, ty.as_ref())?;
                 builder.push(scalar.as_ref());
             }
             let col = builder.build();
             Scalar::Array(col)
         }
         _ => unreachable!(),
-    }
+    };
+    Ok(scalar)
 }
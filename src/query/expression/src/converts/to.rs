@@ -15,6 +15,7 @@
 use common_datavalues::DataValue;
 use ordered_float::OrderedFloat;
 
+use crate::types::uuid::uuid_to_string;
 use crate::Scalar;
 
 pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
@@ -39,7 +40,10 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
-        Scalar::String(x) | Scalar::Variant(x) => DataValue::String(x.clone()),
+        Scalar::String(x) | Scalar::Variant(x) | Scalar::Geometry(x) => {
+            DataValue::String(x.clone())
+        }
+        Scalar::Uuid(x) => DataValue::String(uuid_to_string(*x).into_bytes()),
         Scalar::Array(x) => {
             let values = (0..x.len())
                 .map(|idx| scalar_to_datavalue(&x.index(idx).unwrap().to_owned()))
@@ -26,6 +26,7 @@ use tracing::error;
 
 use crate::block::DataBlock;
 use crate::expression::Expr;
+use crate::expression::RemoteExpr;
 use crate::function::EvalContext;
 use crate::property::Domain;
 use crate::type_check::check_function;
@@ -35,6 +36,7 @@ use crate::types::array::ArrayColumn;
 use crate::types::boolean::BooleanDomain;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
+use crate::types::number::NumberScalar;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::NullableType;
@@ -43,6 +45,7 @@ use crate::utils::arrow::constant_bitmap;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
+use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::BlockEntry;
 use crate::ColumnIndex;
@@ -161,6 +164,23 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                return_type,
+                ..
+            } => {
+                let data_types = args
+                    .iter()
+                    .map(|arg| arg.data_type().clone())
+                    .collect::<Vec<_>>();
+                let args = args
+                    .iter()
+                    .map(|expr| self.partial_run(expr, validity.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                self.run_lambda(name, args, &data_types, lambda_expr, return_type)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -840,6 +860,226 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluate a higher-order function. The lambda runs once over the elements of the arrays
+    /// in all the rows, instead of once for each row.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        args: Vec<Value<AnyType>>,
+        data_types: &[DataType],
+        lambda_expr: &RemoteExpr,
+        return_type: &DataType,
+    ) -> Result<Value<AnyType>> {
+        let is_scalar = args.iter().all(|arg| matches!(arg, Value::Scalar(_)));
+        let num_rows = if is_scalar {
+            1
+        } else {
+            self.input_columns.num_rows()
+        };
+        let columns = args
+            .iter()
+            .zip(data_types.iter())
+            .map(|(arg, data_type)| arg.convert_to_full_column(data_type, num_rows))
+            .collect::<Vec<_>>();
+
+        let (array, validity) = match &columns[0] {
+            Column::Nullable(box NullableColumn { column, validity }) => {
+                (column.as_array().unwrap(), Some(validity.clone()))
+            }
+            column => (column.as_array().unwrap(), None),
+        };
+        let element_type = match data_types[0].remove_nullable() {
+            DataType::Array(box element_type) => element_type,
+            _ => unreachable!("the first argument of {func_name} must be an array"),
+        };
+        // Rebase the offsets, a sliced array column shares the values of the whole column.
+        let values = array.underlying_column();
+        let offsets = array
+            .offsets
+            .iter()
+            .map(|offset| offset - array.offsets[0])
+            .collect::<Vec<_>>();
+        let lens = offsets
+            .windows(2)
+            .map(|window| (window[1] - window[0]) as usize)
+            .collect::<Vec<_>>();
+        let outer_columns = columns[1..]
+            .iter()
+            .cloned()
+            .zip(data_types[1..].iter().cloned())
+            .collect::<Vec<_>>();
+        let lambda_expr = lambda_expr.as_expr(self.fn_registry);
+
+        let column = match func_name {
+            "array_transform" => {
+                let rows = element_rows(&lens);
+                let mut entries = vec![(values.clone(), element_type)];
+                entries.extend(take_columns(&outer_columns, &rows));
+                let values = self.run_lambda_expr(&lambda_expr, entries, rows.len())?;
+                Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                }))
+            }
+            "array_filter" => {
+                let rows = element_rows(&lens);
+                let mut entries = vec![(values.clone(), element_type)];
+                entries.extend(take_columns(&outer_columns, &rows));
+                let selection = match self.run_lambda_expr(&lambda_expr, entries, rows.len())? {
+                    Column::Boolean(selection) => selection,
+                    Column::Nullable(box NullableColumn { column, validity }) => {
+                        column.as_boolean().unwrap() & (&validity)
+                    }
+                    column => constant_bitmap(false, column.len()).into(),
+                };
+                let mut new_offsets = Vec::with_capacity(offsets.len());
+                new_offsets.push(0);
+                for window in offsets.windows(2) {
+                    let selected = (window[0]..window[1])
+                        .filter(|idx| selection.get_bit(*idx as usize))
+                        .count() as u64;
+                    new_offsets.push(new_offsets.last().unwrap() + selected);
+                }
+                Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&selection),
+                    offsets: new_offsets.into(),
+                }))
+            }
+            "array_sort" => {
+                // Compare every pair of elements in each array, then sort the arrays with the
+                // results of the comparisons.
+                let mut rows = Vec::new();
+                let mut left = Vec::new();
+                let mut right = Vec::new();
+                for (row, window) in offsets.windows(2).enumerate() {
+                    for i in window[0]..window[1] {
+                        for j in window[0]..window[1] {
+                            rows.push(row as u32);
+                            left.push(i as u32);
+                            right.push(j as u32);
+                        }
+                    }
+                }
+                let mut entries = vec![
+                    (values.take(&left), element_type.clone()),
+                    (values.take(&right), element_type),
+                ];
+                entries.extend(take_columns(&outer_columns, &rows));
+                let comparisons = self.run_lambda_expr(&lambda_expr, entries, rows.len())?;
+
+                let mut indices = Vec::with_capacity(values.len());
+                let mut base = 0;
+                for (window, len) in offsets.windows(2).zip(lens.iter()) {
+                    let mut positions = (0..*len).collect::<Vec<_>>();
+                    positions.sort_by(|i, j| {
+                        match comparisons.index(base + i * len + j).unwrap() {
+                            ScalarRef::Number(NumberScalar::Int64(ordering)) => ordering.cmp(&0),
+                            _ => std::cmp::Ordering::Equal,
+                        }
+                    });
+                    indices.extend(
+                        positions
+                            .into_iter()
+                            .map(|position| (window[0] as usize + position) as u32),
+                    );
+                    base += len * len;
+                }
+                Column::Array(Box::new(ArrayColumn {
+                    values: values.take(&indices),
+                    offsets: offsets.into(),
+                }))
+            }
+            "array_reduce" => {
+                // Fold the k-th elements of all the arrays longer than k into the accumulators
+                // of their rows, one step for each k.
+                let mut results = vec![None; num_rows];
+                let mut active = (0..num_rows as u32)
+                    .filter(|row| lens[*row as usize] > 0)
+                    .collect::<Vec<_>>();
+                let mut acc = values.take(
+                    &active
+                        .iter()
+                        .map(|row| offsets[*row as usize] as u32)
+                        .collect::<Vec<_>>(),
+                );
+                let mut step = 1;
+                loop {
+                    let mut positions = Vec::with_capacity(active.len());
+                    for (position, row) in active.iter().enumerate() {
+                        if lens[*row as usize] == step {
+                            results[*row as usize] = Some(acc.index(position).unwrap().to_owned());
+                        } else {
+                            positions.push(position as u32);
+                        }
+                    }
+                    if positions.is_empty() {
+                        break;
+                    }
+                    active = positions.iter().map(|p| active[*p as usize]).collect();
+                    let elements = active
+                        .iter()
+                        .map(|row| (offsets[*row as usize] as usize + step) as u32)
+                        .collect::<Vec<_>>();
+                    let mut entries = vec![
+                        (acc.take(&positions), element_type.clone()),
+                        (values.take(&elements), element_type.clone()),
+                    ];
+                    entries.extend(take_columns(&outer_columns, &active));
+                    acc = self.run_lambda_expr(&lambda_expr, entries, active.len())?;
+                    step += 1;
+                }
+
+                let validity = validity.as_ref();
+                let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+                for (row, result) in results.iter().enumerate() {
+                    match result {
+                        Some(scalar) if validity.map_or(true, |v| v.get_bit(row)) => {
+                            builder.push(scalar.as_ref())
+                        }
+                        _ => builder.push(ScalarRef::Null),
+                    }
+                }
+                let column = builder.build();
+                return Ok(match is_scalar {
+                    true => Value::Scalar(column.index(0).unwrap().to_owned()),
+                    false => Value::Column(column),
+                });
+            }
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "unknown lambda function {func_name}"
+                )));
+            }
+        };
+
+        let column = match validity {
+            Some(validity) => Column::Nullable(Box::new(NullableColumn { column, validity })),
+            None => column,
+        };
+        Ok(match is_scalar {
+            true => Value::Scalar(column.index(0).unwrap().to_owned()),
+            false => Value::Column(column),
+        })
+    }
+
+    fn run_lambda_expr(
+        &self,
+        lambda_expr: &Expr,
+        entries: Vec<(Column, DataType)>,
+        num_rows: usize,
+    ) -> Result<Column> {
+        let block = DataBlock::new(
+            entries
+                .into_iter()
+                .map(|(column, data_type)| BlockEntry::new(data_type, Value::Column(column)))
+                .collect(),
+            num_rows,
+        );
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+
     /// Evaluate a set-returning-function. Return multiple sets of results
     /// for each input row, along with the number of rows in each set.
     pub fn run_srf(&self, expr: &Expr) -> Result<Vec<(Value<AnyType>, usize)>> {
@@ -878,6 +1118,21 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+/// The row of each element of the arrays with the lengths `lens`.
+fn element_rows(lens: &[usize]) -> Vec<u32> {
+    lens.iter()
+        .enumerate()
+        .flat_map(|(row, len)| std::iter::repeat(row as u32).take(*len))
+        .collect()
+}
+
+fn take_columns(columns: &[(Column, DataType)], rows: &[u32]) -> Vec<(Column, DataType)> {
+    columns
+        .iter()
+        .map(|(column, data_type)| (column.take(rows), data_type.clone()))
+        .collect()
+}
+
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
     input_domains: &'a HashMap<Index, Domain>,
     func_ctx: &'a FunctionContext,
@@ -1214,6 +1469,48 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
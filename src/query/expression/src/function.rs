@@ -64,6 +64,19 @@ pub struct FunctionSignature {
     pub return_type: DataType,
 }
 
+/// The documentation of a function, shown by `system.functions` and `DESCRIBE FUNCTION`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FunctionDoc {
+    /// Function category, e.g. "String", "Conversion".
+    pub category: &'static str,
+    /// Introduce the function in brief.
+    pub description: &'static str,
+    /// The syntax of the function, e.g. "to_uuid(<expr>)".
+    pub syntax: &'static str,
+    /// Example SQL of the function that can be run directly in query.
+    pub example: &'static str,
+}
+
 #[derive(EnumAsInner)]
 #[allow(clippy::type_complexity)]
 pub enum FunctionEval {
@@ -145,6 +158,7 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+    pub docs: HashMap<String, FunctionDoc>,
 }
 
 impl Function {
@@ -340,7 +354,7 @@ impl FunctionRegistry {
         if self.contains(&func_name) {
             Some(
                 self.properties
-                    .get(&func_name.to_lowercase())
+                    .get(&self.resolve_alias(&func_name))
                     .cloned()
                     .unwrap_or_default(),
             )
@@ -349,6 +363,38 @@ impl FunctionRegistry {
         }
     }
 
+    pub fn get_doc(&self, func_name: &str) -> Option<FunctionDoc> {
+        let func_name = self.resolve_alias(func_name);
+        self.docs.get(&func_name).cloned()
+    }
+
+    /// Returns the signatures of the overloads registered with a fixed signature.
+    ///
+    /// The overloads built by factories depend on the types of arguments, so they
+    /// can't be listed, see [`FunctionRegistry::has_factory`].
+    pub fn get_signatures(&self, func_name: &str) -> Vec<FunctionSignature> {
+        let func_name = self.resolve_alias(func_name);
+        let mut funcs = self.funcs.get(&func_name).cloned().unwrap_or_default();
+        funcs.sort_by_key(|(_, id)| *id);
+        funcs
+            .into_iter()
+            .map(|(func, _)| func.signature.clone())
+            .collect()
+    }
+
+    pub fn has_factory(&self, func_name: &str) -> bool {
+        let func_name = self.resolve_alias(func_name);
+        self.factories.contains_key(&func_name)
+    }
+
+    fn resolve_alias(&self, func_name: &str) -> String {
+        let func_name = func_name.to_lowercase();
+        match self.aliases.get(&func_name) {
+            Some(original) => original.clone(),
+            None => func_name,
+        }
+    }
+
     pub fn register_function(&mut self, func: Function) {
         let name = func.signature.name.clone();
         let id = self.next_function_id(&name);
@@ -0,0 +1,102 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+
+/// Builds a bitmap a word at a time, used by the take kernels for boolean
+/// columns and validity bitmaps.
+///
+/// Single bits are collected in a `u64` which is flushed once it is full, and
+/// runs of one value are written as whole words, so `MutableBitmap::push` is
+/// never called per bit.
+pub(crate) struct BitmapBuilder {
+    buffer: Vec<u8>,
+    word: u64,
+    /// Number of bits in `word`.
+    word_len: usize,
+}
+
+impl BitmapBuilder {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity.saturating_add(63) / 64 * 8),
+            word: 0,
+            word_len: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, val: bool) {
+        self.word |= (val as u64) << self.word_len;
+        self.word_len += 1;
+        if self.word_len == 64 {
+            self.flush_word();
+        }
+    }
+
+    /// Push `val` `n` times.
+    #[inline]
+    pub(crate) fn extend_constant(&mut self, val: bool, mut n: usize) {
+        if n == 1 {
+            self.push(val);
+            return;
+        }
+
+        // Fill up the current word first.
+        if self.word_len > 0 {
+            let fill = n.min(64 - self.word_len);
+            if val {
+                // `fill` < 64 since `word_len` > 0.
+                self.word |= ((1u64 << fill) - 1) << self.word_len;
+            }
+            self.word_len += fill;
+            n -= fill;
+            if self.word_len < 64 {
+                return;
+            }
+            self.flush_word();
+        }
+
+        // Then whole words, `word` is empty here.
+        let words = n / 64;
+        if words > 0 {
+            let byte = if val { u8::MAX } else { 0 };
+            self.buffer.resize(self.buffer.len() + words * 8, byte);
+            n -= words * 64;
+        }
+
+        // The remaining bits start a new word.
+        if n > 0 && val {
+            self.word = (1u64 << n) - 1;
+        }
+        self.word_len = n;
+    }
+
+    #[inline]
+    fn flush_word(&mut self) {
+        self.buffer.extend_from_slice(&self.word.to_le_bytes());
+        self.word = 0;
+        self.word_len = 0;
+    }
+
+    pub(crate) fn build(mut self) -> Bitmap {
+        let len = self.buffer.len() * 8 + self.word_len;
+        if self.word_len > 0 {
+            let bytes = (self.word_len + 7) / 8;
+            self.buffer
+                .extend_from_slice(&self.word.to_le_bytes()[..bytes]);
+        }
+        Bitmap::from_u8_vec(self.buffer, len)
+    }
+}
@@ -30,12 +30,14 @@ use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -139,6 +141,10 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
             }
+            Column::Uuid(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<UuidType>(builder, columns)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -168,6 +174,11 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<BitmapType>(builder, columns)
             }
+            Column::Geometry(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<GeometryType>(builder, columns)
+            }
             Column::Nullable(_) => {
                 let mut bitmaps = Vec::with_capacity(columns.len());
                 let mut inners = Vec::with_capacity(columns.len());
@@ -131,6 +131,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Uuid(column) => {
+                let u = Self::filter_primitive_types(column, filter);
+                Column::Uuid(u)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -160,6 +164,10 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Bitmap(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
 
             Column::Nullable(c) => {
                 let column = Self::filter(&c.column, filter);
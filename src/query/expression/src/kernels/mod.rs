@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bitmap_builder;
 mod concat;
 mod filter;
 mod group_by;
@@ -21,6 +22,7 @@ mod sort;
 mod take;
 mod take_chunks;
 mod take_compact;
+mod take_ranges;
 mod topk;
 
 pub use group_by::*;
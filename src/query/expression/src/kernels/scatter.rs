@@ -29,10 +29,12 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -187,6 +189,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Uuid(column) => Self::scatter_scalars::<UuidType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -228,6 +236,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Geometry(column) => Self::scatter_scalars::<GeometryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
             Column::Nullable(c) => {
                 let columns = c.column.scatter(data_type, indices, scatter_size);
                 let validities = Self::scatter_scalars::<BooleanType, _>(
@@ -222,6 +222,16 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_uuid(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Uuid)
+        .into_uuid()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Uuid)
+        .into_uuid()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
 fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = left
         .as_any()
@@ -242,6 +252,8 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
         ArrowType::Extension(name, _, _) => {
             if name == "Variant" {
                 compare_variant(left, right)
+            } else if name == "Uuid" {
+                compare_uuid(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
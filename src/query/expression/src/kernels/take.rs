@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_exception::Result;
 use itertools::Itertools;
 
+use super::bitmap_builder::BitmapBuilder;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
 use crate::types::bitmap::BitmapType;
@@ -25,10 +27,11 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
-use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -69,9 +72,9 @@ impl Column {
     where I: common_arrow::arrow::types::Index {
         let length = indices.len();
         match self {
-            Column::Null { .. } | Column::EmptyArray { .. } | Column::EmptyMap { .. } => {
-                self.slice(0..length)
-            }
+            Column::Null { .. } => Column::Null { len: length },
+            Column::EmptyArray { .. } => Column::EmptyArray { len: length },
+            Column::EmptyMap { .. } => Column::EmptyMap { len: length },
             Column::Number(column) => with_number_mapped_type!(|NUM_TYPE| match column {
                 NumberColumn::NUM_TYPE(values) =>
                     Self::take_arg_types::<NumberType<NUM_TYPE>, _>(values, indices),
@@ -85,7 +88,7 @@ impl Column {
                     Column::Decimal(DecimalColumn::DECIMAL_TYPE(builder.into(), *size))
                 }
             }),
-            Column::Boolean(bm) => Self::take_arg_types::<BooleanType, _>(bm, indices),
+            Column::Boolean(bm) => Column::Boolean(Self::take_boolean_types(bm, indices)),
             Column::String(column) => Self::take_arg_types::<StringType, _>(column, indices),
             Column::Timestamp(column) => {
                 let ts = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
@@ -103,6 +106,7 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Uuid(column) => Self::take_arg_types::<UuidType, _>(column, indices),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -129,13 +133,11 @@ impl Column {
                 Self::take_value_types::<MapType<AnyType, AnyType>, _>(&column, builder, indices)
             }
             Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
+            Column::Geometry(column) => Self::take_arg_types::<GeometryType, _>(column, indices),
             Column::Nullable(c) => {
                 let column = c.column.take(indices);
-                let validity = Self::take_arg_types::<BooleanType, _>(&c.validity, indices);
-                Column::Nullable(Box::new(NullableColumn {
-                    column,
-                    validity: BooleanType::try_downcast_column(&validity).unwrap(),
-                }))
+                let validity = Self::take_boolean_types(&c.validity, indices);
+                Column::Nullable(Box::new(NullableColumn { column, validity }))
             }
             Column::Tuple(fields) => {
                 let fields = fields.iter().map(|c| c.take(indices)).collect();
@@ -156,6 +158,17 @@ impl Column {
         T::upcast_column(col)
     }
 
+    fn take_boolean_types<I>(col: &Bitmap, indices: &[I]) -> Bitmap
+    where I: common_arrow::arrow::types::Index {
+        let mut builder = BitmapBuilder::with_capacity(indices.len());
+        for index in indices {
+            // # Safety
+            // the out-of-bounds `index` for `col` in indices is *[undefined behavior]*
+            builder.push(unsafe { col.get_bit_unchecked(index.to_usize()) });
+        }
+        builder.build()
+    }
+
     fn take_value_types<T: ValueType, I>(
         col: &T::Column,
         mut builder: T::ColumnBuilder,
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::compute::merge_sort::MergeSlice;
 use itertools::Itertools;
 
+use super::bitmap_builder::BitmapBuilder;
 use crate::types::array::ArrayColumnBuilder;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
@@ -27,10 +29,12 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_decimal_type;
@@ -222,8 +226,11 @@ impl Column {
                 }
             }),
             Column::Boolean(_) => {
-                let builder = BooleanType::create_builder(result_size, &[]);
-                Self::take_block_value_types::<BooleanType>(columns, builder, indices)
+                let bitmaps = columns
+                    .iter()
+                    .map(|c| c.as_boolean().unwrap())
+                    .collect_vec();
+                Column::Boolean(Self::take_block_bool_types(&bitmaps, indices, result_size))
             }
             Column::String(_) => {
                 let builder = StringType::create_builder(result_size, &[]);
@@ -237,6 +244,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Uuid(_) => {
+                let builder = UuidType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<UuidType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -265,6 +276,10 @@ impl Column {
                 let builder = BitmapType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BitmapType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -333,6 +348,19 @@ impl Column {
         }
     }
 
+    fn take_block_bool_types(
+        bitmaps: &[&Bitmap],
+        indices: &[BlockRowIndex],
+        result_size: usize,
+    ) -> Bitmap {
+        let mut builder = BitmapBuilder::with_capacity(result_size);
+        for &(block_index, row, times) in indices {
+            let val = unsafe { bitmaps[block_index].get_bit_unchecked(row) };
+            builder.extend_constant(val, times);
+        }
+        builder.build()
+    }
+
     fn take_block_value_types<T: ValueType>(
         columns: &[Column],
         mut builder: T::ColumnBuilder,
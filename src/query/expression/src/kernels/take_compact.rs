@@ -13,10 +13,10 @@
 // limitations under the License.
 
 use common_arrow::arrow::bitmap::Bitmap;
-use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_exception::Result;
 
+use super::bitmap_builder::BitmapBuilder;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
 use crate::types::bitmap::BitmapType;
@@ -24,15 +24,15 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
+use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
-use crate::types::StringType;
 use crate::types::ValueType;
-use crate::types::VariantType;
 use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::BlockEntry;
@@ -67,11 +67,11 @@ impl DataBlock {
 
 impl Column {
     pub fn take_compacted_indices(&self, indices: &[(u32, u32)], row_num: usize) -> Self {
-        let length = indices.len();
         match self {
-            Column::Null { .. } | Column::EmptyArray { .. } | Column::EmptyMap { .. } => {
-                self.slice(0..length)
-            }
+            // Every index is repeated by its count, so `row_num` can exceed the length of `self`.
+            Column::Null { .. } => Column::Null { len: row_num },
+            Column::EmptyArray { .. } => Column::EmptyArray { len: row_num },
+            Column::EmptyMap { .. } => Column::EmptyMap { len: row_num },
             Column::Number(column) => {
                 with_number_mapped_type!(|NUM_TYPE| match column {
                     NumberColumn::NUM_TYPE(values) => {
@@ -96,7 +96,7 @@ impl Column {
                 BooleanType::upcast_column(Self::take_bool_types(bm, indices, row_num))
             }
             Column::String(column) => {
-                Self::take_compact_arg_types::<StringType>(column, indices, row_num)
+                Column::String(Self::take_string_types(column, indices, row_num))
             }
             Column::Timestamp(column) => {
                 let builder = Self::take_primitive_types(column, indices, row_num);
@@ -122,15 +122,19 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Uuid(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::Uuid(builder.into())
+            }
             Column::Array(column) => {
-                let mut offsets = Vec::with_capacity(length + 1);
+                let mut offsets = Vec::with_capacity(row_num + 1);
                 offsets.push(0);
                 let builder = ColumnBuilder::with_capacity(&column.values.data_type(), row_num);
                 let builder = ArrayColumnBuilder { builder, offsets };
                 Self::take_scalar_types::<ArrayType<AnyType>>(column, builder, indices, row_num)
             }
             Column::Map(column) => {
-                let mut offsets = Vec::with_capacity(length + 1);
+                let mut offsets = Vec::with_capacity(row_num + 1);
                 offsets.push(0);
                 let builder = ColumnBuilder::from_column(
                     ColumnBuilder::with_capacity(&column.values.data_type(), row_num).build(),
@@ -152,17 +156,13 @@ impl Column {
             Column::Bitmap(column) => {
                 Self::take_compact_arg_types::<BitmapType>(column, indices, row_num)
             }
+            Column::Geometry(column) => {
+                Self::take_compact_arg_types::<GeometryType>(column, indices, row_num)
+            }
             Column::Nullable(c) => {
                 let column = c.column.take_compacted_indices(indices, row_num);
-                let validity = BooleanType::upcast_column(Self::take_bool_types(
-                    &c.validity,
-                    indices,
-                    row_num,
-                ));
-                Column::Nullable(Box::new(NullableColumn {
-                    column,
-                    validity: BooleanType::try_downcast_column(&validity).unwrap(),
-                }))
+                let validity = Self::take_bool_types(&c.validity, indices, row_num);
+                Column::Nullable(Box::new(NullableColumn { column, validity }))
             }
             Column::Tuple(fields) => {
                 let fields = fields
@@ -172,7 +172,7 @@ impl Column {
                 Column::Tuple(fields)
             }
             Column::Variant(column) => {
-                Self::take_compact_arg_types::<VariantType>(column, indices, row_num)
+                Column::Variant(Self::take_string_types(column, indices, row_num))
             }
         }
     }
@@ -263,6 +263,94 @@ impl Column {
         builder
     }
 
+    pub fn take_string_types(
+        col: &StringColumn,
+        indices: &[(u32, u32)],
+        row_num: usize,
+    ) -> StringColumn {
+        // Each item in the `indices` consists of an `index` and a `cnt`, the sum
+        // of the `cnt` must be equal to the `row_num`.
+        debug_assert_eq!(
+            indices.iter().fold(0, |acc, &(_, x)| acc + x as usize),
+            row_num
+        );
+
+        let col_offsets = col.offsets.as_slice();
+        let col_data_ptr = col.data.as_slice().as_ptr();
+
+        // Compute the total length of the output bytes first,
+        // so that both buffers are allocated exactly once.
+        let data_capacity = indices
+            .iter()
+            .map(|(index, cnt)| {
+                let index = *index as usize;
+                (col_offsets[index + 1] - col_offsets[index]) as usize * *cnt as usize
+            })
+            .sum::<usize>();
+
+        let mut offsets: Vec<u64> = Vec::with_capacity(row_num + 1);
+        let mut data: Vec<u8> = Vec::with_capacity(data_capacity);
+        let data_ptr = data.as_mut_ptr();
+        offsets.push(0);
+
+        let mut data_len = 0;
+        for (index, cnt) in indices {
+            let start = col_offsets[*index as usize] as usize;
+            let len = col_offsets[*index as usize + 1] as usize - start;
+            let base_offset = data_len;
+            offsets.extend((1..=*cnt as usize).map(|i| (base_offset + i * len) as u64));
+            if len == 0 {
+                continue;
+            }
+
+            // # Safety
+            // base_offset + len <= data_capacity
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    col_data_ptr.add(start),
+                    data_ptr.add(base_offset),
+                    len,
+                )
+            };
+
+            // Same doubling method as `take_primitive_types`, but in bytes.
+            let total = len * *cnt as usize;
+            let mut cur_len = len;
+            while cur_len * 2 <= total {
+                // # Safety
+                // base_offset + 2 * cur_len <= data_capacity
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        data_ptr.add(base_offset),
+                        data_ptr.add(base_offset + cur_len),
+                        cur_len,
+                    )
+                };
+                cur_len <<= 1;
+            }
+            if total > cur_len {
+                // # Safety
+                // cur_len > total - cur_len and base_offset + total <= data_capacity
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        data_ptr.add(base_offset),
+                        data_ptr.add(base_offset + cur_len),
+                        total - cur_len,
+                    )
+                };
+            }
+            data_len += total;
+        }
+        // # Safety
+        // `data_len` is equal to `data_capacity`
+        unsafe { data.set_len(data_len) };
+
+        StringColumn {
+            data: data.into(),
+            offsets: offsets.into(),
+        }
+    }
+
     pub fn take_bool_types(col: &Bitmap, indices: &[(u32, u32)], row_num: usize) -> Bitmap {
         // Each item in the `indices` consists of an `index` and a `cnt`, the sum
         // of the `cnt` must be equal to the `row_num`.
@@ -270,16 +358,14 @@ impl Column {
             indices.iter().fold(0, |acc, &(_, x)| acc + x as usize),
             row_num
         );
-        let mut col_builder = MutableBitmap::with_capacity((row_num).saturating_add(7) / 8);
+        let mut builder = BitmapBuilder::with_capacity(row_num);
         for (index, cnt) in indices {
             // # Safety
             // the out-of-bounds `index` for `col` in indices is *[undefined behavior]*
             let val = unsafe { col.get_bit_unchecked(*index as usize) };
-            for _ in 0..*cnt {
-                col_builder.push(val);
-            }
+            builder.extend_constant(val, *cnt as usize);
         }
-        BooleanType::build_column(col_builder)
+        builder.build()
     }
 
     fn take_compact_arg_types<T: ArgType>(
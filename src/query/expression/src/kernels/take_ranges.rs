@@ -0,0 +1,239 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::Result;
+
+use crate::types::array::ArrayColumn;
+use crate::types::array::ArrayColumnBuilder;
+use crate::types::decimal::DecimalColumn;
+use crate::types::map::KvColumnBuilder;
+use crate::types::nullable::NullableColumn;
+use crate::types::number::NumberColumn;
+use crate::types::string::StringColumn;
+use crate::types::AnyType;
+use crate::types::ArrayType;
+use crate::types::MapType;
+use crate::types::NumberType;
+use crate::types::ValueType;
+use crate::with_decimal_type;
+use crate::with_number_mapped_type;
+use crate::BlockEntry;
+use crate::Column;
+use crate::ColumnBuilder;
+use crate::DataBlock;
+use crate::Value;
+
+impl DataBlock {
+    /// Gather the rows in `ranges` into a new block. `num_rows` must be the total length
+    /// of the ranges.
+    pub fn take_ranges(&self, ranges: &[Range<u32>], num_rows: usize) -> Result<Self> {
+        debug_assert_eq!(
+            ranges
+                .iter()
+                .map(|range| (range.end - range.start) as usize)
+                .sum::<usize>(),
+            num_rows
+        );
+
+        let after_columns = self
+            .columns()
+            .iter()
+            .map(|entry| match &entry.value {
+                Value::Scalar(s) => {
+                    BlockEntry::new(entry.data_type.clone(), Value::Scalar(s.clone()))
+                }
+                Value::Column(c) => BlockEntry::new(
+                    entry.data_type.clone(),
+                    Value::Column(Column::take_ranges(c, ranges, num_rows)),
+                ),
+            })
+            .collect();
+
+        Ok(DataBlock::new(after_columns, num_rows))
+    }
+}
+
+impl Column {
+    pub fn take_ranges(&self, ranges: &[Range<u32>], num_rows: usize) -> Self {
+        match self {
+            // Ranges may overlap or repeat rows, so `num_rows` can exceed the length of `self`.
+            Column::Null { .. } => Column::Null { len: num_rows },
+            Column::EmptyArray { .. } => Column::EmptyArray { len: num_rows },
+            Column::EmptyMap { .. } => Column::EmptyMap { len: num_rows },
+            Column::Number(column) => {
+                with_number_mapped_type!(|NUM_TYPE| match column {
+                    NumberColumn::NUM_TYPE(values) => {
+                        let builder = Self::take_ranges_primitive_types(values, ranges, num_rows);
+                        <NumberType<NUM_TYPE>>::upcast_column(
+                            <NumberType<NUM_TYPE>>::column_from_vec(builder, &[]),
+                        )
+                    }
+                })
+            }
+            Column::Decimal(column) => with_decimal_type!(|DECIMAL_TYPE| match column {
+                DecimalColumn::DECIMAL_TYPE(values, size) => {
+                    let builder = Self::take_ranges_primitive_types(values, ranges, num_rows);
+                    Column::Decimal(DecimalColumn::DECIMAL_TYPE(builder.into(), *size))
+                }
+            }),
+            Column::Boolean(bm) => {
+                Column::Boolean(Self::take_ranges_bool_types(bm, ranges, num_rows))
+            }
+            Column::String(column) => {
+                Column::String(Self::take_ranges_string_types(column, ranges, num_rows))
+            }
+            Column::Timestamp(column) => {
+                let builder = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Timestamp(builder.into())
+            }
+            Column::Date(column) => {
+                let builder = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Date(builder.into())
+            }
+            Column::Uuid(column) => {
+                let builder = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Uuid(builder.into())
+            }
+            Column::Array(column) => {
+                let mut offsets = Vec::with_capacity(num_rows + 1);
+                offsets.push(0);
+                let builder = ColumnBuilder::with_capacity(&column.values.data_type(), num_rows);
+                let builder = ArrayColumnBuilder { builder, offsets };
+                Self::take_ranges_scalar_types::<ArrayType<AnyType>>(column, builder, ranges)
+            }
+            Column::Map(column) => {
+                let mut offsets = Vec::with_capacity(num_rows + 1);
+                offsets.push(0);
+                let builder = ColumnBuilder::from_column(
+                    ColumnBuilder::with_capacity(&column.values.data_type(), num_rows).build(),
+                );
+                let (key_builder, val_builder) = match builder {
+                    ColumnBuilder::Tuple(fields) => (fields[0].clone(), fields[1].clone()),
+                    _ => unreachable!(),
+                };
+                let builder = KvColumnBuilder {
+                    keys: key_builder,
+                    values: val_builder,
+                };
+                let builder = ArrayColumnBuilder { builder, offsets };
+                let column = ArrayColumn::try_downcast(column).unwrap();
+                Self::take_ranges_scalar_types::<MapType<AnyType, AnyType>>(
+                    &column, builder, ranges,
+                )
+            }
+            Column::Bitmap(column) => {
+                Column::Bitmap(Self::take_ranges_string_types(column, ranges, num_rows))
+            }
+            Column::Geometry(column) => {
+                Column::Geometry(Self::take_ranges_string_types(column, ranges, num_rows))
+            }
+            Column::Nullable(c) => {
+                let column = c.column.take_ranges(ranges, num_rows);
+                let validity = Self::take_ranges_bool_types(&c.validity, ranges, num_rows);
+                Column::Nullable(Box::new(NullableColumn { column, validity }))
+            }
+            Column::Tuple(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|c| c.take_ranges(ranges, num_rows))
+                    .collect();
+                Column::Tuple(fields)
+            }
+            Column::Variant(column) => {
+                Column::Variant(Self::take_ranges_string_types(column, ranges, num_rows))
+            }
+        }
+    }
+
+    fn take_ranges_primitive_types<T: Copy>(
+        values: &Buffer<T>,
+        ranges: &[Range<u32>],
+        num_rows: usize,
+    ) -> Vec<T> {
+        let mut builder = Vec::with_capacity(num_rows);
+        for range in ranges {
+            builder.extend_from_slice(&values[range.start as usize..range.end as usize]);
+        }
+        builder
+    }
+
+    fn take_ranges_bool_types(bitmap: &Bitmap, ranges: &[Range<u32>], num_rows: usize) -> Bitmap {
+        let mut builder = MutableBitmap::with_capacity(num_rows);
+        let (slice, offset, _) = bitmap.as_slice();
+        for range in ranges {
+            builder.extend_from_slice(
+                slice,
+                offset + range.start as usize,
+                (range.end - range.start) as usize,
+            );
+        }
+        builder.into()
+    }
+
+    fn take_ranges_string_types(
+        column: &StringColumn,
+        ranges: &[Range<u32>],
+        num_rows: usize,
+    ) -> StringColumn {
+        let data_capacity = ranges
+            .iter()
+            .map(|range| {
+                (column.offsets[range.end as usize] - column.offsets[range.start as usize]) as usize
+            })
+            .sum();
+        let mut data: Vec<u8> = Vec::with_capacity(data_capacity);
+        let mut offsets: Vec<u64> = Vec::with_capacity(num_rows + 1);
+        offsets.push(0);
+        for range in ranges {
+            let (start, end) = (range.start as usize, range.end as usize);
+            let data_start = column.offsets[start];
+            let data_end = column.offsets[end];
+            // Rebase the offsets of the range onto the end of the gathered data.
+            let base = data.len() as u64;
+            offsets.extend(
+                column.offsets[start + 1..=end]
+                    .iter()
+                    .map(|offset| offset - data_start + base),
+            );
+            data.extend_from_slice(&column.data[data_start as usize..data_end as usize]);
+        }
+        StringColumn {
+            data: data.into(),
+            offsets: offsets.into(),
+        }
+    }
+
+    fn take_ranges_scalar_types<T: ValueType>(
+        col: &T::Column,
+        mut builder: T::ColumnBuilder,
+        ranges: &[Range<u32>],
+    ) -> Column {
+        for range in ranges {
+            for index in range.start..range.end {
+                T::push_item(
+                    &mut builder,
+                    // # Safety
+                    // the out-of-bounds `index` for `col` in ranges is *[undefined behavior]*
+                    unsafe { T::index_column_unchecked(col, index as usize) },
+                );
+            }
+        }
+        T::upcast_column(T::build_column(builder))
+    }
+}
@@ -50,6 +50,7 @@ impl TopKSorter {
             DataType::String => self.push_column_internal::<StringType>(col, bitmap),
             DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
             DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+            DataType::Uuid => self.push_column_internal::<UuidType>(col, bitmap),
             _ => {}
         });
     }
@@ -122,6 +123,7 @@ impl TopKSorter {
             DataType::String => self.never_match_any_internal::<StringType>(col),
             DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
             DataType::Date => self.never_match_any_internal::<DateType>(col),
+            DataType::Uuid => self.never_match_any_internal::<UuidType>(col),
             _ => false,
         })
     }
@@ -75,6 +75,7 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
 
 impl FixedLengthEncoding for F32 {
     type Encoded = [u8; 4];
@@ -37,6 +37,8 @@ use crate::Scalar;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_UUID;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -134,6 +136,8 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Uuid,
+    Geometry,
 }
 
 impl DataSchema {
@@ -1059,6 +1063,8 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Uuid => DataType::Uuid,
+            TableDataType::Geometry => DataType::Geometry,
         }
     }
 }
@@ -1316,6 +1322,8 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_UUID => TableDataType::Uuid,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1449,6 +1457,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Uuid => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_UUID.to_string(),
+                Box::new(ArrowDataType::FixedSizeBinary(16)),
+                None,
+            ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1533,6 +1551,16 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Uuid => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_UUID.to_string(),
+                Box::new(ArrowDataType::FixedSizeBinary(16)),
+                None,
+            ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1566,6 +1594,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Uuid => Ok(TableDataType::Uuid),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -21,6 +21,7 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -28,6 +29,7 @@ pub mod number;
 pub mod number_class;
 pub mod string;
 pub mod timestamp;
+pub mod uuid;
 pub mod variant;
 
 use std::fmt::Debug;
@@ -48,6 +50,7 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -55,6 +58,7 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
+pub use self::uuid::UuidType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -81,6 +85,8 @@ pub enum DataType {
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Uuid,
+    Geometry,
     Generic(usize),
 }
 
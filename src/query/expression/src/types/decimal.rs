@@ -278,6 +278,10 @@ pub trait Decimal:
 
     fn to_float64(self, scale: u8) -> f64;
 
+    fn to_i256(self) -> i256;
+    /// Returns None if the value does not fit in `Self`.
+    fn from_i256(value: i256) -> Option<Self>;
+
     fn try_downcast_column(column: &Column) -> Option<(Buffer<Self>, DecimalSize)>;
     fn try_downcast_builder<'a>(builder: &'a mut ColumnBuilder) -> Option<&'a mut Vec<Self>>;
 
@@ -402,6 +406,18 @@ impl Decimal for i128 {
         self as f64 / div
     }
 
+    fn to_i256(self) -> i256 {
+        i256::from(self)
+    }
+
+    fn from_i256(value: i256) -> Option<Self> {
+        if value > i256::from(i128::MAX) || value < i256::from(i128::MIN) {
+            None
+        } else {
+            Some(value.as_i128())
+        }
+    }
+
     fn to_scalar(self, size: DecimalSize) -> DecimalScalar {
         DecimalScalar::Decimal128(self, size)
     }
@@ -546,6 +562,14 @@ impl Decimal for i256 {
         self.as_f64() / div
     }
 
+    fn to_i256(self) -> i256 {
+        self
+    }
+
+    fn from_i256(value: i256) -> Option<Self> {
+        Some(value)
+    }
+
     fn to_scalar(self, size: DecimalSize) -> DecimalScalar {
         DecimalScalar::Decimal256(self, size)
     }
@@ -0,0 +1,653 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use geo_types::Coord;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::LineString;
+use geo_types::MultiLineString;
+use geo_types::MultiPoint;
+use geo_types::MultiPolygon;
+use geo_types::Point;
+use geo_types::Polygon;
+
+use crate::property::Domain;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = ();
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            crate::ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.put_slice(GEOMETRY_EMPTY);
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, geometry: &Self::Column) {
+        builder.append_column(geometry)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size<'a>(scalar: &Self::ScalarRef<'a>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data.len() + col.offsets.len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+/// The well-known binary of `GEOMETRYCOLLECTION EMPTY`, used as the default value.
+pub const GEOMETRY_EMPTY: &[u8] = &[1, 7, 0, 0, 0, 0, 0, 0, 0];
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+/// Parse the well-known text into the well-known binary stored by the geometry column.
+pub fn geometry_from_wkt(wkt: impl AsRef<[u8]>) -> Result<Vec<u8>, String> {
+    let text = std::str::from_utf8(wkt.as_ref()).map_err(|e| e.to_string())?;
+    let geometry = WktParser::new(text).parse()?;
+    Ok(geometry_to_wkb(&geometry))
+}
+
+/// Format the well-known binary into the well-known text.
+pub fn geometry_to_wkt(wkb: &[u8]) -> Result<String, String> {
+    let geometry = wkb_to_geometry(wkb)?;
+    let mut buf = String::new();
+    write_wkt(&geometry, &mut buf);
+    Ok(buf)
+}
+
+/// Check the bytes are a valid well-known binary.
+pub fn check_geometry(wkb: &[u8]) -> Result<(), String> {
+    wkb_to_geometry(wkb).map(|_| ())
+}
+
+/// Encode the geometry into well-known binary in little endian.
+pub fn geometry_to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_wkb(geometry, &mut buf);
+    buf
+}
+
+/// Decode the geometry from well-known binary, in either byte order.
+pub fn wkb_to_geometry(wkb: &[u8]) -> Result<Geometry<f64>, String> {
+    let mut reader = WkbReader { buf: wkb, pos: 0 };
+    let geometry = reader.read_geometry()?;
+    if reader.pos != wkb.len() {
+        return Err("invalid WKB: trailing bytes".to_string());
+    }
+    Ok(geometry)
+}
+
+fn write_wkb(geometry: &Geometry<f64>, buf: &mut Vec<u8>) {
+    fn header(buf: &mut Vec<u8>, ty: u32) {
+        buf.push(1);
+        buf.extend_from_slice(&ty.to_le_bytes());
+    }
+    fn coord(buf: &mut Vec<u8>, c: &Coord<f64>) {
+        buf.extend_from_slice(&c.x.to_le_bytes());
+        buf.extend_from_slice(&c.y.to_le_bytes());
+    }
+    fn len(buf: &mut Vec<u8>, len: usize) {
+        buf.extend_from_slice(&(len as u32).to_le_bytes());
+    }
+    fn line(buf: &mut Vec<u8>, line: &LineString<f64>) {
+        len(buf, line.0.len());
+        line.0.iter().for_each(|c| coord(buf, c));
+    }
+    fn polygon(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+        if polygon.exterior().0.is_empty() {
+            len(buf, 0);
+            return;
+        }
+        len(buf, polygon.interiors().len() + 1);
+        line(buf, polygon.exterior());
+        polygon.interiors().iter().for_each(|l| line(buf, l));
+    }
+
+    match geometry {
+        Geometry::Point(p) => {
+            header(buf, WKB_POINT);
+            coord(buf, &p.0);
+        }
+        Geometry::LineString(l) => {
+            header(buf, WKB_LINESTRING);
+            line(buf, l);
+        }
+        Geometry::Polygon(p) => {
+            header(buf, WKB_POLYGON);
+            polygon(buf, p);
+        }
+        Geometry::MultiPoint(points) => {
+            header(buf, WKB_MULTIPOINT);
+            len(buf, points.0.len());
+            for p in points.0.iter() {
+                header(buf, WKB_POINT);
+                coord(buf, &p.0);
+            }
+        }
+        Geometry::MultiLineString(lines) => {
+            header(buf, WKB_MULTILINESTRING);
+            len(buf, lines.0.len());
+            for l in lines.0.iter() {
+                header(buf, WKB_LINESTRING);
+                line(buf, l);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            header(buf, WKB_MULTIPOLYGON);
+            len(buf, polygons.0.len());
+            for p in polygons.0.iter() {
+                header(buf, WKB_POLYGON);
+                polygon(buf, p);
+            }
+        }
+        Geometry::GeometryCollection(geometries) => {
+            header(buf, WKB_GEOMETRYCOLLECTION);
+            len(buf, geometries.0.len());
+            geometries.0.iter().for_each(|g| write_wkb(g, buf));
+        }
+        other => write_wkb(&normalize(other), buf),
+    }
+}
+
+/// `Line`, `Rect` and `Triangle` have no WKB form, store them as linestring or polygon.
+fn normalize(geometry: &Geometry<f64>) -> Geometry<f64> {
+    match geometry {
+        Geometry::Line(l) => Geometry::LineString(LineString::new(vec![l.start, l.end])),
+        Geometry::Rect(r) => Geometry::Polygon(r.to_polygon()),
+        Geometry::Triangle(t) => Geometry::Polygon(t.to_polygon()),
+        other => other.clone(),
+    }
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| "invalid WKB: unexpected end of bytes".to_string())?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, le: bool) -> Result<u32, String> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, le: bool) -> Result<f64, String> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if le {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_coord(&mut self, le: bool) -> Result<Coord<f64>, String> {
+        let x = self.read_f64(le)?;
+        let y = self.read_f64(le)?;
+        Ok(Coord { x, y })
+    }
+
+    fn read_len(&mut self, le: bool) -> Result<usize, String> {
+        let len = self.read_u32(le)? as usize;
+        // Every element takes at least 4 bytes, reject the length can't be filled.
+        if len > (self.buf.len() - self.pos) / 4 {
+            return Err("invalid WKB: length out of range".to_string());
+        }
+        Ok(len)
+    }
+
+    fn read_line(&mut self, le: bool) -> Result<LineString<f64>, String> {
+        let len = self.read_len(le)?;
+        let coords = (0..len)
+            .map(|_| self.read_coord(le))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(LineString::new(coords))
+    }
+
+    fn read_polygon(&mut self, le: bool) -> Result<Polygon<f64>, String> {
+        let len = self.read_len(le)?;
+        let mut rings = (0..len)
+            .map(|_| self.read_line(le))
+            .collect::<Result<Vec<_>, _>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn read_typed<T>(
+        &mut self,
+        expect: u32,
+        f: impl Fn(Geometry<f64>) -> Option<T>,
+    ) -> Result<T, String> {
+        let geometry = self.read_geometry()?;
+        let ty = wkb_type(&geometry);
+        f(geometry).ok_or_else(|| format!("invalid WKB: expect type {expect}, but got {ty}"))
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>, String> {
+        let le = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            v => return Err(format!("invalid WKB: unknown byte order {v}")),
+        };
+        let geometry = match self.read_u32(le)? {
+            WKB_POINT => Geometry::Point(Point(self.read_coord(le)?)),
+            WKB_LINESTRING => Geometry::LineString(self.read_line(le)?),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon(le)?),
+            WKB_MULTIPOINT => {
+                let len = self.read_len(le)?;
+                let points = (0..len)
+                    .map(|_| self.read_typed(WKB_POINT, |g| Point::try_from(g).ok()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTILINESTRING => {
+                let len = self.read_len(le)?;
+                let lines = (0..len)
+                    .map(|_| self.read_typed(WKB_LINESTRING, |g| LineString::try_from(g).ok()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTIPOLYGON => {
+                let len = self.read_len(le)?;
+                let polygons = (0..len)
+                    .map(|_| self.read_typed(WKB_POLYGON, |g| Polygon::try_from(g).ok()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => {
+                let len = self.read_len(le)?;
+                let geometries = (0..len)
+                    .map(|_| self.read_geometry())
+                    .collect::<Result<Vec<_>, _>>()?;
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            ty => return Err(format!("invalid WKB: unsupported geometry type {ty}")),
+        };
+        Ok(geometry)
+    }
+}
+
+fn wkb_type(geometry: &Geometry<f64>) -> u32 {
+    match geometry {
+        Geometry::Point(_) => WKB_POINT,
+        Geometry::LineString(_) | Geometry::Line(_) => WKB_LINESTRING,
+        Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => WKB_POLYGON,
+        Geometry::MultiPoint(_) => WKB_MULTIPOINT,
+        Geometry::MultiLineString(_) => WKB_MULTILINESTRING,
+        Geometry::MultiPolygon(_) => WKB_MULTIPOLYGON,
+        Geometry::GeometryCollection(_) => WKB_GEOMETRYCOLLECTION,
+    }
+}
+
+fn write_wkt(geometry: &Geometry<f64>, buf: &mut String) {
+    fn coord(buf: &mut String, c: &Coord<f64>) {
+        write!(buf, "{} {}", c.x, c.y).unwrap();
+    }
+    fn list<T>(buf: &mut String, items: &[T], f: impl Fn(&mut String, &T)) {
+        if items.is_empty() {
+            buf.push_str(" EMPTY");
+            return;
+        }
+        buf.push('(');
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            f(buf, item);
+        }
+        buf.push(')');
+    }
+    fn line(buf: &mut String, line: &LineString<f64>) {
+        list(buf, &line.0, coord);
+    }
+    fn polygon(buf: &mut String, polygon: &Polygon<f64>) {
+        if polygon.exterior().0.is_empty() {
+            buf.push_str(" EMPTY");
+            return;
+        }
+        buf.push('(');
+        line(buf, polygon.exterior());
+        for ring in polygon.interiors() {
+            buf.push(',');
+            line(buf, ring);
+        }
+        buf.push(')');
+    }
+
+    match geometry {
+        Geometry::Point(p) => {
+            buf.push_str("POINT(");
+            coord(buf, &p.0);
+            buf.push(')');
+        }
+        Geometry::LineString(l) => {
+            buf.push_str("LINESTRING");
+            line(buf, l);
+        }
+        Geometry::Polygon(p) => {
+            buf.push_str("POLYGON");
+            polygon(buf, p);
+        }
+        Geometry::MultiPoint(points) => {
+            buf.push_str("MULTIPOINT");
+            list(buf, &points.0, |buf, p| coord(buf, &p.0));
+        }
+        Geometry::MultiLineString(lines) => {
+            buf.push_str("MULTILINESTRING");
+            list(buf, &lines.0, line);
+        }
+        Geometry::MultiPolygon(polygons) => {
+            buf.push_str("MULTIPOLYGON");
+            list(buf, &polygons.0, polygon);
+        }
+        Geometry::GeometryCollection(geometries) => {
+            buf.push_str("GEOMETRYCOLLECTION");
+            list(buf, &geometries.0, |buf, g| write_wkt(g, buf));
+        }
+        other => write_wkt(&normalize(other), buf),
+    }
+}
+
+struct WktParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> WktParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn parse(mut self) -> Result<Geometry<f64>, String> {
+        let geometry = self.parse_geometry()?;
+        self.skip_whitespace();
+        if self.pos != self.text.len() {
+            return Err(format!(
+                "invalid WKT: unexpected `{}`",
+                &self.text[self.pos..]
+            ));
+        }
+        Ok(geometry)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.text[self.pos..].chars().next()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!(
+                "invalid WKT: expect `{c}` at position {}",
+                self.pos
+            ))
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len].to_ascii_uppercase()
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let value = rest[..len]
+            .parse::<f64>()
+            .map_err(|_| format!("invalid WKT: expect number at position {}", self.pos))?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    /// Returns true if the next word is `EMPTY`.
+    fn empty(&mut self) -> bool {
+        let pos = self.pos;
+        if self.word() == "EMPTY" {
+            true
+        } else {
+            self.pos = pos;
+            false
+        }
+    }
+
+    fn list<T>(&mut self, f: impl Fn(&mut Self) -> Result<T, String>) -> Result<Vec<T>, String> {
+        if self.empty() {
+            return Ok(vec![]);
+        }
+        self.expect('(')?;
+        let mut items = vec![f(self)?];
+        while self.peek() == Some(',') {
+            self.pos += 1;
+            items.push(f(self)?);
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    fn coord(&mut self) -> Result<Coord<f64>, String> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(Coord { x, y })
+    }
+
+    fn line(&mut self) -> Result<LineString<f64>, String> {
+        Ok(LineString::new(self.list(Self::coord)?))
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>, String> {
+        let mut rings = self.list(Self::line)?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    /// The points of a multipoint may or may not be wrapped by parentheses.
+    fn multi_point_item(&mut self) -> Result<Point<f64>, String> {
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let coord = self.coord()?;
+            self.expect(')')?;
+            Ok(Point(coord))
+        } else {
+            Ok(Point(self.coord()?))
+        }
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry<f64>, String> {
+        let geometry = match self.word().as_str() {
+            "POINT" => {
+                self.expect('(')?;
+                let coord = self.coord()?;
+                self.expect(')')?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" => Geometry::LineString(self.line()?),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => Geometry::MultiPoint(MultiPoint(self.list(Self::multi_point_item)?)),
+            "MULTILINESTRING" => Geometry::MultiLineString(MultiLineString(self.list(Self::line)?)),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon(self.list(Self::polygon)?)),
+            "GEOMETRYCOLLECTION" => {
+                Geometry::GeometryCollection(GeometryCollection(self.list(Self::parse_geometry)?))
+            }
+            "" => return Err("invalid WKT: expect geometry type".to_string()),
+            other => return Err(format!("invalid WKT: unsupported geometry type {other}")),
+        };
+        Ok(geometry)
+    }
+}
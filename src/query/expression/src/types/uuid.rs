@@ -0,0 +1,205 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use uuid::Uuid;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// A UUID is stored as the `u128` read from its 16 bytes in big-endian order,
+/// so that comparing the integers gives the same order as comparing the bytes
+/// (and the canonical text form). Time-ordered UUIDs such as v7 therefore keep
+/// their ordering, which makes min-max pruning effective on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UuidType;
+
+impl ValueType for UuidType {
+    type Scalar = u128;
+    type ScalarRef<'a> = u128;
+    type Column = Buffer<u128>;
+    type Domain = SimpleDomain<u128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, u128>>;
+    type ColumnBuilder = Vec<u128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: u128) -> u128 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Uuid(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Uuid(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<u128>> {
+        domain.as_uuid().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Uuid(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Uuid(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Uuid(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<u128>) -> Domain {
+        Domain::Uuid(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for UuidType {
+    fn data_type() -> DataType {
+        DataType::Uuid
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: u128::MIN,
+            max: u128::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+#[inline]
+pub fn string_to_uuid(uuid_str: impl AsRef<[u8]>) -> Option<u128> {
+    let uuid_str = std::str::from_utf8(uuid_str.as_ref()).ok()?;
+    Uuid::try_parse(uuid_str.trim()).ok().map(|uuid| uuid.as_u128())
+}
+
+#[inline]
+pub fn bytes_to_uuid(bytes: impl AsRef<[u8]>) -> Option<u128> {
+    Uuid::from_slice(bytes.as_ref())
+        .ok()
+        .map(|uuid| uuid.as_u128())
+}
+
+#[inline]
+pub fn uuid_to_string(uuid: u128) -> String {
+    Uuid::from_u128(uuid).hyphenated().to_string()
+}
+
+#[inline]
+pub fn uuid_to_bytes(uuid: u128) -> [u8; 16] {
+    uuid.to_be_bytes()
+}
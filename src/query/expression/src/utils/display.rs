@@ -40,6 +40,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::geometry_to_wkt;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -50,6 +51,7 @@ use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::uuid::uuid_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::ValueType;
@@ -157,6 +159,11 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Uuid(u) => write!(f, "{}", uuid_to_string(*u)),
+            ScalarRef::Geometry(g) => match geometry_to_wkt(g) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "0x{}", &hex::encode(g)),
+            },
         }
     }
 }
@@ -179,6 +186,8 @@ impl Debug for Column {
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Uuid(col) => f.debug_tuple("Uuid").field(col).finish(),
+            Column::Geometry(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -239,6 +248,11 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Uuid(u) => write!(f, "'{}'", uuid_to_string(*u)),
+            ScalarRef::Geometry(g) => match geometry_to_wkt(g) {
+                Ok(wkt) => write!(f, "'{wkt}'"),
+                Err(_) => write!(f, "0x{}", &hex::encode(g)),
+            },
         }
     }
 }
@@ -430,6 +444,14 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}({}, {lambda_display})", args[0])
+            }
         }
     }
 }
@@ -469,6 +491,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Uuid => write!(f, "Uuid"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -515,6 +539,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Uuid => write!(f, "Uuid"),
+            TableDataType::Geometry => write!(f, "Geometry"),
         }
     }
 }
@@ -614,6 +640,14 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}({}, {lambda_display})", args[0])
+            }
         }
     }
 }
@@ -746,6 +780,12 @@ impl<Index: ColumnIndex> Expr<Index> {
                         s
                     }
                 },
+                Expr::LambdaFunctionCall {
+                    name,
+                    args,
+                    lambda_display,
+                    ..
+                } => format!("{name}({}, {lambda_display})", args[0].sql_display()),
             }
         }
 
@@ -902,6 +942,9 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Uuid(SimpleDomain { min, max }) => {
+                write!(f, "{{{}..={}}}", uuid_to_string(*min), uuid_to_string(*max))
+            }
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
@@ -55,6 +55,8 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::geometry_to_wkb;
+use crate::types::geometry::GEOMETRY_EMPTY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -112,6 +114,8 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Uuid(u128),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -131,6 +135,8 @@ pub enum ScalarRef<'a> {
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Uuid(u128),
+    Geometry(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -150,6 +156,8 @@ pub enum Column {
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Uuid(Buffer<u128>),
+    Geometry(StringColumn),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -169,6 +177,8 @@ pub enum ColumnBuilder {
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Uuid(Vec<u128>),
+    Geometry(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -313,6 +323,8 @@ impl Scalar {
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Uuid(u) => ScalarRef::Uuid(*u),
+            Scalar::Geometry(g) => ScalarRef::Geometry(g.as_slice()),
         }
     }
 
@@ -352,6 +364,8 @@ impl Scalar {
             DataType::Bitmap => Scalar::Bitmap(vec![]),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
+            DataType::Uuid => Scalar::Uuid(0),
+            DataType::Geometry => Scalar::Geometry(GEOMETRY_EMPTY.to_vec()),
 
             _ => unimplemented!(),
         }
@@ -387,6 +401,8 @@ impl<'a> ScalarRef<'a> {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Uuid(u) => Scalar::Uuid(*u),
+            ScalarRef::Geometry(g) => Scalar::Geometry(g.to_vec()),
         }
     }
 
@@ -424,6 +440,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Uuid(u) => Domain::Uuid(SimpleDomain { min: *u, max: *u }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -455,7 +472,9 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) | ScalarRef::Geometry(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -483,6 +502,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Bitmap(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Uuid(_) => 16,
+            ScalarRef::Geometry(g) => g.len(),
         }
     }
 
@@ -516,6 +537,8 @@ impl<'a> ScalarRef<'a> {
                 DataType::Tuple(inner)
             }
             ScalarRef::Variant(_) => DataType::Variant,
+            ScalarRef::Uuid(_) => DataType::Uuid,
+            ScalarRef::Geometry(_) => DataType::Geometry,
         }
     }
 }
@@ -543,6 +566,8 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Uuid(u1), Scalar::Uuid(u2)) => u1.partial_cmp(u2),
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -581,6 +606,8 @@ impl PartialOrd for ScalarRef<'_> {
             }
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Uuid(u1), ScalarRef::Uuid(u2)) => u1.partial_cmp(u2),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -629,6 +656,8 @@ impl Hash for ScalarRef<'_> {
                 v.hash(state);
             }
             ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Uuid(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
         }
     }
 }
@@ -673,6 +702,10 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Uuid(col1), Column::Uuid(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             _ => None,
         }
     }
@@ -689,6 +722,8 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_UUID: &str = "Uuid";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -708,6 +743,8 @@ impl Column {
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
+            Column::Uuid(col) => col.len(),
+            Column::Geometry(col) => col.len(),
         }
     }
 
@@ -733,6 +770,8 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Uuid(col) => Some(ScalarRef::Uuid(col.get(index).cloned()?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
         }
     }
 
@@ -761,6 +800,8 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Uuid(col) => ScalarRef::Uuid(*col.get_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
         }
     }
 
@@ -810,6 +851,10 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Uuid(col) => {
+                Column::Uuid(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
         }
     }
 
@@ -890,7 +935,14 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Bitmap(_) | Column::Variant(_) | Column::Geometry(_) => Domain::Undefined,
+            Column::Uuid(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Uuid(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
         }
     }
 
@@ -928,6 +980,8 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Uuid(_) => DataType::Uuid,
+            Column::Geometry(_) => DataType::Geometry,
         }
     }
 
@@ -1128,7 +1182,7 @@ impl Column {
                     .unwrap(),
                 )
             }
-            Column::Bitmap(col) => {
+            Column::Bitmap(col) | Column::Geometry(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1166,6 +1220,21 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::Uuid(col) => {
+                // Big-endian, the byte order of the canonical text form.
+                let values = col
+                    .iter()
+                    .flat_map(|uuid| uuid.to_be_bytes())
+                    .collect::<Vec<u8>>();
+                Box::new(
+                    common_arrow::arrow::array::FixedSizeBinaryArray::try_new(
+                        arrow_type,
+                        values.into(),
+                        None,
+                    )
+                    .unwrap(),
+                )
+            }
         }
     }
 
@@ -1556,6 +1625,31 @@ impl Column {
                     offsets,
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Geometry(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_UUID => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::FixedSizeBinaryArray>()
+                    .expect("fail to read from arrow: array should be `FixedSizeBinaryArray`");
+                let values = arrow_col
+                    .values()
+                    .chunks_exact(16)
+                    .map(|bytes| u128::from_be_bytes(bytes.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                Column::Uuid(values.into())
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
@@ -1715,6 +1809,18 @@ impl Column {
                 }
                 VariantType::from_data(data)
             }
+            DataType::Geometry => GeometryType::from_data((0..len).map(|_| {
+                let mut rng = SmallRng::from_entropy();
+                geometry_to_wkb(&geo_types::Geometry::Point(geo_types::Point::new(
+                    rng.gen_range(-180.0..180.0),
+                    rng.gen_range(-90.0..90.0),
+                )))
+            })),
+            DataType::Uuid => UuidType::from_data(
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen::<u128>())
+                    .collect::<Vec<u128>>(),
+            ),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1780,6 +1886,8 @@ impl Column {
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Uuid(col) => col.len() * 16,
+            Column::Geometry(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -1866,6 +1974,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Uuid(col) => ColumnBuilder::Uuid(buffer_into_mut(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -1934,6 +2044,8 @@ impl ColumnBuilder {
                 )
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Uuid(u) => ColumnBuilder::Uuid(vec![*u; n]),
+            ScalarRef::Geometry(g) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(g, n)),
         }
     }
 
@@ -1954,6 +2066,8 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Uuid(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
         }
     }
 
@@ -1988,6 +2102,8 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Uuid(col) => col.len() * 16,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -2021,6 +2137,8 @@ impl ColumnBuilder {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
+            ColumnBuilder::Uuid(_) => DataType::Uuid,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
         }
     }
 
@@ -2091,6 +2209,11 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Uuid => ColumnBuilder::Uuid(Vec::with_capacity(capacity)),
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -2139,6 +2262,11 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => builder.push(value),
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -2167,6 +2295,11 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Uuid(builder) => builder.push(0),
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(GEOMETRY_EMPTY);
+                builder.commit_row();
+            }
         }
     }
 
@@ -2193,7 +2326,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
                 builder.data.resize(offset as usize + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2209,6 +2343,10 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Uuid(builder) => {
+                let value: u128 = reader.read_scalar()?;
+                builder.push(value);
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_uvarint()?;
                 for _ in 0..len {
@@ -2279,7 +2417,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2301,6 +2440,13 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Uuid(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: u128 = reader.read_scalar()?;
+                    builder.push(value);
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2390,6 +2536,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
+            ColumnBuilder::Uuid(builder) => builder.pop().map(Scalar::Uuid),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
         }
     }
 
@@ -2425,6 +2573,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Uuid(builder), Column::Uuid(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2434,6 +2585,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Nullable(builder), Column::Nullable(other)) => {
                 builder.append_column(other);
             }
@@ -2471,6 +2625,8 @@ impl ColumnBuilder {
                 Column::Tuple(fields.into_iter().map(|field| field.build()).collect())
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Uuid(builder) => Column::Uuid(builder.into()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
         }
     }
 
@@ -2497,6 +2653,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Uuid(builder) => Scalar::Uuid(builder[0]),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
         }
     }
 }
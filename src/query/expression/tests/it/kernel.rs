@@ -198,3 +198,164 @@ pub fn test_pass() {
         3,
     );
 }
+
+#[test]
+pub fn test_take_ranges() {
+    let num_rows = 100;
+    let data_types = vec![
+        DataType::Number(NumberDataType::Int32),
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt8))),
+        DataType::Boolean,
+        DataType::String,
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Date,
+        DataType::Timestamp,
+        DataType::Array(Box::new(DataType::Number(NumberDataType::Int64))),
+        DataType::Tuple(vec![DataType::String, DataType::Boolean]),
+        DataType::Variant,
+        DataType::Null,
+    ];
+    let columns = data_types
+        .iter()
+        .map(|ty| Column::random(ty, num_rows))
+        .collect::<Vec<_>>();
+    let block = new_block(&columns);
+
+    let ranges = vec![0..3, 10..10, 50..99, 5..9, 99..100, 3..4];
+    let indices = ranges
+        .iter()
+        .flat_map(|range| range.clone())
+        .collect::<Vec<u32>>();
+
+    let expected = block.take(&indices).unwrap();
+    let result = block.take_ranges(&ranges, indices.len()).unwrap();
+    assert_eq!(result.num_rows(), indices.len());
+    for (expected, result) in expected.columns().iter().zip(result.columns()) {
+        assert_eq!(
+            expected.value.as_column().unwrap(),
+            result.value.as_column().unwrap()
+        );
+    }
+
+    // Overlapping and repeated ranges can produce more rows than the input.
+    let ranges = vec![0..60, 40..100, 90..100, 90..100];
+    let indices = ranges
+        .iter()
+        .flat_map(|range| range.clone())
+        .collect::<Vec<u32>>();
+    assert!(indices.len() > num_rows);
+
+    let expected = block.take(&indices).unwrap();
+    let result = block.take_ranges(&ranges, indices.len()).unwrap();
+    assert_eq!(result.num_rows(), indices.len());
+    for (expected, result) in expected.columns().iter().zip(result.columns()) {
+        assert_eq!(
+            expected.value.as_column().unwrap(),
+            result.value.as_column().unwrap()
+        );
+    }
+
+    let result = block.take_ranges(&[], 0).unwrap();
+    assert_eq!(result.num_rows(), 0);
+}
+
+#[test]
+pub fn test_take_compacted_bitmaps() {
+    let num_rows = 100;
+    let columns = [
+        DataType::Boolean,
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32))),
+        DataType::Nullable(Box::new(DataType::Boolean)),
+    ]
+    .iter()
+    .map(|ty| Column::random(ty, num_rows))
+    .collect::<Vec<_>>();
+
+    // The repeat counts cross the 64 bits word boundaries in different ways.
+    let indices = vec![
+        (3, 1),
+        (7, 63),
+        (10, 64),
+        (99, 1),
+        (0, 130),
+        (42, 7),
+        (51, 200),
+        (8, 1),
+    ];
+    let row_num = indices.iter().map(|(_, cnt)| *cnt as usize).sum::<usize>();
+    let expanded = indices
+        .iter()
+        .flat_map(|(index, cnt)| std::iter::repeat(*index).take(*cnt as usize))
+        .collect::<Vec<u32>>();
+
+    for column in columns {
+        let compacted = column.take_compacted_indices(&indices, row_num);
+        let taken = column.take(&expanded);
+        assert_eq!(compacted.len(), row_num);
+        assert_eq!(taken.len(), row_num);
+        for (row, index) in expanded.iter().enumerate() {
+            let expected = column.index(*index as usize).unwrap();
+            assert_eq!(compacted.index(row).unwrap(), expected);
+            assert_eq!(taken.index(row).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+pub fn test_take_compacted_strings() {
+    let num_rows = 100;
+    let columns = [
+        DataType::String,
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Variant,
+    ]
+    .iter()
+    .map(|ty| Column::random(ty, num_rows))
+    .collect::<Vec<_>>();
+    let empty = StringType::from_data(vec!["", "a", ""]);
+
+    let indices = vec![(3, 1), (7, 2), (0, 5), (99, 1), (42, 33), (8, 64)];
+    let row_num = indices.iter().map(|(_, cnt)| *cnt as usize).sum::<usize>();
+    let expanded = indices
+        .iter()
+        .flat_map(|(index, cnt)| std::iter::repeat(*index).take(*cnt as usize))
+        .collect::<Vec<u32>>();
+
+    for column in columns {
+        let compacted = column.take_compacted_indices(&indices, row_num);
+        assert_eq!(compacted, column.take(&expanded));
+    }
+
+    let indices = vec![(0, 3), (1, 4), (2, 1)];
+    let compacted = empty.take_compacted_indices(&indices, 8);
+    assert_eq!(
+        compacted,
+        StringType::from_data(vec!["", "", "", "a", "a", "a", "a", ""])
+    );
+}
+
+#[test]
+pub fn test_take_null_and_empty_containers() {
+    let columns = [
+        Column::Null { len: 3 },
+        Column::EmptyArray { len: 3 },
+        Column::EmptyMap { len: 3 },
+    ];
+
+    // All the kernels can produce more rows than the input.
+    let indices: Vec<u32> = vec![0, 1, 2, 2, 1, 0, 0, 1, 2, 2];
+    let compacted_indices = vec![(0, 1), (1, 1), (2, 2), (1, 1), (0, 2), (1, 1), (2, 2)];
+    let ranges = vec![0..3, 1..3, 0..3, 1..3];
+
+    for column in columns {
+        let taken = column.take(&indices);
+        assert_eq!(taken.len(), 10);
+        assert_eq!(taken.data_type(), column.data_type());
+
+        let compacted = column.take_compacted_indices(&compacted_indices, 10);
+        assert_eq!(compacted, taken);
+
+        let taken_ranges = column.take_ranges(&ranges, 10);
+        assert_eq!(taken_ranges, taken);
+    }
+}
@@ -23,12 +23,12 @@ use common_expression::types::AnyType;
 use common_expression::ColumnBuilder;
 use common_io::constants::FALSE_BYTES_LOWER;
 use common_io::constants::INF_BYTES_LOWER;
-use common_io::constants::NULL_BYTES_ESCAPE;
 use common_io::constants::TRUE_BYTES_LOWER;
 use common_io::cursor_ext::ReadBytesExt;
 use common_meta_app::principal::CsvFileFormatParams;
 
 use crate::field_decoder::row_based::FieldDecoderRowBased;
+use crate::field_decoder::text_load::TextLoadOptions;
 use crate::field_decoder::values::FieldDecoderValues;
 use crate::CommonSettings;
 use crate::FieldDecoder;
@@ -38,6 +38,7 @@ use crate::FileFormatOptionsExt;
 pub struct FieldDecoderCSV {
     pub nested: FieldDecoderValues,
     pub common_settings: CommonSettings,
+    pub load_options: TextLoadOptions,
 }
 
 impl FieldDecoderCSV {
@@ -47,12 +48,18 @@ impl FieldDecoderCSV {
             common_settings: CommonSettings {
                 true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
                 false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
-                null_bytes: NULL_BYTES_ESCAPE.as_bytes().to_vec(),
+                null_bytes: params.null_display.as_bytes().to_vec(),
                 nan_bytes: params.nan_display.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
                 disable_variant_check: options_ext.disable_variant_check,
             },
+            load_options: TextLoadOptions::create(
+                &params.null_if,
+                &params.empty_field_as,
+                params.trim_space,
+                params.binary_as_hex,
+            ),
         }
     }
 }
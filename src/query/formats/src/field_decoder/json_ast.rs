@@ -26,10 +26,12 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::geometry_from_wkt;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::uuid::string_to_uuid;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
 use common_expression::with_decimal_type;
@@ -89,6 +91,8 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             _ => unimplemented!(),
         }
     }
@@ -210,6 +214,30 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_uuid(&self, column: &mut Vec<u128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let uuid =
+                    string_to_uuid(v).ok_or_else(|| ErrorCode::BadBytes("Incorrect uuid value"))?;
+                column.push(uuid);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let wkb = geometry_from_wkt(v).map_err(ErrorCode::BadBytes)?;
+                column.put_slice(&wkb);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -16,6 +16,7 @@ mod csv;
 mod fast_values;
 mod json_ast;
 mod row_based;
+mod text_load;
 mod tsv;
 mod values;
 mod xml;
@@ -26,6 +27,8 @@ pub use csv::FieldDecoderCSV;
 pub use fast_values::FastFieldDecoderValues;
 pub use json_ast::FieldJsonAstDecoder;
 pub use row_based::FieldDecoderRowBased;
+pub use text_load::EmptyFieldAs;
+pub use text_load::TextLoadOptions;
 pub use tsv::FieldDecoderTSV;
 pub use values::FieldDecoderValues;
 pub use xml::FieldDecoderXML;
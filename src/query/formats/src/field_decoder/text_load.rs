@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bstr::ByteSlice;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use common_meta_app::principal::EMPTY_FIELD_AS_NULL;
+use common_meta_app::principal::EMPTY_FIELD_AS_STRING;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmptyFieldAs {
+    Null,
+    String,
+    FieldDefault,
+}
+
+/// The options shared by the text formats (CSV and TSV) when loading a field.
+#[derive(Clone, Debug)]
+pub struct TextLoadOptions {
+    pub null_if: Vec<Vec<u8>>,
+    pub empty_field_as: EmptyFieldAs,
+    pub trim_space: bool,
+    pub binary_as_hex: bool,
+}
+
+impl TextLoadOptions {
+    pub fn create(
+        null_if: &[String],
+        empty_field_as: &str,
+        trim_space: bool,
+        binary_as_hex: bool,
+    ) -> Self {
+        let empty_field_as = match empty_field_as {
+            EMPTY_FIELD_AS_NULL => EmptyFieldAs::Null,
+            EMPTY_FIELD_AS_STRING => EmptyFieldAs::String,
+            _ => EmptyFieldAs::FieldDefault,
+        };
+        TextLoadOptions {
+            null_if: null_if.iter().map(|v| v.as_bytes().to_vec()).collect(),
+            empty_field_as,
+            trim_space,
+            binary_as_hex,
+        }
+    }
+
+    pub fn trim<'a>(&self, field: &'a [u8]) -> &'a [u8] {
+        if self.trim_space { field.trim() } else { field }
+    }
+
+    /// Load the fields which are not decoded by the field decoder:
+    /// empty fields, fields in `null_if`, and hex encoded strings if `binary_as_hex`.
+    ///
+    /// Returns false if the field should be decoded by the field decoder.
+    pub fn try_load_field(&self, column: &mut ColumnBuilder, field: &[u8]) -> Result<bool> {
+        if field.is_empty() {
+            match self.empty_field_as {
+                EmptyFieldAs::Null => push_null(column)?,
+                EmptyFieldAs::String if is_string(column) => column.push(ScalarRef::String(b"")),
+                _ => column.push_default(),
+            }
+            return Ok(true);
+        }
+
+        if self.null_if.iter().any(|v| v == field) {
+            push_null(column)?;
+            return Ok(true);
+        }
+
+        if self.binary_as_hex && is_string(column) {
+            let data = hex::decode(field)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid hex string: {e}")))?;
+            column.push(ScalarRef::String(&data));
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+}
+
+fn push_null(column: &mut ColumnBuilder) -> Result<()> {
+    match column {
+        ColumnBuilder::Null { .. } | ColumnBuilder::Nullable(_) => {
+            column.push_default();
+            Ok(())
+        }
+        _ => Err(ErrorCode::BadBytes("NULL for a non-nullable column")),
+    }
+}
+
+fn is_string(column: &ColumnBuilder) -> bool {
+    match column {
+        ColumnBuilder::String(_) => true,
+        ColumnBuilder::Nullable(c) => matches!(c.builder, ColumnBuilder::String(_)),
+        _ => false,
+    }
+}
@@ -32,6 +32,7 @@ use common_io::cursor_ext::ReadBytesExt;
 use common_meta_app::principal::TsvFileFormatParams;
 
 use crate::field_decoder::row_based::FieldDecoderRowBased;
+use crate::field_decoder::text_load::TextLoadOptions;
 use crate::CommonSettings;
 use crate::FieldDecoder;
 use crate::FileFormatOptionsExt;
@@ -40,6 +41,7 @@ use crate::FileFormatOptionsExt;
 pub struct FieldDecoderTSV {
     pub common_settings: CommonSettings,
     pub quote_char: u8,
+    pub load_options: TextLoadOptions,
 }
 
 impl FieldDecoderTSV {
@@ -55,6 +57,12 @@ impl FieldDecoderTSV {
                 disable_variant_check: options_ext.disable_variant_check,
             },
             quote_char: params.quote.as_bytes()[0],
+            load_options: TextLoadOptions::create(
+                &params.null_if,
+                &params.empty_field_as,
+                params.trim_space,
+                params.binary_as_hex,
+            ),
         }
     }
 }
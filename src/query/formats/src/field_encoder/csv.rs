@@ -17,9 +17,9 @@ use common_expression::types::ValueType;
 use common_expression::Column;
 use common_io::constants::FALSE_BYTES_LOWER;
 use common_io::constants::INF_BYTES_LOWER;
-use common_io::constants::NULL_BYTES_ESCAPE;
 use common_io::constants::TRUE_BYTES_LOWER;
 use common_meta_app::principal::CsvFileFormatParams;
+use common_meta_app::principal::QUOTE_POLICY_MINIMAL;
 
 use crate::field_encoder::FieldEncoderRowBased;
 use crate::field_encoder::FieldEncoderValues;
@@ -30,6 +30,8 @@ pub struct FieldEncoderCSV {
     pub nested: FieldEncoderValues,
     pub common_settings: CommonSettings,
     pub quote_char: u8,
+    pub field_delimiter: u8,
+    pub quote_minimal: bool,
 }
 
 impl FieldEncoderCSV {
@@ -39,15 +41,25 @@ impl FieldEncoderCSV {
             common_settings: CommonSettings {
                 true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
                 false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
-                null_bytes: NULL_BYTES_ESCAPE.as_bytes().to_vec(),
+                null_bytes: params.null_display.as_bytes().to_vec(),
                 nan_bytes: params.nan_display.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
                 disable_variant_check: options_ext.disable_variant_check,
             },
             quote_char: params.quote.as_bytes()[0],
+            field_delimiter: params.field_delimiter.as_bytes()[0],
+            quote_minimal: params.quote_policy == QUOTE_POLICY_MINIMAL,
         }
     }
+
+    /// A string needs quotes if it can not be read back as it is, without them.
+    fn need_quote(&self, bytes: &[u8]) -> bool {
+        bytes == self.common_settings.null_bytes.as_slice()
+            || bytes.iter().any(|&b| {
+                b == self.field_delimiter || b == self.quote_char || b == b'\n' || b == b'\r'
+            })
+    }
 }
 
 impl FieldEncoderRowBased for FieldEncoderCSV {
@@ -56,7 +68,7 @@ impl FieldEncoderRowBased for FieldEncoderCSV {
     }
 
     fn write_string_inner(&self, in_buf: &[u8], out_buf: &mut Vec<u8>, raw: bool) {
-        if raw {
+        if raw || (self.quote_minimal && !self.need_quote(in_buf)) {
             out_buf.extend_from_slice(in_buf);
         } else {
             write_csv_string(in_buf, out_buf, self.quote_char);
@@ -17,10 +17,12 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::geometry::geometry_to_wkt;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
 use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::uuid::uuid_to_string;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use lexical_core::ToLexical;
@@ -66,6 +68,8 @@ pub trait FieldEncoderRowBased {
             Column::Bitmap(b) => self.write_string(b, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Uuid(c) => self.write_uuid(c, row_index, out_buf, raw),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, raw),
         }
     }
 
@@ -165,6 +169,30 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_uuid(
+        &self,
+        column: &Buffer<u128>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = uuid_to_string(*v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = geometry_to_wkt(v).unwrap_or_else(|_| hex::encode_upper(v));
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
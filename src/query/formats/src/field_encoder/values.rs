@@ -83,6 +83,22 @@ impl FieldEncoderValues {
             quote_char: b'\'',
         }
     }
+    // PostgreSQL sends the booleans as 't' and 'f' in the text format, and the drivers parse
+    // the floats with the names of `float8in`.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: CommonSettings {
+                true_bytes: b"t".to_vec(),
+                false_bytes: b"f".to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+                disable_variant_check: false,
+            },
+            quote_char: b'\'',
+        }
+    }
 }
 
 impl FieldEncoderRowBased for FieldEncoderValues {
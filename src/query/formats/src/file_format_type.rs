@@ -105,6 +105,9 @@ impl FileFormatOptionsExt {
     ) -> Result<Box<dyn OutputFormat>> {
         let output: Box<dyn OutputFormat> = match &params {
             FileFormatParams::Csv(params) => match self.headers {
+                0 if params.output_header => {
+                    Box::new(CSVWithNamesOutputFormat::create(schema, params, self))
+                }
                 0 => Box::new(CSVOutputFormat::create(schema, params, self)),
                 1 => Box::new(CSVWithNamesOutputFormat::create(schema, params, self)),
                 2 => Box::new(CSVWithNamesAndTypesOutputFormat::create(
@@ -120,42 +123,59 @@ impl FileFormatOptionsExt {
                 )),
                 _ => unreachable!(),
             },
-            FileFormatParams::NdJson(_params) => {
+            FileFormatParams::NdJson(params) => {
                 match (self.headers, self.json_strings, self.json_compact) {
                     // string, compact, name, type
                     // not compact
-                    (0, false, false) => Box::new(NDJSONOutputFormatBase::<
-                        false,
-                        false,
-                        false,
-                        false,
-                    >::create(schema, self)),
-                    (0, true, false) => Box::new(
-                        NDJSONOutputFormatBase::<true, false, false, false>::create(schema, self),
-                    ),
+                    (0, false, false) => {
+                        Box::new(
+                            NDJSONOutputFormatBase::<false, false, false, false>::create(
+                                schema, params, self,
+                            ),
+                        )
+                    }
+                    (0, true, false) => {
+                        Box::new(NDJSONOutputFormatBase::<true, false, false, false>::create(
+                            schema, params, self,
+                        ))
+                    }
                     // compact
-                    (0, false, true) => Box::new(
-                        NDJSONOutputFormatBase::<false, true, false, false>::create(schema, self),
-                    ),
-                    (0, true, true) => Box::new(
-                        NDJSONOutputFormatBase::<true, true, false, false>::create(schema, self),
-                    ),
-                    (1, false, true) => Box::new(
-                        NDJSONOutputFormatBase::<false, true, true, false>::create(schema, self),
-                    ),
-                    (1, true, true) => Box::new(
-                        NDJSONOutputFormatBase::<true, true, true, false>::create(schema, self),
-                    ),
-                    (2, false, true) => Box::new(
-                        NDJSONOutputFormatBase::<false, true, true, true>::create(schema, self),
-                    ),
-                    (2, true, true) => Box::new(
-                        NDJSONOutputFormatBase::<true, true, true, true>::create(schema, self),
-                    ),
+                    (0, false, true) => {
+                        Box::new(NDJSONOutputFormatBase::<false, true, false, false>::create(
+                            schema, params, self,
+                        ))
+                    }
+                    (0, true, true) => {
+                        Box::new(NDJSONOutputFormatBase::<true, true, false, false>::create(
+                            schema, params, self,
+                        ))
+                    }
+                    (1, false, true) => {
+                        Box::new(NDJSONOutputFormatBase::<false, true, true, false>::create(
+                            schema, params, self,
+                        ))
+                    }
+                    (1, true, true) => {
+                        Box::new(NDJSONOutputFormatBase::<true, true, true, false>::create(
+                            schema, params, self,
+                        ))
+                    }
+                    (2, false, true) => {
+                        Box::new(NDJSONOutputFormatBase::<false, true, true, true>::create(
+                            schema, params, self,
+                        ))
+                    }
+                    (2, true, true) => {
+                        Box::new(NDJSONOutputFormatBase::<true, true, true, true>::create(
+                            schema, params, self,
+                        ))
+                    }
                     _ => unreachable!(),
                 }
             }
-            FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
+            FileFormatParams::Parquet(params) => {
+                Box::new(ParquetOutputFormat::create(schema, params, self)?)
+            }
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
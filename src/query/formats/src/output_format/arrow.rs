@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::flight::default_ipc_fields;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::flight::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+
+use crate::output_format::OutputFormat;
+
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Writes blocks in the Arrow IPC streaming format, so that each block can be sent
+/// to the client as soon as it is serialized.
+pub struct ArrowOutputFormat {
+    schema: TableSchemaRef,
+    ipc_fields: Vec<IpcField>,
+    options: WriteOptions,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: TableSchemaRef) -> Self {
+        let arrow_schema = schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        Self {
+            schema,
+            ipc_fields,
+            options: WriteOptions { compression: None },
+        }
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        if block.num_rows() == 0 {
+            return Ok(vec![]);
+        }
+        let chunk: Chunk<_> = block.clone().try_into()?;
+        let (dicts, batch) = serialize_batch(&chunk, &self.ipc_fields, &self.options)?;
+
+        let mut buf = Vec::with_capacity(block.memory_size());
+        for dict in dicts {
+            write_message(&mut buf, &dict.data_header, &dict.data_body);
+        }
+        write_message(&mut buf, &batch.data_header, &batch.data_body);
+        Ok(buf)
+    }
+
+    fn serialize_prefix(&self) -> Result<Vec<u8>> {
+        let arrow_schema = self.schema.to_arrow();
+        let schema = serialize_schema(&arrow_schema, Some(&self.ipc_fields));
+        let mut buf = vec![];
+        write_message(&mut buf, &schema.data_header, &[]);
+        Ok(buf)
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        // the end-of-stream marker
+        let mut buf = CONTINUATION_MARKER.to_vec();
+        buf.extend_from_slice(&0i32.to_le_bytes());
+        Ok(buf)
+    }
+}
+
+/// Frames an encapsulated message: the continuation marker, the length of the
+/// metadata padded to 8 bytes, the metadata and the body, which is already aligned.
+fn write_message(buf: &mut Vec<u8>, header: &[u8], body: &[u8]) {
+    let padding = (8 - header.len() % 8) % 8;
+    buf.extend_from_slice(&CONTINUATION_MARKER);
+    buf.extend_from_slice(&((header.len() + padding) as i32).to_le_bytes());
+    buf.extend_from_slice(header);
+    buf.extend(std::iter::repeat(0u8).take(padding));
+    buf.extend_from_slice(body);
+}
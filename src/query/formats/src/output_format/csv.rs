@@ -18,10 +18,10 @@ use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
 use common_meta_app::principal::CsvFileFormatParams;
 
-use crate::field_encoder::write_csv_string;
 use crate::field_encoder::FieldEncoderCSV;
 use crate::field_encoder::FieldEncoderRowBased;
 use crate::output_format::OutputFormat;
+use crate::output_format::UTF8_BOM;
 use crate::FileFormatOptionsExt;
 
 pub type CSVOutputFormat = CSVOutputFormatBase<false, false>;
@@ -33,7 +33,7 @@ pub struct CSVOutputFormatBase<const WITH_NAMES: bool, const WITH_TYPES: bool> {
     field_encoder: FieldEncoderCSV,
     field_delimiter: u8,
     record_delimiter: Vec<u8>,
-    quote: u8,
+    output_bom: bool,
 }
 
 impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NAMES, WITH_TYPES> {
@@ -48,7 +48,7 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             field_encoder,
             field_delimiter: params.field_delimiter.as_bytes()[0],
             record_delimiter: params.record_delimiter.as_bytes().to_vec(),
-            quote: params.quote.as_bytes()[0],
+            output_bom: params.output_bom,
         }
     }
 
@@ -60,7 +60,8 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             if col_index != 0 {
                 buf.push(fd);
             }
-            write_csv_string(v.as_bytes(), &mut buf, self.quote);
+            self.field_encoder
+                .write_string_inner(v.as_bytes(), &mut buf, false);
         }
 
         buf.extend_from_slice(&self.record_delimiter);
@@ -100,6 +101,9 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> OutputFormat
 
     fn serialize_prefix(&self) -> Result<Vec<u8>> {
         let mut buf = vec![];
+        if self.output_bom {
+            buf.extend_from_slice(UTF8_BOM);
+        }
         if WITH_NAMES {
             let names = self
                 .schema
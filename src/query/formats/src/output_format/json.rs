@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::geometry::geometry_to_wkt;
 use common_expression::types::number::NumberScalar;
+use common_expression::types::uuid::uuid_to_string;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
@@ -140,6 +142,10 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Uuid(x) => JsonValue::String(uuid_to_string(x)),
+        ScalarRef::Geometry(x) => {
+            JsonValue::String(geometry_to_wkt(x).unwrap_or_else(|_| hex::encode_upper(x)))
+        }
     }
 }
 
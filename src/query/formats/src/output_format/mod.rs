@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 use common_expression::DataBlock;
+pub mod arrow;
 pub mod csv;
 pub mod json;
 pub mod ndjson;
@@ -21,6 +22,7 @@ pub mod parquet;
 pub mod tsv;
 pub mod values;
 
+pub use arrow::ArrowOutputFormat;
 pub use csv::CSVOutputFormat;
 pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
@@ -32,6 +34,9 @@ pub use tsv::TSVWithNamesAndTypesOutputFormat;
 pub use tsv::TSVWithNamesOutputFormat;
 pub use values::ValuesOutputFormat;
 
+/// The byte order mark of UTF-8, some legacy tools need it to detect the encoding.
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 pub trait OutputFormat: Send {
     fn serialize_block(&mut self, data_block: &DataBlock) -> Result<Vec<u8>>;
 
@@ -16,10 +16,12 @@ use common_exception::Result;
 use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::NdJsonFileFormatParams;
 
 use crate::field_encoder::FieldEncoderJSON;
 use crate::field_encoder::FieldEncoderRowBased;
 use crate::output_format::OutputFormat;
+use crate::output_format::UTF8_BOM;
 use crate::FileFormatOptionsExt;
 
 pub struct NDJSONOutputFormatBase<
@@ -30,16 +32,24 @@ pub struct NDJSONOutputFormatBase<
 > {
     schema: TableSchemaRef,
     field_encoder: FieldEncoderJSON,
+    record_delimiter: Vec<u8>,
+    output_bom: bool,
 }
 
 impl<const STRINGS: bool, const COMPACT: bool, const WITH_NAMES: bool, const WITH_TYPES: bool>
     NDJSONOutputFormatBase<STRINGS, COMPACT, WITH_NAMES, WITH_TYPES>
 {
-    pub fn create(schema: TableSchemaRef, options: &FileFormatOptionsExt) -> Self {
+    pub fn create(
+        schema: TableSchemaRef,
+        params: &NdJsonFileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Self {
         let field_encoder = FieldEncoderJSON::create(options);
         Self {
             schema,
             field_encoder,
+            record_delimiter: params.record_delimiter.as_bytes().to_vec(),
+            output_bom: params.output_bom,
         }
     }
 
@@ -53,7 +63,8 @@ impl<const STRINGS: bool, const COMPACT: bool, const WITH_NAMES: bool, const WIT
             self.field_encoder
                 .write_string_inner(v.as_bytes(), &mut buf, false);
         }
-        buf.extend_from_slice(b"]\n");
+        buf.push(b']');
+        buf.extend_from_slice(&self.record_delimiter);
         buf
     }
 }
@@ -108,16 +119,20 @@ impl<const STRINGS: bool, const COMPACT: bool, const WITH_NAMES: bool, const WIT
                 }
             }
             if COMPACT {
-                buf.extend_from_slice("]\n".as_bytes());
+                buf.push(b']');
             } else {
-                buf.extend_from_slice("}\n".as_bytes());
+                buf.push(b'}');
             }
+            buf.extend_from_slice(&self.record_delimiter);
         }
         Ok(buf)
     }
 
     fn serialize_prefix(&self) -> Result<Vec<u8>> {
         let mut buf = vec![];
+        if self.output_bom {
+            buf.extend_from_slice(UTF8_BOM);
+        }
         if WITH_NAMES {
             assert!(COMPACT);
             let names = self
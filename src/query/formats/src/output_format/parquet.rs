@@ -12,28 +12,57 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::parquet::compression::CompressionOptions;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
-use storages_common_blocks::blocks_to_parquet;
-use storages_common_table_meta::table::TableCompression;
+use common_meta_app::principal::ParquetFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use storages_common_blocks::blocks_to_parquet_with_options;
+use storages_common_blocks::ParquetWriteOptions;
 
 use crate::output_format::OutputFormat;
 use crate::FileFormatOptionsExt;
 
-#[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: TableSchemaRef,
     data_blocks: Vec<DataBlock>,
+    write_options: ParquetWriteOptions,
 }
 
 impl ParquetOutputFormat {
-    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
-        Self {
+    pub fn create(
+        schema: TableSchemaRef,
+        params: &ParquetFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let compression = match params.compression {
+            StageFileCompression::Auto => CompressionOptions::Lz4Raw,
+            StageFileCompression::Zstd => CompressionOptions::Zstd(None),
+            StageFileCompression::Snappy => CompressionOptions::Snappy,
+            StageFileCompression::Gzip => CompressionOptions::Gzip(None),
+            StageFileCompression::None => CompressionOptions::Uncompressed,
+            other => {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Unsupported compression for PARQUET: {}",
+                    other.to_string()
+                )));
+            }
+        };
+        let write_options = ParquetWriteOptions {
+            compression,
+            row_group_size: (params.row_group_size > 0).then_some(params.row_group_size as usize),
+            data_page_size: (params.data_page_size > 0).then_some(params.data_page_size as usize),
+            write_statistics: params.statistics,
+            bloom_filter_columns: params.bloom_filter_columns.clone(),
+        };
+        Ok(Self {
             schema,
             data_blocks: vec![],
-        }
+            write_options,
+        })
     }
 }
 
@@ -53,7 +82,8 @@ impl OutputFormat for ParquetOutputFormat {
             return Ok(vec![]);
         }
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::LZ4)?;
+        let _ =
+            blocks_to_parquet_with_options(&self.schema, blocks, &mut buf, &self.write_options)?;
         Ok(buf)
     }
 }
@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_exception::Result;
+use common_formats::output_format::ArrowOutputFormat;
+use common_formats::output_format::OutputFormat;
+
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_arrow_stream() -> Result<()> {
+    for is_nullable in [false, true] {
+        let (schema, block) = get_simple_block(is_nullable);
+        let mut formatter = ArrowOutputFormat::create(schema.clone());
+
+        let mut buf = formatter.serialize_prefix()?;
+        buf.extend(formatter.serialize_block(&block)?);
+        buf.extend(formatter.serialize_block(&block)?);
+        buf.extend(formatter.finalize()?);
+
+        let mut cursor = Cursor::new(buf);
+        let metadata = read_stream_metadata(&mut cursor)?;
+        assert_eq!(metadata.schema, schema.to_arrow());
+
+        let mut rows = vec![];
+        for state in StreamReader::new(cursor, metadata, None) {
+            match state? {
+                StreamState::Some(chunk) => {
+                    assert_eq!(chunk.arrays().len(), 5);
+                    rows.push(chunk.len());
+                }
+                StreamState::Waiting => unreachable!(),
+            }
+        }
+        assert_eq!(rows, vec![3, 3]);
+    }
+    Ok(())
+}
@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use common_exception::Result;
 use common_expression::types::number::Int32Type;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::FromOptData;
 use common_expression::TableDataType;
 use common_expression::TableField;
@@ -141,3 +142,90 @@ fn test_field_delimiter_with_ascii_control_code() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_csv_unload_options() -> Result<()> {
+    let (schema, block) = get_simple_block(false);
+
+    let settings = Settings::create("default".to_string());
+    let mut options = BTreeMap::<String, String>::new();
+    options.insert("type".to_string(), "csv".to_string());
+    options.insert("quote_policy".to_string(), "MINIMAL".to_string());
+    options.insert("output_header".to_string(), "true".to_string());
+    options.insert("output_bom".to_string(), "true".to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options.clone()), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema, params)?;
+
+    let prefix = String::from_utf8(output_format.serialize_prefix()?)?;
+    assert_eq!(&prefix, "\u{feff}c1,c2,c3,c4,c5\n");
+
+    let csv_block = String::from_utf8(output_format.serialize_block(&block)?)?;
+    let expect =
+        "1,a,true,1.1,1970-01-02\n2,\"b\"\"\",true,2.2,1970-01-03\n3,c',false,NaN,1970-01-04\n";
+    assert_eq!(&csv_block, expect);
+
+    Ok(())
+}
+
+#[test]
+fn test_null_display() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![
+            TableField::new(
+                "c1",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("c2", TableDataType::String.wrap_nullable()),
+        ],
+        vec![
+            Int32Type::from_opt_data(vec![Some(1i32), None]),
+            StringType::from_opt_data(vec![Some(b"NULL".to_vec()), Some(vec![])]),
+        ],
+    );
+
+    let settings = Settings::create("default".to_string());
+    let mut options = BTreeMap::<String, String>::new();
+    options.insert("type".to_string(), "csv".to_string());
+    options.insert("null_display".to_string(), "NULL".to_string());
+    options.insert("quote_policy".to_string(), "minimal".to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options.clone()), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema, params)?;
+
+    // the string that equals to the null display is quoted to tell it from NULL.
+    let csv_block = String::from_utf8(output_format.serialize_block(&block)?)?;
+    let expect = "1,\"NULL\"\nNULL,\n";
+    assert_eq!(&csv_block, expect);
+
+    Ok(())
+}
+
+#[test]
+fn test_ndjson_unload_options() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![TableField::new(
+            "c1",
+            TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+        )],
+        vec![Int32Type::from_opt_data(vec![Some(1i32), None])],
+    );
+
+    let settings = Settings::create("default".to_string());
+    let mut options = BTreeMap::<String, String>::new();
+    options.insert("type".to_string(), "ndjson".to_string());
+    options.insert("record_delimiter".to_string(), "\r\n".to_string());
+    options.insert("output_bom".to_string(), "true".to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options.clone()), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema, params)?;
+
+    let prefix = String::from_utf8(output_format.serialize_prefix()?)?;
+    assert_eq!(&prefix, "\u{feff}");
+
+    let json_block = String::from_utf8(output_format.serialize_block(&block)?)?;
+    let expect = "{\"c1\":1}\r\n{\"c1\":null}\r\n";
+    assert_eq!(&json_block, expect);
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::aggregates::StateAddr;
+
+/// Accumulates the serialized states of the inner function instead of its arguments,
+/// e.g. the states stored in the data of an aggregating index.
+///
+/// The state layout, serialization and result are the same as the inner function.
+#[derive(Clone)]
+pub struct AggregateStateMergeAdaptor {
+    inner: AggregateFunctionRef,
+}
+
+impl AggregateStateMergeAdaptor {
+    pub fn create(inner: AggregateFunctionRef) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(AggregateStateMergeAdaptor { inner }))
+    }
+
+    fn states<'a>(&self, columns: &'a [Column]) -> Result<&'a StringColumn> {
+        columns[0].as_string().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "The states of aggregate function {} must be a string column",
+                self.inner
+            ))
+        })
+    }
+
+    fn merge_state(&self, arena: &Bump, place: StateAddr, mut state: &[u8]) -> Result<()> {
+        let temp: StateAddr = arena.alloc_layout(self.inner.state_layout()).into();
+        self.inner.init_state(temp);
+        let res = self
+            .inner
+            .deserialize(temp, &mut state)
+            .and_then(|_| self.inner.merge(place, temp));
+        if self.inner.need_manual_drop_state() {
+            unsafe {
+                self.inner.drop_state(temp);
+            }
+        }
+        res
+    }
+}
+
+impl AggregateFunction for AggregateStateMergeAdaptor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.inner.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.inner.init_state(place)
+    }
+
+    fn is_state(&self) -> bool {
+        self.inner.is_state()
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.inner.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let states = self.states(columns)?;
+        let arena = Bump::new();
+        match validity {
+            Some(validity) => {
+                for (state, valid) in states.iter().zip(validity.iter()) {
+                    if valid {
+                        self.merge_state(&arena, place, state)?;
+                    }
+                }
+            }
+            None => {
+                for state in states.iter() {
+                    self.merge_state(&arena, place, state)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let states = self.states(columns)?;
+        let arena = Bump::new();
+        for (place, state) in places.iter().zip(states.iter()) {
+            self.merge_state(&arena, place.next(offset), state)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let states = self.states(columns)?;
+        let arena = Bump::new();
+        let state = unsafe { states.index_unchecked(row) };
+        self.merge_state(&arena, place, state)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.inner.serialize(place, writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.inner.deserialize(place, reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.inner.merge(place, rhs)
+    }
+
+    fn batch_merge_result(&self, places: &[StateAddr], builder: &mut ColumnBuilder) -> Result<()> {
+        self.inner.batch_merge_result(places, builder)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.inner.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.inner.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.inner.drop_state(place)
+    }
+}
+
+impl fmt::Display for AggregateStateMergeAdaptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
@@ -0,0 +1,337 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_number;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::with_integer_mapped_type;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::StateAddr;
+use crate::aggregates::assert_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::BUILTIN_FUNCTIONS;
+
+const DEFAULT_TOP_K: u64 = 10;
+const MAX_TOP_K: u64 = 10000;
+
+/// The values tracked by the sketch for each of the top k values.
+/// The more counters, the more accurate the estimated counts are.
+const COUNTERS_PER_TOP_K: usize = 3;
+
+/// A SpaceSaving sketch of the most frequent values.
+///
+/// Each counter is the estimated count of a value and the max overestimation of it.
+/// A value not tracked replaces the value of the least count once all the counters are used,
+/// taking over its count as the overestimation.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SpaceSavingState {
+    counters: HashMap<Scalar, (u64, u64)>,
+}
+
+impl SpaceSavingState {
+    fn add(&mut self, value: Scalar, weight: u64, capacity: usize) {
+        if let Some((count, _)) = self.counters.get_mut(&value) {
+            *count += weight;
+            return;
+        }
+        if self.counters.len() < capacity {
+            self.counters.insert(value, (weight, 0));
+            return;
+        }
+        let (min_value, min_count) = self
+            .counters
+            .iter()
+            .min_by_key(|(_, (count, _))| *count)
+            .map(|(value, (count, _))| (value.clone(), *count))
+            .unwrap();
+        self.counters.remove(&min_value);
+        self.counters.insert(value, (min_count + weight, min_count));
+    }
+
+    /// The max count of the values not tracked.
+    fn min_count(&self, capacity: usize) -> u64 {
+        if self.counters.len() < capacity {
+            return 0;
+        }
+        self.counters
+            .values()
+            .map(|(count, _)| *count)
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn merge(&mut self, rhs: &Self, capacity: usize) {
+        let lhs_min = self.min_count(capacity);
+        let rhs_min = rhs.min_count(capacity);
+        for (value, (count, error)) in self.counters.iter_mut() {
+            if !rhs.counters.contains_key(value) {
+                *count += rhs_min;
+                *error += rhs_min;
+            }
+        }
+        for (value, (rhs_count, rhs_error)) in rhs.counters.iter() {
+            let (count, error) = self
+                .counters
+                .entry(value.clone())
+                .or_insert((lhs_min, lhs_min));
+            *count += rhs_count;
+            *error += rhs_error;
+        }
+
+        if self.counters.len() > capacity {
+            let mut counters = self.counters.drain().collect::<Vec<_>>();
+            counters.sort_by(|a, b| b.1.0.cmp(&a.1.0));
+            counters.truncate(capacity);
+            self.counters = counters.into_iter().collect();
+        }
+    }
+
+    fn top(&self, k: usize) -> Vec<(&Scalar, u64)> {
+        let mut counters = self
+            .counters
+            .iter()
+            .map(|(value, (count, _))| (value, *count))
+            .collect::<Vec<_>>();
+        counters.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        counters.truncate(k);
+        counters
+    }
+}
+
+/// Estimate the most frequent values with a [`SpaceSavingState`], counting the rows of
+/// the values, or summing up the weights of them if `weighted`.
+#[derive(Clone)]
+pub struct AggregateApproxTopKFunction {
+    display_name: String,
+    return_type: DataType,
+    k: usize,
+    weighted: bool,
+}
+
+impl AggregateApproxTopKFunction {
+    fn capacity(&self) -> usize {
+        self.k * COUNTERS_PER_TOP_K
+    }
+}
+
+impl AggregateFunction for AggregateApproxTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(SpaceSavingState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<SpaceSavingState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if validity.map_or(true, |validity| validity.get_bit(row)) {
+                self.accumulate_row(place, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let value = columns[0].index(row).unwrap().to_owned();
+        let weight = match self.weighted {
+            true => weight_of(columns[1].index(row).unwrap())?,
+            false => 1,
+        };
+        state.add(value, weight, self.capacity());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let rhs = rhs.get::<SpaceSavingState>();
+        state.merge(rhs, self.capacity());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let data_type = builder.data_type();
+        let inner_type = data_type.as_array().unwrap();
+
+        let top = state.top(self.k);
+        let mut inner_builder = ColumnBuilder::with_capacity(inner_type, top.len());
+        for (value, count) in top {
+            inner_builder.push(ScalarRef::Tuple(vec![
+                value.as_ref(),
+                ScalarRef::Number(NumberScalar::UInt64(count)),
+            ]));
+        }
+        builder.push(ScalarRef::Array(inner_builder.build()));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<SpaceSavingState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateApproxTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn weight_of(weight: ScalarRef) -> Result<u64> {
+    let ScalarRef::Number(weight) = weight else {
+        return Err(ErrorCode::BadDataValueType("The weight must be an integer"));
+    };
+    with_integer_mapped_type!(|NUM_TYPE| match weight {
+        NumberScalar::NUM_TYPE(weight) => u64::try_from(weight).map_err(|_| {
+            ErrorCode::BadDataValueType(format!("The weight must not be negative, got: {weight}"))
+        }),
+        _ => Err(ErrorCode::BadDataValueType("The weight must be an integer")),
+    })
+}
+
+fn try_create_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+    weighted: bool,
+) -> Result<AggregateFunctionRef> {
+    assert_arguments(display_name, arguments.len(), if weighted { 2 } else { 1 })?;
+    if weighted && !arguments[1].is_integer() {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expect the weight to be an integer, but got {}",
+            display_name, arguments[1]
+        )));
+    }
+
+    let k = match params.len() {
+        0 => DEFAULT_TOP_K,
+        1 => check_number(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::Constant {
+                    span: None,
+                    scalar: params[0].clone(),
+                    data_type: params[0].as_ref().infer_data_type(),
+                }),
+                dest_type: DataType::Number(NumberDataType::UInt64),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most 1 params, but got {}",
+                display_name, n
+            )));
+        }
+    };
+    if !(1..=MAX_TOP_K).contains(&k) {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} expect k range between [1, {}], got: {}",
+            display_name, MAX_TOP_K, k
+        )));
+    }
+
+    let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+        arguments[0].clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])));
+    Ok(Arc::new(AggregateApproxTopKFunction {
+        display_name: display_name.to_string(),
+        return_type,
+        k: k as usize,
+        weighted,
+    }))
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(|display_name, params, arguments| {
+            try_create_approx_top_k_function(display_name, params, arguments, false)
+        }),
+        features,
+    )
+}
+
+pub fn aggregate_approx_top_sum_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(|display_name, params, arguments| {
+            try_create_approx_top_k_function(display_name, params, arguments, true)
+        }),
+        features,
+    )
+}
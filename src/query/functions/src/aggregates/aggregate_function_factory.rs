@@ -280,6 +280,13 @@ impl AggregateFunctionFactory {
         self.case_insensitive_desc.keys().cloned().collect()
     }
 
+    pub fn get_features(&self, func_name: impl AsRef<str>) -> Option<AggregateFunctionFeatures> {
+        let lowercase_name = func_name.as_ref().to_lowercase();
+        self.case_insensitive_desc
+            .get(&lowercase_name)
+            .map(|desc| desc.features.clone())
+    }
+
     pub fn registered_features(&self) -> Vec<AggregateFunctionFeatures> {
         self.case_insensitive_desc
             .values()
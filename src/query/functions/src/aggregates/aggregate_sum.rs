@@ -134,57 +134,73 @@ where
     }
 }
 
+/// Decimals are always summed in i256 whatever the width of the input is, so
+/// a partial sum may exceed the precision of the result type for a while.
+/// The precision is only checked when the result is built.
+///
+/// The serialized state is still a `T`, it is persisted in the aggregating index
+/// data and must stay readable.
 #[derive(Default)]
 pub struct DecimalSumState<T: Decimal> {
-    pub value: T,
+    pub value: i256,
+    _t: PhantomData<T>,
 }
 
 impl<T> DecimalSumState<T>
 where T: Decimal
-        + std::ops::AddAssign
-        + Serialize
-        + DeserializeOwned
-        + Copy
-        + Clone
-        + std::fmt::Debug
-        + std::cmp::PartialOrd
 {
     #[inline]
-    pub fn check_over_flow(&self) -> Result<()> {
-        if self.value > T::max_of_max_precision() {
-            return Err(ErrorCode::Overflow(format!(
-                "Decimal overflow: {} > {}",
-                self.value,
-                T::max_of_max_precision()
-            )));
+    fn add(&mut self, other: i256) -> Result<()> {
+        match self.value.checked_add(other) {
+            Some(value) => {
+                self.value = value;
+                Ok(())
+            }
+            None => Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: {} + {} exceeds the range of i256",
+                self.value, other
+            ))),
+        }
+    }
+
+    /// Converts the accumulated value to `T`, fails if it exceeds the max precision of `T`.
+    #[inline]
+    fn to_result(value: i256) -> Result<T> {
+        let precision = T::default_decimal_size().precision;
+        let max = i256::from(10).pow(precision as u32) - i256::ONE;
+        match T::from_i256(value) {
+            Some(v) if value <= max && value >= -max => Ok(v),
+            _ => Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: {} exceeds (precision: {})",
+                value, precision
+            ))),
         }
-        Ok(())
     }
 }
 
 impl<T> SumState for DecimalSumState<T>
-where T: Decimal
-        + std::ops::AddAssign
-        + Serialize
-        + DeserializeOwned
-        + Copy
-        + Clone
-        + std::fmt::Debug
-        + std::cmp::PartialOrd
+where T: Decimal + Serialize + DeserializeOwned
 {
     fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
-        serialize_into_buf(writer, &self.value)
+        let value = T::from_i256(self.value).ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: {} > {}",
+                self.value,
+                T::max_of_max_precision()
+            ))
+        })?;
+        serialize_into_buf(writer, &value)
     }
 
     fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
-        self.value = deserialize_from_slice(reader)?;
+        let value: T = deserialize_from_slice(reader)?;
+        self.value = value.to_i256();
         Ok(())
     }
 
     fn accumulate_row(&mut self, column: &Column, row: usize) -> Result<()> {
         let buffer = T::try_downcast_column(column).unwrap().0;
-        self.value += buffer[row];
-        self.check_over_flow()
+        self.add(buffer[row].to_i256())
     }
 
     fn accumulate(&mut self, column: &Column, validity: Option<&Bitmap>) -> Result<()> {
@@ -193,64 +209,71 @@ where T: Decimal
             Some(validity) => {
                 for (i, v) in validity.iter().enumerate() {
                     if v {
-                        self.value += buffer[i];
+                        self.add(buffer[i].to_i256())?;
                     }
                 }
             }
             None => {
                 for v in buffer.iter() {
-                    self.value += *v;
+                    self.add(v.to_i256())?;
                 }
             }
         }
-        self.check_over_flow()
+        Ok(())
     }
 
     fn accumulate_keys(places: &[StateAddr], offset: usize, columns: &Column) -> Result<()> {
         let buffer = T::try_downcast_column(columns).unwrap().0;
         for (i, place) in places.iter().enumerate() {
             let state = place.next(offset).get::<DecimalSumState<T>>();
-            state.value += buffer[i];
-            state.check_over_flow()?;
+            state.add(buffer[i].to_i256())?;
         }
         Ok(())
     }
 
     #[inline(always)]
     fn merge(&mut self, other: &mut Self) -> Result<()> {
-        self.value += other.value;
-        self.check_over_flow()
+        self.add(other.value)
     }
 
     fn merge_result(&mut self, builder: &mut ColumnBuilder) -> Result<()> {
+        let value = Self::to_result(self.value)?;
         let builder = T::try_downcast_builder(builder).unwrap();
-        builder.push(self.value);
+        builder.push(value);
         Ok(())
     }
 
+    /// Divides the sum by the count, the quotient is rounded half away from zero
+    /// in the scale of the result, which is `scale_add` larger than the input.
     fn merge_avg_result(
         &mut self,
         builder: &mut ColumnBuilder,
         count: u64,
         scale_add: u8,
     ) -> Result<()> {
-        let builder = T::try_downcast_builder(builder).unwrap();
-
-        match self
-            .value
-            .checked_mul(T::e(scale_add as u32))
-            .and_then(|v| v.checked_div(T::from_u64(count)))
-        {
-            Some(value) => {
-                builder.push(value);
-                Ok(())
+        let value = if count == 0 {
+            T::zero()
+        } else {
+            let count = i256::from(count);
+            let value = self
+                .value
+                .checked_mul(i256::from(10).pow(scale_add as u32))
+                .ok_or_else(|| {
+                    ErrorCode::Overflow(format!(
+                        "Decimal overflow: {} * 10^{} exceeds the range of i256",
+                        self.value, scale_add
+                    ))
+                })?;
+            let mut quotient = value / count;
+            let remainder = value % count;
+            if remainder.unsigned_abs() * 2 >= count.unsigned_abs() {
+                quotient += value.signum();
             }
-            None => Err(ErrorCode::Overflow(format!(
-                "Decimal overflow: {} > (precision: {})",
-                self.value,
-                T::max_of_max_precision()
-            ))),
-        }
+            Self::to_result(quotient)?
+        };
+        let builder = T::try_downcast_builder(builder).unwrap();
+        builder.push(value);
+        Ok(())
     }
 }
 
@@ -71,6 +71,8 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
     (DataType::String, DataType::Boolean),
+    (DataType::String, DataType::Uuid),
+    (DataType::String, DataType::Geometry),
     (DataType::Date, DataType::Timestamp),
     (
         DataType::Number(NumberDataType::UInt8),
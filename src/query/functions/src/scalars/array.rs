@@ -451,8 +451,17 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     registry.register_passthrough_nullable_2_arg::<ArrayType<StringType>, StringType, BooleanType, _, _>(
         "contains",
-        |_, _| {
-            FunctionDomain::Full
+        |lhs, rhs| {
+            // The max of a string domain is `None` if it is unbounded.
+            let has_true = match lhs {
+                Some(lhs) => !(rhs.max.as_ref().map(|max| &lhs.min > max).unwrap_or(false)
+                    || lhs.max.as_ref().map(|max| max < &rhs.min).unwrap_or(false)),
+                None => false,
+            };
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: true,
+                has_true,
+            })
         },
         |lhs, rhs, _| {
             match lhs {
@@ -18,6 +18,8 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
@@ -27,10 +29,12 @@ use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::NullableType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::UuidType;
 use common_expression::types::ValueType;
 use common_expression::types::VariantType;
 use common_expression::types::ALL_NUMBER_CLASSES;
@@ -51,12 +55,14 @@ use regex::bytes::Regex;
 
 use crate::scalars::decimal::register_decimal_compare_op;
 use crate::scalars::string_multi_args::regexp;
+use crate::scalars::unicode::fold_case;
 
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_uuid_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -168,6 +174,10 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_uuid_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, UuidType);
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -322,18 +332,41 @@ fn register_tuple_cmp(registry: &mut FunctionRegistry) {
         registry: &mut FunctionRegistry,
         name: &str,
         default_result: bool,
+        // The ordering comparisons are NULL on the first NULL field, while the equality
+        // comparisons are NULL only if no other field decides the result.
+        null_breaks: bool,
         // Compare the fields of each row from left to right, break on the first `Some()` result.
         // If all fields are `None`, return `default_result`.
         cmp_op: impl Fn(ScalarRef, ScalarRef) -> Option<bool> + 'static + Send + Sync + Copy,
     ) {
         let name_cloned = name.to_string();
         registry.register_function_factory(name, move |_, args_type| {
-            let fields_generics = match args_type {
-                [DataType::Tuple(lhs_fields_ty), _] => (0..lhs_fields_ty.len())
-                    .map(DataType::Generic)
-                    .collect::<Vec<_>>(),
+            let (fields_generics, nullable) = match args_type {
+                [
+                    DataType::Tuple(lhs_fields_ty),
+                    DataType::Tuple(rhs_fields_ty),
+                ] => (
+                    (0..lhs_fields_ty.len())
+                        .map(DataType::Generic)
+                        .collect::<Vec<_>>(),
+                    lhs_fields_ty
+                        .iter()
+                        .chain(rhs_fields_ty.iter())
+                        .any(|ty| ty.is_nullable_or_null()),
+                ),
+                [DataType::Tuple(lhs_fields_ty), _] => (
+                    (0..lhs_fields_ty.len())
+                        .map(DataType::Generic)
+                        .collect::<Vec<_>>(),
+                    lhs_fields_ty.iter().any(|ty| ty.is_nullable_or_null()),
+                ),
                 _ => return None,
             };
+            let return_type = if nullable {
+                DataType::Nullable(Box::new(DataType::Boolean))
+            } else {
+                DataType::Boolean
+            };
             Some(Arc::new(Function {
                 signature: FunctionSignature {
                     name: name_cloned.clone(),
@@ -341,7 +374,7 @@ fn register_tuple_cmp(registry: &mut FunctionRegistry) {
                         DataType::Tuple(fields_generics.clone()),
                         DataType::Tuple(fields_generics),
                     ],
-                    return_type: DataType::Boolean,
+                    return_type,
                 },
                 eval: FunctionEval::Scalar {
                     calc_domain: Box::new(move |_| FunctionDomain::Full),
@@ -371,28 +404,44 @@ fn register_tuple_cmp(registry: &mut FunctionRegistry) {
                         };
 
                         let size = len.unwrap_or(1);
-                        let mut builder = BooleanType::create_builder(size, &[]);
+                        let mut builder = NullableType::<BooleanType>::create_builder(size, &[]);
 
                         'outer: for row in 0..size {
+                            let mut has_null = false;
                             for (lhs_field, rhs_field) in lhs_fields.iter().zip(&rhs_fields) {
                                 let lhs = lhs_field.index(row).unwrap();
                                 let rhs = rhs_field.index(row).unwrap();
+                                if lhs == ScalarRef::Null || rhs == ScalarRef::Null {
+                                    has_null = true;
+                                    if null_breaks {
+                                        break;
+                                    }
+                                    continue;
+                                }
                                 if let Some(result) = cmp_op(lhs, rhs) {
                                     builder.push(result);
                                     continue 'outer;
                                 }
                             }
-                            builder.push(default_result);
+                            if has_null {
+                                builder.push_null();
+                            } else {
+                                builder.push(default_result);
+                            }
                         }
 
-                        match len {
-                            Some(_) => {
-                                let col =
-                                    BooleanType::upcast_column(BooleanType::build_column(builder));
-                                Value::Column(col)
+                        match (len, nullable) {
+                            (Some(_), true) => {
+                                Value::Column(NullableType::<BooleanType>::upcast_column(
+                                    NullableType::<BooleanType>::build_column(builder),
+                                ))
                             }
-                            _ => Value::Scalar(BooleanType::upcast_scalar(
-                                BooleanType::build_scalar(builder),
+                            (Some(_), false) => {
+                                let col = builder.build();
+                                Value::Column(BooleanType::upcast_column(col.column))
+                            }
+                            (None, _) => Value::Scalar(NullableType::<BooleanType>::upcast_scalar(
+                                NullableType::<BooleanType>::build_scalar(builder),
                             )),
                         }
                     }),
@@ -401,34 +450,34 @@ fn register_tuple_cmp(registry: &mut FunctionRegistry) {
         });
     }
 
-    register_tuple_cmp_op(registry, "eq", true, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "eq", true, false, |lhs, rhs| {
         if lhs != rhs { Some(false) } else { None }
     });
-    register_tuple_cmp_op(registry, "noteq", false, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "noteq", false, false, |lhs, rhs| {
         if lhs != rhs { Some(true) } else { None }
     });
-    register_tuple_cmp_op(registry, "gt", false, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "gt", false, true, |lhs, rhs| {
         match lhs.partial_cmp(&rhs) {
             Some(Ordering::Greater) => Some(true),
             Some(Ordering::Less) => Some(false),
             _ => None,
         }
     });
-    register_tuple_cmp_op(registry, "gte", true, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "gte", true, true, |lhs, rhs| {
         match lhs.partial_cmp(&rhs) {
             Some(Ordering::Greater) => Some(true),
             Some(Ordering::Less) => Some(false),
             _ => None,
         }
     });
-    register_tuple_cmp_op(registry, "lt", false, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "lt", false, true, |lhs, rhs| {
         match lhs.partial_cmp(&rhs) {
             Some(Ordering::Less) => Some(true),
             Some(Ordering::Greater) => Some(false),
             _ => None,
         }
     });
-    register_tuple_cmp_op(registry, "lte", true, |lhs, rhs| {
+    register_tuple_cmp_op(registry, "lte", true, true, |lhs, rhs| {
         match lhs.partial_cmp(&rhs) {
             Some(Ordering::Less) => Some(true),
             Some(Ordering::Greater) => Some(false),
@@ -445,7 +494,29 @@ fn register_like(registry: &mut FunctionRegistry) {
         |lhs, rhs| {
             if rhs.max.as_ref() == Some(&rhs.min) {
                 let pattern_type = check_pattern_type(&rhs.min, false);
-                if pattern_type == PatternType::EndOfPercent
+                if pattern_type == PatternType::PatternStr {
+                    // 'abc%def' only matches the strings in ['abc', 'abd').
+                    let min = like_prefix(&rhs.min);
+                    let mut max = min.clone();
+                    match max.last_mut() {
+                        Some(last) if *last != u8::MAX => *last += 1,
+                        _ => return FunctionDomain::Full,
+                    }
+
+                    let other = StringDomain {
+                        min,
+                        max: Some(max),
+                    };
+                    let gte = lhs.domain_gte(&other);
+                    let lt = lhs.domain_lt(&other);
+
+                    if let (FunctionDomain::Domain(lhs), FunctionDomain::Domain(rhs)) = (lt, gte) {
+                        return FunctionDomain::Domain(BooleanDomain {
+                            has_false: true,
+                            has_true: lhs.has_true && rhs.has_true,
+                        });
+                    }
+                } else if pattern_type == PatternType::EndOfPercent
                     || pattern_type == PatternType::OrdinalStr
                 {
                     let (min, max) = if pattern_type == PatternType::EndOfPercent {
@@ -480,31 +551,21 @@ fn register_like(registry: &mut FunctionRegistry) {
             }
             FunctionDomain::Full
         },
-        vectorize_like(|str, pat, _, pattern_type| {
-            match pattern_type {
-                PatternType::OrdinalStr => str == pat,
-                PatternType::EndOfPercent => {
-                    // fast path, can use starts_with
-                    let starts_with = &pat[..pat.len() - 1];
-                    str.starts_with(starts_with)
-                }
-                PatternType::StartOfPercent => {
-                    // fast path, can use ends_with
-                    str.ends_with(&pat[1..])
-                }
-
-                PatternType::SurroundByPercent => {
-                    if pat.len() > 2 {
-                        memmem::find(str, &pat[1..pat.len() - 1]).is_some()
-                    } else {
-                        // true for empty '%%' pattern, which follows pg/mysql way
-                        true
-                    }
-                }
+        vectorize_like(|str, pat, _, pattern_type| like_match(str, pat, pattern_type)),
+    );
 
-                PatternType::PatternStr => like(str, pat),
-            }
-        }),
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ilike",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, ctx| {
+            let lhs = fold_case_value(lhs);
+            let rhs = fold_case_value(rhs);
+            vectorize_like(|str, pat, _, pattern_type| like_match(str, pat, pattern_type))(
+                lhs.as_ref(),
+                rhs.as_ref(),
+                ctx,
+            )
+        },
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
@@ -530,6 +591,85 @@ fn register_like(registry: &mut FunctionRegistry) {
     );
 }
 
+fn like_match(str: &[u8], pat: &[u8], pattern_type: PatternType) -> bool {
+    match pattern_type {
+        PatternType::OrdinalStr => str == pat,
+        PatternType::EndOfPercent => {
+            // fast path, can use starts_with
+            let starts_with = &pat[..pat.len() - 1];
+            str.starts_with(starts_with)
+        }
+        PatternType::StartOfPercent => {
+            // fast path, can use ends_with
+            str.ends_with(&pat[1..])
+        }
+
+        PatternType::SurroundByPercent => {
+            if pat.len() > 2 {
+                memmem::find(str, &pat[1..pat.len() - 1]).is_some()
+            } else {
+                // true for empty '%%' pattern, which follows pg/mysql way
+                true
+            }
+        }
+
+        PatternType::PatternStr => like(str, pat),
+    }
+}
+
+/// The literal prefix of a like pattern, before its first wildcard.
+fn like_prefix(pattern: &[u8]) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(pattern.len());
+    let mut index = 0;
+    while index < pattern.len() {
+        match pattern[index] {
+            b'%' | b'_' => break,
+            b'\\' => {
+                if index + 1 < pattern.len() && is_like_pattern_escape(pattern[index + 1] as char) {
+                    prefix.push(pattern[index + 1]);
+                    index += 2;
+                } else {
+                    break;
+                }
+            }
+            c => {
+                prefix.push(c);
+                index += 1;
+            }
+        }
+    }
+    prefix
+}
+
+/// Fold the cases of the strings for ilike. The patterns keep their meaning, since the
+/// wildcards and the escape are not changed by case folding.
+fn fold_case_value(value: ValueRef<StringType>) -> Value<StringType> {
+    match value {
+        ValueRef::Scalar(val) => {
+            let mut output = Vec::with_capacity(val.len());
+            fold_case(val, &mut output);
+            Value::Scalar(output)
+        }
+        ValueRef::Column(col) => {
+            // The ascii letters keep their length, so the offsets can be reused.
+            if col.data.is_ascii() {
+                let data = col.data.to_ascii_lowercase().into();
+                return Value::Column(StringColumn {
+                    data,
+                    offsets: col.offsets.clone(),
+                });
+            }
+
+            let mut builder = StringColumnBuilder::with_capacity(col.len(), col.data.len());
+            for val in col.iter() {
+                fold_case(val, &mut builder.data);
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        }
+    }
+}
+
 fn vectorize_like(
     func: impl Fn(&[u8], &[u8], &mut EvalContext, PatternType) -> bool + Copy,
 ) -> impl Fn(ValueRef<StringType>, ValueRef<StringType>, &mut EvalContext) -> Value<BooleanType> + Copy
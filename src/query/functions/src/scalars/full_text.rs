@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Full-text search functions. The texts are split into terms by `tokenize`, which is also
+//! used to build the inverted index of the fuse blocks, so the index prunes exactly the
+//! blocks that have no rows matched by `match`.
+
+use bstr::ByteSlice;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::vectorize_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+use crate::scalars::unicode::fold_case;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _| FunctionDomain::Full,
+        vectorize_2_arg::<StringType, StringType, BooleanType>(|text, query, _| {
+            let query_terms = tokenize(query);
+            if query_terms.is_empty() {
+                return true;
+            }
+            let terms = tokenize(text);
+            query_terms.iter().all(|term| terms.contains(term))
+        }),
+    );
+}
+
+/// Splits the text into the terms of the full-text search, i.e. the case folded runs of
+/// alphanumeric chars, e.g. 'Disk-Full: /dev/sda1' to 'disk', 'full', 'dev' and 'sda1'.
+/// The other chars and the invalid UTF-8 bytes separate the terms.
+pub fn tokenize(text: &[u8]) -> Vec<Vec<u8>> {
+    let mut terms = vec![];
+    let mut start = None;
+    for (pos, _, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(pos);
+        } else if let Some(start) = start.take() {
+            terms.push(fold_term(&text[start..pos]));
+        }
+    }
+    if let Some(start) = start {
+        terms.push(fold_term(&text[start..]));
+    }
+    terms
+}
+
+fn fold_term(term: &[u8]) -> Vec<u8> {
+    let mut folded = Vec::with_capacity(term.len());
+    fold_case(term, &mut folded);
+    folded
+}
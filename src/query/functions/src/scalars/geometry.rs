@@ -0,0 +1,309 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::geometry::check_geometry;
+use common_expression::types::geometry::geometry_from_wkt;
+use common_expression::types::geometry::geometry_to_wkb;
+use common_expression::types::geometry::geometry_to_wkt;
+use common_expression::types::geometry::wkb_to_geometry;
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::GeometryType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use geo::Contains;
+use geo::Coord;
+use geo::Geometry;
+use geo::Intersects;
+use geo::Point;
+use geo::Polygon;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("st_geomfromtext", &["st_geometryfromtext"]);
+    registry.register_aliases("st_point", &["st_makepoint"]);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_| FunctionDomain::MayThrow,
+        eval_wkt_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_wkt_to_geometry),
+    );
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromtext",
+        |_| FunctionDomain::MayThrow,
+        eval_wkt_to_geometry,
+    );
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            if let Err(err) = check_geometry(val) {
+                ctx.set_error(output.len(), err);
+            } else {
+                output.put_slice(val);
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_astext",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkb",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
+        "st_point",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType>(
+            |x, y, output, _| {
+                let point = Geometry::Point(Point::new(x.0, y.0));
+                output.put_slice(&geometry_to_wkb(&point));
+                output.commit_row();
+            },
+        ),
+    );
+    registry.register_combine_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_x",
+        |_| FunctionDomain::MayThrow,
+        |val, ctx| eval_point_coord(val, ctx, |coord| coord.x),
+    );
+    registry.register_combine_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_y",
+        |_| FunctionDomain::MayThrow,
+        |val, ctx| eval_point_coord(val, ctx, |coord| coord.y),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match (wkb_to_geometry(a), wkb_to_geometry(b)) {
+                (Ok(a), Ok(b)) => output.push(a.contains(&b)),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match (wkb_to_geometry(a), wkb_to_geometry(b)) {
+                (Ok(a), Ok(b)) => output.push(b.contains(&a)),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, output, ctx| match (wkb_to_geometry(a), wkb_to_geometry(b)) {
+                (Ok(a), Ok(b)) => output.push(a.intersects(&b)),
+                (Err(err), _) | (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, NumberType<F64>>(
+                |a, b, output, ctx| match (wkb_to_geometry(a), wkb_to_geometry(b)) {
+                    (Ok(a), Ok(b)) => output.push(F64::from(euclidean_distance(&a, &b))),
+                    (Err(err), _) | (_, Err(err)) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(F64::from(0.0));
+                    }
+                },
+            ),
+        );
+}
+
+fn eval_wkt_to_geometry(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<GeometryType> {
+    vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+        match geometry_from_wkt(val) {
+            Ok(wkb) => output.put_slice(&wkb),
+            Err(err) => ctx.set_error(output.len(), err),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn eval_geometry_to_wkt(val: ValueRef<GeometryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+        match geometry_to_wkt(val) {
+            Ok(wkt) => output.put_str(&wkt),
+            Err(err) => ctx.set_error(output.len(), err),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+/// Returns the coordinate of a point, or NULL if the geometry is not a point.
+fn eval_point_coord(
+    val: ValueRef<GeometryType>,
+    ctx: &mut EvalContext,
+    f: fn(Coord<f64>) -> f64,
+) -> Value<NullableType<NumberType<F64>>> {
+    vectorize_with_builder_1_arg::<GeometryType, NullableType<NumberType<F64>>>(
+        |val, output, ctx| match wkb_to_geometry(val) {
+            Ok(Geometry::Point(p)) => output.push(F64::from(f(p.0))),
+            Ok(_) => output.push_null(),
+            Err(err) => {
+                ctx.set_error(output.len(), err);
+                output.push_null();
+            }
+        },
+    )(val, ctx)
+}
+
+/// The minimum cartesian distance between two geometries, 0 if they intersect.
+fn euclidean_distance(a: &Geometry<f64>, b: &Geometry<f64>) -> f64 {
+    if a.intersects(b) {
+        return 0.0;
+    }
+    let (a_points, a_segments) = flatten(a);
+    let (b_points, b_segments) = flatten(b);
+
+    let mut distance = f64::INFINITY;
+    for p in a_points.iter() {
+        for q in b_points.iter() {
+            distance = distance.min(point_distance(*p, *q));
+        }
+        for (s, e) in b_segments.iter() {
+            distance = distance.min(point_segment_distance(*p, *s, *e));
+        }
+    }
+    for (s, e) in a_segments.iter() {
+        for q in b_points.iter() {
+            distance = distance.min(point_segment_distance(*q, *s, *e));
+        }
+        // Segments don't intersect, so the closest pair includes an endpoint.
+        for (t, f) in b_segments.iter() {
+            distance = distance
+                .min(point_segment_distance(*s, *t, *f))
+                .min(point_segment_distance(*e, *t, *f))
+                .min(point_segment_distance(*t, *s, *e))
+                .min(point_segment_distance(*f, *s, *e));
+        }
+    }
+    distance
+}
+
+type Segment = (Coord<f64>, Coord<f64>);
+
+/// Split the geometry into isolated points and line segments.
+fn flatten(geometry: &Geometry<f64>) -> (Vec<Coord<f64>>, Vec<Segment>) {
+    fn line(coords: &[Coord<f64>], points: &mut Vec<Coord<f64>>, segments: &mut Vec<Segment>) {
+        match coords {
+            [] => {}
+            [p] => points.push(*p),
+            _ => segments.extend(coords.windows(2).map(|w| (w[0], w[1]))),
+        }
+    }
+    fn polygon(p: &Polygon<f64>, points: &mut Vec<Coord<f64>>, segments: &mut Vec<Segment>) {
+        line(&p.exterior().0, points, segments);
+        for ring in p.interiors() {
+            line(&ring.0, points, segments);
+        }
+    }
+    fn collect(
+        geometry: &Geometry<f64>,
+        points: &mut Vec<Coord<f64>>,
+        segments: &mut Vec<Segment>,
+    ) {
+        match geometry {
+            Geometry::Point(p) => points.push(p.0),
+            Geometry::Line(l) => segments.push((l.start, l.end)),
+            Geometry::LineString(l) => line(&l.0, points, segments),
+            Geometry::Polygon(p) => polygon(p, points, segments),
+            Geometry::MultiPoint(mp) => points.extend(mp.0.iter().map(|p| p.0)),
+            Geometry::MultiLineString(ml) => {
+                for l in ml.0.iter() {
+                    line(&l.0, points, segments);
+                }
+            }
+            Geometry::MultiPolygon(mp) => {
+                for p in mp.0.iter() {
+                    polygon(p, points, segments);
+                }
+            }
+            Geometry::GeometryCollection(gc) => {
+                for g in gc.0.iter() {
+                    collect(g, points, segments);
+                }
+            }
+            Geometry::Rect(r) => polygon(&r.to_polygon(), points, segments),
+            Geometry::Triangle(t) => polygon(&t.to_polygon(), points, segments),
+        }
+    }
+
+    let mut points = vec![];
+    let mut segments = vec![];
+    collect(geometry, &mut points, &mut segments);
+    (points, segments)
+}
+
+fn point_distance(p: Coord<f64>, q: Coord<f64>) -> f64 {
+    (p.x - q.x).hypot(p.y - q.y)
+}
+
+fn point_segment_distance(p: Coord<f64>, s: Coord<f64>, e: Coord<f64>) -> f64 {
+    let (dx, dy) = (e.x - s.x, e.y - s.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0.0 {
+        return point_distance(p, s);
+    }
+    let t = (((p.x - s.x) * dx + (p.y - s.y) * dy) / len2).clamp(0.0, 1.0);
+    point_distance(p, Coord {
+        x: s.x + t * dx,
+        y: s.y + t * dy,
+    })
+}
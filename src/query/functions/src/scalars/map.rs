@@ -22,6 +22,9 @@ use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::SimpleDomain;
+use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
@@ -108,4 +111,69 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+        "map_keys",
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(key_domain, _)| key_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>>(
+            |map, output, _| output.push(map.keys.clone())
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        |_| FunctionDomain::Full,
+        |_, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+        "map_values",
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(_, val_domain)| val_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>>(
+            |map, output, _| output.push(map.values.clone())
+        ),
+    );
+
+    registry.register_1_arg::<EmptyMapType, NumberType<u8>, _, _>(
+        "map_size",
+        |_| FunctionDomain::Domain(SimpleDomain { min: 0, max: 0 }),
+        |_, _| 0u8,
+    );
+
+    registry.register_1_arg::<MapType<GenericType<0>, GenericType<1>>, NumberType<u64>, _, _>(
+        "map_size",
+        |_| FunctionDomain::Full,
+        |map, _| map.len() as u64,
+    );
+
+    registry.register_passthrough_nullable_2_arg::<EmptyMapType, EmptyMapType, EmptyMapType, _, _>(
+        "map_cat",
+        |_, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    // The value of the second map wins if both maps have the same key.
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_cat",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>>(
+            |map1, map2, output, _| {
+                for (k, v) in map1.iter() {
+                    let v = map2.iter().find(|(k2, _)| *k2 == k).map(|(_, v2)| v2).unwrap_or(v);
+                    output.put_item((k, v));
+                }
+                for (k, v) in map2.iter() {
+                    if !map1.iter().any(|(k1, _)| k1 == k) {
+                        output.put_item((k, v));
+                    }
+                }
+                output.commit_row();
+            }
+        ),
+    );
 }
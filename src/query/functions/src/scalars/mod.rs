@@ -23,7 +23,9 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod full_text;
 mod geo;
+mod geometry;
 mod hash;
 mod map;
 mod math;
@@ -31,6 +33,8 @@ mod other;
 mod string;
 mod string_multi_args;
 mod tuple;
+mod unicode;
+mod uuid;
 mod variant;
 mod vector;
 
@@ -38,6 +42,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use full_text::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -58,4 +63,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    uuid::register(registry);
+    geometry::register(registry);
+    unicode::register(registry);
+    full_text::register(registry);
 }
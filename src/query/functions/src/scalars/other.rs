@@ -16,6 +16,8 @@ use std::io::Write;
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
@@ -26,9 +28,11 @@ use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -48,6 +52,7 @@ use common_expression::Column;
 use common_expression::Domain;
 use common_expression::EvalContext;
 use common_expression::Function;
+use common_expression::FunctionDoc;
 use common_expression::FunctionDomain;
 use common_expression::FunctionEval;
 use common_expression::FunctionProperty;
@@ -58,6 +63,8 @@ use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 use ordered_float::OrderedFloat;
+use rand::distributions::Alphanumeric;
+use rand::rngs::SmallRng;
 use rand::Rng;
 use rand::SeedableRng;
 
@@ -73,12 +80,48 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_inet_ntoa(registry);
     register_run_diff(registry);
     register_grouping(registry);
+    register_random(registry);
 
     registry.properties.insert(
         "rand".to_string(),
         FunctionProperty::default().non_deterministic(),
     );
 
+    registry
+        .docs
+        .insert("humanize_size".to_string(), FunctionDoc {
+            category: "Other",
+            description: "Formats a number of bytes as a human readable size, e.g. 1.00 KiB.",
+            syntax: "humanize_size(<expr>)",
+            example: "SELECT humanize_size(1024 * 1024)",
+        });
+    registry
+        .docs
+        .insert("humanize_number".to_string(), FunctionDoc {
+            category: "Other",
+            description: "Formats a number in human readable form, e.g. 1.00 thousand.",
+            syntax: "humanize_number(<expr>)",
+            example: "SELECT humanize_number(1000 * 1000)",
+        });
+    registry.docs.insert("sleep".to_string(), FunctionDoc {
+        category: "Other",
+        description: "Sleeps for the given seconds (at most 3) on each data block, returns 0.",
+        syntax: "sleep(<seconds>)",
+        example: "SELECT sleep(1)",
+    });
+    registry.docs.insert("rand".to_string(), FunctionDoc {
+        category: "Other",
+        description: "Returns a random number in [0, 1), or a deterministic one in [0, 1) for a seed.",
+        syntax: "rand([<seed>])",
+        example: "SELECT rand(), rand(1)",
+    });
+    registry.docs.insert("typeof".to_string(), FunctionDoc {
+        category: "Other",
+        description: "Returns the SQL name of the type of the argument.",
+        syntax: "typeof(<expr>)",
+        example: "SELECT typeof(1 + 1)",
+    });
+
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
         |_| FunctionDomain::Full,
@@ -238,6 +281,153 @@ pub fn register(registry: &mut FunctionRegistry) {
     );
 }
 
+/// Crockford's base32, used to encode ULIDs.
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+fn register_random(registry: &mut FunctionRegistry) {
+    for name in [
+        "uuid_v7",
+        "ulid",
+        "random_int",
+        "random_string",
+        "random_date",
+    ] {
+        registry.properties.insert(
+            name.to_string(),
+            FunctionProperty::default().non_deterministic(),
+        );
+    }
+
+    registry.register_0_arg_core::<StringType, _, _>(
+        "uuid_v7",
+        || FunctionDomain::Full,
+        |ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let mut builder = StringColumnBuilder::with_capacity(ctx.num_rows, ctx.num_rows * 36);
+            for _ in 0..ctx.num_rows {
+                let value = uuid_v7(unix_millis(), &mut rng);
+                write!(&mut builder.data, "{:x}", value).unwrap();
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        },
+    );
+
+    registry.register_0_arg_core::<StringType, _, _>(
+        "ulid",
+        || FunctionDomain::Full,
+        |ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let mut builder = StringColumnBuilder::with_capacity(ctx.num_rows, ctx.num_rows * 26);
+            for _ in 0..ctx.num_rows {
+                write_ulid(unix_millis(), &mut rng, &mut builder.data);
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        },
+    );
+
+    // The arguments are usually constants, so the values must be generated for
+    // `ctx.num_rows` rows instead of vectorizing on the arguments.
+    registry.register_2_arg_core::<Int64Type, Int64Type, Int64Type, _, _>(
+        "random_int",
+        |_, _| FunctionDomain::MayThrow,
+        |min, max, ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let mut values = Vec::with_capacity(ctx.num_rows);
+            for idx in 0..ctx.num_rows {
+                let (min, max) = unsafe { (min.index_unchecked(idx), max.index_unchecked(idx)) };
+                if min > max {
+                    ctx.set_error(
+                        idx,
+                        format!("random_int: min {min} is greater than max {max}"),
+                    );
+                    values.push(0);
+                } else {
+                    values.push(rng.gen_range(min..=max));
+                }
+            }
+            Value::Column(values.into())
+        },
+    );
+
+    registry.register_2_arg_core::<DateType, DateType, DateType, _, _>(
+        "random_date",
+        |_, _| FunctionDomain::MayThrow,
+        |min, max, ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let mut values = Vec::with_capacity(ctx.num_rows);
+            for idx in 0..ctx.num_rows {
+                let (min, max) = unsafe { (min.index_unchecked(idx), max.index_unchecked(idx)) };
+                if min > max {
+                    ctx.set_error(idx, "random_date: min date is later than max date");
+                    values.push(0);
+                } else {
+                    values.push(rng.gen_range(min..=max));
+                }
+            }
+            Value::Column(values.into())
+        },
+    );
+
+    registry.register_2_arg_core::<UInt64Type, UInt64Type, StringType, _, _>(
+        "random_string",
+        |_, _| FunctionDomain::MayThrow,
+        |min_len, max_len, ctx| {
+            let mut rng = SmallRng::from_entropy();
+            let mut builder = StringColumnBuilder::with_capacity(ctx.num_rows, 0);
+            for idx in 0..ctx.num_rows {
+                let (min_len, max_len) =
+                    unsafe { (min_len.index_unchecked(idx), max_len.index_unchecked(idx)) };
+                if min_len > max_len {
+                    ctx.set_error(
+                        idx,
+                        format!(
+                            "random_string: min length {} is greater than max length {}",
+                            min_len, max_len
+                        ),
+                    );
+                } else {
+                    let len = rng.gen_range(min_len..=max_len) as usize;
+                    let chars = (&mut rng).sample_iter(Alphanumeric).take(len);
+                    builder.data.extend(chars);
+                }
+                builder.commit_row();
+            }
+            Value::Column(builder.build())
+        },
+    );
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// UUID version 7: 48 bits of unix timestamp in milliseconds followed by random bits,
+/// so the values are sortable by generation time.
+fn uuid_v7(millis: u64, rng: &mut impl Rng) -> Uuid {
+    let mut bytes: [u8; 16] = rng.gen();
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    // version 7
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    // variant RFC 4122
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    Uuid::from_bytes(bytes)
+}
+
+/// ULID: 48 bits of unix timestamp in milliseconds and 80 random bits, encoded into
+/// 26 characters of Crockford's base32.
+fn write_ulid(millis: u64, rng: &mut impl Rng, output: &mut Vec<u8>) {
+    let random = rng.gen::<u128>() & ((1u128 << 80) - 1);
+    let value = ((millis as u128 & ((1u128 << 48) - 1)) << 80) | random;
+    for i in (0..26).rev() {
+        output.push(ULID_ALPHABET[((value >> (i * 5)) & 0x1F) as usize]);
+    }
+}
+
 fn register_inet_aton(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, UInt32Type, _, _>(
         "inet_aton",
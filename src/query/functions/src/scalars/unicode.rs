@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! String functions ignoring the differences of cases, accents and compatibility forms,
+//! with the Unicode algorithms of ICU4X. The invalid UTF-8 bytes are kept as they are.
+
+use bstr::ByteSlice;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use icu_casemap::CaseMapper;
+use icu_normalizer::ComposingNormalizer;
+use icu_normalizer::DecomposingNormalizer;
+use icu_properties::maps;
+use icu_properties::GeneralCategory;
+
+use crate::scalars::string::vectorize_string_to_string;
+
+const CASE_MAPPER: CaseMapper = CaseMapper::new();
+const NFC: ComposingNormalizer = ComposingNormalizer::new_nfc();
+const NFD: DecomposingNormalizer = DecomposingNormalizer::new_nfd();
+const NFKD: DecomposingNormalizer = DecomposingNormalizer::new_nfkd();
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "istarts_with",
+        |_, _| FunctionDomain::Full,
+        vectorize_folded_2_arg(fold_case, |str, prefix| str.starts_with(prefix)),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "normalized_equals",
+        |_, _| FunctionDomain::Full,
+        vectorize_folded_2_arg(fold_compatibility, |lhs, rhs| lhs == rhs),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "strip_accents",
+        |_| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data.len(),
+            |val, output, _| {
+                strip_accents(val, &mut output.data);
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+/// The full case folding, e.g. 'Straße' is folded to 'strasse'.
+pub(crate) fn fold_case(val: &[u8], output: &mut Vec<u8>) {
+    if val.is_ascii() {
+        output.extend(val.iter().map(|c| c.to_ascii_lowercase()));
+        return;
+    }
+    for chunk in val.utf8_chunks() {
+        output.extend_from_slice(CASE_MAPPER.fold_string(chunk.valid()).as_bytes());
+        output.extend_from_slice(chunk.invalid());
+    }
+}
+
+/// The compatibility caseless form of Unicode (D146), equal for the strings equal after
+/// NFKC normalization and case folding, e.g. 'ＡＢＣ' and 'abc'.
+fn fold_compatibility(val: &[u8], output: &mut Vec<u8>) {
+    if val.is_ascii() {
+        output.extend(val.iter().map(|c| c.to_ascii_lowercase()));
+        return;
+    }
+    for chunk in val.utf8_chunks() {
+        let folded = CASE_MAPPER.fold_string(&NFD.normalize(chunk.valid()));
+        let folded = CASE_MAPPER.fold_string(&NFKD.normalize(&folded));
+        output.extend_from_slice(NFKD.normalize(&folded).as_bytes());
+        output.extend_from_slice(chunk.invalid());
+    }
+}
+
+/// Removes the nonspacing marks after the canonical decomposition, e.g. 'Crème Brûlée' to
+/// 'Creme Brulee'.
+fn strip_accents(val: &[u8], output: &mut Vec<u8>) {
+    if val.is_ascii() {
+        output.extend_from_slice(val);
+        return;
+    }
+    let general_category = maps::general_category();
+    for chunk in val.utf8_chunks() {
+        let stripped: String = NFD
+            .normalize(chunk.valid())
+            .chars()
+            .filter(|c| general_category.get(*c) != GeneralCategory::NonspacingMark)
+            .collect();
+        output.extend_from_slice(NFC.normalize(&stripped).as_bytes());
+        output.extend_from_slice(chunk.invalid());
+    }
+}
+
+/// Compares the folded strings. A constant argument is folded only once, and the values of
+/// the columns are folded into a reused buffer.
+fn vectorize_folded_2_arg(
+    fold: fn(&[u8], &mut Vec<u8>),
+    cmp: fn(&[u8], &[u8]) -> bool,
+) -> impl Fn(ValueRef<StringType>, ValueRef<StringType>, &mut EvalContext) -> Value<BooleanType> + Copy
+{
+    move |lhs, rhs, _| {
+        let mut lhs_folded = vec![];
+        let mut rhs_folded = vec![];
+        match (lhs, rhs) {
+            (ValueRef::Scalar(lhs), ValueRef::Scalar(rhs)) => {
+                fold(lhs, &mut lhs_folded);
+                fold(rhs, &mut rhs_folded);
+                Value::Scalar(cmp(&lhs_folded, &rhs_folded))
+            }
+            (ValueRef::Column(lhs), ValueRef::Scalar(rhs)) => {
+                fold(rhs, &mut rhs_folded);
+                let mut builder = MutableBitmap::with_capacity(lhs.len());
+                for lhs in lhs.iter() {
+                    lhs_folded.clear();
+                    fold(lhs, &mut lhs_folded);
+                    builder.push(cmp(&lhs_folded, &rhs_folded));
+                }
+                Value::Column(builder.into())
+            }
+            (ValueRef::Scalar(lhs), ValueRef::Column(rhs)) => {
+                fold(lhs, &mut lhs_folded);
+                let mut builder = MutableBitmap::with_capacity(rhs.len());
+                for rhs in rhs.iter() {
+                    rhs_folded.clear();
+                    fold(rhs, &mut rhs_folded);
+                    builder.push(cmp(&lhs_folded, &rhs_folded));
+                }
+                Value::Column(builder.into())
+            }
+            (ValueRef::Column(lhs), ValueRef::Column(rhs)) => {
+                let mut builder = MutableBitmap::with_capacity(lhs.len());
+                for (lhs, rhs) in lhs.iter().zip(rhs.iter()) {
+                    lhs_folded.clear();
+                    rhs_folded.clear();
+                    fold(lhs, &mut lhs_folded);
+                    fold(rhs, &mut rhs_folded);
+                    builder.push(cmp(&lhs_folded, &rhs_folded));
+                }
+                Value::Column(builder.into())
+            }
+        }
+    }
+}
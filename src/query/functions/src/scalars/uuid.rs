@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::uuid::bytes_to_uuid;
+use common_expression::types::uuid::string_to_uuid;
+use common_expression::types::uuid::uuid_to_bytes;
+use common_expression::types::uuid::uuid_to_string;
+use common_expression::types::StringType;
+use common_expression::types::UuidType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDoc;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, UuidType, _, _>(
+        "to_uuid",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_uuid,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, UuidType, _, _>(
+        "try_to_uuid",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_uuid),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UuidType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UuidType, StringType>(|val, output, _| {
+            output.put_str(&uuid_to_string(val));
+            output.commit_row();
+        }),
+    );
+
+    // Binary form: the 16 raw bytes of the UUID, in network byte order.
+    registry.register_passthrough_nullable_1_arg::<UuidType, StringType, _, _>(
+        "uuid_to_bytes",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UuidType, StringType>(|val, output, _| {
+            output.put_slice(&uuid_to_bytes(val));
+            output.commit_row();
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<StringType, UuidType, _, _>(
+        "uuid_from_bytes",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, UuidType>(
+            |val, output, ctx| match bytes_to_uuid(val) {
+                Some(uuid) => output.push(uuid),
+                None => {
+                    ctx.set_error(output.len(), "UUID must be exactly 16 bytes");
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.docs.insert("to_uuid".to_string(), FunctionDoc {
+        category: "Conversion",
+        description: "Converts a string in the canonical 8-4-4-4-12 hexadecimal form to UUID.",
+        syntax: "to_uuid(<expr>)",
+        example: "SELECT to_uuid('a3bb189e-8bf9-3888-9912-ace4e6543002')",
+    });
+    registry.docs.insert("try_to_uuid".to_string(), FunctionDoc {
+        category: "Conversion",
+        description: "Converts a string to UUID, returns NULL if the string is not a valid UUID.",
+        syntax: "try_to_uuid(<expr>)",
+        example: "SELECT try_to_uuid('not a uuid')",
+    });
+    registry
+        .docs
+        .insert("uuid_to_bytes".to_string(), FunctionDoc {
+            category: "Conversion",
+            description: "Returns the 16 raw bytes of a UUID, in network byte order.",
+            syntax: "uuid_to_bytes(<expr>)",
+            example: "SELECT uuid_to_bytes(gen_random_uuid())",
+        });
+    registry
+        .docs
+        .insert("uuid_from_bytes".to_string(), FunctionDoc {
+            category: "Conversion",
+            description: "Builds a UUID from 16 raw bytes in network byte order.",
+            syntax: "uuid_from_bytes(<expr>)",
+            example: "SELECT uuid_from_bytes(uuid_to_bytes(gen_random_uuid()))",
+        });
+
+    fn eval_string_to_uuid(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<UuidType> {
+        vectorize_with_builder_1_arg::<StringType, UuidType>(
+            |val, output, ctx| match string_to_uuid(val) {
+                Some(uuid) => output.push(uuid),
+                None => {
+                    ctx.set_error(output.len(), "cannot parse to type `UUID`");
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+}
@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::variant::cast_scalar_to_variant;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::Function;
+use common_expression::FunctionDoc;
 use common_expression::FunctionEval;
 use common_expression::FunctionKind;
 use common_expression::FunctionProperty;
@@ -27,8 +34,13 @@ use common_expression::FunctionSignature;
 use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::Value;
+use jsonb::array_length;
+use jsonb::as_str;
+use jsonb::get_by_index;
+use jsonb::get_by_name;
 use jsonb::get_by_path;
 use jsonb::jsonpath::parse_json_path;
+use jsonb::object_keys;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.properties.insert(
@@ -116,6 +128,133 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    registry.properties.insert(
+        "flatten".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+
+    registry.docs.insert("flatten".to_string(), FunctionDoc {
+        category: "Semi-structured",
+        description: "Expands the elements of an array or the fields of an object into rows with the columns seq, key, path, index and value. If outer is true, a row of NULLs is returned for the input that has no elements.",
+        syntax: "flatten(<array_or_variant> [, <outer>])",
+        example: "SELECT * FROM flatten(input => parse_json('{\"a\": 1, \"b\": [2, 3]}'), outer => true)",
+    });
+
+    registry.register_function_factory("flatten", |_, args_type| {
+        if args_type.is_empty() || args_type.len() > 2 {
+            return None;
+        }
+        if !matches!(
+            args_type[0].remove_nullable(),
+            DataType::Null | DataType::Variant | DataType::EmptyArray | DataType::Array(_)
+        ) {
+            return None;
+        }
+        if args_type.len() == 2 && args_type[1].remove_nullable() != DataType::Boolean {
+            return None;
+        }
+
+        // The sequence number of the input rows, it's unique but not ordered
+        // if the input is evaluated by multiple threads.
+        let seq = Arc::new(AtomicU64::new(1));
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "flatten".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: flatten_return_type(),
+            },
+
+            eval: FunctionEval::SRF {
+                eval: Box::new(move |args, ctx| {
+                    let arg = args[0].clone().to_owned();
+                    let outer = args.get(1).map(|arg| arg.clone().to_owned());
+                    (0..ctx.num_rows)
+                        .map(|row| {
+                            let seq = seq.fetch_add(1, Ordering::Relaxed);
+                            let mut builder =
+                                ColumnBuilder::with_capacity(&flatten_return_type(), 0);
+                            match arg.index(row).unwrap() {
+                                ScalarRef::Variant(val) => flatten_variant(seq, val, &mut builder),
+                                ScalarRef::Null => (),
+                                scalar => {
+                                    let mut val = Vec::new();
+                                    cast_scalar_to_variant(scalar, ctx.func_ctx.tz, &mut val);
+                                    flatten_variant(seq, &val, &mut builder);
+                                }
+                            }
+                            let outer = outer
+                                .as_ref()
+                                .map(|outer| outer.index(row) == Some(ScalarRef::Boolean(true)))
+                                .unwrap_or(false);
+                            if outer && builder.len() == 0 {
+                                builder.push(ScalarRef::Tuple(vec![
+                                    ScalarRef::Number(NumberScalar::UInt64(seq)),
+                                    ScalarRef::Null,
+                                    ScalarRef::Null,
+                                    ScalarRef::Null,
+                                    ScalarRef::Null,
+                                ]));
+                            }
+                            let column = builder.build();
+                            let len = column.len();
+                            (Value::Column(column), len)
+                        })
+                        .collect()
+                }),
+            },
+        }))
+    });
+}
+
+/// Names of the columns returned by `flatten`, in the order of its tuple fields.
+pub const FLATTEN_COLUMNS: [&str; 5] = ["seq", "key", "path", "index", "value"];
+
+fn flatten_return_type() -> DataType {
+    DataType::Tuple(vec![
+        DataType::Number(NumberDataType::UInt64),
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Nullable(Box::new(DataType::String)),
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+        DataType::Nullable(Box::new(DataType::Variant)),
+    ])
+}
+
+/// Expands the elements of an array or the fields of an object into rows.
+/// Scalar values produce no rows.
+fn flatten_variant(seq: u64, val: &[u8], builder: &mut ColumnBuilder) {
+    if let Some(len) = array_length(val) {
+        for i in 0..len {
+            if let Some(elem) = get_by_index(val, i as i32) {
+                let path = format!("[{i}]");
+                builder.push(ScalarRef::Tuple(vec![
+                    ScalarRef::Number(NumberScalar::UInt64(seq)),
+                    ScalarRef::Null,
+                    ScalarRef::String(path.as_bytes()),
+                    ScalarRef::Number(NumberScalar::UInt64(i as u64)),
+                    ScalarRef::Variant(&elem),
+                ]));
+            }
+        }
+    } else if let Some(keys) = object_keys(val) {
+        let len = array_length(&keys).unwrap_or(0);
+        for i in 0..len {
+            let key = get_by_index(&keys, i as i32)
+                .and_then(|key| as_str(&key).map(|key| key.to_string()));
+            if let Some(key) = key {
+                if let Some(elem) = get_by_name(val, &key) {
+                    builder.push(ScalarRef::Tuple(vec![
+                        ScalarRef::Number(NumberScalar::UInt64(seq)),
+                        ScalarRef::String(key.as_bytes()),
+                        ScalarRef::String(key.as_bytes()),
+                        ScalarRef::Null,
+                        ScalarRef::Variant(&elem),
+                    ]));
+                }
+            }
+        }
+    }
 }
 
 fn build_unnest(
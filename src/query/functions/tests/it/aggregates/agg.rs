@@ -14,15 +14,29 @@
 
 use std::io::Write;
 
+use bumpalo::Bump;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::BitmapType;
 use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
 use common_expression::FromData;
+use common_expression::ScalarRef;
 use common_functions::aggregates::eval_aggr;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateStateMergeAdaptor;
+use common_functions::aggregates::StateAddr;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
 use goldenfile::Mint;
 use roaring::RoaringTreemap;
 
@@ -94,6 +108,136 @@ fn test_agg_group_by() {
     test_agg_bitmap_count(file, simulate_two_groups_group_by);
 }
 
+#[test]
+fn test_decimal_sum_state_compatibility() {
+    let size = DecimalSize {
+        precision: 10,
+        scale: 2,
+    };
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory
+        .get("sum", vec![], vec![DataType::Decimal(
+            DecimalDataType::Decimal128(size),
+        )])
+        .unwrap();
+
+    // The state of a decimal sum was serialized as the decimal value itself.
+    let mut old_state = vec![];
+    serialize_into_buf(&mut old_state, &12345i128).unwrap();
+
+    let arena = Bump::new();
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    func.deserialize(addr.into(), &mut old_state.as_slice())
+        .unwrap();
+
+    let mut builder = ColumnBuilder::with_capacity(&func.return_type().unwrap(), 1);
+    func.merge_result(addr.into(), &mut builder).unwrap();
+    let column = builder.build();
+    assert!(matches!(
+        column.index(0),
+        Some(ScalarRef::Decimal(DecimalScalar::Decimal128(12345, _)))
+    ));
+
+    // The state is still serialized in the same format.
+    let mut new_state = vec![];
+    func.serialize(addr.into(), &mut new_state).unwrap();
+    assert_eq!(new_state, old_state);
+    let value: i128 = deserialize_from_slice(&mut new_state.as_slice()).unwrap();
+    assert_eq!(value, 12345);
+}
+
+#[test]
+fn test_agg_state_merge_adaptor() {
+    let factory = AggregateFunctionFactory::instance();
+    let column = Int64Type::from_data(vec![4i64, 3, 2, 1, 7, 9]);
+    let arena = Bump::new();
+
+    for name in ["sum", "count", "max"] {
+        let func = factory
+            .get(name, vec![], vec![DataType::Number(NumberDataType::Int64)])
+            .unwrap();
+        let return_type = func.return_type().unwrap();
+
+        // The result over all rows.
+        let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+        func.init_state(place);
+        func.accumulate(place, &[column.clone()], None, column.len())
+            .unwrap();
+        let mut builder = ColumnBuilder::with_capacity(&return_type, 1);
+        func.merge_result(place, &mut builder).unwrap();
+        let expected = builder.build();
+
+        // The serialized states of the chunks of rows, e.g. the data of an aggregating index.
+        let states = [0..2, 2..3, 3..6]
+            .into_iter()
+            .map(|range| {
+                let chunk = column.slice(range.clone());
+                let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                func.init_state(place);
+                func.accumulate(place, &[chunk], None, range.len()).unwrap();
+                let mut state = vec![];
+                func.serialize(place, &mut state).unwrap();
+                state
+            })
+            .collect::<Vec<_>>();
+        let states = StringType::from_data(states);
+
+        let adaptor = AggregateStateMergeAdaptor::create(func.clone()).unwrap();
+        assert_eq!(adaptor.return_type().unwrap(), return_type);
+
+        // accumulate
+        let place: StateAddr = arena.alloc_layout(adaptor.state_layout()).into();
+        adaptor.init_state(place);
+        adaptor
+            .accumulate(place, &[states.clone()], None, states.len())
+            .unwrap();
+        let mut builder = ColumnBuilder::with_capacity(&return_type, 1);
+        adaptor.merge_result(place, &mut builder).unwrap();
+        assert_eq!(
+            format!("{:?}", builder.build()),
+            format!("{:?}", expected),
+            "{name}"
+        );
+
+        // accumulate_keys, all the states belong to the same group
+        let place: StateAddr = arena.alloc_layout(adaptor.state_layout()).into();
+        adaptor.init_state(place);
+        let places = vec![place; states.len()];
+        adaptor
+            .accumulate_keys(&places, 0, &[states.clone()], states.len())
+            .unwrap();
+        let mut builder = ColumnBuilder::with_capacity(&return_type, 1);
+        adaptor.merge_result(place, &mut builder).unwrap();
+        assert_eq!(
+            format!("{:?}", builder.build()),
+            format!("{:?}", expected),
+            "{name}"
+        );
+
+        // the states of invalid rows are skipped
+        let place: StateAddr = arena.alloc_layout(adaptor.state_layout()).into();
+        adaptor.init_state(place);
+        let validity = Bitmap::from([true, false, false]);
+        adaptor
+            .accumulate(place, &[states.clone()], Some(&validity), states.len())
+            .unwrap();
+        let mut builder = ColumnBuilder::with_capacity(&return_type, 1);
+        adaptor.merge_result(place, &mut builder).unwrap();
+        let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+        func.init_state(place);
+        func.accumulate(place, &[column.slice(0..2)], None, 2)
+            .unwrap();
+        let mut expected_builder = ColumnBuilder::with_capacity(&return_type, 1);
+        func.merge_result(place, &mut expected_builder).unwrap();
+        assert_eq!(
+            format!("{:?}", builder.build()),
+            format!("{:?}", expected_builder.build()),
+            "{name}"
+        );
+    }
+}
+
 fn gen_bitmap_data() -> Column {
     // construct bitmap column with 4 row:
     // 0..5, 1..6, 2..7, 3..8
@@ -170,6 +170,9 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
             BinaryOperator::NotLike => {
                 unimplemented!("please use `not (a like b)` instead")
             }
+            BinaryOperator::NotILike => {
+                unimplemented!("please use `not (a ilike b)` instead")
+            }
             BinaryOperator::NotRLike | BinaryOperator::NotRegexp => {
                 unimplemented!("please use `not (a regexp b)` instead")
             }
@@ -524,6 +527,8 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             DataType::Nullable(Box::new(transform_data_type(*inner_type)))
         }
         common_ast::ast::TypeName::Variant => DataType::Variant,
+        common_ast::ast::TypeName::Uuid => DataType::Uuid,
+        common_ast::ast::TypeName::Geometry => DataType::Geometry,
     }
 }
 
@@ -16,11 +16,13 @@
 
 mod cluster;
 mod file_format;
+mod plan_baseline;
 mod quota;
 mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
 
@@ -28,6 +30,8 @@ pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
+pub use plan_baseline::PlanBaselineApi;
+pub use plan_baseline::PlanBaselineMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -38,6 +42,8 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
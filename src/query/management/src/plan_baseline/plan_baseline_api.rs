@@ -0,0 +1,31 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+use common_meta_types::MatchSeq;
+
+#[async_trait::async_trait]
+pub trait PlanBaselineApi: Sync + Send {
+    // Add or replace a plan baseline to /tenant/baseline-id.
+    async fn upsert_plan_baseline(&self, baseline: PlanBaseline) -> Result<u64>;
+
+    // Get plan baseline by id, [None] if the query has no baseline.
+    async fn get_plan_baseline(&self, id: &str) -> Result<Option<PlanBaseline>>;
+
+    // Get all the plan baselines for a tenant.
+    async fn get_plan_baselines(&self) -> Result<Vec<PlanBaseline>>;
+
+    // Drop the tenant's plan baseline by id.
+    async fn drop_plan_baseline(&self, id: &str, seq: MatchSeq) -> Result<()>;
+}
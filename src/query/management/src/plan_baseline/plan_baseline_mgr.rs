@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::plan_baseline::PlanBaselineApi;
+
+static PLAN_BASELINE_API_KEY_PREFIX: &str = "__fd_plan_baselines";
+
+pub struct PlanBaselineMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    plan_baseline_prefix: String,
+}
+
+impl PlanBaselineMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while plan baseline mgr create)",
+            ));
+        }
+
+        Ok(PlanBaselineMgr {
+            kv_api,
+            plan_baseline_prefix: format!(
+                "{}/{}",
+                PLAN_BASELINE_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanBaselineApi for PlanBaselineMgr {
+    #[async_backtrace::framed]
+    async fn upsert_plan_baseline(&self, info: PlanBaseline) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!(
+            "{}/{}",
+            self.plan_baseline_prefix,
+            escape_for_key(&info.id)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownPlanBaseline(format!(
+                "Unknown Plan Baseline {}",
+                info.id
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_plan_baseline(&self, id: &str) -> Result<Option<PlanBaseline>> {
+        let key = format!("{}/{}", self.plan_baseline_prefix, escape_for_key(id)?);
+        match self.kv_api.get_kv(&key).await? {
+            Some(seq_value) => Ok(Some(PlanBaseline::try_from(seq_value.data)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_plan_baselines(&self) -> Result<Vec<PlanBaseline>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.plan_baseline_prefix)
+            .await?;
+
+        let mut baselines = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let baseline = serde_json::from_slice::<PlanBaseline>(&value.data)?;
+            baselines.push(baseline);
+        }
+        Ok(baselines)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_plan_baseline(&self, id: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.plan_baseline_prefix, escape_for_key(id)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownPlanBaseline(format!(
+                "Unknown Plan Baseline {}",
+                id
+            )))
+        }
+    }
+}
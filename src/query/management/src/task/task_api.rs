@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_meta_app::principal::Task;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    // Add a task to /tenant/task-name.
+    async fn add_task(&self, task: Task) -> Result<u64>;
+
+    // Update a task to /tenant/task-name.
+    async fn update_task(&self, task: Task, seq: MatchSeq) -> Result<u64>;
+
+    // Get task by name.
+    async fn get_task(&self, task_name: &str, seq: MatchSeq) -> Result<SeqV<Task>>;
+
+    // Get all the tasks for a tenant.
+    async fn get_tasks(&self) -> Result<Vec<Task>>;
+
+    // Drop the tenant's task by name.
+    async fn drop_task(&self, task_name: &str, seq: MatchSeq) -> Result<()>;
+}
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_profile::ProfCounters;
 
 use crate::processors::UpdateTrigger;
 use crate::unsafe_cell_wrap::UnSafeCellWrap;
@@ -123,6 +125,8 @@ impl SharedStatus {
 pub struct InputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    /// The number of rows pulled from the port.
+    pulled_rows: AtomicU64,
 }
 
 impl InputPort {
@@ -130,6 +134,7 @@ impl InputPort {
         Arc::new(InputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            pulled_rows: AtomicU64::new(0),
         })
     }
 
@@ -181,11 +186,23 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    let data = (*Box::from_raw(address)).0;
+                    if let Ok(block) = &data {
+                        self.pulled_rows
+                            .fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+                    }
+                    Some(data)
+                }
             }
         }
     }
 
+    #[inline(always)]
+    pub fn pulled_rows(&self) -> u64 {
+        self.pulled_rows.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
@@ -204,6 +221,8 @@ impl InputPort {
 pub struct OutputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    /// The number of rows pushed to the port.
+    pushed_rows: AtomicU64,
 }
 
 impl OutputPort {
@@ -211,6 +230,7 @@ impl OutputPort {
         Arc::new(OutputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            pushed_rows: AtomicU64::new(0),
         })
     }
 
@@ -219,6 +239,12 @@ impl OutputPort {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
+            if let Ok(block) = &data {
+                ProfCounters::record_output_rows(block.num_rows());
+                self.pushed_rows
+                    .fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+            }
+
             let data = Box::into_raw(Box::new(SharedData(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
@@ -246,6 +272,11 @@ impl OutputPort {
         ((flags & NEED_DATA) == NEED_DATA) && ((flags & HAS_DATA) == 0)
     }
 
+    #[inline(always)]
+    pub fn pushed_rows(&self) -> u64 {
+        self.pushed_rows.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
@@ -0,0 +1,768 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads the [object container files](https://avro.apache.org/docs/1.11.1/specification/#object-container-files)
+//! of Avro. The records are decoded into JSON values, loaded into the columns by the same
+//! decoder as NDJSON.
+
+use std::collections::HashMap;
+
+use common_compress::CompressAlgorithm;
+use common_compress::DecompressDecoder;
+use common_compress::DecompressState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+const MAGIC: &[u8] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+
+#[derive(Clone, Debug)]
+pub enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<(String, AvroSchema)>),
+    Enum(Vec<String>),
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Fixed(usize),
+    // Logical types.
+    Decimal {
+        precision: u8,
+        scale: u8,
+        fixed_size: Option<usize>,
+    },
+    Uuid,
+    Date,
+    TimeMillis,
+    TimeMicros,
+    TimestampMillis,
+    TimestampMicros,
+}
+
+impl AvroSchema {
+    pub fn parse(schema: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(schema)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid Avro schema: {e}")))?;
+        SchemaParser::default().parse(&value, None)
+    }
+
+    pub fn to_table_type(&self) -> TableDataType {
+        match self {
+            AvroSchema::Null => TableDataType::Null,
+            AvroSchema::Boolean => TableDataType::Boolean,
+            AvroSchema::Int | AvroSchema::TimeMillis => {
+                TableDataType::Number(NumberDataType::Int32)
+            }
+            AvroSchema::Long | AvroSchema::TimeMicros => {
+                TableDataType::Number(NumberDataType::Int64)
+            }
+            AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+            AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+            AvroSchema::Bytes | AvroSchema::String | AvroSchema::Enum(_) | AvroSchema::Fixed(_) => {
+                TableDataType::String
+            }
+            AvroSchema::Record(fields) => TableDataType::Tuple {
+                fields_name: fields.iter().map(|(name, _)| name.clone()).collect(),
+                fields_type: fields.iter().map(|(_, f)| f.to_table_type()).collect(),
+            },
+            AvroSchema::Array(item) => TableDataType::Array(Box::new(item.to_table_type())),
+            AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![TableDataType::String, value.to_table_type()],
+            })),
+            AvroSchema::Union(branches) => {
+                let nullable = branches.iter().any(|b| matches!(b, AvroSchema::Null));
+                let non_null = branches
+                    .iter()
+                    .filter(|b| !matches!(b, AvroSchema::Null))
+                    .collect::<Vec<_>>();
+                let data_type = match non_null.as_slice() {
+                    [] => return TableDataType::Null,
+                    [branch] => branch.to_table_type(),
+                    // A value of one of several types is loaded as a variant.
+                    _ => TableDataType::Variant,
+                };
+                if nullable {
+                    data_type.wrap_nullable()
+                } else {
+                    data_type
+                }
+            }
+            AvroSchema::Decimal {
+                precision, scale, ..
+            } => match DecimalDataType::from_size(DecimalSize {
+                precision: *precision,
+                scale: *scale,
+            }) {
+                Ok(decimal) => TableDataType::Decimal(decimal),
+                // Too large for the decimal types, kept as a string.
+                Err(_) => TableDataType::String,
+            },
+            AvroSchema::Uuid => TableDataType::Uuid,
+            AvroSchema::Date => TableDataType::Date,
+            AvroSchema::TimestampMillis | AvroSchema::TimestampMicros => TableDataType::Timestamp,
+        }
+    }
+
+    /// The table schema of the records, the schema of an Avro file to load must be a record.
+    pub fn to_table_schema(&self) -> Result<TableSchema> {
+        match self {
+            AvroSchema::Record(fields) => Ok(TableSchema::new(
+                fields
+                    .iter()
+                    .map(|(name, f)| TableField::new(name, f.to_table_type()))
+                    .collect(),
+            )),
+            _ => Err(ErrorCode::BadBytes(
+                "the schema of Avro file must be a record",
+            )),
+        }
+    }
+}
+
+#[derive(Default)]
+struct SchemaParser {
+    // The named types (records, enums and fixed) defined so far, by full name and by name.
+    named: HashMap<String, AvroSchema>,
+}
+
+impl SchemaParser {
+    fn parse(&mut self, value: &Value, namespace: Option<&str>) -> Result<AvroSchema> {
+        match value {
+            Value::String(name) => self.parse_name(name, namespace),
+            Value::Array(branches) => Ok(AvroSchema::Union(
+                branches
+                    .iter()
+                    .map(|b| self.parse(b, namespace))
+                    .collect::<Result<_>>()?,
+            )),
+            Value::Object(object) => self.parse_object(object, namespace),
+            _ => Err(schema_error(format!("unexpected type {value}"))),
+        }
+    }
+
+    fn parse_name(&self, name: &str, namespace: Option<&str>) -> Result<AvroSchema> {
+        Ok(match name {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            _ => match self
+                .named
+                .get(&full_name(name, namespace))
+                .or_else(|| self.named.get(name))
+            {
+                Some(schema) => schema.clone(),
+                None => {
+                    return Err(schema_error(format!(
+                        "unknown type {name}, note that recursive types are not supported"
+                    )));
+                }
+            },
+        })
+    }
+
+    fn parse_object(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<AvroSchema> {
+        let type_name = match object.get("type") {
+            Some(Value::String(type_name)) => type_name.as_str(),
+            // Like `{"type": {"type": "array", "items": "int"}}`.
+            Some(other) => return self.parse(other, namespace),
+            None => return Err(schema_error("missing type".to_string())),
+        };
+
+        let schema = match type_name {
+            "record" | "error" => {
+                let (name, namespace) = type_name_of(object, namespace)?;
+                let fields = object
+                    .get("fields")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| schema_error(format!("missing fields of record {name}")))?;
+                let mut parsed = Vec::with_capacity(fields.len());
+                for field in fields {
+                    let field_name = field
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| schema_error(format!("missing field name in {name}")))?;
+                    let field_type = field.get("type").ok_or_else(|| {
+                        schema_error(format!("missing type of field {field_name}"))
+                    })?;
+                    parsed.push((
+                        field_name.to_string(),
+                        self.parse(field_type, namespace.as_deref())?,
+                    ));
+                }
+                let schema = AvroSchema::Record(parsed);
+                self.register(&name, namespace.as_deref(), &schema);
+                return Ok(schema);
+            }
+            "enum" => {
+                let (name, namespace) = type_name_of(object, namespace)?;
+                let symbols = object
+                    .get("symbols")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| schema_error(format!("missing symbols of enum {name}")))?
+                    .iter()
+                    .map(|s| s.as_str().map(|s| s.to_string()))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| schema_error(format!("invalid symbols of enum {name}")))?;
+                let schema = AvroSchema::Enum(symbols);
+                self.register(&name, namespace.as_deref(), &schema);
+                return Ok(schema);
+            }
+            "fixed" => {
+                let (name, namespace) = type_name_of(object, namespace)?;
+                let size = object
+                    .get("size")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| schema_error(format!("missing size of fixed {name}")))?;
+                let schema = with_logical_type(object, AvroSchema::Fixed(size as usize));
+                self.register(&name, namespace.as_deref(), &schema);
+                return Ok(schema);
+            }
+            "array" => {
+                let items = object
+                    .get("items")
+                    .ok_or_else(|| schema_error("missing items of array".to_string()))?;
+                AvroSchema::Array(Box::new(self.parse(items, namespace)?))
+            }
+            "map" => {
+                let values = object
+                    .get("values")
+                    .ok_or_else(|| schema_error("missing values of map".to_string()))?;
+                AvroSchema::Map(Box::new(self.parse(values, namespace)?))
+            }
+            primitive => self.parse_name(primitive, namespace)?,
+        };
+        Ok(with_logical_type(object, schema))
+    }
+
+    fn register(&mut self, name: &str, namespace: Option<&str>, schema: &AvroSchema) {
+        self.named
+            .insert(full_name(name, namespace), schema.clone());
+        self.named.insert(name.to_string(), schema.clone());
+    }
+}
+
+/// Returns the name of a named type and the namespace of the types defined inside it.
+fn type_name_of(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+) -> Result<(String, Option<String>)> {
+    let name = object
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| schema_error("missing name of named type".to_string()))?;
+    Ok(match name.rsplit_once('.') {
+        Some((namespace, name)) => (name.to_string(), Some(namespace.to_string())),
+        None => {
+            let namespace = object
+                .get("namespace")
+                .and_then(Value::as_str)
+                .or(namespace)
+                .filter(|namespace| !namespace.is_empty());
+            (name.to_string(), namespace.map(|n| n.to_string()))
+        }
+    })
+}
+
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') => format!("{namespace}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+/// Logical types which are not known or not valid are ignored, as the specification requires.
+fn with_logical_type(object: &Map<String, Value>, schema: AvroSchema) -> AvroSchema {
+    let logical_type = object.get("logicalType").and_then(Value::as_str);
+    match (logical_type, &schema) {
+        (Some("decimal"), AvroSchema::Bytes | AvroSchema::Fixed(_)) => {
+            let precision = object.get("precision").and_then(Value::as_u64);
+            let scale = object.get("scale").and_then(Value::as_u64).unwrap_or(0);
+            match precision {
+                Some(precision)
+                    if precision >= 1 && precision <= u8::MAX as u64 && scale <= precision =>
+                {
+                    AvroSchema::Decimal {
+                        precision: precision as u8,
+                        scale: scale as u8,
+                        fixed_size: match schema {
+                            AvroSchema::Fixed(size) => Some(size),
+                            _ => None,
+                        },
+                    }
+                }
+                _ => schema,
+            }
+        }
+        (Some("uuid"), AvroSchema::String) => AvroSchema::Uuid,
+        (Some("date"), AvroSchema::Int) => AvroSchema::Date,
+        (Some("time-millis"), AvroSchema::Int) => AvroSchema::TimeMillis,
+        (Some("time-micros"), AvroSchema::Long) => AvroSchema::TimeMicros,
+        (Some("timestamp-millis" | "local-timestamp-millis"), AvroSchema::Long) => {
+            AvroSchema::TimestampMillis
+        }
+        (Some("timestamp-micros" | "local-timestamp-micros"), AvroSchema::Long) => {
+            AvroSchema::TimestampMicros
+        }
+        _ => schema,
+    }
+}
+
+fn schema_error(msg: String) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid Avro schema: {msg}"))
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Codec {
+    Null,
+    Snappy,
+    Compressed(CompressAlgorithm),
+}
+
+impl Codec {
+    fn from_name(name: &str) -> Result<Self> {
+        Ok(match name {
+            "null" => Codec::Null,
+            "snappy" => Codec::Snappy,
+            "deflate" => Codec::Compressed(CompressAlgorithm::Deflate),
+            "zstandard" => Codec::Compressed(CompressAlgorithm::Zstd),
+            "bzip2" => Codec::Compressed(CompressAlgorithm::Bz2),
+            "xz" => Codec::Compressed(CompressAlgorithm::Xz),
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported Avro codec {name}"
+                )));
+            }
+        })
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Codec::Null => Ok(data.to_vec()),
+            // The compressed data is followed by the CRC32 checksum of the uncompressed data.
+            Codec::Snappy => {
+                let len = data.len().checked_sub(4).ok_or_else(|| {
+                    ErrorCode::BadBytes("invalid Avro block compressed by snappy")
+                })?;
+                snap::raw::Decoder::new()
+                    .decompress_vec(&data[..len])
+                    .map_err(|e| {
+                        ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                    })
+            }
+            Codec::Compressed(algo) => decompress(*algo, data),
+        }
+    }
+}
+
+/// Decompresses the whole data with a streaming decoder.
+pub(crate) fn decompress(algo: CompressAlgorithm, data: &[u8]) -> Result<Vec<u8>> {
+    let mut decoder = DecompressDecoder::new(algo);
+    let mut output = vec![];
+    let mut amt = 0;
+    let mut buf = vec![0u8; 4096];
+    loop {
+        match decoder.state() {
+            // Filling nothing after the whole data tells the decoder the end of the input.
+            DecompressState::Reading => amt += decoder.fill(&data[amt..]),
+            DecompressState::Decoding => {
+                let written = decoder.decode(&mut buf).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                output.extend_from_slice(&buf[..written]);
+            }
+            DecompressState::Flushing => {
+                let written = decoder.finish(&mut buf).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                output.extend_from_slice(&buf[..written]);
+            }
+            DecompressState::Done => break,
+        }
+    }
+    Ok(output)
+}
+
+/// An Avro object container file held in memory.
+pub struct AvroFile<'a> {
+    schema: AvroSchema,
+    codec: Codec,
+    sync: &'a [u8],
+    reader: Reader<'a>,
+    binary_as_hex: bool,
+}
+
+impl<'a> AvroFile<'a> {
+    pub fn try_create(data: &'a [u8]) -> Result<Self> {
+        let mut reader = Reader {
+            data,
+            pos: 0,
+            binary_as_hex: false,
+        };
+        if reader.read_fixed(MAGIC.len())? != MAGIC {
+            return Err(ErrorCode::BadBytes("not an Avro object container file"));
+        }
+
+        // The file metadata is a map of bytes.
+        let mut metadata = HashMap::new();
+        loop {
+            let count = reader.read_block_count()?;
+            if count == 0 {
+                break;
+            }
+            for _ in 0..count {
+                let key = reader.read_string()?;
+                let value = reader.read_bytes()?;
+                metadata.insert(key, value);
+            }
+        }
+        let schema = match metadata.get("avro.schema") {
+            Some(schema) => AvroSchema::parse(&String::from_utf8_lossy(schema))?,
+            None => return Err(ErrorCode::BadBytes("missing schema in Avro file")),
+        };
+        let codec = match metadata.get("avro.codec") {
+            Some(codec) => Codec::from_name(&String::from_utf8_lossy(codec))?,
+            None => Codec::Null,
+        };
+        let sync = reader.read_fixed(SYNC_SIZE)?;
+
+        Ok(Self {
+            schema,
+            codec,
+            sync,
+            reader,
+            binary_as_hex: false,
+        })
+    }
+
+    /// Decodes the values of bytes and fixed as lowercase hex strings instead of UTF-8 strings,
+    /// for the files holding binary values, like the manifests of Iceberg.
+    pub fn with_binary_as_hex(mut self) -> Self {
+        self.binary_as_hex = true;
+        self
+    }
+
+    pub fn schema(&self) -> &AvroSchema {
+        &self.schema
+    }
+
+    /// Returns the records of the next block, or [None] at the end of the file.
+    pub fn next_block(&mut self) -> Result<Option<AvroBlock>> {
+        if self.reader.is_eof() {
+            return Ok(None);
+        }
+        let count = self.reader.read_len()?;
+        let size = self.reader.read_len()?;
+        let data = self.reader.read_fixed(size)?;
+        if self.reader.read_fixed(SYNC_SIZE)? != self.sync {
+            return Err(ErrorCode::BadBytes(
+                "invalid sync marker of Avro block, the file may be corrupted",
+            ));
+        }
+        Ok(Some(AvroBlock {
+            data: self.codec.decompress(data)?,
+            pos: 0,
+            remaining: count,
+            binary_as_hex: self.binary_as_hex,
+        }))
+    }
+}
+
+/// The decompressed records of a block.
+pub struct AvroBlock {
+    data: Vec<u8>,
+    pos: usize,
+    remaining: usize,
+    binary_as_hex: bool,
+}
+
+impl AvroBlock {
+    pub fn next_record(&mut self, schema: &AvroSchema) -> Result<Option<Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let mut reader = Reader {
+            data: &self.data,
+            pos: self.pos,
+            binary_as_hex: self.binary_as_hex,
+        };
+        let value = reader.read_value(schema)?;
+        self.pos = reader.pos;
+        self.remaining -= 1;
+        Ok(Some(value))
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    binary_as_hex: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn is_eof(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn read_fixed(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() - self.pos < len {
+            return Err(ErrorCode::BadBytes("unexpected end of Avro file"));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Longs are zig-zag encoded variable-length integers.
+    fn read_long(&mut self) -> Result<i64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_fixed(1)?[0];
+            if shift > 63 {
+                return Err(ErrorCode::BadBytes(
+                    "invalid variable-length integer in Avro file",
+                ));
+            }
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn read_int(&mut self) -> Result<i32> {
+        i32::try_from(self.read_long()?)
+            .map_err(|_| ErrorCode::BadBytes("invalid int in Avro file"))
+    }
+
+    fn read_len(&mut self) -> Result<usize> {
+        usize::try_from(self.read_long()?)
+            .map_err(|_| ErrorCode::BadBytes("invalid length in Avro file"))
+    }
+
+    /// The items of arrays and maps are written in blocks, a negative count is followed by
+    /// the size of the block in bytes.
+    fn read_block_count(&mut self) -> Result<usize> {
+        let count = self.read_long()?;
+        if count < 0 {
+            self.read_long()?;
+        }
+        Ok(count.unsigned_abs() as usize)
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.read_len()?;
+        self.read_fixed(len)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        to_utf8(self.read_bytes()?)
+    }
+
+    fn binary_to_string(&self, bytes: &[u8]) -> Result<String> {
+        if self.binary_as_hex {
+            Ok(bytes.iter().map(|b| format!("{b:02x}")).collect())
+        } else {
+            to_utf8(bytes)
+        }
+    }
+
+    fn read_value(&mut self, schema: &AvroSchema) -> Result<Value> {
+        Ok(match schema {
+            AvroSchema::Null => Value::Null,
+            AvroSchema::Boolean => Value::Bool(self.read_fixed(1)?[0] != 0),
+            AvroSchema::Int | AvroSchema::Date | AvroSchema::TimeMillis => {
+                Value::from(self.read_int()?)
+            }
+            AvroSchema::Long | AvroSchema::TimeMicros | AvroSchema::TimestampMicros => {
+                Value::from(self.read_long()?)
+            }
+            AvroSchema::TimestampMillis => {
+                let millis = self.read_long()?;
+                let micros = millis
+                    .checked_mul(1000)
+                    .ok_or_else(|| ErrorCode::BadBytes("timestamp out of range"))?;
+                Value::from(micros)
+            }
+            AvroSchema::Float => {
+                let bytes = self.read_fixed(4)?;
+                let v = f32::from_le_bytes(bytes.try_into().unwrap());
+                float_value(v as f64)
+            }
+            AvroSchema::Double => {
+                let bytes = self.read_fixed(8)?;
+                float_value(f64::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            AvroSchema::Bytes => {
+                let bytes = self.read_bytes()?;
+                Value::String(self.binary_to_string(bytes)?)
+            }
+            AvroSchema::Fixed(size) => {
+                let bytes = self.read_fixed(*size)?;
+                Value::String(self.binary_to_string(bytes)?)
+            }
+            AvroSchema::String | AvroSchema::Uuid => Value::String(self.read_string()?),
+            AvroSchema::Enum(symbols) => {
+                let index = self.read_len()?;
+                match symbols.get(index) {
+                    Some(symbol) => Value::String(symbol.clone()),
+                    None => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "invalid index {index} of Avro enum"
+                        )));
+                    }
+                }
+            }
+            AvroSchema::Record(fields) => {
+                let mut object = Map::with_capacity(fields.len());
+                for (name, field) in fields {
+                    object.insert(name.clone(), self.read_value(field)?);
+                }
+                Value::Object(object)
+            }
+            AvroSchema::Array(item) => {
+                let mut values = vec![];
+                loop {
+                    let count = self.read_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        values.push(self.read_value(item)?);
+                    }
+                }
+                Value::Array(values)
+            }
+            AvroSchema::Map(value) => {
+                let mut object = Map::new();
+                loop {
+                    let count = self.read_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        let key = self.read_string()?;
+                        object.insert(key, self.read_value(value)?);
+                    }
+                }
+                Value::Object(object)
+            }
+            AvroSchema::Union(branches) => {
+                let index = self.read_len()?;
+                match branches.get(index) {
+                    Some(branch) => self.read_value(branch)?,
+                    None => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "invalid index {index} of Avro union"
+                        )));
+                    }
+                }
+            }
+            AvroSchema::Decimal {
+                scale, fixed_size, ..
+            } => {
+                let bytes = match fixed_size {
+                    Some(size) => self.read_fixed(*size)?,
+                    None => self.read_bytes()?,
+                };
+                Value::String(decimal_to_string(bytes, *scale as usize))
+            }
+        })
+    }
+}
+
+fn to_utf8(bytes: &[u8]) -> Result<String> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| ErrorCode::BadBytes("invalid UTF-8 in Avro string or bytes"))
+}
+
+/// NaN and infinity can not be represented in JSON, they are loaded as NULL.
+pub(crate) fn float_value(v: f64) -> Value {
+    Number::from_f64(v).map_or(Value::Null, Value::Number)
+}
+
+/// Formats the unscaled value of a decimal, a big-endian two's-complement integer.
+pub(crate) fn decimal_to_string(bytes: &[u8], scale: usize) -> String {
+    let negative = bytes.first().map_or(false, |b| b & 0x80 != 0);
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        // Two's complement: invert the bits and add one.
+        for b in magnitude.iter_mut() {
+            *b = !*b;
+        }
+        for b in magnitude.iter_mut().rev() {
+            let (v, overflow) = b.overflowing_add(1);
+            *b = v;
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    // Divide by ten repeatedly to get the decimal digits, from the lowest one.
+    let mut digits = vec![];
+    while magnitude.iter().any(|b| *b != 0) {
+        let mut remainder = 0u32;
+        for b in magnitude.iter_mut() {
+            let current = (remainder << 8) | *b as u32;
+            *b = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    while digits.len() <= scale {
+        digits.push(b'0');
+    }
+    digits.reverse();
+
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let mut s = String::with_capacity(digits.len() + 2);
+    if negative {
+        s.push('-');
+    }
+    s.push_str(std::str::from_utf8(integer).unwrap());
+    if scale > 0 {
+        s.push('.');
+        s.push_str(std::str::from_utf8(fraction).unwrap());
+    }
+    s
+}
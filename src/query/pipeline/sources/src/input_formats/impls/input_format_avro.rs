@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use serde_json::Value;
+
+use crate::input_formats::impls::avro_reader::AvroFile;
+use crate::input_formats::impls::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_row(
+        field_decoder: &FieldJsonAstDecoder,
+        record: Value,
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
+        if field_decoder.is_select {
+            return field_decoder
+                .read_field(&mut columns[0], &record)
+                .map_err(|e| ErrorCode::BadBytes(format!("fail to decode column $1: {}", e)));
+        }
+
+        let record = match record {
+            Value::Object(fields) if !field_decoder.ident_case_sensitive => fields
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect(),
+            Value::Object(fields) => fields,
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "the schema of Avro file must be a record",
+                ));
+            }
+        };
+        for (field, column) in schema.fields().iter().zip(columns.iter_mut()) {
+            let value = if field_decoder.ident_case_sensitive {
+                record.get(field.name())
+            } else {
+                record.get(&field.name().to_lowercase())
+            };
+            match value {
+                Some(value) => field_decoder.read_field(column, value).map_err(|e| {
+                    ErrorCode::BadBytes(format!("{}. column={} value={:?}", e, field.name(), value))
+                })?,
+                // Fields missing in the file, e.g. added to the writer schema later.
+                None => column.push_default(),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+
+        let mut file =
+            AvroFile::try_create(&batch.data).map_err(|e| avro_error(&e.message(), path, 0))?;
+
+        // for deal with on_error mode
+        let mut num_rows = 0usize;
+        let mut row_index = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+
+        while let Some(mut block) = file
+            .next_block()
+            .map_err(|e| avro_error(&e.message(), path, row_index))?
+        {
+            while let Some(record) = block
+                .next_record(file.schema())
+                .map_err(|e| avro_error(&e.message(), path, row_index))?
+            {
+                row_index += 1;
+                if let Err(e) = Self::read_row(field_decoder, record, columns, &builder.ctx.schema)
+                {
+                    match builder.ctx.on_error_mode {
+                        OnErrorMode::Continue => {
+                            Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                            continue;
+                        }
+                        OnErrorMode::AbortNum(n) => {
+                            Self::on_error_abort(
+                                columns,
+                                num_rows,
+                                n,
+                                &builder.ctx.on_error_count,
+                                e,
+                            )
+                            .map_err(|e| avro_error(&e.message(), path, row_index - 1))?;
+                            continue;
+                        }
+                        _ => return Err(avro_error(&e.message(), path, row_index - 1)),
+                    }
+                }
+                num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+}
+
+fn avro_error(msg: &str, path: &str, row: usize) -> ErrorCode {
+    let row = row + 1;
+    let msg = format!("fail to parse Avro {}:{} {} ", path, row, msg);
+
+    ErrorCode::BadBytes(msg)
+}
@@ -59,30 +59,36 @@ impl InputFormatCSV {
         schema: &TableSchemaRef,
         field_ends: &[usize],
     ) -> Result<()> {
+        let load_options = &field_decoder.load_options;
         let mut field_start = 0;
         for (c, column) in columns.iter_mut().enumerate() {
             let field_end = field_ends[c];
-            let col_data = &buf[field_start..field_end];
-            let mut reader = Cursor::new(col_data);
-            if reader.eof() {
-                column.push_default();
-            } else {
-                if let Err(e) = field_decoder.read_field(column, &mut reader, true) {
+            let col_data = load_options.trim(&buf[field_start..field_end]);
+            match load_options.try_load_field(column, col_data) {
+                Ok(true) => {}
+                Err(e) => {
                     let err_msg = format_column_error(schema, c, col_data, &e.message());
                     return Err(ErrorCode::BadBytes(err_msg));
-                };
-                let mut next = [0u8; 1];
-                let readn = reader.read(&mut next[..])?;
-                if readn > 0 {
-                    let remaining = col_data.len() - reader.position() as usize + 1;
-                    let err_msg = format!(
-                        "bad field end, remain {} bytes, next char is {}",
-                        remaining,
-                        verbose_char(next[0])
-                    );
-
-                    let err_msg = format_column_error(schema, c, col_data, &err_msg);
-                    return Err(ErrorCode::BadBytes(err_msg));
+                }
+                Ok(false) => {
+                    let mut reader = Cursor::new(col_data);
+                    if let Err(e) = field_decoder.read_field(column, &mut reader, true) {
+                        let err_msg = format_column_error(schema, c, col_data, &e.message());
+                        return Err(ErrorCode::BadBytes(err_msg));
+                    };
+                    let mut next = [0u8; 1];
+                    let readn = reader.read(&mut next[..])?;
+                    if readn > 0 {
+                        let remaining = col_data.len() - reader.position() as usize + 1;
+                        let err_msg = format!(
+                            "bad field end, remain {} bytes, next char is {}",
+                            remaining,
+                            verbose_char(next[0])
+                        );
+
+                        let err_msg = format_column_error(schema, c, col_data, &err_msg);
+                        return Err(ErrorCode::BadBytes(err_msg));
+                    }
                 }
             }
             field_start = field_end;
@@ -47,6 +47,7 @@ impl InputFormatTSV {
     }
     fn read_row(
         field_delimiter: u8,
+        record_delimiter: &[u8],
         field_decoder: &FieldDecoderTSV,
         buf: &[u8],
         columns: &mut Vec<ColumnBuilder>,
@@ -58,16 +59,18 @@ impl InputFormatTSV {
         let mut pos = 0;
         let mut err_msg = None;
         let buf_len = buf.len();
+        let load_options = &field_decoder.load_options;
         while pos <= buf_len && column_index < num_columns {
             if pos == buf_len || buf[pos] == field_delimiter {
-                let col_data = &buf[field_start..pos];
-                if col_data.is_empty() {
-                    columns[column_index].push_default();
-                } else {
-                    let mut reader = Cursor::new(col_data);
-                    if let Err(e) =
-                        field_decoder.read_field(&mut columns[column_index], &mut reader, true)
-                    {
+                let mut col_data = &buf[field_start..pos];
+                if pos == buf_len {
+                    // The last field ends with the record delimiter.
+                    col_data = col_data.strip_suffix(record_delimiter).unwrap_or(col_data);
+                }
+                let col_data = load_options.trim(col_data);
+                match load_options.try_load_field(&mut columns[column_index], col_data) {
+                    Ok(true) => {}
+                    Err(e) => {
                         err_msg = Some(format_column_error(
                             schema,
                             column_index,
@@ -75,16 +78,30 @@ impl InputFormatTSV {
                             &e.message(),
                         ));
                         break;
-                    };
-                    reader.ignore_white_spaces();
-                    if reader.must_eof().is_err() {
-                        err_msg = Some(format_column_error(
-                            schema,
-                            column_index,
-                            col_data,
-                            "bad field end",
-                        ));
-                        break;
+                    }
+                    Ok(false) => {
+                        let mut reader = Cursor::new(col_data);
+                        if let Err(e) =
+                            field_decoder.read_field(&mut columns[column_index], &mut reader, true)
+                        {
+                            err_msg = Some(format_column_error(
+                                schema,
+                                column_index,
+                                col_data,
+                                &e.message(),
+                            ));
+                            break;
+                        };
+                        reader.ignore_white_spaces();
+                        if reader.must_eof().is_err() {
+                            err_msg = Some(format_column_error(
+                                schema,
+                                column_index,
+                                col_data,
+                                "bad field end",
+                            ));
+                            break;
+                        }
                     }
                 }
                 column_index += 1;
@@ -165,10 +182,9 @@ impl InputFormatTextBase for InputFormatTSV {
             .as_any()
             .downcast_ref::<FieldDecoderTSV>()
             .expect("must success");
-        let field_delimiter =
-            TsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params)
-                .field_delimiter
-                .as_bytes()[0];
+        let tsv_params = TsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+        let field_delimiter = tsv_params.field_delimiter.as_bytes()[0];
+        let record_delimiter = tsv_params.record_delimiter.as_bytes();
         let schema = &builder.ctx.schema;
         let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
@@ -176,7 +192,14 @@ impl InputFormatTextBase for InputFormatTSV {
         let mut error_map: HashMap<u16, InputError> = HashMap::new();
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
-            if let Err(e) = Self::read_row(field_delimiter, field_decoder, buf, columns, schema) {
+            if let Err(e) = Self::read_row(
+                field_delimiter,
+                record_delimiter,
+                field_decoder,
+                buf,
+                columns,
+                schema,
+            ) {
                 match builder.ctx.on_error_mode {
                     OnErrorMode::Continue => {
                         Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
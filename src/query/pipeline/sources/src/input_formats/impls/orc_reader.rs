@@ -0,0 +1,1193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads the files of [ORC](https://orc.apache.org/specification/ORCv1/). The stripes are
+//! decoded one by one, only the streams of the projected columns are read. The rows are
+//! turned into JSON values, loaded into the columns by the same decoder as NDJSON.
+
+use std::collections::HashMap;
+
+use common_compress::CompressAlgorithm;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::input_formats::impls::avro_reader::decimal_to_string;
+use crate::input_formats::impls::avro_reader::decompress;
+use crate::input_formats::impls::avro_reader::float_value;
+
+const MAGIC: &[u8] = b"ORC";
+/// The seconds of timestamps are relative to 2015-01-01 00:00:00 UTC.
+const TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+// Kinds of streams.
+const STREAM_PRESENT: u64 = 0;
+const STREAM_DATA: u64 = 1;
+const STREAM_LENGTH: u64 = 2;
+const STREAM_DICTIONARY_DATA: u64 = 3;
+const STREAM_SECONDARY: u64 = 5;
+
+/// A column of an ORC file, the columns are numbered in the pre-order of the type tree.
+#[derive(Clone, Debug)]
+pub struct OrcSchema {
+    pub column: usize,
+    pub data_type: OrcType,
+}
+
+#[derive(Clone, Debug)]
+pub enum OrcType {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    /// STRING, VARCHAR and CHAR.
+    String,
+    Binary,
+    /// TIMESTAMP and TIMESTAMP WITH LOCAL TIME ZONE.
+    Timestamp,
+    Date,
+    Decimal {
+        precision: u8,
+        scale: u8,
+    },
+    List(Box<OrcSchema>),
+    Map(Box<OrcSchema>, Box<OrcSchema>),
+    Struct(Vec<(String, OrcSchema)>),
+    Union(Vec<OrcSchema>),
+}
+
+impl OrcSchema {
+    pub fn to_table_type(&self) -> TableDataType {
+        match &self.data_type {
+            OrcType::Boolean => TableDataType::Boolean,
+            OrcType::Byte => TableDataType::Number(NumberDataType::Int8),
+            OrcType::Short => TableDataType::Number(NumberDataType::Int16),
+            OrcType::Int => TableDataType::Number(NumberDataType::Int32),
+            OrcType::Long => TableDataType::Number(NumberDataType::Int64),
+            OrcType::Float => TableDataType::Number(NumberDataType::Float32),
+            OrcType::Double => TableDataType::Number(NumberDataType::Float64),
+            OrcType::String | OrcType::Binary => TableDataType::String,
+            OrcType::Timestamp => TableDataType::Timestamp,
+            OrcType::Date => TableDataType::Date,
+            OrcType::Decimal { precision, scale } => {
+                match DecimalDataType::from_size(DecimalSize {
+                    precision: *precision,
+                    scale: *scale,
+                }) {
+                    Ok(decimal) => TableDataType::Decimal(decimal),
+                    Err(_) => TableDataType::String,
+                }
+            }
+            // Every value of ORC may be null, so are the items of the nested types.
+            OrcType::List(item) => {
+                TableDataType::Array(Box::new(item.to_table_type().wrap_nullable()))
+            }
+            // The keys are loaded as strings, as the keys of JSON objects.
+            OrcType::Map(_, value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![TableDataType::String, value.to_table_type().wrap_nullable()],
+            })),
+            OrcType::Struct(fields) => TableDataType::Tuple {
+                fields_name: fields.iter().map(|(name, _)| name.clone()).collect(),
+                fields_type: fields
+                    .iter()
+                    .map(|(_, f)| f.to_table_type().wrap_nullable())
+                    .collect(),
+            },
+            // A value of one of several types is loaded as a variant.
+            OrcType::Union(_) => TableDataType::Variant,
+        }
+    }
+
+    /// The table schema of the rows, the root type of an ORC file is a struct.
+    pub fn to_table_schema(&self) -> Result<TableSchema> {
+        match &self.data_type {
+            OrcType::Struct(fields) => Ok(TableSchema::new(
+                fields
+                    .iter()
+                    .map(|(name, f)| TableField::new(name, f.to_table_type().wrap_nullable()))
+                    .collect(),
+            )),
+            _ => Err(ErrorCode::BadBytes(
+                "the root type of ORC file must be a struct",
+            )),
+        }
+    }
+
+    /// The names of the fields of the root struct.
+    pub fn field_names(&self) -> Vec<&str> {
+        match &self.data_type {
+            OrcType::Struct(fields) => fields.iter().map(|(name, _)| name.as_str()).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Codec {
+    None,
+    Zlib,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+/// An ORC file held in memory.
+pub struct OrcFile<'a> {
+    data: &'a [u8],
+    codec: Codec,
+    block_size: usize,
+    stripes: Vec<StripeInformation>,
+    schema: OrcSchema,
+}
+
+impl<'a> OrcFile<'a> {
+    pub fn try_create(data: &'a [u8]) -> Result<Self> {
+        if !data.starts_with(MAGIC) || data.len() < MAGIC.len() + 1 {
+            return Err(ErrorCode::BadBytes("not an ORC file"));
+        }
+        let ps_len = data[data.len() - 1] as usize;
+        let ps_start = (data.len() - 1)
+            .checked_sub(ps_len)
+            .ok_or_else(|| orc_error("invalid postscript length"))?;
+        let postscript = PostScript::parse(&data[ps_start..data.len() - 1])?;
+        let codec = match postscript.compression {
+            0 => Codec::None,
+            1 => Codec::Zlib,
+            2 => Codec::Snappy,
+            4 => Codec::Lz4,
+            5 => Codec::Zstd,
+            3 => return Err(ErrorCode::BadBytes("unsupported ORC compression LZO")),
+            other => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unknown ORC compression {other}"
+                )));
+            }
+        };
+        let footer_start = ps_start
+            .checked_sub(postscript.footer_length as usize)
+            .ok_or_else(|| orc_error("invalid footer length"))?;
+
+        let mut file = OrcFile {
+            data,
+            codec,
+            block_size: postscript.compression_block_size as usize,
+            stripes: vec![],
+            schema: OrcSchema {
+                column: 0,
+                data_type: OrcType::Struct(vec![]),
+            },
+        };
+        let footer = Footer::parse(&file.decompress(&data[footer_start..ps_start])?)?;
+        if footer.types.is_empty() {
+            return Err(orc_error("no types in footer"));
+        }
+        file.schema = build_schema(&footer.types, 0)?;
+        file.stripes = footer.stripes;
+        Ok(file)
+    }
+
+    pub fn schema(&self) -> &OrcSchema {
+        &self.schema
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    /// Reads the rows of a stripe as JSON objects, having only the given fields of the
+    /// root struct.
+    pub fn read_stripe(&self, index: usize, projection: &[usize]) -> Result<Vec<Value>> {
+        let stripe = &self.stripes[index];
+        let footer_start = stripe.offset + stripe.index_length + stripe.data_length;
+        let footer = StripeFooter::parse(
+            &self.decompress(self.slice(footer_start, stripe.footer_length)?)?,
+        )?;
+
+        // The streams are stored one after another from the start of the stripe.
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        let mut offset = stripe.offset;
+        for stream in footer.streams.iter() {
+            streams.insert(
+                (stream.column as usize, stream.kind),
+                self.slice(offset, stream.length)?,
+            );
+            offset += stream.length;
+        }
+        let reader = StripeReader {
+            file: self,
+            streams,
+            encodings: footer.encodings,
+            dictionary_sizes: footer.dictionary_sizes,
+        };
+
+        let fields = match &self.schema.data_type {
+            OrcType::Struct(fields) => fields,
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "the root type of ORC file must be a struct",
+                ));
+            }
+        };
+        let num_rows = stripe.number_of_rows as usize;
+        let mut columns = Vec::with_capacity(projection.len());
+        for index in projection {
+            let (name, schema) = &fields[*index];
+            columns.push((name, reader.read_column(schema, num_rows)?.into_iter()));
+        }
+        let mut rows = Vec::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            let mut row = Map::new();
+            for (name, values) in columns.iter_mut() {
+                row.insert(name.to_string(), values.next().unwrap_or(Value::Null));
+            }
+            rows.push(Value::Object(row));
+        }
+        Ok(rows)
+    }
+
+    fn slice(&self, offset: u64, length: u64) -> Result<&'a [u8]> {
+        let start = offset as usize;
+        let end = start.saturating_add(length as usize);
+        self.data
+            .get(start..end)
+            .ok_or_else(|| orc_error("stream out of the file"))
+    }
+
+    /// Streams are split into chunks, each having a 3 bytes header of its length and
+    /// whether it is stored as is.
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if matches!(self.codec, Codec::None) {
+            return Ok(data.to_vec());
+        }
+        let mut output = vec![];
+        let mut pos = 0;
+        while pos < data.len() {
+            let header = data
+                .get(pos..pos + 3)
+                .ok_or_else(|| orc_error("truncated compression chunk header"))?;
+            let header =
+                header[0] as usize | (header[1] as usize) << 8 | (header[2] as usize) << 16;
+            let original = header & 1 == 1;
+            let len = header >> 1;
+            pos += 3;
+            let chunk = data
+                .get(pos..pos + len)
+                .ok_or_else(|| orc_error("truncated compression chunk"))?;
+            pos += len;
+            if original {
+                output.extend_from_slice(chunk);
+                continue;
+            }
+            let decompressed = match self.codec {
+                Codec::None => unreachable!(),
+                // Deflate without the zlib header.
+                Codec::Zlib => decompress(CompressAlgorithm::Deflate, chunk)?,
+                Codec::Zstd => decompress(CompressAlgorithm::Zstd, chunk)?,
+                Codec::Snappy => snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| {
+                        ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                    })?,
+                Codec::Lz4 => {
+                    lz4::block::decompress(chunk, Some(self.block_size as i32)).map_err(|e| {
+                        ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                    })?
+                }
+            };
+            output.extend_from_slice(&decompressed);
+        }
+        Ok(output)
+    }
+}
+
+fn build_schema(types: &[TypeProto], column: usize) -> Result<OrcSchema> {
+    let ty = &types[column];
+    let child = |i: usize| -> Result<OrcSchema> {
+        match ty.subtypes.get(i) {
+            // The children always come after their parent, which rules out cycles.
+            Some(sub) if (*sub as usize) > column && (*sub as usize) < types.len() => {
+                build_schema(types, *sub as usize)
+            }
+            _ => Err(orc_error(format!("invalid subtypes of column {column}"))),
+        }
+    };
+    let data_type = match ty.kind {
+        0 => OrcType::Boolean,
+        1 => OrcType::Byte,
+        2 => OrcType::Short,
+        3 => OrcType::Int,
+        4 => OrcType::Long,
+        5 => OrcType::Float,
+        6 => OrcType::Double,
+        7 | 16 | 17 => OrcType::String,
+        8 => OrcType::Binary,
+        9 | 18 => OrcType::Timestamp,
+        10 => OrcType::List(Box::new(child(0)?)),
+        11 => OrcType::Map(Box::new(child(0)?), Box::new(child(1)?)),
+        12 => {
+            if ty.field_names.len() != ty.subtypes.len() {
+                return Err(orc_error(format!("invalid field names of column {column}")));
+            }
+            OrcType::Struct(
+                ty.field_names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| Ok((name.clone(), child(i)?)))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        13 => OrcType::Union((0..ty.subtypes.len()).map(child).collect::<Result<_>>()?),
+        // Decimals written by old versions of Hive have no precision.
+        14 => match ty.precision {
+            0 => OrcType::Decimal {
+                precision: 38,
+                scale: 10,
+            },
+            precision => OrcType::Decimal {
+                precision: precision.min(38) as u8,
+                scale: ty.scale.min(precision) as u8,
+            },
+        },
+        15 => OrcType::Date,
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported ORC type kind {other}"
+            )));
+        }
+    };
+    Ok(OrcSchema { column, data_type })
+}
+
+struct StripeReader<'a, 'b> {
+    file: &'b OrcFile<'a>,
+    streams: HashMap<(usize, u64), &'a [u8]>,
+    encodings: Vec<u64>,
+    dictionary_sizes: Vec<u64>,
+}
+
+impl<'a, 'b> StripeReader<'a, 'b> {
+    fn stream(&self, column: usize, kind: u64) -> Result<Option<Reader>> {
+        match self.streams.get(&(column, kind)) {
+            Some(data) => Ok(Some(Reader::new(self.file.decompress(data)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// A missing stream is read as an empty one, fine if no value is read from it.
+    fn stream_or_empty(&self, column: usize, kind: u64) -> Result<Reader> {
+        Ok(self
+            .stream(column, kind)?
+            .unwrap_or_else(|| Reader::new(vec![])))
+    }
+
+    /// The integers are encoded by the run length encoding of version 2 in DIRECT_V2
+    /// and DICTIONARY_V2, otherwise of version 1.
+    fn is_rle_v2(&self, column: usize) -> bool {
+        matches!(self.encodings.get(column), Some(2 | 3))
+    }
+
+    fn is_dictionary(&self, column: usize) -> bool {
+        matches!(self.encodings.get(column), Some(1 | 3))
+    }
+
+    fn read_integers(&self, column: usize, kind: u64, signed: bool, n: usize) -> Result<Vec<i64>> {
+        let mut reader = self.stream_or_empty(column, kind)?;
+        if self.is_rle_v2(column) {
+            reader.read_rle_v2(n, signed)
+        } else {
+            reader.read_rle_v1(n, signed)
+        }
+    }
+
+    /// Reads `n` values of a column, NULL if absent.
+    fn read_column(&self, schema: &OrcSchema, n: usize) -> Result<Vec<Value>> {
+        let column = schema.column;
+        let present = match self.stream(column, STREAM_PRESENT)? {
+            Some(mut reader) => Some(reader.read_booleans(n)?),
+            None => None,
+        };
+        let count = present
+            .as_ref()
+            .map_or(n, |present| present.iter().filter(|p| **p).count());
+        let values = self.read_values(schema, count)?;
+        match present {
+            None => Ok(values),
+            Some(present) => {
+                let mut values = values.into_iter();
+                Ok(present
+                    .into_iter()
+                    .map(|p| {
+                        if p {
+                            values.next().unwrap_or(Value::Null)
+                        } else {
+                            Value::Null
+                        }
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Reads `n` non-null values of a column.
+    fn read_values(&self, schema: &OrcSchema, n: usize) -> Result<Vec<Value>> {
+        let column = schema.column;
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        Ok(match &schema.data_type {
+            OrcType::Boolean => self
+                .stream_or_empty(column, STREAM_DATA)?
+                .read_booleans(n)?
+                .into_iter()
+                .map(Value::Bool)
+                .collect(),
+            OrcType::Byte => self
+                .stream_or_empty(column, STREAM_DATA)?
+                .read_byte_rle(n)?
+                .into_iter()
+                .map(|v| Value::from(v as i8))
+                .collect(),
+            OrcType::Short | OrcType::Int | OrcType::Long | OrcType::Date => self
+                .read_integers(column, STREAM_DATA, true, n)?
+                .into_iter()
+                .map(Value::from)
+                .collect(),
+            OrcType::Float => {
+                let mut reader = self.stream_or_empty(column, STREAM_DATA)?;
+                (0..n)
+                    .map(|_| {
+                        let bytes = reader.take(4)?;
+                        Ok(float_value(
+                            f32::from_le_bytes(bytes.try_into().unwrap()) as f64
+                        ))
+                    })
+                    .collect::<Result<_>>()?
+            }
+            OrcType::Double => {
+                let mut reader = self.stream_or_empty(column, STREAM_DATA)?;
+                (0..n)
+                    .map(|_| {
+                        let bytes = reader.take(8)?;
+                        Ok(float_value(f64::from_le_bytes(bytes.try_into().unwrap())))
+                    })
+                    .collect::<Result<_>>()?
+            }
+            OrcType::String | OrcType::Binary => self
+                .read_strings(column, n)?
+                .into_iter()
+                .map(|bytes| Value::String(String::from_utf8_lossy(&bytes).into_owned()))
+                .collect(),
+            OrcType::Timestamp => {
+                let seconds = self.read_integers(column, STREAM_DATA, true, n)?;
+                let nanos = self.read_integers(column, STREAM_SECONDARY, false, n)?;
+                seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let nanos = decode_nanos(nanos as u64);
+                        let mut seconds = seconds.wrapping_add(TIMESTAMP_BASE_SECONDS);
+                        // The seconds of the timestamps before 1970 are rounded towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        Value::from(seconds.wrapping_mul(1_000_000).wrapping_add(nanos / 1000))
+                    })
+                    .collect()
+            }
+            OrcType::Decimal { .. } => {
+                let mut reader = self.stream_or_empty(column, STREAM_DATA)?;
+                let scales = self.read_integers(column, STREAM_SECONDARY, true, n)?;
+                scales
+                    .into_iter()
+                    .map(|scale| {
+                        let unscaled = reader.read_unbounded_varint()?;
+                        Ok(Value::String(decimal_to_string(
+                            &unscaled.to_be_bytes(),
+                            scale.max(0) as usize,
+                        )))
+                    })
+                    .collect::<Result<_>>()?
+            }
+            OrcType::List(item) => {
+                let lengths = self.read_integers(column, STREAM_LENGTH, false, n)?;
+                let total = lengths.iter().map(|l| *l as usize).sum();
+                let mut items = self.read_column(item, total)?.into_iter();
+                lengths
+                    .into_iter()
+                    .map(|len| Value::Array(items.by_ref().take(len as usize).collect()))
+                    .collect()
+            }
+            OrcType::Map(key, value) => {
+                let lengths = self.read_integers(column, STREAM_LENGTH, false, n)?;
+                let total = lengths.iter().map(|l| *l as usize).sum();
+                let mut keys = self.read_column(key, total)?.into_iter();
+                let mut values = self.read_column(value, total)?.into_iter();
+                lengths
+                    .into_iter()
+                    .map(|len| {
+                        let mut entries = Map::new();
+                        for _ in 0..len {
+                            let key = match keys.next() {
+                                Some(Value::String(s)) => s,
+                                Some(other) => other.to_string(),
+                                None => break,
+                            };
+                            entries.insert(key, values.next().unwrap_or(Value::Null));
+                        }
+                        Value::Object(entries)
+                    })
+                    .collect()
+            }
+            OrcType::Struct(fields) => {
+                // The children have values only for the non-null structs.
+                let mut children = fields
+                    .iter()
+                    .map(|(name, f)| Ok((name, self.read_column(f, n)?.into_iter())))
+                    .collect::<Result<Vec<_>>>()?;
+                (0..n)
+                    .map(|_| {
+                        let mut object = Map::new();
+                        for (name, values) in children.iter_mut() {
+                            object.insert(name.to_string(), values.next().unwrap_or(Value::Null));
+                        }
+                        Value::Object(object)
+                    })
+                    .collect()
+            }
+            OrcType::Union(variants) => {
+                let tags = self
+                    .stream_or_empty(column, STREAM_DATA)?
+                    .read_byte_rle(n)?;
+                let mut children = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, variant)| {
+                        let count = tags.iter().filter(|t| **t as usize == i).count();
+                        Ok(self.read_column(variant, count)?.into_iter())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                tags.into_iter()
+                    .map(|tag| {
+                        children
+                            .get_mut(tag as usize)
+                            .and_then(|values| values.next())
+                            .unwrap_or(Value::Null)
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    fn read_strings(&self, column: usize, n: usize) -> Result<Vec<Vec<u8>>> {
+        if self.is_dictionary(column) {
+            let dictionary_size = *self
+                .dictionary_sizes
+                .get(column)
+                .ok_or_else(|| orc_error(format!("no dictionary size of column {column}")))?
+                as usize;
+            let lengths = self.read_integers(column, STREAM_LENGTH, false, dictionary_size)?;
+            let mut data = self.stream_or_empty(column, STREAM_DICTIONARY_DATA)?;
+            let dictionary = lengths
+                .into_iter()
+                .map(|len| Ok(data.take(len as usize)?.to_vec()))
+                .collect::<Result<Vec<_>>>()?;
+            self.read_integers(column, STREAM_DATA, false, n)?
+                .into_iter()
+                .map(|index| {
+                    dictionary
+                        .get(index as usize)
+                        .cloned()
+                        .ok_or_else(|| orc_error("dictionary index out of range"))
+                })
+                .collect()
+        } else {
+            let lengths = self.read_integers(column, STREAM_LENGTH, false, n)?;
+            let mut data = self.stream_or_empty(column, STREAM_DATA)?;
+            lengths
+                .into_iter()
+                .map(|len| Ok(data.take(len as usize)?.to_vec()))
+                .collect()
+        }
+    }
+}
+
+/// The nanoseconds are stored with the trailing decimal zeros removed, the number of
+/// zeros minus one is in the lowest 3 bits.
+fn decode_nanos(value: u64) -> i64 {
+    let zeros = value & 7;
+    let mut nanos = (value >> 3) as i64;
+    if zeros != 0 {
+        for _ in 0..=zeros {
+            nanos *= 10;
+        }
+    }
+    nanos
+}
+
+fn decode_bit_width(encoded: u8) -> usize {
+    match encoded {
+        0..=23 => encoded as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn zigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Reads the decompressed data of a stream.
+struct Reader {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl Reader {
+    fn new(data: Vec<u8>) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        let byte = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| orc_error("unexpected end of stream"))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        let end = self.pos.saturating_add(len);
+        let bytes = self
+            .data
+            .get(self.pos..end)
+            .ok_or_else(|| orc_error("unexpected end of stream"))?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_signed_varint(&mut self) -> Result<i64> {
+        Ok(zigzag(self.read_varint()?))
+    }
+
+    /// The unscaled values of decimals are zigzag varints of up to 128 bits.
+    fn read_unbounded_varint(&mut self) -> Result<i128> {
+        let mut value = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            if shift < 128 {
+                value |= ((byte & 0x7f) as u128) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(((value >> 1) as i128) ^ -((value & 1) as i128));
+            }
+            shift += 7;
+        }
+    }
+
+    /// An unsigned big endian integer of `width` bytes.
+    fn read_be(&mut self, width: usize) -> Result<u64> {
+        Ok(self
+            .take(width)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | *b as u64))
+    }
+
+    /// Reads `n` integers of `width` bits packed from the highest bit, the last byte is
+    /// padded.
+    fn read_bits(&mut self, width: usize, n: usize) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(n);
+        let mut buffer = 0u128;
+        let mut bits = 0;
+        for _ in 0..n {
+            while bits < width {
+                buffer = (buffer << 8) | self.byte()? as u128;
+                bits += 8;
+            }
+            bits -= width;
+            values.push(((buffer >> bits) & ((1u128 << width) - 1)) as u64);
+            buffer &= (1u128 << bits) - 1;
+        }
+        Ok(values)
+    }
+
+    fn read_byte_rle(&mut self, n: usize) -> Result<Vec<u8>> {
+        let mut values = Vec::with_capacity(n);
+        while values.len() < n {
+            let header = self.byte()?;
+            if header < 0x80 {
+                let value = self.byte()?;
+                values.extend(std::iter::repeat(value).take(header as usize + 3));
+            } else {
+                let len = 0x100 - header as usize;
+                values.extend_from_slice(self.take(len)?);
+            }
+        }
+        values.truncate(n);
+        Ok(values)
+    }
+
+    /// The booleans are bits of bytes from the highest one, encoded by the byte run
+    /// length encoding.
+    fn read_booleans(&mut self, n: usize) -> Result<Vec<bool>> {
+        let bytes = self.read_byte_rle((n + 7) / 8)?;
+        Ok((0..n)
+            .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+            .collect())
+    }
+
+    fn read_int(&mut self, signed: bool) -> Result<i64> {
+        if signed {
+            self.read_signed_varint()
+        } else {
+            Ok(self.read_varint()? as i64)
+        }
+    }
+
+    fn read_rle_v1(&mut self, n: usize, signed: bool) -> Result<Vec<i64>> {
+        let mut values = Vec::with_capacity(n);
+        while values.len() < n {
+            let header = self.byte()?;
+            if header < 0x80 {
+                let delta = self.byte()? as i8 as i64;
+                let base = self.read_int(signed)?;
+                for i in 0..(header as i64 + 3) {
+                    values.push(base.wrapping_add(i.wrapping_mul(delta)));
+                }
+            } else {
+                for _ in 0..(0x100 - header as usize) {
+                    values.push(self.read_int(signed)?);
+                }
+            }
+        }
+        values.truncate(n);
+        Ok(values)
+    }
+
+    fn read_rle_v2(&mut self, n: usize, signed: bool) -> Result<Vec<i64>> {
+        let decode = |v: u64| if signed { zigzag(v) } else { v as i64 };
+        let mut values = Vec::with_capacity(n);
+        while values.len() < n {
+            let first = self.byte()?;
+            match first >> 6 {
+                // SHORT_REPEAT
+                0 => {
+                    let width = ((first >> 3) & 0x07) as usize + 1;
+                    let count = (first & 0x07) as usize + 3;
+                    let value = decode(self.read_be(width)?);
+                    values.extend(std::iter::repeat(value).take(count));
+                }
+                // DIRECT
+                1 => {
+                    let width = decode_bit_width((first >> 1) & 0x1f);
+                    let len = (((first & 1) as usize) << 8 | self.byte()? as usize) + 1;
+                    values.extend(self.read_bits(width, len)?.into_iter().map(decode));
+                }
+                // PATCHED_BASE
+                2 => {
+                    let width = decode_bit_width((first >> 1) & 0x1f);
+                    let len = (((first & 1) as usize) << 8 | self.byte()? as usize) + 1;
+                    let third = self.byte()?;
+                    let base_width = ((third >> 5) & 0x07) as usize + 1;
+                    let patch_width = decode_bit_width(third & 0x1f);
+                    let fourth = self.byte()?;
+                    let gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+                    let patch_len = (fourth & 0x1f) as usize;
+                    if patch_width + gap_width > 64 {
+                        return Err(orc_error("invalid patch width"));
+                    }
+
+                    // The base is in sign-magnitude.
+                    let base = self.read_be(base_width)?;
+                    let sign_mask = 1u64 << (base_width * 8 - 1);
+                    let base = if base & sign_mask != 0 {
+                        -((base & !sign_mask) as i64)
+                    } else {
+                        base as i64
+                    };
+                    let mut data = self.read_bits(width, len)?;
+                    let patches =
+                        self.read_bits(closest_fixed_bits(patch_width + gap_width), patch_len)?;
+                    let patch_mask = if patch_width == 64 {
+                        u64::MAX
+                    } else {
+                        (1u64 << patch_width) - 1
+                    };
+                    let mut pos = 0usize;
+                    for entry in patches {
+                        let gap = (entry >> patch_width) as usize;
+                        let patch = entry & patch_mask;
+                        pos += gap;
+                        // A gap longer than 255 is split into entries having no patch.
+                        if patch == 0 {
+                            continue;
+                        }
+                        let value = data
+                            .get_mut(pos)
+                            .ok_or_else(|| orc_error("patch out of range"))?;
+                        *value |= patch << width;
+                    }
+                    values.extend(data.into_iter().map(|v| base.wrapping_add(v as i64)));
+                }
+                // DELTA
+                _ => {
+                    let encoded = (first >> 1) & 0x1f;
+                    let width = if encoded == 0 {
+                        0
+                    } else {
+                        decode_bit_width(encoded)
+                    };
+                    let len = (((first & 1) as usize) << 8 | self.byte()? as usize) + 1;
+                    let base = self.read_int(signed)?;
+                    let delta_base = self.read_signed_varint()?;
+                    values.push(base);
+                    if len > 1 {
+                        let mut value = base.wrapping_add(delta_base);
+                        values.push(value);
+                        if width == 0 {
+                            for _ in 2..len {
+                                value = value.wrapping_add(delta_base);
+                                values.push(value);
+                            }
+                        } else {
+                            // The deltas have the sign of the first one.
+                            for delta in self.read_bits(width, len - 2)? {
+                                value = if delta_base < 0 {
+                                    value.wrapping_sub(delta as i64)
+                                } else {
+                                    value.wrapping_add(delta as i64)
+                                };
+                                values.push(value);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        values.truncate(n);
+        Ok(values)
+    }
+}
+
+/// Reads the fields of a protobuf message.
+struct ProtoReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+enum ProtoValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+impl<'a> ProtoValue<'a> {
+    fn as_u64(&self) -> u64 {
+        match self {
+            ProtoValue::Varint(v) => *v,
+            _ => 0,
+        }
+    }
+
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            ProtoValue::Bytes(bytes) => bytes,
+            _ => &[],
+        }
+    }
+
+    /// A repeated integer field, packed or not.
+    fn extend_u64s(&self, values: &mut Vec<u64>) -> Result<()> {
+        match self {
+            ProtoValue::Varint(v) => values.push(*v),
+            ProtoValue::Bytes(bytes) => {
+                let mut reader = ProtoReader::new(bytes);
+                while reader.pos < bytes.len() {
+                    values.push(reader.varint()?);
+                }
+            }
+            ProtoValue::Fixed => {}
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = *self
+                .data
+                .get(self.pos)
+                .ok_or_else(|| orc_error("truncated metadata"))?;
+            self.pos += 1;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.saturating_add(len);
+        let bytes = self
+            .data
+            .get(self.pos..end)
+            .ok_or_else(|| orc_error("truncated metadata"))?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// The number and value of the next field, [None] at the end of the message.
+    fn next_field(&mut self) -> Result<Option<(u64, ProtoValue<'a>)>> {
+        if self.pos >= self.data.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 0x07 {
+            0 => ProtoValue::Varint(self.varint()?),
+            1 => {
+                self.take(8)?;
+                ProtoValue::Fixed
+            }
+            2 => {
+                let len = self.varint()? as usize;
+                ProtoValue::Bytes(self.take(len)?)
+            }
+            5 => {
+                self.take(4)?;
+                ProtoValue::Fixed
+            }
+            other => return Err(orc_error(format!("unsupported protobuf wire type {other}"))),
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+struct PostScript {
+    footer_length: u64,
+    compression: u64,
+    compression_block_size: u64,
+}
+
+impl PostScript {
+    fn parse(data: &[u8]) -> Result<Self> {
+        // The default block size of the writers.
+        let mut postscript = PostScript {
+            footer_length: 0,
+            compression: 0,
+            compression_block_size: 256 * 1024,
+        };
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => postscript.footer_length = value.as_u64(),
+                2 => postscript.compression = value.as_u64(),
+                3 => postscript.compression_block_size = value.as_u64(),
+                8000 if value.as_bytes() != MAGIC => {
+                    return Err(ErrorCode::BadBytes("not an ORC file"));
+                }
+                _ => {}
+            }
+        }
+        Ok(postscript)
+    }
+}
+
+struct StripeInformation {
+    offset: u64,
+    index_length: u64,
+    data_length: u64,
+    footer_length: u64,
+    number_of_rows: u64,
+}
+
+struct TypeProto {
+    kind: u64,
+    subtypes: Vec<u64>,
+    field_names: Vec<String>,
+    precision: u64,
+    scale: u64,
+}
+
+struct Footer {
+    stripes: Vec<StripeInformation>,
+    types: Vec<TypeProto>,
+}
+
+impl Footer {
+    fn parse(data: &[u8]) -> Result<Self> {
+        let mut footer = Footer {
+            stripes: vec![],
+            types: vec![],
+        };
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                3 => {
+                    let mut stripe = StripeInformation {
+                        offset: 0,
+                        index_length: 0,
+                        data_length: 0,
+                        footer_length: 0,
+                        number_of_rows: 0,
+                    };
+                    let mut reader = ProtoReader::new(value.as_bytes());
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => stripe.offset = value.as_u64(),
+                            2 => stripe.index_length = value.as_u64(),
+                            3 => stripe.data_length = value.as_u64(),
+                            4 => stripe.footer_length = value.as_u64(),
+                            5 => stripe.number_of_rows = value.as_u64(),
+                            _ => {}
+                        }
+                    }
+                    footer.stripes.push(stripe);
+                }
+                4 => {
+                    let mut ty = TypeProto {
+                        kind: 0,
+                        subtypes: vec![],
+                        field_names: vec![],
+                        precision: 0,
+                        scale: 0,
+                    };
+                    let mut reader = ProtoReader::new(value.as_bytes());
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => ty.kind = value.as_u64(),
+                            2 => value.extend_u64s(&mut ty.subtypes)?,
+                            3 => ty
+                                .field_names
+                                .push(String::from_utf8_lossy(value.as_bytes()).into_owned()),
+                            5 => ty.precision = value.as_u64(),
+                            6 => ty.scale = value.as_u64(),
+                            _ => {}
+                        }
+                    }
+                    footer.types.push(ty);
+                }
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+struct StreamInformation {
+    kind: u64,
+    column: u64,
+    length: u64,
+}
+
+struct StripeFooter {
+    streams: Vec<StreamInformation>,
+    /// The kinds of the encodings of the columns.
+    encodings: Vec<u64>,
+    dictionary_sizes: Vec<u64>,
+}
+
+impl StripeFooter {
+    fn parse(data: &[u8]) -> Result<Self> {
+        let mut footer = StripeFooter {
+            streams: vec![],
+            encodings: vec![],
+            dictionary_sizes: vec![],
+        };
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            let mut values = [0u64; 3];
+            let mut reader = ProtoReader::new(value.as_bytes());
+            while let Some((field, value)) = reader.next_field()? {
+                if (1..=3).contains(&field) {
+                    values[field as usize - 1] = value.as_u64();
+                }
+            }
+            match field {
+                1 => footer.streams.push(StreamInformation {
+                    kind: values[0],
+                    column: values[1],
+                    length: values[2],
+                }),
+                2 => {
+                    footer.encodings.push(values[0]);
+                    footer.dictionary_sizes.push(values[1]);
+                }
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+fn orc_error(msg: impl Into<String>) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid ORC file: {}", msg.into()))
+}
@@ -24,6 +24,10 @@ mod source_deserializer;
 mod transform_deserializer;
 
 pub use beyond_end_reader::BeyondEndReader;
+pub use impls::AvroFile;
+pub use impls::AvroSchema;
+pub use impls::OrcFile;
+pub use impls::OrcSchema;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
@@ -12,11 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
+use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_exception::Result;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::thread_cpu_time;
+use common_profile::ProfCounters;
 use common_profile::ProfSpanBuilder;
 use common_profile::ProfSpanSetRef;
 
@@ -25,6 +31,11 @@ pub struct ProfileWrapper<T> {
     prof_span_id: u32,
     prof_span_set: ProfSpanSetRef,
     prof_span_builder: ProfSpanBuilder,
+
+    // The time of the first schedule, used to compute the wall time.
+    started: Option<Instant>,
+    // Tracks the memory allocated while the inner processor is running.
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> ProfileWrapper<T>
@@ -40,8 +51,20 @@ where T: Processor + 'static
             prof_span_id,
             prof_span_set,
             prof_span_builder: ProfSpanBuilder::default(),
+            started: None,
+            mem_stat: None,
         })
     }
+
+    fn mem_stat(&mut self) -> Arc<MemStat> {
+        // Created lazily on the executor thread, so the memory stat of the query
+        // is the parent and the memory limit of the query is still respected.
+        self.mem_stat
+            .get_or_insert_with(|| {
+                MemStat::create(format!("{}-{}", self.inner.name(), self.prof_span_id))
+            })
+            .clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -57,8 +80,21 @@ where T: Processor + 'static
     }
 
     fn event(&mut self) -> Result<Event> {
-        match self.inner.event()? {
+        let started = *self.started.get_or_insert_with(Instant::now);
+
+        let saved = ProfCounters::swap(ProfCounters::default());
+        let event = self.inner.event();
+        let counters = ProfCounters::swap(saved);
+        self.prof_span_builder.accumulate_counters(&counters);
+
+        match event? {
             Event::Finished => {
+                self.prof_span_builder
+                    .set_wall_time(started.elapsed().as_nanos() as u64);
+                if let Some(mem_stat) = &self.mem_stat {
+                    self.prof_span_builder
+                        .set_memory_peak(mem_stat.get_peak_memory_usage().max(0) as u64);
+                }
                 self.prof_span_set
                     .lock()
                     .unwrap()
@@ -70,17 +106,58 @@ where T: Processor + 'static
     }
 
     fn process(&mut self) -> Result<()> {
+        let mem_stat = self.mem_stat();
+        let saved = ProfCounters::swap(ProfCounters::default());
         let instant = Instant::now();
-        self.inner.process()?;
+        let cpu_time = thread_cpu_time();
+
+        let res = {
+            let _guard = ThreadTracker::enter(Some(mem_stat));
+            self.inner.process()
+        };
+
+        let cpu_time = thread_cpu_time().saturating_sub(cpu_time);
         let elapsed = instant.elapsed();
+        let counters = ProfCounters::swap(saved);
         self.prof_span_builder
             .accumulate_process_time(elapsed.as_nanos() as u64);
-        Ok(())
+        self.prof_span_builder.accumulate_cpu_time(cpu_time);
+        self.prof_span_builder.accumulate_counters(&counters);
+        res
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        // TODO: record profile information for async process
-        self.inner.async_process().await
+        // The future may be polled on different threads, so the counters and the
+        // memory stat are installed for every poll.
+        let mem_stat = self.mem_stat();
+        let mut counters = ProfCounters::default();
+        let mut cpu_time = 0;
+        let instant = Instant::now();
+
+        let mut future = Box::pin(self.inner.async_process());
+        let res = std::future::poll_fn(|cx| {
+            let saved = ProfCounters::swap(counters);
+            let start = thread_cpu_time();
+            let poll = {
+                let _guard = ThreadTracker::enter(Some(mem_stat.clone()));
+                future.as_mut().poll(cx)
+            };
+            cpu_time += thread_cpu_time().saturating_sub(start);
+            counters = ProfCounters::swap(saved);
+            poll
+        })
+        .await;
+
+        // The time neither on CPU nor waiting for the exchanges or the locks is
+        // taken as waiting for IO.
+        let waited = (instant.elapsed().as_nanos() as u64)
+            .saturating_sub(cpu_time)
+            .saturating_sub(counters.exchange_wait_time)
+            .saturating_sub(counters.lock_wait_time);
+        self.prof_span_builder.accumulate_io_wait_time(waited);
+        self.prof_span_builder.accumulate_cpu_time(cpu_time);
+        self.prof_span_builder.accumulate_counters(&counters);
+        res
     }
 }
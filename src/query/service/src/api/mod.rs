@@ -16,6 +16,7 @@
 
 pub use http_service::HttpService;
 pub use rpc::serialize_block;
+pub use rpc::AdaptiveFlightScatter;
 pub use rpc::BroadcastExchange;
 pub use rpc::BroadcastFlightScatter;
 pub use rpc::ConnectionInfo;
@@ -28,6 +29,7 @@ pub use rpc::ExchangeDeserializeMeta;
 pub use rpc::ExchangeInjector;
 pub use rpc::ExchangeSerializeMeta;
 pub use rpc::ExchangeShuffleMeta;
+pub use rpc::ExchangeShuffleTransform;
 pub use rpc::ExchangeSorting;
 pub use rpc::ExecutePartialQueryPacket;
 pub use rpc::FlightAction;
@@ -40,6 +42,7 @@ pub use rpc::InitNodesChannelPacket;
 pub use rpc::MergeExchange;
 pub use rpc::MergeExchangeParams;
 pub use rpc::QueryFragmentsPlanPacket;
+pub use rpc::ScatterTransform;
 pub use rpc::ShuffleDataExchange;
 pub use rpc::ShuffleExchangeParams;
 pub use rpc::TransformExchangeDeserializer;
@@ -43,13 +43,21 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    /// The plan id of the broadcast join, if the data is kept on the local node until
+    /// the join switches to a partitioned join.
+    pub adaptive_join: Option<u32>,
 }
 
 impl ShuffleDataExchange {
-    pub fn create(destination_ids: Vec<String>, shuffle_keys: Vec<RemoteExpr>) -> DataExchange {
+    pub fn create(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<RemoteExpr>,
+        adaptive_join: Option<u32>,
+    ) -> DataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            adaptive_join,
         })
     }
 }
@@ -23,11 +23,13 @@ use crate::api::rpc::exchange::serde::exchange_deserializer::TransformExchangeDe
 use crate::api::rpc::exchange::serde::exchange_serializer::TransformExchangeSerializer;
 use crate::api::rpc::exchange::serde::exchange_serializer::TransformScatterExchangeSerializer;
 use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::AdaptiveFlightScatter;
 use crate::api::BroadcastFlightScatter;
 use crate::api::DataExchange;
 use crate::api::ExchangeSorting;
 use crate::api::HashFlightScatter;
 use crate::api::ShuffleExchangeParams;
+use crate::clusters::ClusterHelper;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -83,11 +85,23 @@ impl ExchangeInjector for DefaultExchangeInjector {
             DataExchange::Broadcast(exchange) => Box::new(BroadcastFlightScatter::try_create(
                 exchange.destination_ids.len(),
             )?),
-            DataExchange::ShuffleDataExchange(exchange) => HashFlightScatter::try_create(
-                ctx.get_function_context()?,
-                exchange.shuffle_keys.clone(),
-                exchange.destination_ids.len(),
-            )?,
+            DataExchange::ShuffleDataExchange(exchange) => {
+                let hash_scatter = HashFlightScatter::try_create(
+                    ctx.get_function_context()?,
+                    exchange.shuffle_keys.clone(),
+                    exchange.destination_ids.len(),
+                )?;
+
+                match exchange.adaptive_join {
+                    None => hash_scatter,
+                    Some(plan_id) => AdaptiveFlightScatter::try_create(
+                        ctx.get_adaptive_join_state(plan_id),
+                        hash_scatter,
+                        &exchange.destination_ids,
+                        &ctx.get_cluster().local_id(),
+                    )?,
+                }
+            }
         }))
     }
 
@@ -773,6 +773,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        adaptive_join: None,
                     }))
                 }
                 DataExchange::ShuffleDataExchange(exchange) => {
@@ -785,6 +786,9 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        adaptive_join: exchange
+                            .adaptive_join
+                            .map(|plan_id| info.query_ctx.get_adaptive_join_state(plan_id)),
                     }))
                 }
             };
@@ -23,6 +23,7 @@ use common_expression::DataSchemaRef;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::ExchangeInjector;
+use crate::pipelines::processors::transforms::AdaptiveJoinState;
 
 #[derive(Clone)]
 pub struct SerializeParams {
@@ -40,6 +41,8 @@ pub struct ShuffleExchangeParams {
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    /// Set if the data is held until the broadcast join decides whether it switches
+    pub adaptive_join: Option<Arc<AdaptiveJoinState>>,
 }
 
 #[derive(Clone)]
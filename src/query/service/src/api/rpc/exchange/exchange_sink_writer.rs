@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -24,6 +25,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_sinks::AsyncSink;
 use common_pipeline_sinks::AsyncSinker;
+use common_profile::ProfCounters;
 
 use crate::api::rpc::exchange::serde::exchange_serializer::ExchangeSerializeMeta;
 use crate::api::rpc::flight_client::FlightSender;
@@ -64,7 +66,10 @@ impl AsyncSink for ExchangeWriterSink {
         }?;
 
         for packet in serialize_meta.packet {
-            if let Err(error) = self.flight_sender.send(packet).await {
+            let instant = Instant::now();
+            let res = self.flight_sender.send(packet).await;
+            ProfCounters::record_exchange_wait_time(instant.elapsed().as_nanos() as u64);
+            if let Err(error) = res {
                 if error.code() == ErrorCode::ABORTED_QUERY {
                     return Ok(true);
                 }
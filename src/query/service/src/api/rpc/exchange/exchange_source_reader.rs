@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_expression::DataBlock;
 use common_pipeline_core::pipe::Pipe;
@@ -25,6 +26,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::TransformDummy;
+use common_profile::ProfCounters;
 
 use crate::api::rpc::exchange::serde::exchange_deserializer::ExchangeDeserializeMeta;
 use crate::api::rpc::flight_client::FlightReceiver;
@@ -92,7 +94,13 @@ impl Processor for ExchangeSourceReader {
         if self.output_data.is_empty() {
             let mut dictionaries = Vec::new();
 
-            while let Some(output_data) = self.flight_receiver.recv().await? {
+            loop {
+                let instant = Instant::now();
+                let output_data = self.flight_receiver.recv().await?;
+                ProfCounters::record_exchange_wait_time(instant.elapsed().as_nanos() as u64);
+                let Some(output_data) = output_data else {
+                    break;
+                };
                 if !matches!(&output_data, DataPacket::Dictionary(_)) {
                     dictionaries.push(output_data);
                     self.output_data = dictionaries;
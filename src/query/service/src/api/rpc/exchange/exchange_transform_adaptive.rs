@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+
+use crate::pipelines::processors::transforms::AdaptiveJoinState;
+
+/// Holds the probe rows of a broadcast join until the join decides whether it switches to a
+/// partitioned join, so that they are scattered the way the join decides.
+pub struct TransformAdaptiveJoinWait {
+    state: Arc<AdaptiveJoinState>,
+}
+
+impl TransformAdaptiveJoinWait {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        state: Arc<AdaptiveJoinState>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AsyncTransformer::create(
+            input,
+            output,
+            TransformAdaptiveJoinWait { state },
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAdaptiveJoinWait {
+    const NAME: &'static str = "AdaptiveJoinWait";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        self.state.wait_decided().await;
+        Ok(data)
+    }
+}
@@ -36,6 +36,7 @@ use common_pipeline_core::Pipeline;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
 use crate::api::rpc::exchange::exchange_sorting::ExchangeSorting;
 use crate::api::rpc::exchange::exchange_sorting::TransformExchangeSorting;
+use crate::api::rpc::exchange::exchange_transform_adaptive::TransformAdaptiveJoinWait;
 use crate::api::rpc::exchange::exchange_transform_scatter::ScatterTransform;
 use crate::api::rpc::exchange::serde::exchange_serializer::ExchangeSerializeMeta;
 
@@ -276,6 +277,16 @@ impl ExchangeShuffleTransform {
 
 // Scatter the data block and push it to the corresponding output port
 pub fn exchange_shuffle(params: &ShuffleExchangeParams, pipeline: &mut Pipeline) -> Result<()> {
+    if let Some(state) = &params.adaptive_join {
+        pipeline.add_transform(|input, output| {
+            Ok(TransformAdaptiveJoinWait::create(
+                input,
+                output,
+                state.clone(),
+            ))
+        })?;
+    }
+
     // append scatter transform
     pipeline.add_transform(|input, output| {
         Ok(ScatterTransform::create(
@@ -22,6 +22,7 @@ mod exchange_sorting;
 mod exchange_source;
 mod exchange_source_reader;
 mod exchange_transform;
+mod exchange_transform_adaptive;
 mod exchange_transform_scatter;
 mod exchange_transform_shuffle;
 mod serde;
@@ -38,7 +39,9 @@ pub use exchange_manager::DataExchangeManager;
 pub use exchange_params::MergeExchangeParams;
 pub use exchange_params::ShuffleExchangeParams;
 pub use exchange_sorting::ExchangeSorting;
+pub use exchange_transform_scatter::ScatterTransform;
 pub use exchange_transform_shuffle::ExchangeShuffleMeta;
+pub use exchange_transform_shuffle::ExchangeShuffleTransform;
 
 pub use self::serde::exchange_deserializer::ExchangeDeserializeMeta;
 pub use self::serde::exchange_deserializer::TransformExchangeDeserializer;
@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::pipelines::processors::transforms::AdaptiveJoinState;
+
+/// Scatters the probe rows of a broadcast join that may switch to a partitioned join. The rows
+/// are kept on the local node, or scattered by hash once the join on the local node switches.
+pub struct AdaptiveFlightScatter {
+    state: Arc<AdaptiveJoinState>,
+    hash_scatter: Box<dyn FlightScatter>,
+    scatter_size: usize,
+    local_pos: usize,
+}
+
+impl AdaptiveFlightScatter {
+    pub fn try_create(
+        state: Arc<AdaptiveJoinState>,
+        hash_scatter: Box<dyn FlightScatter>,
+        destination_ids: &[String],
+        local_id: &str,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let local_pos = destination_ids
+            .iter()
+            .position(|destination_id| destination_id == local_id)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the local executor {} in the destinations",
+                    local_id
+                ))
+            })?;
+
+        Ok(Box::new(AdaptiveFlightScatter {
+            state,
+            hash_scatter,
+            scatter_size: destination_ids.len(),
+            local_pos,
+        }))
+    }
+}
+
+impl FlightScatter for AdaptiveFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if self.state.is_partitioned() {
+            return self.hash_scatter.execute(data_block);
+        }
+
+        let mut data_blocks = Vec::with_capacity(self.scatter_size);
+        for index in 0..self.scatter_size {
+            match index == self.local_pos {
+                true => data_blocks.push(data_block.clone()),
+                false => data_blocks.push(data_block.slice(0..0)),
+            }
+        }
+
+        Ok(data_blocks)
+    }
+}
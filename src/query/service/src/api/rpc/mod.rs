@@ -20,6 +20,7 @@ mod exchange;
 mod flight_actions;
 mod flight_client;
 mod flight_scatter;
+mod flight_scatter_adaptive;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
 mod flight_service;
@@ -35,13 +36,16 @@ pub use exchange::ExchangeDeserializeMeta;
 pub use exchange::ExchangeInjector;
 pub use exchange::ExchangeSerializeMeta;
 pub use exchange::ExchangeShuffleMeta;
+pub use exchange::ExchangeShuffleTransform;
 pub use exchange::ExchangeSorting;
 pub use exchange::MergeExchange;
 pub use exchange::MergeExchangeParams;
+pub use exchange::ScatterTransform;
 pub use exchange::ShuffleDataExchange;
 pub use exchange::ShuffleExchangeParams;
 pub use exchange::TransformExchangeDeserializer;
 pub use flight_scatter::FlightScatter;
+pub use flight_scatter_adaptive::AdaptiveFlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
 pub use flight_scatter_hash::HashFlightScatter;
 pub use packets::ConnectionInfo;
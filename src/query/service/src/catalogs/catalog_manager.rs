@@ -33,6 +33,7 @@ use common_storages_iceberg::IcebergCatalog;
 use dashmap::DashMap;
 
 use crate::catalogs::DatabaseCatalog;
+use crate::catalogs::DatabendCatalog;
 
 #[async_trait::async_trait]
 pub trait CatalogManagerHelper {
@@ -152,6 +153,13 @@ impl CatalogManagerHelper for CatalogManager {
                     data_operator,
                 )?);
 
+                let if_not_exists = req.if_not_exists;
+                self.insert_catalog(ctl_name, catalog, if_not_exists)
+            }
+            CatalogOption::Databend(opt) => {
+                let ctl_name = &req.name_ident.catalog_name;
+                let catalog: Arc<dyn Catalog> = Arc::new(DatabendCatalog::create(ctl_name, opt));
+
                 let if_not_exists = req.if_not_exists;
                 self.insert_catalog(ctl_name, catalog, if_not_exists)
             }
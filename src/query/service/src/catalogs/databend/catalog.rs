@@ -0,0 +1,469 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::database::Database;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_meta_app::schema::CountTablesReply;
+use common_meta_app::schema::CountTablesReq;
+use common_meta_app::schema::CreateDatabaseReply;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateIndexReply;
+use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateVirtualColumnReply;
+use common_meta_app::schema::CreateVirtualColumnReq;
+use common_meta_app::schema::DatabendCatalogOption;
+use common_meta_app::schema::DropDatabaseReply;
+use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropIndexReply;
+use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropVirtualColumnReply;
+use common_meta_app::schema::DropVirtualColumnReq;
+use common_meta_app::schema::GetTableCopiedFileReply;
+use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_meta_app::schema::RenameDatabaseReply;
+use common_meta_app::schema::RenameDatabaseReq;
+use common_meta_app::schema::RenameTableReply;
+use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TruncateTableReply;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::schema::UndropDatabaseReply;
+use common_meta_app::schema::UndropDatabaseReq;
+use common_meta_app::schema::UndropTableReply;
+use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateTableMetaReply;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateVirtualColumnReply;
+use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertTableOptionReply;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::schema::VirtualColumnMeta;
+use common_meta_types::MetaId;
+
+use crate::catalogs::databend::client::quote_string;
+use crate::catalogs::databend::DatabendClient;
+use crate::catalogs::databend::DatabendDatabase;
+use crate::catalogs::databend::DatabendTable;
+use crate::interpreters::common::quote_ident;
+
+/// A catalog of a remote databend deployment, its tables are read through the Flight SQL handler
+/// of the deployment and can be joined with the local ones.
+///
+/// The catalog is read-only, the databases and tables are looked up on the remote deployment on
+/// each access.
+pub struct DatabendCatalog {
+    name: String,
+    client: Arc<DatabendClient>,
+}
+
+impl DatabendCatalog {
+    pub fn create(name: &str, option: DatabendCatalogOption) -> DatabendCatalog {
+        DatabendCatalog {
+            name: name.to_string(),
+            client: Arc::new(DatabendClient::create(option)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_get_table(&self, db_name: &str, table_name: &str) -> Result<Arc<dyn Table>> {
+        let table_ids = self
+            .client
+            .query_strings(&format!(
+                "SELECT CAST(table_id AS STRING) FROM system.tables WHERE database = {} AND name = {}",
+                quote_string(db_name),
+                quote_string(table_name)
+            ))
+            .await?;
+        let Some(table_id) = table_ids.first() else {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table `{}`.`{}` in catalog '{}'",
+                db_name, table_name, self.name
+            )));
+        };
+        let table_id = table_id.parse::<u64>().unwrap_or_default();
+
+        let schema = self
+            .client
+            .query_schema(&format!(
+                "SELECT * FROM {}.{}",
+                quote_ident(db_name),
+                quote_ident(table_name)
+            ))
+            .await?;
+        let schema = infer_table_schema(&Arc::new(schema))?;
+        Ok(Arc::new(DatabendTable::create(
+            &self.name,
+            db_name,
+            table_name,
+            table_id,
+            schema,
+            self.client.clone(),
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl Catalog for DatabendCatalog {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[async_backtrace::framed]
+    async fn get_database(&self, tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        let databases = self
+            .client
+            .query_strings(&format!(
+                "SELECT name FROM system.databases WHERE name = {}",
+                quote_string(db_name)
+            ))
+            .await?;
+        if databases.is_empty() {
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "Unknown database `{}` in catalog '{}'",
+                db_name, self.name
+            )));
+        }
+        Ok(Arc::new(DatabendDatabase::create(tenant, db_name)))
+    }
+
+    // Get all the databases.
+    #[async_backtrace::framed]
+    async fn list_databases(&self, tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
+        let databases = self
+            .client
+            .query_strings("SELECT name FROM system.databases ORDER BY name")
+            .await?;
+        Ok(databases
+            .iter()
+            .map(|db_name| Arc::new(DatabendDatabase::create(tenant, db_name)) as Arc<dyn Database>)
+            .collect())
+    }
+
+    // Operation with database.
+    #[async_backtrace::framed]
+    async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot create database in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_database(&self, _req: DropDatabaseReq) -> Result<DropDatabaseReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop database in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_database(&self, _req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot undrop database in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_database(&self, _req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot rename database in DATABEND catalog",
+        ))
+    }
+
+    fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        let res: Arc<dyn Table> =
+            Arc::new(DatabendTable::from_info(table_info, self.client.clone())?);
+        Ok(res)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_meta_by_id(
+        &self,
+        _table_id: MetaId,
+    ) -> Result<(TableIdent, Arc<TableMeta>)> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot get table by id in DATABEND catalog",
+        ))
+    }
+
+    // Get one table by db and table name.
+    #[async_backtrace::framed]
+    async fn get_table(
+        &self,
+        _tenant: &str,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Arc<dyn Table>> {
+        self.do_get_table(db_name, table_name).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self, _tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let table_names = self
+            .client
+            .query_strings(&format!(
+                "SELECT name FROM system.tables WHERE database = {} ORDER BY name",
+                quote_string(db_name)
+            ))
+            .await?;
+        let mut tables = Vec::with_capacity(table_names.len());
+        for table_name in table_names {
+            tables.push(self.do_get_table(db_name, &table_name).await?);
+        }
+        Ok(tables)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables_history(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot list table history in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot create table in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_table_by_id(&self, _req: DropTableByIdReq) -> Result<DropTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop table in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot undrop table in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot rename table in DATABEND catalog",
+        ))
+    }
+
+    // Check a db.table is exists or not.
+    #[async_backtrace::framed]
+    async fn exists_table(&self, tenant: &str, db_name: &str, table_name: &str) -> Result<bool> {
+        match self.get_table(tenant, db_name, table_name).await {
+            Ok(_) => Ok(true),
+            Err(err) => {
+                if err.code() == ErrorCode::UNKNOWN_TABLE {
+                    Ok(false)
+                } else {
+                    Err(err)
+                }
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn upsert_table_option(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        _req: UpsertTableOptionReq,
+    ) -> Result<UpsertTableOptionReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot upsert table option in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn update_table_meta(
+        &self,
+        _table_info: &TableInfo,
+        _req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot update table meta in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_copied_file_info(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        _req: GetTableCopiedFileReq,
+    ) -> Result<GetTableCopiedFileReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot get table copied file info in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate_table(
+        &self,
+        _table_info: &TableInfo,
+        _req: TruncateTableReq,
+    ) -> Result<TruncateTableReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot truncate table in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn list_table_lock_revs(&self, _table_id: u64) -> Result<Vec<u64>> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot list table lock revisions in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table_lock_rev(
+        &self,
+        _expire_sec: u64,
+        _table_info: &TableInfo,
+    ) -> Result<CreateTableLockRevReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot create table lock in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn extend_table_lock_rev(
+        &self,
+        _expire_sec: u64,
+        _table_info: &TableInfo,
+        _revision: u64,
+    ) -> Result<()> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot extend table lock in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_table_lock_rev(&self, _table_info: &TableInfo, _revision: u64) -> Result<()> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot delete table lock in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn count_tables(&self, _req: CountTablesReq) -> Result<CountTablesReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot count tables in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn create_index(&self, _req: CreateIndexReq) -> Result<CreateIndexReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot create index in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_index(&self, _req: DropIndexReq) -> Result<DropIndexReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop index in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn list_indexes(&self, _req: ListIndexesReq) -> Result<Vec<(u64, String, IndexMeta)>> {
+        Ok(vec![])
+    }
+
+    #[async_backtrace::framed]
+    async fn create_virtual_column(
+        &self,
+        _req: CreateVirtualColumnReq,
+    ) -> Result<CreateVirtualColumnReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot create virtual column in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn update_virtual_column(
+        &self,
+        _req: UpdateVirtualColumnReq,
+    ) -> Result<UpdateVirtualColumnReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot update virtual column in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_virtual_column(
+        &self,
+        _req: DropVirtualColumnReq,
+    ) -> Result<DropVirtualColumnReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop virtual column in DATABEND catalog",
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn list_virtual_columns(
+        &self,
+        _req: ListVirtualColumnsReq,
+    ) -> Result<Vec<VirtualColumnMeta>> {
+        Ok(vec![])
+    }
+
+    /// Table function
+
+    // Get function by name.
+    fn get_table_function(
+        &self,
+        func_name: &str,
+        _tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        Err(ErrorCode::Unimplemented(format!(
+            "Cannot get table function {} in DATABEND catalog",
+            func_name
+        )))
+    }
+
+    // List all table functions' names.
+    fn list_table_functions(&self) -> Vec<String> {
+        vec![]
+    }
+
+    // Get table engines
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        vec![]
+    }
+}
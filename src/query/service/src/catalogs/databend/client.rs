@@ -0,0 +1,164 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
+use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_schema::ArrowError;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::SendableDataBlockStream;
+use common_grpc::ConnectionFactory;
+use common_meta_app::schema::DatabendCatalogOption;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use tonic::transport::Channel;
+
+/// Runs queries on a remote databend deployment through its Flight SQL handler.
+pub struct DatabendClient {
+    option: DatabendCatalogOption,
+}
+
+impl DatabendClient {
+    const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn create(option: DatabendCatalogOption) -> Self {
+        DatabendClient { option }
+    }
+
+    fn query_error(&self, sql: &str, cause: ArrowError) -> ErrorCode {
+        ErrorCode::ReadTableDataError(format!(
+            "remote query `{}` on {} failed: {}",
+            sql, self.option.address, cause
+        ))
+    }
+
+    /// Every query runs in a session of its own, the sessions are not shared between queries.
+    #[async_backtrace::framed]
+    async fn connect(&self) -> Result<FlightSqlServiceClient<Channel>> {
+        let channel = ConnectionFactory::create_rpc_channel(
+            &self.option.address,
+            Some(Self::CONNECT_TIMEOUT),
+            None,
+        )
+        .await?;
+        let mut client = FlightSqlServiceClient::new(channel);
+        client
+            .handshake(&self.option.username, &self.option.password)
+            .await
+            .map_err(|cause| {
+                ErrorCode::CannotConnectNode(format!(
+                    "cannot authenticate to remote databend {}: {}",
+                    self.option.address, cause
+                ))
+            })?;
+        Ok(client)
+    }
+
+    /// Returns the schema of the result of `sql`, the query is planned but not run.
+    #[async_backtrace::framed]
+    pub async fn query_schema(&self, sql: &str) -> Result<DataSchema> {
+        let mut client = self.connect().await?;
+        let stmt = client
+            .prepare(sql.to_string())
+            .await
+            .map_err(|cause| self.query_error(sql, cause))?;
+        let schema = stmt
+            .dataset_schema()
+            .and_then(DataSchema::try_from)
+            .map_err(|cause| self.query_error(sql, cause))?;
+        Ok(schema)
+    }
+
+    /// Runs `sql`, the result blocks are streamed back as they arrive.
+    #[async_backtrace::framed]
+    pub async fn query(&self, sql: &str) -> Result<SendableDataBlockStream> {
+        let mut client = self.connect().await?;
+        let mut stmt = client
+            .prepare(sql.to_string())
+            .await
+            .map_err(|cause| self.query_error(sql, cause))?;
+        let flight_info = stmt
+            .execute()
+            .await
+            .map_err(|cause| self.query_error(sql, cause))?;
+
+        let mut streams = Vec::with_capacity(flight_info.endpoint.len());
+        for endpoint in flight_info.endpoint {
+            let Some(ticket) = endpoint.ticket else {
+                continue;
+            };
+            let flight_data = client
+                .do_get(ticket)
+                .await
+                .map_err(|cause| self.query_error(sql, cause))?;
+            let sql = sql.to_string();
+            let address = self.option.address.clone();
+            let blocks = FlightRecordBatchStream::new_from_flight_data(
+                flight_data.map_err(FlightError::Tonic),
+            )
+            .map(move |batch| {
+                let block = batch
+                    .map_err(|cause| cause.to_string())
+                    .and_then(|batch| {
+                        DataBlock::from_record_batch(&batch).map_err(|cause| cause.to_string())
+                    })
+                    .map_err(|cause| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "remote query `{}` on {} failed: {}",
+                            sql, address, cause
+                        ))
+                    })?;
+                Ok(block.0)
+            });
+            streams.push(blocks);
+        }
+        Ok(Box::pin(futures::stream::iter(streams).flatten()))
+    }
+
+    /// Runs `sql` and returns the values of its first column, which must be a string column.
+    #[async_backtrace::framed]
+    pub async fn query_strings(&self, sql: &str) -> Result<Vec<String>> {
+        let blocks = self.query(sql).await?.try_collect::<Vec<_>>().await?;
+        let mut values = vec![];
+        for block in blocks {
+            let Some(entry) = block.columns().first() else {
+                continue;
+            };
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            let column = column.remove_nullable();
+            let Some(strings) = column.as_string() else {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "remote query `{}` on {} returned a non-string column",
+                    sql, self.option.address
+                )));
+            };
+            for value in strings.iter() {
+                values.push(String::from_utf8_lossy(value).to_string());
+            }
+        }
+        Ok(values)
+    }
+}
+
+/// Quotes `s` as a string literal of the remote query.
+pub fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
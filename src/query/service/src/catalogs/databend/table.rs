@@ -0,0 +1,410 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataBlock;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::SendableDataBlockStream;
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_pipeline_sources::EmptySource;
+use futures::StreamExt;
+
+use crate::catalogs::databend::client::quote_string;
+use crate::catalogs::databend::DatabendClient;
+use crate::interpreters::common::quote_ident;
+
+pub const DATABEND_TABLE_ENGINE: &str = "DATABEND";
+
+const OPT_KEY_REMOTE_DATABASE: &str = "database";
+const OPT_KEY_REMOTE_TABLE: &str = "table";
+
+/// The remote query reading a table, built from the push-downs of the scan.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct DatabendTablePart {
+    pub sql: String,
+}
+
+#[typetag::serde(name = "databend")]
+impl PartInfo for DatabendTablePart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<DatabendTablePart>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        0
+    }
+}
+
+/// A table of a remote databend deployment.
+///
+/// The table is read by a query sent to the remote deployment, with the projection, the filter
+/// and the limit of the scan pushed down to it. The filter is also applied locally after the
+/// scan, so the parts of it which can not be expressed in SQL are left out of the remote query.
+pub struct DatabendTable {
+    info: TableInfo,
+    client: Arc<DatabendClient>,
+}
+
+impl DatabendTable {
+    pub fn create(
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+        table_id: u64,
+        schema: TableSchemaRef,
+        client: Arc<DatabendClient>,
+    ) -> DatabendTable {
+        let info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'.'{}'", catalog, database, table_name),
+            name: table_name.to_string(),
+            meta: TableMeta {
+                schema,
+                catalog: catalog.to_string(),
+                engine: DATABEND_TABLE_ENGINE.to_string(),
+                engine_options: BTreeMap::from([
+                    (OPT_KEY_REMOTE_DATABASE.to_string(), database.to_string()),
+                    (OPT_KEY_REMOTE_TABLE.to_string(), table_name.to_string()),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        DatabendTable { info, client }
+    }
+
+    pub fn from_info(info: &TableInfo, client: Arc<DatabendClient>) -> Result<DatabendTable> {
+        for key in [OPT_KEY_REMOTE_DATABASE, OPT_KEY_REMOTE_TABLE] {
+            if !info.meta.engine_options.contains_key(key) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "missing {} of databend table {}",
+                    key, info.name
+                )));
+            }
+        }
+        Ok(DatabendTable {
+            info: info.clone(),
+            client,
+        })
+    }
+
+    fn remote_table(&self) -> String {
+        let options = &self.info.meta.engine_options;
+        format!(
+            "{}.{}",
+            quote_ident(&options[OPT_KEY_REMOTE_DATABASE]),
+            quote_ident(&options[OPT_KEY_REMOTE_TABLE])
+        )
+    }
+
+    /// Builds the remote query of a scan, the columns are selected in the order of the output
+    /// schema of the scan.
+    fn build_remote_query(&self, push_downs: Option<&PushDownInfo>) -> String {
+        let schema = self.info.schema();
+        let columns = match push_downs.and_then(|p| p.projection.as_ref()) {
+            Some(Projection::Columns(indices)) => indices
+                .iter()
+                .map(|i| quote_ident(schema.field(*i).name()))
+                .collect::<Vec<_>>(),
+            // the inner fields of tuples are accessed by their 1-based positions.
+            Some(Projection::InnerColumns(path_indices)) => path_indices
+                .values()
+                .map(|path| {
+                    let mut column = format!("({})", quote_ident(schema.field(path[0]).name()));
+                    for i in &path[1..] {
+                        column = format!("({}).{}", column, i + 1);
+                    }
+                    column
+                })
+                .collect::<Vec<_>>(),
+            None => schema
+                .fields()
+                .iter()
+                .map(|f| quote_ident(f.name()))
+                .collect::<Vec<_>>(),
+        };
+
+        let mut sql = format!(
+            "SELECT {} FROM {}",
+            if columns.is_empty() {
+                // no column is read, e.g. `SELECT count(*)`, only the rows are counted.
+                "1".to_string()
+            } else {
+                columns.join(", ")
+            },
+            self.remote_table()
+        );
+        let Some(push_downs) = push_downs else {
+            return sql;
+        };
+
+        if let Some(filter) = push_downs.filter.as_ref().and_then(conjuncts_to_sql) {
+            sql.push_str(&format!(" WHERE {}", filter));
+        }
+        if let Some(limit) = push_downs.limit {
+            if push_downs.order_by.is_empty() {
+                sql.push_str(&format!(" LIMIT {}", limit));
+            } else if let Some(order_by) = push_downs
+                .order_by
+                .iter()
+                .map(|(expr, asc, nulls_first)| {
+                    expr_to_sql(expr).map(|expr| {
+                        format!(
+                            "{} {} NULLS {}",
+                            expr,
+                            if *asc { "ASC" } else { "DESC" },
+                            if *nulls_first { "FIRST" } else { "LAST" }
+                        )
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+            {
+                // the limit is only correct with the order of the scan.
+                sql.push_str(&format!(
+                    " ORDER BY {} LIMIT {}",
+                    order_by.join(", "),
+                    limit
+                ));
+            }
+        }
+        sql
+    }
+}
+
+/// Renders the conjuncts of a filter which can be expressed in SQL, the others are left to the
+/// local filter.
+fn conjuncts_to_sql(filter: &RemoteExpr<String>) -> Option<String> {
+    fn collect<'a>(expr: &'a RemoteExpr<String>, conjuncts: &mut Vec<&'a RemoteExpr<String>>) {
+        match expr {
+            RemoteExpr::FunctionCall { id, args, .. }
+                if id.name() == "and" || id.name() == "and_filters" =>
+            {
+                args.iter().for_each(|arg| collect(arg, conjuncts));
+            }
+            _ => conjuncts.push(expr),
+        }
+    }
+
+    let mut conjuncts = vec![];
+    collect(filter, &mut conjuncts);
+    let conjuncts = conjuncts
+        .into_iter()
+        .filter_map(expr_to_sql)
+        .collect::<Vec<_>>();
+    if conjuncts.is_empty() {
+        None
+    } else {
+        Some(conjuncts.join(" AND "))
+    }
+}
+
+/// Renders an expression in SQL, returns `None` if the expression uses anything but columns,
+/// literals, comparisons, logical and arithmetic operators.
+fn expr_to_sql(expr: &RemoteExpr<String>) -> Option<String> {
+    match expr {
+        RemoteExpr::Constant { scalar, .. } => match scalar {
+            Scalar::Null => Some("NULL".to_string()),
+            // the floats are displayed rounded.
+            Scalar::Number(NumberScalar::Float32(_) | NumberScalar::Float64(_)) => None,
+            Scalar::Number(_) | Scalar::Decimal(_) | Scalar::Boolean(_) => Some(scalar.to_string()),
+            Scalar::String(s) => {
+                let s = std::str::from_utf8(s).ok()?;
+                Some(quote_string(s))
+            }
+            // the literals of dates and timestamps depend on the timezone of the session.
+            _ => None,
+        },
+        RemoteExpr::ColumnRef { id, .. } => Some(quote_ident(id)),
+        RemoteExpr::Cast {
+            is_try,
+            expr,
+            dest_type,
+            ..
+        } => {
+            if !matches!(
+                dest_type.remove_nullable(),
+                DataType::Number(_) | DataType::Decimal(_) | DataType::String | DataType::Boolean
+            ) {
+                return None;
+            }
+            Some(format!(
+                "{}({} AS {})",
+                if *is_try { "TRY_CAST" } else { "CAST" },
+                expr_to_sql(expr)?,
+                dest_type
+            ))
+        }
+        RemoteExpr::FunctionCall { id, args, .. } => {
+            let args = args.iter().map(expr_to_sql).collect::<Option<Vec<_>>>()?;
+            let op = match id.name().as_ref() {
+                "and" | "and_filters" => "AND",
+                "or" => "OR",
+                "eq" => "=",
+                "noteq" => "<>",
+                "gt" => ">",
+                "gte" => ">=",
+                "lt" => "<",
+                "lte" => "<=",
+                "plus" => "+",
+                "minus" => "-",
+                "multiply" => "*",
+                "divide" => "/",
+                "modulo" => "%",
+                "like" => "LIKE",
+                "not" if args.len() == 1 => return Some(format!("(NOT {})", args[0])),
+                "is_null" if args.len() == 1 => return Some(format!("({} IS NULL)", args[0])),
+                "is_not_null" if args.len() == 1 => {
+                    return Some(format!("({} IS NOT NULL)", args[0]));
+                }
+                // a filter is true only if its result is true, as in the `WHERE` clause.
+                "is_true" if args.len() == 1 => return Some(args[0].clone()),
+                _ => return None,
+            };
+            if args.len() < 2 {
+                return None;
+            }
+            Some(format!("({})", args.join(&format!(" {} ", op))))
+        }
+        RemoteExpr::LambdaFunctionCall { .. } => None,
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DatabendTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let sql = self.build_remote_query(push_downs.as_ref());
+        Ok((
+            PartStatistics::default(),
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, vec![Arc::new(Box::new(
+                DatabendTablePart { sql },
+            ))]),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let sql = match plan.parts.partitions.first() {
+            Some(part) => match part.as_any().downcast_ref::<DatabendTablePart>() {
+                Some(part) => part.sql.clone(),
+                None => {
+                    return Err(ErrorCode::Internal(
+                        "Cannot downcast from PartInfo to DatabendTablePart.",
+                    ));
+                }
+            },
+            None => {
+                pipeline.add_source(EmptySource::create, 1)?;
+                return Ok(());
+            }
+        };
+
+        let client = self.client.clone();
+        pipeline.add_source(
+            |output| DatabendTableSource::create(ctx.clone(), output, client.clone(), sql.clone()),
+            1,
+        )
+    }
+}
+
+/// Streams the result blocks of the remote query of a scan.
+struct DatabendTableSource {
+    client: Arc<DatabendClient>,
+    sql: String,
+    stream: Option<SendableDataBlockStream>,
+}
+
+impl DatabendTableSource {
+    fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        client: Arc<DatabendClient>,
+        sql: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output, DatabendTableSource {
+            client,
+            sql,
+            stream: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for DatabendTableSource {
+    const NAME: &'static str = "DatabendTableSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.stream.is_none() {
+            self.stream = Some(self.client.query(&self.sql).await?);
+        }
+        match self.stream.as_mut().unwrap().next().await {
+            Some(block) => block.map(Some),
+            None => Ok(None),
+        }
+    }
+}
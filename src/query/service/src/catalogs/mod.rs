@@ -15,12 +15,14 @@
 mod catalog;
 
 mod catalog_manager;
+pub mod databend;
 pub mod default;
 pub use catalog::Catalog;
 pub use catalog_manager::CatalogManager;
 pub use catalog_manager::CatalogManagerHelper;
 #[cfg(feature = "hive")]
 pub use common_storages_hive as hive;
+pub use databend::DatabendCatalog;
 pub use default::table_id_ranges::*;
 pub use default::table_memory_meta::InMemoryMetas;
 pub use default::DatabaseCatalog;
@@ -0,0 +1,271 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Mutex;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::RwLock;
+use serde::Serialize;
+use tracing::info;
+use tracing::warn;
+
+use crate::sessions::SessionManager;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarehouseState {
+    Running,
+    Suspending,
+    Suspended,
+    Resuming,
+}
+
+impl Display for WarehouseState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarehouseState::Running => write!(f, "running"),
+            WarehouseState::Suspending => write!(f, "suspending"),
+            WarehouseState::Suspended => write!(f, "suspended"),
+            WarehouseState::Resuming => write!(f, "resuming"),
+        }
+    }
+}
+
+/// Hook that releases or acquires the compute resources of the warehouse,
+/// e.g. by scaling a kubernetes statefulset or calling a cloud scaler.
+#[async_trait::async_trait]
+pub trait WarehouseScaler: Send + Sync {
+    async fn suspend(&self) -> Result<()>;
+
+    async fn resume(&self) -> Result<()>;
+}
+
+#[derive(Serialize)]
+struct ScaleRequest<'a> {
+    action: &'a str,
+    tenant_id: &'a str,
+    cluster_id: &'a str,
+}
+
+/// Scaler that POSTs `{"action": "suspend" | "resume", "tenant_id", "cluster_id"}`
+/// to an external endpoint, any non-2xx response is treated as a failure.
+pub struct WebhookScaler {
+    url: String,
+    tenant_id: String,
+    cluster_id: String,
+    client: reqwest::Client,
+}
+
+impl WebhookScaler {
+    pub fn create(url: &str, tenant_id: &str, cluster_id: &str) -> WebhookScaler {
+        WebhookScaler {
+            url: url.to_string(),
+            tenant_id: tenant_id.to_string(),
+            cluster_id: cluster_id.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn call(&self, action: &str) -> Result<()> {
+        let request = ScaleRequest {
+            action,
+            tenant_id: &self.tenant_id,
+            cluster_id: &self.cluster_id,
+        };
+
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|cause| {
+                ErrorCode::Internal(format!(
+                    "Cannot call auto suspend webhook {}, cause: {:?}",
+                    self.url, cause
+                ))
+            })?;
+
+        match response.status().is_success() {
+            true => Ok(()),
+            false => Err(ErrorCode::Internal(format!(
+                "Auto suspend webhook {} returned {} for {}",
+                self.url,
+                response.status(),
+                action
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WarehouseScaler for WebhookScaler {
+    #[async_backtrace::framed]
+    async fn suspend(&self) -> Result<()> {
+        self.call("suspend").await
+    }
+
+    #[async_backtrace::framed]
+    async fn resume(&self) -> Result<()> {
+        self.call("resume").await
+    }
+}
+
+/// Suspends the warehouse once no user query has run for `auto_suspend_secs`,
+/// and resumes it again when the next query arrives.
+pub struct WarehouseAutoSuspend {
+    idle_timeout: Duration,
+    state: RwLock<WarehouseState>,
+    scaler: RwLock<Option<Arc<dyn WarehouseScaler>>>,
+    // Serializes suspend and resume so that they never interleave.
+    transition: Mutex<()>,
+}
+
+impl WarehouseAutoSuspend {
+    const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scaler: Option<Arc<dyn WarehouseScaler>> =
+            match conf.query.auto_suspend_webhook_url.is_empty() {
+                true => None,
+                false => Some(Arc::new(WebhookScaler::create(
+                    &conf.query.auto_suspend_webhook_url,
+                    &conf.query.tenant_id,
+                    &conf.query.cluster_id,
+                ))),
+            };
+
+        let auto_suspend = Arc::new(WarehouseAutoSuspend {
+            idle_timeout: Duration::from_secs(conf.query.auto_suspend_secs),
+            state: RwLock::new(WarehouseState::Running),
+            scaler: RwLock::new(scaler),
+            transition: Mutex::new(()),
+        });
+
+        GlobalInstance::set(auto_suspend.clone());
+
+        if conf.query.auto_suspend_secs > 0 {
+            tokio::spawn(async_backtrace::location!().frame(auto_suspend.idle_loop()));
+        }
+
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WarehouseAutoSuspend> {
+        GlobalInstance::get()
+    }
+
+    /// Replace the hook used to suspend and resume the warehouse.
+    pub fn set_scaler(&self, scaler: Arc<dyn WarehouseScaler>) {
+        *self.scaler.write() = Some(scaler);
+    }
+
+    pub fn get_state(&self) -> WarehouseState {
+        *self.state.read()
+    }
+
+    fn set_state(&self, state: WarehouseState) {
+        info!("Warehouse state changed to {}", state);
+        *self.state.write() = state;
+    }
+
+    fn get_scaler(&self) -> Option<Arc<dyn WarehouseScaler>> {
+        self.scaler.read().clone()
+    }
+
+    /// Called before a user query runs, blocks until the warehouse is running again.
+    #[async_backtrace::framed]
+    pub async fn resume_if_suspended(&self) -> Result<()> {
+        if self.get_state() == WarehouseState::Running {
+            return Ok(());
+        }
+
+        let _guard = self.transition.lock().await;
+        if self.get_state() == WarehouseState::Running {
+            return Ok(());
+        }
+
+        self.set_state(WarehouseState::Resuming);
+        if let Some(scaler) = self.get_scaler() {
+            if let Err(cause) = scaler.resume().await {
+                self.set_state(WarehouseState::Suspended);
+                return Err(cause.add_message_back(" (while resuming the warehouse)"));
+            }
+        }
+
+        self.set_state(WarehouseState::Running);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn suspend_if_idle(&self) -> Result<()> {
+        if self.get_state() != WarehouseState::Running || !self.is_idle() {
+            return Ok(());
+        }
+
+        let _guard = self.transition.lock().await;
+        // A query may have arrived while waiting for the lock.
+        if self.get_state() != WarehouseState::Running || !self.is_idle() {
+            return Ok(());
+        }
+
+        self.set_state(WarehouseState::Suspending);
+        if let Some(scaler) = self.get_scaler() {
+            if let Err(cause) = scaler.suspend().await {
+                self.set_state(WarehouseState::Running);
+                return Err(cause);
+            }
+        }
+
+        self.set_state(WarehouseState::Suspended);
+        Ok(())
+    }
+
+    fn is_idle(&self) -> bool {
+        let status = SessionManager::instance().get_current_session_status();
+        if status.running_queries_count > 0 {
+            return false;
+        }
+
+        let last_active = [status.last_query_started_at, status.last_query_finished_at]
+            .into_iter()
+            .flatten()
+            .fold(status.instance_started_at, |a, b| a.max(b));
+
+        match SystemTime::now().duration_since(last_active) {
+            Ok(idle) => idle >= self.idle_timeout,
+            Err(_) => false,
+        }
+    }
+
+    async fn idle_loop(self: Arc<Self>) {
+        loop {
+            tokio_async_sleep(Self::CHECK_INTERVAL).await;
+
+            if let Err(cause) = self.suspend_if_idle().await {
+                warn!("Cannot auto suspend the warehouse, cause {:?}", cause);
+            }
+        }
+    }
+}
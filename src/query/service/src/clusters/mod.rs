@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod auto_suspend;
 mod cluster;
 mod metrics;
 
+pub use auto_suspend::WarehouseAutoSuspend;
+pub use auto_suspend::WarehouseScaler;
+pub use auto_suspend::WarehouseState;
+pub use auto_suspend::WebhookScaler;
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
 pub use cluster::ClusterHelper;
@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_storages_system::BackgroundJobsTable;
 use common_storages_system::BuildOptionsTable;
 use common_storages_system::CachesTable;
 use common_storages_system::CatalogsTable;
@@ -31,22 +32,29 @@ use common_storages_system::ContributorsTable;
 use common_storages_system::CreditsTable;
 use common_storages_system::DatabasesTable;
 use common_storages_system::EnginesTable;
+use common_storages_system::FunctionSignaturesTable;
 use common_storages_system::FunctionsTable;
 use common_storages_system::IndexesTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
 use common_storages_system::MetricsTable;
 use common_storages_system::OneTable;
+use common_storages_system::PipelineTasksTable;
+use common_storages_system::PlanBaselinesTable;
 use common_storages_system::ProcessesTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
+use common_storages_system::QuotasTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TaskHistoryTable;
+use common_storages_system::TasksTable;
 use common_storages_system::TracingTable;
+use common_storages_system::UsageTable;
 use common_storages_system::UsersTable;
 
 use crate::catalogs::InMemoryMetas;
@@ -80,6 +88,7 @@ impl SystemDatabase {
             DatabasesTable::create(sys_db_meta.next_table_id()),
             Arc::new(TracingTable::create(sys_db_meta.next_table_id())),
             ProcessesTable::create(sys_db_meta.next_table_id()),
+            PipelineTasksTable::create(sys_db_meta.next_table_id()),
             ConfigsTable::create(sys_db_meta.next_table_id()),
             MetricsTable::create(sys_db_meta.next_table_id()),
             MallocStatsTable::create(sys_db_meta.next_table_id()),
@@ -97,12 +106,22 @@ impl SystemDatabase {
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
+            QuotasTable::create(sys_db_meta.next_table_id()),
             BuildOptionsTable::create(sys_db_meta.next_table_id()),
             CatalogsTable::create(sys_db_meta.next_table_id()),
             QueryCacheTable::create(sys_db_meta.next_table_id()),
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
             CachesTable::create(sys_db_meta.next_table_id()),
             IndexesTable::create(sys_db_meta.next_table_id()),
+            FunctionSignaturesTable::create(sys_db_meta.next_table_id()),
+            UsageTable::create(sys_db_meta.next_table_id()),
+            TasksTable::create(sys_db_meta.next_table_id()),
+            Arc::new(TaskHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            PlanBaselinesTable::create(sys_db_meta.next_table_id()),
+            BackgroundJobsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
+use common_storage::SpillOperator;
 use common_tracing::QueryLogger;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
@@ -31,6 +32,10 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::clusters::WarehouseAutoSuspend;
+use crate::interpreters::MaterializedViewScheduler;
+use crate::interpreters::TableCompactionScheduler;
+use crate::interpreters::TaskScheduler;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -58,6 +63,7 @@ impl GlobalServices {
         ClusterDiscovery::init(config.clone()).await?;
 
         DataOperator::init(&config.storage).await?;
+        SpillOperator::init(&config.query.spill_local_path).await?;
 
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
@@ -70,6 +76,7 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        WarehouseAutoSuspend::init(&config)?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -79,6 +86,9 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        MaterializedViewScheduler::init(&config)?;
+        TaskScheduler::init(&config)?;
+        TableCompactionScheduler::init(&config)?;
         ShareEndpointManager::init()?;
 
         Ok(())
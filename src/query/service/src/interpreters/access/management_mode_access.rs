@@ -45,6 +45,7 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ShowEngines
                             | RewriteKind::ShowSettings
                             | RewriteKind::ShowFunctions
+                            | RewriteKind::DescribeFunction
                             | RewriteKind::ShowTableFunctions
                             | RewriteKind::ShowUsers
                             | RewriteKind::ShowStages
@@ -53,6 +53,7 @@ impl AccessChecker for PrivilegeAccess {
                     | Some(RewriteKind::ShowColumns)
                     | Some(RewriteKind::ShowEngines)
                     | Some(RewriteKind::ShowFunctions)
+                    | Some(RewriteKind::DescribeFunction)
                     | Some(RewriteKind::ShowTableFunctions) => {
                         return Ok(());
                     }
@@ -60,7 +61,14 @@ impl AccessChecker for PrivilegeAccess {
                 };
                 let metadata = metadata.read().clone();
                 for table in metadata.tables() {
-                    if table.is_source_of_view() {
+                    // The results kept in the session by `RESULT_SCAN` and `DECLARE ... RESULT`
+                    // were checked when their queries ran.
+                    if table.is_source_of_view() || table.table().engine() == "result_scan" {
+                        continue;
+                    }
+                    // The materialized views read in place of their tables are covered by
+                    // the privileges on the tables.
+                    if metadata.is_materialized_view_table(table.index()) {
                         continue;
                     }
                     session
@@ -109,6 +117,54 @@ impl AccessChecker for PrivilegeAccess {
                     .await?
             }
 
+            Plan::CreateBloomIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropBloomIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             // Virtual Column.
             Plan::CreateVirtualColumns(plan) => {
                 session
@@ -292,6 +348,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::AmendTable(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::ReclusterTable(plan) => {
                 session
                     .validate_privilege(
@@ -401,6 +469,29 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                            UserPrivilegeType::Insert,
+                        ],
+                    )
+                    .await?;
+            }
+            Plan::PrepareCommit(plan) => self.check(&plan.plan).await?,
+            Plan::DeclareResult(plan) => self.check(&plan.query).await?,
+            // Only the user who prepared the commit can commit or roll it back,
+            // which is checked by the interpreters.
+            Plan::CommitPrepared(_) | Plan::RollbackPrepared(_) => {}
+            // The statements in a transaction are checked on their own.
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -425,6 +516,75 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(
+                            plan.table.catalog.clone(),
+                            plan.table.database.clone(),
+                        ),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::DropMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
+            // The SQL of tasks runs in the background as the builtin admin.
+            Plan::CreateTask(_) | Plan::AlterTask(_) | Plan::DropTask(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+            }
+            // A plan baseline changes the plans of all the sessions running the query.
+            Plan::AlterStatement(_) => {
+                session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use aggregating_index::get_agg_index_handler;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_license::license_manager::get_license_manager;
+use common_meta_app::schema::ListIndexesReq;
+use common_pipeline_core::Pipeline;
+use common_sql::Binder;
+use common_sql::Metadata;
+use common_sql::NameResolutionContext;
+use common_storages_fuse::FuseTable;
+use parking_lot::RwLock;
+use storages_common_table_meta::meta::Location;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::common::appended_segments;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Build the aggregating index data of the blocks committed by the pipeline
+/// once it is finished.
+///
+/// Failing to build the index data does not fail the ingestion,
+/// the missing index data is built from the raw data when it is read.
+pub fn refresh_agg_index_on_finished(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    pipeline: &mut Pipeline,
+) -> Result<()> {
+    if !table.support_index() || FuseTable::try_from_table(table.as_ref()).is_err() {
+        return Ok(());
+    }
+
+    pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            GlobalIORuntime::instance().block_on(async move {
+                if let Err(e) = refresh_agg_index(ctx, table).await {
+                    warn!("failed to refresh aggregating index: {}", e);
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+#[async_backtrace::framed]
+async fn refresh_agg_index(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let license_manager = get_license_manager();
+    if license_manager
+        .manager
+        .check_enterprise_enabled(
+            &ctx.get_settings(),
+            tenant.clone(),
+            "aggregating_index".to_string(),
+        )
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let table_info = table.get_table_info();
+    let catalog = ctx.get_catalog(table_info.catalog())?;
+    let indexes = catalog
+        .list_indexes(ListIndexesReq::new(
+            &tenant,
+            Some(table_info.ident.table_id),
+        ))
+        .await?;
+    if indexes.is_empty() {
+        return Ok(());
+    }
+
+    let Some((new_table, new_segments)) = appended_segments(ctx.as_ref(), table.as_ref()).await?
+    else {
+        return Ok(());
+    };
+    let fuse_table = FuseTable::try_from_table(new_table.as_ref())?;
+    if new_segments.is_empty() {
+        return Ok(());
+    }
+
+    // A failed index is left to be built from the raw data when it is read,
+    // it does not stop the other indexes from being refreshed.
+    for (index_id, index_name, index_meta) in indexes {
+        match refresh_one_agg_index(
+            ctx.clone(),
+            fuse_table,
+            index_id,
+            &index_meta.query,
+            &new_segments,
+        )
+        .await
+        {
+            Ok(()) => info!(
+                "refreshed aggregating index {} of {} segments",
+                index_name,
+                new_segments.len()
+            ),
+            Err(e) => warn!("failed to refresh aggregating index {}: {}", index_name, e),
+        }
+    }
+
+    Ok(())
+}
+
+#[async_backtrace::framed]
+async fn refresh_one_agg_index(
+    ctx: Arc<QueryContext>,
+    fuse_table: &FuseTable,
+    index_id: u64,
+    query: &str,
+    segments: &[Location],
+) -> Result<()> {
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let Statement::Query(query) = &stmt else {
+        return Ok(());
+    };
+
+    let name_resolution_ctx = NameResolutionContext::try_from(ctx.get_settings().as_ref())?;
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let mut binder = Binder::new(
+        ctx.clone(),
+        CatalogManager::instance(),
+        name_resolution_ctx,
+        metadata,
+    );
+    let Some(definition) = binder.bind_agg_index_definition(query).await? else {
+        return Ok(());
+    };
+
+    get_agg_index_handler()
+        .do_refresh_index(
+            fuse_table,
+            ctx,
+            index_id,
+            &definition,
+            Some(segments.to_vec()),
+        )
+        .await
+}
@@ -0,0 +1,198 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::Result;
+use common_storages_system::BackgroundJobState;
+use common_storages_system::BackgroundJobs;
+use tracing::warn;
+
+/// How a failed background job is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The delay before the first retry, doubled on each consecutive failure.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// The job is quarantined once it fails this many times in a row.
+    pub max_consecutive_failures: u64,
+}
+
+impl RetryPolicy {
+    /// Returns the delay before retrying a job which has failed `consecutive_failures` times.
+    pub fn backoff(&self, consecutive_failures: u64) -> Duration {
+        let exp = consecutive_failures.saturating_sub(1).min(31) as u32;
+        self.initial_backoff
+            .saturating_mul(1 << exp)
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundJobType {
+    Task,
+    MaterializedView,
+    Compaction,
+    Recluster,
+}
+
+impl BackgroundJobType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackgroundJobType::Task => "TASK",
+            BackgroundJobType::MaterializedView => "MATERIALIZED_VIEW",
+            BackgroundJobType::Compaction => "COMPACTION",
+            BackgroundJobType::Recluster => "RECLUSTER",
+        }
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        match self {
+            BackgroundJobType::Task => RetryPolicy {
+                initial_backoff: Duration::from_secs(60),
+                max_backoff: Duration::from_secs(3600),
+                max_consecutive_failures: 5,
+            },
+            BackgroundJobType::MaterializedView => RetryPolicy {
+                initial_backoff: Duration::from_secs(60),
+                max_backoff: Duration::from_secs(3600),
+                max_consecutive_failures: 8,
+            },
+            BackgroundJobType::Compaction | BackgroundJobType::Recluster => RetryPolicy {
+                initial_backoff: Duration::from_secs(300),
+                max_backoff: Duration::from_secs(6 * 3600),
+                max_consecutive_failures: 5,
+            },
+        }
+    }
+}
+
+/// A job run by a background scheduler, its runs are tracked in `system.background_jobs`.
+///
+/// A failed job is retried with exponential backoff, and is quarantined once it fails
+/// [`RetryPolicy::max_consecutive_failures`] times in a row. A quarantined job does not run
+/// until the scheduler releases it.
+pub struct BackgroundJob {
+    job_type: BackgroundJobType,
+    name: String,
+}
+
+impl BackgroundJob {
+    pub fn new(job_type: BackgroundJobType, name: impl Into<String>) -> Self {
+        BackgroundJob {
+            job_type,
+            name: name.into(),
+        }
+    }
+
+    fn state(&self) -> (BackgroundJobState, Option<i64>) {
+        BackgroundJobs::instance()
+            .get(self.job_type.as_str(), &self.name)
+            .map(|status| (status.state, status.next_retry_time))
+            .unwrap_or_default()
+    }
+
+    pub fn is_quarantined(&self) -> bool {
+        self.state().0 == BackgroundJobState::Quarantined
+    }
+
+    /// Returns the start time of the last run in microseconds, if the job has run.
+    pub fn last_run_time(&self) -> Option<i64> {
+        BackgroundJobs::instance()
+            .get(self.job_type.as_str(), &self.name)
+            .and_then(|status| status.last_run_time)
+    }
+
+    /// Returns whether the job may run at `now`, i.e. it is not quarantined and the backoff
+    /// after its last failure has elapsed.
+    pub fn can_run(&self, now: &DateTime<Utc>) -> bool {
+        match self.state() {
+            (BackgroundJobState::Quarantined, _) => false,
+            (_, Some(next_retry_time)) => next_retry_time <= now.timestamp_micros(),
+            (_, None) => true,
+        }
+    }
+
+    /// Returns whether the last run failed and its retry is due at `now`.
+    pub fn is_retry_due(&self, now: &DateTime<Utc>) -> bool {
+        match self.state() {
+            (BackgroundJobState::Retrying, Some(next_retry_time)) => {
+                next_retry_time <= now.timestamp_micros()
+            }
+            _ => false,
+        }
+    }
+
+    pub fn on_start(&self) {
+        let now = Utc::now().timestamp_micros();
+        BackgroundJobs::instance().update(self.job_type.as_str(), &self.name, |status| {
+            status.state = BackgroundJobState::Running;
+            status.last_run_time = Some(now);
+        });
+    }
+
+    /// Records the result of a run, returns the state of the job afterwards.
+    pub fn on_finish(&self, res: &Result<()>) -> BackgroundJobState {
+        let now = Utc::now();
+        let policy = self.job_type.retry_policy();
+        BackgroundJobs::instance().update(self.job_type.as_str(), &self.name, |status| {
+            status.total_runs += 1;
+            match res {
+                Ok(_) => {
+                    status.state = BackgroundJobState::Idle;
+                    status.consecutive_failures = 0;
+                    status.last_success_time = Some(now.timestamp_micros());
+                    status.next_retry_time = None;
+                }
+                Err(cause) => {
+                    status.total_failures += 1;
+                    status.consecutive_failures += 1;
+                    status.last_error_code = cause.code() as i32;
+                    status.last_error_message = cause.message();
+                    if status.consecutive_failures >= policy.max_consecutive_failures {
+                        warn!(
+                            "{} {} is quarantined after {} consecutive failures",
+                            self.job_type.as_str(),
+                            self.name,
+                            status.consecutive_failures
+                        );
+                        status.state = BackgroundJobState::Quarantined;
+                        status.next_retry_time = None;
+                    } else {
+                        let backoff = policy.backoff(status.consecutive_failures);
+                        status.state = BackgroundJobState::Retrying;
+                        status.next_retry_time =
+                            Some(now.timestamp_micros() + backoff.as_micros() as i64);
+                    }
+                }
+            }
+            status.state
+        })
+    }
+
+    /// Releases the job from quarantine, it runs again on its next schedule.
+    pub fn release(&self) {
+        if !self.is_quarantined() {
+            return;
+        }
+        BackgroundJobs::instance().update(self.job_type.as_str(), &self.name, |status| {
+            if status.state == BackgroundJobState::Quarantined {
+                status.state = BackgroundJobState::Idle;
+                status.consecutive_failures = 0;
+            }
+        });
+    }
+}
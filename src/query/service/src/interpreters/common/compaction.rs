@@ -0,0 +1,404 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_expression::BlockThresholds;
+use common_meta_app::principal::UserInfo;
+use common_sql::plans::OptimizeTableAction;
+use common_sql::plans::OptimizeTablePlan;
+use common_sql::plans::ReclusterTablePlan;
+use common_sql::Metadata;
+use common_storages_fuse::FuseTable;
+use common_storages_system::BackgroundJobs;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures::TryStreamExt;
+use parking_lot::RwLock;
+use storages_common_table_meta::meta::Statistics;
+use tracing::info;
+use tracing::warn;
+
+use crate::clusters::ClusterHelper;
+use crate::clusters::WarehouseAutoSuspend;
+use crate::clusters::WarehouseState;
+use crate::interpreters::common::BackgroundJob;
+use crate::interpreters::common::BackgroundJobType;
+use crate::interpreters::Interpreter;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::ReclusterTableInterpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// A fuse table with too many small blocks.
+struct CompactionCandidate {
+    database: String,
+    table: Arc<dyn Table>,
+    /// The average compressed size of the segments of the table, in bytes.
+    bytes_per_segment: u64,
+}
+
+/// A clustered fuse table whose blocks overlap too much.
+struct ReclusterCandidate {
+    database: String,
+    table: Arc<dyn Table>,
+    average_depth: f64,
+    block_count: u64,
+}
+
+/// Compacts the small blocks of the fuse tables in the background, as `OPTIMIZE TABLE ... COMPACT`
+/// does, and reclusters the clustered ones, as `ALTER TABLE ... RECLUSTER FINAL` does.
+///
+/// A table is compacted once it has at least `auto_compaction_min_blocks` blocks, and its blocks
+/// are too small on average according to the block thresholds of the table. Clustered tables are
+/// reclustered instead, once the average clustering depth of their blocks exceeds the
+/// `auto_recluster_depth` of the table, the deepest tables first. The depth is tracked in the
+/// summary of the snapshot on every commit, the depth of a block is counted among the blocks of
+/// its segment.
+///
+/// Only the first node of the cluster maintains the tables, and only while this node runs no user
+/// query. The tables are checked every `auto_compaction_check_secs` seconds, each round reads at
+/// most `auto_compaction_io_budget` bytes of blocks to compact, and rewrites at most
+/// `auto_recluster_block_budget` blocks to recluster. The jobs are tracked in
+/// `system.background_jobs`, a failed job is retried with backoff, see [`BackgroundJob`].
+pub struct TableCompactionScheduler {
+    check_interval: Duration,
+}
+
+impl TableCompactionScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scheduler = Arc::new(TableCompactionScheduler {
+            check_interval: Duration::from_secs(conf.query.auto_compaction_check_secs),
+        });
+
+        GlobalInstance::set(scheduler.clone());
+
+        if conf.query.auto_compaction_check_secs > 0 {
+            tokio::spawn(async_backtrace::location!().frame(scheduler.compact_loop()));
+        }
+
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TableCompactionScheduler> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session
+            .set_authed_user(
+                UserInfo::new_no_auth("root", "localhost"),
+                Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            )
+            .await?;
+        session.create_query_context().await
+    }
+
+    fn compaction_job(database: &str, table_name: &str) -> BackgroundJob {
+        BackgroundJob::new(
+            BackgroundJobType::Compaction,
+            format!("{}.{}", database, table_name),
+        )
+    }
+
+    fn recluster_job(database: &str, table_name: &str) -> BackgroundJob {
+        BackgroundJob::new(
+            BackgroundJobType::Recluster,
+            format!("{}.{}", database, table_name),
+        )
+    }
+
+    /// Returns whether this node is idle, the compactions never compete with user queries nor
+    /// wake up a suspended warehouse.
+    fn is_idle() -> bool {
+        if WarehouseAutoSuspend::instance().get_state() != WarehouseState::Running {
+            return false;
+        }
+        SessionManager::instance()
+            .get_current_session_status()
+            .running_queries_count
+            == 0
+    }
+
+    /// Returns whether a table with the `summary` has at least `min_blocks` blocks, and its
+    /// blocks are too small on average to be left alone.
+    pub fn needs_compaction(
+        summary: &Statistics,
+        thresholds: &BlockThresholds,
+        min_blocks: u64,
+    ) -> bool {
+        summary.block_count >= min_blocks.max(2)
+            && !thresholds.check_large_enough(
+                (summary.row_count / summary.block_count) as usize,
+                (summary.uncompressed_byte_size / summary.block_count) as usize,
+            )
+    }
+
+    /// Takes the segments of a table to compact out of `io_budget`, returns how many segments
+    /// are compacted, or None if the budget cannot afford a single one.
+    pub fn take_io_budget(io_budget: &mut u64, bytes_per_segment: u64) -> Option<u64> {
+        if *io_budget < bytes_per_segment {
+            return None;
+        }
+        let num_segments = *io_budget / bytes_per_segment;
+        *io_budget -= num_segments * bytes_per_segment;
+        Some(num_segments)
+    }
+
+    /// Takes the blocks of a table to recluster out of `block_budget`, the table takes the
+    /// budget of the blocks it may rewrite at most. Returns None once out of budget.
+    pub fn take_block_budget(block_budget: &mut u64, block_count: u64) -> Option<u64> {
+        if *block_budget == 0 {
+            return None;
+        }
+        let num_blocks = (*block_budget).min(block_count);
+        *block_budget -= num_blocks;
+        Some(num_blocks)
+    }
+
+    /// Returns the fuse tables of the default catalog which have too many small blocks, and the
+    /// clustered ones which overlap too much, except the ones backing off after a failed job or
+    /// quarantined. The clustered tables are ordered by their average depth, the deepest first.
+    #[async_backtrace::framed]
+    async fn due_tables(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog: &dyn Catalog,
+        min_blocks: u64,
+    ) -> Result<(Vec<CompactionCandidate>, Vec<ReclusterCandidate>)> {
+        let tenant = ctx.get_tenant();
+        let mut to_compact = vec![];
+        let mut to_recluster = vec![];
+        let mut compaction_names = vec![];
+        let mut recluster_names = vec![];
+        let now = Utc::now();
+        for database in catalog.list_databases(&tenant).await? {
+            let db_name = database.name().to_string();
+            for table in catalog.list_tables(&tenant, &db_name).await? {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+                    continue;
+                };
+                let summary = &snapshot.summary;
+
+                if fuse_table.cluster_key_str().is_some() {
+                    recluster_names.push(format!("{}.{}", db_name, table.name()));
+                    let job = Self::recluster_job(&db_name, table.name());
+                    let Some(threshold) = fuse_table.auto_recluster_depth() else {
+                        job.release();
+                        continue;
+                    };
+                    // The blocks written before the depth is tracked are not counted in the
+                    // summary, the depth of the whole table is computed instead.
+                    let average_depth = match summary.average_cluster_depth() {
+                        Some(depth) => depth,
+                        None => match fuse_table.clustering_depth(ctx.clone()).await? {
+                            Some(depth) => depth.average_depth,
+                            None => 0.0,
+                        },
+                    };
+                    if average_depth <= threshold {
+                        job.release();
+                        continue;
+                    }
+                    if job.can_run(&now) {
+                        to_recluster.push(ReclusterCandidate {
+                            database: db_name.clone(),
+                            table,
+                            average_depth,
+                            block_count: summary.block_count,
+                        });
+                    }
+                    continue;
+                }
+
+                compaction_names.push(format!("{}.{}", db_name, table.name()));
+                let due =
+                    Self::needs_compaction(summary, &table.get_block_thresholds(), min_blocks);
+                let job = Self::compaction_job(&db_name, table.name());
+                // A quarantined table is released once it no longer needs compaction, e.g.
+                // compacted by `OPTIMIZE TABLE`.
+                if !due {
+                    job.release();
+                    continue;
+                }
+                if job.can_run(&now) {
+                    let bytes_per_segment =
+                        summary.compressed_byte_size / snapshot.segments.len().max(1) as u64;
+                    to_compact.push(CompactionCandidate {
+                        database: db_name.clone(),
+                        table,
+                        bytes_per_segment: bytes_per_segment.max(1),
+                    });
+                }
+            }
+        }
+        BackgroundJobs::instance().retain(BackgroundJobType::Compaction.as_str(), |name| {
+            compaction_names.iter().any(|table| table == name)
+        });
+        BackgroundJobs::instance().retain(BackgroundJobType::Recluster.as_str(), |name| {
+            recluster_names.iter().any(|table| table == name)
+        });
+        to_recluster.sort_by(|a, b| b.average_depth.total_cmp(&a.average_depth));
+        Ok((to_compact, to_recluster))
+    }
+
+    #[async_backtrace::framed]
+    async fn compact_due_tables(&self) -> Result<()> {
+        if !Self::is_idle() {
+            return Ok(());
+        }
+
+        let ctx = self.create_context().await?;
+        // Only one node of the cluster compacts the tables.
+        let cluster = ctx.get_cluster();
+        if !cluster.is_empty() {
+            let first_node = cluster
+                .get_nodes()
+                .into_iter()
+                .map(|node| node.id.clone())
+                .min();
+            if first_node != Some(cluster.local_id()) {
+                return Ok(());
+            }
+        }
+
+        let settings = ctx.get_settings();
+        let min_blocks = settings.get_auto_compaction_min_blocks()?;
+        let mut io_budget = settings.get_auto_compaction_io_budget()?;
+        let mut block_budget = settings.get_auto_recluster_block_budget()?;
+        if (min_blocks == 0 || io_budget == 0) && block_budget == 0 {
+            return Ok(());
+        }
+
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let (to_compact, to_recluster) = self
+            .due_tables(ctx.clone(), catalog.as_ref(), min_blocks)
+            .await?;
+        for candidate in to_compact {
+            // Stop the round once out of budget or a user query arrives, the rest of the tables
+            // are compacted in the next rounds.
+            if min_blocks == 0 || !Self::is_idle() {
+                break;
+            }
+            let Some(num_segments) =
+                Self::take_io_budget(&mut io_budget, candidate.bytes_per_segment)
+            else {
+                break;
+            };
+
+            // Each compaction runs as a query of its own.
+            let ctx = self.create_context().await?;
+            let table_name = candidate.table.name().to_string();
+            let plan = OptimizeTablePlan {
+                catalog: CATALOG_DEFAULT.to_string(),
+                database: candidate.database.clone(),
+                table: table_name.clone(),
+                action: OptimizeTableAction::CompactBlocks(Some(num_segments as usize)),
+            };
+            let job = Self::compaction_job(&candidate.database, &table_name);
+            job.on_start();
+            let res = match OptimizeTableInterpreter::try_create(ctx.clone(), plan) {
+                Ok(interpreter) => match interpreter.execute(ctx).await {
+                    Ok(stream) => stream.try_collect::<Vec<_>>().await.map(|_| ()),
+                    Err(cause) => Err(cause),
+                },
+                Err(cause) => Err(cause),
+            };
+            job.on_finish(&res);
+            match res {
+                Ok(_) => info!(
+                    "compacted up to {} segments of table {}.{}",
+                    num_segments, candidate.database, table_name
+                ),
+                Err(cause) => warn!(
+                    "Cannot compact table {}.{}, cause {:?}",
+                    candidate.database, table_name, cause
+                ),
+            }
+        }
+
+        for candidate in to_recluster {
+            if !Self::is_idle() {
+                break;
+            }
+            let Some(num_blocks) = Self::take_block_budget(&mut block_budget, candidate.block_count)
+            else {
+                break;
+            };
+
+            let ctx = self.create_context().await?;
+            let table_name = candidate.table.name().to_string();
+            let plan = ReclusterTablePlan {
+                tenant: ctx.get_tenant(),
+                catalog: CATALOG_DEFAULT.to_string(),
+                database: candidate.database.clone(),
+                table: table_name.clone(),
+                is_final: true,
+                metadata: Arc::new(RwLock::new(Metadata::default())),
+                push_downs: None,
+                limit: Some(num_blocks as usize),
+            };
+            let job = Self::recluster_job(&candidate.database, &table_name);
+            job.on_start();
+            let res = match ReclusterTableInterpreter::try_create(ctx.clone(), plan) {
+                Ok(interpreter) => match interpreter.execute(ctx).await {
+                    Ok(stream) => stream.try_collect::<Vec<_>>().await.map(|_| ()),
+                    Err(cause) => Err(cause),
+                },
+                Err(cause) => Err(cause),
+            };
+            job.on_finish(&res);
+            match res {
+                Ok(_) => info!(
+                    "reclustered up to {} blocks of table {}.{}, average depth {}",
+                    num_blocks, candidate.database, table_name, candidate.average_depth
+                ),
+                Err(cause) => warn!(
+                    "Cannot recluster table {}.{}, cause {:?}",
+                    candidate.database, table_name, cause
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn compact_loop(self: Arc<Self>) {
+        loop {
+            tokio_async_sleep(self.check_interval).await;
+
+            if let Err(cause) = self.compact_due_tables().await {
+                warn!("Cannot compact tables, cause {:?}", cause);
+            }
+        }
+    }
+}
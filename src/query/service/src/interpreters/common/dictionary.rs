@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_pipeline_core::Pipeline;
+use common_storages_fuse::FuseTable;
+use tracing::warn;
+
+use crate::interpreters::common::appended_segments;
+use crate::sessions::QueryContext;
+
+/// Add the values of the blocks committed by the pipeline to the dictionaries of
+/// the `dictionary_columns` of the table once it is finished.
+///
+/// Failing to update the dictionaries does not fail the ingestion, a query
+/// just does not use the dictionary of a column missing some of its values.
+pub fn refresh_dictionaries_on_finished(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    pipeline: &mut Pipeline,
+) -> Result<()> {
+    match FuseTable::try_from_table(table.as_ref()) {
+        Ok(fuse_table) if !fuse_table.dictionary_columns().is_empty() => {}
+        _ => return Ok(()),
+    }
+
+    pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            GlobalIORuntime::instance().block_on(async move {
+                if let Err(e) = refresh_dictionaries(ctx, table).await {
+                    warn!("failed to refresh column dictionaries: {}", e);
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+#[async_backtrace::framed]
+async fn refresh_dictionaries(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<()> {
+    let Some((new_table, new_segments)) = appended_segments(ctx.as_ref(), table.as_ref()).await?
+    else {
+        return Ok(());
+    };
+    let fuse_table = FuseTable::try_from_table(new_table.as_ref())?;
+
+    fuse_table
+        .update_column_dictionaries(ctx, &new_segments)
+        .await
+}
@@ -0,0 +1,779 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::catalog::Catalog;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::normalize_identifier;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::NameResolutionContext;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::StreamTable;
+use common_storages_fuse::CHANGE_ACTION_COLUMN_NAME;
+use common_storages_system::BackgroundJobs;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures::TryStreamExt;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::info;
+use tracing::warn;
+
+use crate::clusters::ClusterHelper;
+use crate::interpreters::common::quote_ident;
+use crate::interpreters::common::BackgroundJob;
+use crate::interpreters::common::BackgroundJobType;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Build the pipeline refreshing the materialized view with the current data of its table.
+///
+/// A view of simple aggregates over a fuse table is maintained incrementally: the changes of the
+/// table since the last refresh are aggregated and merged into the view, see [`DeltaRefresh`].
+/// Other views are overwritten with the result of their query.
+///
+/// The snapshot of the source table and the time of the refresh are recorded in the options
+/// of the view once the pipeline is finished, they tell if the view is fresh enough to be read
+/// in place of the source table.
+#[async_backtrace::framed]
+pub async fn build_refresh_materialized_view_pipeline(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view_name: &str,
+) -> Result<PipelineBuildResult> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name)?;
+    let view = catalog.get_table(&tenant, database, view_name).await?;
+    let options = view.options();
+    let (Some(query), Some(source_table_id)) = (
+        options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+        options
+            .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+            .and_then(|v| v.parse::<u64>().ok()),
+    ) else {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} is not a materialized view",
+            database, view_name
+        )));
+    };
+
+    // Record the snapshot before reading the source table, the view is considered
+    // stale if the source table is changed during the refresh.
+    let refreshed_on = Utc::now().timestamp();
+    let (ident, source_meta) = catalog.get_table_meta_by_id(source_table_id).await?;
+    if source_meta.drop_on.is_some() {
+        return Err(ErrorCode::UnknownTable(format!(
+            "The source table of materialized view {}.{} is dropped",
+            database, view_name
+        )));
+    }
+    let source_snapshot = source_meta
+        .options
+        .get(OPT_KEY_SNAPSHOT_LOCATION)
+        .cloned()
+        .unwrap_or_default();
+
+    // The source table is attached at the recorded snapshot, so that the refresh reads exactly
+    // the data of the snapshot even if the table is changed meanwhile.
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let Statement::Query(query_ast) = &stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "The query of materialized view {}.{} is not a SELECT",
+            database, view_name
+        )));
+    };
+    let name_resolution_ctx = NameResolutionContext::try_from(ctx.get_settings().as_ref())?;
+    let source = match source_table_name(query_ast, &name_resolution_ctx) {
+        Some((source_catalog, source_database, source_name)) => {
+            let table = catalog.get_table_by_info(&TableInfo::new(
+                &source_database,
+                &source_name,
+                ident,
+                source_meta.as_ref().clone(),
+            ))?;
+            let source_catalog = source_catalog.unwrap_or_else(|| ctx.get_current_catalog());
+            ctx.attach_table(
+                &source_catalog,
+                &source_database,
+                &source_name,
+                table.clone(),
+            );
+            Some(table)
+        }
+        None => None,
+    };
+
+    // The query of the view should read the source table itself.
+    ctx.get_settings()
+        .set_enable_materialized_view_rewrite(false)?;
+    let incremental = match &source {
+        Some(source) => {
+            build_incremental_refresh_pipeline(
+                &ctx,
+                catalog_name,
+                database,
+                view.as_ref(),
+                query_ast,
+                source.as_ref(),
+                &source_snapshot,
+            )
+            .await
+        }
+        None => Ok(None),
+    };
+    let mut build_res = match incremental {
+        Ok(Some(build_res)) => build_res,
+        res => {
+            if let Err(cause) = res {
+                warn!(
+                    "Cannot refresh materialized view {}.{} incrementally, fall back to recompute it, cause {:?}",
+                    database, view_name, cause
+                );
+            }
+            let sql = format!(
+                "INSERT OVERWRITE {}.{}.{} {}",
+                quote_ident(catalog_name),
+                quote_ident(database),
+                quote_ident(view_name),
+                query
+            );
+            let mut planner = Planner::new(ctx.clone());
+            let (plan, _) = planner.plan_sql(&sql).await?;
+            InterpreterFactory::get_inner(ctx.clone(), &plan)?
+                .execute2()
+                .await?
+        }
+    };
+
+    let view_id = view.get_id();
+    let database = database.to_string();
+    build_res.main_pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            GlobalIORuntime::instance().block_on(async move {
+                let (ident, _) = catalog.get_table_meta_by_id(view_id).await?;
+                let options = HashMap::from([
+                    (
+                        OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT.to_string(),
+                        Some(source_snapshot),
+                    ),
+                    (
+                        OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON.to_string(),
+                        Some(refreshed_on.to_string()),
+                    ),
+                ]);
+                catalog
+                    .upsert_table_option(&tenant, &database, UpsertTableOptionReq {
+                        table_id: view_id,
+                        seq: MatchSeq::Exact(ident.seq),
+                        options,
+                    })
+                    .await?;
+                Ok(())
+            })?;
+        }
+        Ok(())
+    });
+
+    Ok(build_res)
+}
+
+/// Build the pipeline merging the changes of the source table since the last refresh into the
+/// view, returns `None` if the view can't be maintained incrementally.
+///
+/// The changes are read by a bounded stream from the snapshot recorded by the last refresh to
+/// `source_snapshot`, so that the snapshot recorded by this refresh covers exactly the changes
+/// merged.
+#[async_backtrace::framed]
+async fn build_incremental_refresh_pipeline(
+    ctx: &Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view: &dyn Table,
+    query: &Query,
+    source: &dyn Table,
+    source_snapshot: &str,
+) -> Result<Option<PipelineBuildResult>> {
+    let Some(base_snapshot) = view.options().get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT) else {
+        return Ok(None);
+    };
+    let Ok(fuse_table) = FuseTable::try_from_table(source) else {
+        return Ok(None);
+    };
+    let Some(mut delta) = DeltaRefresh::try_create(query, &view.schema()) else {
+        return Ok(None);
+    };
+    if !delta.check_sums(&fuse_table.schema()) {
+        return Ok(None);
+    }
+
+    let changes_name = format!("{}$changes", view.name());
+    let changes_info = StreamTable::bounded_table_info(
+        &changes_name,
+        catalog_name,
+        fuse_table,
+        source.name(),
+        base_snapshot,
+        source_snapshot,
+    );
+    let changes = ctx
+        .get_catalog(catalog_name)?
+        .get_table_by_info(&changes_info)?;
+    ctx.attach_table(catalog_name, database, &changes_name, changes);
+
+    let sql = delta.merge_sql(
+        &format!(
+            "{}.{}.{}",
+            quote_ident(catalog_name),
+            quote_ident(database),
+            quote_ident(view.name())
+        ),
+        &format!(
+            "{}.{}.{}",
+            quote_ident(catalog_name),
+            quote_ident(database),
+            quote_ident(&changes_name)
+        ),
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let build_res = InterpreterFactory::get_inner(ctx.clone(), &plan)?
+        .execute2()
+        .await?;
+    Ok(Some(build_res))
+}
+
+/// The catalog, the database and the name of the table a materialized view is defined on.
+fn source_table_name(
+    query: &Query,
+    name_resolution_ctx: &NameResolutionContext,
+) -> Option<(Option<String>, String, String)> {
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    match select.from.as_slice() {
+        [
+            TableReference::Table {
+                catalog,
+                database: Some(database),
+                table,
+                ..
+            },
+        ] => Some((
+            catalog
+                .as_ref()
+                .map(|catalog| normalize_identifier(catalog, name_resolution_ctx).name),
+            normalize_identifier(database, name_resolution_ctx).name,
+            normalize_identifier(table, name_resolution_ctx).name,
+        )),
+        _ => None,
+    }
+}
+
+/// A column of a materialized view maintained by [`DeltaRefresh`].
+enum DeltaColumn {
+    /// A key of `GROUP BY`.
+    Key,
+    /// `count(*)`.
+    CountAll,
+    /// `count(<arg>)`, the number of the rows where the argument is not NULL.
+    Count(Expr),
+    /// `sum(<arg>)`, with the column counting the rows summed if the sum can be NULL.
+    Sum(Expr, Option<usize>),
+}
+
+/// The incremental maintenance of a materialized view of the query
+/// `SELECT <keys>, <aggregates> FROM <table> [WHERE ...] [GROUP BY <keys>]`,
+/// whose aggregates are `count(*)`, `count(<arg>)` and `sum(<arg>)`.
+///
+/// The changes of the table are aggregated by the keys, the deleted rows are counted and summed
+/// negatively, then merged into the view by adding the aggregates of the same keys. A group is
+/// deleted once its `count(*)` drops to zero, so the view needs `count(*)` if it has keys, and a
+/// nullable sum needs a count of its argument to tell if it becomes NULL.
+struct DeltaRefresh {
+    // The name the columns of the table are qualified with in the query.
+    table_ref: Identifier,
+    selection: Option<Expr>,
+    // The view fields, with their expressions in the query.
+    fields: Vec<(TableField, Expr, DeltaColumn)>,
+    keys: Vec<usize>,
+    count_all: Option<usize>,
+}
+
+impl DeltaRefresh {
+    fn try_create(query: &Query, view_schema: &TableSchema) -> Option<DeltaRefresh> {
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return None;
+        }
+        let SetExpr::Select(select) = &query.body else {
+            return None;
+        };
+        if select.distinct
+            || select.having.is_some()
+            || select.window_list.is_some()
+            || select.from.len() != 1
+            || select.select_list.len() != view_schema.num_fields()
+        {
+            return None;
+        }
+        let TableReference::Table {
+            table,
+            alias,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+            ..
+        } = &select.from[0]
+        else {
+            return None;
+        };
+
+        let mut exprs = Vec::with_capacity(select.select_list.len());
+        for target in select.select_list.iter() {
+            let SelectTarget::AliasedExpr { expr, alias } = target else {
+                return None;
+            };
+            exprs.push((expr.as_ref(), alias.as_ref()));
+        }
+
+        // The keys are resolved to the select list, by the positions, the aliases or the
+        // expressions, e.g. `GROUP BY 1`.
+        let group_by = match &select.group_by {
+            None => vec![],
+            Some(GroupBy::Normal(group_by)) => group_by.clone(),
+            Some(_) => return None,
+        };
+        let mut keys = Vec::with_capacity(group_by.len());
+        for key in group_by.iter() {
+            let position = match key {
+                Expr::Literal {
+                    lit: Literal::UInt64(n),
+                    ..
+                } => (*n as usize).checked_sub(1),
+                _ => exprs.iter().position(|(expr, alias)| {
+                    let is_alias = matches!(
+                        (key, alias),
+                        (Expr::ColumnRef { table: None, column: ColumnID::Name(name), .. }, Some(alias))
+                            if name.name == alias.name
+                    );
+                    is_alias || expr.to_string() == key.to_string()
+                }),
+            };
+            match position {
+                Some(position) if position < exprs.len() => keys.push(position),
+                _ => return None,
+            }
+        }
+
+        let mut fields = Vec::with_capacity(exprs.len());
+        for (i, (expr, _)) in exprs.iter().enumerate() {
+            let column = if keys.contains(&i) {
+                DeltaColumn::Key
+            } else {
+                match expr {
+                    Expr::CountAll { window: None, .. } => DeltaColumn::CountAll,
+                    Expr::FunctionCall {
+                        distinct: false,
+                        name,
+                        args,
+                        params,
+                        window: None,
+                        ..
+                    } if args.len() == 1 && params.is_empty() => {
+                        match name.name.to_lowercase().as_str() {
+                            "count" => DeltaColumn::Count(args[0].clone()),
+                            "sum" => DeltaColumn::Sum(args[0].clone(), None),
+                            _ => return None,
+                        }
+                    }
+                    _ => return None,
+                }
+            };
+            fields.push((view_schema.field(i).clone(), (*expr).clone(), column));
+        }
+
+        let count_all = fields
+            .iter()
+            .position(|(_, _, column)| matches!(column, DeltaColumn::CountAll));
+        if !keys.is_empty() && count_all.is_none() {
+            return None;
+        }
+
+        Some(DeltaRefresh {
+            table_ref: alias
+                .as_ref()
+                .map_or_else(|| table.clone(), |alias| alias.name.clone()),
+            selection: select.selection.clone(),
+            fields,
+            keys,
+            count_all,
+        })
+    }
+
+    /// Finds the counts of the nullable sums, returns false if a nullable sum has no count.
+    ///
+    /// The `count(*)` counts the argument of a sum which is a not nullable column of the table.
+    fn check_sums(&mut self, source_schema: &TableSchema) -> bool {
+        let counts = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (_, _, column))| match column {
+                DeltaColumn::Count(arg) => Some((arg.to_string(), i)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        for (field, _, column) in self.fields.iter_mut() {
+            let DeltaColumn::Sum(arg, count) = column else {
+                continue;
+            };
+            if !field.data_type().is_nullable() {
+                continue;
+            }
+            let not_null_column = match arg {
+                Expr::ColumnRef {
+                    column: ColumnID::Name(name),
+                    ..
+                } => source_schema
+                    .fields()
+                    .iter()
+                    .any(|f| f.name() == &name.name && !f.data_type().is_nullable()),
+                _ => false,
+            };
+            *count = match counts.get(&arg.to_string()) {
+                Some(i) => Some(*i),
+                None if not_null_column && self.count_all.is_some() => self.count_all,
+                None => return false,
+            };
+        }
+        true
+    }
+
+    /// The statement merging the aggregated changes read from `changes` into `view`.
+    fn merge_sql(&self, view: &str, changes: &str) -> String {
+        let action = format!("{} = 'INSERT'", quote_ident(CHANGE_ACTION_COLUMN_NAME));
+        let view_col = |i: usize| format!("`v`.{}", quote_ident(self.fields[i].0.name()));
+        let delta_col = |i: usize| format!("`d`.`c{}`", i);
+
+        let mut delta = String::from("SELECT ");
+        for (i, (_, expr, column)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                delta.push_str(", ");
+            }
+            let value = match column {
+                DeltaColumn::Key => expr.to_string(),
+                DeltaColumn::CountAll => format!("sum(if({}, 1, -1))", action),
+                DeltaColumn::Count(arg) => {
+                    format!("sum(if(({}) IS NULL, 0, if({}, 1, -1)))", arg, action)
+                }
+                DeltaColumn::Sum(arg, _) => format!("sum(if({}, {}, -({})))", action, arg, arg),
+            };
+            write!(delta, "{} AS `c{}`", value, i).unwrap();
+        }
+        write!(delta, " FROM {} AS {}", changes, self.table_ref).unwrap();
+        if let Some(selection) = &self.selection {
+            write!(delta, " WHERE {}", selection).unwrap();
+        }
+        if !self.keys.is_empty() {
+            let keys = self
+                .keys
+                .iter()
+                .map(|i| self.fields[*i].1.to_string())
+                .collect::<Vec<_>>();
+            write!(delta, " GROUP BY {}", keys.join(", ")).unwrap();
+        }
+
+        // NULL is a key of its own.
+        let on = if self.keys.is_empty() {
+            "1 = 1".to_string()
+        } else {
+            self.keys
+                .iter()
+                .map(|i| {
+                    let op = if self.fields[*i].0.data_type().is_nullable() {
+                        "IS NOT DISTINCT FROM"
+                    } else {
+                        "="
+                    };
+                    format!("{} {} {}", view_col(*i), op, delta_col(*i))
+                })
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        };
+        let mut sql = format!(
+            "MERGE INTO {} AS `v` USING ({}) AS `d` ON {}",
+            view, delta, on
+        );
+
+        let added = |i: usize| format!("{} + coalesce({}, 0)", view_col(i), delta_col(i));
+        if let Some(count_all) = self.count_all.filter(|_| !self.keys.is_empty()) {
+            write!(
+                sql,
+                " WHEN MATCHED AND {} = 0 THEN DELETE",
+                added(count_all)
+            )
+            .unwrap();
+        }
+
+        let mut updates = vec![];
+        for (i, (field, _, column)) in self.fields.iter().enumerate() {
+            let value = match column {
+                DeltaColumn::Key => continue,
+                DeltaColumn::CountAll | DeltaColumn::Count(_) => added(i),
+                DeltaColumn::Sum(_, count) => {
+                    let sum = format!(
+                        "coalesce({}, 0) + coalesce({}, 0)",
+                        view_col(i),
+                        delta_col(i)
+                    );
+                    match count {
+                        Some(count) => format!("if({} = 0, NULL, {})", added(*count), sum),
+                        None => sum,
+                    }
+                }
+            };
+            updates.push(format!("{} = {}", quote_ident(field.name()), value));
+        }
+        write!(sql, " WHEN MATCHED THEN UPDATE SET {}", updates.join(", ")).unwrap();
+
+        // A view without keys always has one row.
+        if let Some(count_all) = self.count_all.filter(|_| !self.keys.is_empty()) {
+            let mut columns = vec![];
+            let mut values = vec![];
+            for (i, (field, _, column)) in self.fields.iter().enumerate() {
+                columns.push(quote_ident(field.name()));
+                values.push(match column {
+                    DeltaColumn::Sum(_, Some(count)) => {
+                        format!("if({} = 0, NULL, {})", delta_col(*count), delta_col(i))
+                    }
+                    _ => delta_col(i),
+                });
+            }
+            write!(
+                sql,
+                " WHEN NOT MATCHED AND {} > 0 THEN INSERT ({}) VALUES ({})",
+                delta_col(count_all),
+                columns.join(", "),
+                values.join(", ")
+            )
+            .unwrap();
+        }
+        sql
+    }
+}
+
+/// Refreshes the materialized views created with `REFRESH EVERY <interval>` once their interval
+/// has elapsed since their last refresh.
+///
+/// Only the first node of the cluster refreshes the views, the views are checked every
+/// `materialized_view_refresh_check_secs` seconds. The refreshes are tracked in
+/// `system.background_jobs`, a failed refresh is retried with backoff, see [`BackgroundJob`].
+pub struct MaterializedViewScheduler {
+    check_interval: Duration,
+}
+
+impl MaterializedViewScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scheduler = Arc::new(MaterializedViewScheduler {
+            check_interval: Duration::from_secs(conf.query.materialized_view_refresh_check_secs),
+        });
+
+        GlobalInstance::set(scheduler.clone());
+
+        if conf.query.materialized_view_refresh_check_secs > 0 {
+            tokio::spawn(async_backtrace::location!().frame(scheduler.refresh_loop()));
+        }
+
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<MaterializedViewScheduler> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session
+            .set_authed_user(
+                UserInfo::new_no_auth("root", "localhost"),
+                Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            )
+            .await?;
+        session.create_query_context().await
+    }
+
+    fn refresh_job(database: &str, view_name: &str) -> BackgroundJob {
+        BackgroundJob::new(
+            BackgroundJobType::MaterializedView,
+            format!("{}.{}", database, view_name),
+        )
+    }
+
+    /// Returns the materialized views of the default catalog which are due to refresh, except
+    /// the ones backing off after a failed refresh or quarantined.
+    #[async_backtrace::framed]
+    async fn views_to_refresh(
+        &self,
+        catalog: &dyn Catalog,
+        tenant: &str,
+    ) -> Result<Vec<(String, Arc<dyn Table>)>> {
+        let now = Utc::now().timestamp() as u64;
+        let mut views = vec![];
+        let mut names = vec![];
+        for database in catalog.list_databases(tenant).await? {
+            let db_name = database.name().to_string();
+            for table in catalog.list_tables(tenant, &db_name).await? {
+                let options = table.options();
+                let Some(interval) = options
+                    .get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL)
+                    .and_then(|v| v.parse::<u64>().ok())
+                else {
+                    continue;
+                };
+                let refreshed_on = options
+                    .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON)
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or_default();
+                let job = Self::refresh_job(&db_name, table.name());
+                // A quarantined view is released once refreshed by `REFRESH MATERIALIZED VIEW`.
+                if job.is_quarantined()
+                    && job
+                        .last_run_time()
+                        .map_or(false, |time| (refreshed_on as i64) * 1_000_000 > time)
+                {
+                    job.release();
+                }
+                names.push(format!("{}.{}", db_name, table.name()));
+                if now.saturating_sub(refreshed_on) >= interval && job.can_run(&Utc::now()) {
+                    views.push((db_name.clone(), table));
+                }
+            }
+        }
+        BackgroundJobs::instance().retain(BackgroundJobType::MaterializedView.as_str(), |name| {
+            names.iter().any(|view| view == name)
+        });
+        Ok(views)
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_due_views(&self) -> Result<()> {
+        let ctx = self.create_context().await?;
+        // Only one node of the cluster refreshes the views.
+        let cluster = ctx.get_cluster();
+        if !cluster.is_empty() {
+            let first_node = cluster
+                .get_nodes()
+                .into_iter()
+                .map(|node| node.id.clone())
+                .min();
+            if first_node != Some(cluster.local_id()) {
+                return Ok(());
+            }
+        }
+
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        for (database, view) in self.views_to_refresh(catalog.as_ref(), &tenant).await? {
+            // Each refresh runs as a query of its own.
+            let ctx = self.create_context().await?;
+            let plan = RefreshMaterializedViewPlan {
+                tenant: tenant.clone(),
+                catalog: CATALOG_DEFAULT.to_string(),
+                database: database.clone(),
+                view_name: view.name().to_string(),
+            };
+            let job = Self::refresh_job(&database, view.name());
+            job.on_start();
+            let res = match RefreshMaterializedViewInterpreter::try_create(ctx.clone(), plan) {
+                Ok(interpreter) => match interpreter.execute(ctx).await {
+                    Ok(stream) => stream.try_collect::<Vec<_>>().await.map(|_| ()),
+                    Err(cause) => Err(cause),
+                },
+                Err(cause) => Err(cause),
+            };
+            job.on_finish(&res);
+            match res {
+                Ok(_) => info!("refreshed materialized view {}.{}", database, view.name()),
+                Err(cause) => warn!(
+                    "Cannot refresh materialized view {}.{}, cause {:?}",
+                    database,
+                    view.name(),
+                    cause
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn refresh_loop(self: Arc<Self>) {
+        loop {
+            tokio_async_sleep(self.check_interval).await;
+
+            if let Err(cause) = self.refresh_due_views().await {
+                warn!("Cannot refresh materialized views, cause {:?}", cause);
+            }
+        }
+    }
+}
@@ -12,12 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index;
+mod background_job;
+mod compaction;
+mod dictionary;
 mod grant;
+mod materialized_view;
 mod stage;
 mod table;
+mod task;
 mod util;
+mod virtual_column;
+pub use agg_index::refresh_agg_index_on_finished;
+pub use background_job::BackgroundJob;
+pub use background_job::BackgroundJobType;
+pub use background_job::RetryPolicy;
+pub use compaction::TableCompactionScheduler;
+pub use dictionary::refresh_dictionaries_on_finished;
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::build_refresh_materialized_view_pipeline;
+pub use materialized_view::MaterializedViewScheduler;
 pub use stage::try_purge_files;
+pub use stage::StageQuota;
 pub use table::append2table;
 pub use table::fill_missing_columns;
+pub use task::CronSchedule;
+pub use task::TaskScheduler;
+pub use util::appended_segments;
 pub use util::check_deduplicate_label;
+pub use util::quote_ident;
+pub use virtual_column::refresh_virtual_columns_on_finished;
@@ -15,10 +15,16 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
+use common_meta_types::MatchSeq;
 use common_storage::StageFileInfo;
+use common_storage::StageFilesInfo;
 use common_storages_fuse::io::Files;
 use common_storages_stage::StageTable;
+use common_users::UserApiProvider;
 use tracing::error;
 
 use crate::sessions::QueryContext;
@@ -47,3 +53,70 @@ pub async fn try_purge_files(
         }
     }
 }
+
+/// The usage of an internal stage against the `max_files_per_stage` and
+/// `max_storage_bytes_per_stage` quotas of the tenant, 0 means unlimited.
+pub struct StageQuota {
+    max_files: u64,
+    max_bytes: u64,
+    files: u64,
+    bytes: u64,
+}
+
+impl StageQuota {
+    /// Loads the quotas and lists the files of the stage if any quota is set, the
+    /// external stages are not limited.
+    #[async_backtrace::framed]
+    pub async fn try_load(tenant: &str, stage_info: &StageInfo) -> Result<Self> {
+        let quota = UserApiProvider::instance()
+            .get_tenant_quota_api_client(tenant)?
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data;
+        let mut stage_quota = StageQuota {
+            max_files: quota.max_files_per_stage as u64,
+            max_bytes: quota.max_storage_bytes_per_stage,
+            files: 0,
+            bytes: 0,
+        };
+        if stage_info.stage_type == StageType::External {
+            stage_quota.max_files = 0;
+            stage_quota.max_bytes = 0;
+        }
+        if stage_quota.max_files == 0 && stage_quota.max_bytes == 0 {
+            return Ok(stage_quota);
+        }
+
+        let op = StageTable::get_op(stage_info)?;
+        let files_info = StageFilesInfo {
+            path: "/".to_string(),
+            files: None,
+            pattern: None,
+        };
+        for file in files_info.list(&op, false, None).await? {
+            stage_quota.files += 1;
+            stage_quota.bytes += file.size;
+        }
+        Ok(stage_quota)
+    }
+
+    /// Accounts the files to write into the stage, fails if any quota would be exceeded.
+    pub fn check_and_add(&mut self, files: u64, bytes: u64) -> Result<()> {
+        if self.max_files != 0 && self.files + files > self.max_files {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max files per stage quota exceeded {}",
+                self.max_files
+            )));
+        }
+        if self.max_bytes != 0 && self.bytes + bytes > self.max_bytes {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max storage bytes per stage quota exceeded {}, the stage would take {} bytes",
+                self.max_bytes,
+                self.bytes + bytes
+            )));
+        }
+        self.files += files;
+        self.bytes += bytes;
+        Ok(())
+    }
+}
@@ -21,6 +21,8 @@ use common_expression::DataSchemaRef;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_pipeline_core::Pipeline;
 
+use crate::interpreters::common::refresh_agg_index_on_finished;
+use crate::interpreters::common::refresh_dictionaries_on_finished;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
@@ -85,7 +87,16 @@ pub fn append2table(
 
     table.append_data(ctx.clone(), &mut build_res.main_pipeline, append_mode)?;
 
-    table.commit_insertion(ctx, &mut build_res.main_pipeline, copied_files, overwrite)?;
+    table.commit_insertion(
+        ctx.clone(),
+        &mut build_res.main_pipeline,
+        copied_files,
+        overwrite,
+    )?;
+
+    refresh_agg_index_on_finished(ctx.clone(), table.clone(), &mut build_res.main_pipeline)?;
+
+    refresh_dictionaries_on_finished(ctx, table, &mut build_res.main_pipeline)?;
 
     Ok(())
 }
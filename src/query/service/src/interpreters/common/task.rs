@@ -0,0 +1,425 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_base::base::GlobalInstance;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::Task;
+use common_meta_app::principal::UserInfo;
+use common_sql::Planner;
+use common_storages_system::BackgroundJobState;
+use common_storages_system::BackgroundJobs;
+use common_storages_system::TaskHistoryLogElement;
+use common_storages_system::TaskHistoryQueue;
+use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures::TryStreamExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::clusters::ClusterHelper;
+use crate::interpreters::common::BackgroundJob;
+use crate::interpreters::common::BackgroundJobType;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// A cron schedule of five fields: minute, hour, day of month, month and day of week, in UTC.
+///
+/// A field is `*`, a value or a range `<a>-<b>`, optionally with a step like `*/15`, and
+/// several of them can be separated by `,`. Day of week is 0 (Sunday) to 6, 7 is also Sunday.
+/// As in cron, when both day of month and day of week are restricted, a day matching either
+/// of them fires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid cron expression '{}', expect 5 fields: minute hour day-of-month month day-of-week",
+                expr
+            )));
+        }
+        let parse_field = |i: usize, min: u32, max: u32| {
+            Self::parse_field(fields[i], min, max).map_err(|msg| {
+                ErrorCode::BadArguments(format!("Invalid cron expression '{}', {}", expr, msg))
+            })
+        };
+
+        let mut days_of_week = parse_field(4, 0, 7)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week |= 1;
+        }
+        Ok(CronSchedule {
+            minutes: parse_field(0, 0, 59)?,
+            hours: parse_field(1, 0, 23)?,
+            days_of_month: parse_field(2, 1, 31)?,
+            months: parse_field(3, 1, 12)?,
+            days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    /// Returns the bitmap of the values in the field.
+    fn parse_field(field: &str, min: u32, max: u32) -> std::result::Result<u64, String> {
+        let parse_value = |v: &str| {
+            v.parse::<u32>()
+                .map_err(|_| format!("invalid value '{}'", v))
+        };
+
+        let mut bits = 0;
+        for part in field.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => match step.parse::<u32>() {
+                    Ok(step) if step > 0 => (range, Some(step)),
+                    _ => return Err(format!("invalid step '{}'", step)),
+                },
+                None => (part, None),
+            };
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (parse_value(start)?, parse_value(end)?)
+            } else {
+                let value = parse_value(range)?;
+                // `<a>/<step>` starts from `a` to the end.
+                match step {
+                    Some(_) => (value, max),
+                    None => (value, value),
+                }
+            };
+            if start < min || end > max || start > end {
+                return Err(format!("'{}' is out of range {}-{}", part, min, max));
+            }
+            for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Ok(bits)
+    }
+
+    pub fn matches(&self, time: &DateTime<Utc>) -> bool {
+        let contains = |bits: u64, value: u32| bits & (1 << value) != 0;
+
+        let day_of_month = contains(self.days_of_month, time.day());
+        let day_of_week = contains(self.days_of_week, time.weekday().num_days_from_sunday());
+        let day = if self.any_day_of_month || self.any_day_of_week {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        };
+        day && contains(self.minutes, time.minute())
+            && contains(self.hours, time.hour())
+            && contains(self.months, time.month())
+    }
+
+    /// Returns the last time the schedule fires in `(after, until]`.
+    pub fn last_fire_between(
+        &self,
+        after: &DateTime<Utc>,
+        until: &DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let first_minute = after.timestamp().div_euclid(60) + 1;
+        let last_minute = until.timestamp().div_euclid(60);
+        (first_minute..=last_minute)
+            .rev()
+            .filter_map(|minute| Utc.timestamp_opt(minute * 60, 0).single())
+            .find(|time| self.matches(time))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskRunState {
+    Succeeded,
+    Failed,
+    /// Not run because one of its predecessors did not succeed.
+    Skipped,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+            TaskRunState::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+/// Runs the tasks created by `CREATE TASK`, the runs are recorded in `system.task_history`.
+///
+/// A task with a schedule runs when its cron expression fires, then the tasks `AFTER` it run
+/// once all their predecessors have succeeded in the same round. Suspended tasks do not run,
+/// nor do the tasks after them. The runs are tracked in `system.background_jobs`, a failed
+/// task is retried with backoff, see [`BackgroundJob`].
+///
+/// Only the first node of the cluster runs the tasks, the tasks are checked every
+/// `task_schedule_check_secs` seconds.
+pub struct TaskScheduler {
+    check_interval: Duration,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scheduler = Arc::new(TaskScheduler {
+            check_interval: Duration::from_secs(conf.query.task_schedule_check_secs),
+        });
+        GlobalInstance::set(scheduler.clone());
+        if conf.query.task_schedule_check_secs > 0 {
+            tokio::spawn(async_backtrace::location!().frame(scheduler.schedule_loop()));
+        }
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    async fn create_context(&self) -> Result<Arc<QueryContext>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session
+            .set_authed_user(
+                UserInfo::new_no_auth("root", "localhost"),
+                Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            )
+            .await?;
+        session.create_query_context().await
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_task(ctx: Arc<QueryContext>, task: &Task) -> Result<()> {
+        ctx.set_current_database(task.database.clone()).await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, extras) = planner.plan_sql(&task.query_text).await?;
+        ctx.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+
+    /// Each run of the tasks is a query of its own.
+    ///
+    /// A task quarantined after failing too many times in a row is suspended, so it runs
+    /// again once resumed by `ALTER TASK ... RESUME`.
+    #[async_backtrace::framed]
+    async fn run_task(
+        &self,
+        tenant: &str,
+        task: &Task,
+        scheduled_time: DateTime<Utc>,
+    ) -> TaskRunState {
+        let job = BackgroundJob::new(BackgroundJobType::Task, &task.name);
+        job.on_start();
+        let start_time = Utc::now();
+        let (query_id, res) = match self.create_context().await {
+            Ok(ctx) => (ctx.get_id(), Self::execute_task(ctx, task).await),
+            Err(cause) => (String::new(), Err(cause)),
+        };
+        if job.on_finish(&res) == BackgroundJobState::Quarantined {
+            let mut suspended = task.clone();
+            suspended.suspended = true;
+            if let Err(cause) = UserApiProvider::instance()
+                .update_task(tenant, suspended)
+                .await
+            {
+                warn!(
+                    "Cannot suspend quarantined task {}, cause {:?}",
+                    task.name, cause
+                );
+            }
+        }
+        let state = match &res {
+            Ok(_) => {
+                info!("task {} succeeded", task.name);
+                TaskRunState::Succeeded
+            }
+            Err(cause) => {
+                warn!("task {} failed, cause {:?}", task.name, cause);
+                TaskRunState::Failed
+            }
+        };
+        Self::record_history(task, query_id, scheduled_time, start_time, state, res.err());
+        state
+    }
+
+    fn record_history(
+        task: &Task,
+        query_id: String,
+        scheduled_time: DateTime<Utc>,
+        start_time: DateTime<Utc>,
+        state: TaskRunState,
+        error: Option<ErrorCode>,
+    ) {
+        let (error_code, error_message) = match error {
+            Some(cause) => (cause.code() as i32, cause.message()),
+            None => (0, String::new()),
+        };
+        let element = TaskHistoryLogElement {
+            name: task.name.clone(),
+            query_id,
+            scheduled_time: scheduled_time.timestamp_micros(),
+            start_time: start_time.timestamp_micros(),
+            end_time: Utc::now().timestamp_micros(),
+            state: state.to_string(),
+            error_code,
+            error_message,
+            query_text: task.query_text.clone(),
+        };
+        let res = TaskHistoryQueue::instance().and_then(|queue| queue.append_data(element));
+        if let Err(cause) = res {
+            warn!(
+                "Cannot record the run of task {}, cause {:?}",
+                task.name, cause
+            );
+        }
+    }
+
+    /// Runs the tasks whose schedule fires in `(after, until]`, and the tasks after them.
+    #[async_backtrace::framed]
+    async fn run_due_tasks(&self, after: &DateTime<Utc>, until: &DateTime<Utc>) -> Result<()> {
+        let ctx = self.create_context().await?;
+        // Only one node of the cluster runs the tasks.
+        let cluster = ctx.get_cluster();
+        if !cluster.is_empty() {
+            let first_node = cluster
+                .get_nodes()
+                .into_iter()
+                .map(|node| node.id.clone())
+                .min();
+            if first_node != Some(cluster.local_id()) {
+                return Ok(());
+            }
+        }
+
+        let tenant = ctx.get_tenant();
+        let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+        BackgroundJobs::instance().retain(BackgroundJobType::Task.as_str(), |name| {
+            tasks.iter().any(|task| task.name == name)
+        });
+        // The quarantined tasks are suspended, a resumed one is released.
+        for task in tasks.iter().filter(|task| !task.suspended) {
+            BackgroundJob::new(BackgroundJobType::Task, &task.name).release();
+        }
+
+        let mut states: HashMap<String, TaskRunState> = HashMap::new();
+        for task in tasks.iter().filter(|task| !task.suspended) {
+            let Some(schedule) = &task.schedule else {
+                continue;
+            };
+            match CronSchedule::parse(schedule) {
+                Ok(schedule) => {
+                    // A failed task is retried once its backoff elapses, and a schedule
+                    // firing during the backoff is skipped.
+                    let job = BackgroundJob::new(BackgroundJobType::Task, &task.name);
+                    let fire_time = schedule.last_fire_between(after, until);
+                    if (fire_time.is_some() || job.is_retry_due(until)) && job.can_run(until) {
+                        let scheduled_time = fire_time.unwrap_or(*until);
+                        let state = self.run_task(&tenant, task, scheduled_time).await;
+                        states.insert(task.name.clone(), state);
+                    }
+                }
+                Err(cause) => warn!("Cannot schedule task {}, cause {:?}", task.name, cause),
+            }
+        }
+
+        // Run the tasks after the finished ones, until no more task is ready.
+        loop {
+            let mut progressed = false;
+            for task in tasks.iter() {
+                if task.suspended || task.after.is_empty() || states.contains_key(&task.name) {
+                    continue;
+                }
+                let Some(predecessors) = task
+                    .after
+                    .iter()
+                    .map(|name| states.get(name).copied())
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+
+                let scheduled_time = Utc::now();
+                let job = BackgroundJob::new(BackgroundJobType::Task, &task.name);
+                let state = if predecessors
+                    .iter()
+                    .all(|state| *state == TaskRunState::Succeeded)
+                {
+                    if !job.can_run(&scheduled_time) {
+                        continue;
+                    }
+                    self.run_task(&tenant, task, scheduled_time).await
+                } else {
+                    Self::record_history(
+                        task,
+                        String::new(),
+                        scheduled_time,
+                        scheduled_time,
+                        TaskRunState::Skipped,
+                        None,
+                    );
+                    TaskRunState::Skipped
+                };
+                states.insert(task.name.clone(), state);
+                progressed = true;
+            }
+            if !progressed {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    async fn schedule_loop(self: Arc<Self>) {
+        let mut last_check = Utc::now();
+        loop {
+            tokio_async_sleep(self.check_interval).await;
+            let now = Utc::now();
+            if let Err(cause) = self.run_due_tasks(&last_check, &now).await {
+                warn!("Cannot run tasks, cause {:?}", cause);
+            }
+            last_check = now;
+        }
+    }
+}
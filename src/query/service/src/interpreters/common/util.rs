@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_kvapi::kvapi::KVApi;
+use common_storages_fuse::FuseTable;
 use common_users::UserApiProvider;
+use storages_common_table_meta::meta::Location;
 
 /// Checks if a duplicate label exists in the meta store.
 ///
@@ -41,3 +45,37 @@ pub async fn check_deduplicate_label(ctx: Arc<dyn TableContext>) -> Result<bool>
         }
     }
 }
+
+/// Quote an identifier with backticks to use it in a SQL statement.
+pub fn quote_ident(ident: &str) -> String {
+    format!("`{}`", ident.replace('`', "``"))
+}
+
+/// Refreshes `table` and returns it with the segments appended since `table` was read,
+/// which are the ones not in the snapshot of `table`.
+///
+/// Returns `None` if the refreshed table has no snapshot.
+pub async fn appended_segments(
+    ctx: &dyn TableContext,
+    table: &dyn Table,
+) -> Result<Option<(Arc<dyn Table>, Vec<Location>)>> {
+    let old_segments = FuseTable::try_from_table(table)?
+        .read_table_snapshot()
+        .await?
+        .map(|snapshot| snapshot.segments.iter().cloned().collect::<HashSet<_>>())
+        .unwrap_or_default();
+    let new_table = table.refresh(ctx).await?;
+    let Some(snapshot) = FuseTable::try_from_table(new_table.as_ref())?
+        .read_table_snapshot()
+        .await?
+    else {
+        return Ok(None);
+    };
+    let new_segments = snapshot
+        .segments
+        .iter()
+        .filter(|loc| !old_segments.contains(*loc))
+        .cloned()
+        .collect();
+    Ok(Some((new_table, new_segments)))
+}
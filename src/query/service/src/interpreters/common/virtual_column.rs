@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_license::license_manager::get_license_manager;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_pipeline_core::Pipeline;
+use common_storages_fuse::FuseTable;
+use tracing::info;
+use tracing::warn;
+use virtual_columns_handler::get_virtual_columns_handler;
+
+use crate::interpreters::common::appended_segments;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Generate the virtual columns of the blocks committed by the pipeline
+/// once it is finished.
+///
+/// Failing to generate the virtual columns does not fail the ingestion,
+/// the missing virtual columns are extracted from the source columns when they are read.
+pub fn refresh_virtual_columns_on_finished(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    pipeline: &mut Pipeline,
+) -> Result<()> {
+    if !table.support_virtual_columns() || FuseTable::try_from_table(table.as_ref()).is_err() {
+        return Ok(());
+    }
+
+    pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            GlobalIORuntime::instance().block_on(async move {
+                if let Err(e) = refresh_virtual_columns(ctx, table).await {
+                    warn!("failed to refresh virtual columns: {}", e);
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+#[async_backtrace::framed]
+async fn refresh_virtual_columns(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let license_manager = get_license_manager();
+    if license_manager
+        .manager
+        .check_enterprise_enabled(
+            &ctx.get_settings(),
+            tenant.clone(),
+            "generate_virtual_columns".to_string(),
+        )
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let Some((new_table, new_segments)) = appended_segments(ctx.as_ref(), table.as_ref()).await?
+    else {
+        return Ok(());
+    };
+    let fuse_table = FuseTable::try_from_table(new_table.as_ref())?;
+    if new_segments.is_empty() {
+        return Ok(());
+    }
+
+    let catalog = ctx.get_catalog(table.get_table_info().catalog())?;
+    let handler = get_virtual_columns_handler();
+    let virtual_columns = handler
+        .do_list_virtual_columns(catalog, ListVirtualColumnsReq {
+            tenant,
+            table_id: Some(table.get_id()),
+        })
+        .await?
+        .first()
+        .map(|meta| meta.virtual_columns.clone())
+        .unwrap_or_default();
+
+    handler
+        .do_generate_virtual_columns(
+            fuse_table,
+            ctx.clone(),
+            virtual_columns,
+            Some(new_segments.clone()),
+        )
+        .await?;
+    info!(
+        "refreshed virtual columns of {} segments",
+        new_segments.len()
+    );
+
+    Ok(())
+}
@@ -15,15 +15,20 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use common_base::runtime::GlobalIORuntime;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnState;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::SendableDataBlockStream;
+use common_storages_fuse::FuseTable;
 
+use crate::clusters::WarehouseAutoSuspend;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterUsage;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -58,9 +63,17 @@ pub trait Interpreter: Sync + Send {
             return Err(err);
         }
 
+        if ctx.get_current_session().get_type().is_user_session() {
+            if let Err(err) = WarehouseAutoSuspend::instance().resume_if_suspended().await {
+                log_query_finished(&ctx, Some(err.clone()));
+                return Err(err);
+            }
+        }
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                let _ = finish_statement_txn(ctx.clone(), Some(build_error.clone())).await;
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
                 return Err(build_error);
@@ -68,6 +81,11 @@ pub trait Interpreter: Sync + Send {
         };
 
         if build_res.main_pipeline.is_empty() {
+            if let Err(err) = finish_statement_txn(ctx.clone(), None).await {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()));
+                return Err(err);
+            }
             InterpreterMetrics::record_query_finished(&ctx, None);
             log_query_finished(&ctx, None);
 
@@ -76,12 +94,19 @@ pub trait Interpreter: Sync + Send {
 
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            let txn_state = query_ctx.txn_mgr().lock().state();
+            let may_error = match txn_state {
+                TxnState::Statement => GlobalIORuntime::instance()
+                    .block_on(finish_statement_txn(query_ctx.clone(), may_error.clone()))
+                    .err(),
+                _ => may_error.clone(),
+            };
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
             log_query_finished(&query_ctx, may_error.clone());
 
             match may_error {
                 None => Ok(()),
-                Some(error) => Err(error.clone()),
+                Some(error) => Err(error),
             }
         });
 
@@ -90,6 +115,7 @@ pub trait Interpreter: Sync + Send {
         build_res.set_max_threads(settings.get_max_threads()? as usize);
         let settings = ExecutorSettings::try_create(&settings, query_id)?;
 
+        InterpreterMetrics::record_query_queue_wait(&ctx);
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
             pipelines.push(build_res.main_pipeline);
@@ -123,6 +149,20 @@ pub trait Interpreter: Sync + Send {
 
 pub type InterpreterPtr = Arc<dyn Interpreter>;
 
+/// Commits the statement transaction of a statement writing multiple tables if it succeeds,
+/// or rolls it back if it fails. Returns the error of the statement or of the commit.
+#[async_backtrace::framed]
+async fn finish_statement_txn(ctx: Arc<QueryContext>, may_error: Option<ErrorCode>) -> Result<()> {
+    let mutations = ctx.txn_mgr().lock().end_statement();
+    match may_error {
+        None => FuseTable::commit_txn(ctx.as_ref(), mutations).await,
+        Some(error) => {
+            FuseTable::rollback_txn(ctx.as_ref(), mutations).await;
+            Err(error)
+        }
+    }
+}
+
 fn log_query_start(ctx: &QueryContext) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
@@ -148,4 +188,8 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         tracing::error!("interpreter.finish.error: {:?}", error)
     }
+
+    if let Err(error) = InterpreterUsage::record_finish(ctx, now) {
+        tracing::error!("interpreter.usage.error: {:?}", error)
+    }
 }
@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateBloomIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Adds columns to the `bloom_index_columns` option of a fuse table, the bloom filters of the
+/// columns are written in the blocks written afterwards.
+pub struct CreateBloomIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateBloomIndexPlan,
+}
+
+impl CreateBloomIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateBloomIndexPlan) -> Result<Self> {
+        Ok(CreateBloomIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateBloomIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateBloomIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = fuse_table.get_table_info();
+
+        let mut columns = fuse_table.bloom_index_columns();
+        for column in &self.plan.columns {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+            } else if !self.plan.if_not_exists {
+                return Err(ErrorCode::IndexAlreadyExists(format!(
+                    "Bloom index on column {} of table {}.{} already exists",
+                    column, self.plan.database, self.plan.table
+                )));
+            }
+        }
+
+        let option = columns.join(",");
+        FuseTable::parse_bloom_index_columns(&table_info.schema(), &option)?;
+        if table_info.options().get(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS) == Some(&option) {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        catalog
+            .upsert_table_option(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                UpsertTableOptionReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    options: HashMap::from([(
+                        FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS.to_string(),
+                        Some(option),
+                    )]),
+                },
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::CommitPreparedPlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Applies the prepared commit of `COMMIT PREPARED` to its table.
+pub struct CommitPreparedInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CommitPreparedPlan,
+}
+
+impl CommitPreparedInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CommitPreparedPlan) -> Result<Self> {
+        Ok(CommitPreparedInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitPreparedInterpreter {
+    fn name(&self) -> &str {
+        "CommitPreparedInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        FuseTable::commit_prepared(self.ctx.as_ref(), &self.plan.id).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
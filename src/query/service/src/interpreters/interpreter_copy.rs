@@ -19,6 +19,7 @@ use std::time::Instant;
 use chrono::Utc;
 use common_base::runtime::GlobalIORuntime;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::plan::UnloadPartitionKey;
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_exception::ErrorCode;
@@ -34,8 +35,11 @@ use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_pipeline_core::Pipeline;
 use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use common_sql::optimizer::SExpr;
 use common_sql::plans::CopyIntoTableMode;
 use common_sql::plans::CopyIntoTablePlan;
+use common_sql::plans::Exchange;
+use common_sql::plans::RelOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
 use common_storages_fuse::io::Files;
@@ -46,6 +50,7 @@ use tracing::info;
 
 use crate::interpreters::common::append2table;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::StageQuota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
@@ -113,9 +118,21 @@ impl CopyInterpreter {
         stage: &StageInfo,
         path: &str,
         query: &Plan,
+        partition_by: &[UnloadPartitionKey],
+        file_name_prefix: &Option<String>,
     ) -> Result<PipelineBuildResult> {
+        // the size of the unloaded files is unknown before running the query, so only the
+        // stages which have already reached their quotas are rejected
+        StageQuota::try_load(&self.ctx.get_tenant(), stage)
+            .await?
+            .check_and_add(1, 0)?;
+
         let (mut build_res, data_schema) = self.build_query(query).await?;
         let table_schema = infer_table_schema(&data_schema)?;
+        let is_ordered = match query {
+            Plan::Query { s_expr, .. } => is_ordered_query(s_expr),
+            _ => false,
+        };
         let stage_table_info = StageTableInfo {
             schema: table_schema,
             stage_info: stage.clone(),
@@ -126,8 +143,12 @@ impl CopyInterpreter {
             },
             files_to_copy: None,
             is_select: false,
+            is_ordered,
+            partition_by: partition_by.to_vec(),
+            file_name_prefix: file_name_prefix.clone(),
         };
         let table = StageTable::try_create(stage_table_info)?;
+        self.ctx.attach_accessed_table(table.clone());
         append2table(
             self.ctx.clone(),
             table,
@@ -203,6 +224,7 @@ impl CopyInterpreter {
         let mut stage_table_info = plan.stage_table_info.clone();
         stage_table_info.files_to_copy = Some(files.clone());
         let stage_table = StageTable::try_create(stage_table_info.clone())?;
+        ctx.attach_accessed_table(stage_table.clone());
         let read_source_plan = {
             stage_table
                 .read_plan_with_catalog(ctx.clone(), plan.catalog_name.to_string(), None, None)
@@ -454,13 +476,41 @@ impl Interpreter for CopyInterpreter {
             CopyPlan::IntoTable(plan) => self.build_copy_into_table_pipeline(plan).await,
 
             CopyPlan::IntoStage {
-                stage, from, path, ..
-            } => self.build_copy_into_stage_pipeline(stage, path, from).await,
+                stage,
+                from,
+                path,
+                partition_by,
+                file_name_prefix,
+                ..
+            } => {
+                self.build_copy_into_stage_pipeline(
+                    stage,
+                    path,
+                    from,
+                    partition_by,
+                    file_name_prefix,
+                )
+                .await
+            }
             CopyPlan::NoFileToCopy => Ok(PipelineBuildResult::create()),
         }
     }
 }
 
+/// Whether the output of the query is globally ordered, i.e. there is a sort on top of
+/// the plan which is only followed by order-preserving operators.
+fn is_ordered_query(s_expr: &SExpr) -> bool {
+    match s_expr.plan() {
+        RelOperator::Sort(_) => true,
+        RelOperator::EvalScalar(_)
+        | RelOperator::Limit(_)
+        | RelOperator::Exchange(Exchange::Merge) => {
+            s_expr.arity() == 1 && s_expr.child(0).map_or(false, is_ordered_query)
+        }
+        _ => false,
+    }
+}
+
 fn fill_const_columns(
     ctx: Arc<QueryContext>,
     pipeline: &mut Pipeline,
@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_sql::plans::DeclareResultPlan;
+use common_sql::plans::Plan;
+use common_storages_result_cache::WriteDeclaredResultSink;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Runs the query of `DECLARE <name> RESULT AS <query>` and writes its result to the
+/// storage, instead of sending it to the client.
+pub struct DeclareResultInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DeclareResultPlan,
+}
+
+impl DeclareResultInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DeclareResultPlan) -> Result<Self> {
+        Ok(DeclareResultInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DeclareResultInterpreter {
+    fn name(&self) -> &str {
+        "DeclareResultInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let select_interpreter = match self.plan.query.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => SelectInterpreter::try_create(
+                self.ctx.clone(),
+                *bind_context.clone(),
+                *s_expr.clone(),
+                metadata.clone(),
+                None,
+                false,
+            )?,
+            plan => {
+                return Err(ErrorCode::Internal(format!(
+                    "DECLARE RESULT expects a query plan, but got {plan}"
+                )));
+            }
+        };
+
+        let physical_plan = select_interpreter.build_physical_plan().await?;
+        let mut build_res = select_interpreter.build_pipeline(physical_plan).await?;
+        let schema = infer_table_schema(&select_interpreter.schema())?;
+
+        // All the outputs of the query go to one `WriteDeclaredResultSink`.
+        let output_len = build_res.main_pipeline.output_len();
+        let mut inputs = Vec::with_capacity(output_len);
+        for _ in 0..output_len {
+            inputs.push(InputPort::create());
+        }
+        let sink = WriteDeclaredResultSink::try_create(
+            self.ctx.clone(),
+            self.plan.name.clone(),
+            schema,
+            inputs.clone(),
+        )?;
+        let item = PipeItem::create(sink, inputs, vec![]);
+        build_res
+            .main_pipeline
+            .add_pipe(Pipe::create(output_len, 0, vec![item]));
+
+        Ok(build_res)
+    }
+}
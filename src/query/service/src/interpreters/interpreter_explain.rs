@@ -12,21 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::types::StringType;
+use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
+use common_meta_app::principal::StageInfo;
 use common_profile::ProfSpanSetRef;
+use common_sql::optimizer::CardinalityFeedback;
+use common_sql::plans::CopyPlan;
+use common_sql::plans::InsertInputSource;
 use common_sql::MetadataRef;
+use common_storage::init_stage_operator;
+use serde_json::json;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -39,6 +50,9 @@ use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
+use crate::sql::optimizer::optimize;
+use crate::sql::optimizer::OptimizerConfig;
+use crate::sql::optimizer::OptimizerContext;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Plan;
 
@@ -158,6 +172,10 @@ impl Interpreter for ExplainInterpreter {
                 }
             },
 
+            ExplainKind::Verify => self.verify(&self.plan).await?,
+
+            ExplainKind::Repro(sql) => self.explain_repro(sql).await?,
+
             ExplainKind::Graph => {
                 return Err(ErrorCode::Unimplemented(
                     "ExplainKind graph is unimplemented",
@@ -276,6 +294,286 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    /// Packages the query with what its planning depends on into a JSON bundle, which can
+    /// be attached to a bug report and replayed against another build.
+    ///
+    /// No data of the tables is included, the column statistics leave out the min/max values.
+    #[async_backtrace::framed]
+    async fn explain_repro(&self, sql: &str) -> Result<Vec<DataBlock>> {
+        let Plan::Query { metadata, .. } = &self.plan else {
+            return Err(ErrorCode::Unimplemented(
+                "Unsupported EXPLAIN(REPRO) statement",
+            ));
+        };
+
+        // The tables are collected before optimizing, which may add tables to the metadata.
+        let entries = metadata.read().tables().to_vec();
+        let raw_plan = self.plan.format_indent()?;
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let optimized = optimize(self.ctx.clone(), opt_ctx, self.plan.clone())?;
+        let optimized_plan = optimized.format_indent()?;
+        let physical_plan = match &optimized {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => {
+                let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                let plan = builder.build(s_expr).await?;
+                plan.format(metadata.clone(), ProfSpanSetRef::default())?
+                    .format_pretty()?
+            }
+            _ => unreachable!(),
+        };
+
+        let settings = self
+            .ctx
+            .get_settings()
+            .get_changes()
+            .into_iter()
+            .map(|(name, change)| (name, change.value.to_string()))
+            .collect::<BTreeMap<_, _>>();
+        let mut replay = settings
+            .iter()
+            .map(|(name, value)| format!("SET {} = '{}'", name, value.replace('\'', "\\'")))
+            .collect::<Vec<_>>();
+
+        let mut tables = vec![];
+        let mut visited = HashSet::new();
+        for entry in entries {
+            let table = entry.table();
+            let key = (
+                entry.catalog().to_string(),
+                entry.database().to_string(),
+                table.name().to_string(),
+            );
+            if !visited.insert(key) {
+                continue;
+            }
+
+            let create_table = Self::repro_create_table(entry.database(), table.as_ref());
+            let statistics = table.table_statistics()?.map(|stat| {
+                json!({
+                    "num_rows": stat.num_rows,
+                    "data_size": stat.data_size,
+                    "data_size_compressed": stat.data_size_compressed,
+                    "index_size": stat.index_size,
+                })
+            });
+            let provider = table.column_statistics_provider().await?;
+            let mut column_statistics = serde_json::Map::new();
+            for (column_id, field) in table.schema().leaf_fields().iter().enumerate() {
+                if let Some(stat) = provider.column_statistics(column_id as ColumnId) {
+                    column_statistics.insert(
+                        field.name().clone(),
+                        json!({
+                            "null_count": stat.null_count,
+                            "number_of_distinct_values": stat.number_of_distinct_values,
+                        }),
+                    );
+                }
+            }
+
+            replay.push(format!(
+                "CREATE DATABASE IF NOT EXISTS `{}`",
+                entry.database()
+            ));
+            replay.push(create_table.clone());
+            tables.push(json!({
+                "catalog": entry.catalog(),
+                "database": entry.database(),
+                "name": table.name(),
+                "engine": table.engine(),
+                "create_table": create_table,
+                "statistics": statistics,
+                "column_statistics": column_statistics,
+            }));
+        }
+        replay.push(format!("USE `{}`", self.ctx.get_current_database()));
+        replay.push(format!("EXPLAIN {}", sql));
+
+        let bundle = json!({
+            "version": DATABEND_COMMIT_VERSION.as_str(),
+            "sql": sql,
+            "current_database": self.ctx.get_current_database(),
+            "settings": settings,
+            "tables": tables,
+            "optimizer_trace": {
+                "raw_plan": raw_plan.lines().collect::<Vec<_>>(),
+                "optimized_plan": optimized_plan.lines().collect::<Vec<_>>(),
+                "physical_plan": physical_plan.lines().collect::<Vec<_>>(),
+            },
+            "replay": replay,
+        });
+        let bundle = serde_json::to_string_pretty(&bundle)?;
+        let line_split_result: Vec<&str> = bundle.lines().collect();
+        let column = StringType::from_data(line_split_result);
+        Ok(vec![DataBlock::new_from_columns(vec![column])])
+    }
+
+    fn repro_create_table(database: &str, table: &dyn Table) -> String {
+        let columns = table
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| format!("  `{}` {}", field.name(), field.data_type().sql_name()))
+            .collect::<Vec<_>>();
+        let mut create_table = format!(
+            "CREATE TABLE IF NOT EXISTS `{}`.`{}` (\n{}\n) ENGINE={}",
+            database,
+            table.name(),
+            columns.join(",\n"),
+            table.engine()
+        );
+        if let Some((_, cluster_keys)) = table.get_table_info().meta.cluster_key() {
+            create_table.push_str(&format!(" CLUSTER BY {}", cluster_keys));
+        }
+        create_table
+    }
+
+    /// Check that the statement can be executed without executing it.
+    ///
+    /// The statement has already been parsed, bound (which resolves the tables and
+    /// type checks the expressions) and passed the privilege check when we get here.
+    /// The remaining checks are: the target tables of DML exist, the query plans can be
+    /// built into physical plans and the stages are accessible.
+    #[async_backtrace::framed]
+    async fn verify(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
+        let mut checks = vec![format!("statement: {}", plan), "privileges: OK".to_string()];
+
+        let mut plans = vec![plan];
+        while let Some(plan) = plans.pop() {
+            match plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => {
+                    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                    builder.build(s_expr).await?;
+                    checks.push("query: OK".to_string());
+                }
+                Plan::Insert(insert) => {
+                    self.verify_table(
+                        &insert.catalog,
+                        &insert.database,
+                        &insert.table,
+                        &mut checks,
+                    )
+                    .await?;
+                    if let InsertInputSource::SelectPlan(plan) | InsertInputSource::Stage(plan) =
+                        &insert.source
+                    {
+                        plans.push(plan);
+                    }
+                }
+                Plan::Replace(replace) => {
+                    self.verify_table(
+                        &replace.catalog,
+                        &replace.database,
+                        &replace.table,
+                        &mut checks,
+                    )
+                    .await?;
+                    if let InsertInputSource::SelectPlan(plan) | InsertInputSource::Stage(plan) =
+                        &replace.source
+                    {
+                        plans.push(plan);
+                    }
+                }
+                Plan::Delete(delete) => {
+                    self.verify_table(
+                        &delete.catalog_name,
+                        &delete.database_name,
+                        &delete.table_name,
+                        &mut checks,
+                    )
+                    .await?;
+                }
+                Plan::Update(update) => {
+                    self.verify_table(
+                        &update.catalog,
+                        &update.database,
+                        &update.table,
+                        &mut checks,
+                    )
+                    .await?;
+                }
+                Plan::MergeInto(merge_into) => {
+                    self.verify_table(
+                        &merge_into.catalog,
+                        &merge_into.database,
+                        &merge_into.table,
+                        &mut checks,
+                    )
+                    .await?;
+                    let mut builder =
+                        PhysicalPlanBuilder::new(merge_into.metadata.clone(), self.ctx.clone());
+                    builder.build(&merge_into.input).await?;
+                    checks.push("query: OK".to_string());
+                }
+                Plan::Copy(copy) => match copy.as_ref() {
+                    CopyPlan::IntoTable(copy) => {
+                        self.verify_table(
+                            &copy.catalog_name,
+                            &copy.database_name,
+                            &copy.table_name,
+                            &mut checks,
+                        )
+                        .await?;
+                        let stage_info = &copy.stage_table_info.stage_info;
+                        self.verify_stage(&stage_info.stage_name, stage_info, &mut checks)
+                            .await?;
+                        if let Some(query) = &copy.query {
+                            plans.push(query);
+                        }
+                    }
+                    CopyPlan::IntoStage { stage, from, .. } => {
+                        self.verify_stage(&stage.stage_name, stage, &mut checks)
+                            .await?;
+                        plans.push(from);
+                    }
+                    CopyPlan::NoFileToCopy => {}
+                },
+                Plan::PrepareCommit(prepare) => plans.push(&prepare.plan),
+                Plan::DeclareResult(declare) => plans.push(&declare.query),
+                _ => {}
+            }
+        }
+
+        let column = StringType::from_data(checks);
+        Ok(vec![DataBlock::new_from_columns(vec![column])])
+    }
+
+    #[async_backtrace::framed]
+    async fn verify_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        checks: &mut Vec<String>,
+    ) -> Result<()> {
+        let table = self.ctx.get_table(catalog, database, table).await?;
+        checks.push(format!("target table {}.{}: OK", database, table.name()));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn verify_stage(
+        &self,
+        name: &str,
+        stage_info: &StageInfo,
+        checks: &mut Vec<String>,
+    ) -> Result<()> {
+        let operator = init_stage_operator(stage_info)?;
+        if let Err(cause) = operator.check().await {
+            return Err(ErrorCode::StorageUnavailable(format!(
+                "stage {} is not accessible: {}",
+                name, cause
+            )));
+        }
+        checks.push(format!("stage {}: OK", name));
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn explain_analyze(
         &self,
@@ -309,10 +607,30 @@ impl ExplainInterpreter {
             while (pulling_executor.pull_data()?).is_some() {}
         }
 
+        if let Some(fingerprint) = metadata.read().plan_fingerprint() {
+            CardinalityFeedback::instance().record(
+                fingerprint,
+                &plan,
+                &prof_span_set.lock().unwrap(),
+            );
+        }
+
+        // Where the time of the query goes, to tell CPU-bound queries from IO-bound ones.
+        let total = prof_span_set.lock().unwrap().total();
+        let wait_analysis = format!(
+            "cpu time: {}ms, io wait time: {}ms, exchange wait time: {}ms, lock wait time: {}ms",
+            total.cpu_time / 1000 / 1000,
+            total.io_wait_time / 1000 / 1000,
+            total.exchange_wait_time / 1000 / 1000,
+            total.lock_wait_time / 1000 / 1000,
+        );
+
         let result = plan
             .format(metadata.clone(), prof_span_set)?
             .format_pretty()?;
-        let line_split_result: Vec<&str> = result.lines().collect();
+        let line_split_result: Vec<&str> = std::iter::once(wait_analysis.as_str())
+            .chain(result.lines())
+            .collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -59,9 +61,56 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+
+        // With `dry_run`, statements are only verified. Settings and `USE` still take
+        // effect so that a script can be verified as a whole.
+        if ctx.get_settings().get_dry_run()?
+            && !matches!(
+                plan,
+                Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::UseDatabase(_)
+            )
+        {
+            return Ok(Arc::new(ExplainInterpreter::try_create(
+                ctx,
+                plan.clone(),
+                ExplainKind::Verify,
+            )?));
+        }
+
+        // Only the table mutations of fuse tables are buffered in a transaction, the
+        // others would take effect immediately and can not be rolled back.
+        if ctx.txn_mgr().lock().is_active() && !Self::allowed_in_txn(plan) {
+            return Err(ErrorCode::InvalidTransactionState(format!(
+                "{} is not allowed in a transaction, COMMIT or ROLLBACK the transaction first",
+                plan
+            )));
+        }
         Self::get_inner(ctx, plan)
     }
 
+    fn allowed_in_txn(plan: &Plan) -> bool {
+        matches!(
+            plan,
+            Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::Copy(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::UseDatabase(_)
+        )
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -223,6 +272,46 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+            Plan::DropMaterializedView(drop_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_view.clone())?,
+            )),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *create_task.clone(),
+            )?)),
+            Plan::AlterTask(alter_task) => Ok(Arc::new(AlterTaskInterpreter::try_create(
+                ctx,
+                *alter_task.clone(),
+            )?)),
+            Plan::DropTask(drop_task) => Ok(Arc::new(DropTaskInterpreter::try_create(
+                ctx,
+                *drop_task.clone(),
+            )?)),
+
+            // Plan baselines
+            Plan::AlterStatement(alter_statement) => Ok(Arc::new(
+                AlterStatementInterpreter::try_create(ctx, *alter_statement.clone())?,
+            )),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -233,6 +322,20 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateBloomIndex(index) => Ok(Arc::new(CreateBloomIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::DropBloomIndex(index) => Ok(Arc::new(DropBloomIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
 
             // Virtual columns
             Plan::CreateVirtualColumns(create_virtual_columns) => Ok(Arc::new(
@@ -278,6 +381,36 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => Ok(Arc::new(MergeIntoInterpreter::try_create(
+                ctx,
+                *merge_into.clone(),
+            )?)),
+
+            // Results
+            Plan::DeclareResult(p) => Ok(Arc::new(DeclareResultInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            // Prepared commits
+            Plan::PrepareCommit(p) => Ok(Arc::new(PrepareCommitInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CommitPrepared(p) => Ok(Arc::new(CommitPreparedInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::RollbackPrepared(p) => Ok(Arc::new(RollbackPreparedInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -417,6 +550,10 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::AmendTable(p) => Ok(Arc::new(AmendTableInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
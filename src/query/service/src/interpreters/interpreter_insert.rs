@@ -51,6 +51,9 @@ use parking_lot::RwLock;
 
 use crate::interpreters::common::append2table;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::refresh_agg_index_on_finished;
+use crate::interpreters::common::refresh_dictionaries_on_finished;
+use crate::interpreters::common::refresh_virtual_columns_on_finished;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
@@ -250,6 +253,24 @@ impl Interpreter for InsertInterpreter {
                     self.plan.overwrite,
                 )?;
 
+                refresh_agg_index_on_finished(
+                    self.ctx.clone(),
+                    table.clone(),
+                    &mut build_res.main_pipeline,
+                )?;
+
+                refresh_dictionaries_on_finished(
+                    self.ctx.clone(),
+                    table.clone(),
+                    &mut build_res.main_pipeline,
+                )?;
+
+                refresh_virtual_columns_on_finished(
+                    self.ctx.clone(),
+                    table.clone(),
+                    &mut build_res.main_pipeline,
+                )?;
+
                 return Ok(build_res);
             }
         };
@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_INVERTED_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Adds an index to the `inverted_indexes` option of a fuse table, the terms of the indexed
+/// columns are written with the bloom index of the blocks written afterwards.
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = fuse_table.get_table_info();
+
+        let mut indexes = fuse_table.inverted_indexes();
+        if indexes
+            .iter()
+            .any(|(name, _)| name == &self.plan.index_name)
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "Inverted index {} of table {}.{} already exists",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+        indexes.push((self.plan.index_name.clone(), self.plan.columns.clone()));
+
+        let option = FuseTable::format_inverted_indexes(&indexes);
+        FuseTable::parse_inverted_indexes(&table_info.schema(), &option)?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        catalog
+            .upsert_table_option(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                UpsertTableOptionReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    options: HashMap::from([(
+                        FUSE_OPT_KEY_INVERTED_INDEXES.to_string(),
+                        Some(option),
+                    )]),
+                },
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
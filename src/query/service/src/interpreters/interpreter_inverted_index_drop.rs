@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_INVERTED_INDEXES;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Removes an index from the `inverted_indexes` option of a fuse table. The option is unset
+/// when the last index is dropped.
+pub struct DropInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropInvertedIndexPlan,
+}
+
+impl DropInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropInvertedIndexPlan) -> Result<Self> {
+        Ok(DropInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = fuse_table.get_table_info();
+
+        let mut indexes = fuse_table.inverted_indexes();
+        if !indexes
+            .iter()
+            .any(|(name, _)| name == &self.plan.index_name)
+        {
+            return if self.plan.if_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::UnknownIndex(format!(
+                    "Inverted index {} of table {}.{} does not exist",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+        indexes.retain(|(name, _)| name != &self.plan.index_name);
+
+        let option = if indexes.is_empty() {
+            None
+        } else {
+            Some(FuseTable::format_inverted_indexes(&indexes))
+        };
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        catalog
+            .upsert_table_option(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                UpsertTableOptionReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    options: HashMap::from([(FUSE_OPT_KEY_INVERTED_INDEXES.to_string(), option)]),
+                },
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DropMaterializedViewPlan;
+use common_sql::plans::DropTablePlan;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::DropTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tbl = self
+            .ctx
+            .get_table(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.view_name,
+            )
+            .await
+            .ok();
+
+        if tbl.is_none() && !self.plan.if_exists {
+            return Err(ErrorCode::UnknownTable(format!(
+                "unknown materialized view {}.{}",
+                self.plan.database, self.plan.view_name
+            )));
+        }
+
+        if let Some(table) = &tbl {
+            if !table
+                .options()
+                .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            {
+                return Err(ErrorCode::Internal(format!(
+                    "{}.{} is not MATERIALIZED VIEW, please use `DROP TABLE {}.{}`",
+                    &self.plan.database,
+                    &self.plan.view_name,
+                    &self.plan.database,
+                    &self.plan.view_name
+                )));
+            }
+
+            DropTableInterpreter::try_create(self.ctx.clone(), DropTablePlan {
+                if_exists: self.plan.if_exists,
+                tenant: self.plan.tenant.clone(),
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.view_name.clone(),
+                all: false,
+            })?
+            .execute2()
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
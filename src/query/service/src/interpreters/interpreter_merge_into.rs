@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::RemoteExpr;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::executor::MergeInto as PhysicalMergeInto;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::plans::MergeInto;
+use common_sql::ScalarExpr;
+use common_sql::TypeCheck;
+use table_lock::TableLockHandlerWrapper;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_local_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// interprets MergeInto
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    /// Create the MergeIntoInterpreter from MergeInto
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<Self> {
+        Ok(MergeIntoInterpreter { ctx, plan })
+    }
+
+    /// Build the join of the source and the target table, and apply the clauses to its rows
+    /// by the physical MergeInto.
+    #[async_backtrace::framed]
+    async fn build_physical_plan(&self, table: &Arc<dyn Table>) -> Result<PhysicalPlan> {
+        let join = PhysicalPlanBuilder::new(self.plan.metadata.clone(), self.ctx.clone())
+            .build(&self.plan.input)
+            .await?;
+        let input_schema = join.output_schema()?;
+
+        let lower = |scalar: &ScalarExpr| -> Result<Expr> {
+            Ok(scalar
+                .resolve_and_check(input_schema.as_ref())?
+                .project_column_ref(|index| input_schema.index_of(&index.to_string()).unwrap()))
+        };
+        let compile =
+            |scalar: &ScalarExpr| -> Result<RemoteExpr> { Ok(lower(scalar)?.as_remote_expr()) };
+        let compile_condition = |condition: &Option<ScalarExpr>| -> Result<Option<RemoteExpr>> {
+            condition
+                .as_ref()
+                .map(|scalar| Ok(cast_expr_to_non_null_boolean(lower(scalar)?)?.as_remote_expr()))
+                .transpose()
+        };
+
+        let mut matched = Vec::with_capacity(self.plan.matched_evaluators.len());
+        for evaluator in self.plan.matched_evaluators.iter() {
+            let update = match &evaluator.update {
+                Some(values) => Some(values.iter().map(compile).collect::<Result<Vec<_>>>()?),
+                None => None,
+            };
+            matched.push((compile_condition(&evaluator.condition)?, update));
+        }
+        let mut unmatched = Vec::with_capacity(self.plan.unmatched_evaluators.len());
+        for evaluator in self.plan.unmatched_evaluators.iter() {
+            let values = evaluator
+                .values
+                .iter()
+                .map(compile)
+                .collect::<Result<Vec<_>>>()?;
+            unmatched.push((compile_condition(&evaluator.condition)?, values));
+        }
+
+        let row_id_offset = input_schema.index_of(&self.plan.row_id_index.to_string())?;
+        Ok(PhysicalPlan::MergeInto(Box::new(PhysicalMergeInto {
+            input: Box::new(join),
+            catalog: self.plan.catalog.clone(),
+            table_info: table.get_table_info().clone(),
+            row_id_offset,
+            matched,
+            unmatched,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    async fn build_pipeline(&self, table: &Arc<dyn Table>) -> Result<PipelineBuildResult> {
+        let physical_plan = self.build_physical_plan(table).await?;
+        // The join is planned for standalone execution, see `optimize`.
+        let mut build_res = build_local_pipeline(&self.ctx, &physical_plan, false).await?;
+        table
+            .merge_into(self.ctx.clone(), &mut build_res.main_pipeline)
+            .await?;
+        Ok(build_res)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_into_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let table_info = tbl.get_table_info().clone();
+
+        // Add table lock heartbeat.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler
+            .try_lock(self.ctx.clone(), table_info.clone())
+            .await?;
+
+        // refresh table.
+        let tbl = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // The target table is bound before it's locked, the join must read the snapshot
+        // which is mutated.
+        self.plan
+            .metadata
+            .write()
+            .replace_table(self.plan.target_table_index, tbl.clone());
+
+        let mut build_res = match self.build_pipeline(&tbl).await {
+            Ok(build_res) => build_res,
+            Err(e) => {
+                heartbeat.shutdown().await?;
+                return Err(e);
+            }
+        };
+
+        if build_res.main_pipeline.is_empty() {
+            heartbeat.shutdown().await?;
+        } else {
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                // shutdown table lock heartbeat.
+                GlobalIORuntime::instance().block_on(async move { heartbeat.shutdown().await })?;
+                match may_error {
+                    None => Ok(()),
+                    Some(error_code) => Err(error_code.clone()),
+                }
+            });
+        }
+
+        Ok(build_res)
+    }
+}
@@ -20,6 +20,8 @@ use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_metrics::label_counter_with_val_and_labels;
 use common_metrics::label_histogram_with_val;
+use common_metrics::METRIC_QUERY_LATENCY_SECONDS;
+use common_metrics::METRIC_QUERY_QUEUE_WAIT_SECONDS;
 
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -90,6 +92,11 @@ impl InterpreterMetrics {
         let result_bytes = ctx.get_result_progress_value().bytes as u64;
 
         label_histogram_with_val(QUERY_DURATION_MS, labels, query_duration_ms);
+        label_histogram_with_val(
+            METRIC_QUERY_LATENCY_SECONDS,
+            labels,
+            query_duration_ms / 1_000.0,
+        );
 
         label_counter_with_val_and_labels(QUERY_WRITE_ROWS, labels, written_rows);
         label_counter_with_val_and_labels(QUERY_WRITE_BYTES, labels, written_bytes);
@@ -124,6 +131,20 @@ impl InterpreterMetrics {
         label_counter_with_val_and_labels(QUERY_START, &labels, 1);
     }
 
+    /// Records the time the query waits from being accepted to its pipeline starting to execute,
+    /// which includes planning and waiting for the warehouse to resume.
+    pub fn record_query_queue_wait(ctx: &QueryContext) {
+        let labels = Self::common_labels(ctx);
+        let queue_wait = SystemTime::now()
+            .duration_since(ctx.get_created_time())
+            .unwrap_or(Duration::new(0, 0));
+        label_histogram_with_val(
+            METRIC_QUERY_QUEUE_WAIT_SECONDS,
+            &labels,
+            queue_wait.as_secs_f64(),
+        );
+    }
+
     pub fn record_query_finished(ctx: &QueryContext, err: Option<ErrorCode>) {
         let mut labels = Self::common_labels(ctx);
         Self::record_query_detail(ctx, &labels);
@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_sql::plans::PrepareCommitPlan;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+
+/// Runs the write of `PREPARE COMMIT` with the `prepared_commit_id` setting, so the
+/// fuse table keeps the commit aside instead of applying it.
+pub struct PrepareCommitInterpreter {
+    plan: PrepareCommitPlan,
+    interpreter: InterpreterPtr,
+}
+
+impl PrepareCommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: PrepareCommitPlan) -> Result<Self> {
+        // The setting only lives in the settings of this query.
+        ctx.get_settings().set_prepared_commit_id(plan.id.clone())?;
+        let interpreter = InterpreterFactory::get_inner(ctx, &plan.plan)?;
+        Ok(PrepareCommitInterpreter { plan, interpreter })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PrepareCommitInterpreter {
+    fn name(&self) -> &str {
+        "PrepareCommitInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.plan.schema()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.interpreter.execute2().await
+    }
+
+    fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
+        self.interpreter.set_source_pipe_builder(builder)
+    }
+}
@@ -35,6 +35,76 @@ use crate::sessions::TableContext;
 
 pub struct InterpreterQueryLog;
 
+/// Minimum number of column reads before a query can be tagged as cache-miss-heavy.
+const CACHE_MISS_HEAVY_MIN_READS: u64 = 64;
+/// Fraction of column reads missing the data cache to tag a query as cache-miss-heavy.
+const CACHE_MISS_HEAVY_RATIO: f64 = 0.8;
+/// Minimum number of partitions before a query can be tagged as pruning-ineffective.
+const PRUNING_INEFFECTIVE_MIN_PARTITIONS: u64 = 16;
+/// Fraction of partitions surviving pruning to tag a query as pruning-ineffective.
+const PRUNING_INEFFECTIVE_RATIO: f64 = 0.9;
+/// Ratio of the busiest hash join probe worker to the average one to tag a query as skewed-join.
+const SKEWED_JOIN_RATIO: f64 = 2.0;
+
+/// Anomaly flags derived from the runtime metrics of a finished query.
+#[derive(Default)]
+struct QueryLogAnomalies {
+    spilled: bool,
+    cache_miss_heavy: bool,
+    pruning_ineffective: bool,
+    skewed_join: bool,
+    slow_query_class: String,
+}
+
+impl QueryLogAnomalies {
+    fn analyze(ctx: &QueryContext, query_duration_ms: i64) -> Result<QueryLogAnomalies> {
+        let settings = ctx.get_settings();
+        if !settings.get_enable_query_log_analyzer()? {
+            return Ok(QueryLogAnomalies::default());
+        }
+
+        let spilled = ctx.get_spill_metrics().get_write_bytes() > 0;
+
+        let cache_metrics = ctx.get_data_cache_metrics();
+        let cache_misses = cache_metrics.get_misses();
+        let cache_reads = cache_metrics.get_hits() + cache_misses;
+        let cache_miss_heavy = cache_reads >= CACHE_MISS_HEAVY_MIN_READS
+            && cache_misses as f64 >= cache_reads as f64 * CACHE_MISS_HEAVY_RATIO;
+
+        let data_metrics = ctx.get_data_metrics();
+        let scan_partitions = data_metrics.get_partitions_scanned();
+        let total_partitions = data_metrics.get_partitions_total();
+        let pruning_ineffective = total_partitions >= PRUNING_INEFFECTIVE_MIN_PARTITIONS
+            && scan_partitions as f64 >= total_partitions as f64 * PRUNING_INEFFECTIVE_RATIO;
+
+        let skewed_join = ctx.get_max_join_skew() >= SKEWED_JOIN_RATIO;
+
+        // A slow query is classified by its most likely cause, in order of severity.
+        let slow_threshold_ms = settings.get_query_log_slow_query_threshold_ms()?;
+        let slow_query_class = if query_duration_ms < slow_threshold_ms as i64 {
+            ""
+        } else if spilled {
+            "spilled"
+        } else if skewed_join {
+            "skewed-join"
+        } else if pruning_ineffective {
+            "pruning-ineffective"
+        } else if cache_miss_heavy {
+            "cache-miss-heavy"
+        } else {
+            "unclassified"
+        };
+
+        Ok(QueryLogAnomalies {
+            spilled,
+            cache_miss_heavy,
+            pruning_ineffective,
+            skewed_join,
+            slow_query_class: slow_query_class.to_string(),
+        })
+    }
+}
+
 fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, String, String) {
     match err {
         None => (log_type, 0, "".to_string(), "".to_string()),
@@ -92,6 +162,12 @@ impl InterpreterQueryLog {
         let query_text = ctx.get_query_str();
         // Schema.
         let current_database = ctx.get_current_database();
+        let snapshots = ctx
+            .get_pinned_snapshots()
+            .into_iter()
+            .map(|(table, snapshot_id)| format!("{table}@{snapshot_id}"))
+            .collect::<Vec<_>>()
+            .join(", ");
 
         // Stats.
         let event_time = convert_query_log_timestamp(now);
@@ -153,6 +229,7 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            snapshots: "".to_string(),
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -167,6 +244,11 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            spilled: false,
+            cache_miss_heavy: false,
+            pruning_ineffective: false,
+            skewed_join: false,
+            slow_query_class: "".to_string(),
             client_info: "".to_string(),
             client_address,
 
@@ -220,6 +302,9 @@ impl InterpreterQueryLog {
         let result_rows = ctx.get_result_progress_value().rows as u64;
         let result_bytes = ctx.get_result_progress_value().bytes as u64;
 
+        // Anomaly.
+        let anomalies = QueryLogAnomalies::analyze(ctx, query_duration_ms)?;
+
         // Client.
         let client_address = match ctx.get_client_address() {
             Some(addr) => format!("{:?}", addr),
@@ -263,6 +348,7 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            snapshots,
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -277,6 +363,11 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            spilled: anomalies.spilled,
+            cache_miss_heavy: anomalies.cache_miss_heavy,
+            pruning_ineffective: anomalies.pruning_ineffective,
+            skewed_join: anomalies.skewed_join,
+            slow_query_class: anomalies.slow_query_class,
             client_info: "".to_string(),
             client_address,
             current_database,
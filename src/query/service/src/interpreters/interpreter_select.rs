@@ -28,6 +28,7 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::TransformDummy;
 use common_sql::executor::PhysicalPlan;
+use common_sql::optimizer::CardinalityFeedback;
 use common_sql::parse_result_scan_args;
 use common_sql::MetadataRef;
 use common_storages_result_cache::gen_result_cache_key;
@@ -82,14 +83,37 @@ impl SelectInterpreter {
 
     #[async_backtrace::framed]
     pub async fn build_pipeline(&self, physical_plan: PhysicalPlan) -> Result<PipelineBuildResult> {
-        build_query_pipeline(
+        // The query is profiled to record its cardinality feedback, which is not supported
+        // for distributed queries.
+        let fingerprint = self
+            .metadata
+            .read()
+            .plan_fingerprint()
+            .filter(|_| !physical_plan.is_distributed_plan())
+            .map(|fingerprint| fingerprint.to_string());
+        let mut build_res = build_query_pipeline(
             &self.ctx,
             &self.bind_context.columns,
             &physical_plan,
             self.ignore_result,
-            false,
+            fingerprint.is_some(),
         )
-        .await
+        .await?;
+
+        if let Some(fingerprint) = fingerprint {
+            let prof_span_set = build_res.prof_span_set.clone();
+            build_res.main_pipeline.set_on_finished(move |may_error| {
+                if may_error.is_none() {
+                    CardinalityFeedback::instance().record(
+                        &fingerprint,
+                        &physical_plan,
+                        &prof_span_set.lock().unwrap(),
+                    );
+                }
+                Ok(())
+            });
+        }
+        Ok(build_res)
     }
 
     /// Add pipelines for writing query result cache.
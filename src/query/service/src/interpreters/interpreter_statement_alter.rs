@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+use common_sql::optimizer::capture_plan_shape;
+use common_sql::plans::AlterStatementPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
+
+#[derive(Debug)]
+pub struct AlterStatementInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterStatementPlan,
+}
+
+impl AlterStatementInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterStatementPlan) -> Result<Self> {
+        Ok(AlterStatementInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterStatementInterpreter {
+    fn name(&self) -> &str {
+        "AlterStatementInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let user_mgr = UserApiProvider::instance();
+        if !plan.pin {
+            user_mgr
+                .drop_plan_baseline(&plan.tenant, &plan.baseline_id)
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // Capture the plan the query runs with now, which is the pinned one if it's still valid.
+        let mut planner = Planner::new(self.ctx.clone());
+        let (query_plan, _) = planner.plan_sql(&plan.query_text).await?;
+        let shape = match &query_plan {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => capture_plan_shape(s_expr, metadata),
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Expect a query plan, got {query_plan}"
+                )));
+            }
+        };
+        let baseline = PlanBaseline {
+            id: plan.baseline_id.clone(),
+            database: plan.database.clone(),
+            query_text: plan.query_text.clone(),
+            shape,
+            created_on: Utc::now(),
+        };
+        user_mgr
+            .upsert_plan_baseline(&plan.tenant, baseline)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
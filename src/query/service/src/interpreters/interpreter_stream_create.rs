@@ -0,0 +1,100 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::StreamTable;
+use common_storages_fuse::STREAM_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.table_database, &plan.table_name)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::Unimplemented(format!(
+                "Stream is only supported on FUSE tables, but {}.{} is of engine {}",
+                plan.table_database,
+                plan.table_name,
+                table.engine()
+            ))
+        })?;
+
+        // The stream starts from the current snapshot of the table.
+        let offset_snapshot = fuse_table.snapshot_loc().await?.unwrap_or_default();
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_STREAM_SOURCE_TABLE_ID.to_string(),
+            table.get_id().to_string(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_SOURCE_TABLE.to_string(),
+            format!("{}.{}", plan.table_database, plan.table_name),
+        );
+        options.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT.to_string(), offset_snapshot);
+
+        let req = CreateTableReq {
+            if_not_exists: plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: plan.tenant.clone(),
+                db_name: plan.database.clone(),
+                table_name: plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(StreamTable::schema_of(&fuse_table.schema())),
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                ..Default::default()
+            },
+        };
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
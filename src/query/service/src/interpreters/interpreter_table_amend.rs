@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataSchemaRef;
+use common_sql::plans::AmendTablePlan;
+use common_storages_fuse::table_functions::FuseAmend;
+use common_storages_fuse::FuseTable;
+use table_lock::TableLockHandlerWrapper;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Rolls the table back to the latest snapshot whose segments and blocks all exist.
+pub struct AmendTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AmendTablePlan,
+}
+
+impl AmendTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AmendTablePlan) -> Result<Self> {
+        Ok(AmendTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AmendTableInterpreter {
+    fn name(&self) -> &str {
+        "AmendTableInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let table_info = table.get_table_info().clone();
+
+        // Hold the table lock, no mutation may commit on top of the broken snapshot
+        // while the table is rolled back.
+        let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
+        let mut heartbeat = handler.try_lock(self.ctx.clone(), table_info).await?;
+
+        let res = async {
+            // refresh table.
+            let table = self
+                .ctx
+                .get_catalog(catalog_name)?
+                .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+                .await?;
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            FuseAmend::new(self.ctx.clone(), fuse_table, db_name.to_string())
+                .amend(true)
+                .await
+        }
+        .await;
+
+        heartbeat.shutdown().await?;
+        PipelineBuildResult::from_blocks(vec![res?])
+    }
+}
@@ -14,13 +14,29 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberScalar;
+use common_expression::ComputedExpr;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_sql::optimizer::DEFAULT_HISTOGRAM_BUCKETS;
 use common_sql::plans::AnalyzeTablePlan;
+use common_storages_fuse::operations::AnalyzedColumns;
+use common_storages_fuse::FuseTable;
+use futures::TryStreamExt;
+use storages_common_table_meta::meta::HistogramBucket;
 
+use crate::interpreters::common::quote_ident;
 use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+use crate::sql::Planner;
 
 pub struct AnalyzeTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -31,6 +47,99 @@ impl AnalyzeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: AnalyzeTablePlan) -> Result<Self> {
         Ok(AnalyzeTableInterpreter { ctx, plan })
     }
+
+    /// Scans the table to compute the NDV of the columns with HyperLogLog,
+    /// and the equi-height histograms of the numeric columns.
+    #[async_backtrace::framed]
+    async fn analyze_columns(&self, table: &FuseTable) -> Result<AnalyzedColumns> {
+        let plan = &self.plan;
+        let mut select_list = vec![];
+        // (column id, whether the histogram is collected)
+        let mut columns = vec![];
+        for field in table.schema().fields() {
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                continue;
+            }
+            let with_histogram = match field.data_type().remove_nullable() {
+                TableDataType::Number(_) => true,
+                TableDataType::Boolean
+                | TableDataType::String
+                | TableDataType::Date
+                | TableDataType::Timestamp
+                | TableDataType::Decimal(_) => false,
+                _ => continue,
+            };
+            let name = quote_ident(field.name());
+            select_list.push(format!("count({name})"));
+            select_list.push(format!("approx_count_distinct({name})"));
+            if with_histogram {
+                let levels = (1..=DEFAULT_HISTOGRAM_BUCKETS)
+                    .map(|i| (i as f64 / DEFAULT_HISTOGRAM_BUCKETS as f64).to_string())
+                    .collect::<Vec<_>>();
+                select_list.push(format!("quantile_disc({})({name})", levels.join(", ")));
+            }
+            columns.push((field.column_id(), with_histogram));
+        }
+        if columns.is_empty() {
+            return Ok(AnalyzedColumns::default());
+        }
+
+        let query = format!(
+            "SELECT {} FROM {}.{}.{}",
+            select_list.join(", "),
+            quote_ident(&plan.catalog),
+            quote_ident(&plan.database),
+            quote_ident(&plan.table)
+        );
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&query).await?;
+        let stream = if let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = plan
+        {
+            let interpreter = SelectInterpreter::try_create(
+                self.ctx.clone(),
+                *bind_context,
+                *s_expr,
+                metadata,
+                None,
+                false,
+            )?;
+            interpreter.execute(self.ctx.clone()).await
+        } else {
+            return Err(ErrorCode::Internal("analyze table build query error"));
+        }?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let block = match blocks.into_iter().find(|block| block.num_rows() > 0) {
+            Some(block) => block,
+            None => return Ok(AnalyzedColumns::default()),
+        };
+
+        let mut analyzed = AnalyzedColumns::default();
+        let mut offset = 0;
+        for (column_id, with_histogram) in columns {
+            let num_values = u64_at(&block, offset);
+            let ndv = u64_at(&block, offset + 1);
+            offset += 2;
+            analyzed.distinct_values.insert(column_id, ndv);
+            if with_histogram {
+                if let Some(ScalarRef::Array(upper_bounds)) =
+                    block.get_by_offset(offset).value.index(0)
+                {
+                    let upper_bounds = upper_bounds.iter().map(|v| v.to_owned()).collect();
+                    let histogram = equi_height_histogram(upper_bounds, num_values, ndv);
+                    if !histogram.is_empty() {
+                        analyzed.histograms.insert(column_id, histogram);
+                    }
+                }
+                offset += 1;
+            }
+        }
+        Ok(analyzed)
+    }
 }
 
 #[async_trait::async_trait]
@@ -47,7 +156,61 @@ impl Interpreter for AnalyzeTableInterpreter {
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
 
-        table.analyze(self.ctx.clone()).await?;
-        return Ok(PipelineBuildResult::create());
+        // Only fuse tables keep the statistics of columns.
+        let fuse_table = match FuseTable::try_from_table(table.as_ref()) {
+            Ok(fuse_table) => fuse_table,
+            Err(_) => {
+                table.analyze(self.ctx.clone()).await?;
+                return Ok(PipelineBuildResult::create());
+            }
+        };
+        if fuse_table.read_table_snapshot().await?.is_none() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let analyzed = self.analyze_columns(fuse_table).await?;
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        fuse_table.do_analyze(&ctx, Some(analyzed)).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+fn u64_at(block: &DataBlock, offset: usize) -> u64 {
+    match block.get_by_offset(offset).value.index(0) {
+        Some(ScalarRef::Number(NumberScalar::UInt64(v))) => v,
+        _ => 0,
+    }
+}
+
+/// Builds the histogram of `num_values` values from the upper bounds of
+/// the buckets, every bucket holds the same number of values.
+fn equi_height_histogram(
+    upper_bounds: Vec<Scalar>,
+    num_values: u64,
+    ndv: u64,
+) -> Vec<HistogramBucket> {
+    if upper_bounds.is_empty() || num_values == 0 {
+        return vec![];
+    }
+    let values_per_bucket = num_values as f64 / upper_bounds.len() as f64;
+    let mut buckets: Vec<HistogramBucket> = Vec::with_capacity(upper_bounds.len());
+    for upper_bound in upper_bounds {
+        match buckets.last_mut() {
+            // A value that spans several buckets is a frequent one, keep
+            // all of its occurrences in a single bucket.
+            Some(last) if last.upper_bound == upper_bound => {
+                last.num_values += values_per_bucket;
+            }
+            _ => buckets.push(HistogramBucket {
+                upper_bound,
+                num_values: values_per_bucket,
+                num_distinct: 0.0,
+            }),
+        }
+    }
+    let distinct_per_bucket = (ndv as f64 / buckets.len() as f64).max(1.0);
+    for bucket in buckets.iter_mut() {
+        bucket.num_distinct = distinct_per_bucket.min(bucket.num_values);
     }
+    buckets
 }
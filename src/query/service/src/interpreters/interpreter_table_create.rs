@@ -28,21 +28,36 @@ use common_sql::binder::INTERNAL_COLUMN_FACTORY;
 use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS;
+use common_storages_fuse::FUSE_OPT_KEY_DICTIONARY_COLUMNS;
+use common_storages_fuse::FUSE_OPT_KEY_INVERTED_INDEXES;
+use common_storages_fuse::FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use common_storages_fuse::FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN;
 use common_users::UserApiProvider;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_ENCODING;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_EXTERNAL_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PATTERN;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
@@ -239,6 +254,25 @@ impl CreateTableInterpreter {
             }
         }
 
+        if let Some(columns) = table_meta.options.get(FUSE_OPT_KEY_DICTIONARY_COLUMNS) {
+            FuseTable::parse_dictionary_columns(&table_meta.schema, columns)?;
+        }
+        if let Some(columns) = table_meta
+            .options
+            .get(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS)
+        {
+            FuseTable::parse_parquet_bloom_filter_columns(&table_meta.schema, columns)?;
+        }
+        if let Some(columns) = table_meta.options.get(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS) {
+            FuseTable::parse_bloom_index_columns(&table_meta.schema, columns)?;
+        }
+        if let Some(indexes) = table_meta.options.get(FUSE_OPT_KEY_INVERTED_INDEXES) {
+            FuseTable::parse_inverted_indexes(&table_meta.schema, indexes)?;
+        }
+        FuseTable::check_column_codecs(&table_meta.schema, &table_meta.options)?;
+        FuseTable::check_string_stats_prefix_len(&table_meta.options)?;
+        FuseTable::check_auto_recluster_depth(&table_meta.options)?;
+
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
@@ -265,10 +299,17 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH);
+    r.insert(FUSE_OPT_KEY_DICTIONARY_COLUMNS);
+    r.insert(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS);
+    r.insert(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(FUSE_OPT_KEY_INVERTED_INDEXES);
+    r.insert(FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN);
 
     r.insert(OPT_KEY_SNAPSHOT_LOCATION);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
+    r.insert(OPT_KEY_COLUMN_ENCODING);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
 
@@ -276,6 +317,15 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(OPT_KEY_EXTERNAL_LOCATION);
     r.insert(OPT_KEY_ENGINE);
 
+    r.insert(OPT_KEY_EXTERNAL_TABLE_LOCATION);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PATTERN);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS);
+
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL);
+
     r.insert("transient");
     r
 });
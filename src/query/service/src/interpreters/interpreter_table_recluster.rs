@@ -17,6 +17,7 @@ use std::time::SystemTime;
 
 use common_catalog::plan::PushDownInfo;
 use common_exception::Result;
+use common_storages_fuse::MAX_RECLUSTER_BLOCK_COUNT;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -75,7 +76,14 @@ impl Interpreter for ReclusterTableInterpreter {
             tracing::info!(status);
         }
         let mut times = 0;
+        // The blocks left to rewrite, each round rewrites at most MAX_RECLUSTER_BLOCK_COUNT blocks.
+        let mut remaining = plan.limit;
         loop {
+            if remaining == Some(0) {
+                break;
+            }
+            let limit = remaining.map(|remaining| remaining.min(MAX_RECLUSTER_BLOCK_COUNT));
+
             let table = self
                 .ctx
                 .get_catalog(&plan.catalog)?
@@ -84,11 +92,14 @@ impl Interpreter for ReclusterTableInterpreter {
 
             let mut pipeline = Pipeline::create();
             table
-                .recluster(ctx.clone(), &mut pipeline, extras.clone())
+                .recluster(ctx.clone(), &mut pipeline, extras.clone(), limit)
                 .await?;
             if pipeline.is_empty() {
                 break;
             };
+            remaining = remaining
+                .zip(limit)
+                .map(|(remaining, limit)| remaining - limit);
 
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
 
@@ -24,6 +24,8 @@ use common_expression::DataSchemaRef;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_sql::plans::ShowCreateTablePlan;
+use common_storages_stage::ExternalTable;
+use common_storages_stage::EXTERNAL_ENGINE;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_internal_opt_key;
@@ -101,12 +103,21 @@ impl Interpreter for ShowCreateTableInterpreter {
         let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
         if table.options().contains_key("TRANSIENT") {
             table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
+        } else if engine == EXTERNAL_ENGINE {
+            table_create_sql = format!("CREATE EXTERNAL TABLE `{}` (\n", name)
         }
 
+        // The partition columns of an external table are discovered again from the paths.
+        let n_columns = if engine == EXTERNAL_ENGINE {
+            n_fields - ExternalTable::partition_columns(table.get_table_info()).len()
+        } else {
+            n_fields
+        };
+
         // Append columns.
         {
             let mut columns = vec![];
-            for (idx, field) in schema.fields().iter().enumerate() {
+            for (idx, field) in schema.fields().iter().enumerate().take(n_columns) {
                 let default_expr = match field.default_expr() {
                     Some(expr) => {
                         format!(" DEFAULT {expr}")
@@ -153,30 +164,49 @@ impl Interpreter for ShowCreateTableInterpreter {
             table_create_sql.push_str(&columns_str);
         }
 
-        let table_engine = format!(") ENGINE={}", engine);
-        table_create_sql.push_str(table_engine.as_str());
-
         let table_info = table.get_table_info();
-        if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
-            table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
-        }
+        if engine == EXTERNAL_ENGINE {
+            // The options of an external table are the clauses of `CREATE EXTERNAL TABLE`.
+            table_create_sql.push_str(&format!(
+                ") LOCATION = '{}'",
+                ExternalTable::location(table_info)?
+            ));
+            if let Some(pattern) = ExternalTable::pattern(table_info) {
+                table_create_sql.push_str(&format!(" PATTERN = '{pattern}'"));
+            }
+            let file_format = ExternalTable::file_format(table_info)?;
+            if !file_format.is_empty() {
+                table_create_sql.push_str(" FILE_FORMAT = (");
+                for (k, v) in file_format.iter() {
+                    table_create_sql.push_str(&format!(" {k} = '{v}'"));
+                }
+                table_create_sql.push_str(" )");
+            }
+        } else {
+            let table_engine = format!(") ENGINE={}", engine);
+            table_create_sql.push_str(table_engine.as_str());
+
+            if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
+                table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
+            }
 
-        let settings = self.ctx.get_settings();
-        let hide_options_in_show_create_table = settings
-            .get_hide_options_in_show_create_table()
-            .unwrap_or(false);
-
-        if !hide_options_in_show_create_table {
-            table_create_sql.push_str({
-                let mut opts = table_info.options().iter().collect::<Vec<_>>();
-                opts.sort_by_key(|(k, _)| *k);
-                opts.iter()
-                    .filter(|(k, _)| !is_internal_opt_key(k))
-                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
-                    .collect::<Vec<_>>()
-                    .join("")
-                    .as_str()
-            });
+            let settings = self.ctx.get_settings();
+            let hide_options_in_show_create_table = settings
+                .get_hide_options_in_show_create_table()
+                .unwrap_or(false);
+
+            if !hide_options_in_show_create_table {
+                table_create_sql.push_str({
+                    let mut opts = table_info.options().iter().collect::<Vec<_>>();
+                    opts.sort_by_key(|(k, _)| *k);
+                    opts.iter()
+                        .filter(|(k, _)| !is_internal_opt_key(k))
+                        .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                        .collect::<Vec<_>>()
+                        .join("")
+                        .as_str()
+                });
+            }
         }
 
         let block = DataBlock::new(
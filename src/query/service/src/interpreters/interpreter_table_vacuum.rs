@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::FromData;
@@ -79,32 +80,52 @@ impl Interpreter for VacuumTableInterpreter {
         let ctx = self.ctx.clone();
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        // The freed bytes are measured by the storage used by the table before and after.
+        let dry_run = self.plan.option.dry_run.is_some();
+        let usage_before = if dry_run {
+            (0, 0)
+        } else {
+            fuse_table.stored_files_usage().await?
+        };
         let handler = get_vacuum_handler();
         let purge_files_opt = handler
             .do_vacuum(
                 fuse_table,
                 ctx,
                 retention_time,
-                if self.plan.option.dry_run.is_some() {
-                    Some(DRY_RUN_LIMIT)
-                } else {
-                    None
-                },
+                if dry_run { Some(DRY_RUN_LIMIT) } else { None },
             )
             .await?;
 
         match purge_files_opt {
-            None => return Ok(PipelineBuildResult::create()),
+            None => {
+                let usage_after = fuse_table.stored_files_usage().await?;
+                let removed_files = usage_before.0.saturating_sub(usage_after.0);
+                let freed_bytes = usage_before.1.saturating_sub(usage_after.1);
+                PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                    UInt64Type::from_data(vec![removed_files]),
+                    UInt64Type::from_data(vec![freed_bytes]),
+                ])])
+            }
             Some(purge_files) => {
                 let len = min(purge_files.len(), DRY_RUN_LIMIT);
                 let mut files: Vec<Vec<u8>> = Vec::with_capacity(len);
+                let mut sizes: Vec<u64> = Vec::with_capacity(len);
                 let purge_files = &purge_files[0..len];
+                let operator = fuse_table.get_operator();
                 for file in purge_files.iter() {
                     files.push(file.to_string().as_bytes().to_vec());
+                    // The file may have been removed by a concurrent vacuum.
+                    let size = match operator.stat(file).await {
+                        Ok(meta) => meta.content_length(),
+                        Err(_) => 0,
+                    };
+                    sizes.push(size);
                 }
 
                 PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
                     StringType::from_data(files),
+                    UInt64Type::from_data(sizes),
                 ])])
             }
         }
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::Task;
+use common_sql::plans::CreateTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::common::CronSchedule;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTaskPlan,
+}
+
+impl CreateTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTaskPlan) -> Result<Self> {
+        Ok(CreateTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTaskInterpreter {
+    fn name(&self) -> &str {
+        "CreateTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        if let Some(schedule) = &plan.schedule {
+            CronSchedule::parse(schedule)?;
+        }
+
+        let user_mgr = UserApiProvider::instance();
+        let tasks = user_mgr
+            .get_tasks(&plan.tenant)
+            .await?
+            .into_iter()
+            .map(|task| (task.name.clone(), task))
+            .collect::<HashMap<_, _>>();
+        // The predecessors must exist and must not run after the task itself.
+        let mut predecessors = plan.after.clone();
+        let mut visited = HashSet::new();
+        while let Some(name) = predecessors.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            if name == plan.task_name {
+                return Err(ErrorCode::InvalidTaskDependency(format!(
+                    "Task {} can not run after {}, they depend on each other",
+                    plan.task_name,
+                    plan.after.join(", ")
+                )));
+            }
+            match tasks.get(&name) {
+                Some(task) => predecessors.extend(task.after.iter().cloned()),
+                None if plan.after.contains(&name) => {
+                    return Err(ErrorCode::UnknownTask(format!("Unknown Task {}", name)));
+                }
+                None => {}
+            }
+        }
+
+        let task = Task {
+            name: plan.task_name.clone(),
+            schedule: plan.schedule.clone(),
+            after: plan.after.clone(),
+            database: plan.database.clone(),
+            query_text: plan.query_text.clone(),
+            suspended: false,
+            owner: self.ctx.get_current_user()?.identity().to_string(),
+            comment: plan.comment.clone(),
+            created_on: Utc::now(),
+        };
+        user_mgr
+            .add_task(&plan.tenant, task, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
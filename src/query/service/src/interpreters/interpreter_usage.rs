@@ -0,0 +1,134 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_catalog::plan::DataSourceInfo;
+use common_exception::Result;
+use common_storages_system::UsageCollector;
+use common_storages_system::UsageKey;
+use common_storages_system::UsageObjectType;
+use common_storages_system::UsageValue;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const MILLIS_PER_DAY: u128 = 24 * 3_600_000;
+
+/// Accounts the cost of a finished query to its user and to every table and stage it accessed.
+pub struct InterpreterUsage;
+
+impl InterpreterUsage {
+    pub fn record_finish(ctx: &QueryContext, now: SystemTime) -> Result<()> {
+        let tenant = ctx.get_tenant();
+        let user = ctx.get_current_user()?.name;
+
+        let finish_ms = now
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::new(0, 0))
+            .as_millis();
+        let usage_date = (finish_ms / MILLIS_PER_DAY) as i32;
+        let compute_ms = now
+            .duration_since(ctx.get_created_time())
+            .unwrap_or(Duration::new(0, 0))
+            .as_secs_f64()
+            * 1000.0;
+
+        // (object_type, database, name) -> (scan_io_bytes, written_io_bytes)
+        let mut objects: BTreeMap<(UsageObjectType, String, String), (u64, u64)> = BTreeMap::new();
+        let tables_names = ctx.get_tables_refs_names();
+        for table in ctx.get_accessed_tables() {
+            let metrics = match table.get_data_metrics() {
+                None => continue,
+                Some(metrics) => metrics,
+            };
+
+            let object = match table.get_data_source_info() {
+                DataSourceInfo::StageSource(info) => (
+                    UsageObjectType::Stage,
+                    "".to_string(),
+                    info.stage_info.stage_name,
+                ),
+                _ => match tables_names.get(&table.get_id()) {
+                    Some((database, name)) => {
+                        (UsageObjectType::Table, database.clone(), name.clone())
+                    }
+                    None => (
+                        UsageObjectType::Table,
+                        "".to_string(),
+                        table.name().to_string(),
+                    ),
+                },
+            };
+
+            let bytes = objects.entry(object).or_default();
+            bytes.0 += metrics.get_read_bytes() as u64;
+            bytes.1 += metrics.get_write_bytes() as u64;
+        }
+
+        let total_bytes: u64 = objects.values().map(|(read, write)| read + write).sum();
+        let collector = UsageCollector::instance();
+        let mut user_value = UsageValue {
+            queries: 1,
+            compute_ms,
+            ..Default::default()
+        };
+
+        for ((object_type, database, name), (scan_io_bytes, written_io_bytes)) in objects.iter() {
+            user_value.scan_io_bytes += scan_io_bytes;
+            user_value.written_io_bytes += written_io_bytes;
+
+            // Split the compute time of the query by the io bytes of each object,
+            // or evenly if the query did not touch the storage at all.
+            let share = match total_bytes {
+                0 => 1.0 / objects.len() as f64,
+                _ => (scan_io_bytes + written_io_bytes) as f64 / total_bytes as f64,
+            };
+
+            collector.record(
+                UsageKey {
+                    usage_date,
+                    tenant: tenant.clone(),
+                    user: user.clone(),
+                    object_type: *object_type,
+                    database: database.clone(),
+                    name: name.clone(),
+                },
+                UsageValue {
+                    queries: 1,
+                    scan_io_bytes: *scan_io_bytes,
+                    written_io_bytes: *written_io_bytes,
+                    compute_ms: compute_ms * share,
+                },
+            );
+        }
+
+        collector.record(
+            UsageKey {
+                usage_date,
+                tenant,
+                user: user.clone(),
+                object_type: UsageObjectType::User,
+                database: "".to_string(),
+                name: user,
+            },
+            user_value,
+        );
+
+        Ok(())
+    }
+}
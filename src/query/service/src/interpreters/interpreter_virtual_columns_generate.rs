@@ -76,14 +76,15 @@ impl Interpreter for GenerateVirtualColumnsInterpreter {
             .do_list_virtual_columns(catalog, list_virtual_columns_req)
             .await?;
 
-        if res.is_empty() {
-            return Ok(PipelineBuildResult::create());
-        }
-        let virtual_columns = res[0].virtual_columns.clone();
+        // the frequent paths are generated even if no virtual columns are declared
+        let virtual_columns = res
+            .first()
+            .map(|meta| meta.virtual_columns.clone())
+            .unwrap_or_default();
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
         let _ = handler
-            .do_generate_virtual_columns(fuse_table, self.ctx.clone(), virtual_columns)
+            .do_generate_virtual_columns(fuse_table, self.ctx.clone(), virtual_columns, None)
             .await?;
 
         Ok(PipelineBuildResult::create())
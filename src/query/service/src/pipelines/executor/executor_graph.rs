@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use common_base::runtime::TrackedFuture;
 use common_base::runtime::TrySpawn;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_exception::Result;
 use petgraph::dot::Config;
 use petgraph::dot::Dot;
@@ -46,19 +47,37 @@ use crate::pipelines::processors::UpdateTrigger;
 
 enum State {
     Idle,
+    /// Waiting for the upstream processors to push data.
+    NeedData,
+    /// Waiting for the downstream processors to consume the pushed data.
+    NeedConsume,
     // Preparing,
     Processing,
     Finished,
 }
 
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Idle => "Idle",
+            State::NeedData => "NeedData",
+            State::NeedConsume => "NeedConsume",
+            State::Processing => "Processing",
+            State::Finished => "Finished",
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        matches!(self, State::Idle | State::NeedData | State::NeedConsume)
+    }
+}
+
 struct Node {
     state: std::sync::Mutex<State>,
     processor: ProcessorPtr,
 
     updated_list: Arc<UpdateList>,
-    #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
-    #[allow(dead_code)]
     outputs_port: Vec<Arc<OutputPort>>,
 }
 
@@ -220,7 +239,7 @@ impl ExecutingGraph {
                 let node = &locker.graph[target_index];
                 let node_state = node.state.lock().unwrap();
 
-                if matches!(*node_state, State::Idle) {
+                if node_state.is_idle() {
                     state_guard_cache = Some(node_state);
                     need_schedule_nodes.push_back(target_index);
                 }
@@ -243,7 +262,8 @@ impl ExecutingGraph {
                 }
                 let processor_state = match event {
                     Event::Finished => State::Finished,
-                    Event::NeedData | Event::NeedConsume => State::Idle,
+                    Event::NeedData => State::NeedData,
+                    Event::NeedConsume => State::NeedConsume,
                     Event::Sync => {
                         schedule_queue.push_sync(node.processor.clone());
                         State::Processing
@@ -419,17 +439,47 @@ impl RunningGraph {
                 nodes_display.push(NodeDisplay {
                     id: self.0.graph[node_index].processor.id().index(),
                     name: self.0.graph[node_index].processor.name(),
-                    state: String::from(match *state {
-                        State::Idle => "Idle",
-                        State::Processing => "Processing",
-                        State::Finished => "Finished",
-                    }),
+                    state: String::from(state.name()),
                 });
             }
         }
 
         format!("{:?}", nodes_display)
     }
+
+    /// Take a snapshot of the states of the processors.
+    pub fn tasks_info(&self, query_id: &str) -> Vec<PipelineTaskInfo> {
+        let graph = &self.0.graph;
+        let mut tasks = Vec::with_capacity(graph.node_count());
+
+        for node_index in graph.node_indices() {
+            let node = &graph[node_index];
+            let state = node.state.lock().unwrap().name().to_string();
+            let neighbors = |direction| {
+                graph
+                    .neighbors_directed(node_index, direction)
+                    .map(|index| index.index())
+                    .collect::<Vec<_>>()
+            };
+
+            tasks.push(PipelineTaskInfo {
+                query_id: query_id.to_string(),
+                processor_id: node_index.index(),
+                processor_name: unsafe { node.processor.name() },
+                state,
+                input_rows: node.inputs_port.iter().map(|port| port.pulled_rows()).sum(),
+                output_rows: node
+                    .outputs_port
+                    .iter()
+                    .map(|port| port.pushed_rows())
+                    .sum(),
+                inputs: neighbors(Direction::Incoming),
+                outputs: neighbors(Direction::Outgoing),
+            });
+        }
+
+        tasks
+    }
 }
 
 impl Debug for Node {
@@ -23,6 +23,7 @@ use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::ThreadJoinHandle;
 use common_base::runtime::TrySpawn;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::future::select;
@@ -389,6 +390,10 @@ impl PipelineExecutor {
     pub fn format_graph_nodes(&self) -> String {
         self.graph.format_graph_nodes()
     }
+
+    pub fn tasks_info(&self, query_id: &str) -> Vec<PipelineTaskInfo> {
+        self.graph.tasks_info(query_id)
+    }
 }
 
 impl Drop for PipelineExecutor {
@@ -25,13 +25,16 @@ use common_expression::with_hash_method;
 use common_expression::with_mappedhash_method;
 use common_expression::with_number_mapped_type;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_expression::FunctionContext;
 use common_expression::HashMethodKind;
 use common_expression::RemoteExpr;
 use common_expression::SortColumnDescription;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::AggregateStateMergeAdaptor;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
@@ -41,6 +44,7 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
 use common_pipeline_transforms::processors::ProfileWrapper;
 use common_profile::ProfSpanSetRef;
@@ -50,6 +54,8 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::DistinctFinal;
+use common_sql::executor::DistinctPartial;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -57,46 +63,68 @@ use common_sql::executor::ExchangeSource;
 use common_sql::executor::Filter;
 use common_sql::executor::HashJoin;
 use common_sql::executor::Limit;
+use common_sql::executor::MergeInto;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
 use common_sql::executor::RangeJoin;
+use common_sql::executor::RecursiveCteScan;
+use common_sql::executor::RecursiveUnion;
 use common_sql::executor::RowFetch;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
+use common_sql::executor::SortMergeJoin;
 use common_sql::executor::TableScan;
 use common_sql::executor::UnionAll;
 use common_sql::executor::Window;
 use common_sql::plans::JoinType;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
-use common_storage::DataOperator;
 use common_storages_fuse::operations::build_row_fetcher_pipeline;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
-use petgraph::matrix_graph::Zero;
+use parking_lot::Mutex;
 
 use super::processors::transforms::FrameBound;
 use super::processors::transforms::WindowFunctionInfo;
 use super::processors::TransformExpandGroupingSets;
 use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
+use crate::api::ExchangeShuffleTransform;
+use crate::api::HashFlightScatter;
+use crate::api::ScatterTransform;
 use crate::interpreters::fill_missing_columns;
 use crate::pipelines::processors::transforms::build_partition_bucket;
+use crate::pipelines::processors::transforms::enable_aggregate_spilling;
+use crate::pipelines::processors::transforms::sort_spilling_memory_threshold;
+use crate::pipelines::processors::transforms::spilling_memory_threshold;
+use crate::pipelines::processors::transforms::support_sort_spilling;
+use crate::pipelines::processors::transforms::AdaptiveJoinBuild;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
+use crate::pipelines::processors::transforms::GroupDictionary;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RangeJoinState;
+use crate::pipelines::processors::transforms::RecursiveUnionSource;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
+use crate::pipelines::processors::transforms::TransformDecodeGroupDictionary;
+use crate::pipelines::processors::transforms::TransformDistinctFinal;
+use crate::pipelines::processors::transforms::TransformDistinctPartial;
+use crate::pipelines::processors::transforms::TransformEncodeGroupDictionary;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
+use crate::pipelines::processors::transforms::TransformMergeInto;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::TransformRangeJoinRight;
+use crate::pipelines::processors::transforms::TransformSortMergeJoin;
+use crate::pipelines::processors::transforms::TransformSortMergeRuns;
+use crate::pipelines::processors::transforms::TransformSortSpill;
 use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::WorkingTables;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::MarkJoinCompactor;
@@ -106,6 +134,7 @@ use crate::pipelines::processors::TransformHashJoinBuild;
 use crate::pipelines::processors::TransformHashJoinProbe;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformRuntimeFilter;
+use crate::pipelines::processors::TransformSortPartial;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -121,6 +150,11 @@ pub struct PipelineBuilder {
     pub join_state: Option<Arc<JoinHashTable>>,
     // record the index of join build side pipeline in `pipelines`
     pub index: Option<usize>,
+    // Used in recursive CTE, the working tables read by `RecursiveCteScan`
+    pub working_tables: WorkingTables,
+    // Used in aggregation, the dictionaries of the group by columns shared by
+    // the partial and the final aggregation
+    group_dictionaries: Vec<(IndexType, Arc<GroupDictionary>)>,
 
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
@@ -142,6 +176,8 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            working_tables: WorkingTables::new(),
+            group_dictionaries: vec![],
         }
     }
 
@@ -173,6 +209,8 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateExpand(aggregate) => self.build_aggregate_expand(aggregate),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
+            PhysicalPlan::DistinctPartial(distinct) => self.build_distinct_partial(distinct),
+            PhysicalPlan::DistinctFinal(distinct) => self.build_distinct_final(distinct),
             PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
@@ -181,9 +219,12 @@ impl PipelineBuilder {
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
             PhysicalPlan::UnionAll(union_all) => self.build_union_all(union_all),
+            PhysicalPlan::RecursiveUnion(union) => self.build_recursive_union(union),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::DistributedInsertSelect(insert_select) => {
                 self.build_distributed_insert_select(insert_select)
             }
+            PhysicalPlan::MergeInto(merge_into) => self.build_merge_into(merge_into),
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
@@ -192,6 +233,7 @@ impl PipelineBuilder {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
             PhysicalPlan::RangeJoin(range_join) => self.build_range_join(range_join),
+            PhysicalPlan::SortMergeJoin(join) => self.build_sort_merge_join(join),
         }
     }
 
@@ -230,11 +272,12 @@ impl PipelineBuilder {
         state: Arc<RangeJoinState>,
     ) -> Result<()> {
         let right_side_context = QueryContext::create_from(self.ctx.clone());
-        let right_side_builder = PipelineBuilder::create(
+        let mut right_side_builder = PipelineBuilder::create(
             right_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        right_side_builder.working_tables = self.working_tables.clone();
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
             let transform = Sinker::<TransformRangeJoinRight>::create(
@@ -257,6 +300,72 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    // Both sides are merged in a single processor, each side must stay in one sorted stream.
+    fn build_sort_merge_join(&mut self, join: &SortMergeJoin) -> Result<()> {
+        let right_ctx = QueryContext::create_from(self.ctx.clone());
+        let mut right_builder =
+            PipelineBuilder::create(right_ctx, self.enable_profiling, self.prof_span_set.clone());
+        right_builder.working_tables = self.working_tables.clone();
+        let mut right_res = right_builder.finalize(&join.right)?;
+        right_res.main_pipeline.resize(1)?;
+
+        let (tx, rx) = async_channel::unbounded();
+        right_res.main_pipeline.add_sink(|input| {
+            let transform = UnionReceiveSink::create(Some(tx.clone()), input);
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+        self.pipelines.push(right_res.main_pipeline);
+        self.pipelines
+            .extend(right_res.sources_pipelines.into_iter());
+
+        self.build_pipeline(&join.left)?;
+        self.main_pipeline.resize(1)?;
+
+        let field_types = |schema: DataSchemaRef| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| field.data_type().clone())
+                .collect::<Vec<_>>()
+        };
+        let left_types = field_types(join.left.output_schema()?);
+        let right_types = field_types(join.right.output_schema()?);
+        let func_ctx = self.ctx.get_function_context()?;
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformSortMergeJoin::try_create(
+                input,
+                output,
+                rx.clone(),
+                &join.left_keys,
+                &join.right_keys,
+                &join.non_equi_conditions,
+                join.join_type.clone(),
+                left_types.clone(),
+                right_types.clone(),
+                func_ctx.clone(),
+                max_block_size,
+            )?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         let state = self.build_join_state(join)?;
         self.expand_build_side_pipeline(&join.build, join, state.clone())?;
@@ -270,6 +379,7 @@ impl PipelineBuilder {
             join.build.output_schema()?,
             join.probe.output_schema()?,
             HashJoinDesc::create(join)?,
+            AdaptiveJoinBuild::try_create(&self.ctx, join)?,
         )
     }
 
@@ -280,11 +390,12 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.working_tables = self.working_tables.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -599,12 +710,31 @@ impl PipelineBuilder {
     }
 
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
+        // Take the dictionaries before building the input, which may have its own aggregation.
+        let group_dictionaries = std::mem::take(&mut self.group_dictionaries);
         self.build_pipeline(&aggregate.input)?;
 
+        let input_schema = aggregate.input.output_schema()?;
+        if !group_dictionaries.is_empty() {
+            let columns = group_dictionaries
+                .iter()
+                .map(|(index, dictionary)| {
+                    Ok((
+                        input_schema.index_of(&index.to_string())?,
+                        dictionary.clone(),
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            self.main_pipeline.add_transform(|input, output| {
+                TransformEncodeGroupDictionary::try_create(input, output, columns.clone())
+            })?;
+        }
+
         let params = Self::build_aggregator_params(
-            aggregate.input.output_schema()?,
+            Self::group_dictionary_schema(&input_schema, &group_dictionaries),
             &aggregate.group_by,
             &aggregate.agg_funcs,
+            aggregate.from_agg_index,
             None,
         )?;
 
@@ -667,14 +797,9 @@ impl PipelineBuilder {
         })?;
 
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
-        if self.ctx.get_cluster().is_empty()
-            && !self
-                .ctx
-                .get_settings()
-                .get_spilling_bytes_threshold_per_proc()?
-                .is_zero()
+        if self.ctx.get_cluster().is_empty() && enable_aggregate_spilling(&self.ctx.get_settings())?
         {
-            let operator = DataOperator::instance().operator();
+            let operator = self.ctx.get_spill_operator();
             let location_prefix = format!("_aggregate_spill/{}", self.ctx.get_tenant());
             self.main_pipeline.add_transform(|input, output| {
                 let transform = match params.aggregate_functions.is_empty() {
@@ -735,10 +860,21 @@ impl PipelineBuilder {
     }
 
     fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
+        let group_dictionaries = aggregate
+            .dictionaries
+            .iter()
+            .map(|dictionary| {
+                Ok((
+                    dictionary.column,
+                    GroupDictionary::create(&dictionary.values)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
         let params = Self::build_aggregator_params(
-            aggregate.before_group_by_schema.clone(),
+            Self::group_dictionary_schema(&aggregate.before_group_by_schema, &group_dictionaries),
             &aggregate.group_by,
             &aggregate.agg_funcs,
+            false,
             aggregate.limit,
         )?;
 
@@ -770,6 +906,7 @@ impl PipelineBuilder {
 
         let tenant = self.ctx.get_tenant();
         let old_inject = self.exchange_injector.clone();
+        self.group_dictionaries = group_dictionaries.clone();
 
         match params.aggregate_functions.is_empty() {
             true => with_hash_method!(|T| match method {
@@ -815,13 +952,156 @@ impl PipelineBuilder {
                     )
                 }
             }),
+        }?;
+
+        if !group_dictionaries.is_empty() {
+            // The group by columns follow the aggregate functions in the output.
+            let columns = group_dictionaries
+                .into_iter()
+                .map(|(index, dictionary)| {
+                    let position = aggregate
+                        .group_by
+                        .iter()
+                        .position(|column| *column == index)
+                        .ok_or_else(|| {
+                            ErrorCode::Internal(format!(
+                                "group by dictionary column {index} is not grouped by"
+                            ))
+                        })?;
+                    Ok((aggregate.agg_funcs.len() + position, dictionary))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            self.main_pipeline.add_transform(|input, output| {
+                TransformDecodeGroupDictionary::try_create(input, output, columns.clone())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn build_distinct_partial(&mut self, distinct: &DistinctPartial) -> Result<()> {
+        self.build_pipeline(&distinct.input)?;
+
+        let input_schema = distinct.input.output_schema()?;
+        let columns = distinct
+            .columns
+            .iter()
+            .map(|index| input_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformDistinctPartial::create(input, output, columns.clone());
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    distinct.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    // Repartition the rows by hash -> deduplicate each partition while streaming.
+    fn build_distinct_final(&mut self, distinct: &DistinctFinal) -> Result<()> {
+        self.build_pipeline(&distinct.input)?;
+
+        // The rows from other nodes are repartitioned by the exchange, the rows of the
+        // pipelines are repartitioned again for the dedup in each pipeline.
+        let output_len = self.main_pipeline.output_len();
+        if output_len > 1 {
+            let schema = distinct.output_schema()?;
+            let keys = schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(id, field)| RemoteExpr::ColumnRef {
+                    span: None,
+                    id,
+                    data_type: field.data_type().clone(),
+                    display_name: field.name().clone(),
+                })
+                .collect();
+            let scatter = Arc::new(HashFlightScatter::try_create(
+                self.ctx.get_function_context()?,
+                keys,
+                output_len,
+            )?);
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(ScatterTransform::create(input, output, scatter.clone()))
+            })?;
+
+            let transform = ExchangeShuffleTransform::create(output_len, output_len);
+            let inputs = transform.get_inputs();
+            let outputs = transform.get_outputs();
+            self.main_pipeline
+                .add_pipe(Pipe::create(output_len, output_len, vec![
+                    PipeItem::create(ProcessorPtr::create(Box::new(transform)), inputs, outputs),
+                ]));
+        }
+
+        let settings = self.ctx.get_settings();
+        let block_size = settings.get_max_block_size()? as usize;
+        let memory_threshold = spilling_memory_threshold(&settings)?;
+        let operator = self.ctx.get_spill_operator();
+        let location_prefix = format!("_distinct_spill/{}", self.ctx.get_tenant());
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformDistinctFinal::create(
+                input,
+                output,
+                distinct.limit,
+                block_size,
+                operator.clone(),
+                location_prefix.clone(),
+                memory_threshold,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    distinct.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    /// The schema of the input of the aggregation, with the group by columns
+    /// having dictionaries replaced by their codes.
+    fn group_dictionary_schema(
+        schema: &DataSchemaRef,
+        group_dictionaries: &[(IndexType, Arc<GroupDictionary>)],
+    ) -> DataSchemaRef {
+        if group_dictionaries.is_empty() {
+            return schema.clone();
         }
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let encoded = group_dictionaries
+                    .iter()
+                    .any(|(index, _)| field.name() == &index.to_string());
+                match encoded {
+                    true => {
+                        DataField::new(field.name(), GroupDictionary::code_type(field.data_type()))
+                    }
+                    false => field.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        DataSchemaRefExt::create(fields)
     }
 
     pub fn build_aggregator_params(
         input_schema: DataSchemaRef,
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
+        merge_states: bool,
         limit: Option<usize>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
@@ -839,11 +1119,16 @@ impl PipelineBuilder {
             .iter()
             .map(|agg_func| {
                 agg_args.push(agg_func.args.clone());
-                AggregateFunctionFactory::instance().get(
+                let func = AggregateFunctionFactory::instance().get(
                     agg_func.sig.name.as_str(),
                     agg_func.sig.params.clone(),
                     agg_func.sig.args.clone(),
-                )
+                )?;
+                match merge_states {
+                    // The arguments are the serialized states, e.g. from an aggregating index.
+                    true => AggregateStateMergeAdaptor::create(func),
+                    false => Ok(func),
+                }
             })
             .collect::<Result<_>>()?;
 
@@ -921,6 +1206,7 @@ impl PipelineBuilder {
                     partition_by.clone(),
                     order_by.clone(),
                     (start_bound, end_bound),
+                    window.window_frame.exclusion,
                 )?) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
@@ -942,6 +1228,7 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
+                                    window.window_frame.exclusion,
                                 )?,
                             )
                                 as Box<dyn Processor>));
@@ -961,6 +1248,7 @@ impl PipelineBuilder {
                     partition_by.clone(),
                     order_by.clone(),
                     (start_bound, end_bound),
+                    window.window_frame.exclusion,
                 )?) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
@@ -1018,6 +1306,21 @@ impl PipelineBuilder {
             None
         };
 
+        // A sort with limit only keeps `limit` rows in each processor, it never needs to spill.
+        let memory_threshold = sort_spilling_memory_threshold(&self.ctx.get_settings())?;
+        if limit.is_none()
+            && memory_threshold != usize::MAX
+            && support_sort_spilling(&input_schema, &sort_desc)
+        {
+            return self.build_external_sort_pipeline(
+                input_schema,
+                sort_desc,
+                plan_id,
+                block_size,
+                memory_threshold,
+            );
+        }
+
         build_full_sort_pipeline(
             &mut self.main_pipeline,
             input_schema,
@@ -1029,6 +1332,75 @@ impl PipelineBuilder {
         )
     }
 
+    // Partial sort -> spill the sorted runs in each pipeline -> k-way merge of all the runs.
+    fn build_external_sort_pipeline(
+        &mut self,
+        input_schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        plan_id: u32,
+        block_size: usize,
+        memory_threshold: usize,
+    ) -> Result<()> {
+        self.main_pipeline.add_transform(|input, output| {
+            let transform =
+                TransformSortPartial::try_create(input, output, None, sort_desc.clone())?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        let operator = self.ctx.get_spill_operator();
+        let location_prefix = format!("_sort_spill/{}", self.ctx.get_tenant());
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformSortSpill::create(
+                input,
+                output,
+                input_schema.clone(),
+                sort_desc.clone(),
+                block_size,
+                operator.clone(),
+                location_prefix.clone(),
+                memory_threshold,
+            );
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformSortMergeRuns::try_create(
+                input,
+                output,
+                input_schema.clone(),
+                sort_desc.clone(),
+                block_size,
+                operator.clone(),
+            )?;
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     fn build_limit(&mut self, limit: &Limit) -> Result<()> {
         self.build_pipeline(&limit.input)?;
 
@@ -1135,8 +1507,9 @@ impl PipelineBuilder {
         union_plan: &UnionAll,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.working_tables = self.working_tables.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -1190,6 +1563,36 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub fn build_recursive_union(&mut self, union: &RecursiveUnion) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveUnionSource::create(
+                    self.ctx.clone(),
+                    output,
+                    union.clone(),
+                    self.enable_profiling,
+                    self.prof_span_set.clone(),
+                    self.working_tables.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    pub fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let working_table = self.working_tables.get(&scan.cte_idx).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Working table of recursive CTE {} is not found",
+                scan.cte_name
+            ))
+        })?;
+        let blocks = Arc::new(Mutex::new(working_table.iter().cloned().collect()));
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
@@ -1244,6 +1647,45 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// Applies the `WHEN [NOT] MATCHED` clauses to the rows of the join, the outputs are
+    /// connected to the target table by `Table::merge_into` afterwards.
+    pub fn build_merge_into(&mut self, merge_into: &MergeInto) -> Result<()> {
+        self.build_pipeline(&merge_into.input)?;
+
+        // resize input to 1, the two outputs of the transform are the two inputs
+        // expected by the target table.
+        self.main_pipeline.resize(1)?;
+
+        let compile = |expr: &RemoteExpr| expr.as_expr(&BUILTIN_FUNCTIONS);
+        let matched: Vec<_> = merge_into
+            .matched
+            .iter()
+            .map(|(condition, update)| {
+                let values = update
+                    .as_ref()
+                    .map(|values| values.iter().map(compile).collect::<Vec<_>>());
+                (condition.as_ref().map(compile), values)
+            })
+            .collect();
+        let unmatched: Vec<_> = merge_into
+            .unmatched
+            .iter()
+            .map(|(condition, values)| {
+                let values = values.iter().map(compile).collect::<Vec<_>>();
+                (condition.as_ref().map(compile), values)
+            })
+            .collect();
+
+        let transform = TransformMergeInto::create(
+            self.ctx.get_function_context()?,
+            merge_into.row_id_offset,
+            matched,
+            unmatched,
+        );
+        self.main_pipeline.add_pipe(transform.into_pipe());
+        Ok(())
+    }
+
     pub fn build_runtime_filter_source(
         &mut self,
         runtime_filter_source: &RuntimeFilterSource,
@@ -26,7 +26,6 @@ use common_hashtable::HashtableEntryRefLike;
 use common_hashtable::HashtableLike;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
-use common_storage::DataOperator;
 use strength_reduce::StrengthReducedU64;
 
 use crate::api::DataExchange;
@@ -38,6 +37,7 @@ use crate::api::ShuffleExchangeParams;
 use crate::api::TransformExchangeDeserializer;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::HashTablePayload;
+use crate::pipelines::processors::transforms::aggregator::enable_aggregate_spilling;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterAggregateSerializer;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::serde::TransformScatterGroupBySerializer;
@@ -246,13 +246,8 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
 
-        if self
-            .ctx
-            .get_settings()
-            .get_spilling_bytes_threshold_per_proc()?
-            != 0
-        {
-            let operator = DataOperator::instance().operator();
+        if enable_aggregate_spilling(&self.ctx.get_settings())? {
+            let operator = self.ctx.get_spill_operator();
             let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
             pipeline.add_transform(|input, output| {
@@ -298,7 +293,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
     ) -> Result<()> {
         let method = &self.method;
         let params = self.aggregator_params.clone();
-        let operator = DataOperator::instance().operator();
+        let operator = self.ctx.get_spill_operator();
         let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
         pipeline.add_transform(|input, output| {
@@ -20,6 +20,7 @@ mod serde;
 mod transform_aggregate_expand;
 mod transform_aggregate_final;
 mod transform_aggregate_partial;
+mod transform_dictionary;
 mod transform_group_by_final;
 mod transform_group_by_partial;
 mod transform_partition_bucket;
@@ -33,6 +34,9 @@ pub use aggregator_params::AggregatorParams;
 pub use transform_aggregate_expand::TransformExpandGroupingSets;
 pub use transform_aggregate_final::TransformFinalAggregate;
 pub use transform_aggregate_partial::TransformPartialAggregate;
+pub use transform_dictionary::GroupDictionary;
+pub use transform_dictionary::TransformDecodeGroupDictionary;
+pub use transform_dictionary::TransformEncodeGroupDictionary;
 pub use transform_group_by_final::TransformFinalGroupBy;
 pub use transform_group_by_partial::TransformPartialGroupBy;
 pub use transform_partition_bucket::build_partition_bucket;
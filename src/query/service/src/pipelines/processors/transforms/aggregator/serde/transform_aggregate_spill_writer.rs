@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::ProfCounters;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 use tracing::info;
@@ -203,6 +204,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     payload: HashTablePayload<Method, usize>,
 ) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
     let (bucket, total_size, data) = serialize_spill_file(method, params, payload)?;
+    ProfCounters::record_spill_bytes(total_size);
 
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
@@ -18,6 +18,7 @@ use std::vec;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::Column;
 use common_expression::DataBlock;
 use common_functions::aggregates::StateAddr;
@@ -32,7 +33,11 @@ use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::exceeds_memory_threshold;
+use crate::pipelines::processors::transforms::aggregator::spilling_memory_threshold;
+use crate::pipelines::processors::transforms::group_by::Area;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
+use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
 use crate::pipelines::processors::transforms::HashTableCell;
@@ -47,6 +52,16 @@ enum HashTable<Method: HashMethodBounds> {
     PartitionedHashTable(HashTableCell<PartitionedHashMethod<Method>, usize>),
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn len(&self) -> usize {
+        match self {
+            HashTable::MovedOut => unreachable!(),
+            HashTable::HashTable(cell) => cell.len(),
+            HashTable::PartitionedHashTable(cell) => cell.len(),
+        }
+    }
+}
+
 impl<Method: HashMethodBounds> Default for HashTable<Method> {
     fn default() -> Self {
         Self::MovedOut
@@ -56,6 +71,9 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
 struct AggregateSettings {
     convert_threshold: usize,
     spilling_bytes_threshold_per_proc: usize,
+    spilling_memory_threshold: usize,
+    abandon_min_rows: usize,
+    abandon_ratio: usize,
 }
 
 impl TryFrom<Arc<QueryContext>> for AggregateSettings {
@@ -65,6 +83,11 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
         let settings = ctx.get_settings();
         let convert_threshold = settings.get_group_by_two_level_threshold()? as usize;
         let value = settings.get_spilling_bytes_threshold_per_proc()?;
+        // The exchange of cluster mode only accepts hash tables, never abandon it.
+        let abandon_min_rows = match ctx.get_cluster().is_empty() {
+            true => settings.get_partial_aggregation_abandon_min_rows()? as usize,
+            false => 0,
+        };
 
         Ok(AggregateSettings {
             convert_threshold,
@@ -72,6 +95,9 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
                 true => usize::MAX,
                 false => value,
             },
+            spilling_memory_threshold: spilling_memory_threshold(&settings)?,
+            abandon_min_rows,
+            abandon_ratio: settings.get_partial_aggregation_abandon_ratio()? as usize,
         })
     }
 }
@@ -81,6 +107,10 @@ pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     method: Method,
     settings: AggregateSettings,
     hash_table: HashTable<Method>,
+    processed_rows: usize,
+    // The keys are too distinct for the hash table to reduce rows, pass the
+    // remaining rows through to the final aggregation without hashing them.
+    pass_through: bool,
 
     params: Arc<AggregatorParams>,
 }
@@ -113,6 +143,8 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                 params,
                 hash_table,
                 settings: AggregateSettings::try_from(ctx)?,
+                processed_rows: 0,
+                pass_through: false,
             },
         ))
     }
@@ -171,6 +203,66 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
         Ok(())
     }
 
+    // Aggregate every row into its own states, and serialize them with the keys.
+    fn pass_through_one_block(&self, block: DataBlock) -> Result<DataBlock> {
+        let block = block.convert_to_full();
+        let rows_num = block.num_rows();
+
+        let group_columns = self
+            .params
+            .group_columns
+            .iter()
+            .map(|&index| block.get_by_offset(index))
+            .map(|c| (c.value.as_column().unwrap().clone(), c.data_type.clone()))
+            .collect::<Vec<_>>();
+        let state = self.method.build_keys_state(&group_columns, rows_num)?;
+
+        let mut arena = Area::create();
+        let places = (0..rows_num)
+            .map(|_| self.params.alloc_layout(&mut arena))
+            .collect::<Vec<_>>();
+
+        let funcs = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
+        let mut state_builders = (0..funcs.len())
+            .map(|_| StringColumnBuilder::with_capacity(rows_num, rows_num * 4))
+            .collect::<Vec<_>>();
+
+        let res = Self::execute(&self.params, &block, &places).and_then(|_| {
+            for place in places.iter() {
+                for (idx, func) in funcs.iter().enumerate() {
+                    let arg_place = place.next(offsets_aggregate_states[idx]);
+                    func.serialize(arg_place, &mut state_builders[idx].data)?;
+                    state_builders[idx].commit_row();
+                }
+            }
+            Ok(())
+        });
+
+        for (idx, func) in funcs.iter().enumerate() {
+            if func.need_manual_drop_state() {
+                for place in places.iter() {
+                    unsafe { func.drop_state(place.next(offsets_aggregate_states[idx])) }
+                }
+            }
+        }
+        res?;
+
+        let value_size = group_columns.iter().map(|(c, _)| c.memory_size()).sum();
+        let mut keys_builder = self.method.keys_column_builder(rows_num, value_size);
+        for key in self.method.build_keys_iter(&state)? {
+            keys_builder.append_value(key);
+        }
+
+        let mut columns = Vec::with_capacity(state_builders.len() + 1);
+        for builder in state_builders.into_iter() {
+            columns.push(Column::String(builder.build()));
+        }
+
+        columns.push(keys_builder.finish());
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
     fn execute_one_block(&mut self, block: DataBlock) -> Result<()> {
         let block = block.convert_to_full();
 
@@ -229,12 +321,35 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
     const NAME: &'static str = "TransformPartialAggregate";
 
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        if self.pass_through {
+            let data_block = self.pass_through_one_block(block)?;
+            let meta = AggregateMeta::<Method, usize>::create_serialized(-1, data_block);
+            return Ok(vec![DataBlock::empty_with_meta(meta)]);
+        }
+
+        self.processed_rows += block.num_rows();
         self.execute_one_block(block)?;
 
+        // Abandon the hash table if it doesn't reduce rows. It's kept as is and flushed
+        // on finish, so that the passed through blocks are ahead of the partitioned ones.
+        if self.settings.abandon_min_rows != 0
+            && self.processed_rows >= self.settings.abandon_min_rows
+            && self.hash_table.len() * 100 >= self.processed_rows * self.settings.abandon_ratio
+        {
+            self.pass_through = true;
+            return Ok(vec![]);
+        }
+
         #[allow(clippy::collapsible_if)]
         if Method::SUPPORT_PARTITIONED {
+            // Spill the partitions once the process is short of memory, even if this
+            // aggregator is still under its own threshold.
+            let memory_pressure = self.hash_table.len() != 0
+                && exceeds_memory_threshold(self.settings.spilling_memory_threshold);
+
             if matches!(&self.hash_table, HashTable::HashTable(cell)
-                if cell.len() >= self.settings.convert_threshold ||
+                if memory_pressure ||
+                    cell.len() >= self.settings.convert_threshold ||
                     cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc
             ) {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
@@ -244,7 +359,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                 }
             }
 
-            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
+            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if memory_pressure || cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
             {
                 if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table) {
                     let _dropper = v._dropper.clone();
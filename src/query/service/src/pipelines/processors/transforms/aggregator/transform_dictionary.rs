@@ -0,0 +1,238 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Value;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// The codes of the values of a string group by column, shared by the transforms
+/// encoding and decoding the column in a query.
+///
+/// It is seeded by the dictionary kept by the table, the values missing in it
+/// are given new codes when they are met.
+pub struct GroupDictionary {
+    inner: RwLock<GroupDictionaryInner>,
+}
+
+struct GroupDictionaryInner {
+    codes: HashMap<Vec<u8>, u32>,
+    values: Vec<Vec<u8>>,
+}
+
+impl GroupDictionaryInner {
+    fn code_of(&mut self, value: &[u8]) -> Result<u32> {
+        if let Some(code) = self.codes.get(value) {
+            return Ok(*code);
+        }
+        let code = u32::try_from(self.values.len()).map_err(|_| {
+            ErrorCode::Internal("Too many distinct values in the group by dictionary")
+        })?;
+        self.codes.insert(value.to_vec(), code);
+        self.values.push(value.to_vec());
+        Ok(code)
+    }
+}
+
+impl GroupDictionary {
+    pub fn create(values: &[Vec<u8>]) -> Result<Arc<GroupDictionary>> {
+        let mut inner = GroupDictionaryInner {
+            codes: HashMap::with_capacity(values.len()),
+            values: Vec::with_capacity(values.len()),
+        };
+        for value in values {
+            inner.code_of(value)?;
+        }
+        Ok(Arc::new(GroupDictionary {
+            inner: RwLock::new(inner),
+        }))
+    }
+
+    /// The type of the codes of a column of `data_type`.
+    pub fn code_type(data_type: &DataType) -> DataType {
+        let code_type = DataType::Number(NumberDataType::UInt32);
+        match data_type.is_nullable() {
+            true => code_type.wrap_nullable(),
+            false => code_type,
+        }
+    }
+
+    fn encode(&self, column: &Column) -> Result<Column> {
+        let (strings, validity) = match column {
+            Column::Nullable(box nullable) => (&nullable.column, Some(nullable.validity.clone())),
+            column => (column, None),
+        };
+        let strings = StringType::try_downcast_column(strings).ok_or_else(|| {
+            ErrorCode::Internal("The group by dictionary column must be a string column")
+        })?;
+
+        let mut codes = Vec::with_capacity(strings.len());
+        let mut missing = vec![];
+        {
+            let inner = self.inner.read();
+            for (row, value) in strings.iter().enumerate() {
+                match inner.codes.get(value) {
+                    Some(code) => codes.push(*code),
+                    None => {
+                        codes.push(0);
+                        missing.push((row, value));
+                    }
+                }
+            }
+        }
+        if !missing.is_empty() {
+            let mut inner = self.inner.write();
+            for (row, value) in missing {
+                codes[row] = inner.code_of(value)?;
+            }
+        }
+
+        let column = UInt32Type::upcast_column(codes.into());
+        Ok(match validity {
+            Some(validity) => Column::Nullable(Box::new(NullableColumn { column, validity })),
+            None => column,
+        })
+    }
+
+    fn decode(&self, column: &Column) -> Result<Column> {
+        let (codes, validity) = match column {
+            Column::Nullable(box nullable) => (&nullable.column, Some(nullable.validity.clone())),
+            column => (column, None),
+        };
+        let codes = UInt32Type::try_downcast_column(codes).ok_or_else(|| {
+            ErrorCode::Internal("The group by dictionary codes must be a UInt32 column")
+        })?;
+
+        let inner = self.inner.read();
+        let mut builder = StringColumnBuilder::with_capacity(codes.len(), 0);
+        for code in codes.iter() {
+            // The codes of the NULLs may not be in the dictionary.
+            if let Some(value) = inner.values.get(*code as usize) {
+                builder.put_slice(value);
+            }
+            builder.commit_row();
+        }
+
+        let column = StringType::upcast_column(builder.build());
+        Ok(match validity {
+            Some(validity) => Column::Nullable(Box::new(NullableColumn { column, validity })),
+            None => column,
+        })
+    }
+}
+
+/// Replace the values of the group by columns with their codes in the dictionaries
+/// before the partial aggregation.
+pub struct TransformEncodeGroupDictionary {
+    columns: Vec<(usize, Arc<GroupDictionary>)>,
+}
+
+/// Replace the codes of the group by columns with their values in the dictionaries
+/// after the final aggregation.
+pub struct TransformDecodeGroupDictionary {
+    columns: Vec<(usize, Arc<GroupDictionary>)>,
+}
+
+impl TransformEncodeGroupDictionary
+where Self: Transform
+{
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        columns: Vec<(usize, Arc<GroupDictionary>)>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input_port,
+            output_port,
+            Self { columns },
+        )))
+    }
+}
+
+impl TransformDecodeGroupDictionary
+where Self: Transform
+{
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        columns: Vec<(usize, Arc<GroupDictionary>)>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input_port,
+            output_port,
+            Self { columns },
+        )))
+    }
+}
+
+impl Transform for TransformEncodeGroupDictionary {
+    const NAME: &'static str = "EncodeGroupDictionaryTransform";
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut columns = data_block.columns().to_vec();
+        for (offset, dictionary) in &self.columns {
+            let entry = &columns[*offset];
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            columns[*offset] = BlockEntry::new(
+                GroupDictionary::code_type(&entry.data_type),
+                Value::Column(dictionary.encode(&column)?),
+            );
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+impl Transform for TransformDecodeGroupDictionary {
+    const NAME: &'static str = "DecodeGroupDictionaryTransform";
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut columns = data_block.columns().to_vec();
+        for (offset, dictionary) in &self.columns {
+            let entry = &columns[*offset];
+            let data_type = match entry.data_type.is_nullable() {
+                true => DataType::String.wrap_nullable(),
+                false => DataType::String,
+            };
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            columns[*offset] =
+                BlockEntry::new(data_type, Value::Column(dictionary.decode(&column)?));
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
@@ -30,7 +30,10 @@ use common_sql::IndexType;
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::GroupByHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::HashTableCell;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::exceeds_memory_threshold;
+use crate::pipelines::processors::transforms::aggregator::spilling_memory_threshold;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
+use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
 use crate::pipelines::processors::transforms::PartitionedHashTableDropper;
@@ -44,6 +47,16 @@ enum HashTable<Method: HashMethodBounds> {
     PartitionedHashTable(HashTableCell<PartitionedHashMethod<Method>, ()>),
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn len(&self) -> usize {
+        match self {
+            HashTable::MovedOut => unreachable!(),
+            HashTable::HashTable(cell) => cell.len(),
+            HashTable::PartitionedHashTable(cell) => cell.len(),
+        }
+    }
+}
+
 impl<Method: HashMethodBounds> Default for HashTable<Method> {
     fn default() -> Self {
         Self::MovedOut
@@ -53,6 +66,9 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
 struct GroupBySettings {
     convert_threshold: usize,
     spilling_bytes_threshold_per_proc: usize,
+    spilling_memory_threshold: usize,
+    abandon_min_rows: usize,
+    abandon_ratio: usize,
 }
 
 impl TryFrom<Arc<QueryContext>> for GroupBySettings {
@@ -62,6 +78,11 @@ impl TryFrom<Arc<QueryContext>> for GroupBySettings {
         let settings = ctx.get_settings();
         let convert_threshold = settings.get_group_by_two_level_threshold()? as usize;
         let value = settings.get_spilling_bytes_threshold_per_proc()?;
+        // The exchange of cluster mode only accepts hash tables, never abandon it.
+        let abandon_min_rows = match ctx.get_cluster().is_empty() {
+            true => settings.get_partial_aggregation_abandon_min_rows()? as usize,
+            false => 0,
+        };
 
         Ok(GroupBySettings {
             convert_threshold,
@@ -69,6 +90,9 @@ impl TryFrom<Arc<QueryContext>> for GroupBySettings {
                 true => usize::MAX,
                 false => value,
             },
+            spilling_memory_threshold: spilling_memory_threshold(&settings)?,
+            abandon_min_rows,
+            abandon_ratio: settings.get_partial_aggregation_abandon_ratio()? as usize,
         })
     }
 }
@@ -79,6 +103,10 @@ pub struct TransformPartialGroupBy<Method: HashMethodBounds> {
     hash_table: HashTable<Method>,
     group_columns: Vec<IndexType>,
     settings: GroupBySettings,
+    processed_rows: usize,
+    // The keys are too distinct for the hash table to reduce rows, pass the
+    // remaining rows through to the final aggregation without hashing them.
+    pass_through: bool,
 }
 
 impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
@@ -101,6 +129,8 @@ impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
                 hash_table,
                 group_columns: params.group_columns.clone(),
                 settings: GroupBySettings::try_from(ctx)?,
+                processed_rows: 0,
+                pass_through: false,
             },
         ))
     }
@@ -126,6 +156,20 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
             let rows_num = block.num_rows();
             let state = self.method.build_keys_state(&group_columns, rows_num)?;
 
+            if self.pass_through {
+                let value_size = group_columns.iter().map(|(c, _)| c.memory_size()).sum();
+                let mut keys_builder = self.method.keys_column_builder(rows_num, value_size);
+                for key in self.method.build_keys_iter(&state)? {
+                    keys_builder.append_value(key);
+                }
+
+                let data_block = DataBlock::new_from_columns(vec![keys_builder.finish()]);
+                return Ok(vec![DataBlock::empty_with_meta(
+                    AggregateMeta::<Method, ()>::create_serialized(-1, data_block),
+                )]);
+            }
+
+            self.processed_rows += rows_num;
             match &mut self.hash_table {
                 HashTable::MovedOut => unreachable!(),
                 HashTable::HashTable(cell) => {
@@ -140,10 +184,26 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                 }
             };
 
+            // Abandon the hash table if it doesn't reduce rows. It's kept as is and flushed
+            // on finish, so that the passed through blocks are ahead of the partitioned ones.
+            if self.settings.abandon_min_rows != 0
+                && self.processed_rows >= self.settings.abandon_min_rows
+                && self.hash_table.len() * 100 >= self.processed_rows * self.settings.abandon_ratio
+            {
+                self.pass_through = true;
+                return Ok(vec![]);
+            }
+
             #[allow(clippy::collapsible_if)]
             if Method::SUPPORT_PARTITIONED {
+                // Spill the partitions once the process is short of memory, even if this
+                // aggregator is still under its own threshold.
+                let memory_pressure = self.hash_table.len() != 0
+                    && exceeds_memory_threshold(self.settings.spilling_memory_threshold);
+
                 if matches!(&self.hash_table, HashTable::HashTable(cell)
-                    if cell.len() >= self.settings.convert_threshold ||
+                    if memory_pressure ||
+                        cell.len() >= self.settings.convert_threshold ||
                         cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc
                 ) {
                     if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
@@ -153,7 +213,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                     }
                 }
 
-                if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
+                if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if memory_pressure || cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
                 {
                     if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table)
                     {
@@ -34,12 +34,11 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::Pipeline;
-use common_storage::DataOperator;
-use petgraph::matrix_graph::Zero;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::HashTablePayload;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::SerializedPayload;
+use crate::pipelines::processors::transforms::aggregator::enable_aggregate_spilling;
 use crate::pipelines::processors::transforms::aggregator::TransformFinalGroupBy;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
@@ -426,8 +425,8 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     pipeline.resize(input_nums)?;
 
     let settings = ctx.get_settings();
-    if !settings.get_spilling_bytes_threshold_per_proc()?.is_zero() {
-        let operator = DataOperator::instance().operator();
+    if enable_aggregate_spilling(&settings)? {
+        let operator = ctx.get_spill_operator();
         pipeline.add_transform(|input, output| {
             let operator = operator.clone();
             match params.aggregate_functions.is_empty() {
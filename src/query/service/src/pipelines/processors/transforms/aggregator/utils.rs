@@ -12,9 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::runtime::GLOBAL_MEM_STAT;
+use common_exception::Result;
 use common_hashtable::HashtableLike;
+use common_settings::Settings;
 
 #[inline]
 pub fn estimated_key_size<Table: HashtableLike>(table: &Table) -> usize {
     table.unsize_key_size().unwrap_or_default()
 }
+
+/// Whether the aggregators may spill, the spill writers and readers are only built in this case.
+pub fn enable_aggregate_spilling(settings: &Settings) -> Result<bool> {
+    Ok(settings.get_spilling_bytes_threshold_per_proc()? != 0
+        || settings.get_spilling_memory_ratio()? != 0)
+}
+
+/// The process memory usage above which the aggregators and hash joins spill,
+/// `usize::MAX` if disabled.
+pub fn spilling_memory_threshold(settings: &Settings) -> Result<usize> {
+    let ratio = std::cmp::min(settings.get_spilling_memory_ratio()?, 100) as usize;
+    Ok(match ratio {
+        0 => usize::MAX,
+        _ => settings.get_max_memory_usage()? as usize / 100 * ratio,
+    })
+}
+
+#[inline]
+pub fn exceeds_memory_threshold(threshold: usize) -> bool {
+    threshold != usize::MAX && GLOBAL_MEM_STAT.get_memory_usage().max(0) as usize >= threshold
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod transform_distinct_final;
+mod transform_distinct_partial;
+
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_expression::KeysState;
+use common_expression::Value;
+use common_profile::ProfCounters;
+use opendal::Operator;
+use tracing::error;
+pub use transform_distinct_final::TransformDistinctFinal;
+pub use transform_distinct_partial::TransformDistinctPartial;
+
+/// The serialized rows of all the columns of the block, the duplicated rows have the same keys.
+fn distinct_keys(block: &DataBlock) -> Result<KeysState> {
+    let num_rows = block.num_rows();
+    let columns = block
+        .columns()
+        .iter()
+        .map(|entry| {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            (column, entry.data_type.clone())
+        })
+        .collect::<Vec<_>>();
+    HashMethodSerializer::default().build_keys_state(&columns, num_rows)
+}
+
+/// A block written to the spill storage.
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+}
+
+#[async_backtrace::framed]
+async fn write_spilled_block(
+    operator: &Operator,
+    location_prefix: &str,
+    block: DataBlock,
+) -> Result<SpilledBlock> {
+    let block = block.convert_to_full();
+
+    let mut columns_layout = Vec::with_capacity(block.num_columns());
+    let mut write_data = vec![];
+    for entry in block.columns() {
+        let column_data = serialize_column(entry.value.as_column().unwrap());
+        columns_layout.push(column_data.len());
+        write_data.extend(column_data);
+    }
+
+    let bytes = write_data.len();
+    let location = format!("{}/{}", location_prefix, GlobalUniqName::unique());
+    operator.write(&location, write_data).await?;
+    ProfCounters::record_spill_bytes(bytes);
+
+    Ok(SpilledBlock {
+        location,
+        columns_layout,
+        num_rows: block.num_rows(),
+    })
+}
+
+#[async_backtrace::framed]
+async fn read_spilled_block(
+    operator: &Operator,
+    spilled_block: &SpilledBlock,
+) -> Result<DataBlock> {
+    let data = operator.read(&spilled_block.location).await?;
+    if let Err(cause) = operator.delete(&spilled_block.location).await {
+        error!(
+            "Cannot delete spill file {}, cause: {:?}",
+            &spilled_block.location, cause
+        );
+    }
+
+    let mut begin = 0;
+    let mut columns = Vec::with_capacity(spilled_block.columns_layout.len());
+    for column_layout in spilled_block.columns_layout.iter() {
+        let column = deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Cannot deserialize spill file {}",
+                &spilled_block.location
+            ))
+        })?;
+        columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+        begin += column_layout;
+    }
+
+    Ok(DataBlock::new(columns, spilled_block.num_rows))
+}
@@ -0,0 +1,411 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_expression::Value;
+use common_hashtable::hash2bucket;
+use common_hashtable::FastHash;
+use common_hashtable::HashtableLike;
+use common_hashtable::ShortStringHashSet;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use opendal::Operator;
+use tracing::info;
+
+use super::distinct_keys;
+use super::read_spilled_block;
+use super::write_spilled_block;
+use super::SpilledBlock;
+use crate::pipelines::processors::transforms::aggregator::exceeds_memory_threshold;
+
+const PARTITIONS_LG2: u32 = 4;
+const NUM_PARTITIONS: usize = 1 << PARTITIONS_LG2;
+
+enum PendingBlock {
+    Memory(DataBlock),
+    Spilled(SpilledBlock),
+}
+
+/// A partition whose keys are written to the spill storage.
+#[derive(Default)]
+struct SpilledPartition {
+    /// The keys of the rows output before the partition is spilled.
+    keys: Vec<SpilledBlock>,
+    /// The rows arrived after the partition is spilled, they are deduplicated against
+    /// the spilled keys when the input is finished.
+    pending: VecDeque<PendingBlock>,
+    buffer: Vec<DataBlock>,
+    buffer_rows: usize,
+}
+
+enum Partition {
+    Memory(ShortStringHashSet<[u8]>),
+    Spilled(SpilledPartition),
+}
+
+enum SpillData {
+    Keys(DataBlock),
+    Rows(DataBlock),
+}
+
+/// The spilled partition being deduplicated after the input is finished.
+struct RestoringPartition {
+    set: ShortStringHashSet<[u8]>,
+    pending: VecDeque<PendingBlock>,
+}
+
+/// Removes the duplicated rows while streaming, the rows are output as soon as they're
+/// first seen. The rows of a pipeline are partitioned by the hash of their keys, once the
+/// process memory exceeds `spilling_memory_ratio`, the keys of the largest partition are
+/// written to the spill storage, and the rows of the partition arrived later are held back
+/// until the input is finished, then the spilled partitions are deduplicated one by one.
+///
+/// The rows with the same keys must be sent to the same pipeline.
+pub struct TransformDistinctFinal {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    limit: Option<usize>,
+    block_size: usize,
+
+    operator: Operator,
+    location_prefix: String,
+    memory_threshold: usize,
+
+    partitions: Vec<Partition>,
+    num_output_rows: usize,
+    input_data: Option<DataBlock>,
+    restored_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+
+    spilling_data: Vec<(usize, SpillData)>,
+    restoring: Option<RestoringPartition>,
+    loading_partition: Option<SpilledPartition>,
+    reading_block: Option<SpilledBlock>,
+}
+
+impl TransformDistinctFinal {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        limit: Option<usize>,
+        block_size: usize,
+        operator: Operator,
+        location_prefix: String,
+        memory_threshold: usize,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformDistinctFinal {
+            input,
+            output,
+            limit,
+            block_size,
+            operator,
+            location_prefix,
+            memory_threshold,
+            partitions: (0..NUM_PARTITIONS)
+                .map(|_| Partition::Memory(ShortStringHashSet::new()))
+                .collect(),
+            num_output_rows: 0,
+            input_data: None,
+            restored_data: None,
+            output_data: None,
+            spilling_data: vec![],
+            restoring: None,
+            loading_partition: None,
+            reading_block: None,
+        })
+    }
+
+    fn reach_limit(&self) -> bool {
+        self.limit
+            .map_or(false, |limit| self.num_output_rows >= limit)
+    }
+
+    fn push_output(&mut self, block: DataBlock) {
+        let block = match self.limit {
+            Some(limit) if self.num_output_rows + block.num_rows() > limit => {
+                block.slice(0..limit - self.num_output_rows)
+            }
+            _ => block,
+        };
+        if block.num_rows() > 0 {
+            self.num_output_rows += block.num_rows();
+            self.output_data = Some(block);
+        }
+    }
+
+    fn process_input(&mut self, block: DataBlock) -> Result<()> {
+        let num_rows = block.num_rows();
+        let keys_state = distinct_keys(&block)?;
+        let keys_iter = HashMethodSerializer::default().build_keys_iter(&keys_state)?;
+
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        let mut pending_rows: Vec<Vec<u32>> = vec![vec![]; NUM_PARTITIONS];
+        for (row, key) in keys_iter.enumerate() {
+            let partition = hash2bucket::<PARTITIONS_LG2, true>(key.fast_hash() as usize);
+            match &mut self.partitions[partition] {
+                Partition::Memory(set) => bitmap.push(set.set_insert(key).is_ok()),
+                Partition::Spilled(_) => {
+                    bitmap.push(false);
+                    pending_rows[partition].push(row as u32);
+                }
+            }
+        }
+
+        for (index, rows) in pending_rows.iter().enumerate() {
+            if rows.is_empty() {
+                continue;
+            }
+            if let Partition::Spilled(partition) = &mut self.partitions[index] {
+                partition.buffer.push(block.take(rows)?);
+                partition.buffer_rows += rows.len();
+                if partition.buffer_rows >= self.block_size {
+                    let rows = DataBlock::concat(&std::mem::take(&mut partition.buffer))?;
+                    partition.buffer_rows = 0;
+                    self.spilling_data.push((index, SpillData::Rows(rows)));
+                }
+            }
+        }
+
+        self.push_output(block.filter_with_bitmap(&bitmap.into())?);
+
+        if exceeds_memory_threshold(self.memory_threshold) {
+            self.spill_largest_partition();
+        }
+        Ok(())
+    }
+
+    fn spill_largest_partition(&mut self) {
+        let largest = self
+            .partitions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, partition)| match partition {
+                Partition::Memory(set) if !set.is_empty() => Some((index, set.len())),
+                _ => None,
+            })
+            .max_by_key(|(_, len)| *len);
+
+        if let Some((index, _)) = largest {
+            let partition = std::mem::replace(
+                &mut self.partitions[index],
+                Partition::Spilled(SpilledPartition::default()),
+            );
+            if let Partition::Memory(set) = partition {
+                let mut builder = StringColumnBuilder::with_capacity(set.len(), 0);
+                for entry in set.iter() {
+                    builder.put_slice(entry.key());
+                    builder.commit_row();
+                }
+                let keys = DataBlock::new(
+                    vec![BlockEntry::new(
+                        DataType::String,
+                        Value::Column(Column::String(builder.build())),
+                    )],
+                    set.len(),
+                );
+                self.spilling_data.push((index, SpillData::Keys(keys)));
+            }
+        }
+    }
+
+    fn process_restored(&mut self, block: DataBlock) -> Result<()> {
+        let restoring = self.restoring.as_mut().unwrap();
+        let keys_state = distinct_keys(&block)?;
+        let keys_iter = HashMethodSerializer::default().build_keys_iter(&keys_state)?;
+
+        let mut bitmap = MutableBitmap::with_capacity(block.num_rows());
+        for key in keys_iter {
+            bitmap.push(restoring.set.set_insert(key).is_ok());
+        }
+
+        self.push_output(block.filter_with_bitmap(&bitmap.into())?);
+        Ok(())
+    }
+
+    /// Takes the next spilled partition to deduplicate, the rows held in memory are
+    /// deduplicated after the spilled ones.
+    fn next_spilled_partition(&mut self) -> Result<Option<SpilledPartition>> {
+        for partition in self.partitions.iter_mut() {
+            if let Partition::Spilled(spilled) = partition {
+                let mut spilled = std::mem::take(spilled);
+                if !spilled.buffer.is_empty() {
+                    let rows = DataBlock::concat(&std::mem::take(&mut spilled.buffer))?;
+                    spilled.pending.push_back(PendingBlock::Memory(rows));
+                }
+                *partition = Partition::Memory(ShortStringHashSet::new());
+                return Ok(Some(spilled));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformDistinctFinal {
+    fn name(&self) -> String {
+        String::from("TransformDistinctFinal")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_data.take() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.reach_limit() {
+            self.input.finish();
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.spilling_data.is_empty() {
+            self.input.set_not_need_data();
+            return Ok(Event::Async);
+        }
+
+        if self.input_data.is_some() || self.restored_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            if data_block.num_rows() > 0 {
+                self.input_data = Some(data_block);
+                return Ok(Event::Sync);
+            }
+        }
+
+        if !self.input.is_finished() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        if let Some(restoring) = self.restoring.as_mut() {
+            match restoring.pending.pop_front() {
+                Some(PendingBlock::Memory(block)) => {
+                    self.restored_data = Some(block);
+                    return Ok(Event::Sync);
+                }
+                Some(PendingBlock::Spilled(block)) => {
+                    self.reading_block = Some(block);
+                    return Ok(Event::Async);
+                }
+                None => self.restoring = None,
+            }
+        }
+
+        if let Some(partition) = self.next_spilled_partition()? {
+            self.loading_partition = Some(partition);
+            return Ok(Event::Async);
+        }
+
+        self.output.finish();
+        Ok(Event::Finished)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            return self.process_input(block);
+        }
+
+        if let Some(block) = self.restored_data.take() {
+            return self.process_restored(block);
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        for (index, data) in std::mem::take(&mut self.spilling_data) {
+            let Partition::Spilled(partition) = &mut self.partitions[index] else {
+                unreachable!("the spilled data must belong to a spilled partition");
+            };
+            match data {
+                SpillData::Keys(keys) => {
+                    let instant = Instant::now();
+                    let num_keys = keys.num_rows();
+                    let block =
+                        write_spilled_block(&self.operator, &self.location_prefix, keys).await?;
+                    partition.keys.push(block);
+                    info!(
+                        "Write distinct spill partition of {} keys successfully, elapsed: {:?}",
+                        num_keys,
+                        instant.elapsed()
+                    );
+                }
+                SpillData::Rows(rows) => {
+                    let block =
+                        write_spilled_block(&self.operator, &self.location_prefix, rows).await?;
+                    partition.pending.push_back(PendingBlock::Spilled(block));
+                }
+            }
+        }
+
+        if let Some(partition) = self.loading_partition.take() {
+            let mut set = ShortStringHashSet::new();
+            for spilled_block in partition.keys.iter() {
+                let keys = read_spilled_block(&self.operator, spilled_block).await?;
+                let column = keys.get_by_offset(0).value.as_column().unwrap();
+                let column = StringType::try_downcast_column(column).unwrap();
+                for key in column.iter() {
+                    let _ = set.set_insert(key);
+                }
+            }
+            self.restoring = Some(RestoringPartition {
+                set,
+                pending: partition.pending,
+            });
+        }
+
+        if let Some(spilled_block) = self.reading_block.take() {
+            let block = read_spilled_block(&self.operator, &spilled_block).await?;
+            self.restored_data = Some(block);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_hashtable::ShortStringHashSet;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::Transform;
+use common_pipeline_transforms::processors::transforms::Transformer;
+
+use super::distinct_keys;
+
+/// The number of the keys remembered by a [`TransformDistinctPartial`], the keys are
+/// forgotten once it's reached to bound the memory.
+const MAX_PARTIAL_KEYS: usize = 65536;
+
+/// Projects the distinct columns of the blocks and removes the rows seen recently in the
+/// pipeline, which reduces the rows to repartition. The duplicated rows across pipelines
+/// and the ones seen long ago are removed by [`super::TransformDistinctFinal`].
+pub struct TransformDistinctPartial {
+    columns: Vec<usize>,
+    set: ShortStringHashSet<[u8]>,
+}
+
+impl TransformDistinctPartial {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        columns: Vec<usize>,
+    ) -> Box<dyn Processor> {
+        Transformer::create(input, output, TransformDistinctPartial {
+            columns,
+            set: ShortStringHashSet::with_capacity(MAX_PARTIAL_KEYS),
+        })
+    }
+}
+
+impl Transform for TransformDistinctPartial {
+    const NAME: &'static str = "TransformDistinctPartial";
+    const SKIP_EMPTY_DATA_BLOCK: bool = true;
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        let entries = self
+            .columns
+            .iter()
+            .map(|offset| data.get_by_offset(*offset).clone())
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+
+        if self.set.len() >= MAX_PARTIAL_KEYS {
+            self.set = ShortStringHashSet::with_capacity(MAX_PARTIAL_KEYS);
+        }
+
+        let keys_state = distinct_keys(&block)?;
+        let keys_iter = HashMethodSerializer::default().build_keys_iter(&keys_state)?;
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for key in keys_iter {
+            bitmap.push(self.set.set_insert(key).is_ok());
+        }
+
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
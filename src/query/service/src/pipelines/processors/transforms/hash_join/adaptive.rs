@@ -0,0 +1,152 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Notify;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_sql::executor::HashJoin;
+use parking_lot::Mutex;
+
+use crate::api::FlightScatter;
+use crate::api::HashFlightScatter;
+use crate::clusters::ClusterHelper;
+use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
+use crate::schedulers::Fragmenter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Whether a broadcast join switched to a partitioned join on the local node. It is shared by
+/// the build side of the join and the exchange of its probe side, which holds the probe rows
+/// until the join decides.
+///
+/// Every node receives all the build rows of a broadcast join and decides on its own. A node
+/// switching keeps the build rows of its hash partition only and sends its probe rows to the
+/// nodes of their partitions, while the other nodes still join their own probe rows locally.
+#[derive(Default)]
+pub struct AdaptiveJoinState {
+    partitioned: Mutex<Option<bool>>,
+    decided_notify: Notify,
+}
+
+impl AdaptiveJoinState {
+    /// Decide if the join is partitioned, returns false if the join had been decided.
+    pub fn decide(&self, partitioned: bool) -> bool {
+        let mut guard = self.partitioned.lock();
+        if guard.is_some() {
+            return false;
+        }
+
+        *guard = Some(partitioned);
+        self.decided_notify.notify_waiters();
+        true
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        self.partitioned.lock().unwrap_or(false)
+    }
+
+    /// Wait until the join is decided.
+    #[async_backtrace::framed]
+    pub async fn wait_decided(&self) {
+        let notified = {
+            let guard = self.partitioned.lock();
+
+            match *guard {
+                Some(_) => None,
+                None => Some(self.decided_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+    }
+}
+
+/// The build side of a broadcast join that switches to a partitioned join once the local
+/// node receives more build rows than `fallback_build_rows`.
+pub struct AdaptiveJoinBuild {
+    pub(crate) state: Arc<AdaptiveJoinState>,
+    fallback_build_rows: usize,
+    received_rows: AtomicUsize,
+    /// Scatters the build rows the same way as the exchange of the probe side
+    scatter: Box<dyn FlightScatter>,
+    local_pos: usize,
+}
+
+impl AdaptiveJoinBuild {
+    pub fn try_create(ctx: &Arc<QueryContext>, join: &HashJoin) -> Result<Option<Self>> {
+        let fallback_build_rows = match join.fallback_build_rows {
+            Some(rows) => rows as usize,
+            None => return Ok(None),
+        };
+
+        let executors = Fragmenter::get_executors(ctx.clone());
+        let local_id = ctx.get_cluster().local_id();
+        let local_pos = executors
+            .iter()
+            .position(|executor| executor == &local_id)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the local executor {} in the cluster",
+                    local_id
+                ))
+            })?;
+        let scatter = HashFlightScatter::try_create(
+            ctx.get_function_context()?,
+            join.build_keys.clone(),
+            executors.len(),
+        )?;
+
+        Ok(Some(AdaptiveJoinBuild {
+            state: ctx.get_adaptive_join_state(join.plan_id),
+            fallback_build_rows,
+            received_rows: AtomicUsize::new(0),
+            scatter,
+            local_pos,
+        }))
+    }
+
+    /// Count the received build rows, returns true if the join switches by them.
+    pub fn receive(&self, rows: usize) -> bool {
+        let received_rows = self.received_rows.fetch_add(rows, Ordering::Relaxed) + rows;
+        received_rows > self.fallback_build_rows && self.state.decide(true)
+    }
+
+    /// Keep the build rows of the hash partition of the local node.
+    pub fn local_partition(&self, data_block: DataBlock) -> Result<DataBlock> {
+        let mut data_blocks = self.scatter.execute(data_block)?;
+        Ok(data_blocks.swap_remove(self.local_pos))
+    }
+
+    /// Drop the build rows received before the switch, but not of the local node.
+    pub fn partition_row_space(&self, row_space: &RowSpace) -> Result<()> {
+        let mut buffer = row_space.buffer.write();
+        for data_block in buffer.iter_mut() {
+            *data_block = self.local_partition(data_block.clone())?;
+        }
+        drop(buffer);
+
+        let mut chunks = row_space.chunks.write();
+        for chunk in chunks.iter_mut() {
+            chunk.data_block = self.local_partition(chunk.data_block.clone())?;
+        }
+        Ok(())
+    }
+}
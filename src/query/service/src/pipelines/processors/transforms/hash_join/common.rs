@@ -18,6 +18,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::arrow::constant_bitmap;
 use common_expression::arrow::or_validities;
+use common_expression::type_check::check_function;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::AnyType;
 use common_expression::types::BooleanType;
@@ -29,6 +30,7 @@ use common_expression::Evaluator;
 use common_expression::Expr;
 use common_expression::Scalar;
 use common_expression::Value;
+use common_functions::aggregates::eval_aggr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_hashtable::HashJoinHashtableLike;
 use common_hashtable::MarkerKind;
@@ -275,4 +277,102 @@ impl JoinHashTable {
         }
         Ok(())
     }
+
+    /// Push the range of the build keys down to the table scans of the probe side, so the
+    /// blocks holding no key in the range are not read.
+    pub(crate) fn add_runtime_filters(&self) -> Result<()> {
+        let runtime_filter_keys = &self.hash_join_desc.runtime_filter_keys;
+        if runtime_filter_keys.iter().all(Option::is_none) {
+            return Ok(());
+        }
+        // The keys of the spilled partitions are not in the chunks.
+        if let Some(spill_state) = &self.spill_state {
+            if spill_state.is_spilled() {
+                return Ok(());
+            }
+        }
+        // The build rows of a partitioned join only hold the keys of the local node.
+        if let Some(adaptive_build) = &self.adaptive_build {
+            if adaptive_build.state.is_partitioned() {
+                return Ok(());
+            }
+        }
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let chunks = self.row_space.chunks.read();
+        for (build_key, runtime_filter_key) in self
+            .hash_join_desc
+            .build_keys
+            .iter()
+            .zip(runtime_filter_keys.iter())
+        {
+            let (table_index, probe_key) = match runtime_filter_key {
+                Some(runtime_filter_key) => runtime_filter_key,
+                None => continue,
+            };
+
+            let mut columns = Vec::with_capacity(chunks.len());
+            for chunk in chunks.iter() {
+                let evaluator = Evaluator::new(&chunk.data_block, &func_ctx, &BUILTIN_FUNCTIONS);
+                let column = evaluator
+                    .run(build_key)?
+                    .convert_to_full_column(build_key.data_type(), chunk.num_rows());
+                columns.push(column);
+            }
+
+            let filter = match Self::key_range(&columns)? {
+                Some((min, max)) => {
+                    let data_type = build_key.data_type().remove_nullable();
+                    let gte = check_function(
+                        None,
+                        "gte",
+                        &[],
+                        &[probe_key.clone(), Expr::Constant {
+                            span: None,
+                            scalar: min,
+                            data_type: data_type.clone(),
+                        }],
+                        &BUILTIN_FUNCTIONS,
+                    )?;
+                    let lte = check_function(
+                        None,
+                        "lte",
+                        &[],
+                        &[probe_key.clone(), Expr::Constant {
+                            span: None,
+                            scalar: max,
+                            data_type,
+                        }],
+                        &BUILTIN_FUNCTIONS,
+                    )?;
+                    check_function(None, "and", &[], &[gte, lte], &BUILTIN_FUNCTIONS)?
+                }
+                // No key matches the probe rows, all the blocks can be pruned.
+                None => Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Boolean(false),
+                    data_type: DataType::Boolean,
+                },
+            };
+            self.ctx.add_runtime_filters(*table_index, vec![filter]);
+        }
+        Ok(())
+    }
+
+    /// The min and max of the non-null keys, `None` if there is no such key.
+    fn key_range(columns: &[Column]) -> Result<Option<(Scalar, Scalar)>> {
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        let column = Column::concat(columns);
+        let rows = column.len();
+        let (mins, _) = eval_aggr("min", vec![], &[column.clone()], rows)?;
+        let (maxs, _) = eval_aggr("max", vec![], &[column], rows)?;
+        let min = mins.index(0).map(|v| v.to_owned()).unwrap_or(Scalar::Null);
+        let max = maxs.index(0).map(|v| v.to_owned()).unwrap_or(Scalar::Null);
+        if min.is_null() || max.is_null() {
+            return Ok(None);
+        }
+        Ok(Some((min, max)))
+    }
 }
@@ -47,10 +47,14 @@ pub struct HashJoinDesc {
     pub(crate) probe_keys: Vec<Expr>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
+    /// Indexes of the keys on which NULL matches NULL (`IS NOT DISTINCT FROM`).
+    pub(crate) is_null_equal: Vec<usize>,
     pub(crate) marker_join_desc: MarkJoinDesc,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) join_state: JoinState,
+    /// The probe keys pushed down to the table scans of the probe side, by table index.
+    pub(crate) runtime_filter_keys: Vec<Option<(usize, Expr<String>)>>,
 }
 
 impl HashJoinDesc {
@@ -73,15 +77,44 @@ impl HashJoinDesc {
             build_keys,
             probe_keys,
             other_predicate,
+            is_null_equal: join.is_null_equal.clone(),
             marker_join_desc: MarkJoinDesc {
                 has_null: RwLock::new(false),
                 // marker_index: join.marker_index,
             },
             from_correlated_subquery: join.from_correlated_subquery,
             join_state: JoinState::create()?,
+            runtime_filter_keys: join
+                .runtime_filter_keys
+                .iter()
+                .map(|key| {
+                    key.as_ref()
+                        .map(|(table_index, key)| (*table_index, key.as_expr(&BUILTIN_FUNCTIONS)))
+                })
+                .collect(),
         })
     }
 
+    /// Create a desc of the same join with fresh states, for the hash table of a spilled partition.
+    /// The runtime filters are not built, the partition only holds a part of the build side.
+    pub fn duplicate(&self) -> HashJoinDesc {
+        HashJoinDesc {
+            build_keys: self.build_keys.clone(),
+            probe_keys: self.probe_keys.clone(),
+            join_type: self.join_type.clone(),
+            other_predicate: self.other_predicate.clone(),
+            is_null_equal: self.is_null_equal.clone(),
+            marker_join_desc: MarkJoinDesc {
+                has_null: RwLock::new(false),
+            },
+            from_correlated_subquery: self.from_correlated_subquery,
+            join_state: JoinState {
+                _build_indexes: RwLock::new(Vec::with_capacity(1)),
+            },
+            runtime_filter_keys: vec![],
+        }
+    }
+
     fn join_predicate(non_equi_conditions: &[RemoteExpr]) -> Result<Option<Expr>> {
         non_equi_conditions
             .iter()
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 use common_expression::DataBlock;
 
+use super::HashJoinSpillState;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
 
@@ -24,6 +27,10 @@ pub trait HashJoinState: Send + Sync {
     /// Add input `DataBlock` to `row_space`.
     fn build(&self, input: DataBlock) -> Result<()>;
 
+    /// Keep the build rows of the local node only, once the broadcast join switches to a
+    /// partitioned join.
+    fn partition_build_block(&self, input: DataBlock) -> Result<DataBlock>;
+
     /// Probe the hash table and retrieve matched rows as DataBlocks.
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>>;
 
@@ -55,6 +62,9 @@ pub trait HashJoinState: Send + Sync {
     // Detach to state: `probe_count`.
     fn probe_done(&self) -> Result<()>;
 
+    /// Record the number of rows probed by a probe worker once its input is finished.
+    fn report_probed_rows(&self, rows: usize);
+
     /// Check if need outer scan.
     fn need_outer_scan(&self) -> bool;
 
@@ -99,4 +109,7 @@ pub trait HashJoinState: Send + Sync {
 
     /// Get mark join results.
     fn mark_join_blocks(&self) -> Result<Vec<DataBlock>>;
+
+    /// Get the spilling state if the join may spill.
+    fn spill_state(&self) -> Option<Arc<HashJoinSpillState>>;
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -39,6 +40,7 @@ use common_hashtable::StringRawEntry;
 use common_hashtable::STRING_EARLY_SIZE;
 use ethnum::U256;
 
+use super::HashJoinSpillState;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
 use crate::pipelines::processors::transforms::hash_join::desc::JOIN_MAX_BLOCK_SIZE;
@@ -67,6 +69,21 @@ impl HashJoinState for JoinHashTable {
         }
     }
 
+    fn partition_build_block(&self, input: DataBlock) -> Result<DataBlock> {
+        let adaptive_build = match &self.adaptive_build {
+            Some(adaptive_build) => adaptive_build,
+            None => return Ok(input),
+        };
+
+        if adaptive_build.receive(input.num_rows()) {
+            adaptive_build.partition_row_space(&self.row_space)?;
+        }
+        match adaptive_build.state.is_partitioned() {
+            true => adaptive_build.local_partition(input),
+            false => Ok(input),
+        }
+    }
+
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
         match self.hash_join_desc.join_type {
             JoinType::Inner
@@ -86,6 +103,10 @@ impl HashJoinState for JoinHashTable {
 
     fn interrupt(&self) {
         self.interrupt.store(true, Ordering::Release);
+        // Release the exchange of the probe side waiting for the join.
+        if let Some(adaptive_build) = &self.adaptive_build {
+            adaptive_build.state.decide(false);
+        }
     }
 
     fn join_state(&self) -> &JoinState {
@@ -105,9 +126,23 @@ impl HashJoinState for JoinHashTable {
         let mut count = self.build_count.lock();
         *count -= 1;
         if *count == 0 {
+            // The join is not switched if all the build rows fit.
+            if let Some(adaptive_build) = &self.adaptive_build {
+                adaptive_build.state.decide(false);
+            }
+
+            // Add the build blocks of the partitions not spilled.
+            if let Some(spill_state) = &self.spill_state {
+                for block in spill_state.take_in_memory_blocks() {
+                    self.build(block)?;
+                }
+            }
+
             // Divide the finalize phase into multiple tasks.
             self.generate_finalize_task()?;
 
+            self.add_runtime_filters()?;
+
             // Get the number of rows of the build side.
             let chunks = self.row_space.chunks.read();
             let mut row_num = 0;
@@ -454,9 +489,27 @@ impl HashJoinState for JoinHashTable {
     fn probe_attach(&self) -> Result<()> {
         let mut count = self.probe_count.lock();
         *count += 1;
+        self.probe_worker_num.fetch_add(1, Ordering::Relaxed);
         Ok(())
     }
 
+    fn report_probed_rows(&self, rows: usize) {
+        let mut probed_rows = self.probed_rows.lock();
+        probed_rows.push(rows);
+        let worker_num = self.probe_worker_num.load(Ordering::Relaxed) as usize;
+        if worker_num < 2 || probed_rows.len() != worker_num {
+            return;
+        }
+        let total_rows = probed_rows.iter().sum::<usize>();
+        if total_rows == 0 {
+            return;
+        }
+        // Skew is the ratio of the busiest worker to the average worker.
+        let max_rows = probed_rows.iter().max().copied().unwrap_or_default();
+        let mean_rows = total_rows as f64 / worker_num as f64;
+        self.ctx.set_join_skew(max_rows as f64 / mean_rows);
+    }
+
     fn probe_done(&self) -> Result<()> {
         let mut count = self.probe_count.lock();
         *count -= 1;
@@ -464,6 +517,11 @@ impl HashJoinState for JoinHashTable {
             // Divide the outer scan phase into multiple tasks.
             self.generate_outer_scan_task()?;
 
+            // Divide the spilled partitions into tasks.
+            if let Some(spill_state) = &self.spill_state {
+                spill_state.generate_partition_tasks();
+            }
+
             let mut probe_done = self.probe_done.lock();
             *probe_done = true;
             self.probe_done_notify.notify_waiters();
@@ -750,4 +808,8 @@ impl HashJoinState for JoinHashTable {
         let build_block = self.row_space.gather(&row_ptrs, &data_blocks, &num_rows)?;
         Ok(vec![self.merge_eq_block(&marker_block, &build_block)?])
     }
+
+    fn spill_state(&self) -> Option<Arc<HashJoinSpillState>> {
+        self.spill_state.clone()
+    }
 }
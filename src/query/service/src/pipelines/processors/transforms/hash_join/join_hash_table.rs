@@ -45,6 +45,8 @@ use ethnum::U256;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
+use super::AdaptiveJoinBuild;
+use super::HashJoinSpillState;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
@@ -111,6 +113,13 @@ pub struct JoinHashTable {
     pub(crate) finalize_tasks: Arc<RwLock<VecDeque<(usize, usize)>>>,
     /// OuterScan tasks
     pub(crate) outer_scan_tasks: Arc<RwLock<VecDeque<usize>>>,
+    /// Rows probed by each finished probe worker, used to measure the join skew
+    pub(crate) probe_worker_num: Arc<AtomicU32>,
+    pub(crate) probed_rows: Mutex<Vec<usize>>,
+    /// Spilling state, only set if spilling is enabled and supported by the join type
+    pub(crate) spill_state: Option<Arc<HashJoinSpillState>>,
+    /// Only set if the join is a broadcast join that may switch to a partitioned join
+    pub(crate) adaptive_build: Option<AdaptiveJoinBuild>,
 }
 
 impl JoinHashTable {
@@ -120,19 +129,25 @@ impl JoinHashTable {
         build_schema: DataSchemaRef,
         probe_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        adaptive_build: Option<AdaptiveJoinBuild>,
     ) -> Result<Arc<JoinHashTable>> {
         let hash_key_types = build_keys
             .iter()
             .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).data_type().clone())
             .collect::<Vec<_>>();
         let method = DataBlock::choose_hash_method_with_types(&hash_key_types, false)?;
-        Ok(Arc::new(JoinHashTable::try_create(
-            ctx,
-            build_schema,
-            probe_schema,
-            hash_join_desc,
-            method,
-        )?))
+        let spill_state = HashJoinSpillState::try_create(
+            ctx.clone(),
+            build_schema.clone(),
+            probe_schema.clone(),
+            &hash_join_desc,
+            &method,
+        )?;
+        let mut join_hash_table =
+            JoinHashTable::try_create(ctx, build_schema, probe_schema, hash_join_desc, method)?;
+        join_hash_table.spill_state = spill_state;
+        join_hash_table.adaptive_build = adaptive_build;
+        Ok(Arc::new(join_hash_table))
     }
 
     pub fn try_create(
@@ -179,6 +194,10 @@ impl JoinHashTable {
             build_worker_num: Arc::new(AtomicU32::new(0)),
             finalize_tasks: Arc::new(RwLock::new(VecDeque::new())),
             outer_scan_tasks: Arc::new(RwLock::new(VecDeque::new())),
+            probe_worker_num: Arc::new(AtomicU32::new(0)),
+            probed_rows: Mutex::new(vec![]),
+            spill_state: None,
+            adaptive_build: None,
         })
     }
 
@@ -225,12 +244,20 @@ impl JoinHashTable {
             probe_state.markers = Some(Self::init_markers(&probe_keys, input.num_rows()));
         }
 
-        if probe_keys
+        // Keys compared with `IS NOT DISTINCT FROM` may match NULL, so they don't
+        // take part in the validity of the probe row.
+        let nullable_probe_keys = probe_keys
             .iter()
-            .any(|(_, ty)| ty.is_nullable() || ty.is_null())
-        {
+            .enumerate()
+            .filter(|(idx, (_, ty))| {
+                (ty.is_nullable() || ty.is_null())
+                    && !self.hash_join_desc.is_null_equal.contains(idx)
+            })
+            .map(|(_, key)| key)
+            .collect::<Vec<_>>();
+        if !nullable_probe_keys.is_empty() {
             let mut valids = None;
-            for (col, _) in probe_keys.iter() {
+            for (col, _) in nullable_probe_keys.iter() {
                 let (is_all_null, tmp_valids) = col.validity();
                 if is_all_null {
                     let mut m = MutableBitmap::with_capacity(input.num_rows());
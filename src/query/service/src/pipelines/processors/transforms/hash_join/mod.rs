@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptive;
 mod common;
 mod desc;
 mod hash_join_state;
@@ -21,11 +22,18 @@ mod probe_join;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod spill;
 mod util;
 
+pub use adaptive::AdaptiveJoinBuild;
+pub use adaptive::AdaptiveJoinState;
 pub use desc::HashJoinDesc;
 pub use hash_join_state::HashJoinState;
 pub use join_hash_table::FixedKeyHashJoinHashTable;
 pub use join_hash_table::JoinHashTable;
 pub use probe_state::ProbeState;
 pub use result_blocks::*;
+pub use spill::HashJoinSpillState;
+pub use spill::HashJoinSpiller;
+pub use spill::PartitionJoin;
+pub use spill::SpilledPartition;
@@ -0,0 +1,602 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::type_check::check_function;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::HashMethodKind;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ProfCounters;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::aggregator::exceeds_memory_threshold;
+use crate::pipelines::processors::transforms::aggregator::spilling_memory_threshold;
+use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The build and probe sides are partitioned into `1 << SPILL_PARTITION_BITS` partitions by the hash of the join keys.
+const SPILL_PARTITION_BITS: usize = 4;
+const SPILL_PARTITIONS: usize = 1 << SPILL_PARTITION_BITS;
+/// A spilled partition which still exceeds the threshold is repartitioned with a new hash seed,
+/// at most this many times, and then joined in memory anyway.
+const MAX_SPILL_LEVEL: usize = 3;
+/// The rows of a spilled partition are buffered by each processor and written once they reach this size.
+const SPILL_WRITE_BYTES: usize = 8 * 1024 * 1024;
+
+/// A block written to the spill storage.
+#[derive(Clone, Debug)]
+pub struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+    bytes: usize,
+}
+
+/// The spilled build and probe rows of one partition, joined after the in-memory partitions.
+pub struct SpilledPartition {
+    /// How many times the rows have been partitioned, used as the seed of the next partitioning.
+    level: usize,
+    build: Vec<SpilledBlock>,
+    probe: Vec<SpilledBlock>,
+}
+
+impl SpilledPartition {
+    fn build_bytes(&self) -> usize {
+        self.build.iter().map(|block| block.bytes).sum()
+    }
+}
+
+struct BuildPartitions {
+    /// The build blocks of the partitions kept in memory.
+    blocks: Vec<Vec<DataBlock>>,
+    bytes: Vec<usize>,
+    total_bytes: usize,
+    spilled: Vec<bool>,
+    /// The spilled blocks of each partition, for the build and the probe side.
+    spilled_build: Vec<Vec<SpilledBlock>>,
+    spilled_probe: Vec<Vec<SpilledBlock>>,
+}
+
+/// Shared spilling state of a hash join, it implements a hybrid hash join:
+///
+/// - The build rows are partitioned by the hash of the build keys. Once the build side
+///   exceeds `join_spilling_threshold` (or the process memory exceeds `spilling_memory_ratio`),
+///   the largest partitions are spilled to storage, the others are kept in memory.
+/// - The probe rows of the in-memory partitions are joined as usual, the probe rows of
+///   the spilled partitions are spilled as well.
+/// - After the probe side is finished, the spilled partitions are joined one by one by the
+///   probe processors, a partition that is still too large is repartitioned recursively.
+pub struct HashJoinSpillState {
+    ctx: Arc<QueryContext>,
+    operator: Operator,
+    location_prefix: String,
+    func_ctx: FunctionContext,
+    build_schema: DataSchemaRef,
+    probe_schema: DataSchemaRef,
+    hash_join_desc: HashJoinDesc,
+    method: HashMethodKind,
+    build_hash_keys: Vec<Expr>,
+    probe_hash_keys: Vec<Expr>,
+    threshold: usize,
+    memory_threshold: usize,
+    partitions: Mutex<BuildPartitions>,
+    tasks: Mutex<VecDeque<SpilledPartition>>,
+}
+
+impl HashJoinSpillState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        build_schema: DataSchemaRef,
+        probe_schema: DataSchemaRef,
+        hash_join_desc: &HashJoinDesc,
+        method: &HashMethodKind,
+    ) -> Result<Option<Arc<HashJoinSpillState>>> {
+        let settings = ctx.get_settings();
+        let threshold = settings.get_join_spilling_threshold()? as usize;
+        let memory_threshold = spilling_memory_threshold(&settings)?;
+        if threshold == 0 && memory_threshold == usize::MAX {
+            return Ok(None);
+        }
+
+        // Mark joins need the whole build side to decide the NULL markers.
+        let supported = matches!(
+            hash_join_desc.join_type,
+            JoinType::Inner
+                | JoinType::Left
+                | JoinType::Right
+                | JoinType::Full
+                | JoinType::LeftSemi
+                | JoinType::LeftAnti
+                | JoinType::RightSemi
+                | JoinType::RightAnti
+                | JoinType::Single
+        );
+        if !supported || hash_join_desc.build_keys.is_empty() {
+            return Ok(None);
+        }
+
+        let hash_keys = |keys: &[Expr]| {
+            keys.iter()
+                .map(|key| check_function(None, "siphash", &[], &[key.clone()], &BUILTIN_FUNCTIONS))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Some(Arc::new(HashJoinSpillState {
+            operator: ctx.get_spill_operator(),
+            location_prefix: format!("_hash_join_spill/{}", ctx.get_tenant()),
+            func_ctx: ctx.get_function_context()?,
+            build_schema,
+            probe_schema,
+            hash_join_desc: hash_join_desc.duplicate(),
+            method: method.clone(),
+            build_hash_keys: hash_keys(&hash_join_desc.build_keys)?,
+            probe_hash_keys: hash_keys(&hash_join_desc.probe_keys)?,
+            threshold,
+            memory_threshold,
+            partitions: Mutex::new(BuildPartitions {
+                blocks: vec![vec![]; SPILL_PARTITIONS],
+                bytes: vec![0; SPILL_PARTITIONS],
+                total_bytes: 0,
+                spilled: vec![false; SPILL_PARTITIONS],
+                spilled_build: vec![vec![]; SPILL_PARTITIONS],
+                spilled_probe: vec![vec![]; SPILL_PARTITIONS],
+            }),
+            tasks: Mutex::new(VecDeque::new()),
+            ctx,
+        })))
+    }
+
+    /// Partition the build block, returns the rows of the spilled partitions to be written.
+    pub fn build(&self, block: DataBlock) -> Result<Vec<(usize, DataBlock)>> {
+        let mut spilling = vec![];
+        if block.is_empty() {
+            return Ok(spilling);
+        }
+
+        let scattered = self.scatter(&block, &self.build_hash_keys, 0)?;
+        let mut partitions = self.partitions.lock();
+        for (partition, block) in scattered.into_iter().enumerate() {
+            if block.is_empty() {
+                continue;
+            }
+
+            if partitions.spilled[partition] {
+                spilling.push((partition, block));
+            } else {
+                let bytes = block.memory_size();
+                partitions.bytes[partition] += bytes;
+                partitions.total_bytes += bytes;
+                partitions.blocks[partition].push(block);
+            }
+        }
+
+        // Under the memory pressure of the process, spill one partition at a time,
+        // the process memory does not drop until the blocks are written.
+        let memory_pressure = exceeds_memory_threshold(self.memory_threshold);
+        while (self.threshold != 0 && partitions.total_bytes > self.threshold) || memory_pressure {
+            let largest = (0..SPILL_PARTITIONS)
+                .filter(|partition| !partitions.spilled[*partition])
+                .max_by_key(|partition| partitions.bytes[*partition]);
+
+            let partition = match largest {
+                Some(partition) if partitions.bytes[partition] != 0 => partition,
+                _ => break,
+            };
+
+            partitions.spilled[partition] = true;
+            partitions.total_bytes -= partitions.bytes[partition];
+            partitions.bytes[partition] = 0;
+            for block in std::mem::take(&mut partitions.blocks[partition]) {
+                spilling.push((partition, block));
+            }
+
+            if memory_pressure {
+                break;
+            }
+        }
+
+        Ok(spilling)
+    }
+
+    /// Take the build blocks of the partitions kept in memory, called once the build side is finished.
+    pub fn take_in_memory_blocks(&self) -> Vec<DataBlock> {
+        let mut partitions = self.partitions.lock();
+        partitions.total_bytes = 0;
+        partitions.bytes.iter_mut().for_each(|bytes| *bytes = 0);
+        partitions
+            .blocks
+            .iter_mut()
+            .flat_map(std::mem::take)
+            .collect()
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        let partitions = self.partitions.lock();
+        partitions.spilled.iter().any(|spilled| *spilled)
+    }
+
+    /// Partition the probe block, returns the rows of the in-memory partitions
+    /// and the rows of the spilled partitions to be written.
+    pub fn probe(&self, block: DataBlock) -> Result<(Option<DataBlock>, Vec<(usize, DataBlock)>)> {
+        let scattered = self.scatter(&block, &self.probe_hash_keys, 0)?;
+        let spilled = self.partitions.lock().spilled.clone();
+
+        let mut in_memory = vec![];
+        let mut spilling = vec![];
+        for (partition, block) in scattered.into_iter().enumerate() {
+            match block.is_empty() {
+                true => continue,
+                false if spilled[partition] => spilling.push((partition, block)),
+                false => in_memory.push(block),
+            }
+        }
+
+        match in_memory.is_empty() {
+            true => Ok((None, spilling)),
+            false => Ok((Some(DataBlock::concat(&in_memory)?), spilling)),
+        }
+    }
+
+    fn add_spilled_block(&self, is_build: bool, partition: usize, block: SpilledBlock) {
+        let mut partitions = self.partitions.lock();
+        match is_build {
+            true => partitions.spilled_build[partition].push(block),
+            false => partitions.spilled_probe[partition].push(block),
+        }
+    }
+
+    /// Divide the spilled partitions into tasks, called once the probe side is finished.
+    pub fn generate_partition_tasks(&self) {
+        let mut partitions = self.partitions.lock();
+        let mut tasks = self.tasks.lock();
+        for partition in 0..SPILL_PARTITIONS {
+            if !partitions.spilled[partition] {
+                continue;
+            }
+
+            tasks.push_back(SpilledPartition {
+                level: 1,
+                build: std::mem::take(&mut partitions.spilled_build[partition]),
+                probe: std::mem::take(&mut partitions.spilled_probe[partition]),
+            });
+        }
+    }
+
+    pub fn partition_task(&self) -> Option<SpilledPartition> {
+        let mut tasks = self.tasks.lock();
+        tasks.pop_front()
+    }
+
+    /// Whether the partition has to be repartitioned before being joined in memory.
+    pub fn need_repartition(&self, task: &SpilledPartition) -> bool {
+        task.level < MAX_SPILL_LEVEL
+            && ((self.threshold != 0 && task.build_bytes() > self.threshold)
+                || exceeds_memory_threshold(self.memory_threshold))
+    }
+
+    /// Partition the rows of the spilled partition again with a new hash seed.
+    #[async_backtrace::framed]
+    pub async fn repartition(&self, task: SpilledPartition) -> Result<Vec<SpilledPartition>> {
+        let mut sub_tasks = (0..SPILL_PARTITIONS)
+            .map(|_| SpilledPartition {
+                level: task.level + 1,
+                build: vec![],
+                probe: vec![],
+            })
+            .collect::<Vec<_>>();
+
+        for spilled_block in task.build.iter() {
+            let block = self.read_block(spilled_block).await?;
+            let scattered = self.scatter(&block, &self.build_hash_keys, task.level)?;
+            for (partition, block) in scattered.into_iter().enumerate() {
+                if !block.is_empty() {
+                    sub_tasks[partition]
+                        .build
+                        .push(self.write_block(block).await?);
+                }
+            }
+        }
+
+        for spilled_block in task.probe.iter() {
+            let block = self.read_block(spilled_block).await?;
+            let scattered = self.scatter(&block, &self.probe_hash_keys, task.level)?;
+            for (partition, block) in scattered.into_iter().enumerate() {
+                if !block.is_empty() {
+                    sub_tasks[partition]
+                        .probe
+                        .push(self.write_block(block).await?);
+                }
+            }
+        }
+
+        Ok(sub_tasks
+            .into_iter()
+            .filter(|task| !task.build.is_empty() || !task.probe.is_empty())
+            .collect())
+    }
+
+    /// Read the build side of the spilled partition and create a hash table for it.
+    #[async_backtrace::framed]
+    pub async fn restore(&self, task: SpilledPartition) -> Result<PartitionJoin> {
+        let mut build_blocks = Vec::with_capacity(task.build.len());
+        for spilled_block in task.build.iter() {
+            build_blocks.push(self.read_block(spilled_block).await?);
+        }
+
+        let join_state = Arc::new(JoinHashTable::try_create(
+            self.ctx.clone(),
+            self.build_schema.clone(),
+            self.probe_schema.clone(),
+            self.hash_join_desc.duplicate(),
+            self.method.clone(),
+        )?);
+
+        Ok(PartitionJoin {
+            join_state,
+            build_blocks,
+            built: false,
+            probe_blocks: task.probe.into(),
+            outer_scanned: false,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read_block(&self, spilled_block: &SpilledBlock) -> Result<DataBlock> {
+        let data = self.operator.read(&spilled_block.location).await?;
+        if let Err(cause) = self.operator.delete(&spilled_block.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &spilled_block.location, cause
+            );
+        }
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(spilled_block.columns_layout.len());
+        for column_layout in spilled_block.columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize spill file {}",
+                        &spilled_block.location
+                    ))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+
+        Ok(DataBlock::new(columns, spilled_block.num_rows))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write_block(&self, block: DataBlock) -> Result<SpilledBlock> {
+        let instant = Instant::now();
+        let block = block.convert_to_full();
+
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut write_data = vec![];
+        for entry in block.columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let bytes = write_data.len();
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        self.operator.write(&location, write_data).await?;
+        ProfCounters::record_spill_bytes(bytes);
+
+        info!(
+            "Write hash join spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+
+        Ok(SpilledBlock {
+            location,
+            columns_layout,
+            num_rows: block.num_rows(),
+            bytes,
+        })
+    }
+
+    fn scatter(
+        &self,
+        block: &DataBlock,
+        hash_keys: &[Expr],
+        seed: usize,
+    ) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        hashes
+            .iter_mut()
+            .for_each(|hash| hash.write_u64(seed as u64));
+        for hash_key in hash_keys {
+            let values = evaluator.run(hash_key)?;
+            for (hash, value) in hashes.iter_mut().zip(get_hash_values(&values, num_rows)) {
+                hash.write_u64(value);
+            }
+        }
+
+        let indices = hashes
+            .into_iter()
+            .map(|hash| hash.finish() % SPILL_PARTITIONS as u64)
+            .collect::<Vec<_>>();
+        DataBlock::scatter(block, &indices, SPILL_PARTITIONS)
+    }
+}
+
+fn get_hash_values(values: &Value<AnyType>, num_rows: usize) -> Vec<u64> {
+    match values {
+        Value::Scalar(Scalar::Number(NumberScalar::UInt64(value))) => vec![*value; num_rows],
+        Value::Scalar(_) => vec![0; num_rows],
+        Value::Column(column) => {
+            if let Some(column) = NumberType::<u64>::try_downcast_column(column) {
+                column.iter().copied().collect()
+            } else if let Some(column) =
+                NullableType::<NumberType<u64>>::try_downcast_column(column)
+            {
+                column
+                    .column
+                    .iter()
+                    .zip(column.validity.iter())
+                    .map(|(value, valid)| if valid { *value } else { 0 })
+                    .collect()
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// Buffers the spilled rows of a processor by partition, and writes them.
+pub struct HashJoinSpiller {
+    spill_state: Arc<HashJoinSpillState>,
+    is_build: bool,
+    buffers: Vec<Vec<DataBlock>>,
+    buffer_bytes: Vec<usize>,
+}
+
+impl HashJoinSpiller {
+    pub fn create(spill_state: Arc<HashJoinSpillState>, is_build: bool) -> Self {
+        HashJoinSpiller {
+            spill_state,
+            is_build,
+            buffers: vec![vec![]; SPILL_PARTITIONS],
+            buffer_bytes: vec![0; SPILL_PARTITIONS],
+        }
+    }
+
+    pub fn spill_state(&self) -> &Arc<HashJoinSpillState> {
+        &self.spill_state
+    }
+
+    pub fn add(&mut self, blocks: Vec<(usize, DataBlock)>) {
+        for (partition, block) in blocks {
+            self.buffer_bytes[partition] += block.memory_size();
+            self.buffers[partition].push(block);
+        }
+    }
+
+    /// Whether any partition is large enough to be written.
+    pub fn need_write(&self) -> bool {
+        self.buffer_bytes
+            .iter()
+            .any(|bytes| *bytes >= SPILL_WRITE_BYTES)
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.buffer_bytes.iter().any(|bytes| *bytes != 0)
+    }
+
+    /// Write the partitions large enough, or all the buffered partitions if `flush`.
+    #[async_backtrace::framed]
+    pub async fn write(&mut self, flush: bool) -> Result<()> {
+        for partition in 0..SPILL_PARTITIONS {
+            let bytes = self.buffer_bytes[partition];
+            if bytes == 0 || (!flush && bytes < SPILL_WRITE_BYTES) {
+                continue;
+            }
+
+            let block = DataBlock::concat(&std::mem::take(&mut self.buffers[partition]))?;
+            self.buffer_bytes[partition] = 0;
+
+            let spilled_block = self.spill_state.write_block(block).await?;
+            self.spill_state
+                .add_spilled_block(self.is_build, partition, spilled_block);
+        }
+        Ok(())
+    }
+}
+
+/// The join of one spilled partition, using a hash table of its own.
+pub struct PartitionJoin {
+    pub join_state: Arc<JoinHashTable>,
+    build_blocks: Vec<DataBlock>,
+    built: bool,
+    probe_blocks: VecDeque<SpilledBlock>,
+    outer_scanned: bool,
+}
+
+impl PartitionJoin {
+    /// Build the hash table of the partition, it is only driven by the current processor.
+    pub fn build(&mut self) -> Result<()> {
+        if self.built {
+            return Ok(());
+        }
+
+        self.join_state.build_attach()?;
+        for block in std::mem::take(&mut self.build_blocks) {
+            self.join_state.build(block)?;
+        }
+        self.join_state.build_done()?;
+        while let Some(task) = self.join_state.finalize_task() {
+            self.join_state.finalize(task)?;
+        }
+        self.join_state.finalize_done()?;
+        self.join_state.probe_attach()?;
+        self.built = true;
+        Ok(())
+    }
+
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+
+    pub fn next_probe_block(&mut self) -> Option<SpilledBlock> {
+        self.probe_blocks.pop_front()
+    }
+
+    pub fn is_probe_finished(&self) -> bool {
+        self.probe_blocks.is_empty()
+    }
+
+    pub fn is_outer_scanned(&self) -> bool {
+        self.outer_scanned
+    }
+
+    pub fn set_outer_scanned(&mut self) {
+        self.outer_scanned = true;
+    }
+}
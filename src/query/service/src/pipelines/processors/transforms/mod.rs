@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod aggregator;
+mod distinct;
 pub mod group_by;
 pub(crate) mod hash_join;
 mod transform_cast_schema;
@@ -24,18 +25,25 @@ mod window;
 
 mod range_join;
 mod runtime_filter;
+mod sort;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_ie_join;
 mod transform_merge_block;
+mod transform_merge_into;
+mod transform_recursive_union;
 mod transform_resort_addon;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_merge_join;
 
 pub use aggregator::build_partition_bucket;
+pub use aggregator::enable_aggregate_spilling;
+pub use aggregator::spilling_memory_threshold;
 pub use aggregator::AggregateInjector;
 pub use aggregator::AggregatorParams;
 pub use aggregator::FinalSingleStateAggregator;
+pub use aggregator::GroupDictionary;
 pub use aggregator::HashTableCell;
 pub use aggregator::PartialSingleStateAggregator;
 pub use aggregator::PartitionedHashTableDropper;
@@ -43,6 +51,8 @@ pub use aggregator::TransformAggregateDeserializer;
 pub use aggregator::TransformAggregateSerializer;
 pub use aggregator::TransformAggregateSpillReader;
 pub use aggregator::TransformAggregateSpillWriter;
+pub use aggregator::TransformDecodeGroupDictionary;
+pub use aggregator::TransformEncodeGroupDictionary;
 pub use aggregator::TransformExpandGroupingSets;
 pub use aggregator::TransformFinalAggregate;
 pub use aggregator::TransformGroupByDeserializer;
@@ -55,12 +65,20 @@ use common_pipeline_transforms::processors::transforms::transform;
 use common_pipeline_transforms::processors::transforms::transform_block_compact;
 use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
+pub use distinct::TransformDistinctFinal;
+pub use distinct::TransformDistinctPartial;
+pub use hash_join::AdaptiveJoinBuild;
+pub use hash_join::AdaptiveJoinState;
 pub use hash_join::FixedKeyHashJoinHashTable;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::JoinHashTable;
 pub use range_join::RangeJoinState;
 pub use runtime_filter::RuntimeFilterState;
+pub use sort::sort_spilling_memory_threshold;
+pub use sort::support_sort_spilling;
+pub use sort::TransformSortMergeRuns;
+pub use sort::TransformSortSpill;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_block_compact::BlockCompactor;
@@ -78,10 +96,14 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_merge_into::TransformMergeInto;
+pub use transform_recursive_union::RecursiveUnionSource;
+pub use transform_recursive_union::WorkingTables;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_sort_merge_join::TransformSortMergeJoin;
 pub use transform_sort_partial::TransformSortPartial;
 pub use window::FrameBound;
 pub use window::TransformWindow;
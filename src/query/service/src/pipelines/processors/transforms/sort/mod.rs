@@ -0,0 +1,53 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod sort_meta;
+mod transform_sort_merge_runs;
+mod transform_sort_spill;
+
+use common_exception::Result;
+use common_expression::row::RowConverter;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_expression::SortField;
+use common_settings::Settings;
+pub use sort_meta::SortedRunBlock;
+pub use sort_meta::SortedRunsMeta;
+pub use sort_meta::SpilledSortBlock;
+pub use transform_sort_merge_runs::TransformSortMergeRuns;
+pub use transform_sort_spill::TransformSortSpill;
+
+/// The process memory usage above which the sorts spill, `usize::MAX` if disabled.
+pub fn sort_spilling_memory_threshold(settings: &Settings) -> Result<usize> {
+    let ratio = std::cmp::min(settings.get_sort_spilling_memory_ratio()?, 100) as usize;
+    Ok(match ratio {
+        0 => usize::MAX,
+        _ => settings.get_max_memory_usage()? as usize / 100 * ratio,
+    })
+}
+
+/// Whether the sort keys can be compared in the row format, which the merge of the sorted runs relies on.
+pub fn support_sort_spilling(schema: &DataSchemaRef, sort_desc: &[SortColumnDescription]) -> bool {
+    RowConverter::new(sort_fields(schema, sort_desc)).is_ok()
+}
+
+fn sort_fields(schema: &DataSchemaRef, sort_desc: &[SortColumnDescription]) -> Vec<SortField> {
+    sort_desc
+        .iter()
+        .map(|desc| {
+            let data_type = schema.field(desc.offset).data_type().clone();
+            SortField::new_with_options(data_type, desc.asc, desc.nulls_first)
+        })
+        .collect()
+}
@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoPtr;
+use common_expression::DataBlock;
+
+/// A sorted block written to the spill storage.
+pub struct SpilledSortBlock {
+    pub location: String,
+    pub columns_layout: Vec<usize>,
+    pub num_rows: usize,
+}
+
+pub enum SortedRunBlock {
+    Memory(DataBlock),
+    Spilled(SpilledSortBlock),
+}
+
+/// The sorted runs of a [`super::TransformSortSpill`], each run is a sequence of sorted blocks.
+pub struct SortedRunsMeta {
+    pub runs: Vec<VecDeque<SortedRunBlock>>,
+}
+
+impl SortedRunsMeta {
+    pub fn create(runs: Vec<VecDeque<SortedRunBlock>>) -> BlockMetaInfoPtr {
+        Box::new(SortedRunsMeta { runs })
+    }
+}
+
+impl serde::Serialize for SortedRunsMeta {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unreachable!("SortedRunsMeta does not support exchanging between multiple nodes")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SortedRunsMeta {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unreachable!("SortedRunsMeta does not support exchanging between multiple nodes")
+    }
+}
+
+impl Debug for SortedRunsMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortedRunsMeta")
+            .field("runs", &self.runs.len())
+            .finish()
+    }
+}
+
+impl BlockMetaInfo for SortedRunsMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("SortedRunsMeta does not support exchanging between multiple nodes")
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        unimplemented!("SortedRunsMeta does not support exchanging between multiple nodes")
+    }
+
+    fn equals(&self, _: &Box<dyn BlockMetaInfo>) -> bool {
+        unimplemented!("Unimplemented equals for SortedRunsMeta")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unimplemented!("Unimplemented clone for SortedRunsMeta")
+    }
+}
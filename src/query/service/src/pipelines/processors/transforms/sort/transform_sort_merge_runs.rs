@@ -0,0 +1,276 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::row::RowConverter;
+use common_expression::types::string::StringColumn;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort_merge;
+use opendal::Operator;
+use tracing::error;
+
+use super::sort_fields;
+use super::SortedRunBlock;
+use super::SortedRunsMeta;
+use super::SpilledSortBlock;
+
+struct SortedRun {
+    current: Option<DataBlock>,
+    blocks: VecDeque<SortedRunBlock>,
+}
+
+impl SortedRun {
+    fn is_finished(&self) -> bool {
+        self.current.is_none() && self.blocks.is_empty()
+    }
+
+    fn need_read(&self) -> bool {
+        self.current.is_none() && matches!(self.blocks.front(), Some(SortedRunBlock::Spilled(_)))
+    }
+}
+
+/// K-way merge of the sorted runs of all the [`super::TransformSortSpill`], it only keeps
+/// one block of each run in memory.
+///
+/// Each round takes the smallest last row of the current blocks as a bound: no row after it
+/// can be smaller in any run, so the rows up to the bound of all the current blocks are merged
+/// and output. The run holding the bound is drained and its next block is read.
+pub struct TransformSortMergeRuns {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    block_size: usize,
+    row_converter: RowConverter,
+    operator: Operator,
+
+    runs: Vec<SortedRun>,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformSortMergeRuns {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        operator: Operator,
+    ) -> Result<Box<dyn Processor>> {
+        let row_converter = RowConverter::new(sort_fields(&schema, &sort_desc))?;
+        Ok(Box::new(TransformSortMergeRuns {
+            input,
+            output,
+            schema,
+            sort_desc,
+            block_size,
+            row_converter,
+            operator,
+            runs: vec![],
+            output_blocks: VecDeque::new(),
+        }))
+    }
+
+    fn convert_rows(&self, block: &DataBlock) -> StringColumn {
+        let columns = self
+            .sort_desc
+            .iter()
+            .map(|desc| {
+                let entry = block.get_by_offset(desc.offset);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows())
+            })
+            .collect::<Vec<_>>();
+        self.row_converter
+            .convert_columns(&columns, block.num_rows())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_block(&self, spilled_block: &SpilledSortBlock) -> Result<DataBlock> {
+        let data = self.operator.read(&spilled_block.location).await?;
+        if let Err(cause) = self.operator.delete(&spilled_block.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                &spilled_block.location, cause
+            );
+        }
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(spilled_block.columns_layout.len());
+        for column_layout in spilled_block.columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Cannot deserialize spill file {}",
+                        &spilled_block.location
+                    ))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+
+        Ok(DataBlock::new(columns, spilled_block.num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortMergeRuns {
+    fn name(&self) -> String {
+        String::from("TransformSortMergeRuns")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(output_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let mut block = self.input.pull_data().unwrap()?;
+            if let Some(meta) = block.take_meta().and_then(SortedRunsMeta::downcast_from) {
+                self.runs
+                    .extend(meta.runs.into_iter().map(|blocks| SortedRun {
+                        current: None,
+                        blocks,
+                    }));
+            }
+        }
+
+        // The merge starts once all the runs are received.
+        if !self.input.is_finished() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        for run in self.runs.iter_mut() {
+            if run.current.is_none() {
+                if let Some(SortedRunBlock::Memory(_)) = run.blocks.front() {
+                    if let Some(SortedRunBlock::Memory(block)) = run.blocks.pop_front() {
+                        run.current = Some(block);
+                    }
+                }
+            }
+        }
+
+        if self.runs.iter().any(SortedRun::need_read) {
+            return Ok(Event::Async);
+        }
+
+        self.runs.retain(|run| !run.is_finished());
+        if self.runs.is_empty() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::Sync)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if self.runs.len() == 1 {
+            let block = self.runs[0].current.take().unwrap();
+            self.output_blocks.push_back(block);
+            return Ok(());
+        }
+
+        let rows = self
+            .runs
+            .iter()
+            .map(|run| self.convert_rows(run.current.as_ref().unwrap()))
+            .collect::<Vec<_>>();
+        let bound = rows
+            .iter()
+            .map(|rows| rows.index(rows.len() - 1).unwrap())
+            .min()
+            .unwrap();
+
+        let mut blocks = Vec::with_capacity(self.runs.len());
+        for (run, rows) in self.runs.iter_mut().zip(rows.iter()) {
+            // The number of rows not greater than the bound, the rows are sorted.
+            let (mut low, mut high) = (0, rows.len());
+            while low < high {
+                let mid = (low + high) / 2;
+                match rows.index(mid).unwrap() <= bound {
+                    true => low = mid + 1,
+                    false => high = mid,
+                }
+            }
+
+            if low == 0 {
+                continue;
+            }
+
+            let block = run.current.take().unwrap();
+            if low < block.num_rows() {
+                run.current = Some(block.slice(low..block.num_rows()));
+            }
+            blocks.push(block.slice(0..low));
+        }
+
+        self.output_blocks.extend(sort_merge(
+            self.schema.clone(),
+            self.block_size,
+            self.sort_desc.clone(),
+            &blocks,
+        )?);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        for index in 0..self.runs.len() {
+            if !self.runs[index].need_read() {
+                continue;
+            }
+
+            if let Some(SortedRunBlock::Spilled(spilled_block)) =
+                self.runs[index].blocks.pop_front()
+            {
+                let block = self.read_block(&spilled_block).await?;
+                self.runs[index].current = Some(block);
+            }
+        }
+
+        Ok(())
+    }
+}
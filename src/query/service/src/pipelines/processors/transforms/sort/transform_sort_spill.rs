@@ -0,0 +1,226 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_exception::Result;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort_merge;
+use common_profile::ProfCounters;
+use opendal::Operator;
+use tracing::info;
+
+use super::SortedRunBlock;
+use super::SortedRunsMeta;
+use super::SpilledSortBlock;
+use crate::pipelines::processors::transforms::aggregator::exceeds_memory_threshold;
+
+/// Buffers the partially sorted blocks of one pipeline. Once the process memory exceeds
+/// `sort_spilling_memory_ratio`, the buffered blocks are merged into a sorted run and
+/// written to the spill storage.
+///
+/// When the input is finished, the remaining blocks are merged into a last in-memory run,
+/// and all the runs are sent to [`super::TransformSortMergeRuns`] as a [`SortedRunsMeta`].
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    block_size: usize,
+
+    operator: Operator,
+    location_prefix: String,
+    memory_threshold: usize,
+
+    blocks: Vec<DataBlock>,
+    num_rows: usize,
+    runs: Vec<VecDeque<SortedRunBlock>>,
+    input_finished: bool,
+    spilling_run: Option<Vec<DataBlock>>,
+    output_block: Option<DataBlock>,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        sort_desc: Vec<SortColumnDescription>,
+        block_size: usize,
+        operator: Operator,
+        location_prefix: String,
+        memory_threshold: usize,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSortSpill {
+            input,
+            output,
+            schema,
+            sort_desc,
+            block_size,
+            operator,
+            location_prefix,
+            memory_threshold,
+            blocks: vec![],
+            num_rows: 0,
+            runs: vec![],
+            input_finished: false,
+            spilling_run: None,
+            output_block: None,
+        })
+    }
+
+    fn need_spill(&self) -> bool {
+        // Avoid writing tiny runs, each run costs a block of memory while merging.
+        self.num_rows >= self.block_size && exceeds_memory_threshold(self.memory_threshold)
+    }
+
+    #[async_backtrace::framed]
+    async fn write_block(&self, block: DataBlock) -> Result<SpilledSortBlock> {
+        let block = block.convert_to_full();
+
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut write_data = vec![];
+        for entry in block.columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let bytes = write_data.len();
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        self.operator.write(&location, write_data).await?;
+        ProfCounters::record_spill_bytes(bytes);
+
+        Ok(SpilledSortBlock {
+            location,
+            columns_layout,
+            num_rows: block.num_rows(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_block) = self.output_block.take() {
+            self.output.push_data(Ok(output_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.spilling_run.is_some() {
+            self.input.set_not_need_data();
+            return Ok(Event::Async);
+        }
+
+        if self.input.has_data() {
+            let block = self.input.pull_data().unwrap()?;
+            if block.num_rows() > 0 {
+                self.num_rows += block.num_rows();
+                self.blocks.push(block);
+            }
+        }
+
+        if self.input.is_finished() {
+            if self.blocks.is_empty() && self.runs.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+
+            self.input_finished = true;
+            return Ok(Event::Sync);
+        }
+
+        if self.need_spill() {
+            self.input.set_not_need_data();
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.blocks);
+        self.num_rows = 0;
+        let run = sort_merge(
+            self.schema.clone(),
+            self.block_size,
+            self.sort_desc.clone(),
+            &blocks,
+        )?;
+
+        if !self.input_finished {
+            self.spilling_run = Some(run);
+            return Ok(());
+        }
+
+        if !run.is_empty() {
+            self.runs
+                .push(run.into_iter().map(SortedRunBlock::Memory).collect());
+        }
+        let runs = std::mem::take(&mut self.runs);
+        self.output_block = Some(DataBlock::empty_with_meta(SortedRunsMeta::create(runs)));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(run) = self.spilling_run.take() {
+            let instant = Instant::now();
+            let mut spilled_run = VecDeque::with_capacity(run.len());
+            for block in run {
+                spilled_run.push_back(SortedRunBlock::Spilled(self.write_block(block).await?));
+            }
+
+            info!(
+                "Write sort spill run of {} blocks successfully, elapsed: {:?}",
+                spilled_run.len(),
+                instant.elapsed()
+            );
+            self.runs.push(spilled_run);
+        }
+
+        Ok(())
+    }
+}
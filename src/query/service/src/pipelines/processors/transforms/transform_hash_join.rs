@@ -20,7 +20,10 @@ use common_exception::Result;
 use common_expression::DataBlock;
 use common_sql::plans::JoinType;
 
+use super::hash_join::HashJoinSpiller;
+use super::hash_join::PartitionJoin;
 use super::hash_join::ProbeState;
+use super::hash_join::SpilledPartition;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -34,6 +37,8 @@ enum HashJoinStep {
     Finalize,
     Probe,
     OuterScan,
+    /// Join the spilled partitions one by one.
+    Restore,
 }
 
 pub struct TransformHashJoinProbe {
@@ -47,6 +52,14 @@ pub struct TransformHashJoinProbe {
     probe_state: ProbeState,
     block_size: u64,
     outer_scan_finished: bool,
+    probed_rows: usize,
+    /// Only set if the build side has been spilled
+    spiller: Option<HashJoinSpiller>,
+    /// Write the spilled probe rows, all of them if true
+    spill_flush: Option<bool>,
+    partition_tasks: Vec<SpilledPartition>,
+    partition_join: Option<PartitionJoin>,
+    partitions_finished: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -69,6 +82,12 @@ impl TransformHashJoinProbe {
             probe_state: ProbeState::create(join_type, with_conjunct, ctx.get_function_context()?),
             block_size: default_block_size,
             outer_scan_finished: false,
+            probed_rows: 0,
+            spiller: None,
+            spill_flush: None,
+            partition_tasks: vec![],
+            partition_join: None,
+            partitions_finished: false,
         }))
     }
 
@@ -79,6 +98,7 @@ impl TransformHashJoinProbe {
 
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
         self.probe_state.clear();
+        self.probed_rows += block.num_rows();
         self.output_data_blocks
             .extend(self.join_state.probe(block, &mut self.probe_state)?);
         Ok(())
@@ -126,6 +146,13 @@ impl Processor for TransformHashJoinProbe {
                     return Ok(Event::Sync);
                 }
 
+                if let Some(spiller) = &self.spiller {
+                    if spiller.need_write() {
+                        self.spill_flush = Some(false);
+                        return Ok(Event::Async);
+                    }
+                }
+
                 if self.input_port.has_data() {
                     let data = self.input_port.pull_data().unwrap()?;
                     // Split data to `block_size` rows per sub block.
@@ -138,7 +165,16 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
-                    if self.join_state.need_outer_scan() {
+                    if let Some(spiller) = &self.spiller {
+                        if spiller.has_pending() {
+                            self.spill_flush = Some(true);
+                            return Ok(Event::Async);
+                        }
+                    }
+
+                    self.join_state.report_probed_rows(self.probed_rows);
+                    // Wait for all the spilled probe rows before joining the spilled partitions.
+                    if self.join_state.need_outer_scan() || self.spiller.is_some() {
                         self.join_state.probe_done()?;
                         return Ok(Event::Async);
                     } else {
@@ -169,12 +205,53 @@ impl Processor for TransformHashJoinProbe {
 
                 match self.outer_scan_finished {
                     false => Ok(Event::Sync),
+                    true if self.spiller.is_some() => {
+                        self.step = HashJoinStep::Restore;
+                        Ok(Event::Async)
+                    }
                     true => {
                         self.output_port.finish();
                         Ok(Event::Finished)
                     }
                 }
             }
+            HashJoinStep::Restore => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.output_data_blocks.is_empty() {
+                    let data = self.output_data_blocks.pop_front().unwrap();
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if !self.input_data.is_empty() {
+                    return Ok(Event::Sync);
+                }
+
+                match &self.partition_join {
+                    None if self.partitions_finished => {
+                        self.output_port.finish();
+                        Ok(Event::Finished)
+                    }
+                    // Fetch the next spilled partition.
+                    None => Ok(Event::Async),
+                    Some(partition_join) if !partition_join.is_built() => Ok(Event::Sync),
+                    // Read the next spilled probe block.
+                    Some(partition_join) if !partition_join.is_probe_finished() => Ok(Event::Async),
+                    Some(partition_join) if !partition_join.is_outer_scanned() => Ok(Event::Sync),
+                    Some(_) => {
+                        self.partition_join = None;
+                        Ok(Event::Async)
+                    }
+                }
+            }
         }
     }
 
@@ -189,7 +266,18 @@ impl Processor for TransformHashJoinProbe {
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.pop_front() {
                     let data = data.convert_to_full();
-                    self.probe(&data)?;
+                    // The rows of the spilled partitions are probed later.
+                    let data = match &mut self.spiller {
+                        Some(spiller) => {
+                            let (data, spilling) = spiller.spill_state().probe(data)?;
+                            spiller.add(spilling);
+                            data
+                        }
+                        None => Some(data),
+                    };
+                    if let Some(data) = data {
+                        self.probe(&data)?;
+                    }
                 }
                 Ok(())
             }
@@ -201,6 +289,31 @@ impl Processor for TransformHashJoinProbe {
                 }
                 Ok(())
             }
+            HashJoinStep::Restore => {
+                if let Some(partition_join) = &mut self.partition_join {
+                    if !partition_join.is_built() {
+                        partition_join.build()?;
+                    } else if let Some(data) = self.input_data.pop_front() {
+                        self.probe_state.clear();
+                        self.output_data_blocks.extend(
+                            partition_join
+                                .join_state
+                                .probe(&data, &mut self.probe_state)?,
+                        );
+                    } else if partition_join.is_probe_finished() {
+                        let join_state = partition_join.join_state.clone();
+                        if join_state.need_outer_scan() {
+                            join_state.probe_done()?;
+                            while let Some(task) = join_state.outer_scan_task() {
+                                self.output_data_blocks
+                                    .extend(join_state.outer_scan(task, &mut self.probe_state)?);
+                            }
+                        }
+                        partition_join.set_outer_scanned();
+                    }
+                }
+                Ok(())
+            }
         }
     }
 
@@ -209,14 +322,63 @@ impl Processor for TransformHashJoinProbe {
         match self.step {
             HashJoinStep::Build => {
                 self.join_state.wait_finalize_finish().await?;
+                if let Some(spill_state) = self.join_state.spill_state() {
+                    if spill_state.is_spilled() {
+                        self.spiller = Some(HashJoinSpiller::create(spill_state, false));
+                    }
+                }
                 self.step = HashJoinStep::Probe;
             }
             HashJoinStep::Finalize => unreachable!(),
             HashJoinStep::Probe => {
+                if let Some(flush) = self.spill_flush.take() {
+                    if let Some(spiller) = &mut self.spiller {
+                        spiller.write(flush).await?;
+                    }
+                    return Ok(());
+                }
+
                 self.join_state.wait_probe_finish().await?;
-                self.step = HashJoinStep::OuterScan;
+                self.step = match self.join_state.need_outer_scan() {
+                    true => HashJoinStep::OuterScan,
+                    false => HashJoinStep::Restore,
+                };
             }
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Restore => {
+                let spill_state = match self.join_state.spill_state() {
+                    Some(spill_state) => spill_state,
+                    None => unreachable!(),
+                };
+
+                if let Some(partition_join) = &mut self.partition_join {
+                    if let Some(spilled_block) = partition_join.next_probe_block() {
+                        let data = spill_state.read_block(&spilled_block).await?;
+                        // Split data to `block_size` rows per sub block.
+                        let (sub_blocks, remain_block) =
+                            data.split_by_rows(self.block_size as usize);
+                        self.input_data.extend(sub_blocks);
+                        if let Some(remain) = remain_block {
+                            self.input_data.push_back(remain);
+                        }
+                    }
+                    return Ok(());
+                }
+
+                // The sub partitions of a repartitioned partition are joined by the same processor.
+                let task = match self.partition_tasks.pop() {
+                    Some(task) => Some(task),
+                    None => spill_state.partition_task(),
+                };
+                match task {
+                    None => self.partitions_finished = true,
+                    Some(task) if spill_state.need_repartition(&task) => {
+                        let sub_tasks = spill_state.repartition(task).await?;
+                        self.partition_tasks.extend(sub_tasks);
+                    }
+                    Some(task) => self.partition_join = Some(spill_state.restore(task).await?),
+                }
+            }
         };
         Ok(())
     }
@@ -229,6 +391,10 @@ pub struct TransformHashJoinBuild {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     finalize_finished: bool,
+    /// Only set if the join may spill
+    spiller: Option<HashJoinSpiller>,
+    /// Write the spilled build rows, all of them if true
+    spill_flush: Option<bool>,
 }
 
 impl TransformHashJoinBuild {
@@ -236,12 +402,17 @@ impl TransformHashJoinBuild {
         input_port: Arc<InputPort>,
         join_state: Arc<dyn HashJoinState>,
     ) -> Box<dyn Processor> {
+        let spiller = join_state
+            .spill_state()
+            .map(|spill_state| HashJoinSpiller::create(spill_state, true));
         Box::new(TransformHashJoinBuild {
             input_port,
             input_data: None,
             step: HashJoinStep::Build,
             join_state,
             finalize_finished: false,
+            spiller,
+            spill_flush: None,
         })
     }
 
@@ -268,7 +439,22 @@ impl Processor for TransformHashJoinBuild {
                     return Ok(Event::Sync);
                 }
 
+                if let Some(spiller) = &self.spiller {
+                    if spiller.need_write() {
+                        self.spill_flush = Some(false);
+                        return Ok(Event::Async);
+                    }
+                }
+
                 if self.input_port.is_finished() {
+                    // Write all the spilled build rows before the build side is done.
+                    if let Some(spiller) = &self.spiller {
+                        if spiller.has_pending() {
+                            self.spill_flush = Some(true);
+                            return Ok(Event::Async);
+                        }
+                    }
+
                     self.join_state.build_done()?;
                     return Ok(Event::Async);
                 }
@@ -290,6 +476,7 @@ impl Processor for TransformHashJoinBuild {
             },
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
@@ -301,7 +488,14 @@ impl Processor for TransformHashJoinBuild {
         match self.step {
             HashJoinStep::Build => {
                 if let Some(data_block) = self.input_data.take() {
-                    self.join_state.build(data_block)?;
+                    let data_block = self.join_state.partition_build_block(data_block)?;
+                    match &mut self.spiller {
+                        Some(spiller) => {
+                            let spilling = spiller.spill_state().build(data_block)?;
+                            spiller.add(spilling);
+                        }
+                        None => self.join_state.build(data_block)?,
+                    }
                 }
                 Ok(())
             }
@@ -315,11 +509,19 @@ impl Processor for TransformHashJoinBuild {
             }
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if let Some(flush) = self.spill_flush.take() {
+            if let Some(spiller) = &mut self.spiller {
+                spiller.write(flush).await?;
+            }
+            return Ok(());
+        }
+
         if let HashJoinStep::Build = &self.step {
             self.join_state.wait_build_finish().await?;
             self.step = HashJoinStep::Finalize;
@@ -0,0 +1,258 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::Processor;
+
+/// Applies the `WHEN [NOT] MATCHED` clauses of MERGE INTO to the rows of the join.
+///
+/// A row is matched if its `_row_id` of the target table is not NULL, and it's handled
+/// by the first clause of its kind whose condition is satisfied. The output ports are:
+/// (0) -> the updated and inserted rows, in the schema of the target table
+/// (1) -> the `_row_id` of the updated and deleted rows
+pub struct TransformMergeInto {
+    func_ctx: FunctionContext,
+    row_id_offset: usize,
+    matched: Vec<(Option<Expr>, Option<Vec<Expr>>)>,
+    unmatched: Vec<(Option<Expr>, Vec<Expr>)>,
+
+    input_port: Arc<InputPort>,
+    output_port_append_data: Arc<OutputPort>,
+    output_port_row_id: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data_append: Option<DataBlock>,
+    output_data_row_id: Option<DataBlock>,
+}
+
+impl TransformMergeInto {
+    pub fn create(
+        func_ctx: FunctionContext,
+        row_id_offset: usize,
+        matched: Vec<(Option<Expr>, Option<Vec<Expr>>)>,
+        unmatched: Vec<(Option<Expr>, Vec<Expr>)>,
+    ) -> Self {
+        Self {
+            func_ctx,
+            row_id_offset,
+            matched,
+            unmatched,
+            input_port: InputPort::create(),
+            output_port_append_data: OutputPort::create(),
+            output_port_row_id: OutputPort::create(),
+            input_data: None,
+            output_data_append: None,
+            output_data_row_id: None,
+        }
+    }
+
+    pub fn into_pipe(self) -> Pipe {
+        let input = self.input_port.clone();
+        let output_port_append_data = self.output_port_append_data.clone();
+        let output_port_row_id = self.output_port_row_id.clone();
+        let processor_ptr = ProcessorPtr::create(Box::new(self));
+        let pipe_item = PipeItem::create(processor_ptr, vec![input], vec![
+            output_port_append_data,
+            output_port_row_id,
+        ]);
+        Pipe::create(1, 2, vec![pipe_item])
+    }
+
+    /// Split the block into the rows satisfying `condition` and the rest.
+    fn split_block(
+        &self,
+        block: DataBlock,
+        condition: &Option<Expr>,
+    ) -> Result<(DataBlock, DataBlock)> {
+        let num_rows = block.num_rows();
+        let predicate = match condition {
+            Some(expr) => {
+                let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                evaluator.run(expr)?.try_downcast::<BooleanType>().unwrap()
+            }
+            None => Value::Scalar(true),
+        };
+        let bitmap: Bitmap = FilterHelpers::filter_to_bitmap(predicate, num_rows).into();
+        let hit = block.clone().filter_with_bitmap(&bitmap)?;
+        let rest = block.filter_with_bitmap(&!&bitmap)?;
+        Ok((hit, rest))
+    }
+
+    /// Evaluate the values of the target table columns.
+    fn evaluate_block(&self, block: &DataBlock, exprs: &[Expr]) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut entries = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            let data_type = expr.data_type().clone();
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(&data_type, num_rows);
+            entries.push(BlockEntry::new(data_type, Value::Column(column)));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+
+    fn merge(&self, block: DataBlock) -> Result<(Vec<DataBlock>, Vec<u64>)> {
+        let num_rows = block.num_rows();
+        let mut append_blocks = Vec::new();
+        let mut row_ids = Vec::new();
+
+        let entry = block.get_by_offset(self.row_id_offset);
+        let row_id_column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let matched = match row_id_column.as_nullable() {
+            Some(column) => column.validity.clone(),
+            None => FilterHelpers::filter_to_bitmap(Value::Scalar(true), num_rows).into(),
+        };
+        let mut matched_block = block.clone().filter_with_bitmap(&matched)?;
+        let mut unmatched_block = block.filter_with_bitmap(&!&matched)?;
+
+        for (condition, update) in self.matched.iter() {
+            if matched_block.num_rows() == 0 {
+                break;
+            }
+            let (hit, rest) = self.split_block(matched_block, condition)?;
+            matched_block = rest;
+            if hit.num_rows() == 0 {
+                continue;
+            }
+
+            let entry = hit.get_by_offset(self.row_id_offset);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, hit.num_rows())
+                .remove_nullable();
+            row_ids.extend(UInt64Type::try_downcast_column(&column).unwrap().iter());
+            if let Some(values) = update {
+                append_blocks.push(self.evaluate_block(&hit, values)?);
+            }
+        }
+
+        for (condition, values) in self.unmatched.iter() {
+            if unmatched_block.num_rows() == 0 {
+                break;
+            }
+            let (hit, rest) = self.split_block(unmatched_block, condition)?;
+            unmatched_block = rest;
+            if hit.num_rows() > 0 {
+                append_blocks.push(self.evaluate_block(&hit, values)?);
+            }
+        }
+
+        Ok((append_blocks, row_ids))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformMergeInto {
+    fn name(&self) -> String {
+        "TransformMergeInto".to_owned()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        let finished = self.input_port.is_finished()
+            && self.output_data_append.is_none()
+            && self.output_data_row_id.is_none();
+
+        if finished {
+            self.output_port_append_data.finish();
+            self.output_port_row_id.finish();
+            return Ok(Event::Finished);
+        }
+
+        let mut pushed_something = false;
+        if self.output_port_append_data.can_push() {
+            if let Some(data) = self.output_data_append.take() {
+                self.output_port_append_data.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if self.output_port_row_id.can_push() {
+            if let Some(data) = self.output_data_row_id.take() {
+                self.output_port_row_id.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if pushed_something
+            || self.output_data_append.is_some()
+            || self.output_data_row_id.is_some()
+        {
+            // data pending
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.has_data() {
+            self.input_data = Some(self.input_port.pull_data().unwrap()?);
+            Ok(Event::Sync)
+        } else {
+            self.input_port.set_need_data();
+            Ok(Event::NeedData)
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            if data_block.num_rows() == 0 {
+                return Ok(());
+            }
+
+            let (append_blocks, row_ids) = self.merge(data_block)?;
+            if !append_blocks.is_empty() {
+                self.output_data_append = Some(DataBlock::concat(&append_blocks)?);
+            }
+            if !row_ids.is_empty() {
+                self.output_data_row_id =
+                    Some(DataBlock::new_from_columns(vec![UInt64Type::from_data(
+                        row_ids,
+                    )]));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::HashMethod;
+use common_expression::HashMethodSerializer;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveUnion;
+use common_sql::IndexType;
+use futures_util::TryStreamExt;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::stream::PullingExecutorStream;
+
+/// Rows of the working tables of the recursive CTEs being evaluated, indexed by `cte_idx`.
+pub type WorkingTables = HashMap<IndexType, Arc<Vec<DataBlock>>>;
+
+/// Evaluates a recursive CTE to its fixpoint.
+///
+/// The anchor member is executed first, then the recursive member is executed
+/// repeatedly against the rows produced by the previous iteration, until an
+/// iteration produces no new rows. Each iteration is emitted as soon as it's done.
+pub struct RecursiveUnionSource {
+    ctx: Arc<QueryContext>,
+    plan: RecursiveUnion,
+    enable_profiling: bool,
+    prof_span_set: ProfSpanSetRef,
+    working_tables: WorkingTables,
+
+    max_depth: u64,
+    iterations: u64,
+    // Rows produced by the previous iteration, `None` before the anchor member is executed.
+    working_table: Option<Arc<Vec<DataBlock>>>,
+    // Serialized rows produced so far, only used by `UNION`.
+    seen_rows: HashSet<Vec<u8>>,
+}
+
+impl RecursiveUnionSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        plan: RecursiveUnion,
+        enable_profiling: bool,
+        prof_span_set: ProfSpanSetRef,
+        working_tables: WorkingTables,
+    ) -> Result<ProcessorPtr> {
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()?;
+        AsyncSourcer::create(ctx.clone(), output, RecursiveUnionSource {
+            ctx,
+            plan,
+            enable_profiling,
+            prof_span_set,
+            working_tables,
+            max_depth,
+            iterations: 0,
+            working_table: None,
+            seen_rows: HashSet::new(),
+        })
+    }
+
+    async fn execute(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut builder = PipelineBuilder::create(
+            ctx.clone(),
+            self.enable_profiling,
+            self.prof_span_set.clone(),
+        );
+        builder.working_tables = self.working_tables.clone();
+        if let Some(working_table) = &self.working_table {
+            builder
+                .working_tables
+                .insert(self.plan.cte_idx, working_table.clone());
+        }
+
+        let mut build_res = builder.finalize(plan)?;
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        PullingExecutorStream::create(executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await
+    }
+
+    /// Reorder the columns as the output of `RecursiveUnion`, and discard the rows
+    /// that have been produced before if needed.
+    fn project_block(&mut self, block: DataBlock, is_anchor: bool) -> Result<DataBlock> {
+        let schema = if is_anchor {
+            self.plan.anchor.output_schema()?
+        } else {
+            self.plan.recursive.output_schema()?
+        };
+        let num_rows = block.num_rows();
+        let columns = self
+            .plan
+            .pairs
+            .iter()
+            .map(|(anchor, recursive)| {
+                let name = if is_anchor { anchor } else { recursive };
+                Ok(block.get_by_offset(schema.index_of(name)?).clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let block = DataBlock::new(columns, num_rows);
+
+        if !self.plan.distinct || num_rows == 0 {
+            return Ok(block);
+        }
+
+        let keys = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                (
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows),
+                    entry.data_type.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let method = HashMethodSerializer::default();
+        let keys_state = method.build_keys_state(&keys, num_rows)?;
+        let mut new_rows = MutableBitmap::with_capacity(num_rows);
+        for key in method.build_keys_iter(&keys_state)? {
+            new_rows.push(self.seen_rows.insert(key.to_vec()));
+        }
+        let new_rows: Bitmap = new_rows.into();
+        block.filter_with_bitmap(&new_rows)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveUnionSource {
+    const NAME: &'static str = "RecursiveUnionSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let is_anchor = match &self.working_table {
+            None => true,
+            Some(working_table) if working_table.is_empty() => return Ok(None),
+            Some(_) => {
+                self.iterations += 1;
+                if self.iterations > self.max_depth {
+                    return Err(ErrorCode::Overflow(format!(
+                        "Recursive CTE {} exceeded the maximum recursion depth {}, consider raising the setting max_cte_recursive_depth",
+                        self.plan.cte_name, self.max_depth
+                    )));
+                }
+                false
+            }
+        };
+
+        let plan = if is_anchor {
+            &self.plan.anchor
+        } else {
+            &self.plan.recursive
+        };
+        let mut blocks = Vec::new();
+        for block in self.execute(plan).await? {
+            let block = self.project_block(block, is_anchor)?;
+            if block.num_rows() > 0 {
+                blocks.push(block);
+            }
+        }
+
+        if blocks.is_empty() {
+            self.working_table = Some(Arc::new(vec![]));
+            return Ok(None);
+        }
+        let output = DataBlock::concat(&blocks)?;
+        self.working_table = Some(Arc::new(blocks));
+        Ok(Some(output))
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_sql::executor::cast_expr_to_non_null_boolean;
+use common_sql::plans::JoinType;
+
+/// The rows of one side of the join, buffered until the other side has caught up with them.
+struct SortedInput {
+    keys: Vec<Expr>,
+    /// The blocks and their evaluated join keys.
+    blocks: VecDeque<(DataBlock, Vec<Column>)>,
+    /// The first row of the front block that hasn't been joined yet.
+    row: usize,
+    finished: bool,
+}
+
+impl SortedInput {
+    fn create(keys: &[RemoteExpr]) -> Self {
+        SortedInput {
+            keys: keys
+                .iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+                .collect(),
+            blocks: VecDeque::new(),
+            row: 0,
+            finished: false,
+        }
+    }
+
+    fn push(&mut self, block: DataBlock, func_ctx: &FunctionContext) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let keys = self
+            .keys
+            .iter()
+            .map(|key| {
+                Ok(evaluator
+                    .run(key)?
+                    .convert_to_full_column(key.data_type(), block.num_rows()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.blocks.push_back((block, keys));
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// The rows left in the front block.
+    fn front_rows(&self) -> usize {
+        self.blocks[0].0.num_rows() - self.row
+    }
+
+    /// The keys of the `offset`-th row left in the front block.
+    fn key(&self, offset: usize) -> Vec<ScalarRef> {
+        self.blocks[0]
+            .1
+            .iter()
+            .map(|column| column.index(self.row + offset).unwrap())
+            .collect()
+    }
+
+    /// The number of rows at the front of the front block whose keys satisfy `predicate`.
+    fn run_len(&self, predicate: impl Fn(&[ScalarRef]) -> bool) -> usize {
+        (0..self.front_rows())
+            .take_while(|offset| predicate(&self.key(*offset)))
+            .count()
+    }
+
+    /// The number of rows with the key `key` at the front of the input, `None` if the
+    /// group may go on in the blocks not received yet.
+    fn group_len(&self, key: &[ScalarRef]) -> Option<usize> {
+        let mut len = 0;
+        for (idx, (block, keys)) in self.blocks.iter().enumerate() {
+            let start = if idx == 0 { self.row } else { 0 };
+            for row in start..block.num_rows() {
+                // `ScalarRef::cmp` takes NULL as equal to any value, a row with a NULL key
+                // must end the group instead.
+                if keys.iter().zip(key.iter()).any(|(column, key)| {
+                    column.index(row).unwrap().partial_cmp(key) != Some(Ordering::Equal)
+                }) {
+                    return Some(len);
+                }
+                len += 1;
+            }
+        }
+        self.finished.then_some(len)
+    }
+
+    /// Removes the first `num_rows` rows from the input.
+    fn take_rows(&mut self, mut num_rows: usize) -> Result<DataBlock> {
+        let mut blocks = Vec::new();
+        while num_rows > 0 {
+            let rows = num_rows.min(self.front_rows());
+            blocks.push(self.blocks[0].0.slice(self.row..self.row + rows));
+            self.row += rows;
+            num_rows -= rows;
+            if self.row == self.blocks[0].0.num_rows() {
+                self.blocks.pop_front();
+                self.row = 0;
+            }
+        }
+        DataBlock::concat(&blocks)
+    }
+}
+
+fn has_null(key: &[ScalarRef]) -> bool {
+    key.iter().any(|scalar| scalar == &ScalarRef::Null)
+}
+
+fn compare_keys(left: &[ScalarRef], right: &[ScalarRef]) -> Ordering {
+    left.iter()
+        .zip(right.iter())
+        .map(|(left, right)| left.cmp(right))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+enum Action {
+    NeedLeft,
+    NeedRight,
+    Finished,
+    LeftUnmatched(usize),
+    RightUnmatched(usize),
+    Matched(usize, usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Merge,
+    NeedLeft,
+    NeedRight,
+    Finished,
+}
+
+/// Joins the rows from the left input port with the rows received from the right side,
+/// both sorted ascending on the join keys, by merging them.
+pub struct TransformSortMergeJoin {
+    step: Step,
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    receiver: Receiver<DataBlock>,
+    output_blocks: VecDeque<DataBlock>,
+
+    left: SortedInput,
+    right: SortedInput,
+    left_types: Vec<DataType>,
+    right_types: Vec<DataType>,
+    filter: Option<Expr>,
+    join_type: JoinType,
+    func_ctx: FunctionContext,
+    max_block_size: usize,
+}
+
+impl TransformSortMergeJoin {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        receiver: Receiver<DataBlock>,
+        left_keys: &[RemoteExpr],
+        right_keys: &[RemoteExpr],
+        non_equi_conditions: &[RemoteExpr],
+        join_type: JoinType,
+        left_types: Vec<DataType>,
+        right_types: Vec<DataType>,
+        func_ctx: FunctionContext,
+        max_block_size: usize,
+    ) -> Result<Box<dyn Processor>> {
+        let filter = non_equi_conditions
+            .iter()
+            .map(|condition| condition.as_expr(&BUILTIN_FUNCTIONS))
+            .try_reduce(|lhs, rhs| {
+                check_function(None, "and_filters", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS)
+            })?
+            .map(cast_expr_to_non_null_boolean)
+            .transpose()?;
+
+        Ok(Box::new(TransformSortMergeJoin {
+            step: Step::Merge,
+            input,
+            output,
+            receiver,
+            output_blocks: VecDeque::new(),
+            left: SortedInput::create(left_keys),
+            right: SortedInput::create(right_keys),
+            left_types,
+            right_types,
+            filter,
+            join_type,
+            func_ctx,
+            max_block_size,
+        }))
+    }
+
+    fn next_action(&self) -> Action {
+        if self.left.is_empty() && !self.left.finished {
+            return Action::NeedLeft;
+        }
+        if self.right.is_empty() && !self.right.finished {
+            return Action::NeedRight;
+        }
+        match (self.left.is_empty(), self.right.is_empty()) {
+            (true, true) => Action::Finished,
+            (false, true) => Action::LeftUnmatched(self.left.front_rows()),
+            (true, false) => Action::RightUnmatched(self.right.front_rows()),
+            (false, false) => {
+                let left_key = self.left.key(0);
+                let right_key = self.right.key(0);
+                // Null keys never match, the sort puts them after the non-null keys.
+                if has_null(&left_key) {
+                    return Action::LeftUnmatched(self.left.run_len(has_null));
+                }
+                if has_null(&right_key) {
+                    return Action::RightUnmatched(self.right.run_len(has_null));
+                }
+                match compare_keys(&left_key, &right_key) {
+                    Ordering::Less => Action::LeftUnmatched(self.left.run_len(|key| {
+                        !has_null(key) && compare_keys(key, &right_key) == Ordering::Less
+                    })),
+                    Ordering::Greater => Action::RightUnmatched(self.right.run_len(|key| {
+                        !has_null(key) && compare_keys(key, &left_key) == Ordering::Less
+                    })),
+                    Ordering::Equal => {
+                        match (
+                            self.left.group_len(&left_key),
+                            self.right.group_len(&right_key),
+                        ) {
+                            (Some(left_rows), Some(right_rows)) => {
+                                Action::Matched(left_rows, right_rows)
+                            }
+                            (None, _) => Action::NeedLeft,
+                            (_, None) => Action::NeedRight,
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn merge(&mut self) -> Result<()> {
+        while self.output_blocks.is_empty() {
+            match self.next_action() {
+                Action::NeedLeft => {
+                    self.step = Step::NeedLeft;
+                    return Ok(());
+                }
+                Action::NeedRight => {
+                    self.step = Step::NeedRight;
+                    return Ok(());
+                }
+                Action::Finished => {
+                    self.step = Step::Finished;
+                    return Ok(());
+                }
+                Action::LeftUnmatched(num_rows) => {
+                    let block = self.left.take_rows(num_rows)?;
+                    self.output_left_unmatched(block);
+                }
+                Action::RightUnmatched(num_rows) => {
+                    let block = self.right.take_rows(num_rows)?;
+                    self.output_right_unmatched(block);
+                }
+                Action::Matched(left_rows, right_rows) => {
+                    let left = self.left.take_rows(left_rows)?;
+                    let right = self.right.take_rows(right_rows)?;
+                    self.output_matched(left, right)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Joins the groups of rows with equal keys from both sides.
+    fn output_matched(&mut self, left: DataBlock, right: DataBlock) -> Result<()> {
+        let mut left_matched = vec![self.filter.is_none(); left.num_rows()];
+        let mut right_matched = vec![self.filter.is_none(); right.num_rows()];
+
+        let pairs = (0..left.num_rows() as u32)
+            .flat_map(|left_row| {
+                (0..right.num_rows() as u32).map(move |right_row| (left_row, right_row))
+            })
+            .collect::<Vec<_>>();
+        for chunk in pairs.chunks(self.max_block_size.max(1)) {
+            let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = chunk.iter().cloned().unzip();
+            let block = self.merge_columns(left.take(&left_indices)?, right.take(&right_indices)?);
+            let block = match &self.filter {
+                None => block,
+                Some(filter) => {
+                    let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                    let predicate = evaluator
+                        .run(filter)?
+                        .try_downcast::<BooleanType>()
+                        .unwrap();
+                    for (idx, (left_row, right_row)) in chunk.iter().enumerate() {
+                        let selected = match &predicate {
+                            Value::Scalar(selected) => *selected,
+                            Value::Column(bitmap) => bitmap.get_bit(idx),
+                        };
+                        if selected {
+                            left_matched[*left_row as usize] = true;
+                            right_matched[*right_row as usize] = true;
+                        }
+                    }
+                    block.filter_boolean_value(&predicate)?
+                }
+            };
+            if !block.is_empty() {
+                self.output_blocks.push_back(block);
+            }
+        }
+
+        let unmatched = |matched: &[bool]| {
+            matched
+                .iter()
+                .enumerate()
+                .filter(|(_, matched)| !**matched)
+                .map(|(row, _)| row as u32)
+                .collect::<Vec<_>>()
+        };
+        let left_unmatched = unmatched(&left_matched);
+        if !left_unmatched.is_empty() {
+            self.output_left_unmatched(left.take(&left_unmatched)?);
+        }
+        let right_unmatched = unmatched(&right_matched);
+        if !right_unmatched.is_empty() {
+            self.output_right_unmatched(right.take(&right_unmatched)?);
+        }
+        Ok(())
+    }
+
+    fn output_left_unmatched(&mut self, left: DataBlock) {
+        if matches!(self.join_type, JoinType::Left | JoinType::Full) {
+            let right = null_block(&self.right_types, left.num_rows());
+            let block = self.merge_columns(left, right);
+            self.output_blocks.push_back(block);
+        }
+    }
+
+    fn output_right_unmatched(&mut self, right: DataBlock) {
+        if matches!(self.join_type, JoinType::Right | JoinType::Full) {
+            let left = null_block(&self.left_types, right.num_rows());
+            let block = self.merge_columns(left, right);
+            self.output_blocks.push_back(block);
+        }
+    }
+
+    /// Puts the columns of both sides together, the side opposite to the outer side of the
+    /// join becomes nullable.
+    fn merge_columns(&self, left: DataBlock, right: DataBlock) -> DataBlock {
+        let num_rows = left.num_rows();
+        let nullable_left = matches!(self.join_type, JoinType::Right | JoinType::Full);
+        let nullable_right = matches!(self.join_type, JoinType::Left | JoinType::Full);
+        let columns = left
+            .columns()
+            .iter()
+            .map(|entry| wrap_nullable(entry, nullable_left))
+            .chain(
+                right
+                    .columns()
+                    .iter()
+                    .map(|entry| wrap_nullable(entry, nullable_right)),
+            )
+            .collect();
+        DataBlock::new(columns, num_rows)
+    }
+}
+
+fn wrap_nullable(entry: &BlockEntry, nullable: bool) -> BlockEntry {
+    if !nullable || entry.data_type.is_nullable_or_null() {
+        return entry.clone();
+    }
+    BlockEntry::new(
+        entry.data_type.wrap_nullable(),
+        entry.value.clone().wrap_nullable(None),
+    )
+}
+
+fn null_block(data_types: &[DataType], num_rows: usize) -> DataBlock {
+    let columns = data_types
+        .iter()
+        .map(|data_type| BlockEntry::new(data_type.wrap_nullable(), Value::Scalar(Scalar::Null)))
+        .collect();
+    DataBlock::new(columns, num_rows)
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortMergeJoin {
+    fn name(&self) -> String {
+        "TransformSortMergeJoin".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(output_data) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(output_data));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.step {
+            Step::Merge => Ok(Event::Sync),
+            Step::NeedRight => Ok(Event::Async),
+            Step::NeedLeft => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    self.left.push(block, &self.func_ctx)?;
+                    self.step = Step::Merge;
+                    return Ok(Event::Sync);
+                }
+                if self.input.is_finished() {
+                    self.left.finished = true;
+                    self.step = Step::Merge;
+                    return Ok(Event::Sync);
+                }
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            Step::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        self.merge()
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.receiver.recv().await {
+            Ok(block) => self.right.push(block, &self.func_ctx)?,
+            Err(_) => self.right.finished = true,
+        }
+        self.step = Step::Merge;
+        Ok(())
+    }
+}
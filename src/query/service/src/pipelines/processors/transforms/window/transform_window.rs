@@ -38,6 +38,7 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
 use common_sql::executor::LagLeadDefault;
+use common_sql::plans::WindowFuncFrameExclusion;
 use common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
@@ -98,6 +99,8 @@ pub struct TransformWindow<T: Number> {
     frame_unit: WindowFuncFrameUnits,
     start_bound: FrameBound<T>,
     end_bound: FrameBound<T>,
+    // Rows of the frame excluded by `EXCLUDE CURRENT ROW | GROUP | TIES`.
+    exclusion: WindowFuncFrameExclusion,
 
     // Only used for ROWS frame, default value: 0. (when not used)
     rows_start_bound: usize,
@@ -383,6 +386,26 @@ impl<T: Number> TransformWindow<T> {
         true
     }
 
+    /// If the row is excluded from the frame of the current row.
+    fn is_excluded(&self, row: &RowPtr) -> bool {
+        match self.exclusion {
+            WindowFuncFrameExclusion::NoOthers => false,
+            WindowFuncFrameExclusion::CurrentRow => *row == self.current_row,
+            WindowFuncFrameExclusion::Group => self.are_peers(row, &self.current_row, false),
+            WindowFuncFrameExclusion::Ties => {
+                *row != self.current_row && self.are_peers(row, &self.current_row, false)
+            }
+        }
+    }
+
+    /// Advance the row to the first row that is not excluded, or to `frame_end`.
+    fn skip_excluded_rows(&self, mut row: RowPtr) -> RowPtr {
+        while row < self.frame_end && self.is_excluded(&row) {
+            row = self.advance_row(row);
+        }
+        row
+    }
+
     fn check_outputs(&mut self) {
         while self.next_output_block - self.first_block < self.blocks.len() {
             let block = &mut self.blocks[self.next_output_block - self.first_block];
@@ -429,11 +452,15 @@ impl<T: Number> TransformWindow<T> {
         debug_assert!(self.partition_start <= self.frame_start);
         debug_assert!(self.frame_end <= self.partition_end);
 
-        let (rows_start, rows_end, reset) = if self.frame_start == self.prev_frame_start {
-            (self.prev_frame_end, self.frame_end, false)
-        } else {
-            (self.frame_start, self.frame_end, true)
-        };
+        // The excluded rows depend on the current row, so the frame can't slide
+        // and is aggregated from scratch.
+        let has_exclusion = !self.exclusion.is_no_others();
+        let (rows_start, rows_end, reset) =
+            if !has_exclusion && self.frame_start == self.prev_frame_start {
+                (self.prev_frame_end, self.frame_end, false)
+            } else {
+                (self.frame_start, self.frame_end, true)
+            };
 
         if reset {
             agg.reset();
@@ -459,6 +486,9 @@ impl<T: Number> TransformWindow<T> {
             };
             let cols = agg.arg_columns(data);
             for row in start_row..end_row {
+                if has_exclusion && self.is_excluded(&RowPtr::new(block, row)) {
+                    continue;
+                }
                 agg.accumulate_row(&cols, row)?;
             }
         }
@@ -529,10 +559,10 @@ impl<T: Number> TransformWindow<T> {
                 let value = if self.frame_start == self.frame_end {
                     Scalar::Null
                 } else if let Some(mut n) = func.n {
-                    let mut cur = self.frame_start;
+                    let mut cur = self.skip_excluded_rows(self.frame_start);
                     // n is counting from 1
                     while n > 1 && cur < self.frame_end {
-                        cur = self.advance_row(cur);
+                        cur = self.skip_excluded_rows(self.advance_row(cur));
                         n -= 1;
                     }
                     if cur != self.frame_end {
@@ -545,11 +575,19 @@ impl<T: Number> TransformWindow<T> {
                     }
                 } else {
                     // last_value
-                    let cur = self.goback_row(self.frame_end);
+                    let mut cur = self.goback_row(self.frame_end);
                     debug_assert!(self.frame_start <= cur);
-                    let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
-                    let col = block.get_by_offset(func.arg).value.as_column().unwrap();
-                    col.index(cur.row).unwrap().to_owned()
+                    while cur > self.frame_start && self.is_excluded(&cur) {
+                        cur = self.goback_row(cur);
+                    }
+                    if !self.is_excluded(&cur) {
+                        let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
+                        let col = block.get_by_offset(func.arg).value.as_column().unwrap();
+                        col.index(cur.row).unwrap().to_owned()
+                    } else {
+                        // All rows of the frame are excluded
+                        Scalar::Null
+                    }
                 };
                 let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
                 builder.push(value.as_ref());
@@ -588,6 +626,7 @@ impl TransformWindow<u64> {
         partition_indices: Vec<usize>,
         order_by: Vec<SortColumnDescription>,
         bounds: (FrameBound<u64>, FrameBound<u64>),
+        exclusion: WindowFuncFrameExclusion,
     ) -> Result<Self> {
         let func = WindowFunctionImpl::try_create(func)?;
         let (start_bound, end_bound) = bounds;
@@ -615,6 +654,7 @@ impl TransformWindow<u64> {
             frame_unit: WindowFuncFrameUnits::Rows,
             start_bound,
             end_bound,
+            exclusion,
             rows_start_bound,
             rows_end_bound,
             need_check_null_frame: false,
@@ -649,6 +689,7 @@ where T: Number + ResultTypeOfUnary
         partition_indices: Vec<usize>,
         order_by: Vec<SortColumnDescription>,
         bounds: (FrameBound<T>, FrameBound<T>),
+        exclusion: WindowFuncFrameExclusion,
     ) -> Result<Self> {
         let func = WindowFunctionImpl::try_create(func)?;
         let (start_bound, end_bound) = bounds;
@@ -680,6 +721,7 @@ where T: Number + ResultTypeOfUnary
             frame_unit: WindowFuncFrameUnits::Range,
             start_bound,
             end_bound,
+            exclusion,
             rows_start_bound: 0,
             rows_end_bound: 0,
             need_check_null_frame,
@@ -1165,6 +1207,7 @@ mod tests {
     use common_pipeline_core::processors::port::OutputPort;
     use common_pipeline_core::processors::processor::Event;
     use common_pipeline_core::processors::Processor;
+    use common_sql::plans::WindowFuncFrameExclusion;
     use common_sql::plans::WindowFuncFrameUnits;
 
     use super::TransformWindow;
@@ -1187,6 +1230,7 @@ mod tests {
             vec![0],
             vec![],
             bounds,
+            WindowFuncFrameExclusion::NoOthers,
         )
     }
 
@@ -1805,6 +1849,7 @@ mod tests {
             vec![0],
             vec![],
             bounds,
+            WindowFuncFrameExclusion::NoOthers,
         )?;
 
         Ok((Box::new(transform), input, output))
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::ValueType;
@@ -53,7 +54,7 @@ impl OneBlockProcedure for TenantQuotaProcedure {
 
     fn features(&self) -> ProcedureFeatures {
         ProcedureFeatures::default()
-            .variadic_arguments(0, 5)
+            .variadic_arguments(0, 8)
             .management_mode_required(true)
     }
 
@@ -63,6 +64,9 @@ impl OneBlockProcedure for TenantQuotaProcedure {
     /// max_tables_per_database: u32
     /// max_stages: u32
     /// max_files_per_stage: u32
+    /// max_storage_bytes_per_database: u64
+    /// max_storage_bytes_per_table: u64
+    /// max_storage_bytes_per_stage: u64
     #[async_backtrace::framed]
     async fn all_data(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
         let mut tenant = ctx.get_tenant();
@@ -95,6 +99,15 @@ impl OneBlockProcedure for TenantQuotaProcedure {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_bytes) = args.get(5) {
+            quota.max_storage_bytes_per_database = max_bytes.parse::<u64>()?;
+        };
+        if let Some(max_bytes) = args.get(6) {
+            quota.max_storage_bytes_per_table = max_bytes.parse::<u64>()?;
+        };
+        if let Some(max_bytes) = args.get(7) {
+            quota.max_storage_bytes_per_stage = max_bytes.parse::<u64>()?;
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
@@ -115,6 +128,18 @@ impl OneBlockProcedure for TenantQuotaProcedure {
                 "max_files_per_stage",
                 DataType::Number(NumberDataType::UInt32),
             ),
+            DataField::new(
+                "max_storage_bytes_per_database",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "max_storage_bytes_per_table",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "max_storage_bytes_per_stage",
+                DataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 }
@@ -139,6 +164,20 @@ impl TenantQuotaProcedure {
                     DataType::Number(NumberDataType::UInt32),
                     Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(
+                        quota.max_storage_bytes_per_database,
+                    )),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_storage_bytes_per_table)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_storage_bytes_per_stage)),
+                ),
             ],
             1,
         ))
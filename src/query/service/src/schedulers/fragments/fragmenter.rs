@@ -79,6 +79,12 @@ impl Fragmenter {
                 FragmentKind::Normal => Ok(Some(ShuffleDataExchange::create(
                     Self::get_executors(ctx),
                     plan.keys.clone(),
+                    None,
+                ))),
+                FragmentKind::Adaptive(join_plan_id) => Ok(Some(ShuffleDataExchange::create(
+                    Self::get_executors(ctx),
+                    plan.keys.clone(),
+                    Some(join_plan_id),
                 ))),
                 FragmentKind::Merge => {
                     Ok(Some(MergeExchange::create(Self::get_local_executor(ctx))))
@@ -147,10 +153,13 @@ impl PhysicalPlanReplacer for Fragmenter {
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
+            is_null_equal: plan.is_null_equal.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
             contain_runtime_filter: plan.contain_runtime_filter,
+            runtime_filter_keys: plan.runtime_filter_keys.clone(),
+            fallback_build_rows: plan.fallback_build_rows,
             stat_info: plan.stat_info.clone(),
         }))
     }
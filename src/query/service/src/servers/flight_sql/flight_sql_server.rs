@@ -27,7 +27,7 @@ use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
 use tracing::info;
 
-use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::servers::flight_sql::flight_sql_service::FlightIngestService;
 use crate::servers::Server as DatabendQueryServer;
 
 pub struct FlightSQLServer {
@@ -62,7 +62,7 @@ impl FlightSQLServer {
 
     #[async_backtrace::framed]
     pub async fn start_with_incoming(&mut self, addr: SocketAddr) -> Result<()> {
-        let flight_sql_service = FlightSqlServiceImpl::create();
+        let flight_sql_service = FlightIngestService::create();
         let builder = Server::builder();
         let mut builder = if self.config.flight_sql_tls_server_enabled() {
             info!("databend query tls flight sql enabled");
@@ -0,0 +1,391 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CommandPreparedStatementQuery;
+use arrow_flight::sql::CommandPreparedStatementUpdate;
+use arrow_flight::sql::CommandStatementUpdate;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::utils::flight_data_to_arrow_batch;
+use arrow_flight::Action;
+use arrow_flight::Criteria;
+use arrow_flight::Empty;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::HandshakeRequest;
+use arrow_flight::PutResult;
+use arrow_flight::SchemaResult;
+use arrow_flight::Ticket;
+use arrow_schema::ArrowError;
+use arrow_schema::Schema as ArrowSchema;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_sources::StreamSource;
+use common_storages_fuse::TableContext;
+use futures::StreamExt;
+use prost::Message;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use super::status;
+use super::FlightSqlServiceImpl;
+use crate::interpreters::append2table;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::Session;
+
+/// The Flight service served on the Flight SQL port.
+///
+/// It's the Flight SQL service, except that a `DoPut` whose descriptor is a path instead of a
+/// command ingests the streamed record batches into the table named by the path, without
+/// going through the SQL parser, see [`FlightSqlServiceImpl::ingest`].
+pub struct FlightIngestService {
+    inner: FlightSqlServiceImpl,
+}
+
+impl FlightIngestService {
+    pub fn create() -> Self {
+        FlightIngestService {
+            inner: FlightSqlServiceImpl::create(),
+        }
+    }
+}
+
+fn update_result(
+    record_count: i64,
+) -> Response<<FlightSqlServiceImpl as FlightService>::DoPutStream> {
+    let result = DoPutUpdateResult { record_count };
+    let result = PutResult {
+        app_metadata: result.as_any().encode_to_vec().into(),
+    };
+    Response::new(Box::pin(futures::stream::iter(vec![Ok(result)])))
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightIngestService {
+    type HandshakeStream = <FlightSqlServiceImpl as FlightService>::HandshakeStream;
+    type ListFlightsStream = <FlightSqlServiceImpl as FlightService>::ListFlightsStream;
+    type DoGetStream = <FlightSqlServiceImpl as FlightService>::DoGetStream;
+    type DoPutStream = <FlightSqlServiceImpl as FlightService>::DoPutStream;
+    type DoActionStream = <FlightSqlServiceImpl as FlightService>::DoActionStream;
+    type ListActionsStream = <FlightSqlServiceImpl as FlightService>::ListActionsStream;
+    type DoExchangeStream = <FlightSqlServiceImpl as FlightService>::DoExchangeStream;
+
+    #[async_backtrace::framed]
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        FlightService::handshake(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_flights(
+        &self,
+        request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        FlightService::list_flights(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        FlightService::get_flight_info(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        FlightService::get_schema(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        FlightService::do_get(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_put(
+        &self,
+        mut request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        // The first message carries the descriptor, and the schema of the batches if any.
+        let first = request
+            .get_mut()
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("do_put: the stream is empty"))?;
+        let descriptor = first
+            .flight_descriptor
+            .clone()
+            .ok_or_else(|| Status::invalid_argument("do_put: the descriptor is missing"))?;
+
+        if descriptor.r#type == DescriptorType::Path as i32 {
+            let session = self.inner.get_session(&request)?;
+            tracing::info!("do_put ingest into {:?}", descriptor.path);
+            let record_count = self
+                .inner
+                .ingest(session, &descriptor.path, &first, request.into_inner())
+                .await
+                .map_err(|e| status!("fail to ingest", e))?;
+            return Ok(update_result(record_count));
+        }
+
+        // Dispatch the commands of Flight SQL like the `FlightService` of `FlightSqlService` does.
+        let message = Any::decode(&*descriptor.cmd)
+            .map_err(|e| Status::invalid_argument(format!("{e:?}")))?;
+        let unpack_error = |e: ArrowError| Status::invalid_argument(format!("{e:?}"));
+        if let Some(command) = message
+            .unpack::<CommandStatementUpdate>()
+            .map_err(unpack_error)?
+        {
+            let record_count = self.inner.do_put_statement_update(command, request).await?;
+            return Ok(update_result(record_count));
+        }
+        if let Some(command) = message
+            .unpack::<CommandPreparedStatementQuery>()
+            .map_err(unpack_error)?
+        {
+            return self
+                .inner
+                .do_put_prepared_statement_query(command, request)
+                .await;
+        }
+        if let Some(command) = message
+            .unpack::<CommandPreparedStatementUpdate>()
+            .map_err(unpack_error)?
+        {
+            let record_count = self
+                .inner
+                .do_put_prepared_statement_update(command, request)
+                .await?;
+            return Ok(update_result(record_count));
+        }
+        Err(Status::invalid_argument(format!(
+            "do_put: The defined request is invalid: {}",
+            message.type_url
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        FlightService::do_action(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_actions(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        FlightService::list_actions(&self.inner, request).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_exchange(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        FlightService::do_exchange(&self.inner, request).await
+    }
+}
+
+impl FlightSqlServiceImpl {
+    /// Append the record batches of a `DoPut` stream to the table named by `path`, which is
+    /// `[table]`, `[database, table]` or `[catalog, database, table]`.
+    ///
+    /// The columns of the batches are matched with the columns of the table by name and cast
+    /// to the types of the table, the other columns are filled with their default values.
+    /// The batches are committed at once after the stream ends, returns the number of rows.
+    #[async_backtrace::framed]
+    pub(super) async fn ingest(
+        &self,
+        session: Arc<Session>,
+        path: &[String],
+        schema_data: &FlightData,
+        stream: Streaming<FlightData>,
+    ) -> Result<i64> {
+        let context = session.create_query_context().await?;
+        let (catalog, database, table_name) = match path {
+            [table] => (
+                context.get_current_catalog(),
+                context.get_current_database(),
+                table.clone(),
+            ),
+            [database, table] => (
+                context.get_current_catalog(),
+                database.clone(),
+                table.clone(),
+            ),
+            [catalog, database, table] => (catalog.clone(), database.clone(), table.clone()),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "the path of the descriptor must be [[catalog, ]database, ]table, got {path:?}"
+                )));
+            }
+        };
+        session
+            .validate_privilege(
+                &GrantObject::Table(catalog.clone(), database.clone(), table_name.clone()),
+                vec![UserPrivilegeType::Insert],
+            )
+            .await?;
+        let table = context.get_table(&catalog, &database, &table_name).await?;
+
+        let arrow_schema = Arc::new(ArrowSchema::try_from(schema_data).map_err(|e| {
+            ErrorCode::BadArguments(format!("invalid schema of the record batches: {e}"))
+        })?);
+        let source_schema = Arc::new(
+            DataSchema::try_from(arrow_schema.as_ref())
+                .map_err(|e| ErrorCode::BadArguments(format!("{e}")))?,
+        );
+        let insert_schema = ingest_schema(table.as_ref(), &source_schema)?;
+
+        let column_names = insert_schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        context.attach_query_str(
+            "Insert".to_string(),
+            format!("INSERT INTO {database}.{table_name} ({column_names}) FROM FLIGHT DO_PUT"),
+        );
+
+        let dictionaries_by_id = HashMap::new();
+        let blocks = stream.filter_map(move |data| {
+            let block = match data {
+                // Skip the messages without a body, such as the ones only carrying app metadata.
+                Ok(data) if data.data_header.is_empty() => None,
+                Ok(data) => Some(
+                    flight_data_to_arrow_batch(&data, arrow_schema.clone(), &dictionaries_by_id)
+                        .and_then(|batch| DataBlock::from_record_batch(&batch))
+                        .map(|(block, _)| block)
+                        .map_err(|e| ErrorCode::BadBytes(format!("invalid record batch: {e}"))),
+                ),
+                Err(status) => Some(Err(ErrorCode::BadBytes(format!(
+                    "fail to receive the record batches: {status}"
+                )))),
+            };
+            futures::future::ready(block)
+        });
+
+        let mut build_res = PipelineBuildResult::create();
+        let output = OutputPort::create();
+        let source = StreamSource::create(context.clone(), Some(Box::pin(blocks)), output.clone())?;
+        build_res
+            .main_pipeline
+            .add_pipe(Pipe::create(0, 1, vec![PipeItem::create(
+                source,
+                vec![],
+                vec![output],
+            )]));
+        if source_schema != insert_schema {
+            let func_ctx = context.get_function_context()?;
+            build_res.main_pipeline.add_transform(|input, output| {
+                TransformCastSchema::try_create(
+                    input,
+                    output,
+                    source_schema.clone(),
+                    insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+        }
+        append2table(
+            context.clone(),
+            table,
+            insert_schema,
+            &mut build_res,
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+
+        let settings = context.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, context.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        context.set_executor(executor.get_inner())?;
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || executor.execute())
+            .await?;
+
+        Ok(context.get_write_progress_value().rows as i64)
+    }
+}
+
+/// Resolve the fields of the table that the columns of the record batches are inserted into.
+fn ingest_schema(table: &dyn Table, source_schema: &DataSchema) -> Result<DataSchemaRef> {
+    let table_schema = table.schema();
+    let mut names = HashSet::with_capacity(source_schema.fields().len());
+    let mut fields = Vec::with_capacity(source_schema.fields().len());
+    for source_field in source_schema.fields() {
+        let name = source_field.name();
+        if !names.insert(name.as_str()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {name} is specified more than once in the record batches"
+            )));
+        }
+        let Some(field) = table_schema.fields().iter().find(|f| f.name() == name) else {
+            return Err(ErrorCode::UnknownColumn(format!(
+                "column {name} doesn't exist in table {}",
+                table.name()
+            )));
+        };
+        if field.computed_expr().is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "computed column {name} can't be inserted into"
+            )));
+        }
+        fields.push(DataField::from(field));
+    }
+    Ok(Arc::new(DataSchema::new(fields)))
+}
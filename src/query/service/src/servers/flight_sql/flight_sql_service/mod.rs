@@ -15,6 +15,7 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod ingest;
 mod query;
 mod service;
 mod session;
@@ -29,6 +30,7 @@ use common_sql::plans::Plan;
 use common_sql::PlanExtras;
 use dashmap::DashMap;
 use futures::Stream;
+pub use ingest::FlightIngestService;
 use parking_lot::Mutex;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
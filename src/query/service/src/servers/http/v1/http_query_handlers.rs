@@ -14,6 +14,8 @@
 
 use common_exception::ErrorCode;
 use common_expression::DataSchemaRef;
+use common_sql::ColumnOrigin;
+use http::HeaderMap;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
@@ -32,13 +34,15 @@ use tracing::info;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::result_format::stream_result;
+use super::result_format::ResultFormat;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
-const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
+pub(crate) const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
 
@@ -82,18 +86,23 @@ pub struct QueryStats {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct QueryResponseField {
-    name: String,
-    r#type: String,
+    pub name: String,
+    pub r#type: String,
+    /// The catalog, database, table and column this field is read from, if derivable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<ColumnOrigin>,
 }
 
 impl QueryResponseField {
-    fn from_schema(schema: DataSchemaRef) -> Vec<Self> {
+    fn from_schema(schema: DataSchemaRef, column_origins: &[Option<ColumnOrigin>]) -> Vec<Self> {
         schema
             .fields()
             .iter()
-            .map(|f| Self {
+            .enumerate()
+            .map(|(idx, f)| Self {
                 name: f.name().to_string(),
                 r#type: f.data_type().wrapped_display(),
+                origin: column_origins.get(idx).cloned().flatten(),
             })
             .collect()
     }
@@ -163,7 +172,7 @@ impl QueryResponse {
         Json(QueryResponse {
             data: data.into(),
             state: state.state,
-            schema: QueryResponseField::from_schema(schema),
+            schema: QueryResponseField::from_schema(schema, &r.column_origins),
             session_id: Some(session_id),
             session: r.session,
             stats,
@@ -276,6 +285,7 @@ async fn query_page_handler(
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
+    headers: &HeaderMap,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     info!("receive http query: {:?}", req);
@@ -288,6 +298,9 @@ pub(crate) async fn query_handler(
         .map_err(|err| err.display_with_sql(&sql));
     match query {
         Ok(query) => {
+            if let Some(format) = ResultFormat::from_headers(headers) {
+                return Ok(stream_result(query, format).await);
+            }
             query.update_expire_time(true).await;
             let resp = query
                 .get_response_page(0)
@@ -16,6 +16,7 @@ mod http_query_handlers;
 pub mod json_block;
 mod load;
 mod query;
+mod result_format;
 mod stage;
 
 pub use http_query_handlers::make_final_uri;
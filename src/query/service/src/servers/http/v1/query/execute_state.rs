@@ -287,8 +287,10 @@ async fn execute(
                         block_sender.send(block.clone(), block.num_rows()).await;
                     }
                     Err(err) => {
+                        // stop before closing the channel, so the reader sees the error at the end
+                        Executor::stop(&executor, Err(err), false).await;
                         block_sender.close();
-                        return Err(err);
+                        return Ok(());
                     }
                 };
             }
@@ -26,6 +26,10 @@ use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_settings::Settings;
+use common_sql::ColumnOrigin;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -44,6 +48,7 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultCursor;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::short_sql;
@@ -104,6 +109,14 @@ pub struct PaginationConf {
     pub(crate) max_rows_in_buffer: usize,
     #[serde(default = "default_max_rows_per_page")]
     pub(crate) max_rows_per_page: usize,
+    /// Retain the whole result on the server, the pages can be fetched in any order until the
+    /// result expires. The rows beyond `max_rows_in_buffer` are spilled to the storage.
+    #[serde(default)]
+    pub(crate) cursor: bool,
+    /// Seconds to keep the result after the last request, overrides
+    /// `http_handler_result_timeout_secs` of the config.
+    #[serde(default)]
+    pub(crate) result_timeout_secs: Option<u64>,
 }
 
 impl Default for PaginationConf {
@@ -112,6 +125,8 @@ impl Default for PaginationConf {
             wait_time_secs: 1,
             max_rows_in_buffer: DEFAULT_MAX_ROWS_IN_BUFFER,
             max_rows_per_page: DEFAULT_MAX_ROWS_PER_PAGE,
+            cursor: false,
+            result_timeout_secs: None,
         }
     }
 }
@@ -180,6 +195,7 @@ pub struct ResponseState {
 
 pub struct HttpQueryResponseInternal {
     pub data: Option<ResponseData>,
+    pub column_origins: Vec<Option<ColumnOrigin>>,
     pub session_id: String,
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
@@ -197,12 +213,20 @@ pub enum ExpireResult {
     Removed,
 }
 
+/// The pages of the result, consumed in order by default, or retained in the cursor mode.
+enum ResultPages {
+    Paged(Arc<TokioMutex<PageManager>>),
+    Cursor(Arc<ResultCursor>),
+}
+
 pub struct HttpQuery {
     pub(crate) id: String,
     pub(crate) session_id: String,
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
-    page_manager: Arc<TokioMutex<PageManager>>,
+    pages: ResultPages,
+    column_origins: Vec<Option<ColumnOrigin>>,
+    settings: Arc<Settings>,
     config: HttpQueryConfig,
     expire_state: Arc<TokioMutex<ExpireState>>,
 }
@@ -304,6 +328,7 @@ impl HttpQuery {
 
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
         let schema = InterpreterFactory::get(ctx.clone(), &plan).await?.schema();
+        let column_origins = plan.result_column_origins();
 
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
         http_query_runtime_instance
@@ -339,19 +364,34 @@ impl HttpQuery {
             })?;
 
         let format_settings = ctx.get_format_settings()?;
-        let data = Arc::new(TokioMutex::new(PageManager::new(
-            query_id_clone,
-            request.pagination.max_rows_per_page,
-            block_receiver,
-            schema,
-            format_settings,
-        )));
+        let pages = if request.pagination.cursor {
+            ResultPages::Cursor(ResultCursor::create(
+                query_id_clone.clone(),
+                request.pagination.max_rows_per_page,
+                request.pagination.max_rows_in_buffer,
+                block_receiver,
+                schema,
+                format_settings,
+                ctx.get_spill_operator(),
+                format!("_http_query_spill/{}/{}", ctx.get_tenant(), query_id_clone),
+            )?)
+        } else {
+            ResultPages::Paged(Arc::new(TokioMutex::new(PageManager::new(
+                query_id_clone,
+                request.pagination.max_rows_per_page,
+                block_receiver,
+                schema,
+                format_settings,
+            ))))
+        };
         let query = HttpQuery {
             id,
             session_id,
             request,
             state,
-            page_manager: data,
+            pages,
+            column_origins,
+            settings: ctx.get_settings(),
             config,
             expire_state: Arc::new(TokioMutex::new(ExpireState::Working)),
         };
@@ -372,6 +412,7 @@ impl HttpQuery {
 
         Ok(HttpQueryResponseInternal {
             data,
+            column_origins: self.column_origins.clone(),
             state,
             session: session_conf,
             session_id: self.session_id.clone(),
@@ -382,6 +423,7 @@ impl HttpQuery {
     pub async fn get_response_state_only(&self) -> HttpQueryResponseInternal {
         HttpQueryResponseInternal {
             data: None,
+            column_origins: vec![],
             session_id: self.session_id.clone(),
             state: self.get_state().await,
             session: None,
@@ -403,15 +445,40 @@ impl HttpQuery {
 
     #[async_backtrace::framed]
     async fn get_page(&self, page_no: usize) -> Result<ResponseData> {
-        let mut page_manager = self.page_manager.lock().await;
-        let page = page_manager
-            .get_a_page(page_no, &self.request.pagination.get_wait_type())
-            .await?;
-        let response = ResponseData {
-            page,
-            next_page_no: page_manager.next_page_no(),
-        };
-        Ok(response)
+        let wait = self.request.pagination.get_wait_type();
+        match &self.pages {
+            ResultPages::Paged(page_manager) => {
+                let mut page_manager = page_manager.lock().await;
+                let page = page_manager.get_a_page(page_no, &wait).await?;
+                let response = ResponseData {
+                    page,
+                    next_page_no: page_manager.next_page_no(),
+                };
+                Ok(response)
+            }
+            ResultPages::Cursor(cursor) => cursor.get_a_page(page_no, &wait).await,
+        }
+    }
+
+    pub fn settings(&self) -> Arc<Settings> {
+        self.settings.clone()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn result_schema(&self) -> DataSchemaRef {
+        match &self.pages {
+            ResultPages::Paged(page_manager) => page_manager.lock().await.schema(),
+            ResultPages::Cursor(cursor) => cursor.schema(),
+        }
+    }
+
+    /// Used when the result is streamed instead of paged.
+    #[async_backtrace::framed]
+    pub async fn next_block(&self) -> Option<DataBlock> {
+        match &self.pages {
+            ResultPages::Paged(page_manager) => page_manager.lock().await.next_block().await,
+            ResultPages::Cursor(cursor) => cursor.next_block().await,
+        }
     }
 
     #[async_backtrace::framed]
@@ -426,13 +493,22 @@ impl HttpQuery {
 
     #[async_backtrace::framed]
     pub async fn detach(&self) {
-        let data = self.page_manager.lock().await;
-        data.detach().await
+        match &self.pages {
+            ResultPages::Paged(page_manager) => page_manager.lock().await.detach().await,
+            ResultPages::Cursor(cursor) => cursor.close(),
+        }
+    }
+
+    pub fn result_timeout_secs(&self) -> u64 {
+        self.request
+            .pagination
+            .result_timeout_secs
+            .unwrap_or(self.config.result_timeout_secs)
     }
 
     #[async_backtrace::framed]
     pub async fn update_expire_time(&self, before_wait: bool) {
-        let duration = Duration::from_secs(self.config.result_timeout_secs)
+        let duration = Duration::from_secs(self.result_timeout_secs())
             + if before_wait {
                 Duration::from_secs(self.request.pagination.wait_time_secs as u64)
             } else {
@@ -447,6 +523,10 @@ impl HttpQuery {
     pub async fn mark_removed(&self) {
         let mut t = self.expire_state.lock().await;
         *t = ExpireState::Removed;
+        // The retained result is useless once the query is removed.
+        if let ResultPages::Cursor(cursor) = &self.pages {
+            cursor.close();
+        }
     }
 
     // return Duration to sleep
@@ -464,7 +544,7 @@ impl HttpQuery {
             }
             ExpireState::Removed => ExpireResult::Removed,
             ExpireState::Working => {
-                ExpireResult::Sleep(Duration::from_secs(self.config.result_timeout_secs))
+                ExpireResult::Sleep(Duration::from_secs(self.result_timeout_secs()))
             }
         }
     }
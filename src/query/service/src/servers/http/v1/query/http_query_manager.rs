@@ -86,7 +86,7 @@ impl HttpQueryManager {
     async fn add_query(self: &Arc<Self>, query_id: &str, query: Arc<HttpQuery>) {
         let mut queries = self.queries.write().await;
         queries.insert(query_id.to_string(), query.clone());
-        let timeout = self.config.result_timeout_secs;
+        let timeout = query.result_timeout_secs();
 
         let self_clone = self.clone();
         let query_id_clone = query_id.to_string();
@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+mod result_cursor;
 pub mod sized_spsc;
 
 pub(crate) use execute_state::ExecuteState;
@@ -39,3 +40,4 @@ pub use page_manager::Page;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
+pub use result_cursor::ResultCursor;
@@ -90,6 +90,21 @@ impl PageManager {
         }
     }
 
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    /// Takes the next block of the result without paging it, returns None after the last block.
+    #[async_backtrace::framed]
+    pub async fn next_block(&mut self) -> Option<DataBlock> {
+        let block = self.block_receiver.recv().await;
+        if block.is_none() {
+            self.block_end = true;
+            self.end = true;
+        }
+        block
+    }
+
     #[async_backtrace::framed]
     pub async fn get_a_page(&mut self, page_no: usize, tp: &Wait) -> Result<Page> {
         let next_no = self.total_pages;
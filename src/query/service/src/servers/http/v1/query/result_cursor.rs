@@ -0,0 +1,420 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Value;
+use common_io::prelude::FormatSettings;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
+use crate::servers::http::v1::query::Page;
+use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::Wait;
+use crate::servers::http::v1::JsonBlock;
+
+/// A page of the result, kept in memory or written to the spill storage.
+enum CursorPage {
+    Memory(DataBlock),
+    Spilled {
+        location: String,
+        columns_layout: Vec<usize>,
+        num_rows: usize,
+    },
+}
+
+impl CursorPage {
+    fn num_rows(&self) -> usize {
+        match self {
+            CursorPage::Memory(block) => block.num_rows(),
+            CursorPage::Spilled { num_rows, .. } => *num_rows,
+        }
+    }
+}
+
+enum Lookup {
+    Page {
+        page: Arc<CursorPage>,
+        total_rows: usize,
+        next_page_no: Option<usize>,
+    },
+    /// The page is not produced yet.
+    Pending { total_rows: usize },
+    /// The page right after the last page.
+    End { total_rows: usize },
+}
+
+#[derive(Default)]
+struct CursorState {
+    pages: Vec<Arc<CursorPage>>,
+    /// The total rows of the pages before each page.
+    rows_before: Vec<usize>,
+    rows_in_memory: usize,
+    end: bool,
+    closed: bool,
+    error: Option<ErrorCode>,
+}
+
+/// Retains the whole result of an http query in the cursor mode, so the pages are fetched in
+/// any order until the query expires, and the query is not blocked by a slow client. The
+/// pages beyond `max_rows_in_memory` rows are spilled to the storage.
+pub struct ResultCursor {
+    query_id: String,
+    schema: DataSchemaRef,
+    format_settings: FormatSettings,
+    max_rows_per_page: usize,
+    max_rows_in_memory: usize,
+    operator: Operator,
+    location_prefix: String,
+    block_receiver: SizedChannelReceiver<DataBlock>,
+    state: Mutex<CursorState>,
+    notify: Notify,
+    /// The next page taken by `next_block` when the result is streamed.
+    next_block_page: AtomicUsize,
+}
+
+impl ResultCursor {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        query_id: String,
+        max_rows_per_page: usize,
+        max_rows_in_memory: usize,
+        block_receiver: SizedChannelReceiver<DataBlock>,
+        schema: DataSchemaRef,
+        format_settings: FormatSettings,
+        operator: Operator,
+        location_prefix: String,
+    ) -> Result<Arc<ResultCursor>> {
+        let cursor = Arc::new(ResultCursor {
+            query_id,
+            schema,
+            format_settings,
+            max_rows_per_page,
+            max_rows_in_memory,
+            operator,
+            location_prefix,
+            block_receiver,
+            state: Mutex::new(CursorState::default()),
+            notify: Notify::new(),
+            next_block_page: AtomicUsize::new(0),
+        });
+
+        let cursor_clone = cursor.clone();
+        GlobalIORuntime::instance().try_spawn(async move {
+            if let Err(cause) = cursor_clone.fill().await {
+                error!(
+                    "http query {} fail to retain the result: {:?}",
+                    &cursor_clone.query_id, cause
+                );
+                cursor_clone.block_receiver.close();
+                cursor_clone.state.lock().error = Some(cause);
+            }
+            cursor_clone.state.lock().end = true;
+            cursor_clone.notify.notify_waiters();
+        })?;
+        Ok(cursor)
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    /// Drains the result of the query into the pages.
+    #[async_backtrace::framed]
+    async fn fill(&self) -> Result<()> {
+        let mut blocks = vec![];
+        let mut num_rows = 0;
+        while let Some(block) = self.block_receiver.recv().await {
+            num_rows += block.num_rows();
+            blocks.push(block);
+            if num_rows < self.max_rows_per_page {
+                continue;
+            }
+
+            let block = DataBlock::concat(&std::mem::take(&mut blocks))?;
+            let mut start = 0;
+            while num_rows - start >= self.max_rows_per_page {
+                let end = start + self.max_rows_per_page;
+                self.add_page(block.slice(start..end)).await?;
+                start = end;
+            }
+            if start < num_rows {
+                blocks.push(block.slice(start..num_rows));
+            }
+            num_rows -= start;
+        }
+
+        if num_rows > 0 {
+            self.add_page(DataBlock::concat(&blocks)?).await?;
+        }
+        info!("http query {} retained the whole result", &self.query_id);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn add_page(&self, block: DataBlock) -> Result<()> {
+        let num_rows = block.num_rows();
+        let spill = {
+            let state = self.state.lock();
+            if state.closed {
+                return Ok(());
+            }
+            state.rows_in_memory + num_rows > self.max_rows_in_memory
+        };
+
+        let page = if spill {
+            self.write_page(block).await?
+        } else {
+            CursorPage::Memory(block)
+        };
+
+        let mut state = self.state.lock();
+        if !spill {
+            state.rows_in_memory += num_rows;
+        }
+        let rows_before = match state.pages.last() {
+            Some(last) => state.rows_before[state.pages.len() - 1] + last.num_rows(),
+            None => 0,
+        };
+        state.pages.push(Arc::new(page));
+        state.rows_before.push(rows_before);
+        drop(state);
+
+        self.notify.notify_waiters();
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_a_page(&self, page_no: usize, tp: &Wait) -> Result<ResponseData> {
+        loop {
+            // Register before looking up the page, so a page added meanwhile is not missed.
+            let notified = self.notify.notified();
+            match self.lookup(page_no)? {
+                Lookup::Page {
+                    page,
+                    total_rows,
+                    next_page_no,
+                } => {
+                    let block = self.read_page(&page).await?;
+                    return Ok(ResponseData {
+                        page: Page {
+                            data: JsonBlock::new(
+                                self.schema.clone(),
+                                &block,
+                                &self.format_settings,
+                            )?,
+                            total_rows,
+                        },
+                        next_page_no,
+                    });
+                }
+                Lookup::End { total_rows } => {
+                    // The result has no more rows, e.g. it's empty.
+                    return Ok(self.empty_response(total_rows, None));
+                }
+                Lookup::Pending { total_rows } => {
+                    let timeout = match tp {
+                        Wait::Async => true,
+                        Wait::Deadline(t) => {
+                            let d = t.saturating_duration_since(Instant::now());
+                            tokio::time::timeout(d, notified).await.is_err()
+                        }
+                    };
+                    if timeout {
+                        // The page is not ready yet, ask the client to fetch it again.
+                        return Ok(self.empty_response(total_rows, Some(page_no)));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Takes the pages one by one when the result is streamed instead of paged.
+    #[async_backtrace::framed]
+    pub async fn next_block(&self) -> Option<DataBlock> {
+        let page_no = self.next_block_page.fetch_add(1, Ordering::SeqCst);
+        loop {
+            let notified = self.notify.notified();
+            match self.lookup(page_no) {
+                Ok(Lookup::Page { page, .. }) => {
+                    return match self.read_page(&page).await {
+                        Ok(block) => Some(block),
+                        Err(cause) => {
+                            error!(
+                                "http query {} fail to read the result: {:?}",
+                                &self.query_id, cause
+                            );
+                            None
+                        }
+                    };
+                }
+                Ok(Lookup::Pending { .. }) => notified.await,
+                Ok(Lookup::End { .. }) | Err(_) => return None,
+            }
+        }
+    }
+
+    fn lookup(&self, page_no: usize) -> Result<Lookup> {
+        let state = self.state.lock();
+        if let Some(error) = &state.error {
+            return Err(error.clone());
+        }
+        if state.closed {
+            return Err(ErrorCode::HttpNotFound(format!(
+                "the result of query {} is released",
+                &self.query_id
+            )));
+        }
+
+        let num_pages = state.pages.len();
+        let total_rows = |pages: usize| match pages {
+            0 => 0,
+            n => state.rows_before[n - 1] + state.pages[n - 1].num_rows(),
+        };
+        if page_no < num_pages {
+            let next_page_no = if page_no + 1 < num_pages || !state.end {
+                Some(page_no + 1)
+            } else {
+                None
+            };
+            Ok(Lookup::Page {
+                page: state.pages[page_no].clone(),
+                total_rows: total_rows(page_no + 1),
+                next_page_no,
+            })
+        } else if !state.end {
+            Ok(Lookup::Pending {
+                total_rows: total_rows(num_pages),
+            })
+        } else if page_no == num_pages {
+            Ok(Lookup::End {
+                total_rows: total_rows(num_pages),
+            })
+        } else {
+            let message = format!("wrong page number {}", page_no);
+            Err(ErrorCode::HttpNotFound(message))
+        }
+    }
+
+    fn empty_response(&self, total_rows: usize, next_page_no: Option<usize>) -> ResponseData {
+        ResponseData {
+            page: Page {
+                data: JsonBlock {
+                    schema: self.schema.clone(),
+                    data: vec![],
+                },
+                total_rows,
+            },
+            next_page_no,
+        }
+    }
+
+    /// Releases the result, the spilled pages are removed from the storage.
+    pub fn close(&self) {
+        self.block_receiver.close();
+
+        let pages = {
+            let mut state = self.state.lock();
+            state.closed = true;
+            state.rows_in_memory = 0;
+            std::mem::take(&mut state.pages)
+        };
+        self.notify.notify_waiters();
+
+        let locations = pages
+            .iter()
+            .filter_map(|page| match page.as_ref() {
+                CursorPage::Spilled { location, .. } => Some(location.clone()),
+                CursorPage::Memory(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!("Cannot delete spill file {}, cause: {:?}", &location, cause);
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn write_page(&self, block: DataBlock) -> Result<CursorPage> {
+        let block = block.convert_to_full();
+
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut write_data = vec![];
+        for entry in block.columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        self.operator.write(&location, write_data).await?;
+
+        Ok(CursorPage::Spilled {
+            location,
+            columns_layout,
+            num_rows: block.num_rows(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_page(&self, page: &CursorPage) -> Result<DataBlock> {
+        let (location, columns_layout, num_rows) = match page {
+            CursorPage::Memory(block) => return Ok(block.clone()),
+            CursorPage::Spilled {
+                location,
+                columns_layout,
+                num_rows,
+            } => (location, columns_layout, *num_rows),
+        };
+
+        let data = self.operator.read(location).await?;
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(columns_layout.len());
+        for column_layout in columns_layout.iter() {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!("Cannot deserialize spill file {}", location))
+                })?;
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
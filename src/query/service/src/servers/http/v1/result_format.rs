@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_formats::output_format::ArrowOutputFormat;
+use common_formats::output_format::OutputFormat;
+use common_formats::ClickhouseFormatType;
+use common_formats::FileFormatOptionsExt;
+use http::header::ACCEPT;
+use http::HeaderMap;
+use poem::Body;
+use poem::IntoResponse;
+use poem::Response;
+use tracing::info;
+
+use super::http_query_handlers::HEADER_QUERY_ID;
+use super::query::HttpQuery;
+use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::http::v1::QueryResponse;
+
+/// The encodings of the result a client can ask for with the `Accept` header of `/v1/query`,
+/// the whole result is streamed in the body of the response instead of being paged in json.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultFormat {
+    Arrow,
+    Csv,
+    JsonCompactEachRow,
+}
+
+impl ResultFormat {
+    /// The first supported media type in the `Accept` header, parameters are ignored.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .find_map(|media_type| {
+                let essence = media_type.split(';').next().unwrap_or_default().trim();
+                match essence.to_ascii_lowercase().as_str() {
+                    "application/vnd.apache.arrow.stream" => Some(Self::Arrow),
+                    "text/csv" => Some(Self::Csv),
+                    "application/x-ndjson" => Some(Self::JsonCompactEachRow),
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Arrow => "application/vnd.apache.arrow.stream",
+            Self::Csv => "text/csv; charset=UTF-8; header=present",
+            Self::JsonCompactEachRow => "application/x-ndjson; charset=UTF-8",
+        }
+    }
+
+    async fn output_format(&self, query: &HttpQuery) -> Result<Box<dyn OutputFormat>> {
+        let schema = infer_table_schema(&query.result_schema().await)?;
+        let name = match self {
+            Self::Arrow => return Ok(Box::new(ArrowOutputFormat::create(schema))),
+            Self::Csv => "CSVWithNames",
+            Self::JsonCompactEachRow => "JSONCompactEachRow",
+        };
+        let format = ClickhouseFormatType::parse_clickhouse_format(name)?;
+        FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+            format,
+            schema,
+            &query.settings(),
+        )
+    }
+}
+
+/// Streams all the result of the query in one response.
+///
+/// A query failed before producing any data gets the usual json response with the error,
+/// while an error in the middle of the result aborts the body, so that a client never takes
+/// a truncated result as a complete one.
+#[async_backtrace::framed]
+pub(crate) async fn stream_result(query: Arc<HttpQuery>, format: ResultFormat) -> Response {
+    let http_query_manager = HttpQueryManager::instance();
+    query.update_expire_time(true).await;
+
+    let mut output_format = match format.output_format(&query).await {
+        Ok(output_format) => output_format,
+        Err(err) => {
+            query.kill().await;
+            http_query_manager.remove_query(&query.id).await;
+            return QueryResponse::fail_to_start_sql(&err).into_response();
+        }
+    };
+
+    let first_block = query.next_block().await;
+    if first_block.is_none() {
+        let resp = query.get_response_state_only().await;
+        if resp.state.error.is_some() {
+            http_query_manager.remove_query(&query.id).await;
+            return QueryResponse::from_internal(query.id.to_string(), resp, true).into_response();
+        }
+    }
+    info!(
+        "streaming result of http query {} as {:?}",
+        &query.id, format
+    );
+
+    let query_id = query.id.clone();
+    let body = stream! {
+        let mut ok = true;
+        let mut next_block = first_block;
+        yield output_format.serialize_prefix();
+        while let Some(block) = next_block {
+            let bytes = output_format.serialize_block(&block);
+            ok = bytes.is_ok();
+            yield bytes;
+            if !ok {
+                break;
+            }
+            // a client reading slowly should not lose the query
+            query.update_expire_time(true).await;
+            next_block = query.next_block().await;
+        }
+        if ok {
+            match query.get_response_state_only().await.state.error {
+                Some(err) => yield Err(err),
+                None => yield output_format.finalize(),
+            }
+        } else {
+            query.kill().await;
+        }
+        // a dropped stream leaves the query to expire
+        http_query_manager.remove_query(&query.id).await;
+    };
+
+    Body::from_bytes_stream(body)
+        .with_content_type(format.content_type())
+        .with_header(HEADER_QUERY_ID, query_id)
+        .into_response()
+}
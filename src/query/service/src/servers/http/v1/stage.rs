@@ -25,6 +25,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::HttpQueryContext;
+use crate::interpreters::StageQuota;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 
@@ -76,6 +77,9 @@ pub async fn upload_to_stage(
     };
 
     let op = StageTable::get_op(&stage).map_err(InternalServerError)?;
+    let mut quota = StageQuota::try_load(context.get_tenant().as_str(), &stage)
+        .await
+        .map_err(InternalServerError)?;
 
     let relative_path = req
         .headers()
@@ -92,6 +96,9 @@ pub async fn upload_to_stage(
             None => uuid::Uuid::new_v4().to_string(),
         };
         let bytes = field.bytes().await.map_err(InternalServerError)?;
+        quota
+            .check_and_add(1, bytes.len() as u64)
+            .map_err(|e| poem::Error::from_string(e.message(), StatusCode::FORBIDDEN))?;
         let file_path = format!("{relative_path}/{name}")
             .trim_start_matches('/')
             .to_string();
@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_ast::ast::Expr;
+use common_ast::ast::Literal;
+use common_ast::ast::Statement;
+use common_ast::ast::TypeName;
+use common_ast::ast::UnaryOperator;
 use common_base::base::convert_byte_size;
 use common_base::base::convert_number_size;
 use common_base::base::tokio::io::AsyncWrite;
@@ -28,17 +34,26 @@ use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
+use common_sql::bind_placeholders;
+use common_sql::count_placeholders;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
 use common_sql::Planner;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
 use futures_util::StreamExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::Value;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use tracing::error;
 use tracing::info;
@@ -60,9 +75,20 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase<W: AsyncWrite + Send + Unpin> {
     session: Arc<Session>,
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
     generic_hold: PhantomData<W>,
 }
 
+/// A statement prepared by `COM_STMT_PREPARE`, the parameters of `COM_STMT_EXECUTE` are
+/// bound to its `?` placeholders.
+#[derive(Clone)]
+struct PreparedStatement {
+    sql: String,
+    stmt: Statement,
+    format: Option<String>,
+}
+
 pub struct InteractiveWorker<W: AsyncWrite + Send + Unpin> {
     base: InteractiveWorkerBase<W>,
     version: String,
@@ -270,34 +296,133 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let prepared = self.prepare_statement(query).await;
+        let (statement, num_params) = match prepared {
+            Ok(prepared) => prepared,
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        info!("Prepare statement: {}", query);
+        self.next_statement_id = self.next_statement_id.wrapping_add(1);
+        let id = self.next_statement_id;
+        self.prepared_statements.insert(id, statement);
+
+        // The types of the parameters are unknown until they are bound, and the result
+        // columns are sent by `COM_STMT_EXECUTE`.
+        let params = vec![
+            Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            };
+            num_params
+        ];
+        writer.reply(id, &params, &[]).await?;
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn prepare_statement(&self, query: &str) -> Result<(PreparedStatement, usize)> {
+        let context = self.session.create_query_context().await?;
+        let planner = Planner::new(context);
+        let (stmt, format) = planner.parse_prepared_sql(query)?;
+        let num_params = count_placeholders(&stmt)?;
+        Ok((
+            PreparedStatement {
+                sql: query.to_string(),
+                stmt,
+                format,
+            },
+            num_params,
+        ))
+    }
+
     #[async_backtrace::framed]
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let statement = match self.prepared_statements.get(&id) {
+            Some(statement) => statement.clone(),
+            None => {
+                let message = format!(
+                    "Unknown prepared statement handler ({}) given to mysqld_stmt_execute",
+                    id
+                );
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_STMT_HANDLER, message.as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let params = params
+            .into_iter()
+            .map(|param| param_to_expr(param.value))
+            .collect::<Result<Vec<_>>>();
+        let sql = statement.sql.clone();
+        let query_result = match params {
+            Ok(params) => self.execute_statement(statement, &params).await,
+            Err(error) => Err(error),
+        }
+        .map_err(|err| err.display_with_sql(&sql));
+
+        let format = self.session.get_format_settings();
+
+        let mut write_result = writer.write(query_result, &format).await;
+
+        if let Err(cause) = write_result {
+            let suffix = format!("(while in query {})", sql);
+            write_result = Err(cause.add_message_back(suffix));
+        }
+
+        histogram!(
+            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+            instant.elapsed()
+        );
+
+        write_result
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, statement, params))]
+    #[async_backtrace::framed]
+    async fn execute_statement(
+        &mut self,
+        statement: PreparedStatement,
+        params: &[Expr],
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Execute statement: {}", statement.sql);
+        let PreparedStatement {
+            sql,
+            mut stmt,
+            format,
+        } = statement;
+        bind_placeholders(&mut stmt, params)?;
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_stmt(stmt, format).await?;
+        Self::exec_plan(context, plan, extras, &sql).await
     }
 
+    /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, stmt_id: u32) {
+        self.prepared_statements.remove(&stmt_id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -326,6 +451,7 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
                         None,
                         has_result,
                         schema,
+                        vec![],
                         query.to_string(),
                     ),
                     None,
@@ -337,33 +463,43 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::exec_plan(context, plan, extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = interpreter.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    #[async_backtrace::framed]
+    async fn exec_plan(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        extras: PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+        let has_result_set = plan.has_result_set();
+        let column_origins = plan.result_column_origins();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = interpreter.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        column_origins,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -442,6 +578,8 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
         InteractiveWorker::<W> {
             base: InteractiveWorkerBase::<W> {
                 session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
                 generic_hold: PhantomData::default(),
             },
             salt: scramble,
@@ -451,6 +589,82 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorker<W> {
     }
 }
 
+/// Converts a parameter of `COM_STMT_EXECUTE` to the expression bound to a placeholder.
+fn param_to_expr(value: Value) -> Result<Expr> {
+    let literal = |lit| Expr::Literal { span: None, lit };
+    let expr = match value.into_inner() {
+        ValueInner::NULL => literal(Literal::Null),
+        ValueInner::Int(v) if v < 0 => Expr::UnaryOp {
+            span: None,
+            op: UnaryOperator::Minus,
+            expr: Box::new(literal(Literal::UInt64(v.unsigned_abs()))),
+        },
+        ValueInner::Int(v) => literal(Literal::UInt64(v as u64)),
+        ValueInner::UInt(v) => literal(Literal::UInt64(v)),
+        ValueInner::Double(v) => literal(Literal::Float64(v)),
+        ValueInner::Bytes(v) => {
+            let v = String::from_utf8(v.to_vec()).map_err(|e| {
+                ErrorCode::BadBytes(format!("Invalid utf8 string parameter: {}", e))
+            })?;
+            literal(Literal::String(v))
+        }
+        ValueInner::Date(v) => Expr::Cast {
+            span: None,
+            expr: Box::new(literal(Literal::String(format_date_param(v)))),
+            target_type: TypeName::Date,
+            pg_style: false,
+        },
+        ValueInner::Datetime(v) => Expr::Cast {
+            span: None,
+            expr: Box::new(literal(Literal::String(format_datetime_param(v)))),
+            target_type: TypeName::Timestamp,
+            pg_style: false,
+        },
+        ValueInner::Time(v) => literal(Literal::String(format_time_param(v))),
+    };
+    Ok(expr)
+}
+
+/// Formats a `DATE` in the binary protocol, which is `year(2) month(1) day(1)`.
+fn format_date_param(v: &[u8]) -> String {
+    let byte = |i: usize| v.get(i).copied().unwrap_or_default();
+    let year = u16::from_le_bytes([byte(0), byte(1)]);
+    format!("{:04}-{:02}-{:02}", year, byte(2), byte(3))
+}
+
+/// Formats a `DATETIME` or `TIMESTAMP` in the binary protocol, which is the date followed
+/// by `[hour(1) minute(1) second(1) [microsecond(4)]]`.
+fn format_datetime_param(v: &[u8]) -> String {
+    let byte = |i: usize| v.get(i).copied().unwrap_or_default();
+    let micros = u32::from_le_bytes([byte(7), byte(8), byte(9), byte(10)]);
+    format!(
+        "{} {:02}:{:02}:{:02}.{:06}",
+        format_date_param(v),
+        byte(4),
+        byte(5),
+        byte(6),
+        micros
+    )
+}
+
+/// Formats a `TIME` in the binary protocol, which is
+/// `is_negative(1) days(4) hour(1) minute(1) second(1) [microsecond(4)]`.
+fn format_time_param(v: &[u8]) -> String {
+    let byte = |i: usize| v.get(i).copied().unwrap_or_default();
+    let sign = if byte(0) == 1 { "-" } else { "" };
+    let days = u32::from_le_bytes([byte(1), byte(2), byte(3), byte(4)]);
+    let hours = days as u64 * 24 + byte(5) as u64;
+    let micros = u32::from_le_bytes([byte(8), byte(9), byte(10), byte(11)]);
+    format!(
+        "{}{:02}:{:02}:{:02}.{:06}",
+        sign,
+        hours,
+        byte(6),
+        byte(7),
+        micros
+    )
+}
+
 struct ContextProgressReporter {
     context: Arc<QueryContext>,
     instant: Instant,
@@ -26,6 +26,7 @@ use common_expression::SendableDataBlockStream;
 use common_formats::field_encoder::FieldEncoderRowBased;
 use common_formats::field_encoder::FieldEncoderValues;
 use common_io::prelude::FormatSettings;
+use common_sql::ColumnOrigin;
 use futures_util::StreamExt;
 use opensrv_mysql::*;
 use tracing::error;
@@ -45,6 +46,7 @@ pub struct QueryResult {
     extra_info: Option<Box<dyn ProgressReporter + Send>>,
     has_result_set: bool,
     schema: DataSchemaRef,
+    column_origins: Vec<Option<ColumnOrigin>>,
     sql: String,
 }
 
@@ -54,6 +56,7 @@ impl QueryResult {
         extra_info: Option<Box<dyn ProgressReporter + Send>>,
         has_result_set: bool,
         schema: DataSchemaRef,
+        column_origins: Vec<Option<ColumnOrigin>>,
         sql: String,
     ) -> QueryResult {
         QueryResult {
@@ -61,6 +64,7 @@ impl QueryResult {
             extra_info,
             has_result_set,
             schema,
+            column_origins,
             sql,
         }
     }
@@ -68,6 +72,8 @@ impl QueryResult {
 
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
+    /// Whether the rows are written in the binary protocol of the prepared statements.
+    binary: bool,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -85,7 +91,17 @@ fn write_field<W: AsyncWrite + Unpin>(
 
 impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: false,
+        }
+    }
+
+    pub fn create_binary(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: true,
+        }
     }
 
     #[async_backtrace::framed]
@@ -98,9 +114,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             match query_result {
                 Ok((query_result, query_format)) => {
                     if let Some(format) = query_format {
-                        Self::ok(query_result, writer, &format).await?
+                        Self::ok(query_result, writer, &format, self.binary).await?
                     } else {
-                        Self::ok(query_result, writer, format).await?
+                        Self::ok(query_result, writer, format, self.binary).await?
                     }
                 }
                 Err(error) => Self::err(&error, writer).await?,
@@ -114,6 +130,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         mut query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
         format: &FormatSettings,
+        binary: bool,
     ) -> Result<()> {
         // XXX: num_columns == 0 may is error?
         if !query_result.has_result_set {
@@ -176,6 +193,8 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Uuid => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
                 _ => Err(ErrorCode::Unimplemented(format!(
                     "Unsupported column type:{:?}",
@@ -184,21 +203,54 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             }
         }
 
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
+        fn make_column_from_field(
+            field: &DataField,
+            origin: Option<&ColumnOrigin>,
+            binary: bool,
+        ) -> Result<Column> {
+            convert_field_type(field).map(|column_type| {
+                // The values of these types are encoded as text, which are only valid for the
+                // string columns in the binary protocol.
+                let column_type = match column_type {
+                    ColumnType::MYSQL_TYPE_FLOAT
+                    | ColumnType::MYSQL_TYPE_DOUBLE
+                    | ColumnType::MYSQL_TYPE_DATE
+                    | ColumnType::MYSQL_TYPE_DATETIME
+                        if binary =>
+                    {
+                        ColumnType::MYSQL_TYPE_VARCHAR
+                    }
+                    column_type => column_type,
+                };
+                // The column definition only carries the table, the column keeps the
+                // name of the result so that aliases are not lost.
+                Column {
+                    table: origin.map(|o| o.table.clone()).unwrap_or_default(),
+                    column: field.name().to_string(),
+                    coltype: column_type,
+                    colflags: ColumnFlags::empty(),
+                }
             })
         }
 
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
+        fn convert_schema(
+            schema: &DataSchemaRef,
+            column_origins: &[Option<ColumnOrigin>],
+            binary: bool,
+        ) -> Result<Vec<Column>> {
+            schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| {
+                    let origin = column_origins.get(idx).and_then(|o| o.as_ref());
+                    make_column_from_field(field, origin, binary)
+                })
+                .collect()
         }
 
         let _tz = format.timezone;
-        match convert_schema(&query_result.schema) {
+        match convert_schema(&query_result.schema, &query_result.column_origins, binary) {
             Err(error) => Self::err(&error, dataset_writer).await,
             Ok(columns) => {
                 let mut row_writer = dataset_writer.start(&columns).await?;
@@ -0,0 +1,564 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_sql::Planner;
+use futures_util::StreamExt;
+use metrics::histogram;
+use rand::Rng;
+use tracing::error;
+use tracing::info;
+use tracing::Instrument;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_message::read_message;
+use crate::servers::postgres::postgres_message::read_startup_message;
+use crate::servers::postgres::postgres_message::BackendMessage;
+use crate::servers::postgres::postgres_message::FrontendMessage;
+use crate::servers::postgres::postgres_message::StartupMessage;
+use crate::servers::postgres::postgres_types::bind_params;
+use crate::servers::postgres::postgres_types::command_tag;
+use crate::servers::postgres::postgres_types::encode_rows;
+use crate::servers::postgres::postgres_types::field_descriptions;
+use crate::servers::postgres::postgres_types::num_params;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+// The buffered messages are written to the client once they are larger than this.
+const WRITE_BUFFER_FLUSH_SIZE: usize = 64 * 1024;
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: String,
+    statements: HashMap<String, PreparedStatement>,
+    // The bound queries by portal name.
+    portals: HashMap<String, String>,
+    buf: Vec<u8>,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
+        InteractiveWorker {
+            session,
+            client_addr,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            buf: Vec::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run<R, W>(mut self, mut reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let params = loop {
+            match read_startup_message(&mut reader).await? {
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    // the client goes on without encryption
+                    self.send(BackendMessage::EncryptionNotSupported);
+                    self.flush(&mut writer).await?;
+                }
+                StartupMessage::CancelRequest { .. } => {
+                    info!("PostgreSQL cancel request is not supported, ignored");
+                    return Ok(());
+                }
+                StartupMessage::Startup { params } => break params,
+            }
+        };
+
+        if let Err(cause) = self.startup(&params, &mut reader, &mut writer).await {
+            self.send_error(&cause);
+            self.flush(&mut writer).await?;
+            return Err(cause);
+        }
+
+        // After an error in the extended query protocol, the messages are discarded until `Sync`.
+        let mut discarding = false;
+        while let Some(message) = read_message(&mut reader).await? {
+            if self.session.is_aborting() {
+                let cause = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.send_error(&cause);
+                self.flush(&mut writer).await?;
+                return Err(cause);
+            }
+
+            match message {
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Sync => {
+                    discarding = false;
+                    self.portals.remove("");
+                    self.send(BackendMessage::ReadyForQuery);
+                    self.flush(&mut writer).await?;
+                }
+                FrontendMessage::Flush => self.flush(&mut writer).await?,
+                FrontendMessage::Query(query) => {
+                    let instant = Instant::now();
+                    if let Err(cause) = self.do_simple_query(&query, &mut writer).await {
+                        self.send_error(&cause.display_with_sql(&query));
+                    }
+                    histogram!(
+                        super::postgres_metrics::METRIC_POSTGRES_PROCESSOR_REQUEST_DURATION,
+                        instant.elapsed()
+                    );
+                    self.send(BackendMessage::ReadyForQuery);
+                    self.flush(&mut writer).await?;
+                }
+                _ if discarding => {}
+                message => {
+                    if let Err(cause) = self.do_extended_query(message, &mut writer).await {
+                        self.send_error(&cause);
+                        discarding = true;
+                    }
+                }
+            }
+        }
+
+        self.flush(&mut writer).await
+    }
+
+    #[async_backtrace::framed]
+    async fn startup<R, W>(
+        &mut self,
+        params: &HashMap<String, String>,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let user = params
+            .get("user")
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("no user name in startup message"))?;
+        self.authenticate(user, reader, writer).await?;
+        self.send(BackendMessage::AuthenticationOk);
+
+        let settings = self.session.get_settings();
+        let application_name = params.get("application_name").cloned();
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION.to_string()),
+            ("server_encoding", "UTF8".to_string()),
+            ("client_encoding", "UTF8".to_string()),
+            ("DateStyle", "ISO, YMD".to_string()),
+            ("TimeZone", settings.get_timezone()?),
+            ("integer_datetimes", "on".to_string()),
+            ("standard_conforming_strings", "on".to_string()),
+            ("application_name", application_name.unwrap_or_default()),
+        ] {
+            self.send(BackendMessage::ParameterStatus(name.to_string(), value));
+        }
+
+        // the cancel requests are not supported, the key is only expected by the clients
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            (rng.gen(), rng.gen())
+        };
+        self.send(BackendMessage::BackendKeyData {
+            process_id,
+            secret_key,
+        });
+
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            let init_query = format!("USE `{}`", database.replace('`', "``"));
+            let len = self.buf.len();
+            self.execute_query(&init_query, false, writer).await?;
+            // the command tag of `USE` is not expected by the client
+            self.buf.truncate(len);
+        }
+
+        self.send(BackendMessage::ReadyForQuery);
+        self.flush(writer).await
+    }
+
+    /// Authenticates the user with the cleartext password, which is only asked for if the
+    /// user has a password.
+    #[async_backtrace::framed]
+    async fn authenticate<R, W>(&mut self, user: &str, reader: &mut R, writer: &mut W) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let auth_mgr = AuthMgr::instance();
+        let hostname = self.client_addr.split(':').next().map(|ip| ip.to_string());
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password: None,
+            hostname: hostname.clone(),
+        };
+        if auth_mgr
+            .auth(self.session.clone(), &credential)
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+
+        self.send(BackendMessage::AuthenticationCleartextPassword);
+        self.flush(writer).await?;
+        let password = match read_message(reader).await? {
+            Some(FrontendMessage::Password(password)) => password,
+            _ => return Err(ErrorCode::AuthenticateFailure("password is expected")),
+        };
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password: Some(password),
+            hostname,
+        };
+        auth_mgr
+            .auth(self.session.clone(), &credential)
+            .await
+            .map_err(|failure| {
+                error!(
+                    "PostgreSQL handler authenticate failed, \
+                        user_name: {}, \
+                        client_address: {}, \
+                        failure_cause: {}",
+                    user, self.client_addr, failure
+                );
+                ErrorCode::AuthenticateFailure(format!(
+                    "password authentication failed for user \"{}\"",
+                    user
+                ))
+            })
+    }
+
+    /// Runs the statements of the query one by one, the rest are skipped after an error.
+    #[async_backtrace::framed]
+    async fn do_simple_query<W>(&mut self, query: &str, writer: &mut W) -> Result<()>
+    where W: AsyncWrite + Send + Unpin {
+        let statements = split_statements(query)?;
+        if statements.is_empty() {
+            self.send(BackendMessage::EmptyQueryResponse);
+            return Ok(());
+        }
+
+        for statement in statements {
+            self.execute_query(statement, true, writer).await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_extended_query<W>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut W,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                self.send(BackendMessage::ParseComplete);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                if result_formats.iter().any(|format| *format != 0) {
+                    return Err(ErrorCode::Unimplemented(
+                        "binary format of results is not supported",
+                    ));
+                }
+                let statement = self.statement(&statement)?;
+                let query = bind_params(
+                    &statement.query,
+                    &statement.param_types,
+                    &param_formats,
+                    &params,
+                )?;
+                self.portals.insert(portal, query);
+                self.send(BackendMessage::BindComplete);
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.statement(&name)?;
+                let num_params = num_params(&statement.query).max(statement.param_types.len());
+                let param_types = (0..num_params)
+                    .map(|i| match statement.param_types.get(i) {
+                        Some(oid) if *oid != 0 => *oid,
+                        _ => TEXT_OID,
+                    })
+                    .collect::<Vec<_>>();
+                // the result columns don't depend on the values of the parameters
+                let params = vec![None; num_params];
+                let query = bind_params(&statement.query, &[], &[], &params)?;
+                self.send(BackendMessage::ParameterDescription(param_types));
+                self.describe(&query).await?;
+            }
+            FrontendMessage::Describe { name, .. } => {
+                let query = self.portal(&name)?.to_string();
+                self.describe(&query).await?;
+            }
+            FrontendMessage::Execute { portal, .. } => {
+                // all the rows are sent, the row limit of the portal is not supported
+                let query = self.portal(&portal)?.to_string();
+                let instant = Instant::now();
+                self.execute_query(&query, false, writer)
+                    .await
+                    .map_err(|cause| cause.display_with_sql(&query))?;
+                histogram!(
+                    super::postgres_metrics::METRIC_POSTGRES_PROCESSOR_REQUEST_DURATION,
+                    instant.elapsed()
+                );
+            }
+            FrontendMessage::Close { kind, name } => {
+                match kind {
+                    b'S' => self.statements.remove(&name).map(|_| ()),
+                    _ => self.portals.remove(&name).map(|_| ()),
+                };
+                self.send(BackendMessage::CloseComplete);
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("unexpected password message"));
+            }
+            _ => unreachable!("handled by the main loop"),
+        }
+        Ok(())
+    }
+
+    fn statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn portal(&self, name: &str) -> Result<&str> {
+        self.portals
+            .get(name)
+            .map(|query| query.as_str())
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    /// Describes the result columns of the query by planning it.
+    #[async_backtrace::framed]
+    async fn describe(&mut self, query: &str) -> Result<()> {
+        match self.result_schema(query).await? {
+            Some(schema) => {
+                self.send(BackendMessage::RowDescription(field_descriptions(&schema)));
+            }
+            None => self.send(BackendMessage::NoData),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn result_schema(&self, query: &str) -> Result<Option<DataSchemaRef>> {
+        if transaction_command(query).is_some() {
+            return Ok(None);
+        }
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await?;
+        Ok(plan.has_result_set().then(|| plan.schema()))
+    }
+
+    /// Executes the query and sends the result rows, the `RowDescription` is sent first if
+    /// `describe`, otherwise the result has been described by `Describe`.
+    #[async_backtrace::framed]
+    async fn execute_query<W>(
+        &mut self,
+        query: &str,
+        describe: bool,
+        writer: &mut W,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        // Databend has no transactions, every statement is committed once it finishes.
+        if let Some(command) = transaction_command(query) {
+            self.send(BackendMessage::CommandComplete(command.to_string()));
+            return Ok(());
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+
+        let has_result_set = plan.has_result_set();
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+        let schema = interpreter.schema();
+        let mut blocks = Self::exec_query(interpreter, &context).await?;
+
+        if has_result_set && describe {
+            self.send(BackendMessage::RowDescription(field_descriptions(&schema)));
+        }
+
+        let format = context.get_format_settings()?;
+        let encoder = FieldEncoderValues::create_for_postgres_handler(format.timezone);
+        let mut num_rows = 0;
+        while let Some(block) = blocks.next().await {
+            let block = block?;
+            if !has_result_set {
+                continue;
+            }
+            num_rows += block.num_rows() as u64;
+            for row in encode_rows(&block, &encoder) {
+                self.send(BackendMessage::DataRow(row));
+            }
+            if self.buf.len() >= WRITE_BUFFER_FLUSH_SIZE {
+                self.flush(writer).await?;
+            }
+        }
+
+        if !has_result_set {
+            num_rows = context.get_write_progress_value().rows as u64;
+        }
+        self.send(BackendMessage::CommandComplete(command_tag(
+            query,
+            has_result_set,
+            num_rows,
+        )));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn exec_query(
+        interpreter: InterpreterPtr,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn({
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx).await }.in_current_span()
+        })?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+
+    fn send(&mut self, message: BackendMessage) {
+        message.encode(&mut self.buf);
+    }
+
+    fn send_error(&mut self, cause: &ErrorCode) {
+        if cause.code() != ErrorCode::ABORTED_QUERY && cause.code() != ErrorCode::ABORTED_SESSION {
+            error!("OnQuery Error: {:?}", cause);
+        }
+        self.send(BackendMessage::ErrorResponse {
+            code: sql_state(cause).to_string(),
+            message: cause.message(),
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn flush<W>(&mut self, writer: &mut W) -> Result<()>
+    where W: AsyncWrite + Send + Unpin {
+        writer.write_all(&self.buf).await?;
+        writer.flush().await?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+/// The statements of a simple query, which may contain several statements separated by `;`.
+fn split_statements(query: &str) -> Result<Vec<&str>> {
+    let mut statements = vec![];
+    let mut start = 0;
+    for token in tokenize_sql(query)? {
+        if matches!(token.kind, TokenKind::SemiColon | TokenKind::EOI) {
+            let statement = query[start..token.span.start].trim();
+            if !statement.is_empty() {
+                statements.push(statement);
+            }
+            start = token.span.end;
+        }
+    }
+    Ok(statements)
+}
+
+/// The tag of the transaction statements, which are accepted and ignored.
+fn transaction_command(query: &str) -> Option<&'static str> {
+    let words = query
+        .trim()
+        .trim_end_matches(';')
+        .split_whitespace()
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+    match words.first().map(|word| word.as_str()) {
+        Some("BEGIN") | Some("START") if words.len() <= 2 || words[1] == "TRANSACTION" => {
+            Some("BEGIN")
+        }
+        Some("COMMIT") | Some("END") if words.len() <= 2 => Some("COMMIT"),
+        Some("ROLLBACK") | Some("ABORT") if words.len() <= 2 => Some("ROLLBACK"),
+        _ => None,
+    }
+}
+
+/// The SQLSTATE of the error, see https://www.postgresql.org/docs/current/errcodes-appendix.html
+fn sql_state(cause: &ErrorCode) -> &'static str {
+    match cause.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::UNKNOWN_FUNCTION => "42883",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        ErrorCode::ABORTED_QUERY | ErrorCode::ABORTED_SESSION => "57014",
+        _ => "XX000",
+    }
+}
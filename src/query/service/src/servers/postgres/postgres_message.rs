@@ -0,0 +1,410 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// The frontend messages larger than this are rejected, as PostgreSQL does for the startup packet.
+const MAX_MESSAGE_SIZE: usize = 1024 * 1024 * 1024;
+
+/// The first message of a connection, which has no message type.
+#[derive(Debug, PartialEq)]
+pub enum StartupMessage {
+    Startup { params: HashMap<String, String> },
+    SslRequest,
+    GssEncRequest,
+    CancelRequest { process_id: u32, secret_key: u32 },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// Describes a prepared statement (`S`) or a portal (`P`).
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    /// Closes a prepared statement (`S`) or a portal (`P`).
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Password(Vec<u8>),
+    Terminate,
+}
+
+/// The field of a `RowDescription`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BackendMessage {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus(String, String),
+    BackendKeyData {
+        process_id: u32,
+        secret_key: u32,
+    },
+    /// The transaction status, always `I` (idle) as there are no transactions.
+    ReadyForQuery,
+    RowDescription(Vec<FieldDescription>),
+    /// The values of a row in the text format, `None` is NULL.
+    DataRow(Vec<Option<Vec<u8>>>),
+    CommandComplete(String),
+    EmptyQueryResponse,
+    ErrorResponse {
+        code: String,
+        message: String,
+    },
+    NoticeResponse {
+        message: String,
+    },
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    ParameterDescription(Vec<u32>),
+    NoData,
+    /// The answer to an SSL or GSSAPI encryption request, which is not supported.
+    EncryptionNotSupported,
+}
+
+impl BackendMessage {
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            BackendMessage::AuthenticationOk => {
+                write_message(buf, b'R', |buf| write_i32(buf, 0));
+            }
+            BackendMessage::AuthenticationCleartextPassword => {
+                write_message(buf, b'R', |buf| write_i32(buf, 3));
+            }
+            BackendMessage::ParameterStatus(name, value) => {
+                write_message(buf, b'S', |buf| {
+                    write_cstr(buf, name);
+                    write_cstr(buf, value);
+                });
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                write_message(buf, b'K', |buf| {
+                    write_i32(buf, *process_id as i32);
+                    write_i32(buf, *secret_key as i32);
+                });
+            }
+            BackendMessage::ReadyForQuery => {
+                write_message(buf, b'Z', |buf| buf.push(b'I'));
+            }
+            BackendMessage::RowDescription(fields) => {
+                write_message(buf, b'T', |buf| {
+                    write_i16(buf, fields.len() as i16);
+                    for field in fields {
+                        write_cstr(buf, &field.name);
+                        // table oid and column attribute number
+                        write_i32(buf, 0);
+                        write_i16(buf, 0);
+                        write_i32(buf, field.type_oid as i32);
+                        write_i16(buf, field.type_size);
+                        // type modifier and format code (text)
+                        write_i32(buf, -1);
+                        write_i16(buf, 0);
+                    }
+                });
+            }
+            BackendMessage::DataRow(values) => {
+                write_message(buf, b'D', |buf| {
+                    write_i16(buf, values.len() as i16);
+                    for value in values {
+                        match value {
+                            None => write_i32(buf, -1),
+                            Some(value) => {
+                                write_i32(buf, value.len() as i32);
+                                buf.extend_from_slice(value);
+                            }
+                        }
+                    }
+                });
+            }
+            BackendMessage::CommandComplete(tag) => {
+                write_message(buf, b'C', |buf| write_cstr(buf, tag));
+            }
+            BackendMessage::EmptyQueryResponse => write_message(buf, b'I', |_| {}),
+            BackendMessage::ErrorResponse { code, message } => {
+                write_message(buf, b'E', |buf| {
+                    write_notice_fields(buf, "ERROR", code, message);
+                });
+            }
+            BackendMessage::NoticeResponse { message } => {
+                write_message(buf, b'N', |buf| {
+                    write_notice_fields(buf, "NOTICE", "00000", message);
+                });
+            }
+            BackendMessage::ParseComplete => write_message(buf, b'1', |_| {}),
+            BackendMessage::BindComplete => write_message(buf, b'2', |_| {}),
+            BackendMessage::CloseComplete => write_message(buf, b'3', |_| {}),
+            BackendMessage::ParameterDescription(types) => {
+                write_message(buf, b't', |buf| {
+                    write_i16(buf, types.len() as i16);
+                    for type_oid in types {
+                        write_i32(buf, *type_oid as i32);
+                    }
+                });
+            }
+            BackendMessage::NoData => write_message(buf, b'n', |_| {}),
+            BackendMessage::EncryptionNotSupported => buf.push(b'N'),
+        }
+    }
+}
+
+fn write_message(buf: &mut Vec<u8>, tag: u8, body: impl FnOnce(&mut Vec<u8>)) {
+    buf.push(tag);
+    let len_pos = buf.len();
+    write_i32(buf, 0);
+    body(buf);
+    // the length includes itself but not the message type
+    let len = (buf.len() - len_pos) as i32;
+    buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+fn write_notice_fields(buf: &mut Vec<u8>, severity: &str, code: &str, message: &str) {
+    buf.push(b'S');
+    write_cstr(buf, severity);
+    buf.push(b'V');
+    write_cstr(buf, severity);
+    buf.push(b'C');
+    write_cstr(buf, code);
+    buf.push(b'M');
+    write_cstr(buf, message);
+    buf.push(0);
+}
+
+fn write_i16(buf: &mut Vec<u8>, v: i16) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn write_i32(buf: &mut Vec<u8>, v: i32) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn write_cstr(buf: &mut Vec<u8>, s: &str) {
+    // a string can't contain the terminator
+    buf.extend(s.bytes().filter(|b| *b != 0));
+    buf.push(0);
+}
+
+#[async_backtrace::framed]
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await? as usize;
+    if !(8..=MAX_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of startup message: {}",
+            len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageBody::new(&body);
+    match body.i32()? {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest {
+            process_id: body.i32()? as u32,
+            secret_key: body.i32()? as u32,
+        }),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let name = body.cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = body.cstr()?;
+                params.insert(name, value);
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        version => Err(ErrorCode::Unimplemented(format!(
+            "unsupported frontend protocol {}.{}, only 3.0 is supported",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Reads the next message, `None` if the connection is closed.
+#[async_backtrace::framed]
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await? as usize;
+    if !(4..=MAX_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of message '{}': {}",
+            tag as char, len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    decode_message(tag, &body).map(Some)
+}
+
+pub fn decode_message(tag: u8, body: &[u8]) -> Result<FrontendMessage> {
+    let mut body = MessageBody::new(body);
+    match tag {
+        b'Q' => Ok(FrontendMessage::Query(body.cstr()?)),
+        b'P' => {
+            let name = body.cstr()?;
+            let query = body.cstr()?;
+            let num_types = body.i16()?;
+            let param_types = (0..num_types)
+                .map(|_| body.i32().map(|oid| oid as u32))
+                .collect::<Result<_>>()?;
+            Ok(FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            })
+        }
+        b'B' => {
+            let portal = body.cstr()?;
+            let statement = body.cstr()?;
+            let num_formats = body.i16()?;
+            let param_formats = (0..num_formats)
+                .map(|_| body.i16())
+                .collect::<Result<_>>()?;
+            let num_params = body.i16()?;
+            let params = (0..num_params)
+                .map(|_| match body.i32()? {
+                    -1 => Ok(None),
+                    len => body.bytes(len as usize).map(|v| Some(v.to_vec())),
+                })
+                .collect::<Result<_>>()?;
+            let num_formats = body.i16()?;
+            let result_formats = (0..num_formats)
+                .map(|_| body.i16())
+                .collect::<Result<_>>()?;
+            Ok(FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            })
+        }
+        b'D' => Ok(FrontendMessage::Describe {
+            kind: body.u8()?,
+            name: body.cstr()?,
+        }),
+        b'E' => Ok(FrontendMessage::Execute {
+            portal: body.cstr()?,
+            max_rows: body.i32()?,
+        }),
+        b'C' => Ok(FrontendMessage::Close {
+            kind: body.u8()?,
+            name: body.cstr()?,
+        }),
+        b'S' => Ok(FrontendMessage::Sync),
+        b'H' => Ok(FrontendMessage::Flush),
+        b'p' => Ok(FrontendMessage::Password(body.cstr()?.into_bytes())),
+        b'X' => Ok(FrontendMessage::Terminate),
+        tag => Err(ErrorCode::Unimplemented(format!(
+            "unsupported frontend message '{}'",
+            tag as char
+        ))),
+    }
+}
+
+struct MessageBody<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> MessageBody<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.buf.len() < len {
+            return Err(ErrorCode::BadBytes("unexpected end of message"));
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn cstr(&mut self) -> Result<String> {
+        let len = self
+            .buf
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| ErrorCode::BadBytes("unterminated string in message"))?;
+        let s = String::from_utf8(self.bytes(len)?.to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf-8 string in message: {e}")))?;
+        self.buf = &self.buf[1..];
+        Ok(s)
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::postgres_message::FieldDescription;
+
+// The oids of the types in `pg_type`.
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const NUMERIC_OID: u32 = 1700;
+pub const UUID_OID: u32 = 2950;
+
+/// The PostgreSQL type of the values of `data_type`. The unsigned integers are mapped to
+/// the signed type that holds all their values, the nested types are sent as text.
+pub fn type_oid(data_type: &DataType) -> u32 {
+    match data_type.remove_nullable() {
+        DataType::Null => UNKNOWN_OID,
+        DataType::Boolean => BOOL_OID,
+        DataType::String => VARCHAR_OID,
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::Int16 | NumberDataType::UInt8 => INT2_OID,
+            NumberDataType::Int32 | NumberDataType::UInt16 => INT4_OID,
+            NumberDataType::Int64 | NumberDataType::UInt32 => INT8_OID,
+            NumberDataType::UInt64 => NUMERIC_OID,
+            NumberDataType::Float32 => FLOAT4_OID,
+            NumberDataType::Float64 => FLOAT8_OID,
+        },
+        DataType::Decimal(_) => NUMERIC_OID,
+        DataType::Date => DATE_OID,
+        DataType::Timestamp => TIMESTAMP_OID,
+        DataType::Variant => JSON_OID,
+        DataType::Uuid => UUID_OID,
+        _ => TEXT_OID,
+    }
+}
+
+/// The `typlen` of the type, -1 for the types of variable length.
+fn type_size(type_oid: u32) -> i16 {
+    match type_oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID => 8,
+        UUID_OID => 16,
+        _ => -1,
+    }
+}
+
+pub fn field_descriptions(schema: &DataSchemaRef) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let type_oid = type_oid(field.data_type());
+            FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_size: type_size(type_oid),
+            }
+        })
+        .collect()
+}
+
+/// The rows of the block in the text format.
+pub fn encode_rows(block: &DataBlock, encoder: &FieldEncoderValues) -> Vec<Vec<Option<Vec<u8>>>> {
+    let columns = block
+        .convert_to_full()
+        .columns()
+        .iter()
+        .map(|column| column.value.clone().into_column().unwrap())
+        .collect::<Vec<_>>();
+
+    (0..block.num_rows())
+        .map(|row_index| {
+            columns
+                .iter()
+                .map(
+                    |column| match unsafe { column.index_unchecked(row_index) } {
+                        ScalarRef::Null => None,
+                        ScalarRef::Bitmap(_) => Some(b"<bitmap binary>".to_vec()),
+                        _ => {
+                            let mut buf = Vec::new();
+                            encoder.write_field(column, row_index, &mut buf, true);
+                            Some(buf)
+                        }
+                    },
+                )
+                .collect()
+        })
+        .collect()
+}
+
+/// Replaces the placeholders `$1`, `$2`, ... of `query` with the literals of the parameters
+/// bound by the extended query protocol.
+pub fn bind_params(
+    query: &str,
+    param_types: &[u32],
+    param_formats: &[i16],
+    params: &[Option<Vec<u8>>],
+) -> Result<String> {
+    // `$1` also refers to the columns of staged files, which is kept if no parameters are bound
+    if params.is_empty() {
+        return Ok(query.to_string());
+    }
+
+    let literals = params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| {
+            let format = match param_formats {
+                [] => 0,
+                [format] => *format,
+                formats => formats.get(i).copied().unwrap_or_default(),
+            };
+            let type_oid = param_types.get(i).copied().unwrap_or(UNKNOWN_OID);
+            param_literal(param.as_deref(), type_oid, format)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    replace_placeholders(query, |index| {
+        index
+            .checked_sub(1)
+            .and_then(|i| literals.get(i))
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "there is no parameter ${}, {} parameters are bound",
+                    index,
+                    literals.len()
+                ))
+            })
+    })
+}
+
+/// The number of parameters of `query`, that is the largest placeholder.
+pub fn num_params(query: &str) -> usize {
+    let mut num = 0;
+    let _ = replace_placeholders(query, |index| {
+        num = num.max(index);
+        Ok(String::new())
+    });
+    num
+}
+
+/// Replaces the placeholders `$n` by `replace(n)`. The placeholders in string literals,
+/// quoted identifiers and comments are kept.
+fn replace_placeholders(
+    query: &str,
+    mut replace: impl FnMut(usize) -> Result<String>,
+) -> Result<String> {
+    let bytes = query.as_bytes();
+    let mut sql = String::with_capacity(query.len());
+    let mut pos = 0;
+    let mut last = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                pos += 1;
+                while pos < bytes.len() && bytes[pos] != quote {
+                    // a backslash escapes the next character in string literals
+                    if bytes[pos] == b'\\' && quote == b'\'' {
+                        pos += 1;
+                    }
+                    pos += 1;
+                }
+                pos += 1;
+            }
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos += 2;
+                while pos < bytes.len() && !bytes[pos..].starts_with(b"*/") {
+                    pos += 1;
+                }
+                pos += 2;
+            }
+            b'$' if bytes.get(pos + 1).map_or(false, u8::is_ascii_digit) => {
+                let start = pos;
+                pos += 1;
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                let index = query[start + 1..pos].parse::<usize>().unwrap_or_default();
+                sql.push_str(&query[last..start]);
+                sql.push_str(&replace(index)?);
+                last = pos;
+            }
+            _ => pos += 1,
+        }
+    }
+    sql.push_str(&query[last..]);
+    Ok(sql)
+}
+
+fn param_literal(value: Option<&[u8]>, type_oid: u32, format: i16) -> Result<String> {
+    let Some(value) = value else {
+        return Ok("NULL".to_string());
+    };
+
+    if format != 0 {
+        // only the binary format of the numbers and booleans is decoded
+        return match (type_oid, value.len()) {
+            (BOOL_OID, 1) => Ok(if value[0] != 0 { "TRUE" } else { "FALSE" }.to_string()),
+            (INT2_OID, 2) => Ok(i16::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (INT4_OID, 4) => Ok(i32::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (INT8_OID, 8) => Ok(i64::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (FLOAT4_OID, 4) => Ok(f32::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (FLOAT8_OID, 8) => Ok(f64::from_be_bytes(value.try_into().unwrap()).to_string()),
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "binary format of parameters of type {} is not supported",
+                type_oid
+            ))),
+        };
+    }
+
+    let value = std::str::from_utf8(value)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid utf-8 parameter: {e}")))?;
+    match type_oid {
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID
+            if value.trim().parse::<f64>().is_ok() =>
+        {
+            Ok(value.trim().to_string())
+        }
+        BOOL_OID => match value.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "invalid boolean parameter: {}",
+                value
+            ))),
+        },
+        _ => Ok(format!(
+            "'{}'",
+            value.replace('\\', "\\\\").replace('\'', "''")
+        )),
+    }
+}
+
+/// The tag of `CommandComplete` of the query, like `SELECT 3` or `INSERT 0 3`.
+pub fn command_tag(query: &str, has_result_set: bool, rows: u64) -> String {
+    let command = query
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(';')
+        .to_ascii_uppercase();
+    match command.as_str() {
+        _ if has_result_set => format!("SELECT {}", rows),
+        "INSERT" => format!("INSERT 0 {}", rows),
+        "UPDATE" | "DELETE" | "COPY" | "REPLACE" => format!("{} {}", command, rows),
+        _ => command,
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::min;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -24,6 +25,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use chrono::DateTime;
+use chrono::Utc;
 use chrono_tz::Tz;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
@@ -35,12 +38,15 @@ use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::date_helper::TzFactory;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -53,9 +59,12 @@ use common_meta_app::schema::TableInfo;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataCacheMetrics;
 use common_storage::DataOperator;
+use common_storage::SpillOperator;
 use common_storage::StageFileInfo;
 use common_storage::StorageMetrics;
+use common_storage::StorageMetricsLayer;
 use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
@@ -63,13 +72,16 @@ use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
+use opendal::Operator;
 use parking_lot::RwLock;
 use tracing::debug;
 
 use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::clusters::WarehouseAutoSuspend;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::AdaptiveJoinState;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
@@ -199,6 +211,58 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    /// Attach a table which is not in the catalog, e.g. a bounded stream, so that the queries
+    /// of this context can read it by the name.
+    pub fn attach_table(&self, catalog: &str, database: &str, name: &str, table: Arc<dyn Table>) {
+        self.shared.attach_table(catalog, database, name, table)
+    }
+
+    /// Track a table instance which is not attached by `get_table`, so its data metrics are kept.
+    pub fn attach_accessed_table(&self, table: Arc<dyn Table>) {
+        self.shared.attach_accessed_table(table)
+    }
+
+    pub fn get_accessed_tables(&self) -> Vec<Arc<dyn Table>> {
+        self.shared.get_accessed_tables()
+    }
+
+    pub fn get_tables_refs_names(&self) -> HashMap<u64, (String, String)> {
+        self.shared.get_tables_refs_names()
+    }
+
+    pub fn get_spill_metrics(&self) -> Arc<StorageMetrics> {
+        self.shared.spill_metrics.clone()
+    }
+
+    /// Get the operator used to write and read spill files, it records the spilled bytes.
+    ///
+    /// The nodes of a cluster read the spill files of each other, so the local spill
+    /// directory is only used in standalone mode.
+    pub fn get_spill_operator(&self) -> Operator {
+        let operator = match self.get_cluster().is_empty() {
+            true => SpillOperator::instance().operator(),
+            false => DataOperator::instance().operator(),
+        };
+        operator.layer(StorageMetricsLayer::new(self.shared.spill_metrics.clone()))
+    }
+
+    pub fn set_join_skew(&self, skew: f64) {
+        let mut max_join_skew = self.shared.max_join_skew.write();
+        if skew > *max_join_skew {
+            *max_join_skew = skew;
+        }
+    }
+
+    pub fn get_max_join_skew(&self) -> f64 {
+        *self.shared.max_join_skew.read()
+    }
+
+    /// Get the state of the broadcast join of the plan id that may switch to a partitioned join.
+    pub fn get_adaptive_join_state(&self, plan_id: u32) -> Arc<AdaptiveJoinState> {
+        let mut adaptive_joins = self.shared.adaptive_joins.write();
+        adaptive_joins.entry(plan_id).or_default().clone()
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -227,7 +291,7 @@ impl TableContext for QueryContext {
     /// A plan just contains raw information about a table or table function.
     /// This method builds a `dyn Table`, which provides table specific io methods the plan needs.
     fn build_table_from_source_plan(&self, plan: &DataSourcePlan) -> Result<Arc<dyn Table>> {
-        match &plan.source_info {
+        let table = match &plan.source_info {
             DataSourceInfo::TableSource(table_info) => {
                 self.build_table_by_table_info(&plan.catalog, table_info, plan.tbl_args.clone())
             }
@@ -236,7 +300,9 @@ impl TableContext for QueryContext {
             }
             DataSourceInfo::ParquetSource(table_info) => ParquetTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
-        }
+        }?;
+        self.attach_accessed_table(table.clone());
+        Ok(table)
     }
 
     fn incr_total_scan_value(&self, value: ProgressValues) {
@@ -447,11 +513,19 @@ impl TableContext for QueryContext {
         self.shared.get_cluster()
     }
 
+    fn get_warehouse_state(&self) -> String {
+        WarehouseAutoSuspend::instance().get_state().to_string()
+    }
+
     // Get all the processes list info.
     fn get_processes_info(&self) -> Vec<ProcessInfo> {
         SessionManager::instance().processes_info()
     }
 
+    fn get_pipeline_tasks_info(&self) -> Vec<PipelineTaskInfo> {
+        SessionManager::instance().pipeline_tasks_info()
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -479,6 +553,36 @@ impl TableContext for QueryContext {
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
 
+    fn get_declared_result(&self, name: &str) -> Option<String> {
+        self.shared.session.session_ctx.get_declared_result(name)
+    }
+
+    fn set_declared_result(&self, name: String, location: String) {
+        self.shared
+            .session
+            .session_ctx
+            .set_declared_result(name, location)
+    }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.session_ctx.txn_mgr()
+    }
+
+    fn get_snapshot_pin_time(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from(self.shared.created_time)
+    }
+
+    fn get_pinned_snapshots(&self) -> BTreeMap<String, String> {
+        self.shared.pinned_snapshots.read().clone()
+    }
+
+    fn add_pinned_snapshot(&self, table: String, snapshot_id: String) {
+        self.shared
+            .pinned_snapshots
+            .write()
+            .insert(table, snapshot_id);
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         self.shared.get_on_error_map()
     }
@@ -531,6 +635,26 @@ impl TableContext for QueryContext {
         Ok(self.shared.data_operator.clone())
     }
 
+    fn get_data_cache_metrics(&self) -> Arc<DataCacheMetrics> {
+        self.shared.data_cache_metrics.clone()
+    }
+
+    fn add_runtime_filters(&self, table_index: usize, filters: Vec<Expr<String>>) {
+        let mut runtime_filters = self.shared.runtime_filters.write();
+        runtime_filters
+            .entry(table_index)
+            .or_default()
+            .extend(filters);
+    }
+
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Expr<String>> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        runtime_filters
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -22,15 +23,18 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Expr;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataCacheMetrics;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
 use dashmap::DashMap;
@@ -41,6 +45,7 @@ use uuid::Uuid;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::AdaptiveJoinState;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::storages::Table;
@@ -74,6 +79,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    /// accessed_tables for table instances built outside of tables_refs, e.g. from the source plans.
+    pub(in crate::sessions) accessed_tables: Arc<Mutex<Vec<Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
@@ -91,6 +98,20 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    /// spill_metrics for bytes written to and read from spill files
+    pub(in crate::sessions) spill_metrics: Arc<StorageMetrics>,
+    /// data_cache_metrics for hits and misses of the table data cache
+    pub(in crate::sessions) data_cache_metrics: Arc<DataCacheMetrics>,
+    /// max_join_skew is the largest ratio of max to mean probed rows per hash join worker
+    pub(in crate::sessions) max_join_skew: Arc<RwLock<f64>>,
+    /// runtime_filters of the table scans by table index, built from the build side of hash joins
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<usize, Vec<Expr<String>>>>>,
+    /// adaptive_joins are the states of the broadcast joins that may switch to partitioned joins,
+    /// by plan id
+    pub(in crate::sessions) adaptive_joins: Arc<RwLock<HashMap<u32, Arc<AdaptiveJoinState>>>>,
+    /// pinned_snapshots are the snapshot ids that the tables read by the query are pinned to,
+    /// by `catalog.database.table`
+    pub(in crate::sessions) pinned_snapshots: Arc<RwLock<BTreeMap<String, String>>>,
 }
 
 impl QueryContextShared {
@@ -114,6 +135,7 @@ impl QueryContextShared {
             running_query_kind: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            accessed_tables: Arc::new(Mutex::new(vec![])),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
@@ -123,6 +145,12 @@ impl QueryContextShared {
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            spill_metrics: Arc::new(StorageMetrics::default()),
+            data_cache_metrics: Arc::new(DataCacheMetrics::default()),
+            max_join_skew: Arc::new(RwLock::new(0.0)),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
+            adaptive_joins: Arc::new(RwLock::new(HashMap::new())),
+            pinned_snapshots: Arc::new(RwLock::new(BTreeMap::new())),
         }))
     }
 
@@ -214,8 +242,39 @@ impl QueryContextShared {
         tables.values().cloned().collect()
     }
 
+    /// Get the names of the tables attached in this query, keyed by table id.
+    pub fn get_tables_refs_names(&self) -> HashMap<u64, (String, String)> {
+        let tables = self.tables_refs.lock();
+        tables
+            .iter()
+            .map(|((_, database, table), v)| (v.get_id(), (database.clone(), table.clone())))
+            .collect()
+    }
+
+    /// Attach a table under the name, the later `get_table` of the name in this query returns it.
+    pub fn attach_table(&self, catalog: &str, database: &str, name: &str, table: Arc<dyn Table>) {
+        let table_meta_key = (catalog.to_string(), database.to_string(), name.to_string());
+        self.tables_refs.lock().insert(table_meta_key, table);
+    }
+
+    pub fn attach_accessed_table(&self, table: Arc<dyn Table>) {
+        self.accessed_tables.lock().push(table);
+    }
+
+    /// Get all table instances this query has read from or written to.
+    pub fn get_accessed_tables(&self) -> Vec<Arc<dyn Table>> {
+        let mut tables = self.get_tables_refs();
+        for table in self.accessed_tables.lock().iter() {
+            let ptr = Arc::as_ptr(table) as *const ();
+            if !tables.iter().any(|v| Arc::as_ptr(v) as *const () == ptr) {
+                tables.push(table.clone());
+            }
+        }
+        tables
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
-        let tables = self.get_tables_refs();
+        let tables = self.get_accessed_tables();
         let metrics: Vec<Arc<StorageMetrics>> =
             tables.iter().filter_map(|v| v.get_data_metrics()).collect();
         StorageMetrics::merge(&metrics)
@@ -271,6 +330,17 @@ impl QueryContextShared {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The statements of a transaction see the mutations made by the transaction.
+        let txn_table_info = self
+            .session
+            .session_ctx
+            .txn_mgr()
+            .lock()
+            .get_table_info(cache_table.get_id());
+        let cache_table = match txn_table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => cache_table,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -342,6 +412,13 @@ impl QueryContextShared {
         *guard = Some(affect);
     }
 
+    pub fn get_pipeline_tasks_info(&self) -> Vec<PipelineTaskInfo> {
+        match self.executor.read().upgrade() {
+            None => vec![],
+            Some(executor) => executor.tasks_info(&self.init_query_id.read()),
+        }
+    }
+
     pub fn set_executor(&self, executor: Arc<PipelineExecutor>) -> Result<()> {
         let mut guard = self.executor.write();
         match self.check_aborting() {
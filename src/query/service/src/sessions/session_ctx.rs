@@ -20,6 +20,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
@@ -57,6 +59,12 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The results pinned by `DECLARE <name> RESULT AS ...`, `name -> location`. They are kept in
+    // the storage and can be referenced like tables by the later statements of this session.
+    declared_results: RwLock<HashMap<String, String>>,
+    // The transaction started by `BEGIN`, which buffers the table mutations of the statements
+    // until `COMMIT`.
+    txn_mgr: TxnManagerRef,
     typ: SessionType,
 }
 
@@ -75,6 +83,8 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            declared_results: Default::default(),
+            txn_mgr: TxnManager::init(),
             typ,
         }))
     }
@@ -260,6 +270,18 @@ impl SessionContext {
         lock.push((query_id, value))
     }
 
+    pub fn get_declared_result(&self, name: &str) -> Option<String> {
+        self.declared_results.read().get(name).cloned()
+    }
+
+    pub fn set_declared_result(&self, name: String, location: String) {
+        self.declared_results.write().insert(name, location);
+    }
+
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     pub fn get_last_query_id(&self, index: i32) -> String {
         let lock = self.query_ids_results.read();
         let query_ids_len = lock.len();
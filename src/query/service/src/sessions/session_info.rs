@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use common_base::base::ProgressValues;
+use common_catalog::table_context::PipelineTaskInfo;
 pub use common_catalog::table_context::ProcessInfo;
 use common_storage::StorageMetrics;
 
@@ -29,6 +30,13 @@ impl Session {
         self.to_process_info(&session_ctx)
     }
 
+    pub fn pipeline_tasks_info(self: &Arc<Self>) -> Vec<PipelineTaskInfo> {
+        match self.session_ctx.get_query_context_shared() {
+            None => vec![],
+            Some(shared) => shared.get_pipeline_tasks_info(),
+        }
+    }
+
     fn to_process_info(self: &Arc<Self>, session_ctx: &SessionContext) -> ProcessInfo {
         let mut memory_usage = 0;
 
@@ -24,6 +24,7 @@ use std::time::Duration;
 use common_base::base::tokio;
 use common_base::base::GlobalInstance;
 use common_base::base::SignalStream;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
@@ -257,6 +258,20 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    pub fn pipeline_tasks_info(&self) -> Vec<PipelineTaskInfo> {
+        // Same as `processes_info`, drop the read lock before upgrading the sessions.
+        let active_sessions = {
+            let active_sessions_guard = self.active_sessions.read();
+            active_sessions_guard.values().cloned().collect::<Vec<_>>()
+        };
+
+        active_sessions
+            .into_iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .flat_map(|session| session.pipeline_tasks_info())
+            .collect::<Vec<_>>()
+    }
+
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
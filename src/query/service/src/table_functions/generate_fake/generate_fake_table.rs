@@ -0,0 +1,238 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+use common_sql::resolve_type_name_by_str;
+use common_storages_fuse::table_functions::string_value;
+use common_storages_fuse::table_functions::u64_value;
+
+const GENERATE_FAKE: &str = "generate_fake";
+
+/// `generate_fake('<column> <type>, ...', <rows>)` produces `rows` rows of random
+/// values for the given columns, e.g. to build test datasets inside the engine.
+pub struct GenerateFakeTable {
+    table_info: TableInfo,
+    table_args: TableArgs,
+    rows: u64,
+}
+
+impl GenerateFakeTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(2))?;
+        let schema = parse_fake_schema(&string_value(&args[0])?)?;
+        let rows = u64_value(&args[1])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: GENERATE_FAKE.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            table_args,
+            rows,
+        }))
+    }
+}
+
+/// Parse a comma separated list of column definitions, like `id INT, name VARCHAR`.
+fn parse_fake_schema(definitions: &str) -> Result<TableSchemaRef> {
+    let mut fields = vec![];
+    for definition in split_top_level(definitions) {
+        let definition = definition.trim();
+        let (name, type_name) = definition.split_once(char::is_whitespace).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "generate_fake: invalid column definition '{}', expect '<name> <type>'",
+                definition
+            ))
+        })?;
+        let data_type = resolve_type_name_by_str(type_name.trim())?;
+        fields.push(TableField::new(name, data_type));
+    }
+    if fields.is_empty() {
+        return Err(ErrorCode::BadArguments(
+            "generate_fake: at least one column is required",
+        ));
+    }
+    Ok(Arc::new(TableSchema::new(fields)))
+}
+
+/// Split on the commas which are not inside parentheses, e.g. `TUPLE(INT, INT)`.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !s[start..].trim().is_empty() {
+        parts.push(&s[start..]);
+    }
+    parts
+}
+
+#[async_trait::async_trait]
+impl Table for GenerateFakeTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((
+            PartStatistics::new_exact(self.rows as usize, 0, 0, 0),
+            Partitions::default(),
+        ))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(self.table_args.clone())
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let data_types = self
+            .table_info
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| DataType::from(f.data_type()))
+            .collect::<Vec<_>>();
+        let block_size = ctx.get_settings().get_max_block_size()?;
+        pipeline.add_source(
+            |output| {
+                GenerateFakeSource::create(
+                    ctx.clone(),
+                    output,
+                    data_types.clone(),
+                    self.rows,
+                    block_size,
+                )
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for GenerateFakeTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct GenerateFakeSource {
+    data_types: Vec<DataType>,
+    remaining: u64,
+    block_size: u64,
+}
+
+impl GenerateFakeSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        data_types: Vec<DataType>,
+        rows: u64,
+        block_size: u64,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, Self {
+            data_types,
+            remaining: rows,
+            block_size: block_size.max(1),
+        })
+    }
+}
+
+impl SyncSource for GenerateFakeSource {
+    const NAME: &'static str = GENERATE_FAKE;
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        let num_rows = self.remaining.min(self.block_size) as usize;
+        self.remaining -= num_rows as u64;
+
+        let columns = self
+            .data_types
+            .iter()
+            .map(|ty| Column::random(ty, num_rows))
+            .collect::<Vec<_>>();
+        Ok(Some(DataBlock::new_from_columns(columns)))
+    }
+}
@@ -21,6 +21,9 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_args::TableArgs;
+use common_compress::CompressAlgorithm;
+use common_compress::DecompressDecoder;
+use common_compress::DecompressState;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::BooleanType;
@@ -33,22 +36,30 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
-use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileCompression;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::input_formats::AvroFile;
+use common_pipeline_sources::input_formats::OrcFile;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::parse_stage_location;
 use common_storage::init_stage_operator;
 use common_storage::read_parquet_schema_async;
+use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
+use opendal::Operator;
 
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::table_functions::infer_schema::merge_schema::SchemaMerger;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
+use crate::table_functions::infer_schema::text_schema::infer_csv_schema;
+use crate::table_functions::infer_schema::text_schema::infer_ndjson_schema;
 use crate::table_functions::TableFunction;
 
 const INFER_SCHEMA: &str = "infer_schema";
@@ -162,6 +173,127 @@ impl InferSchemaSource {
             args_parsed,
         })
     }
+
+    /// Read at most `max_bytes` (decompressed) bytes from the head of a text file.
+    ///
+    /// Returns the data and whether it is only a prefix of the file.
+    #[async_backtrace::framed]
+    async fn read_text_sample(
+        &self,
+        operator: &Operator,
+        file: &StageFileInfo,
+        compression: StageFileCompression,
+    ) -> Result<(Vec<u8>, bool)> {
+        let max_bytes = self.args_parsed.max_bytes as u64;
+        let algo = match compression {
+            StageFileCompression::None => None,
+            StageFileCompression::Auto => CompressAlgorithm::from_path(&file.path),
+            StageFileCompression::Gzip => Some(CompressAlgorithm::Gzip),
+            StageFileCompression::Bz2 => Some(CompressAlgorithm::Bz2),
+            StageFileCompression::Brotli => Some(CompressAlgorithm::Brotli),
+            StageFileCompression::Zstd => Some(CompressAlgorithm::Zstd),
+            StageFileCompression::Deflate => Some(CompressAlgorithm::Zlib),
+            StageFileCompression::RawDeflate => Some(CompressAlgorithm::Deflate),
+            StageFileCompression::Xz => Some(CompressAlgorithm::Xz),
+            StageFileCompression::Lzo | StageFileCompression::Snappy => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "infer_schema does not support compression {:?} yet",
+                    compression
+                )));
+            }
+        };
+
+        let end = file.size.min(max_bytes);
+        let data = operator.range_read(&file.path, 0..end).await?;
+        let mut truncated = end < file.size;
+        let mut data = match algo {
+            None => data,
+            Some(algo) => decompress_prefix(DecompressDecoder::new(algo), &data)?,
+        };
+        if data.len() as u64 > max_bytes {
+            data.truncate(max_bytes as usize);
+            truncated = true;
+        }
+        Ok((data, truncated))
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_file_schema(
+        &self,
+        operator: &Operator,
+        file: &StageFileInfo,
+        file_format_params: &FileFormatParams,
+    ) -> Result<TableSchema> {
+        match file_format_params {
+            FileFormatParams::Parquet(_) => {
+                let arrow_schema = read_parquet_schema_async(operator, &file.path).await?;
+                Ok(TableSchema::from(&arrow_schema))
+            }
+            FileFormatParams::Csv(params) => {
+                let (data, truncated) = self
+                    .read_text_sample(operator, file, params.compression)
+                    .await?;
+                infer_csv_schema(&data, params, self.args_parsed.max_records, truncated)
+            }
+            FileFormatParams::NdJson(params) => {
+                let (data, truncated) = self
+                    .read_text_sample(operator, file, params.compression)
+                    .await?;
+                infer_ndjson_schema(&data, self.args_parsed.max_records, truncated)
+            }
+            FileFormatParams::Avro(_) => {
+                // The schema is in the header of the file, which is read as a whole only when
+                // the header is larger than the sample.
+                let (data, truncated) = self
+                    .read_text_sample(operator, file, StageFileCompression::None)
+                    .await?;
+                let data = match AvroFile::try_create(&data) {
+                    Err(_) if truncated => operator.read(&file.path).await?,
+                    _ => data,
+                };
+                AvroFile::try_create(&data)?.schema().to_table_schema()
+            }
+            FileFormatParams::Orc(_) => {
+                // The schema is in the footer at the end of the file.
+                let data = operator.read(&file.path).await?;
+                OrcFile::try_create(&data)?.schema().to_table_schema()
+            }
+            _ => Err(ErrorCode::BadArguments(
+                "infer_schema is currently limited to format Parquet, CSV, NDJSON, Avro and ORC",
+            )),
+        }
+    }
+}
+
+/// Decompress `compressed`, which may be only a prefix of the compressed stream.
+fn decompress_prefix(mut decoder: DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
+    let mut output = vec![];
+    let mut amt = 0;
+    let mut buf = vec![0u8; 4096];
+    loop {
+        match decoder.state() {
+            DecompressState::Reading => {
+                if amt == compressed.len() {
+                    break;
+                }
+                amt += decoder.fill(&compressed[amt..]);
+            }
+            DecompressState::Decoding => {
+                let written = decoder.decode(&mut buf).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                output.extend_from_slice(&buf[..written]);
+            }
+            DecompressState::Flushing => {
+                let written = decoder.finish(&mut buf).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                output.extend_from_slice(&buf[..written]);
+            }
+            DecompressState::Done => break,
+        }
+    }
+    Ok(output)
 }
 
 #[async_trait::async_trait]
@@ -184,22 +316,29 @@ impl AsyncSource for InferSchemaSource {
         };
         let operator = init_stage_operator(&stage_info)?;
 
-        let first_file = files_info.first_file(&operator).await?;
+        let files = if self.args_parsed.max_files == 1 {
+            vec![files_info.first_file(&operator).await?]
+        } else {
+            files_info
+                .list(&operator, false, Some(self.args_parsed.max_files))
+                .await?
+        };
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments("no file found"));
+        }
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
             None => stage_info.file_format_params.clone(),
         };
-        let schema = match file_format_params.get_type() {
-            StageFileFormatType::Parquet => {
-                let arrow_schema = read_parquet_schema_async(&operator, &first_file.path).await?;
-                TableSchema::from(&arrow_schema)
-            }
-            _ => {
-                return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
-                ));
-            }
-        };
+
+        let mut merger = SchemaMerger::new();
+        for file in files.iter() {
+            let schema = self
+                .infer_file_schema(&operator, file, &file_format_params)
+                .await?;
+            merger.add(&file.path, &schema);
+        }
+        let schema = merger.finish()?;
 
         let mut names: Vec<Vec<u8>> = vec![];
         let mut types: Vec<Vec<u8>> = vec![];
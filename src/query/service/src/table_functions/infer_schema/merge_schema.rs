@@ -0,0 +1,200 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merge the schemas inferred from multiple files into one.
+//!
+//! Columns are matched by name. A column which is missing in some of the files
+//! becomes nullable, and the types of one column are widened until they agree
+//! (e.g. Int32 and Int64 become Int64). Types that can not be widened are
+//! reported as conflicts.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+struct MergedColumn {
+    name: String,
+    data_type: TableDataType,
+    num_files: usize,
+    /// Conflicting types of this column, with the file they come from.
+    conflicts: Vec<(String, TableDataType)>,
+}
+
+pub(crate) struct SchemaMerger {
+    columns: Vec<MergedColumn>,
+    column_indexes: HashMap<String, usize>,
+    num_files: usize,
+    first_file: Option<String>,
+}
+
+impl SchemaMerger {
+    pub(crate) fn new() -> Self {
+        Self {
+            columns: vec![],
+            column_indexes: HashMap::new(),
+            num_files: 0,
+            first_file: None,
+        }
+    }
+
+    pub(crate) fn add(&mut self, path: &str, schema: &TableSchema) {
+        self.num_files += 1;
+        if self.first_file.is_none() {
+            self.first_file = Some(path.to_string());
+        }
+        for field in schema.fields() {
+            match self.column_indexes.get(field.name()) {
+                Some(idx) => {
+                    let column = &mut self.columns[*idx];
+                    column.num_files += 1;
+                    match merge_type(&column.data_type, field.data_type()) {
+                        Some(ty) => column.data_type = ty,
+                        None => column
+                            .conflicts
+                            .push((path.to_string(), field.data_type().clone())),
+                    }
+                }
+                None => {
+                    self.column_indexes
+                        .insert(field.name().clone(), self.columns.len());
+                    self.columns.push(MergedColumn {
+                        name: field.name().clone(),
+                        data_type: field.data_type().clone(),
+                        num_files: 1,
+                        conflicts: vec![],
+                    });
+                }
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> Result<TableSchema> {
+        let first_file = self.first_file.unwrap_or_default();
+        let conflicts = self
+            .columns
+            .iter()
+            .filter(|c| !c.conflicts.is_empty())
+            .map(|c| {
+                let types = c
+                    .conflicts
+                    .iter()
+                    .map(|(path, ty)| format!("{} in {}", ty.sql_name(), path))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "column '{}': {} in {} vs {}",
+                    c.name,
+                    c.data_type.sql_name(),
+                    first_file,
+                    types
+                )
+            })
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "infer_schema: can not merge the schemas of {} files, conflicting columns: {}",
+                self.num_files,
+                conflicts.join("; ")
+            )));
+        }
+
+        let num_files = self.num_files;
+        let fields = self
+            .columns
+            .into_iter()
+            .map(|c| {
+                if c.num_files < num_files {
+                    TableField::new(&c.name, c.data_type.wrap_nullable())
+                } else {
+                    TableField::new(&c.name, c.data_type)
+                }
+            })
+            .collect();
+        Ok(TableSchema::new(fields))
+    }
+}
+
+/// The type that can hold the values of both `a` and `b`, None if there is none.
+fn merge_type(a: &TableDataType, b: &TableDataType) -> Option<TableDataType> {
+    match (a, b) {
+        (a, b) if a == b => Some(a.clone()),
+        (TableDataType::Null, ty) | (ty, TableDataType::Null) => Some(ty.wrap_nullable()),
+        (TableDataType::Nullable(a), TableDataType::Nullable(b)) => {
+            merge_type(a, b).map(|ty| ty.wrap_nullable())
+        }
+        (TableDataType::Nullable(a), b) | (b, TableDataType::Nullable(a)) => {
+            merge_type(a, b).map(|ty| ty.wrap_nullable())
+        }
+        (TableDataType::Number(a), TableDataType::Number(b)) => {
+            merge_number(*a, *b).map(TableDataType::Number)
+        }
+        (TableDataType::Date, TableDataType::Timestamp)
+        | (TableDataType::Timestamp, TableDataType::Date) => Some(TableDataType::Timestamp),
+        (TableDataType::EmptyArray, TableDataType::Array(ty))
+        | (TableDataType::Array(ty), TableDataType::EmptyArray) => {
+            Some(TableDataType::Array(ty.clone()))
+        }
+        (TableDataType::Array(a), TableDataType::Array(b)) => {
+            merge_type(a, b).map(|ty| TableDataType::Array(Box::new(ty)))
+        }
+        (TableDataType::EmptyMap, TableDataType::Map(ty))
+        | (TableDataType::Map(ty), TableDataType::EmptyMap) => Some(TableDataType::Map(ty.clone())),
+        (TableDataType::Map(a), TableDataType::Map(b)) => {
+            merge_type(a, b).map(|ty| TableDataType::Map(Box::new(ty)))
+        }
+        (
+            TableDataType::Tuple {
+                fields_name: a_names,
+                fields_type: a_types,
+            },
+            TableDataType::Tuple {
+                fields_name: b_names,
+                fields_type: b_types,
+            },
+        ) if a_names == b_names => {
+            let fields_type = a_types
+                .iter()
+                .zip(b_types.iter())
+                .map(|(a, b)| merge_type(a, b))
+                .collect::<Option<Vec<_>>>()?;
+            Some(TableDataType::Tuple {
+                fields_name: a_names.clone(),
+                fields_type,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn merge_number(a: NumberDataType, b: NumberDataType) -> Option<NumberDataType> {
+    if a.is_float() || b.is_float() {
+        return Some(NumberDataType::Float64);
+    }
+    if a.is_signed() == b.is_signed() {
+        let bit_width = a.bit_width().max(b.bit_width());
+        return Some(NumberDataType::new(bit_width, a.is_signed(), false));
+    }
+    // A signed type holds an unsigned one only if it is wider.
+    let (signed, unsigned) = if a.is_signed() { (a, b) } else { (b, a) };
+    let bit_width = signed.bit_width().max(unsigned.bit_width() * 2);
+    if bit_width > 64 {
+        return None;
+    }
+    Some(NumberDataType::new(bit_width, true, false))
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod infer_schema_table;
+mod merge_schema;
 mod table_args;
+mod text_schema;
 
 pub use infer_schema_table::InferSchemaTable;
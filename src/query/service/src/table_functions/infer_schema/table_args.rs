@@ -17,12 +17,26 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_storage::StageFilesInfo;
 use common_storages_fuse::table_functions::string_value;
+use common_storages_fuse::table_functions::u64_value;
+
+/// Default number of records sampled from text files.
+const DEFAULT_MAX_RECORDS: usize = 1000;
+/// Default number of bytes read from the head of text files.
+const DEFAULT_MAX_BYTES: usize = 4 * 1024 * 1024;
+/// Default number of files whose schemas are merged.
+const DEFAULT_MAX_FILES: usize = 1;
 
 #[derive(Clone)]
 pub(crate) struct InferSchemaArgsParsed {
     pub(crate) location: String,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    /// Max number of records sampled from text formats (CSV, NDJSON).
+    pub(crate) max_records: usize,
+    /// Max number of bytes read from the file for text formats (CSV, NDJSON).
+    pub(crate) max_bytes: usize,
+    /// Max number of files to read, the schemas of the files are merged into one.
+    pub(crate) max_files: usize,
 }
 
 impl InferSchemaArgsParsed {
@@ -31,6 +45,9 @@ impl InferSchemaArgsParsed {
 
         let mut location = None;
         let mut file_format = None;
+        let mut max_records = DEFAULT_MAX_RECORDS;
+        let mut max_bytes = DEFAULT_MAX_BYTES;
+        let mut max_files = DEFAULT_MAX_FILES;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -48,6 +65,20 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_records" => {
+                    max_records = u64_value(v)? as usize;
+                }
+                "max_bytes" => {
+                    max_bytes = u64_value(v)? as usize;
+                }
+                "max_files" => {
+                    max_files = u64_value(v)? as usize;
+                    if max_files == 0 {
+                        return Err(ErrorCode::BadArguments(
+                            "max_files of infer_schema must be greater than 0",
+                        ));
+                    }
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -65,6 +96,9 @@ impl InferSchemaArgsParsed {
             location,
             file_format,
             files_info,
+            max_records,
+            max_bytes,
+            max_files,
         })
     }
 }
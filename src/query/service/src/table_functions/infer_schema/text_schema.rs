@@ -0,0 +1,516 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema inference for row based text formats (CSV and NDJSON).
+//!
+//! Only a sample of the file is inspected: at most `max_records` records from the
+//! first `max_bytes` bytes. Every value is classified into an [`InferredType`] and
+//! the types seen for one column are widened until they agree.
+
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_formats::RecordDelimiter;
+use common_meta_app::principal::CsvFileFormatParams;
+use csv_core::ReadRecordResult;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InferredType {
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    Date,
+    Timestamp,
+    String,
+    Variant,
+}
+
+impl InferredType {
+    /// Widen two types into one that can hold the values of both.
+    ///
+    /// `fallback` is used when the types are not compatible: String for CSV,
+    /// Variant for NDJSON.
+    fn merge(self, other: InferredType, fallback: InferredType) -> InferredType {
+        use InferredType::*;
+        match (self, other) {
+            (Null, t) | (t, Null) => t,
+            (a, b) if a == b => a,
+            (Int64, Float64) | (Float64, Int64) => Float64,
+            (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+            (Variant, _) | (_, Variant) => Variant,
+            _ => fallback,
+        }
+    }
+
+    fn to_table_type(self) -> TableDataType {
+        match self {
+            // A column that only has nulls in the sample.
+            InferredType::Null | InferredType::String => TableDataType::String,
+            InferredType::Boolean => TableDataType::Boolean,
+            InferredType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            InferredType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            InferredType::Date => TableDataType::Date,
+            InferredType::Timestamp => TableDataType::Timestamp,
+            InferredType::Variant => TableDataType::Variant,
+        }
+    }
+}
+
+struct InferredColumn {
+    name: String,
+    data_type: InferredType,
+    has_null: bool,
+    num_values: usize,
+}
+
+/// Collects the per column state while records are visited.
+struct SchemaBuilder {
+    columns: Vec<InferredColumn>,
+    fallback: InferredType,
+    num_records: usize,
+}
+
+impl SchemaBuilder {
+    fn new(fallback: InferredType) -> Self {
+        Self {
+            columns: vec![],
+            fallback,
+            num_records: 0,
+        }
+    }
+
+    fn add_column(&mut self, name: String) -> usize {
+        self.columns.push(InferredColumn {
+            name,
+            data_type: InferredType::Null,
+            has_null: false,
+            num_values: 0,
+        });
+        self.columns.len() - 1
+    }
+
+    fn update(&mut self, idx: usize, ty: InferredType) {
+        let column = &mut self.columns[idx];
+        column.data_type = column.data_type.merge(ty, self.fallback);
+        column.has_null |= ty == InferredType::Null;
+        column.num_values += 1;
+    }
+
+    fn build(self) -> Result<TableSchema> {
+        if self.columns.is_empty() {
+            return Err(ErrorCode::BadBytes(
+                "infer_schema: no record found in the sampled data",
+            ));
+        }
+        let num_records = self.num_records;
+        let fields = self
+            .columns
+            .into_iter()
+            .map(|c| {
+                let ty = c.data_type.to_table_type();
+                // Columns missing in some records are nullable too.
+                if c.has_null || c.num_values < num_records {
+                    TableField::new(&c.name, TableDataType::Nullable(Box::new(ty)))
+                } else {
+                    TableField::new(&c.name, ty)
+                }
+            })
+            .collect();
+        Ok(TableSchema::new(fields))
+    }
+}
+
+fn infer_temporal(s: &str) -> Option<InferredType> {
+    if NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok() {
+        return Some(InferredType::Date);
+    }
+    if NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").is_ok()
+        || NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+        || DateTime::parse_from_rfc3339(s).is_ok()
+    {
+        return Some(InferredType::Timestamp);
+    }
+    None
+}
+
+fn infer_text_field(field: &[u8], null_if: &[String]) -> InferredType {
+    let s = match std::str::from_utf8(field) {
+        Ok(s) => s.trim(),
+        Err(_) => return InferredType::String,
+    };
+    if s.is_empty() || s == "\\N" || null_if.iter().any(|n| n == s) {
+        return InferredType::Null;
+    }
+    if s.eq_ignore_ascii_case("true") || s.eq_ignore_ascii_case("false") {
+        return InferredType::Boolean;
+    }
+    if s.parse::<i64>().is_ok() {
+        return InferredType::Int64;
+    }
+    // `f64::from_str` also accepts `inf` and `NaN`, which we keep as strings.
+    if s.bytes().any(|b| b.is_ascii_digit())
+        && s.bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'-' | b'+' | b'e' | b'E'))
+        && s.parse::<f64>().is_ok()
+    {
+        return InferredType::Float64;
+    }
+    infer_temporal(s).unwrap_or(InferredType::String)
+}
+
+fn infer_json_value(value: &serde_json::Value) -> InferredType {
+    match value {
+        serde_json::Value::Null => InferredType::Null,
+        serde_json::Value::Bool(_) => InferredType::Boolean,
+        serde_json::Value::Number(n) => {
+            if n.is_i64() {
+                InferredType::Int64
+            } else {
+                InferredType::Float64
+            }
+        }
+        serde_json::Value::String(s) => infer_temporal(s).unwrap_or(InferredType::String),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => InferredType::Variant,
+    }
+}
+
+/// The CSV reader only supports delimiters, quotes and escapes of one byte.
+fn single_byte(option: &str, value: &str) -> Result<u8> {
+    match value.as_bytes() {
+        [b] => Ok(*b),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "infer_schema: {} must be a single byte character, got '{}'",
+            option, value
+        ))),
+    }
+}
+
+/// Infer the schema of CSV data.
+///
+/// If `truncated` is true, `data` is a prefix of the file and the last record
+/// may be incomplete, so it is ignored.
+pub(crate) fn infer_csv_schema(
+    data: &[u8],
+    params: &CsvFileFormatParams,
+    max_records: usize,
+    truncated: bool,
+) -> Result<TableSchema> {
+    let escape = if params.escape.is_empty() {
+        None
+    } else {
+        Some(single_byte("escape", &params.escape)?)
+    };
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(single_byte("field_delimiter", &params.field_delimiter)?)
+        .quote(single_byte("quote", &params.quote)?)
+        .escape(escape)
+        .terminator(match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        })
+        .build();
+
+    let mut builder = SchemaBuilder::new(InferredType::String);
+    let mut header: Option<Vec<String>> = None;
+    let mut records_to_skip = params.headers as usize;
+
+    // The unescaped output of one record is never longer than its input.
+    let mut output = vec![0u8; data.len() + 1];
+    let mut ends = vec![0usize; 128];
+    let mut input = data;
+    let mut out_pos = 0;
+    let mut end_pos = 0;
+
+    while builder.num_records < max_records {
+        let (res, n_in, n_out, n_end) =
+            reader.read_record(input, &mut output[out_pos..], &mut ends[end_pos..]);
+        input = &input[n_in..];
+        out_pos += n_out;
+        end_pos += n_end;
+        match res {
+            ReadRecordResult::InputEmpty => {
+                if truncated && input.is_empty() {
+                    break;
+                }
+            }
+            ReadRecordResult::OutputFull => {
+                output.resize(output.len() * 2, 0);
+            }
+            ReadRecordResult::OutputEndsFull => {
+                ends.resize(ends.len() * 2, 0);
+            }
+            ReadRecordResult::Record => {
+                let mut fields = Vec::with_capacity(end_pos);
+                let mut start = 0;
+                for end in &ends[..end_pos] {
+                    fields.push(&output[start..*end]);
+                    start = *end;
+                }
+
+                if records_to_skip > 0 {
+                    records_to_skip -= 1;
+                    if header.is_none() {
+                        header = Some(
+                            fields
+                                .iter()
+                                .map(|f| String::from_utf8_lossy(f).trim().to_string())
+                                .collect(),
+                        );
+                    }
+                } else {
+                    builder.num_records += 1;
+                    for (i, field) in fields.iter().enumerate() {
+                        if i >= builder.columns.len() {
+                            let name = header
+                                .as_ref()
+                                .and_then(|h| h.get(i))
+                                .filter(|h| !h.is_empty())
+                                .cloned()
+                                .unwrap_or_else(|| format!("column_{}", i + 1));
+                            builder.add_column(name);
+                        }
+                        builder.update(i, infer_text_field(field, &params.null_if));
+                    }
+                }
+                out_pos = 0;
+                end_pos = 0;
+            }
+            ReadRecordResult::End => break,
+        }
+    }
+
+    builder.build()
+}
+
+/// Infer the schema of NDJSON data, one JSON object per line.
+///
+/// If `truncated` is true, `data` is a prefix of the file and the last line
+/// may be incomplete, so it is ignored.
+pub(crate) fn infer_ndjson_schema(
+    data: &[u8],
+    max_records: usize,
+    truncated: bool,
+) -> Result<TableSchema> {
+    let data = if truncated {
+        match data.iter().rposition(|b| *b == b'\n') {
+            Some(pos) => &data[..pos],
+            None => &[],
+        }
+    } else {
+        data
+    };
+
+    let mut builder = SchemaBuilder::new(InferredType::Variant);
+    let mut column_indexes: HashMap<String, usize> = HashMap::new();
+
+    for (line_no, line) in data.split(|b| *b == b'\n').enumerate() {
+        if builder.num_records >= max_records {
+            break;
+        }
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            continue;
+        }
+        let value: serde_json::Value = serde_json::from_slice(line).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "infer_schema: invalid json at line {}: {}",
+                line_no + 1,
+                e
+            ))
+        })?;
+        let object = match value {
+            serde_json::Value::Object(object) => object,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "infer_schema: expect a json object at line {}",
+                    line_no + 1
+                )));
+            }
+        };
+
+        builder.num_records += 1;
+        for (key, value) in object.iter() {
+            let idx = match column_indexes.get(key) {
+                Some(idx) => *idx,
+                None => {
+                    let idx = builder.add_column(key.clone());
+                    column_indexes.insert(key.clone(), idx);
+                    idx
+                }
+            };
+            builder.update(idx, infer_json_value(value));
+        }
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+    use common_expression::types::NumberDataType;
+    use common_expression::TableDataType;
+    use common_meta_app::principal::CsvFileFormatParams;
+
+    use super::infer_csv_schema;
+    use super::infer_ndjson_schema;
+    use super::InferredType;
+
+    fn field_types(data: &[u8], max_records: usize, truncated: bool) -> Result<Vec<TableDataType>> {
+        let schema = infer_csv_schema(
+            data,
+            &CsvFileFormatParams::default(),
+            max_records,
+            truncated,
+        )?;
+        Ok(schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect())
+    }
+
+    fn nullable(ty: TableDataType) -> TableDataType {
+        TableDataType::Nullable(Box::new(ty))
+    }
+
+    #[test]
+    fn test_merge_inferred_types() {
+        use InferredType::*;
+
+        let cases = [
+            (Null, Int64, Int64),
+            (Boolean, Null, Boolean),
+            (Int64, Int64, Int64),
+            (Int64, Float64, Float64),
+            (Float64, Int64, Float64),
+            (Date, Timestamp, Timestamp),
+            (Timestamp, Date, Timestamp),
+            (Variant, Int64, Variant),
+            (String, Variant, Variant),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(a.merge(b, String), expected, "{:?} + {:?}", a, b);
+            assert_eq!(a.merge(b, Variant), expected, "{:?} + {:?}", a, b);
+        }
+
+        // Incompatible types are widened to the fallback of the format.
+        for (a, b) in [(Int64, Boolean), (Float64, Date), (Boolean, String)] {
+            assert_eq!(a.merge(b, String), String, "{:?} + {:?}", a, b);
+            assert_eq!(a.merge(b, Variant), Variant, "{:?} + {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_infer_csv_widening() -> Result<()> {
+        let data = b"1,2023-01-01,true,1\n2.5,2023-01-01 10:00:00,false,x\n3,,true,2\n";
+        assert_eq!(field_types(data, 100, false)?, vec![
+            TableDataType::Number(NumberDataType::Float64),
+            nullable(TableDataType::Timestamp),
+            TableDataType::Boolean,
+            TableDataType::String,
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_csv_sample_limits() -> Result<()> {
+        let data = b"1\n2\n3.5";
+        assert_eq!(field_types(data, 100, false)?, vec![TableDataType::Number(
+            NumberDataType::Float64
+        )]);
+
+        // The data is cut at `max_bytes`, the incomplete final record is dropped.
+        assert_eq!(field_types(data, 100, true)?, vec![TableDataType::Number(
+            NumberDataType::Int64
+        )]);
+
+        // Only the first `max_records` records are inspected.
+        let data = b"1\n2\n3.5\n";
+        assert_eq!(field_types(data, 2, false)?, vec![TableDataType::Number(
+            NumberDataType::Int64
+        )]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_csv_invalid_delimiters() {
+        let cases = [
+            ("field_delimiter", "", "\""),
+            ("field_delimiter", "||", "\""),
+            ("field_delimiter", "\u{00e9}", "\""),
+            ("quote", ",", ""),
+            ("quote", ",", "''"),
+        ];
+        for (option, field_delimiter, quote) in cases {
+            let params = CsvFileFormatParams {
+                field_delimiter: field_delimiter.to_string(),
+                quote: quote.to_string(),
+                ..Default::default()
+            };
+            let err = infer_csv_schema(b"1,2\n", &params, 100, false).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+            assert!(err.message().contains(option), "{}", err.message());
+        }
+    }
+
+    #[test]
+    fn test_infer_ndjson_widening() -> Result<()> {
+        let data = b"{\"a\": 1, \"b\": \"x\", \"c\": true}\n{\"a\": 2.5, \"b\": [1], \"c\": 1}\n{\"a\": 3}\n";
+        let schema = infer_ndjson_schema(data, 100, false)?;
+        let types = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().clone(), f.data_type().clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec![
+            (
+                "a".to_string(),
+                TableDataType::Number(NumberDataType::Float64)
+            ),
+            ("b".to_string(), nullable(TableDataType::Variant)),
+            ("c".to_string(), nullable(TableDataType::Variant)),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_ndjson_sample_limits() -> Result<()> {
+        let data = b"{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3.5, \"b";
+        let schema = infer_ndjson_schema(data, 100, true)?;
+        assert_eq!(schema.fields().len(), 1);
+        assert_eq!(
+            schema.fields()[0].data_type(),
+            &TableDataType::Number(NumberDataType::Int64)
+        );
+
+        let data = b"{\"a\": 1}\n{\"a\": 2.5}\n";
+        let schema = infer_ndjson_schema(data, 1, false)?;
+        assert_eq!(
+            schema.fields()[0].data_type(),
+            &TableDataType::Number(NumberDataType::Int64)
+        );
+        Ok(())
+    }
+}
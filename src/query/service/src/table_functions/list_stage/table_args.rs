@@ -25,19 +25,32 @@ pub(crate) struct ListStageArgsParsed {
 }
 
 impl ListStageArgsParsed {
+    /// The location can be given either as the first positioned arg or as the named arg
+    /// `location`, e.g. `list_stage('@s1/path/', pattern => '.*parquet')`.
     pub fn parse(table_args: &TableArgs) -> Result<Self> {
-        let args = table_args.expect_all_named("infer_schema")?;
-
-        let mut location = None;
+        let mut location = match table_args.positioned.as_slice() {
+            [] => None,
+            [v] => Some(string_value(v)?),
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "list_stage accepts only the location as positioned arg",
+                ));
+            }
+        };
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
             pattern: None,
         };
 
-        for (k, v) in &args {
+        for (k, v) in &table_args.named {
             match k.to_lowercase().as_str() {
                 "location" => {
+                    if location.is_some() {
+                        return Err(ErrorCode::BadArguments(
+                            "location of list_stage is specified more than once",
+                        ));
+                    }
                     location = Some(string_value(v)?);
                 }
                 "pattern" => {
@@ -25,11 +25,15 @@ use parking_lot::RwLock;
 use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::table_functions::ClusteringInformationTable;
+use crate::storages::fuse::table_functions::FuseAmendTable;
 use crate::storages::fuse::table_functions::FuseBlockTable;
+use crate::storages::fuse::table_functions::FuseLayoutTable;
+use crate::storages::fuse::table_functions::FuseOrphanTable;
 use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
+use crate::table_functions::generate_fake::GenerateFakeTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
@@ -119,6 +123,19 @@ impl TableFunctionFactory {
             "fuse_statistic".to_string(),
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
+        creators.insert(
+            "fuse_layout".to_string(),
+            (next_id(), Arc::new(FuseLayoutTable::create)),
+        );
+        creators.insert(
+            "fuse_orphans".to_string(),
+            (next_id(), Arc::new(FuseOrphanTable::create)),
+        );
+
+        creators.insert(
+            "fuse_amend".to_string(),
+            (next_id(), Arc::new(FuseAmendTable::create)),
+        );
 
         creators.insert(
             "clustering_information".to_string(),
@@ -155,6 +172,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(RangeTable::create)),
         );
 
+        creators.insert(
+            "generate_fake".to_string(),
+            (next_id(), Arc::new(GenerateFakeTable::create)),
+        );
+
         creators.insert(
             "ai_to_sql".to_string(),
             (next_id(), Arc::new(GPT2SQLTable::create)),
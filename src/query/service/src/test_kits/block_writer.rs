@@ -73,7 +73,7 @@ impl<'a> BlockWriter<'a> {
         };
 
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let (file_size, col_metas) = serialize_block(&write_settings, schema, block, &mut buf)?;
+        let (file_size, col_metas, _) = serialize_block(&write_settings, schema, block, &mut buf)?;
 
         data_accessor.write(&location.0, buf).await?;
 
@@ -103,8 +103,14 @@ impl<'a> BlockWriter<'a> {
             .location_generator
             .block_bloom_index_location(&block_id);
 
-        let maybe_bloom_index =
-            BloomIndex::try_create(FunctionContext::default(), schema, location.1, &[block])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            FunctionContext::default(),
+            schema,
+            location.1,
+            &[block],
+            None,
+            &[],
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
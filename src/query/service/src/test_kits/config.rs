@@ -27,6 +27,9 @@ impl ConfigBuilder {
         let mut conf = InnerConfig::default();
         conf.query.tenant_id = "test".to_string();
         conf.log = common_tracing::Config::new_testing();
+        conf.query.materialized_view_refresh_check_secs = 0;
+        conf.query.task_schedule_check_secs = 0;
+        conf.query.auto_compaction_check_secs = 0;
 
         ConfigBuilder { conf }
     }
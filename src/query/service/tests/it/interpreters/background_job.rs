@@ -0,0 +1,115 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_system::BackgroundJobState;
+use common_storages_system::BackgroundJobs;
+use databend_query::interpreters::BackgroundJob;
+use databend_query::interpreters::BackgroundJobType;
+use databend_query::interpreters::RetryPolicy;
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy {
+        initial_backoff: Duration::from_secs(60),
+        max_backoff: Duration::from_secs(3600),
+        max_consecutive_failures: 5,
+    };
+
+    let backoffs = (1..=8)
+        .map(|failures| policy.backoff(failures).as_secs())
+        .collect::<Vec<_>>();
+    assert_eq!(backoffs, vec![60, 120, 240, 480, 960, 1920, 3600, 3600]);
+
+    // never below the initial backoff, nor above the max one however many failures
+    assert_eq!(policy.backoff(0), Duration::from_secs(60));
+    assert_eq!(policy.backoff(64), Duration::from_secs(3600));
+    assert_eq!(policy.backoff(u64::MAX), Duration::from_secs(3600));
+}
+
+#[test]
+fn test_background_job_quarantine() {
+    let name = "test_background_job_quarantine";
+    let job = BackgroundJob::new(BackgroundJobType::Task, name);
+    let policy = BackgroundJobType::Task.retry_policy();
+    let failed: Result<()> = Err(ErrorCode::Internal("job failed"));
+
+    assert!(job.can_run(&Utc::now()));
+
+    // retried with backoff until the job fails max_consecutive_failures times in a row
+    for failures in 1..policy.max_consecutive_failures {
+        assert_eq!(job.on_finish(&failed), BackgroundJobState::Retrying);
+        let now = Utc::now();
+        assert!(!job.can_run(&now));
+        assert!(!job.is_retry_due(&now));
+
+        let due = now + chrono::Duration::from_std(policy.backoff(failures)).unwrap();
+        assert!(job.can_run(&due));
+        assert!(job.is_retry_due(&due));
+
+        let status = BackgroundJobs::instance()
+            .get(BackgroundJobType::Task.as_str(), name)
+            .unwrap();
+        assert_eq!(status.consecutive_failures, failures);
+        assert_eq!(status.last_error_code, ErrorCode::INTERNAL as i32);
+    }
+
+    assert_eq!(job.on_finish(&failed), BackgroundJobState::Quarantined);
+    assert!(job.is_quarantined());
+    let far_future = Utc::now() + chrono::Duration::days(365);
+    assert!(!job.can_run(&far_future));
+    assert!(!job.is_retry_due(&far_future));
+
+    // released, the job runs again on its next schedule
+    job.release();
+    assert!(!job.is_quarantined());
+    assert!(job.can_run(&Utc::now()));
+    let status = BackgroundJobs::instance()
+        .get(BackgroundJobType::Task.as_str(), name)
+        .unwrap();
+    assert_eq!(status.state, BackgroundJobState::Idle);
+    assert_eq!(status.consecutive_failures, 0);
+    assert_eq!(status.total_failures, policy.max_consecutive_failures);
+}
+
+#[test]
+fn test_background_job_success_resets_failures() {
+    let name = "test_background_job_success_resets_failures";
+    let job = BackgroundJob::new(BackgroundJobType::MaterializedView, name);
+    let failed: Result<()> = Err(ErrorCode::Internal("job failed"));
+
+    job.on_start();
+    assert_eq!(job.on_finish(&failed), BackgroundJobState::Retrying);
+    job.on_start();
+    assert_eq!(job.on_finish(&failed), BackgroundJobState::Retrying);
+    job.on_start();
+    assert!(job.last_run_time().is_some());
+    assert_eq!(job.on_finish(&Ok(())), BackgroundJobState::Idle);
+    assert!(job.can_run(&Utc::now()));
+
+    let status = BackgroundJobs::instance()
+        .get(BackgroundJobType::MaterializedView.as_str(), name)
+        .unwrap();
+    assert_eq!(status.total_runs, 3);
+    assert_eq!(status.total_failures, 2);
+    assert_eq!(status.consecutive_failures, 0);
+    assert_eq!(status.next_retry_time, None);
+    assert!(status.last_success_time.is_some());
+    // the error of the last failed run is kept
+    assert_eq!(status.last_error_code, ErrorCode::INTERNAL as i32);
+}
@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::BlockThresholds;
+use databend_query::interpreters::TableCompactionScheduler;
+use storages_common_table_meta::meta::Statistics;
+
+fn summary(block_count: u64, row_count: u64, uncompressed_byte_size: u64) -> Statistics {
+    Statistics {
+        row_count,
+        block_count,
+        uncompressed_byte_size,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_needs_compaction() {
+    let thresholds = BlockThresholds::new(1000, 800, 100 * 1024 * 1024);
+
+    // many small blocks
+    assert!(TableCompactionScheduler::needs_compaction(
+        &summary(10, 100, 1024),
+        &thresholds,
+        5
+    ));
+    // not enough blocks yet
+    assert!(!TableCompactionScheduler::needs_compaction(
+        &summary(4, 40, 1024),
+        &thresholds,
+        5
+    ));
+    // a single block is never compacted
+    assert!(!TableCompactionScheduler::needs_compaction(
+        &summary(1, 10, 1024),
+        &thresholds,
+        0
+    ));
+    assert!(TableCompactionScheduler::needs_compaction(
+        &summary(2, 20, 1024),
+        &thresholds,
+        0
+    ));
+    // the blocks have enough rows on average
+    assert!(!TableCompactionScheduler::needs_compaction(
+        &summary(10, 8000, 1024),
+        &thresholds,
+        5
+    ));
+    // the blocks are large enough on average
+    assert!(!TableCompactionScheduler::needs_compaction(
+        &summary(10, 100, 1000 * 1024 * 1024),
+        &thresholds,
+        5
+    ));
+}
+
+#[test]
+fn test_compaction_io_budget() {
+    let mut io_budget = 100;
+    assert_eq!(
+        TableCompactionScheduler::take_io_budget(&mut io_budget, 30),
+        Some(3)
+    );
+    assert_eq!(io_budget, 10);
+    // the budget left cannot afford a single segment of the next table
+    assert_eq!(
+        TableCompactionScheduler::take_io_budget(&mut io_budget, 30),
+        None
+    );
+    assert_eq!(io_budget, 10);
+    assert_eq!(
+        TableCompactionScheduler::take_io_budget(&mut io_budget, 10),
+        Some(1)
+    );
+    assert_eq!(io_budget, 0);
+}
+
+#[test]
+fn test_recluster_block_budget() {
+    let mut block_budget = 100;
+    assert_eq!(
+        TableCompactionScheduler::take_block_budget(&mut block_budget, 30),
+        Some(30)
+    );
+    assert_eq!(block_budget, 70);
+    // the table takes the rest of the budget
+    assert_eq!(
+        TableCompactionScheduler::take_block_budget(&mut block_budget, 100),
+        Some(70)
+    );
+    assert_eq!(block_budget, 0);
+    assert_eq!(
+        TableCompactionScheduler::take_block_budget(&mut block_budget, 1),
+        None
+    );
+}
@@ -25,6 +25,7 @@ mod clusters;
 mod configs;
 mod databases;
 mod frame;
+mod interpreters;
 mod metrics;
 mod pipelines;
 mod servers;
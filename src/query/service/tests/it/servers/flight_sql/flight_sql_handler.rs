@@ -16,23 +16,40 @@
 
 use std::fs;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::flight_service_client::FlightServiceClient;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::utils::flight_data_from_arrow_batch;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::PutResult;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use common_base::base::tokio;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::flight_sql::flight_sql_service::FlightIngestService;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
 use futures::TryStreamExt;
 use goldenfile::Mint;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -40,20 +57,24 @@ use tokio_stream::wrappers::UnixListenerStream;
 use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Request;
 use tower::service_fn;
 use tracing::debug;
 
 const TEST_USER: &str = "test_user";
 const TEST_PASSWORD: &str = "test_password";
 
-async fn client_with_uds(path: String) -> FlightSqlServiceClient<Channel> {
+async fn channel_with_uds(path: String) -> Channel {
     let connector = service_fn(move |_| UnixStream::connect(path.clone()));
-    let channel = Endpoint::try_from("http://example.com")
+    Endpoint::try_from("http://example.com")
         .unwrap()
         .connect_with_connector(connector)
         .await
-        .unwrap();
-    FlightSqlServiceClient::new(channel)
+        .unwrap()
+}
+
+async fn client_with_uds(path: String) -> FlightSqlServiceClient<Channel> {
+    FlightSqlServiceClient::new(channel_with_uds(path).await)
 }
 
 async fn run_query(
@@ -148,3 +169,114 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_ingest() -> Result<()> {
+    let _guard = TestGlobalServices::setup(prepare_config()).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightIngestService::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path.clone()).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+        run_query(&mut client, "drop table if exists test_ingest")
+            .await
+            .unwrap();
+        run_query(
+            &mut client,
+            "create table test_ingest(a int null, b string, c int default 7)",
+        )
+        .await
+        .unwrap();
+
+        // The columns are matched by name, `a` is cast to int and `c` is filled with its default.
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Utf8, false),
+            Field::new("a", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![
+            Arc::new(StringArray::from(vec!["x", "y"])),
+            Arc::new(Int64Array::from(vec![Some(1), None])),
+        ])
+        .unwrap();
+        let options = IpcWriteOptions::default();
+        let mut schema_data: FlightData = SchemaAsIpc::new(&schema, &options).into();
+        schema_data.flight_descriptor = Some(FlightDescriptor::new_path(vec![
+            "default".to_string(),
+            "test_ingest".to_string(),
+        ]));
+        let (_, batch_data) = flight_data_from_arrow_batch(&batch, &options);
+
+        let mut flight_client = FlightServiceClient::new(channel_with_uds(path.clone()).await);
+        let mut request = Request::new(futures::stream::iter(vec![schema_data, batch_data]));
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        let results: Vec<PutResult> = flight_client
+            .do_put(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .try_collect()
+            .await
+            .unwrap();
+        let result = Any::decode(&*results[0].app_metadata)
+            .unwrap()
+            .unpack::<DoPutUpdateResult>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record_count, 2);
+
+        let res = run_query(&mut client, "select * from test_ingest order by b")
+            .await
+            .unwrap();
+        let expected = vec![
+            "+---+---+---+",
+            "| a | b | c |",
+            "+---+---+---+",
+            "| 1 | x | 7 |",
+            "|   | y | 7 |",
+            "+---+---+---+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+
+        // Unknown columns are rejected before anything is written.
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Int64, true)]));
+        let mut schema_data: FlightData = SchemaAsIpc::new(&schema, &options).into();
+        schema_data.flight_descriptor = Some(FlightDescriptor::new_path(vec![
+            "default".to_string(),
+            "test_ingest".to_string(),
+        ]));
+        let mut request = Request::new(futures::stream::iter(vec![schema_data]));
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        let err = flight_client.do_put(request).await.unwrap_err();
+        assert!(err.message().contains("column d doesn't exist"));
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+    debug!("Server shutdown!");
+
+    Ok(())
+}
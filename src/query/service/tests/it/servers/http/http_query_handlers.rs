@@ -25,6 +25,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
+use common_sql::ColumnOrigin;
 use common_users::CustomClaims;
 use common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
@@ -197,6 +198,32 @@ async fn test_show_databases() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_column_origins() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let sql = "select name as db, 1 as one from system.databases limit 1";
+    let (status, result) = post_sql(sql, 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.schema.len(), 2, "{:?}", result);
+    assert_eq!(result.schema[0].name, "db", "{:?}", result);
+    assert_eq!(
+        result.schema[0].origin,
+        Some(ColumnOrigin {
+            catalog: "default".to_string(),
+            database: "system".to_string(),
+            table: "databases".to_string(),
+            column: "name".to_string(),
+        }),
+        "{:?}",
+        result
+    );
+    // computed column has no origin
+    assert!(result.schema[1].origin.is_none(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_return_when_finish() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -396,6 +423,60 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_pagination_cursor() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select * from numbers(10)";
+    // The pages beyond the first 4 rows are spilled.
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 2, "max_rows_per_page": 3, "max_rows_in_buffer": 4, "cursor": true, "result_timeout_secs": 10}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let query_id = &result.id;
+    assert_eq!(result.data.len(), 3, "{:?}", result);
+    assert_eq!(
+        result.next_uri,
+        Some(make_page_uri(query_id, 1)),
+        "{:?}",
+        result
+    );
+
+    // The pages can be fetched in any order, and more than once.
+    for (page, rows, first) in [
+        (3, 1, "9"),
+        (1, 3, "3"),
+        (0, 3, "0"),
+        (2, 3, "6"),
+        (3, 1, "9"),
+    ] {
+        let (status, result) = get_uri_checked(&ep, &make_page_uri(query_id, page)).await?;
+        let msg = || format!("page {}: {:?}", page, result);
+        assert_eq!(status, StatusCode::OK, "{:?}", msg());
+        assert!(result.error.is_none(), "{:?}", msg());
+        assert_eq!(result.data.len(), rows, "{:?}", msg());
+        assert_eq!(result.data[0][0], first, "{:?}", msg());
+        if page == 3 {
+            let next_uri = make_page_uri(query_id, page + 1);
+            assert_ne!(result.next_uri, Some(next_uri), "{:?}", msg());
+        } else {
+            let next_uri = make_page_uri(query_id, page + 1);
+            assert_eq!(result.next_uri, Some(next_uri), "{:?}", msg());
+        }
+    }
+
+    let response = get_uri(&ep, &make_page_uri(query_id, 5)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The result is released after the final uri.
+    check_final(&ep, &make_final_uri(query_id)).await?;
+    let response = get_uri(&ep, &make_page_uri(query_id, 0)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -1253,3 +1334,60 @@ async fn test_auth_configured_user() -> Result<()> {
     assert_auth_current_user(&ep, user_name, basic, "%").await?;
     Ok(())
 }
+
+async fn post_sql_with_accept(ep: &EndpointType, sql: &str, accept: &str) -> Response {
+    let json = serde_json::json!({ "sql": sql.to_string() });
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, accept)
+        .typed_header(basic)
+        .body(serde_json::to_vec(&json).unwrap());
+    ep.call(req).await.unwrap_or_else(|err| err.into_response())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_stream_result() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let ep = create_endpoint().await?;
+    let sql = "select number as a, number::string as b from numbers(3) order by a";
+
+    let response = post_sql_with_accept(&ep, sql, "text/csv").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("text/csv; charset=UTF-8; header=present")
+    );
+    assert!(response.headers().contains_key("X-DATABEND-QUERY-ID"));
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(body, "\"a\",\"b\"\n0,\"0\"\n1,\"1\"\n2,\"2\"\n");
+
+    // the first supported media type is used
+    let accept = "text/html, application/x-ndjson;q=0.9, text/csv";
+    let response = post_sql_with_accept(&ep, sql, accept).await;
+    assert_eq!(
+        response.content_type(),
+        Some("application/x-ndjson; charset=UTF-8")
+    );
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(body, "[0,\"0\"]\n[1,\"1\"]\n[2,\"2\"]\n");
+
+    let accept = "application/vnd.apache.arrow.stream";
+    let response = post_sql_with_accept(&ep, sql, accept).await;
+    assert_eq!(response.content_type(), Some(accept));
+    let body = response.into_body().into_vec().await.unwrap();
+    // starts with the schema message, ends with the end-of-stream marker
+    assert_eq!(&body[..4], &[0xff; 4]);
+    assert_eq!(&body[body.len() - 8..], &[
+        0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0
+    ]);
+
+    // fails before any data, the error is returned in json
+    let response = post_sql_with_accept(&ep, "select * from t_not_exists", "text/csv").await;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
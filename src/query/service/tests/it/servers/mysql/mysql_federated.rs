@@ -65,5 +65,21 @@ fn test_mysql_federated() -> Result<()> {
         }
     }
 
+    // txn
+    {
+        // handled by databend
+        for query in [
+            "BEGIN",
+            "start transaction;",
+            "COMMIT",
+            "rollback;",
+            "ROLLBACK WORK",
+            "COMMIT PREPARED 'etl_1'",
+            "rollback prepared 'etl_1'",
+        ] {
+            assert!(federated.check(query).is_none(), "{}", query);
+        }
+    }
+
     Ok(())
 }
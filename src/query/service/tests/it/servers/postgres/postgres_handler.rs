@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = handler.start("127.0.0.1:0".parse::<SocketAddr>()?).await?;
+    let mut stream = connect(listening.port()).await?;
+
+    let messages = query(
+        &mut stream,
+        b'Q',
+        &cstring("SELECT 1 AS a, 'x' AS b; SELECT 2"),
+    )
+    .await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'T', b'D', b'C', b'T', b'D', b'C', b'Z']);
+    assert_eq!(data_row(&messages[1].1), vec![
+        Some("1".to_string()),
+        Some("x".to_string())
+    ]);
+    assert_eq!(messages[2].1, cstring("SELECT 1"));
+
+    let messages = query(&mut stream, b'Q', &cstring("SELECT * FROM no_such_table")).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'E', b'Z']);
+
+    let messages = query(&mut stream, b'Q', &cstring(" ; ")).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'I', b'Z']);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = handler.start("127.0.0.1:0".parse::<SocketAddr>()?).await?;
+    let mut stream = connect(listening.port()).await?;
+
+    // Parse: unnamed statement with one parameter of type int8
+    let mut parse = cstring("");
+    parse.extend(cstring("SELECT $1 + 1 AS a, '$1' AS b"));
+    parse.extend(1i16.to_be_bytes());
+    parse.extend(20u32.to_be_bytes());
+    write_message(&mut stream, b'P', &parse).await?;
+
+    // Bind: unnamed portal, the parameter in text format
+    let mut bind = cstring("");
+    bind.extend(cstring(""));
+    bind.extend(0i16.to_be_bytes());
+    bind.extend(1i16.to_be_bytes());
+    bind.extend(2i32.to_be_bytes());
+    bind.extend(b"41");
+    bind.extend(0i16.to_be_bytes());
+    write_message(&mut stream, b'B', &bind).await?;
+
+    let mut describe = vec![b'P'];
+    describe.extend(cstring(""));
+    write_message(&mut stream, b'D', &describe).await?;
+
+    let mut execute = cstring("");
+    execute.extend(0i32.to_be_bytes());
+    write_message(&mut stream, b'E', &execute).await?;
+
+    let messages = query(&mut stream, b'S', &[]).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'1', b'2', b'T', b'D', b'C', b'Z']);
+    assert_eq!(data_row(&messages[3].1), vec![
+        Some("42".to_string()),
+        Some("$1".to_string())
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_rejected_session() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(ConfigBuilder::create().max_active_sessions(1).build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = handler.start("127.0.0.1:0".parse::<SocketAddr>()?).await?;
+
+    let _accepted = connect(listening.port()).await?;
+
+    let mut stream = TcpStream::connect(("127.0.0.1", listening.port())).await?;
+    write_startup(&mut stream).await?;
+    let (tag, body) = read_message(&mut stream).await?;
+    assert_eq!(tag, b'E');
+    assert!(String::from_utf8_lossy(&body).contains("53300"));
+
+    Ok(())
+}
+
+fn cstring(s: &str) -> Vec<u8> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+async fn write_startup(stream: &mut TcpStream) -> Result<()> {
+    let mut body = 196608i32.to_be_bytes().to_vec();
+    body.extend(cstring("user"));
+    body.extend(cstring("root"));
+    body.push(0);
+    stream
+        .write_all(&(body.len() as i32 + 4).to_be_bytes())
+        .await?;
+    stream.write_all(&body).await?;
+    Ok(())
+}
+
+async fn connect(port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+    write_startup(&mut stream).await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(messages.first().map(|(tag, _)| *tag), Some(b'R'));
+    Ok(stream)
+}
+
+async fn write_message(stream: &mut TcpStream, tag: u8, body: &[u8]) -> Result<()> {
+    stream.write_all(&[tag]).await?;
+    stream
+        .write_all(&(body.len() as i32 + 4).to_be_bytes())
+        .await?;
+    stream.write_all(body).await?;
+    Ok(())
+}
+
+async fn read_message(stream: &mut TcpStream) -> Result<(u8, Vec<u8>)> {
+    let tag = stream.read_u8().await?;
+    let len = stream.read_i32().await?;
+    let mut body = vec![0; len as usize - 4];
+    stream.read_exact(&mut body).await?;
+    Ok((tag, body))
+}
+
+async fn read_until_ready(stream: &mut TcpStream) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut messages = vec![];
+    loop {
+        let message = read_message(stream).await?;
+        let ready = message.0 == b'Z';
+        messages.push(message);
+        if ready {
+            return Ok(messages);
+        }
+    }
+}
+
+async fn query(stream: &mut TcpStream, tag: u8, body: &[u8]) -> Result<Vec<(u8, Vec<u8>)>> {
+    write_message(stream, tag, body).await?;
+    read_until_ready(stream).await
+}
+
+fn data_row(body: &[u8]) -> Vec<Option<String>> {
+    let num_columns = i16::from_be_bytes([body[0], body[1]]) as usize;
+    let mut pos = 2;
+    let mut values = Vec::with_capacity(num_columns);
+    for _ in 0..num_columns {
+        let len = i32::from_be_bytes(body[pos..pos + 4].try_into().unwrap());
+        pos += 4;
+        if len < 0 {
+            values.push(None);
+        } else {
+            let len = len as usize;
+            values.push(Some(
+                String::from_utf8_lossy(&body[pos..pos + len]).to_string(),
+            ));
+            pos += len;
+        }
+    }
+    values
+}
@@ -155,6 +155,7 @@ fn test_format() {
                     .into(),
                 ],
                 non_equi_conditions: vec![],
+                is_null_equal: vec![],
                 join_type: JoinType::Inner,
                 marker_index: None,
                 from_correlated_subquery: false,
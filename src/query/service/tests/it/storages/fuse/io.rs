@@ -12,8 +12,23 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::parquet::bloom_filter;
+use common_arrow::parquet::read::read_metadata;
 use common_exception::Result;
+use common_expression::types::number::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use storages_common_blocks::blocks_to_parquet_with_options;
+use storages_common_blocks::ParquetWriteOptions;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
@@ -31,3 +46,42 @@ fn test_meta_locations() -> Result<()> {
     assert!(snapshot_loc.starts_with(test_prefix));
     Ok(())
 }
+
+#[test]
+fn test_parquet_bloom_filters() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3, 4]),
+        StringType::from_data(vec!["aa", "bb", "cc", "dd"]),
+    ]);
+    let options = ParquetWriteOptions {
+        row_group_size: Some(2),
+        bloom_filter_columns: vec!["b".to_string()],
+        ..Default::default()
+    };
+    let mut buf = vec![];
+    let (size, _) = blocks_to_parquet_with_options(&schema, vec![block], &mut buf, &options)?;
+    assert_eq!(size as usize, buf.len());
+
+    let mut reader = Cursor::new(buf);
+    let meta = read_metadata(&mut reader)?;
+    assert_eq!(meta.row_groups.len(), 2);
+    for (row_group, values) in meta.row_groups.iter().zip([["aa", "bb"], ["cc", "dd"]]) {
+        let mut bitset = vec![];
+        bloom_filter::read(&row_group.columns()[0], &mut reader, &mut bitset)?;
+        assert!(bitset.is_empty());
+
+        bloom_filter::read(&row_group.columns()[1], &mut reader, &mut bitset)?;
+        assert!(!bitset.is_empty());
+        for value in values {
+            assert!(bloom_filter::is_in_set(
+                &bitset,
+                bloom_filter::hash_byte(value)
+            ));
+        }
+    }
+    Ok(())
+}
@@ -13,5 +13,6 @@
 //  limitations under the License.
 
 mod snapshot;
+mod table_snapshot_statistics;
 
 mod serialization_format_compatability;
@@ -0,0 +1,68 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use storages_common_table_meta::meta::HistogramBucket;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
+
+#[test]
+fn test_table_snapshot_statistics_backward_compat() {
+    // written before the histograms and the clustering depth were collected.
+    let old = r#"{
+        "format_version": 0,
+        "snapshot_id": "6d8f5c3c-0a6e-4c7c-9d64-2f3b1b0e8c41",
+        "column_distinct_values": {"0": 3, "2": 7}
+    }"#;
+    let stats: TableSnapshotStatistics = serde_json::from_str(old).unwrap();
+
+    assert_eq!(stats.format_version(), 0);
+    assert_eq!(
+        stats.get_column_distinct_values(),
+        &HashMap::from([(0, 3), (2, 7)])
+    );
+    assert!(stats.get_column_histograms().is_empty());
+    assert!(stats.get_clustering_depth().is_none());
+}
+
+#[test]
+fn test_table_snapshot_statistics_histograms_round_trip() {
+    let histogram = vec![
+        HistogramBucket {
+            upper_bound: Scalar::Number(NumberScalar::Int32(1)),
+            num_values: 1.0,
+            num_distinct: 1.0,
+        },
+        HistogramBucket {
+            upper_bound: Scalar::Number(NumberScalar::Int32(3)),
+            num_values: 2.0,
+            num_distinct: 1.0,
+        },
+    ];
+    let stats = TableSnapshotStatistics::new(
+        HashMap::from([(0, 2)]),
+        HashMap::from([(0, histogram.clone())]),
+        None,
+    );
+
+    let bytes = serde_json::to_vec(&stats).unwrap();
+    let stats: TableSnapshotStatistics = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(stats.get_column_distinct_values(), &HashMap::from([(0, 2)]));
+    assert_eq!(
+        stats.get_column_histograms(),
+        &HashMap::from([(0, histogram)])
+    );
+}
@@ -12,10 +12,13 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::base::tokio;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
@@ -26,12 +29,15 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::table::Table;
+use common_catalog::table_context::PipelineTaskInfo;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -86,6 +92,7 @@ use common_meta_types::MetaId;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_storage::DataCacheMetrics;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storages_fuse::FuseTable;
@@ -476,10 +483,18 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_warehouse_state(&self) -> String {
+        todo!()
+    }
+
     fn get_processes_info(&self) -> Vec<ProcessInfo> {
         todo!()
     }
 
+    fn get_pipeline_tasks_info(&self) -> Vec<PipelineTaskInfo> {
+        todo!()
+    }
+
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         todo!()
     }
@@ -496,6 +511,24 @@ impl TableContext for CtxDelegation {
     fn set_query_id_result_cache(&self, _query_id: String, _result_cache_key: String) {
         todo!()
     }
+    fn get_declared_result(&self, _name: &str) -> Option<String> {
+        todo!()
+    }
+    fn set_declared_result(&self, _name: String, _location: String) {
+        todo!()
+    }
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+    fn get_snapshot_pin_time(&self) -> DateTime<Utc> {
+        todo!()
+    }
+    fn get_pinned_snapshots(&self) -> BTreeMap<String, String> {
+        todo!()
+    }
+    fn add_pinned_snapshot(&self, _table: String, _snapshot_id: String) {
+        todo!()
+    }
 
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         todo!()
@@ -525,6 +558,18 @@ impl TableContext for CtxDelegation {
         self.ctx.get_data_operator()
     }
 
+    fn get_data_cache_metrics(&self) -> Arc<DataCacheMetrics> {
+        self.ctx.get_data_cache_metrics()
+    }
+
+    fn add_runtime_filters(&self, _table_index: usize, _filters: Vec<Expr<String>>) {
+        todo!()
+    }
+
+    fn get_runtime_filters(&self, _table_index: usize) -> Vec<Expr<String>> {
+        todo!()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
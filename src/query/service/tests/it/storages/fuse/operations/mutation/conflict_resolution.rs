@@ -0,0 +1,85 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::TableSchema;
+use common_storages_fuse::operations::MutationGenerator;
+use common_storages_fuse::operations::SegmentChanges;
+use common_storages_fuse::operations::SnapshotGenerator;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::TableSnapshot;
+
+fn segment(name: &str) -> Location {
+    (name.to_string(), 3)
+}
+
+fn stats(row_count: u64) -> Statistics {
+    Statistics {
+        row_count,
+        ..Default::default()
+    }
+}
+
+fn snapshot(segments: &[&str], row_count: u64) -> Arc<TableSnapshot> {
+    let mut snapshot = TableSnapshot::new_empty_snapshot(TableSchema::empty());
+    snapshot.segments = segments.iter().map(|name| segment(name)).collect();
+    snapshot.summary = stats(row_count);
+    Arc::new(snapshot)
+}
+
+// a mutation replacing `s1` of the base snapshot [s1, s2, s3], each segment has 10 rows.
+fn mutation_generator(base: Arc<TableSnapshot>) -> MutationGenerator {
+    let mut generator = MutationGenerator::new(base);
+    generator.set_merged_segments(vec![segment("s1_new"), segment("s2"), segment("s3")]);
+    generator.set_merged_summary(stats(25));
+    generator.set_segment_changes(Some(SegmentChanges {
+        replaced_segments: vec![(segment("s1"), Some(segment("s1_new")))],
+        appended_segments: vec![],
+        removed_statistics: stats(10),
+        added_statistics: stats(5),
+    }));
+    generator
+}
+
+#[test]
+fn test_rebase_on_concurrent_disjoint_mutation() -> Result<()> {
+    let base = snapshot(&["s1", "s2", "s3"], 30);
+    let generator = mutation_generator(base);
+
+    // `s3` has been removed, and a segment has been appended concurrently.
+    let latest = snapshot(&["s4", "s1", "s2"], 30);
+    let new_snapshot = generator.generate_new_snapshot(TableSchema::empty(), None, Some(latest))?;
+    assert_eq!(new_snapshot.segments, vec![
+        segment("s4"),
+        segment("s1_new"),
+        segment("s2")
+    ]);
+    assert_eq!(new_snapshot.summary.row_count, 25);
+    Ok(())
+}
+
+#[test]
+fn test_rebase_on_concurrent_overlapping_mutation() -> Result<()> {
+    let base = snapshot(&["s1", "s2", "s3"], 30);
+    let generator = mutation_generator(base);
+
+    // `s1` has been replaced concurrently as well.
+    let latest = snapshot(&["s1_other", "s2", "s3"], 28);
+    let res = generator.generate_new_snapshot(TableSchema::empty(), None, Some(latest));
+    assert!(res.is_err());
+    Ok(())
+}
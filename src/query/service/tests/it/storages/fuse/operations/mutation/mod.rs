@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod block_compact_mutator;
+mod conflict_resolution;
 mod deletion;
 mod recluster_mutator;
 mod segments_compact_mutator;
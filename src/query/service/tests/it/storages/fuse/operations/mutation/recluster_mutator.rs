@@ -28,6 +28,7 @@ use common_storages_fuse::operations::BlockMetaIndex;
 use common_storages_fuse::pruning::create_segment_location_vector;
 use common_storages_fuse::pruning::FusePruner;
 use common_storages_fuse::statistics::reducers::reduce_block_metas;
+use common_storages_fuse::MAX_RECLUSTER_BLOCK_COUNT;
 use databend_query::sessions::TableContext;
 use databend_query::storages::fuse::io::SegmentWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
@@ -141,7 +142,8 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         }
     });
 
-    let mut mutator = ReclusterMutator::try_create(1.0, BlockThresholds::default())?;
+    let mut mutator =
+        ReclusterMutator::try_create(1.0, BlockThresholds::default(), MAX_RECLUSTER_BLOCK_COUNT)?;
 
     let need_recluster = mutator.target_select(blocks_map).await?;
     assert!(need_recluster);
@@ -149,3 +151,60 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_recluster_mutator_select_worst_level() -> Result<()> {
+    let block = |level: i32, min: i64, max: i64| {
+        Arc::new(BlockMeta::new(
+            1,
+            1,
+            1,
+            HashMap::default(),
+            HashMap::default(),
+            Some(ClusterStatistics {
+                cluster_key_id: 0,
+                min: vec![Scalar::from(min)],
+                max: vec![Scalar::from(max)],
+                level,
+                pages: None,
+            }),
+            (Uuid::new_v4().simple().to_string(), DataBlock::VERSION),
+            None,
+            0,
+            meta::Compression::Lz4Raw,
+        ))
+    };
+    let index = |block_idx: usize| BlockMetaIndex {
+        segment_idx: 0,
+        block_idx,
+    };
+
+    let mut blocks_map: BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>> = BTreeMap::new();
+    // average depth of level 0 is 1.6667.
+    blocks_map.insert(0, vec![
+        (index(0), block(0, 1, 3)),
+        (index(1), block(0, 2, 4)),
+        (index(2), block(0, 4, 5)),
+    ]);
+    // average depth of level 1 is 3.
+    blocks_map.insert(1, vec![
+        (index(3), block(1, 1, 5)),
+        (index(4), block(1, 1, 5)),
+        (index(5), block(1, 1, 5)),
+    ]);
+
+    // the blocks are not small enough to be merged directly.
+    let block_thresholds = BlockThresholds::new(1, 0, 1);
+    let mut mutator = ReclusterMutator::try_create(1.0, block_thresholds, 2)?;
+    let need_recluster = mutator.target_select(blocks_map.clone()).await?;
+    assert!(need_recluster);
+    assert_eq!(mutator.level(), 1);
+    // limited by the max block count.
+    assert_eq!(mutator.selected_blocks().len(), 2);
+
+    let mut mutator = ReclusterMutator::try_create(3.0, block_thresholds, 2)?;
+    let need_recluster = mutator.target_select(blocks_map).await?;
+    assert!(!need_recluster);
+
+    Ok(())
+}
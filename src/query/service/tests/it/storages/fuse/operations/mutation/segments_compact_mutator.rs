@@ -720,6 +720,8 @@ impl CompactSegmentTestFixture {
                 compressed_byte_size: stats_acc.file_size,
                 index_size: stats_acc.index_size,
                 col_stats,
+                cluster_depth_sum: 0,
+                clustered_block_count: 0,
             });
             let location = segment_writer.write_segment_no_cache(&segment_info).await?;
             segment_infos.push(segment_info);
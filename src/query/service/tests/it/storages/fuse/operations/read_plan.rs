@@ -54,6 +54,7 @@ fn test_to_partitions() -> Result<()> {
             offset: 0,
             len: col_size as u64,
             num_values: 0,
+            compression: None,
         })
     };
 
@@ -44,6 +44,7 @@ use common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use common_storages_fuse::FuseStorageFormat;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
+use databend_query::storages::fuse::statistics::gen_columns_statistics_with_prefix_len;
 use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
 use databend_query::storages::fuse::statistics::StatisticsAccumulator;
@@ -108,6 +109,41 @@ fn test_ft_stats_block_stats_with_column_distinct_count() -> common_exception::R
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_block_stats_string_prefix() -> common_exception::Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "url",
+        TableDataType::String,
+    )]));
+    let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![
+        "https://databend.rs/doc/sql-commands/ddl/table/create-table",
+        "https://databend.rs/doc/sql-functions/string-functions/concat",
+    ])]);
+
+    // the urls share a prefix longer than the default prefix length
+    let r = gen_columns_statistics(&block, None, &schema)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.min, Scalar::String(b"https://databend".to_vec()));
+    assert_eq!(
+        col_stats.max,
+        Scalar::String(format!("https://databen{STATS_REPLACEMENT_CHAR}").into_bytes())
+    );
+
+    let r = gen_columns_statistics_with_prefix_len(&block, None, &schema, 32)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(
+        col_stats.min,
+        Scalar::String(b"https://databend.rs/doc/sql-comm".to_vec())
+    );
+    assert_eq!(
+        col_stats.max,
+        Scalar::String(
+            format!("https://databend.rs/doc/sql-fun{STATS_REPLACEMENT_CHAR}").into_bytes()
+        )
+    );
+    Ok(())
+}
+
 #[test]
 fn test_ft_tuple_stats_block_stats() -> common_exception::Result<()> {
     let schema = Arc::new(TableSchema::new(vec![TableField::new(
@@ -358,8 +394,8 @@ fn test_ft_stats_block_stats_string_columns_trimming() -> common_exception::Resu
         let data_value_min = Scalar::String(min_expr.clone().into_bytes());
         let data_value_max = Scalar::String(max_expr.clone().into_bytes());
 
-        let trimmed_min = data_value_min.clone().trim_min();
-        let trimmed_max = data_value_max.clone().trim_max();
+        let trimmed_min = data_value_min.clone().trim_min(STATS_STRING_PREFIX_LEN);
+        let trimmed_max = data_value_max.clone().trim_max(STATS_STRING_PREFIX_LEN);
 
         let meaningless_to_collect_max = is_degenerated_case(max_expr.as_str());
 
@@ -549,3 +585,56 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_reduce_cluster_depth() -> common_exception::Result<()> {
+    let block_meta = |cluster_key_id: u32, min: i64, max: i64| {
+        BlockMeta::new(
+            1,
+            1,
+            1,
+            HashMap::new(),
+            HashMap::new(),
+            Some(ClusterStatistics {
+                cluster_key_id,
+                min: vec![Scalar::Number(NumberScalar::Int64(min))],
+                max: vec![Scalar::Number(NumberScalar::Int64(max))],
+                level: 0,
+                pages: None,
+            }),
+            ("".to_owned(), 0),
+            None,
+            0,
+            Compression::Lz4Raw,
+        )
+    };
+
+    // [1, 3] and [2, 4] overlap, [5, 6] does not, the block of the previous cluster key is
+    // not counted.
+    let blocks = vec![
+        block_meta(1, 1, 3),
+        block_meta(1, 2, 4),
+        block_meta(1, 5, 6),
+        block_meta(0, 1, 6),
+    ];
+    let stats = reduce_block_metas(&blocks, BlockThresholds::default());
+    assert_eq!(stats.cluster_depth_sum, 5);
+    assert_eq!(stats.clustered_block_count, 3);
+    assert_eq!(stats.average_cluster_depth(), Some(5.0 / 3.0));
+
+    // the depths of the segments are summed up in the summary of the snapshot.
+    let other = reduce_block_metas(&[block_meta(1, 1, 2)], BlockThresholds::default());
+    let merged = reducers::merge_statistics(&stats, &other);
+    assert_eq!(merged.cluster_depth_sum, 6);
+    assert_eq!(merged.clustered_block_count, 4);
+    let deducted = reducers::deduct_statistics(&merged, &stats);
+    assert_eq!(deducted.average_cluster_depth(), Some(1.0));
+
+    // blocks without cluster statistics are not tracked.
+    let mut unclustered = block_meta(1, 1, 2);
+    unclustered.cluster_stats = None;
+    let stats = reduce_block_metas(&[unclustered], BlockThresholds::default());
+    assert_eq!(stats.average_cluster_depth(), None);
+
+    Ok(())
+}
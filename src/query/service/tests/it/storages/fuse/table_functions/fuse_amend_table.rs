@@ -0,0 +1,166 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashSet;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::DataBlock;
+use databend_query::storages::fuse::io::MetaReaders;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::table_test_fixture::*;
+use futures::TryStreamExt;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_amend_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // three insertions, each of them appends one segment of one block
+    let mut snapshots = vec![];
+    for values in ["(1, (2, 3)), (2, (4, 6))", "(3, (6, 9))", "(4, (8, 12))"] {
+        let qry = format!("insert into {}.{} values {}", db, tbl, values);
+        execute_query(ctx.clone(), qry.as_str())
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let location = fuse_table.snapshot_loc().await?.unwrap();
+        let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+        snapshots.push((location, snapshot));
+    }
+    let (first_location, first) = &snapshots[0];
+    let (_, second) = &snapshots[1];
+    let (_, third) = &snapshots[2];
+    assert_eq!(third.segments.len(), 3);
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+
+    // remove the segment of the second insertion
+    let second_segment = new_segments(first, second).pop().unwrap();
+    operator.delete(&second_segment).await?;
+
+    // remove the block of the third insertion, its segment is kept
+    let third_segment = new_segments(second, third).pop().unwrap();
+    let segment_reader =
+        MetaReaders::segment_info_reader(operator.clone(), TestFixture::default_table_schema());
+    let params = LoadParams {
+        location: third_segment,
+        len_hint: None,
+        ver: SegmentInfo::VERSION,
+        put_cache: false,
+    };
+    let compact_segment_info = segment_reader.read(&params).await?;
+    let segment_info = SegmentInfo::try_from(compact_segment_info.as_ref())?;
+    assert_eq!(segment_info.blocks.len(), 1);
+    operator.delete(&segment_info.blocks[0].location.0).await?;
+
+    let columns = "missing_segment_count, missing_block_count, missing_row_count, lost_row_count, consistent, action";
+
+    {
+        // check only, the table is left untouched
+        let qry = format!("select {} from fuse_amend('{}', '{}')", columns, db, tbl);
+        let expected = vec![
+            "+----------+----------+----------+----------+----------+-------------------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5          |",
+            "+----------+----------+----------+----------+----------+-------------------+",
+            "| 1        | 1        | 1        | 2        | false    | 'to be discarded' |",
+            "| 1        | 0        | 0        | 1        | false    | 'to be discarded' |",
+            "| 0        | 0        | 0        | 0        | true     | 'roll back to'    |",
+            "+----------+----------+----------+----------+----------+-------------------+",
+        ];
+        expects_ok(
+            "check",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        assert_eq!(
+            fuse_table.snapshot_loc().await?,
+            Some(snapshots[2].0.clone())
+        );
+    }
+
+    {
+        // repair, the table is rolled back to the snapshot of the first insertion
+        let qry = format!("alter table {}.{} amend", db, tbl);
+        let blocks = execute_query(ctx.clone(), qry.as_str())
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+        let block = DataBlock::concat(&blocks)?;
+        assert_eq!(block.num_rows(), 3);
+        let actions = (0..block.num_rows())
+            .map(|i| block.get_by_offset(8).value.index(i).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(actions, vec![
+            "'discarded'",
+            "'discarded'",
+            "'rolled back to'"
+        ]);
+
+        let table = fixture.latest_default_table().await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        assert_eq!(
+            fuse_table.snapshot_loc().await?,
+            Some(first_location.clone())
+        );
+        let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+        assert_eq!(snapshot.summary.row_count, 2);
+    }
+
+    {
+        // the repaired table is consistent
+        let qry = format!("select {} from fuse_amend('{}', '{}')", columns, db, tbl);
+        let expected = vec![
+            "+----------+----------+----------+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5 |",
+            "+----------+----------+----------+----------+----------+----------+",
+            "| 0        | 0        | 0        | 0        | true     | 'none'   |",
+            "+----------+----------+----------+----------+----------+----------+",
+        ];
+        expects_ok(
+            "after_repair",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// The locations of the segments that are referenced by `current` but not by `previous`.
+fn new_segments(previous: &TableSnapshot, current: &TableSnapshot) -> Vec<String> {
+    let previous: HashSet<_> = previous.segments.iter().map(|l| &l.0).collect();
+    current
+        .segments
+        .iter()
+        .filter(|l| !previous.contains(&l.0))
+        .map(|l| l.0.clone())
+        .collect()
+}
@@ -88,6 +88,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("storage_prefetch_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum byte size of the column data of the next partitions prefetched into the data cache during a scan. Setting it to 0 disables the prefetching.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
@@ -118,12 +124,30 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("partial_aggregation_abandon_min_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100000),
+                    desc: "Sets the number of rows a partial aggregation processes before checking whether to abandon it, 0 disables it.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("partial_aggregation_abandon_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(90),
+                    desc: "Abandons a partial aggregation when its number of keys exceeds this percentage of the rows it processed.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("max_inlist_to_or", DefaultSettingValue {
                     value: UserSettingValue::UInt64(3),
                     desc: "Sets the maximum number of values that can be included in an IN expression to be converted to an OR operator.",
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("max_cte_recursive_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations a recursive CTE can run before the query is aborted.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
@@ -138,7 +162,7 @@ impl DefaultSettings {
                 }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
-                    desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\", and \"Hive\".",
+                    desc: "Sets the SQL dialect, which affects the quoting, the `||` operator, division by zero, date formats and function names. Available values include \"PostgreSQL\", \"MySQL\", and \"Hive\".",
                     possible_values: Some(vec!["PostgreSQL", "MySQL", "Hive"]),
                     display_in_show_settings: true,
                 }),
@@ -148,6 +172,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_plan_baselines", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables reusing the plan shapes pinned by ALTER STATEMENT ... PIN PLAN.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("enable_cardinality_feedback", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables recording the actual cardinalities of the operators of queries, and estimating them with the recorded ones when the same queries are planned again.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
@@ -160,6 +196,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_runtime_filter_pushdown", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables pruning the blocks of the probe side table scans with the range of the build keys of hash joins.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("enable_sort_merge_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables sort-merge join for the equi-joins whose inputs are both sorted on the join keys.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("max_execute_time", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -184,12 +232,24 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_aggregating_index_scan", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting the queries matching an aggregating index to read the index data.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("prefer_broadcast_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables broadcast join.",
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("broadcast_join_fallback_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Switches a broadcast join to a partitioned join at runtime, once its build side has this many times the estimated rows. Setting it to 0 disables the switch.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -245,11 +305,29 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("external_file_list_cache_ttl_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(60), // seconds
+                    desc: "Sets the time-to-live (TTL) in seconds for the cached file lists of external tables, 0 lists the files on every read.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("query_result_cache_allow_inconsistent", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
                     possible_values: None,
                     display_in_show_settings: true,
+                }),
+                ("enable_query_log_analyzer", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables tagging finished queries in system.query_log with anomaly flags derived from their runtime metrics.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("query_log_slow_query_threshold_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10000),
+                    desc: "Sets the duration in milliseconds above which the query log analyzer classifies a query as slow.",
+                    possible_values: None,
+                    display_in_show_settings: true,
                 }),
                     #[cfg(feature = "hive")]
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
@@ -271,6 +349,24 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("spilling_memory_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the percentage of max_memory_usage the process can use before the aggregators, distincts and hash joins start spilling data to storage, 0 disables it.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("join_spilling_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that the build side of a hash join can use before spilling partitions to storage, 0 disables it.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("sort_spilling_memory_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the percentage of max_memory_usage the process can use before the sorts start spilling sorted runs to storage, 0 disables it.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
@@ -289,12 +385,48 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("dry_run", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Validates statements like EXPLAIN VERIFY instead of executing them.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column.",
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting queries to read the matching materialized views instead of their tables.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("materialized_view_staleness_tolerance", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the seconds a materialized view can lag behind its table and still be read in place of the table.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("auto_compaction_min_blocks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100),
+                    desc: "Sets the number of blocks a fuse table with small blocks must have to be compacted in the background, 0 disables background compaction.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("auto_compaction_io_budget", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024 * 1024 * 1024),
+                    desc: "Sets the bytes of blocks that background compaction reads in each round, 0 disables background compaction.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("auto_recluster_block_budget", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(500),
+                    desc: "Sets the number of blocks that background recluster rewrites in each round, 0 disables background recluster.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
 
                 // enterprise license related settings
                 ("enterprise_license", DefaultSettingValue {
@@ -310,6 +442,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("prepared_commit_id", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Keeps the commits of fuse table writes aside as a prepared commit with this id, see PREPARE COMMIT.",
+                    possible_values: None,
+                    display_in_show_settings: false,
+                }),
+                ("prepared_commit_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(600),
+                    desc: "Sets the seconds after which a prepared commit that has not been committed is rolled back.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("deduplicate_label", DefaultSettingValue {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "Sql duplicate label for deduplication.",
@@ -149,6 +149,14 @@ impl Settings {
         self.try_set_u64("storage_io_max_page_bytes_for_read", val)
     }
 
+    pub fn get_storage_prefetch_bytes(&self) -> Result<u64> {
+        self.try_get_u64("storage_prefetch_bytes")
+    }
+
+    pub fn set_storage_prefetch_bytes(&self, val: u64) -> Result<()> {
+        self.try_set_u64("storage_prefetch_bytes", val)
+    }
+
     // Get max_execute_time.
     pub fn get_max_execute_time(&self) -> Result<u64> {
         self.try_get_u64("max_execute_time")
@@ -191,10 +199,22 @@ impl Settings {
         self.try_set_u64("group_by_two_level_threshold", val)
     }
 
+    pub fn get_partial_aggregation_abandon_min_rows(&self) -> Result<u64> {
+        self.try_get_u64("partial_aggregation_abandon_min_rows")
+    }
+
+    pub fn get_partial_aggregation_abandon_ratio(&self) -> Result<u64> {
+        self.try_get_u64("partial_aggregation_abandon_ratio")
+    }
+
     pub fn get_max_inlist_to_or(&self) -> Result<u64> {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -215,6 +235,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_eval_index")? != 0)
     }
 
+    pub fn get_enable_aggregating_index_scan(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
+    }
+
     pub fn get_max_result_rows(&self) -> Result<u64> {
         self.try_get_u64("max_result_rows")
     }
@@ -231,6 +255,14 @@ impl Settings {
         self.try_set_u64("enable_dphyp", u64::from(val))
     }
 
+    pub fn get_enable_plan_baselines(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_baselines")? != 0)
+    }
+
+    pub fn get_enable_cardinality_feedback(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_cardinality_feedback")? != 0)
+    }
+
     pub fn get_enable_cbo(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
@@ -247,6 +279,14 @@ impl Settings {
         self.try_set_u64("enable_runtime_filter", u64::from(val))
     }
 
+    pub fn get_enable_runtime_filter_pushdown(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_runtime_filter_pushdown")? != 0)
+    }
+
+    pub fn get_enable_sort_merge_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_sort_merge_join")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -255,6 +295,10 @@ impl Settings {
         self.try_set_u64("join_distribution_type", u64::from(val))
     }
 
+    pub fn get_broadcast_join_fallback_ratio(&self) -> Result<u64> {
+        self.try_get_u64("broadcast_join_fallback_ratio")
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -306,10 +350,22 @@ impl Settings {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
 
+    pub fn get_external_file_list_cache_ttl_secs(&self) -> Result<u64> {
+        self.try_get_u64("external_file_list_cache_ttl_secs")
+    }
+
     pub fn get_query_result_cache_allow_inconsistent(&self) -> Result<bool> {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_enable_query_log_analyzer(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_log_analyzer")? != 0)
+    }
+
+    pub fn get_query_log_slow_query_threshold_ms(&self) -> Result<u64> {
+        self.try_get_u64("query_log_slow_query_threshold_ms")
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -318,6 +374,18 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_spilling_memory_ratio(&self) -> Result<u64> {
+        self.try_get_u64("spilling_memory_ratio")
+    }
+
+    pub fn get_join_spilling_threshold(&self) -> Result<u64> {
+        self.try_get_u64("join_spilling_threshold")
+    }
+
+    pub fn get_sort_spilling_memory_ratio(&self) -> Result<u64> {
+        self.try_get_u64("sort_spilling_memory_ratio")
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -350,6 +418,34 @@ impl Settings {
         self.try_get_u64("table_lock_expire_secs")
     }
 
+    pub fn get_dry_run(&self) -> Result<bool> {
+        Ok(self.try_get_u64("dry_run")? != 0)
+    }
+
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn set_enable_materialized_view_rewrite(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_materialized_view_rewrite", u64::from(val))
+    }
+
+    pub fn get_materialized_view_staleness_tolerance(&self) -> Result<u64> {
+        self.try_get_u64("materialized_view_staleness_tolerance")
+    }
+
+    pub fn get_auto_compaction_min_blocks(&self) -> Result<u64> {
+        self.try_get_u64("auto_compaction_min_blocks")
+    }
+
+    pub fn get_auto_compaction_io_budget(&self) -> Result<u64> {
+        self.try_get_u64("auto_compaction_io_budget")
+    }
+
+    pub fn get_auto_recluster_block_budget(&self) -> Result<u64> {
+        self.try_get_u64("auto_recluster_block_budget")
+    }
+
     pub fn get_enterprise_license(&self) -> Result<String> {
         self.try_get_string("enterprise_license")
     }
@@ -366,4 +462,21 @@ impl Settings {
             Ok(Some(deduplicate_label))
         }
     }
+
+    pub fn get_prepared_commit_id(&self) -> Result<Option<String>> {
+        let prepared_commit_id = self.try_get_string("prepared_commit_id")?;
+        if prepared_commit_id.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(prepared_commit_id))
+        }
+    }
+
+    pub fn set_prepared_commit_id(&self, val: String) -> Result<()> {
+        self.set_setting("prepared_commit_id".to_string(), val)
+    }
+
+    pub fn get_prepared_commit_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("prepared_commit_timeout_secs")
+    }
 }
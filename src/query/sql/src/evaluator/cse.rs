@@ -103,7 +103,7 @@ pub fn apply_cse(
 /// and stores the count in a HashMap.
 fn count_expressions(expr: &Expr, counter: &mut HashMap<Expr, usize>) {
     match expr {
-        Expr::FunctionCall { args, .. } => {
+        Expr::FunctionCall { args, .. } | Expr::LambdaFunctionCall { args, .. } => {
             let entry = counter.entry(expr.clone()).or_insert(0);
             *entry += 1;
 
@@ -139,7 +139,7 @@ fn perform_cse_replacement(expr: &mut Expr, cse_replacements: &HashMap<String, E
         } => {
             perform_cse_replacement(inner_expr.as_mut(), cse_replacements);
         }
-        Expr::FunctionCall { args, .. } => {
+        Expr::FunctionCall { args, .. } | Expr::LambdaFunctionCall { args, .. } => {
             for arg in args.iter_mut() {
                 perform_cse_replacement(arg, cse_replacements);
             }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::FormatTreeNode;
+use common_base::base::convert_byte_size;
 use common_catalog::plan::PartStatistics;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
@@ -23,6 +24,8 @@ use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::DistinctFinal;
+use super::DistinctPartial;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
@@ -31,6 +34,8 @@ use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCteScan;
+use super::RecursiveUnion;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
@@ -41,9 +46,11 @@ use crate::executor::DistributedInsertSelect;
 use crate::executor::ExchangeSink;
 use crate::executor::ExchangeSource;
 use crate::executor::FragmentKind;
+use crate::executor::MergeInto;
 use crate::executor::RangeJoin;
 use crate::executor::RangeJoinType;
 use crate::executor::RuntimeFilterSource;
+use crate::executor::SortMergeJoin;
 use crate::executor::Window;
 use crate::planner::MetadataRef;
 use crate::planner::DUMMY_TABLE_INDEX;
@@ -130,6 +137,12 @@ fn to_format_tree(
         PhysicalPlan::AggregateFinal(plan) => {
             aggregate_final_to_format_tree(plan, metadata, prof_span_set)
         }
+        PhysicalPlan::DistinctPartial(plan) => {
+            distinct_partial_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::DistinctFinal(plan) => {
+            distinct_final_to_format_tree(plan, metadata, prof_span_set)
+        }
         PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, prof_span_set),
@@ -137,6 +150,12 @@ fn to_format_tree(
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveUnion(plan) => {
+            recursive_union_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => {
+            recursive_cte_scan_to_format_tree(plan, prof_span_set)
+        }
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => {
             exchange_sink_to_format_tree(plan, metadata, prof_span_set)
@@ -144,11 +163,17 @@ fn to_format_tree(
         PhysicalPlan::DistributedInsertSelect(plan) => {
             distributed_insert_to_format_tree(plan.as_ref(), metadata, prof_span_set)
         }
+        PhysicalPlan::MergeInto(plan) => {
+            merge_into_to_format_tree(plan.as_ref(), metadata, prof_span_set)
+        }
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::SortMergeJoin(plan) => {
+            sort_merge_join_to_format_tree(plan, metadata, prof_span_set)
+        }
     }
 }
 
@@ -247,8 +272,10 @@ fn table_scan_to_format_tree(
     let output_columns = plan.source.output_schema.fields();
 
     // If output_columns contains all columns of the source,
-    // Then output_columns won't show in explain
-    if output_columns.len() < plan.source.source_info.schema().fields().len() {
+    // Then output_columns won't show in explain.
+    // The columns of an aggregating index scan are shown in the rewritten query.
+    if agg_index.is_none() && output_columns.len() < plan.source.source_info.schema().fields().len()
+    {
         children.push(FormatTreeNode::new(format!(
             "output columns: [{}]",
             output_columns.iter().map(|f| f.name()).join(", ")
@@ -283,12 +310,7 @@ fn filter_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -317,12 +339,7 @@ fn project_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -350,12 +367,7 @@ fn eval_scalar_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -402,12 +414,7 @@ fn aggregate_expand_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -448,12 +455,7 @@ fn aggregate_partial_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -490,6 +492,18 @@ fn aggregate_final_to_format_tree(
         FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
     ];
 
+    if !plan.dictionaries.is_empty() {
+        let columns = plan
+            .dictionaries
+            .iter()
+            .map(|dictionary| metadata.read().column(dictionary.column).name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.push(FormatTreeNode::new(format!(
+            "group by dictionaries: [{columns}]"
+        )));
+    }
+
     if let Some(limit) = &plan.limit {
         let items = FormatTreeNode::new(format!("limit: {limit}"));
         children.push(items);
@@ -500,12 +514,7 @@ fn aggregate_final_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -515,6 +524,72 @@ fn aggregate_final_to_format_tree(
     ))
 }
 
+fn distinct_partial_to_format_tree(
+    plan: &DistinctPartial,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let columns = plan
+        .columns
+        .iter()
+        .map(|&index| metadata.read().column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![FormatTreeNode::new(format!(
+        "distinct columns: [{columns}]"
+    ))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "DistinctPartial".to_string(),
+        children,
+    ))
+}
+
+fn distinct_final_to_format_tree(
+    plan: &DistinctFinal,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let columns = plan
+        .columns
+        .iter()
+        .map(|&index| metadata.read().column(index).name())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut children = vec![FormatTreeNode::new(format!(
+        "distinct columns: [{columns}]"
+    ))];
+
+    if let Some(limit) = &plan.limit {
+        children.push(FormatTreeNode::new(format!("limit: {limit}")));
+    }
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
+
+    children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
+
+    Ok(FormatTreeNode::with_children(
+        "DistinctFinal".to_string(),
+        children,
+    ))
+}
+
 fn window_to_format_tree(
     plan: &Window,
     metadata: &MetadataRef,
@@ -554,12 +629,7 @@ fn window_to_format_tree(
         FormatTreeNode::new(format!("frame: [{frame}]")),
     ];
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -600,12 +670,7 @@ fn sort_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -631,12 +696,7 @@ fn limit_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -662,12 +722,7 @@ fn row_fetch_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(to_format_tree(&plan.input, metadata, prof_span_set)?);
 
@@ -722,12 +777,7 @@ fn range_join_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(left_child);
     children.push(right_child);
@@ -741,6 +791,59 @@ fn range_join_to_format_tree(
     ))
 }
 
+fn sort_merge_join_to_format_tree(
+    plan: &SortMergeJoin,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let left_keys = plan
+        .left_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let right_keys = plan
+        .right_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let filters = plan
+        .non_equi_conditions
+        .iter()
+        .map(|filter| filter.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut left_child = to_format_tree(&plan.left, metadata, prof_span_set)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, prof_span_set)?;
+
+    left_child.payload = format!("{}(Left)", left_child.payload);
+    right_child.payload = format!("{}(Right)", right_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("left keys: [{left_keys}]")),
+        FormatTreeNode::new(format!("right keys: [{right_keys}]")),
+        FormatTreeNode::new(format!("filters: [{filters}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
+
+    children.push(left_child);
+    children.push(right_child);
+
+    Ok(FormatTreeNode::with_children(
+        "SortMergeJoin".to_string(),
+        children,
+    ))
+}
+
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &MetadataRef,
@@ -783,12 +886,7 @@ fn hash_join_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.push(build_child);
     children.push(probe_child);
@@ -817,6 +915,14 @@ fn exchange_to_format_tree(
             ),
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
+            FragmentKind::Adaptive(_) => format!(
+                "Adaptive(Hash({}))",
+                plan.keys
+                    .iter()
+                    .map(|key| { key.as_expr(&BUILTIN_FUNCTIONS).sql_display() })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         })),
         to_format_tree(&plan.input, metadata, prof_span_set)?,
     ]))
@@ -834,12 +940,7 @@ fn union_all_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.extend(vec![
         to_format_tree(&plan.left, metadata, prof_span_set)?,
@@ -852,6 +953,53 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_union_to_format_tree(
+    plan: &RecursiveUnion,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("cte: {}", plan.cte_name)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveUnion".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte: {}", plan.cte_name))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -882,6 +1030,50 @@ fn plan_stats_info_to_format_tree(info: &PlanStatsInfo) -> Vec<FormatTreeNode<St
     ))]
 }
 
+fn prof_span_to_format_tree(
+    prof_span_set: &ProfSpanSetRef,
+    plan_id: u32,
+) -> Vec<FormatTreeNode<String>> {
+    let prof_span_set = prof_span_set.lock().unwrap();
+    let Some(prof_span) = prof_span_set.get(&plan_id) else {
+        return vec![];
+    };
+
+    // Times are displayed in milliseconds.
+    vec![
+        FormatTreeNode::new(format!("output rows: {}", prof_span.output_rows)),
+        FormatTreeNode::new(format!(
+            "total process time: {}ms",
+            prof_span.process_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!("cpu time: {}ms", prof_span.cpu_time / 1000 / 1000)),
+        FormatTreeNode::new(format!(
+            "wall time: {}ms",
+            prof_span.wall_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "memory peak: {}",
+            convert_byte_size(prof_span.memory_peak as f64)
+        )),
+        FormatTreeNode::new(format!(
+            "spilled bytes: {}",
+            convert_byte_size(prof_span.spill_bytes as f64)
+        )),
+        FormatTreeNode::new(format!(
+            "io wait time: {}ms",
+            prof_span.io_wait_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "exchange wait time: {}ms",
+            prof_span.exchange_wait_time / 1000 / 1000
+        )),
+        FormatTreeNode::new(format!(
+            "lock wait time: {}ms",
+            prof_span.lock_wait_time / 1000 / 1000
+        )),
+    ]
+}
+
 fn exchange_source_to_format_tree(plan: &ExchangeSource) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
@@ -929,6 +1121,19 @@ fn distributed_insert_to_format_tree(
     ))
 }
 
+fn merge_into_to_format_tree(
+    plan: &MergeInto,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![to_format_tree(&plan.input, metadata, prof_span_set)?];
+
+    Ok(FormatTreeNode::with_children(
+        "MergeInto".to_string(),
+        children,
+    ))
+}
+
 fn project_set_to_format_tree(
     plan: &ProjectSet,
     metadata: &MetadataRef,
@@ -941,12 +1146,7 @@ fn project_set_to_format_tree(
         children.extend(items);
     }
 
-    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
-        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
-        children.push(FormatTreeNode::new(format!(
-            "total process time: {process_time}ms"
-        )));
-    }
+    children.extend(prof_span_to_format_tree(prof_span_set, plan.plan_id));
 
     children.extend(vec![FormatTreeNode::new(format!(
         "set returning functions: {}",
@@ -25,6 +25,7 @@ mod util;
 pub use physical_join::hash_join;
 pub use physical_join::physical_join;
 pub use physical_join::range_join;
+pub use physical_join::sort_merge_join;
 pub use physical_join::PhysicalJoinType;
 pub use physical_plan::Exchange;
 pub use physical_plan::*;
@@ -16,19 +16,28 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_cast;
 use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Expr;
+use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::Exchange;
+use crate::executor::FragmentKind;
 use crate::executor::HashJoin;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::ScalarExpr;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
 use crate::TypeCheck;
 
 impl PhysicalPlanBuilder {
@@ -123,12 +132,18 @@ impl PhysicalPlanBuilder {
         };
 
         assert_eq!(join.left_conditions.len(), join.right_conditions.len());
+        let enable_runtime_filter_pushdown = self
+            .ctx
+            .get_settings()
+            .get_enable_runtime_filter_pushdown()?;
         let mut left_join_conditions = Vec::new();
         let mut right_join_conditions = Vec::new();
-        for (left_condition, right_condition) in join
+        let mut runtime_filter_keys = Vec::new();
+        for (idx, (left_condition, right_condition)) in join
             .left_conditions
             .iter()
             .zip(join.right_conditions.iter())
+            .enumerate()
         {
             let left_expr = left_condition
                 .resolve_and_check(probe_schema.as_ref())?
@@ -166,6 +181,14 @@ impl PhysicalPlanBuilder {
                 &BUILTIN_FUNCTIONS,
             )?;
 
+            let runtime_filter_key = match enable_runtime_filter_pushdown {
+                true => {
+                    self.runtime_filter_key(join, idx, left_condition, &probe_side, &common_ty)?
+                }
+                false => None,
+            };
+            runtime_filter_keys.push(runtime_filter_key);
+
             let (left_expr, _) =
                 ConstantFolder::fold(&left_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
             let (right_expr, _) =
@@ -184,8 +207,28 @@ impl PhysicalPlanBuilder {
                 .collect::<Vec<_>>(),
         );
 
+        let plan_id = self.next_plan_id();
+        let fallback_build_rows = self.fallback_build_rows(join, s_expr, &build_side)?;
+        if fallback_build_rows.is_some() {
+            // Exchange the probe side by the join keys, so that it can be partitioned as well
+            // once the join switches. The random exchange of the probe side is replaced.
+            let input = match *probe_side {
+                PhysicalPlan::Exchange(Exchange {
+                    input,
+                    kind: FragmentKind::Init,
+                    ..
+                }) => input,
+                probe_side => Box::new(probe_side),
+            };
+            probe_side = Box::new(PhysicalPlan::Exchange(Exchange {
+                input,
+                kind: FragmentKind::Adaptive(plan_id),
+                keys: left_join_conditions.clone(),
+            }));
+        }
+
         Ok(PhysicalPlan::HashJoin(HashJoin {
-            plan_id: self.next_plan_id(),
+            plan_id,
             build: build_side,
             probe: probe_side,
             join_type: join.join_type.clone(),
@@ -204,11 +247,126 @@ impl PhysicalPlanBuilder {
                     Ok(expr.as_remote_expr())
                 })
                 .collect::<Result<_>>()?,
+            is_null_equal: join.is_null_equal.clone(),
             marker_index: join.marker_index,
             from_correlated_subquery: join.from_correlated_subquery,
 
             contain_runtime_filter: join.contain_runtime_filter,
+            runtime_filter_keys,
+            fallback_build_rows,
             stat_info: Some(stat_info),
         }))
     }
+
+    /// The number of build rows above which a broadcast join switches to a partitioned join
+    /// at runtime, `None` if the join can't switch.
+    fn fallback_build_rows(
+        &self,
+        join: &Join,
+        s_expr: &SExpr,
+        build_side: &PhysicalPlan,
+    ) -> Result<Option<u64>> {
+        let ratio = self
+            .ctx
+            .get_settings()
+            .get_broadcast_join_fallback_ratio()?;
+        if ratio == 0 || join.left_conditions.is_empty() {
+            return Ok(None);
+        }
+        // Only the joins needing no build rows but the matched ones. A partitioned node holds the
+        // build rows of its keys only, and the mark joins need all of them for the NULL markers.
+        if !matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::LeftSemi | JoinType::LeftAnti
+        ) {
+            return Ok(None);
+        }
+        if !matches!(
+            build_side,
+            PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Expansive,
+                ..
+            })
+        ) {
+            return Ok(None);
+        }
+
+        let estimated_rows = RelExpr::with_s_expr(s_expr)
+            .derive_cardinality_child(1)?
+            .cardinality;
+        Ok(Some(
+            (estimated_rows.ceil() as u64).max(1).saturating_mul(ratio),
+        ))
+    }
+
+    /// Push the probe key down to the table scan of the probe side, if it is a column of
+    /// the scan read through filters and projections only. Rows passing through the other
+    /// operators, such as limits and windows, can't be pruned before them.
+    fn runtime_filter_key(
+        &self,
+        join: &Join,
+        idx: usize,
+        probe_condition: &ScalarExpr,
+        probe_side: &PhysicalPlan,
+        common_ty: &DataType,
+    ) -> Result<Option<(IndexType, RemoteExpr<String>)>> {
+        // Only the joins dropping the probe rows without a match.
+        if !matches!(
+            join.join_type,
+            JoinType::Inner
+                | JoinType::LeftSemi
+                | JoinType::Right
+                | JoinType::RightSemi
+                | JoinType::RightAnti
+        ) || join.is_null_equal.contains(&idx)
+        {
+            return Ok(None);
+        }
+        // Only the types with the min/max statistics.
+        if !matches!(
+            common_ty.remove_nullable(),
+            DataType::Number(_)
+                | DataType::Decimal(_)
+                | DataType::String
+                | DataType::Date
+                | DataType::Timestamp
+        ) {
+            return Ok(None);
+        }
+
+        let column = match probe_condition {
+            ScalarExpr::BoundColumnRef(column_ref) => &column_ref.column,
+            _ => return Ok(None),
+        };
+        let (table_index, column_name) = match self.metadata.read().column(column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) => (*table_index, column_name.clone()),
+            _ => return Ok(None),
+        };
+        if !scans_table(probe_side, table_index) {
+            return Ok(None);
+        }
+
+        let column_ref = Expr::ColumnRef {
+            span: None,
+            id: column_name.clone(),
+            data_type: *column.data_type.clone(),
+            display_name: column_name,
+        };
+        let key = check_cast(None, false, column_ref, common_ty, &BUILTIN_FUNCTIONS)?;
+        Ok(Some((table_index, key.as_remote_expr())))
+    }
+}
+
+fn scans_table(plan: &PhysicalPlan, table_index: IndexType) -> bool {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan.table_index == table_index,
+        PhysicalPlan::Filter(filter) => scans_table(&filter.input, table_index),
+        PhysicalPlan::EvalScalar(eval_scalar) => scans_table(&eval_scalar.input, table_index),
+        _ => false,
+    }
 }
@@ -24,17 +24,30 @@ use crate::ScalarExpr;
 
 pub mod hash_join;
 pub mod range_join;
+pub mod sort_merge_join;
 
 pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    // The positions of the equi conditions, in the order both inputs are sorted by
+    SortMerge(Vec<usize>),
 }
 
 // Choose physical join type by join conditions
-pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+pub fn physical_join(
+    join: &Join,
+    s_expr: &SExpr,
+    enable_sort_merge_join: bool,
+) -> Result<PhysicalJoinType> {
     if !join.left_conditions.is_empty() {
-        // Contain equi condition, use hash join
+        // Contain equi condition, use sort merge join if both inputs are sorted on the keys,
+        // otherwise use hash join
+        if enable_sort_merge_join {
+            if let Some(key_order) = sort_merge_join::sorted_key_order(join, s_expr)? {
+                return Ok(PhysicalJoinType::SortMerge(key_order));
+            }
+        }
         return Ok(PhysicalJoinType::Hash);
     }
 
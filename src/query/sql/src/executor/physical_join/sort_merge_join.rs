@@ -0,0 +1,222 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::executor::SortMergeJoin;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::SortItem;
+use crate::IndexType;
+use crate::TypeCheck;
+
+/// Returns the positions of the equi conditions in the order both inputs of the join are
+/// sorted by, `None` if the inputs are not both sorted ascending with NULLs last on all the
+/// join keys.
+///
+/// Only the orders from the sorts below the join are used. The cluster keys of a table
+/// only order the rows inside each block, so a scan doesn't output the rows in order.
+pub fn sorted_key_order(join: &Join, s_expr: &SExpr) -> Result<Option<Vec<usize>>> {
+    if !matches!(
+        join.join_type,
+        JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+    ) || join.has_null_equal_conditions()
+        || join.marker_index.is_some()
+        || join.from_correlated_subquery
+        || join.contain_runtime_filter
+    {
+        return Ok(None);
+    }
+
+    let (left_keys, right_keys) = match (
+        column_indexes(&join.left_conditions),
+        column_indexes(&join.right_conditions),
+    ) {
+        (Some(left_keys), Some(right_keys)) => (left_keys, right_keys),
+        _ => return Ok(None),
+    };
+    let (left_items, right_items) =
+        match (sort_items(s_expr.child(0)?), sort_items(s_expr.child(1)?)) {
+            (Some(left_items), Some(right_items)) => (left_items, right_items),
+            _ => return Ok(None),
+        };
+    if left_items.len() < left_keys.len() || right_items.len() < right_keys.len() {
+        return Ok(None);
+    }
+    for (left, right) in join
+        .left_conditions
+        .iter()
+        .zip(join.right_conditions.iter())
+    {
+        let left_type = left.data_type()?.remove_nullable();
+        if left_type != right.data_type()?.remove_nullable() || !is_mergeable_type(&left_type) {
+            return Ok(None);
+        }
+    }
+
+    let mut key_order = Vec::with_capacity(left_keys.len());
+    for (left_item, right_item) in left_items.iter().zip(right_items.iter()) {
+        if key_order.len() == left_keys.len() {
+            break;
+        }
+        if !left_item.asc || !right_item.asc || left_item.nulls_first || right_item.nulls_first {
+            return Ok(None);
+        }
+        let position = left_keys
+            .iter()
+            .zip(right_keys.iter())
+            .position(|(left, right)| *left == left_item.index && *right == right_item.index);
+        match position {
+            Some(position) if !key_order.contains(&position) => key_order.push(position),
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(key_order))
+}
+
+fn column_indexes(conditions: &[ScalarExpr]) -> Option<Vec<IndexType>> {
+    conditions
+        .iter()
+        .map(|condition| match condition {
+            ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The types whose sort order is the order the join compares the keys in.
+fn is_mergeable_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Boolean
+            | DataType::String
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::Timestamp
+            | DataType::Date
+    )
+}
+
+/// The order of the rows output by `s_expr`, looking through the operators keeping it.
+fn sort_items(s_expr: &SExpr) -> Option<Vec<SortItem>> {
+    match s_expr.plan() {
+        RelOperator::Sort(sort) => Some(sort.items.clone()),
+        RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Limit(_) => {
+            sort_items(s_expr.child(0).ok()?)
+        }
+        _ => None,
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_sort_merge_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        key_order: Vec<usize>,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let left_side = Box::new(self.build(s_expr.child(0)?).await?);
+        let right_side = Box::new(self.build(s_expr.child(1)?).await?);
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut left_keys = Vec::with_capacity(key_order.len());
+        let mut right_keys = Vec::with_capacity(key_order.len());
+        for idx in key_order {
+            let left_expr = join.left_conditions[idx]
+                .resolve_and_check(left_schema.as_ref())?
+                .project_column_ref(|index| left_schema.index_of(&index.to_string()).unwrap());
+            let right_expr = join.right_conditions[idx]
+                .resolve_and_check(right_schema.as_ref())?
+                .project_column_ref(|index| right_schema.index_of(&index.to_string()).unwrap());
+
+            // The keys only differ in nullability, so the casts keep the inputs sorted.
+            let left_type = left_expr.data_type();
+            let right_type = right_expr.data_type();
+            let common_ty = common_super_type(
+                left_type.clone(),
+                right_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::IllegalDataType(format!(
+                    "Cannot find common type for {:?} and {:?}",
+                    left_type, right_type
+                ))
+            })?;
+            let left_expr = check_cast(
+                left_expr.span(),
+                false,
+                left_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let right_expr = check_cast(
+                right_expr.span(),
+                false,
+                right_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+
+            let (left_expr, _) =
+                ConstantFolder::fold(&left_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let (right_expr, _) =
+                ConstantFolder::fold(&right_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            left_keys.push(left_expr.as_remote_expr());
+            right_keys.push(right_expr.as_remote_expr());
+        }
+
+        let mut plan = SortMergeJoin {
+            plan_id: self.next_plan_id(),
+            left: left_side,
+            right: right_side,
+            left_keys,
+            right_keys,
+            non_equi_conditions: vec![],
+            join_type: join.join_type.clone(),
+            stat_info: Some(stat_info),
+        };
+
+        let merged_schema = plan.output_schema()?;
+        plan.non_equi_conditions = join
+            .non_equi_conditions
+            .iter()
+            .map(|scalar| {
+                let expr = scalar
+                    .resolve_and_check(merged_schema.as_ref())?
+                    .project_column_ref(|index| {
+                        merged_schema.index_of(&index.to_string()).unwrap()
+                    });
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Ok(expr.as_remote_expr())
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(PhysicalPlan::SortMergeJoin(plan))
+    }
+}
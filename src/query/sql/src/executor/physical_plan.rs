@@ -224,6 +224,9 @@ pub struct AggregatePartial {
     pub input: Box<PhysicalPlan>,
     pub group_by: Vec<IndexType>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
+    /// The input is the data of an aggregating index,
+    /// the arguments of the aggregate functions are the serialized states to merge.
+    pub from_agg_index: bool,
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -268,6 +271,9 @@ pub struct AggregateFinal {
     pub group_by: Vec<IndexType>,
     pub agg_funcs: Vec<AggregateFunctionDesc>,
     pub before_group_by_schema: DataSchemaRef,
+    /// The group by columns grouped by their codes in the dictionaries,
+    /// see [`GroupByDictionary`].
+    pub dictionaries: Vec<GroupByDictionary>,
 
     pub limit: Option<usize>,
     /// Only used for explain
@@ -293,6 +299,64 @@ impl AggregateFinal {
     }
 }
 
+/// Removes the duplicated rows of the distinct columns in each pipeline before
+/// they are repartitioned by hash, only the rows seen recently are remembered.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DistinctPartial {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub columns: Vec<IndexType>,
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl DistinctPartial {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let fields = self
+            .columns
+            .iter()
+            .map(|index| Ok(input_schema.field_with_name(&index.to_string())?.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+/// Repartitions the rows by the hash of the distinct columns, and removes the
+/// duplicated rows of each partition while streaming, spilling if the memory is exceeded.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DistinctFinal {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+    pub columns: Vec<IndexType>,
+    pub limit: Option<usize>,
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl DistinctFinal {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        self.input.output_schema()
+    }
+}
+
+/// The dictionary of a low-cardinality string column kept by the table.
+///
+/// The values of the column are replaced by their codes in the dictionary before
+/// the aggregation, and the codes are decoded back to the values after it.
+/// The values missing in the dictionary are added to it while the query runs.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GroupByDictionary {
+    pub column: IndexType,
+    pub values: Vec<Vec<u8>>,
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub enum WindowFunction {
     Aggregate(AggregateFunctionDesc),
@@ -441,12 +505,22 @@ pub struct HashJoin {
     pub build_keys: Vec<RemoteExpr>,
     pub probe_keys: Vec<RemoteExpr>,
     pub non_equi_conditions: Vec<RemoteExpr>,
+    /// Indexes of the keys on which NULL matches NULL.
+    pub is_null_equal: Vec<usize>,
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
     pub from_correlated_subquery: bool,
 
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
+    /// The probe keys pushed down to the table scans of the probe side, as
+    /// `(table_index, key over the table columns)`, to prune the blocks of the scans
+    /// with the range of the build keys. `None` for the keys not pushed down.
+    pub runtime_filter_keys: Vec<Option<(IndexType, RemoteExpr<String>)>>,
+    /// Set if the join is a broadcast join switching to a partitioned join at runtime, once
+    /// its build side has more rows than it. The probe side is exchanged with
+    /// `FragmentKind::Adaptive` then.
+    pub fallback_build_rows: Option<u64>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -567,6 +641,51 @@ impl RangeJoin {
     }
 }
 
+/// Join of two inputs which are both sorted ascending on the join keys. The inputs are
+/// merged as streams, only the rows of the current key are buffered.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeJoin {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    /// The join keys, in the order the inputs are sorted by.
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    /// Evaluated on the output schema, i.e. the left fields followed by the right ones.
+    pub non_equi_conditions: Vec<RemoteExpr>,
+    /// One of inner, left, right and full join.
+    pub join_type: JoinType,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl SortMergeJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let wrap = |schema: DataSchemaRef, nullable: bool| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| match nullable {
+                    true => DataField::new(field.name(), field.data_type().wrap_nullable()),
+                    false => field.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut fields = wrap(
+            self.left.output_schema()?,
+            matches!(self.join_type, JoinType::Right | JoinType::Full),
+        );
+        fields.extend(wrap(
+            self.right.output_schema()?,
+            matches!(self.join_type, JoinType::Left | JoinType::Full),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Exchange {
     pub input: Box<PhysicalPlan>,
@@ -605,6 +724,9 @@ pub enum FragmentKind {
     // Broadcast
     Expansive,
     Merge,
+    // Kept on the local node, or partitioned by hash once the broadcast join
+    // of the plan id switches to a partitioned join at runtime
+    Adaptive(u32),
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -649,6 +771,52 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveUnion {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    /// Id of the working table read by the `RecursiveCteScan`s in `recursive`.
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    pub anchor: Box<PhysicalPlan>,
+    pub recursive: Box<PhysicalPlan>,
+    pub pairs: Vec<(String, String)>,
+    /// Discard the rows that have been produced before.
+    pub distinct: bool,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveUnion {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -666,6 +834,27 @@ impl DistributedInsertSelect {
     }
 }
 
+/// Applies the rows of the join of MERGE INTO to the target table.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MergeInto {
+    pub input: Box<PhysicalPlan>,
+    pub catalog: String,
+    pub table_info: TableInfo,
+    /// Offset of `_row_id` of the target table in the input, it's NULL if the source row is not matched.
+    pub row_id_offset: usize,
+    /// The condition and the new values of the target columns of `WHEN MATCHED` clauses,
+    /// no values means `DELETE`.
+    pub matched: Vec<(Option<RemoteExpr>, Option<Vec<RemoteExpr>>)>,
+    /// The condition and the values of the target columns of `WHEN NOT MATCHED` clauses.
+    pub unmatched: Vec<(Option<RemoteExpr>, Vec<RemoteExpr>)>,
+}
+
+impl MergeInto {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(DataSchemaRef::default())
+    }
+}
+
 // Build runtime predicate data from join build side
 // Then pass it to runtime filter on join probe side
 // It's the children of join node
@@ -697,19 +886,27 @@ pub enum PhysicalPlan {
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
+    DistinctPartial(DistinctPartial),
+    DistinctFinal(DistinctFinal),
     Window(Window),
     Sort(Sort),
     Limit(Limit),
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
+    SortMergeJoin(SortMergeJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    RecursiveUnion(RecursiveUnion),
+    RecursiveCteScan(RecursiveCteScan),
     RuntimeFilterSource(RuntimeFilterSource),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
 
+    /// For merge into
+    MergeInto(Box<MergeInto>),
+
     /// Synthesized by fragmenter
     ExchangeSource(ExchangeSource),
     ExchangeSink(ExchangeSink),
@@ -733,6 +930,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
+            PhysicalPlan::DistinctPartial(plan) => plan.output_schema(),
+            PhysicalPlan::DistinctFinal(plan) => plan.output_schema(),
             PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
@@ -742,10 +941,14 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveUnion(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
+            PhysicalPlan::MergeInto(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
+            PhysicalPlan::SortMergeJoin(plan) => plan.output_schema(),
         }
     }
 
@@ -758,6 +961,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(_) => "AggregateExpand".to_string(),
             PhysicalPlan::AggregatePartial(_) => "AggregatePartial".to_string(),
             PhysicalPlan::AggregateFinal(_) => "AggregateFinal".to_string(),
+            PhysicalPlan::DistinctPartial(_) => "DistinctPartial".to_string(),
+            PhysicalPlan::DistinctFinal(_) => "DistinctFinal".to_string(),
             PhysicalPlan::Window(_) => "Window".to_string(),
             PhysicalPlan::Sort(_) => "Sort".to_string(),
             PhysicalPlan::Limit(_) => "Limit".to_string(),
@@ -765,12 +970,16 @@ impl PhysicalPlan {
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
+            PhysicalPlan::RecursiveUnion(_) => "RecursiveUnion".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
+            PhysicalPlan::MergeInto(_) => "MergeInto".to_string(),
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
             PhysicalPlan::RangeJoin(_) => "RangeJoin".to_string(),
+            PhysicalPlan::SortMergeJoin(_) => "SortMergeJoin".to_string(),
         }
     }
 
@@ -783,6 +992,8 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::DistinctPartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::DistinctFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -796,9 +1007,15 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveUnion(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
             PhysicalPlan::DistributedInsertSelect(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
+            PhysicalPlan::MergeInto(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RuntimeFilterSource(plan) => Box::new(
                 std::iter::once(plan.left_side.as_ref())
@@ -807,6 +1024,9 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::SortMergeJoin(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
         }
     }
 
@@ -823,16 +1043,22 @@ impl PhysicalPlan {
             PhysicalPlan::Exchange(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::ExchangeSink(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::MergeInto(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::ProjectSet(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::RowFetch(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::RuntimeFilterSource(_)
             | PhysicalPlan::UnionAll(_)
+            | PhysicalPlan::RecursiveUnion(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
+            | PhysicalPlan::SortMergeJoin(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
-            | PhysicalPlan::AggregatePartial(_) => None,
+            | PhysicalPlan::AggregatePartial(_)
+            | PhysicalPlan::DistinctPartial(_)
+            | PhysicalPlan::DistinctFinal(_) => None,
         }
     }
 }
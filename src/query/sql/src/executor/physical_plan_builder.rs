@@ -27,6 +27,7 @@ use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::infer_table_schema;
 use common_expression::type_check;
 use common_expression::type_check::check_function;
 use common_expression::type_check::common_super_type;
@@ -35,6 +36,7 @@ use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FunctionContext;
 use common_expression::RawExpr;
@@ -50,9 +52,12 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::DistinctFinal;
+use super::DistinctPartial;
 use super::EvalScalar;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
+use super::GroupByDictionary;
 use super::Limit;
 use super::NthValueFunctionDesc;
 use super::ProjectSet;
@@ -70,6 +75,8 @@ use crate::executor::LagLeadDefault;
 use crate::executor::LagLeadFunctionDesc;
 use crate::executor::PhysicalJoinType;
 use crate::executor::PhysicalPlan;
+use crate::executor::RecursiveCteScan;
+use crate::executor::RecursiveUnion;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
@@ -78,6 +85,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner;
+use crate::plans::Aggregate;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
 use crate::plans::Exchange;
@@ -104,8 +112,8 @@ use crate::DUMMY_COLUMN_INDEX;
 use crate::DUMMY_TABLE_INDEX;
 
 pub struct PhysicalPlanBuilder {
-    metadata: MetadataRef,
-    ctx: Arc<dyn TableContext>,
+    pub(crate) metadata: MetadataRef,
+    pub(crate) ctx: Arc<dyn TableContext>,
     pub(crate) func_ctx: FunctionContext,
 
     next_plan_id: u32,
@@ -298,6 +306,10 @@ impl PhysicalPlanBuilder {
 
     #[async_backtrace::framed]
     async fn build_scan(&mut self, scan: &Scan, stat_info: PlanStatsInfo) -> Result<PhysicalPlan> {
+        if scan.agg_index.is_some() {
+            return self.build_agg_index_scan(scan, stat_info).await;
+        }
+
         let mut has_inner_column = false;
         let mut has_virtual_column = false;
         let mut name_mapping = BTreeMap::new();
@@ -366,7 +378,7 @@ impl PhysicalPlanBuilder {
         let push_downs =
             self.push_downs(scan, &table_schema, has_inner_column, has_virtual_column)?;
 
-        let source = table
+        let mut source = table
             .read_plan_with_catalog(
                 self.ctx.clone(),
                 table_entry.catalog().to_string(),
@@ -378,6 +390,7 @@ impl PhysicalPlanBuilder {
                 },
             )
             .await?;
+        source.table_index = Some(scan.table_index);
 
         let internal_column = if project_internal_columns.is_empty() {
             None
@@ -394,6 +407,69 @@ impl PhysicalPlanBuilder {
         }))
     }
 
+    /// Build the scan reading the data of an aggregating index.
+    ///
+    /// The scan reads the table columns used by the index definition, so that the index data
+    /// of the blocks which are not refreshed yet can be built on the fly.
+    #[async_backtrace::framed]
+    async fn build_agg_index_scan(
+        &mut self,
+        scan: &Scan,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let agg_index = scan.agg_index.as_ref().unwrap();
+        let metadata = self.metadata.read().clone();
+        let column_names = agg_index.definition.column_names();
+
+        let mut raw_scan = scan.clone();
+        raw_scan.columns = metadata
+            .columns_by_table_index(scan.table_index)
+            .iter()
+            .filter(|column| {
+                matches!(column, ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    column_name,
+                    path_indices: None,
+                    ..
+                }) if column_names.contains(column_name))
+            })
+            .map(|column| column.index())
+            .collect();
+        raw_scan.prewhere = None;
+        raw_scan.limit = None;
+        raw_scan.order_by = None;
+
+        let table_entry = metadata.table(scan.table_index);
+        let table = table_entry.table();
+        let push_downs = self.push_downs(&raw_scan, &table.schema(), false, false)?;
+        let mut source = table
+            .read_plan_with_catalog(
+                self.ctx.clone(),
+                table_entry.catalog().to_string(),
+                Some(push_downs),
+                None,
+            )
+            .await?;
+        source.table_index = Some(scan.table_index);
+
+        // The columns are output in the order of `outputs`.
+        let mut name_mapping = BTreeMap::new();
+        let mut fields = Vec::with_capacity(agg_index.outputs.len());
+        for (index, scalar) in agg_index.outputs.iter() {
+            fields.push(DataField::new(&index.to_string(), scalar.data_type()?));
+            name_mapping.insert(index.to_string(), *index);
+        }
+        source.output_schema = infer_table_schema(&DataSchemaRefExt::create(fields))?;
+
+        Ok(PhysicalPlan::TableScan(TableScan {
+            plan_id: self.next_plan_id(),
+            name_mapping,
+            source: Box::new(source),
+            table_index: scan.table_index,
+            stat_info: Some(stat_info),
+            internal_column: None,
+        }))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn build(&mut self, s_expr: &SExpr) -> Result<PhysicalPlan> {
@@ -429,12 +505,18 @@ impl PhysicalPlanBuilder {
             }
             RelOperator::Join(join) => {
                 // Choose physical join type by join conditions
-                let physical_join = physical_join(join, s_expr)?;
+                let enable_sort_merge_join =
+                    self.ctx.get_settings().get_enable_sort_merge_join()?;
+                let physical_join = physical_join(join, s_expr, enable_sort_merge_join)?;
                 match physical_join {
                     PhysicalJoinType::Hash => self.build_hash_join(join, s_expr, stat_info).await,
                     PhysicalJoinType::RangeJoin(range, other) => {
                         self.build_range_join(range, other, s_expr).await
                     }
+                    PhysicalJoinType::SortMerge(key_order) => {
+                        self.build_sort_merge_join(join, s_expr, key_order, stat_info)
+                            .await
+                    }
                 }
             }
 
@@ -475,7 +557,75 @@ impl PhysicalPlanBuilder {
                 let group_items = agg.group_items.iter().map(|v| v.index).collect::<Vec<_>>();
 
                 let result = match &agg.mode {
+                    // The rows of DISTINCT are deduplicated while streaming instead of
+                    // being aggregated into the hash tables of the groups.
+                    AggregateMode::Partial if is_distinct(agg) && !is_agg_index_scan(&input) => {
+                        let settings = self.ctx.get_settings();
+                        let group_by_shuffle_mode = settings.get_group_by_shuffle_mode()?;
+
+                        match input {
+                            PhysicalPlan::Exchange(PhysicalExchange { input, kind, .. })
+                                if group_by_shuffle_mode == "before_merge" =>
+                            {
+                                let distinct_partial = DistinctPartial {
+                                    plan_id: self.next_plan_id(),
+                                    input,
+                                    columns: group_items.clone(),
+                                    stat_info: Some(stat_info),
+                                };
+
+                                // Repartition the rows deduplicated in each pipeline by all
+                                // the distinct columns.
+                                let output_schema = distinct_partial.output_schema()?;
+                                let keys = group_items
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(id, index)| RemoteExpr::ColumnRef {
+                                        span: None,
+                                        id,
+                                        data_type: output_schema.field(id).data_type().clone(),
+                                        display_name: self.metadata.read().column(*index).name(),
+                                    })
+                                    .collect();
+
+                                PhysicalPlan::Exchange(PhysicalExchange {
+                                    kind,
+                                    input: Box::new(PhysicalPlan::DistinctPartial(
+                                        distinct_partial,
+                                    )),
+                                    keys,
+                                })
+                            }
+                            _ => PhysicalPlan::DistinctPartial(DistinctPartial {
+                                plan_id: self.next_plan_id(),
+                                input: Box::new(input),
+                                columns: group_items,
+                                stat_info: Some(stat_info),
+                            }),
+                        }
+                    }
+
+                    AggregateMode::Final
+                        if matches!(
+                            input,
+                            PhysicalPlan::DistinctPartial(_)
+                                | PhysicalPlan::Exchange(PhysicalExchange {
+                                    input: box PhysicalPlan::DistinctPartial(_),
+                                    ..
+                                })
+                        ) =>
+                    {
+                        PhysicalPlan::DistinctFinal(DistinctFinal {
+                            plan_id: self.next_plan_id(),
+                            input: Box::new(input),
+                            columns: group_items,
+                            limit: agg.limit,
+                            stat_info: Some(stat_info),
+                        })
+                    }
+
                     AggregateMode::Partial => {
+                        let from_agg_index = is_agg_index_scan(&input);
                         let agg_funcs: Vec<AggregateFunctionDesc> = agg.aggregate_functions.iter().map(|v| {
                             if let ScalarExpr::AggregateFunction(agg) = &v.scalar {
                                 Ok(AggregateFunctionDesc {
@@ -483,6 +633,10 @@ impl PhysicalPlanBuilder {
                                         name: agg.func_name.clone(),
                                         args: agg.args.iter().map(|s| {
                                             if let ScalarExpr::BoundColumnRef(col) = s {
+                                                if from_agg_index {
+                                                    // The arguments are evaluated while building the index data.
+                                                    return Ok(*col.column.data_type.clone());
+                                                }
                                                 Ok(input_schema.field_with_name(&col.column.index.to_string())?.data_type().clone())
                                             } else {
                                                 Err(ErrorCode::Internal(
@@ -493,7 +647,11 @@ impl PhysicalPlanBuilder {
                                         params: agg.params.clone(),
                                     },
                                     output_column: v.index,
-                                    args: agg.args.iter().map(|arg| {
+                                    args: if from_agg_index {
+                                        // The index data holds the states of the function as a single column.
+                                        vec![input_schema.index_of(&v.index.to_string())?]
+                                    } else {
+                                        agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             input_schema.index_of(&col.column.index.to_string())
                                         } else {
@@ -501,7 +659,8 @@ impl PhysicalPlanBuilder {
                                                 "Aggregate function argument must be a BoundColumnRef".to_string()
                                             ))
                                         }
-                                    }).collect::<Result<_>>()?,
+                                    }).collect::<Result<_>>()?
+                                    },
                                     arg_indices: agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             Ok(col.column.index)
@@ -538,6 +697,7 @@ impl PhysicalPlanBuilder {
                                         input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                         agg_funcs,
                                         group_by: group_items,
+                                        from_agg_index,
                                         stat_info: Some(stat_info),
                                     }
                                 } else {
@@ -546,6 +706,7 @@ impl PhysicalPlanBuilder {
                                         input,
                                         agg_funcs,
                                         group_by: group_items,
+                                        from_agg_index,
                                         stat_info: Some(stat_info),
                                     }
                                 };
@@ -594,6 +755,7 @@ impl PhysicalPlanBuilder {
                                         agg_funcs,
                                         group_by: group_items,
                                         input: Box::new(PhysicalPlan::AggregateExpand(expand)),
+                                        from_agg_index,
                                         stat_info: Some(stat_info),
                                     })
                                 } else {
@@ -602,6 +764,7 @@ impl PhysicalPlanBuilder {
                                         agg_funcs,
                                         group_by: group_items,
                                         input: Box::new(input),
+                                        from_agg_index,
                                         stat_info: Some(stat_info),
                                     })
                                 }
@@ -611,13 +774,15 @@ impl PhysicalPlanBuilder {
 
                     // Hack to get before group by schema, we should refactor this
                     AggregateMode::Final => {
-                        let input_schema = match input {
-                            PhysicalPlan::AggregatePartial(ref agg) => agg.input.output_schema()?,
+                        let (input_schema, from_agg_index) = match input {
+                            PhysicalPlan::AggregatePartial(ref agg) => {
+                                (agg.input.output_schema()?, agg.from_agg_index)
+                            }
 
                             PhysicalPlan::Exchange(PhysicalExchange {
                                 input: box PhysicalPlan::AggregatePartial(ref agg),
                                 ..
-                            }) => agg.input.output_schema()?,
+                            }) => (agg.input.output_schema()?, agg.from_agg_index),
 
                             _ => {
                                 return Err(ErrorCode::Internal(format!(
@@ -634,6 +799,10 @@ impl PhysicalPlanBuilder {
                                         name: agg.func_name.clone(),
                                         args: agg.args.iter().map(|s| {
                                             if let ScalarExpr::BoundColumnRef(col) = s {
+                                                if from_agg_index {
+                                                    // The arguments are evaluated while building the index data.
+                                                    return Ok(*col.column.data_type.clone());
+                                                }
                                                 Ok(input_schema.field_with_name(&col.column.index.to_string())?.data_type().clone())
                                             } else {
                                                 Err(ErrorCode::Internal(
@@ -644,7 +813,11 @@ impl PhysicalPlanBuilder {
                                         params: agg.params.clone(),
                                     },
                                     output_column: v.index,
-                                    args: agg.args.iter().map(|arg| {
+                                    args: if from_agg_index {
+                                        // The index data holds the states of the function as a single column.
+                                        vec![input_schema.index_of(&v.index.to_string())?]
+                                    } else {
+                                        agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             input_schema.index_of(&col.column.index.to_string())
                                         } else {
@@ -652,7 +825,8 @@ impl PhysicalPlanBuilder {
                                                 "Aggregate function argument must be a BoundColumnRef".to_string()
                                             ))
                                         }
-                                    }).collect::<Result<_>>()?,
+                                    }).collect::<Result<_>>()?
+                                    },
                                     arg_indices: agg.args.iter().map(|arg| {
                                         if let ScalarExpr::BoundColumnRef(col) = arg {
                                             Ok(col.column.index)
@@ -672,12 +846,26 @@ impl PhysicalPlanBuilder {
                             PhysicalPlan::AggregatePartial(ref partial) => {
                                 let before_group_by_schema = partial.input.output_schema()?;
                                 let limit = agg.limit;
+                                // The dictionaries are filled while the query runs, they are
+                                // only used if both of the phases run in the same process.
+                                let dictionaries =
+                                    if agg.grouping_sets.is_empty() && !partial.from_agg_index {
+                                        self.build_group_by_dictionaries(
+                                            &group_items,
+                                            &agg_funcs,
+                                            &before_group_by_schema,
+                                        )
+                                        .await?
+                                    } else {
+                                        vec![]
+                                    };
                                 PhysicalPlan::AggregateFinal(AggregateFinal {
                                     plan_id: self.next_plan_id(),
                                     input: Box::new(input),
                                     group_by: group_items,
                                     agg_funcs,
                                     before_group_by_schema,
+                                    dictionaries,
 
                                     stat_info: Some(stat_info),
                                     limit,
@@ -697,6 +885,7 @@ impl PhysicalPlanBuilder {
                                     group_by: group_items,
                                     agg_funcs,
                                     before_group_by_schema,
+                                    dictionaries: vec![],
 
                                     stat_info: Some(stat_info),
                                     limit,
@@ -806,60 +995,6 @@ impl PhysicalPlanBuilder {
                     })
                 }).collect::<Result<Vec<_>>>()?;
 
-                async fn cast_plan(
-                    plan_builder: &mut PhysicalPlanBuilder,
-                    plan: PhysicalPlan,
-                    plan_schema: &DataSchema,
-                    indexes: &[IndexType],
-                    common_types: &[DataType],
-                    stat_info: PlanStatsInfo,
-                ) -> Result<PhysicalPlan> {
-                    debug_assert!(indexes.len() == common_types.len());
-                    let scalar_items = indexes
-                        .iter()
-                        .map(|index| plan_schema.field_with_name(&index.to_string()).unwrap())
-                        .zip(common_types)
-                        .filter(|(f, common_ty)| f.data_type() != *common_ty)
-                        .map(|(f, common_ty)| {
-                            let cast_expr = wrap_cast(
-                                &ScalarExpr::BoundColumnRef(BoundColumnRef {
-                                    span: None,
-                                    column: ColumnBinding {
-                                        database_name: None,
-                                        table_name: None,
-                                        column_position: None,
-                                        table_index: None,
-                                        column_name: f.name().clone(),
-                                        index: f.name().parse().unwrap(),
-                                        data_type: Box::new(f.data_type().clone()),
-                                        visibility: Visibility::Visible,
-                                        virtual_computed_expr: None,
-                                    },
-                                }),
-                                common_ty,
-                            );
-                            ScalarItem {
-                                scalar: cast_expr,
-                                index: f.name().parse().unwrap(),
-                            }
-                        })
-                        .collect::<Vec<_>>();
-
-                    let new_plan = if scalar_items.is_empty() {
-                        plan
-                    } else {
-                        plan_builder.build_eval_scalar(
-                            plan,
-                            &crate::plans::EvalScalar {
-                                items: scalar_items,
-                            },
-                            stat_info,
-                        )?
-                    };
-
-                    Ok(new_plan)
-                }
-
                 let left_indexes = op.pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
                 let right_indexes = op.pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
                 let left_plan = cast_plan(
@@ -903,6 +1038,81 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::RecursiveUnion(op) => {
+                let anchor_plan = self.build(s_expr.child(0)?).await?;
+                let recursive_plan = self.build(s_expr.child(1)?).await?;
+                let anchor_schema = anchor_plan.output_schema()?;
+                let recursive_schema = recursive_plan.output_schema()?;
+
+                // The working table has the types of the anchor member, so the output of
+                // the recursive member must be cast to them.
+                let anchor_indexes = op.pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
+                let recursive_indexes = op.pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
+                let anchor_types = anchor_indexes
+                    .iter()
+                    .map(|index| {
+                        Ok(anchor_schema
+                            .field_with_name(&index.to_string())?
+                            .data_type()
+                            .clone())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let recursive_plan = cast_plan(
+                    self,
+                    recursive_plan,
+                    recursive_schema.as_ref(),
+                    &recursive_indexes,
+                    &anchor_types,
+                    stat_info.clone(),
+                )
+                .await?;
+
+                let pairs = op
+                    .pairs
+                    .iter()
+                    .map(|(l, r)| (l.to_string(), r.to_string()))
+                    .collect::<Vec<_>>();
+                let fields = anchor_indexes
+                    .iter()
+                    .zip(&anchor_types)
+                    .map(|(index, ty)| DataField::new(&index.to_string(), ty.clone()))
+                    .collect::<Vec<_>>();
+
+                Ok(PhysicalPlan::RecursiveUnion(RecursiveUnion {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: op.cte_idx,
+                    cte_name: op.cte_name.clone(),
+                    anchor: Box::new(anchor_plan),
+                    recursive: Box::new(recursive_plan),
+                    pairs,
+                    distinct: op.distinct,
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(scan) => {
+                let fields = {
+                    let metadata = self.metadata.read();
+                    scan.columns
+                        .iter()
+                        .map(|index| {
+                            DataField::new(&index.to_string(), metadata.column(*index).data_type())
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_idx: scan.cte_idx,
+                    cte_name: scan.cte_name.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::RuntimeFilterSource(op) => {
                 let left_side = Box::new(self.build(s_expr.child(0)?).await?);
                 let left_schema = left_side.output_schema()?;
@@ -996,6 +1206,55 @@ impl PhysicalPlanBuilder {
         }
     }
 
+    /// Get the dictionaries kept by the tables of the group by columns,
+    /// which are the string columns of the tables not used by the aggregate functions.
+    #[async_backtrace::framed]
+    async fn build_group_by_dictionaries(
+        &self,
+        group_by: &[IndexType],
+        agg_funcs: &[AggregateFunctionDesc],
+        input_schema: &DataSchemaRef,
+    ) -> Result<Vec<GroupByDictionary>> {
+        let columns = {
+            let metadata = self.metadata.read();
+            group_by
+                .iter()
+                .filter(|index| agg_funcs.iter().all(|agg| !agg.arg_indices.contains(index)))
+                .filter_map(|index| match metadata.column(*index) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn {
+                        table_index,
+                        column_name,
+                        path_indices: None,
+                        virtual_computed_expr: None,
+                        ..
+                    }) => Some((
+                        *index,
+                        metadata.table(*table_index).table(),
+                        column_name.clone(),
+                    )),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut dictionaries = vec![];
+        for (index, table, column_name) in columns {
+            let data_type = input_schema
+                .field_with_name(&index.to_string())?
+                .data_type();
+            if data_type.remove_nullable() != DataType::String {
+                continue;
+            }
+            if let Some(values) = table.column_dictionary(&column_name).await? {
+                dictionaries.push(GroupByDictionary {
+                    column: index,
+                    values,
+                });
+            }
+        }
+        Ok(dictionaries)
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn build_physical_window(
@@ -1461,9 +1720,9 @@ impl PhysicalPlanBuilder {
                     })
                     .transpose()?;
                 let selection = agg
-                    .selection
+                    .outputs
                     .iter()
-                    .map(|sel| {
+                    .map(|(_, sel)| {
                         Ok(sel
                             .as_expr()?
                             .project_column_ref(|col| col.index)
@@ -1474,6 +1733,7 @@ impl PhysicalPlanBuilder {
                     index_id: agg.index_id,
                     filter,
                     selection,
+                    definition: agg.definition.clone(),
                 })
             })
             .transpose()?;
@@ -1509,3 +1769,80 @@ pub struct RangeJoinCondition {
     // "gt" | "lt" | "gte" | "lte"
     pub operator: String,
 }
+
+/// Cast the given output columns of a plan to the target types.
+async fn cast_plan(
+    plan_builder: &mut PhysicalPlanBuilder,
+    plan: PhysicalPlan,
+    plan_schema: &DataSchema,
+    indexes: &[IndexType],
+    common_types: &[DataType],
+    stat_info: PlanStatsInfo,
+) -> Result<PhysicalPlan> {
+    debug_assert!(indexes.len() == common_types.len());
+    let scalar_items = indexes
+        .iter()
+        .map(|index| plan_schema.field_with_name(&index.to_string()).unwrap())
+        .zip(common_types)
+        .filter(|(f, common_ty)| f.data_type() != *common_ty)
+        .map(|(f, common_ty)| {
+            let cast_expr = wrap_cast(
+                &ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_position: None,
+                        table_index: None,
+                        column_name: f.name().clone(),
+                        index: f.name().parse().unwrap(),
+                        data_type: Box::new(f.data_type().clone()),
+                        visibility: Visibility::Visible,
+                        virtual_computed_expr: None,
+                    },
+                }),
+                common_ty,
+            );
+            ScalarItem {
+                scalar: cast_expr,
+                index: f.name().parse().unwrap(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let new_plan = if scalar_items.is_empty() {
+        plan
+    } else {
+        plan_builder.build_eval_scalar(
+            plan,
+            &crate::plans::EvalScalar {
+                items: scalar_items,
+            },
+            stat_info,
+        )?
+    };
+
+    Ok(new_plan)
+}
+
+/// Whether the aggregate only removes the duplicated rows of the group by columns,
+/// like the one of `SELECT DISTINCT`.
+fn is_distinct(agg: &Aggregate) -> bool {
+    agg.from_distinct
+        && agg.aggregate_functions.is_empty()
+        && agg.grouping_sets.is_empty()
+        && !agg.group_items.is_empty()
+}
+
+/// Whether the plan reads the data of an aggregating index.
+fn is_agg_index_scan(plan: &PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan
+            .source
+            .push_downs
+            .as_ref()
+            .map_or(false, |push_downs| push_downs.agg_index.is_some()),
+        PhysicalPlan::Exchange(PhysicalExchange { input, .. }) => is_agg_index_scan(input),
+        _ => false,
+    }
+}
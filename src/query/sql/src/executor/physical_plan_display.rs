@@ -15,15 +15,19 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::AggregateExpand;
 use super::DistributedInsertSelect;
+use super::MergeInto;
 use super::ProjectSet;
 use super::RowFetch;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
+use crate::executor::DistinctFinal;
+use crate::executor::DistinctPartial;
 use crate::executor::EvalScalar;
 use crate::executor::Exchange;
 use crate::executor::ExchangeSink;
@@ -34,8 +38,11 @@ use crate::executor::Limit;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
 use crate::executor::RangeJoin;
+use crate::executor::RecursiveCteScan;
+use crate::executor::RecursiveUnion;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
+use crate::executor::SortMergeJoin;
 use crate::executor::TableScan;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -64,6 +71,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::AggregateExpand(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
+            PhysicalPlan::DistinctPartial(distinct) => write!(f, "{}", distinct)?,
+            PhysicalPlan::DistinctFinal(distinct) => write!(f, "{}", distinct)?,
             PhysicalPlan::Window(window) => write!(f, "{}", window)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
@@ -73,10 +82,14 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
             PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
             PhysicalPlan::UnionAll(union_all) => write!(f, "{}", union_all)?,
+            PhysicalPlan::RecursiveUnion(union) => write!(f, "{}", union)?,
+            PhysicalPlan::RecursiveCteScan(scan) => write!(f, "{}", scan)?,
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
+            PhysicalPlan::MergeInto(merge_into) => write!(f, "{}", merge_into)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::RangeJoin(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::SortMergeJoin(plan) => write!(f, "{}", plan)?,
         }
 
         for node in self.node.children() {
@@ -205,6 +218,20 @@ impl Display for AggregateFinal {
     }
 }
 
+impl Display for DistinctPartial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let columns = self.columns.iter().map(|v| v.to_string()).join(", ");
+        write!(f, "Distinct(Partial): columns: [{}]", columns)
+    }
+}
+
+impl Display for DistinctFinal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let columns = self.columns.iter().map(|v| v.to_string()).join(", ");
+        write!(f, "Distinct(Final): columns: [{}]", columns)
+    }
+}
+
 impl Display for AggregatePartial {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let group_items = self
@@ -302,6 +329,30 @@ impl Display for RangeJoin {
     }
 }
 
+impl Display for SortMergeJoin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display_keys = |keys: &[RemoteExpr]| {
+            keys.iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+                .join(", ")
+        };
+        let join_filters = self
+            .non_equi_conditions
+            .iter()
+            .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+            .join(", ");
+
+        write!(
+            f,
+            "SortMergeJoin: {}, left keys: [{}], right keys: [{}], join filters: [{}]",
+            &self.join_type,
+            display_keys(&self.left_keys),
+            display_keys(&self.right_keys),
+            join_filters,
+        )
+    }
+}
+
 impl Display for Exchange {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let keys = self
@@ -340,12 +391,30 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveUnion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveUnion: [{}]", self.cte_name)
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan: [{}]", self.cte_name)
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
     }
 }
 
+impl Display for MergeInto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MergeInto")
+    }
+}
+
 impl Display for RuntimeFilterSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "RuntimeFilterSource")
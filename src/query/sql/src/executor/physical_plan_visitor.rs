@@ -17,6 +17,8 @@ use common_exception::Result;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::DistinctFinal;
+use super::DistinctPartial;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
@@ -25,13 +27,17 @@ use super::ExchangeSource;
 use super::Filter;
 use super::HashJoin;
 use super::Limit;
+use super::MergeInto;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
 use super::RowFetch;
 use super::Sort;
+use super::SortMergeJoin;
 use super::TableScan;
 use crate::executor::RangeJoin;
+use crate::executor::RecursiveCteScan;
+use crate::executor::RecursiveUnion;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -46,6 +52,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
+            PhysicalPlan::DistinctPartial(plan) => self.replace_distinct_partial(plan),
+            PhysicalPlan::DistinctFinal(plan) => self.replace_distinct_final(plan),
             PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
@@ -55,10 +63,14 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
+            PhysicalPlan::RecursiveUnion(plan) => self.replace_recursive_union(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
+            PhysicalPlan::MergeInto(plan) => self.replace_merge_into(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
             PhysicalPlan::RangeJoin(plan) => self.replace_range_join(plan),
+            PhysicalPlan::SortMergeJoin(plan) => self.replace_sort_merge_join(plan),
         }
     }
 
@@ -121,6 +133,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             group_by: plan.group_by.clone(),
             agg_funcs: plan.agg_funcs.clone(),
+            from_agg_index: plan.from_agg_index,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -132,6 +145,7 @@ pub trait PhysicalPlanReplacer {
             plan_id: plan.plan_id,
             input: Box::new(input),
             before_group_by_schema: plan.before_group_by_schema.clone(),
+            dictionaries: plan.dictionaries.clone(),
             group_by: plan.group_by.clone(),
             agg_funcs: plan.agg_funcs.clone(),
             stat_info: plan.stat_info.clone(),
@@ -139,6 +153,29 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_distinct_partial(&mut self, plan: &DistinctPartial) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::DistinctPartial(DistinctPartial {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            columns: plan.columns.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_distinct_final(&mut self, plan: &DistinctFinal) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::DistinctFinal(DistinctFinal {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            columns: plan.columns.clone(),
+            limit: plan.limit,
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -164,10 +201,13 @@ pub trait PhysicalPlanReplacer {
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
+            is_null_equal: plan.is_null_equal.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
             from_correlated_subquery: plan.from_correlated_subquery,
             contain_runtime_filter: plan.contain_runtime_filter,
+            runtime_filter_keys: plan.runtime_filter_keys.clone(),
+            fallback_build_rows: plan.fallback_build_rows,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -188,6 +228,22 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_sort_merge_join(&mut self, plan: &SortMergeJoin) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+
+        Ok(PhysicalPlan::SortMergeJoin(SortMergeJoin {
+            plan_id: plan.plan_id,
+            left: Box::new(left),
+            right: Box::new(right),
+            left_keys: plan.left_keys.clone(),
+            right_keys: plan.right_keys.clone(),
+            non_equi_conditions: plan.non_equi_conditions.clone(),
+            join_type: plan.join_type.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_sort(&mut self, plan: &Sort) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -268,6 +324,26 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_union(&mut self, plan: &RecursiveUnion) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveUnion(RecursiveUnion {
+            plan_id: plan.plan_id,
+            cte_idx: plan.cte_idx,
+            cte_name: plan.cte_name.clone(),
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            pairs: plan.pairs.clone(),
+            distinct: plan.distinct,
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -284,6 +360,19 @@ pub trait PhysicalPlanReplacer {
         )))
     }
 
+    fn replace_merge_into(&mut self, plan: &MergeInto) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::MergeInto(Box::new(MergeInto {
+            input: Box::new(input),
+            catalog: plan.catalog.clone(),
+            table_info: plan.table_info.clone(),
+            row_id_offset: plan.row_id_offset,
+            matched: plan.matched.clone(),
+            unmatched: plan.unmatched.clone(),
+        })))
+    }
+
     fn replace_project_set(&mut self, plan: &ProjectSet) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
         Ok(PhysicalPlan::ProjectSet(ProjectSet {
@@ -339,6 +428,12 @@ impl PhysicalPlan {
                 PhysicalPlan::AggregateFinal(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::DistinctPartial(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::DistinctFinal(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Window(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -366,9 +461,17 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveUnion(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
                 PhysicalPlan::DistributedInsertSelect(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::MergeInto(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::ProjectSet(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit)
                 }
@@ -380,6 +483,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::SortMergeJoin(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
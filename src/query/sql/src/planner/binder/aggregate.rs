@@ -43,6 +43,7 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -129,6 +130,22 @@ impl<'a> AggregateRewriter<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
 
             // TODO(leiysky): should we recursively process subquery here?
             ScalarExpr::SubqueryExpr(_) => Ok(scalar.clone()),
@@ -399,21 +416,8 @@ impl Binder {
                 self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)
                     .await
             }
-            // TODO: avoid too many clones.
-            GroupBy::Rollup(exprs) => {
-                // ROLLUP (a,b,c) => GROUPING SETS ((a,b,c), (a,b), (a), ())
-                let mut sets = Vec::with_capacity(exprs.len() + 1);
-                for i in (0..=exprs.len()).rev() {
-                    sets.push(exprs[0..i].to_vec());
-                }
-                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
-                    .await
-            }
-            GroupBy::Cube(exprs) => {
-                // CUBE (a,b) => GROUPING SETS ((a,b),(a),(b),()) // All subsets
-                let sets = (0..=exprs.len())
-                    .flat_map(|count| exprs.clone().into_iter().combinations(count))
-                    .collect::<Vec<_>>();
+            GroupBy::Rollup(_) | GroupBy::Cube(_) | GroupBy::Combined(_) => {
+                let sets = expand_grouping_sets(group_by);
                 self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
                     .await
             }
@@ -754,3 +758,35 @@ impl Binder {
         }
     }
 }
+
+/// Expand a `GROUP BY` clause into the grouping sets it stands for.
+// TODO: avoid too many clones.
+fn expand_grouping_sets(group_by: &GroupBy) -> Vec<Vec<Expr>> {
+    match group_by {
+        GroupBy::Normal(exprs) => vec![exprs.clone()],
+        GroupBy::GroupingSets(sets) => sets.clone(),
+        GroupBy::Rollup(exprs) => {
+            // ROLLUP (a,b,c) => GROUPING SETS ((a,b,c), (a,b), (a), ())
+            (0..=exprs.len())
+                .rev()
+                .map(|i| exprs[0..i].to_vec())
+                .collect()
+        }
+        GroupBy::Cube(exprs) => {
+            // CUBE (a,b) => GROUPING SETS ((a,b),(a),(b),()) // All subsets
+            (0..=exprs.len())
+                .flat_map(|count| exprs.clone().into_iter().combinations(count))
+                .collect()
+        }
+        GroupBy::Combined(items) => {
+            // a, ROLLUP (b,c) => GROUPING SETS ((a,b,c), (a,b), (a)) // Cross product
+            items.iter().fold(vec![vec![]], |sets, item| {
+                let item_sets = expand_grouping_sets(item);
+                sets.iter()
+                    .cartesian_product(item_sets.iter())
+                    .map(|(set, item_set)| set.iter().chain(item_set.iter()).cloned().collect())
+                    .collect()
+            })
+        }
+    }
+}
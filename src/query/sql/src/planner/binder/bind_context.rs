@@ -37,6 +37,8 @@ use crate::binder::window::WindowInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::ScalarExpr;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -100,6 +102,15 @@ pub struct ColumnBinding {
     pub virtual_computed_expr: Option<String>,
 }
 
+/// The catalog, database, table and column a result column is read from.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ColumnOrigin {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct InternalColumnBinding {
     /// Database name of this `InternalColumnBinding` in current context
@@ -178,6 +189,17 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Declared in a `WITH RECURSIVE` clause.
+    pub recursive: bool,
+    /// Set while binding the recursive member of the CTE, where references to the
+    /// CTE itself read the rows produced by the previous iteration.
+    pub working_table: Option<WorkingTableInfo>,
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkingTableInfo {
+    pub cte_idx: IndexType,
+    pub columns: Vec<ColumnBinding>,
 }
 
 impl BindContext {
@@ -466,6 +488,38 @@ impl BindContext {
         DataSchemaRefExt::create(fields)
     }
 
+    /// Origins of the output columns, in the same order as `output_schema`.
+    /// Only the columns that are read from a base table as is have an origin,
+    /// computed or aggregated columns get `None`.
+    pub fn output_column_origins(&self, metadata: &MetadataRef) -> Vec<Option<ColumnOrigin>> {
+        let metadata = metadata.read();
+        self.columns
+            .iter()
+            .map(|column_binding| {
+                if column_binding.index >= metadata.columns().len() {
+                    return None;
+                }
+                match metadata.column(column_binding.index) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn {
+                        table_index,
+                        column_name,
+                        virtual_computed_expr: None,
+                        ..
+                    }) => {
+                        let table = metadata.table(*table_index);
+                        Some(ColumnOrigin {
+                            catalog: table.catalog().to_string(),
+                            database: table.database().to_string(),
+                            table: table.name().to_string(),
+                            column: column_name.clone(),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     fn get_internal_column_table_index(
         column_binding: &InternalColumnBinding,
         metadata: MetadataRef,
@@ -42,16 +42,21 @@ use crate::normalize_identifier;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CallPlan;
+use crate::plans::CommitPreparedPlan;
+use crate::plans::CopyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DeclareResultPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::Plan;
+use crate::plans::PrepareCommitPlan;
 use crate::plans::RewriteKind;
+use crate::plans::RollbackPreparedPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
@@ -184,6 +189,10 @@ impl<'a> Binder {
                 self.bind_show_functions(bind_context, limit).await?
             }
 
+            Statement::DescribeFunction { name } => {
+                self.bind_describe_function(bind_context, name).await?
+            }
+
             Statement::ShowTableFunctions { limit } => {
                 self.bind_show_table_functions(bind_context, limit).await?
             }
@@ -247,6 +256,7 @@ impl<'a> Binder {
                 self.bind_show_tables_status(bind_context, stmt).await?
             }
             Statement::CreateTable(stmt) => self.bind_create_table(stmt).await?,
+            Statement::CreateExternalTable(stmt) => self.bind_create_external_table(stmt).await?,
             Statement::DropTable(stmt) => self.bind_drop_table(stmt).await?,
             Statement::UndropTable(stmt) => self.bind_undrop_table(stmt).await?,
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
@@ -257,14 +267,50 @@ impl<'a> Binder {
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
 
+            // Results
+            Statement::DeclareResult(stmt) => {
+                let name = normalize_identifier(&stmt.name, &self.name_resolution_ctx).name;
+                let query = Statement::Query(stmt.query.clone());
+                let plan = self.bind_statement(bind_context, &query).await?;
+                Plan::DeclareResult(Box::new(DeclareResultPlan {
+                    name,
+                    query: Box::new(plan),
+                }))
+            }
+
             // Views
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Materialized views
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
+
+            // Tasks
+            Statement::CreateTask(stmt) => self.bind_create_task(stmt).await?,
+            Statement::AlterTask(stmt) => self.bind_alter_task(stmt).await?,
+            Statement::DropTask(stmt) => self.bind_drop_task(stmt).await?,
+
+            // Plan baselines
+            Statement::AlterStatement(stmt) => self.bind_alter_statement(stmt).await?,
+
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
+            Statement::CreateBloomIndex(stmt) => self.bind_create_bloom_index(stmt).await?,
+            Statement::DropBloomIndex(stmt) => self.bind_drop_bloom_index(stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumns(stmt) => self.bind_create_virtual_columns(stmt).await?,
@@ -348,6 +394,47 @@ impl<'a> Binder {
                 }
                 self.bind_update(bind_context, stmt).await?
             },
+            Statement::MergeInto(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!("In MERGE INTO resolve optimize hints {:?} failed, err: {:?}", hints, e);
+                    }
+                }
+                self.bind_merge_into(bind_context, stmt).await?
+            },
+
+            // Prepared commits
+            Statement::PrepareCommit { id, statement } => {
+                let plan = self.bind_statement(bind_context, statement).await?;
+                match &plan {
+                    Plan::Insert(_)
+                    | Plan::Replace(_)
+                    | Plan::Delete(_)
+                    | Plan::Update(_)
+                    | Plan::Copy(box CopyPlan::IntoTable(_))
+                    | Plan::Copy(box CopyPlan::NoFileToCopy) => {}
+                    _ => {
+                        return Err(ErrorCode::SemanticError(
+                            "PREPARE COMMIT only supports INSERT, REPLACE, COPY INTO <table>, DELETE and UPDATE",
+                        ));
+                    }
+                }
+                Plan::PrepareCommit(Box::new(PrepareCommitPlan {
+                    id: id.clone(),
+                    plan: Box::new(plan),
+                }))
+            }
+            Statement::CommitPrepared { id } => {
+                Plan::CommitPrepared(Box::new(CommitPreparedPlan { id: id.clone() }))
+            }
+            Statement::RollbackPrepared { id } => {
+                Plan::RollbackPrepared(Box::new(RollbackPreparedPlan { id: id.clone() }))
+            }
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -34,15 +35,20 @@ use common_ast::parser::parser_values_with_placeholder;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::plan::StageTableInfo;
+use common_catalog::plan::UnloadPartitionKey;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_license::license_manager::get_license_manager;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -53,13 +59,17 @@ use parking_lot::RwLock;
 use tracing::debug;
 
 use crate::binder::location::parse_uri_location;
+use crate::binder::wrap_cast;
 use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::normalize_identifier;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CopyPlan;
 use crate::plans::Plan;
 use crate::plans::ValidationMode;
 use crate::BindContext;
+use crate::IndexType;
 use crate::Metadata;
 use crate::NameResolutionContext;
 
@@ -70,6 +80,26 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyStmt,
     ) -> Result<Plan> {
+        if matches!(&stmt.dst, CopyUnit::Table { .. }) && stmt.partition_by.is_some() {
+            return Err(ErrorCode::SemanticError(
+                "PARTITION BY can only be used in COPY INTO <location>",
+            ));
+        }
+        if matches!(&stmt.dst, CopyUnit::Table { .. })
+            && (stmt.apply_masking || stmt.sample_percent.is_some())
+        {
+            return Err(ErrorCode::SemanticError(
+                "APPLY MASKING and SAMPLE can only be used in COPY INTO <location>",
+            ));
+        }
+        if let Some(percent) = stmt.sample_percent {
+            if !(percent > 0.0 && percent <= 100.0) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "SAMPLE percent must be in (0, 100], but got {percent}"
+                )));
+            }
+        }
+
         match (&stmt.src, &stmt.dst) {
             (
                 CopyUnit::StageLocation(location),
@@ -122,6 +152,9 @@ impl<'a> Binder {
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        is_ordered: false,
+                        partition_by: vec![],
+                        file_name_prefix: None,
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -197,6 +230,9 @@ impl<'a> Binder {
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        is_ordered: false,
+                        partition_by: vec![],
+                        file_name_prefix: None,
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -341,6 +377,9 @@ impl<'a> Binder {
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        is_ordered: false,
+                        partition_by: vec![],
+                        file_name_prefix: None,
                     },
                     write_mode: CopyIntoTableMode::Copy,
                     query: None,
@@ -460,6 +499,9 @@ impl<'a> Binder {
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                is_ordered: false,
+                partition_by: vec![],
+                file_name_prefix: None,
             },
             write_mode,
             query: None,
@@ -489,10 +531,7 @@ impl<'a> Binder {
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
         let sub_stmt = sub_stmt_msg.0;
         let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -508,11 +547,15 @@ impl<'a> Binder {
             parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
+        let partition_by = self.bind_unload_partition_keys(stmt, &query).await?;
+
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
             stage: Box::new(stage_info),
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            file_name_prefix: stmt.file_name_prefix.clone(),
         })))
     }
 
@@ -534,10 +577,7 @@ impl<'a> Binder {
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
         let sub_stmt = sub_stmt_msg.0;
         let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -559,11 +599,15 @@ impl<'a> Binder {
         let mut stage_info = StageInfo::new_external_stage(storage_params, &path);
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
+        let partition_by = self.bind_unload_partition_keys(stmt, &query).await?;
+
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
             stage: Box::new(stage_info),
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            file_name_prefix: stmt.file_name_prefix.clone(),
         })))
     }
 
@@ -578,7 +622,7 @@ impl<'a> Binder {
         dst_path: &str,
     ) -> Result<Plan> {
         let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+            .bind_unload_query(bind_context, stmt, src_query)
             .await?;
 
         // Validation mode.
@@ -589,11 +633,15 @@ impl<'a> Binder {
             parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
+        let partition_by = self.bind_unload_partition_keys(stmt, &query).await?;
+
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
             stage: Box::new(stage_info),
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            file_name_prefix: stmt.file_name_prefix.clone(),
         })))
     }
 
@@ -608,7 +656,7 @@ impl<'a> Binder {
         dst_uri_location: &mut UriLocation,
     ) -> Result<Plan> {
         let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+            .bind_unload_query(bind_context, stmt, src_query)
             .await?;
 
         // Validation mode.
@@ -625,14 +673,67 @@ impl<'a> Binder {
         let mut stage_info = StageInfo::new_external_stage(storage_params, &path);
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
+        let partition_by = self.bind_unload_partition_keys(stmt, &query).await?;
+
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
             stage: Box::new(stage_info),
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            file_name_prefix: stmt.file_name_prefix.clone(),
         })))
     }
 
+    /// Binds the query unloaded by `COPY INTO <location>`. With `SAMPLE`, only a random sample
+    /// of its rows is unloaded. With `APPLY MASKING`, the unload fails unless the masking policies
+    /// of the read tables are applied, they are skipped without the enterprise license otherwise.
+    #[async_backtrace::framed]
+    async fn bind_unload_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CopyStmt,
+        query: &Query,
+    ) -> Result<Plan> {
+        let plan = match stmt.sample_percent {
+            Some(percent) => {
+                let sql =
+                    format!("SELECT * FROM ({query}) AS _sample WHERE rand() * 100 < {percent}");
+                let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+                let tokens = tokenize_sql(&sql)?;
+                let (sample_stmt, _) = parse_sql(&tokens, sql_dialect)?;
+                self.bind_statement(bind_context, &sample_stmt).await?
+            }
+            None => {
+                self.bind_statement(bind_context, &Statement::Query(Box::new(query.clone())))
+                    .await?
+            }
+        };
+
+        if stmt.apply_masking {
+            let masked = self.metadata.read().tables().iter().any(|table| {
+                match &table.table().get_table_info().meta.column_mask_policy {
+                    Some(policies) => !policies.is_empty(),
+                    None => false,
+                }
+            });
+            if masked {
+                get_license_manager()
+                    .manager
+                    .check_enterprise_enabled(
+                        &self.ctx.get_settings(),
+                        self.ctx.get_tenant(),
+                        "data_mask".to_string(),
+                    )
+                    .map_err(|cause| {
+                        cause.add_message("APPLY MASKING cannot apply the masking policies")
+                    })?;
+            }
+        }
+
+        Ok(plan)
+    }
+
     /// Bind COPY INTO <table> FROM <query>
     #[async_backtrace::framed]
     #[allow(clippy::too_many_arguments)]
@@ -684,6 +785,92 @@ impl<'a> Binder {
         Ok(Plan::Copy(Box::new(CopyPlan::IntoTable(plan))))
     }
 
+    /// Binds the keys of `PARTITION BY` on the output columns of the unloaded query, the
+    /// keys are evaluated on the unloaded blocks to the values of the partition directories.
+    #[async_backtrace::framed]
+    async fn bind_unload_partition_keys(
+        &mut self,
+        stmt: &CopyStmt,
+        query: &Plan,
+    ) -> Result<Vec<UnloadPartitionKey>> {
+        let keys = match &stmt.partition_by {
+            Some(keys) => keys,
+            None => return Ok(vec![]),
+        };
+        if stmt.single {
+            return Err(ErrorCode::SemanticError(
+                "SINGLE can not be used with PARTITION BY",
+            ));
+        }
+        let mut bind_context = match query {
+            Plan::Query { bind_context, .. } => *bind_context.clone(),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "COPY INTO <location> expects the plan of a query",
+                ));
+            }
+        };
+
+        // The unloaded blocks have the output columns of the query in order.
+        let positions: HashMap<IndexType, usize> = bind_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(position, column)| (column.index, position))
+            .collect();
+        let func_ctx = self.ctx.get_function_context()?;
+
+        let mut partition_keys = Vec::with_capacity(keys.len());
+        for key in keys {
+            let name = match (&key.alias, &key.expr) {
+                (Some(alias), _) => normalize_identifier(alias, &self.name_resolution_ctx).name,
+                (
+                    None,
+                    Expr::ColumnRef {
+                        column: AstColumnID::Name(column),
+                        ..
+                    },
+                ) => normalize_identifier(column, &self.name_resolution_ctx).name,
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "PARTITION BY expression `{}` must have an alias as the name of the partition",
+                        key.expr
+                    ))
+                    .set_span(key.expr.span()));
+                }
+            };
+
+            let mut scalar_binder = ScalarBinder::new(
+                &mut bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(&key.expr).await?;
+            let scalar = wrap_cast(&scalar, &DataType::String.wrap_nullable());
+            let expr = scalar.as_expr()?.project_column_ref(|col| col.index);
+            if expr
+                .column_refs()
+                .keys()
+                .any(|index| !positions.contains_key(index))
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "PARTITION BY expression `{}` can only refer to the columns of the unloaded query",
+                    key.expr
+                ))
+                .set_span(key.expr.span()));
+            }
+            let expr = expr.project_column_ref(|index| positions[index]);
+            let (expr, _) = ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS);
+            partition_keys.push(UnloadPartitionKey {
+                name,
+                expr: expr.as_remote_expr(),
+            });
+        }
+        Ok(partition_keys)
+    }
+
     #[async_backtrace::framed]
     async fn apply_stage_options(&mut self, stmt: &CopyStmt, stage: &mut StageInfo) -> Result<()> {
         if !stmt.file_format.is_empty() {
@@ -30,6 +30,7 @@ use common_expression::DataSchemaRefExt;
 use common_meta_app::schema::CatalogMeta;
 use common_meta_app::schema::CatalogOption;
 use common_meta_app::schema::CatalogType;
+use common_meta_app::schema::DatabendCatalogOption;
 use common_meta_app::schema::IcebergCatalogOption;
 use url::Url;
 
@@ -217,6 +218,22 @@ impl Binder {
                 };
                 CatalogOption::Iceberg(opt)
             }
+            CatalogType::Databend => {
+                let address = options
+                    .get("address")
+                    .ok_or_else(|| ErrorCode::InvalidArgument("expected field: ADDRESS"))?;
+                let username = options
+                    .get("username")
+                    .cloned()
+                    .unwrap_or_else(|| "root".to_string());
+                let password = options.get("password").cloned().unwrap_or_default();
+
+                CatalogOption::Databend(DatabendCatalogOption {
+                    address: address.to_string(),
+                    username,
+                    password,
+                })
+            }
         };
 
         Ok(CatalogMeta {
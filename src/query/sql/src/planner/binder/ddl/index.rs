@@ -12,19 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::CreateBloomIndexStmt;
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
+use common_ast::ast::DropBloomIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
+use common_catalog::plan::AggIndexDefinition;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
 
 use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::agg_index::build_index_definition;
+use crate::plans::CreateBloomIndexPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::DropBloomIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::BindContext;
 
@@ -48,7 +60,7 @@ impl Binder {
         let index_name = self.normalize_object_identifier(index_name);
 
         bind_context.planning_agg_index = true;
-        self.bind_query(bind_context, query).await?;
+        let (s_expr, _) = self.bind_query(bind_context, query).await?;
         bind_context.planning_agg_index = false;
 
         let tables = self.metadata.read().tables().to_vec();
@@ -69,6 +81,12 @@ impl Binder {
             )));
         }
 
+        if build_index_definition(&s_expr)?.is_none() {
+            return Err(ErrorCode::UnsupportedIndex(
+                "The index data can not be built block by block, aggregate functions can not be mixed with other expressions",
+            ));
+        }
+
         let table_id = table.get_id();
         let mut query = *query.clone();
         Self::rewrite_query_with_database(&mut query, table_entry.database());
@@ -83,6 +101,18 @@ impl Binder {
         Ok(Plan::CreateIndex(Box::new(plan)))
     }
 
+    /// Bind the query of an aggregating index and build the definition of the index data.
+    #[async_backtrace::framed]
+    pub async fn bind_agg_index_definition(
+        &mut self,
+        query: &Query,
+    ) -> Result<Option<AggIndexDefinition>> {
+        let mut bind_context = BindContext::new();
+        bind_context.planning_agg_index = true;
+        let (s_expr, _) = self.bind_query(&mut bind_context, query).await?;
+        build_index_definition(&s_expr)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_index(
         &mut self,
@@ -97,7 +127,180 @@ impl Binder {
         Ok(Plan::DropIndex(Box::new(plan)))
     }
 
-    fn check_index_support(query: &Query) -> Result<()> {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_bloom_index(
+        &mut self,
+        stmt: &CreateBloomIndexStmt,
+    ) -> Result<Plan> {
+        let CreateBloomIndexStmt {
+            if_not_exists,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let columns = self
+            .bind_bloom_index_columns(&catalog, &database, &table, columns)
+            .await?;
+
+        let plan = CreateBloomIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            columns,
+        };
+        Ok(Plan::CreateBloomIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_bloom_index(
+        &mut self,
+        stmt: &DropBloomIndexStmt,
+    ) -> Result<Plan> {
+        let DropBloomIndexStmt {
+            if_exists,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let columns = self
+            .bind_bloom_index_columns(&catalog, &database, &table, columns)
+            .await?;
+
+        let plan = DropBloomIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            columns,
+        };
+        Ok(Plan::DropBloomIndex(Box::new(plan)))
+    }
+
+    /// Normalize the columns of a bloom index, they must be columns of the fuse table.
+    #[async_backtrace::framed]
+    async fn bind_bloom_index_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        columns: &[Identifier],
+    ) -> Result<Vec<String>> {
+        let table_info = self.ctx.get_table(catalog, database, table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Bloom index only support FUSE engine",
+            ));
+        }
+
+        let schema = table_info.schema();
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if schema.field_with_name(&name).is_err() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Unknown column {name} in table {database}.{table}"
+                )));
+            }
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+
+        let schema = table_info.schema();
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "Unknown column {name} in table {database}.{table}"
+                ))
+            })?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index only support string columns, but column {name} is {}",
+                    field.data_type()
+                )));
+            }
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns: names,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) fn check_index_support(query: &Query) -> Result<()> {
         let err = Err(ErrorCode::UnsupportedIndex(format!(
             "Currently create index just support simple query, like: {}",
             "SELECT ... FROM ... WHERE ... GROUP BY ..."
@@ -132,7 +335,7 @@ impl Binder {
         Ok(())
     }
 
-    fn rewrite_query_with_database(query: &mut Query, name: &str) {
+    pub(in crate::planner::binder) fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
                 if database.is_none() {
@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_ast::ast::CreateMaterializedViewStmt;
+use common_ast::ast::CreateTableStmt;
+use common_ast::ast::DropMaterializedViewStmt;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+
+use crate::binder::Binder;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropMaterializedViewPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            refresh_interval_secs,
+            query,
+        } = stmt;
+
+        // The view data is maintained the same way as the aggregating index data.
+        Self::check_index_support(query).map_err(|_| {
+            ErrorCode::SemanticError(format!(
+                "Currently materialized view just support simple query, like: {}",
+                "SELECT ... FROM ... WHERE ... GROUP BY ..."
+            ))
+        })?;
+        if *refresh_interval_secs == Some(0) {
+            return Err(ErrorCode::SemanticError(
+                "The refresh interval of materialized view must be positive",
+            ));
+        }
+
+        let (catalog_name, database_name, _) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let mut bind_context = BindContext::new();
+        bind_context.planning_agg_index = true;
+        self.bind_query(&mut bind_context, query).await?;
+
+        let tables = self.metadata.read().tables().to_vec();
+        if tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view currently only support single table",
+            ));
+        }
+
+        let table_entry = &tables[0];
+        let table = table_entry.table();
+        if !table.support_index() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table engine {} does not support materialized view",
+                table.engine()
+            )));
+        }
+        // The views of a table are looked up in the database of the table.
+        if table_entry.catalog() != catalog_name || table_entry.database() != database_name {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must be created in the database of its table",
+            ));
+        }
+
+        let mut query = *query.clone();
+        Self::rewrite_query_with_database(&mut query, table_entry.database());
+
+        let create_table = CreateTableStmt {
+            if_not_exists: *if_not_exists,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: None,
+            uri_location: None,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: Some(Box::new(query.clone())),
+            transient: false,
+        };
+        let mut table_plan = match self.bind_create_table(&create_table).await? {
+            Plan::CreateTable(plan) => *plan,
+            _ => unreachable!(),
+        };
+        // The view data is inserted by the refreshes.
+        table_plan.as_select = None;
+        table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            query.to_string(),
+        );
+        table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+            table.get_id().to_string(),
+        );
+        if let Some(secs) = refresh_interval_secs {
+            table_plan.options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL.to_string(),
+                secs.to_string(),
+            );
+        }
+
+        let plan = CreateMaterializedViewPlan { table: table_plan };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(Box::new(plan)))
+    }
+}
@@ -18,9 +18,13 @@ mod column;
 mod data_mask;
 mod database;
 mod index;
+mod materialized_view;
+mod plan_baseline;
 mod role;
 mod share;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod view;
 mod virtual_column;
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::AlterStatementAction;
+use common_ast::ast::AlterStatementStmt;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+
+use crate::binder::Binder;
+use crate::plans::AlterStatementPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_statement(
+        &mut self,
+        stmt: &AlterStatementStmt,
+    ) -> Result<Plan> {
+        let AlterStatementStmt { query, action } = stmt;
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(query)?;
+        let (query_stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        if !matches!(query_stmt, Statement::Query(_)) {
+            return Err(ErrorCode::SemanticError(
+                "Only the plans of queries can be pinned",
+            ));
+        }
+
+        // Queries are matched by the text formatted from their ASTs, see `Planner::plan_sql`.
+        let database = self.ctx.get_current_database();
+        let query_text = query_stmt.to_string();
+        let plan = AlterStatementPlan {
+            tenant: self.ctx.get_tenant(),
+            baseline_id: PlanBaseline::gen_id(&database, &query_text),
+            database,
+            query_text,
+            pin: *action == AlterStatementAction::PinPlan,
+        };
+        Ok(Plan::AlterStatement(Box::new(plan)))
+    }
+}
@@ -22,6 +22,7 @@ use common_ast::ast::AnalyzeTableStmt;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
+use common_ast::ast::CreateExternalTableStmt;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
@@ -62,18 +63,28 @@ use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_delta::DeltaTable;
+use common_storages_stage::ExternalTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use parking_lot::RwLock;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::parse_table_compression_option;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PATTERN;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
 
 use crate::binder::location::parse_uri_location;
+use crate::binder::parse_stage_location;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
 use crate::binder::Visibility;
@@ -85,6 +96,7 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AmendTablePlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
 use crate::plans::CreateTablePlan;
@@ -370,6 +382,13 @@ impl Binder {
             None => (None, "".to_string()),
         };
 
+        // The data of DELTA tables is in the location
+        if engine == Engine::Delta && storage_params.is_none() {
+            return Err(ErrorCode::BadArguments(
+                "Location of the DELTA table is required",
+            ));
+        }
+
         // If table is TRANSIENT, set a flag in table option
         if *transient {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
@@ -421,6 +440,14 @@ impl Binder {
                 Self::validate_create_table_schema(&source_schema)?;
                 (source_schema, source_comments)
             }
+            (None, None) if engine == Engine::Delta => {
+                // `CREATE TABLE ... ENGINE = DELTA` reads the columns of the existing table,
+                // the location is checked above.
+                let sp = storage_params.as_ref().unwrap();
+                let schema = Arc::new(DeltaTable::infer_schema(sp).await?);
+                Self::validate_create_table_schema(&schema)?;
+                (schema, vec![])
+            }
             _ => Err(ErrorCode::BadArguments(
                 "Incorrect CREATE query: required list of column descriptions or AS section or SELECT..",
             ))?,
@@ -466,21 +493,29 @@ impl Binder {
                 );
             }
 
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
+            let default_compression = match config.query.default_compression.as_str() {
+                "" | "auto" => {
+                    if is_blocking_fs {
+                        "lz4"
+                    } else {
+                        "zstd"
                     }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
+                }
+                _ => config.query.default_compression.as_str(),
+            };
+            match options.get_mut(OPT_KEY_TABLE_COMPRESSION) {
+                None => {
+                    options.insert(
+                        OPT_KEY_TABLE_COMPRESSION.to_owned(),
+                        default_compression.to_owned(),
+                    );
+                }
+                // Only the compressions of columns are given, like `c1=zstd(9),c2=lz4`.
+                Some(compression) => {
+                    if parse_table_compression_option(compression)?.0.is_none() {
+                        *compression = format!("{default_compression},{compression}");
+                    }
+                }
             }
         }
 
@@ -523,6 +558,182 @@ impl Binder {
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_external_table(
+        &mut self,
+        stmt: &CreateExternalTableStmt,
+    ) -> Result<Plan> {
+        let CreateExternalTableStmt {
+            if_not_exists,
+            catalog,
+            database,
+            table,
+            source,
+            location,
+            connection,
+            pattern,
+            file_format,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        // The connection of an uri location is kept in the table meta, like the location of
+        // the DELTA tables.
+        let storage_params = if location.starts_with('@') {
+            if !connection.is_empty() {
+                return Err(ErrorCode::BadArguments(
+                    "CONNECTION can only be given with an uri location of an external table",
+                ));
+            }
+            None
+        } else {
+            let mut uri =
+                UriLocation::from_uri(location.clone(), "".to_string(), connection.clone())?;
+            let (sp, _) = parse_uri_location(&mut uri)?;
+            if !sp.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
+                return Err(ErrorCode::StorageInsecure(
+                    "external table on insecure storage is not allowed",
+                ));
+            }
+            Some(sp)
+        };
+
+        // Check the location and the file format, they are resolved again when the table is read.
+        let (stage_info, path) = self
+            .resolve_external_table_location(location, storage_params.as_ref(), file_format)
+            .await?;
+        let is_parquet = matches!(stage_info.file_format_params, FileFormatParams::Parquet(_));
+
+        // The parquet files are listed to infer the columns and the partition columns.
+        let files = if is_parquet {
+            ExternalTable::list_location_files(&stage_info, &path, pattern.as_deref()).await?
+        } else {
+            vec![]
+        };
+
+        let (schema, mut field_comments) = match source {
+            Some(source) => self.analyze_create_table_schema(source).await?,
+            None if is_parquet => {
+                let schema =
+                    ExternalTable::infer_parquet_schema(&stage_info, &path, files.clone()).await?;
+                Self::validate_create_table_schema(&schema)?;
+                (schema, vec![])
+            }
+            None => {
+                return Err(ErrorCode::BadArguments(
+                    "the columns of an external table can only be omitted for parquet files",
+                ));
+            }
+        };
+        if schema.fields().iter().any(|f| f.computed_expr().is_some()) {
+            return Err(ErrorCode::SemanticError(
+                "computed columns are not supported by external tables",
+            ));
+        }
+
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_EXTERNAL_TABLE_LOCATION.to_string(),
+            location.to_string(),
+        );
+        if let Some(pattern) = pattern {
+            options.insert(OPT_KEY_EXTERNAL_TABLE_PATTERN.to_string(), pattern.clone());
+        }
+        if !file_format.is_empty() {
+            options.insert(
+                OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT.to_string(),
+                ExternalTable::encode_file_format(file_format)?,
+            );
+        }
+
+        // The hive partition columns, like `year` of `year=2023/`, are appended to the columns.
+        let partition_fields = ExternalTable::discover_partition_columns(&path, &files, &schema);
+        let schema = if partition_fields.is_empty() {
+            schema
+        } else {
+            options.insert(
+                OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS.to_string(),
+                ExternalTable::encode_partition_columns(&partition_fields),
+            );
+            if !field_comments.is_empty() {
+                field_comments.resize(
+                    field_comments.len() + partition_fields.len(),
+                    "".to_string(),
+                );
+            }
+            let mut fields = schema.fields().clone();
+            fields.extend(partition_fields);
+            TableSchemaRefExt::create(fields)
+        };
+
+        let plan = CreateTablePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            schema,
+            engine: Engine::External,
+            storage_params,
+            part_prefix: "".to_string(),
+            options,
+            field_comments,
+            cluster_key: None,
+            as_select: None,
+        };
+        Ok(Plan::CreateTable(Box::new(plan)))
+    }
+
+    /// Resolves the location of an external table to the stage with the file format of the
+    /// table and the path in the stage. The location is a stage location like `@my_stage/path/`,
+    /// or an uri like `s3://bucket/path/` with the `storage_params` in the table meta.
+    ///
+    /// The user stages are not allowed, an external table is read by the other users too.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn resolve_external_table_location(
+        &self,
+        location: &str,
+        storage_params: Option<&StorageParams>,
+        file_format: &BTreeMap<String, String>,
+    ) -> Result<(StageInfo, String)> {
+        let (mut stage_info, path) = match (location.strip_prefix('@'), storage_params) {
+            (Some(name), _) => {
+                if name.is_empty() || name.starts_with('/') {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "the location of an external table must be a stage location like '@<stage>/<path>', got '{location}'"
+                    )));
+                }
+                if name == "~" || name.starts_with("~/") {
+                    return Err(ErrorCode::BadArguments(
+                        "the location of an external table can not be in the user stage",
+                    ));
+                }
+                parse_stage_location(&self.ctx, location).await?
+            }
+            (None, Some(sp)) => {
+                // The directory of the uri is the root of the storage, see `parse_uri_location`.
+                let uri =
+                    UriLocation::from_uri(location.to_string(), "".to_string(), BTreeMap::new())?;
+                let path = if uri.path.ends_with('/') {
+                    "/".to_string()
+                } else {
+                    uri.path
+                };
+                (StageInfo::new_external_stage(sp.clone(), &path), path)
+            }
+            (None, None) => {
+                return Err(ErrorCode::Internal(format!(
+                    "missing the connection of the external table location '{location}'"
+                )));
+            }
+        };
+        if !file_format.is_empty() {
+            stage_info.file_format_params = self.try_resolve_file_format(file_format).await?;
+        }
+        Ok((stage_info, path))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
@@ -699,10 +910,13 @@ impl Binder {
                     is_final: *is_final,
                     metadata: self.metadata.clone(),
                     push_downs,
+                    limit: None,
                 })))
             }
             AlterTableAction::RevertTo { point } => {
-                let point = self.resolve_data_travel_point(bind_context, point).await?;
+                let point = self
+                    .resolve_data_travel_point(bind_context, &catalog, &database, &table, point)
+                    .await?;
                 Ok(Plan::RevertTable(Box::new(RevertTablePlan {
                     tenant,
                     catalog,
@@ -711,6 +925,11 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::Amend => Ok(Plan::AmendTable(Box::new(AmendTablePlan {
+                catalog,
+                database,
+                table,
+            }))),
         }
     }
 
@@ -795,7 +1014,9 @@ impl Binder {
             AstOptimizeTableAction::All => OptimizeTableAction::All,
             AstOptimizeTableAction::Purge { before } => {
                 let p = if let Some(point) = before {
-                    let point = self.resolve_data_travel_point(bind_context, point).await?;
+                    let point = self
+                        .resolve_data_travel_point(bind_context, &catalog, &database, &table, point)
+                        .await?;
                     Some(point)
                 } else {
                     None
@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::AlterTaskAction;
+use common_ast::ast::AlterTaskStmt;
+use common_ast::ast::CreateTaskStmt;
+use common_ast::ast::DropTaskStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            name,
+            schedule,
+            after,
+            comment,
+            sql,
+        } = stmt;
+
+        if schedule.is_some() == !after.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Task must have either a SCHEDULE or AFTER tasks, but not both",
+            ));
+        }
+
+        let task_name = self.normalize_object_identifier(name);
+        let after = after
+            .iter()
+            .map(|ident| self.normalize_object_identifier(ident))
+            .collect::<Vec<_>>();
+        if after.contains(&task_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Task {task_name} can not run after itself"
+            )));
+        }
+
+        let plan = CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            task_name,
+            schedule: schedule.clone(),
+            after,
+            comment: comment.clone().unwrap_or_default(),
+            database: self.ctx.get_current_database(),
+            query_text: sql.to_string(),
+        };
+        Ok(Plan::CreateTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt { name, action } = stmt;
+
+        let plan = AlterTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(name),
+            suspend: *action == AlterTaskAction::Suspend,
+        };
+        Ok(Plan::AlterTask(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, name } = stmt;
+
+        let plan = DropTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            task_name: self.normalize_object_identifier(name),
+        };
+        Ok(Plan::DropTask(Box::new(plan)))
+    }
+}
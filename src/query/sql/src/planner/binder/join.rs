@@ -47,6 +47,7 @@ pub struct JoinConditions {
     pub(crate) right_conditions: Vec<ScalarExpr>,
     pub(crate) non_equi_conditions: Vec<ScalarExpr>,
     pub(crate) other_conditions: Vec<ScalarExpr>,
+    pub(crate) is_null_equal: Vec<usize>,
 }
 
 impl Binder {
@@ -85,6 +86,7 @@ impl Binder {
         let mut right_join_conditions: Vec<ScalarExpr> = vec![];
         let mut non_equi_conditions: Vec<ScalarExpr> = vec![];
         let mut other_conditions: Vec<ScalarExpr> = vec![];
+        let mut is_null_equal: Vec<usize> = vec![];
         let mut join_condition_resolver = JoinConditionResolver::new(
             self.ctx.clone(),
             &self.name_resolution_ctx,
@@ -101,6 +103,7 @@ impl Binder {
                 &mut right_join_conditions,
                 &mut non_equi_conditions,
                 &mut other_conditions,
+                &mut is_null_equal,
                 &join.op,
             )
             .await?;
@@ -110,6 +113,7 @@ impl Binder {
             right_conditions: right_join_conditions,
             non_equi_conditions,
             other_conditions,
+            is_null_equal,
         };
         let s_expr = match &join.op {
             JoinOperator::Inner => {
@@ -178,6 +182,7 @@ impl Binder {
         let right_conditions = join_conditions.right_conditions;
         let mut non_equi_conditions = join_conditions.non_equi_conditions;
         let other_conditions = join_conditions.other_conditions;
+        let is_null_equal = join_conditions.is_null_equal;
         if join_type == JoinType::Cross
             && (!left_conditions.is_empty() || !right_conditions.is_empty())
         {
@@ -195,6 +200,7 @@ impl Binder {
             left_conditions,
             right_conditions,
             non_equi_conditions,
+            is_null_equal,
             join_type,
             marker_index: None,
             from_correlated_subquery: false,
@@ -401,6 +407,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
         join_op: &JoinOperator,
     ) -> Result<()> {
         match &self.join_condition {
@@ -411,6 +418,7 @@ impl<'a> JoinConditionResolver<'a> {
                     right_join_conditions,
                     non_equi_conditions,
                     other_join_conditions,
+                    is_null_equal,
                 )
                 .await?;
             }
@@ -467,6 +475,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
     ) -> Result<()> {
         let conjunctions = split_conjunctions_expr(condition);
         for expr in conjunctions.iter() {
@@ -476,6 +485,7 @@ impl<'a> JoinConditionResolver<'a> {
                 right_join_conditions,
                 non_equi_conditions,
                 other_join_conditions,
+                is_null_equal,
             )
             .await?;
         }
@@ -496,6 +506,7 @@ impl<'a> JoinConditionResolver<'a> {
         right_join_conditions: &mut Vec<ScalarExpr>,
         non_equi_conditions: &mut Vec<ScalarExpr>,
         other_join_conditions: &mut Vec<ScalarExpr>,
+        is_null_equal: &mut Vec<usize>,
     ) -> Result<()> {
         let mut join_context = (*self.join_context).clone();
         wrap_nullable_for_column(
@@ -519,11 +530,28 @@ impl<'a> JoinConditionResolver<'a> {
         //     For example, `t1.a + t1.b = t2.a` is a valid one while `t1.a + t2.a = t2.b` isn't.
         //
         // Only equi-predicate can be exploited by common join algorithms(e.g. sort-merge join, hash join).
+        //
+        // `t1.a IS NOT DISTINCT FROM t2.a` is also an equi-predicate, except that NULL matches NULL.
 
         let mut added = if let Some((left, right)) = split_equivalent_predicate_expr(predicate) {
             let (left, _) = scalar_binder.bind(&left).await?;
             let (right, _) = scalar_binder.bind(&right).await?;
             self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?
+        } else if let Expr::IsDistinctFrom {
+            left,
+            right,
+            not: true,
+            ..
+        } = predicate
+        {
+            let (left, _) = scalar_binder.bind(left).await?;
+            let (right, _) = scalar_binder.bind(right).await?;
+            let added =
+                self.add_equi_conditions(left, right, left_join_conditions, right_join_conditions)?;
+            if added {
+                is_null_equal.push(left_join_conditions.len() - 1);
+            }
+            added
         } else {
             false
         };
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
@@ -154,6 +155,11 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     }
     .to_string();
 
+    let session_tags = match l.connection.get("session_tags") {
+        Some(tags) => parse_session_tags(tags)?,
+        None => BTreeMap::new(),
+    };
+
     let allow_anonymous = {
         if let Some(s) = l.connection.get("allow_anonymous") {
             s
@@ -180,11 +186,12 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         master_key,
         root,
         // Disable credential load by default.
-        // TODO(xuanwo): we should support AssumeRole.
+        // AssumeRole is still allowed, the source credential comes from the node itself.
         disable_credential_loader: !GlobalConfig::instance().storage.allow_insecure,
         enable_virtual_host_style,
         role_arn,
         external_id,
+        session_tags,
         allow_anonymous,
     });
 
@@ -193,6 +200,25 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     Ok(sp)
 }
 
+/// parse_session_tags parses tags like `team=data,env=prod` into a map.
+fn parse_session_tags(tags: &str) -> Result<BTreeMap<String, String>> {
+    let mut session_tags = BTreeMap::new();
+    for tag in tags.split(',').map(str::trim).filter(|v| !v.is_empty()) {
+        match tag.split_once('=') {
+            Some((k, v)) if !k.trim().is_empty() => {
+                session_tags.insert(k.trim().to_string(), v.trim().to_string());
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    anyhow!("value for session_tags is invalid: {tag:?}, expect key=value"),
+                ));
+            }
+        }
+    }
+    Ok(session_tags)
+}
+
 fn parse_gcs_params(l: &mut UriLocation) -> Result<StorageParams> {
     let endpoint = l
         .connection
@@ -0,0 +1,304 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::binder::wrap_cast_scalar;
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::normalize_identifier;
+use crate::plans::ConstantExpr;
+use crate::plans::MatchedEvaluator;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::UnmatchedEvaluator;
+use crate::BindContext;
+use crate::ColumnEntry;
+use crate::TableInternalColumn;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table: table_ident,
+            target_alias,
+            source,
+            join_expr,
+            ..
+        } = stmt;
+        let (matched_clauses, unmatched_clauses) = stmt.split_clauses();
+
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table_ident);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        if !table.support_row_id_column() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "table {}, engine type {}, does not support MERGE INTO",
+                table.name(),
+                table.get_table_info().engine(),
+            )));
+        }
+        let schema = table.schema();
+        if schema.fields().iter().any(|f| f.computed_expr().is_some()) {
+            return Err(ErrorCode::Unimplemented(
+                "MERGE INTO does not support tables with computed columns yet",
+            ));
+        }
+
+        // The source rows that match nothing are only needed by `WHEN NOT MATCHED`.
+        let op = if unmatched_clauses.is_empty() {
+            JoinOperator::Inner
+        } else {
+            JoinOperator::LeftOuter
+        };
+        let target = TableReference::Table {
+            span: None,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table_ident.clone(),
+            alias: target_alias.clone(),
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let join = TableReference::Join {
+            span: None,
+            join: Join {
+                op,
+                condition: JoinCondition::On(Box::new(join_expr.clone())),
+                left: Box::new(source.clone()),
+                right: Box::new(target),
+            },
+        };
+        let (input, mut context) = self.bind_table_reference(bind_context, &join).await?;
+
+        let target_name = target_alias
+            .as_ref()
+            .map_or_else(|| table_ident.clone(), |alias| alias.name.clone());
+        let target_column = |name: &str| Expr::ColumnRef {
+            span: None,
+            database: None,
+            table: Some(target_name.clone()),
+            column: ColumnID::Name(Identifier {
+                name: name.to_string(),
+                quote: Some('"'),
+                span: None,
+            }),
+        };
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        // `_row_id` of the target table tells whether a source row is matched,
+        // and which row of the target table is matched.
+        let row_id_index = match scalar_binder.bind(&target_column(ROW_ID_COL_NAME)).await? {
+            (ScalarExpr::BoundColumnRef(column_ref), _) => column_ref.column.index,
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "should not happen, `_row_id` must be bound to a column",
+                ));
+            }
+        };
+        let target_table_index = match self.metadata.read().column(row_id_index) {
+            ColumnEntry::InternalColumn(TableInternalColumn { table_index, .. }) => *table_index,
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "should not happen, `_row_id` must be an internal column of the target table",
+                ));
+            }
+        };
+
+        // The current values of the target table, they're kept by `UPDATE`
+        // if the column is not assigned.
+        let mut target_values = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let (scalar, data_type) = scalar_binder.bind(&target_column(field.name())).await?;
+            let target_type = DataType::from(field.data_type());
+            target_values.push(wrap_cast_scalar(&scalar, &data_type, &target_type)?);
+        }
+
+        let mut matched_evaluators = Vec::with_capacity(matched_clauses.len());
+        for clause in matched_clauses.iter() {
+            let condition = match &clause.selection {
+                Some(expr) => Some(bind_merge_into_expr(&mut scalar_binder, expr).await?.0),
+                None => None,
+            };
+            let update = match &clause.operation {
+                MatchOperation::Update { update_list } => {
+                    let mut values = target_values.clone();
+                    let mut assigned = HashSet::with_capacity(update_list.len());
+                    for update_expr in update_list {
+                        let col_name =
+                            normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                        let index = schema.index_of(&col_name)?;
+                        if !assigned.insert(index) {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Multiple assignments in the single statement to column `{}`",
+                                col_name
+                            )));
+                        }
+                        let (scalar, data_type) =
+                            bind_merge_into_expr(&mut scalar_binder, &update_expr.expr).await?;
+                        let target_type = DataType::from(schema.field(index).data_type());
+                        values[index] = wrap_cast_scalar(&scalar, &data_type, &target_type)?;
+                    }
+                    Some(values)
+                }
+                MatchOperation::Delete => None,
+            };
+            matched_evaluators.push(MatchedEvaluator { condition, update });
+        }
+
+        let mut unmatched_evaluators = Vec::with_capacity(unmatched_clauses.len());
+        for clause in unmatched_clauses.iter() {
+            let condition = match &clause.selection {
+                Some(expr) => Some(bind_merge_into_expr(&mut scalar_binder, expr).await?.0),
+                None => None,
+            };
+            let columns = if clause.columns.is_empty() {
+                (0..schema.num_fields()).collect::<Vec<_>>()
+            } else {
+                clause
+                    .columns
+                    .iter()
+                    .map(|ident| {
+                        schema
+                            .index_of(&normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            };
+            if columns.len() != clause.values.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "MERGE INTO expects {} values to insert, but got {}",
+                    columns.len(),
+                    clause.values.len()
+                )));
+            }
+
+            let mut values = vec![None; schema.num_fields()];
+            for (index, expr) in columns.into_iter().zip(clause.values.iter()) {
+                if values[index].is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{}` is specified more than once",
+                        schema.field(index).name()
+                    )));
+                }
+                let (scalar, data_type) = bind_merge_into_expr(&mut scalar_binder, expr).await?;
+                let target_type = DataType::from(schema.field(index).data_type());
+                values[index] = Some(wrap_cast_scalar(&scalar, &data_type, &target_type)?);
+            }
+            let mut filled_values = Vec::with_capacity(values.len());
+            for (index, value) in values.into_iter().enumerate() {
+                let value = match value {
+                    Some(value) => value,
+                    None => default_value(&mut scalar_binder, &schema, index).await?,
+                };
+                filled_values.push(value);
+            }
+            unmatched_evaluators.push(UnmatchedEvaluator {
+                condition,
+                values: filled_values,
+            });
+        }
+
+        // Make sure `_row_id` will be read by the scan of the target table.
+        let input = context.add_internal_column_into_expr(input);
+
+        let plan = MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id: table.get_id(),
+            input: Box::new(input),
+            bind_context: Box::new(context.clone()),
+            metadata: self.metadata.clone(),
+            target_table_index,
+            row_id_index,
+            matched_evaluators,
+            unmatched_evaluators,
+        };
+        Ok(Plan::MergeInto(Box::new(plan)))
+    }
+}
+
+async fn bind_merge_into_expr(
+    scalar_binder: &mut ScalarBinder<'_>,
+    expr: &Expr,
+) -> Result<(ScalarExpr, DataType)> {
+    let (scalar, data_type) = scalar_binder.bind(expr).await?;
+    if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
+        return Err(ErrorCode::Unimplemented(
+            "MERGE INTO does not support subquery temporarily",
+        ));
+    }
+    Ok((scalar, data_type))
+}
+
+async fn default_value(
+    scalar_binder: &mut ScalarBinder<'_>,
+    schema: &TableSchemaRef,
+    index: usize,
+) -> Result<ScalarExpr> {
+    let field = schema.field(index);
+    let target_type = DataType::from(field.data_type());
+    if let Some(default_expr) = field.default_expr() {
+        let tokens = tokenize_sql(default_expr)?;
+        let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let (scalar, data_type) = scalar_binder.bind(&ast).await?;
+        wrap_cast_scalar(&scalar, &data_type, &target_type)
+    } else {
+        let value = if target_type.is_nullable() {
+            Scalar::Null
+        } else {
+            Scalar::default_value(&target_type)
+        };
+        let scalar = ScalarExpr::ConstantExpr(ConstantExpr { span: None, value });
+        let data_type = scalar.data_type()?;
+        wrap_cast_scalar(&scalar, &data_type, &target_type)
+    }
+}
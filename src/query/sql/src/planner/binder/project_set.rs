@@ -16,21 +16,26 @@ use std::sync::Arc;
 
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::TableAlias;
 use common_ast::ast::Window;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::FunctionKind;
+use common_functions::srfs::FLATTEN_COLUMNS;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::binder::ExprContext;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::ProjectSet;
+use crate::plans::ScalarItem;
 use crate::plans::SrfItem;
 use crate::BindContext;
 use crate::Binder;
@@ -52,6 +57,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         args: &'a [Expr],
         params: &'a [Literal],
         over: &'a Option<Window>,
+        lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
             .get_property(&name.name)
@@ -66,6 +72,7 @@ impl<'a> Visitor<'a> for SrfCollector {
                 args: args.to_vec(),
                 params: params.to_vec(),
                 window: over.clone(),
+                lambda: lambda.clone(),
             });
         } else {
             for arg in args.iter() {
@@ -195,4 +202,200 @@ impl Binder {
             Arc::new(s_expr),
         ))
     }
+
+    /// Bind a set-returning function used as a table function, e.g. `FROM t, LATERAL flatten(t.v) AS f`.
+    ///
+    /// The function is bound as a `ProjectSet` over `s_expr` and its arguments are resolved in
+    /// `bind_context`, so it's evaluated once for every row of `s_expr` and the rows it returns
+    /// are joined with that row. The tuple fields of the result are added to `bind_context`
+    /// as the columns of the table function.
+    #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bind_srf_table_function(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: Span,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let params = resolve_srf_named_params(span, &func_name, params, named_params)?;
+
+        let original_context = bind_context.expr_context.clone();
+        bind_context.set_expr_context(ExprContext::InSetReturningFunction);
+
+        let mut arguments = Vec::with_capacity(params.len());
+        for param in params.iter() {
+            let mut scalar_binder = ScalarBinder::new(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = scalar_binder.bind(param).await?;
+            arguments.push(scalar);
+        }
+
+        // Restore the original context
+        bind_context.set_expr_context(original_context);
+
+        let srf_scalar = ScalarExpr::FunctionCall(FunctionCall {
+            span,
+            func_name: func_name.clone(),
+            params: vec![],
+            arguments,
+        });
+        let srf_type = srf_scalar.as_expr()?.data_type().clone();
+        let num_fields = srf_type.as_tuple().unwrap().len();
+
+        let mut column_names = if func_name == "flatten" {
+            FLATTEN_COLUMNS
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+        } else {
+            vec![func_name.clone()]
+        };
+        if column_names.len() != num_fields {
+            return Err(ErrorCode::Unimplemented(
+                "set-returning functions with more than one return type are not supported yet",
+            )
+            .set_span(span));
+        }
+
+        let table_name = match alias {
+            Some(alias) => {
+                if alias.columns.len() > column_names.len() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "table function {} has {} columns available but {} columns specified",
+                        func_name,
+                        column_names.len(),
+                        alias.columns.len()
+                    ))
+                    .set_span(span));
+                }
+                for (column_name, ident) in column_names.iter_mut().zip(alias.columns.iter()) {
+                    *column_name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                }
+                normalize_identifier(&alias.name, &self.name_resolution_ctx).name
+            }
+            None => func_name.clone(),
+        };
+
+        let srf_index = self
+            .metadata
+            .write()
+            .add_derived_column(func_name.clone(), srf_type.clone());
+        let srf_column = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_position: None,
+            table_index: None,
+            column_name: func_name,
+            index: srf_index,
+            data_type: Box::new(srf_type),
+            visibility: Visibility::InVisible,
+            virtual_computed_expr: None,
+        };
+        let project_set = ProjectSet {
+            srfs: vec![SrfItem {
+                scalar: srf_scalar,
+                index: srf_index,
+            }],
+        };
+
+        // Flatten the tuple fields of the srf to the columns of the table function
+        let mut items = Vec::with_capacity(column_names.len());
+        for (i, column_name) in column_names.into_iter().enumerate() {
+            let scalar = ScalarExpr::FunctionCall(FunctionCall {
+                span,
+                func_name: "get".to_string(),
+                params: vec![i + 1],
+                arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span,
+                    column: srf_column.clone(),
+                })],
+            });
+            let data_type = scalar.data_type()?;
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: Some(table_name.clone()),
+                column_position: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+            items.push(ScalarItem { scalar, index });
+        }
+
+        Ok(SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(project_set.into()),
+                Arc::new(s_expr),
+            )),
+        ))
+    }
+}
+
+/// Names of the arguments of `flatten`, in the order of its positional arguments.
+const FLATTEN_ARGUMENTS: [&str; 2] = ["input", "outer"];
+
+/// Resolve the named arguments of a set-returning table function to positional ones,
+/// e.g. `flatten(input => v, outer => true)` is resolved to `flatten(v, true)`.
+fn resolve_srf_named_params(
+    span: Span,
+    func_name: &str,
+    params: &[Expr],
+    named_params: &[(String, Expr)],
+) -> Result<Vec<Expr>> {
+    if named_params.is_empty() {
+        return Ok(params.to_vec());
+    }
+    if func_name != "flatten" {
+        return Err(ErrorCode::SemanticError(format!(
+            "table function {func_name} doesn't accept named arguments"
+        ))
+        .set_span(span));
+    }
+
+    let mut args: Vec<Option<Expr>> = params.iter().cloned().map(Some).collect();
+    args.resize(FLATTEN_ARGUMENTS.len().max(args.len()), None);
+    for (name, expr) in named_params {
+        let Some(pos) = FLATTEN_ARGUMENTS
+            .iter()
+            .position(|arg| arg.eq_ignore_ascii_case(name))
+        else {
+            return Err(ErrorCode::SemanticError(format!(
+                "unknown argument {name} of table function flatten, expected one of {}",
+                FLATTEN_ARGUMENTS.join(", ")
+            ))
+            .set_span(span));
+        };
+        if args[pos].is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "argument {name} of table function flatten is specified more than once"
+            ))
+            .set_span(span));
+        }
+        args[pos] = Some(expr.clone());
+    }
+    if args[0].is_none() {
+        return Err(ErrorCode::SemanticError(
+            "argument input of table function flatten must be specified",
+        )
+        .set_span(span));
+    }
+    Ok(args.into_iter().flatten().collect())
 }
@@ -0,0 +1,205 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableReference;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+
+use crate::binder::CteInfo;
+use crate::binder::WorkingTableInfo;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RecursiveUnion;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::NameResolutionContext;
+use crate::Visibility;
+
+/// Check whether a query refers to the given common table expression.
+struct CteReferenceFinder<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceFinder<'a> {
+    fn visit_table_reference(&mut self, table_ref: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.found = true;
+            }
+        }
+        walk_table_reference(self, table_ref);
+    }
+}
+
+impl Binder {
+    fn refers_to_cte(&self, cte_name: &str, set_expr: &SetExpr) -> bool {
+        let mut finder = CteReferenceFinder {
+            cte_name,
+            name_resolution_ctx: &self.name_resolution_ctx,
+            found: false,
+        };
+        finder.visit_set_expr(set_expr);
+        finder.found
+    }
+
+    /// A CTE declared with `WITH RECURSIVE` is only bound as a recursive CTE if it
+    /// actually refers to itself, otherwise it's an ordinary CTE.
+    pub(super) fn is_recursive_cte(&self, cte_name: &str, cte_info: &CteInfo) -> bool {
+        cte_info.recursive && self.refers_to_cte(cte_name, &cte_info.query.body)
+    }
+
+    /// Bind a recursive CTE of the form `<anchor> UNION [ALL] <recursive member>`.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query: &Query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive CTE {cte_name} must be of the form `<anchor> UNION [ALL] <recursive member>`"
+                ))
+                .set_span(span));
+            }
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not allowed in recursive CTE {cte_name}"
+            ))
+            .set_span(span));
+        }
+        if self.refers_to_cte(cte_name, &set_operation.left) {
+            return Err(ErrorCode::SemanticError(format!(
+                "the anchor member of recursive CTE {cte_name} can't refer to itself"
+            ))
+            .set_span(set_operation.left.span()));
+        }
+
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+
+        // References to the CTE in the recursive member read the working table, which
+        // has the same columns as the anchor member.
+        let cte_idx = self
+            .metadata
+            .write()
+            .add_recursive_cte(cte_name.to_string());
+        let mut recursive_context = bind_context.clone();
+        recursive_context
+            .ctes_map
+            .insert(cte_name.to_string(), CteInfo {
+                working_table: Some(WorkingTableInfo {
+                    cte_idx,
+                    columns: anchor_context.columns.clone(),
+                }),
+                ..cte_info.clone()
+            });
+        let (recursive_expr, recursive_context) = self
+            .bind_set_expr(&mut recursive_context, &set_operation.right, &[], 0)
+            .await?;
+
+        if anchor_context.columns.len() != recursive_context.columns.len() {
+            return Err(ErrorCode::SemanticError(
+                "SetOperation must have the same number of columns",
+            )
+            .set_span(span));
+        }
+
+        let pairs = anchor_context
+            .columns
+            .iter()
+            .zip(recursive_context.columns.iter())
+            .map(|(l, r)| (l.index, r.index))
+            .collect();
+        let recursive_union = RecursiveUnion {
+            cte_idx,
+            cte_name: cte_name.to_string(),
+            pairs,
+            distinct: !set_operation.all,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(recursive_union.into()),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+
+        Ok((s_expr, anchor_context))
+    }
+
+    /// Bind a reference to a recursive CTE from within its recursive member.
+    pub(super) fn bind_cte_working_table(
+        &mut self,
+        bind_context: &BindContext,
+        cte_name: &str,
+        working_table: &WorkingTableInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut bind_context = bind_context.clone();
+        let mut columns = Vec::with_capacity(working_table.columns.len());
+        for column in working_table.columns.iter() {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), *column.data_type.clone());
+            columns.push(index);
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: Some(cte_name.to_string()),
+                column_position: None,
+                table_index: None,
+                column_name: column.column_name.clone(),
+                index,
+                data_type: column.data_type.clone(),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+
+        let scan = RecursiveCteScan {
+            cte_idx: working_table.cte_idx,
+            cte_name: cte_name.to_string(),
+            columns,
+        };
+        Ok((SExpr::create_leaf(Arc::new(scan.into())), bind_context))
+    }
+}
@@ -93,6 +93,11 @@ pub trait ScalarVisitor: Sized {
                                     stack.push(RecursionProcessing::Call(&cast.argument))
                                 }
                                 ScalarExpr::SubqueryExpr(_) => {}
+                                ScalarExpr::LambdaFunction(lambda) => {
+                                    for arg in lambda.args.iter() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                             }
 
                             visitor
@@ -310,6 +310,8 @@ impl Binder {
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -576,6 +578,7 @@ impl Binder {
             right_conditions,
             non_equi_conditions: vec![],
             other_conditions: vec![],
+            is_null_equal: vec![],
         };
         let s_expr = self.bind_join_with_type(join_type, join_conditions, left_expr, right_expr)?;
         Ok((s_expr, left_context))
@@ -734,6 +737,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
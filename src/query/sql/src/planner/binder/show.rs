@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::Identifier;
 use common_ast::ast::ShowLimit;
 use common_exception::Result;
 
+use crate::normalize_identifier;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
 use crate::BindContext;
@@ -50,6 +52,22 @@ impl Binder {
             .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_describe_function(
+        &mut self,
+        bind_context: &mut BindContext,
+        name: &Identifier,
+    ) -> Result<Plan> {
+        // rewrite describe function to select * from system.function_signatures ...
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let query = format!(
+            "SELECT name, arguments, return_type, is_aggregate, volatility, description FROM system.function_signatures WHERE name = '{}'",
+            name.to_lowercase()
+        );
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::DescribeFunction)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_table_functions(
         &mut self,
@@ -19,11 +19,13 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use async_recursion::async_recursion;
+use chrono::Duration;
 use chrono::TimeZone;
 use chrono::Utc;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
-use common_ast::ast::SelectStmt;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
@@ -66,10 +68,17 @@ use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
+use common_storages_stage::ExternalTable;
 use common_storages_stage::StageTable;
+use common_storages_stage::EXTERNAL_ENGINE;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -89,6 +98,7 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::MaterializedViewEntry;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -169,6 +179,7 @@ impl Binder {
                 pivot: _,
                 unpivot: _,
             } => {
+                let is_qualified = catalog.is_some() || database.is_some();
                 let (catalog, database, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
                 let table_alias_name = if let Some(table_alias) = alias {
@@ -183,6 +194,16 @@ impl Binder {
                         .await;
                 }
 
+                // Check and bind result declared in the session, it shadows the table of
+                // the current database with the same name.
+                if !is_qualified {
+                    if let Some(location) = self.ctx.get_declared_result(&table_name) {
+                        return self
+                            .bind_declared_result(bind_context, &table_name, alias, &location)
+                            .await;
+                    }
+                }
+
                 if database == "system" {
                     self.ctx.set_cacheable(false);
                 }
@@ -190,7 +211,19 @@ impl Binder {
                 let tenant = self.ctx.get_tenant();
 
                 let navigation_point = match travel_point {
-                    Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp).await?),
+                    Some(tp) => {
+                        let point = self
+                            .resolve_data_travel_point(
+                                bind_context,
+                                &catalog,
+                                &database,
+                                &table_name,
+                                tp,
+                            )
+                            .await?;
+                        self.check_travel_point_retention(&point)?;
+                        Some(point)
+                    }
                     None => None,
                 };
 
@@ -231,6 +264,10 @@ impl Binder {
                 if !bind_context.planning_agg_index
                     && table_meta.support_index()
                     && table_meta.engine() != "VIEW"
+                    && self
+                        .ctx
+                        .get_settings()
+                        .get_enable_aggregating_index_scan()?
                 {
                     let license_manager = get_license_manager();
                     if license_manager
@@ -267,6 +304,81 @@ impl Binder {
                     }
                 }
 
+                // The materialized views are not used by the queries of views and indexes,
+                // nor by the queries reading history data.
+                let mut materialized_views = vec![];
+                if !bind_context.planning_agg_index
+                    && navigation_point.is_none()
+                    && table_meta.support_index()
+                    && table_meta.engine() != "VIEW"
+                    && self
+                        .ctx
+                        .get_settings()
+                        .get_enable_materialized_view_rewrite()?
+                {
+                    let views = self
+                        .resolve_materialized_views(
+                            tenant.as_str(),
+                            catalog.as_str(),
+                            database.as_str(),
+                            table_meta.as_ref(),
+                        )
+                        .await?;
+                    for (view, query) in views {
+                        let tokens = tokenize_sql(&query)?;
+                        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+                        let Statement::Query(query) = &stmt else {
+                            continue;
+                        };
+                        let mut new_bind_context =
+                            BindContext::with_parent(Box::new(bind_context.clone()));
+                        new_bind_context.planning_agg_index = true;
+                        // Skip the views whose query can not be bound anymore.
+                        let Ok((s_expr, view_bind_context)) =
+                            self.bind_query(&mut new_bind_context, query).await
+                        else {
+                            continue;
+                        };
+                        // Skip the views whose table does not match the query outputs anymore.
+                        let fields = view.schema().fields().clone();
+                        if fields.len() != view_bind_context.columns.len()
+                            || fields
+                                .iter()
+                                .zip(view_bind_context.columns.iter())
+                                .any(|(field, column)| field.name() != &column.column_name)
+                        {
+                            continue;
+                        }
+                        materialized_views.push(MaterializedViewEntry {
+                            catalog: catalog.clone(),
+                            database: database.clone(),
+                            table: view,
+                            plan: s_expr,
+                            output_columns: view_bind_context
+                                .columns
+                                .iter()
+                                .map(|column| column.index)
+                                .collect(),
+                        });
+                    }
+                }
+
+                // The stage and the file format of an external table are resolved on every read,
+                // the table is bound as a base table then.
+                let table_meta = if table_meta.engine() == EXTERNAL_ENGINE {
+                    let info = table_meta.get_table_info();
+                    let (stage_info, path) = self
+                        .resolve_external_table_location(
+                            ExternalTable::location(info)?,
+                            info.meta.storage_params.as_ref(),
+                            &ExternalTable::file_format(info)?,
+                        )
+                        .await?;
+                    ExternalTable::with_stage(info.clone(), stage_info, path)
+                } else {
+                    table_meta
+                };
+
                 match table_meta.engine() {
                     "VIEW" => {
                         Self::check_view_dep(bind_context, &database, &table_name)?;
@@ -324,6 +436,12 @@ impl Binder {
                             bind_context.view_info.is_some(),
                         );
 
+                        if !materialized_views.is_empty() {
+                            self.metadata.write().add_materialized_views(
+                                full_table_name.clone(),
+                                materialized_views,
+                            );
+                        }
                         if !agg_indexes.is_empty() {
                             // Should use bound table id.
                             self.metadata
@@ -347,6 +465,7 @@ impl Binder {
                 params,
                 named_params,
                 alias,
+                ..
             } => {
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
@@ -419,40 +538,25 @@ impl Binder {
                     return Ok((s_expr, bind_context));
                 }
 
-                if BUILTIN_FUNCTIONS
-                    .get_property(&func_name.name)
-                    .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
-                    // If it is a set-returning function, we bind it as a subquery.
-                    let mut bind_context = BindContext::new();
-                    let stmt = SelectStmt {
-                        span: *span,
-                        hints: None,
-                        distinct: false,
-                        select_list: vec![SelectTarget::AliasedExpr {
-                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
-                                span: *span,
-                                distinct: false,
-                                name: common_ast::ast::Identifier {
-                                    span: *span,
-                                    name: func_name.name.clone(),
-                                    quote: None,
-                                },
-                                params: vec![],
-                                args: params.clone(),
-                                window: None,
-                            }),
-                            alias: None,
-                        }],
-                        from: vec![],
-                        selection: None,
-                        group_by: None,
-                        having: None,
-                        window_list: None,
-                    };
-                    self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
-                        .await
+                if is_srf(&func_name.name) {
+                    // If it is a set-returning function, we bind it as a `ProjectSet` over the
+                    // one row table. The arguments can't reference any outer columns unless
+                    // it's on the right side of a `LATERAL` join, see `bind_lateral_join`.
+                    let (s_expr, mut bind_context) =
+                        self.bind_one_table(&BindContext::new(), &vec![]).await?;
+                    bind_context.columns.clear();
+                    let s_expr = self
+                        .bind_srf_table_function(
+                            &mut bind_context,
+                            *span,
+                            name,
+                            params,
+                            named_params,
+                            alias,
+                            s_expr,
+                        )
+                        .await?;
+                    Ok((s_expr, bind_context))
                 } else {
                     // Other table functions always reside is default catalog
                     let table_meta: Arc<dyn TableFunction> = self
@@ -485,9 +589,7 @@ impl Binder {
                 }
             }
             TableReference::Subquery {
-                span: _,
-                subquery,
-                alias,
+                subquery, alias, ..
             } => {
                 // For subquery, we need use a new context to bind it.
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
@@ -540,7 +642,9 @@ impl Binder {
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
                     .await?
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..)
+            | FileFormatParams::Avro(..)
+            | FileFormatParams::Orc(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -551,12 +655,15 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    is_ordered: false,
+                    partition_by: vec![],
+                    file_name_prefix: None,
                 };
                 StageTable::try_create(info)?
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "stage table function only support parquet/NDJson format for now",
+                    "stage table function only support parquet/NDJson/Avro/ORC format for now",
                 ));
             }
         };
@@ -636,6 +743,30 @@ impl Binder {
                     result_expr = join_expr;
                     result_ctx = ctx;
                 }
+                TableReference::TableFunction { lateral: true, .. }
+                | TableReference::Subquery { lateral: true, .. } => {
+                    let (join_expr, ctx) = self
+                        .bind_lateral_join(result_ctx, result_expr, join)
+                        .await?;
+                    result_expr = join_expr;
+                    result_ctx = ctx;
+                }
+                // A set-returning function on the right side of a cross or inner join is
+                // implicitly lateral, e.g. `FROM t, flatten(t.v)`.
+                TableReference::TableFunction { name, .. }
+                    if is_srf(&name.name)
+                        && matches!(
+                            (&join.op, &join.condition),
+                            (JoinOperator::CrossJoin, JoinCondition::None)
+                                | (JoinOperator::Inner, JoinCondition::On(_))
+                        ) =>
+                {
+                    let (join_expr, ctx) = self
+                        .bind_lateral_join(result_ctx, result_expr, join)
+                        .await?;
+                    result_expr = join_expr;
+                    result_ctx = ctx;
+                }
                 _ => {
                     let (right_expr, right_ctx) =
                         self.bind_single_table(current_ctx, &join.right).await?;
@@ -658,6 +789,111 @@ impl Binder {
         Ok((result_expr, result_ctx))
     }
 
+    /// Bind a join whose right side is marked as `LATERAL`, e.g. `FROM t, LATERAL flatten(t.v)`.
+    ///
+    /// The arguments of the table function can reference the columns of the left side,
+    /// so it's bound as a `ProjectSet` over the left side instead of a join.
+    #[async_backtrace::framed]
+    async fn bind_lateral_join(
+        &mut self,
+        mut left_context: BindContext,
+        left_expr: SExpr,
+        join: &Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let (span, name, params, named_params, alias) = match &*join.right {
+            TableReference::TableFunction {
+                span,
+                name,
+                params,
+                named_params,
+                alias,
+                ..
+            } if is_srf(&name.name) => (*span, name, params, named_params, alias),
+            TableReference::TableFunction { span, .. } => {
+                return Err(ErrorCode::SemanticError(
+                    "LATERAL is only supported for set-returning functions, such as unnest and flatten",
+                )
+                .set_span(*span));
+            }
+            TableReference::Subquery { span, .. } => {
+                return Err(
+                    ErrorCode::Unimplemented("LATERAL subqueries are not supported yet")
+                        .set_span(*span),
+                );
+            }
+            _ => unreachable!(),
+        };
+
+        match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin, JoinCondition::None)
+            | (JoinOperator::Inner, JoinCondition::None | JoinCondition::On(_)) => (),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "LATERAL can only be used with CROSS JOIN or INNER JOIN ... ON",
+                )
+                .set_span(span));
+            }
+        }
+
+        let s_expr = self
+            .bind_srf_table_function(
+                &mut left_context,
+                span,
+                name,
+                params,
+                named_params,
+                alias,
+                left_expr,
+            )
+            .await?;
+
+        let s_expr = if let JoinCondition::On(condition) = &join.condition {
+            self.bind_where(&mut left_context, &[], condition, s_expr)
+                .await?
+                .0
+        } else {
+            s_expr
+        };
+        Ok((s_expr, left_context))
+    }
+
+    /// Bind the result declared as `name` by `DECLARE <name> RESULT AS ...` in the session.
+    #[async_backtrace::framed]
+    async fn bind_declared_result(
+        &mut self,
+        bind_context: &BindContext,
+        name: &str,
+        alias: &Option<TableAlias>,
+        location: &str,
+    ) -> Result<(SExpr, BindContext)> {
+        // The declared result may be declared again with different data.
+        self.ctx.set_cacheable(false);
+
+        let op = DataOperator::instance().operator();
+        let (table_schema, block_raw_data) =
+            ResultCacheReader::read_table_schema_and_data(op, location).await?;
+        let table = ResultScan::try_create_declared(name, table_schema, block_raw_data)?;
+
+        let table_alias_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name);
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table,
+            table_alias_name,
+            false,
+        );
+
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((s_expr, bind_context))
+    }
+
     #[async_backtrace::framed]
     async fn bind_cte(
         &mut self,
@@ -681,9 +917,15 @@ impl Binder {
             planning_agg_index: false,
             window_definitions: DashMap::new(),
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut new_bind_context) = if let Some(working_table) = &cte_info.working_table {
+            self.bind_cte_working_table(&new_bind_context, table_name, working_table)?
+        } else if self.is_recursive_cte(table_name, cte_info) {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -824,6 +1066,23 @@ impl Binder {
 
         if let Some(tp) = travel_point {
             table_meta = table_meta.navigate_to(tp).await?;
+            return Ok(table_meta);
+        }
+
+        // Pin the table to the snapshot at the logical time of the query, and reuse the
+        // snapshot if the table has been pinned by the query, so that all the tables and
+        // the references to the same table are consistent under concurrent commits.
+        let key = format!("{catalog_name}.{database_name}.{table_name}");
+        if let Some(snapshot_id) = self.ctx.get_pinned_snapshots().get(&key) {
+            let point = NavigationPoint::SnapshotID(snapshot_id.clone());
+            return table_meta.navigate_to(&point).await;
+        }
+        if let Some((table, snapshot_id)) = table_meta
+            .pin_snapshot(self.ctx.get_snapshot_pin_time())
+            .await?
+        {
+            self.ctx.add_pinned_snapshot(key, snapshot_id);
+            table_meta = table;
         }
         Ok(table_meta)
     }
@@ -832,10 +1091,32 @@ impl Binder {
     pub(crate) async fn resolve_data_travel_point(
         &self,
         bind_context: &mut BindContext,
+        catalog_name: &str,
+        database_name: &str,
+        table_name: &str,
         travel_point: &TimeTravelPoint,
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Stream {
+                catalog,
+                database,
+                name,
+            } => {
+                let tenant = self.ctx.get_tenant();
+                let table = self
+                    .ctx
+                    .get_table(catalog_name, database_name, table_name)
+                    .await?;
+                let (catalog, database, name) =
+                    self.normalize_object_identifier_triple(catalog, database, name);
+                let stream = self
+                    .catalogs
+                    .get_catalog(&catalog)?
+                    .get_table(tenant.as_str(), &database, &name)
+                    .await?;
+                stream.resolve_navigation_point(table.get_id()).await
+            }
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::new(
                     bind_context,
@@ -872,6 +1153,22 @@ impl Binder {
         }
     }
 
+    /// The data older than the retention period may have been removed by `VACUUM`,
+    /// so the table can not be read at a time point beyond it.
+    fn check_travel_point_retention(&self, point: &NavigationPoint) -> Result<()> {
+        if let NavigationPoint::TimePoint(time_point) = point {
+            let retention_period = self.ctx.get_settings().get_retention_period()?;
+            let retention_time = Utc::now() - Duration::hours(retention_period as i64);
+            if *time_point < retention_time {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Time travel point {} is beyond the data retention period of {} hours",
+                    time_point, retention_period
+                )));
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn resolve_table_indexes(
         &self,
@@ -886,6 +1183,64 @@ impl Binder {
 
         Ok(index_metas)
     }
+
+    /// Resolve the materialized views of the table which are fresh enough to be read in place of it,
+    /// returns their tables and queries.
+    ///
+    /// A view is fresh if it was refreshed from the current snapshot of the table,
+    /// or if it was refreshed within the seconds of `materialized_view_staleness_tolerance`.
+    #[async_backtrace::framed]
+    pub(crate) async fn resolve_materialized_views(
+        &self,
+        tenant: &str,
+        catalog_name: &str,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<Vec<(Arc<dyn Table>, String)>> {
+        let catalog = self.catalogs.get_catalog(catalog_name)?;
+        let table_id = table.get_id().to_string();
+        let snapshot_location = table
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned()
+            .unwrap_or_default();
+        let tolerance = self
+            .ctx
+            .get_settings()
+            .get_materialized_view_staleness_tolerance()?;
+        let now = Utc::now().timestamp() as u64;
+
+        let mut views = vec![];
+        for view in catalog.list_tables(tenant, database).await? {
+            let options = view.options();
+            if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID) != Some(&table_id) {
+                continue;
+            }
+            // The views never refreshed have no data.
+            let (Some(query), Some(refreshed_on)) = (
+                options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+                options
+                    .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON)
+                    .and_then(|v| v.parse::<u64>().ok()),
+            ) else {
+                continue;
+            };
+            let up_to_date =
+                options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT) == Some(&snapshot_location);
+            if up_to_date || now.saturating_sub(refreshed_on) <= tolerance {
+                views.push((view.clone(), query.clone()));
+            }
+        }
+
+        Ok(views)
+    }
+}
+
+fn is_srf(func_name: &str) -> bool {
+    BUILTIN_FUNCTIONS
+        .get_property(func_name)
+        .map(|p| p.kind == FunctionKind::SRF)
+        .unwrap_or(false)
 }
 
 // copy from common-storages-fuse to avoid cyclic dependency.
@@ -76,13 +76,35 @@ impl Plan {
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
             // Views
+            Plan::DeclareResult(declare_result) => Ok(format!("{:?}", declare_result)),
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(format!("{:?}", create_view)),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(format!("{:?}", refresh_view)),
+            Plan::DropMaterializedView(drop_view) => Ok(format!("{:?}", drop_view)),
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
+
+            // Tasks
+            Plan::CreateTask(create_task) => Ok(format!("{:?}", create_task)),
+            Plan::AlterTask(alter_task) => Ok(format!("{:?}", alter_task)),
+            Plan::DropTask(drop_task) => Ok(format!("{:?}", drop_task)),
+
+            // Plan baselines
+            Plan::AlterStatement(alter_statement) => Ok(format!("{:?}", alter_statement)),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
+            Plan::CreateBloomIndex(index) => Ok(format!("{:?}", index)),
+            Plan::DropBloomIndex(index) => Ok(format!("{:?}", index)),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{:?}", index)),
+            Plan::DropInvertedIndex(index) => Ok(format!("{:?}", index)),
 
             // Virtual Columns
             Plan::CreateVirtualColumns(create_virtual_columns) => {
@@ -103,6 +125,17 @@ impl Plan {
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
+
+            // Prepared commits
+            Plan::PrepareCommit(p) => Ok(format!("{:?}", p)),
+            Plan::CommitPrepared(p) => Ok(format!("{:?}", p)),
+            Plan::RollbackPrepared(p) => Ok(format!("{:?}", p)),
+
+            // Transactions
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
@@ -151,6 +184,7 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(p) => Ok(format!("{:?}", p)),
             Plan::ShowGrantTenantsOfShare(p) => Ok(format!("{:?}", p)),
             Plan::RevertTable(p) => Ok(format!("{:?}", p)),
+            Plan::AmendTable(p) => Ok(format!("{:?}", p)),
 
             // data mask
             Plan::CreateDatamaskPolicy(p) => Ok(format!("{:?}", p)),
@@ -59,6 +59,15 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// The materialized views which may be read in place of the tables, indexed by the full table names.
+    materialized_views: HashMap<String, Vec<MaterializedViewEntry>>,
+    /// The tables of the materialized views read in place of the tables of the query.
+    materialized_view_tables: HashSet<IndexType>,
+    /// Names of the recursive CTEs, indexed by the id of their working tables.
+    recursive_ctes: Vec<String>,
+    /// The fingerprint of the query, under which the actual cardinalities of its operators
+    /// are recorded. Set only if cardinality feedback is enabled.
+    plan_fingerprint: Option<String>,
 }
 
 impl Metadata {
@@ -70,6 +79,15 @@ impl Metadata {
         self.tables.as_slice()
     }
 
+    /// Replace the table of the entry, e.g. with the latest version of the table read after it's locked.
+    pub fn replace_table(&mut self, index: IndexType, table: Arc<dyn Table>) {
+        let entry = self
+            .tables
+            .get_mut(index)
+            .expect("metadata must contain table");
+        entry.table = table;
+    }
+
     pub fn table_index_by_column_indexes(&self, column_indexes: &ColumnSet) -> Option<IndexType> {
         self.columns.iter().find_map(|v| match v {
             ColumnEntry::BaseTableColumn(BaseTableColumn {
@@ -123,6 +141,14 @@ impl Metadata {
         &self.lazy_columns
     }
 
+    pub fn set_plan_fingerprint(&mut self, fingerprint: String) {
+        self.plan_fingerprint = Some(fingerprint);
+    }
+
+    pub fn plan_fingerprint(&self) -> Option<&str> {
+        self.plan_fingerprint.as_deref()
+    }
+
     pub fn columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
@@ -233,6 +259,40 @@ impl Metadata {
             .or_insert(agg_indexes);
     }
 
+    pub fn add_materialized_views(&mut self, table: String, views: Vec<MaterializedViewEntry>) {
+        self.materialized_views
+            .entry(table)
+            .and_modify(|entries| entries.extend_from_slice(&views))
+            .or_insert(views);
+    }
+
+    pub fn get_materialized_views(&self, table: &str) -> Option<&[MaterializedViewEntry]> {
+        self.materialized_views.get(table).map(|v| v.as_slice())
+    }
+
+    /// Add the table of a materialized view read in place of a table of the query.
+    pub fn add_materialized_view_table(&mut self, view: &MaterializedViewEntry) -> IndexType {
+        let table_index = self.add_table(
+            view.catalog.clone(),
+            view.database.clone(),
+            view.table.clone(),
+            None,
+            false,
+        );
+        self.materialized_view_tables.insert(table_index);
+        table_index
+    }
+
+    pub fn is_materialized_view_table(&self, table_index: IndexType) -> bool {
+        self.materialized_view_tables.contains(&table_index)
+    }
+
+    pub fn add_recursive_cte(&mut self, cte_name: String) -> IndexType {
+        let cte_idx = self.recursive_ctes.len();
+        self.recursive_ctes.push(cte_name);
+        cte_idx
+    }
+
     pub fn get_agg_indexes(&self, table: &str) -> Option<&[(u64, String, SExpr)]> {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
@@ -426,6 +486,28 @@ impl TableEntry {
     }
 }
 
+/// A materialized view of a table, whose data can answer the queries matching its query.
+#[derive(Clone)]
+pub struct MaterializedViewEntry {
+    pub catalog: String,
+    pub database: String,
+    pub table: Arc<dyn Table>,
+    /// The plan of the query of the view.
+    pub plan: SExpr,
+    /// The indexes of the columns output by the plan, in the order of the columns of the view.
+    pub output_columns: Vec<IndexType>,
+}
+
+impl Debug for MaterializedViewEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaterializedViewEntry")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("name", &self.table.name())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BaseTableColumn {
     pub table_index: IndexType,
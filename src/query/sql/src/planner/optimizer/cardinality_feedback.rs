@@ -0,0 +1,260 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cardinality feedback: the actual cardinalities of the operators of a query are recorded
+//! from its profile, and are used in place of the estimated ones when a query of the same
+//! fingerprint is planned again.
+//!
+//! An operator is identified in both the logical and the physical plans by a key built from
+//! the operators below it, e.g. `Filter(Scan(t0))`. The key of a join is the set of tables it
+//! joins, so it is kept when the join order changes.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_profile::ProfSpanSet;
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+
+use crate::executor::PhysicalPlan;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::optimizer::StatInfo;
+use crate::plans::AggregateMode;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::DUMMY_TABLE_INDEX;
+
+/// The actual cardinalities of the operators of a query, indexed by the keys of the operators.
+pub type Cardinalities = HashMap<String, f64>;
+
+/// Node-local cardinality feedback of the recently run queries, indexed by their fingerprints.
+pub struct CardinalityFeedback {
+    /// The cardinalities and the sequence number of the run recording them.
+    queries: RwLock<HashMap<String, (u64, Arc<Cardinalities>)>>,
+    next_seq: AtomicU64,
+}
+
+static CARDINALITY_FEEDBACK: OnceCell<Arc<CardinalityFeedback>> = OnceCell::new();
+
+impl CardinalityFeedback {
+    /// The max number of queries to keep, the least recently recorded ones are evicted.
+    const MAX_QUERIES: usize = 1024;
+
+    pub fn instance() -> Arc<CardinalityFeedback> {
+        CARDINALITY_FEEDBACK
+            .get_or_init(|| {
+                Arc::new(CardinalityFeedback {
+                    queries: RwLock::new(HashMap::new()),
+                    next_seq: AtomicU64::new(0),
+                })
+            })
+            .clone()
+    }
+
+    pub fn get(&self, fingerprint: &str) -> Option<Arc<Cardinalities>> {
+        let queries = self.queries.read();
+        queries
+            .get(fingerprint)
+            .map(|(_, cardinalities)| cardinalities.clone())
+    }
+
+    /// Records the actual cardinalities of the operators of `plan` in the profile of its run.
+    ///
+    /// The operators below a `LIMIT` are left out, as they may stop before producing all rows.
+    pub fn record(&self, fingerprint: &str, plan: &PhysicalPlan, spans: &ProfSpanSet) {
+        let mut cardinalities = Cardinalities::new();
+        collect_actual_cardinalities(plan, spans, &mut cardinalities);
+        if cardinalities.is_empty() {
+            return;
+        }
+
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        let mut queries = self.queries.write();
+        if queries.len() >= Self::MAX_QUERIES && !queries.contains_key(fingerprint) {
+            let oldest = queries
+                .iter()
+                .min_by_key(|(_, (seq, _))| *seq)
+                .map(|(fingerprint, _)| fingerprint.clone());
+            if let Some(oldest) = oldest {
+                queries.remove(&oldest);
+            }
+        }
+        queries.insert(fingerprint.to_string(), (seq, Arc::new(cardinalities)));
+    }
+}
+
+/// The key of an operator, with the tables read below it.
+struct OperatorKey {
+    key: String,
+    tables: BTreeSet<IndexType>,
+}
+
+impl OperatorKey {
+    fn scan(table_index: IndexType) -> Self {
+        OperatorKey {
+            key: format!("Scan(t{})", table_index),
+            tables: BTreeSet::from([table_index]),
+        }
+    }
+
+    fn wrap(self, name: &str) -> Self {
+        OperatorKey {
+            key: format!("{}({})", name, self.key),
+            tables: self.tables,
+        }
+    }
+
+    fn join(left: Self, right: Self) -> Self {
+        let tables: BTreeSet<IndexType> = left.tables.into_iter().chain(right.tables).collect();
+        let key = format!(
+            "Join({})",
+            tables
+                .iter()
+                .map(|table| format!("t{}", table))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        OperatorKey { key, tables }
+    }
+
+    /// Only the cardinalities of filters, joins and aggregations are fed back, the others
+    /// are estimated well from their inputs.
+    fn is_fed_back(&self) -> bool {
+        !self.key.starts_with("Scan(")
+    }
+}
+
+fn is_mark_join(join_type: &JoinType) -> bool {
+    matches!(join_type, JoinType::LeftMark | JoinType::RightMark)
+}
+
+/// Replaces the estimated cardinalities of the operators in `s_expr` with the actual ones
+/// in `cardinalities`. The operators above them are estimated again from them.
+pub fn apply_cardinality_feedback(s_expr: &SExpr, cardinalities: &Cardinalities) -> Result<()> {
+    apply_feedback(s_expr, cardinalities)?;
+    Ok(())
+}
+
+fn apply_feedback(s_expr: &SExpr, cardinalities: &Cardinalities) -> Result<Option<OperatorKey>> {
+    let mut children = Vec::with_capacity(s_expr.arity());
+    for child in s_expr.children() {
+        children.push(apply_feedback(child, cardinalities)?);
+    }
+
+    let key = match (s_expr.plan(), children.as_mut_slice()) {
+        (RelOperator::Scan(scan), []) => Some(OperatorKey::scan(scan.table_index)),
+        (RelOperator::Filter(_), [child]) => child.take().map(|child| child.wrap("Filter")),
+        (RelOperator::Join(join), [left, right]) if !is_mark_join(&join.join_type) => {
+            match (left.take(), right.take()) {
+                (Some(left), Some(right)) => Some(OperatorKey::join(left, right)),
+                _ => None,
+            }
+        }
+        (RelOperator::Aggregate(agg), [child]) => match agg.mode {
+            AggregateMode::Partial => child.take(),
+            AggregateMode::Final | AggregateMode::Initial => {
+                child.take().map(|child| child.wrap("Aggregate"))
+            }
+        },
+        (RelOperator::EvalScalar(_), [child])
+        | (RelOperator::Sort(_), [child])
+        | (RelOperator::Exchange(_), [child]) => child.take(),
+        _ => None,
+    };
+
+    // Drop the estimation cached before the feedback of the children is applied.
+    *s_expr.stat_info.lock().unwrap() = None;
+    if let Some(actual) = key
+        .as_ref()
+        .filter(|key| key.is_fed_back())
+        .and_then(|key| cardinalities.get(&key.key))
+    {
+        let estimated = RelExpr::with_s_expr(s_expr).derive_cardinality()?;
+        *s_expr.stat_info.lock().unwrap() = Some(Arc::new(StatInfo {
+            cardinality: *actual,
+            statistics: estimated.statistics.clone(),
+        }));
+    }
+    Ok(key)
+}
+
+fn collect_actual_cardinalities(
+    plan: &PhysicalPlan,
+    spans: &ProfSpanSet,
+    cardinalities: &mut Cardinalities,
+) -> Option<OperatorKey> {
+    let (key, plan_id) = match plan {
+        PhysicalPlan::TableScan(scan) if scan.table_index != DUMMY_TABLE_INDEX => {
+            (Some(OperatorKey::scan(scan.table_index)), scan.plan_id)
+        }
+        PhysicalPlan::Filter(filter) => (
+            collect_actual_cardinalities(&filter.input, spans, cardinalities)
+                .map(|child| child.wrap("Filter")),
+            filter.plan_id,
+        ),
+        PhysicalPlan::HashJoin(join) => {
+            let probe = collect_actual_cardinalities(&join.probe, spans, cardinalities);
+            let build = collect_actual_cardinalities(&join.build, spans, cardinalities);
+            let key = match (probe, build) {
+                (Some(probe), Some(build)) if !is_mark_join(&join.join_type) => {
+                    Some(OperatorKey::join(probe, build))
+                }
+                _ => None,
+            };
+            (key, join.plan_id)
+        }
+        PhysicalPlan::AggregateFinal(agg) => (
+            collect_actual_cardinalities(&agg.input, spans, cardinalities)
+                .map(|child| child.wrap("Aggregate")),
+            agg.plan_id,
+        ),
+        PhysicalPlan::DistinctFinal(distinct) => (
+            collect_actual_cardinalities(&distinct.input, spans, cardinalities)
+                .map(|child| child.wrap("Aggregate")),
+            distinct.plan_id,
+        ),
+        PhysicalPlan::AggregatePartial(agg) => {
+            return collect_actual_cardinalities(&agg.input, spans, cardinalities);
+        }
+        PhysicalPlan::DistinctPartial(distinct) => {
+            return collect_actual_cardinalities(&distinct.input, spans, cardinalities);
+        }
+        PhysicalPlan::EvalScalar(eval_scalar) => {
+            return collect_actual_cardinalities(&eval_scalar.input, spans, cardinalities);
+        }
+        PhysicalPlan::Sort(sort) => {
+            return collect_actual_cardinalities(&sort.input, spans, cardinalities);
+        }
+        PhysicalPlan::Limit(_) => return None,
+        _ => {
+            for child in plan.children() {
+                collect_actual_cardinalities(child, spans, cardinalities);
+            }
+            return None;
+        }
+    };
+
+    if let (Some(key), Some(span)) = (&key, spans.get(&plan_id)) {
+        if key.is_fed_back() {
+            cardinalities.insert(key.key.clone(), span.output_rows as f64);
+        }
+    }
+    key
+}
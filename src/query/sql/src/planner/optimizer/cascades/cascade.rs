@@ -67,6 +67,13 @@ impl CascadesOptimizer {
         })
     }
 
+    /// Disable the exploration rules, so the join order and the sides of joins are kept,
+    /// e.g. they are pinned by a plan baseline.
+    pub fn keep_join_shape(mut self) -> Self {
+        self.explore_rule_set = RuleSet::create();
+        self
+    }
+
     fn init(&mut self, expression: SExpr) -> Result<()> {
         self.memo.init(expression)?;
 
@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
@@ -179,6 +180,7 @@ impl SubqueryRewriter {
             left_conditions,
             right_conditions,
             non_equi_conditions,
+            is_null_equal: vec![],
             join_type: match &subquery.typ {
                 SubqueryType::Any | SubqueryType::All | SubqueryType::Scalar => {
                     return Ok(None);
@@ -263,6 +265,7 @@ impl SubqueryRewriter {
                     left_conditions,
                     right_conditions,
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
                     from_correlated_subquery: true,
@@ -306,6 +309,7 @@ impl SubqueryRewriter {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
@@ -368,6 +372,7 @@ impl SubqueryRewriter {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions,
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
@@ -430,6 +435,7 @@ impl SubqueryRewriter {
                 left_conditions: vec![],
                 right_conditions: vec![],
                 non_equi_conditions: vec![],
+                is_null_equal: vec![],
                 join_type: JoinType::Cross,
                 marker_index: None,
                 from_correlated_subquery: false,
@@ -548,6 +554,7 @@ impl SubqueryRewriter {
                             left_conditions: join.left_conditions.clone(),
                             right_conditions: join.right_conditions.clone(),
                             non_equi_conditions: join.non_equi_conditions.clone(),
+                            is_null_equal: join.is_null_equal.clone(),
                             join_type: join.join_type.clone(),
                             marker_index: join.marker_index,
                             from_correlated_subquery: false,
@@ -779,6 +786,21 @@ impl SubqueryRewriter {
                     target_type: cast_expr.target_type.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.flatten_scalar(arg, correlated_columns))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             _ => Err(ErrorCode::Internal(
                 "Invalid scalar for flattening subquery",
             )),
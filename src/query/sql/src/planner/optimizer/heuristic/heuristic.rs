@@ -48,7 +48,8 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
         RuleID::FoldCountAggregate,
-        RuleID::TryApplyAggIndex, // TryApplyAggIndex should before SplitAggregate
+        RuleID::TryApplyMaterializedView, // TryApplyMaterializedView should before SplitAggregate
+        RuleID::TryApplyAggIndex,         // TryApplyAggIndex should before SplitAggregate
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
@@ -24,6 +24,7 @@ use crate::plans::Aggregate;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
+use crate::plans::UnionAll;
 use crate::ColumnEntry;
 use crate::MetadataRef;
 
@@ -192,20 +193,24 @@ impl UnusedColumnPruner {
                 }
             }
             RelOperator::Window(p) => {
-                if required.contains(&p.index) {
-                    // The scalar items in window function is not replaced yet.
-                    // The will be replaced in physical plan builder.
-                    p.arguments.iter().for_each(|item| {
-                        required.extend(item.scalar.used_columns());
-                    });
-                    p.partition_by.iter().for_each(|item| {
-                        required.extend(item.scalar.used_columns());
-                    });
-                    p.order_by.iter().for_each(|item| {
-                        required.extend(item.order_by_item.scalar.used_columns());
-                    });
+                if !required.contains(&p.index) {
+                    // A window function never changes the cardinality of its input,
+                    // so it can be removed if its result is not used.
+                    return self.keep_required_columns(expr.child(0)?, required);
                 }
 
+                // The scalar items in window function is not replaced yet.
+                // The will be replaced in physical plan builder.
+                p.arguments.iter().for_each(|item| {
+                    required.extend(item.scalar.used_columns());
+                });
+                p.partition_by.iter().for_each(|item| {
+                    required.extend(item.scalar.used_columns());
+                });
+                p.order_by.iter().for_each(|item| {
+                    required.extend(item.order_by_item.scalar.used_columns());
+                });
+
                 Ok(SExpr::create_unary(
                     Arc::new(RelOperator::Window(p.clone())),
                     Arc::new(self.keep_required_columns(expr.child(0)?, required)?),
@@ -226,16 +231,23 @@ impl UnusedColumnPruner {
             )),
 
             RelOperator::UnionAll(p) => {
-                let left_used = p.pairs.iter().fold(required.clone(), |mut acc, v| {
-                    acc.insert(v.0);
-                    acc
-                });
-                let right_used = p.pairs.iter().fold(required, |mut acc, v| {
-                    acc.insert(v.1);
-                    acc
-                });
+                // The output columns of UNION ALL are the columns of its left child,
+                // so only the pairs whose left column is required need to be kept.
+                // At least one pair is kept to preserve the row count.
+                let mut pairs = p
+                    .pairs
+                    .iter()
+                    .filter(|(left, _)| required.contains(left))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if pairs.is_empty() && !p.pairs.is_empty() {
+                    pairs.push(p.pairs[0]);
+                }
+
+                let left_used = pairs.iter().map(|v| v.0).collect();
+                let right_used = pairs.iter().map(|v| v.1).collect();
                 Ok(SExpr::create_binary(
-                    Arc::new(RelOperator::UnionAll(p.clone())),
+                    Arc::new(RelOperator::UnionAll(UnionAll { pairs })),
                     Arc::new(self.keep_required_columns(expr.child(0)?, left_used)?),
                     Arc::new(self.keep_required_columns(expr.child(1)?, right_used)?),
                 ))
@@ -254,7 +266,19 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::RecursiveUnion(p) => {
+                // Every column feeds the working table of the next iteration, so none of
+                // them can be pruned.
+                let left_used = p.pairs.iter().map(|v| v.0).collect();
+                let right_used = p.pairs.iter().map(|v| v.1).collect();
+                Ok(SExpr::create_binary(
+                    Arc::new(RelOperator::RecursiveUnion(p.clone())),
+                    Arc::new(self.keep_required_columns(expr.child(0)?, left_used)?),
+                    Arc::new(self.keep_required_columns(expr.child(1)?, right_used)?),
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -143,18 +144,24 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
-                Arc::new(s_expr.plan().clone()),
-                Arc::new(self.rewrite(s_expr.child(0)?)?),
-                Arc::new(self.rewrite(s_expr.child(1)?)?),
-            )),
+            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::RecursiveUnion(_) => {
+                Ok(SExpr::create_binary(
+                    Arc::new(s_expr.plan().clone()),
+                    Arc::new(self.rewrite(s_expr.child(0)?)?),
+                    Arc::new(self.rewrite(s_expr.child(1)?)?),
+                ))
+            }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
-                Arc::new(s_expr.plan().clone()),
-                Arc::new(self.rewrite(s_expr.child(0)?)?),
-            )),
+            RelOperator::Limit(_) | RelOperator::Sort(_) | RelOperator::ProjectSet(_) => {
+                Ok(SExpr::create_unary(
+                    Arc::new(s_expr.plan().clone()),
+                    Arc::new(self.rewrite(s_expr.child(0)?)?),
+                ))
+            }
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::Scan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -192,6 +199,27 @@ impl SubqueryRewriter {
 
                 Ok((expr, s_expr))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let (scalar, s_expr) = self.try_rewrite_subquery(&cast.argument, s_expr, false)?;
                 Ok((
@@ -339,6 +367,7 @@ impl SubqueryRewriter {
                     left_conditions: vec![],
                     right_conditions: vec![],
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Single,
                     marker_index: None,
                     from_correlated_subquery: false,
@@ -441,6 +470,7 @@ impl SubqueryRewriter {
                     left_conditions: vec![],
                     right_conditions: vec![],
                     non_equi_conditions: vec![],
+                    is_null_equal: vec![],
                     join_type: JoinType::Cross,
                     marker_index: None,
                     from_correlated_subquery: false,
@@ -512,6 +542,7 @@ impl SubqueryRewriter {
                     left_conditions: right_conditions,
                     right_conditions: left_conditions,
                     non_equi_conditions,
+                    is_null_equal: vec![],
                     join_type: JoinType::RightMark,
                     marker_index: Some(marker_index),
                     from_correlated_subquery: false,
@@ -542,6 +573,15 @@ pub fn check_child_expr_in_subquery(
             let (_, is_non_equi_condition) = check_child_expr_in_subquery(arg, op)?;
             Ok((child_expr.clone(), is_non_equi_condition))
         }
+        // The row of a row-value comparison, such as `(a, b) in (select x, y from t)`.
+        // The rows are compared as non-equi condition, because the nulls of the fields
+        // are not handled by the hash keys.
+        ScalarExpr::FunctionCall(func) if func.func_name == "tuple" => {
+            for arg in func.arguments.iter() {
+                check_child_expr_in_subquery(arg, op)?;
+            }
+            Ok((child_expr.clone(), true))
+        }
         other => Err(ErrorCode::Internal(format!(
             "Invalid child expr in subquery: {:?}",
             other
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_base::runtime::Thread;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_meta_app::principal::PlanShape;
 
 use crate::optimizer::hyper_dp::join_node::JoinNode;
 use crate::optimizer::hyper_dp::join_relation::JoinRelation;
@@ -52,6 +53,8 @@ pub struct DPhpy {
     query_graph: QueryGraph,
     relation_set_tree: RelationSetTree,
     filters: HashSet<Filter>,
+    // The join tree pinned by a plan baseline, which replaces the enumeration if it covers all relations
+    pinned_shape: Option<Arc<PlanShape>>,
 }
 
 impl DPhpy {
@@ -65,9 +68,15 @@ impl DPhpy {
             query_graph: QueryGraph::new(),
             relation_set_tree: Default::default(),
             filters: HashSet::new(),
+            pinned_shape: None,
         }
     }
 
+    pub fn with_pinned_shape(mut self, pinned_shape: Option<Arc<PlanShape>>) -> Self {
+        self.pinned_shape = pinned_shape;
+        self
+    }
+
     // Traverse the s_expr and get all base relations and join conditions
     fn get_base_relations(
         &mut self,
@@ -84,7 +93,8 @@ impl DPhpy {
 
         if is_subquery {
             // If it's a subquery, start a new dphyp
-            let mut dphyp = DPhpy::new(self.ctx.clone(), self.metadata.clone());
+            let mut dphyp = DPhpy::new(self.ctx.clone(), self.metadata.clone())
+                .with_pinned_shape(self.pinned_shape.clone());
             let (new_s_expr, optimized) = dphyp.optimize(s_expr)?;
             if optimized {
                 let relation_idx = self.join_relations.len() as IndexType;
@@ -118,6 +128,9 @@ impl DPhpy {
                 {
                     is_inner_join = false;
                 }
+                if op.has_null_equal_conditions() {
+                    is_inner_join = false;
+                }
                 let mut left_is_subquery = false;
                 let mut right_is_subquery = false;
                 // Fixme: If join's child is EvalScalar, we think it is a subquery.
@@ -145,16 +158,18 @@ impl DPhpy {
                     // Parallel process subquery
                     let ctx = self.ctx.clone();
                     let metadata = self.metadata.clone();
+                    let pinned_shape = self.pinned_shape.clone();
                     let left_expr = s_expr.children()[0].clone();
                     let left_res = Thread::spawn(move || {
-                        let mut dphyp = DPhpy::new(ctx, metadata);
+                        let mut dphyp = DPhpy::new(ctx, metadata).with_pinned_shape(pinned_shape);
                         (dphyp.optimize(left_expr), dphyp.table_index_map)
                     });
                     let ctx = self.ctx.clone();
                     let metadata = self.metadata.clone();
+                    let pinned_shape = self.pinned_shape.clone();
                     let right_expr = s_expr.children()[1].clone();
                     let right_res = Thread::spawn(move || {
-                        let mut dphyp = DPhpy::new(ctx, metadata);
+                        let mut dphyp = DPhpy::new(ctx, metadata).with_pinned_shape(pinned_shape);
                         (dphyp.optimize(right_expr), dphyp.table_index_map)
                     });
                     let left_res = left_res.join()?;
@@ -231,7 +246,9 @@ impl DPhpy {
             RelOperator::Exchange(_) | RelOperator::Pattern(_) => unreachable!(),
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
+            | RelOperator::RecursiveUnion(_)
             | RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::RuntimeFilterSource(_) => Ok((s_expr, false)),
         }
     }
@@ -290,7 +307,12 @@ impl DPhpy {
                 continue;
             }
         }
-        let optimized = self.solve()?;
+        let optimized = if self.solve_pinned()? {
+            true
+        } else {
+            self.dp_table.clear();
+            self.solve()?
+        };
         // Get all join relations in `relation_set_tree`
         let all_relations = self
             .relation_set_tree
@@ -307,9 +329,8 @@ impl DPhpy {
         }
     }
 
-    // This method will run dynamic programming algorithm to find the optimal join order
-    fn solve(&mut self) -> Result<bool> {
-        // Initial `dp_table` with plan for single relation
+    // Initial `dp_table` with plan for single relation
+    fn init_dp_table(&mut self) -> Result<()> {
         for (idx, relation) in self.join_relations.iter().enumerate() {
             // Get nodes  in `relation_set_tree`
             let nodes = self.relation_set_tree.get_relation_set_by_index(idx)?;
@@ -325,6 +346,12 @@ impl DPhpy {
             };
             let _ = self.dp_table.insert(nodes, join);
         }
+        Ok(())
+    }
+
+    // This method will run dynamic programming algorithm to find the optimal join order
+    fn solve(&mut self) -> Result<bool> {
+        self.init_dp_table()?;
 
         // Choose all nodes as enumeration start node once (desc order)
         for idx in (0..self.join_relations.len()).rev() {
@@ -346,6 +373,72 @@ impl DPhpy {
         Ok(true)
     }
 
+    // Rebuild the join tree pinned by the plan baseline instead of enumerating join orders.
+    // The joins keep the probe and build sides of the pinned plan regardless of the cardinalities.
+    // Return false if the pinned joins don't cover all relations, e.g. the query has been changed.
+    fn solve_pinned(&mut self) -> Result<bool> {
+        let pinned_shape = match &self.pinned_shape {
+            Some(pinned_shape) => pinned_shape.clone(),
+            None => return Ok(false),
+        };
+        self.init_dp_table()?;
+
+        // Joins are in post-order, so both sides of a join have been built before it
+        for join in pinned_shape.joins.iter() {
+            let (left, right) = match (
+                self.pinned_relation_set(&join.probe)?,
+                self.pinned_relation_set(&join.build)?,
+            ) {
+                (Some(left), Some(right)) => (left, right),
+                // The join is inside a relation, or outside the current join tree
+                _ => continue,
+            };
+            let parent_set = union(&left, &right);
+            if intersect(&left, &right)
+                || self.dp_table.contains_key(&parent_set)
+                || !self.dp_table.contains_key(&left)
+                || !self.dp_table.contains_key(&right)
+            {
+                continue;
+            }
+            let join_conditions = self.query_graph.is_connected(&left, &right)?;
+            let join_node = JoinNode {
+                join_type: if join_conditions.is_empty() {
+                    JoinType::Cross
+                } else {
+                    JoinType::Inner
+                },
+                leaves: Arc::new(parent_set.clone()),
+                children: Arc::new(vec![
+                    self.dp_table[&left].clone(),
+                    self.dp_table[&right].clone(),
+                ]),
+                join_conditions: Arc::new(join_conditions),
+                cost: 0.0,
+                cardinality: None,
+                s_expr: None,
+            };
+            self.dp_table.insert(parent_set, join_node);
+        }
+
+        let all_relations = self
+            .relation_set_tree
+            .get_relation_set(&(0..self.join_relations.len()).collect())?;
+        Ok(self.dp_table.contains_key(&all_relations))
+    }
+
+    // Map the table indexes of a pinned join side to the relations containing them
+    fn pinned_relation_set(&mut self, tables: &[IndexType]) -> Result<Option<Vec<IndexType>>> {
+        let relations: HashSet<IndexType> = tables
+            .iter()
+            .filter_map(|table| self.table_index_map.get(table).copied())
+            .collect();
+        if relations.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.relation_set_tree.get_relation_set(&relations)?))
+    }
+
     // EmitCsg will take a non-empty subset of hyper_graph's nodes(V) which contains a connected subgraph.
     // Then it will possibly generate a connected complement which will combine `nodes` to be a csg-cmp-pair.
     fn emit_csg(&mut self, nodes: &[IndexType]) -> Result<bool> {
@@ -83,6 +83,7 @@ impl JoinNode {
             left_conditions,
             right_conditions,
             non_equi_conditions: vec![],
+            is_null_equal: vec![],
             join_type: self.join_type.clone(),
             marker_index: None,
             from_correlated_subquery: false,
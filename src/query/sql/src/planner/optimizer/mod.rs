@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cardinality_feedback;
 mod cascades;
 mod cost;
 mod distributed;
@@ -24,12 +25,15 @@ mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
 mod pattern_extractor;
+mod plan_shape;
 mod property;
 mod rule;
 mod runtime_filter;
 pub mod s_expr;
 mod util;
 
+pub use cardinality_feedback::Cardinalities;
+pub use cardinality_feedback::CardinalityFeedback;
 pub use heuristic::HeuristicOptimizer;
 pub use heuristic::SubqueryRewriter;
 pub use heuristic::DEFAULT_REWRITE_RULES;
@@ -39,6 +43,7 @@ pub use optimizer::optimize;
 pub use optimizer::OptimizerConfig;
 pub use optimizer::OptimizerContext;
 pub use pattern_extractor::PatternExtractor;
+pub use plan_shape::capture_plan_shape;
 pub use property::*;
 pub use rule::agg_index;
 pub use rule::try_push_down_filter_join;
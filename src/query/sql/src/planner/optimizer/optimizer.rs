@@ -19,19 +19,27 @@ use common_ast::ast::ExplainKind;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::PlanShape;
 
 use super::cost::CostContext;
 use super::format::display_memo;
 use super::Memo;
+use crate::optimizer::cardinality_feedback::apply_cardinality_feedback;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::plan_shape::is_valid_plan_shape;
+use crate::optimizer::plan_shape::orient_joins;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
+use crate::optimizer::Cardinalities;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
+use crate::plans::DeclareResultPlan;
 use crate::plans::Plan;
+use crate::plans::PrepareCommitPlan;
 use crate::BindContext;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -44,11 +52,32 @@ pub struct OptimizerConfig {
 #[derive(Debug)]
 pub struct OptimizerContext {
     pub config: OptimizerConfig,
+    /// The plan shape pinned by the baseline of the query.
+    pub pinned_shape: Option<PlanShape>,
+    /// The actual cardinalities recorded by the previous run of the query.
+    pub cardinality_feedback: Option<Arc<Cardinalities>>,
 }
 
 impl OptimizerContext {
     pub fn new(config: OptimizerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            pinned_shape: None,
+            cardinality_feedback: None,
+        }
+    }
+
+    pub fn with_pinned_shape(mut self, pinned_shape: Option<PlanShape>) -> Self {
+        self.pinned_shape = pinned_shape;
+        self
+    }
+
+    pub fn with_cardinality_feedback(
+        mut self,
+        cardinality_feedback: Option<Arc<Cardinalities>>,
+    ) -> Self {
+        self.cardinality_feedback = cardinality_feedback;
+        self
     }
 }
 
@@ -80,9 +109,12 @@ pub fn optimize(
             ignore_result,
         }),
         Plan::Explain { kind, plan } => match kind {
-            ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
-                Ok(Plan::Explain { kind, plan })
-            }
+            // The plan of `EXPLAIN(REPRO)` is optimized when the bundle is built, which
+            // records the plans before and after optimization.
+            ExplainKind::Raw
+            | ExplainKind::Ast(_)
+            | ExplainKind::Syntax(_)
+            | ExplainKind::Repro(_) => Ok(Plan::Explain { kind, plan }),
             ExplainKind::Memo(_) => {
                 if let box Plan::Query {
                     ref s_expr,
@@ -115,6 +147,14 @@ pub fn optimize(
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(ctx, opt_ctx, *plan)?),
         }),
+        Plan::PrepareCommit(v) => Ok(Plan::PrepareCommit(Box::new(PrepareCommitPlan {
+            id: v.id,
+            plan: Box::new(optimize(ctx, opt_ctx, *v.plan)?),
+        }))),
+        Plan::DeclareResult(v) => Ok(Plan::DeclareResult(Box::new(DeclareResultPlan {
+            name: v.name,
+            query: Box::new(optimize(ctx, opt_ctx, *v.query)?),
+        }))),
         Plan::Copy(v) => {
             Ok(Plan::Copy(Box::new(match *v {
                 CopyPlan::IntoStage {
@@ -122,6 +162,8 @@ pub fn optimize(
                     path,
                     validation_mode,
                     from,
+                    partition_by,
+                    file_name_prefix,
                 } => {
                     CopyPlan::IntoStage {
                         stage,
@@ -129,11 +171,28 @@ pub fn optimize(
                         validation_mode,
                         // Make sure the subquery has been optimized.
                         from: Box::new(optimize(ctx, opt_ctx, *from)?),
+                        partition_by,
+                        file_name_prefix,
                     }
                 }
                 into_table => into_table,
             })))
         }
+        Plan::MergeInto(mut plan) => {
+            // The merged rows are applied to the target table on the local node,
+            // so the join is always planned for standalone execution.
+            let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+                enable_distributed_optimization: false,
+            }));
+            plan.input = Box::new(optimize_query(
+                ctx,
+                opt_ctx,
+                plan.metadata.clone(),
+                plan.bind_context.clone(),
+                *plan.input,
+            )?);
+            Ok(Plan::MergeInto(plan))
+        }
         // Passthrough statements
         _ => Ok(plan),
     }
@@ -147,14 +206,44 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    let contains_recursive_cte = contains_recursive_cte(&s_expr);
 
     let heuristic =
         HeuristicOptimizer::new(ctx.get_function_context()?, bind_context, metadata.clone());
     let mut result = heuristic.optimize(s_expr)?;
-    if ctx.get_settings().get_enable_dphyp()? {
+    // Correct the estimations of the operators before the joins are reordered.
+    let cardinality_feedback = opt_ctx.cardinality_feedback.as_deref();
+    if let Some(cardinalities) = cardinality_feedback {
+        apply_cardinality_feedback(&result, cardinalities)?;
+    }
+    // The pinned shape is stale once the query reads other tables.
+    let pinned_shape = opt_ctx
+        .pinned_shape
+        .as_ref()
+        .filter(|shape| is_valid_plan_shape(shape, &metadata))
+        .map(|shape| Arc::new(shape.clone()));
+    if let Some(pinned_shape) = pinned_shape {
+        // Rebuild the pinned join order, then keep it and the sides of the joins.
+        let (dp_res, optimized) = DPhpy::new(ctx.clone(), metadata.clone())
+            .with_pinned_shape(Some(pinned_shape.clone()))
+            .optimize(Arc::new(result))?;
+        result = (*dp_res).clone();
+        if let Some(cardinalities) = cardinality_feedback {
+            apply_cardinality_feedback(&result, cardinalities)?;
+        }
+        let mut cascades = CascadesOptimizer::create(ctx.clone(), metadata.clone(), optimized)?;
+        if optimized {
+            cascades = cascades.keep_join_shape();
+        }
+        result = cascades.optimize(result)?;
+        result = orient_joins(ctx.clone(), metadata, &pinned_shape, &result)?;
+    } else if ctx.get_settings().get_enable_dphyp()? {
         let (dp_res, optimized) =
             DPhpy::new(ctx.clone(), metadata.clone()).optimize(Arc::new(result))?;
         result = (*dp_res).clone();
+        if let Some(cardinalities) = cardinality_feedback {
+            apply_cardinality_feedback(&result, cardinalities)?;
+        }
         let mut cascades = CascadesOptimizer::create(ctx.clone(), metadata, optimized)?;
         result = cascades.optimize(result)?;
     } else {
@@ -162,9 +251,10 @@ pub fn optimize_query(
         result = cascades.optimize(result)?;
     }
     // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
+    // with reading data from local tales(e.g. system tables) or with recursive CTEs.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_recursive_cte;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
@@ -175,6 +265,10 @@ pub fn optimize_query(
     if enable_distributed_query {
         result = optimize_distributed_query(ctx.clone(), &result)?;
     }
+    // The expressions chosen by the cascades optimizer are estimated again.
+    if let Some(cardinalities) = cardinality_feedback {
+        apply_cardinality_feedback(&result, cardinalities)?;
+    }
 
     Ok(result)
 }
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::principal::JoinShape;
+use common_meta_app::principal::PlanShape;
+
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleFactory;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Capture the join tree of an optimized expression to be pinned by a plan baseline.
+pub fn capture_plan_shape(s_expr: &SExpr, metadata: &MetadataRef) -> PlanShape {
+    let table_ids = metadata
+        .read()
+        .tables()
+        .iter()
+        .map(|table| table.table().get_id())
+        .collect();
+    let mut joins = vec![];
+    collect_joins(s_expr, &mut joins);
+    PlanShape { table_ids, joins }
+}
+
+/// The shape is valid if the query reads the same tables as when it was captured.
+pub fn is_valid_plan_shape(shape: &PlanShape, metadata: &MetadataRef) -> bool {
+    let metadata = metadata.read();
+    let tables = metadata.tables();
+    tables.len() == shape.table_ids.len()
+        && tables
+            .iter()
+            .zip(shape.table_ids.iter())
+            .all(|(table, id)| table.table().get_id() == *id)
+}
+
+/// Commute the joins whose probe and build sides are reversed to the pinned ones.
+pub fn orient_joins(
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    shape: &PlanShape,
+    s_expr: &SExpr,
+) -> Result<SExpr> {
+    let mut children = Vec::with_capacity(s_expr.arity());
+    for child in s_expr.children() {
+        children.push(Arc::new(orient_joins(
+            ctx.clone(),
+            metadata.clone(),
+            shape,
+            child,
+        )?));
+    }
+    let s_expr = s_expr.replace_children(children);
+
+    if let RelOperator::Join(_) = s_expr.plan() {
+        let probe = collect_tables(s_expr.child(0)?);
+        let build = collect_tables(s_expr.child(1)?);
+        if shape
+            .joins
+            .iter()
+            .any(|join| join.probe == build && join.build == probe)
+        {
+            let rule = RuleFactory::create_rule(
+                RuleID::CommuteJoin,
+                metadata,
+                ctx.get_function_context()?,
+            )?;
+            let mut state = TransformResult::new();
+            rule.apply(&s_expr, &mut state)?;
+            // Joins of other types can't be commuted.
+            if let Some(result) = state.results().first() {
+                return Ok(result.clone());
+            }
+        }
+    }
+    Ok(s_expr)
+}
+
+fn collect_joins(s_expr: &SExpr, joins: &mut Vec<JoinShape>) {
+    for child in s_expr.children() {
+        collect_joins(child, joins);
+    }
+    if let RelOperator::Join(_) = s_expr.plan() {
+        let probe = collect_tables(&s_expr.children()[0]);
+        let build = collect_tables(&s_expr.children()[1]);
+        if !probe.is_empty() && !build.is_empty() {
+            joins.push(JoinShape { probe, build });
+        }
+    }
+}
+
+// Get the sorted indexes of the tables scanned by the expression.
+fn collect_tables(s_expr: &SExpr) -> Vec<IndexType> {
+    let mut tables = vec![];
+    collect_tables_rec(s_expr, &mut tables);
+    tables.sort();
+    tables.dedup();
+    tables
+}
+
+fn collect_tables_rec(s_expr: &SExpr, tables: &mut Vec<IndexType>) {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => tables.push(scan.table_index),
+        // The second child of runtime filter source is the build side of the join.
+        RelOperator::RuntimeFilterSource(_) => collect_tables_rec(&s_expr.children()[0], tables),
+        _ => {
+            for child in s_expr.children() {
+                collect_tables_rec(child, tables);
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::plan::AggIndexItem;
+use common_exception::Result;
+use common_expression::RemoteExpr;
+
+use super::query_rewrite::collect_information;
+use super::query_rewrite::RewriteInfomartion;
+use crate::executor::cast_expr_to_non_null_boolean;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::CastExpr;
+use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
+use crate::plans::RelOperator;
+use crate::ScalarExpr;
+
+/// Build the definition of an aggregating index from its bound (not optimized) plan.
+///
+/// Returns [None] if the index can not be built block by block, e.g. an item of
+/// the selection mixes aggregate functions with other expressions.
+pub fn build_index_definition(s_expr: &SExpr) -> Result<Option<AggIndexDefinition>> {
+    if !matches!(s_expr.plan(), RelOperator::EvalScalar(_)) {
+        return Ok(None);
+    }
+    let info = collect_information(s_expr)?;
+    if !info.can_apply_index() {
+        return Ok(None);
+    }
+
+    let filter = match info.predicates {
+        Some(predicates) => {
+            let mut resolved = Vec::with_capacity(predicates.len());
+            for pred in predicates {
+                match resolve_scalar(&info, pred) {
+                    Some(pred) if !contains_aggregate(&pred) => resolved.push(pred),
+                    _ => return Ok(None),
+                }
+            }
+            resolved.into_iter().reduce(|lhs, rhs| {
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "and".to_string(),
+                    params: vec![],
+                    arguments: vec![lhs, rhs],
+                })
+            })
+        }
+        None => None,
+    };
+    let filter = filter
+        .map(|pred| -> Result<_> {
+            let expr = cast_expr_to_non_null_boolean(
+                pred.as_expr()?
+                    .project_column_ref(|col| col.column_name.clone()),
+            )?;
+            Ok(expr.as_remote_expr())
+        })
+        .transpose()?;
+
+    let mut group_items = vec![];
+    if let Some((agg, _)) = &info.aggregation {
+        for item in agg.group_items.iter() {
+            match resolve_scalar(&info, &item.scalar) {
+                Some(scalar) if !contains_aggregate(&scalar) => {
+                    group_items.push(to_remote_expr(&scalar)?);
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    let mut selection = Vec::with_capacity(info.selection.items.len());
+    for item in info.selection.items.iter() {
+        let scalar = match resolve_scalar(&info, &item.scalar) {
+            Some(scalar) => scalar,
+            None => return Ok(None),
+        };
+        match &scalar {
+            ScalarExpr::AggregateFunction(func) => {
+                if func.args.iter().any(contains_aggregate) {
+                    return Ok(None);
+                }
+                selection.push(AggIndexItem::Aggregate {
+                    func_name: func.func_name.clone(),
+                    params: func.params.clone(),
+                    args: func
+                        .args
+                        .iter()
+                        .map(to_remote_expr)
+                        .collect::<Result<_>>()?,
+                });
+            }
+            scalar if !contains_aggregate(scalar) => {
+                selection.push(AggIndexItem::Scalar(to_remote_expr(scalar)?));
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    Ok(Some(AggIndexDefinition {
+        filter,
+        is_agg: info.aggregation.is_some(),
+        group_items,
+        selection,
+    }))
+}
+
+fn to_remote_expr(scalar: &ScalarExpr) -> Result<RemoteExpr<String>> {
+    Ok(scalar
+        .as_expr()?
+        .project_column_ref(|col| col.column_name.clone())
+        .as_remote_expr())
+}
+
+/// Replace the columns derived in the plan with the expressions computing them,
+/// so that the result only refers to the columns of the table.
+fn resolve_scalar(info: &RewriteInfomartion<'_>, scalar: &ScalarExpr) -> Option<ScalarExpr> {
+    match scalar {
+        ScalarExpr::BoundColumnRef(col) => match info.actual_column_ref(scalar) {
+            ScalarExpr::BoundColumnRef(actual) => {
+                if actual.column.table_index.is_some() {
+                    return Some(actual.clone().into());
+                }
+                // The column may be a group item, which is not evaluated below the aggregation.
+                let (agg, _) = info.aggregation.as_ref()?;
+                let item = agg
+                    .group_items
+                    .iter()
+                    .find(|item| item.index == col.column.index)?;
+                match &item.scalar {
+                    ScalarExpr::BoundColumnRef(c) if c.column.index == item.index => None,
+                    s => resolve_scalar(info, s),
+                }
+            }
+            s => resolve_scalar(info, s),
+        },
+        ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+        ScalarExpr::FunctionCall(func) => Some(
+            FunctionCall {
+                span: func.span,
+                func_name: func.func_name.clone(),
+                params: func.params.clone(),
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| resolve_scalar(info, arg))
+                    .collect::<Option<_>>()?,
+            }
+            .into(),
+        ),
+        ScalarExpr::CastExpr(cast) => Some(
+            CastExpr {
+                span: cast.span,
+                is_try: cast.is_try,
+                argument: Box::new(resolve_scalar(info, &cast.argument)?),
+                target_type: cast.target_type.clone(),
+            }
+            .into(),
+        ),
+        ScalarExpr::LambdaFunction(lambda) => Some(
+            LambdaFunc {
+                span: lambda.span,
+                func_name: lambda.func_name.clone(),
+                args: lambda
+                    .args
+                    .iter()
+                    .map(|arg| resolve_scalar(info, arg))
+                    .collect::<Option<_>>()?,
+                lambda_expr: lambda.lambda_expr.clone(),
+                lambda_display: lambda.lambda_display.clone(),
+                return_type: lambda.return_type.clone(),
+            }
+            .into(),
+        ),
+        ScalarExpr::AggregateFunction(func) => Some(
+            AggregateFunction {
+                func_name: func.func_name.clone(),
+                distinct: func.distinct,
+                params: func.params.clone(),
+                args: func
+                    .args
+                    .iter()
+                    .map(|arg| resolve_scalar(info, arg))
+                    .collect::<Option<_>>()?,
+                return_type: func.return_type.clone(),
+                display_name: func.display_name.clone(),
+            }
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
+fn contains_aggregate(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::AggregateFunction(_) => true,
+        ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contains_aggregate),
+        ScalarExpr::CastExpr(cast) => contains_aggregate(&cast.argument),
+        ScalarExpr::LambdaFunction(lambda) => lambda.args.iter().any(contains_aggregate),
+        _ => false,
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod definition;
 mod query_rewrite;
 
+pub use definition::build_index_definition;
 pub use query_rewrite::*;
@@ -15,24 +15,33 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::plan::AggIndexItem;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::Scalar;
 
+use super::definition::build_index_definition;
 use crate::binder::split_conjunctions;
 use crate::optimizer::SExpr;
 use crate::plans::AggIndexInfo;
 use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
+use crate::MaterializedViewEntry;
 use crate::ScalarExpr;
 use crate::Visibility;
 
@@ -45,6 +54,11 @@ pub fn try_rewrite(
         return Ok(None);
     }
 
+    let scan = find_scan(s_expr)?;
+    if scan.agg_index.is_some() || scan.prewhere.is_some() {
+        return Ok(None);
+    }
+
     let query_info = collect_information(s_expr)?;
     if !query_info.can_apply_index() {
         return Ok(None);
@@ -65,6 +79,15 @@ pub fn try_rewrite(
         let index_info = collect_information(&plan)?;
         debug_assert!(index_info.can_apply_index());
 
+        // Aggregated index data only can be used by aggregated queries, and vice versa.
+        if query_info.aggregation.is_some() != index_info.aggregation.is_some() {
+            continue;
+        }
+        let definition = match build_index_definition(&plan)? {
+            Some(definition) => definition,
+            None => continue,
+        };
+
         // 1. Check if group items are the same.
         // TODO: support aggregate from index data (if index data is not aggregated)
         let index_group_items = index_info.formatted_group_items();
@@ -135,10 +158,24 @@ pub fn try_rewrite(
             (None, None) => { /* Matched */ }
         }
 
-        let result = push_down_index_scan(s_expr, AggIndexInfo {
+        // 4. Collect the columns output by the index scan.
+        let outputs = match collect_outputs(
+            &query_info,
+            scan,
+            &new_selection,
+            &index_selection,
+            &definition,
+        ) {
+            Some(outputs) => outputs,
+            None => continue,
+        };
+
+        let result = rewrite_with_index_scan(s_expr, scan, AggIndexInfo {
             index_id: *index_id,
             selection: new_selection,
             predicates: new_predicates,
+            outputs,
+            definition,
         })?;
         return Ok(Some(result));
     }
@@ -146,6 +183,240 @@ pub fn try_rewrite(
     Ok(None)
 }
 
+/// Try to rewrite the query to read a materialized view of its table instead of the table.
+///
+/// The view data is the result of the view query, so it can answer the query if they have
+/// the same group items and the same filters, except the ranges of the columns output by
+/// the view, which can be filtered again on the view data.
+///
+/// `add_view_table` adds the table of the matched view to the metadata,
+/// and returns its index and its columns in the order of the view outputs.
+pub fn try_rewrite_by_view(
+    base_columns: &[ColumnEntry],
+    s_expr: &SExpr,
+    views: &[MaterializedViewEntry],
+    add_view_table: impl FnOnce(&MaterializedViewEntry) -> (IndexType, Vec<ColumnBinding>),
+) -> Result<Option<SExpr>> {
+    if views.is_empty() {
+        return Ok(None);
+    }
+
+    let scan = find_scan(s_expr)?;
+    if scan.agg_index.is_some() || scan.prewhere.is_some() {
+        return Ok(None);
+    }
+
+    let query_info = collect_information(s_expr)?;
+    if !query_info.can_apply_index() {
+        return Ok(None);
+    }
+
+    let col_index_map = base_columns
+        .iter()
+        .map(|col| (col.name(), col.index()))
+        .collect::<HashMap<_, _>>();
+
+    let query_predicates = query_info
+        .predicates
+        .map(distinguish_predicates)
+        .unwrap_or_default();
+    let query_group_items = query_info.formatted_group_items();
+
+    // Search all views, find the first matched view to rewrite the query.
+    for view in views.iter() {
+        // The plan of the view should be started with [`EvalScalar`] as the index plans.
+        if !matches!(view.plan.plan(), RelOperator::EvalScalar(_)) {
+            continue;
+        }
+        let plan = rewrite_index_plan(&col_index_map, &view.plan);
+        let view_info = collect_information(&plan)?;
+        if !view_info.can_apply_index() {
+            continue;
+        }
+
+        // 1. Check if the query and the view are both aggregated by the same group items.
+        if query_info.aggregation.is_some() != view_info.aggregation.is_some()
+            || query_group_items != view_info.formatted_group_items()
+        {
+            continue;
+        }
+
+        // 2. Check query output and try to rewrite it.
+        let view_selection = view_info.formatted_selection();
+        let new_selection = match query_info
+            .selection
+            .items
+            .iter()
+            .map(|item| rewrite_by_selection(&query_info, &item.scalar, &view_selection))
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(new_selection) => new_selection,
+            None => continue,
+        };
+
+        // 3. Check filter predicates. The view data only holds the rows passing the filters
+        // of the view, so the filters must be the same, except the ranges can be narrowed.
+        let (qe, qr, qo) = &query_predicates;
+        let (ve, vr, vo) = view_info
+            .predicates
+            .map(distinguish_predicates)
+            .unwrap_or_default();
+        if !check_predicates_equal(qe, &ve)
+            || !check_predicates_equal(&ve, qe)
+            || !check_predicates_other(qo, &vo)
+            || !check_predicates_other(&vo, qo)
+            || vr.keys().any(|col| !qr.contains_key(col))
+        {
+            continue;
+        }
+        let new_predicates = match check_predicates_range(
+            qr,
+            &vr,
+            &view_info.output_bound_cols(),
+            &view_selection,
+        ) {
+            Some(preds) => preds,
+            None => continue,
+        };
+
+        // 4. Collect the columns output to the parent plan.
+        let outputs = match &query_info.aggregation {
+            Some(_) => query_info
+                .selection
+                .items
+                .iter()
+                .zip(new_selection)
+                .map(|(item, scalar)| (item.index, scalar))
+                .collect::<Vec<_>>(),
+            None => {
+                let definition = match build_index_definition(&plan)? {
+                    Some(definition) => definition,
+                    None => continue,
+                };
+                match collect_outputs(
+                    &query_info,
+                    scan,
+                    &new_selection,
+                    &view_selection,
+                    &definition,
+                ) {
+                    Some(outputs) => outputs,
+                    None => continue,
+                }
+            }
+        };
+
+        // 5. The rewritten scalars refer to the selection items of the view,
+        // check if all of them are output by the view.
+        let positions = view_info
+            .selection
+            .items
+            .iter()
+            .map(|item| {
+                view.output_columns
+                    .iter()
+                    .position(|col| *col == item.index)
+            })
+            .collect::<Vec<_>>();
+        let mut used_items = ColumnSet::new();
+        for (_, scalar) in outputs.iter() {
+            used_items.extend(scalar.used_columns());
+        }
+        for pred in new_predicates.iter() {
+            used_items.extend(pred.used_columns());
+        }
+        if used_items.iter().any(|i| positions[*i].is_none()) {
+            continue;
+        }
+
+        let (view_table_index, view_columns) = add_view_table(view);
+        let columns = positions
+            .iter()
+            .map(|pos| pos.map(|pos| view_columns[pos].clone()))
+            .collect::<Vec<_>>();
+
+        let mut scan_columns = ColumnSet::new();
+        let mut items = Vec::with_capacity(outputs.len());
+        for (index, mut scalar) in outputs {
+            replace_view_columns(&mut scalar, &columns);
+            scan_columns.extend(scalar.used_columns());
+            items.push(ScalarItem { scalar, index });
+        }
+        let mut predicates = Vec::with_capacity(new_predicates.len());
+        for mut pred in new_predicates {
+            replace_view_columns(&mut pred, &columns);
+            scan_columns.extend(pred.used_columns());
+            predicates.push(pred);
+        }
+
+        let mut result = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index: view_table_index,
+                columns: scan_columns,
+                ..Default::default()
+            }
+            .into(),
+        ));
+        if !predicates.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(
+                    Filter {
+                        predicates,
+                        is_having: false,
+                    }
+                    .into(),
+                ),
+                Arc::new(result),
+            );
+        }
+        result = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(result));
+        return Ok(Some(result));
+    }
+
+    Ok(None)
+}
+
+/// Replace the references to the selection items of a view with the columns of the view table.
+fn replace_view_columns(scalar: &mut ScalarExpr, columns: &[Option<ColumnBinding>]) {
+    match scalar {
+        ScalarExpr::BoundColumnRef(col) => {
+            let view_column = columns[col.column.index].clone().unwrap();
+            let target_type = col.column.data_type.clone();
+            let view_column: ScalarExpr = BoundColumnRef {
+                span: None,
+                column: view_column,
+            }
+            .into();
+            *scalar = if view_column.data_type().ok().as_ref() == Some(&*target_type) {
+                view_column
+            } else {
+                CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(view_column),
+                    target_type,
+                }
+                .into()
+            };
+        }
+        ScalarExpr::FunctionCall(func) => {
+            func.arguments
+                .iter_mut()
+                .for_each(|arg| replace_view_columns(arg, columns));
+        }
+        ScalarExpr::CastExpr(cast) => {
+            replace_view_columns(&mut cast.argument, columns);
+        }
+        ScalarExpr::LambdaFunction(lambda) => {
+            lambda
+                .args
+                .iter_mut()
+                .for_each(|arg| replace_view_columns(arg, columns));
+        }
+        _ => { /*  do nothing */ }
+    }
+}
+
 /// Rewrite base column index in the original index plan by `columns`.
 fn rewrite_index_plan(columns: &HashMap<String, IndexType>, s_expr: &SExpr) -> SExpr {
     match s_expr.plan() {
@@ -197,6 +468,12 @@ fn rewrite_scalar_index(columns: &HashMap<String, IndexType>, scalar: &mut Scala
         ScalarExpr::CastExpr(cast) => {
             rewrite_scalar_index(columns, &mut cast.argument);
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            lambda
+                .args
+                .iter_mut()
+                .for_each(|arg| rewrite_scalar_index(columns, arg));
+        }
         _ => { /*  do nothing */ }
     }
 }
@@ -452,7 +729,7 @@ impl RewriteInfomartion<'_> {
         cols
     }
 
-    fn can_apply_index(&self) -> bool {
+    pub(super) fn can_apply_index(&self) -> bool {
         if let Some((agg, _)) = self.aggregation {
             if !agg.grouping_sets.is_empty() {
                 // Grouping sets is not supported.
@@ -485,7 +762,7 @@ impl RewriteInfomartion<'_> {
     }
 
     // If the column ref is already rewritten, recover it.
-    fn actual_column_ref<'a>(&'a self, col: &'a ScalarExpr) -> &'a ScalarExpr {
+    pub(super) fn actual_column_ref<'a>(&'a self, col: &'a ScalarExpr) -> &'a ScalarExpr {
         if let ScalarExpr::BoundColumnRef(col) = col {
             if let Some((agg, args)) = &self.aggregation {
                 // Check if the col is an aggregation function.
@@ -526,6 +803,17 @@ impl RewriteInfomartion<'_> {
                 self.format_scalar(&cast.argument),
                 cast.target_type
             ),
+            ScalarExpr::LambdaFunction(lambda) => format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { self.format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display
+            ),
             ScalarExpr::AggregateFunction(agg) => {
                 format!(
                     "{}<{}>({})",
@@ -547,7 +835,7 @@ impl RewriteInfomartion<'_> {
     }
 }
 
-fn collect_information(s_expr: &SExpr) -> Result<RewriteInfomartion<'_>> {
+pub(super) fn collect_information(s_expr: &SExpr) -> Result<RewriteInfomartion<'_>> {
     // The plan tree should be started with [`EvalScalar`].
     if let RelOperator::EvalScalar(eval) = s_expr.plan() {
         let mut info = RewriteInfomartion {
@@ -834,20 +1122,117 @@ fn rewrite_query_item(
                 .into(),
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            let mut new_args = Vec::with_capacity(lambda.args.len());
+            for arg in lambda.args.iter() {
+                let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
+                new_args.push(new_arg);
+            }
+            Some(
+                LambdaFunc {
+                    span: None,
+                    func_name: lambda.func_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into(),
+            )
+        }
         ScalarExpr::AggregateFunction(_) => None, /* Aggregate function must appear in index selection. */
         _ => unreachable!(), // Window function and subquery will not appear in index.
     }
 }
 
-fn push_down_index_scan(s_expr: &SExpr, agg_info: AggIndexInfo) -> Result<SExpr> {
+/// Collect the columns the index scan outputs to the parent plan.
+///
+/// - If the query is aggregated, they are the group items and the states of the aggregate functions,
+///   the states will be merged by the aggregation upon the scan.
+/// - Otherwise, they are the items of the query selection, and the columns of the table passed
+///   through to the parent plan.
+fn collect_outputs(
+    query_info: &RewriteInfomartion<'_>,
+    scan: &Scan,
+    new_selection: &[ScalarExpr],
+    index_selection: &SelectionMap<'_>,
+    definition: &AggIndexDefinition,
+) -> Option<Vec<(IndexType, ScalarExpr)>> {
+    let mut outputs = vec![];
+    match &query_info.aggregation {
+        Some((agg, _)) => {
+            for item in agg.group_items.iter() {
+                let col = try_create_column_binding(
+                    index_selection,
+                    &query_info.format_scalar(&item.scalar),
+                )?;
+                outputs.push((item.index, col.into()));
+            }
+            for item in agg.aggregate_functions.iter() {
+                let mut col = try_create_column_binding(
+                    index_selection,
+                    &query_info.format_scalar(&item.scalar),
+                )?;
+                if !matches!(
+                    definition.selection[col.column.index],
+                    AggIndexItem::Aggregate { .. }
+                ) {
+                    return None;
+                }
+                // The index data holds the serialized state of the aggregate function.
+                col.column.data_type = Box::new(DataType::String);
+                outputs.push((item.index, col.into()));
+            }
+        }
+        None => {
+            let mut used_columns = ColumnSet::new();
+            for (item, scalar) in query_info.selection.items.iter().zip(new_selection) {
+                used_columns.extend(item.scalar.used_columns());
+                outputs.push((item.index, scalar.clone()));
+            }
+            for pred in query_info.predicates.unwrap_or_default() {
+                used_columns.extend(pred.used_columns());
+            }
+            for col in scan.columns.iter() {
+                if used_columns.contains(col) || outputs.iter().any(|(index, _)| index == col) {
+                    continue;
+                }
+                let binding = try_create_column_binding(index_selection, &format_col_name(*col))?;
+                outputs.push((*col, binding.into()));
+            }
+        }
+    }
+    Some(outputs)
+}
+
+fn find_scan(s_expr: &SExpr) -> Result<&Scan> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Ok(scan),
+        _ => find_scan(s_expr.child(0)?),
+    }
+}
+
+/// The evaluations and the filters below the aggregation (or the whole plan if the query
+/// is not aggregated) are done while building the index data, so replace them with the index scan.
+fn rewrite_with_index_scan(s_expr: &SExpr, scan: &Scan, agg_info: AggIndexInfo) -> Result<SExpr> {
+    let is_agg = agg_info.definition.is_agg;
+    let mut new_scan = scan.clone();
+    new_scan.columns = agg_info.outputs.iter().map(|(index, _)| *index).collect();
+    new_scan.agg_index = Some(agg_info);
+    let index_scan = SExpr::create_leaf(Arc::new(new_scan.into()));
+    if !is_agg {
+        return Ok(index_scan);
+    }
+    replace_below_aggregate(s_expr, index_scan)
+}
+
+fn replace_below_aggregate(s_expr: &SExpr, index_scan: SExpr) -> Result<SExpr> {
     Ok(match s_expr.plan() {
-        RelOperator::Scan(scan) => {
-            let mut new_scan = scan.clone();
-            new_scan.agg_index = Some(agg_info);
-            s_expr.replace_plan(Arc::new(new_scan.into()))
+        RelOperator::Aggregate(agg) if agg.mode == AggregateMode::Partial => {
+            s_expr.replace_children(vec![Arc::new(index_scan)])
         }
         _ => {
-            let child = push_down_index_scan(s_expr.child(0)?, agg_info)?;
+            let child = replace_below_aggregate(s_expr.child(0)?, index_scan)?;
             s_expr.replace_children(vec![Arc::new(child)])
         }
     })
@@ -88,6 +88,11 @@ impl RulePushDownPrewhere {
             ScalarExpr::CastExpr(cast) => {
                 Self::collect_columns_impl(table_index, schema, cast.argument.as_ref(), columns)?;
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter() {
+                    Self::collect_columns_impl(table_index, schema, arg, columns)?;
+                }
+            }
             ScalarExpr::ConstantExpr(_) => {}
             _ => {
                 // SubqueryExpr and AggregateFunction will not appear in Filter-LogicalGet
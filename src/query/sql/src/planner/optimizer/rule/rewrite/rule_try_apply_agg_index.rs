@@ -38,152 +38,7 @@ impl RuleTryApplyAggIndex {
         Self {
             id: RuleID::TryApplyAggIndex,
             metadata,
-            patterns: vec![
-                // Expression
-                //     |
-                //    Scan
-                SExpr::create_unary(
-                    Arc::new(
-                        PatternPlan {
-                            plan_type: RelOp::EvalScalar,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(SExpr::create_leaf(Arc::new(
-                        PatternPlan {
-                            plan_type: RelOp::Scan,
-                        }
-                        .into(),
-                    ))),
-                ),
-                // Expression
-                //     |
-                //   Filter
-                //     |
-                //    Scan
-                SExpr::create_unary(
-                    Arc::new(
-                        PatternPlan {
-                            plan_type: RelOp::EvalScalar,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(SExpr::create_unary(
-                        Arc::new(
-                            PatternPlan {
-                                plan_type: RelOp::Filter,
-                            }
-                            .into(),
-                        ),
-                        Arc::new(SExpr::create_leaf(Arc::new(
-                            PatternPlan {
-                                plan_type: RelOp::Scan,
-                            }
-                            .into(),
-                        ))),
-                    )),
-                ),
-                // Expression
-                //     |
-                // Aggregation
-                //     |
-                // Expression
-                //     |
-                //    Scan
-                SExpr::create_unary(
-                    Arc::new(
-                        PatternPlan {
-                            plan_type: RelOp::EvalScalar,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(SExpr::create_unary(
-                        Arc::new(
-                            PatternPlan {
-                                plan_type: RelOp::Aggregate,
-                            }
-                            .into(),
-                        ),
-                        Arc::new(SExpr::create_unary(
-                            Arc::new(
-                                PatternPlan {
-                                    plan_type: RelOp::Aggregate,
-                                }
-                                .into(),
-                            ),
-                            Arc::new(SExpr::create_unary(
-                                Arc::new(
-                                    PatternPlan {
-                                        plan_type: RelOp::EvalScalar,
-                                    }
-                                    .into(),
-                                ),
-                                Arc::new(SExpr::create_leaf(Arc::new(
-                                    PatternPlan {
-                                        plan_type: RelOp::Scan,
-                                    }
-                                    .into(),
-                                ))),
-                            )),
-                        )),
-                    )),
-                ),
-                // Expression
-                //     |
-                // Aggregation
-                //     |
-                // Expression
-                //     |
-                //   Filter
-                //     |
-                //    Scan
-                SExpr::create_unary(
-                    Arc::new(
-                        PatternPlan {
-                            plan_type: RelOp::EvalScalar,
-                        }
-                        .into(),
-                    ),
-                    Arc::new(SExpr::create_unary(
-                        Arc::new(
-                            PatternPlan {
-                                plan_type: RelOp::Aggregate,
-                            }
-                            .into(),
-                        ),
-                        Arc::new(SExpr::create_unary(
-                            Arc::new(
-                                PatternPlan {
-                                    plan_type: RelOp::Aggregate,
-                                }
-                                .into(),
-                            ),
-                            Arc::new(SExpr::create_unary(
-                                Arc::new(
-                                    PatternPlan {
-                                        plan_type: RelOp::EvalScalar,
-                                    }
-                                    .into(),
-                                ),
-                                Arc::new(SExpr::create_unary(
-                                    Arc::new(
-                                        PatternPlan {
-                                            plan_type: RelOp::Filter,
-                                        }
-                                        .into(),
-                                    ),
-                                    Arc::new(SExpr::create_leaf(Arc::new(
-                                        PatternPlan {
-                                            plan_type: RelOp::Scan,
-                                        }
-                                        .into(),
-                                    ))),
-                                )),
-                            )),
-                        )),
-                    )),
-                ),
-            ],
+            patterns: index_rewrite_patterns(),
         }
     }
 }
@@ -215,6 +70,20 @@ impl Rule for RuleTryApplyAggIndex {
             return Ok(());
         }
 
+        // The index data has neither the row ids for lazy materialization
+        // nor the columns to apply the masking policies.
+        if !metadata.lazy_columns().is_empty()
+            || metadata
+                .table(table_inedx)
+                .table()
+                .get_table_info()
+                .meta
+                .column_mask_policy
+                .is_some()
+        {
+            return Ok(());
+        }
+
         let base_columns = metadata.columns_by_table_index(table_inedx);
 
         if let Some(mut result) = agg_index::try_rewrite(&base_columns, s_expr, index_plans)? {
@@ -241,3 +110,153 @@ impl RuleTryApplyAggIndex {
         }
     }
 }
+
+/// The plans which can be answered by an aggregating index or a materialized view of the table.
+pub(super) fn index_rewrite_patterns() -> Vec<SExpr> {
+    vec![
+        // Expression
+        //     |
+        //    Scan
+        SExpr::create_unary(
+            Arc::new(
+                PatternPlan {
+                    plan_type: RelOp::EvalScalar,
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_leaf(Arc::new(
+                PatternPlan {
+                    plan_type: RelOp::Scan,
+                }
+                .into(),
+            ))),
+        ),
+        // Expression
+        //     |
+        //   Filter
+        //     |
+        //    Scan
+        SExpr::create_unary(
+            Arc::new(
+                PatternPlan {
+                    plan_type: RelOp::EvalScalar,
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Filter,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_leaf(Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Scan,
+                    }
+                    .into(),
+                ))),
+            )),
+        ),
+        // Expression
+        //     |
+        // Aggregation
+        //     |
+        // Expression
+        //     |
+        //    Scan
+        SExpr::create_unary(
+            Arc::new(
+                PatternPlan {
+                    plan_type: RelOp::EvalScalar,
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Aggregate,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::EvalScalar,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_leaf(Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ))),
+                    )),
+                )),
+            )),
+        ),
+        // Expression
+        //     |
+        // Aggregation
+        //     |
+        // Expression
+        //     |
+        //   Filter
+        //     |
+        //    Scan
+        SExpr::create_unary(
+            Arc::new(
+                PatternPlan {
+                    plan_type: RelOp::EvalScalar,
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Aggregate,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Aggregate,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::EvalScalar,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_unary(
+                            Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::Filter,
+                                }
+                                .into(),
+                            ),
+                            Arc::new(SExpr::create_leaf(Arc::new(
+                                PatternPlan {
+                                    plan_type: RelOp::Scan,
+                                }
+                                .into(),
+                            ))),
+                        )),
+                    )),
+                )),
+            )),
+        ),
+    ]
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use super::agg_index;
+use super::rule_try_apply_agg_index::index_rewrite_patterns;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// Rewrite the query to read a materialized view of its table instead of the table.
+pub struct RuleTryApplyMaterializedView {
+    id: RuleID,
+    metadata: MetadataRef,
+
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyMaterializedView {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyMaterializedView,
+            metadata,
+            patterns: index_rewrite_patterns(),
+        }
+    }
+}
+
+impl Rule for RuleTryApplyMaterializedView {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let table_index = self.get_table_index(s_expr);
+        let (base_columns, views) = {
+            let metadata = self.metadata.read();
+            // Do not rewrite the scan of a view table again.
+            if metadata.is_materialized_view_table(table_index) {
+                return Ok(());
+            }
+
+            let table = metadata.table(table_index);
+            let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+            let views = match metadata.get_materialized_views(&table_name) {
+                Some(views) if !views.is_empty() => views.to_vec(),
+                _ => return Ok(()),
+            };
+
+            // The view data has neither the row ids for lazy materialization
+            // nor the columns to apply the masking policies.
+            if !metadata.lazy_columns().is_empty()
+                || table
+                    .table()
+                    .get_table_info()
+                    .meta
+                    .column_mask_policy
+                    .is_some()
+            {
+                return Ok(());
+            }
+
+            (metadata.columns_by_table_index(table_index), views)
+        };
+
+        let add_view_table = |view: &MaterializedViewEntry| {
+            let mut metadata = self.metadata.write();
+            let view_table_index = metadata.add_materialized_view_table(view);
+            let columns = metadata
+                .columns_by_table_index(view_table_index)
+                .into_iter()
+                .filter_map(|col| match col {
+                    ColumnEntry::BaseTableColumn(col) if col.path_indices.is_none() => {
+                        Some(ColumnBinding {
+                            database_name: Some(view.database.clone()),
+                            table_name: Some(view.table.name().to_string()),
+                            column_position: col.column_position,
+                            table_index: Some(view_table_index),
+                            column_name: col.column_name,
+                            index: col.column_index,
+                            data_type: Box::new(DataType::from(&col.data_type)),
+                            visibility: Visibility::Visible,
+                            virtual_computed_expr: None,
+                        })
+                    }
+                    _ => None,
+                })
+                .collect();
+            (view_table_index, columns)
+        };
+
+        if let Some(mut result) =
+            agg_index::try_rewrite_by_view(&base_columns, s_expr, &views, add_view_table)?
+        {
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
+        }
+
+        Ok(())
+    }
+}
+
+impl RuleTryApplyMaterializedView {
+    fn get_table_index(&self, s_expr: &SExpr) -> IndexType {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.table_index,
+            _ => self.get_table_index(s_expr.child(0).unwrap()),
+        }
+    }
+}
@@ -120,6 +120,10 @@ impl Rule for RuleLeftAssociateJoin {
             return Ok(());
         }
 
+        if join1.has_null_equal_conditions() || join2.has_null_equal_conditions() {
+            return Ok(());
+        }
+
         // Check if original sexpr contains cross join.
         // We will reject the results contain cross join if there is no cross join in original sexpr.
         let contains_cross_join =
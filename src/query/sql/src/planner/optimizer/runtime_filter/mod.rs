@@ -41,6 +41,10 @@ fn create_runtime_filters(join: &Join) -> Result<RuntimeFilterResult> {
         .zip(join.left_conditions.iter())
         .enumerate()
     {
+        // A runtime filter would drop the NULL probe keys, which may match in `IS NOT DISTINCT FROM`.
+        if join.is_null_equal.contains(&idx) {
+            continue;
+        }
         right_runtime_filters.insert(RuntimeFilterId::new(idx), exprs.0.clone());
         left_runtime_filters.insert(RuntimeFilterId::new(idx), exprs.1.clone());
     }
@@ -31,6 +31,15 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         }
 }
 
+/// Check the expr contains a recursive CTE, whose iterations are always executed locally.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    s_expr.plan().rel_op() == RelOp::RecursiveUnion
+        || s_expr
+            .children()
+            .iter()
+            .any(|s_expr| contains_recursive_cte(s_expr))
+}
+
 /// Check the expr contains ProjectSet op.
 pub fn contains_project_set(s_expr: &SExpr) -> bool {
     if let Some(child) = s_expr.children().iter().next() {
@@ -21,16 +21,22 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::token::Token;
 use common_ast::parser::token::TokenKind;
 use common_ast::parser::token::Tokenizer;
+use common_ast::parser::tokenize_sql;
 use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+use common_meta_app::principal::PlanShape;
+use common_users::UserApiProvider;
 use parking_lot::RwLock;
+use tracing::warn;
 
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
+use crate::optimizer::CardinalityFeedback;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
@@ -91,31 +97,8 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-                self.replace_stmt(&mut stmt, sql_dialect);
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
-                    enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
-                }));
-
-                let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
-                Ok((optimized_plan, PlanExtras {
-                    metadata,
-                    format,
-                    statement: stmt,
-                }))
+                let (stmt, format) = parse_sql(&tokens, sql_dialect)?;
+                self.plan_stmt(stmt, format).await
             }
             .await;
 
@@ -159,6 +142,105 @@ impl Planner {
         }
     }
 
+    /// Parses the SQL of a prepared statement, the `?` placeholders in it are bound by
+    /// [`crate::bind_placeholders`] before planning the statement with [`Planner::plan_stmt`].
+    pub fn parse_prepared_sql(&self, sql: &str) -> Result<(Statement, Option<String>)> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(sql)?;
+        parse_sql(&tokens, sql_dialect)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn plan_stmt(
+        &mut self,
+        mut stmt: Statement,
+        format: Option<String>,
+    ) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+
+        // Look up the baseline and the feedback before the statement is rewritten.
+        let fingerprint = self.plan_fingerprint(&stmt);
+        let pinned_shape = self.get_pinned_shape(fingerprint.as_deref()).await?;
+        let cardinality_feedback = match &fingerprint {
+            Some(fingerprint) if settings.get_enable_cardinality_feedback()? => {
+                CardinalityFeedback::instance().get(fingerprint)
+            }
+            _ => None,
+        };
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder.bind(&stmt).await?;
+        if let Some(fingerprint) = fingerprint {
+            if settings.get_enable_cardinality_feedback()? {
+                metadata.write().set_plan_fingerprint(fingerprint);
+            }
+        }
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let opt_ctx = Arc::new(
+            OptimizerContext::new(OptimizerConfig {
+                enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+            })
+            .with_pinned_shape(pinned_shape)
+            .with_cardinality_feedback(cardinality_feedback),
+        );
+
+        let optimized_plan = optimize(self.ctx.clone(), opt_ctx, plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format,
+            statement: stmt,
+        }))
+    }
+
+    // Get the fingerprint of the query, or of the query of an EXPLAIN.
+    fn plan_fingerprint(&self, stmt: &Statement) -> Option<String> {
+        let query = match stmt {
+            Statement::Query(_) => stmt,
+            Statement::Explain { query, .. } if matches!(query.as_ref(), Statement::Query(_)) => {
+                query.as_ref()
+            }
+            _ => return None,
+        };
+        Some(PlanBaseline::gen_id(
+            &self.ctx.get_current_database(),
+            &query.to_string(),
+        ))
+    }
+
+    // Get the plan shape pinned by `ALTER STATEMENT ... PIN PLAN` for the query.
+    #[async_backtrace::framed]
+    async fn get_pinned_shape(&self, fingerprint: Option<&str>) -> Result<Option<PlanShape>> {
+        let Some(id) = fingerprint else {
+            return Ok(None);
+        };
+        if !self.ctx.get_settings().get_enable_plan_baselines()? {
+            return Ok(None);
+        }
+
+        match UserApiProvider::instance()
+            .get_plan_baseline(&self.ctx.get_tenant(), id)
+            .await
+        {
+            Ok(baseline) => Ok(baseline.map(|baseline| baseline.shape)),
+            Err(e) => {
+                // Plan the query as usual rather than failing it.
+                warn!("Failed to get the plan baseline {}: {}", id, e);
+                Ok(None)
+            }
+        }
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use common_catalog::plan::StageTableInfo;
+use common_catalog::plan::UnloadPartitionKey;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
@@ -198,6 +199,8 @@ pub enum CopyPlan {
         path: String,
         validation_mode: ValidationMode,
         from: Box<Plan>,
+        partition_by: Vec<UnloadPartitionKey>,
+        file_name_prefix: Option<String>,
     },
 }
 
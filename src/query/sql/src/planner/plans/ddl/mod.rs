@@ -17,8 +17,12 @@ mod catalog;
 mod database;
 mod file_format;
 mod index;
+mod materialized_view;
+mod plan_baseline;
 mod stage;
+mod stream;
 mod table;
+mod task;
 mod udf;
 mod view;
 mod virtual_column;
@@ -28,8 +32,12 @@ pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use materialized_view::*;
+pub use plan_baseline::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
@@ -0,0 +1,24 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/// Pin the plan shape of a query as its baseline, or unpin it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterStatementPlan {
+    pub tenant: String,
+    /// The database the query runs in.
+    pub database: String,
+    /// The query formatted from its AST.
+    pub query_text: String,
+    pub baseline_id: String,
+    pub pin: bool,
+}
@@ -102,8 +102,19 @@ pub struct VacuumTablePlan {
 }
 
 impl VacuumTablePlan {
+    /// `DRY RUN` lists the candidate files, otherwise the removed files are summarized.
     pub fn schema(&self) -> DataSchemaRef {
-        Arc::new(DataSchema::empty())
+        if self.option.dry_run.is_some() {
+            DataSchemaRefExt::create(vec![
+                DataField::new("Files", DataType::String),
+                DataField::new("Size", DataType::Number(NumberDataType::UInt64)),
+            ])
+        } else {
+            DataSchemaRefExt::create(vec![
+                DataField::new("removed_files", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("freed_bytes", DataType::Number(NumberDataType::UInt64)),
+            ])
+        }
     }
 }
 
@@ -149,6 +160,43 @@ impl AnalyzeTablePlan {
     }
 }
 
+/// Amend.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AmendTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl AmendTablePlan {
+    /// One row per checked snapshot, the same as the `fuse_amend` table function.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("snapshot_id", DataType::String),
+            DataField::new("timestamp", DataType::Timestamp.wrap_nullable()),
+            DataField::new("row_count", DataType::Number(NumberDataType::UInt64)),
+            DataField::new(
+                "missing_segment_count",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "missing_block_count",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "missing_row_count",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "lost_row_count",
+                DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            DataField::new("consistent", DataType::Boolean),
+            DataField::new("action", DataType::String),
+        ])
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenameTablePlan {
@@ -0,0 +1,40 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/// Create a task, which runs its SQL by a cron schedule or after its predecessors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub task_name: String,
+    pub schedule: Option<String>,
+    pub after: Vec<String>,
+    pub comment: String,
+    /// The database the SQL of the task runs in.
+    pub database: String,
+    pub query_text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterTaskPlan {
+    pub tenant: String,
+    pub task_name: String,
+    pub suspend: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTaskPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub task_name: String,
+}
@@ -0,0 +1,23 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::plans::Plan;
+
+/// `DECLARE <name> RESULT AS <query>`, the result of `query` is written to the storage
+/// once, and can be referenced as the table `name` in the later statements of the session.
+#[derive(Clone, Debug)]
+pub struct DeclareResultPlan {
+    pub name: String,
+    pub query: Box<Plan>,
+}
@@ -140,6 +140,9 @@ pub struct Join {
     pub left_conditions: Vec<ScalarExpr>,
     pub right_conditions: Vec<ScalarExpr>,
     pub non_equi_conditions: Vec<ScalarExpr>,
+    // Indexes of the equi-conditions that treat NULL as equal to NULL,
+    // i.e. `IS NOT DISTINCT FROM` instead of `=`.
+    pub is_null_equal: Vec<usize>,
     pub join_type: JoinType,
     // marker_index is for MarkJoin only.
     pub marker_index: Option<IndexType>,
@@ -154,6 +157,7 @@ impl Default for Join {
             left_conditions: Default::default(),
             right_conditions: Default::default(),
             non_equi_conditions: Default::default(),
+            is_null_equal: Default::default(),
             join_type: JoinType::Cross,
             marker_index: Default::default(),
             from_correlated_subquery: Default::default(),
@@ -163,6 +167,14 @@ impl Default for Join {
 }
 
 impl Join {
+    /// Whether some equi-conditions are `IS NOT DISTINCT FROM`.
+    ///
+    /// The join reorder rules rebuild the conditions of the reordered joins from their
+    /// predicates, which loses whether NULL equals NULL, so such joins are kept in place.
+    pub fn has_null_equal_conditions(&self) -> bool {
+        !self.is_null_equal.is_empty()
+    }
+
     pub fn used_columns(&self) -> Result<ColumnSet> {
         let mut used_columns = ColumnSet::new();
         for cond in self
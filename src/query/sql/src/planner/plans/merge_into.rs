@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_meta_types::MetaId;
+
+use crate::optimizer::SExpr;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// `WHEN MATCHED [AND <condition>] THEN UPDATE SET ... | DELETE`
+#[derive(Clone, Debug)]
+pub struct MatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    /// The new value of every column of the target table, `None` means `DELETE`.
+    pub update: Option<Vec<ScalarExpr>>,
+}
+
+/// `WHEN NOT MATCHED [AND <condition>] THEN INSERT ... VALUES ...`
+#[derive(Clone, Debug)]
+pub struct UnmatchedEvaluator {
+    pub condition: Option<ScalarExpr>,
+    /// The value of every column of the target table.
+    pub values: Vec<ScalarExpr>,
+}
+
+#[derive(Clone)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    /// `source LEFT OUTER JOIN target ON <join_expr>`, or an inner join if there is
+    /// no `WHEN NOT MATCHED` clause.
+    pub input: Box<SExpr>,
+    pub bind_context: Box<BindContext>,
+    pub metadata: MetadataRef,
+    /// Index of the target table in `metadata`.
+    pub target_table_index: IndexType,
+    /// `_row_id` of the target table, it's NULL if the source row is not matched.
+    pub row_id_index: IndexType,
+    pub matched_evaluators: Vec<MatchedEvaluator>,
+    pub unmatched_evaluators: Vec<UnmatchedEvaluator>,
+}
+
+impl MergeInto {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl std::fmt::Debug for MergeInto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeInto")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .field("matched_evaluators", &self.matched_evaluators)
+            .field("unmatched_evaluators", &self.unmatched_evaluators)
+            .finish()
+    }
+}
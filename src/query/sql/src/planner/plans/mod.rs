@@ -17,6 +17,7 @@ mod call;
 mod copy;
 pub mod data_mask;
 mod ddl;
+mod declare_result;
 mod delete;
 mod dummy_table_scan;
 mod eval_scalar;
@@ -26,12 +27,16 @@ pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
+mod prepared_commit;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte_scan;
+mod recursive_union;
 mod replace;
 mod revert_table;
 mod runtime_filter_source;
@@ -49,6 +54,7 @@ pub use call::CallPlan;
 pub use copy::*;
 pub use data_mask::*;
 pub use ddl::*;
+pub use declare_result::DeclareResultPlan;
 pub use delete::DeletePlan;
 pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
@@ -59,14 +65,18 @@ pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use merge_into::*;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
 pub use plan::RewriteKind::*;
 pub use plan::*;
+pub use prepared_commit::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte_scan::RecursiveCteScan;
+pub use recursive_union::RecursiveUnion;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use runtime_filter_source::RuntimeFilterId;
@@ -25,6 +25,8 @@ use super::filter::Filter;
 use super::join::Join;
 use super::limit::Limit;
 use super::pattern::PatternPlan;
+use super::recursive_cte_scan::RecursiveCteScan;
+use super::recursive_union::RecursiveUnion;
 use super::scan::Scan;
 use super::sort::Sort;
 use super::union_all::UnionAll;
@@ -76,6 +78,8 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    RecursiveUnion,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -97,6 +101,8 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    RecursiveUnion(RecursiveUnion),
+    RecursiveCteScan(RecursiveCteScan),
 
     Pattern(PatternPlan),
 }
@@ -118,6 +124,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -137,6 +145,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -156,6 +166,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -175,6 +187,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveUnion(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -228,6 +242,12 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveUnion(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -453,6 +473,44 @@ impl TryFrom<RelOperator> for DummyTableScan {
     }
 }
 
+impl From<RecursiveUnion> for RelOperator {
+    fn from(v: RecursiveUnion) -> Self {
+        Self::RecursiveUnion(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveUnion {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveUnion(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveUnion",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(v: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(v)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
 impl From<RuntimeFilterSource> for RelOperator {
     fn from(value: RuntimeFilterSource) -> Self {
         Self::RuntimeFilterSource(value)
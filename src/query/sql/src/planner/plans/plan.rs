@@ -24,12 +24,17 @@ use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 
 use super::data_mask::CreateDatamaskPolicyPlan;
+use super::AmendTablePlan;
 use super::CopyIntoTableMode;
+use super::CreateBloomIndexPlan;
 use super::CreateIndexPlan;
+use super::CreateInvertedIndexPlan;
 use super::CreateShareEndpointPlan;
 use super::DescDatamaskPolicyPlan;
+use super::DropBloomIndexPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropInvertedIndexPlan;
 use super::DropShareEndpointPlan;
 use super::ModifyTableColumnPlan;
 use super::VacuumTablePlan;
@@ -48,33 +53,43 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AlterStatementPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnsPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
+use crate::plans::CommitPreparedPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
+use crate::plans::DeclareResultPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
@@ -84,7 +99,10 @@ use crate::plans::GenerateVirtualColumnsPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::PrepareCommitPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -92,6 +110,7 @@ use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
+use crate::plans::RollbackPreparedPlan;
 use crate::plans::SetRolePlan;
 use crate::plans::SettingPlan;
 use crate::plans::ShowCreateCatalogPlan;
@@ -108,6 +127,7 @@ use crate::plans::UndropTablePlan;
 use crate::plans::UpdatePlan;
 use crate::plans::UseDatabasePlan;
 use crate::BindContext;
+use crate::ColumnOrigin;
 use crate::MetadataRef;
 
 #[derive(Clone, Debug)]
@@ -170,6 +190,7 @@ pub enum Plan {
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
+    AmendTable(Box<AmendTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
@@ -181,15 +202,50 @@ pub enum Plan {
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeInto>),
+
+    // Prepared commits
+    PrepareCommit(Box<PrepareCommitPlan>),
+    CommitPrepared(Box<CommitPreparedPlan>),
+    RollbackPrepared(Box<RollbackPreparedPlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
+    // Results
+    DeclareResult(Box<DeclareResultPlan>),
 
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+    DropMaterializedView(Box<DropMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
+
+    // Tasks
+    CreateTask(Box<CreateTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+
+    // Plan baselines
+    AlterStatement(Box<AlterStatementPlan>),
+
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
+    CreateBloomIndex(Box<CreateBloomIndexPlan>),
+    DropBloomIndex(Box<DropBloomIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumns(Box<CreateVirtualColumnsPlan>),
@@ -271,6 +327,7 @@ pub enum RewriteKind {
     ShowTablesStatus,
 
     ShowFunctions,
+    DescribeFunction,
     ShowTableFunctions,
 
     ShowUsers,
@@ -319,11 +376,25 @@ impl Display for Plan {
             Plan::VacuumTable(_) => write!(f, "VacuumTable"),
             Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
+            Plan::DeclareResult(_) => write!(f, "DeclareResult"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::DropMaterializedView(_) => write!(f, "DropMaterializedView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::AlterStatement(_) => write!(f, "AlterStatement"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
+            Plan::CreateBloomIndex(_) => write!(f, "CreateBloomIndex"),
+            Plan::DropBloomIndex(_) => write!(f, "DropBloomIndex"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
             Plan::AlterVirtualColumns(_) => write!(f, "AlterVirtualColumns"),
             Plan::DropVirtualColumns(_) => write!(f, "DropVirtualColumns"),
@@ -352,6 +423,13 @@ impl Display for Plan {
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
+            Plan::PrepareCommit(_) => write!(f, "PrepareCommit"),
+            Plan::CommitPrepared(_) => write!(f, "CommitPrepared"),
+            Plan::RollbackPrepared(_) => write!(f, "RollbackPrepared"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
@@ -373,6 +451,7 @@ impl Display for Plan {
             Plan::ExplainAst { .. } => write!(f, "ExplainAst"),
             Plan::ExplainSyntax { .. } => write!(f, "ExplainSyntax"),
             Plan::RevertTable(..) => write!(f, "RevertTable"),
+            Plan::AmendTable(..) => write!(f, "AmendTable"),
             Plan::CreateDatamaskPolicy(..) => {
                 write!(f, "Create Data Mask Policy")
             }
@@ -408,6 +487,7 @@ impl Plan {
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
+            Plan::AmendTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
@@ -415,6 +495,7 @@ impl Plan {
 
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
+            Plan::PrepareCommit(plan) => plan.plan.schema(),
 
             Plan::Call(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
@@ -432,6 +513,19 @@ impl Plan {
         }
     }
 
+    /// Origins of the result columns, only derivable for `Plan::Query`.
+    /// Other plans return an empty list, which means no column has a known origin.
+    pub fn result_column_origins(&self) -> Vec<Option<ColumnOrigin>> {
+        match self {
+            Plan::Query {
+                metadata,
+                bind_context,
+                ..
+            } => bind_context.output_column_origins(metadata),
+            _ => vec![],
+        }
+    }
+
     pub fn has_result_set(&self) -> bool {
         matches!(
             self,
@@ -454,6 +548,7 @@ impl Plan {
                 | Plan::ShowGrants(_)
                 | Plan::Presign(_)
                 | Plan::VacuumTable(_)
+                | Plan::AmendTable(_)
                 | Plan::DescDatamaskPolicy(_)
         )
     }
@@ -0,0 +1,33 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::plans::Plan;
+
+/// `PREPARE COMMIT '<id>' AS <statement>`, the commit of the write `plan` is kept
+/// aside as the prepared commit `id`.
+#[derive(Clone, Debug)]
+pub struct PrepareCommitPlan {
+    pub id: String,
+    pub plan: Box<Plan>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitPreparedPlan {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollbackPreparedPlan {
+    pub id: String,
+}
@@ -24,4 +24,6 @@ pub struct ReclusterTablePlan {
     pub is_final: bool,
     pub metadata: MetadataRef,
     pub push_downs: Option<ScalarExpr>,
+    /// The max number of blocks to rewrite, unlimited if `None`.
+    pub limit: Option<usize>,
 }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::AggIndexDefinition;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -28,6 +29,8 @@ use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Datum;
 use crate::optimizer::Distribution;
+use crate::optimizer::Histogram;
+use crate::optimizer::HistogramBucket;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -58,6 +61,11 @@ pub struct AggIndexInfo {
     pub index_id: u64,
     pub selection: Vec<ScalarExpr>,
     pub predicates: Vec<ScalarExpr>,
+    /// Columns output by the scan: the column index in the query and how to compute it
+    /// from the index data. If the query is aggregated, they are the group items and the
+    /// states of the aggregate functions, and the final aggregation is still needed.
+    pub outputs: Vec<(IndexType, ScalarExpr)>,
+    pub definition: AggIndexDefinition,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -183,13 +191,17 @@ impl Operator for Scan {
                 let min_datum = Datum::from_scalar(&min);
                 let max_datum = Datum::from_scalar(&max);
                 if let (Some(min), Some(max)) = (min_datum, max_datum) {
-                    let histogram = histogram_from_ndv(
-                        col_stat.number_of_distinct_values,
-                        num_rows,
-                        Some((min.clone(), max.clone())),
-                        DEFAULT_HISTOGRAM_BUCKETS,
-                    )
-                    .ok();
+                    // Prefer the histogram collected by `ANALYZE TABLE`, otherwise
+                    // assume the values are uniformly distributed.
+                    let histogram = histogram_from_statistics(col_stat).or_else(|| {
+                        histogram_from_ndv(
+                            col_stat.number_of_distinct_values,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok()
+                    });
                     let column_stat = ColumnStat {
                         min,
                         max,
@@ -232,8 +244,9 @@ impl Operator for Scan {
             (_, _) => 0.0,
         };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If prewhere is not none or the data is read from an aggregating index,
+        // we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.agg_index.is_none() {
             precise_cardinality
         } else {
             None
@@ -258,3 +271,19 @@ impl Operator for Scan {
         unreachable!()
     }
 }
+
+fn histogram_from_statistics(col_stat: &ColumnStatistics) -> Option<Histogram> {
+    if col_stat.histogram.is_empty() {
+        return None;
+    }
+    col_stat
+        .histogram
+        .iter()
+        .map(|bucket| {
+            Datum::from_scalar(&bucket.upper_bound).map(|upper_bound| {
+                HistogramBucket::new(upper_bound, bucket.num_values, bucket.num_distinct)
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(Histogram::new)
+}
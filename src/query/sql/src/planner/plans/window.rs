@@ -183,6 +183,7 @@ pub struct WindowFuncFrame {
     pub units: WindowFuncFrameUnits,
     pub start_bound: WindowFuncFrameBound,
     pub end_bound: WindowFuncFrameBound,
+    pub exclusion: WindowFuncFrameExclusion,
 }
 
 impl Display for WindowFuncFrame {
@@ -191,7 +192,11 @@ impl Display for WindowFuncFrame {
             f,
             "{:?}: {:?} ~ {:?}",
             self.units, self.start_bound, self.end_bound
-        )
+        )?;
+        if self.exclusion != WindowFuncFrameExclusion::NoOthers {
+            write!(f, " exclude {:?}", self.exclusion)?;
+        }
+        Ok(())
     }
 }
 
@@ -213,6 +218,17 @@ pub enum WindowFuncFrameBound {
     Following(Option<Scalar>),
 }
 
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, EnumAsInner)]
+pub enum WindowFuncFrameExclusion {
+    #[default]
+    NoOthers,
+    CurrentRow,
+    /// The current row and its peers
+    Group,
+    /// The peers of the current row, excluding the current row itself
+    Ties,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum WindowFuncType {
     Aggregate(AggregateFunction),
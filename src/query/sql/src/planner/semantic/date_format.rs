@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::Expr;
+use common_ast::ast::Literal;
+use common_ast::Dialect;
+
+/// The patterns of `to_char` of PostgreSQL, matched case-insensitively and longest first.
+const POSTGRES_PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("MONTH", "%B"),
+    ("MON", "%b"),
+    ("MM", "%m"),
+    ("DAY", "%A"),
+    ("DY", "%a"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("TZ", "%Z"),
+    ("IW", "%V"),
+];
+
+/// Rewrite the literal format argument of the date formatting functions of the dialect,
+/// e.g. `DATE_FORMAT(d, '%Y-%m-%d %H:%i:%s')` of MySQL, to the format of chrono used by
+/// the builtin functions.
+///
+/// Returns `None` if there is nothing to rewrite.
+pub(super) fn rewrite_date_format_args(
+    dialect: Dialect,
+    func_name: &str,
+    args: &[Expr],
+) -> Option<Vec<Expr>> {
+    let to_strftime: fn(&str) -> String = match (dialect, func_name) {
+        (Dialect::MySQL, "date_format" | "str_to_date") => mysql_to_strftime,
+        (Dialect::Hive, "date_format") => java_to_strftime,
+        (Dialect::PostgreSQL, "to_char") => postgres_to_strftime,
+        _ => return None,
+    };
+    match args {
+        [
+            arg,
+            Expr::Literal {
+                span,
+                lit: Literal::String(format),
+            },
+        ] => Some(vec![arg.clone(), Expr::Literal {
+            span: *span,
+            lit: Literal::String(to_strftime(format)),
+        }]),
+        _ => None,
+    }
+}
+
+fn push_literal(output: &mut String, c: char) {
+    if c == '%' {
+        output.push_str("%%");
+    } else {
+        output.push(c);
+    }
+}
+
+/// `%` codes of MySQL, an unknown code is the character itself.
+fn mysql_to_strftime(format: &str) -> String {
+    let mut output = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            push_literal(&mut output, c);
+            continue;
+        }
+        let code = match chars.next() {
+            Some(code) => code,
+            None => {
+                output.push_str("%%");
+                break;
+            }
+        };
+        let spec = match code {
+            'a' => "%a",
+            'b' => "%b",
+            'c' => "%-m",
+            'd' => "%d",
+            'e' => "%-d",
+            'f' => "%6f",
+            'H' => "%H",
+            'h' | 'I' => "%I",
+            'i' => "%M",
+            'j' => "%j",
+            'k' => "%-H",
+            'l' => "%-I",
+            'M' => "%B",
+            'm' => "%m",
+            'p' => "%p",
+            'r' => "%I:%M:%S %p",
+            'S' | 's' => "%S",
+            'T' => "%H:%M:%S",
+            'U' => "%U",
+            'u' => "%W",
+            'W' => "%A",
+            'w' => "%w",
+            'Y' => "%Y",
+            'y' => "%y",
+            other => {
+                push_literal(&mut output, other);
+                continue;
+            }
+        };
+        output.push_str(spec);
+    }
+    output
+}
+
+/// Patterns of `SimpleDateFormat` of Java used by Hive, text in single quotes is literal.
+fn java_to_strftime(format: &str) -> String {
+    let chars = format.chars().collect::<Vec<_>>();
+    let mut output = String::with_capacity(format.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\'' {
+            // `''` is a single quote
+            if chars.get(i + 1) == Some(&'\'') {
+                output.push('\'');
+                i += 2;
+                continue;
+            }
+            i += 1;
+            while i < chars.len() && chars[i] != '\'' {
+                push_literal(&mut output, chars[i]);
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            push_literal(&mut output, c);
+            i += 1;
+            continue;
+        }
+
+        let mut n = 1;
+        while chars.get(i + n) == Some(&c) {
+            n += 1;
+        }
+        i += n;
+        let spec = match (c, n) {
+            ('y', 2) => "%y",
+            ('y', _) => "%Y",
+            ('M', 1) => "%-m",
+            ('M', 2) => "%m",
+            ('M', 3) => "%b",
+            ('M', _) => "%B",
+            ('d', 1) => "%-d",
+            ('d', _) => "%d",
+            ('D', _) => "%j",
+            ('H', 1) => "%-H",
+            ('H', _) => "%H",
+            ('h', 1) => "%-I",
+            ('h', _) => "%I",
+            ('m', 1) => "%-M",
+            ('m', _) => "%M",
+            ('s', 1) => "%-S",
+            ('s', _) => "%S",
+            ('S', _) => "%3f",
+            ('a', _) => "%p",
+            ('E', 1..=3) => "%a",
+            ('E', _) => "%A",
+            ('u', _) => "%u",
+            ('Z', _) => "%z",
+            ('z', _) => "%Z",
+            _ => {
+                (0..n).for_each(|_| output.push(c));
+                continue;
+            }
+        };
+        output.push_str(spec);
+    }
+    output
+}
+
+/// Template patterns of PostgreSQL, text in double quotes is literal.
+fn postgres_to_strftime(format: &str) -> String {
+    let mut output = String::with_capacity(format.len());
+    let mut rest = format;
+    'outer: while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            let text = &rest[1..];
+            let end = text.find('"').unwrap_or(text.len());
+            text[..end]
+                .chars()
+                .for_each(|c| push_literal(&mut output, c));
+            rest = text.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+        for (pattern, spec) in POSTGRES_PATTERNS {
+            if let Some(prefix) = rest.get(..pattern.len()) {
+                if prefix.eq_ignore_ascii_case(pattern) {
+                    output.push_str(spec);
+                    rest = &rest[pattern.len()..];
+                    continue 'outer;
+                }
+            }
+        }
+        push_literal(&mut output, c);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
@@ -13,14 +13,17 @@
 // limitations under the License.
 
 use common_ast::ast::Expr;
-use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::TableReference;
 use common_ast::VisitorMut;
 
+/// Rewrites `SELECT count(DISTINCT a) FROM t` to `SELECT count() FROM (SELECT DISTINCT a FROM t)`,
+/// the rows are deduplicated by the distinct operator in parallel instead of being kept in
+/// the state of a single aggregate function.
 #[derive(Debug, Clone, Default)]
 pub struct DistinctToGroupBy {}
 
@@ -37,7 +40,7 @@ impl VisitorMut for DistinctToGroupBy {
         } = stmt;
 
         if group_by.is_none() && select_list.len() == 1 && from.len() == 1 {
-            if let common_ast::ast::SelectTarget::AliasedExpr {
+            if let SelectTarget::AliasedExpr {
                 expr:
                     box Expr::FunctionCall {
                         span,
@@ -59,11 +62,17 @@ impl VisitorMut for DistinctToGroupBy {
                         body: SetExpr::Select(Box::new(SelectStmt {
                             span: None,
                             hints: None,
-                            distinct: false,
-                            select_list: vec![],
+                            distinct: true,
+                            select_list: args
+                                .iter()
+                                .map(|arg| SelectTarget::AliasedExpr {
+                                    expr: Box::new(arg.clone()),
+                                    alias: None,
+                                })
+                                .collect(),
                             from: from.clone(),
                             selection: selection.clone(),
-                            group_by: Some(GroupBy::Normal(args.clone())),
+                            group_by: None,
                             having: None,
                             window_list: None,
                         })),
@@ -77,7 +86,7 @@ impl VisitorMut for DistinctToGroupBy {
                         span: None,
                         hints: None,
                         distinct: false,
-                        select_list: vec![common_ast::ast::SelectTarget::AliasedExpr {
+                        select_list: vec![SelectTarget::AliasedExpr {
                             expr: Box::new(Expr::FunctionCall {
                                 span: None,
                                 distinct: false,
@@ -89,11 +98,13 @@ impl VisitorMut for DistinctToGroupBy {
                                 args: vec![],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             }),
                             alias: alias.clone(),
                         }],
                         from: vec![TableReference::Subquery {
                             span: None,
+                            lateral: false,
                             subquery: Box::new(subquery),
                             alias: None,
                         }],
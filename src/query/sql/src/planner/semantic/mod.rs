@@ -13,10 +13,12 @@
 // limitations under the License.
 
 mod aggregate_rewriter;
+mod date_format;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod placeholder;
 mod type_check;
 mod window_check;
 
@@ -28,6 +30,8 @@ pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use placeholder::bind_placeholders;
+pub use placeholder::count_placeholders;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -0,0 +1,167 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_ast::ast::Expr;
+use common_ast::ast::InsertSource;
+use common_ast::ast::InsertStmt;
+use common_ast::ast::ReplaceStmt;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::ast::UpdateStmt;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_exception::ErrorCode;
+use common_exception::Range;
+use common_exception::Result;
+
+/// Returns the number of the `?` placeholders of a prepared statement.
+pub fn count_placeholders(stmt: &Statement) -> Result<usize> {
+    let mut stmt = stmt.clone();
+    if let Some(values) = insert_values(&mut stmt) {
+        return Ok(values_placeholders(values)?.len());
+    }
+
+    let mut visitor = PlaceholderVisitor::default();
+    visitor.visit_statement(&mut stmt);
+    Ok(visitor.positions.len())
+}
+
+/// Binds the parameters to the `?` placeholders of a prepared statement, in the order
+/// the placeholders appear in the SQL text.
+///
+/// The raw `VALUES` of `INSERT` and `REPLACE` are parsed at execute time, so their
+/// placeholders are replaced with the parameters in the text.
+pub fn bind_placeholders(stmt: &mut Statement, params: &[Expr]) -> Result<()> {
+    if let Some(values) = insert_values(stmt) {
+        let spans = values_placeholders(values)?;
+        check_num_params(spans.len(), params.len())?;
+
+        let mut bound = String::with_capacity(values.len());
+        let mut pos = 0;
+        for (span, param) in spans.iter().zip(params.iter()) {
+            bound.push_str(&values[pos..span.start]);
+            bound.push_str(&param.to_string());
+            pos = span.end;
+        }
+        bound.push_str(&values[pos..]);
+        *values = bound;
+        return Ok(());
+    }
+
+    let mut visitor = PlaceholderVisitor::default();
+    visitor.visit_statement(stmt);
+    check_num_params(visitor.positions.len(), params.len())?;
+
+    // The visiting order of the expressions may differ from the SQL text, e.g. the
+    // conditions of `CASE` are visited before the results.
+    let mut positions = visitor.positions;
+    positions.sort();
+    let mut visitor = PlaceholderVisitor {
+        positions: vec![],
+        params: positions.into_iter().zip(params.iter().cloned()).collect(),
+    };
+    visitor.visit_statement(stmt);
+    Ok(())
+}
+
+fn check_num_params(num_placeholders: usize, num_params: usize) -> Result<()> {
+    if num_placeholders != num_params {
+        return Err(ErrorCode::BadArguments(format!(
+            "The prepared statement expects {} parameters, but got {}",
+            num_placeholders, num_params
+        )));
+    }
+    Ok(())
+}
+
+fn insert_values(stmt: &mut Statement) -> Option<&mut String> {
+    match stmt {
+        Statement::Insert(InsertStmt {
+            source: InsertSource::Values { rest_str },
+            ..
+        })
+        | Statement::Replace(ReplaceStmt {
+            source: InsertSource::Values { rest_str },
+            ..
+        }) => Some(rest_str),
+        _ => None,
+    }
+}
+
+fn values_placeholders(values: &str) -> Result<Vec<Range>> {
+    Tokenizer::new(values)
+        .filter_map(|token| match token {
+            Ok(token) if token.kind == TokenKind::Placeholder => Some(Ok(token.span)),
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct PlaceholderVisitor {
+    /// The start positions of the placeholders visited.
+    positions: Vec<usize>,
+    /// The parameters to bind, keyed by the start positions of the placeholders.
+    params: HashMap<usize, Expr>,
+}
+
+impl VisitorMut for PlaceholderVisitor {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span } = expr {
+            let position = span.map(|span| span.start).unwrap_or_default();
+            self.positions.push(position);
+            if let Some(param) = self.params.get(&position) {
+                *expr = param.clone();
+            }
+        } else {
+            walk_expr_mut(self, expr);
+        }
+    }
+
+    fn visit_insert(&mut self, insert: &mut InsertStmt) {
+        if let InsertSource::Select { query } = &mut insert.source {
+            self.visit_query(query);
+        }
+    }
+
+    fn visit_replace(&mut self, replace: &mut ReplaceStmt) {
+        if let InsertSource::Select { query } = &mut replace.source {
+            self.visit_query(query);
+        }
+    }
+
+    fn visit_delete(
+        &mut self,
+        _table_reference: &mut TableReference,
+        selection: &mut Option<Expr>,
+    ) {
+        if let Some(selection) = selection {
+            self.visit_expr(selection);
+        }
+    }
+
+    fn visit_update(&mut self, update: &mut UpdateStmt) {
+        for update_expr in update.update_list.iter_mut() {
+            self.visit_expr(&mut update_expr.expr);
+        }
+        if let Some(selection) = &mut update.selection {
+            self.visit_expr(selection);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use common_ast::ast::ColumnID;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
@@ -32,6 +33,7 @@ use common_ast::ast::UnaryOperator;
 use common_ast::ast::Window;
 use common_ast::ast::WindowFrame;
 use common_ast::ast::WindowFrameBound;
+use common_ast::ast::WindowFrameExclusion;
 use common_ast::ast::WindowFrameUnits;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
@@ -73,21 +75,27 @@ use crate::binder::Binder;
 use crate::binder::ExprContext;
 use crate::binder::NameResolutionResult;
 use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
 use crate::planner::metadata::optimize_remove_count_args;
+use crate::planner::semantic::date_format::rewrite_date_format_args;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameExclusion;
 use crate::plans::WindowFuncFrameUnits;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -323,11 +331,20 @@ impl<'a> TypeChecker<'a> {
                 ..
             } => {
                 let get_max_inlist_to_or = self.ctx.get_settings().get_max_inlist_to_or()? as usize;
-                if list.len() > get_max_inlist_to_or
-                    && list
-                        .iter()
-                        .all(|e| matches!(e, Expr::Literal { lit, .. } if lit != &Literal::Null))
-                {
+                let is_constant = |e: &Expr| match e {
+                    Expr::Literal { lit, .. } => lit != &Literal::Null,
+                    // Negative numbers are parsed as the negation of a literal.
+                    Expr::UnaryOp {
+                        op: UnaryOperator::Minus,
+                        expr,
+                        ..
+                    } => matches!(expr.as_ref(), Expr::Literal {
+                        lit: Literal::UInt64(_) | Literal::Float64(_) | Literal::Decimal256 { .. },
+                        ..
+                    }),
+                    _ => false,
+                };
+                if list.len() > get_max_inlist_to_or && list.iter().all(is_constant) {
                     let array_expr = Expr::Array {
                         span: *span,
                         exprs: list.clone(),
@@ -345,6 +362,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -570,6 +588,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -620,14 +639,26 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
+                let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
-                let func_name = func_name.as_str();
+                let mut func_name = func_name.as_str();
+                if let Some(lambda) = lambda {
+                    return self
+                        .resolve_lambda_function(*span, func_name, args, lambda)
+                        .await;
+                }
+                // The format codes of the dialect are rewritten before the function is renamed.
+                let dialect_args = rewrite_date_format_args(sql_dialect, func_name, args);
+                let args = dialect_args.as_ref().unwrap_or(args);
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
                     if let Some(udf) = self.resolve_udf(*span, func_name, args).await? {
                         return Ok(udf);
+                    } else if let Some(alias) = sql_dialect.function_alias(func_name) {
+                        func_name = alias;
                     } else {
                         // Function not found, try to find and suggest similar function name.
                         let all_funcs = BUILTIN_FUNCTIONS
@@ -814,6 +845,18 @@ impl<'a> TypeChecker<'a> {
                 .await?
             }
 
+            // The key isn't a literal, e.g. `m[k]`, it's desugared into a `get` function.
+            Expr::MapAccess {
+                span,
+                expr: inner_expr,
+                accessor: MapAccessor::Bracket { key },
+            } if !matches!(**key, Expr::Literal { .. }) => {
+                let box (inner_arg, _) = self.resolve(inner_expr).await?;
+                let box (key_arg, _) = self.resolve(key).await?;
+                self.resolve_scalar_function_call(*span, "get", vec![], vec![inner_arg, key_arg])
+                    .await?
+            }
+
             expr @ Expr::MapAccess { .. } => {
                 let mut expr = expr;
                 let mut paths = VecDeque::new();
@@ -823,24 +866,19 @@ impl<'a> TypeChecker<'a> {
                     accessor,
                 } = expr
                 {
-                    expr = &**inner_expr;
                     let path = match accessor {
                         MapAccessor::Bracket {
                             key: box Expr::Literal { lit, .. },
                         } => lit.clone(),
+                        // The rest of the accessors are resolved along with the inner expression.
+                        MapAccessor::Bracket { .. } => break,
                         MapAccessor::Period { key } | MapAccessor::Colon { key } => {
                             Literal::String(key.name.clone())
                         }
                         MapAccessor::PeriodNumber { key } => Literal::UInt64(*key),
-                        _ => {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "Unsupported accessor: {:?}",
-                                accessor
-                            ))
-                            .set_span(*span));
-                        }
                     };
                     paths.push_front((*span, path));
+                    expr = &**inner_expr;
                 }
                 self.resolve_map_access(expr, paths).await?
             }
@@ -909,6 +947,13 @@ impl<'a> TypeChecker<'a> {
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs).await?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs).await?,
+
+            Expr::Placeholder { span } => {
+                return Err(ErrorCode::SemanticError(
+                    "placeholder `?` is only allowed in prepared statements".to_string(),
+                )
+                .set_span(*span));
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1016,6 +1061,17 @@ impl<'a> TypeChecker<'a> {
         .set_span(expr.span()))
     }
 
+    fn resolve_window_frame_exclusion(
+        exclusion: &WindowFrameExclusion,
+    ) -> WindowFuncFrameExclusion {
+        match exclusion {
+            WindowFrameExclusion::NoOthers => WindowFuncFrameExclusion::NoOthers,
+            WindowFrameExclusion::CurrentRow => WindowFuncFrameExclusion::CurrentRow,
+            WindowFrameExclusion::Group => WindowFuncFrameExclusion::Group,
+            WindowFrameExclusion::Ties => WindowFuncFrameExclusion::Ties,
+        }
+    }
+
     fn resolve_window_rows_frame(&self, frame: WindowFrame) -> Result<WindowFuncFrame> {
         let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
@@ -1060,6 +1116,7 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(&frame.exclusion),
         })
     }
 
@@ -1108,6 +1165,7 @@ impl<'a> TypeChecker<'a> {
             units,
             start_bound: start,
             end_bound: end,
+            exclusion: Self::resolve_window_frame_exclusion(&frame.exclusion),
         })
     }
 
@@ -1125,6 +1183,7 @@ impl<'a> TypeChecker<'a> {
                     units: WindowFuncFrameUnits::Rows,
                     start_bound: WindowFuncFrameBound::Preceding(None),
                     end_bound: WindowFuncFrameBound::Following(None),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
@@ -1136,6 +1195,7 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Preceding(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             WindowFuncType::LagLead(lag_lead) => {
@@ -1147,12 +1207,33 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(Some(Scalar::Number(
                         NumberScalar::UInt64(lag_lead.offset),
                     ))),
+                    exclusion: WindowFuncFrameExclusion::NoOthers,
                 });
             }
             _ => {}
         }
         if let Some(frame) = window_frame {
-            if frame.units.is_range() {
+            if matches!(frame.start_bound, WindowFrameBound::Following(None)) {
+                return Err(ErrorCode::SemanticError(
+                    "Frame start cannot be UNBOUNDED FOLLOWING".to_string(),
+                )
+                .set_span(span));
+            }
+            if matches!(frame.end_bound, WindowFrameBound::Preceding(None)) {
+                return Err(ErrorCode::SemanticError(
+                    "Frame end cannot be UNBOUNDED PRECEDING".to_string(),
+                )
+                .set_span(span));
+            }
+            let has_offset = |bound: &WindowFrameBound| {
+                matches!(
+                    bound,
+                    WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+                )
+            };
+            if frame.units.is_range()
+                && (has_offset(&frame.start_bound) || has_offset(&frame.end_bound))
+            {
                 if order_by.len() != 1 {
                     return Err(ErrorCode::SemanticError(format!(
                         "The RANGE OFFSET window frame requires exactly one ORDER BY column, {} given.",
@@ -1168,12 +1249,14 @@ impl<'a> TypeChecker<'a> {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::Following(None),
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         } else {
             Ok(WindowFuncFrame {
                 units: WindowFuncFrameUnits::Range,
                 start_bound: WindowFuncFrameBound::Preceding(None),
                 end_bound: WindowFuncFrameBound::CurrentRow,
+                exclusion: WindowFuncFrameExclusion::NoOthers,
             })
         }
     }
@@ -1563,10 +1646,52 @@ impl<'a> TypeChecker<'a> {
         left: &Expr,
         right: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
         match op {
-            BinaryOperator::NotLike | BinaryOperator::NotRegexp | BinaryOperator::NotRLike => {
+            BinaryOperator::Divide
+            | BinaryOperator::Div
+            | BinaryOperator::IntDiv
+            | BinaryOperator::Modulo
+                if sql_dialect.is_divide_by_zero_null() =>
+            {
+                // rewrite "expr1 / expr2" to "IF(expr2 = 0, NULL, expr1 / expr2)"
+                let box (left, _) = self.resolve(left).await?;
+                let box (right, _) = self.resolve(right).await?;
+                let zero = ConstantExpr {
+                    span,
+                    value: Scalar::Number(NumberScalar::UInt8(0)),
+                };
+                let (is_zero, _) = *self
+                    .resolve_scalar_function_call(
+                        span,
+                        &BinaryOperator::Eq.to_func_name(),
+                        vec![],
+                        vec![right.clone(), zero.into()],
+                    )
+                    .await?;
+                let (result, _) = *self
+                    .resolve_scalar_function_call(span, &op.to_func_name(), vec![], vec![
+                        left, right,
+                    ])
+                    .await?;
+                let null = ConstantExpr {
+                    span,
+                    value: Scalar::Null,
+                };
+                self.resolve_scalar_function_call(span, "if", vec![], vec![
+                    is_zero,
+                    null.into(),
+                    result,
+                ])
+                .await
+            }
+            BinaryOperator::NotLike
+            | BinaryOperator::NotILike
+            | BinaryOperator::NotRegexp
+            | BinaryOperator::NotRLike => {
                 let positive_op = match op {
                     BinaryOperator::NotLike => BinaryOperator::Like,
+                    BinaryOperator::NotILike => BinaryOperator::ILike,
                     BinaryOperator::NotRegexp => BinaryOperator::Regexp,
                     BinaryOperator::NotRLike => BinaryOperator::RLike,
                     _ => unreachable!(),
@@ -1797,23 +1922,40 @@ impl<'a> TypeChecker<'a> {
         let mut bind_context = BindContext::with_parent(Box::new(self.bind_context.clone()));
         let (s_expr, output_context) = binder.bind_query(&mut bind_context, subquery).await?;
 
-        if (typ == SubqueryType::Scalar || typ == SubqueryType::Any)
-            && output_context.columns.len() > 1
-        {
-            return Err(ErrorCode::SemanticError(format!(
-                "Subquery must return only one column, but got {} columns",
-                output_context.columns.len()
-            )));
-        }
+        let num_columns = output_context.columns.len();
+        let (s_expr, output_column) = match &child_expr {
+            // Row-value comparison such as `(a, b) IN (SELECT x, y FROM t)`, the output
+            // columns of the subquery are packed into a tuple to compare with the row.
+            Some(Expr::Tuple { exprs, .. }) if typ == SubqueryType::Any && num_columns > 1 => {
+                if exprs.len() != num_columns {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Subquery must return {} columns to compare with the row, but got {} columns",
+                        exprs.len(),
+                        num_columns
+                    ))
+                    .set_span(subquery.span));
+                }
+                self.pack_subquery_columns(subquery.span, s_expr, &output_context.columns)
+                    .await?
+            }
+            _ => {
+                if (typ == SubqueryType::Scalar || typ == SubqueryType::Any) && num_columns > 1 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Subquery must return only one column, but got {} columns",
+                        num_columns
+                    )));
+                }
+                (s_expr, output_context.columns[0].clone())
+            }
+        };
 
-        let mut data_type = output_context.columns[0].data_type.clone();
+        let mut data_type = output_column.data_type.clone();
 
         let rel_expr = RelExpr::with_s_expr(&s_expr);
         let rel_prop = rel_expr.derive_relational_prop()?;
 
         let mut child_scalar = None;
         if let Some(expr) = child_expr {
-            assert_eq!(output_context.columns.len(), 1);
             let box (scalar, _) = self.resolve(&expr).await?;
             child_scalar = Some(Box::new(scalar));
         }
@@ -1827,7 +1969,7 @@ impl<'a> TypeChecker<'a> {
             subquery: Box::new(s_expr),
             child_expr: child_scalar,
             compare_op,
-            output_column: output_context.columns[0].clone(),
+            output_column,
             projection_index: None,
             data_type: data_type.clone(),
             typ,
@@ -1838,6 +1980,52 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((subquery_expr.into(), data_type)))
     }
 
+    /// Packs the output columns of a subquery into a tuple column, returns the subquery
+    /// evaluating the tuple and the binding of the tuple column.
+    #[async_backtrace::framed]
+    async fn pack_subquery_columns(
+        &mut self,
+        span: Span,
+        s_expr: SExpr,
+        columns: &[ColumnBinding],
+    ) -> Result<(SExpr, ColumnBinding)> {
+        let args = columns
+            .iter()
+            .map(|column| {
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                })
+            })
+            .collect();
+        let box (scalar, data_type) = self
+            .resolve_scalar_function_call(span, "tuple", vec![], args)
+            .await?;
+
+        let column_name = "subquery_tuple".to_string();
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(column_name.clone(), data_type.clone());
+        let eval_scalar = EvalScalar {
+            items: vec![ScalarItem { scalar, index }],
+        };
+        let s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+
+        let column = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_position: None,
+            table_index: None,
+            column_name,
+            index,
+            data_type: Box::new(data_type),
+            visibility: Visibility::Visible,
+            virtual_computed_expr: None,
+        };
+        Ok((s_expr, column))
+    }
+
     pub fn all_rewritable_scalar_function() -> &'static [&'static str] {
         &[
             "database",
@@ -1857,6 +2045,7 @@ impl<'a> TypeChecker<'a> {
             "last_query_id",
             "array_sort",
             "array_aggregate",
+            "query",
         ]
     }
 
@@ -1970,6 +2159,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -2006,6 +2196,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -2183,6 +2374,62 @@ impl<'a> TypeChecker<'a> {
                     "Aggregate function name be a constant string",
                 )))
             }
+            ("query", &[arg]) => {
+                // Rewrite query('col1:terms col2:"more terms"') to
+                // match(col1, 'terms') AND match(col2, 'more terms')
+                let Expr::Literal {
+                    lit: Literal::String(query),
+                    ..
+                } = arg
+                else {
+                    return Some(Err(ErrorCode::SemanticError(
+                        "QUERY needs a constant string argument",
+                    )
+                    .set_span(span)));
+                };
+                let column_terms = match parse_full_text_query(query) {
+                    Ok(column_terms) => column_terms,
+                    Err(e) => return Some(Err(e.set_span(span))),
+                };
+                let match_expr = column_terms
+                    .into_iter()
+                    .map(|(column, terms)| Expr::FunctionCall {
+                        span,
+                        distinct: false,
+                        name: Identifier {
+                            name: "match".to_string(),
+                            quote: None,
+                            span,
+                        },
+                        args: vec![
+                            Expr::ColumnRef {
+                                span,
+                                database: None,
+                                table: None,
+                                column: ColumnID::Name(Identifier {
+                                    name: column,
+                                    quote: None,
+                                    span,
+                                }),
+                            },
+                            Expr::Literal {
+                                span,
+                                lit: Literal::String(terms),
+                            },
+                        ],
+                        params: vec![],
+                        window: None,
+                        lambda: None,
+                    })
+                    .reduce(|left, right| Expr::BinaryOp {
+                        span,
+                        op: BinaryOperator::And,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    })
+                    .unwrap();
+                Some(self.resolve(&match_expr).await)
+            }
             _ => None,
         }
     }
@@ -2405,6 +2652,188 @@ impl<'a> TypeChecker<'a> {
         Ok(Some(self.resolve(&udf_expr).await?))
     }
 
+    /// Resolve a higher-order function like `array_transform(arr, x -> x + 1)`.
+    ///
+    /// The parameters of the lambda are bound as new columns of the elements, and the
+    /// outer columns used in the lambda are passed to the function after the array.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = func_name.to_lowercase();
+        let params_len = match func_name.as_str() {
+            "array_transform" | "array_filter" => 1,
+            "array_reduce" | "array_sort" => 2,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} doesn't accept a lambda"
+                ))
+                .set_span(span));
+            }
+        };
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} requires an array and a lambda, but got {} arguments",
+                args.len() + 1
+            ))
+            .set_span(span));
+        }
+        if lambda.params.len() != params_len {
+            return Err(ErrorCode::SemanticError(format!(
+                "the lambda of function {func_name} requires {params_len} parameters, but got {}",
+                lambda.params.len()
+            ))
+            .set_span(lambda.expr.span()));
+        }
+
+        let box (arg, arg_type) = self.resolve(&args[0]).await?;
+        let element_type = match arg_type.remove_nullable() {
+            DataType::Array(box element_type) => element_type,
+            // The lambda is never called on the elements of an empty array.
+            DataType::EmptyArray | DataType::Null => {
+                return if func_name == "array_reduce" || arg_type == DataType::Null {
+                    Ok(Box::new((
+                        ConstantExpr {
+                            span,
+                            value: Scalar::Null,
+                        }
+                        .into(),
+                        DataType::Null,
+                    )))
+                } else {
+                    Ok(Box::new((arg, arg_type)))
+                };
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} requires an array, but got {arg_type}"
+                ))
+                .set_span(args[0].span()));
+            }
+        };
+
+        let mut lambda_context = BindContext::with_parent(Box::new(self.bind_context.clone()));
+        let mut params = Vec::with_capacity(params_len);
+        for param in lambda.params.iter() {
+            let column_name = normalize_identifier(param, self.name_resolution_ctx).name;
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), element_type.clone());
+            lambda_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(element_type.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+            params.push(index);
+        }
+
+        let mut type_checker = TypeChecker::new(
+            &mut lambda_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.allow_pushdown,
+        );
+        let box (body, _) = type_checker.resolve(&lambda.expr).await?;
+        if contains_aggregate_or_subquery(&body) {
+            return Err(ErrorCode::SemanticError(
+                "lambda can't contain aggregate functions, window functions or subqueries"
+                    .to_string(),
+            )
+            .set_span(lambda.expr.span()));
+        }
+
+        let body = body.as_expr()?;
+        let body = match func_name.as_str() {
+            "array_filter" => {
+                if !matches!(body.data_type().remove_nullable(), DataType::Boolean) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "the lambda of function array_filter must return a boolean, but got {}",
+                        body.data_type()
+                    ))
+                    .set_span(lambda.expr.span()));
+                }
+                body
+            }
+            "array_reduce" => {
+                type_check::check_cast(body.span(), false, body, &element_type, &BUILTIN_FUNCTIONS)?
+            }
+            "array_sort" => {
+                let dest_type = if body.data_type().is_nullable_or_null() {
+                    DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int64)))
+                } else {
+                    DataType::Number(NumberDataType::Int64)
+                };
+                type_check::check_cast(body.span(), false, body, &dest_type, &BUILTIN_FUNCTIONS)?
+            }
+            _ => body,
+        };
+
+        let mut outer_columns = body
+            .column_refs()
+            .into_keys()
+            .filter(|column| !params.contains(&column.index))
+            .collect::<Vec<_>>();
+        outer_columns.sort_by_key(|column| column.index);
+        outer_columns.dedup_by_key(|column| column.index);
+
+        let return_type = match func_name.as_str() {
+            "array_transform" if arg_type.is_nullable() => DataType::Nullable(Box::new(
+                DataType::Array(Box::new(body.data_type().clone())),
+            )),
+            "array_transform" => DataType::Array(Box::new(body.data_type().clone())),
+            "array_reduce" => element_type.wrap_nullable(),
+            _ => arg_type.clone(),
+        };
+        let lambda_expr = body
+            .project_column_ref(
+                |column| match params.iter().position(|p| *p == column.index) {
+                    Some(position) => position,
+                    None => {
+                        params.len()
+                            + outer_columns
+                                .iter()
+                                .position(|outer| outer.index == column.index)
+                                .unwrap()
+                    }
+                },
+            )
+            .as_remote_expr();
+
+        let mut lambda_args = vec![arg];
+        lambda_args.extend(
+            outer_columns
+                .into_iter()
+                .map(|column| BoundColumnRef { span, column }.into()),
+        );
+
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name,
+                args: lambda_args,
+                lambda_expr: Box::new(lambda_expr),
+                lambda_display: lambda.to_string(),
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_map_access(
@@ -2889,6 +3318,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2899,6 +3329,15 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: match lambda {
+                        Some(lambda) => Some(Lambda {
+                            params: lambda.params.clone(),
+                            expr: Box::new(
+                                self.clone_expr_with_replacement(&lambda.expr, replacement_fn)?,
+                            ),
+                        }),
+                        None => None,
+                    },
                 }),
                 Expr::Case {
                     span,
@@ -3098,6 +3537,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Uuid => TableDataType::Uuid,
+        TypeName::Geometry => TableDataType::Geometry,
     };
 
     Ok(data_type)
@@ -3134,6 +3575,46 @@ pub fn validate_function_arg(
 }
 
 // Some check functions for like expression
+/// Parse the query of the `QUERY` function, which is made of `<column>:<term>` and
+/// `<column>:"<term> ..."`, into the columns with their terms. The terms of the same
+/// column are joined.
+fn parse_full_text_query(query: &str) -> Result<Vec<(String, String)>> {
+    let mut column_terms: Vec<(String, String)> = vec![];
+    let mut rest = query.trim_start();
+    while !rest.is_empty() {
+        let invalid = || {
+            ErrorCode::SemanticError(format!(
+                "Invalid query '{query}' of QUERY, expect <column>:<terms> separated by spaces"
+            ))
+        };
+        let (column, value) = rest.split_once(':').ok_or_else(invalid)?;
+        if column.is_empty() || column.contains(char::is_whitespace) {
+            return Err(invalid());
+        }
+        let (terms, next) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').ok_or_else(invalid)?,
+            None => value.split_at(value.find(char::is_whitespace).unwrap_or(value.len())),
+        };
+        if terms.trim().is_empty() {
+            return Err(invalid());
+        }
+        match column_terms.iter_mut().find(|(c, _)| c == column) {
+            Some((_, column_terms)) => {
+                column_terms.push(' ');
+                column_terms.push_str(terms);
+            }
+            None => column_terms.push((column.to_string(), terms.to_string())),
+        }
+        rest = next.trim_start();
+    }
+    if column_terms.is_empty() {
+        return Err(ErrorCode::SemanticError(
+            "The query of QUERY must not be empty",
+        ));
+    }
+    Ok(column_terms)
+}
+
 fn check_const(like_str: &str) -> bool {
     for char in like_str.chars() {
         if char == '_' || char == '%' {
@@ -3171,3 +3652,19 @@ fn check_prefix(like_str: &str) -> bool {
     }
     true
 }
+
+/// Check if the scalar contains aggregate functions, window functions or subqueries,
+/// which can't be evaluated on the elements of an array.
+fn contains_aggregate_or_subquery(scalar: &ScalarExpr) -> bool {
+    match scalar {
+        ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => false,
+        ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contains_aggregate_or_subquery),
+        ScalarExpr::CastExpr(cast) => contains_aggregate_or_subquery(&cast.argument),
+        ScalarExpr::LambdaFunction(lambda) => {
+            lambda.args.iter().any(contains_aggregate_or_subquery)
+        }
+        ScalarExpr::AggregateFunction(_)
+        | ScalarExpr::WindowFunction(_)
+        | ScalarExpr::SubqueryExpr(_) => true,
+    }
+}
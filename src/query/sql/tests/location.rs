@@ -184,6 +184,7 @@ fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    session_tags: Default::default(),
                     allow_anonymous: false,
                 }),
                 "/".to_string(),
@@ -219,6 +220,7 @@ fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    session_tags: Default::default(),
                     allow_anonymous: false,
                 }),
                 "/".to_string(),
@@ -254,6 +256,7 @@ fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    session_tags: Default::default(),
                     allow_anonymous: false,
                 }),
                 "/".to_string(),
@@ -285,6 +288,46 @@ fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "aws::iam::xxxx".to_string(),
                     external_id: "".to_string(),
+                    session_tags: Default::default(),
+                    allow_anonymous: false,
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "s3_with_role_arn_and_session_tags",
+            UriLocation::new(
+                "s3".to_string(),
+                "test".to_string(),
+                "/tmp/".to_string(),
+                "".to_string(),
+                vec![
+                    ("role_arn", "aws::iam::xxxx"),
+                    ("external_id", "databend"),
+                    ("session_tags", "team=data, env=prod"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+            ),
+            (
+                StorageParams::S3(StorageS3Config {
+                    endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+                    region: "".to_string(),
+                    bucket: "test".to_string(),
+                    access_key_id: "".to_string(),
+                    secret_access_key: "".to_string(),
+                    security_token: "".to_string(),
+                    master_key: "".to_string(),
+                    root: "/tmp/".to_string(),
+                    disable_credential_loader: true,
+                    enable_virtual_host_style: false,
+                    role_arn: "aws::iam::xxxx".to_string(),
+                    external_id: "databend".to_string(),
+                    session_tags: BTreeMap::from([
+                        ("env".to_string(), "prod".to_string()),
+                        ("team".to_string(), "data".to_string()),
+                    ]),
                     allow_anonymous: false,
                 }),
                 "/".to_string(),
@@ -12,13 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::DictionaryArray;
+use common_arrow::arrow::array::MutableBinaryArray;
+use common_arrow::arrow::array::MutableDictionaryArray;
+use common_arrow::arrow::array::TryExtend;
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::IntegerType;
+use common_arrow::arrow::io::parquet::write::array_to_columns;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::parquet::compression::CompressionOptions;
 use common_arrow::parquet::encoding::Encoding;
+use common_arrow::parquet::error::Error as ParquetError;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_arrow::parquet::write::Compressor;
+use common_arrow::parquet::write::DynIter;
+use common_arrow::parquet::write::DynStreamingIterator;
+use common_arrow::parquet::write::FileWriter;
 use common_arrow::parquet::write::Version;
 use common_arrow::write_parquet_file;
 use common_exception::ErrorCode;
@@ -26,27 +43,101 @@ use common_exception::Result;
 use common_expression::serialize::col_encoding;
 use common_expression::DataBlock;
 use common_expression::TableSchema;
+use storages_common_table_meta::table::ColumnCompression;
+use storages_common_table_meta::table::ColumnEncoding;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::bloom_filter::append_bloom_filters;
+use crate::bloom_filter::build_bloom_filters;
+
 /// Serialize data blocks to parquet format.
 pub fn blocks_to_parquet(
     schema: impl AsRef<TableSchema>,
     blocks: Vec<DataBlock>,
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
+) -> Result<(u64, ThriftFileMetaData)> {
+    let options = ParquetWriteOptions {
+        compression: compression.into(),
+        ..Default::default()
+    };
+    blocks_to_parquet_with_options(schema, blocks, write_buffer, &options)
+}
+
+/// Options of the parquet writer.
+#[derive(Clone, Debug)]
+pub struct ParquetWriteOptions {
+    pub compression: CompressionOptions,
+    /// Max rows of a row group, each data block is written as a row group if not set.
+    pub row_group_size: Option<usize>,
+    /// Max bytes of a data page, the default of the writer is used if not set.
+    pub data_page_size: Option<usize>,
+    pub write_statistics: bool,
+    /// The columns to write the bloom filters of the column chunks for, keyed by the names of
+    /// the top level fields of the schema.
+    pub bloom_filter_columns: Vec<String>,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: TableCompression::default().into(),
+            row_group_size: None,
+            data_page_size: None,
+            write_statistics: false,
+            bloom_filter_columns: vec![],
+        }
+    }
+}
+
+/// Serialize data blocks to parquet format with the options of the writer.
+pub fn blocks_to_parquet_with_options(
+    schema: impl AsRef<TableSchema>,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    options: &ParquetWriteOptions,
 ) -> Result<(u64, ThriftFileMetaData)> {
     let arrow_schema = schema.as_ref().to_arrow();
 
     let row_group_write_options = WriteOptions {
-        write_statistics: false,
+        write_statistics: options.write_statistics,
         version: Version::V2,
-        compression: compression.into(),
-        data_pagesize_limit: None,
+        compression: options.compression,
+        data_pagesize_limit: options.data_page_size,
+    };
+    let blocks = match options.row_group_size {
+        Some(row_group_size) if row_group_size > 0 && !blocks.is_empty() => {
+            let block = DataBlock::concat(&blocks)?;
+            (0..block.num_rows())
+                .step_by(row_group_size)
+                .map(|start| {
+                    let end = (start + row_group_size).min(block.num_rows());
+                    block.slice(start..end)
+                })
+                .collect()
+        }
+        _ => blocks,
     };
     let batches = blocks
         .into_iter()
         .map(Chunk::try_from)
         .collect::<Result<Vec<_>>>()?;
+    let bloom_filters = if options.bloom_filter_columns.is_empty() {
+        vec![]
+    } else {
+        batches
+            .iter()
+            .enumerate()
+            .flat_map(|(row_group, batch)| {
+                build_bloom_filters(
+                    &arrow_schema,
+                    batch.arrays(),
+                    &options.bloom_filter_columns,
+                    row_group,
+                )
+            })
+            .collect()
+    };
 
     let encoding_map = |data_type: &ArrowDataType| match data_type {
         ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
@@ -68,15 +159,162 @@ pub fn blocks_to_parquet(
 
     use common_arrow::parquet::write::WriteOptions as FileWriteOption;
     let options = FileWriteOption {
-        write_statistics: false,
+        write_statistics: options.write_statistics,
         version: Version::V2,
     };
 
+    let start = write_buffer.len();
     match write_parquet_file(write_buffer, row_groups, arrow_schema, options) {
-        Ok(result) => Ok(result),
+        Ok((_, mut meta)) => {
+            let size = append_bloom_filters(write_buffer, start, &mut meta, bloom_filters)?;
+            Ok((size, meta))
+        }
         Err(cause) => Err(ErrorCode::Internal(format!(
             "write_parquet_file: {:?}",
             cause,
         ))),
     }
 }
+
+/// Serialize data blocks to parquet format, with the compressions and encodings
+/// of some columns set apart from the defaults, and the bloom filters of some columns.
+/// The columns are keyed by the names of the top level fields of the schema.
+pub fn blocks_to_parquet_with_column_options(
+    schema: impl AsRef<TableSchema>,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    compression: TableCompression,
+    column_compressions: &HashMap<String, ColumnCompression>,
+    column_encodings: &HashMap<String, ColumnEncoding>,
+    bloom_filter_columns: &[String],
+) -> Result<(u64, ThriftFileMetaData)> {
+    if column_compressions.is_empty() && column_encodings.is_empty() {
+        let options = ParquetWriteOptions {
+            compression: compression.into(),
+            bloom_filter_columns: bloom_filter_columns.to_vec(),
+            ..Default::default()
+        };
+        return blocks_to_parquet_with_options(schema, blocks, write_buffer, &options);
+    }
+
+    let mut arrow_schema = schema.as_ref().to_arrow();
+    let mut compressions = Vec::with_capacity(arrow_schema.fields.len());
+    let mut encodings = Vec::with_capacity(arrow_schema.fields.len());
+    for field in arrow_schema.fields.iter_mut() {
+        let column_compression: CompressionOptions = match column_compressions.get(&field.name) {
+            Some(column_compression) => (*column_compression).into(),
+            None => compression.into(),
+        };
+        compressions.push(column_compression);
+
+        let encoding = column_encodings.get(&field.name).copied();
+        if encoding == Some(ColumnEncoding::Dictionary)
+            && field.data_type == ArrowDataType::LargeBinary
+        {
+            // dictionary encoded columns are written from dictionary arrays.
+            field.data_type = ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(field.data_type.clone()),
+                false,
+            );
+        }
+        encodings.push(transverse(&field.data_type, |data_type| {
+            match (data_type, encoding) {
+                (ArrowDataType::Dictionary(..), _) => Encoding::RleDictionary,
+                (
+                    ArrowDataType::Int8
+                    | ArrowDataType::Int16
+                    | ArrowDataType::Int32
+                    | ArrowDataType::Int64
+                    | ArrowDataType::UInt8
+                    | ArrowDataType::UInt16
+                    | ArrowDataType::UInt32
+                    | ArrowDataType::UInt64
+                    | ArrowDataType::Date32
+                    | ArrowDataType::Timestamp(..),
+                    Some(ColumnEncoding::Delta),
+                ) => Encoding::DeltaBinaryPacked,
+                _ => col_encoding(data_type),
+            }
+        }));
+    }
+
+    let parquet_schema = to_parquet_schema(&arrow_schema)?;
+    use common_arrow::parquet::write::WriteOptions as FileWriteOption;
+    let options = FileWriteOption {
+        write_statistics: false,
+        version: Version::V2,
+    };
+    // Arrow2 should be honored
+    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+    let mut file_writer =
+        FileWriter::new(write_buffer, parquet_schema.clone(), options, created_by);
+
+    let start = write_buffer.len();
+    let mut bloom_filters = vec![];
+    for (row_group_index, block) in blocks.into_iter().enumerate() {
+        let chunk = Chunk::try_from(block)?;
+        if !bloom_filter_columns.is_empty() {
+            bloom_filters.extend(build_bloom_filters(
+                &arrow_schema,
+                chunk.arrays(),
+                bloom_filter_columns,
+                row_group_index,
+            ));
+        }
+        let mut row_group = vec![];
+        for (((array, field), parquet_type), (compression, encoding)) in chunk
+            .into_arrays()
+            .into_iter()
+            .zip(arrow_schema.fields.iter())
+            .zip(parquet_schema.fields().iter())
+            .zip(compressions.iter().zip(encodings.iter()))
+        {
+            let array = match &field.data_type {
+                ArrowDataType::Dictionary(..) => to_dictionary_array(array)?,
+                _ => array,
+            };
+            let write_options = WriteOptions {
+                write_statistics: false,
+                version: Version::V2,
+                compression: *compression,
+                data_pagesize_limit: None,
+            };
+            for pages in array_to_columns(array, parquet_type.clone(), write_options, encoding)? {
+                let pages = DynIter::new(
+                    pages.map(|page| page.map_err(|e| ParquetError::OutOfSpec(e.to_string()))),
+                );
+                let compressed_pages = Compressor::new(pages, *compression, vec![]);
+                row_group.push(Ok(DynStreamingIterator::new(compressed_pages)));
+            }
+        }
+        file_writer.write(DynIter::new(row_group.into_iter()))?;
+    }
+
+    file_writer.end(None)?;
+    let (_meta_size, mut thrift_file_meta_data) = file_writer.into_inner_and_metadata();
+    let file_size = append_bloom_filters(
+        write_buffer,
+        start,
+        &mut thrift_file_meta_data,
+        bloom_filters,
+    )?;
+    Ok((file_size, thrift_file_meta_data))
+}
+
+/// Convert a string array to a dictionary array.
+fn to_dictionary_array(array: Box<dyn Array>) -> Result<Box<dyn Array>> {
+    let array = array
+        .as_any()
+        .downcast_ref::<BinaryArray<i64>>()
+        .ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "dictionary encoding expects a string column, but got {:?}",
+                array.data_type()
+            ))
+        })?;
+    let mut dictionary = MutableDictionaryArray::<u32, MutableBinaryArray<i64>>::new();
+    dictionary.try_extend(array.iter())?;
+    let dictionary: DictionaryArray<u32> = dictionary.into();
+    Ok(Box::new(dictionary))
+}
@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::write::transverse;
+use common_arrow::parquet::bloom_filter::hash_byte;
+use common_arrow::parquet::bloom_filter::hash_native;
+use common_arrow::parquet::bloom_filter::insert;
+use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+use parquet_format_safe::BloomFilterAlgorithm;
+use parquet_format_safe::BloomFilterCompression;
+use parquet_format_safe::BloomFilterHash;
+use parquet_format_safe::BloomFilterHeader;
+use parquet_format_safe::SplitBlockAlgorithm;
+use parquet_format_safe::Uncompressed;
+use parquet_format_safe::XxHash;
+
+/// The false positive probability of the bloom filters.
+const BLOOM_FILTER_FPP: f64 = 0.01;
+/// The size of a block of the split block bloom filter.
+const BLOOM_FILTER_MIN_BYTES: usize = 32;
+const BLOOM_FILTER_MAX_BYTES: usize = 128 * 1024 * 1024;
+
+const PARQUET_MAGIC: &[u8] = b"PAR1";
+
+/// The bloom filter of a column chunk, written after the row groups of a parquet file.
+pub(crate) struct ColumnBloomFilter {
+    row_group: usize,
+    /// the index of the leaf column in the row group
+    column: usize,
+    bitset: Vec<u8>,
+}
+
+/// Builds the bloom filters of the `columns` in a row group, the columns are keyed by the names
+/// of the top level fields of the schema. The nested columns, the boolean and the decimal
+/// columns are skipped.
+pub(crate) fn build_bloom_filters(
+    arrow_schema: &ArrowSchema,
+    arrays: &[Box<dyn Array>],
+    columns: &[String],
+    row_group: usize,
+) -> Vec<ColumnBloomFilter> {
+    let mut filters = vec![];
+    let mut leaf_index = 0;
+    for (field, array) in arrow_schema.fields.iter().zip(arrays.iter()) {
+        let num_leaves = transverse(&field.data_type, |_| ()).len();
+        if num_leaves == 1 && columns.contains(&field.name) {
+            if let Some(hashes) = hash_values(array.as_ref()) {
+                let mut bitset = vec![0; bloom_filter_bytes(hashes.len())];
+                for hash in hashes {
+                    insert(&mut bitset, hash);
+                }
+                filters.push(ColumnBloomFilter {
+                    row_group,
+                    column: leaf_index,
+                    bitset,
+                });
+            }
+        }
+        leaf_index += num_leaves;
+    }
+    filters
+}
+
+/// Writes the bloom filters into the parquet file written at `start` of `buf`, and rewrites
+/// the footer with the offsets of the bloom filters. Returns the new size of the file.
+pub(crate) fn append_bloom_filters(
+    buf: &mut Vec<u8>,
+    start: usize,
+    meta: &mut ThriftFileMetaData,
+    filters: Vec<ColumnBloomFilter>,
+) -> Result<u64> {
+    if filters.is_empty() {
+        return Ok((buf.len() - start) as u64);
+    }
+
+    // The footer is the metadata, the length of the metadata and the magic.
+    let len_pos = buf.len() - 4 - PARQUET_MAGIC.len();
+    let meta_len = u32::from_le_bytes(buf[len_pos..len_pos + 4].try_into().unwrap()) as usize;
+    buf.truncate(len_pos - meta_len);
+
+    for filter in filters {
+        let offset = (buf.len() - start) as i64;
+        let header = BloomFilterHeader {
+            num_bytes: filter.bitset.len() as i32,
+            algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+            hash: BloomFilterHash::XXHASH(XxHash {}),
+            compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+        };
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut *buf);
+            header
+                .write_to_out_protocol(&mut protocol)
+                .map_err(|e| ErrorCode::Internal(format!("write parquet bloom filter: {e}")))?;
+        }
+        buf.extend_from_slice(&filter.bitset);
+
+        let column_meta = meta.row_groups[filter.row_group].columns[filter.column]
+            .meta_data
+            .as_mut();
+        if let Some(column_meta) = column_meta {
+            column_meta.bloom_filter_offset = Some(offset);
+        }
+    }
+
+    let meta_len = {
+        let mut protocol = TCompactOutputProtocol::new(&mut *buf);
+        meta.write_to_out_protocol(&mut protocol)
+            .map_err(|e| ErrorCode::Internal(format!("write parquet metadata: {e}")))?
+    };
+    buf.extend_from_slice(&(meta_len as u32).to_le_bytes());
+    buf.extend_from_slice(PARQUET_MAGIC);
+    Ok((buf.len() - start) as u64)
+}
+
+/// The bytes of a bloom filter of `ndv` distinct values, a power of two.
+fn bloom_filter_bytes(ndv: usize) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - BLOOM_FILTER_FPP.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(BLOOM_FILTER_MIN_BYTES, BLOOM_FILTER_MAX_BYTES)
+        .next_power_of_two()
+}
+
+/// The hashes of the non-null values, hashed as the values of the parquet physical type.
+fn hash_values(array: &dyn Array) -> Option<HashSet<u64>> {
+    macro_rules! hash_primitive {
+        ($t: ty, $physical: ty) => {
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$t>>()?
+                .iter()
+                .flatten()
+                .map(|v| hash_native(*v as $physical))
+                .collect()
+        };
+    }
+
+    let hashes = match array.data_type() {
+        ArrowDataType::Int8 => hash_primitive!(i8, i32),
+        ArrowDataType::Int16 => hash_primitive!(i16, i32),
+        ArrowDataType::Int32 | ArrowDataType::Date32 => hash_primitive!(i32, i32),
+        ArrowDataType::UInt8 => hash_primitive!(u8, i32),
+        ArrowDataType::UInt16 => hash_primitive!(u16, i32),
+        ArrowDataType::UInt32 => hash_primitive!(u32, i32),
+        ArrowDataType::Int64 | ArrowDataType::Timestamp(..) => hash_primitive!(i64, i64),
+        ArrowDataType::UInt64 => hash_primitive!(u64, i64),
+        ArrowDataType::Float32 => hash_primitive!(f32, f32),
+        ArrowDataType::Float64 => hash_primitive!(f64, f64),
+        ArrowDataType::Binary => array
+            .as_any()
+            .downcast_ref::<BinaryArray<i32>>()?
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        ArrowDataType::LargeBinary => array
+            .as_any()
+            .downcast_ref::<BinaryArray<i64>>()?
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        ArrowDataType::Utf8 => array
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()?
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        ArrowDataType::LargeUtf8 => array
+            .as_any()
+            .downcast_ref::<Utf8Array<i64>>()?
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        _ => return None,
+    };
+    Some(hashes)
+}
@@ -15,5 +15,14 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod block;
+mod bloom_filter;
+mod native_encoding;
 
 pub use block::blocks_to_parquet;
+pub use block::blocks_to_parquet_with_column_options;
+pub use block::blocks_to_parquet_with_options;
+pub use block::ParquetWriteOptions;
+pub use native_encoding::decode_native_page;
+pub use native_encoding::decode_native_pages;
+pub use native_encoding::encode_native_columns;
+pub use native_encoding::native_encoded_field;
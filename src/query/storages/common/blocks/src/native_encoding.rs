@@ -0,0 +1,446 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lightweight encodings of the integer, date and timestamp columns of native blocks.
+//!
+//! The values of an encoded column are transformed page by page into small unsigned
+//! integers, which are stored as a `UInt8`, `UInt16`, `UInt32` or `UInt64` column of the
+//! narrowest width fitting all of them, and compressed by the table compression as usual.
+//! The reference values of each page are kept in the [`NativeColumnEncoding`] of the column.
+
+use std::collections::HashMap;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::PhysicalType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::types::PrimitiveType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableSchema;
+use storages_common_table_meta::meta::NativeColumnEncoding;
+use storages_common_table_meta::table::ColumnEncoding;
+
+/// Encode the columns of `chunk` with the encodings in `column_encodings`, keyed by the names
+/// of the top level fields of the schema. Each page of `page_size` rows is encoded on its own.
+///
+/// Returns the schema and the chunk to write, and the encodings of the encoded columns.
+pub fn encode_native_columns(
+    schema: &TableSchema,
+    arrow_schema: ArrowSchema,
+    chunk: Chunk<Box<dyn Array>>,
+    page_size: usize,
+    column_encodings: &HashMap<String, ColumnEncoding>,
+) -> Result<(
+    ArrowSchema,
+    Chunk<Box<dyn Array>>,
+    HashMap<ColumnId, NativeColumnEncoding>,
+)> {
+    let mut encodings = HashMap::new();
+    if column_encodings.is_empty() {
+        return Ok((arrow_schema, chunk, encodings));
+    }
+
+    let mut arrow_schema = arrow_schema;
+    let mut arrays = chunk.into_arrays();
+    for (i, field) in schema.fields().iter().enumerate() {
+        let Some(encoding) = column_encodings.get(field.name()) else {
+            continue;
+        };
+        if matches!(encoding, ColumnEncoding::Plain | ColumnEncoding::Dictionary) {
+            continue;
+        }
+        let Some(values) = integer_values(arrays[i].as_ref()) else {
+            continue;
+        };
+
+        let signed = is_signed(arrays[i].data_type());
+        let values = fill_nulls(values, arrays[i].validity());
+        let page_size = page_size.max(1);
+        let mut column_encoding = NativeColumnEncoding {
+            encoding: *encoding,
+            width: 0,
+            page_bases: vec![],
+            page_deltas: vec![],
+        };
+        let mut encoded = Vec::with_capacity(values.len());
+        for page in values.chunks(page_size) {
+            encode_page(page, signed, &mut column_encoding, &mut encoded);
+        }
+        let max = encoded.iter().copied().max().unwrap_or_default();
+        column_encoding.width = width_of(max);
+
+        let array = to_unsigned_array(
+            encoded,
+            column_encoding.width,
+            arrays[i].validity().cloned(),
+        );
+        arrow_schema.fields[i] = native_encoded_field(&arrow_schema.fields[i], &column_encoding);
+        arrays[i] = array;
+        encodings.insert(field.column_id(), column_encoding);
+    }
+
+    Ok((arrow_schema, Chunk::try_new(arrays)?, encodings))
+}
+
+/// Returns the field of the stored values of a column encoded with `encoding`.
+pub fn native_encoded_field(field: &ArrowField, encoding: &NativeColumnEncoding) -> ArrowField {
+    let data_type = match encoding.width {
+        1 => ArrowDataType::UInt8,
+        2 => ArrowDataType::UInt16,
+        4 => ArrowDataType::UInt32,
+        _ => ArrowDataType::UInt64,
+    };
+    ArrowField::new(field.name.clone(), data_type, field.is_nullable)
+}
+
+/// Decode the page `page` of a column encoded with `encoding` back into `data_type`.
+pub fn decode_native_page(
+    encoding: &NativeColumnEncoding,
+    page: usize,
+    array: &dyn Array,
+    data_type: &ArrowDataType,
+) -> Result<Box<dyn Array>> {
+    let stored = unsigned_values(array)?;
+    let mut values = Vec::with_capacity(stored.len());
+    decode_page(encoding, page, is_signed(data_type), &stored, &mut values)?;
+    from_integer_values(values, array.validity().cloned(), data_type)
+}
+
+/// Decode all pages of a column encoded with `encoding`, given the row counts of its pages.
+pub fn decode_native_pages(
+    encoding: &NativeColumnEncoding,
+    page_rows: &[u64],
+    array: &dyn Array,
+    data_type: &ArrowDataType,
+) -> Result<Box<dyn Array>> {
+    let stored = unsigned_values(array)?;
+    let signed = is_signed(data_type);
+    let mut values = Vec::with_capacity(stored.len());
+    let mut offset = 0;
+    for (page, rows) in page_rows.iter().enumerate() {
+        let end = (offset + *rows as usize).min(stored.len());
+        decode_page(encoding, page, signed, &stored[offset..end], &mut values)?;
+        offset = end;
+    }
+    if offset != stored.len() {
+        return Err(ErrorCode::StorageOther(
+            "unexpected row count of the pages of an encoded native column",
+        ));
+    }
+    from_integer_values(values, array.validity().cloned(), data_type)
+}
+
+fn encode_page(page: &[i64], signed: bool, meta: &mut NativeColumnEncoding, out: &mut Vec<u64>) {
+    match meta.encoding {
+        ColumnEncoding::BitPacking => {
+            if signed {
+                out.extend(page.iter().map(|v| zigzag(*v)));
+            } else {
+                out.extend(page.iter().map(|v| *v as u64));
+            }
+        }
+        ColumnEncoding::FrameOfReference => {
+            let min = if signed {
+                page.iter().copied().min().unwrap_or_default()
+            } else {
+                page.iter().map(|v| *v as u64).min().unwrap_or_default() as i64
+            };
+            meta.page_bases.push(min);
+            out.extend(page.iter().map(|v| (*v as u64).wrapping_sub(min as u64)));
+        }
+        ColumnEncoding::Delta => {
+            meta.page_bases.push(page[0]);
+            out.push(0);
+            out.extend(page.windows(2).map(|w| zigzag(w[1].wrapping_sub(w[0]))));
+        }
+        ColumnEncoding::DeltaOfDelta => {
+            let first_delta = page.get(1).map_or(0, |v| v.wrapping_sub(page[0]));
+            meta.page_bases.push(page[0]);
+            meta.page_deltas.push(first_delta);
+            out.extend(std::iter::repeat(0).take(page.len().min(2)));
+            out.extend(page.windows(3).map(|w| {
+                let delta = w[2].wrapping_sub(w[1]);
+                zigzag(delta.wrapping_sub(w[1].wrapping_sub(w[0])))
+            }));
+        }
+        ColumnEncoding::Plain | ColumnEncoding::Dictionary => unreachable!(),
+    }
+}
+
+fn decode_page(
+    meta: &NativeColumnEncoding,
+    page: usize,
+    signed: bool,
+    stored: &[u64],
+    out: &mut Vec<i64>,
+) -> Result<()> {
+    if stored.is_empty() {
+        return Ok(());
+    }
+    let base = || {
+        meta.page_bases.get(page).copied().ok_or_else(|| {
+            ErrorCode::StorageOther(format!(
+                "missing the reference value of page {} of an encoded native column",
+                page
+            ))
+        })
+    };
+    match meta.encoding {
+        ColumnEncoding::BitPacking => {
+            if signed {
+                out.extend(stored.iter().map(|v| unzigzag(*v)));
+            } else {
+                out.extend(stored.iter().map(|v| *v as i64));
+            }
+        }
+        ColumnEncoding::FrameOfReference => {
+            let min = base()? as u64;
+            out.extend(stored.iter().map(|v| v.wrapping_add(min) as i64));
+        }
+        ColumnEncoding::Delta => {
+            let mut value = base()?;
+            out.push(value);
+            out.extend(stored[1..].iter().map(|v| {
+                value = value.wrapping_add(unzigzag(*v));
+                value
+            }));
+        }
+        ColumnEncoding::DeltaOfDelta => {
+            let mut value = base()?;
+            let mut delta = meta.page_deltas.get(page).copied().unwrap_or_default();
+            out.push(value);
+            if stored.len() > 1 {
+                value = value.wrapping_add(delta);
+                out.push(value);
+            }
+            out.extend(stored.iter().skip(2).map(|v| {
+                delta = delta.wrapping_add(unzigzag(*v));
+                value = value.wrapping_add(delta);
+                value
+            }));
+        }
+        ColumnEncoding::Plain | ColumnEncoding::Dictionary => {
+            return Err(ErrorCode::StorageOther(format!(
+                "unexpected encoding {} of a native column",
+                meta.encoding
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The null slots take the value of the previous valid slot, so that they do not widen
+/// the encoded values.
+fn fill_nulls(mut values: Vec<i64>, validity: Option<&Bitmap>) -> Vec<i64> {
+    if let Some(validity) = validity {
+        let mut last = values
+            .iter()
+            .zip(validity.iter())
+            .find(|(_, valid)| *valid)
+            .map(|(v, _)| *v)
+            .unwrap_or_default();
+        for (value, valid) in values.iter_mut().zip(validity.iter()) {
+            if valid {
+                last = *value;
+            } else {
+                *value = last;
+            }
+        }
+    }
+    values
+}
+
+#[inline]
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+#[inline]
+fn unzigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn width_of(max: u64) -> u8 {
+    if max <= u8::MAX as u64 {
+        1
+    } else if max <= u16::MAX as u64 {
+        2
+    } else if max <= u32::MAX as u64 {
+        4
+    } else {
+        8
+    }
+}
+
+fn is_signed(data_type: &ArrowDataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Primitive(
+            PrimitiveType::Int8
+                | PrimitiveType::Int16
+                | PrimitiveType::Int32
+                | PrimitiveType::Int64
+        )
+    )
+}
+
+fn primitive_values<T: common_arrow::arrow::types::NativeType>(array: &dyn Array) -> &[T] {
+    array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values()
+}
+
+/// Returns the values of an integer array as `i64`, or `None` if it is not an integer array.
+fn integer_values(array: &dyn Array) -> Option<Vec<i64>> {
+    let values = match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Int8) => primitive_values::<i8>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Int16) => primitive_values::<i16>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Int32) => primitive_values::<i32>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Int64) => primitive_values::<i64>(array).to_vec(),
+        PhysicalType::Primitive(PrimitiveType::UInt8) => primitive_values::<u8>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt16) => primitive_values::<u16>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt32) => primitive_values::<u32>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt64) => primitive_values::<u64>(array)
+            .iter()
+            .map(|v| *v as i64)
+            .collect(),
+        _ => return None,
+    };
+    Some(values)
+}
+
+fn unsigned_values(array: &dyn Array) -> Result<Vec<u64>> {
+    let values = match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::UInt8) => primitive_values::<u8>(array)
+            .iter()
+            .map(|v| *v as u64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt16) => primitive_values::<u16>(array)
+            .iter()
+            .map(|v| *v as u64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt32) => primitive_values::<u32>(array)
+            .iter()
+            .map(|v| *v as u64)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::UInt64) => primitive_values::<u64>(array).to_vec(),
+        _ => {
+            return Err(ErrorCode::StorageOther(format!(
+                "unexpected type {:?} of an encoded native column",
+                array.data_type()
+            )));
+        }
+    };
+    Ok(values)
+}
+
+fn to_unsigned_array(values: Vec<u64>, width: u8, validity: Option<Bitmap>) -> Box<dyn Array> {
+    match width {
+        1 => Box::new(PrimitiveArray::<u8>::new(
+            ArrowDataType::UInt8,
+            values
+                .into_iter()
+                .map(|v| v as u8)
+                .collect::<Vec<_>>()
+                .into(),
+            validity,
+        )),
+        2 => Box::new(PrimitiveArray::<u16>::new(
+            ArrowDataType::UInt16,
+            values
+                .into_iter()
+                .map(|v| v as u16)
+                .collect::<Vec<_>>()
+                .into(),
+            validity,
+        )),
+        4 => Box::new(PrimitiveArray::<u32>::new(
+            ArrowDataType::UInt32,
+            values
+                .into_iter()
+                .map(|v| v as u32)
+                .collect::<Vec<_>>()
+                .into(),
+            validity,
+        )),
+        _ => Box::new(PrimitiveArray::<u64>::new(
+            ArrowDataType::UInt64,
+            values.into(),
+            validity,
+        )),
+    }
+}
+
+fn from_integer_values(
+    values: Vec<i64>,
+    validity: Option<Bitmap>,
+    data_type: &ArrowDataType,
+) -> Result<Box<dyn Array>> {
+    macro_rules! build {
+        ($t:ty) => {
+            Box::new(PrimitiveArray::<$t>::try_new(
+                data_type.clone(),
+                values
+                    .into_iter()
+                    .map(|v| v as $t)
+                    .collect::<Vec<_>>()
+                    .into(),
+                validity,
+            )?)
+        };
+    }
+    let array: Box<dyn Array> = match data_type.to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Int8) => build!(i8),
+        PhysicalType::Primitive(PrimitiveType::Int16) => build!(i16),
+        PhysicalType::Primitive(PrimitiveType::Int32) => build!(i32),
+        PhysicalType::Primitive(PrimitiveType::Int64) => build!(i64),
+        PhysicalType::Primitive(PrimitiveType::UInt8) => build!(u8),
+        PhysicalType::Primitive(PrimitiveType::UInt16) => build!(u16),
+        PhysicalType::Primitive(PrimitiveType::UInt32) => build!(u32),
+        PhysicalType::Primitive(PrimitiveType::UInt64) => build!(u64),
+        _ => {
+            return Err(ErrorCode::StorageOther(format!(
+                "unexpected type {:?} of an encoded native column",
+                data_type
+            )));
+        }
+    };
+    Ok(array)
+}
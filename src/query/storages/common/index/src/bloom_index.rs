@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -23,11 +24,13 @@ use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
 use common_expression::types::AnyType;
+use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -43,6 +46,7 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::Value;
+use common_functions::scalars::tokenize;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_table_meta::meta::SingleColumnMeta;
 use storages_common_table_meta::meta::Versioned;
@@ -85,6 +89,7 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
                         offset: col_start as u64,
                         len: col_len as u64,
                         num_values,
+                        compression: None,
                     };
                     let column_name = chunk_meta.path_in_schema[0].to_owned();
                     col_metas.push((column_name, res));
@@ -120,6 +125,9 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// The string columns with an inverted index also have a filter of the terms in them, named
+/// 'Inverted(column_id)', which prunes the blocks by the predicates of `match`.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -172,14 +180,27 @@ impl BloomIndex {
         })
     }
 
+    /// Returns whether a filter can be built for a column of `data_type`, the filter of a map
+    /// column is built for its values.
+    pub fn supported_type(data_type: &DataType) -> bool {
+        match data_type {
+            DataType::Map(box DataType::Tuple(kv_tys)) => Xor8Filter::supported_type(&kv_tys[1]),
+            _ => Xor8Filter::supported_type(data_type),
+        }
+    }
+
     /// Create a filter block from source data.
     ///
     /// All input blocks should belong to a Parquet file, e.g. the block array represents the parquet file in memory.
+    /// If `bloom_columns` is given, the filters are built only for the columns named in it.
+    /// The term filters are built for the string columns named in `inverted_columns`.
     pub fn try_create(
         func_ctx: FunctionContext,
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        bloom_columns: Option<&[String]>,
+        inverted_columns: &[String],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -193,6 +214,11 @@ impl BloomIndex {
         let mut fields = Vec::new();
         let mut columns = Vec::new();
         for i in 0..num_columns {
+            if let Some(bloom_columns) = bloom_columns {
+                if !bloom_columns.contains(source_schema.field(i).name()) {
+                    continue;
+                }
+            }
             let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
             match data_type {
                 DataType::Map(box inner_ty) => {
@@ -237,7 +263,33 @@ impl BloomIndex {
                 }
             };
         }
-        if columns.is_empty() {
+
+        let mut term_columns = Vec::new();
+        for i in 0..num_columns {
+            let field = source_schema.field(i);
+            if !inverted_columns.contains(field.name()) {
+                continue;
+            }
+            let data_type = &data_blocks_tobe_indexed[0].get_by_offset(i).data_type;
+            if data_type.remove_nullable() != DataType::String {
+                continue;
+            }
+            let mut terms = HashSet::new();
+            for block in data_blocks_tobe_indexed {
+                let value = &block.get_by_offset(i).value;
+                let column = value.convert_to_full_column(data_type, block.num_rows());
+                let column = column.remove_nullable();
+                for text in StringType::try_downcast_column(&column).unwrap().iter() {
+                    terms.extend(tokenize(text));
+                }
+            }
+            if !terms.is_empty() {
+                let column = StringType::column_from_iter(terms.into_iter(), &[]);
+                term_columns.push((field, StringType::upcast_column(column)));
+            }
+        }
+
+        if columns.is_empty() && term_columns.is_empty() {
             return Ok(None);
         }
 
@@ -296,6 +348,22 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for (field, column) in term_columns {
+            let digests = Self::calculate_column_digest(
+                &func_ctx,
+                &column,
+                &DataType::String,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(UInt64Type::try_downcast_column(&digests).unwrap().deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_inverted_column_name(field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
 
         Ok(Some(Self {
@@ -345,6 +413,18 @@ impl BloomIndex {
                 }
             },
         )?;
+        visit_expr_column_match_constant(&mut expr, &mut |span, col_name, terms, return_type| {
+            // If the column doesn't contain one of the terms, no row of the block is matched.
+            if self.find_terms(col_name, terms, scalar_map)? == FilterEvalResult::MustFalse {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
 
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -407,6 +487,23 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `match(col, <constant>)` in the expression,
+    /// with the terms of the constant.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<(String, Vec<Vec<u8>>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_match_constant(&mut expr.clone(), &mut |_, col_name, terms, _| {
+            cols.push((col_name.to_string(), terms.to_vec()));
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
+    /// The filter of the terms in a string column with an inverted index will be stored with
+    /// field name 'Inverted(column_id)'.
+    pub fn build_inverted_column_name(field: &TableField) -> String {
+        format!("Inverted({})", field.column_id())
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -461,6 +558,85 @@ impl BloomIndex {
             Ok(FilterEvalResult::MustFalse)
         }
     }
+
+    fn find_terms(
+        &self,
+        column_name: &str,
+        terms: &[Vec<u8>],
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        let filter_column =
+            &Self::build_inverted_column_name(self.source_schema.field_with_name(column_name)?);
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a term filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        let missing =
+            terms
+                .iter()
+                .any(|term| match scalar_map.get(&Scalar::String(term.clone())) {
+                    Some(digest) => !filter.contains_digest(*digest),
+                    None => false,
+                });
+
+        if missing {
+            Ok(FilterEvalResult::MustFalse)
+        } else {
+            Ok(FilterEvalResult::Uncertain)
+        }
+    }
+}
+
+/// Find the patterns like `match(Column, <constant>)`, the visitor is called with the terms of
+/// the constant if there is any.
+fn visit_expr_column_match_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[Vec<u8>], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "match" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let terms = tokenize(query);
+                if !terms.is_empty() {
+                    if let Some(new_expr) = visitor(*span, id, &terms, return_type)? {
+                        *expr = new_expr;
+                    }
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_match_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_match_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
 }
 
 fn visit_expr_column_eq_constant(
@@ -468,8 +644,52 @@ fn visit_expr_column_eq_constant(
     visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
 ) -> Result<()> {
     // Find patterns like `Column = <constant>`, `<constant> = Column`,
-    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`
+    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`,
+    // or `contains(<constant array>, Column)` of `Column IN (<constants>)`.
     match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "contains" => {
+            if let [
+                Expr::Constant {
+                    scalar: Scalar::Array(values),
+                    data_type: array_type,
+                    ..
+                },
+                Expr::ColumnRef {
+                    id,
+                    data_type: column_type,
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let same_type = match array_type.remove_nullable() {
+                    DataType::Array(value_type) => {
+                        value_type.remove_nullable() == column_type.remove_nullable()
+                    }
+                    _ => false,
+                };
+                if same_type && !values.is_empty() {
+                    // Rewrite the expression only if the equality of every value is rewritten.
+                    let mut new_expr = None;
+                    let mut all_rewritten = true;
+                    for value in values.iter() {
+                        match visitor(*span, id, &value.to_owned(), column_type, return_type)? {
+                            Some(expr) => new_expr = Some(expr),
+                            None => all_rewritten = false,
+                        }
+                    }
+                    if let (true, Some(new_expr)) = (all_rewritten, new_expr) {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
         Expr::FunctionCall {
             span,
             function,
@@ -21,10 +21,12 @@ pub trait Index {
         matches!(
             inner_type,
             DataType::Number(_)
+                | DataType::Boolean
                 | DataType::Date
                 | DataType::Timestamp
                 | DataType::String
                 | DataType::Decimal(_)
+                | DataType::Uuid
         )
     }
 }
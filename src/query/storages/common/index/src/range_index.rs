@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::UuidType;
 use common_expression::types::ValueType;
 use common_expression::with_number_mapped_type;
 use common_expression::ColumnId;
@@ -178,6 +181,18 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                             .unwrap(),
                     })
                 }
+                // The min is false if the column has false values, the max is true if it has
+                // true values.
+                DataType::Boolean => match (
+                    BooleanType::try_downcast_scalar(&stat.min.as_ref()),
+                    BooleanType::try_downcast_scalar(&stat.max.as_ref()),
+                ) {
+                    (Some(min), Some(max)) => Domain::Boolean(BooleanDomain {
+                        has_false: !min,
+                        has_true: max,
+                    }),
+                    _ => Domain::full(data_type),
+                },
                 DataType::String => Domain::String(StringDomain {
                     min: StringType::try_downcast_scalar(&stat.min.as_ref())
                         .unwrap()
@@ -196,6 +211,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&stat.min.as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max.as_ref()).unwrap(),
                 }),
+                DataType::Uuid => UuidType::upcast_domain(SimpleDomain {
+                    min: UuidType::try_downcast_scalar(&stat.min.as_ref()).unwrap(),
+                    max: UuidType::try_downcast_scalar(&stat.max.as_ref()).unwrap(),
+                }),
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
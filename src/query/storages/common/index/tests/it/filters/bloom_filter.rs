@@ -111,6 +111,8 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        None,
+        &[],
     )?
     .unwrap();
 
@@ -154,6 +156,43 @@ fn test_bloom_filter() -> Result<()> {
         eval_index(&index, "1", Scalar::String(b"d".to_vec()), DataType::String)
     );
 
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_in_list_index(
+            &index,
+            "0",
+            UInt8Type::from_data(vec![0, 4, 5]),
+            DataType::Number(NumberDataType::UInt8)
+        )
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_in_list_index(
+            &index,
+            "0",
+            UInt8Type::from_data(vec![0, 3]),
+            DataType::Number(NumberDataType::UInt8)
+        )
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_in_list_index(
+            &index,
+            "1",
+            StringType::from_data(vec!["d", "e"]),
+            DataType::String
+        )
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_in_list_index(
+            &index,
+            "1",
+            StringType::from_data(vec!["a", "x"]),
+            DataType::String
+        )
+    );
+
     assert_eq!(
         FilterEvalResult::Uncertain,
         eval_map_index(
@@ -229,6 +268,47 @@ fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) ->
     index.apply(expr, &scalar_map).unwrap()
 }
 
+fn eval_in_list_index(
+    index: &BloomIndex,
+    col_name: &str,
+    values: Column,
+    ty: DataType,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        "contains",
+        &[],
+        &[
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::Array(values),
+                data_type: DataType::Array(Box::new(ty.clone())),
+            },
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: ty.clone(),
+                display_name: col_name.to_string(),
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let point_query_cols = BloomIndex::find_eq_columns(&expr).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, scalar, ty) in point_query_cols.iter() {
+        if !scalar_map.contains_key(scalar) {
+            let digest = BloomIndex::calculate_scalar_digest(&func_ctx, scalar, ty).unwrap();
+            scalar_map.insert(scalar.clone(), digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map).unwrap()
+}
+
 fn eval_map_index(
     index: &BloomIndex,
     col_name: &str,
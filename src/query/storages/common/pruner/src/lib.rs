@@ -20,6 +20,7 @@ mod limiter_pruner;
 mod page_pruner;
 mod range_pruner;
 mod topn_pruner;
+mod virtual_column_pruner;
 
 pub use internal_column_pruner::InternalColumnPruner;
 pub use limiter_pruner::Limiter;
@@ -31,3 +32,4 @@ pub use range_pruner::RangePruner;
 pub use range_pruner::RangePrunerCreator;
 pub use topn_pruner::BlockMetaIndex;
 pub use topn_pruner::TopNPrunner;
+pub use virtual_column_pruner::VirtualColumnPruner;
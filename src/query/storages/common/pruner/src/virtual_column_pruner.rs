@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::ConstantFolder;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::statistics_to_domain;
+use storages_common_table_meta::meta::ColumnStatistics;
+
+// The suffix of the names of the typed columns replacing the virtual columns.
+const TYPED_COLUMN_SUFFIX: &str = "#typed";
+
+/// Prunes the blocks by the typed min/max statistics of the virtual columns.
+///
+/// The virtual columns are variants, which have no domains, so only the casts of them,
+/// e.g. `v['a']::int > 10`, are evaluated, with the statistics of the cast values.
+pub struct VirtualColumnPruner {
+    func_ctx: FunctionContext,
+    expr: Expr<String>,
+    virtual_columns: HashSet<String>,
+}
+
+impl VirtualColumnPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        expr: Option<&Expr<String>>,
+        virtual_columns: &[String],
+    ) -> Option<Self> {
+        let expr = expr?;
+        let virtual_columns = expr
+            .column_refs()
+            .into_keys()
+            .filter(|name| virtual_columns.contains(name))
+            .collect::<HashSet<_>>();
+        if virtual_columns.is_empty() {
+            return None;
+        }
+        Some(VirtualColumnPruner {
+            func_ctx,
+            expr: expr.clone(),
+            virtual_columns,
+        })
+    }
+
+    /// Returns false if no rows of the block can match the filter by the statistics of
+    /// the virtual columns of the block.
+    pub fn should_keep(&self, stats: &HashMap<String, ColumnStatistics>) -> bool {
+        let expr = self.rewrite_casts(&self.expr, stats);
+        let input_domains = expr
+            .column_refs()
+            .into_iter()
+            .map(|(name, ty)| {
+                let stat = name
+                    .strip_suffix(TYPED_COLUMN_SUFFIX)
+                    .and_then(|name| stats.get(name));
+                let domain = match stat {
+                    Some(stat) => statistics_to_domain(vec![stat], &ty),
+                    None => Domain::full(&ty),
+                };
+                (name, domain)
+            })
+            .collect();
+
+        let (folded_expr, _) = ConstantFolder::fold_with_domain(
+            &expr,
+            &input_domains,
+            &self.func_ctx,
+            &BUILTIN_FUNCTIONS,
+        );
+
+        !matches!(folded_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    }
+
+    // Replaces the casts of the virtual columns having statistics with the casts of the
+    // typed columns, whose domains are built from the statistics in `should_keep`.
+    fn rewrite_casts(
+        &self,
+        expr: &Expr<String>,
+        stats: &HashMap<String, ColumnStatistics>,
+    ) -> Expr<String> {
+        match expr {
+            Expr::Cast {
+                span,
+                is_try,
+                expr,
+                dest_type,
+            } => {
+                let expr = match expr.as_ref() {
+                    Expr::ColumnRef { id, .. } if self.virtual_columns.contains(id) => {
+                        match stats
+                            .get(id)
+                            .and_then(|stat| stat_data_type(stat, dest_type))
+                        {
+                            Some(data_type) => Expr::ColumnRef {
+                                span: *span,
+                                id: format!("{id}{TYPED_COLUMN_SUFFIX}"),
+                                data_type: data_type.wrap_nullable(),
+                                display_name: id.clone(),
+                            },
+                            None => expr.as_ref().clone(),
+                        }
+                    }
+                    expr => self.rewrite_casts(expr, stats),
+                };
+                Expr::Cast {
+                    span: *span,
+                    is_try: *is_try,
+                    expr: Box::new(expr),
+                    dest_type: dest_type.clone(),
+                }
+            }
+            Expr::FunctionCall {
+                span,
+                id,
+                function,
+                generics,
+                args,
+                return_type,
+            } => Expr::FunctionCall {
+                span: *span,
+                id: id.clone(),
+                function: function.clone(),
+                generics: generics.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| self.rewrite_casts(arg, stats))
+                    .collect(),
+                return_type: return_type.clone(),
+            },
+            _ => expr.clone(),
+        }
+    }
+}
+
+// The type of the statistics if the values of the virtual column can be cast to the
+// destination type without changing their order.
+fn stat_data_type(stat: &ColumnStatistics, dest_type: &DataType) -> Option<DataType> {
+    let stat_type = match (&stat.min, &stat.max) {
+        (Scalar::Null, Scalar::Null) => return None,
+        (min, max) => {
+            let min_type = min.as_ref().infer_data_type();
+            if min_type != max.as_ref().infer_data_type() {
+                return None;
+            }
+            min_type
+        }
+    };
+    match (&stat_type, dest_type.remove_nullable()) {
+        (DataType::Number(NumberDataType::Int64), DataType::Number(_))
+        | (DataType::Number(NumberDataType::Float64), DataType::Number(NumberDataType::Float64))
+        | (DataType::String, DataType::String)
+        | (DataType::Boolean, DataType::Boolean) => Some(stat_type),
+        _ => None,
+    }
+}
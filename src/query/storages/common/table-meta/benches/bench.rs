@@ -142,6 +142,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         offset: 0,
         len: 0,
         num_values: 0,
+        compression: None,
     });
 
     let col_stat = ColumnStatistics {
@@ -191,6 +192,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_location: Some(location_gen.block_bloom_index_location(&block_uuid)),
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
+        native_encodings: HashMap::new(),
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -205,6 +207,8 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         compressed_byte_size: 0,
         index_size: 0,
         col_stats: col_stats.clone(),
+        cluster_depth_sum: 0,
+        clustered_block_count: 0,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::ClusteringDepth;
+pub use v1::HistogramBucket;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ColumnMeta;
+pub use v2::NativeColumnEncoding;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
 pub use v4::TableSnapshot;
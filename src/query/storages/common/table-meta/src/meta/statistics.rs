@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_base::base::uuid::Uuid;
+use common_exception::Result;
 use common_expression::converts::from_scalar;
 use common_expression::ColumnId;
 use common_expression::Scalar;
@@ -60,6 +61,14 @@ pub struct Statistics {
     pub index_size: u64,
 
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
+
+    /// the sum of the clustering depths of the blocks with statistics of the cluster key, the
+    /// depth of a block is counted among the blocks of its segment.
+    #[serde(default)]
+    pub cluster_depth_sum: u64,
+    /// the number of blocks counted in `cluster_depth_sum`.
+    #[serde(default)]
+    pub clustered_block_count: u64,
 }
 
 // conversions from old meta data
@@ -69,15 +78,15 @@ impl ColumnStatistics {
     pub fn from_v0(
         v0: &crate::meta::v0::statistics::ColumnStatistics,
         data_type: &TableDataType,
-    ) -> Self {
+    ) -> Result<Self> {
         let data_type = data_type.into();
-        Self {
-            min: from_scalar(&v0.min, &data_type),
-            max: from_scalar(&v0.max, &data_type),
+        Ok(Self {
+            min: from_scalar(&v0.min, &data_type)?,
+            max: from_scalar(&v0.max, &data_type)?,
             null_count: v0.null_count,
             in_memory_size: v0.in_memory_size,
             distinct_of_values: None,
-        }
+        })
     }
 }
 
@@ -85,37 +94,40 @@ impl ClusterStatistics {
     pub fn from_v0(
         v0: crate::meta::v0::statistics::ClusterStatistics,
         data_type: &TableDataType,
-    ) -> Self {
+    ) -> Result<Self> {
         let data_type = data_type.into();
-        Self {
+        Ok(Self {
             cluster_key_id: v0.cluster_key_id,
             min: v0
                 .min
                 .into_iter()
                 .map(|s| from_scalar(&s, &data_type))
-                .collect(),
+                .collect::<Result<_>>()?,
             max: v0
                 .max
                 .into_iter()
                 .map(|s| from_scalar(&s, &data_type))
-                .collect(),
+                .collect::<Result<_>>()?,
             level: v0.level,
             pages: None,
-        }
+        })
     }
 }
 
 impl Statistics {
-    pub fn from_v0(v0: crate::meta::v0::statistics::Statistics, fields: &[TableField]) -> Self {
+    pub fn from_v0(
+        v0: crate::meta::v0::statistics::Statistics,
+        fields: &[TableField],
+    ) -> Result<Self> {
         let col_stats = v0
             .col_stats
             .into_iter()
             .map(|(k, v)| {
                 let t = fields[k as usize].data_type();
-                (k, ColumnStatistics::from_v0(&v, t))
+                Ok((k, ColumnStatistics::from_v0(&v, t)?))
             })
-            .collect();
-        Self {
+            .collect::<Result<_>>()?;
+        Ok(Self {
             row_count: v0.row_count,
             block_count: v0.block_count,
             perfect_block_count: v0.perfect_block_count,
@@ -123,6 +135,18 @@ impl Statistics {
             compressed_byte_size: v0.compressed_byte_size,
             index_size: v0.index_size,
             col_stats,
+            cluster_depth_sum: 0,
+            clustered_block_count: 0,
+        })
+    }
+
+    /// Returns the average clustering depth of the blocks counted in the summary, `None` if no
+    /// block is counted, e.g. the blocks are written before the depth is tracked.
+    pub fn average_cluster_depth(&self) -> Option<f64> {
+        if self.clustered_block_count == 0 {
+            None
+        } else {
+            Some(self.cluster_depth_sum as f64 / self.clustered_block_count as f64)
         }
     }
 }
@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use common_expression::ColumnId;
 
 use super::statistics::*;
+use crate::meta::Compression;
 
 /// A segment comprised of one or more blocks
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
@@ -48,6 +49,9 @@ pub struct ColumnMeta {
     pub len: u64,
     /// num of "rows"
     pub num_values: u64,
+    /// the compression of the column, if it differs from the one of the block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<Compression>,
 }
 
 impl ColumnMeta {
@@ -56,6 +60,7 @@ impl ColumnMeta {
             offset,
             len,
             num_values,
+            compression: None,
         }
     }
 }
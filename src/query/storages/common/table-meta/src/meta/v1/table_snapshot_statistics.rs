@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,60 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// equi-height histograms of the columns, collected by `ANALYZE TABLE`.
+    ///
+    /// absent in statistics written before histograms were collected.
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+
+    /// clustering depth of the blocks of a clustered table, collected by `ANALYZE TABLE`.
+    ///
+    /// absent in statistics of tables without cluster key, or written before it was collected.
+    #[serde(default)]
+    pub clustering_depth: Option<ClusteringDepth>,
+}
+
+/// A bucket of an equi-height histogram, covering the values that are
+/// greater than the upper bound of the previous bucket, up to `upper_bound`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
+}
+
+/// How much the cluster key ranges of the blocks of a clustered table overlap.
+///
+/// The depth of a block is the max number of blocks whose ranges overlap at a point of its
+/// range, the depth of a perfectly clustered table is 1.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClusteringDepth {
+    /// the snapshot the depth is computed on, the statistics are carried over to the later
+    /// snapshots until the next `ANALYZE TABLE`.
+    pub snapshot_id: SnapshotId,
+    pub cluster_key_id: u32,
+    pub total_block_count: u64,
+    pub total_constant_block_count: u64,
+    /// the blocks without statistics of the cluster key, e.g. written before it was set.
+    pub unclustered_block_count: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub max_depth: u64,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+        clustering_depth: Option<ClusteringDepth>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
+            clustering_depth,
         }
     }
 
@@ -49,4 +96,12 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, Vec<HistogramBucket>> {
+        &self.column_histograms
+    }
+
+    pub fn get_clustering_depth(&self) -> Option<&ClusteringDepth> {
+        self.clustering_depth.as_ref()
+    }
 }
@@ -17,5 +17,6 @@ mod snapshot;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
+pub use segment::NativeColumnEncoding;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
@@ -17,6 +17,8 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use common_arrow::native::ColumnMeta as NativeColumnMeta;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::TableField;
 use enum_as_inner::EnumAsInner;
@@ -32,6 +34,7 @@ use crate::meta::Compression;
 use crate::meta::Location;
 use crate::meta::Statistics;
 use crate::meta::Versioned;
+use crate::table::ColumnEncoding;
 
 /// A segment comprises one or more blocks
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -73,6 +76,10 @@ pub struct BlockMeta {
     #[serde(default)]
     pub bloom_filter_index_size: u64,
     pub compression: Compression,
+
+    /// The lightweight encodings of the integer columns of a native block.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub native_encodings: HashMap<ColumnId, NativeColumnEncoding>,
 }
 
 impl BlockMeta {
@@ -100,6 +107,7 @@ impl BlockMeta {
             bloom_filter_index_location,
             bloom_filter_index_size,
             compression,
+            native_encodings: HashMap::new(),
         }
     }
 
@@ -121,33 +129,33 @@ impl BlockMeta {
 }
 
 impl SegmentInfo {
-    pub fn from_v0(s: v0::SegmentInfo, fields: &[TableField]) -> Self {
-        let summary = Statistics::from_v0(s.summary, fields);
-        Self {
+    pub fn from_v0(s: v0::SegmentInfo, fields: &[TableField]) -> Result<Self> {
+        let summary = Statistics::from_v0(s.summary, fields)?;
+        Ok(Self {
             // the is no version before v0, and no versions other then 0 can be converted into v0
             format_version: v0::SegmentInfo::VERSION,
             blocks: s
                 .blocks
                 .into_iter()
-                .map(|b| Arc::new(BlockMeta::from_v0(&b, fields)))
-                .collect::<_>(),
+                .map(|b| Ok(Arc::new(BlockMeta::from_v0(&b, fields)?)))
+                .collect::<Result<_>>()?,
             summary,
-        }
+        })
     }
 
-    pub fn from_v1(s: v1::SegmentInfo, fields: &[TableField]) -> Self {
-        let summary = Statistics::from_v0(s.summary, fields);
-        Self {
+    pub fn from_v1(s: v1::SegmentInfo, fields: &[TableField]) -> Result<Self> {
+        let summary = Statistics::from_v0(s.summary, fields)?;
+        Ok(Self {
             // NOTE: it is important to let the format_version return from here
             // carries the format_version of segment info being converted.
             format_version: s.format_version,
             blocks: s
                 .blocks
                 .into_iter()
-                .map(|b| Arc::new(BlockMeta::from_v1(b.as_ref(), fields)))
-                .collect::<_>(),
+                .map(|b| Ok(Arc::new(BlockMeta::from_v1(b.as_ref(), fields)?)))
+                .collect::<Result<_>>()?,
             summary,
-        }
+        })
     }
 }
 
@@ -205,16 +213,34 @@ impl ColumnMeta {
     }
 }
 
+/// The lightweight encoding of an integer column of a native block.
+///
+/// Each page of the column is encoded on its own, so that a range of pages can be read
+/// without the pages before it. The encoded values are stored as unsigned integers of
+/// `width` bytes.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NativeColumnEncoding {
+    pub encoding: ColumnEncoding,
+    pub width: u8,
+    /// The first value of each page for the delta encodings, or the minimum value of
+    /// each page for frame-of-reference.
+    #[serde(default)]
+    pub page_bases: Vec<i64>,
+    /// The first delta of each page for delta-of-delta encoding.
+    #[serde(default)]
+    pub page_deltas: Vec<i64>,
+}
+
 impl BlockMeta {
-    pub fn from_v0(s: &v0::BlockMeta, fields: &[TableField]) -> Self {
+    pub fn from_v0(s: &v0::BlockMeta, fields: &[TableField]) -> Result<Self> {
         let col_stats = s
             .col_stats
             .iter()
             .map(|(k, v)| {
                 let data_type = fields[*k as usize].data_type();
-                (*k, ColumnStatistics::from_v0(v, data_type))
+                Ok((*k, ColumnStatistics::from_v0(v, data_type)?))
             })
-            .collect();
+            .collect::<Result<_>>()?;
 
         let col_metas = s
             .col_metas
@@ -222,7 +248,7 @@ impl BlockMeta {
             .map(|(k, v)| (*k, ColumnMeta::Parquet(v.clone())))
             .collect();
 
-        Self {
+        Ok(Self {
             row_count: s.row_count,
             block_size: s.block_size,
             file_size: s.file_size,
@@ -233,18 +259,19 @@ impl BlockMeta {
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
-        }
+            native_encodings: HashMap::new(),
+        })
     }
 
-    pub fn from_v1(s: &v1::BlockMeta, fields: &[TableField]) -> Self {
+    pub fn from_v1(s: &v1::BlockMeta, fields: &[TableField]) -> Result<Self> {
         let col_stats = s
             .col_stats
             .iter()
             .map(|(k, v)| {
                 let data_type = fields[*k as usize].data_type();
-                (*k, ColumnStatistics::from_v0(v, data_type))
+                Ok((*k, ColumnStatistics::from_v0(v, data_type)?))
             })
-            .collect();
+            .collect::<Result<_>>()?;
 
         let col_metas = s
             .col_metas
@@ -252,7 +279,7 @@ impl BlockMeta {
             .map(|(k, v)| (*k, ColumnMeta::Parquet(v.clone())))
             .collect();
 
-        Self {
+        Ok(Self {
             row_count: s.row_count,
             block_size: s.block_size,
             file_size: s.file_size,
@@ -263,18 +290,23 @@ impl BlockMeta {
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
-        }
+            native_encodings: HashMap::new(),
+        })
     }
 }
 
-impl From<(v1::SegmentInfo, &[TableField])> for SegmentInfo {
-    fn from((v, fields): (v1::SegmentInfo, &[TableField])) -> Self {
+impl TryFrom<(v1::SegmentInfo, &[TableField])> for SegmentInfo {
+    type Error = ErrorCode;
+
+    fn try_from((v, fields): (v1::SegmentInfo, &[TableField])) -> Result<Self> {
         SegmentInfo::from_v1(v, fields)
     }
 }
 
-impl From<(v0::SegmentInfo, &[TableField])> for SegmentInfo {
-    fn from((v, fields): (v0::SegmentInfo, &[TableField])) -> Self {
+impl TryFrom<(v0::SegmentInfo, &[TableField])> for SegmentInfo {
+    type Error = ErrorCode;
+
+    fn try_from((v, fields): (v0::SegmentInfo, &[TableField])) -> Result<Self> {
         SegmentInfo::from_v0(v, fields)
     }
 }
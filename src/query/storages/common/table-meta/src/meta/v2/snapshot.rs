@@ -14,6 +14,8 @@
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::converts::from_schema;
 use common_expression::TableSchema;
 use serde::Deserialize;
@@ -95,13 +97,15 @@ impl TableSnapshot {
     }
 }
 
-impl From<v0::TableSnapshot> for TableSnapshot {
-    fn from(s: v0::TableSnapshot) -> Self {
+impl TryFrom<v0::TableSnapshot> for TableSnapshot {
+    type Error = ErrorCode;
+
+    fn try_from(s: v0::TableSnapshot) -> Result<Self> {
         let schema = from_schema(&s.schema);
         let schema = TableSchema::init_if_need(schema);
         let leaf_fields = schema.leaf_fields();
-        let summary = Statistics::from_v0(s.summary, &leaf_fields);
-        Self {
+        let summary = Statistics::from_v0(s.summary, &leaf_fields)?;
+        Ok(Self {
             // the is no version before v0, and no versions other then 0 can be converted into v0
             format_version: v0::TableSnapshot::VERSION,
             snapshot_id: s.snapshot_id,
@@ -112,17 +116,19 @@ impl From<v0::TableSnapshot> for TableSnapshot {
             segments: s.segments.into_iter().map(|l| (l, 0)).collect(),
             cluster_key_meta: None,
             table_statistics_location: None,
-        }
+        })
     }
 }
 
-impl From<v1::TableSnapshot> for TableSnapshot {
-    fn from(s: v1::TableSnapshot) -> Self {
+impl TryFrom<v1::TableSnapshot> for TableSnapshot {
+    type Error = ErrorCode;
+
+    fn try_from(s: v1::TableSnapshot) -> Result<Self> {
         let schema = from_schema(&s.schema);
         let schema = TableSchema::init_if_need(schema);
         let leaf_fields = schema.leaf_fields();
-        let summary = Statistics::from_v0(s.summary, &leaf_fields);
-        Self {
+        let summary = Statistics::from_v0(s.summary, &leaf_fields)?;
+        Ok(Self {
             // NOTE: it is important to let the format_version return from here
             // carries the format_version of snapshot being converted.
             format_version: s.format_version,
@@ -134,6 +140,6 @@ impl From<v1::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
-        }
+        })
     }
 }
@@ -61,6 +61,7 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_location: value.bloom_filter_index_location,
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
+            native_encodings: Default::default(),
         }
     }
 }
@@ -116,6 +117,7 @@ impl From<PageMeta> for common_arrow::native::PageMeta {
         Self {
             length: value.length,
             num_values: value.num_values,
+            compression: None,
         }
     }
 }
@@ -153,6 +155,7 @@ impl From<ParquetColumnMeta> for crate::meta::v0::ColumnMeta {
             offset: value.offset,
             len: value.len,
             num_values: value.num_values,
+            compression: None,
         }
     }
 }
@@ -46,6 +46,8 @@ impl From<Statistics> for crate::meta::Statistics {
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            cluster_depth_sum: 0,
+            clustered_block_count: 0,
         }
     }
 }
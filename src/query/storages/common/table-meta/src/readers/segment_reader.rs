@@ -20,6 +20,7 @@ use futures_util::AsyncReadExt;
 use crate::meta::load_json;
 use crate::meta::CompactSegmentInfo;
 use crate::meta::SegmentInfo;
+use crate::meta::SegmentInfoV2;
 use crate::meta::SegmentInfoV3;
 use crate::meta::SegmentInfoVersion;
 use crate::readers::VersionedReader;
@@ -48,7 +49,7 @@ impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef
                 let v1 = load_json(&buffer, v).await?;
                 // need leaf fields info to migrate from v1
                 let fields = schema.leaf_fields();
-                let current: SegmentInfo = (v1, &fields[..]).into();
+                let current: SegmentInfo = SegmentInfoV2::try_from((v1, &fields[..]))?.into();
                 current.to_bytes()
             }
 
@@ -56,7 +57,7 @@ impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef
                 let v0 = load_json(&buffer, v).await?;
                 // need leaf fields info to migrate from v0
                 let fields = schema.leaf_fields();
-                let current: SegmentInfo = (v0, &fields[..]).into();
+                let current: SegmentInfo = SegmentInfoV2::try_from((v0, &fields[..]))?.into();
                 current.to_bytes()
             }
         }?;
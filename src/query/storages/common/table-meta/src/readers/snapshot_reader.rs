@@ -42,11 +42,11 @@ impl VersionedReader<TableSnapshot> for SnapshotVersion {
             }
             SnapshotVersion::V1(v) => {
                 let ts = load_json(&buffer, v).await?;
-                TableSnapshotV2::from(ts).into()
+                TableSnapshotV2::try_from(ts)?.into()
             }
             SnapshotVersion::V0(v) => {
                 let ts = load_json(&buffer, v).await?;
-                TableSnapshotV2::from(ts).into()
+                TableSnapshotV2::try_from(ts)?.into()
             }
         };
         Ok(r)
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The encoding of a column, set by the `encoding` table option.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    Plain,
+    /// Delta encoding of integer columns.
+    Delta,
+    /// Dictionary encoding of string columns.
+    Dictionary,
+    /// Delta-of-delta encoding of integer columns, only for the native storage format.
+    DeltaOfDelta,
+    /// Bit-packing of integer columns, only for the native storage format.
+    BitPacking,
+    /// Frame-of-reference encoding of integer columns, only for the native storage format.
+    FrameOfReference,
+}
+
+/// Convert from str.
+impl TryFrom<&str> for ColumnEncoding {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "plain" => Ok(ColumnEncoding::Plain),
+            "delta" => Ok(ColumnEncoding::Delta),
+            "dictionary" | "dict" => Ok(ColumnEncoding::Dictionary),
+            "delta_of_delta" => Ok(ColumnEncoding::DeltaOfDelta),
+            "bitpacking" | "bit_packing" => Ok(ColumnEncoding::BitPacking),
+            "for" | "frame_of_reference" => Ok(ColumnEncoding::FrameOfReference),
+            other => Err(ErrorCode::UnknownFormat(format!(
+                "unsupported column encoding: {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for ColumnEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnEncoding::Plain => write!(f, "plain"),
+            ColumnEncoding::Delta => write!(f, "delta"),
+            ColumnEncoding::Dictionary => write!(f, "dictionary"),
+            ColumnEncoding::DeltaOfDelta => write!(f, "delta_of_delta"),
+            ColumnEncoding::BitPacking => write!(f, "bitpacking"),
+            ColumnEncoding::FrameOfReference => write!(f, "for"),
+        }
+    }
+}
+
+/// Parse the value of the `encoding` table option, a comma separated list of the
+/// encodings of columns, like `c1=delta,c2=dictionary`.
+pub fn parse_column_encoding_option(option: &str) -> Result<BTreeMap<String, ColumnEncoding>> {
+    let mut encodings = BTreeMap::new();
+    for item in option
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+    {
+        let (column, encoding) = item.split_once('=').ok_or_else(|| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid column encoding: {}, expect `<column>=<encoding>`",
+                item
+            ))
+        })?;
+        let column = column.trim().to_string();
+        if encodings
+            .insert(column.clone(), ColumnEncoding::try_from(encoding)?)
+            .is_some()
+        {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "duplicated encoding of column {}",
+                column
+            )));
+        }
+    }
+    Ok(encodings)
+}
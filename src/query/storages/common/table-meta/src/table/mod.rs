@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_encoding;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use column_encoding::parse_column_encoding_option;
+pub use column_encoding::ColumnEncoding;
+pub use table_compression::parse_table_compression_option;
+pub use table_compression::ColumnCompression;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
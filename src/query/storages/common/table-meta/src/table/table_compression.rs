@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use common_arrow::native;
 use common_arrow::parquet;
+use common_arrow::parquet::compression::ZstdLevel;
 use common_exception::ErrorCode;
+use common_exception::Result;
 
 use crate::meta;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum TableCompression {
     None,
     LZ4,
@@ -82,3 +88,116 @@ impl From<TableCompression> for meta::Compression {
         }
     }
 }
+
+impl Display for TableCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableCompression::None => write!(f, "none"),
+            TableCompression::LZ4 => write!(f, "lz4"),
+            TableCompression::Snappy => write!(f, "snappy"),
+            TableCompression::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// The compression of a column overriding the one of the table, `zstd` may
+/// have a level like `zstd(9)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnCompression {
+    pub compression: TableCompression,
+    pub zstd_level: Option<i32>,
+}
+
+/// Convert from str.
+impl TryFrom<&str> for ColumnCompression {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim().to_lowercase();
+        if let Some(level) = value
+            .strip_prefix("zstd(")
+            .and_then(|v| v.strip_suffix(')'))
+        {
+            let zstd_level = level
+                .trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|l| (1..=22).contains(l))
+                .ok_or_else(|| {
+                    ErrorCode::UnknownFormat(format!(
+                        "invalid zstd level: {}, expect 1 to 22",
+                        level
+                    ))
+                })?;
+            return Ok(ColumnCompression {
+                compression: TableCompression::Zstd,
+                zstd_level: Some(zstd_level),
+            });
+        }
+        Ok(ColumnCompression {
+            compression: TableCompression::try_from(value.as_str())?,
+            zstd_level: None,
+        })
+    }
+}
+
+impl Display for ColumnCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.zstd_level {
+            Some(level) => write!(f, "zstd({})", level),
+            None => write!(f, "{}", self.compression),
+        }
+    }
+}
+
+/// Convert to parquet CompressionOptions.
+impl From<ColumnCompression> for parquet::compression::CompressionOptions {
+    fn from(value: ColumnCompression) -> Self {
+        match (value.compression, value.zstd_level) {
+            (TableCompression::Zstd, Some(level)) => {
+                parquet::compression::CompressionOptions::Zstd(ZstdLevel::try_new(level).ok())
+            }
+            (compression, _) => compression.into(),
+        }
+    }
+}
+
+/// Parse the value of the `compression` table option, a comma separated list of
+/// the compression of the table and the compressions of columns, like
+/// `zstd,c1=zstd(9),c2=lz4`. The compression of the table is [None] if absent.
+pub fn parse_table_compression_option(
+    option: &str,
+) -> Result<(
+    Option<TableCompression>,
+    BTreeMap<String, ColumnCompression>,
+)> {
+    let mut table_compression = None;
+    let mut column_compressions = BTreeMap::new();
+    for item in option.split(',').map(|s| s.trim()) {
+        match item.split_once('=') {
+            Some((column, compression)) => {
+                let column = column.trim().to_string();
+                let compression = ColumnCompression::try_from(compression)?;
+                if column_compressions
+                    .insert(column.clone(), compression)
+                    .is_some()
+                {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "duplicated compression of column {}",
+                        column
+                    )));
+                }
+            }
+            None if table_compression.is_none() => {
+                table_compression = Some(TableCompression::try_from(item)?);
+            }
+            None => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "duplicated table compression: {}",
+                    option
+                )));
+            }
+        }
+    }
+    Ok((table_compression, column_compressions))
+}
@@ -19,10 +19,43 @@ pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+/// The encodings of columns, like `c1=delta,c2=dictionary`.
+pub const OPT_KEY_COLUMN_ENCODING: &str = "encoding";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
 
+/// The query of a materialized view, with the tables qualified by their databases.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The id of the table a materialized view is defined on.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID: &str = "materialized_view_source_table_id";
+/// The interval in seconds between the scheduled refreshes of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL: &str = "materialized_view_refresh_interval";
+/// The snapshot location of the source table read by the last refresh of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT: &str = "materialized_view_source_snapshot";
+/// The unix timestamp in seconds of the last refresh of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON: &str = "materialized_view_refreshed_on";
+
+/// The id of the table a stream is created on.
+pub const OPT_KEY_STREAM_SOURCE_TABLE_ID: &str = "stream_source_table_id";
+/// The name of the table a stream is created on, qualified by its database.
+pub const OPT_KEY_STREAM_SOURCE_TABLE: &str = "stream_source_table";
+/// The snapshot location of the source table that the offset of a stream is at,
+/// empty if the source table had no snapshot.
+pub const OPT_KEY_STREAM_OFFSET_SNAPSHOT: &str = "stream_offset_snapshot";
+/// The snapshot location of the source table that a bounded stream reads the changes up to,
+/// the offset of a bounded stream is never moved.
+pub const OPT_KEY_STREAM_END_SNAPSHOT: &str = "stream_end_snapshot";
+
+/// The stage location that an external table reads, like `@my_stage/path/`.
+pub const OPT_KEY_EXTERNAL_TABLE_LOCATION: &str = "external_table_location";
+/// The regex that the files read by an external table match, relative to the location.
+pub const OPT_KEY_EXTERNAL_TABLE_PATTERN: &str = "external_table_pattern";
+/// The file format options of an external table, encoded as json.
+pub const OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT: &str = "external_table_file_format";
+/// The comma separated hive partition columns of an external table, discovered from the paths.
+pub const OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS: &str = "external_table_partition_columns";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -40,6 +73,19 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE);
+    r.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT);
+    r.insert(OPT_KEY_STREAM_END_SNAPSHOT);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_LOCATION);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PATTERN);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS);
     r
 });
 
@@ -48,6 +94,19 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_INTERVAL);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_STREAM_SOURCE_TABLE);
+    r.insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT);
+    r.insert(OPT_KEY_STREAM_END_SNAPSHOT);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_LOCATION);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PATTERN);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT);
+    r.insert(OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS);
     r
 });
 
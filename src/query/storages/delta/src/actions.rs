@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! actions of the transaction log, only the parts needed for reading are parsed.
+//!
+//! Each line of a commit file is a json object with one action,
+//! a checkpoint has a column for each kind of action instead.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Action {
+    #[serde(default)]
+    pub add: Option<AddFile>,
+    #[serde(default)]
+    pub remove: Option<RemoveFile>,
+    #[serde(default)]
+    pub meta_data: Option<Metadata>,
+    #[serde(default)]
+    pub protocol: Option<Protocol>,
+}
+
+/// a data file added to the table
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AddFile {
+    /// percent-encoded path relative to the table root, or an absolute uri
+    pub path: String,
+    pub size: i64,
+    /// rows of the file deleted without rewriting it
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVector>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeletionVector {
+    pub storage_type: String,
+}
+
+/// a data file removed from the table, matched with the added files by path
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RemoveFile {
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Metadata {
+    /// the schema in the json format of spark
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Protocol {
+    pub min_reader_version: i32,
+    /// features the reader must support, since reader version 3
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+impl Metadata {
+    pub fn column_mapping_mode(&self) -> &str {
+        match self.configuration.get("delta.columnMapping.mode") {
+            Some(Some(mode)) => mode,
+            _ => "none",
+        }
+    }
+}
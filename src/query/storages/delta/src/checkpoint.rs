@@ -0,0 +1,306 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! checkpoints are parquet files with a struct column for each kind of action,
+//! each row has one of them not null.
+//!
+//! Removed files in a checkpoint are only kept for vacuum, so they are not read.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema;
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::actions::Action;
+use crate::actions::AddFile;
+use crate::actions::DeletionVector;
+use crate::actions::Metadata;
+use crate::actions::Protocol;
+
+/// the columns of the actions needed for reading
+const ACTION_COLUMNS: [&str; 3] = ["add", "metaData", "protocol"];
+
+pub(crate) fn read_checkpoint(path: &str, bytes: Vec<u8>) -> Result<Vec<Action>> {
+    let mut reader = Cursor::new(bytes);
+    let meta = read_metadata(&mut reader)?;
+    let fields = infer_schema(&meta)?
+        .fields
+        .into_iter()
+        .filter(|f| ACTION_COLUMNS.contains(&f.name.as_str()))
+        .collect::<Vec<_>>();
+    let names = fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    let schema = Schema::from(fields);
+
+    let mut actions = vec![];
+    for chunk in pread::FileReader::new(reader, meta.row_groups, schema, None, None, None) {
+        let chunk = chunk?;
+        actions.extend(actions_of_chunk(&names, &chunk).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid checkpoint {path}: {e}"))
+        })?);
+    }
+    Ok(actions)
+}
+
+fn actions_of_chunk(
+    names: &[String],
+    chunk: &Chunk<Box<dyn Array>>,
+) -> std::result::Result<Vec<Action>, String> {
+    let mut actions = vec![Action::default(); chunk.len()];
+    for (name, array) in names.iter().zip(chunk.arrays()) {
+        let array = downcast::<StructArray>(array.as_ref(), name)?;
+        for (row, action) in actions.iter_mut().enumerate() {
+            if array.is_null(row) {
+                continue;
+            }
+            match name.as_str() {
+                "add" => action.add = Some(add_file_at(array, row)?),
+                "metaData" => action.meta_data = Some(metadata_at(array, row)?),
+                "protocol" => action.protocol = Some(protocol_at(array, row)?),
+                _ => unreachable!(),
+            }
+        }
+    }
+    Ok(actions)
+}
+
+fn add_file_at(array: &StructArray, row: usize) -> std::result::Result<AddFile, String> {
+    let deletion_vector = match child(array, "deletionVector") {
+        Some(dv) if !dv.is_null(row) => {
+            let dv = downcast::<StructArray>(dv, "deletionVector")?;
+            Some(DeletionVector {
+                storage_type: required(dv, "storageType", row, string_at)?,
+            })
+        }
+        _ => None,
+    };
+    Ok(AddFile {
+        path: required(array, "path", row, string_at)?,
+        size: required(array, "size", row, i64_at)?,
+        deletion_vector,
+    })
+}
+
+fn metadata_at(array: &StructArray, row: usize) -> std::result::Result<Metadata, String> {
+    Ok(Metadata {
+        schema_string: required(array, "schemaString", row, string_at)?,
+        partition_columns: optional(array, "partitionColumns", row, string_list_at)?
+            .unwrap_or_default(),
+        configuration: optional(array, "configuration", row, string_map_at)?.unwrap_or_default(),
+    })
+}
+
+fn protocol_at(array: &StructArray, row: usize) -> std::result::Result<Protocol, String> {
+    Ok(Protocol {
+        min_reader_version: required(array, "minReaderVersion", row, i64_at)? as i32,
+        reader_features: optional(array, "readerFeatures", row, string_list_at)?,
+    })
+}
+
+fn child<'a>(array: &'a StructArray, name: &str) -> Option<&'a dyn Array> {
+    array
+        .fields()
+        .iter()
+        .position(|f| f.name == name)
+        .map(|i| array.values()[i].as_ref())
+}
+
+fn required<T>(
+    array: &StructArray,
+    name: &str,
+    row: usize,
+    value_at: fn(&dyn Array, usize) -> std::result::Result<T, String>,
+) -> std::result::Result<T, String> {
+    optional(array, name, row, value_at)?.ok_or_else(|| format!("missing {name}"))
+}
+
+fn optional<T>(
+    array: &StructArray,
+    name: &str,
+    row: usize,
+    value_at: fn(&dyn Array, usize) -> std::result::Result<T, String>,
+) -> std::result::Result<Option<T>, String> {
+    match child(array, name) {
+        Some(child) if !child.is_null(row) => value_at(child, row).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn downcast<'a, T: 'static>(
+    array: &'a dyn Array,
+    name: &str,
+) -> std::result::Result<&'a T, String> {
+    array
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| format!("unexpected type of {name}: {:?}", array.data_type()))
+}
+
+fn string_at(array: &dyn Array, row: usize) -> std::result::Result<String, String> {
+    let any = array.as_any();
+    if let Some(array) = any.downcast_ref::<Utf8Array<i32>>() {
+        Ok(array.value(row).to_string())
+    } else if let Some(array) = any.downcast_ref::<Utf8Array<i64>>() {
+        Ok(array.value(row).to_string())
+    } else if let Some(array) = any.downcast_ref::<BinaryArray<i32>>() {
+        String::from_utf8(array.value(row).to_vec()).map_err(|e| e.to_string())
+    } else if let Some(array) = any.downcast_ref::<BinaryArray<i64>>() {
+        String::from_utf8(array.value(row).to_vec()).map_err(|e| e.to_string())
+    } else {
+        Err(format!("expect string, got {:?}", array.data_type()))
+    }
+}
+
+fn i64_at(array: &dyn Array, row: usize) -> std::result::Result<i64, String> {
+    let any = array.as_any();
+    if let Some(array) = any.downcast_ref::<PrimitiveArray<i64>>() {
+        Ok(array.value(row))
+    } else if let Some(array) = any.downcast_ref::<PrimitiveArray<i32>>() {
+        Ok(array.value(row) as i64)
+    } else {
+        Err(format!("expect integer, got {:?}", array.data_type()))
+    }
+}
+
+fn string_list_at(array: &dyn Array, row: usize) -> std::result::Result<Vec<String>, String> {
+    let any = array.as_any();
+    let (values, start, end) = if let Some(array) = any.downcast_ref::<ListArray<i32>>() {
+        let offsets = array.offsets().buffer();
+        let range = (offsets[row] as usize, offsets[row + 1] as usize);
+        (array.values().as_ref(), range.0, range.1)
+    } else if let Some(array) = any.downcast_ref::<ListArray<i64>>() {
+        let offsets = array.offsets().buffer();
+        let range = (offsets[row] as usize, offsets[row + 1] as usize);
+        (array.values().as_ref(), range.0, range.1)
+    } else {
+        return Err(format!("expect list, got {:?}", array.data_type()));
+    };
+    (start..end).map(|i| string_at(values, i)).collect()
+}
+
+fn string_map_at(
+    array: &dyn Array,
+    row: usize,
+) -> std::result::Result<HashMap<String, Option<String>>, String> {
+    let array = downcast::<MapArray>(array, "map")?;
+    let entries = downcast::<StructArray>(array.field().as_ref(), "map entries")?;
+    let (keys, values) = match entries.values() {
+        [keys, values] => (keys.as_ref(), values.as_ref()),
+        _ => return Err("expect map entries of key and value".to_string()),
+    };
+    let offsets = array.offsets().buffer();
+    let mut map = HashMap::new();
+    for i in offsets[row] as usize..offsets[row + 1] as usize {
+        let value = if values.is_null(i) {
+            None
+        } else {
+            Some(string_at(values, i)?)
+        };
+        map.insert(string_at(keys, i)?, value);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod checkpoint_test {
+    use common_arrow::arrow::array::Array;
+    use common_arrow::arrow::array::MapArray;
+    use common_arrow::arrow::array::PrimitiveArray;
+    use common_arrow::arrow::array::StructArray;
+    use common_arrow::arrow::array::Utf8Array;
+    use common_arrow::arrow::bitmap::Bitmap;
+    use common_arrow::arrow::chunk::Chunk;
+    use common_arrow::arrow::datatypes::DataType;
+    use common_arrow::arrow::datatypes::Field;
+    use common_arrow::arrow::offset::OffsetsBuffer;
+
+    use super::actions_of_chunk;
+
+    #[test]
+    fn test_actions_of_chunk() {
+        let entries_type = DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Utf8, true),
+        ]);
+        let entries = StructArray::new(
+            entries_type.clone(),
+            vec![
+                Utf8Array::<i32>::from_slice(["delta.columnMapping.mode"]).boxed(),
+                Utf8Array::<i32>::from([Some("name")]).boxed(),
+            ],
+            None,
+        );
+        let map_type = DataType::Map(Box::new(Field::new("entries", entries_type, false)), false);
+        let configuration = MapArray::new(
+            map_type.clone(),
+            OffsetsBuffer::try_from(vec![0, 0, 1]).unwrap(),
+            entries.boxed(),
+            None,
+        );
+
+        // the first row is an add action, the second one is a metaData action
+        let add_type = DataType::Struct(vec![
+            Field::new("path", DataType::Utf8, true),
+            Field::new("size", DataType::Int64, true),
+        ]);
+        let add = StructArray::new(
+            add_type,
+            vec![
+                Utf8Array::<i32>::from([Some("part-0.parquet"), None]).boxed(),
+                PrimitiveArray::<i64>::from([Some(100), None]).boxed(),
+            ],
+            Some(Bitmap::from([true, false])),
+        );
+        let metadata_type = DataType::Struct(vec![
+            Field::new("schemaString", DataType::Utf8, true),
+            Field::new("configuration", map_type, true),
+        ]);
+        let metadata = StructArray::new(
+            metadata_type,
+            vec![
+                Utf8Array::<i32>::from([None, Some(r#"{"type":"struct","fields":[]}"#)]).boxed(),
+                configuration.boxed(),
+            ],
+            Some(Bitmap::from([false, true])),
+        );
+
+        let chunk = Chunk::new(vec![add.boxed(), metadata.boxed()]);
+        let names = ["add".to_string(), "metaData".to_string()];
+        let actions = actions_of_chunk(&names, &chunk).unwrap();
+        assert_eq!(actions.len(), 2);
+
+        let file = actions[0].add.as_ref().unwrap();
+        assert_eq!(file.path, "part-0.parquet");
+        assert_eq!(file.size, 100);
+        assert!(file.deletion_vector.is_none());
+        assert!(actions[0].meta_data.is_none());
+
+        let metadata = actions[1].meta_data.as_ref().unwrap();
+        assert!(actions[1].add.is_none());
+        assert!(metadata.partition_columns.is_empty());
+        assert_eq!(metadata.column_mapping_mode(), "name");
+    }
+}
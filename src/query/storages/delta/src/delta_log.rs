@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! replaying the transaction log in `_delta_log/` to get the latest version of a table.
+//!
+//! The log is made of commits named by their versions, like `00000000000000000010.json`,
+//! and checkpoints of the table at some versions, like `00000000000000000010.checkpoint.parquet`
+//! or `00000000000000000010.checkpoint.0000000001.0000000002.parquet` if split into parts.
+//! The latest complete checkpoint is read first, then the commits after it.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+use crate::actions::Action;
+use crate::actions::AddFile;
+use crate::actions::Metadata;
+use crate::actions::Protocol;
+use crate::checkpoint::read_checkpoint;
+
+const LOG_DIR: &str = "_delta_log/";
+
+/// the reader features of protocol version 3 that can be read,
+/// column mapping and deletion vectors are checked with the metadata and the files.
+const SUPPORTED_READER_FEATURES: [&str; 3] = ["columnMapping", "deletionVectors", "timestampNtz"];
+
+/// a version of a delta table
+pub(crate) struct DeltaSnapshot {
+    pub version: i64,
+    pub metadata: Metadata,
+    /// the active data files
+    pub files: Vec<AddFile>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum LogFile {
+    Commit(i64),
+    /// version, index of the part and number of parts, starting from 1
+    Checkpoint(i64, u32, u32),
+}
+
+fn parse_log_file_name(name: &str) -> Option<LogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse::<i64>().ok()?;
+    match rest.split('.').collect::<Vec<_>>().as_slice() {
+        ["json"] => Some(LogFile::Commit(version)),
+        ["checkpoint", "parquet"] => Some(LogFile::Checkpoint(version, 1, 1)),
+        ["checkpoint", part, parts, "parquet"] => Some(LogFile::Checkpoint(
+            version,
+            part.parse().ok()?,
+            parts.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+/// the paths of the log files to read
+#[derive(Debug, Default, PartialEq, Eq)]
+struct LogSegment {
+    version: i64,
+    checkpoint_parts: Vec<String>,
+    commits: Vec<String>,
+}
+
+impl LogSegment {
+    fn try_create(names: &[String]) -> Result<LogSegment> {
+        let mut commits = BTreeMap::new();
+        // version -> (number of parts, paths of the parts)
+        let mut checkpoints: BTreeMap<i64, (u32, Vec<String>)> = BTreeMap::new();
+        for name in names {
+            match parse_log_file_name(name) {
+                Some(LogFile::Commit(version)) => {
+                    commits.insert(version, format!("{LOG_DIR}{name}"));
+                }
+                Some(LogFile::Checkpoint(version, _, parts)) => {
+                    let entry = checkpoints.entry(version).or_insert((parts, vec![]));
+                    if entry.0 == parts {
+                        entry.1.push(format!("{LOG_DIR}{name}"));
+                    }
+                }
+                None => {}
+            }
+        }
+
+        // a checkpoint is being written if some parts are missing
+        let checkpoint = checkpoints
+            .into_iter()
+            .rev()
+            .find(|(_, (parts, paths))| paths.len() == *parts as usize);
+        let (start, mut checkpoint_parts) = match checkpoint {
+            Some((version, (_, paths))) => (version + 1, paths),
+            None => (0, vec![]),
+        };
+        checkpoint_parts.sort();
+
+        let mut version = start - 1;
+        let mut paths = vec![];
+        for (commit, path) in commits.range(start..) {
+            if *commit != version + 1 {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid delta table: missing commit of version {}",
+                    version + 1
+                )));
+            }
+            version = *commit;
+            paths.push(path.clone());
+        }
+        if version < 0 {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "invalid delta table: no commits found in {LOG_DIR}"
+            )));
+        }
+        Ok(LogSegment {
+            version,
+            checkpoint_parts,
+            commits: paths,
+        })
+    }
+}
+
+/// the state of the table after applying the actions
+#[derive(Default)]
+struct LogReplay {
+    files: HashMap<String, AddFile>,
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, actions: Vec<Action>) {
+        for action in actions {
+            if let Some(add) = action.add {
+                self.files.insert(add.path.clone(), add);
+            }
+            if let Some(remove) = action.remove {
+                self.files.remove(&remove.path);
+            }
+            if let Some(metadata) = action.meta_data {
+                self.metadata = Some(metadata);
+            }
+            if let Some(protocol) = action.protocol {
+                self.protocol = Some(protocol);
+            }
+        }
+    }
+
+    fn finish(self, version: i64) -> Result<DeltaSnapshot> {
+        let (metadata, protocol) = match (self.metadata, self.protocol) {
+            (Some(metadata), Some(protocol)) => (metadata, protocol),
+            _ => {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid delta table: missing metadata or protocol at version {version}"
+                )));
+            }
+        };
+        check_readable(&protocol, &metadata)?;
+
+        let mut files = self.files.into_values().collect::<Vec<_>>();
+        if let Some((file, dv)) = files
+            .iter()
+            .find_map(|f| f.deletion_vector.as_ref().map(|dv| (f, dv)))
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "reading delta tables with deletion vectors is not supported yet, \
+                found in {} with storage type {}",
+                file.path, dv.storage_type
+            )));
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(DeltaSnapshot {
+            version,
+            metadata,
+            files,
+        })
+    }
+}
+
+fn check_readable(protocol: &Protocol, metadata: &Metadata) -> Result<()> {
+    match protocol.min_reader_version {
+        1 | 2 => {}
+        3 => {
+            let features = protocol.reader_features.as_deref().unwrap_or_default();
+            if let Some(feature) = features
+                .iter()
+                .find(|f| !SUPPORTED_READER_FEATURES.contains(&f.as_str()))
+            {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "reading delta tables with reader feature {feature} is not supported yet"
+                )));
+            }
+        }
+        version => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "reading delta tables of reader version {version} is not supported yet"
+            )));
+        }
+    }
+    if metadata.column_mapping_mode() != "none" {
+        return Err(ErrorCode::Unimplemented(format!(
+            "reading delta tables with column mapping mode {} is not supported yet",
+            metadata.column_mapping_mode()
+        )));
+    }
+    if !metadata.partition_columns.is_empty() {
+        return Err(ErrorCode::Unimplemented(
+            "reading partitioned delta tables is not supported yet",
+        ));
+    }
+    Ok(())
+}
+
+fn read_commit(path: &str, bytes: &[u8]) -> Result<Vec<Action>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| {
+            serde_json::from_slice::<Action>(line)
+                .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid commit {path}: {e}")))
+        })
+        .collect()
+}
+
+/// read the latest version of the table rooted at `op`
+#[async_backtrace::framed]
+pub(crate) async fn read_latest_snapshot(op: &Operator) -> Result<DeltaSnapshot> {
+    let mut names = vec![];
+    let mut lister = op.list(LOG_DIR).await.map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot list {LOG_DIR} of delta table: {e:?}"))
+    })?;
+    while let Some(page) = lister.next_page().await? {
+        names.extend(page.into_iter().map(|entry| entry.name().to_string()));
+    }
+    let segment = LogSegment::try_create(&names)?;
+
+    let mut replay = LogReplay::default();
+    let reads = segment.checkpoint_parts.iter().map(|path| async move {
+        let bytes = op.read(path).await?;
+        read_checkpoint(path, bytes)
+    });
+    for actions in futures::future::try_join_all(reads).await? {
+        replay.apply(actions);
+    }
+    let reads = segment.commits.iter().map(|path| async move {
+        let bytes = op.read(path).await?;
+        read_commit(path, &bytes)
+    });
+    for actions in futures::future::try_join_all(reads).await? {
+        replay.apply(actions);
+    }
+    replay.finish(segment.version)
+}
+
+#[cfg(test)]
+mod delta_log_test {
+    use super::read_commit;
+    use super::LogReplay;
+    use super::LogSegment;
+
+    #[test]
+    fn test_log_segment() {
+        let names = [
+            "00000000000000000000.json",
+            "00000000000000000001.json",
+            "00000000000000000002.checkpoint.parquet",
+            "00000000000000000002.json",
+            "00000000000000000003.json",
+            // a checkpoint being written
+            "00000000000000000004.checkpoint.0000000001.0000000002.parquet",
+            "00000000000000000004.json",
+            "_last_checkpoint",
+        ]
+        .map(|s| s.to_string());
+        let segment = LogSegment::try_create(&names).unwrap();
+        assert_eq!(segment, LogSegment {
+            version: 4,
+            checkpoint_parts: vec![
+                "_delta_log/00000000000000000002.checkpoint.parquet".to_string()
+            ],
+            commits: vec![
+                "_delta_log/00000000000000000003.json".to_string(),
+                "_delta_log/00000000000000000004.json".to_string(),
+            ],
+        });
+
+        let names =
+            ["00000000000000000000.json", "00000000000000000002.json"].map(|s| s.to_string());
+        assert!(LogSegment::try_create(&names).is_err());
+        assert!(LogSegment::try_create(&[]).is_err());
+    }
+
+    #[test]
+    fn test_log_replay() {
+        let commits = [
+            r#"{"commitInfo":{"operation":"CREATE TABLE"}}
+{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"metaData":{"id":"1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[]}","partitionColumns":[],"configuration":{},"createdTime":1}}
+{"add":{"path":"part-0.parquet","partitionValues":{},"size":100,"modificationTime":1,"dataChange":true}}
+"#,
+            r#"{"remove":{"path":"part-0.parquet","deletionTimestamp":2,"dataChange":true}}
+{"add":{"path":"part-1%20a.parquet","partitionValues":{},"size":200,"modificationTime":2,"dataChange":true}}
+"#,
+        ];
+        let mut replay = LogReplay::default();
+        for commit in commits {
+            replay.apply(read_commit("commit", commit.as_bytes()).unwrap());
+        }
+        let snapshot = replay.finish(1).unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.files.len(), 1);
+        assert_eq!(snapshot.files[0].path, "part-1%20a.parquet");
+        assert_eq!(snapshot.files[0].size, 200);
+    }
+}
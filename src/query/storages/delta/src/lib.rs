@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This is the Delta Lake table engine for databend.
+//!
+//! A delta table is created on the directory of an existing table, e.g. written by spark,
+//! the columns are read from the transaction log if not given:
+//! ```sql
+//! CREATE TABLE t ENGINE = DELTA 's3://bkt/path/to/table/' CONNECTION = ( ... );
+//! SELECT * FROM t;
+//! ```
+//!
+//! Tables are read-only. The active data files of the latest version are resolved
+//! from the checkpoint and commits in `_delta_log/` on every read,
+//! then read as parquet files with only the needed columns.
+//!
+//! Partitioned tables, tables with column mapping or deletion vectors
+//! are not supported yet.
+
+/// actions in the transaction log
+mod actions;
+/// reading checkpoint files
+mod checkpoint;
+/// replaying the transaction log
+mod delta_log;
+/// schema converter
+mod schema;
+/// table implementation
+mod table;
+
+pub use table::DeltaTable;
@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! converting the schema of delta tables, which is in the json format of spark,
+//! to databend
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct StructField {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: DataType,
+    nullable: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DataType {
+    Primitive(String),
+    Complex(ComplexType),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ComplexType {
+    Struct {
+        fields: Vec<StructField>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Array {
+        element_type: Box<DataType>,
+        contains_null: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Map {
+        key_type: Box<DataType>,
+        value_type: Box<DataType>,
+        value_contains_null: bool,
+    },
+}
+
+/// parse the `schemaString` of the metadata of a delta table
+pub(crate) fn schema_delta_to_databend(schema_string: &str) -> Result<TableSchema> {
+    let schema: ComplexType = serde_json::from_str(schema_string).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("invalid schema of delta table: {e}"))
+    })?;
+    let fields = match schema {
+        ComplexType::Struct { fields } => fields,
+        _ => {
+            return Err(ErrorCode::ReadTableDataError(
+                "invalid schema of delta table: expect struct",
+            ));
+        }
+    };
+    let fields = fields
+        .iter()
+        .map(|f| {
+            let ty = type_delta_to_databend(&f.data_type)?;
+            Ok(TableField::new(&f.name, nullable_if(ty, f.nullable)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+fn nullable_if(ty: TableDataType, nullable: bool) -> TableDataType {
+    if nullable { ty.wrap_nullable() } else { ty }
+}
+
+fn type_delta_to_databend(ty: &DataType) -> Result<TableDataType> {
+    let ty = match ty {
+        DataType::Primitive(name) => match name.as_str() {
+            "boolean" => TableDataType::Boolean,
+            "byte" => TableDataType::Number(NumberDataType::Int8),
+            "short" => TableDataType::Number(NumberDataType::Int16),
+            "integer" => TableDataType::Number(NumberDataType::Int32),
+            "long" => TableDataType::Number(NumberDataType::Int64),
+            "float" => TableDataType::Number(NumberDataType::Float32),
+            "double" => TableDataType::Number(NumberDataType::Float64),
+            "string" | "binary" => TableDataType::String,
+            "date" => TableDataType::Date,
+            "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+            decimal if decimal.starts_with("decimal(") && decimal.ends_with(')') => {
+                let size = &decimal["decimal(".len()..decimal.len() - 1];
+                let size = match size.split_once(',') {
+                    Some((precision, scale)) => {
+                        (precision.trim().parse::<u8>(), scale.trim().parse::<u8>())
+                    }
+                    None => {
+                        return Err(ErrorCode::ReadTableDataError(format!(
+                            "invalid type of delta table: {decimal}"
+                        )));
+                    }
+                };
+                match size {
+                    (Ok(precision), Ok(scale)) => {
+                        TableDataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                            precision,
+                            scale,
+                        })?)
+                    }
+                    _ => {
+                        return Err(ErrorCode::ReadTableDataError(format!(
+                            "invalid type of delta table: {decimal}"
+                        )));
+                    }
+                }
+            }
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "reading delta tables with columns of {other} is not supported yet"
+                )));
+            }
+        },
+        DataType::Complex(ComplexType::Struct { fields }) => {
+            let (fields_name, fields_type) = fields
+                .iter()
+                .map(|f| {
+                    let ty = type_delta_to_databend(&f.data_type)?;
+                    Ok((f.name.clone(), nullable_if(ty, f.nullable)))
+                })
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        DataType::Complex(ComplexType::Array {
+            element_type,
+            contains_null,
+        }) => {
+            let element_type = type_delta_to_databend(element_type)?;
+            TableDataType::Array(Box::new(nullable_if(element_type, *contains_null)))
+        }
+        DataType::Complex(ComplexType::Map {
+            key_type,
+            value_type,
+            value_contains_null,
+        }) => {
+            let value_type = type_delta_to_databend(value_type)?;
+            TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    type_delta_to_databend(key_type)?,
+                    nullable_if(value_type, *value_contains_null),
+                ],
+            }))
+        }
+    };
+    Ok(ty)
+}
+
+#[cfg(test)]
+mod schema_test {
+    use common_expression::types::decimal::DecimalSize;
+    use common_expression::types::DecimalDataType;
+    use common_expression::types::NumberDataType;
+    use common_expression::TableDataType;
+
+    use super::schema_delta_to_databend;
+
+    /// the schema of a table created by spark with
+    /// `CREATE TABLE t (id BIGINT NOT NULL, price DECIMAL(10,2), tags ARRAY<STRING>, attrs MAP<STRING, INT>) USING DELTA`
+    const SCHEMA_STRING: &str = r#"{"type":"struct","fields":[
+        {"name":"id","type":"long","nullable":false,"metadata":{}},
+        {"name":"price","type":"decimal(10,2)","nullable":true,"metadata":{}},
+        {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+        {"name":"attrs","type":{"type":"map","keyType":"string","valueType":"integer","valueContainsNull":false},"nullable":true,"metadata":{}}
+    ]}"#;
+
+    #[test]
+    fn test_schema_delta_to_databend() {
+        let schema = schema_delta_to_databend(SCHEMA_STRING).unwrap();
+        let types = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.data_type().clone()))
+            .collect::<Vec<_>>();
+        let decimal = DecimalDataType::from_size(DecimalSize {
+            precision: 10,
+            scale: 2,
+        })
+        .unwrap();
+        assert_eq!(types, vec![
+            ("id", TableDataType::Number(NumberDataType::Int64)),
+            ("price", TableDataType::Decimal(decimal).wrap_nullable()),
+            (
+                "tags",
+                TableDataType::Array(Box::new(TableDataType::String.wrap_nullable()))
+                    .wrap_nullable()
+            ),
+            (
+                "attrs",
+                TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        TableDataType::String,
+                        TableDataType::Number(NumberDataType::Int32)
+                    ],
+                }))
+                .wrap_nullable()
+            ),
+        ]);
+    }
+}
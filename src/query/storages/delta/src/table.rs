@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::ParquetTableInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
+use common_storage::StageFileInfo;
+use common_storage::StageFileStatus;
+use common_storage::StageFilesInfo;
+use common_storages_parquet::ParquetTable;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+
+use crate::delta_log::read_latest_snapshot;
+use crate::schema::schema_delta_to_databend;
+
+/// A read-only delta lake table.
+///
+/// The active data files are resolved from the transaction log in `_delta_log/`
+/// on every read, then read as parquet files.
+pub struct DeltaTable {
+    /// operator pointing to the root of the table
+    op: Operator,
+    /// table information
+    info: TableInfo,
+}
+
+impl DeltaTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::BadArguments(format!("missing location of delta table {}", info.name))
+        })?;
+        Ok(Box::new(Self {
+            op: init_operator(sp)?,
+            info,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "DELTA".to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// the schema of the latest version of the delta table at the location,
+    /// used when the table is created without columns
+    #[async_backtrace::framed]
+    pub async fn infer_schema(sp: &StorageParams) -> Result<TableSchema> {
+        let op = init_operator(sp)?;
+        let snapshot = read_latest_snapshot(&op).await?;
+        schema_delta_to_databend(&snapshot.metadata.schema_string)
+    }
+
+    /// data files are read by the parquet table, as columns in the schema order
+    fn parquet_table_info(&self, files_to_read: Option<Vec<StageFileInfo>>) -> ParquetTableInfo {
+        let storage_params = self.info.meta.storage_params.clone().unwrap_or_default();
+        let mut stage_info = StageInfo::new_external_stage(storage_params, "/");
+        stage_info.stage_name = self.info.desc.clone();
+        ParquetTableInfo {
+            read_options: ParquetReadOptions::default(),
+            stage_info,
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: None,
+            },
+            table_info: self.info.clone(),
+            arrow_schema: self.info.schema().to_arrow(),
+            files_to_read,
+        }
+    }
+}
+
+/// the path of a data file relative to the table root
+fn relative_path(path: &str) -> Result<String> {
+    if path.contains("://") {
+        return Err(ErrorCode::Unimplemented(format!(
+            "reading delta data files out of the table directory is not supported yet: {path}"
+        )));
+    }
+    percent_decode_str(path)
+        .decode_utf8()
+        .map(|path| path.to_string())
+        .map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid path of delta file {path}: {e}"))
+        })
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    /// the partitions are read by the parquet table, see [`DeltaTable::read_partitions`]
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::ParquetSource(self.parquet_table_info(None))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = read_latest_snapshot(&self.op).await?;
+        if snapshot.files.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
+        let files_to_read = snapshot
+            .files
+            .iter()
+            .map(|file| {
+                Ok(StageFileInfo {
+                    path: relative_path(&file.path)?,
+                    size: file.size as u64,
+                    md5: None,
+                    last_modified: DateTime::<Utc>::default(),
+                    etag: None,
+                    status: StageFileStatus::NeedCopy,
+                    creator: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let parquet_table = ParquetTable::from_info(&self.parquet_table_info(Some(files_to_read)))?;
+        let (mut stats, parts) = parquet_table.read_partitions(ctx, push_downs).await?;
+        stats.snapshot = Some(snapshot.version.to_string());
+        Ok((stats, parts))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parquet_table = ParquetTable::from_info(&self.parquet_table_info(None))?;
+        parquet_table.read_data(ctx, plan, pipeline)
+    }
+}
+
+#[cfg(test)]
+mod table_test {
+    use super::relative_path;
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            relative_path("part-00000-a%20b.snappy.parquet").unwrap(),
+            "part-00000-a b.snappy.parquet"
+        );
+        assert!(relative_path("s3://bucket/other/part-00000.parquet").is_err());
+    }
+}
@@ -17,6 +17,12 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH: &str = "auto_recluster_depth";
+pub const FUSE_OPT_KEY_DICTIONARY_COLUMNS: &str = "dictionary_columns";
+pub const FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN: &str = "string_stats_prefix_len";
+pub const FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS: &str = "parquet_bloom_filter_columns";
+pub const FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const FUSE_OPT_KEY_INVERTED_INDEXES: &str = "inverted_indexes";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -26,9 +32,19 @@ pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
+pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
+pub const FUSE_TBL_DICTIONARY_PREFIX: &str = "_dict";
+/// The key prefix of the typed statistics of the virtual columns, kept in the schema
+/// metadata of the virtual blocks.
+pub const FUSE_VIRTUAL_COLUMN_STATS_PREFIX: &str = "stats:";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 8192;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.01;
+/// The average depth of the blocks above which a table is reclustered in the background, the
+/// depth of a block is counted among the blocks of its segment.
+pub const DEFAULT_AUTO_RECLUSTER_DEPTH: f64 = 2.0;
+/// The max number of blocks rewritten by a round of recluster.
+pub const MAX_RECLUSTER_BLOCK_COUNT: usize = 50;
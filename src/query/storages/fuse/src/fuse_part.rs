@@ -30,6 +30,8 @@ use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::NativeColumnEncoding;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
 pub struct FusePartInfo {
@@ -39,11 +41,16 @@ pub struct FusePartInfo {
     pub format_version: u64,
     pub nums_rows: usize,
     pub columns_meta: HashMap<ColumnId, ColumnMeta>,
+    /// Statistics of the read columns, to prune the partition with the runtime filters.
+    pub columns_stat: StatisticsOfColumns,
     pub virtual_columns_meta: Option<HashMap<String, VirtualColumnMeta>>,
     pub compression: Compression,
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The lightweight encodings of the read columns of a native block.
+    #[serde(default)]
+    pub native_encodings: HashMap<ColumnId, NativeColumnEncoding>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -73,20 +80,24 @@ impl FusePartInfo {
         format_version: u64,
         rows_count: u64,
         columns_meta: HashMap<ColumnId, ColumnMeta>,
+        columns_stat: StatisticsOfColumns,
         virtual_columns_meta: Option<HashMap<String, VirtualColumnMeta>>,
         compression: Compression,
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
+        native_encodings: HashMap<ColumnId, NativeColumnEncoding>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
             format_version,
             columns_meta,
+            columns_stat,
             virtual_columns_meta,
             nums_rows: rows_count as usize,
             compression,
             sort_min_max,
             block_meta_index,
+            native_encodings,
         }))
     }
 
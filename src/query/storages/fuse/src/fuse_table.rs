@@ -19,12 +19,15 @@ use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_catalog::catalog::StorageDescription;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::AppendMode;
+use common_catalog::table::ColumnHistogramBucket;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_catalog::table::CompactTarget;
@@ -32,11 +35,15 @@ use common_catalog::table::NavigationDescriptor;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
+use common_expression::ComputedExpr;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_expression::TableDataType;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -51,13 +58,18 @@ use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
+use storages_common_table_meta::meta::HistogramBucket;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::parse_table_compression_option;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::ColumnCompression;
+use storages_common_table_meta::table::ColumnEncoding;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
@@ -72,6 +84,8 @@ use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
 use crate::pipelines::Pipeline;
+use crate::statistics::STATS_STRING_PREFIX_LEN;
+use crate::statistics::STATS_STRING_PREFIX_MAX_LEN;
 use crate::table_functions::unwrap_tuple;
 use crate::NavigationPoint;
 use crate::Table;
@@ -79,10 +93,15 @@ use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
+use crate::FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS;
+use crate::FUSE_OPT_KEY_INVERTED_INDEXES;
+use crate::FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 
 #[derive(Clone)]
@@ -93,6 +112,8 @@ pub struct FuseTable {
     pub(crate) cluster_key_meta: Option<ClusterKey>,
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
+    pub(crate) column_compressions: HashMap<String, ColumnCompression>,
+    pub(crate) column_encodings: HashMap<String, ColumnEncoding>,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -138,6 +159,9 @@ impl FuseTable {
             .get(OPT_KEY_TABLE_COMPRESSION)
             .cloned()
             .unwrap_or_default();
+        let (table_compression, _) = parse_table_compression_option(&table_compression)?;
+        let (column_compressions, column_encodings) =
+            Self::parse_column_codecs(table_info.options())?;
 
         let part_prefix = table_info.meta.part_prefix.clone();
 
@@ -151,7 +175,9 @@ impl FuseTable {
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
-            table_compression: table_compression.as_str().try_into()?,
+            table_compression: table_compression.unwrap_or_default(),
+            column_compressions,
+            column_encodings,
         }))
     }
 
@@ -180,13 +206,238 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let string_stats_prefix_len = self.get_option(
+            FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN,
+            STATS_STRING_PREFIX_LEN,
+        );
+
+        let parquet_bloom_filter_columns = match self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS)
+        {
+            Some(option) => {
+                Self::parse_parquet_bloom_filter_columns(&self.table_info.schema(), option)
+                    .unwrap_or_default()
+            }
+            None => vec![],
+        };
+
+        let bloom_index_columns = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS)
+            .map(|option| {
+                Self::parse_bloom_index_columns(&self.table_info.schema(), option)
+                    .unwrap_or_default()
+            });
+
+        let mut inverted_index_columns = vec![];
+        for (_, columns) in self.inverted_indexes() {
+            for column in columns {
+                if !inverted_index_columns.contains(&column) {
+                    inverted_index_columns.push(column);
+                }
+            }
+        }
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
+            column_compressions: self.column_compressions.clone(),
+            column_encodings: self.column_encodings.clone(),
+            parquet_bloom_filter_columns,
+            bloom_index_columns,
+            inverted_index_columns,
             max_page_size,
             block_per_seg,
+            string_stats_prefix_len,
+        }
+    }
+
+    /// Parse the value of the `parquet_bloom_filter_columns` option, the stored columns that
+    /// the parquet bloom filters are written for in the blocks of the parquet format.
+    pub fn parse_parquet_bloom_filter_columns(
+        schema: &TableSchema,
+        option: &str,
+    ) -> Result<Vec<String>> {
+        let mut columns = vec![];
+        for name in option
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "Unknown column {name} in table option {FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS}"
+                ))
+            })?;
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Column {name} in table option {FUSE_OPT_KEY_PARQUET_BLOOM_FILTER_COLUMNS} must be a stored column"
+                )));
+            }
+            columns.push(field.name().clone());
+        }
+        Ok(columns)
+    }
+
+    /// Parse the value of the `bloom_index_columns` option, the stored columns that the bloom
+    /// index is built for. The index is built for all the supported columns if the option
+    /// is not set, and for no column if it is empty.
+    pub fn parse_bloom_index_columns(schema: &TableSchema, option: &str) -> Result<Vec<String>> {
+        let mut columns = vec![];
+        for name in option
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "Unknown column {name} in table option {FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS}"
+                ))
+            })?;
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                || !BloomIndex::supported_type(&DataType::from(field.data_type()))
+            {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Column {name} in table option {FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS} must be a stored column of a type supported by the bloom index"
+                )));
+            }
+            if !columns.contains(field.name()) {
+                columns.push(field.name().clone());
+            }
+        }
+        Ok(columns)
+    }
+
+    /// The columns the bloom index is built for.
+    pub fn bloom_index_columns(&self) -> Vec<String> {
+        let schema = self.table_info.schema();
+        match self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_BLOOM_INDEX_COLUMNS)
+        {
+            Some(option) => Self::parse_bloom_index_columns(&schema, option).unwrap_or_default(),
+            None => schema
+                .fields()
+                .iter()
+                .filter(|field| {
+                    !matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                        && BloomIndex::supported_type(&DataType::from(field.data_type()))
+                })
+                .map(|field| field.name().clone())
+                .collect(),
+        }
+    }
+
+    /// Parse the value of the `inverted_indexes` option, the inverted indexes of the table in
+    /// the form of `<index>:<column>,...;<index>:<column>,...`. The indexed columns must be
+    /// stored string columns.
+    pub fn parse_inverted_indexes(
+        schema: &TableSchema,
+        option: &str,
+    ) -> Result<Vec<(String, Vec<String>)>> {
+        let mut indexes: Vec<(String, Vec<String>)> = vec![];
+        for index in option
+            .split(';')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let (name, option_columns) = index.split_once(':').ok_or_else(|| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "Invalid inverted index {index} in table option {FUSE_OPT_KEY_INVERTED_INDEXES}, expect <index>:<column>,..."
+                ))
+            })?;
+            let name = name.trim();
+            if name.is_empty() || indexes.iter().any(|(n, _)| n == name) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Invalid or duplicated index name {name} in table option {FUSE_OPT_KEY_INVERTED_INDEXES}"
+                )));
+            }
+
+            let mut columns = vec![];
+            for column in option_columns
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+            {
+                let field = schema.field_with_name(column).map_err(|_| {
+                    ErrorCode::TableOptionInvalid(format!(
+                        "Unknown column {column} in table option {FUSE_OPT_KEY_INVERTED_INDEXES}"
+                    ))
+                })?;
+                if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                    || field.data_type().remove_nullable() != TableDataType::String
+                {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Column {column} in table option {FUSE_OPT_KEY_INVERTED_INDEXES} must be a stored string column"
+                    )));
+                }
+                if !columns.contains(field.name()) {
+                    columns.push(field.name().clone());
+                }
+            }
+            if columns.is_empty() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Inverted index {name} in table option {FUSE_OPT_KEY_INVERTED_INDEXES} has no column"
+                )));
+            }
+            indexes.push((name.to_string(), columns));
+        }
+        Ok(indexes)
+    }
+
+    /// Format the inverted indexes as the value of the `inverted_indexes` option.
+    pub fn format_inverted_indexes(indexes: &[(String, Vec<String>)]) -> String {
+        indexes
+            .iter()
+            .map(|(name, columns)| format!("{}:{}", name, columns.join(",")))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+
+    /// The inverted indexes of the table, with the columns of each index.
+    pub fn inverted_indexes(&self) -> Vec<(String, Vec<String>)> {
+        match self.table_info.options().get(FUSE_OPT_KEY_INVERTED_INDEXES) {
+            Some(option) => {
+                Self::parse_inverted_indexes(&self.table_info.schema(), option).unwrap_or_default()
+            }
+            None => vec![],
+        }
+    }
+
+    /// Check the value of the `string_stats_prefix_len` option, the number of chars kept in
+    /// the min/max statistics of string columns.
+    pub fn check_string_stats_prefix_len(options: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(value) = options.get(FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN) {
+            match value.parse::<usize>() {
+                Ok(len) if (1..=STATS_STRING_PREFIX_MAX_LEN).contains(&len) => {}
+                _ => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "table option {FUSE_OPT_KEY_STRING_STATS_PREFIX_LEN} must be an integer between 1 and {STATS_STRING_PREFIX_MAX_LEN}, but got {value}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the value of the `auto_recluster_depth` option, the average depth above which the
+    /// table is reclustered in the background, 0 disables it.
+    pub fn check_auto_recluster_depth(options: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH) {
+            match value.parse::<f64>() {
+                Ok(depth) if depth == 0.0 || depth >= 1.0 => {}
+                _ => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "table option {FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH} must be 0 or a number not less than 1, but got {value}"
+                    )));
+                }
+            }
         }
+        Ok(())
     }
 
     /// Get max page size.
@@ -568,7 +819,7 @@ impl Table for FuseTable {
     #[tracing::instrument(level = "debug", name = "analyze", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn analyze(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
-        self.do_analyze(&ctx).await
+        self.do_analyze(&ctx, None).await
     }
 
     fn table_statistics(&self) -> Result<Option<TableStatistics>> {
@@ -592,12 +843,14 @@ impl Table for FuseTable {
                     row_count: snapshot.summary.row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
+                    column_histograms: table_statistics.column_histograms.clone(),
                 }
             } else {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
                     column_distinct_values: None,
+                    column_histograms: HashMap::new(),
                 }
             }
         } else {
@@ -606,6 +859,23 @@ impl Table for FuseTable {
         Ok(Box::new(provider))
     }
 
+    #[async_backtrace::framed]
+    async fn column_dictionary(&self, column_name: &str) -> Result<Option<Vec<Vec<u8>>>> {
+        let Some((column_id, _)) = self
+            .dictionary_columns()
+            .into_iter()
+            .find(|(_, name)| name == column_name)
+        else {
+            return Ok(None);
+        };
+        // The dictionary of a column having too many distinct values is of no use.
+        Ok(self
+            .read_column_dictionary(column_id)
+            .await?
+            .filter(|dictionary| !dictionary.overflow)
+            .map(|dictionary| dictionary.values))
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_navigate_to", skip_all)]
     #[async_backtrace::framed]
     async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
@@ -628,6 +898,40 @@ impl Table for FuseTable {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn pin_snapshot(
+        &self,
+        time_point: DateTime<Utc>,
+    ) -> Result<Option<(Arc<dyn Table>, String)>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        // Most of the time nothing has been committed since the query started.
+        if snapshot.timestamp.map_or(true, |ts| ts <= time_point) {
+            let snapshot_id = snapshot.snapshot_id.simple().to_string();
+            return Ok(Some((Arc::new(self.clone()), snapshot_id)));
+        }
+
+        let location = self.snapshot_loc().await?.unwrap();
+        match self.navigate_to_time_point(location, time_point).await {
+            Ok(table) => {
+                let snapshot_id = match table.read_table_snapshot().await? {
+                    Some(snapshot) => snapshot.snapshot_id.simple().to_string(),
+                    None => return Ok(None),
+                };
+                Ok(Some((table as Arc<dyn Table>, snapshot_id)))
+            }
+            // The table is created after `time_point`, e.g. the clocks of the nodes are skewed,
+            // the latest snapshot is the only choice.
+            Err(e) if e.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                let snapshot_id = snapshot.snapshot_id.simple().to_string();
+                Ok(Some((Arc::new(self.clone()), snapshot_id)))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn delete(
         &self,
@@ -662,6 +966,11 @@ impl Table for FuseTable {
         .await
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        self.build_merge_into_pipeline(ctx, pipeline).await
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         let max_rows_per_block =
             self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, DEFAULT_BLOCK_MAX_ROWS);
@@ -690,8 +999,9 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         push_downs: Option<PushDownInfo>,
+        limit: Option<usize>,
     ) -> Result<()> {
-        self.do_recluster(ctx, pipeline, push_downs).await
+        self.do_recluster(ctx, pipeline, push_downs, limit).await
     }
 
     #[async_backtrace::framed]
@@ -745,6 +1055,7 @@ impl FromStr for FuseStorageFormat {
 struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
+    pub column_histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
     pub row_count: u64,
 }
 
@@ -762,6 +1073,20 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
                 max: s.max.clone(),
                 null_count: s.null_count,
                 number_of_distinct_values: ndv,
+                histogram: self
+                    .column_histograms
+                    .get(&column_id)
+                    .map(|buckets| {
+                        buckets
+                            .iter()
+                            .map(|bucket| ColumnHistogramBucket {
+                                upper_bound: bucket.upper_bound.clone(),
+                                num_values: bucket.num_values,
+                                num_distinct: bucket.num_distinct,
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
             }
         })
     }
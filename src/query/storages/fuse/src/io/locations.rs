@@ -15,6 +15,7 @@
 use std::marker::PhantomData;
 
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -23,7 +24,9 @@ use storages_common_table_meta::meta::TableSnapshotStatisticsVersion;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
+use crate::constants::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_DICTIONARY_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
@@ -145,6 +148,20 @@ impl TableMetaLocationGenerator {
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
+
+    pub fn gen_agg_index_location_from_block_location(location: &str, index_id: u64) -> String {
+        location.replace(
+            FUSE_TBL_BLOCK_PREFIX,
+            &format!("{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}"),
+        )
+    }
+
+    pub fn gen_dictionary_location(&self, column_id: ColumnId) -> String {
+        format!(
+            "{}/{}/{}.json",
+            &self.prefix, FUSE_TBL_DICTIONARY_PREFIX, column_id
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -33,6 +33,7 @@ use common_expression::TableSchemaRef;
 use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
+use common_storage::DataCacheMetrics;
 use opendal::Operator;
 
 // TODO: make BlockReader as a trait.
@@ -45,6 +46,7 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    pub(crate) data_cache_metrics: Arc<DataCacheMetrics>,
     pub query_internal_columns: bool,
 }
 
@@ -134,6 +136,7 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            data_cache_metrics: ctx.get_data_cache_metrics(),
             query_internal_columns,
         }))
     }
@@ -24,6 +24,7 @@ use storages_common_cache_manager::SizedColumnArray;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::NativeColumnEncoding;
 
 use super::BlockReader;
 use crate::io::read::block::block_reader_merge_io::DataItem;
@@ -62,9 +63,11 @@ impl BlockReader {
             &part.columns_meta,
             chunks,
             storage_format,
+            &part.native_encodings,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn deserialize_chunks(
         &self,
         block_path: &str,
@@ -73,6 +76,7 @@ impl BlockReader {
         column_metas: &HashMap<ColumnId, ColumnMeta>,
         column_chunks: HashMap<ColumnId, DataItem>,
         storage_format: &FuseStorageFormat,
+        native_encodings: &HashMap<ColumnId, NativeColumnEncoding>,
     ) -> Result<DataBlock> {
         match storage_format {
             FuseStorageFormat::Parquet => self.deserialize_parquet_chunks(
@@ -88,6 +92,7 @@ impl BlockReader {
                 compression,
                 column_metas,
                 column_chunks,
+                native_encodings,
             ),
         }
     }
@@ -128,6 +133,7 @@ impl BlockReader {
                 columns_meta,
                 column_chunks,
                 None,
+                &meta.native_encodings,
             ),
         }
     }
@@ -21,6 +21,7 @@ use common_base::runtime::UnlimitedFuture;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
+use common_storage::RangeReadPolicy;
 use futures::future::try_join_all;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
@@ -102,6 +103,7 @@ impl BlockReader {
         // Perf.
         {
             metrics_inc_remote_io_read_milliseconds(start.elapsed().as_millis() as u64);
+            metrics_observe_block_read_latency(start.elapsed());
         }
 
         for (raw_idx, raw_range) in &raw_ranges {
@@ -142,6 +144,7 @@ impl BlockReader {
         // for async read, try using table data cache (if enabled in settings)
         let column_data_cache = CacheManager::instance().get_table_data_cache();
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
+        let cache_enabled = column_data_cache.is_some() || column_array_cache.is_some();
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
         for (_index, (column_id, ..)) in self.project_indices.iter() {
@@ -172,6 +175,12 @@ impl BlockReader {
             }
         }
 
+        if cache_enabled {
+            let hits = cached_column_data.len() + cached_column_array.len();
+            self.data_cache_metrics.inc_hits(hits as u64);
+            self.data_cache_metrics.inc_misses(ranges.len() as u64);
+        }
+
         let mut merge_io_read_res =
             Self::merge_io_read(settings, self.operator.clone(), location, ranges).await?;
 
@@ -180,6 +189,57 @@ impl BlockReader {
         Ok(merge_io_read_res)
     }
 
+    /// Returns the ranges of the projected columns of a block which are neither
+    /// in the table data cache nor in the column array cache.
+    pub fn uncached_column_ranges(
+        &self,
+        location: &str,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Vec<(ColumnId, Range<u64>)> {
+        let column_data_cache = CacheManager::instance().get_table_data_cache();
+        let column_array_cache = CacheManager::instance().get_table_data_array_cache();
+        let mut ranges = vec![];
+        for (_index, (column_id, ..)) in self.project_indices.iter() {
+            let column_cache_key = TableDataCacheKey::new(location, *column_id);
+            if column_array_cache.contains_key(column_cache_key.as_ref())
+                || column_data_cache.contains_key(column_cache_key.as_ref())
+            {
+                continue;
+            }
+            if let Some(column_meta) = columns_meta.get(column_id) {
+                let (offset, len) = column_meta.offset_length();
+                ranges.push((*column_id, offset..(offset + len)));
+            }
+        }
+        ranges
+    }
+
+    /// Read the given column ranges of a block into the table data cache, so
+    /// that reading the block later hits the cache instead of the object storage.
+    ///
+    /// Does nothing if the table data cache is disabled.
+    #[async_backtrace::framed]
+    pub async fn prefetch_columns_data(
+        &self,
+        settings: &ReadSettings,
+        location: &str,
+        ranges: Vec<(ColumnId, Range<u64>)>,
+    ) -> Result<()> {
+        if ranges.is_empty() || CacheManager::instance().get_table_data_cache().is_none() {
+            return Ok(());
+        }
+
+        // Perf
+        {
+            metrics_inc_remote_io_prefetch_parts(1);
+            metrics_inc_remote_io_prefetch_bytes(ranges.iter().map(|(_, r)| r.end - r.start).sum());
+        }
+
+        // The chunks are put into the table data cache when the result is built.
+        Self::merge_io_read(settings, self.operator.clone(), location, ranges).await?;
+        Ok(())
+    }
+
     #[inline]
     #[async_backtrace::framed]
     pub async fn read_range(
@@ -189,7 +249,10 @@ impl BlockReader {
         start: u64,
         end: u64,
     ) -> Result<(usize, Vec<u8>)> {
-        let chunk = op.range_read(path, start..end).await?;
+        let chunk = match RangeReadPolicy::get(&op) {
+            Some(policy) => policy.range_read(&op, path, start..end).await?,
+            None => op.range_read(path, start..end).await?,
+        };
         Ok((index, chunk))
     }
 }
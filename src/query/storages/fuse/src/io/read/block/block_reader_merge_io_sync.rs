@@ -120,6 +120,12 @@ impl BlockReader {
             }
         }
 
+        if column_array_cache.is_some() {
+            self.data_cache_metrics
+                .inc_hits(cached_column_array.len() as u64);
+            self.data_cache_metrics.inc_misses(ranges.len() as u64);
+        }
+
         let mut merge_io_result =
             Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, ranges)?;
         merge_io_result.cached_column_array = cached_column_array;
@@ -40,6 +40,7 @@ use crate::metrics::metrics_inc_remote_io_read_bytes;
 use crate::metrics::metrics_inc_remote_io_read_milliseconds;
 use crate::metrics::metrics_inc_remote_io_read_parts;
 use crate::metrics::metrics_inc_remote_io_seeks;
+use crate::metrics::metrics_observe_block_read_latency;
 
 // Native storage format
 
@@ -103,6 +104,7 @@ impl BlockReader {
         // Perf.
         {
             metrics_inc_remote_io_read_milliseconds(start.elapsed().as_millis() as u64);
+            metrics_observe_block_read_latency(start.elapsed());
         }
         Ok(results)
     }
@@ -123,7 +125,8 @@ impl BlockReader {
                 native_meta = native_meta.slice(range.start, range.end);
             }
 
-            let reader = op.range_read(path, offset..offset + length).await?;
+            let (_, reader) =
+                Self::read_range(op.clone(), path, 0, offset, offset + length).await?;
             let reader: Reader = Box::new(std::io::Cursor::new(reader));
 
             let native_reader = NativeReader::new(reader, native_meta.pages.clone(), vec![]);
@@ -26,11 +26,14 @@ use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_storage::ColumnNode;
+use storages_common_blocks::decode_native_pages;
+use storages_common_blocks::native_encoded_field;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
 use storages_common_cache_manager::CacheManager;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::NativeColumnEncoding;
 
 use super::block_reader_deserialize::DeserializedArray;
 use super::block_reader_deserialize::FieldDeserializationContext;
@@ -48,6 +51,7 @@ impl BlockReader {
         compression: &Compression,
         column_metas: &HashMap<ColumnId, ColumnMeta>,
         column_chunks: HashMap<ColumnId, DataItem>,
+        native_encodings: &HashMap<ColumnId, NativeColumnEncoding>,
     ) -> Result<DataBlock> {
         let start = Instant::now();
 
@@ -62,6 +66,7 @@ impl BlockReader {
             column_metas,
             column_chunks,
             None,
+            native_encodings,
         );
 
         // Perf.
@@ -73,6 +78,7 @@ impl BlockReader {
     }
 
     /// Deserialize column chunks data from native format to DataBlock with a uncompressed buffer.
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn deserialize_native_chunks_with_buffer(
         &self,
         block_path: &str,
@@ -81,6 +87,7 @@ impl BlockReader {
         column_metas: &HashMap<ColumnId, ColumnMeta>,
         column_chunks: HashMap<ColumnId, DataItem>,
         uncompressed_buffer: Option<Arc<UncompressedBuffer>>,
+        native_encodings: &HashMap<ColumnId, NativeColumnEncoding>,
     ) -> Result<DataBlock> {
         if column_chunks.is_empty() {
             return self.build_default_values_block(num_rows);
@@ -98,7 +105,15 @@ impl BlockReader {
         };
 
         for column_node in &self.project_column_nodes {
-            match self.deserialize_native_field(&field_deserialization_ctx, column_node)? {
+            let native_encoding = match column_node.leaf_column_ids.as_slice() {
+                [column_id] => native_encodings.get(column_id),
+                _ => None,
+            };
+            match self.deserialize_native_field(
+                &field_deserialization_ctx,
+                column_node,
+                native_encoding,
+            )? {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
@@ -167,6 +182,7 @@ impl BlockReader {
         chunks: Vec<&[u8]>,
         column_descriptors: Vec<ColumnDescriptor>,
         field: Field,
+        native_encoding: Option<&NativeColumnEncoding>,
     ) -> Result<Box<dyn Array>> {
         let is_nested = column_node.is_nested;
         let mut page_metas = Vec::with_capacity(chunks.len());
@@ -178,16 +194,37 @@ impl BlockReader {
             page_metas.push(meta.pages.clone());
         }
 
-        match batch_read_array(readers, column_descriptors, field, is_nested, page_metas) {
-            Ok(array) => Ok(array),
-            Err(err) => Err(err.into()),
-        }
+        let Some(native_encoding) = native_encoding else {
+            let array = batch_read_array(readers, column_descriptors, field, is_nested, page_metas)?;
+            return Ok(array);
+        };
+
+        // the lightweight encoded values are read as they are stored, and decoded page by page.
+        let page_rows = page_metas[0]
+            .iter()
+            .map(|page| page.num_values)
+            .collect::<Vec<_>>();
+        let stored_field = native_encoded_field(&field, native_encoding);
+        let array = batch_read_array(
+            readers,
+            column_descriptors,
+            stored_field,
+            is_nested,
+            page_metas,
+        )?;
+        decode_native_pages(
+            native_encoding,
+            &page_rows,
+            array.as_ref(),
+            field.data_type(),
+        )
     }
 
     fn deserialize_native_field<'a>(
         &self,
         deserialization_context: &'a FieldDeserializationContext,
         column: &ColumnNode,
+        native_encoding: Option<&NativeColumnEncoding>,
     ) -> Result<Option<DeserializedArray<'a>>> {
         let indices = &column.leaf_indices;
         let column_chunks = deserialization_context.column_chunks;
@@ -239,6 +276,7 @@ impl BlockReader {
                 field_column_data,
                 field_column_descriptors,
                 column.field.clone(),
+                native_encoding,
             )?;
             // mark the array
             if is_nested {
@@ -184,6 +184,8 @@ impl BlockReader {
             .zip(chunks.into_iter().zip(column_descriptors.iter()))
             .map(|(meta, (chunk, column_descriptor))| {
                 let meta = meta.as_parquet().unwrap();
+                // the compression of the column may be set apart from the one of the block.
+                let compression = meta.compression.as_ref().unwrap_or(compression);
 
                 let page_meta_data = PageMetaData {
                     column_start: meta.offset,
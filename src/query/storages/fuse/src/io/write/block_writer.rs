@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::native::write::NativeWriter;
 use common_catalog::table_context::TableContext;
@@ -22,23 +23,28 @@ use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
+use storages_common_blocks::blocks_to_parquet_with_column_options;
+use storages_common_blocks::encode_native_columns;
 use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::NativeColumnEncoding;
+use storages_common_table_meta::table::ColumnEncoding;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::fuse_table::FuseStorageFormat;
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
-use crate::statistics::gen_columns_statistics;
+use crate::statistics::gen_columns_statistics_with_prefix_len;
 use crate::statistics::ClusterStatsGenerator;
 
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
@@ -47,43 +53,120 @@ pub fn serialize_block(
     schema: &TableSchemaRef,
     block: DataBlock,
     buf: &mut Vec<u8>,
-) -> Result<(u64, HashMap<ColumnId, ColumnMeta>)> {
+) -> Result<(
+    u64,
+    HashMap<ColumnId, ColumnMeta>,
+    HashMap<ColumnId, NativeColumnEncoding>,
+)> {
     let schema = Arc::new(schema.remove_virtual_computed_fields());
     match write_settings.storage_format {
         FuseStorageFormat::Parquet => {
-            let result =
-                blocks_to_parquet(&schema, vec![block], buf, write_settings.table_compression)?;
-            let meta = util::column_parquet_metas(&result.1, &schema)?;
-            Ok((result.0, meta))
+            let result = blocks_to_parquet_with_column_options(
+                &schema,
+                vec![block],
+                buf,
+                write_settings.table_compression,
+                &write_settings.column_compressions,
+                &write_settings.column_encodings,
+                &write_settings.parquet_bloom_filter_columns,
+            )?;
+            let mut meta = util::column_parquet_metas(&result.1, &schema)?;
+            // record the compressions of the columns that differ from the one of the block.
+            for (name, compression) in &write_settings.column_compressions {
+                let Ok(field) = schema.field_with_name(name) else {
+                    continue;
+                };
+                for column_id in field.leaf_column_ids() {
+                    if let Some(ColumnMeta::Parquet(column_meta)) = meta.get_mut(&column_id) {
+                        column_meta.compression = Some(compression.compression.into());
+                    }
+                }
+            }
+            Ok((result.0, meta, HashMap::new()))
         }
         FuseStorageFormat::Native => {
-            let arrow_schema = schema.to_arrow();
-            let mut writer = NativeWriter::new(
+            let batch = ArrowChunk::try_from(block)?;
+            let start = buf.len();
+            let (file_size, metas, encodings) = write_native_block(
+                write_settings,
+                &schema,
+                batch.clone(),
                 buf,
-                arrow_schema,
-                common_arrow::native::write::WriteOptions {
-                    compression: write_settings.table_compression.into(),
-                    max_page_size: Some(write_settings.max_page_size),
-                },
-            );
+                &write_settings.column_encodings,
+            )?;
+            if encodings.is_empty() {
+                return Ok((file_size, metas, encodings));
+            }
 
-            let batch = ArrowChunk::try_from(block)?;
+            // the pages are encoded on their own, which relies on each page of a column
+            // holding `max_page_size` rows, write the block again without the encodings
+            // if the writer splits the pages otherwise.
+            let page_size = write_settings.max_page_size as u64;
+            let as_expected = encodings
+                .keys()
+                .all(|column_id| match metas.get(column_id) {
+                    Some(ColumnMeta::Native(meta)) => {
+                        meta.pages
+                            .iter()
+                            .rev()
+                            .skip(1)
+                            .all(|page| page.num_values == page_size)
+                            && meta
+                                .pages
+                                .last()
+                                .map_or(true, |page| page.num_values <= page_size)
+                    }
+                    _ => false,
+                });
+            if as_expected {
+                return Ok((file_size, metas, encodings));
+            }
+            buf.truncate(start);
+            write_native_block(write_settings, &schema, batch, buf, &HashMap::new())
+        }
+    }
+}
 
-            writer.start()?;
-            writer.write(&batch)?;
-            writer.finish()?;
+fn write_native_block(
+    write_settings: &WriteSettings,
+    schema: &TableSchema,
+    batch: ArrowChunk<Box<dyn Array>>,
+    buf: &mut Vec<u8>,
+    column_encodings: &HashMap<String, ColumnEncoding>,
+) -> Result<(
+    u64,
+    HashMap<ColumnId, ColumnMeta>,
+    HashMap<ColumnId, NativeColumnEncoding>,
+)> {
+    let (arrow_schema, batch, encodings) = encode_native_columns(
+        schema,
+        schema.to_arrow(),
+        batch,
+        write_settings.max_page_size,
+        column_encodings,
+    )?;
+    let mut writer = NativeWriter::new(
+        buf,
+        arrow_schema,
+        common_arrow::native::write::WriteOptions {
+            compression: write_settings.table_compression.into(),
+            max_page_size: Some(write_settings.max_page_size),
+        },
+    );
 
-            let leaf_column_ids = &schema.to_leaf_column_ids();
-            let mut metas = HashMap::with_capacity(writer.metas.len());
-            for (idx, meta) in writer.metas.iter().enumerate() {
-                // use column id as key instead of index
-                let column_id = leaf_column_ids.get(idx).unwrap();
-                metas.insert(*column_id, ColumnMeta::Native(meta.clone()));
-            }
+    writer.start()?;
+    writer.write(&batch)?;
+    writer.finish()?;
 
-            Ok((writer.total_size() as u64, metas))
-        }
+    let leaf_column_ids = &schema.to_leaf_column_ids();
+    let mut metas = HashMap::with_capacity(writer.metas.len());
+    for (idx, meta) in writer.metas.iter().enumerate() {
+        // use column id as key instead of index
+        let column_id = leaf_column_ids.get(idx).unwrap();
+        metas.insert(*column_id, ColumnMeta::Native(meta.clone()));
     }
+
+    Ok((writer.total_size() as u64, metas, encodings))
 }
 
 /// Take ownership here to avoid extra copy.
@@ -107,12 +190,18 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        bloom_columns: Option<&[String]>,
+        inverted_columns: &[String],
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            bloom_columns,
+            inverted_columns,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -166,6 +255,8 @@ impl BlockBuilder {
             self.source_schema.clone(),
             &data_block,
             bloom_index_location,
+            self.write_settings.bloom_index_columns.as_deref(),
+            &self.write_settings.inverted_index_columns,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -173,11 +264,15 @@ impl BlockBuilder {
 
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
-        let col_stats =
-            gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        let col_stats = gen_columns_statistics_with_prefix_len(
+            &data_block,
+            column_distinct_count,
+            &self.source_schema,
+            self.write_settings.string_stats_prefix_len,
+        )?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let (file_size, col_metas) = serialize_block(
+        let (file_size, col_metas, native_encodings) = serialize_block(
             &self.write_settings,
             &self.source_schema,
             data_block,
@@ -198,6 +293,7 @@ impl BlockBuilder {
                 .map(|v| v.size)
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
+            native_encodings,
         };
 
         let serialized = BlockSerialization {
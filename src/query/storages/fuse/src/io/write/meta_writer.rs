@@ -171,7 +171,7 @@ mod tests {
         // we omit the checking of invalid format versions, otherwise clippy will complain about empty_ranges
 
         // current version allowed
-        let snapshot_stats = TableSnapshotStatistics::new(HashMap::new());
+        let snapshot_stats = TableSnapshotStatistics::new(HashMap::new(), HashMap::new(), None);
         snapshot_stats.marshal().unwrap();
     }
 }
@@ -12,8 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use storages_common_table_meta::table::ColumnCompression;
+use storages_common_table_meta::table::ColumnEncoding;
 use storages_common_table_meta::table::TableCompression;
 
+use crate::statistics::STATS_STRING_PREFIX_LEN;
 use crate::FuseStorageFormat;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
@@ -22,10 +27,21 @@ use crate::DEFAULT_ROW_PER_PAGE;
 pub struct WriteSettings {
     pub storage_format: FuseStorageFormat,
     pub table_compression: TableCompression,
+    // compressions and encodings of columns, current only work in parquet format
+    pub column_compressions: HashMap<String, ColumnCompression>,
+    pub column_encodings: HashMap<String, ColumnEncoding>,
+    // columns written with the parquet bloom filters, current only work in parquet format
+    pub parquet_bloom_filter_columns: Vec<String>,
+    // columns the bloom index is built for, all the supported columns if not given
+    pub bloom_index_columns: Option<Vec<String>>,
+    // string columns of the inverted indexes, the terms in them are written with the bloom index
+    pub inverted_index_columns: Vec<String>,
     // rows per page, current only work in native format
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+    // chars kept in the min/max statistics of string columns
+    pub string_stats_prefix_len: usize,
 }
 
 impl Default for WriteSettings {
@@ -33,8 +49,14 @@ impl Default for WriteSettings {
         Self {
             storage_format: FuseStorageFormat::Parquet,
             table_compression: TableCompression::default(),
+            column_compressions: HashMap::new(),
+            column_encodings: HashMap::new(),
+            parquet_bloom_filter_columns: vec![],
+            bloom_index_columns: None,
+            inverted_index_columns: vec![],
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            string_stats_prefix_len: STATS_STRING_PREFIX_LEN,
         }
     }
 }
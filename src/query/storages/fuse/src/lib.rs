@@ -26,6 +26,7 @@ pub mod io;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
+mod stream_table;
 pub mod table_functions;
 
 mod metrics;
@@ -39,6 +40,10 @@ pub use fuse_part::FusePartInfo;
 pub use fuse_table::FuseStorageFormat;
 pub use fuse_table::FuseTable;
 pub use io::MergeIOReadResult;
+pub use stream_table::StreamTable;
+pub use stream_table::CHANGE_ACTION_COLUMN_NAME;
+pub use stream_table::CHANGE_ROW_ID_COLUMN_NAME;
+pub use stream_table::STREAM_ENGINE;
 
 pub use crate::metrics::metrics_reset;
 
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use metrics::counter;
 use metrics::gauge;
+use metrics::histogram;
 use metrics::increment_gauge;
 
 macro_rules! key {
@@ -66,10 +69,23 @@ pub fn metrics_inc_remote_io_read_milliseconds(c: u64) {
     increment_gauge!(key!("remote_io_read_milliseconds"), c as f64);
 }
 
+/// Observes the latency of reading a block, see `common_metrics::METRIC_BLOCK_READ_LATENCY_SECONDS`.
+pub fn metrics_observe_block_read_latency(elapsed: Duration) {
+    histogram!(key!("block_read_latency_seconds"), elapsed.as_secs_f64());
+}
+
 pub fn metrics_inc_remote_io_deserialize_milliseconds(c: u64) {
     increment_gauge!(key!("remote_io_deserialize_milliseconds"), c as f64);
 }
 
+pub fn metrics_inc_remote_io_prefetch_parts(c: u64) {
+    increment_gauge!(key!("remote_io_prefetch_parts"), c as f64);
+}
+
+pub fn metrics_inc_remote_io_prefetch_bytes(c: u64) {
+    increment_gauge!(key!("remote_io_prefetch_bytes"), c as f64);
+}
+
 /// Block metrics.
 pub fn metrics_inc_block_write_nums(c: u64) {
     increment_gauge!(key!("block_write_nums"), c as f64);
@@ -157,6 +173,10 @@ pub fn metrics_inc_bytes_block_range_pruning_after(c: u64) {
     increment_gauge!(key!("bytes_block_range_pruning_after"), c as f64);
 }
 
+pub fn metrics_inc_blocks_runtime_filter_pruned(c: u64) {
+    increment_gauge!(key!("blocks_runtime_filter_pruned"), c as f64);
+}
+
 pub fn metrics_inc_blocks_bloom_pruning_before(c: u64) {
     increment_gauge!(key!("blocks_bloom_pruning_before"), c as f64);
 }
@@ -228,4 +248,5 @@ pub fn metrics_reset() {
     gauge!(key!("bytes_block_bloom_pruning_after"), c);
     gauge!(key!("bytes_block_range_pruning_before"), c);
     gauge!(key!("bytes_block_range_pruning_after"), c);
+    gauge!(key!("blocks_runtime_filter_pruned"), c);
 }
@@ -0,0 +1,265 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bumpalo::Bump;
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_catalog::plan::AggIndexDefinition;
+use common_catalog::plan::AggIndexItem;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BooleanType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::StateAddr;
+use common_functions::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+
+/// Build the aggregating index data of a block from its raw data.
+///
+/// `schema` is the schema of `block`, the expressions of the definition refer to its columns by name.
+/// If the index is aggregated, the aggregate functions are stored as their serialized states.
+pub fn build_agg_index_block(
+    func_ctx: &FunctionContext,
+    definition: &AggIndexDefinition,
+    schema: &TableSchema,
+    block: DataBlock,
+) -> Result<DataBlock> {
+    let to_expr = |expr: &RemoteExpr<String>| -> Expr {
+        expr.as_expr(&BUILTIN_FUNCTIONS)
+            .project_column_ref(|name| schema.index_of(name).unwrap())
+    };
+
+    let block = match &definition.filter {
+        Some(filter) => {
+            let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+            let filter = evaluator
+                .run(&to_expr(filter))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            block.filter_boolean_value(&filter)?
+        }
+        None => block,
+    };
+
+    let num_rows = block.num_rows();
+    let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+    let eval = |expr: &RemoteExpr<String>| -> Result<Column> {
+        let expr = to_expr(expr);
+        let value = evaluator.run(&expr)?;
+        Ok(value.convert_to_full_column(expr.data_type(), num_rows))
+    };
+
+    if !definition.is_agg {
+        let mut columns = Vec::with_capacity(definition.selection.len());
+        for item in definition.selection.iter() {
+            match item {
+                AggIndexItem::Scalar(expr) => columns.push(eval(expr)?),
+                AggIndexItem::Aggregate { func_name, .. } => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Aggregate function {func_name} in a non-aggregated index"
+                    )));
+                }
+            }
+        }
+        return Ok(to_block(columns, num_rows));
+    }
+
+    // Assign each row to its group.
+    let group_columns = definition
+        .group_items
+        .iter()
+        .map(eval)
+        .collect::<Result<Vec<_>>>()?;
+    let mut groups: HashMap<Vec<Scalar>, usize> = HashMap::new();
+    let mut first_rows = vec![];
+    let mut row_groups = Vec::with_capacity(num_rows);
+    for row in 0..num_rows {
+        let key = group_columns
+            .iter()
+            .map(|col| unsafe { col.index_unchecked(row) }.to_owned())
+            .collect::<Vec<_>>();
+        let group = *groups.entry(key).or_insert_with(|| {
+            first_rows.push(row as u32);
+            first_rows.len() - 1
+        });
+        row_groups.push(group);
+    }
+    let num_groups = first_rows.len();
+
+    let mut columns = Vec::with_capacity(definition.selection.len());
+    for item in definition.selection.iter() {
+        match item {
+            AggIndexItem::Scalar(expr) => columns.push(eval(expr)?.take(&first_rows)),
+            AggIndexItem::Aggregate {
+                func_name,
+                params,
+                args,
+            } => {
+                let args = args.iter().map(eval).collect::<Result<Vec<_>>>()?;
+                let arg_types = args.iter().map(|arg| arg.data_type()).collect();
+                let func = AggregateFunctionFactory::instance().get(
+                    func_name,
+                    params.clone(),
+                    arg_types,
+                )?;
+
+                let arena = Bump::new();
+                let places = (0..num_groups)
+                    .map(|_| {
+                        let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                        func.init_state(place);
+                        place
+                    })
+                    .collect::<Vec<_>>();
+                let row_places = row_groups
+                    .iter()
+                    .map(|group| places[*group])
+                    .collect::<Vec<_>>();
+
+                let res = func
+                    .accumulate_keys(&row_places, 0, &args, num_rows)
+                    .and_then(|_| {
+                        let mut builder = StringColumnBuilder::with_capacity(num_groups, 0);
+                        for place in places.iter() {
+                            func.serialize(*place, &mut builder.data)?;
+                            builder.commit_row();
+                        }
+                        Ok(Column::String(builder.build()))
+                    });
+                if func.need_manual_drop_state() {
+                    for place in places.iter() {
+                        unsafe { func.drop_state(*place) };
+                    }
+                }
+                columns.push(res?);
+            }
+        }
+    }
+    Ok(to_block(columns, num_groups))
+}
+
+fn to_block(columns: Vec<Column>, num_rows: usize) -> DataBlock {
+    let entries = columns
+        .into_iter()
+        .map(|col| BlockEntry::new(col.data_type(), Value::Column(col)))
+        .collect();
+    DataBlock::new(entries, num_rows)
+}
+
+/// Serialize the aggregating index data of a block into a parquet file.
+pub fn serialize_agg_index_block(
+    block: DataBlock,
+    compression: TableCompression,
+) -> Result<Vec<u8>> {
+    let fields = block
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            Ok(TableField::new(
+                &format!("index_col_{i}"),
+                infer_schema_type(&entry.data_type)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = TableSchema::new(fields);
+
+    let mut data = vec![];
+    blocks_to_parquet(&schema, vec![block], &mut data, compression)?;
+    Ok(data)
+}
+
+/// Read the aggregating index data of a block.
+///
+/// Returns [None] if the index data of the block is not built yet.
+#[async_backtrace::framed]
+pub async fn read_agg_index_block(
+    operator: &Operator,
+    location: &str,
+) -> Result<Option<DataBlock>> {
+    let data = match operator.read(location).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut reader = Cursor::new(data);
+    let meta = read_metadata(&mut reader)?;
+    let arrow_schema = infer_schema(&meta)?;
+    let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
+
+    let chunks_iter =
+        pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+    let mut blocks = vec![];
+    for chunk in chunks_iter {
+        blocks.push(DataBlock::from_arrow_chunk(&chunk?, &schema)?);
+    }
+
+    match blocks.is_empty() {
+        true => Ok(Some(DataBlock::empty_with_schema(schema.into()))),
+        false => Ok(Some(DataBlock::concat(&blocks)?)),
+    }
+}
+
+/// Apply the filter and the selection of the rewritten query to the index data.
+pub fn eval_agg_index_selection(
+    func_ctx: &FunctionContext,
+    selection: &[RemoteExpr],
+    filter: Option<&RemoteExpr>,
+    block: DataBlock,
+) -> Result<DataBlock> {
+    let block = match filter {
+        Some(filter) => {
+            let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+            let filter = evaluator
+                .run(&filter.as_expr(&BUILTIN_FUNCTIONS))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            block.filter_boolean_value(&filter)?
+        }
+        None => block,
+    };
+
+    let num_rows = block.num_rows();
+    let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+    let mut entries = Vec::with_capacity(selection.len());
+    for expr in selection {
+        let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+        entries.push(BlockEntry::new(
+            expr.data_type().clone(),
+            evaluator.run(&expr)?,
+        ));
+    }
+    Ok(DataBlock::new(entries, num_rows))
+}
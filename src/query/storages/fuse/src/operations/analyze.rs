@@ -20,6 +20,8 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use storages_common_table_meta::meta::HistogramBucket;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -27,12 +29,29 @@ use tracing::info;
 use tracing::warn;
 
 use crate::io::SegmentsIO;
+use crate::statistics::calc_clustering_depth;
 use crate::statistics::reduce_block_statistics;
 use crate::FuseTable;
 
+/// Statistics of columns computed by scanning the data of the table.
+#[derive(Default)]
+pub struct AnalyzedColumns {
+    /// NDV of the columns, estimated by HyperLogLog.
+    pub distinct_values: HashMap<ColumnId, u64>,
+    pub histograms: HashMap<ColumnId, Vec<HistogramBucket>>,
+}
+
 impl FuseTable {
+    /// Persists the statistics of the columns of the latest snapshot, and the clustering depth of
+    /// its blocks if the table has a cluster key.
+    ///
+    /// The NDV of the columns not in `analyzed` is estimated from the NDV of the blocks.
     #[async_backtrace::framed]
-    pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+    pub async fn do_analyze(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        analyzed: Option<AnalyzedColumns>,
+    ) -> Result<()> {
         // 1. Read table snapshot.
         let r = self.read_table_snapshot().await;
         let snapshot_opt = match r {
@@ -56,6 +75,10 @@ impl FuseTable {
             let mut block_count_sum: u64 = 0;
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
+            // The cluster key ranges of the blocks, to compute the clustering depth.
+            let cluster_key_id = self.cluster_key_meta.as_ref().map(|(id, _)| *id);
+            let mut cluster_ranges = Vec::new();
+            let mut unclustered_block_count = 0;
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -75,6 +98,14 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     segment.blocks.iter().for_each(|block| {
                         let block = block.as_ref();
+                        if let Some(cluster_key_id) = cluster_key_id {
+                            match &block.cluster_stats {
+                                Some(stats) if stats.cluster_key_id == cluster_key_id => {
+                                    cluster_ranges.push((stats.min.clone(), stats.max.clone()));
+                                }
+                                _ => unclustered_block_count += 1,
+                            }
+                        }
                         let row_count = block.row_count;
                         if row_count != 0 {
                             block_count_sum += 1;
@@ -122,7 +153,21 @@ impl FuseTable {
             }
 
             // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            let AnalyzedColumns {
+                distinct_values,
+                histograms,
+            } = analyzed.unwrap_or_default();
+            ndv_map.extend(distinct_values);
+            let clustering_depth = cluster_key_id.map(|cluster_key_id| {
+                calc_clustering_depth(
+                    snapshot.snapshot_id,
+                    cluster_key_id,
+                    &cluster_ranges,
+                    unclustered_block_count,
+                )
+            });
+            let table_statistics =
+                TableSnapshotStatistics::new(ndv_map, histograms, clustering_depth);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
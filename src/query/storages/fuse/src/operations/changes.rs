@@ -0,0 +1,241 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::Value;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// The action of the rows inserted into the table, in the `change$action` column of a stream.
+pub const CHANGE_ACTION_INSERT: &str = "INSERT";
+/// The action of the rows deleted from the table, in the `change$action` column of a stream.
+pub const CHANGE_ACTION_DELETE: &str = "DELETE";
+
+impl FuseTable {
+    /// Returns the rows changed between the snapshot at `base_location` and the current
+    /// snapshot of the table, `base_location` is [None] if the table had no snapshot.
+    ///
+    /// The blocks hold the columns at `field_indices` of the table schema, followed by the
+    /// action of the change, `INSERT` or `DELETE`, and the id of the row. An updated row is
+    /// returned as the deletion of its old value and the insertion of its new value, the rows
+    /// of the rewritten blocks which are not changed are left out.
+    #[async_backtrace::framed]
+    pub async fn changes_since(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<String>,
+        field_indices: Vec<usize>,
+    ) -> Result<Vec<DataBlock>> {
+        let base_segments = match base_location {
+            Some(location) => self.read_snapshot_at(location).await?.segments.clone(),
+            None => vec![],
+        };
+        let current_segments = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+
+        // The segments are immutable, only the ones not shared by both snapshots hold changes.
+        let base_set = base_segments.iter().collect::<HashSet<_>>();
+        let current_set = current_segments.iter().collect::<HashSet<_>>();
+        let removed_segments = base_segments
+            .iter()
+            .filter(|loc| !current_set.contains(loc))
+            .cloned()
+            .collect::<Vec<_>>();
+        let added_segments = current_segments
+            .iter()
+            .filter(|loc| !base_set.contains(loc))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed_blocks = self.read_block_metas(&removed_segments).await?;
+        let added_blocks = self.read_block_metas(&added_segments).await?;
+
+        // The compaction of segments moves the blocks to new segments without rewriting them.
+        let removed_locations = removed_blocks
+            .iter()
+            .map(|meta| meta.location.0.clone())
+            .collect::<HashSet<_>>();
+        let added_locations = added_blocks
+            .iter()
+            .map(|meta| meta.location.0.clone())
+            .collect::<HashSet<_>>();
+        let removed_blocks = removed_blocks
+            .into_iter()
+            .filter(|meta| !added_locations.contains(&meta.location.0))
+            .collect::<Vec<_>>();
+        let added_blocks = added_blocks
+            .into_iter()
+            .filter(|meta| !removed_locations.contains(&meta.location.0))
+            .collect::<Vec<_>>();
+        if removed_blocks.is_empty() && added_blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block_reader =
+            self.create_block_reader(Projection::Columns(field_indices), false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let mut removed = Vec::with_capacity(removed_blocks.len());
+        for meta in removed_blocks.iter() {
+            removed.push(
+                block_reader
+                    .read_by_meta(&settings, meta, &self.storage_format)
+                    .await?,
+            );
+        }
+        let mut added = Vec::with_capacity(added_blocks.len());
+        for meta in added_blocks.iter() {
+            added.push(
+                block_reader
+                    .read_by_meta(&settings, meta, &self.storage_format)
+                    .await?,
+            );
+        }
+
+        // A removed row and an added row with the same values cancel each other out.
+        let mut removed_rows: HashMap<Vec<Scalar>, Vec<(usize, u32)>> = HashMap::new();
+        for (i, block) in removed.iter().enumerate() {
+            for row in 0..block.num_rows() {
+                removed_rows
+                    .entry(row_values(block, row))
+                    .or_default()
+                    .push((i, row as u32));
+            }
+        }
+        let mut inserted_rows = vec![vec![]; added.len()];
+        for (i, block) in added.iter().enumerate() {
+            for row in 0..block.num_rows() {
+                let cancelled = removed_rows
+                    .get_mut(&row_values(block, row))
+                    .and_then(|rows| rows.pop())
+                    .is_some();
+                if !cancelled {
+                    inserted_rows[i].push(row as u32);
+                }
+            }
+        }
+        let mut deleted_rows = vec![vec![]; removed.len()];
+        for (i, row) in removed_rows.into_values().flatten() {
+            deleted_rows[i].push(row);
+        }
+
+        let mut blocks = vec![];
+        for ((block, meta), mut rows) in removed.iter().zip(&removed_blocks).zip(deleted_rows) {
+            if !rows.is_empty() {
+                rows.sort_unstable();
+                blocks.push(change_block(block, meta, &rows, CHANGE_ACTION_DELETE)?);
+            }
+        }
+        for ((block, meta), rows) in added.iter().zip(&added_blocks).zip(inserted_rows) {
+            if !rows.is_empty() {
+                blocks.push(change_block(block, meta, &rows, CHANGE_ACTION_INSERT)?);
+            }
+        }
+        Ok(blocks)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_snapshot_at(&self, location: String) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = TableMetaLocationGenerator::snapshot_version(&location);
+        reader
+            .read(&LoadParams {
+                location,
+                len_hint: None,
+                ver,
+                put_cache: false,
+            })
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_block_metas(&self, segments: &[Location]) -> Result<Vec<Arc<BlockMeta>>> {
+        let reader =
+            MetaReaders::segment_info_reader(self.get_operator(), self.table_info.schema());
+        let mut block_metas = vec![];
+        for (location, ver) in segments {
+            let segment_info = reader
+                .read(&LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: true,
+                })
+                .await?;
+            block_metas.extend(segment_info.block_metas()?);
+        }
+        Ok(block_metas)
+    }
+}
+
+fn row_values(block: &DataBlock, row: usize) -> Vec<Scalar> {
+    block
+        .columns()
+        .iter()
+        .map(|entry| entry.value.index(row).unwrap().to_owned())
+        .collect()
+}
+
+/// Take the changed rows of a block, and append the action and the row id columns.
+///
+/// The id of a row is the name of its block file followed by its position in the block.
+fn change_block(
+    block: &DataBlock,
+    meta: &BlockMeta,
+    rows: &[u32],
+    action: &str,
+) -> Result<DataBlock> {
+    let block_name = meta
+        .location
+        .0
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.split('.').next())
+        .unwrap_or_default();
+    let actions = vec![action.as_bytes().to_vec(); rows.len()];
+    let row_ids = rows
+        .iter()
+        .map(|row| format!("{block_name}#{row}").into_bytes())
+        .collect::<Vec<_>>();
+
+    let mut block = block.take(rows)?;
+    block.add_column(BlockEntry::new(
+        DataType::String,
+        Value::Column(StringType::from_data(actions)),
+    ));
+    block.add_column(BlockEntry::new(
+        DataType::String,
+        Value::Column(StringType::from_data(row_ids)),
+    ));
+    Ok(block)
+}
@@ -0,0 +1,142 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use storages_common_table_meta::table::parse_column_encoding_option;
+use storages_common_table_meta::table::parse_table_compression_option;
+use storages_common_table_meta::table::ColumnCompression;
+use storages_common_table_meta::table::ColumnEncoding;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_ENCODING;
+use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Check the compressions and encodings of columns set by the `compression` and
+    /// `encoding` options against the schema of the table.
+    pub fn check_column_codecs(
+        schema: &TableSchema,
+        options: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        let (compressions, encodings) = Self::parse_column_codecs(options)?;
+        if compressions.is_empty() && encodings.is_empty() {
+            return Ok(());
+        }
+
+        let storage_format = options
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .map(|s| FuseStorageFormat::from_str(s))
+            .transpose()?
+            .unwrap_or(FuseStorageFormat::Parquet);
+        let is_native = matches!(storage_format, FuseStorageFormat::Native);
+        if is_native && !compressions.is_empty() {
+            return Err(ErrorCode::TableOptionInvalid(
+                "Compressions of columns are not supported by the native storage format",
+            ));
+        }
+
+        let stored_field = |name: &str, option: &str| {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "Unknown column {name} in table option {option}"
+                ))
+            })?;
+            if field.computed_expr().is_some() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Column {name} in table option {option} must be a stored column"
+                )));
+            }
+            Ok(field)
+        };
+        for name in compressions.keys() {
+            stored_field(name, OPT_KEY_TABLE_COMPRESSION)?;
+        }
+        for (name, encoding) in encodings.iter() {
+            let field = stored_field(name, OPT_KEY_COLUMN_ENCODING)?;
+            let data_type = field.data_type().remove_nullable();
+            let format_supported = match encoding {
+                ColumnEncoding::Plain | ColumnEncoding::Delta => true,
+                ColumnEncoding::Dictionary => !is_native,
+                ColumnEncoding::DeltaOfDelta
+                | ColumnEncoding::BitPacking
+                | ColumnEncoding::FrameOfReference => is_native,
+            };
+            if !format_supported {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Encoding {encoding} of column {name} is not supported by the {} storage format",
+                    if is_native { "native" } else { "parquet" }
+                )));
+            }
+            let supported = match encoding {
+                ColumnEncoding::Plain => true,
+                ColumnEncoding::Delta
+                | ColumnEncoding::DeltaOfDelta
+                | ColumnEncoding::BitPacking
+                | ColumnEncoding::FrameOfReference => matches!(
+                    data_type,
+                    TableDataType::Number(
+                        NumberDataType::UInt8
+                            | NumberDataType::UInt16
+                            | NumberDataType::UInt32
+                            | NumberDataType::UInt64
+                            | NumberDataType::Int8
+                            | NumberDataType::Int16
+                            | NumberDataType::Int32
+                            | NumberDataType::Int64
+                    ) | TableDataType::Date
+                        | TableDataType::Timestamp
+                ),
+                ColumnEncoding::Dictionary => data_type == TableDataType::String,
+            };
+            if !supported {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Encoding {encoding} of column {name} is not supported for type {}",
+                    data_type.sql_name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the compressions and encodings of columns from the table options.
+    pub(crate) fn parse_column_codecs(
+        options: &BTreeMap<String, String>,
+    ) -> Result<(
+        HashMap<String, ColumnCompression>,
+        HashMap<String, ColumnEncoding>,
+    )> {
+        let compressions = match options.get(OPT_KEY_TABLE_COMPRESSION) {
+            Some(option) => parse_table_compression_option(option)?.1,
+            None => BTreeMap::new(),
+        };
+        let encodings = match options.get(OPT_KEY_COLUMN_ENCODING) {
+            Some(option) => parse_column_encoding_option(option)?,
+            None => BTreeMap::new(),
+        };
+        Ok((
+            compressions.into_iter().collect(),
+            encodings.into_iter().collect(),
+        ))
+    }
+}
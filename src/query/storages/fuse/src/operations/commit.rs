@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -34,6 +35,7 @@ use common_meta_types::MatchSeq;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
+use common_users::UserApiProvider;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
@@ -174,27 +176,116 @@ impl FuseTable {
         copied_files: &Option<UpsertTableCopiedFileReq>,
         operator: &Operator,
     ) -> Result<()> {
-        // 1. prepare table meta
-        let mut new_table_meta = table_info.meta.clone();
-        // 1.1 set new snapshot location
-        new_table_meta.options.insert(
-            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-            snapshot_location.clone(),
-        );
-        // remove legacy options
-        Self::remove_legacy_options(&mut new_table_meta.options);
+        if let Err(e) = Self::check_storage_quota(ctx, table_info, &snapshot).await {
+            let _ = operator.delete(&snapshot_location).await;
+            return Err(e);
+        }
 
-        // 1.2 setup table statistics
-        let stats = &snapshot.summary;
-        // update statistics
-        new_table_meta.statistics = TableStatistics {
-            number_of_rows: stats.row_count,
-            data_bytes: stats.uncompressed_byte_size,
-            compressed_data_bytes: stats.compressed_byte_size,
-            index_data_bytes: stats.index_size,
-            number_of_segments: Some(snapshot.segments.len() as u64),
-            number_of_blocks: Some(stats.block_count),
-        };
+        // two-phase commit, the snapshot takes effect on `COMMIT PREPARED`
+        if let Some(id) = ctx.get_settings().get_prepared_commit_id()? {
+            return Self::prepare_commit(ctx, table_info, &id, snapshot_location, copied_files)
+                .await;
+        }
+
+        // in a transaction, the new table meta takes effect on `COMMIT`, or once the
+        // statement finishes in a statement transaction
+        let txn_mgr = ctx.txn_mgr();
+        if txn_mgr.lock().is_buffering() {
+            let new_table_meta =
+                Self::build_new_table_meta(table_info, &snapshot, snapshot_location.clone());
+            txn_mgr.lock().update_table_meta(
+                table_info,
+                new_table_meta,
+                copied_files,
+                ctx.get_settings().get_deduplicate_label()?,
+                Some(snapshot_location.clone()),
+            );
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+
+        Self::do_update_table_meta(
+            ctx,
+            table_info,
+            location_generator,
+            snapshot,
+            snapshot_location,
+            copied_files,
+            operator,
+        )
+        .await
+    }
+
+    // Checks the storage quotas of the tenant before the table grows to the new snapshot,
+    // the size of a table is its compressed data plus the indexes.
+    #[async_backtrace::framed]
+    async fn check_storage_quota(
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let old_bytes = table_info.meta.statistics.compressed_data_bytes
+            + table_info.meta.statistics.index_data_bytes;
+        let new_bytes = snapshot.summary.compressed_byte_size + snapshot.summary.index_size;
+        if new_bytes <= old_bytes {
+            return Ok(());
+        }
+
+        let tenant = ctx.get_tenant();
+        let quota = UserApiProvider::instance()
+            .get_tenant_quota_api_client(&tenant)?
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data;
+
+        if quota.max_storage_bytes_per_table != 0 && new_bytes > quota.max_storage_bytes_per_table {
+            return Err(ErrorCode::TenantQuotaExceeded(format!(
+                "Max storage bytes per table quota exceeded {}, table {} would take {} bytes",
+                quota.max_storage_bytes_per_table, table_info.desc, new_bytes
+            )));
+        }
+
+        if quota.max_storage_bytes_per_database != 0 {
+            // the desc of a table is `'<database>'.'<table>'`
+            let db_name = match table_info.desc.strip_prefix('\'') {
+                Some(desc) => match desc.split_once("'.'") {
+                    Some((db_name, _)) => db_name.to_string(),
+                    None => return Ok(()),
+                },
+                None => return Ok(()),
+            };
+            let catalog = ctx.get_catalog(table_info.catalog())?;
+            let mut db_bytes = new_bytes;
+            for table in catalog.list_tables(&tenant, &db_name).await? {
+                if table.get_id() == table_info.ident.table_id {
+                    continue;
+                }
+                let statistics = &table.get_table_info().meta.statistics;
+                db_bytes += statistics.compressed_data_bytes + statistics.index_data_bytes;
+            }
+            if db_bytes > quota.max_storage_bytes_per_database {
+                return Err(ErrorCode::TenantQuotaExceeded(format!(
+                    "Max storage bytes per database quota exceeded {}, database {} would take {} bytes",
+                    quota.max_storage_bytes_per_database, db_name, db_bytes
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn do_update_table_meta(
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        location_generator: &TableMetaLocationGenerator,
+        snapshot: TableSnapshot,
+        snapshot_location: String,
+        copied_files: &Option<UpsertTableCopiedFileReq>,
+        operator: &Operator,
+    ) -> Result<()> {
+        // 1. prepare table meta
+        let new_table_meta =
+            Self::build_new_table_meta(table_info, &snapshot, snapshot_location.clone());
 
         // 2. prepare the request
         let catalog = ctx.get_catalog(table_info.catalog())?;
@@ -236,6 +327,32 @@ impl FuseTable {
         }
     }
 
+    fn build_new_table_meta(
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+        snapshot_location: String,
+    ) -> TableMeta {
+        let mut new_table_meta = table_info.meta.clone();
+        // set new snapshot location
+        new_table_meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), snapshot_location);
+        // remove legacy options
+        Self::remove_legacy_options(&mut new_table_meta.options);
+
+        // setup table statistics
+        let stats = &snapshot.summary;
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: stats.row_count,
+            data_bytes: stats.uncompressed_byte_size,
+            compressed_data_bytes: stats.compressed_byte_size,
+            index_data_bytes: stats.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(stats.block_count),
+        };
+        new_table_meta
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
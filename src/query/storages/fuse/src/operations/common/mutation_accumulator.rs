@@ -40,6 +40,7 @@ use crate::operations::common::CommitMeta;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::Replacement;
 use crate::operations::common::ReplacementLogEntry;
+use crate::operations::common::SegmentChanges;
 use crate::operations::mutation::BlockIndex;
 use crate::operations::mutation::SegmentIndex;
 use crate::statistics::reducers::deduct_statistics_mut;
@@ -172,6 +173,7 @@ impl MutationAccumulator {
         let start = Instant::now();
         let mut count = 0;
 
+        let mut changes = SegmentChanges::default();
         let segment_locations = self.base_segments.clone();
         let mut segments_editor =
             BTreeMap::<_, _>::from_iter(segment_locations.into_iter().enumerate());
@@ -181,14 +183,22 @@ impl MutationAccumulator {
         for chunk in segment_indices.chunks(chunk_size) {
             let results = self.partial_apply(chunk.to_vec()).await?;
             for result in results {
+                let origin_location = self.base_segments[result.index].clone();
+                merge_statistics_mut(&mut changes.removed_statistics, &result.origin_summary);
                 if let Some((location, summary)) = result.new_segment_info {
                     // replace the old segment location with the new one.
                     self.abort_operation.add_segment(location.clone());
-                    segments_editor.insert(result.index, (location.clone(), SegmentInfo::VERSION));
+                    let new_location = (location.clone(), SegmentInfo::VERSION);
+                    segments_editor.insert(result.index, new_location.clone());
                     merge_statistics_mut(&mut self.summary, &summary);
+                    merge_statistics_mut(&mut changes.added_statistics, &summary);
+                    changes
+                        .replaced_segments
+                        .push((origin_location, Some(new_location)));
                 } else {
                     // remove the old segment location.
                     segments_editor.remove(&result.index);
+                    changes.replaced_segments.push((origin_location, None));
                 }
 
                 if !recalc_stats {
@@ -211,8 +221,12 @@ impl MutationAccumulator {
             }
         }
 
-        for (_path, new_segment, _format_version) in &self.appended_segments {
+        for (path, new_segment, format_version) in &self.appended_segments {
             merge_statistics_mut(&mut self.summary, &new_segment.summary);
+            merge_statistics_mut(&mut changes.added_statistics, &new_segment.summary);
+            changes
+                .appended_segments
+                .push((path.clone(), *format_version));
         }
 
         let updated_segments = segments_editor.into_values();
@@ -230,6 +244,7 @@ impl MutationAccumulator {
             self.summary.clone(),
             self.abort_operation.clone(),
             false,
+            Some(changes),
         );
         Ok(meta)
     }
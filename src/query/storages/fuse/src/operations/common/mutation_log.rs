@@ -125,12 +125,29 @@ impl TryFrom<DataBlock> for MutationLogs {
     }
 }
 
+/// The changes made by a mutation to the segments of its base snapshot.
+///
+/// If the table has been modified concurrently, the changes are applied to the latest
+/// snapshot instead, as long as the segments changed by the mutation are still there.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct SegmentChanges {
+    /// The base segments changed by the mutation, with the new segments replacing them.
+    /// `None` if the segment is removed.
+    pub replaced_segments: Vec<(Location, Option<Location>)>,
+    pub appended_segments: Vec<Location>,
+    /// Statistics of the base segments changed by the mutation.
+    pub removed_statistics: Statistics,
+    /// Statistics of the segments written by the mutation.
+    pub added_statistics: Statistics,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CommitMeta {
     pub segments: Vec<Location>,
     pub summary: Statistics,
     pub abort_operation: AbortOperation,
     pub need_lock: bool,
+    pub changes: Option<SegmentChanges>,
 }
 
 impl CommitMeta {
@@ -139,12 +156,14 @@ impl CommitMeta {
         summary: Statistics,
         abort_operation: AbortOperation,
         need_lock: bool,
+        changes: Option<SegmentChanges>,
     ) -> Self {
         CommitMeta {
             segments,
             summary,
             abort_operation,
             need_lock,
+            changes,
         }
     }
 }
@@ -135,6 +135,7 @@ where F: SnapshotGenerator + Send + 'static
 
         self.snapshot_gen.set_merged_segments(meta.segments.clone());
         self.snapshot_gen.set_merged_summary(meta.summary.clone());
+        self.snapshot_gen.set_segment_changes(meta.changes.clone());
         self.abort_operation = meta.abort_operation.clone();
 
         self.backoff = FuseTable::set_backoff(self.max_retry_elapsed);
@@ -49,6 +49,7 @@ use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::Processor;
+use crate::statistics::reduce_cluster_depth;
 use crate::statistics::ClusterStatsGenerator;
 use crate::statistics::StatisticsAccumulator;
 use crate::FuseTable;
@@ -211,6 +212,8 @@ impl Processor for AppendTransform {
             State::GenerateSegment => {
                 let acc = std::mem::take(&mut self.accumulator);
                 let col_stats = acc.summary();
+                let (cluster_depth_sum, clustered_block_count) =
+                    reduce_cluster_depth(&acc.blocks_metas);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, Statistics {
                     row_count: acc.summary_row_count,
@@ -220,6 +223,8 @@ impl Processor for AppendTransform {
                     compressed_byte_size: acc.file_size,
                     index_size: acc.index_size,
                     col_stats,
+                    cluster_depth_sum,
+                    clustered_block_count,
                 });
 
                 self.state = State::SerializedSegment {
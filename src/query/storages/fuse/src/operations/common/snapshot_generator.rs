@@ -33,6 +33,7 @@ use crate::metrics::metrics_inc_commit_mutation_resolvable_conflict;
 use crate::metrics::metrics_inc_commit_mutation_unresolvable_conflict;
 use crate::operations::commit::Conflict;
 use crate::operations::commit::MutatorConflictDetector;
+use crate::operations::common::SegmentChanges;
 use crate::statistics::merge_statistics;
 use crate::statistics::reducers::deduct_statistics;
 use crate::statistics::reducers::merge_statistics_mut;
@@ -43,6 +44,8 @@ pub trait SnapshotGenerator {
 
     fn set_merged_summary(&mut self, summary: Statistics);
 
+    fn set_segment_changes(&mut self, _changes: Option<SegmentChanges>) {}
+
     async fn fill_default_values(
         &mut self,
         _schema: TableSchema,
@@ -64,6 +67,7 @@ pub struct MutationGenerator {
     base_snapshot: Arc<TableSnapshot>,
     merged_segments: Vec<Location>,
     merged_statistics: Statistics,
+    changes: Option<SegmentChanges>,
 }
 
 impl MutationGenerator {
@@ -72,7 +76,38 @@ impl MutationGenerator {
             base_snapshot,
             merged_segments: vec![],
             merged_statistics: Statistics::default(),
+            changes: None,
+        }
+    }
+
+    // Applies the segment changes of the mutation to the latest snapshot, returns `None`
+    // if any of the changed segments has been changed by others as well.
+    fn rebase(&self, latest: &TableSnapshot) -> Option<(Vec<Location>, Statistics)> {
+        let changes = self.changes.as_ref()?;
+        let replaced = changes
+            .replaced_segments
+            .iter()
+            .map(|(origin, new)| (origin, new))
+            .collect::<HashMap<_, _>>();
+
+        let mut num_replaced = 0;
+        let mut segments = changes.appended_segments.clone();
+        for segment in latest.segments.iter() {
+            match replaced.get(segment) {
+                Some(new) => {
+                    num_replaced += 1;
+                    segments.extend(new.iter().cloned());
+                }
+                None => segments.push(segment.clone()),
+            }
         }
+        if num_replaced != replaced.len() {
+            return None;
+        }
+
+        let mut summary = deduct_statistics(&latest.summary, &changes.removed_statistics);
+        merge_statistics_mut(&mut summary, &changes.added_statistics);
+        Some((segments, summary))
     }
 }
 
@@ -85,6 +120,10 @@ impl SnapshotGenerator for MutationGenerator {
         self.merged_statistics = summary;
     }
 
+    fn set_segment_changes(&mut self, changes: Option<SegmentChanges>) {
+        self.changes = changes;
+    }
+
     fn generate_new_snapshot(
         &self,
         schema: TableSchema,
@@ -98,12 +137,29 @@ impl SnapshotGenerator for MutationGenerator {
             self.base_snapshot.as_ref(),
             previous.as_ref(),
         ) {
-            Conflict::Unresolvable => {
-                metrics_inc_commit_mutation_unresolvable_conflict();
-                Err(ErrorCode::StorageOther(
-                    "mutation conflicts, concurrent mutation detected while committing segment compaction operation",
-                ))
-            }
+            Conflict::Unresolvable => match self.rebase(previous.as_ref()) {
+                // the segments changed concurrently are not changed by the mutation.
+                Some((new_segments, new_summary)) => {
+                    tracing::info!("resolvable conflicts detected, rebased on the latest snapshot");
+                    metrics_inc_commit_mutation_resolvable_conflict();
+                    Ok(TableSnapshot::new(
+                        Uuid::new_v4(),
+                        &previous.timestamp,
+                        Some((previous.snapshot_id, previous.format_version)),
+                        schema,
+                        new_summary,
+                        new_segments,
+                        cluster_key_meta,
+                        previous.table_statistics_location.clone(),
+                    ))
+                }
+                None => {
+                    metrics_inc_commit_mutation_unresolvable_conflict();
+                    Err(ErrorCode::StorageOther(
+                        "mutation conflicts, concurrent mutation detected while committing segment compaction operation",
+                    ))
+                }
+            },
             Conflict::ResolvableAppend(range_of_newly_append) => {
                 tracing::info!("resolvable conflicts detected");
                 metrics_inc_commit_mutation_resolvable_conflict();
@@ -0,0 +1,190 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_DICTIONARY_COLUMNS;
+
+/// The most distinct values kept in the dictionary of a column. A column having more
+/// values is not low-cardinality, and its dictionary is not maintained any more.
+pub const DICTIONARY_MAX_VALUES: usize = 65536;
+
+/// The distinct values of a low-cardinality string column of the table.
+///
+/// The dictionary of a column only grows, the values are in the order they are added.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ColumnDictionary {
+    /// The column has more than [DICTIONARY_MAX_VALUES] distinct values.
+    pub overflow: bool,
+    pub values: Vec<Vec<u8>>,
+}
+
+impl FuseTable {
+    /// Parse the value of the `dictionary_columns` option, a comma separated list of the
+    /// string columns to keep the dictionaries of.
+    pub fn parse_dictionary_columns(
+        schema: &TableSchema,
+        option: &str,
+    ) -> Result<Vec<(ColumnId, String)>> {
+        let mut columns = vec![];
+        for name in option
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "Unknown column {name} in table option {FUSE_OPT_KEY_DICTIONARY_COLUMNS}"
+                ))
+            })?;
+            if field.data_type().remove_nullable() != TableDataType::String
+                || field.computed_expr().is_some()
+            {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Column {name} in table option {FUSE_OPT_KEY_DICTIONARY_COLUMNS} must be a stored STRING column"
+                )));
+            }
+            columns.push((field.column_id(), field.name().clone()));
+        }
+        Ok(columns)
+    }
+
+    /// The columns declared by the `dictionary_columns` option.
+    pub fn dictionary_columns(&self) -> Vec<(ColumnId, String)> {
+        match self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_DICTIONARY_COLUMNS)
+        {
+            Some(option) => Self::parse_dictionary_columns(&self.table_info.schema(), option)
+                .unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    /// Read the dictionary of a column, returns [None] if it is not built yet.
+    #[async_backtrace::framed]
+    pub async fn read_column_dictionary(
+        &self,
+        column_id: ColumnId,
+    ) -> Result<Option<ColumnDictionary>> {
+        let location = self
+            .meta_location_generator
+            .gen_dictionary_location(column_id);
+        let data = match self.operator.read(&location).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Some(serde_json::from_slice(&data)?))
+    }
+
+    /// Add the values of the dictionary columns in the blocks of `segments`
+    /// to the dictionaries of the columns.
+    #[async_backtrace::framed]
+    pub async fn update_column_dictionaries(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segments: &[Location],
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let mut columns = vec![];
+        for (column_id, name) in self.dictionary_columns() {
+            let dictionary = self
+                .read_column_dictionary(column_id)
+                .await?
+                .unwrap_or_default();
+            if !dictionary.overflow {
+                columns.push((column_id, schema.index_of(&name)?, dictionary));
+            }
+        }
+        if columns.is_empty() || segments.is_empty() {
+            return Ok(());
+        }
+
+        let projection = Projection::Columns(columns.iter().map(|(_, i, _)| *i).collect());
+        let block_reader = self.create_block_reader(projection, false, ctx.clone())?;
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let segment_reader = MetaReaders::segment_info_reader(self.get_operator(), schema);
+
+        let mut known = columns
+            .iter()
+            .map(|(_, _, dictionary)| dictionary.values.iter().cloned().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+        let mut added = vec![false; columns.len()];
+        for (location, ver) in segments {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: true,
+                })
+                .await?;
+            for block_meta in segment_info.block_metas()? {
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &self.storage_format)
+                    .await?;
+                for (i, (_, _, dictionary)) in columns.iter_mut().enumerate() {
+                    if dictionary.overflow {
+                        continue;
+                    }
+                    let entry = block.get_by_offset(i);
+                    for row in 0..block.num_rows() {
+                        if let Some(ScalarRef::String(value)) = entry.value.index(row) {
+                            if !known[i].contains(value) {
+                                known[i].insert(value.to_vec());
+                                dictionary.values.push(value.to_vec());
+                                added[i] = true;
+                            }
+                        }
+                    }
+                    if dictionary.values.len() > DICTIONARY_MAX_VALUES {
+                        dictionary.overflow = true;
+                        dictionary.values.clear();
+                    }
+                }
+            }
+        }
+
+        for ((column_id, _, dictionary), added) in columns.iter().zip(added) {
+            if added {
+                let location = self
+                    .meta_location_generator
+                    .gen_dictionary_location(*column_id);
+                let data = serde_json::to_vec(dictionary)?;
+                write_data(data, &self.operator, &location).await?;
+            }
+        }
+        Ok(())
+    }
+}
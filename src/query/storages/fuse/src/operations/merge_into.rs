@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Semaphore;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::create_dummy_item;
+use common_pipeline_transforms::processors::transforms::TransformSortPartial;
+use common_sql::evaluator::CompoundBlockOperator;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::BlockBuilder;
+use crate::io::ReadSettings;
+use crate::operations::common::AppendTransform;
+use crate::operations::mutation::RowIdDeletionAggregator;
+use crate::operations::replace_into::BroadcastProcessor;
+use crate::pipelines::Pipeline;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Applies the outcome of MERGE INTO to the table in a single commit.
+    ///
+    /// The last pipe of `pipeline` is expected to have two outputs:
+    /// (0) -> the updated and inserted rows, in the schema of the table
+    /// (1) -> a single non-nullable UInt64 column of the `_row_id` of the matched rows
+    ///
+    /// ┌──────────────────┐       ┌───────────────────────┐
+    /// │ AppendTransform  ├──────►│    DummyTransform     ├──────┐
+    /// └──────────────────┘       └───────────────────────┘      │
+    /// ┌──────────────────┐       ┌───────────────────────┐      │
+    /// │                  ├──────►│RowIdDeletionAggregator├──────┤
+    /// │BroadcastProcessor│       └───────────────────────┘      │
+    /// │                  │       ┌───────────────────────┐      │
+    /// │                  ├──────►│RowIdDeletionAggregator├──────┤
+    /// └──────────────────┘       └───────────────────────┘      │
+    ///                                                           │
+    /// ┌─────────────────────────────────────────────────────────┘
+    /// │   ┌──────────────────┐     ┌───────────────────────┐     ┌──────────┐
+    /// └──►│ResizeProcessor(1)├────►│TableMutationAggregator├────►│CommitSink│
+    ///     └──────────────────┘     └───────────────────────┘     └──────────┘
+    ///
+    /// If the table has no segments, nothing can be matched, and the broadcast processor
+    /// is replaced by a dummy transform.
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if pipeline.output_len() != 2 {
+            return Err(ErrorCode::Internal(format!(
+                "unexpected, MERGE INTO expects 2 inputs, got {}",
+                pipeline.output_len()
+            )));
+        }
+
+        let schema = self.table_info.schema();
+        let base_snapshot = self.read_table_snapshot().await?.unwrap_or_else(|| {
+            Arc::new(TableSnapshot::new_empty_snapshot(schema.as_ref().clone()))
+        });
+
+        // 1. the appended blocks must carry the extra cluster key columns, and be sorted
+        // by the cluster keys, just like the blocks written by INSERT.
+        let thresholds = self.get_block_thresholds();
+        let cluster_stats_gen = self.get_cluster_stats_gen(ctx.clone(), 0, thresholds)?;
+        Self::add_cluster_transforms(&cluster_stats_gen, schema.fields().len(), pipeline)?;
+
+        // 2. connect to append transform and broadcast processor
+        let max_threads = ctx.get_settings().get_max_threads()?;
+        let segment_partition_num =
+            std::cmp::min(base_snapshot.segments.len(), max_threads as usize);
+
+        let append_transform = AppendTransform::new(
+            ctx.clone(),
+            InputPort::create(),
+            OutputPort::create(),
+            self,
+            cluster_stats_gen,
+            thresholds,
+        );
+        let block_builder = append_transform.get_block_builder();
+
+        if segment_partition_num == 0 {
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                append_transform.into_pipe_item(),
+                create_dummy_item(),
+            ]));
+        } else {
+            let broadcast_processor = BroadcastProcessor::new(segment_partition_num);
+            pipeline.add_pipe(Pipe::create(2, segment_partition_num + 1, vec![
+                append_transform.into_pipe_item(),
+                broadcast_processor.into_pipe_item(),
+            ]));
+
+            // 3. connect with RowIdDeletionAggregators
+            let item_size = segment_partition_num + 1;
+            let mut pipe_items = Vec::with_capacity(item_size);
+            pipe_items.push(create_dummy_item());
+            let mut deletion_aggregators = self.row_id_deletion_aggregators(
+                ctx.clone(),
+                segment_partition_num,
+                block_builder,
+                &base_snapshot,
+            )?;
+            pipe_items.append(&mut deletion_aggregators);
+            pipeline.add_pipe(Pipe::create(item_size, item_size, pipe_items));
+        }
+
+        // 4. connect with mutation pipes, the TableMutationAggregator, then CommitSink
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot)
+            .await
+    }
+
+    /// Adds the cluster key operators and the partial sort to the first output of `pipeline`,
+    /// the second output is passed through.
+    fn add_cluster_transforms(
+        cluster_stats_gen: &ClusterStatsGenerator,
+        num_input_columns: usize,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let operators = cluster_stats_gen.operators.clone();
+        if !operators.is_empty() {
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor = CompoundBlockOperator::create(
+                input.clone(),
+                output.clone(),
+                num_input_columns,
+                cluster_stats_gen.func_ctx.clone(),
+                operators,
+            );
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                PipeItem::create(ProcessorPtr::create(processor), vec![input], vec![output]),
+                create_dummy_item(),
+            ]));
+        }
+
+        let cluster_keys = &cluster_stats_gen.cluster_key_index;
+        if !cluster_keys.is_empty() {
+            let sort_descs = cluster_keys
+                .iter()
+                .map(|index| SortColumnDescription {
+                    offset: *index,
+                    asc: true,
+                    nulls_first: false,
+                    is_nullable: false,
+                })
+                .collect::<Vec<_>>();
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor =
+                TransformSortPartial::try_create(input.clone(), output.clone(), None, sort_descs)?;
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                PipeItem::create(ProcessorPtr::create(processor), vec![input], vec![output]),
+                create_dummy_item(),
+            ]));
+        }
+        Ok(())
+    }
+
+    fn row_id_deletion_aggregators(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        num_partition: usize,
+        block_builder: BlockBuilder,
+        table_snapshot: &TableSnapshot,
+    ) -> Result<Vec<PipeItem>> {
+        let chunks = Self::partition_segments(&table_snapshot.segments, num_partition);
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let max_io_request = ctx.get_settings().get_max_storage_io_requests()?;
+        let io_request_semaphore = Arc::new(Semaphore::new(max_io_request as usize));
+        let mut items = Vec::with_capacity(num_partition);
+        for chunk_of_segment_locations in chunks {
+            let aggregator = RowIdDeletionAggregator::try_create(
+                ctx.clone(),
+                chunk_of_segment_locations,
+                self.operator.clone(),
+                self.table_info.schema(),
+                self.get_write_settings(),
+                read_settings.clone(),
+                block_builder.clone(),
+                io_request_semaphore.clone(),
+            )?;
+            items.push(aggregator.into_pipe_item());
+        }
+        Ok(items)
+    }
+}
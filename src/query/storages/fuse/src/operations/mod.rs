@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index;
 mod analyze;
 mod append;
+mod changes;
+mod column_codecs;
 mod commit;
 mod common;
 mod compact;
 mod delete;
+mod dictionary;
 mod gc;
+mod merge_into;
 mod mutation;
 mod navigate;
+mod prepared_commit;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -29,17 +35,31 @@ mod replace;
 mod replace_into;
 mod revert;
 mod truncate;
+mod txn_commit;
 mod update;
 pub mod util;
 
+pub use agg_index::build_agg_index_block;
+pub use agg_index::eval_agg_index_selection;
+pub use agg_index::read_agg_index_block;
+pub use agg_index::serialize_agg_index_block;
+pub use analyze::AnalyzedColumns;
+pub use changes::CHANGE_ACTION_DELETE;
+pub use changes::CHANGE_ACTION_INSERT;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
+pub use common::MutationGenerator;
+pub use common::SegmentChanges;
+pub use common::SnapshotGenerator;
 pub use compact::CompactOptions;
+pub use dictionary::ColumnDictionary;
+pub use dictionary::DICTIONARY_MAX_VALUES;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
+pub use prepared_commit::PreparedCommit;
 pub use read::build_row_fetcher_pipeline;
 pub use util::column_parquet_metas;
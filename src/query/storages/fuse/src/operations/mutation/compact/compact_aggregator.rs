@@ -171,6 +171,7 @@ impl AsyncAccumulatingTransform for CompactAggregator {
             std::mem::take(&mut self.merged_statistics),
             std::mem::take(&mut self.abort_operation),
             true,
+            None,
         );
         Ok(Some(DataBlock::empty_with_meta(Box::new(meta))))
     }
@@ -30,6 +30,7 @@ use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
 use crate::operations::CompactOptions;
+use crate::statistics::reduce_cluster_depth;
 use crate::statistics::reducers::merge_statistics_mut;
 use crate::FuseTable;
 use crate::TableContext;
@@ -42,6 +43,10 @@ pub struct SegmentCompactionState {
     pub new_segment_paths: Vec<String>,
     // number of fragmented segments compacted
     pub num_fragments_compacted: usize,
+    // (depth_sum, block_count) of the cluster depth of the compacted fragments, and of the
+    // segments they are compacted into, the depth is counted among the blocks of the new segments.
+    pub compacted_cluster_depth: (u64, u64),
+    pub new_cluster_depth: (u64, u64),
 }
 
 pub struct SegmentCompactMutator {
@@ -129,8 +134,18 @@ impl SegmentCompactMutator {
             ..Default::default()
         };
 
-        // summary of snapshot is unchanged for compact segments.
-        let statistics = self.compact_params.base_snapshot.summary.clone();
+        // summary of snapshot is unchanged for compact segments, except the cluster depth.
+        let mut statistics = self.compact_params.base_snapshot.summary.clone();
+        let (compacted_depth_sum, compacted_block_count) = self.compaction.compacted_cluster_depth;
+        let (new_depth_sum, new_block_count) = self.compaction.new_cluster_depth;
+        statistics.cluster_depth_sum = statistics
+            .cluster_depth_sum
+            .saturating_sub(compacted_depth_sum)
+            + new_depth_sum;
+        statistics.clustered_block_count = statistics
+            .clustered_block_count
+            .saturating_sub(compacted_block_count)
+            + new_block_count;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let handler = TableLockHandlerWrapper::instance(self.ctx.clone());
         let mut heartbeat = handler
@@ -324,6 +339,13 @@ impl<'a> SegmentCompactor<'a> {
             merge_statistics_mut(&mut new_statistics, &segment.summary);
             blocks.append(&mut segment.blocks.clone());
         }
+        self.compacted_state.compacted_cluster_depth.0 += new_statistics.cluster_depth_sum;
+        self.compacted_state.compacted_cluster_depth.1 += new_statistics.clustered_block_count;
+        let (cluster_depth_sum, clustered_block_count) = reduce_cluster_depth(&blocks);
+        new_statistics.cluster_depth_sum = cluster_depth_sum;
+        new_statistics.clustered_block_count = clustered_block_count;
+        self.compacted_state.new_cluster_depth.0 += cluster_depth_sum;
+        self.compacted_state.new_cluster_depth.1 += clustered_block_count;
 
         // 2.2 write down new segment
         let new_segment = SegmentInfo::new(blocks, new_statistics);
@@ -17,6 +17,7 @@ mod mutation_meta;
 mod mutation_part;
 mod mutation_source;
 mod recluster_mutator;
+mod row_id_deletion_aggregator;
 mod transform_serialize_data;
 
 pub use compact::BlockCompactMutator;
@@ -31,6 +32,7 @@ pub use mutation_part::MutationPartInfo;
 pub use mutation_source::MutationAction;
 pub use mutation_source::MutationSource;
 pub use recluster_mutator::ReclusterMutator;
+pub use row_id_deletion_aggregator::RowIdDeletionAggregator;
 pub use transform_serialize_data::SerializeDataTransform;
 
 pub type SegmentIndex = usize;
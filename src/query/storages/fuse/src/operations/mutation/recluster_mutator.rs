@@ -14,8 +14,8 @@
 
 use std::cmp;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -28,24 +28,30 @@ use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::common::Replacement;
 use crate::operations::common::ReplacementLogEntry;
-
-static MAX_BLOCK_COUNT: usize = 50;
+use crate::MAX_RECLUSTER_BLOCK_COUNT;
 
 #[derive(Clone)]
 pub struct ReclusterMutator {
     selected_blocks: Vec<Arc<BlockMeta>>,
     level: i32,
     threshold: f64,
+    max_block_count: usize,
     mutation_logs: MutationLogs,
     block_thresholds: BlockThresholds,
 }
 
 impl ReclusterMutator {
-    pub fn try_create(threshold: f64, block_thresholds: BlockThresholds) -> Result<Self> {
+    /// At most `max_block_count` blocks are selected, capped by [`MAX_RECLUSTER_BLOCK_COUNT`].
+    pub fn try_create(
+        threshold: f64,
+        block_thresholds: BlockThresholds,
+        max_block_count: usize,
+    ) -> Result<Self> {
         Ok(Self {
             selected_blocks: Vec::new(),
             level: 0,
             threshold,
+            max_block_count: max_block_count.min(MAX_RECLUSTER_BLOCK_COUNT),
             block_thresholds,
             mutation_logs: Default::default(),
         })
@@ -63,11 +69,40 @@ impl ReclusterMutator {
         self.mutation_logs.clone()
     }
 
+    fn select(&mut self, level: i32, blocks: Vec<(BlockMetaIndex, Arc<BlockMeta>)>) {
+        self.selected_blocks = blocks
+            .into_iter()
+            .take(self.max_block_count)
+            .map(|(block_idx, block_meta)| {
+                let entry = ReplacementLogEntry {
+                    index: block_idx,
+                    op: Replacement::Deleted,
+                };
+                self.mutation_logs
+                    .entries
+                    .push(MutationLogEntry::Replacement(entry));
+                block_meta
+            })
+            .collect::<Vec<_>>();
+        self.level = level;
+    }
+
+    /// Selects the blocks to recluster.
+    ///
+    /// The small blocks of a level are merged first. Otherwise the level with the largest average
+    /// depth above the threshold is reclustered, its blocks overlapping at the deepest points
+    /// are selected.
     #[async_backtrace::framed]
     pub async fn target_select(
         &mut self,
         blocks_map: BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>>,
     ) -> Result<bool> {
+        if self.max_block_count == 0 {
+            return Ok(false);
+        }
+
+        // (average depth, level, the selected blocks)
+        let mut worst: Option<(f64, i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>)> = None;
         for (level, block_metas) in blocks_map.into_iter() {
             if block_metas.len() <= 1 {
                 continue;
@@ -96,21 +131,7 @@ impl ReclusterMutator {
                 .block_thresholds
                 .check_for_recluster(total_rows as usize, total_bytes as usize)
             {
-                self.selected_blocks = block_metas
-                    .into_iter()
-                    .map(|(block_idx, block_meta)| {
-                        let entry = ReplacementLogEntry {
-                            index: block_idx,
-                            op: Replacement::Deleted,
-                        };
-                        self.mutation_logs
-                            .entries
-                            .push(MutationLogEntry::Replacement(entry));
-                        block_meta
-                    })
-                    .collect::<Vec<_>>();
-                self.level = level;
-
+                self.select(level, block_metas);
                 return Ok(true);
             }
 
@@ -157,12 +178,16 @@ impl ReclusterMutator {
                 average_depth,
                 level
             );
-            if average_depth <= self.threshold {
+            if average_depth <= self.threshold
+                || worst
+                    .as_ref()
+                    .map_or(false, |(worst_depth, _, _)| average_depth <= *worst_depth)
+            {
                 continue;
             }
 
             // find the max point, gather the blocks.
-            let mut selected_idx = HashSet::new();
+            let mut selected_idx = BTreeSet::new();
             let mut find = false;
             for overlap in point_overlaps {
                 if overlap.len() == max_depth {
@@ -175,25 +200,19 @@ impl ReclusterMutator {
                 }
             }
 
-            self.selected_blocks = selected_idx
-                .iter()
-                .take(MAX_BLOCK_COUNT)
-                .map(|idx| {
-                    let (block_idx, block_meta) = block_metas[*idx].clone();
-                    let entry = ReplacementLogEntry {
-                        index: block_idx,
-                        op: Replacement::Deleted,
-                    };
-                    self.mutation_logs
-                        .entries
-                        .push(MutationLogEntry::Replacement(entry));
-                    block_meta
-                })
+            let selected = selected_idx
+                .into_iter()
+                .map(|idx| block_metas[idx].clone())
                 .collect::<Vec<_>>();
-            self.level = level;
-            return Ok(true);
+            worst = Some((average_depth, level, selected));
         }
 
-        Ok(false)
+        match worst {
+            Some((_, level, selected)) => {
+                self.select(level, selected);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
     }
 }
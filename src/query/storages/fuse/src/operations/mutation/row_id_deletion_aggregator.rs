@@ -0,0 +1,394 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::ProgressValues;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::plan::block_idx_in_segment;
+use common_catalog::plan::split_prefix;
+use common_catalog::plan::split_row_id;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::TableSchema;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::transform_accumulating_async::AsyncAccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
+use opendal::Operator;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use tracing::info;
+
+use crate::io::write_data;
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::CompactSegmentInfoReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::WriteSettings;
+use crate::operations::common::BlockMetaIndex;
+use crate::operations::common::MutationLogEntry;
+use crate::operations::common::MutationLogs;
+use crate::operations::common::Replacement;
+use crate::operations::common::ReplacementLogEntry;
+use crate::operations::mutation::BlockIndex;
+use crate::operations::mutation::SegmentIndex;
+
+struct DeletionContext {
+    segment_locations: HashMap<SegmentIndex, Location>,
+    block_reader: Arc<BlockReader>,
+    data_accessor: Operator,
+    write_settings: WriteSettings,
+    read_settings: ReadSettings,
+    segment_reader: CompactSegmentInfoReader,
+    block_builder: BlockBuilder,
+    io_request_semaphore: Arc<Semaphore>,
+}
+
+/// Deletes the rows of the segments belonging to this aggregator by their `_row_id`.
+///
+/// Takes blocks of a single non-nullable UInt64 column of `_row_id` in, accumulates the
+/// ones pointing to its segments, and rewrites the blocks in the `final` stage.
+/// Outputs [MutationLogs] logs(to be committed).
+pub struct RowIdDeletionAggregator {
+    /// The offsets of the deleted rows, indexed by the block prefix of `_row_id`.
+    deletions: HashMap<u64, HashSet<u64>>,
+    deletion_ctx: Arc<DeletionContext>,
+}
+
+impl RowIdDeletionAggregator {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        segment_locations: Vec<(SegmentIndex, Location)>,
+        data_accessor: Operator,
+        table_schema: Arc<TableSchema>,
+        write_settings: WriteSettings,
+        read_settings: ReadSettings,
+        block_builder: BlockBuilder,
+        io_request_semaphore: Arc<Semaphore>,
+    ) -> Result<Self> {
+        let segment_reader =
+            MetaReaders::segment_info_reader(data_accessor.clone(), table_schema.clone());
+        let indices = (0..table_schema.fields().len()).collect::<Vec<usize>>();
+        let projection = Projection::Columns(indices);
+        let block_reader = BlockReader::create(
+            data_accessor.clone(),
+            table_schema,
+            projection,
+            ctx.clone(),
+            false,
+        )?;
+
+        Ok(Self {
+            deletions: HashMap::new(),
+            deletion_ctx: Arc::new(DeletionContext {
+                segment_locations: HashMap::from_iter(segment_locations.into_iter()),
+                block_reader,
+                data_accessor,
+                write_settings,
+                read_settings,
+                segment_reader,
+                block_builder,
+                io_request_semaphore,
+            }),
+        })
+    }
+
+    pub fn into_pipe_item(self) -> PipeItem {
+        let input = InputPort::create();
+        let output = OutputPort::create();
+        let processor_ptr =
+            AsyncAccumulatingTransformer::create(input.clone(), output.clone(), self);
+        PipeItem::create(ProcessorPtr::create(processor_ptr), vec![input], vec![
+            output,
+        ])
+    }
+
+    fn accumulate(&mut self, data: DataBlock) -> Result<()> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+        let column = data
+            .get_by_offset(0)
+            .value
+            .convert_to_full_column(&DataType::Number(NumberDataType::UInt64), num_rows);
+        let row_ids = UInt64Type::try_downcast_column(&column).ok_or_else(|| {
+            ErrorCode::Internal("unexpected, `_row_id` to be deleted is not a UInt64 column")
+        })?;
+
+        for row_id in row_ids.iter() {
+            let (prefix, offset) = split_row_id(*row_id);
+            let (segment_idx, _) = split_prefix(prefix);
+            if !self
+                .deletion_ctx
+                .segment_locations
+                .contains_key(&(segment_idx as usize))
+            {
+                continue;
+            }
+            if !self.deletions.entry(prefix).or_default().insert(offset) {
+                return Err(ErrorCode::BadArguments(
+                    "MERGE INTO failed: multiple source rows matched the same row of the target table",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&mut self) -> Result<Option<MutationLogs>> {
+        let deletion_ctx = &self.deletion_ctx;
+
+        let mut deletions_by_segment: HashMap<SegmentIndex, Vec<(u64, HashSet<u64>)>> =
+            HashMap::new();
+        for (prefix, offsets) in self.deletions.drain() {
+            let (segment_idx, block_id) = split_prefix(prefix);
+            deletions_by_segment
+                .entry(segment_idx as usize)
+                .or_default()
+                .push((block_id, offsets));
+        }
+
+        let io_runtime = GlobalIORuntime::instance();
+        let mut mutation_log_handlers = Vec::new();
+        for (segment_idx, block_deletions) in deletions_by_segment {
+            let (path, ver) = deletion_ctx
+                .segment_locations
+                .get(&segment_idx)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "unexpected, segment (idx {}) not found, during applying mutation log",
+                        segment_idx
+                    ))
+                })?;
+
+            let load_param = LoadParams {
+                location: path.clone(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: true,
+            };
+            let compact_segment_info = deletion_ctx.segment_reader.read(&load_param).await?;
+            let segment_info: SegmentInfo = compact_segment_info.as_ref().try_into()?;
+
+            for (block_id, offsets) in block_deletions {
+                let block_idx = block_idx_in_segment(segment_info.blocks.len(), block_id as usize);
+                let block_meta = segment_info.blocks[block_idx].clone();
+                let permit = deletion_ctx.acquire_task_permit().await?;
+                let deletion_ctx = deletion_ctx.clone();
+                let handle = io_runtime.spawn(async_backtrace::location!().frame({
+                    async move {
+                        let mutation_log_entry = deletion_ctx
+                            .apply_deletion_to_data_block(
+                                segment_idx,
+                                block_idx,
+                                &block_meta,
+                                &offsets,
+                            )
+                            .await?;
+                        drop(permit);
+                        Ok::<_, ErrorCode>(mutation_log_entry)
+                    }
+                }));
+                mutation_log_handlers.push(handle)
+            }
+        }
+
+        let log_entries = futures::future::try_join_all(mutation_log_handlers)
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal("unexpected, failed to join apply-deletion tasks.")
+                    .add_message_back(e.to_string())
+            })?;
+
+        let mut mutation_logs = Vec::new();
+        for maybe_log_entry in log_entries {
+            if let Some(segment_mutation_log) = maybe_log_entry? {
+                mutation_logs.push(MutationLogEntry::Replacement(segment_mutation_log));
+            }
+        }
+
+        Ok(Some(MutationLogs {
+            entries: mutation_logs,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for RowIdDeletionAggregator {
+    const NAME: &'static str = "RowIdDeletionAggregator";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        self.accumulate(data)?;
+        // no partial output
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let mutation_logs = self.apply().await?;
+        Ok(mutation_logs.map(|logs| logs.into()))
+    }
+}
+
+impl DeletionContext {
+    #[async_backtrace::framed]
+    async fn apply_deletion_to_data_block(
+        &self,
+        segment_index: SegmentIndex,
+        block_index: BlockIndex,
+        block_meta: &BlockMeta,
+        deleted_offsets: &HashSet<u64>,
+    ) -> Result<Option<ReplacementLogEntry>> {
+        info!(
+            "apply delete to segment idx {}, block idx {}",
+            segment_index, block_index
+        );
+        let num_rows = block_meta.row_count as usize;
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            bitmap.push(!deleted_offsets.contains(&(row as u64)));
+        }
+        let delete_nums = bitmap.unset_bits();
+        if delete_nums == 0 {
+            return Ok(None);
+        }
+
+        let progress_values = ProgressValues {
+            rows: delete_nums,
+            // ignore bytes.
+            bytes: 0,
+        };
+        self.block_builder
+            .ctx
+            .get_write_progress()
+            .incr(&progress_values);
+
+        if delete_nums == num_rows {
+            info!("whole block deletion");
+            return Ok(Some(ReplacementLogEntry {
+                index: BlockMetaIndex {
+                    segment_idx: segment_index,
+                    block_idx: block_index,
+                },
+                op: Replacement::Deleted,
+            }));
+        }
+
+        let reader = &self.block_reader;
+        let merged_io_read_result = reader
+            .read_columns_data_by_merge_io(
+                &self.read_settings,
+                &block_meta.location.0,
+                &block_meta.col_metas,
+            )
+            .await?;
+
+        // deserialize block data
+        // cpu intensive task, send them to dedicated thread pool
+        let data_block = {
+            let storage_format = self.write_settings.storage_format;
+            let block_meta_ptr = block_meta.clone();
+            let reader = reader.clone();
+            GlobalIORuntime::instance()
+                .spawn_blocking(move || {
+                    let column_chunks = merged_io_read_result.columns_chunks()?;
+                    reader.deserialize_chunks(
+                        block_meta_ptr.location.0.as_str(),
+                        block_meta_ptr.row_count as usize,
+                        &block_meta_ptr.compression,
+                        &block_meta_ptr.col_metas,
+                        column_chunks,
+                        &storage_format,
+                        &block_meta_ptr.native_encodings,
+                    )
+                })
+                .await?
+        };
+
+        let bitmap = bitmap.into();
+        let new_block = data_block.filter_with_bitmap(&bitmap)?;
+        info!("number of row deleted: {}", delete_nums);
+
+        // serialization and compression is cpu intensive, send them to dedicated thread pool
+        let block_builder = self.block_builder.clone();
+        let origin_stats = block_meta.cluster_stats.clone();
+        let serialized = GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                block_builder.build(new_block, |block, generator| {
+                    let cluster_stats =
+                        generator.gen_with_origin_stats(&block, origin_stats.clone())?;
+                    Ok((cluster_stats, block))
+                })
+            })
+            .await?;
+
+        // persistent data
+        let new_block_meta = serialized.block_meta;
+        let new_block_location = new_block_meta.location.0.clone();
+        let data_accessor = self.data_accessor.clone();
+        write_data(
+            serialized.block_raw_data,
+            &data_accessor,
+            &new_block_location,
+        )
+        .await?;
+        if let Some(index_state) = serialized.bloom_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
+
+        Ok(Some(ReplacementLogEntry {
+            index: BlockMetaIndex {
+                segment_idx: segment_index,
+                block_idx: block_index,
+            },
+            op: Replacement::Replaced(Arc::new(new_block_meta)),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_task_permit(&self) -> Result<OwnedSemaphorePermit> {
+        let permit = self
+            .io_request_semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal("unexpected, io request semaphore is closed. {}")
+                    .add_message_back(e.to_string())
+            })?;
+        Ok(permit)
+    }
+}
@@ -276,4 +276,24 @@ impl FuseTable {
 
         Ok(file_list.into_iter().map(|v| v.0).collect())
     }
+
+    /// Returns the number and the total size of the files stored under the prefix of the table.
+    #[async_backtrace::framed]
+    pub async fn stored_files_usage(&self) -> Result<(u64, u64)> {
+        let prefix = format!("{}/", self.meta_location_generator().prefix());
+        let op = self.operator.clone();
+        let mut files = 0;
+        let mut bytes = 0;
+        let mut ds = op.scan(&prefix).await?;
+        while let Some(de) = ds.try_next().await? {
+            let meta = op
+                .metadata(&de, Metakey::Mode | Metakey::ContentLength)
+                .await?;
+            if meta.mode().is_file() {
+                files += 1;
+                bytes += meta.content_length();
+            }
+        }
+        Ok((files, bytes))
+    }
 }
@@ -0,0 +1,263 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Two-phase commit of fuse table writes.
+//!
+//! If the `prepared_commit_id` setting is set, a write does not make its new snapshot
+//! the current snapshot of the table. The snapshot location is kept in the meta store
+//! as a prepared commit instead, and takes effect on `COMMIT PREPARED`. The record
+//! expires after `prepared_commit_timeout_secs`, which rolls the commit back.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_users::UserApiProvider;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_cache::LoadParams;
+use tracing::info;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+const PREPARED_COMMIT_KEY_PREFIX: &str = "__fd_prepared_commits";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PreparedCommit {
+    pub id: String,
+    /// Name of the user who prepared the commit, only this user can commit or roll it back.
+    pub user: String,
+    pub catalog: String,
+    pub table_id: u64,
+    /// Version of the table meta that the snapshot is based on.
+    pub table_version: u64,
+    pub table_name: String,
+    pub table_desc: String,
+    pub snapshot_location: String,
+    pub copied_files: Option<UpsertTableCopiedFileReq>,
+    /// Seconds since the epoch.
+    pub prepared_at: u64,
+    pub expire_at: u64,
+}
+
+fn prepared_commit_key(tenant: &str, id: &str) -> Result<String> {
+    Ok(format!(
+        "{}/{}/{}",
+        PREPARED_COMMIT_KEY_PREFIX,
+        escape_for_key(tenant)?,
+        escape_for_key(id)?
+    ))
+}
+
+impl FuseTable {
+    /// Keeps the new snapshot of a write aside as the prepared commit `id`, instead of
+    /// making it the current snapshot of the table.
+    #[async_backtrace::framed]
+    pub async fn prepare_commit(
+        ctx: &dyn TableContext,
+        table_info: &TableInfo,
+        id: &str,
+        snapshot_location: String,
+        copied_files: &Option<UpsertTableCopiedFileReq>,
+    ) -> Result<()> {
+        let prepared_at = SeqV::<()>::now_ms() / 1000;
+        let expire_at = prepared_at + ctx.get_settings().get_prepared_commit_timeout_secs()?;
+        let prepared = PreparedCommit {
+            id: id.to_string(),
+            user: ctx.get_current_user()?.name,
+            catalog: table_info.catalog().to_string(),
+            table_id: table_info.ident.table_id,
+            table_version: table_info.ident.seq,
+            table_name: table_info.name.clone(),
+            table_desc: table_info.desc.clone(),
+            snapshot_location,
+            copied_files: copied_files.clone(),
+            prepared_at,
+            expire_at,
+        };
+
+        let key = prepared_commit_key(&ctx.get_tenant(), id)?;
+        let kv_store = UserApiProvider::instance().get_meta_store_client();
+        kv_store
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(serde_json::to_vec(&prepared)?),
+                Some(KVMeta {
+                    expire_at: Some(expire_at),
+                }),
+            ))
+            .await?
+            .added_or_else(|_| {
+                ErrorCode::PreparedCommitAlreadyExists(format!(
+                    "prepared commit '{}' already exists",
+                    id
+                ))
+            })?;
+
+        info!(
+            "prepared commit '{}' of table {}, snapshot {}",
+            id, table_info.desc, prepared.snapshot_location
+        );
+        Ok(())
+    }
+
+    /// Makes the snapshot of the prepared commit `id` the current snapshot of its table.
+    ///
+    /// The table must not have been modified since the commit was prepared.
+    #[async_backtrace::framed]
+    pub async fn commit_prepared(ctx: &dyn TableContext, id: &str) -> Result<PreparedCommit> {
+        let (seq, prepared) = Self::get_prepared_commit(ctx, id).await?;
+        let table = Self::get_prepared_commit_table(ctx, &prepared).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if fuse_table.table_info.ident.seq != prepared.table_version {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "table {} has been modified since the commit '{}' was prepared",
+                prepared.table_desc, id
+            )));
+        }
+
+        let operator = fuse_table.get_operator();
+        let params = LoadParams {
+            location: prepared.snapshot_location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(&prepared.snapshot_location),
+            put_cache: false,
+        };
+        let snapshot = MetaReaders::table_snapshot_reader(operator.clone())
+            .read(&params)
+            .await?;
+
+        let res = Self::do_update_table_meta(
+            ctx,
+            &fuse_table.table_info,
+            fuse_table.meta_location_generator(),
+            snapshot.as_ref().clone(),
+            prepared.snapshot_location.clone(),
+            &prepared.copied_files,
+            &operator,
+        )
+        .await;
+        match res {
+            Ok(_) => {
+                Self::remove_prepared_commit(ctx, id, seq).await?;
+                Ok(prepared)
+            }
+            Err(e) => {
+                // The snapshot has been removed if the table meta is known to be unchanged,
+                // the prepared commit can not be committed anymore.
+                if Self::no_side_effects_in_meta_store(&e) {
+                    Self::remove_prepared_commit(ctx, id, seq).await?;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Discards the prepared commit `id`.
+    ///
+    /// The snapshot of the prepared commit is removed. Its segments and blocks are not
+    /// referenced by any snapshot, they are left to be cleaned up as orphan files.
+    #[async_backtrace::framed]
+    pub async fn rollback_prepared(ctx: &dyn TableContext, id: &str) -> Result<PreparedCommit> {
+        let (seq, prepared) = Self::get_prepared_commit(ctx, id).await?;
+        Self::remove_prepared_commit(ctx, id, seq).await?;
+
+        // The table may have been dropped in the meantime.
+        if let Ok(table) = Self::get_prepared_commit_table(ctx, &prepared).await {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            let _ = fuse_table
+                .get_operator()
+                .delete(&prepared.snapshot_location)
+                .await;
+        }
+
+        info!(
+            "rolled back prepared commit '{}' of table {}",
+            id, prepared.table_desc
+        );
+        Ok(prepared)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_prepared_commit(
+        ctx: &dyn TableContext,
+        id: &str,
+    ) -> Result<(u64, PreparedCommit)> {
+        let key = prepared_commit_key(&ctx.get_tenant(), id)?;
+        let kv_store = UserApiProvider::instance().get_meta_store_client();
+        let seq_value = kv_store.get_kv(&key).await?.ok_or_else(|| {
+            ErrorCode::UnknownPreparedCommit(format!(
+                "unknown prepared commit '{}', it may have been rolled back after timeout",
+                id
+            ))
+        })?;
+        let prepared: PreparedCommit = serde_json::from_slice(&seq_value.data)?;
+
+        let user = ctx.get_current_user()?.name;
+        if prepared.user != user {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "prepared commit '{}' was prepared by user '{}', not '{}'",
+                id, prepared.user, user
+            )));
+        }
+        Ok((seq_value.seq, prepared))
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_prepared_commit(ctx: &dyn TableContext, id: &str, seq: u64) -> Result<()> {
+        let key = prepared_commit_key(&ctx.get_tenant(), id)?;
+        let kv_store = UserApiProvider::instance().get_meta_store_client();
+        kv_store
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_prepared_commit_table(
+        ctx: &dyn TableContext,
+        prepared: &PreparedCommit,
+    ) -> Result<Arc<dyn Table>> {
+        let catalog = ctx.get_catalog(&prepared.catalog)?;
+        let (ident, meta) = catalog.get_table_meta_by_id(prepared.table_id).await?;
+        let table_info = TableInfo {
+            ident,
+            desc: prepared.table_desc.clone(),
+            name: prepared.table_name.clone(),
+            meta: meta.as_ref().clone(),
+            tenant: ctx.get_tenant(),
+            ..Default::default()
+        };
+        catalog.get_table_by_info(&table_info)
+    }
+}
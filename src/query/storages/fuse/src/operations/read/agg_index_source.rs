@@ -0,0 +1,172 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::StealablePartitions;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::FunctionContext;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::build_agg_index_block;
+use crate::operations::eval_agg_index_selection;
+use crate::operations::read::fuse_source::dispatch_partitions;
+use crate::operations::read_agg_index_block;
+use crate::FuseStorageFormat;
+
+/// Reads the aggregating index data of the blocks instead of their raw data.
+///
+/// The index data of the blocks which are not refreshed yet is built from the raw data on the fly.
+pub struct AggIndexSource {
+    id: usize,
+    ctx: Arc<dyn TableContext>,
+    func_ctx: FunctionContext,
+    operator: Operator,
+    block_reader: Arc<BlockReader>,
+    storage_format: FuseStorageFormat,
+    agg_index: AggIndexInfo,
+    partitions: StealablePartitions,
+}
+
+impl AggIndexSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        id: usize,
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        block_reader: Arc<BlockReader>,
+        storage_format: FuseStorageFormat,
+        agg_index: AggIndexInfo,
+        partitions: StealablePartitions,
+    ) -> Result<ProcessorPtr> {
+        let func_ctx = ctx.get_function_context()?;
+        AsyncSourcer::create(ctx.clone(), output, AggIndexSource {
+            id,
+            ctx,
+            func_ctx,
+            operator,
+            block_reader,
+            storage_format,
+            agg_index,
+            partitions,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_index_block(&self, part: PartInfoPtr) -> Result<DataBlock> {
+        let fuse_part = FusePartInfo::from_part(&part)?;
+        let location = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+            &fuse_part.location,
+            self.agg_index.index_id,
+        );
+        if let Some(block) = read_agg_index_block(&self.operator, &location).await? {
+            return Ok(block);
+        }
+
+        // The index data is not refreshed yet, build it from the raw data.
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let merge_io_result = self
+            .block_reader
+            .read_columns_data_by_merge_io(&settings, &fuse_part.location, &fuse_part.columns_meta)
+            .await?;
+        let chunks = merge_io_result.columns_chunks()?;
+        let raw_block = self.block_reader.deserialize_chunks_with_part_info(
+            part.clone(),
+            chunks,
+            &self.storage_format,
+        )?;
+
+        build_agg_index_block(
+            &self.func_ctx,
+            &self.agg_index.definition,
+            &self.block_reader.schema(),
+            raw_block,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for AggIndexSource {
+    const NAME: &'static str = "AggIndexSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.partitions.steal_one(self.id) {
+            None => Ok(None),
+            Some(part) => {
+                let block = self.read_index_block(part).await?;
+                // Empty blocks are skipped by the sourcer.
+                Ok(Some(eval_agg_index_selection(
+                    &self.func_ctx,
+                    &self.agg_index.selection,
+                    self.agg_index.filter.as_ref(),
+                    block,
+                )?))
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn build_agg_index_source_pipeline(
+    ctx: Arc<dyn TableContext>,
+    pipeline: &mut Pipeline,
+    operator: Operator,
+    block_reader: Arc<BlockReader>,
+    storage_format: FuseStorageFormat,
+    plan: &DataSourcePlan,
+    agg_index: &AggIndexInfo,
+    max_io_requests: usize,
+) -> Result<()> {
+    let max_threads = ctx.get_settings().get_max_threads()? as usize;
+    let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
+    let partitions = StealablePartitions::new(partitions, ctx.clone());
+
+    let mut source_builder = SourcePipeBuilder::create();
+    for i in 0..max_io_requests {
+        let output = OutputPort::create();
+        source_builder.add_source(
+            output.clone(),
+            AggIndexSource::create(
+                i,
+                ctx.clone(),
+                output,
+                operator.clone(),
+                block_reader.clone(),
+                storage_format,
+                agg_index.clone(),
+                partitions.clone(),
+            )?,
+        );
+    }
+    pipeline.add_pipe(source_builder.finalize());
+    pipeline.resize(std::cmp::min(max_threads, max_io_requests))
+}
@@ -35,6 +35,7 @@ use crate::operations::read::native_data_source_deserializer::NativeDeserializeD
 use crate::operations::read::native_data_source_reader::ReadNativeDataSource;
 use crate::operations::read::parquet_data_source_deserializer::DeserializeDataTransform;
 use crate::operations::read::parquet_data_source_reader::ReadParquetDataSource;
+use crate::operations::read::runtime_filter_pruner::RuntimeFilterPruner;
 
 pub fn build_fuse_native_source_pipeline(
     ctx: Arc<dyn TableContext>,
@@ -54,6 +55,7 @@ pub fn build_fuse_native_source_pipeline(
     }
 
     let mut source_builder = SourcePipeBuilder::create();
+    let runtime_filter_pruner = RuntimeFilterPruner::create(ctx.clone(), plan)?;
 
     match block_reader.support_blocking_api() {
         true => {
@@ -74,6 +76,7 @@ pub fn build_fuse_native_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -97,6 +100,7 @@ pub fn build_fuse_native_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -131,6 +135,7 @@ pub fn build_fuse_parquet_source_pipeline(
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
     let mut source_builder = SourcePipeBuilder::create();
+    let runtime_filter_pruner = RuntimeFilterPruner::create(ctx.clone(), plan)?;
 
     match block_reader.support_blocking_api() {
         true => {
@@ -147,6 +152,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -168,6 +174,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        runtime_filter_pruner.clone(),
                     )?,
                 );
             }
@@ -22,6 +22,8 @@ use common_arrow::arrow::array::Array;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::error::Error as ArrowError;
+use common_arrow::arrow::error::Result as ArrowResult;
 use common_arrow::native::read::column_iter_to_arrays;
 use common_arrow::native::read::reader::NativeReader;
 use common_arrow::native::read::ArrayIter;
@@ -60,6 +62,9 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_storage::ColumnNode;
+use storages_common_blocks::decode_native_page;
+use storages_common_blocks::native_encoded_field;
+use storages_common_table_meta::meta::NativeColumnEncoding;
 
 use super::fuse_source::fill_internal_column_meta;
 use crate::fuse_part::FusePartInfo;
@@ -481,11 +486,27 @@ impl NativeDeserializeDataTransform {
         column_node: &ColumnNode,
         leaves: Vec<ColumnDescriptor>,
         readers: Vec<NativeReader<Box<dyn NativeReaderExt>>>,
+        native_encoding: Option<&NativeColumnEncoding>,
+        first_page: usize,
     ) -> Result<ArrayIter<'static>> {
         let field = column_node.field.clone();
         let is_nested = column_node.is_nested;
-        match column_iter_to_arrays(readers, leaves, field, is_nested) {
-            Ok(array_iter) => Ok(array_iter),
+        let Some(native_encoding) = native_encoding else {
+            return match column_iter_to_arrays(readers, leaves, field, is_nested) {
+                Ok(array_iter) => Ok(array_iter),
+                Err(err) => Err(err.into()),
+            };
+        };
+
+        // the lightweight encoded values are read as they are stored, and decoded page by page.
+        let stored_field = native_encoded_field(&field, native_encoding);
+        match column_iter_to_arrays(readers, leaves, stored_field, is_nested) {
+            Ok(array_iter) => Ok(Box::new(DecodeArrayIter {
+                inner: array_iter,
+                native_encoding: native_encoding.clone(),
+                data_type: field.data_type().clone(),
+                page: first_page,
+            })),
             Err(err) => Err(err.into()),
         }
     }
@@ -599,7 +620,18 @@ impl Processor for NativeDeserializeDataTransform {
                     let readers = chunks.remove(&index).unwrap();
                     if !readers.is_empty() {
                         let leaves = self.column_leaves.get(index).unwrap().clone();
-                        let array_iter = Self::build_array_iter(column_node, leaves, readers)?;
+                        let native_encoding = match column_node.leaf_column_ids.as_slice() {
+                            [column_id] => fuse_part.native_encodings.get(column_id),
+                            _ => None,
+                        };
+                        let first_page = fuse_part.range().map_or(0, |range| range.start);
+                        let array_iter = Self::build_array_iter(
+                            column_node,
+                            leaves,
+                            readers,
+                            native_encoding,
+                            first_page,
+                        )?;
                         self.array_iters.insert(index, array_iter);
                         self.array_skip_pages.insert(index, 0);
                     } else {
@@ -823,3 +855,47 @@ impl Processor for NativeDeserializeDataTransform {
         Ok(())
     }
 }
+
+/// Decodes the pages of a column with a lightweight encoding, read from `inner` as they
+/// are stored.
+struct DecodeArrayIter {
+    inner: ArrayIter<'static>,
+    native_encoding: NativeColumnEncoding,
+    data_type: ArrowType,
+    /// The index in the block of the next page.
+    page: usize,
+}
+
+impl DecodeArrayIter {
+    fn decode(
+        &mut self,
+        array: Option<ArrowResult<Box<dyn Array>>>,
+    ) -> Option<ArrowResult<Box<dyn Array>>> {
+        let page = self.page;
+        self.page += 1;
+        array.map(|array| {
+            decode_native_page(
+                &self.native_encoding,
+                page,
+                array?.as_ref(),
+                &self.data_type,
+            )
+            .map_err(|err| ArrowError::ExternalFormat(err.message()))
+        })
+    }
+}
+
+impl Iterator for DecodeArrayIter {
+    type Item = ArrowResult<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let array = self.inner.next();
+        self.decode(array)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.page += n;
+        let array = self.inner.nth(n);
+        self.decode(array)
+    }
+}
@@ -31,6 +31,7 @@ use common_pipeline_sources::SyncSourcer;
 use crate::io::BlockReader;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::runtime_filter_pruner::RuntimeFilterPruner;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     id: usize,
@@ -41,6 +42,7 @@ pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<DataChunks>)>,
     partitions: StealablePartitions,
+    runtime_filter_pruner: RuntimeFilterPruner,
 }
 
 impl ReadNativeDataSource<true> {
@@ -50,6 +52,7 @@ impl ReadNativeDataSource<true> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: RuntimeFilterPruner,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadNativeDataSource::<true> {
@@ -60,6 +63,7 @@ impl ReadNativeDataSource<true> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter_pruner,
         })
     }
 }
@@ -71,6 +75,7 @@ impl ReadNativeDataSource<false> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: RuntimeFilterPruner,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
@@ -83,6 +88,7 @@ impl ReadNativeDataSource<false> {
             finished: false,
             output_data: None,
             partitions,
+            runtime_filter_pruner,
         })))
     }
 }
@@ -91,13 +97,18 @@ impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(
-                NativeDataSourceMeta::create(vec![part.clone()], vec![
-                    self.block_reader.sync_read_native_columns_data(part)?,
-                ]),
-            ))),
+        loop {
+            match self.partitions.steal_one(self.id) {
+                None => return Ok(None),
+                Some(part) if self.runtime_filter_pruner.should_prune(&part)? => continue,
+                Some(part) => {
+                    return Ok(Some(DataBlock::empty_with_meta(
+                        NativeDataSourceMeta::create(vec![part.clone()], vec![
+                            self.block_reader.sync_read_native_columns_data(part)?,
+                        ]),
+                    )));
+                }
+            }
         }
     }
 }
@@ -137,7 +148,20 @@ impl Processor for ReadNativeDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let mut parts = self.partitions.steal(self.id, self.batch_size);
+        while !parts.is_empty() {
+            let mut remain_parts = Vec::with_capacity(parts.len());
+            for part in parts {
+                if !self.runtime_filter_pruner.should_prune(&part)? {
+                    remain_parts.push(part);
+                }
+            }
+            if !remain_parts.is_empty() {
+                parts = remain_parts;
+                break;
+            }
+            parts = self.partitions.steal(self.id, self.batch_size);
+        }
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -37,6 +37,7 @@ use super::native_data_source_deserializer::NativeDeserializeDataTransform;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
 use crate::io::MetaReaders;
+use crate::FusePartInfo;
 use crate::FuseTable;
 
 pub(super) struct NativeRowsFetcher<const BLOCKING_IO: bool> {
@@ -170,15 +171,30 @@ impl<const BLOCKING_IO: bool> NativeRowsFetcher<BLOCKING_IO> {
         Ok(())
     }
 
-    fn build_blocks(&self, mut chunks: DataChunks, needed_pages: &[u64]) -> Result<Vec<DataBlock>> {
+    fn build_blocks(
+        &self,
+        part: &PartInfoPtr,
+        mut chunks: DataChunks,
+        needed_pages: &[u64],
+    ) -> Result<Vec<DataBlock>> {
+        let part = FusePartInfo::from_part(part)?;
         let mut array_iters = BTreeMap::new();
 
         for (index, column_node) in self.reader.project_column_nodes.iter().enumerate() {
             let readers = chunks.remove(&index).unwrap();
             if !readers.is_empty() {
                 let leaves = self.column_leaves.get(index).unwrap().clone();
-                let array_iter =
-                    NativeDeserializeDataTransform::build_array_iter(column_node, leaves, readers)?;
+                let native_encoding = match column_node.leaf_column_ids.as_slice() {
+                    [column_id] => part.native_encodings.get(column_id),
+                    _ => None,
+                };
+                let array_iter = NativeDeserializeDataTransform::build_array_iter(
+                    column_node,
+                    leaves,
+                    readers,
+                    native_encoding,
+                    0,
+                )?;
                 array_iters.insert(index, array_iter);
             }
         }
@@ -235,7 +251,8 @@ impl<const BLOCKING_IO: bool> NativeRowsFetcher<BLOCKING_IO> {
 
         let mut offset = 0_usize;
         for (prefix, chunk, needed_pages) in chunks.into_iter() {
-            let fetched_blocks = self.build_blocks(chunk, &needed_pages)?;
+            let part = &self.part_map[&prefix].0;
+            let fetched_blocks = self.build_blocks(part, chunk, &needed_pages)?;
             for (block, page) in fetched_blocks.into_iter().zip(needed_pages) {
                 idx_map.insert((prefix, page), offset);
                 offset += 1;
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_base::base::tokio;
@@ -27,13 +30,19 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
+use storages_common_cache_manager::CacheManager;
+use tracing::warn;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::runtime_filter_pruner::RuntimeFilterPruner;
 use crate::MergeIOReadResult;
 
+/// The maximum number of the next partitions looked at for prefetching.
+const MAX_PREFETCH_PARTS: usize = 64;
+
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     id: usize,
     finished: bool,
@@ -43,6 +52,11 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
     partitions: StealablePartitions,
+    runtime_filter_pruner: RuntimeFilterPruner,
+
+    prefetch_bytes: u64,
+    prefetching_bytes: Arc<AtomicU64>,
+    prefetched_locations: HashSet<String>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -52,8 +66,10 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        runtime_filter_pruner: RuntimeFilterPruner,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
+        let prefetch_bytes = ctx.get_settings().get_storage_prefetch_bytes()?;
 
         if BLOCKING_IO {
             SyncSourcer::create(ctx.clone(), output.clone(), ReadParquetDataSource::<true> {
@@ -64,6 +80,10 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
+                prefetch_bytes,
+                prefetching_bytes: Arc::new(AtomicU64::new(0)),
+                prefetched_locations: HashSet::new(),
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -76,24 +96,93 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                runtime_filter_pruner,
+                prefetch_bytes,
+                prefetching_bytes: Arc::new(AtomicU64::new(0)),
+                prefetched_locations: HashSet::new(),
             })))
         }
     }
+
+    /// Prefetch the column data of the partitions this source reads next into the
+    /// table data cache, while the current ones are being read and processed.
+    ///
+    /// The column data being prefetched is bounded by the `storage_prefetch_bytes` setting.
+    fn prefetch_next_parts(&mut self) -> Result<()> {
+        if self.prefetch_bytes == 0 || CacheManager::instance().get_table_data_cache().is_none() {
+            return Ok(());
+        }
+
+        let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
+        let next_parts = self.partitions.peek(self.id, MAX_PREFETCH_PARTS);
+        let mut next_locations = HashSet::with_capacity(next_parts.len());
+        let mut exhausted = false;
+        for part in next_parts {
+            let fuse_part = FusePartInfo::from_part(&part)?;
+            next_locations.insert(fuse_part.location.clone());
+            if exhausted
+                || self.prefetched_locations.contains(&fuse_part.location)
+                || self.runtime_filter_pruner.should_prune(&part)?
+            {
+                continue;
+            }
+
+            let ranges = self
+                .block_reader
+                .uncached_column_ranges(&fuse_part.location, &fuse_part.columns_meta);
+            let bytes = ranges.iter().map(|(_, r)| r.end - r.start).sum::<u64>();
+            if bytes == 0 {
+                continue;
+            }
+            if self.prefetching_bytes.load(Ordering::Acquire) + bytes > self.prefetch_bytes {
+                // Keep the partition order, do not skip to the smaller ones after it.
+                exhausted = true;
+                continue;
+            }
+
+            self.prefetching_bytes.fetch_add(bytes, Ordering::AcqRel);
+            self.prefetched_locations.insert(fuse_part.location.clone());
+
+            let block_reader = self.block_reader.clone();
+            let prefetching_bytes = self.prefetching_bytes.clone();
+            let settings = settings.clone();
+            let location = fuse_part.location.clone();
+            tokio::spawn(async_backtrace::location!().frame(async move {
+                if let Err(e) = block_reader
+                    .prefetch_columns_data(&settings, &location, ranges)
+                    .await
+                {
+                    warn!("failed to prefetch the block {}: {}", location, e);
+                }
+                prefetching_bytes.fetch_sub(bytes, Ordering::AcqRel);
+            }));
+        }
+
+        // Forget the partitions which are taken already.
+        self.prefetched_locations
+            .retain(|location| next_locations.contains(location));
+        Ok(())
+    }
 }
 
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                vec![part.clone()],
-                vec![self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
-                    part,
-                )?],
-            )))),
+        loop {
+            match self.partitions.steal_one(self.id) {
+                None => return Ok(None),
+                Some(part) if self.runtime_filter_pruner.should_prune(&part)? => continue,
+                Some(part) => {
+                    return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                        vec![part.clone()],
+                        vec![self.block_reader.sync_read_columns_data_by_merge_io(
+                            &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                            part,
+                        )?],
+                    ))));
+                }
+            }
         }
     }
 }
@@ -134,9 +223,24 @@ impl Processor for ReadParquetDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        let mut parts = self.partitions.steal(self.id, self.batch_size);
+        while !parts.is_empty() {
+            let mut remain_parts = Vec::with_capacity(parts.len());
+            for part in parts {
+                if !self.runtime_filter_pruner.should_prune(&part)? {
+                    remain_parts.push(part);
+                }
+            }
+            if !remain_parts.is_empty() {
+                parts = remain_parts;
+                break;
+            }
+            parts = self.partitions.steal(self.id, self.batch_size);
+        }
 
         if !parts.is_empty() {
+            self.prefetch_next_parts()?;
+
             let mut chunks = Vec::with_capacity(parts.len());
             for part in &parts {
                 let part = part.clone();
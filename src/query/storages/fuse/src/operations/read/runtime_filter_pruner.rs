@@ -0,0 +1,79 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::fuse_part::FusePartInfo;
+use crate::metrics::metrics_inc_blocks_runtime_filter_pruned;
+
+/// Prunes the partitions of a table scan with the runtime filters that the hash joins
+/// over the scan build from their build side.
+///
+/// The filters arrive once the build side of a join finishes, the partitions read
+/// before that are not pruned.
+#[derive(Clone)]
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    table_index: Option<usize>,
+    table_schema: TableSchemaRef,
+    func_ctx: FunctionContext,
+}
+
+impl RuntimeFilterPruner {
+    pub fn create(ctx: Arc<dyn TableContext>, plan: &DataSourcePlan) -> Result<Self> {
+        Ok(RuntimeFilterPruner {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            table_index: plan.table_index,
+            table_schema: plan.source_info.schema(),
+        })
+    }
+
+    /// Whether no row of the partition can pass the runtime filters, by the statistics
+    /// of its block.
+    pub fn should_prune(&self, part: &PartInfoPtr) -> Result<bool> {
+        let table_index = match self.table_index {
+            Some(table_index) => table_index,
+            None => return Ok(false),
+        };
+        let filters = self.ctx.get_runtime_filters(table_index);
+        if filters.is_empty() {
+            return Ok(false);
+        }
+
+        let part = FusePartInfo::from_part(part)?;
+        for filter in &filters {
+            let range_index = RangeIndex::try_create(
+                self.func_ctx.clone(),
+                filter,
+                self.table_schema.clone(),
+                StatisticsOfColumns::default(),
+            )?;
+            if !range_index.apply(&part.columns_stat, |_| false)? {
+                metrics_inc_blocks_runtime_filter_pruned(1);
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -32,6 +32,7 @@ use storages_common_index::RangeIndex;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
+use crate::operations::read::build_agg_index_source_pipeline;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
 use crate::pruning::SegmentLocation;
@@ -183,6 +184,23 @@ impl FuseTable {
         let block_reader = self.build_block_reader(plan, ctx.clone())?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
+        if let Some(agg_index) = plan
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.agg_index.as_ref())
+        {
+            return build_agg_index_source_pipeline(
+                ctx,
+                pipeline,
+                self.operator.clone(),
+                block_reader,
+                self.storage_format,
+                plan,
+                agg_index,
+                max_io_requests,
+            );
+        }
+
         let topk = plan.push_downs.as_ref().and_then(|x| {
             x.top_k(
                 plan.schema().as_ref(),
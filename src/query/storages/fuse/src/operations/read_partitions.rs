@@ -33,6 +33,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
 use opendal::Operator;
@@ -43,8 +44,10 @@ use storages_common_cache_manager::CachedObject;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_pruner::VirtualColumnPruner;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::ColumnStatistics;
 use tracing::debug;
 use tracing::info;
 
@@ -55,6 +58,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::pruning::FusePruner;
 use crate::pruning::SegmentLocation;
 use crate::FuseTable;
+use crate::FUSE_VIRTUAL_COLUMN_STATS_PREFIX;
 
 impl FuseTable {
     #[tracing::instrument(level = "debug", name = "do_read_partitions", skip_all, fields(ctx.id = ctx.get_id().as_str()))]
@@ -191,19 +195,43 @@ impl FuseTable {
             start.elapsed().as_secs()
         );
 
-        let virtual_columns_metas = if let Some(virtual_columns) =
+        let (block_metas, virtual_columns_metas) = if let Some(virtual_columns) =
             PushDownInfo::virtual_columns_of_push_downs(&push_downs)
         {
+            let filter_expr = push_downs
+                .as_ref()
+                .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+            let virtual_column_names = virtual_columns
+                .iter()
+                .map(|virtual_column| virtual_column.name.clone())
+                .collect::<Vec<_>>();
+            let virtual_column_pruner = VirtualColumnPruner::try_create(
+                ctx.get_function_context()?,
+                filter_expr.as_ref(),
+                &virtual_column_names,
+            );
+
+            let mut kept_block_metas = Vec::with_capacity(block_metas.len());
             let mut virtual_columns_metas = Vec::with_capacity(block_metas.len());
-            for (_, block_meta) in &block_metas {
-                let virtual_columns_meta = self
-                    .get_virtual_columns_meta(dal.clone(), block_meta, &virtual_columns)
+            for (block_meta_index, block_meta) in block_metas {
+                let (virtual_columns_meta, virtual_columns_stats) = self
+                    .get_virtual_columns_meta(dal.clone(), &block_meta, &virtual_columns)
                     .await?;
+                if let Some(pruner) = &virtual_column_pruner {
+                    if !pruner.should_keep(&virtual_columns_stats) {
+                        continue;
+                    }
+                }
+                kept_block_metas.push((block_meta_index, block_meta));
                 virtual_columns_metas.push(virtual_columns_meta);
             }
-            Some(virtual_columns_metas)
+            info!(
+                "prune snapshot block by virtual columns, final block numbers:{}",
+                kept_block_metas.len()
+            );
+            (kept_block_metas, Some(virtual_columns_metas))
         } else {
-            None
+            (block_metas, None)
         };
 
         let block_metas = block_metas
@@ -234,12 +262,16 @@ impl FuseTable {
         dal: Operator,
         block_meta: &Arc<BlockMeta>,
         virtual_columns: &Vec<VirtualColumnInfo>,
-    ) -> Result<Option<HashMap<String, VirtualColumnMeta>>> {
+    ) -> Result<(
+        Option<HashMap<String, VirtualColumnMeta>>,
+        HashMap<String, ColumnStatistics>,
+    )> {
         // TODO: read parquet
         let virtual_loc =
             TableMetaLocationGenerator::gen_virtual_block_location(&block_meta.location.0);
 
         // Read virtual column schema from file meta
+        let mut virtual_columns_stats = HashMap::new();
         let virtual_columns_meta = if let Ok(virtual_meta) = dal.stat(&virtual_loc).await {
             let length = virtual_meta.content_length();
             let schema_size_bytes = dal
@@ -255,6 +287,14 @@ impl FuseTable {
                 )
                 .await?;
             let (virtual_schema, _) = deserialize_schema(&bytes)?;
+            for virtual_column in virtual_columns {
+                let key = format!("{FUSE_VIRTUAL_COLUMN_STATS_PREFIX}{}", virtual_column.name);
+                if let Some(stats) = virtual_schema.metadata.get(&key) {
+                    if let Ok(stats) = serde_json::from_str(stats) {
+                        virtual_columns_stats.insert(virtual_column.name.clone(), stats);
+                    }
+                }
+            }
             let schema_descriptor = to_parquet_schema(&virtual_schema)?;
 
             let mut off = schema_size as usize;
@@ -301,7 +341,7 @@ impl FuseTable {
             None
         };
 
-        Ok(virtual_columns_meta)
+        Ok((virtual_columns_meta, virtual_columns_stats))
     }
 
     pub fn read_partitions_with_metas(
@@ -536,6 +576,7 @@ impl FuseTable {
         virtual_columns_meta: Option<HashMap<String, VirtualColumnMeta>>,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(meta.col_metas.len());
+        let mut columns_stat = HashMap::with_capacity(meta.col_stats.len());
 
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
@@ -549,6 +590,9 @@ impl FuseTable {
             if let Some(meta) = meta.col_metas.get(column_id) {
                 columns_meta.insert(*column_id, meta.clone());
             }
+            if let Some(stat) = meta.col_stats.get(column_id) {
+                columns_stat.insert(*column_id, stat.clone());
+            }
         }
 
         let rows_count = meta.row_count;
@@ -560,15 +604,24 @@ impl FuseTable {
             (stat.min.clone(), stat.max.clone())
         });
 
+        let native_encodings = meta
+            .native_encodings
+            .iter()
+            .filter(|(column_id, _)| columns_meta.contains_key(column_id))
+            .map(|(column_id, encoding)| (*column_id, encoding.clone()))
+            .collect();
+
         FusePartInfo::create(
             location,
             format_version,
             rows_count,
             columns_meta,
+            columns_stat,
             virtual_columns_meta,
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            native_encodings,
         )
     }
 
@@ -581,6 +634,7 @@ impl FuseTable {
         projection: &Projection,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
+        let mut columns_stat = HashMap::with_capacity(projection.len());
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         for column in &columns {
@@ -589,6 +643,9 @@ impl FuseTable {
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
+                if let Some(stat) = meta.col_stats.get(column_id) {
+                    columns_stat.insert(*column_id, stat.clone());
+                }
             }
         }
 
@@ -601,6 +658,13 @@ impl FuseTable {
             stat.map(|stat| (stat.min.clone(), stat.max.clone()))
         });
 
+        let native_encodings = meta
+            .native_encodings
+            .iter()
+            .filter(|(column_id, _)| columns_meta.contains_key(column_id))
+            .map(|(column_id, encoding)| (*column_id, encoding.clone()))
+            .collect();
+
         // TODO
         // row_count should be a hint value of  LIMIT,
         // not the count the rows in this partition
@@ -609,10 +673,12 @@ impl FuseTable {
             format_version,
             rows_count,
             columns_meta,
+            columns_stat,
             virtual_columns_meta,
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            native_encodings,
         )
     }
 }
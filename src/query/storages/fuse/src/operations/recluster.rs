@@ -30,7 +30,10 @@ use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
 use common_sql::evaluator::CompoundBlockOperator;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ClusteringDepth;
+use storages_common_table_meta::meta::SegmentInfo;
 
+use crate::io::SegmentsIO;
 use crate::operations::common::AppendTransform;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::CommitSink;
@@ -40,17 +43,94 @@ use crate::operations::ReclusterMutator;
 use crate::pipelines::Pipeline;
 use crate::pruning::create_segment_location_vector;
 use crate::pruning::FusePruner;
+use crate::statistics::calc_clustering_depth;
 use crate::FuseTable;
+use crate::DEFAULT_AUTO_RECLUSTER_DEPTH;
 use crate::DEFAULT_AVG_DEPTH_THRESHOLD;
+use crate::FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH;
 use crate::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
+use crate::MAX_RECLUSTER_BLOCK_COUNT;
 
 impl FuseTable {
+    /// Returns the average depth above which the blocks of a level of the table are reclustered.
+    pub fn recluster_depth_threshold(&self, block_count: u64) -> f64 {
+        let avg_depth_threshold = self.get_option(
+            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+            DEFAULT_AVG_DEPTH_THRESHOLD,
+        );
+        if block_count > 100 {
+            block_count as f64 * avg_depth_threshold
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the average depth of the blocks above which the table is reclustered in the
+    /// background, `None` if the background recluster is disabled for the table.
+    pub fn auto_recluster_depth(&self) -> Option<f64> {
+        let depth = self.get_option(
+            FUSE_OPT_KEY_AUTO_RECLUSTER_DEPTH,
+            DEFAULT_AUTO_RECLUSTER_DEPTH,
+        );
+        if depth > 0.0 { Some(depth) } else { None }
+    }
+
+    /// Returns the clustering depth of the blocks of the latest snapshot, the one persisted by
+    /// `ANALYZE TABLE` is used if it is computed on this snapshot.
+    #[async_backtrace::framed]
+    pub async fn clustering_depth(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<ClusteringDepth>> {
+        let Some((cluster_key_id, _)) = self.cluster_key_meta.clone() else {
+            return Ok(None);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        if let Some(table_statistics) = self.read_table_snapshot_statistics(Some(&snapshot)).await?
+        {
+            if let Some(depth) = table_statistics.get_clustering_depth() {
+                if depth.snapshot_id == snapshot.snapshot_id
+                    && depth.cluster_key_id == cluster_key_id
+                {
+                    return Ok(Some(depth.clone()));
+                }
+            }
+        }
+
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<Arc<SegmentInfo>>(&snapshot.segments, true)
+            .await?;
+        let mut ranges = Vec::new();
+        let mut unclustered_block_count = 0;
+        for segment in segments {
+            for block in segment?.blocks.iter() {
+                match &block.cluster_stats {
+                    Some(stats) if stats.cluster_key_id == cluster_key_id => {
+                        ranges.push((stats.min.clone(), stats.max.clone()));
+                    }
+                    _ => unclustered_block_count += 1,
+                }
+            }
+        }
+        Ok(Some(calc_clustering_depth(
+            snapshot.snapshot_id,
+            cluster_key_id,
+            &ranges,
+            unclustered_block_count,
+        )))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn do_recluster(
         &self,
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         push_downs: Option<PushDownInfo>,
+        limit: Option<usize>,
     ) -> Result<()> {
         if self.cluster_key_meta.is_none() {
             return Ok(());
@@ -87,18 +167,14 @@ impl FuseTable {
         });
 
         let block_thresholds = self.get_block_thresholds();
-        let avg_depth_threshold = self.get_option(
-            FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
-            DEFAULT_AVG_DEPTH_THRESHOLD,
-        );
         let block_count = snapshot.summary.block_count;
-        let threshold = if block_count > 100 {
-            block_count as f64 * avg_depth_threshold
-        } else {
-            1.0
-        };
+        let threshold = self.recluster_depth_threshold(block_count);
 
-        let mut mutator = ReclusterMutator::try_create(threshold, block_thresholds)?;
+        let mut mutator = ReclusterMutator::try_create(
+            threshold,
+            block_thresholds,
+            limit.unwrap_or(MAX_RECLUSTER_BLOCK_COUNT),
+        )?;
 
         let need_recluster = mutator.target_select(blocks_map).await?;
         if !need_recluster {
@@ -131,6 +207,7 @@ impl FuseTable {
             push_downs: None,
             query_internal_columns: false,
             data_mask_policy: None,
+            table_index: None,
         };
 
         ctx.set_partitions(plan.parts.clone())?;
@@ -279,6 +279,7 @@ impl AggregationContext {
                         &block_meta_ptr.col_metas,
                         column_chunks,
                         &storage_format,
+                        &block_meta_ptr.native_encodings,
                     )
                 })
                 .await?
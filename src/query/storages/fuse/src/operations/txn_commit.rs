@@ -0,0 +1,136 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commit and rollback of the fuse table mutations buffered in a transaction.
+//!
+//! The new table metas of all the tables mutated in a transaction are committed to the
+//! meta store in a single meta transaction. It fails as a whole if any of the tables has
+//! been modified by others since it was read by the transaction.
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnTableMutation;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::info;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Commits the buffered mutations of a transaction atomically.
+    #[async_backtrace::framed]
+    pub async fn commit_txn(
+        ctx: &dyn TableContext,
+        mutations: Vec<TxnTableMutation>,
+    ) -> Result<()> {
+        let Some(first) = mutations.first() else {
+            return Ok(());
+        };
+
+        let catalog_name = first.table_info.catalog().to_string();
+        if mutations
+            .iter()
+            .any(|mutation| mutation.table_info.catalog() != catalog_name)
+        {
+            Self::rollback_txn(ctx, mutations).await;
+            return Err(ErrorCode::InvalidTransactionState(
+                "a transaction can not mutate tables of different catalogs",
+            ));
+        }
+
+        let catalog = ctx.get_catalog(&catalog_name)?;
+        let req = UpdateMultiTableMetaReq {
+            update_table_metas: mutations
+                .iter()
+                .map(|mutation| UpdateTableMetaReq {
+                    table_id: mutation.table_info.ident.table_id,
+                    seq: MatchSeq::Exact(mutation.table_info.ident.seq),
+                    new_table_meta: mutation.table_info.meta.clone(),
+                    copied_files: mutation.copied_files.clone(),
+                    deduplicated_label: mutation.deduplicated_label.clone(),
+                })
+                .collect(),
+        };
+
+        match catalog.update_multi_table_meta(req).await {
+            Ok(_) => {
+                for mutation in mutations.iter() {
+                    let Some(snapshot_location) = mutation
+                        .table_info
+                        .meta
+                        .options
+                        .get(OPT_KEY_SNAPSHOT_LOCATION)
+                    else {
+                        continue;
+                    };
+                    if let Ok(table) = catalog.get_table_by_info(&mutation.table_info) {
+                        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                        Self::write_last_snapshot_hint(
+                            &fuse_table.get_operator(),
+                            fuse_table.meta_location_generator(),
+                            snapshot_location.clone(),
+                        )
+                        .await;
+                    }
+                }
+                info!("committed transaction of {} tables", mutations.len());
+                Ok(())
+            }
+            Err(e) => {
+                // the snapshots are safe to be removed only if the tables are known to be
+                // unchanged in the meta store.
+                if !Self::no_side_effects_in_meta_store(&e) {
+                    return Err(e);
+                }
+                Self::rollback_txn(ctx, mutations).await;
+                Err(ErrorCode::TransactionConflict(format!(
+                    "transaction rolled back, {}",
+                    e.message()
+                )))
+            }
+        }
+    }
+
+    /// Discards the buffered mutations of a transaction.
+    ///
+    /// The uncommitted snapshots are removed. Their segments and blocks are not referenced
+    /// by any snapshot, they are left to be cleaned up as orphan files.
+    #[async_backtrace::framed]
+    pub async fn rollback_txn(ctx: &dyn TableContext, mutations: Vec<TxnTableMutation>) {
+        for mutation in mutations {
+            let Ok(catalog) = ctx.get_catalog(mutation.table_info.catalog()) else {
+                continue;
+            };
+            let Ok(table) = catalog.get_table_by_info(&mutation.table_info) else {
+                continue;
+            };
+            let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                continue;
+            };
+            let operator = fuse_table.get_operator();
+            for file in mutation.uncommitted_files.iter() {
+                let _ = operator.delete(file).await;
+            }
+            info!(
+                "rolled back {} uncommitted snapshots of table {}",
+                mutation.uncommitted_files.len(),
+                mutation.table_info.desc
+            );
+        }
+    }
+}
@@ -58,6 +58,7 @@ pub fn column_parquet_metas(
                     offset: col_start as u64,
                     len: col_len as u64,
                     num_values,
+                    compression: None,
                 };
                 // use column id as key instead of index
                 let column_id = column_ids[idx];
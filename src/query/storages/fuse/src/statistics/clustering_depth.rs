@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::cmp;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_expression::Scalar;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ClusteringDepth;
+use storages_common_table_meta::meta::SnapshotId;
+
+/// Returns the `(overlaps, depth)` of the blocks with the cluster key ranges `(min, max)`.
+///
+/// The overlaps of a block is the number of the other blocks whose ranges overlap with its range,
+/// the depth is the max number of ranges overlapping at a point of its range.
+pub fn calc_block_depths(ranges: &[(Vec<Scalar>, Vec<Scalar>)]) -> Vec<(usize, usize)> {
+    // Gather all cluster statistics points to a sorted Map.
+    // Key: The cluster statistics points.
+    // Value: 0: The block indexes with key as min value;
+    //        1: The block indexes with key as max value;
+    let mut points_map: BTreeMap<&Vec<Scalar>, (Vec<usize>, Vec<usize>)> = BTreeMap::new();
+    for (i, (min, max)) in ranges.iter().enumerate() {
+        points_map.entry(min).or_default().0.push(i);
+        points_map.entry(max).or_default().1.push(i);
+    }
+
+    // calculate overlaps and depth.
+    let mut stats = Vec::with_capacity(ranges.len());
+    // key: the block index.
+    // value: (overlaps, depth).
+    let mut unfinished_parts: HashMap<usize, (usize, usize)> = HashMap::new();
+    for (start, end) in points_map.values() {
+        let point_depth = unfinished_parts.len() + start.len();
+
+        for (_, val) in unfinished_parts.iter_mut() {
+            val.0 += start.len();
+            val.1 = cmp::max(val.1, point_depth);
+        }
+
+        start.iter().for_each(|&idx| {
+            unfinished_parts.insert(idx, (point_depth - 1, point_depth));
+        });
+
+        end.iter().for_each(|&idx| {
+            let stat = unfinished_parts.remove(&idx).unwrap();
+            stats.push(stat);
+        });
+    }
+    assert_eq!(unfinished_parts.len(), 0);
+    stats
+}
+
+/// Summarizes the clustering depth of the blocks with the cluster key ranges `ranges`, and of
+/// `unclustered_block_count` blocks without statistics of the cluster key. The averages are
+/// rounded to 4 decimal places.
+pub fn calc_clustering_depth(
+    snapshot_id: SnapshotId,
+    cluster_key_id: u32,
+    ranges: &[(Vec<Scalar>, Vec<Scalar>)],
+    unclustered_block_count: u64,
+) -> ClusteringDepth {
+    let total_constant_block_count = ranges.iter().filter(|(min, max)| min == max).count();
+    let block_depths = calc_block_depths(ranges);
+    let (sum_overlaps, sum_depth, max_depth) = block_depths.iter().fold(
+        (0, 0, 0),
+        |(sum_overlaps, sum_depth, max_depth), (overlaps, depth)| {
+            (
+                sum_overlaps + overlaps,
+                sum_depth + depth,
+                cmp::max(max_depth, *depth),
+            )
+        },
+    );
+    let average = |sum: usize| {
+        if block_depths.is_empty() {
+            0.0
+        } else {
+            (10000.0 * sum as f64 / block_depths.len() as f64).round() / 10000.0
+        }
+    };
+
+    ClusteringDepth {
+        snapshot_id,
+        cluster_key_id,
+        total_block_count: ranges.len() as u64 + unclustered_block_count,
+        total_constant_block_count: total_constant_block_count as u64,
+        unclustered_block_count,
+        average_overlaps: average(sum_overlaps),
+        average_depth: average(sum_depth),
+        max_depth: max_depth as u64,
+    }
+}
+
+/// Returns the `(depth_sum, block_count)` of the blocks of a segment, tracked in the summary of
+/// the segment. Only the blocks with statistics of the latest cluster key of the segment are
+/// counted.
+pub fn reduce_cluster_depth<T: Borrow<BlockMeta>>(block_metas: &[T]) -> (u64, u64) {
+    let Some(cluster_key_id) = block_metas
+        .iter()
+        .filter_map(|b| b.borrow().cluster_stats.as_ref())
+        .map(|stats| stats.cluster_key_id)
+        .max()
+    else {
+        return (0, 0);
+    };
+    let ranges = block_metas
+        .iter()
+        .filter_map(|b| match &b.borrow().cluster_stats {
+            Some(stats) if stats.cluster_key_id == cluster_key_id => {
+                Some((stats.min.clone(), stats.max.clone()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let depth_sum = calc_block_depths(&ranges)
+        .iter()
+        .map(|(_, depth)| *depth as u64)
+        .sum();
+    (depth_sum, ranges.len() as u64)
+}
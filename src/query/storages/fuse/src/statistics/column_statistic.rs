@@ -42,6 +42,22 @@ pub fn gen_columns_statistics(
     data_block: &DataBlock,
     column_distinct_count: Option<HashMap<FieldIndex, usize>>,
     schema: &TableSchemaRef,
+) -> Result<StatisticsOfColumns> {
+    gen_columns_statistics_with_prefix_len(
+        data_block,
+        column_distinct_count,
+        schema,
+        STATS_STRING_PREFIX_LEN,
+    )
+}
+
+/// Generate the statistics of columns, the min/max values of string columns are trimmed
+/// to prefixes of at most `string_prefix_len` chars, see [`Trim`].
+pub fn gen_columns_statistics_with_prefix_len(
+    data_block: &DataBlock,
+    column_distinct_count: Option<HashMap<FieldIndex, usize>>,
+    schema: &TableSchemaRef,
+    string_prefix_len: usize,
 ) -> Result<StatisticsOfColumns> {
     let mut statistics = StatisticsOfColumns::new();
     let data_block = data_block.convert_to_full();
@@ -64,7 +80,7 @@ pub fn gen_columns_statistics(
 
         if mins.len() > 0 {
             min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min() {
+                if let Some(v) = v.to_owned().trim_min(string_prefix_len) {
                     v
                 } else {
                     continue;
@@ -76,7 +92,7 @@ pub fn gen_columns_statistics(
 
         if maxs.len() > 0 {
             max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max() {
+                if let Some(v) = v.to_owned().trim_max(string_prefix_len) {
                     v
                 } else {
                     continue;
@@ -205,25 +221,32 @@ pub mod traverse {
 //
 // the trimmed max should be larger than the non-trimmed one (if possible).
 // and the trimmed min should be lesser than the non-trimmed one (if possible).
+//
+// strings are compared byte-wise, which is the order of chars in utf-8, so a prefix of
+// a string is a valid min, and a prefix with its tail replaced by larger chars is a valid max.
 pub trait Trim: Sized {
-    fn trim_min(self) -> Option<Self>;
-    fn trim_max(self) -> Option<Self>;
+    fn trim_min(self, prefix_len: usize) -> Option<Self>;
+    fn trim_max(self, prefix_len: usize) -> Option<Self>;
 }
 
 pub const STATS_REPLACEMENT_CHAR: char = '\u{FFFD}';
+/// The default number of chars kept in the min/max statistics of string columns,
+/// could be changed by the `string_stats_prefix_len` table option.
 pub const STATS_STRING_PREFIX_LEN: usize = 16;
+/// The max number of chars could be kept in the min/max statistics of string columns.
+pub const STATS_STRING_PREFIX_MAX_LEN: usize = 1024;
 
 impl Trim for Scalar {
-    fn trim_min(self) -> Option<Self> {
+    fn trim_min(self, prefix_len: usize) -> Option<Self> {
         match self {
             Scalar::String(bytes) => match String::from_utf8(bytes) {
                 Ok(mut v) => {
-                    if v.len() <= STATS_STRING_PREFIX_LEN {
+                    if v.len() <= prefix_len {
                         Some(Scalar::String(v.into_bytes()))
                     } else {
                         // find the character boundary to prevent String::truncate from panic
                         let vs = v.as_str();
-                        let slice = match vs.char_indices().nth(STATS_STRING_PREFIX_LEN) {
+                        let slice = match vs.char_indices().nth(prefix_len) {
                             None => vs,
                             Some((idx, _)) => &vs[..idx],
                         };
@@ -244,23 +267,23 @@ impl Trim for Scalar {
         }
     }
 
-    fn trim_max(self) -> Option<Self> {
+    fn trim_max(self, prefix_len: usize) -> Option<Self> {
         match self {
             Scalar::String(bytes) => match String::from_utf8(bytes) {
                 Ok(v) => {
-                    if v.len() <= STATS_STRING_PREFIX_LEN {
+                    if v.len() <= prefix_len {
                         // if number of bytes is lesser, just return
                         Some(Scalar::String(v.into_bytes()))
                     } else {
-                        // no need to trim, less than STRING_PREFIX_LEN chars
+                        // no need to trim, less than prefix_len chars
                         let number_of_chars = v.as_str().chars().count();
-                        if number_of_chars <= STATS_STRING_PREFIX_LEN {
+                        if number_of_chars <= prefix_len {
                             return Some(Scalar::String(v.into_bytes()));
                         }
 
-                        // slice the input (at the boundary of chars), takes at most STRING_PREFIX_LEN chars
+                        // slice the input (at the boundary of chars), takes at most prefix_len chars
                         let vs = v.as_str();
-                        let sliced = match vs.char_indices().nth(STATS_STRING_PREFIX_LEN) {
+                        let sliced = match vs.char_indices().nth(prefix_len) {
                             None => vs,
                             Some((idx, _)) => &vs[..idx],
                         };
@@ -279,7 +302,7 @@ impl Trim for Scalar {
                         let replacement_point = idx?;
 
                         // rebuild the string (since the len of result string is rather small)
-                        let mut r = String::with_capacity(STATS_STRING_PREFIX_LEN);
+                        let mut r = String::with_capacity(sliced.len());
                         for (i, c) in sliced.char_indices() {
                             if i < replacement_point {
                                 r.push(c)
@@ -15,18 +15,24 @@
 pub mod accumulator;
 mod block_statistics;
 mod cluster_statistics;
+mod clustering_depth;
 mod column_statistic;
 pub mod reducers;
 
 pub use accumulator::StatisticsAccumulator;
 pub use block_statistics::BlockStatistics;
 pub use cluster_statistics::ClusterStatsGenerator;
+pub use clustering_depth::calc_block_depths;
+pub use clustering_depth::calc_clustering_depth;
+pub use clustering_depth::reduce_cluster_depth;
 pub use column_statistic::calc_column_distinct_of_values;
 pub use column_statistic::gen_columns_statistics;
+pub use column_statistic::gen_columns_statistics_with_prefix_len;
 pub use column_statistic::get_traverse_columns_dfs;
 pub use column_statistic::traverse;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
+pub use column_statistic::STATS_STRING_PREFIX_MAX_LEN;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_statistics;
@@ -23,6 +23,8 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::statistics::reduce_cluster_depth;
+
 pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
     stats_of_columns: &[T],
 ) -> StatisticsOfColumns {
@@ -104,6 +106,8 @@ pub fn merge_statistics_mut(l: &mut Statistics, r: &Statistics) {
     l.compressed_byte_size += r.compressed_byte_size;
     l.index_size += r.index_size;
     l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
+    l.cluster_depth_sum += r.cluster_depth_sum;
+    l.clustered_block_count += r.clustered_block_count;
 }
 
 // Deduct statistics, only be used for calculate snapshot summary.
@@ -121,6 +125,11 @@ pub fn deduct_statistics_mut(l: &mut Statistics, r: &Statistics) {
     l.uncompressed_byte_size -= r.uncompressed_byte_size;
     l.compressed_byte_size -= r.compressed_byte_size;
     l.index_size -= r.index_size;
+    // The summary of a snapshot written before the depth is tracked doesn't count the blocks.
+    l.cluster_depth_sum = l.cluster_depth_sum.saturating_sub(r.cluster_depth_sum);
+    l.clustered_block_count = l
+        .clustered_block_count
+        .saturating_sub(r.clustered_block_count);
     for (id, col_stats) in &mut l.col_stats {
         if let Some(r_col_stats) = r.col_stats.get(id) {
             // The MinMax of a column cannot be recalculated by the right statistics,
@@ -167,6 +176,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         .map(|v| &v.borrow().col_stats)
         .collect::<Vec<_>>();
     let merged_col_stats = reduce_block_statistics(&stats);
+    let (cluster_depth_sum, clustered_block_count) = reduce_cluster_depth(block_metas);
 
     Statistics {
         row_count,
@@ -176,5 +186,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         compressed_byte_size,
         index_size,
         col_stats: merged_col_stats,
+        cluster_depth_sum,
+        clustered_block_count,
     }
 }
@@ -0,0 +1,376 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use parking_lot::Mutex;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_STREAM_END_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_STREAM_OFFSET_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_STREAM_SOURCE_TABLE_ID;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+use crate::Table;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+/// The column of a stream holding the action of a change, `INSERT` or `DELETE`.
+pub const CHANGE_ACTION_COLUMN_NAME: &str = "change$action";
+/// The column of a stream holding the id of a changed row.
+pub const CHANGE_ROW_ID_COLUMN_NAME: &str = "change$row_id";
+
+/// A stream on a fuse table, reading the rows changed in the table since the offset of the stream.
+///
+/// The offset is a snapshot of the source table. Reading the stream in an INSERT, REPLACE or
+/// MERGE INTO statement moves the offset to the snapshot read once the statement succeeds,
+/// so that the next statement only reads the changes made after it. The new offset and the
+/// writes of the statement are committed atomically.
+///
+/// A bounded stream, which is not stored in the meta, reads the changes between its offset and
+/// a fixed end snapshot, and its offset is never moved.
+#[derive(Clone)]
+pub struct StreamTable {
+    table_info: TableInfo,
+    source_table_id: u64,
+    source_table: String,
+    offset_snapshot: Option<String>,
+    end_snapshot: Option<String>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let Some(source_table_id) = options
+            .get(OPT_KEY_STREAM_SOURCE_TABLE_ID)
+            .and_then(|v| v.parse::<u64>().ok())
+        else {
+            return Err(ErrorCode::Internal(
+                "Need `stream_source_table_id` when creating StreamTable",
+            ));
+        };
+        let source_table = options
+            .get(OPT_KEY_STREAM_SOURCE_TABLE)
+            .cloned()
+            .unwrap_or_default();
+        let offset_snapshot = options
+            .get(OPT_KEY_STREAM_OFFSET_SNAPSHOT)
+            .filter(|v| !v.is_empty())
+            .cloned();
+        let end_snapshot = options.get(OPT_KEY_STREAM_END_SNAPSHOT).cloned();
+
+        Ok(Box::new(StreamTable {
+            table_info,
+            source_table_id,
+            source_table,
+            offset_snapshot,
+            end_snapshot,
+        }))
+    }
+
+    /// The table info of a bounded stream named `name` on `source_table`, reading the changes
+    /// made from `offset_snapshot` to `end_snapshot`.
+    pub fn bounded_table_info(
+        name: &str,
+        catalog: &str,
+        source_table: &FuseTable,
+        source_table_name: &str,
+        offset_snapshot: &str,
+        end_snapshot: &str,
+    ) -> TableInfo {
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_STREAM_SOURCE_TABLE_ID.to_string(),
+            source_table.get_id().to_string(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_SOURCE_TABLE.to_string(),
+            source_table_name.to_string(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_OFFSET_SNAPSHOT.to_string(),
+            offset_snapshot.to_string(),
+        );
+        options.insert(
+            OPT_KEY_STREAM_END_SNAPSHOT.to_string(),
+            end_snapshot.to_string(),
+        );
+
+        TableInfo {
+            ident: TableIdent::default(),
+            desc: name.to_string(),
+            name: name.to_string(),
+            meta: TableMeta {
+                schema: Arc::new(Self::schema_of(&source_table.schema())),
+                engine: STREAM_ENGINE.to_string(),
+                catalog: catalog.to_string(),
+                options,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The schema of a stream on a table of `source_schema`: the stored columns of the table,
+    /// followed by the action and the row id of the changes.
+    pub fn schema_of(source_schema: &TableSchema) -> TableSchema {
+        let mut fields = source_schema
+            .remove_virtual_computed_fields()
+            .fields()
+            .iter()
+            .map(|f| TableField::new(f.name(), f.data_type().clone()))
+            .collect::<Vec<_>>();
+        fields.push(TableField::new(
+            CHANGE_ACTION_COLUMN_NAME,
+            TableDataType::String,
+        ));
+        fields.push(TableField::new(
+            CHANGE_ROW_ID_COLUMN_NAME,
+            TableDataType::String,
+        ));
+        TableSchema::new(fields)
+    }
+
+    pub fn source_table_id(&self) -> u64 {
+        self.source_table_id
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changes(&self, ctx: Arc<dyn TableContext>) -> Result<(String, Vec<DataBlock>)> {
+        let catalog = ctx.get_catalog(self.table_info.catalog())?;
+        let (ident, meta) = catalog.get_table_meta_by_id(self.source_table_id).await?;
+        if meta.drop_on.is_some() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "The source table {} of stream {} is dropped",
+                self.source_table,
+                self.name()
+            )));
+        }
+        let mut meta = meta.as_ref().clone();
+        match &self.end_snapshot {
+            Some(end_snapshot) if end_snapshot.is_empty() => {
+                meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            }
+            Some(end_snapshot) => {
+                meta.options
+                    .insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), end_snapshot.clone());
+            }
+            None => {}
+        }
+        let table = catalog.get_table_by_info(&TableInfo {
+            ident,
+            desc: self.source_table.clone(),
+            name: self.source_table.clone(),
+            meta,
+            ..Default::default()
+        })?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        // The columns of the stream are fixed when it is created.
+        let source_schema = fuse_table.schema();
+        let expected = Self::schema_of(&source_schema);
+        let same_columns = expected.fields().len() == self.schema().fields().len()
+            && expected
+                .fields()
+                .iter()
+                .zip(self.schema().fields().iter())
+                .all(|(a, b)| a.name() == b.name() && a.data_type() == b.data_type());
+        if !same_columns {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "The columns of the source table {} of stream {} are changed, the stream needs to be recreated",
+                self.source_table,
+                self.name()
+            )));
+        }
+
+        let field_indices = source_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_))))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let snapshot = fuse_table.snapshot_loc().await?.unwrap_or_default();
+        let blocks = fuse_table
+            .changes_since(ctx, self.offset_snapshot.clone(), field_indices)
+            .await?;
+        Ok((snapshot, blocks))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let stream = Arc::new(self.clone());
+        let consumed = Arc::new(Mutex::new(None));
+        pipeline.add_source(
+            |output| StreamSource::create(ctx.clone(), output, stream.clone(), consumed.clone()),
+            1,
+        )?;
+
+        // Only the statements writing the changes somewhere consume them. The offset is
+        // moved in a statement transaction, so it is committed along with the writes.
+        if self.end_snapshot.is_none()
+            && matches!(
+                ctx.get_query_kind().as_str(),
+                "Insert" | "Replace" | "MergeInto"
+            )
+        {
+            ctx.txn_mgr().lock().begin_statement();
+            let table_info = self.table_info.clone();
+            pipeline.set_on_finished(move |may_error| {
+                if may_error.is_none() {
+                    if let Some(snapshot) = consumed.lock().take() {
+                        move_offset(ctx.as_ref(), &table_info, snapshot);
+                    }
+                }
+                Ok(())
+            });
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn resolve_navigation_point(&self, table_id: u64) -> Result<NavigationPoint> {
+        if table_id != self.source_table_id {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Stream {} is not on the table, its source table is {}",
+                self.name(),
+                self.source_table
+            )));
+        }
+        let Some(offset) = &self.offset_snapshot else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "The offset of stream {} is before the first snapshot of {}",
+                self.name(),
+                self.source_table
+            )));
+        };
+        // The name of a snapshot file is the id of the snapshot followed by the version suffix.
+        let snapshot_id = offset
+            .rsplit('/')
+            .next()
+            .and_then(|name| name.split(['_', '.']).next())
+            .unwrap_or_default();
+        Ok(NavigationPoint::SnapshotID(snapshot_id.to_string()))
+    }
+}
+
+/// Move the offset of the stream to `snapshot`.
+///
+/// The new offset is buffered in the transaction of the statement. The transaction fails to
+/// commit if the stream is changed since it is read, by another statement consuming it for
+/// example.
+fn move_offset(ctx: &dyn TableContext, table_info: &TableInfo, snapshot: String) {
+    let mut new_table_meta = table_info.meta.clone();
+    new_table_meta
+        .options
+        .insert(OPT_KEY_STREAM_OFFSET_SNAPSHOT.to_string(), snapshot);
+    ctx.txn_mgr()
+        .lock()
+        .update_table_meta(table_info, new_table_meta, &None, None, None);
+}
+
+struct StreamSource {
+    ctx: Arc<dyn TableContext>,
+    stream: Arc<StreamTable>,
+    consumed: Arc<Mutex<Option<String>>>,
+    blocks: Option<VecDeque<DataBlock>>,
+}
+
+impl StreamSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        stream: Arc<StreamTable>,
+        consumed: Arc<Mutex<Option<String>>>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, StreamSource {
+            ctx,
+            stream,
+            consumed,
+            blocks: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for StreamSource {
+    const NAME: &'static str = "StreamSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_none() {
+            let (snapshot, blocks) = self.stream.read_changes(self.ctx.clone()).await?;
+            *self.consumed.lock() = Some(snapshot);
+            self.blocks = Some(blocks.into());
+        }
+        Ok(self.blocks.as_mut().and_then(|blocks| blocks.pop_front()))
+    }
+}
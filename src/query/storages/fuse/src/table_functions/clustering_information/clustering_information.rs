@@ -14,7 +14,6 @@
 
 use std::cmp;
 use std::collections::BTreeMap;
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -39,6 +38,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 
 use crate::io::SegmentsIO;
 use crate::sessions::TableContext;
+use crate::statistics::calc_block_depths;
 use crate::FuseTable;
 use crate::Table;
 
@@ -49,12 +49,15 @@ pub struct ClusteringInformation<'a> {
     pub cluster_keys: Vec<RemoteExpr<String>>,
 }
 
-struct ClusteringStatistics {
-    total_block_count: u64,
-    total_constant_block_count: u64,
-    average_overlaps: f64,
-    average_depth: f64,
-    block_depth_histogram: JsonValue,
+pub(crate) struct ClusteringStatistics {
+    pub(crate) total_block_count: u64,
+    pub(crate) total_constant_block_count: u64,
+    pub(crate) average_overlaps: f64,
+    pub(crate) average_depth: f64,
+    pub(crate) block_depth_histogram: JsonValue,
+    /// The blocks without statistics of the cluster key, not counted in the depth.
+    pub(crate) unclustered_block_count: u64,
+    pub(crate) max_depth: u64,
 }
 
 impl Default for ClusteringStatistics {
@@ -65,6 +68,8 @@ impl Default for ClusteringStatistics {
             average_overlaps: 0.0,
             average_depth: 0.0,
             block_depth_histogram: json!({}),
+            unclustered_block_count: 0,
+            max_depth: 0,
         }
     }
 }
@@ -143,98 +148,73 @@ impl<'a> ClusteringInformation<'a> {
                         JsonbValue::from(&info.block_depth_histogram).to_vec(),
                     )),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(Scalar::Number(NumberScalar::UInt64(
+                        info.unclustered_block_count,
+                    ))),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(Scalar::Number(NumberScalar::UInt64(info.max_depth))),
+                ),
             ],
             1,
         ))
     }
 
-    fn get_min_max_stats(&self, block: &BlockMeta) -> Result<(Vec<Scalar>, Vec<Scalar>)> {
-        if self.table.cluster_keys(self.ctx.clone()) != self.cluster_keys
-            || block.cluster_stats.is_none()
-        {
+    pub(crate) fn get_clustering_stats<'b>(
+        &self,
+        blocks: impl Iterator<Item = &'b Arc<BlockMeta>>,
+    ) -> Result<ClusteringStatistics> {
+        if self.table.cluster_keys(self.ctx.clone()) != self.cluster_keys {
             // Todo(zhyass): support manually specifying the cluster key.
             return Err(ErrorCode::Unimplemented("Unimplemented"));
         }
 
-        let cluster_key_id = block.cluster_stats.clone().unwrap().cluster_key_id;
         let default_cluster_key_id = self.table.cluster_key_meta.clone().unwrap().0;
-        if cluster_key_id != default_cluster_key_id {
-            return Err(ErrorCode::Unimplemented("Unimplemented"));
-        }
-
-        let cluster_stats = block.cluster_stats.clone().unwrap();
-        Ok((cluster_stats.min, cluster_stats.max))
-    }
-
-    fn get_clustering_stats<'b>(
-        &self,
-        blocks: impl Iterator<Item = &'b Arc<BlockMeta>>,
-    ) -> Result<ClusteringStatistics> {
-        // Gather all cluster statistics points to a sorted Map.
-        // Key: The cluster statistics points.
-        // Value: 0: The block indexes with key as min value;
-        //        1: The block indexes with key as max value;
-        let mut points_map: BTreeMap<Vec<Scalar>, (Vec<usize>, Vec<usize>)> = BTreeMap::new();
-        let mut total_constant_block_count = 0;
+        let mut ranges = Vec::new();
         let mut total_block_count = 0;
-        for (i, block) in blocks.enumerate() {
-            let (min, max) = self.get_min_max_stats(block.as_ref())?;
-            if min.eq(&max) {
-                total_constant_block_count += 1;
-            }
-
-            points_map
-                .entry(min.clone())
-                .and_modify(|v| v.0.push(i))
-                .or_insert((vec![i], vec![]));
-
-            points_map
-                .entry(max.clone())
-                .and_modify(|v| v.1.push(i))
-                .or_insert((vec![], vec![i]));
+        let mut total_constant_block_count = 0;
+        for block in blocks {
             total_block_count += 1;
-        }
-
-        // calculate overlaps and depth.
-        let mut stats = Vec::new();
-        // key: the block index.
-        // value: (overlaps, depth).
-        let mut unfinished_parts: HashMap<usize, (usize, usize)> = HashMap::new();
-        for (start, end) in points_map.values() {
-            let point_depth = unfinished_parts.len() + start.len();
-
-            for (_, val) in unfinished_parts.iter_mut() {
-                val.0 += start.len();
-                val.1 = cmp::max(val.1, point_depth);
+            match &block.cluster_stats {
+                Some(stats) if stats.cluster_key_id == default_cluster_key_id => {
+                    if stats.min.eq(&stats.max) {
+                        total_constant_block_count += 1;
+                    }
+                    ranges.push((stats.min.clone(), stats.max.clone()));
+                }
+                // The blocks written before the cluster key was set or altered are left out.
+                _ => (),
             }
-
-            start.iter().for_each(|&idx| {
-                unfinished_parts.insert(idx, (point_depth - 1, point_depth));
-            });
-
-            end.iter().for_each(|&idx| {
-                let stat = unfinished_parts.remove(&idx).unwrap();
-                stats.push(stat);
-            });
         }
-        assert_eq!(unfinished_parts.len(), 0);
 
+        let stats = calc_block_depths(&ranges);
         let mut sum_overlap = 0;
         let mut sum_depth = 0;
+        let mut max_depth = 0;
         let length = stats.len();
         let mp = stats
             .into_iter()
             .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
                 sum_overlap += overlap;
                 sum_depth += depth;
+                max_depth = cmp::max(max_depth, depth);
 
                 let bucket = get_buckets(depth);
                 acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
                 acc
             });
         // round the float to 4 decimal places.
-        let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
-        let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
+        let (average_depth, average_overlaps) = if length == 0 {
+            (0.0, 0.0)
+        } else {
+            (
+                (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0,
+                (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0,
+            )
+        };
 
         let objects = mp.iter().fold(
             serde_json::Map::with_capacity(mp.len()),
@@ -248,8 +228,10 @@ impl<'a> ClusteringInformation<'a> {
         Ok(ClusteringStatistics {
             total_block_count,
             total_constant_block_count,
+            unclustered_block_count: total_block_count - length as u64,
             average_overlaps,
             average_depth,
+            max_depth: max_depth as u64,
             block_depth_histogram,
         })
     }
@@ -274,6 +256,11 @@ impl<'a> ClusteringInformation<'a> {
                 TableDataType::Number(NumberDataType::Float64),
             ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
+            TableField::new(
+                "unclustered_block_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("max_depth", TableDataType::Number(NumberDataType::UInt64)),
         ])
     }
 }
@@ -282,7 +269,7 @@ impl<'a> ClusteringInformation<'a> {
 // 1 to 16 with increments of 1.
 // For buckets larger than 16, increments of twice the width of the previous bucket (e.g. 32, 64, 128, …).
 // e.g. If val is 2, the bucket is 2. If val is 18, the bucket is 32.
-fn get_buckets(val: usize) -> u32 {
+pub(crate) fn get_buckets(val: usize) -> u32 {
     let mut val = val as u32;
     if val <= 16 || val & (val - 1) == 0 {
         return val;
@@ -17,6 +17,7 @@ mod clustering_information;
 mod clustering_information_table;
 mod table_args;
 
+pub(crate) use clustering_information::get_buckets;
 pub use clustering_information::ClusteringInformation;
 pub use clustering_information_table::ClusteringInformationTable;
 pub use table_args::*;
@@ -0,0 +1,259 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::NavigationDescriptor;
+use common_catalog::table::NavigationPoint;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use futures_util::TryStreamExt;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+
+/// Result of checking the objects referenced by one snapshot.
+struct SnapshotCheck {
+    snapshot: Arc<TableSnapshot>,
+    missing_segments: u64,
+    missing_blocks: u64,
+    /// Rows of the missing blocks. Rows of missing segments are unknown and not counted.
+    missing_rows: u64,
+}
+
+impl SnapshotCheck {
+    fn is_consistent(&self) -> bool {
+        self.missing_segments == 0 && self.missing_blocks == 0
+    }
+}
+
+/// Detects snapshots which reference missing segments or blocks (e.g. after an
+/// accidental cleanup of the bucket), and optionally rolls the table back to the
+/// latest snapshot whose segments and blocks are all present.
+pub struct FuseAmend<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+    pub database_name: String,
+}
+
+impl<'a> FuseAmend<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable, database_name: String) -> Self {
+        Self {
+            ctx,
+            table,
+            database_name,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn amend(&self, repair: bool) -> Result<DataBlock> {
+        let tbl = self.table;
+        let snapshot_location = match tbl.snapshot_loc().await? {
+            Some(loc) => loc,
+            None => {
+                return Ok(DataBlock::empty_with_schema(Arc::new(
+                    Self::schema().into(),
+                )));
+            }
+        };
+
+        let snapshot_version = TableMetaLocationGenerator::snapshot_version(&snapshot_location);
+        let reader = MetaReaders::table_snapshot_reader(tbl.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            snapshot_location,
+            snapshot_version,
+            tbl.meta_location_generator().clone(),
+        );
+
+        // Objects are shared between snapshots, remember which ones have been checked.
+        let mut checked = HashMap::new();
+        let mut checks = vec![];
+        let mut target = None;
+        while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
+            let check = self.check_snapshot(snapshot, &mut checked).await?;
+            let consistent = check.is_consistent();
+            checks.push(check);
+            if consistent {
+                target = Some(checks.len() - 1);
+                break;
+            }
+        }
+
+        let mut repaired = false;
+        if let Some(target) = target {
+            if repair && target > 0 {
+                let snapshot_id = checks[target].snapshot.snapshot_id.simple().to_string();
+                tracing::warn!(
+                    "fuse_amend: rolling table {}.{} back to snapshot {}",
+                    self.database_name,
+                    tbl.name(),
+                    snapshot_id
+                );
+                tbl.do_revert_to(self.ctx.as_ref(), NavigationDescriptor {
+                    database_name: self.database_name.clone(),
+                    point: NavigationPoint::SnapshotID(snapshot_id),
+                })
+                .await?;
+                repaired = true;
+            }
+        }
+
+        self.to_block(&checks, target, repaired)
+    }
+
+    #[async_backtrace::framed]
+    async fn check_snapshot(
+        &self,
+        snapshot: Arc<TableSnapshot>,
+        checked: &mut HashMap<String, bool>,
+    ) -> Result<SnapshotCheck> {
+        let operator = self.table.get_operator();
+        let mut check = SnapshotCheck {
+            snapshot: snapshot.clone(),
+            missing_segments: 0,
+            missing_blocks: 0,
+            missing_rows: 0,
+        };
+
+        for location in snapshot.segments.iter() {
+            // Do not trust the segment cache here, the object itself must exist.
+            if !operator.is_exist(&location.0).await? {
+                check.missing_segments += 1;
+                continue;
+            }
+            let segment = SegmentsIO::read_segment(
+                operator.clone(),
+                location.clone(),
+                self.table.schema(),
+                false,
+            )
+            .await?;
+            for block in segment.blocks.iter() {
+                let path = &block.location.0;
+                let exists = match checked.get(path) {
+                    Some(exists) => *exists,
+                    None => {
+                        let exists = operator.is_exist(path).await?;
+                        checked.insert(path.clone(), exists);
+                        exists
+                    }
+                };
+                if !exists {
+                    check.missing_blocks += 1;
+                    check.missing_rows += block.row_count;
+                }
+            }
+        }
+        Ok(check)
+    }
+
+    fn to_block(
+        &self,
+        checks: &[SnapshotCheck],
+        target: Option<usize>,
+        repaired: bool,
+    ) -> Result<DataBlock> {
+        let len = checks.len();
+        let target_rows = target.map(|i| checks[i].snapshot.summary.row_count);
+
+        let mut snapshot_ids: Vec<Vec<u8>> = Vec::with_capacity(len);
+        let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut row_count: Vec<u64> = Vec::with_capacity(len);
+        let mut missing_segments: Vec<u64> = Vec::with_capacity(len);
+        let mut missing_blocks: Vec<u64> = Vec::with_capacity(len);
+        let mut missing_rows: Vec<u64> = Vec::with_capacity(len);
+        let mut lost_rows: Vec<Option<u64>> = Vec::with_capacity(len);
+        let mut consistent: Vec<bool> = Vec::with_capacity(len);
+        let mut actions: Vec<Vec<u8>> = Vec::with_capacity(len);
+
+        for (i, check) in checks.iter().enumerate() {
+            let snapshot = &check.snapshot;
+            snapshot_ids.push(snapshot.snapshot_id.simple().to_string().into_bytes());
+            timestamps.push(snapshot.timestamp.map(|dt| dt.timestamp_micros()));
+            row_count.push(snapshot.summary.row_count);
+            missing_segments.push(check.missing_segments);
+            missing_blocks.push(check.missing_blocks);
+            missing_rows.push(check.missing_rows);
+            lost_rows.push(target_rows.map(|rows| snapshot.summary.row_count.saturating_sub(rows)));
+            consistent.push(check.is_consistent());
+
+            let action = match target {
+                // The current snapshot is fine, nothing to do.
+                Some(0) => "none",
+                Some(t) if i < t && repaired => "discarded",
+                Some(t) if i < t => "to be discarded",
+                Some(_) if repaired => "rolled back to",
+                Some(_) => "roll back to",
+                None => "no consistent snapshot found",
+            };
+            actions.push(action.as_bytes().to_vec());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(snapshot_ids),
+            TimestampType::from_opt_data(timestamps),
+            UInt64Type::from_data(row_count),
+            UInt64Type::from_data(missing_segments),
+            UInt64Type::from_data(missing_blocks),
+            UInt64Type::from_data(missing_rows),
+            UInt64Type::from_opt_data(lost_rows),
+            BooleanType::from_data(consistent),
+            StringType::from_data(actions),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("snapshot_id", TableDataType::String),
+            TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "missing_segment_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "missing_block_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "missing_row_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "lost_row_count",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new("consistent", TableDataType::Boolean),
+            TableField::new("action", TableDataType::String),
+        ])
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_expression::Value;
+use jsonb::Value as JsonbValue;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::SegmentsIO;
+use crate::sessions::TableContext;
+use crate::table_functions::clustering_information::get_buckets;
+use crate::table_functions::ClusteringInformation;
+use crate::FuseTable;
+use crate::Table;
+
+const MB: u64 = 1024 * 1024;
+
+/// Summarizes how the data of a fuse table is laid out in the storage, to help
+/// deciding whether the table should be compacted or reclustered.
+pub struct FuseLayout<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseLayout<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
+        Self { ctx, table }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_layout(self) -> Result<DataBlock> {
+        let snapshot_opt = self.table.read_table_snapshot().await?;
+        if let Some(snapshot) = snapshot_opt {
+            return self.to_block(snapshot).await;
+        }
+        Ok(DataBlock::empty_with_schema(Arc::new(
+            FuseLayout::schema().into(),
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn to_block(&self, snapshot: Arc<TableSnapshot>) -> Result<DataBlock> {
+        let segments_io = SegmentsIO::create(
+            self.ctx.clone(),
+            self.table.operator.clone(),
+            self.table.schema(),
+        );
+        let segments = segments_io
+            .read_segments::<Arc<SegmentInfo>>(&snapshot.segments, true)
+            .await?
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        let blocks = segments
+            .iter()
+            .flat_map(|s| s.blocks.iter())
+            .collect::<Vec<_>>();
+
+        let summary = &snapshot.summary;
+        let block_count = blocks.len() as u64;
+        let row_count = blocks.iter().map(|b| b.row_count).sum::<u64>();
+        let file_size = blocks.iter().map(|b| b.file_size).sum::<u64>();
+
+        // Rows per block, bucketed to the next power of two when above 16.
+        let rows_per_block_histogram = histogram(
+            blocks
+                .iter()
+                .map(|b| get_buckets(b.row_count as usize) as u64),
+        );
+        // Compressed size of blocks in MB, bucketed the same way.
+        let block_size_histogram = histogram(
+            blocks
+                .iter()
+                .map(|b| get_buckets(((b.file_size + MB - 1) / MB) as usize) as u64),
+        );
+
+        // Blocks written before the cluster key was altered have no matching
+        // cluster statistics, they are left out of the depth.
+        let clustering = match self.table.cluster_key_str() {
+            Some(plain_cluster_keys) if !blocks.is_empty() => ClusteringInformation::new(
+                self.ctx.clone(),
+                self.table,
+                plain_cluster_keys.clone(),
+                self.table.cluster_keys(self.ctx.clone()),
+            )
+            .get_clustering_stats(blocks.iter().copied())
+            .ok(),
+            _ => None,
+        };
+
+        Ok(DataBlock::new(
+            vec![
+                uint64_entry(snapshot.segments.len() as u64),
+                uint64_entry(block_count),
+                uint64_entry(row_count),
+                float64_entry(ratio(row_count, block_count)),
+                variant_entry(&rows_per_block_histogram),
+                float64_entry(ratio(file_size, block_count)),
+                variant_entry(&block_size_histogram),
+                float64_entry(ratio(
+                    summary.uncompressed_byte_size,
+                    summary.compressed_byte_size,
+                )),
+                variant_entry(&self.column_compression_ratios(&blocks)),
+                variant_entry(&self.column_compressions(&blocks)),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Scalar(
+                        self.table
+                            .cluster_key_str()
+                            .map(|keys| Scalar::String(keys.as_bytes().to_vec()))
+                            .unwrap_or(Scalar::Null),
+                    ),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::Float64).wrap_nullable(),
+                    Value::Scalar(
+                        clustering
+                            .as_ref()
+                            .map(|c| Scalar::Number(NumberScalar::Float64(c.average_depth.into())))
+                            .unwrap_or(Scalar::Null),
+                    ),
+                ),
+                BlockEntry::new(
+                    DataType::Variant.wrap_nullable(),
+                    Value::Scalar(
+                        clustering
+                            .as_ref()
+                            .map(|c| {
+                                Scalar::Variant(JsonbValue::from(&c.block_depth_histogram).to_vec())
+                            })
+                            .unwrap_or(Scalar::Null),
+                    ),
+                ),
+            ],
+            1,
+        ))
+    }
+
+    /// The ratio of the in-memory size to the compressed size of each leaf column.
+    fn column_compression_ratios(&self, blocks: &[&Arc<BlockMeta>]) -> JsonValue {
+        // key: the column id.
+        // value: (in-memory size, compressed size).
+        let mut sizes: HashMap<ColumnId, (u64, u64)> = HashMap::new();
+        for block in blocks {
+            for (column_id, column_meta) in block.col_metas.iter() {
+                let size = sizes.entry(*column_id).or_default();
+                size.1 += column_meta.offset_length().1;
+                if let Some(stats) = block.col_stats.get(column_id) {
+                    size.0 += stats.in_memory_size;
+                }
+            }
+        }
+
+        let objects = self.table.schema().leaf_fields().into_iter().fold(
+            serde_json::Map::new(),
+            |mut acc, field| {
+                if let Some((in_memory_size, compressed_size)) = sizes.get(&field.column_id()) {
+                    acc.insert(
+                        field.name().to_string(),
+                        json!(ratio(*in_memory_size, *compressed_size)),
+                    );
+                }
+                acc
+            },
+        );
+        JsonValue::Object(objects)
+    }
+
+    /// The compressions used by the blocks of each leaf column, comma separated.
+    fn column_compressions(&self, blocks: &[&Arc<BlockMeta>]) -> JsonValue {
+        let mut compressions: HashMap<ColumnId, BTreeSet<&'static str>> = HashMap::new();
+        for block in blocks {
+            for (column_id, column_meta) in block.col_metas.iter() {
+                let compression = match column_meta {
+                    ColumnMeta::Parquet(meta) => meta.compression.unwrap_or(block.compression),
+                    ColumnMeta::Native(_) => block.compression,
+                };
+                compressions
+                    .entry(*column_id)
+                    .or_default()
+                    .insert(compression_name(compression));
+            }
+        }
+
+        let objects = self.table.schema().leaf_fields().into_iter().fold(
+            serde_json::Map::new(),
+            |mut acc, field| {
+                if let Some(names) = compressions.get(&field.column_id()) {
+                    let names = names.iter().copied().collect::<Vec<_>>().join(",");
+                    acc.insert(field.name().to_string(), json!(names));
+                }
+                acc
+            },
+        );
+        JsonValue::Object(objects)
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new(
+                "segment_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("block_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "average_rows_per_block",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("rows_per_block_histogram", TableDataType::Variant),
+            TableField::new(
+                "average_block_size",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("block_size_mb_histogram", TableDataType::Variant),
+            TableField::new(
+                "compression_ratio",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("column_compression_ratios", TableDataType::Variant),
+            TableField::new("column_compressions", TableDataType::Variant),
+            TableField::new("cluster_by_keys", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "average_depth",
+                TableDataType::Number(NumberDataType::Float64).wrap_nullable(),
+            ),
+            TableField::new(
+                "block_depth_histogram",
+                TableDataType::Variant.wrap_nullable(),
+            ),
+        ])
+    }
+}
+
+fn histogram(buckets: impl Iterator<Item = u64>) -> JsonValue {
+    let mp = buckets.fold(BTreeMap::new(), |mut acc, bucket| {
+        acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
+        acc
+    });
+    let objects = mp.iter().fold(
+        serde_json::Map::with_capacity(mp.len()),
+        |mut acc, (bucket, count)| {
+            acc.insert(format!("{:010}", bucket), json!(count));
+            acc
+        },
+    );
+    JsonValue::Object(objects)
+}
+
+// round the float to 4 decimal places.
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    (10000.0 * numerator as f64 / denominator as f64).round() / 10000.0
+}
+
+fn compression_name(compression: Compression) -> &'static str {
+    match compression {
+        Compression::Lz4 | Compression::Lz4Raw => "lz4",
+        Compression::Snappy => "snappy",
+        Compression::Zstd => "zstd",
+        Compression::Gzip => "gzip",
+        Compression::None => "none",
+    }
+}
+
+fn uint64_entry(value: u64) -> BlockEntry {
+    BlockEntry::new(
+        DataType::Number(NumberDataType::UInt64),
+        Value::Scalar(Scalar::Number(NumberScalar::UInt64(value))),
+    )
+}
+
+fn float64_entry(value: f64) -> BlockEntry {
+    BlockEntry::new(
+        DataType::Number(NumberDataType::Float64),
+        Value::Scalar(Scalar::Number(NumberScalar::Float64(value.into()))),
+    )
+}
+
+fn variant_entry(value: &JsonValue) -> BlockEntry {
+    BlockEntry::new(
+        DataType::Variant,
+        Value::Scalar(Scalar::Variant(JsonbValue::from(value).to_vec())),
+    )
+}
@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use futures_util::TryStreamExt;
+use opendal::Metakey;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotHistoryReader;
+use crate::io::TableMetaLocationGenerator;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_BLOCK_PREFIX;
+use crate::FUSE_TBL_SEGMENT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+const STATUS_ORPHAN: &str = "orphan";
+const STATUS_MISSING: &str = "missing";
+
+/// Cross-checks the files stored under the prefix of a fuse table against the files
+/// referenced by the retained snapshots, which are the current snapshot and the
+/// snapshots chained before it.
+///
+/// A stored file referenced by none of them is an orphan, a referenced file which is
+/// not stored is missing.
+pub struct FuseOrphan<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+}
+
+#[derive(Default)]
+struct ReferencedFiles {
+    snapshots: HashSet<String>,
+    segments: HashSet<String>,
+    blocks: HashSet<String>,
+    blooms: HashSet<String>,
+    statistics: HashSet<String>,
+}
+
+impl<'a> FuseOrphan<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
+        Self { ctx, table }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_files(self) -> Result<DataBlock> {
+        let referenced = self.referenced_files().await?;
+
+        let prefix = self.table.meta_location_generator().prefix();
+        let operator = self.table.get_operator();
+
+        // (status, file type, location, size, last modified), ordered for a stable output.
+        let mut files = BTreeSet::new();
+        let mut stored = HashSet::new();
+        let mut lister = operator.scan(&format!("{}/", prefix)).await?;
+        while let Some(entry) = lister.try_next().await? {
+            let meta = operator
+                .metadata(
+                    &entry,
+                    Metakey::Mode | Metakey::ContentLength | Metakey::LastModified,
+                )
+                .await?;
+            if !meta.mode().is_file() {
+                continue;
+            }
+
+            let location = entry.path();
+            stored.insert(location.to_string());
+            let Some(relative) = location
+                .strip_prefix(prefix)
+                .and_then(|path| path.strip_prefix('/'))
+            else {
+                continue;
+            };
+            let Some((dir, rest)) = relative.split_once('/') else {
+                // The last snapshot hint is overwritten in place, it is never an orphan.
+                continue;
+            };
+
+            let (file_type, reachable) = match dir {
+                FUSE_TBL_SNAPSHOT_PREFIX => ("snapshot", referenced.snapshots.contains(location)),
+                FUSE_TBL_SEGMENT_PREFIX => ("segment", referenced.segments.contains(location)),
+                FUSE_TBL_BLOCK_PREFIX => ("block", referenced.blocks.contains(location)),
+                FUSE_TBL_XOR_BLOOM_INDEX_PREFIX => {
+                    ("bloom_index", referenced.blooms.contains(location))
+                }
+                FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX => {
+                    ("table_statistics", referenced.statistics.contains(location))
+                }
+                // Virtual columns and aggregating indexes of a block are written beside the
+                // block, they are reachable as long as the block is.
+                FUSE_TBL_VIRTUAL_BLOCK_PREFIX => (
+                    "virtual_block",
+                    referenced.blocks.contains(&block_location(prefix, rest)),
+                ),
+                FUSE_TBL_AGG_INDEX_PREFIX => match rest.split_once('/') {
+                    Some((_index_id, rest)) => (
+                        "aggregating_index",
+                        referenced.blocks.contains(&block_location(prefix, rest)),
+                    ),
+                    None => continue,
+                },
+                // Dictionaries are maintained with the table metadata, not with snapshots.
+                _ => continue,
+            };
+            if !reachable {
+                files.insert((
+                    STATUS_ORPHAN,
+                    file_type,
+                    location.to_string(),
+                    Some(meta.content_length()),
+                    meta.last_modified().map(|t| t.timestamp_micros()),
+                ));
+            }
+        }
+
+        // Files referenced by the retained snapshots but not found in the storage.
+        for (file_type, locations) in [
+            ("segment", referenced.segments),
+            ("block", referenced.blocks),
+            ("bloom_index", referenced.blooms),
+            ("table_statistics", referenced.statistics),
+        ] {
+            for location in locations {
+                if !stored.contains(&location) {
+                    files.insert((STATUS_MISSING, file_type, location, None, None));
+                }
+            }
+        }
+
+        let mut locations = Vec::with_capacity(files.len());
+        let mut file_types = Vec::with_capacity(files.len());
+        let mut statuses = Vec::with_capacity(files.len());
+        let mut file_sizes = Vec::with_capacity(files.len());
+        let mut last_modified = Vec::with_capacity(files.len());
+        for (status, file_type, location, file_size, modified) in files {
+            locations.push(location.into_bytes());
+            file_types.push(file_type.as_bytes().to_vec());
+            statuses.push(status.as_bytes().to_vec());
+            file_sizes.push(file_size);
+            last_modified.push(modified);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(locations),
+            StringType::from_data(file_types),
+            StringType::from_data(statuses),
+            UInt64Type::from_opt_data(file_sizes),
+            TimestampType::from_opt_data(last_modified),
+        ]))
+    }
+
+    /// Collects the files referenced by the retained snapshots. The chain stops at the
+    /// first snapshot which has been purged.
+    #[async_backtrace::framed]
+    async fn referenced_files(&self) -> Result<ReferencedFiles> {
+        let mut referenced = ReferencedFiles::default();
+        let Some(root_snapshot) = self.table.snapshot_loc().await? else {
+            return Ok(referenced);
+        };
+
+        let location_generator = self.table.meta_location_generator().clone();
+        let format_version = TableMetaLocationGenerator::snapshot_version(root_snapshot.as_str());
+        let reader = MetaReaders::table_snapshot_reader(self.table.get_operator());
+        let mut snapshots =
+            reader.snapshot_history(root_snapshot, format_version, location_generator.clone());
+
+        let mut segments: HashSet<Location> = HashSet::new();
+        while let Some((snapshot, ver)) = snapshots.try_next().await? {
+            referenced.snapshots.insert(
+                location_generator.snapshot_location_from_uuid(&snapshot.snapshot_id, ver)?,
+            );
+            segments.extend(snapshot.segments.iter().cloned());
+            if let Some(location) = &snapshot.table_statistics_location {
+                referenced.statistics.insert(location.clone());
+            }
+        }
+
+        // Segments which can not be found are skipped here, they are reported as missing.
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let chunk_size = self.ctx.get_settings().get_max_storage_io_requests()? as usize;
+        for chunk in segments.chunks(chunk_size.max(1)) {
+            let locations = self
+                .table
+                .get_block_locations(self.ctx.clone(), chunk, false)
+                .await?;
+            referenced.blocks.extend(locations.block_location);
+            referenced.blooms.extend(locations.bloom_location);
+        }
+        referenced
+            .segments
+            .extend(segments.into_iter().map(|(location, _)| location));
+
+        Ok(referenced)
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("file_location", TableDataType::String),
+            TableField::new("file_type", TableDataType::String),
+            TableField::new("status", TableDataType::String),
+            TableField::new(
+                "file_size",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new("last_modified", TableDataType::Timestamp.wrap_nullable()),
+        ])
+    }
+}
+
+fn block_location(prefix: &str, file_name: &str) -> String {
+    format!("{}/{}/{}", prefix, FUSE_TBL_BLOCK_PREFIX, file_name)
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -59,23 +60,44 @@ impl<'a> FuseStatistic<'a> {
         table_statistics: &Option<Arc<TableSnapshotStatistics>>,
     ) -> Result<DataBlock> {
         let mut col_ndvs: Vec<Vec<u8>> = Vec::with_capacity(1);
+        let mut col_histograms: Vec<Vec<u8>> = Vec::with_capacity(1);
         if let Some(table_statistics) = table_statistics {
-            let mut ndvs: String = "".to_string();
-            for (i, n) in table_statistics.column_distinct_values.iter() {
-                ndvs.push_str(&format!("({},{});", *i, *n));
+            let ndvs = table_statistics
+                .column_distinct_values
+                .iter()
+                .collect::<BTreeMap<_, _>>();
+            let mut ndvs_str: String = "".to_string();
+            for (i, n) in ndvs {
+                ndvs_str.push_str(&format!("({},{});", *i, *n));
             }
-            col_ndvs.push(ndvs.into_bytes());
+            col_ndvs.push(ndvs_str.into_bytes());
+
+            // Only the upper bounds of the buckets are shown.
+            let histograms = table_statistics
+                .column_histograms
+                .iter()
+                .collect::<BTreeMap<_, _>>();
+            let mut histograms_str: String = "".to_string();
+            for (i, buckets) in histograms {
+                let upper_bounds = buckets
+                    .iter()
+                    .map(|bucket| bucket.upper_bound.to_string())
+                    .collect::<Vec<_>>();
+                histograms_str.push_str(&format!("({},[{}]);", *i, upper_bounds.join(",")));
+            }
+            col_histograms.push(histograms_str.into_bytes());
         };
 
-        Ok(DataBlock::new_from_columns(vec![StringType::from_data(
-            col_ndvs,
-        )]))
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(col_ndvs),
+            StringType::from_data(col_histograms),
+        ]))
     }
 
     pub fn schema() -> Arc<TableSchema> {
-        TableSchemaRefExt::create(vec![TableField::new(
-            "column_distinct_values",
-            TableDataType::String,
-        )])
+        TableSchemaRefExt::create(vec![
+            TableField::new("column_distinct_values", TableDataType::String),
+            TableField::new("column_histograms", TableDataType::String),
+        ])
     }
 }
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod clustering_information;
+mod fuse_amend;
 mod fuse_blocks;
+mod fuse_layout;
+mod fuse_orphans;
 mod fuse_segments;
 mod fuse_snapshots;
 mod fuse_statistics;
@@ -25,8 +28,12 @@ pub use clustering_information::ClusteringInformation;
 pub use clustering_information::ClusteringInformationTable;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
+pub use fuse_amend::FuseAmend;
+pub use fuse_amend::FuseAmendTable;
 pub use fuse_blocks::FuseBlock;
 pub use fuse_blocks::FuseBlockTable;
+pub use fuse_layout::FuseLayoutTable;
+pub use fuse_orphans::FuseOrphanTable;
 pub use fuse_segments::FuseSegment;
 pub use fuse_segments::FuseSegmentTable;
 pub use fuse_snapshots::FuseSnapshot;
@@ -34,3 +41,4 @@ pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
 pub use table_args::string_literal;
 pub use table_args::string_value;
+pub use table_args::u64_value;
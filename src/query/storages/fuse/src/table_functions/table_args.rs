@@ -14,6 +14,7 @@
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberScalar;
 use common_expression::Scalar;
 
 pub fn string_value(value: &Scalar) -> Result<String> {
@@ -24,6 +25,21 @@ pub fn string_value(value: &Scalar) -> Result<String> {
     }
 }
 
+pub fn u64_value(value: &Scalar) -> Result<u64> {
+    let v = match value {
+        Scalar::Number(NumberScalar::UInt8(v)) => Some(*v as u64),
+        Scalar::Number(NumberScalar::UInt16(v)) => Some(*v as u64),
+        Scalar::Number(NumberScalar::UInt32(v)) => Some(*v as u64),
+        Scalar::Number(NumberScalar::UInt64(v)) => Some(*v),
+        Scalar::Number(NumberScalar::Int8(v)) => u64::try_from(*v).ok(),
+        Scalar::Number(NumberScalar::Int16(v)) => u64::try_from(*v).ok(),
+        Scalar::Number(NumberScalar::Int32(v)) => u64::try_from(*v).ok(),
+        Scalar::Number(NumberScalar::Int64(v)) => u64::try_from(*v).ok(),
+        _ => None,
+    };
+    v.ok_or_else(|| ErrorCode::BadArguments(format!("invalid unsigned integer: {}", value)))
+}
+
 pub fn string_literal(val: &str) -> Scalar {
     Scalar::String(val.as_bytes().to_vec())
 }
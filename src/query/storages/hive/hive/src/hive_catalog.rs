@@ -141,6 +141,35 @@ impl HiveCatalog {
         Ok(partitions)
     }
 
+    /// the partitions matching the filter, see [`crate::hive_partition_filter::metastore_filter`]
+    #[tracing::instrument(level = "info", skip(self))]
+    #[async_backtrace::framed]
+    pub async fn get_partitions_by_filter(
+        &self,
+        db: String,
+        table: String,
+        filter: String,
+    ) -> Result<Vec<Partition>> {
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            Self::do_get_partitions_by_filter(client, db, table, filter)
+        })
+        .await
+        .unwrap()
+    }
+
+    pub fn do_get_partitions_by_filter(
+        client: impl TThriftHiveMetastoreSyncClient,
+        db: String,
+        table: String,
+        filter: String,
+    ) -> Result<Vec<Partition>> {
+        let mut client = client;
+        client
+            .get_partitions_by_filter(db, table, filter, -1)
+            .map_err(from_thrift_error)
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     #[async_backtrace::framed]
     pub async fn get_partition_names(
@@ -94,7 +94,73 @@ pub fn parse_hive_partitions(partitions: &str) -> HashMap<String, String> {
     let parts = partitions.split('/').collect::<Vec<_>>();
     for part in parts {
         let kv = part.split('=').collect::<Vec<_>>();
-        partition_map.insert(kv[0].to_string(), kv[1].to_string());
+        partition_map.insert(
+            unescape_hive_path_name(kv[0]),
+            unescape_hive_path_name(kv[1]),
+        );
     }
     partition_map
 }
+
+// hive escapes the special characters in partition names like '%2F' for '/'
+pub fn unescape_hive_path_name(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(b) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                unescaped.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
+
+// the inverse of `unescape_hive_path_name`, as `FileUtils.escapePathName` of hive
+pub fn escape_hive_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\u{01}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// partition name like 'c_region=ASIA/c_nation=CHINA' of the partition values
+pub fn hive_partition_name(partition_keys: &[String], values: &[String]) -> String {
+    partition_keys
+        .iter()
+        .zip(values)
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                escape_hive_path_name(key),
+                escape_hive_path_name(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
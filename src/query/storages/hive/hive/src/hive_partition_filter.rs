@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::Expr;
+use common_expression::Scalar;
+
+/// Converts the predicates over partition columns of `expr` to the filter of
+/// `get_partitions_by_filter` of hive metastore, like `c_region = "ASIA" and c_nation <> "CHINA"`.
+///
+/// Only comparisons between string partition columns and string constants are converted,
+/// which the metastore can always push down to the database. The filter may select more
+/// partitions than `expr`, the partitions are pruned by the [`HivePartitionPruner`] later.
+///
+/// [`HivePartitionPruner`]: crate::hive_partition_pruner::HivePartitionPruner
+pub fn metastore_filter(expr: &Expr<String>, partition_keys: &[String]) -> Option<String> {
+    match expr {
+        Expr::FunctionCall { function, args, .. } => {
+            match (function.signature.name.as_str(), args.as_slice()) {
+                ("and" | "and_filters", args) => {
+                    // a conjunct that can't be converted selects all partitions
+                    let filters = args
+                        .iter()
+                        .filter_map(|arg| metastore_filter(arg, partition_keys))
+                        .collect::<Vec<_>>();
+                    match filters.len() {
+                        0 => None,
+                        1 => filters.into_iter().next(),
+                        _ => Some(format!("({})", filters.join(") and ("))),
+                    }
+                }
+                ("or", [lhs, rhs]) => {
+                    let lhs = metastore_filter(lhs, partition_keys)?;
+                    let rhs = metastore_filter(rhs, partition_keys)?;
+                    Some(format!("({lhs}) or ({rhs})"))
+                }
+                (name, [lhs, rhs]) => {
+                    let op = comparison_op(name)?;
+                    match (string_column(lhs, partition_keys), string_constant(rhs)) {
+                        (Some(column), Some(value)) => Some(format!("{column} {op} {value}")),
+                        _ => {
+                            let column = string_column(rhs, partition_keys)?;
+                            let value = string_constant(lhs)?;
+                            Some(format!("{column} {} {value}", flip_comparison_op(op)))
+                        }
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn comparison_op(name: &str) -> Option<&'static str> {
+    match name {
+        "eq" => Some("="),
+        "noteq" => Some("<>"),
+        "lt" => Some("<"),
+        "lte" => Some("<="),
+        "gt" => Some(">"),
+        "gte" => Some(">="),
+        _ => None,
+    }
+}
+
+fn flip_comparison_op(op: &'static str) -> &'static str {
+    match op {
+        "<" => ">",
+        "<=" => ">=",
+        ">" => "<",
+        ">=" => "<=",
+        op => op,
+    }
+}
+
+fn is_string(data_type: &DataType) -> bool {
+    data_type.remove_nullable() == DataType::String
+}
+
+fn string_column<'a>(expr: &'a Expr<String>, partition_keys: &[String]) -> Option<&'a str> {
+    match expr {
+        Expr::ColumnRef { id, data_type, .. }
+            if is_string(data_type) && partition_keys.contains(id) =>
+        {
+            Some(id)
+        }
+        Expr::Cast {
+            expr, dest_type, ..
+        } if is_string(dest_type) => string_column(expr, partition_keys),
+        _ => None,
+    }
+}
+
+/// the quoted string, the filter of metastore has no escapes in string literals
+fn string_constant(expr: &Expr<String>) -> Option<String> {
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::String(value),
+            ..
+        } => {
+            let value = String::from_utf8(value.clone()).ok()?;
+            if !value.contains('"') {
+                Some(format!("\"{value}\""))
+            } else if !value.contains('\'') {
+                Some(format!("'{value}'"))
+            } else {
+                None
+            }
+        }
+        Expr::Cast {
+            expr, dest_type, ..
+        } if is_string(dest_type) => string_constant(expr),
+        _ => None,
+    }
+}
@@ -24,6 +24,7 @@ use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::hive_partition::parse_hive_partitions;
 use crate::utils::str_field_to_scalar;
 
 pub struct HivePartitionPruner {
@@ -53,10 +54,11 @@ impl HivePartitionPruner {
         let mut data = Vec::with_capacity(partitions.len());
         for partition in partitions {
             let mut stats = HashMap::new();
-            for (index, singe_value) in partition.split('/').enumerate() {
-                let kv = singe_value.split('=').collect::<Vec<&str>>();
-                let field = self.partition_schema.fields()[index].clone();
-                let scalar = str_field_to_scalar(kv[1], &field.data_type().into())?;
+            // the levels of a multi-level partition are matched to the partition columns by name
+            for (key, value) in parse_hive_partitions(partition) {
+                let index = self.partition_schema.index_of(&key)?;
+                let field = self.partition_schema.field(index);
+                let scalar = str_field_to_scalar(&value, &field.data_type().into())?;
                 let null_count = u64::from(scalar.is_null());
                 let column_stats = ColumnStatistics {
                     min: scalar.clone(),
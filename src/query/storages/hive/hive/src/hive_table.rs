@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
@@ -63,6 +64,8 @@ use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_partition::hive_partition_name;
+use crate::hive_partition_filter::metastore_filter;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
@@ -392,11 +395,43 @@ impl HiveTable {
         let hive_catalog = ctx.get_catalog(CATALOG_HIVE)?;
         let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
 
-        // todo may use get_partition_names_ps to filter
         let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
-        let mut partition_names = hive_catalog
-            .get_partition_names(table_info[0].to_string(), table_info[1].to_string(), -1)
-            .await?;
+        let (db, table) = (table_info[0].to_string(), table_info[1].to_string());
+
+        // partition name -> location, if the partitions are filtered by the metastore
+        let metastore_filter = filter_expression
+            .as_ref()
+            .and_then(|expr| metastore_filter(expr, &partition_keys));
+        let mut locations = None;
+        let mut partition_names = match metastore_filter {
+            Some(filter) => {
+                tracing::debug!("get partitions from hive metastore by filter: {}", filter);
+                let partitions = hive_catalog
+                    .get_partitions_by_filter(db.clone(), table.clone(), filter)
+                    .await?;
+                let mut partition_locations = HashMap::with_capacity(partitions.len());
+                let mut partition_names = Vec::with_capacity(partitions.len());
+                for partition in partitions {
+                    let name =
+                        hive_partition_name(&partition_keys, &partition.values.unwrap_or_default());
+                    let location = partition.sd.and_then(|sd| sd.location).ok_or_else(|| {
+                        ErrorCode::TableInfoError(format!(
+                            "{}, location of partition {} is empty",
+                            self.table_info.name, name
+                        ))
+                    })?;
+                    partition_locations.insert(name.clone(), location);
+                    partition_names.push(name);
+                }
+                locations = Some(partition_locations);
+                partition_names
+            }
+            None => {
+                hive_catalog
+                    .get_partition_names(db.clone(), table.clone(), -1)
+                    .await?
+            }
+        };
 
         if tracing::enabled!(tracing::Level::TRACE) {
             let partition_num = partition_names.len();
@@ -426,18 +461,25 @@ impl HiveTable {
             )
         }
 
-        let partitions = hive_catalog
-            .get_partitions(
-                table_info[0].to_string(),
-                table_info[1].to_string(),
-                partition_names.clone(),
-            )
-            .await?;
-        let res = partitions
-            .into_iter()
-            .map(|p| convert_hdfs_path(&p.sd.unwrap().location.unwrap(), true))
-            .zip(partition_names.into_iter().map(Some))
-            .collect::<Vec<_>>();
+        let res = match locations {
+            Some(mut locations) => partition_names
+                .into_iter()
+                .filter_map(|name| {
+                    let location = locations.remove(&name)?;
+                    Some((convert_hdfs_path(&location, true), Some(name)))
+                })
+                .collect::<Vec<_>>(),
+            None => {
+                let partitions = hive_catalog
+                    .get_partitions(db, table, partition_names.clone())
+                    .await?;
+                partitions
+                    .into_iter()
+                    .map(|p| convert_hdfs_path(&p.sd.unwrap().location.unwrap(), true))
+                    .zip(partition_names.into_iter().map(Some))
+                    .collect::<Vec<_>>()
+            }
+        };
         Ok(res)
     }
 
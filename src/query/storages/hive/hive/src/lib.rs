@@ -25,6 +25,7 @@ mod hive_meta_data_reader;
 mod hive_parquet_block_reader;
 mod hive_partition;
 mod hive_partition_filler;
+mod hive_partition_filter;
 mod hive_partition_pruner;
 mod hive_table;
 mod hive_table_options;
@@ -37,7 +38,11 @@ pub use hive_catalog::HiveCatalog;
 pub use hive_file_splitter::HiveFileSplitter;
 pub use hive_meta_data_reader::MetaDataReader;
 pub use hive_parquet_block_reader::filter_hive_partition_from_partition_keys;
+pub use hive_partition::escape_hive_path_name;
+pub use hive_partition::hive_partition_name;
+pub use hive_partition::unescape_hive_path_name;
 pub use hive_partition::HivePartInfo;
 pub use hive_partition_filler::HivePartitionFiller;
+pub use hive_partition_filter::metastore_filter;
 pub use hive_table::HiveFileInfo;
 pub use hive_table::HiveTable;
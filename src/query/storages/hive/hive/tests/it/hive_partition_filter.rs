@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::type_check::check;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::RawExpr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_storages_hive::hive_partition_name;
+use common_storages_hive::metastore_filter;
+use common_storages_hive::unescape_hive_path_name;
+
+fn column(name: &str, data_type: DataType) -> RawExpr<String> {
+    RawExpr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type,
+        display_name: name.to_string(),
+    }
+}
+
+fn string(value: &str) -> RawExpr<String> {
+    RawExpr::Constant {
+        span: None,
+        scalar: Scalar::String(value.as_bytes().to_vec()),
+    }
+}
+
+fn call(name: &str, args: Vec<RawExpr<String>>) -> RawExpr<String> {
+    RawExpr::FunctionCall {
+        span: None,
+        name: name.to_string(),
+        params: vec![],
+        args,
+    }
+}
+
+fn filter(raw: RawExpr<String>) -> Option<String> {
+    let expr = check(&raw, &BUILTIN_FUNCTIONS).unwrap();
+    let partition_keys = vec!["c_region".to_string(), "c_nation".to_string()];
+    metastore_filter(&expr, &partition_keys)
+}
+
+#[test]
+fn test_metastore_filter() {
+    let region = || column("c_region", DataType::String.wrap_nullable());
+    let nation = || column("c_nation", DataType::String);
+    let price = || column("price", DataType::Number(NumberDataType::Int32));
+    let ten = || RawExpr::Constant {
+        span: None,
+        scalar: Scalar::Number(NumberScalar::Int32(10)),
+    };
+
+    assert_eq!(
+        filter(call("eq", vec![region(), string("ASIA")])),
+        Some(r#"c_region = "ASIA""#.to_string())
+    );
+    assert_eq!(
+        filter(call("lt", vec![string("CHINA"), nation()])),
+        Some(r#"c_nation > "CHINA""#.to_string())
+    );
+    assert_eq!(
+        filter(call("and", vec![
+            call("eq", vec![region(), string("ASIA")]),
+            call("noteq", vec![nation(), string(r#"say "hi""#)]),
+        ])),
+        Some(r#"(c_region = "ASIA") and (c_nation <> 'say "hi"')"#.to_string())
+    );
+    // the predicates over other columns are left to the pruner and the filter
+    assert_eq!(
+        filter(call("and", vec![
+            call("gt", vec![price(), ten()]),
+            call("eq", vec![region(), string("ASIA")]),
+        ])),
+        Some(r#"c_region = "ASIA""#.to_string())
+    );
+    assert_eq!(
+        filter(call("or", vec![
+            call("gt", vec![price(), ten()]),
+            call("eq", vec![region(), string("ASIA")]),
+        ])),
+        None
+    );
+    assert_eq!(
+        filter(call("or", vec![
+            call("eq", vec![region(), string("ASIA")]),
+            call("eq", vec![region(), string("EUROPE")]),
+        ])),
+        Some(r#"(c_region = "ASIA") or (c_region = "EUROPE")"#.to_string())
+    );
+    assert_eq!(filter(call("gt", vec![price(), ten()])), None);
+}
+
+#[test]
+fn test_hive_partition_name() {
+    let partition_keys = vec!["c_region".to_string(), "c_nation".to_string()];
+    let name = hive_partition_name(&partition_keys, &["ASIA".to_string(), "A/B=C%".to_string()]);
+    assert_eq!(name, "c_region=ASIA/c_nation=A%2FB%3DC%25");
+    assert_eq!(unescape_hive_path_name("A%2FB%3DC%25"), "A/B=C%");
+    assert_eq!(unescape_hive_path_name("100%"), "100%");
+}
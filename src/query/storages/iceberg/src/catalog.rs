@@ -74,6 +74,7 @@ use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::table::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
 
@@ -211,8 +212,9 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
-    fn get_table_by_info(&self, _table_info: &TableInfo) -> Result<Arc<dyn Table>> {
-        unimplemented!()
+    fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        let table = IcebergTable::from_info(table_info)?;
+        Ok(Arc::new(table))
     }
 
     #[async_backtrace::framed]
@@ -84,7 +84,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
         iceberg_rs::model::schema::AllType::Primitive(p) => match p {
             iceberg_rs::model::schema::PrimitiveType::Boolean => TableDataType::Boolean,
             iceberg_rs::model::schema::PrimitiveType::Int => {
-                TableDataType::Number(NumberDataType::UInt32)
+                TableDataType::Number(NumberDataType::Int32)
             }
             iceberg_rs::model::schema::PrimitiveType::Long => {
                 TableDataType::Number(NumberDataType::Int64)
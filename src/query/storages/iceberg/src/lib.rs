@@ -86,6 +86,20 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## Reading Tables
+//!
+//! Tables are read-only. The data files of the current snapshot are listed from its
+//! manifests, pruned with the partition summaries of the manifests, the identity
+//! partition values and the min/max values of the files, then read as parquet files.
+//!
+//! Older snapshots can be read with time travel:
+//! ```sql
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (SNAPSHOT => '3051729675574597004');
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (TIMESTAMP => '2023-06-01 00:00:00'::TIMESTAMP);
+//! ```
+//!
+//! Tables with delete files and data files in formats other than parquet are not supported yet.
 
 /// the Iceberg Catalog implementation
 mod catalog;
@@ -94,11 +108,11 @@ mod converters;
 /// database implementation
 mod database;
 /// reading manifestlist and manifest files
-#[allow(unused)]
 mod manifest;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// pruning manifests and data files
+mod pruning;
 /// table implementation
 mod table;
 
@@ -12,56 +12,187 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+//! manifest lists and manifests are Avro files,
+//! their records are decoded into json values and then into the structs here.
+//!
+//! Values of bytes, like the bounds of columns, are decoded as hex strings,
+//! and maps with int keys are arrays of key-value records in Avro.
 
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_pipeline_sources::input_formats::AvroFile;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+/// status of a manifest entry whose data file is removed in the snapshot
+const STATUS_DELETED: i32 = 2;
 
 /// item in manifest list file
 /// read manifest file by this struct
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ManifestPtr {
     pub manifest_path: String,
-    pub manifest_length: usize,
-    pub partition_spec_id: usize,
-    pub added_snapshot_id: usize,
-    pub existing_data_files_count: usize,
-    pub deleted_data_files_count: usize,
-    pub partitions: Vec<ManiPart>,
-    pub added_rows_count: usize,
-    pub existing_rows_count: usize,
-    pub deleted_rows_count: usize,
+    #[serde(default)]
+    pub partition_spec_id: i32,
+    /// `0` for data files and `1` for delete files, absent in format version 1
+    #[serde(default)]
+    pub content: i32,
+    /// summaries of the partition fields of all files in the manifest
+    #[serde(default)]
+    pub partitions: Option<Vec<ManiPart>>,
 }
 
 /// item of manifest spec in `ManifestPtr`
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct ManiPart {
     pub contains_null: bool,
-    pub lower_bound: String,
-    pub upper_bound: String,
+    #[serde(default)]
+    pub lower_bound: Option<String>,
+    #[serde(default)]
+    pub upper_bound: Option<String>,
 }
 
-/// manifest file
+/// entry of a manifest file
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct Manifest {
-    pub status: isize,
-    pub snapshot_id: i64,
+    pub status: i32,
     pub data_file: DataFile,
 }
 
 /// data file
 #[derive(Clone, Debug, Deserialize)]
 pub(crate) struct DataFile {
+    /// `0` for data files, others for delete files, absent in format version 1
+    #[serde(default)]
+    pub content: i32,
     pub file_path: String,
     pub file_format: String,
-    pub partition: HashMap<String, u64>,
-    pub record_count: usize,
-    pub file_size_in_bytes: usize,
-    pub block_size_in_bytes: usize,
-    pub column_sizes: HashMap<u64, u64>,
-    pub value_counts: HashMap<u64, u64>,
-    pub null_value_counts: HashMap<u64, u64>,
-    pub lower_bounds: HashMap<u64, String>,
-    pub upper_bounds: HashMap<u64, String>,
-    pub key_metadata: Option<String>,
-    pub split_offsets: Vec<u64>,
+    /// values of the partition fields by their names
+    #[serde(default)]
+    pub partition: Map<String, Value>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    #[serde(default)]
+    pub null_value_counts: Option<Vec<KeyValue<i64>>>,
+    #[serde(default)]
+    pub lower_bounds: Option<Vec<KeyValue<String>>>,
+    #[serde(default)]
+    pub upper_bounds: Option<Vec<KeyValue<String>>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct KeyValue<T> {
+    pub key: i32,
+    pub value: T,
+}
+
+impl DataFile {
+    pub fn null_value_count(&self, field_id: i32) -> Option<i64> {
+        find_value(&self.null_value_counts, field_id).copied()
+    }
+
+    pub fn lower_bound(&self, field_id: i32) -> Option<&str> {
+        find_value(&self.lower_bounds, field_id).map(|v| v.as_str())
+    }
+
+    pub fn upper_bound(&self, field_id: i32) -> Option<&str> {
+        find_value(&self.upper_bounds, field_id).map(|v| v.as_str())
+    }
+}
+
+fn find_value<T>(kvs: &Option<Vec<KeyValue<T>>>, key: i32) -> Option<&T> {
+    kvs.as_ref()?
+        .iter()
+        .find(|kv| kv.key == key)
+        .map(|kv| &kv.value)
+}
+
+/// read the manifests of a snapshot from the manifest list file
+pub(crate) fn read_manifest_list(path: &str, bytes: &[u8]) -> Result<Vec<ManifestPtr>> {
+    read_records(path, bytes)
+}
+
+/// read the live data files from a manifest file
+pub(crate) fn read_data_files(path: &str, bytes: &[u8]) -> Result<Vec<DataFile>> {
+    let entries: Vec<Manifest> = read_records(path, bytes)?;
+    let mut files = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if entry.status == STATUS_DELETED {
+            continue;
+        }
+        if entry.data_file.content != 0 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "reading iceberg tables with delete files is not supported yet, found in {path}"
+            )));
+        }
+        files.push(entry.data_file);
+    }
+    Ok(files)
+}
+
+fn read_records<T: DeserializeOwned>(path: &str, bytes: &[u8]) -> Result<Vec<T>> {
+    let mut file = AvroFile::try_create(bytes)
+        .map_err(|e| invalid_manifest(path, e.message()))?
+        .with_binary_as_hex();
+    let schema = file.schema().clone();
+    let mut records = vec![];
+    while let Some(mut block) = file
+        .next_block()
+        .map_err(|e| invalid_manifest(path, e.message()))?
+    {
+        while let Some(value) = block
+            .next_record(&schema)
+            .map_err(|e| invalid_manifest(path, e.message()))?
+        {
+            let record = serde_json::from_value(value)
+                .map_err(|e| invalid_manifest(path, format!("{e:?}")))?;
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+fn invalid_manifest(path: &str, msg: String) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("invalid manifest file {path}: {msg}"))
+}
+
+#[cfg(test)]
+mod manifest_test {
+    use serde_json::json;
+
+    use super::DataFile;
+    use super::Manifest;
+
+    #[test]
+    fn test_decode_manifest_entry() {
+        // a manifest entry as decoded from Avro, with bytes in hex
+        let value = json!({
+            "status": 1,
+            "snapshot_id": 3051729675574597004i64,
+            "data_file": {
+                "file_path": "s3://b/wh/db/t/data/id=1/00000-0.parquet",
+                "file_format": "PARQUET",
+                "partition": {"id": 1},
+                "record_count": 2,
+                "file_size_in_bytes": 1024,
+                "block_size_in_bytes": 67108864,
+                "null_value_counts": [{"key": 1, "value": 0}, {"key": 2, "value": 1}],
+                "lower_bounds": [{"key": 1, "value": "01000000"}],
+                "upper_bounds": [{"key": 1, "value": "02000000"}],
+                "split_offsets": null
+            }
+        });
+        let entry: Manifest = serde_json::from_value(value).unwrap();
+        let file: DataFile = entry.data_file;
+
+        assert_eq!(file.content, 0);
+        assert_eq!(file.partition.get("id"), Some(&json!(1)));
+        assert_eq!(file.null_value_count(2), Some(1));
+        assert_eq!(file.null_value_count(3), None);
+        assert_eq!(file.lower_bound(1), Some("01000000"));
+        assert_eq!(file.upper_bound(1), Some("02000000"));
+        assert_eq!(file.upper_bound(2), None);
+    }
 }
@@ -13,3 +13,138 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+//!
+//! Only the parts needed for reading data files are parsed here:
+//! snapshots, partition specs and the ids of schema fields.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// the metadata json file of a table, both format version 1 and 2
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct MetadataFile {
+    /// the base location of the table,
+    /// all paths of metadata and data files start with it
+    pub location: String,
+    /// `-1` or absent if the table has no snapshots
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// the only partition spec of format version 1, whose id is 0
+    #[serde(default)]
+    pub partition_spec: Vec<PartitionField>,
+    #[serde(default)]
+    pub schemas: Vec<Schema>,
+    /// the only schema of format version 1
+    #[serde(default)]
+    pub schema: Option<Schema>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Snapshot {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+    /// the manifest list file of the snapshot
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    /// the manifest files of the snapshot,
+    /// used by format version 1 tables in place of a manifest list
+    #[serde(default)]
+    pub manifests: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionField {
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct Schema {
+    pub fields: Vec<SchemaField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct SchemaField {
+    pub id: i32,
+    pub name: String,
+    /// a string for primitive types, an object for nested types
+    #[serde(rename = "type")]
+    pub field_type: Value,
+}
+
+impl MetadataFile {
+    pub fn parse(path: &str, bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid metadata in {path}: {e:?}"))
+        })
+    }
+
+    /// the current schema, which is the one the table schema is converted from
+    pub fn current_schema(&self) -> Option<&Schema> {
+        self.schemas.last().or(self.schema.as_ref())
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&[PartitionField]> {
+        if self.partition_specs.is_empty() && spec_id == 0 {
+            return Some(self.partition_spec.as_slice());
+        }
+        self.partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == spec_id)
+            .map(|spec| spec.fields.as_slice())
+    }
+
+    pub fn snapshot(&self, snapshot_id: i64) -> Option<&Snapshot> {
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        match self.current_snapshot_id {
+            Some(id) if id != -1 => self.snapshot(id),
+            _ => None,
+        }
+    }
+
+    /// the latest snapshot committed no later than the time point
+    pub fn snapshot_at(&self, time_point: &DateTime<Utc>) -> Option<&Snapshot> {
+        let millis = time_point.timestamp_millis();
+        self.snapshots
+            .iter()
+            .filter(|snapshot| snapshot.timestamp_ms <= millis)
+            .max_by_key(|snapshot| snapshot.timestamp_ms)
+    }
+
+    /// turns an absolute path in the metadata into the path relative to the table root
+    pub fn relative_path(&self, path: &str) -> Result<String> {
+        let location = self.location.trim_end_matches('/');
+        match path.strip_prefix(location) {
+            Some(relative) if relative.starts_with('/') => {
+                Ok(relative.trim_start_matches('/').to_string())
+            }
+            _ => Err(ErrorCode::ReadTableDataError(format!(
+                "file {path} is not under the table location {location}"
+            ))),
+        }
+    }
+}
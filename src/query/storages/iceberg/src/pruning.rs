@@ -0,0 +1,280 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! prune manifests and data files with the statistics in the manifest list and manifests
+//!
+//! Manifests are pruned with the summaries of identity partition fields,
+//! data files with the min/max values of columns and their identity partition values.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use itertools::Itertools;
+use serde_json::Value;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::manifest::DataFile;
+use crate::manifest::ManiPart;
+use crate::meta_reader::MetadataFile;
+use crate::meta_reader::PartitionField;
+
+/// a column that can be pruned by
+struct PrunableColumn {
+    column_id: ColumnId,
+    /// iceberg primitive type
+    ty: String,
+}
+
+pub(crate) struct IcebergPruner {
+    range_index: RangeIndex,
+    /// prunable columns by iceberg field id
+    columns: HashMap<i32, PrunableColumn>,
+}
+
+impl IcebergPruner {
+    /// returns None if there is no filter to prune with
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        filter: Option<Expr<String>>,
+        schema: TableSchemaRef,
+        metadata: &MetadataFile,
+    ) -> Result<Option<Self>> {
+        let filter = match filter {
+            Some(filter) => filter,
+            None => return Ok(None),
+        };
+        // the table schema is converted from the current schema with fields sorted by id
+        let mut columns = HashMap::new();
+        if let Some(iceberg_schema) = metadata.current_schema() {
+            let iceberg_fields = iceberg_schema.fields.iter().sorted_by_key(|f| f.id);
+            for (iceberg_field, field) in iceberg_fields.zip(schema.fields()) {
+                // nested fields are not used for pruning
+                if let Value::String(ty) = &iceberg_field.field_type {
+                    if &iceberg_field.name == field.name() {
+                        columns.insert(iceberg_field.id, PrunableColumn {
+                            column_id: field.column_id(),
+                            ty: ty.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        let range_index = RangeIndex::try_create(
+            ctx.get_function_context()?,
+            &filter,
+            schema,
+            StatisticsOfColumns::default(),
+        )?;
+        Ok(Some(Self {
+            range_index,
+            columns,
+        }))
+    }
+
+    /// prune a manifest with the summaries of its partition fields
+    pub fn keep_manifest(&self, spec: &[PartitionField], summaries: &[ManiPart]) -> Result<bool> {
+        let mut stats = HashMap::new();
+        for (field, summary) in spec.iter().zip(summaries) {
+            let column = match self.identity_source(field) {
+                Some(column) => column,
+                None => continue,
+            };
+            let bounds = (
+                summary.lower_bound.as_deref(),
+                summary.upper_bound.as_deref(),
+            );
+            let (min, max) = match bounds {
+                (Some(lower), Some(upper)) => {
+                    match (
+                        decode_bound(&column.ty, lower),
+                        decode_bound(&column.ty, upper),
+                    ) {
+                        (Some(min), Some(max)) => (min, max),
+                        _ => continue,
+                    }
+                }
+                // all values are null
+                (None, None) if summary.contains_null => (Scalar::Null, Scalar::Null),
+                _ => continue,
+            };
+            stats.insert(column.column_id, ColumnStatistics {
+                min,
+                max,
+                null_count: u64::from(summary.contains_null),
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+        self.range_index.apply(&stats, |_| false)
+    }
+
+    /// prune a data file with the bounds of its columns and its partition values
+    pub fn keep_data_file(&self, spec: &[PartitionField], file: &DataFile) -> Result<bool> {
+        let mut stats = HashMap::new();
+        for (field_id, column) in &self.columns {
+            let null_count = file.null_value_count(*field_id);
+            let bounds = (file.lower_bound(*field_id), file.upper_bound(*field_id));
+            let (min, max) = match bounds {
+                (Some(lower), Some(upper)) => {
+                    match (
+                        decode_bound(&column.ty, lower),
+                        decode_bound(&column.ty, upper),
+                    ) {
+                        (Some(min), Some(max)) => (min, max),
+                        _ => continue,
+                    }
+                }
+                // all values are null
+                (None, None) if null_count == Some(file.record_count) && file.record_count > 0 => {
+                    (Scalar::Null, Scalar::Null)
+                }
+                _ => continue,
+            };
+            stats.insert(column.column_id, ColumnStatistics {
+                min,
+                max,
+                // assume there are nulls if unknown
+                null_count: null_count.map_or(1, |n| n as u64),
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+
+        // the values of identity partition fields are exact
+        for field in spec {
+            let column = match self.identity_source(field) {
+                Some(column) => column,
+                None => continue,
+            };
+            let scalar = match file.partition.get(&field.name) {
+                Some(Value::Null) => Scalar::Null,
+                Some(value) => match json_to_scalar(&column.ty, value) {
+                    Some(scalar) => scalar,
+                    None => continue,
+                },
+                None => continue,
+            };
+            let null_count = if scalar.is_null() {
+                file.record_count as u64
+            } else {
+                0
+            };
+            stats.insert(column.column_id, ColumnStatistics {
+                min: scalar.clone(),
+                max: scalar,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+
+        self.range_index.apply(&stats, |_| false)
+    }
+
+    fn identity_source(&self, field: &PartitionField) -> Option<&PrunableColumn> {
+        if field.transform != "identity" {
+            return None;
+        }
+        self.columns.get(&field.source_id)
+    }
+}
+
+/// decode a bound in the binary single-value serialization of iceberg
+///
+/// Floating point types are not used for pruning, as NaN is not covered by their bounds.
+fn decode_bound(ty: &str, hex_bytes: &str) -> Option<Scalar> {
+    let bytes = hex::decode(hex_bytes).ok()?;
+    Some(match ty {
+        "boolean" => Scalar::Boolean(*bytes.first()? != 0),
+        "int" => Scalar::Number(NumberScalar::Int32(i32::from_le_bytes(
+            bytes.try_into().ok()?,
+        ))),
+        "long" => Scalar::Number(NumberScalar::Int64(i64::from_le_bytes(
+            bytes.try_into().ok()?,
+        ))),
+        "date" => Scalar::Date(i32::from_le_bytes(bytes.try_into().ok()?)),
+        "timestamp" | "timestamptz" => {
+            Scalar::Timestamp(i64::from_le_bytes(bytes.try_into().ok()?))
+        }
+        "string" => Scalar::String(String::from_utf8(bytes).ok()?.into_bytes()),
+        _ => return None,
+    })
+}
+
+/// convert a partition value decoded from Avro
+fn json_to_scalar(ty: &str, value: &Value) -> Option<Scalar> {
+    Some(match ty {
+        "boolean" => Scalar::Boolean(value.as_bool()?),
+        "int" => Scalar::Number(NumberScalar::Int32(i32::try_from(value.as_i64()?).ok()?)),
+        "long" => Scalar::Number(NumberScalar::Int64(value.as_i64()?)),
+        "date" => Scalar::Date(i32::try_from(value.as_i64()?).ok()?),
+        "timestamp" | "timestamptz" => Scalar::Timestamp(value.as_i64()?),
+        "string" => Scalar::String(value.as_str()?.as_bytes().to_vec()),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod pruning_test {
+    use common_expression::types::NumberScalar;
+    use common_expression::Scalar;
+    use serde_json::json;
+
+    use super::decode_bound;
+    use super::json_to_scalar;
+
+    #[test]
+    fn test_decode_bound() {
+        assert_eq!(
+            decode_bound("int", "feffffff"),
+            Some(Scalar::Number(NumberScalar::Int32(-2)))
+        );
+        assert_eq!(
+            decode_bound("long", "0100000000000000"),
+            Some(Scalar::Number(NumberScalar::Int64(1)))
+        );
+        assert_eq!(decode_bound("date", "b04c0000"), Some(Scalar::Date(19632)));
+        assert_eq!(
+            decode_bound("string", "696365626572"),
+            Some(Scalar::String(b"iceber".to_vec()))
+        );
+        assert_eq!(decode_bound("boolean", "01"), Some(Scalar::Boolean(true)));
+        // wrong length
+        assert_eq!(decode_bound("int", "0100"), None);
+        // not used for pruning
+        assert_eq!(decode_bound("double", "000000000000f03f"), None);
+    }
+
+    #[test]
+    fn test_partition_value() {
+        assert_eq!(
+            json_to_scalar("int", &json!(3)),
+            Some(Scalar::Number(NumberScalar::Int32(3)))
+        );
+        assert_eq!(
+            json_to_scalar("string", &json!("a")),
+            Some(Scalar::String(b"a".to_vec()))
+        );
+        assert_eq!(json_to_scalar("int", &json!("a")), None);
+    }
+}
@@ -12,48 +12,63 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! 2022-11-25:
-//! TODO: support synchronize with remote
-//! Note:
-//! currently, we only care about immutable tables
-//! once the table created we don't update it.
-
 use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::ParquetTableInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
 use common_storage::DataOperator;
+use common_storage::StageFileInfo;
+use common_storage::StageFileStatus;
+use common_storage::StageFilesInfo;
+use common_storages_parquet::ParquetTable;
 use futures::StreamExt;
 use iceberg_rs::model::table::TableMetadata;
 use opendal::Operator;
 
 use crate::converters::meta_iceberg_to_databend;
+use crate::manifest::read_data_files;
+use crate::manifest::read_manifest_list;
+use crate::manifest::DataFile;
+use crate::meta_reader::MetadataFile;
+use crate::meta_reader::Snapshot;
+use crate::pruning::IcebergPruner;
 
-/// file marking the current version of metadata file
 const META_PTR: &str = "metadata/version_hint.text";
 
-/// accessor wrapper as a table
-#[allow(unused)]
+/// A read-only iceberg table.
+///
+/// Data files are listed from the manifests of a snapshot and pruned with
+/// their statistics, then read as parquet files.
+#[derive(Clone)]
 pub struct IcebergTable {
-    /// database that belongs to
-    database: String,
-    /// name of the current table
-    name: String,
-    /// root of the table
-    tbl_root: DataOperator,
-    /// table metadata
-    manifests: TableMetadata,
+    /// operator pointing to the root of the table
+    op: Operator,
     /// table information
     info: TableInfo,
+    /// parsed table metadata, read lazily if the table is built from table info
+    metadata: Option<Arc<MetadataFile>>,
+    /// the snapshot to read, the current snapshot if absent
+    snapshot_id: Option<i64>,
 }
 
 impl IcebergTable {
@@ -82,6 +97,7 @@ impl IcebergTable {
                     &latest_manifest, e
                 ))
             })?;
+        let metadata_file = MetadataFile::parse(&latest_manifest, &meta_json)?;
 
         let sp = tbl_root.params();
 
@@ -96,11 +112,26 @@ impl IcebergTable {
 
         // finish making table
         Ok(Self {
-            database: database.to_string(),
-            name: table_name.to_string(),
-            tbl_root,
-            manifests: metadata,
+            op,
             info,
+            metadata: Some(Arc::new(metadata_file)),
+            snapshot_id: None,
+        })
+    }
+
+    /// create a table from the table info, the metadata will be read when needed
+    pub fn from_info(info: &TableInfo) -> Result<IcebergTable> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "missing storage params of iceberg table {}",
+                info.name
+            ))
+        })?;
+        Ok(Self {
+            op: init_operator(sp)?,
+            info: info.clone(),
+            metadata: None,
+            snapshot_id: None,
         })
     }
 
@@ -145,6 +176,113 @@ impl IcebergTable {
             .map(|s| format!("metadata/{s}"))
             .ok_or_else(|| ErrorCode::ReadTableDataError("Cannot get the latest manifest file"))
     }
+
+    #[async_backtrace::framed]
+    async fn load_metadata(&self) -> Result<Arc<MetadataFile>> {
+        if let Some(metadata) = &self.metadata {
+            return Ok(metadata.clone());
+        }
+        let path = Self::version_detect(&self.op).await?;
+        let bytes = self.op.read(&path).await?;
+        Ok(Arc::new(MetadataFile::parse(&path, &bytes)?))
+    }
+
+    /// the table reading the given snapshot
+    fn with_snapshot(&self, metadata: Arc<MetadataFile>, snapshot: &Snapshot) -> IcebergTable {
+        IcebergTable {
+            metadata: Some(metadata),
+            snapshot_id: Some(snapshot.snapshot_id),
+            ..self.clone()
+        }
+    }
+
+    /// list the live data files of a snapshot, pruned by the pruner
+    #[async_backtrace::framed]
+    async fn list_data_files(
+        &self,
+        metadata: &MetadataFile,
+        snapshot: &Snapshot,
+        pruner: Option<&IcebergPruner>,
+    ) -> Result<Vec<DataFile>> {
+        // the manifests to read, with the ids of their partition specs
+        let mut manifests = vec![];
+        match &snapshot.manifest_list {
+            Some(manifest_list) => {
+                let path = metadata.relative_path(manifest_list)?;
+                let bytes = self.op.read(&path).await?;
+                for ptr in read_manifest_list(&path, &bytes)? {
+                    if ptr.content != 0 {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "reading iceberg tables with delete files is not supported yet, \
+                            found in {manifest_list}"
+                        )));
+                    }
+                    if let (Some(pruner), Some(spec), Some(summaries)) = (
+                        pruner,
+                        metadata.partition_spec(ptr.partition_spec_id),
+                        &ptr.partitions,
+                    ) {
+                        if !pruner.keep_manifest(spec, summaries)? {
+                            continue;
+                        }
+                    }
+                    manifests.push((ptr.manifest_path, ptr.partition_spec_id));
+                }
+            }
+            // format version 1 tables may list manifests in the snapshot
+            None => {
+                for manifest in &snapshot.manifests {
+                    manifests.push((manifest.clone(), 0));
+                }
+            }
+        }
+
+        let reads = manifests.iter().map(|(manifest, _)| async move {
+            let path = metadata.relative_path(manifest)?;
+            let bytes = self.op.read(&path).await?;
+            read_data_files(&path, &bytes)
+        });
+        let manifest_files = futures::future::try_join_all(reads).await?;
+
+        let mut data_files = vec![];
+        for ((_, spec_id), files) in manifests.iter().zip(manifest_files) {
+            let spec = metadata.partition_spec(*spec_id).unwrap_or_default();
+            for file in files {
+                if !file.file_format.eq_ignore_ascii_case("parquet") {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "reading iceberg data files in {} is not supported yet",
+                        file.file_format
+                    )));
+                }
+                if let Some(pruner) = pruner {
+                    if !pruner.keep_data_file(spec, &file)? {
+                        continue;
+                    }
+                }
+                data_files.push(file);
+            }
+        }
+        Ok(data_files)
+    }
+
+    /// data files are read by the parquet table, as columns in the schema order
+    fn parquet_table_info(&self, files_to_read: Option<Vec<StageFileInfo>>) -> ParquetTableInfo {
+        let storage_params = self.info.meta.storage_params.clone().unwrap_or_default();
+        let mut stage_info = StageInfo::new_external_stage(storage_params, "/");
+        stage_info.stage_name = self.info.desc.clone();
+        ParquetTableInfo {
+            read_options: ParquetReadOptions::default(),
+            stage_info,
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: None,
+            },
+            table_info: self.info.clone(),
+            arrow_schema: self.info.schema().to_arrow(),
+            files_to_read,
+        }
+    }
 }
 
 #[async_trait]
@@ -165,12 +303,118 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    /// the partitions are read by the parquet table, see [`IcebergTable::read_partitions`]
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::ParquetSource(self.parquet_table_info(None))
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        let metadata = self.load_metadata().await?;
+        let snapshot = match self.snapshot_id {
+            Some(snapshot_id) => metadata.snapshot(snapshot_id).ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "snapshot {snapshot_id} of iceberg table {} not found",
+                    self.name()
+                ))
+            })?,
+            None => match metadata.current_snapshot() {
+                Some(snapshot) => snapshot,
+                // the table is empty
+                None => return Ok((PartStatistics::default(), Partitions::default())),
+            },
+        };
+
+        let filter = push_downs
+            .as_ref()
+            .and_then(|p| p.filter.as_ref())
+            .map(|f| f.as_expr(&BUILTIN_FUNCTIONS));
+        let pruner = IcebergPruner::try_create(ctx.clone(), filter, self.info.schema(), &metadata)?;
+        let data_files = self
+            .list_data_files(&metadata, snapshot, pruner.as_ref())
+            .await?;
+        if data_files.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
+        let files_to_read = data_files
+            .iter()
+            .map(|file| {
+                Ok(StageFileInfo {
+                    path: metadata.relative_path(&file.file_path)?,
+                    size: file.file_size_in_bytes as u64,
+                    md5: None,
+                    last_modified: DateTime::<Utc>::default(),
+                    etag: None,
+                    status: StageFileStatus::NeedCopy,
+                    creator: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let parquet_table = ParquetTable::from_info(&self.parquet_table_info(Some(files_to_read)))?;
+        let (mut stats, parts) = parquet_table.read_partitions(ctx, push_downs).await?;
+        stats.snapshot = Some(snapshot.snapshot_id.to_string());
+        Ok((stats, parts))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parquet_table = ParquetTable::from_info(&self.parquet_table_info(None))?;
+        parquet_table.read_data(ctx, plan, pipeline)
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, instant: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let metadata = self.load_metadata().await?;
+        let snapshot = match instant {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let snapshot_id = snapshot_id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "invalid snapshot id of iceberg table: {snapshot_id}"
+                    ))
+                })?;
+                metadata.snapshot(snapshot_id)
+            }
+            NavigationPoint::TimePoint(time_point) => metadata.snapshot_at(time_point),
+        };
+        match snapshot {
+            Some(snapshot) => Ok(Arc::new(self.with_snapshot(metadata.clone(), snapshot))),
+            None => Err(ErrorCode::TableHistoricalDataNotFound(
+                "No historical data found at given point",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn pin_snapshot(
+        &self,
+        time_point: DateTime<Utc>,
+    ) -> Result<Option<(Arc<dyn Table>, String)>> {
+        let metadata = self.load_metadata().await?;
+        let current = match metadata.current_snapshot() {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        // the current snapshot if the table is created after `time_point`,
+        // e.g. the clocks are skewed
+        let snapshot = if current.timestamp_ms <= time_point.timestamp_millis() {
+            current
+        } else {
+            metadata.snapshot_at(&time_point).unwrap_or(current)
+        };
+        let table = self.with_snapshot(metadata.clone(), snapshot);
+        Ok(Some((Arc::new(table), snapshot.snapshot_id.to_string())))
     }
 }
@@ -46,6 +46,7 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 
+use crate::hive_partition::PartitionColumnFiller;
 use crate::parquet_part::ParquetPart;
 use crate::parquet_part::ParquetRowGroupPart;
 use crate::parquet_part::ParquetSmallFilesPart;
@@ -86,6 +87,9 @@ pub struct ParquetDeserializeTransform {
     // Used for reading from small files
     source_reader: Arc<ParquetReader>,
     partition_pruner: PartitionPruner,
+
+    // Used for filling the hive partition columns
+    partition_filler: Option<PartitionColumnFiller>,
 }
 
 impl ParquetDeserializeTransform {
@@ -100,6 +104,7 @@ impl ParquetDeserializeTransform {
         source_reader: Arc<ParquetReader>,
         remain_reader: Arc<ParquetReader>,
         partition_pruner: PartitionPruner,
+        partition_filler: Option<PartitionColumnFiller>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
 
@@ -119,10 +124,18 @@ impl ParquetDeserializeTransform {
                 source_reader,
                 remain_reader,
                 partition_pruner,
+                partition_filler,
             },
         )))
     }
 
+    fn fill_partition_columns(&self, data_block: DataBlock, path: &str) -> Result<DataBlock> {
+        match &self.partition_filler {
+            Some(filler) => filler.fill(data_block, path),
+            None => Ok(data_block),
+        }
+    }
+
     fn add_block(&mut self, data_block: DataBlock) -> Result<()> {
         let rows = data_block.num_rows();
         if rows == 0 {
@@ -172,7 +185,7 @@ impl ParquetDeserializeTransform {
                 .source_reader
                 .row_group_readers_from_blocking_io(&part, &blocking_op)?;
             if let Some(block) = self.process_row_group(&part, &mut readers)? {
-                res.push(block)
+                res.push(self.fill_partition_columns(block, path)?)
             }
         }
         Ok(res)
@@ -365,6 +378,7 @@ impl Processor for ParquetDeserializeTransform {
             match (&part, data) {
                 (ParquetPart::RowGroup(rg), ParquetPartData::RowGroup(mut reader)) => {
                     if let Some(block) = self.process_row_group(rg, &mut reader)? {
+                        let block = self.fill_partition_columns(block, &rg.location)?;
                         self.add_block(block)?;
                     }
                 }
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_catalog::plan::Projection;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::Value;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// The directory name of the partition whose value is null, like `year=__HIVE_DEFAULT_PARTITION__`.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The partition values in the hive-style path of a file, like `year=2023/month=01/data.parquet`.
+///
+/// The keys are in lower case, a value is `None` if it's the hive default partition.
+pub fn hive_partition_values(path: &str) -> Vec<(String, Option<String>)> {
+    let mut dirs = path.split('/').collect::<Vec<_>>();
+    // the file name
+    dirs.pop();
+    dirs.into_iter()
+        .filter_map(|dir| dir.split_once('='))
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, value)| {
+            let value = (value != HIVE_DEFAULT_PARTITION).then(|| value.to_string());
+            (key.to_lowercase(), value)
+        })
+        .collect()
+}
+
+/// The type of a partition column with the values, BIGINT if all of them are integers,
+/// otherwise VARCHAR. The partition columns are always nullable.
+pub fn infer_partition_type<'a>(values: impl Iterator<Item = Option<&'a str>>) -> TableDataType {
+    let mut values = values.flatten().peekable();
+    let is_int = values.peek().is_some() && values.all(|v| v.parse::<i64>().is_ok());
+    let data_type = if is_int {
+        TableDataType::Number(NumberDataType::Int64)
+    } else {
+        TableDataType::String
+    };
+    TableDataType::Nullable(Box::new(data_type))
+}
+
+/// The value of the partition column, null if it's not a valid value of the type.
+fn partition_value_to_scalar(value: Option<&str>, data_type: &TableDataType) -> Scalar {
+    match (value, data_type.remove_nullable()) {
+        (Some(v), TableDataType::Number(NumberDataType::Int64)) => v
+            .parse::<i64>()
+            .map(|v| Scalar::Number(NumberScalar::Int64(v)))
+            .unwrap_or(Scalar::Null),
+        (Some(v), TableDataType::String) => Scalar::String(v.as_bytes().to_vec()),
+        _ => Scalar::Null,
+    }
+}
+
+fn partition_value<'a>(
+    values: &'a [(String, Option<String>)],
+    field: &TableField,
+) -> Option<&'a str> {
+    values
+        .iter()
+        .find(|(key, _)| key == field.name())
+        .and_then(|(_, value)| value.as_deref())
+}
+
+/// The statistics of the partition columns of a file, used to prune the files by the filter.
+pub fn partition_statistics(path: &str, fields: &[TableField]) -> StatisticsOfColumns {
+    let values = hive_partition_values(path);
+    fields
+        .iter()
+        .map(|field| {
+            let value =
+                partition_value_to_scalar(partition_value(&values, field), field.data_type());
+            let null_count = u64::from(value.is_null());
+            (field.column_id(), ColumnStatistics {
+                min: value.clone(),
+                max: value,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: Some(1),
+            })
+        })
+        .collect()
+}
+
+/// Splits the projection of a table into the projection of the columns in the files and the
+/// indices of the partition columns, which are the fields from `num_file_fields` on.
+pub fn split_projection(
+    projection: &Projection,
+    num_file_fields: usize,
+) -> (Projection, Vec<FieldIndex>) {
+    match projection {
+        Projection::Columns(indices) => {
+            let (partitions, files) = indices.iter().partition(|i| **i >= num_file_fields);
+            (Projection::Columns(files), partitions)
+        }
+        Projection::InnerColumns(path_indices) => {
+            let mut files = BTreeMap::new();
+            let mut partitions = vec![];
+            for (index, path) in path_indices.iter() {
+                if path[0] >= num_file_fields {
+                    partitions.push(path[0]);
+                } else {
+                    files.insert(*index, path.clone());
+                }
+            }
+            (Projection::InnerColumns(files), partitions)
+        }
+    }
+}
+
+/// Appends the projected partition columns to the blocks read from a file, with the values
+/// in the path of the file.
+#[derive(Clone)]
+pub struct PartitionColumnFiller {
+    fields: Vec<TableField>,
+    /// the columns read from the files followed by the partition columns
+    src_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+}
+
+impl PartitionColumnFiller {
+    pub fn create(
+        fields: Vec<TableField>,
+        src_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Self {
+        PartitionColumnFiller {
+            fields,
+            src_schema,
+            output_schema,
+        }
+    }
+
+    pub fn fill(&self, mut block: DataBlock, path: &str) -> Result<DataBlock> {
+        let values = hive_partition_values(path);
+        for field in self.fields.iter() {
+            let value =
+                partition_value_to_scalar(partition_value(&values, field), field.data_type());
+            block.add_column(BlockEntry::new(
+                field.data_type().into(),
+                Value::Scalar(value),
+            ));
+        }
+        block.resort(&self.src_schema, &self.output_schema)
+    }
+}
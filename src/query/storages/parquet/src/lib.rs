@@ -16,6 +16,7 @@
 #![deny(unused_crate_dependencies)]
 
 mod deserialize_transform;
+mod hive_partition;
 mod parquet_part;
 mod parquet_reader;
 mod parquet_source;
@@ -23,4 +24,6 @@ mod parquet_table;
 mod pruning;
 mod statistics;
 
+pub use hive_partition::hive_partition_values;
+pub use hive_partition::infer_partition_type;
 pub use parquet_table::ParquetTable;
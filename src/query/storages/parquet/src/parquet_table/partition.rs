@@ -26,6 +26,8 @@ use storages_common_index::RangeIndex;
 use storages_common_pruner::RangePrunerCreator;
 
 use super::table::arrow_to_table_schema;
+use crate::hive_partition::partition_statistics;
+use crate::hive_partition::split_projection;
 use crate::parquet_reader::ParquetReader;
 use crate::pruning::build_column_page_pruners;
 use crate::pruning::PartitionPruner;
@@ -49,16 +51,23 @@ impl ParquetTable {
             ..
         }) = &push_down
         {
-            prj.clone()
+            // the partition columns are not in the files
+            split_projection(prj, self.arrow_schema.fields.len()).0
         } else {
             let indices = (0..self.arrow_schema.fields.len()).collect::<Vec<usize>>();
             Projection::Columns(indices)
         };
 
+        let partition_fields = self.partition_fields();
         let top_k = push_down
             .as_ref()
             .map(|p| p.top_k(&self.table_info.schema(), None, RangeIndex::supported_type))
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .filter(|top_k| {
+                partition_fields
+                    .iter()
+                    .all(|f| f.column_id() != top_k.column_id)
+            });
 
         // Currently, arrow2 doesn't support reading stats of a inner column of a nested type.
         // Therefore, if there is inner fields in projection, we skip the row group pruning.
@@ -102,15 +111,17 @@ impl ParquetTable {
             None
         };
 
-        let page_pruners = if self.read_options.prune_pages() && filter.is_some() {
-            Some(build_column_page_pruners(
-                func_ctx,
-                &schema,
-                filter.as_ref().unwrap(),
-            )?)
-        } else {
-            None
-        };
+        // The page pruners require the columns of the filter to be in the files.
+        let page_pruners =
+            if self.read_options.prune_pages() && filter.is_some() && partition_fields.is_empty() {
+                Some(build_column_page_pruners(
+                    func_ctx,
+                    &schema,
+                    filter.as_ref().unwrap(),
+                )?)
+            } else {
+                None
+            };
 
         Ok(PartitionPruner {
             schema,
@@ -133,7 +144,7 @@ impl ParquetTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let pruner = self.create_pruner(ctx.clone(), push_down.clone(), false)?;
 
-        let file_locations = match &self.files_to_read {
+        let mut file_locations = match &self.files_to_read {
             Some(files) => files
                 .iter()
                 .map(|f| (f.path.clone(), f.size))
@@ -148,6 +159,26 @@ impl ParquetTable {
             .collect::<Vec<_>>(),
         };
 
+        // Prune the files by the values of the partition columns in their paths.
+        let partition_fields = self.partition_fields();
+        let filter = push_down
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        if let (false, Some(filter)) = (partition_fields.is_empty(), filter) {
+            let schema = self.table_info.schema();
+            let partition_pruner = RangePrunerCreator::try_create(
+                ctx.get_function_context()?,
+                &schema,
+                Some(&filter),
+            )?;
+            file_locations.retain(|(path, _)| {
+                partition_pruner.should_keep(&partition_statistics(path, &partition_fields), None)
+            });
+            if file_locations.is_empty() {
+                return Ok((PartStatistics::default(), Partitions::default()));
+            }
+        }
+
         pruner
             .read_and_prune_partitions(self.operator.clone(), &file_locations)
             .await
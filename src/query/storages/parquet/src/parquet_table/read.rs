@@ -20,6 +20,7 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRefExt;
 use common_expression::Expr;
@@ -34,6 +35,8 @@ use storages_common_index::RangeIndex;
 use super::ParquetTable;
 use crate::deserialize_transform::ParquetDeserializeTransform;
 use crate::deserialize_transform::ParquetPrewhereInfo;
+use crate::hive_partition::split_projection;
+use crate::hive_partition::PartitionColumnFiller;
 use crate::parquet_part::ParquetPart;
 use crate::parquet_reader::ParquetReader;
 use crate::parquet_source::AsyncParquetSource;
@@ -60,6 +63,9 @@ impl ParquetTable {
         let table_schema: TableSchemaRef = self.table_info.schema();
         let source_projection =
             PushDownInfo::projection_of_push_downs(&table_schema, &plan.push_downs);
+        // The partition columns are not in the files, they are filled with the paths of the files.
+        let (source_projection, partition_indices) =
+            split_projection(&source_projection, self.arrow_schema.fields.len());
 
         // The front of the src_fields are prewhere columns (if exist).
         // The back of the src_fields are remain columns.
@@ -75,6 +81,22 @@ impl ParquetTable {
             source_projection,
         )?;
 
+        let partition_filler = if partition_indices.is_empty() {
+            None
+        } else {
+            let fields = partition_indices
+                .iter()
+                .map(|i| table_schema.field(*i).clone())
+                .collect::<Vec<_>>();
+            let mut filled_fields = source_reader.output_schema.fields().clone();
+            filled_fields.extend(fields.iter().map(DataField::from));
+            Some(PartitionColumnFiller::create(
+                fields,
+                DataSchemaRefExt::create(filled_fields),
+                output_schema.clone(),
+            ))
+        };
+
         // build top k information
         let top_k = plan
             .push_downs
@@ -168,6 +190,7 @@ impl ParquetTable {
                 source_reader.clone(),
                 remain_reader.clone(),
                 self.create_pruner(ctx.clone(), plan.push_downs.clone(), true)?,
+                partition_filler.clone(),
             )
         })
     }
@@ -31,6 +31,7 @@ use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
@@ -68,6 +69,13 @@ impl ParquetTable {
             files_to_read: info.files_to_read.clone(),
         }))
     }
+
+    /// The hive partition columns, whose values are in the paths of the files, like
+    /// `year=2023/month=01/data.parquet`. They are the fields of the table after the fields
+    /// of the files.
+    pub(crate) fn partition_fields(&self) -> Vec<TableField> {
+        self.table_info.schema().fields()[self.arrow_schema.fields.len()..].to_vec()
+    }
 }
 
 #[async_trait::async_trait]
@@ -89,7 +97,8 @@ impl Table for ParquetTable {
     }
 
     fn support_prewhere(&self) -> bool {
-        self.read_options.do_prewhere()
+        // the partition columns are filled after the columns in the files are read
+        self.read_options.do_prewhere() && self.partition_fields().is_empty()
     }
 
     fn has_exact_total_row_count(&self) -> bool {
@@ -27,4 +27,5 @@ pub use common::gen_result_cache_prefix;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
+pub use write::WriteDeclaredResultSink;
 pub use write::WriteResultCacheSink;
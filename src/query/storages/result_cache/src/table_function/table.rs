@@ -53,11 +53,29 @@ impl ResultScan {
         table_schema: TableSchema,
         query_id: String,
         block_raw_data: Vec<u8>,
+    ) -> Result<Arc<dyn Table>> {
+        Self::create(RESULT_SCAN, table_schema, query_id, block_raw_data)
+    }
+
+    /// Create the table of a result declared as `name` by `DECLARE <name> RESULT AS ...`.
+    pub fn try_create_declared(
+        name: &str,
+        table_schema: TableSchema,
+        block_raw_data: Vec<u8>,
+    ) -> Result<Arc<dyn Table>> {
+        Self::create(name, table_schema, String::new(), block_raw_data)
+    }
+
+    fn create(
+        name: &str,
+        table_schema: TableSchema,
+        query_id: String,
+        block_raw_data: Vec<u8>,
     ) -> Result<Arc<dyn Table>> {
         let table_info = TableInfo {
             ident: TableIdent::new(0, 0),
-            desc: format!("''.'{RESULT_SCAN}'"),
-            name: String::from(RESULT_SCAN),
+            desc: format!("''.'{name}'"),
+            name: String::from(name),
             meta: TableMeta {
                 schema: Arc::new(table_schema),
                 engine: String::from(RESULT_SCAN),
@@ -138,9 +156,16 @@ impl Table for ResultScan {
             let chunks_iter =
                 pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
 
+            let mut has_source = false;
             for chunk in chunks_iter {
                 let block = DataBlock::from_arrow_chunk(&chunk?, &schema)?;
                 pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+                has_source = true;
+            }
+
+            // An empty result is written as a parquet file without row groups.
+            if !has_source {
+                pipeline.add_source(EmptySource::create, 1)?;
             }
         }
         Ok(())
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncMpscSink;
+use common_pipeline_sinks::AsyncMpscSinker;
+use common_storage::DataOperator;
+
+use super::writer::ResultCacheWriter;
+use crate::common::gen_result_cache_dir;
+
+/// Writes the result of `DECLARE <name> RESULT AS ...` to the storage, and pins
+/// its location as `name` in the session.
+pub struct WriteDeclaredResultSink {
+    ctx: Arc<dyn TableContext>,
+    name: String,
+    cache_writer: ResultCacheWriter,
+}
+
+#[async_trait::async_trait]
+impl AsyncMpscSink for WriteDeclaredResultSink {
+    const NAME: &'static str = "WriteDeclaredResultSink";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, block: DataBlock) -> Result<bool> {
+        self.cache_writer.append_block(block);
+        Ok(false)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        // Replaces the result declared with the same name before, if any.
+        let location = self.cache_writer.write_to_storage().await?;
+        self.ctx.set_declared_result(self.name.clone(), location);
+        Ok(())
+    }
+}
+
+impl WriteDeclaredResultSink {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        name: String,
+        schema: TableSchemaRef,
+        inputs: Vec<Arc<InputPort>>,
+    ) -> Result<ProcessorPtr> {
+        let location = gen_result_cache_dir(&ctx.get_id());
+        let operator = DataOperator::instance().operator();
+        // A declared result is not bounded like the query result cache, it is always kept.
+        let cache_writer = ResultCacheWriter::create(schema, location, operator, usize::MAX);
+
+        Ok(ProcessorPtr::create(Box::new(AsyncMpscSinker::create(
+            inputs,
+            WriteDeclaredResultSink {
+                ctx,
+                name,
+                cache_writer,
+            },
+        ))))
+    }
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod declare_sink;
 mod sink;
 mod writer;
 
+pub use declare_sink::WriteDeclaredResultSink;
 pub use sink::WriteResultCacheSink;
@@ -0,0 +1,406 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::ParquetTableInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storage::StageFileInfo;
+use common_storage::StageFilesInfo;
+use common_storages_parquet::hive_partition_values;
+use common_storages_parquet::infer_partition_type;
+use common_storages_parquet::ParquetTable;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_EXTERNAL_TABLE_PATTERN;
+
+use crate::StageTable;
+
+pub const EXTERNAL_ENGINE: &str = "EXTERNAL";
+
+/// The files listed by the external tables, by table id.
+static FILE_LIST_CACHE: Lazy<Mutex<HashMap<u64, CachedFileList>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct CachedFileList {
+    /// the version of the table meta the files are listed for
+    seq: u64,
+    listed_at: Instant,
+    files: Vec<StageFileInfo>,
+}
+
+/// A read-only table over the files in a stage location, created by `CREATE EXTERNAL TABLE`.
+///
+/// The files are parsed with the declared columns on every read, the stage and the file format
+/// are resolved by the binder, see [`ExternalTable::with_stage`].
+pub struct ExternalTable {
+    info: TableInfo,
+    /// the resolved stage with the file format of the table, and the path in the stage
+    stage: Option<(StageInfo, String)>,
+}
+
+impl ExternalTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(Self { info, stage: None }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: EXTERNAL_ENGINE.to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// the table reading the files at `path` of the stage, which carries the file format
+    pub fn with_stage(info: TableInfo, stage_info: StageInfo, path: String) -> Arc<dyn Table> {
+        Arc::new(Self {
+            info,
+            stage: Some((stage_info, path)),
+        })
+    }
+
+    /// the location of the table, a stage location like `@my_stage/path/`
+    /// or an uri like `s3://bucket/path/`
+    pub fn location(info: &TableInfo) -> Result<&str> {
+        info.options()
+            .get(OPT_KEY_EXTERNAL_TABLE_LOCATION)
+            .map(|location| location.as_str())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!("missing location of external table {}", info.name))
+            })
+    }
+
+    pub fn pattern(info: &TableInfo) -> Option<&str> {
+        info.options()
+            .get(OPT_KEY_EXTERNAL_TABLE_PATTERN)
+            .map(|pattern| pattern.as_str())
+    }
+
+    /// the file format options given in `CREATE EXTERNAL TABLE`, empty if the table reads
+    /// the files in the file format of the stage
+    pub fn file_format(info: &TableInfo) -> Result<BTreeMap<String, String>> {
+        match info.options().get(OPT_KEY_EXTERNAL_TABLE_FILE_FORMAT) {
+            Some(options) => serde_json::from_str(options).map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "invalid file format of external table {}: {e}",
+                    info.name
+                ))
+            }),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// the columns discovered from the hive-style paths of the parquet files, like
+    /// `year=2023/month=01/`, they are the last columns of the table
+    pub fn partition_columns(info: &TableInfo) -> Vec<&str> {
+        match info.options().get(OPT_KEY_EXTERNAL_TABLE_PARTITION_COLUMNS) {
+            Some(columns) => columns.split(',').filter(|c| !c.is_empty()).collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn encode_partition_columns(fields: &[TableField]) -> String {
+        fields
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Lists the files at `path` of the stage for `CREATE EXTERNAL TABLE`, the files of an
+    /// existing table are listed by [`ExternalTable::read_partitions`].
+    #[async_backtrace::framed]
+    pub async fn list_location_files(
+        stage_info: &StageInfo,
+        path: &str,
+        pattern: Option<&str>,
+    ) -> Result<Vec<StageFileInfo>> {
+        let files_info = StageFilesInfo {
+            path: path.to_string(),
+            files: None,
+            pattern: pattern.map(|pattern| pattern.to_string()),
+        };
+        let op = StageTable::get_op(stage_info)?;
+        files_info.list(&op, false, None).await
+    }
+
+    /// The columns of the parquet files, inferred from the first file like `SELECT FROM @stage`.
+    #[async_backtrace::framed]
+    pub async fn infer_parquet_schema(
+        stage_info: &StageInfo,
+        path: &str,
+        files: Vec<StageFileInfo>,
+    ) -> Result<TableSchemaRef> {
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "no parquet files found in '{path}' to infer the columns of the external table"
+            )));
+        }
+        let files_info = StageFilesInfo {
+            path: path.to_string(),
+            files: None,
+            pattern: None,
+        };
+        let table = ParquetTable::create(
+            stage_info.clone(),
+            files_info,
+            ParquetReadOptions::default(),
+            Some(files),
+        )
+        .await?;
+        Ok(table.schema())
+    }
+
+    /// The hive partition columns in the paths of the files under `path`, in the order they
+    /// appear in the paths. The keys which are already columns of the files are skipped.
+    pub fn discover_partition_columns(
+        path: &str,
+        files: &[StageFileInfo],
+        schema: &TableSchema,
+    ) -> Vec<TableField> {
+        let prefix = path.trim_start_matches('/');
+        let mut columns: Vec<(String, Vec<Option<String>>)> = vec![];
+        for file in files {
+            let file_path = file.path.trim_start_matches('/');
+            let relative = file_path.strip_prefix(prefix).unwrap_or(file_path);
+            for (key, value) in hive_partition_values(relative) {
+                if schema.index_of(&key).is_ok() {
+                    continue;
+                }
+                match columns.iter_mut().find(|(name, _)| *name == key) {
+                    Some((_, values)) => values.push(value),
+                    None => columns.push((key, vec![value])),
+                }
+            }
+        }
+        columns
+            .into_iter()
+            .map(|(name, values)| {
+                let data_type = infer_partition_type(values.iter().map(|v| v.as_deref()));
+                TableField::new(&name, data_type)
+            })
+            .collect()
+    }
+
+    pub fn encode_file_format(options: &BTreeMap<String, String>) -> Result<String> {
+        serde_json::to_string(options)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid file format options: {e}")))
+    }
+
+    fn stage(&self) -> Result<&(StageInfo, String)> {
+        self.stage.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "the stage of external table {} is not resolved",
+                self.info.name
+            ))
+        })
+    }
+
+    fn files_info(&self, path: &str) -> StageFilesInfo {
+        StageFilesInfo {
+            path: path.to_string(),
+            files: None,
+            pattern: Self::pattern(&self.info).map(|pattern| pattern.to_string()),
+        }
+    }
+
+    fn is_parquet(&self) -> bool {
+        matches!(
+            &self.stage,
+            Some((stage_info, _)) if matches!(stage_info.file_format_params, FileFormatParams::Parquet(_))
+        )
+    }
+
+    /// the columns in the files, without the partition columns
+    fn file_schema(&self) -> TableSchema {
+        let schema = self.info.schema();
+        let num_partition_columns = Self::partition_columns(&self.info).len();
+        let num_file_fields = schema.num_fields() - num_partition_columns;
+        TableSchema::new(schema.fields()[..num_file_fields].to_vec())
+    }
+
+    /// parquet files are read by the parquet table, as columns in the schema order, the
+    /// values of the partition columns are filled from the paths of the files
+    fn parquet_table_info(
+        &self,
+        stage_info: &StageInfo,
+        path: &str,
+        files_to_read: Option<Vec<StageFileInfo>>,
+    ) -> ParquetTableInfo {
+        ParquetTableInfo {
+            read_options: ParquetReadOptions::default(),
+            stage_info: stage_info.clone(),
+            files_info: self.files_info(path),
+            table_info: self.info.clone(),
+            arrow_schema: self.file_schema().to_arrow(),
+            files_to_read,
+        }
+    }
+
+    /// the other files are read by the stage table, the fields are matched to the columns
+    /// as in `COPY INTO`
+    fn stage_table_info(
+        &self,
+        stage_info: &StageInfo,
+        path: &str,
+        files_to_copy: Option<Vec<StageFileInfo>>,
+    ) -> StageTableInfo {
+        StageTableInfo {
+            schema: self.info.schema(),
+            files_info: self.files_info(path),
+            stage_info: stage_info.clone(),
+            files_to_copy,
+            is_select: false,
+            is_ordered: false,
+            partition_by: vec![],
+            file_name_prefix: None,
+        }
+    }
+
+    /// Lists the files of the table, the list is kept for
+    /// `external_file_list_cache_ttl_secs` until the table is altered.
+    #[async_backtrace::framed]
+    async fn list_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        stage_info: &StageInfo,
+        path: &str,
+    ) -> Result<Vec<StageFileInfo>> {
+        let ttl = Duration::from_secs(ctx.get_settings().get_external_file_list_cache_ttl_secs()?);
+        let table_id = self.info.ident.table_id;
+        let seq = self.info.ident.seq;
+        if !ttl.is_zero() {
+            let cache = FILE_LIST_CACHE.lock();
+            if let Some(cached) = cache.get(&table_id) {
+                if cached.seq == seq && cached.listed_at.elapsed() < ttl {
+                    return Ok(cached.files.clone());
+                }
+            }
+        }
+
+        let op = StageTable::get_op(stage_info)?;
+        let files = self.files_info(path).list(&op, false, None).await?;
+        if !ttl.is_zero() {
+            let mut cache = FILE_LIST_CACHE.lock();
+            cache.retain(|_, cached| cached.listed_at.elapsed() < ttl);
+            cache.insert(table_id, CachedFileList {
+                seq,
+                listed_at: Instant::now(),
+                files: files.clone(),
+            });
+        }
+        Ok(files)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        self.is_parquet()
+    }
+
+    /// the partitions are read by the parquet table or the stage table,
+    /// see [`ExternalTable::read_partitions`]
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        match &self.stage {
+            Some((stage_info, path)) if self.is_parquet() => {
+                DataSourceInfo::ParquetSource(self.parquet_table_info(stage_info, path, None))
+            }
+            Some((stage_info, path)) => {
+                DataSourceInfo::StageSource(self.stage_table_info(stage_info, path, None))
+            }
+            None => DataSourceInfo::TableSource(self.info.clone()),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let (stage_info, path) = self.stage()?;
+        let files = self.list_files(&ctx, stage_info, path).await?;
+        if files.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
+        if self.is_parquet() {
+            let info = self.parquet_table_info(stage_info, path, Some(files));
+            ParquetTable::from_info(&info)?
+                .read_partitions(ctx, push_downs)
+                .await
+        } else {
+            let info = self.stage_table_info(stage_info, path, Some(files));
+            StageTable::try_create(info)?
+                .read_partitions(ctx, push_downs)
+                .await
+        }
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let (stage_info, path) = self.stage()?;
+        if self.is_parquet() {
+            let info = self.parquet_table_info(stage_info, path, None);
+            ParquetTable::from_info(&info)?.read_data(ctx, plan, pipeline)
+        } else {
+            let info = self.stage_table_info(stage_info, path, None);
+            StageTable::try_create(info)?.read_data(ctx, plan, pipeline)
+        }
+    }
+}
@@ -14,7 +14,11 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod external_table;
 mod stage_table;
+mod stage_table_partitioned_sink;
 mod stage_table_sink;
 
+pub use external_table::ExternalTable;
+pub use external_table::EXTERNAL_ENGINE;
 pub use stage_table::StageTable;
@@ -40,13 +40,15 @@ use common_pipeline_sources::input_formats::InputContext;
 use common_pipeline_sources::input_formats::SplitInfo;
 use common_storage::init_stage_operator;
 use common_storage::StageFileInfo;
+use common_storage::StorageMetrics;
+use common_storage::StorageMetricsLayer;
 use dashmap::DashMap;
 use opendal::Operator;
 use parking_lot::Mutex;
 
+use crate::stage_table_partitioned_sink::StageTablePartitionedSink;
 use crate::stage_table_sink::StageTableSink;
 
-/// TODO: we need to track the data metrics in stage table.
 pub struct StageTable {
     table_info: StageTableInfo,
     // This is no used but a placeholder.
@@ -54,6 +56,7 @@ pub struct StageTable {
     // fn get_table_info(&self) -> &TableInfo).
     table_info_placeholder: TableInfo,
     block_compact_threshold: Mutex<Option<BlockThresholds>>,
+    data_metrics: Arc<StorageMetrics>,
 }
 
 impl StageTable {
@@ -64,6 +67,7 @@ impl StageTable {
             table_info,
             table_info_placeholder,
             block_compact_threshold: Default::default(),
+            data_metrics: Arc::new(StorageMetrics::default()),
         }))
     }
 
@@ -72,6 +76,11 @@ impl StageTable {
         init_stage_operator(stage)
     }
 
+    /// Get operator which records the bytes read from and written to the stage.
+    fn get_metered_op(&self, stage: &StageInfo) -> Result<Operator> {
+        Ok(StageTable::get_op(stage)?.layer(StorageMetricsLayer::new(self.data_metrics.clone())))
+    }
+
     #[async_backtrace::framed]
     pub async fn list_files(
         stage_info: &StageTableInfo,
@@ -104,6 +113,10 @@ impl Table for StageTable {
         DataSourceInfo::StageSource(self.table_info.clone())
     }
 
+    fn get_data_metrics(&self) -> Option<Arc<StorageMetrics>> {
+        Some(self.data_metrics.clone())
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -118,7 +131,7 @@ impl Table for StageTable {
             StageTable::list_files(stage_info, None).await?
         };
         let format = InputContext::get_input_format(&stage_info.stage_info.file_format_params)?;
-        let operator = StageTable::get_op(&stage_info.stage_info)?;
+        let operator = self.get_metered_op(&stage_info.stage_info)?;
         let splits = format
             .get_splits(
                 files,
@@ -172,7 +185,7 @@ impl Table for StageTable {
             .collect::<Vec<_>>();
         let schema = TableSchemaRefExt::create(fields);
         let stage_info = stage_table_info.stage_info.clone();
-        let operator = StageTable::get_op(&stage_table_info.stage_info)?;
+        let operator = self.get_metered_op(&stage_table_info.stage_info)?;
         let compact_threshold = self.get_block_compact_thresholds_with_default();
         let on_error_map = match ctx.get_on_error_map() {
             Some(m) => m,
@@ -205,11 +218,34 @@ impl Table for StageTable {
         _: AppendMode,
     ) -> Result<()> {
         let single = self.table_info.stage_info.copy_options.single;
-        let op = StageTable::get_op(&self.table_info.stage_info)?;
+        let op = self.get_metered_op(&self.table_info.stage_info)?;
 
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
 
+        if self.table_info.is_ordered && pipeline.output_len() > 1 {
+            // Merging the outputs would interleave the rows, the sorted stream should have
+            // been merged into one output already.
+            return Err(ErrorCode::Internal(
+                "ordered unload expects a single sorted stream",
+            ));
+        }
+
+        // the rows are routed to the writers of the partitions in each output.
+        if !self.table_info.partition_by.is_empty() {
+            return pipeline.add_sink(|input| {
+                let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                StageTablePartitionedSink::try_create(
+                    input,
+                    ctx.clone(),
+                    self.table_info.clone(),
+                    op.clone(),
+                    uuid.clone(),
+                    gid,
+                )
+            });
+        }
+
         // parallel compact unload, the partial block will flush into next operator
         if !single && pipeline.output_len() > 1 {
             pipeline.add_transform(|input, output| {
@@ -0,0 +1,365 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_compress::compress_all;
+use common_compress::CompressAlgorithm;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_formats::output_format::OutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use opendal::Operator;
+
+use crate::stage_table_sink::StageTableSink;
+
+/// The partition of the NULL and empty values, the same as Hive.
+const DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+#[derive(Debug)]
+enum State {
+    None,
+    NeedSerialize(DataBlock),
+    // The finalized files, with their paths.
+    NeedWrite(Vec<(String, Vec<u8>)>),
+    Finished,
+}
+
+/// The writer of the files of one partition.
+struct PartitionWriter {
+    output_format: Box<dyn OutputFormat>,
+    working_buffer: Vec<u8>,
+    batch_id: usize,
+}
+
+/// Unloads the rows into the Hive-style directories of the partitions, e.g.
+/// `dt=2023-06-01/region=us/`, each partition is written by its own writer and flushed to
+/// files of at most `max_file_size`.
+pub struct StageTablePartitionedSink {
+    state: State,
+    input: Arc<InputPort>,
+    data_accessor: Operator,
+
+    table_info: StageTableInfo,
+    options_ext: FileFormatOptionsExt,
+    func_ctx: FunctionContext,
+    partition_exprs: Vec<Expr>,
+    // The writers by the directories of the partitions.
+    writers: HashMap<String, PartitionWriter>,
+
+    uuid: String,
+    group_id: usize,
+    max_file_size: usize,
+    compression: Option<CompressAlgorithm>,
+}
+
+impl StageTablePartitionedSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_accessor: Operator,
+
+        uuid: String,
+        group_id: usize,
+    ) -> Result<ProcessorPtr> {
+        let options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+        let max_file_size = StageTableSink::adjust_max_file_size(&ctx, &table_info)?;
+        let compression = StageTableSink::unload_compression(&table_info)?;
+        let partition_exprs = table_info
+            .partition_by
+            .iter()
+            .map(|key| key.expr.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+
+        Ok(ProcessorPtr::create(Box::new(StageTablePartitionedSink {
+            state: State::None,
+            input,
+            data_accessor,
+
+            table_info,
+            options_ext,
+            func_ctx: ctx.get_function_context()?,
+            partition_exprs,
+            writers: HashMap::new(),
+
+            uuid,
+            group_id,
+            max_file_size,
+            compression,
+        })))
+    }
+
+    /// Returns the directories of the partitions of the rows.
+    fn partition_dirs(&self, block: &DataBlock) -> Result<Vec<String>> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        let mut dirs = vec![String::new(); num_rows];
+        for (key, expr) in self
+            .table_info
+            .partition_by
+            .iter()
+            .zip(&self.partition_exprs)
+        {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            let column =
+                NullableType::<StringType>::try_downcast_column(&column).ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "partition key {} should be evaluated to a nullable string",
+                        key.name
+                    ))
+                })?;
+            for (dir, value) in dirs.iter_mut().zip(column.iter()) {
+                escape_partition_path(&key.name, dir);
+                dir.push('=');
+                match value {
+                    Some(value) if !value.is_empty() => {
+                        escape_partition_path(&String::from_utf8_lossy(value), dir)
+                    }
+                    _ => dir.push_str(DEFAULT_PARTITION),
+                }
+                dir.push('/');
+            }
+        }
+        Ok(dirs)
+    }
+
+    fn unload_path(&self, dir: &str, batch_id: usize) -> String {
+        let prefix = self
+            .table_info
+            .file_name_prefix
+            .as_deref()
+            .unwrap_or("data_");
+
+        format!(
+            "{}/{}{}{}_{:0>4}_{:0>8}.{}",
+            self.table_info.files_info.path,
+            dir,
+            prefix,
+            self.uuid,
+            self.group_id,
+            batch_id,
+            StageTableSink::unload_extension(&self.table_info, self.compression)
+        )
+    }
+
+    /// Serializes the rows of one partition, the files which reach `max_file_size` are
+    /// finalized into `files`.
+    fn serialize_partition(
+        &mut self,
+        dir: String,
+        block: DataBlock,
+        files: &mut Vec<(String, Vec<u8>)>,
+    ) -> Result<()> {
+        if !self.writers.contains_key(&dir) {
+            let output_format = self.options_ext.get_output_format(
+                self.table_info.schema(),
+                self.table_info.stage_info.file_format_params.clone(),
+            )?;
+            let writer = PartitionWriter {
+                output_format,
+                working_buffer: vec![],
+                batch_id: 0,
+            };
+            self.writers.insert(dir.clone(), writer);
+        }
+
+        for i in (0..block.num_rows()).step_by(1024) {
+            let end = (i + 1024).min(block.num_rows());
+            let small_block = block.slice(i..end);
+
+            let writer = self.writers.get_mut(&dir).unwrap();
+            if writer.working_buffer.is_empty() {
+                // every file starts with the prefix of the format, e.g. the header row.
+                let prefix = writer.output_format.serialize_prefix()?;
+                writer.working_buffer.extend_from_slice(&prefix);
+            }
+            let bs = writer.output_format.serialize_block(&small_block)?;
+            writer.working_buffer.extend_from_slice(&bs);
+
+            if writer.working_buffer.len() + writer.output_format.buffer_size()
+                >= self.max_file_size
+            {
+                let bs = writer.output_format.finalize()?;
+                writer.working_buffer.extend_from_slice(&bs);
+
+                let data = std::mem::take(&mut writer.working_buffer);
+                let batch_id = writer.batch_id;
+                writer.batch_id += 1;
+                files.push((self.unload_path(&dir, batch_id), data));
+            }
+        }
+        Ok(())
+    }
+
+    /// Finalizes the files of all the partitions at the end of the input.
+    fn finalize(&mut self) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut files = vec![];
+        let mut writers = std::mem::take(&mut self.writers)
+            .into_iter()
+            .collect::<Vec<_>>();
+        writers.sort_by(|a, b| a.0.cmp(&b.0));
+        for (dir, mut writer) in writers {
+            if writer.output_format.buffer_size() > 0 {
+                let bs = writer.output_format.finalize()?;
+                writer.working_buffer.extend_from_slice(&bs);
+            }
+            if !writer.working_buffer.is_empty() {
+                files.push((
+                    self.unload_path(&dir, writer.batch_id),
+                    writer.working_buffer,
+                ));
+            }
+        }
+        Ok(files)
+    }
+}
+
+#[async_trait]
+impl Processor for StageTablePartitionedSink {
+    fn name(&self) -> String {
+        "StagePartitionedSink".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match &self.state {
+            State::NeedSerialize(_) => return Ok(Event::Sync),
+            State::NeedWrite(_) => return Ok(Event::Async),
+            State::Finished => return Ok(Event::Finished),
+            State::None => {}
+        }
+
+        if self.input.is_finished() {
+            // the writers are taken by the first call, then the sink finishes after the
+            // files are written.
+            let files = self.finalize()?;
+            if files.is_empty() {
+                self.state = State::Finished;
+                return Ok(Event::Finished);
+            }
+            self.state = State::NeedWrite(files);
+            return Ok(Event::Async);
+        }
+
+        if !self.input.has_data() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        self.state = State::NeedSerialize(self.input.pull_data().unwrap()?);
+        Ok(Event::Sync)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::None) {
+            State::NeedSerialize(block) => {
+                let dirs = self.partition_dirs(&block)?;
+
+                // The rows of the partitions, in the order of their first rows.
+                let mut partitions: Vec<(String, Vec<u32>)> = vec![];
+                let mut positions: HashMap<String, usize> = HashMap::new();
+                for (row, dir) in dirs.into_iter().enumerate() {
+                    match positions.get(&dir) {
+                        Some(position) => partitions[*position].1.push(row as u32),
+                        None => {
+                            positions.insert(dir.clone(), partitions.len());
+                            partitions.push((dir, vec![row as u32]));
+                        }
+                    }
+                }
+
+                let mut files = vec![];
+                for (dir, rows) in partitions {
+                    let block = if rows.len() == block.num_rows() {
+                        block.clone()
+                    } else {
+                        block.take(&rows)?
+                    };
+                    self.serialize_partition(dir, block, &mut files)?;
+                }
+                if !files.is_empty() {
+                    self.state = State::NeedWrite(files);
+                }
+                Ok(())
+            }
+            _state => Err(ErrorCode::Internal(
+                "Unknown state for stage table partitioned sink.",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::None) {
+            State::NeedWrite(files) => {
+                for (path, bytes) in files {
+                    let bytes = match self.compression {
+                        Some(algo) => compress_all(algo, &bytes).await?,
+                        None => bytes,
+                    };
+                    self.data_accessor.write(&path, bytes).await?;
+                }
+                Ok(())
+            }
+            _state => Err(ErrorCode::Internal(
+                "Unknown state for stage table partitioned sink.",
+            )),
+        }
+    }
+}
+
+/// Escapes the names and values of the partitions like Hive, the characters which are
+/// unsafe in the paths are written as `%XX`.
+fn escape_partition_path(value: &str, output: &mut String) {
+    for c in value.chars() {
+        if c.is_control()
+            || matches!(
+                c,
+                '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+            )
+        {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                write!(output, "%{:02X}", b).unwrap();
+            }
+        } else {
+            output.push(c);
+        }
+    }
+}
@@ -18,26 +18,45 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
+use common_compress::compress_all;
+use common_compress::CompressAlgorithm;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_formats::output_format::OutputFormat;
 use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::StageFileCompression;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use opendal::Operator;
+use serde::Serialize;
 
 #[derive(Debug)]
 enum State {
     None,
     NeedSerialize(DataBlock),
     NeedWrite(Vec<u8>, Option<DataBlock>),
+    NeedWriteManifest,
     Finished,
 }
 
+/// Manifest of an ordered unload, the files are listed in the order of the rows.
+#[derive(Serialize)]
+struct UnloadManifest {
+    ordered: bool,
+    files: Vec<UnloadedFile>,
+}
+
+#[derive(Serialize)]
+struct UnloadedFile {
+    path: String,
+    rows: usize,
+    bytes: usize,
+}
+
 pub struct StageTableSink {
     state: State,
     input: Arc<InputPort>,
@@ -49,6 +68,8 @@ pub struct StageTableSink {
     working_datablocks: Vec<DataBlock>,
     output_format: Box<dyn OutputFormat>,
     write_header: bool,
+    // the codec to compress the whole files with, e.g. gzip for CSV.
+    compression: Option<CompressAlgorithm>,
 
     uuid: String,
     group_id: usize,
@@ -56,6 +77,12 @@ pub struct StageTableSink {
 
     single: bool,
     max_file_size: usize,
+
+    // rows serialized into working_buffer
+    working_rows: usize,
+    is_ordered: bool,
+    unloaded_files: Vec<UnloadedFile>,
+    manifest_written: bool,
 }
 
 impl StageTableSink {
@@ -77,8 +104,10 @@ impl StageTableSink {
             table_info.stage_info.file_format_params.clone(),
         )?;
 
+        let compression = Self::unload_compression(&table_info)?;
         let max_file_size = Self::adjust_max_file_size(&ctx, &table_info)?;
         let single = table_info.stage_info.copy_options.single;
+        let is_ordered = table_info.is_ordered;
 
         Ok(ProcessorPtr::create(Box::new(StageTableSink {
             input,
@@ -91,15 +120,21 @@ impl StageTableSink {
             working_buffer: Vec::with_capacity((max_file_size as f64 * 1.2) as usize),
             working_datablocks: vec![],
             write_header: false,
+            compression,
 
             uuid,
             group_id,
             batch_id: 0,
             max_file_size,
+
+            working_rows: 0,
+            is_ordered,
+            unloaded_files: vec![],
+            manifest_written: false,
         })))
     }
 
-    fn adjust_max_file_size(
+    pub(crate) fn adjust_max_file_size(
         ctx: &Arc<dyn TableContext>,
         stage_info: &StageTableInfo,
     ) -> Result<usize> {
@@ -118,33 +153,77 @@ impl StageTableSink {
         Ok(max_file_size)
     }
 
-    pub fn unload_path(&self) -> String {
-        let format_name = format!(
-            "{:?}",
-            self.table_info.stage_info.file_format_params.get_type()
-        );
+    /// Returns the codec to compress the unloaded files with. The files of Parquet are
+    /// never compressed as a whole, their column chunks are compressed by the writer.
+    pub(crate) fn unload_compression(
+        stage_info: &StageTableInfo,
+    ) -> Result<Option<CompressAlgorithm>> {
+        let compression = stage_info.stage_info.file_format_params.compression();
+        let algo = match compression {
+            StageFileCompression::Auto | StageFileCompression::None => None,
+            StageFileCompression::Gzip => Some(CompressAlgorithm::Gzip),
+            StageFileCompression::Bz2 => Some(CompressAlgorithm::Bz2),
+            StageFileCompression::Brotli => Some(CompressAlgorithm::Brotli),
+            StageFileCompression::Zstd => Some(CompressAlgorithm::Zstd),
+            StageFileCompression::Deflate => Some(CompressAlgorithm::Zlib),
+            StageFileCompression::RawDeflate => Some(CompressAlgorithm::Deflate),
+            StageFileCompression::Xz => Some(CompressAlgorithm::Xz),
+            StageFileCompression::Lzo | StageFileCompression::Snappy => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "unloading to {:?} does not support compression {} yet",
+                    stage_info.stage_info.file_format_params.get_type(),
+                    compression.to_string()
+                )));
+            }
+        };
+        Ok(algo)
+    }
 
-        // assert_eq!("00000110", format!("{:0>8}", "110"))
+    /// Returns the extension of the unloaded files, e.g. `csv.gz`.
+    pub(crate) fn unload_extension(
+        stage_info: &StageTableInfo,
+        compression: Option<CompressAlgorithm>,
+    ) -> String {
+        let format_name = format!("{:?}", stage_info.stage_info.file_format_params.get_type())
+            .to_ascii_lowercase();
+        match compression {
+            Some(algo) => format!("{}.{}", format_name, algo.extension()),
+            None => format_name,
+        }
+    }
+
+    fn unload_prefix(&self) -> String {
         if self.table_info.files_info.path.ends_with("data_") {
-            format!(
-                "{}{}_{:0>4}_{:0>8}.{}",
-                self.table_info.files_info.path,
-                self.uuid,
-                self.group_id,
-                self.batch_id,
-                format_name.to_ascii_lowercase()
-            )
+            format!("{}{}", self.table_info.files_info.path, self.uuid)
         } else {
+            let prefix = self
+                .table_info
+                .file_name_prefix
+                .as_deref()
+                .unwrap_or("data_");
             format!(
-                "{}/data_{}_{:0>4}_{:0>8}.{}",
-                self.table_info.files_info.path,
-                self.uuid,
-                self.group_id,
-                self.batch_id,
-                format_name.to_ascii_lowercase()
+                "{}/{}{}",
+                self.table_info.files_info.path, prefix, self.uuid
             )
         }
     }
+
+    /// The files of one sink are named by an increasing batch id, so the file names of an
+    /// ordered unload sort in the same order as the rows.
+    pub fn unload_path(&self) -> String {
+        // assert_eq!("00000110", format!("{:0>8}", "110"))
+        format!(
+            "{}_{:0>4}_{:0>8}.{}",
+            self.unload_prefix(),
+            self.group_id,
+            self.batch_id,
+            Self::unload_extension(&self.table_info, self.compression)
+        )
+    }
+
+    pub fn manifest_path(&self) -> String {
+        format!("{}_manifest.json", self.unload_prefix())
+    }
 }
 
 #[async_trait]
@@ -162,7 +241,10 @@ impl Processor for StageTableSink {
             return Ok(Event::Sync);
         }
 
-        if matches!(&self.state, State::NeedWrite(_, _)) {
+        if matches!(
+            &self.state,
+            State::NeedWrite(_, _) | State::NeedWriteManifest
+        ) {
             return Ok(Event::Async);
         }
 
@@ -177,6 +259,8 @@ impl Processor for StageTableSink {
                 self.working_datablocks.clear();
                 return Ok(Event::Async);
             }
+            // the remaining rows are flushed into the next operator
+            self.working_rows = 0;
 
             match (&self.output, self.working_datablocks.is_empty()) {
                 (Some(output), false) => {
@@ -187,6 +271,10 @@ impl Processor for StageTableSink {
                     return Ok(Event::NeedConsume);
                 }
                 _ => {
+                    if self.is_ordered && !self.manifest_written {
+                        self.state = State::NeedWriteManifest;
+                        return Ok(Event::Async);
+                    }
                     self.state = State::Finished;
                     if let Some(output) = self.output.as_mut() {
                         output.finish()
@@ -221,6 +309,7 @@ impl Processor for StageTableSink {
 
                         let bs = self.output_format.serialize_block(&small_block)?;
                         self.working_buffer.extend_from_slice(bs.as_slice());
+                        self.working_rows += small_block.num_rows();
 
                         if self.working_buffer.len() + self.output_format.buffer_size()
                             >= self.max_file_size
@@ -242,6 +331,7 @@ impl Processor for StageTableSink {
                 } else {
                     let bs = self.output_format.serialize_block(&datablock)?;
                     self.working_buffer.extend_from_slice(bs.as_slice());
+                    self.working_rows += datablock.num_rows();
                 }
 
                 // hold this datablock
@@ -261,14 +351,42 @@ impl Processor for StageTableSink {
         match std::mem::replace(&mut self.state, State::None) {
             State::NeedWrite(bytes, remaining_block) => {
                 let path = self.unload_path();
+                let rows = std::mem::take(&mut self.working_rows);
+                let bytes = match self.compression {
+                    Some(algo) => compress_all(algo, &bytes).await?,
+                    None => bytes,
+                };
+                let size = bytes.len();
 
                 self.data_accessor.write(&path, bytes).await?;
 
+                if self.is_ordered {
+                    self.unloaded_files.push(UnloadedFile {
+                        path,
+                        rows,
+                        bytes: size,
+                    });
+                }
+
                 match remaining_block {
                     Some(block) => self.state = State::NeedSerialize(block),
                     None => self.state = State::None,
                 }
                 self.batch_id += 1;
+                // every file starts with the prefix of the format, e.g. the header row.
+                self.write_header = false;
+                Ok(())
+            }
+            State::NeedWriteManifest => {
+                let manifest = UnloadManifest {
+                    ordered: true,
+                    files: std::mem::take(&mut self.unloaded_files),
+                };
+                let bytes = serde_json::to_vec(&manifest)?;
+                self.data_accessor
+                    .write(&self.manifest_path(), bytes)
+                    .await?;
+                self.manifest_written = true;
                 Ok(())
             }
             _state => {
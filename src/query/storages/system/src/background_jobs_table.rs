@@ -0,0 +1,233 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackgroundJobState {
+    /// The job is not running, its last run succeeded or it has never run.
+    #[default]
+    Idle,
+    Running,
+    /// The last run failed, the job is retried after its backoff.
+    Retrying,
+    /// The job failed too many times in a row, it does not run until released.
+    Quarantined,
+}
+
+impl BackgroundJobState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackgroundJobState::Idle => "IDLE",
+            BackgroundJobState::Running => "RUNNING",
+            BackgroundJobState::Retrying => "RETRYING",
+            BackgroundJobState::Quarantined => "QUARANTINED",
+        }
+    }
+}
+
+/// The status of a background job. The times are in microseconds since the unix epoch.
+#[derive(Clone, Debug, Default)]
+pub struct BackgroundJobStatus {
+    pub state: BackgroundJobState,
+    pub total_runs: u64,
+    pub total_failures: u64,
+    pub consecutive_failures: u64,
+    pub last_run_time: Option<i64>,
+    pub last_success_time: Option<i64>,
+    pub next_retry_time: Option<i64>,
+    /// The error of the last failed run, kept after the job succeeds again.
+    pub last_error_code: i32,
+    pub last_error_message: String,
+}
+
+/// Node-local status of the background jobs run by this node, shown by `system.background_jobs`.
+///
+/// The jobs are keyed by their type and name.
+pub struct BackgroundJobs {
+    jobs: RwLock<BTreeMap<(String, String), BackgroundJobStatus>>,
+}
+
+static BACKGROUND_JOBS: OnceCell<Arc<BackgroundJobs>> = OnceCell::new();
+
+impl BackgroundJobs {
+    pub fn instance() -> Arc<BackgroundJobs> {
+        BACKGROUND_JOBS
+            .get_or_init(|| {
+                Arc::new(BackgroundJobs {
+                    jobs: RwLock::new(BTreeMap::new()),
+                })
+            })
+            .clone()
+    }
+
+    pub fn get(&self, job_type: &str, name: &str) -> Option<BackgroundJobStatus> {
+        let jobs = self.jobs.read();
+        jobs.get(&(job_type.to_string(), name.to_string())).cloned()
+    }
+
+    /// Updates the status of a job, a job seen for the first time starts from the default status.
+    pub fn update<F, T>(&self, job_type: &str, name: &str, f: F) -> T
+    where F: FnOnce(&mut BackgroundJobStatus) -> T {
+        let mut jobs = self.jobs.write();
+        let status = jobs
+            .entry((job_type.to_string(), name.to_string()))
+            .or_default();
+        f(status)
+    }
+
+    /// Removes the jobs of `job_type` whose names do not satisfy `f`, e.g. the dropped ones.
+    pub fn retain<F>(&self, job_type: &str, f: F)
+    where F: Fn(&str) -> bool {
+        let mut jobs = self.jobs.write();
+        jobs.retain(|(ty, name), _| ty != job_type || f(name));
+    }
+
+    pub fn entries(&self) -> Vec<(String, String, BackgroundJobStatus)> {
+        let jobs = self.jobs.read();
+        jobs.iter()
+            .map(|((job_type, name), status)| (job_type.clone(), name.clone(), status.clone()))
+            .collect()
+    }
+}
+
+pub struct BackgroundJobsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for BackgroundJobsTable {
+    const NAME: &'static str = "system.background_jobs";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let entries = BackgroundJobs::instance().entries();
+
+        let mut names = Vec::with_capacity(entries.len());
+        let mut job_types = Vec::with_capacity(entries.len());
+        let mut states = Vec::with_capacity(entries.len());
+        let mut total_runs = Vec::with_capacity(entries.len());
+        let mut total_failures = Vec::with_capacity(entries.len());
+        let mut consecutive_failures = Vec::with_capacity(entries.len());
+        let mut last_run_times = Vec::with_capacity(entries.len());
+        let mut last_success_times = Vec::with_capacity(entries.len());
+        let mut next_retry_times = Vec::with_capacity(entries.len());
+        let mut last_error_codes = Vec::with_capacity(entries.len());
+        let mut last_error_messages = Vec::with_capacity(entries.len());
+
+        for (job_type, name, status) in entries {
+            names.push(name.into_bytes());
+            job_types.push(job_type.into_bytes());
+            states.push(status.state.as_str().as_bytes().to_vec());
+            total_runs.push(status.total_runs);
+            total_failures.push(status.total_failures);
+            consecutive_failures.push(status.consecutive_failures);
+            last_run_times.push(status.last_run_time);
+            last_success_times.push(status.last_success_time);
+            next_retry_times.push(status.next_retry_time);
+            last_error_codes.push(status.last_error_code);
+            last_error_messages.push(status.last_error_message.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(job_types),
+            StringType::from_data(states),
+            UInt64Type::from_data(total_runs),
+            UInt64Type::from_data(total_failures),
+            UInt64Type::from_data(consecutive_failures),
+            TimestampType::from_opt_data(last_run_times),
+            TimestampType::from_opt_data(last_success_times),
+            TimestampType::from_opt_data(next_retry_times),
+            Int32Type::from_data(last_error_codes),
+            StringType::from_data(last_error_messages),
+        ]))
+    }
+}
+
+impl BackgroundJobsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("job_type", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("total_runs", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "total_failures",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "consecutive_failures",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "last_run_time",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_success_time",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "next_retry_time",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_error_code",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new("last_error_message", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'background_jobs'".to_string(),
+            name: "background_jobs".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemBackgroundJobs".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -54,6 +54,8 @@ impl SyncSystemTable for ClustersTable {
             cluster_nodes.len(),
         );
         let mut versions = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
+        let mut states = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
+        let state = ctx.get_warehouse_state();
 
         for cluster_node in &cluster_nodes {
             let (ip, port) = cluster_node.ip_port()?;
@@ -62,6 +64,7 @@ impl SyncSystemTable for ClustersTable {
             addresses.push(Scalar::String(ip.as_bytes().to_vec()).as_ref());
             addresses_port.push(Scalar::Number(NumberScalar::UInt16(port)).as_ref());
             versions.push(Scalar::String(cluster_node.binary_version.as_bytes().to_vec()).as_ref());
+            states.push(Scalar::String(state.as_bytes().to_vec()).as_ref());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -69,6 +72,7 @@ impl SyncSystemTable for ClustersTable {
             addresses.build(),
             addresses_port.build(),
             versions.build(),
+            states.build(),
         ]))
     }
 }
@@ -80,6 +84,7 @@ impl ClustersTable {
             TableField::new("host", TableDataType::String),
             TableField::new("port", TableDataType::Number(NumberDataType::UInt16)),
             TableField::new("version", TableDataType::String),
+            TableField::new("state", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+use itertools::Itertools;
+
+use crate::functions_table::function_volatility;
+use crate::functions_table::IMMUTABLE;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The arguments and return type of the overloads which can't be listed,
+/// e.g. built by factories depending on the types of arguments.
+const ANY_ARGUMENTS: &str = "...";
+const ANY_RETURN_TYPE: &str = "ANY";
+
+/// Lists every overload of the functions, one row per signature.
+pub struct FunctionSignaturesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for FunctionSignaturesTable {
+    const NAME: &'static str = "system.function_signatures";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut names = vec![];
+        let mut arguments = vec![];
+        let mut return_types = vec![];
+        let mut is_aggregate = vec![];
+        let mut volatilities = vec![];
+        let mut descriptions = vec![];
+
+        let mut func_names = BUILTIN_FUNCTIONS.registered_names();
+        func_names.sort();
+        for name in func_names {
+            let volatility = function_volatility(&name);
+            let description = BUILTIN_FUNCTIONS
+                .get_doc(&name)
+                .map_or("", |doc| doc.description);
+
+            let mut signatures = BUILTIN_FUNCTIONS
+                .get_signatures(&name)
+                .into_iter()
+                .map(|signature| {
+                    (
+                        signature
+                            .args_type
+                            .iter()
+                            .map(|ty| ty.sql_name())
+                            .join(", "),
+                        signature.return_type.sql_name(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if BUILTIN_FUNCTIONS.has_factory(&name) {
+                signatures.push((ANY_ARGUMENTS.to_string(), ANY_RETURN_TYPE.to_string()));
+            }
+
+            for (args, return_type) in signatures {
+                names.push(name.clone());
+                arguments.push(args);
+                return_types.push(return_type);
+                is_aggregate.push(false);
+                volatilities.push(volatility.to_string());
+                descriptions.push(description.to_string());
+            }
+        }
+
+        let aggregate_function_factory = AggregateFunctionFactory::instance();
+        let mut aggr_func_names = aggregate_function_factory.registered_names();
+        aggr_func_names.sort();
+        for name in aggr_func_names {
+            let description = aggregate_function_factory
+                .get_features(&name)
+                .map_or("", |features| features.description);
+            names.push(name);
+            arguments.push(ANY_ARGUMENTS.to_string());
+            return_types.push(ANY_RETURN_TYPE.to_string());
+            is_aggregate.push(true);
+            volatilities.push(IMMUTABLE.to_string());
+            descriptions.push(description.to_string());
+        }
+
+        let tenant = ctx.get_tenant();
+        let udfs = UserApiProvider::instance().get_udfs(&tenant).await?;
+        for udf in udfs {
+            names.push(udf.name);
+            arguments.push(udf.parameters.join(", "));
+            return_types.push(ANY_RETURN_TYPE.to_string());
+            is_aggregate.push(false);
+            volatilities.push("".to_string());
+            descriptions.push(udf.description);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(arguments),
+            StringType::from_data(return_types),
+            BooleanType::from_data(is_aggregate),
+            StringType::from_data(volatilities),
+            StringType::from_data(descriptions),
+        ]))
+    }
+}
+
+impl FunctionSignaturesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("arguments", TableDataType::String),
+            TableField::new("return_type", TableDataType::String),
+            TableField::new("is_aggregate", TableDataType::Boolean),
+            TableField::new("volatility", TableDataType::String),
+            TableField::new("description", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'function_signatures'".to_string(),
+            name: "function_signatures".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemFunctionSignatures".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(FunctionSignaturesTable { table_info })
+    }
+}
@@ -36,6 +36,17 @@ use common_users::UserApiProvider;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
+pub(crate) const IMMUTABLE: &str = "IMMUTABLE";
+pub(crate) const VOLATILE: &str = "VOLATILE";
+
+/// A function is volatile if it may return different results for the same arguments.
+pub(crate) fn function_volatility(name: &str) -> &'static str {
+    match BUILTIN_FUNCTIONS.get_property(name) {
+        Some(property) if property.non_deterministic => VOLATILE,
+        _ => IMMUTABLE,
+    }
+}
+
 pub struct FunctionsTable {
     table_info: TableInfo,
 }
@@ -88,14 +99,47 @@ impl AsyncSystemTable for FunctionsTable {
             })
             .collect::<Vec<&str>>();
 
+        // The documentation of the builtin functions: (category, description, syntax, example).
+        let docs = names[..builtin_func_len]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                if i < func_names.len() {
+                    BUILTIN_FUNCTIONS
+                        .get_doc(name)
+                        .map_or(("", "", "", ""), |doc| {
+                            (doc.category, doc.description, doc.syntax, doc.example)
+                        })
+                } else {
+                    aggregate_function_factory.get_features(name).map_or(
+                        ("", "", "", ""),
+                        |features| {
+                            (
+                                features.category,
+                                features.description,
+                                features.definition,
+                                features.example,
+                            )
+                        },
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
         let categories = (0..names.len())
-            .map(|i| if i < builtin_func_len { "" } else { "UDF" })
+            .map(|i| {
+                if i < builtin_func_len {
+                    docs[i].0
+                } else {
+                    "UDF"
+                }
+            })
             .collect::<Vec<&str>>();
 
         let descriptions = (0..names.len())
             .map(|i| {
                 if i < builtin_func_len {
-                    ""
+                    docs[i].1
                 } else {
                     udfs.get(i - builtin_func_len)
                         .map_or("", |udf| udf.description.as_str())
@@ -106,7 +150,7 @@ impl AsyncSystemTable for FunctionsTable {
         let syntaxes = (0..names.len())
             .map(|i| {
                 if i < builtin_func_len {
-                    ""
+                    docs[i].2
                 } else {
                     udfs.get(i - builtin_func_len)
                         .map_or("", |udf| udf.definition.as_str())
@@ -114,7 +158,21 @@ impl AsyncSystemTable for FunctionsTable {
             })
             .collect::<Vec<&str>>();
 
-        let examples = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
+        let examples = (0..names.len())
+            .map(|i| if i < builtin_func_len { docs[i].3 } else { "" })
+            .collect::<Vec<&str>>();
+
+        let volatilities = (0..names.len())
+            .map(|i| {
+                if i < func_names.len() {
+                    function_volatility(names[i])
+                } else if i < builtin_func_len {
+                    IMMUTABLE
+                } else {
+                    ""
+                }
+            })
+            .collect::<Vec<&str>>();
 
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(names),
@@ -125,6 +183,7 @@ impl AsyncSystemTable for FunctionsTable {
             StringType::from_data(descriptions),
             StringType::from_data(syntaxes),
             StringType::from_data(examples),
+            StringType::from_data(volatilities),
         ]))
     }
 }
@@ -140,6 +199,7 @@ impl FunctionsTable {
             TableField::new("description", TableDataType::String),
             TableField::new("syntax", TableDataType::String),
             TableField::new("example", TableDataType::String),
+            TableField::new("volatility", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
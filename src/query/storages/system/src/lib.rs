@@ -17,6 +17,7 @@
 
 extern crate core;
 
+mod background_jobs_table;
 mod build_options_table;
 mod caches_table;
 mod catalogs_table;
@@ -28,6 +29,7 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod function_signatures_table;
 mod functions_table;
 mod indexes_table;
 mod log_queue;
@@ -35,19 +37,29 @@ mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
 mod one_table;
+mod pipeline_tasks_table;
+mod plan_baselines_table;
 mod processes_table;
 mod query_cache_table;
 mod query_log_table;
+mod quotas_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
+mod usage_table;
 mod users_table;
 mod util;
 
+pub use background_jobs_table::BackgroundJobState;
+pub use background_jobs_table::BackgroundJobStatus;
+pub use background_jobs_table::BackgroundJobs;
+pub use background_jobs_table::BackgroundJobsTable;
 pub use build_options_table::BuildOptionsTable;
 pub use caches_table::CachesTable;
 pub use catalogs_table::CatalogsTable;
@@ -61,6 +73,7 @@ pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
+pub use function_signatures_table::FunctionSignaturesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
 pub use log_queue::SystemLogElement;
@@ -70,12 +83,15 @@ pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
+pub use pipeline_tasks_table::PipelineTasksTable;
+pub use plan_baselines_table::PlanBaselinesTable;
 pub use processes_table::ProcessesTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
+pub use quotas_table::QuotasTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
@@ -85,5 +101,14 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryLogElement;
+pub use task_history_table::TaskHistoryQueue;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
+pub use usage_table::UsageCollector;
+pub use usage_table::UsageKey;
+pub use usage_table::UsageObjectType;
+pub use usage_table::UsageTable;
+pub use usage_table::UsageValue;
 pub use users_table::UsersTable;
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use itertools::Itertools;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The processors of the pipelines executed by the running queries,
+/// with their scheduling states, the rows passed through them and the edges between them.
+pub struct PipelineTasksTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for PipelineTasksTable {
+    const NAME: &'static str = "system.pipeline_tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tasks = ctx.get_pipeline_tasks_info();
+
+        let mut query_ids = Vec::with_capacity(tasks.len());
+        let mut processor_ids = Vec::with_capacity(tasks.len());
+        let mut processor_names = Vec::with_capacity(tasks.len());
+        let mut states = Vec::with_capacity(tasks.len());
+        let mut input_rows = Vec::with_capacity(tasks.len());
+        let mut output_rows = Vec::with_capacity(tasks.len());
+        let mut inputs = Vec::with_capacity(tasks.len());
+        let mut outputs = Vec::with_capacity(tasks.len());
+
+        for task in tasks {
+            query_ids.push(task.query_id.into_bytes());
+            processor_ids.push(task.processor_id as u64);
+            processor_names.push(task.processor_name.into_bytes());
+            states.push(task.state.into_bytes());
+            input_rows.push(task.input_rows);
+            output_rows.push(task.output_rows);
+            inputs.push(task.inputs.iter().join(", ").into_bytes());
+            outputs.push(task.outputs.iter().join(", ").into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(query_ids),
+            UInt64Type::from_data(processor_ids),
+            StringType::from_data(processor_names),
+            StringType::from_data(states),
+            UInt64Type::from_data(input_rows),
+            UInt64Type::from_data(output_rows),
+            StringType::from_data(inputs),
+            StringType::from_data(outputs),
+        ]))
+    }
+}
+
+impl PipelineTasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new(
+                "processor_id",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("processor_name", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("input_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("output_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("inputs", TableDataType::String),
+            TableField::new("outputs", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipeline_tasks'".to_string(),
+            name: "pipeline_tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipelineTasks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(PipelineTasksTable { table_info })
+    }
+}
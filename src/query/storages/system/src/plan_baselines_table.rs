@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PlanBaselinesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanBaselinesTable {
+    const NAME: &'static str = "system.plan_baselines";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let baselines = UserApiProvider::instance()
+            .get_plan_baselines(&tenant)
+            .await?;
+        let mut id = Vec::with_capacity(baselines.len());
+        let mut database = Vec::with_capacity(baselines.len());
+        let mut query = Vec::with_capacity(baselines.len());
+        let mut joins = Vec::with_capacity(baselines.len());
+        let mut created_on = Vec::with_capacity(baselines.len());
+        for baseline in baselines.into_iter() {
+            id.push(baseline.id.into_bytes());
+            database.push(baseline.database.into_bytes());
+            query.push(baseline.query_text.into_bytes());
+            joins.push(baseline.shape.joins.len() as u64);
+            created_on.push(baseline.created_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(id),
+            StringType::from_data(database),
+            StringType::from_data(query),
+            UInt64Type::from_data(joins),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+}
+
+impl PlanBaselinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("id", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            // The number of the pinned joins
+            TableField::new("joins", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'plan_baselines'".to_string(),
+            name: "plan_baselines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanBaselines".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(PlanBaselinesTable { table_info })
+    }
+}
@@ -91,6 +91,7 @@ pub struct QueryLogElement {
     pub tables: String,
     pub columns: String,
     pub projections: String,
+    pub snapshots: String,
 
     // Stats.
     pub written_rows: u64,
@@ -108,6 +109,13 @@ pub struct QueryLogElement {
     pub cpu_usage: u32,
     pub memory_usage: u64,
 
+    // Anomaly.
+    pub spilled: bool,
+    pub cache_miss_heavy: bool,
+    pub pruning_ineffective: bool,
+    pub skewed_join: bool,
+    pub slow_query_class: String,
+
     // Client.
     pub client_info: String,
     pub client_address: String,
@@ -159,6 +167,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("tables", TableDataType::String),
             TableField::new("columns", TableDataType::String),
             TableField::new("projections", TableDataType::String),
+            TableField::new("snapshots", TableDataType::String),
             // Stats.
             TableField::new(
                 "written_rows",
@@ -204,6 +213,12 @@ impl SystemLogElement for QueryLogElement {
                 "memory_usage",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            // Anomaly.
+            TableField::new("spilled", TableDataType::Boolean),
+            TableField::new("cache_miss_heavy", TableDataType::Boolean),
+            TableField::new("pruning_ineffective", TableDataType::Boolean),
+            TableField::new("skewed_join", TableDataType::Boolean),
+            TableField::new("slow_query_class", TableDataType::String),
             // Client.
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
@@ -305,6 +320,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.projections.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.snapshots.as_bytes().to_vec()).as_ref());
         // Stats.
         columns
             .next()
@@ -362,6 +381,27 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::Number(NumberScalar::UInt64(self.memory_usage)).as_ref());
+        // Anomaly.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.spilled).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.cache_miss_heavy).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.pruning_ineffective).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.skewed_join).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.slow_query_class.as_bytes().to_vec()).as_ref());
         // Client.
         columns
             .next()
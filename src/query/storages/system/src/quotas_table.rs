@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct QuotasTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for QuotasTable {
+    const NAME: &'static str = "system.quotas";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let quota = UserApiProvider::instance()
+            .get_tenant_quota_api_client(&tenant)?
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data;
+
+        let quotas = [
+            ("max_databases", quota.max_databases as u64),
+            (
+                "max_tables_per_database",
+                quota.max_tables_per_database as u64,
+            ),
+            ("max_stages", quota.max_stages as u64),
+            ("max_files_per_stage", quota.max_files_per_stage as u64),
+            ("max_users", quota.max_users as u64),
+            (
+                "max_storage_bytes_per_database",
+                quota.max_storage_bytes_per_database,
+            ),
+            (
+                "max_storage_bytes_per_table",
+                quota.max_storage_bytes_per_table,
+            ),
+            (
+                "max_storage_bytes_per_stage",
+                quota.max_storage_bytes_per_stage,
+            ),
+        ];
+        let names: Vec<Vec<u8>> = quotas
+            .iter()
+            .map(|(name, _)| name.as_bytes().to_vec())
+            .collect();
+        let values: Vec<u64> = quotas.iter().map(|(_, value)| *value).collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(values),
+        ]))
+    }
+}
+
+impl QuotasTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            // 0 means unlimited
+            TableField::new("value", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'quotas'".to_string(),
+            name: "quotas".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQuotas".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(QuotasTable { table_info })
+    }
+}
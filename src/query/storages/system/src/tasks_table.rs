@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+        let mut name = Vec::with_capacity(tasks.len());
+        let mut database = Vec::with_capacity(tasks.len());
+        let mut schedule = Vec::with_capacity(tasks.len());
+        let mut after = Vec::with_capacity(tasks.len());
+        let mut state = Vec::with_capacity(tasks.len());
+        let mut owner = Vec::with_capacity(tasks.len());
+        let mut comment = Vec::with_capacity(tasks.len());
+        let mut created_on = Vec::with_capacity(tasks.len());
+        let mut definition = Vec::with_capacity(tasks.len());
+        for task in tasks.into_iter() {
+            name.push(task.name.into_bytes());
+            database.push(task.database.into_bytes());
+            schedule.push(task.schedule.map(|s| s.into_bytes()));
+            after.push(task.after.join(", ").into_bytes());
+            let task_state = if task.suspended {
+                "suspended"
+            } else {
+                "started"
+            };
+            state.push(task_state.as_bytes().to_vec());
+            owner.push(task.owner.into_bytes());
+            comment.push(task.comment.into_bytes());
+            created_on.push(task.created_on.timestamp_micros());
+            definition.push(task.query_text.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(name),
+            StringType::from_data(database),
+            StringType::from_opt_data(schedule),
+            StringType::from_data(after),
+            StringType::from_data(state),
+            StringType::from_data(owner),
+            StringType::from_data(comment),
+            TimestampType::from_data(created_on),
+            StringType::from_data(definition),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            // NULL for the tasks running after other tasks
+            TableField::new(
+                "schedule",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("after", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("definition", TableDataType::String),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TasksTable { table_info })
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DateType;
+use common_expression::types::Float64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// Days of daily rollups kept in memory, older days are evicted on the next record.
+const USAGE_RETENTION_DAYS: i32 = 31;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UsageObjectType {
+    User,
+    Table,
+    Stage,
+}
+
+impl UsageObjectType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageObjectType::User => "user",
+            UsageObjectType::Table => "table",
+            UsageObjectType::Stage => "stage",
+        }
+    }
+}
+
+/// The rollup key: one row per day, tenant, user and object.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UsageKey {
+    /// Days since the unix epoch.
+    pub usage_date: i32,
+    pub tenant: String,
+    pub user: String,
+    pub object_type: UsageObjectType,
+    /// Empty for users and stages.
+    pub database: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct UsageValue {
+    pub queries: u64,
+    pub scan_io_bytes: u64,
+    pub written_io_bytes: u64,
+    pub compute_ms: f64,
+}
+
+impl UsageValue {
+    pub fn merge(&mut self, other: &UsageValue) {
+        self.queries += other.queries;
+        self.scan_io_bytes += other.scan_io_bytes;
+        self.written_io_bytes += other.written_io_bytes;
+        self.compute_ms += other.compute_ms;
+    }
+}
+
+/// Node-local accumulator of the usage rows shown by `system.usage`.
+pub struct UsageCollector {
+    entries: RwLock<BTreeMap<UsageKey, UsageValue>>,
+}
+
+static USAGE_COLLECTOR: OnceCell<Arc<UsageCollector>> = OnceCell::new();
+
+impl UsageCollector {
+    pub fn instance() -> Arc<UsageCollector> {
+        USAGE_COLLECTOR
+            .get_or_init(|| {
+                Arc::new(UsageCollector {
+                    entries: RwLock::new(BTreeMap::new()),
+                })
+            })
+            .clone()
+    }
+
+    pub fn record(&self, key: UsageKey, value: UsageValue) {
+        let mut entries = self.entries.write();
+
+        let expire_before = key.usage_date - USAGE_RETENTION_DAYS;
+        while let Some((first, _)) = entries.first_key_value() {
+            if first.usage_date > expire_before {
+                break;
+            }
+            entries.pop_first();
+        }
+
+        entries.entry(key).or_default().merge(&value);
+    }
+
+    pub fn entries(&self) -> Vec<(UsageKey, UsageValue)> {
+        let entries = self.entries.read();
+        entries
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+pub struct UsageTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for UsageTable {
+    const NAME: &'static str = "system.usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let entries = UsageCollector::instance().entries();
+
+        let mut usage_dates = Vec::with_capacity(entries.len());
+        let mut tenants = Vec::with_capacity(entries.len());
+        let mut users = Vec::with_capacity(entries.len());
+        let mut object_types = Vec::with_capacity(entries.len());
+        let mut databases = Vec::with_capacity(entries.len());
+        let mut names = Vec::with_capacity(entries.len());
+        let mut queries = Vec::with_capacity(entries.len());
+        let mut scan_io_bytes = Vec::with_capacity(entries.len());
+        let mut written_io_bytes = Vec::with_capacity(entries.len());
+        let mut compute_seconds = Vec::with_capacity(entries.len());
+
+        for (key, value) in entries {
+            usage_dates.push(key.usage_date);
+            tenants.push(key.tenant.into_bytes());
+            users.push(key.user.into_bytes());
+            object_types.push(key.object_type.as_str().as_bytes().to_vec());
+            databases.push(key.database.into_bytes());
+            names.push(key.name.into_bytes());
+            queries.push(value.queries);
+            scan_io_bytes.push(value.scan_io_bytes);
+            written_io_bytes.push(value.written_io_bytes);
+            compute_seconds.push(value.compute_ms / 1000.0);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            DateType::from_data(usage_dates),
+            StringType::from_data(tenants),
+            StringType::from_data(users),
+            StringType::from_data(object_types),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            UInt64Type::from_data(queries),
+            UInt64Type::from_data(scan_io_bytes),
+            UInt64Type::from_data(written_io_bytes),
+            Float64Type::from_data(compute_seconds),
+        ]))
+    }
+}
+
+impl UsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("usage_date", TableDataType::Date),
+            TableField::new("tenant", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("object_type", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("queries", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "scan_io_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "written_io_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "compute_seconds",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'usage'".to_string(),
+            name: "usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -35,8 +35,10 @@ mod role_mgr;
 mod user;
 mod user_api;
 mod user_mgr;
+mod user_plan_baseline;
 mod user_setting;
 mod user_stage;
+mod user_task;
 mod user_udf;
 
 pub mod file_format;
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_meta_app::principal::PlanBaseline;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Plan baseline operations.
+impl UserApiProvider {
+    // Add a plan baseline, or replace the existing one of the query.
+    #[async_backtrace::framed]
+    pub async fn upsert_plan_baseline(&self, tenant: &str, info: PlanBaseline) -> Result<u64> {
+        let client = self.get_plan_baseline_api_client(tenant)?;
+        let upsert_plan_baseline = client.upsert_plan_baseline(info);
+        match upsert_plan_baseline.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while upsert plan baseline).")),
+        }
+    }
+
+    // Get the plan baseline by id.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline(&self, tenant: &str, id: &str) -> Result<Option<PlanBaseline>> {
+        let client = self.get_plan_baseline_api_client(tenant)?;
+        let get_plan_baseline = client.get_plan_baseline(id);
+        get_plan_baseline.await
+    }
+
+    // Get all plan baselines for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baselines(&self, tenant: &str) -> Result<Vec<PlanBaseline>> {
+        let client = self.get_plan_baseline_api_client(tenant)?;
+        let get_plan_baselines = client.get_plan_baselines();
+
+        match get_plan_baselines.await {
+            Err(e) => Err(e.add_message_back("(while get plan baselines).")),
+            Ok(baselines) => Ok(baselines),
+        }
+    }
+
+    // Drop the plan baseline by id.
+    #[async_backtrace::framed]
+    pub async fn drop_plan_baseline(&self, tenant: &str, id: &str) -> Result<()> {
+        let client = self.get_plan_baseline_api_client(tenant)?;
+        let drop_plan_baseline = client.drop_plan_baseline(id, MatchSeq::GE(1));
+        match drop_plan_baseline.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while drop plan baseline)")),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::Task;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Task operations.
+impl UserApiProvider {
+    // Add a new task.
+    #[async_backtrace::framed]
+    pub async fn add_task(&self, tenant: &str, info: Task, if_not_exists: bool) -> Result<u64> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let add_task = task_api_client.add_task(info);
+        match add_task.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TASK_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a task.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, tenant: &str, info: Task) -> Result<u64> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let update_task = task_api_client.update_task(info, MatchSeq::GE(1));
+        match update_task.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update task).")),
+        }
+    }
+
+    // Get a task by name.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &str, task_name: &str) -> Result<Task> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let get_task = task_api_client.get_task(task_name, MatchSeq::GE(0));
+        Ok(get_task.await?.data)
+    }
+
+    // Get all tasks for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<Task>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let get_tasks = task_api_client.get_tasks();
+
+        match get_tasks.await {
+            Err(e) => Err(e.add_message_back("(while get tasks).")),
+            Ok(tasks) => Ok(tasks),
+        }
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &str, task_name: &str, if_exists: bool) -> Result<()> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        let drop_task = task_api_client.drop_task(task_name, MatchSeq::GE(1));
+        match drop_task.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop task)"))
+                }
+            }
+        }
+    }
+}
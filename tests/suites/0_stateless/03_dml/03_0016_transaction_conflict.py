@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+
+import mysql.connector
+
+# client1 mutates a table in a transaction, client2 commits to the same table before
+# client1 commits. The commit of client1 must fail and leave the table as client2 wrote it.
+
+client1 = mysql.connector.connect(
+    host="127.0.0.1", user="root", passwd="root", port="3307"
+)
+client2 = mysql.connector.connect(
+    host="127.0.0.1", user="root", passwd="root", port="3307"
+)
+
+cursor1 = client1.cursor()
+cursor2 = client2.cursor()
+
+cursor1.execute("DROP DATABASE IF EXISTS db_03_0016")
+cursor1.execute("CREATE DATABASE db_03_0016")
+cursor1.execute("CREATE TABLE db_03_0016.t1(a int)")
+cursor1.execute("CREATE TABLE db_03_0016.t2(b int)")
+
+cursor1.execute("BEGIN")
+cursor1.execute("INSERT INTO db_03_0016.t1 VALUES (1)")
+cursor1.execute("INSERT INTO db_03_0016.t2 VALUES (10)")
+
+# a concurrent commit of client2 outside of any transaction
+cursor2.execute("INSERT INTO db_03_0016.t1 VALUES (2)")
+
+try:
+    cursor1.execute("COMMIT")
+    print("commit succeeded")
+except mysql.connector.Error as e:
+    assert "Code: 2019" in e.msg, e.msg
+    print("commit conflicted")
+
+# the transaction is rolled back as a whole
+cursor2.execute("SELECT a FROM db_03_0016.t1 ORDER BY a")
+print(cursor2.fetchall())
+cursor2.execute("SELECT count(*) FROM db_03_0016.t2")
+print(cursor2.fetchall())
+
+# the session of client1 is usable again
+cursor1.execute("INSERT INTO db_03_0016.t1 VALUES (3)")
+cursor1.execute("SELECT a FROM db_03_0016.t1 ORDER BY a")
+print(cursor1.fetchall())
+
+cursor1.execute("DROP DATABASE db_03_0016")
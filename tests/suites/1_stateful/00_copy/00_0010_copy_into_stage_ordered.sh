@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "drop STAGE if exists s_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s_ordered;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE t_ordered (a INT, b VARCHAR);" | $MYSQL_CLIENT_CONNECT
+
+for i in `seq 1 5`;do
+    echo "insert into t_ordered select number * 5 + $i, to_string(number) from numbers(2000);" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "copy into @s_ordered from (select a, b from t_ordered order by a desc) FILE_FORMAT = (type = CSV) MAX_FILE_SIZE = 1000;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- manifest"
+echo "list @s_ordered;" | $MYSQL_CLIENT_CONNECT | grep -c "_manifest.json"
+
+DATADIR=$(mktemp -d)
+aws --endpoint-url ${STORAGE_S3_ENDPOINT_URL} s3 cp s3://testbucket/admin/stage/internal/s_ordered $DATADIR --recursive > /dev/null 2>&1
+
+echo "--- more than one file"
+if [ `ls $DATADIR/*.csv | wc -l` -gt 1 ];then
+    echo "true"
+fi
+
+echo "--- rows are ordered across files"
+cat `ls $DATADIR/*.csv | sort` | cut -d, -f1 > $DATADIR/unloaded
+wc -l < $DATADIR/unloaded | sed 's/ //g'
+sort -n -r $DATADIR/unloaded | cmp - $DATADIR/unloaded && echo "ordered"
+
+rm -rf $DATADIR
+
+echo "drop STAGE s_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "drop table t_ordered;" | $MYSQL_CLIENT_CONNECT
+
+aws --endpoint-url ${STORAGE_S3_ENDPOINT_URL} s3 rm s3://testbucket/admin/stage/internal/s_ordered --recursive  > /dev/null 2>&1
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t_partitioned;" | $MYSQL_CLIENT_CONNECT
+echo "drop STAGE if exists s_partitioned;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s_partitioned;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE t_partitioned (id INT, dt DATE, region VARCHAR NULL);" | $MYSQL_CLIENT_CONNECT
+echo "insert into t_partitioned values (1, '2023-06-01', 'us'), (2, '2023-06-01', 'eu'), (3, '2023-06-02', 'us'), (4, '2023-06-02', NULL), (5, '2023-06-01', 'us');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- partition by columns"
+echo "copy into @s_partitioned/by_columns from t_partitioned FILE_FORMAT = (type = PARQUET) PARTITION BY (dt, region) FILE_NAME_PREFIX = 'part_';" | $MYSQL_CLIENT_CONNECT
+echo "list @s_partitioned/by_columns;" | $MYSQL_CLIENT_CONNECT | awk '{print $1}' | sed -E 's/_[0-9a-f-]{36}_[0-9]{4}_[0-9]{8}\./_*./' | sort -u
+echo "select id, dt, region from @s_partitioned/by_columns (file_format => 'parquet', pattern => '.*region=us/.*') order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- partition by expressions"
+echo "copy into @s_partitioned/by_exprs from (select id, dt from t_partitioned) FILE_FORMAT = (type = CSV) PARTITION BY (to_yyyymm(dt) AS month, id % 2 = 0 AS even);" | $MYSQL_CLIENT_CONNECT
+echo "list @s_partitioned/by_exprs;" | $MYSQL_CLIENT_CONNECT | awk '{print $1}' | sed -E 's/_[0-9a-f-]{36}_[0-9]{4}_[0-9]{8}\./_*./' | sort -u
+
+echo "--- max file size"
+echo "copy into @s_partitioned/max_file_size from (select number, number % 2 as k from numbers(10000)) FILE_FORMAT = (type = CSV) PARTITION BY (k) MAX_FILE_SIZE = 1000;" | $MYSQL_CLIENT_CONNECT
+if [ `echo "list @s_partitioned/max_file_size PATTERN = '.*k=0/.*';" | $MYSQL_CLIENT_CONNECT | wc -l` -gt 1 ];then
+    echo "true"
+fi
+echo "select count(*), sum(\$1::UInt64) from @s_partitioned/max_file_size (file_format => 'csv', pattern => '.*k=1/.*');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- errors"
+echo "copy into @s_partitioned/errors from t_partitioned PARTITION BY (id + 1);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "must have an alias"
+echo "copy into @s_partitioned/errors from t_partitioned PARTITION BY (dt) SINGLE = true;" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "SINGLE can not be used with PARTITION BY"
+
+echo "drop STAGE s_partitioned;" | $MYSQL_CLIENT_CONNECT
+echo "drop table t_partitioned;" | $MYSQL_CLIENT_CONNECT
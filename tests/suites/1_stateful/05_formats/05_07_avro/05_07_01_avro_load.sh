@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/05_07_01/"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+
+cp "$CURDIR"/../../../../data/sample.avro ${DATADIR_PATH}/orders.avro
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_avro" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_avro (
+  id          BIGINT,
+  name        VARCHAR,
+  amount      DECIMAL(10, 2),
+  created_at  TIMESTAMP,
+  order_id    UUID,
+  day         DATE,
+  status      VARCHAR,
+  tags        ARRAY(STRING),
+  note        VARCHAR NULL,
+  missing     INT NULL
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+echo "create stage s_avro url = '${DATADIR}' FILE_FORMAT = (type = AVRO);" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into test_avro from @s_avro;" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_avro order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- select from stage:"
+echo "select \$1:name, \$1:amount from @s_avro order by \$1:id;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_avro" | $MYSQL_CLIENT_CONNECT
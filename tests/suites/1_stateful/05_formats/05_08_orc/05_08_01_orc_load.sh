@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+DATADIR_PATH="/tmp/05_08_01/"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+
+cp "$CURDIR"/../../../../data/sample.orc ${DATADIR_PATH}/orders.orc
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_orc" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_orc (
+  id          BIGINT,
+  name        VARCHAR,
+  amount      DECIMAL(10, 2),
+  created_at  TIMESTAMP,
+  day         DATE,
+  flag        BOOLEAN NULL,
+  score       DOUBLE,
+  tags        ARRAY(STRING),
+  attrs       MAP(STRING, INT NULL),
+  point       TUPLE(x INT, y INT NULL) NULL,
+  note        VARCHAR NULL,
+  missing     INT NULL
+) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+
+echo "create stage s_orc url = '${DATADIR}' FILE_FORMAT = (type = ORC);" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into test_orc from @s_orc;" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- projection:"
+echo "drop table if exists test_orc_part" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_orc_part (NAME VARCHAR, score DOUBLE) ENGINE=FUSE;" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_orc_part from @s_orc;" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc_part order by name;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- select from stage:"
+echo "select \$1:name, \$1:amount, \$1:point from @s_orc order by \$1:id;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_orc_part" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_orc" | $MYSQL_CLIENT_CONNECT
@@ -38,3 +38,38 @@ echo "--- complex:"
 cp "$CURDIR"/../../../../data/complex.parquet ${DATADIR_PATH}/data/complex.parquet
 echo "select * from infer_schema(location => '@s2/data/', pattern => 'complex.*');" | $MYSQL_CLIENT_CONNECT
 
+
+echo "--- csv:"
+mkdir ${DATADIR_PATH}/text
+cp "$CURDIR"/../../../../data/sample.csv ${DATADIR_PATH}/text/sample.csv
+echo "select * from infer_schema(location => '@s3/text/sample.csv');" | $MYSQL_CLIENT_CONNECT
+echo "select * from infer_schema(location => '@s3/text/sample.csv', max_records => 1);" | $MYSQL_CLIENT_CONNECT
+
+echo "--- ndjson:"
+cp "$CURDIR"/../../../../data/json_sample.ndjson ${DATADIR_PATH}/text/json_sample.ndjson
+echo "drop file format if exists ndjson_infer;" | $MYSQL_CLIENT_CONNECT
+echo "create file format ndjson_infer type = NDJSON;" | $MYSQL_CLIENT_CONNECT
+echo "select * from infer_schema(location => '@s3/text/json_sample.ndjson', file_format => 'ndjson_infer');" | $MYSQL_CLIENT_CONNECT
+echo "drop file format if exists ndjson_infer;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- avro:"
+mkdir ${DATADIR_PATH}/avro
+cp "$CURDIR"/../../../../data/sample.avro ${DATADIR_PATH}/avro/sample.avro
+echo "select * from infer_schema(location => '@s3/avro/', FILE_FORMAT => 'AVRO');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- orc:"
+mkdir ${DATADIR_PATH}/orc
+cp "$CURDIR"/../../../../data/sample.orc ${DATADIR_PATH}/orc/sample.orc
+echo "select * from infer_schema(location => '@s3/orc/', FILE_FORMAT => 'ORC');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- max_files:"
+mkdir ${DATADIR_PATH}/multi
+cp "$CURDIR"/../../../../data/tuple.parquet ${DATADIR_PATH}/multi/tuple_1.parquet
+cp "$CURDIR"/../../../../data/tuple.parquet ${DATADIR_PATH}/multi/tuple_2.parquet
+echo "select * from infer_schema(location => '@s2/multi/', max_files => 2);" | $MYSQL_CLIENT_CONNECT
+mkdir ${DATADIR_PATH}/multi_csv
+cp "$CURDIR"/../../../../data/sample.csv ${DATADIR_PATH}/multi_csv/sample_1.csv
+printf "4,'Shenzhen',70.5,\n" > ${DATADIR_PATH}/multi_csv/sample_2.csv
+echo "select * from infer_schema(location => '@s3/multi_csv/', max_files => 10);" | $MYSQL_CLIENT_CONNECT
+cp "$CURDIR"/../../../../data/complex.parquet ${DATADIR_PATH}/multi/complex.parquet
+echo "select * from infer_schema(location => '@s2/multi/', max_files => 3);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "conflicting columns: column 'id'"
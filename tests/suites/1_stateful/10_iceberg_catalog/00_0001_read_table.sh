@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS iceberg_ctl" | $MYSQL_CLIENT_CONNECT
+
+## Create iceberg catalog on the fixture table in tests/data/iceberg
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='s3://testbucket/iceberg_data/iceberg_ctl/'
+    AWS_KEY_ID='minioadmin'
+    AWS_SECRET_KEY='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+);
+EOF
+
+## The schema is mapped from the latest iceberg schema, with the added column
+echo "DESC iceberg_ctl.iceberg_db.iceberg_tbl;" | $MYSQL_CLIENT_CONNECT
+
+## Read the current snapshot
+echo "SELECT * FROM iceberg_ctl.iceberg_db.iceberg_tbl ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_db.iceberg_tbl WHERE comment IS NULL;" | $MYSQL_CLIENT_CONNECT
+
+## Read the snapshot of the INSERT OVERWRITE, before the last append
+echo "SELECT * FROM iceberg_ctl.iceberg_db.iceberg_tbl AT (SNAPSHOT => '2966623707104393227') ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "DROP CATALOG iceberg_ctl" | $MYSQL_CLIENT_CONNECT
@@ -65,7 +65,8 @@ if __name__ == "__main__":
 
         mycursor.execute("vacuum table gc_test retain 0 hours dry run;")
         datas = mycursor.fetchall()
-        print(datas)
+        # each candidate file is listed with its size
+        print(all(len(row) == 2 for row in datas))
 
         mycursor.execute("select a from gc_test order by a;")
         datas = mycursor.fetchall()